@@ -7,7 +7,10 @@ use polars::prelude::*;
 use rand::prelude::*;
 use rand::SeedableRng;
 
-use lophi::pipeline::{analyze_features_iv, BinningStrategy, MonotonicityConstraint, SolverConfig};
+use lophi::pipeline::{
+    analyze_features_iv, BinningBackend, BinningStrategy, MissingBinPolicy, MonotonicityConstraint,
+    SolverConfig,
+};
 
 /// Generate synthetic data with controlled characteristics
 fn generate_test_dataframe(n_rows: usize, n_features: usize, seed: u64) -> DataFrame {
@@ -85,6 +88,9 @@ fn benchmark_binning_strategies(c: &mut Criterion) {
                         black_box(&weights),
                         black_box(None),
                         black_box(None),
+                        black_box(None),
+                        black_box(MissingBinPolicy::Separate),
+                        black_box(None),
                     );
                 });
             },
@@ -107,6 +113,9 @@ fn benchmark_binning_strategies(c: &mut Criterion) {
                         black_box(&weights),
                         black_box(None),
                         black_box(None),
+                        black_box(None),
+                        black_box(MissingBinPolicy::Separate),
+                        black_box(None),
                     );
                 });
             },
@@ -142,6 +151,9 @@ fn benchmark_single_feature(c: &mut Criterion) {
                     black_box(&weights),
                     black_box(None),
                     black_box(None),
+                    black_box(None),
+                    black_box(MissingBinPolicy::Separate),
+                    black_box(None),
                 );
             });
         });
@@ -160,6 +172,9 @@ fn benchmark_single_feature(c: &mut Criterion) {
                     black_box(&weights),
                     black_box(None),
                     black_box(None),
+                    black_box(None),
+                    black_box(MissingBinPolicy::Separate),
+                    black_box(None),
                 );
             });
         });
@@ -194,6 +209,9 @@ fn benchmark_bin_counts(c: &mut Criterion) {
                         black_box(&weights),
                         black_box(None),
                         black_box(None),
+                        black_box(None),
+                        black_box(MissingBinPolicy::Separate),
+                        black_box(None),
                     );
                 });
             },
@@ -216,6 +234,9 @@ fn benchmark_bin_counts(c: &mut Criterion) {
                         black_box(&weights),
                         black_box(None),
                         black_box(None),
+                        black_box(None),
+                        black_box(MissingBinPolicy::Separate),
+                        black_box(None),
                     );
                 });
             },
@@ -225,7 +246,7 @@ fn benchmark_bin_counts(c: &mut Criterion) {
     group.finish();
 }
 
-/// Benchmark comparing greedy merge vs MIP solver optimization
+/// Benchmark comparing greedy merge vs the MIP and dynamic-programming backends
 fn benchmark_greedy_vs_solver(c: &mut Criterion) {
     let mut group = c.benchmark_group("greedy_vs_solver");
     group.sample_size(20); // Fewer samples due to solver time
@@ -238,6 +259,14 @@ fn benchmark_greedy_vs_solver(c: &mut Criterion) {
         gap_tolerance: 0.01,
         monotonicity: MonotonicityConstraint::None,
         min_bin_samples: 5,
+        min_bin_pct: 0.0,
+        max_bin_pct: 100.0,
+        min_woe_delta: 0.0,
+        backend: BinningBackend::Mip,
+    };
+    let dp_config = SolverConfig {
+        backend: BinningBackend::Dp,
+        ..solver_config.clone()
     };
 
     for (n_rows, n_features) in sizes {
@@ -263,6 +292,9 @@ fn benchmark_greedy_vs_solver(c: &mut Criterion) {
                         black_box(&weights),
                         black_box(None),
                         black_box(None), // No solver
+                        black_box(None),
+                        black_box(MissingBinPolicy::Separate),
+                        black_box(None),
                     );
                 });
             },
@@ -286,6 +318,35 @@ fn benchmark_greedy_vs_solver(c: &mut Criterion) {
                         black_box(&weights),
                         black_box(None),
                         black_box(Some(&solver_config)),
+                        black_box(None),
+                        black_box(MissingBinPolicy::Separate),
+                        black_box(None),
+                    );
+                });
+            },
+        );
+
+        // Dynamic program, same model without HiGHS
+        group.bench_with_input(
+            BenchmarkId::new("dp", format!("{}x{}", n_rows, n_features)),
+            &df,
+            |b, df| {
+                b.iter(|| {
+                    let _ = analyze_features_iv(
+                        black_box(df),
+                        black_box("target"),
+                        black_box(10),
+                        black_box(20),
+                        black_box(None),
+                        black_box(BinningStrategy::Cart),
+                        black_box(None),
+                        black_box(None),
+                        black_box(&weights),
+                        black_box(None),
+                        black_box(Some(&dp_config)),
+                        black_box(None),
+                        black_box(MissingBinPolicy::Separate),
+                        black_box(None),
                     );
                 });
             },
@@ -316,6 +377,10 @@ fn benchmark_monotonicity_impact(c: &mut Criterion) {
             gap_tolerance: 0.01,
             monotonicity,
             min_bin_samples: 5,
+            min_bin_pct: 0.0,
+            max_bin_pct: 100.0,
+            min_woe_delta: 0.0,
+            backend: BinningBackend::Mip,
         };
 
         group.bench_with_input(BenchmarkId::new("solver", name), &config, |b, config| {
@@ -332,6 +397,9 @@ fn benchmark_monotonicity_impact(c: &mut Criterion) {
                     black_box(&weights),
                     black_box(None),
                     black_box(Some(config)),
+                    black_box(None),
+                    black_box(MissingBinPolicy::Separate),
+                    black_box(None),
                 );
             });
         });
@@ -366,6 +434,9 @@ fn benchmark_prebins_count(c: &mut Criterion) {
                         black_box(&weights),
                         black_box(None),
                         black_box(None),
+                        black_box(None),
+                        black_box(MissingBinPolicy::Separate),
+                        black_box(None),
                     );
                 });
             },
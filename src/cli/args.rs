@@ -3,8 +3,18 @@
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
+use crate::pipeline::{
+    dataset_stem, is_compressed_csv, output_anchor, parse_csv_quote, parse_csv_separator,
+    validate_csv_date_format, BinBreaks, BinningStrategy, CrossValidation, CsvDialect,
+    FairnessCheck, IvStabilityCheck, ParquetCodec, ParquetOptions, ParquetStatistics, PcaSettings,
+    SampleSize, ScreeningSample, ScreeningStep, SpecialValues, StepPlan, TimeFreq,
+    TimeStabilityCheck, DEFAULT_CV_FOLDS, DEFAULT_PCA_LOADING_THRESHOLD,
+    NO_TARGET_DOMINANCE_THRESHOLD,
+};
+use crate::report::{PlotFormat, PlotSettings, TableExports, TableFormat};
+
 /// Lo-phi - Feature reduction tool with guided wizard interface
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Clone)]
 #[command(name = "lophi")]
 #[command(author, version, about, long_about = Some("\
 Lo-phi - Feature reduction tool with guided wizard interface\n\n\
@@ -18,15 +28,34 @@ pub struct Cli {
     #[command(subcommand)]
     pub command: Option<Commands>,
 
-    /// Input file path (CSV, Parquet, or SAS7BDAT)
+    /// Pipeline config file (TOML or YAML) with default values for any of the flags below.
+    /// Flags given on the command line override values from the file.
+    /// Generate a template with `lophi init-config`.
+    #[arg(long, global = true)]
+    pub config: Option<PathBuf>,
+
+    /// Input file path (CSV, also as .csv.gz/.csv.zst/.zip, Parquet, Feather, JSON Lines,
+    /// SAS7BDAT, Excel, SPSS or Stata)
     #[arg(short, long)]
     pub input: Option<PathBuf>,
 
+    /// Worksheet to read when the input is an Excel workbook (.xlsx, .xlsm, .xls).
+    /// Defaults to the first sheet; the interactive modes ask when there are several.
+    #[arg(long)]
+    pub sheet: Option<String>,
+
     /// Target column name (preserved during reduction).
     /// If not provided, will be selected interactively from available columns.
     #[arg(short, long)]
     pub target: Option<String>,
 
+    /// Reduce without a target, e.g. for clustering or segmentation work.
+    /// Runs the near-constant pre-filter (at 0.995 unless --dominance-threshold
+    /// is set), missing analysis and correlation analysis, which also drops
+    /// duplicated features; the Gini/IV step is skipped.
+    #[arg(long, default_value = "false", conflicts_with_all = ["target", "event_value", "non_event_value"])]
+    pub no_target: bool,
+
     /// Value in target column that represents EVENT (maps to 1).
     /// Required with --non-event-value when target is not binary 0/1.
     #[arg(long)]
@@ -37,18 +66,63 @@ pub struct Cli {
     #[arg(long)]
     pub non_event_value: Option<String>,
 
+    /// Name of the event class (target = 1) in reports, e.g. "default".
+    /// Requires --non-event-label.
+    #[arg(long)]
+    pub event_label: Option<String>,
+
+    /// Name of the non-event class (target = 0) in reports, e.g. "good".
+    /// Requires --event-label.
+    #[arg(long)]
+    pub non_event_label: Option<String>,
+
+    /// Target type: "binary" (default; features scored by IV/Gini) or "continuous"
+    /// (regression; features scored by binned R², with --gini-threshold as the
+    /// minimum R²).
+    #[arg(long, default_value = "binary")]
+    pub target_type: String,
+
     /// Column containing sample weights for weighted analysis.
     /// When specified, all calculations (missing ratio, IV/Gini, correlation)
     /// use weighted statistics. Default: equal weights of 1.0 for all rows.
     #[arg(short = 'w', long)]
     pub weight_column: Option<String>,
 
-    /// Output file path (CSV or Parquet, determined by extension).
+    /// Output file path (CSV, Parquet, Feather or SAS XPT, determined by extension).
     /// Defaults to input directory with '_reduced' suffix (e.g., data.csv → data_reduced.csv).
-    /// SAS7BDAT input defaults to Parquet output.
+    /// SAS7BDAT, Excel, SPSS and Stata input default to Parquet output.
     #[arg(short, long)]
     pub output: Option<PathBuf>,
 
+    /// Dominance threshold - before missing analysis, drop constant features and
+    /// features where one value covers more than this share of rows (e.g. 0.995).
+    /// The pre-filter is skipped when not set.
+    #[arg(long, value_parser = validate_threshold)]
+    pub dominance_threshold: Option<f64>,
+
+    /// ID threshold - flag string, categorical, integer and date columns whose
+    /// distinct values make up more than this share of their non-null values
+    /// (likely IDs, free text or timestamps; e.g. 0.95). Skipped when not set.
+    #[arg(long, value_parser = validate_threshold)]
+    pub id_threshold: Option<f64>,
+
+    /// What to do with the columns --id-threshold flags: "flag" (warn and
+    /// list them in the report) or "drop" (drop them before missing analysis)
+    #[arg(long, default_value = "flag")]
+    pub id_action: String,
+
+    /// Screening steps to run, in order (comma-separated): "missing", "gini"
+    /// and "correlation". A step left out is skipped and listed as such in
+    /// the report. Default: missing,gini,correlation
+    #[arg(long, value_delimiter = ',', value_name = "STEP")]
+    pub steps: Vec<String>,
+
+    /// Skip the Gini/IV (R²) step and the checks that build on it (IV
+    /// stability, cross-validation, time stability, proxy screen), e.g. for
+    /// exploratory runs; same as leaving "gini" out of --steps
+    #[arg(long, default_value = "false")]
+    pub skip_gini: bool,
+
     /// Missing value threshold - drop features with missing values above this ratio
     #[arg(long, default_value = "0.3", value_parser = validate_threshold)]
     pub missing_threshold: f64,
@@ -57,6 +131,26 @@ pub struct Cli {
     #[arg(long, default_value = "0.40", value_parser = validate_threshold)]
     pub correlation_threshold: f64,
 
+    /// Cramér's V threshold for categorical-categorical pairs (defaults to --correlation-threshold)
+    #[arg(long, value_parser = validate_threshold)]
+    pub cramers_v_threshold: Option<f64>,
+
+    /// How correlated features are resolved: "pairwise" drops one member of
+    /// each pair in turn; "cluster" groups features linked by correlated pairs
+    /// and keeps only the highest-IV member of each group
+    #[arg(long, default_value = "pairwise")]
+    pub correlation_strategy: String,
+
+    /// Which member of a correlated pair is kept: "higher_iv", "lower_missing",
+    /// "first_alphabetical" or "custom_list" (by --corr-keep-prefixes)
+    #[arg(long, default_value = "higher_iv")]
+    pub corr_keep_policy: String,
+
+    /// Name prefixes in order of preference for --corr-keep-policy custom_list
+    /// (comma-separated), e.g. bureau_,internal_
+    #[arg(long, value_delimiter = ',')]
+    pub corr_keep_prefixes: Vec<String>,
+
     /// Gini threshold - drop features with Gini below this value (calculated via WoE binning)
     #[arg(long, default_value = "0.05", value_parser = validate_threshold)]
     pub gini_threshold: f64,
@@ -66,21 +160,89 @@ pub struct Cli {
     pub gini_bins: usize,
 
     /// Binning strategy for Gini/IV calculation.
-    /// Options: "cart" (decision tree splits, default) or "quantile" (equal-frequency)
+    /// Options: "cart" (decision tree splits, default), "quantile" (equal-frequency),
+    /// "equal_width" (equal-length intervals) or "manual" (breakpoints from --bin-breaks)
     #[arg(long, default_value = "cart")]
     pub binning_strategy: String,
 
+    /// Breakpoints for --binning-strategy manual. B1,B2,... applies to every numeric
+    /// feature, FEATURE:B1,B2,... to one feature only; repeat the flag for more features.
+    /// Numeric features without breakpoints use CART binning.
+    #[arg(long, allow_hyphen_values = true, value_name = "[FEATURE:]B1,B2,...")]
+    pub bin_breaks: Vec<String>,
+
+    /// Bins from a scoring spec or Gini analysis JSON export of an earlier run.
+    /// The Gini step skips the binning search and evaluates IV, WoE and Gini of
+    /// each feature on exactly these bins; features without bins are not scored.
+    #[arg(long, value_name = "PATH")]
+    pub bins_from: Option<PathBuf>,
+
+    /// Univariate filter metric for a binary target.
+    /// Options: "iv" (Gini from WoE bins, default) or "mutual_info" (mutual information in nats,
+    /// compared against --mi-threshold)
+    #[arg(long, default_value = "iv")]
+    pub univariate_metric: String,
+
+    /// Mutual information threshold (nats) for --univariate-metric mutual_info - drop features
+    /// below this value. MI is far smaller than Gini for the same feature (about Gini²/2 on a
+    /// balanced target), so it has its own threshold instead of --gini-threshold
+    #[arg(long, default_value = "0.001", value_parser = validate_threshold)]
+    pub mi_threshold: f64,
+
     /// Number of prebins for initial binning before optimization/merging.
     /// Lower values = faster but less granular. Higher values = more precise but slower solver.
     #[arg(long, default_value = "20")]
     pub prebins: usize,
 
+    /// After binning, merge adjacent numeric bins whose event rates do not differ
+    /// significantly (chi-square p-value above this level, e.g. 0.05)
+    #[arg(long, value_name = "ALPHA", value_parser = validate_threshold)]
+    pub merge_insignificant_bins: Option<f64>,
+
+    /// Values of numeric features that get a bin of their own instead of
+    /// joining the numeric ranges, e.g. sentinel codes like -999997
+    /// (comma-separated). A bare value applies to every numeric feature,
+    /// FEATURE:VALUE to one feature only.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        allow_hyphen_values = true,
+        value_name = "[FEATURE:]VALUE"
+    )]
+    pub special_values: Vec<String>,
+
+    /// What happens to missing values of numeric features.
+    /// Options: "separate" (a Missing bin with its own WoE, default), "merge_worst"
+    /// (folded into the bin with the highest event rate) or "impute" (binned at the
+    /// weighted median)
+    #[arg(long, default_value = "separate")]
+    pub missing_bin_policy: String,
+
+    /// Cross-validate the IV of every feature: bin on K-1 folds and score the
+    /// held-out fold, K times. The mean and standard deviation of the holdout
+    /// IV and Gini are added to the Gini analysis export.
+    #[arg(long, value_name = "K", value_parser = validate_cv_folds)]
+    pub cv_folds: Option<usize>,
+
+    /// Drop features whose cross-validated mean IV is below this value;
+    /// implies --cv-folds 5 unless set
+    #[arg(long, value_name = "IV")]
+    pub cv_min_iv: Option<f64>,
+
     /// Enable solver-based optimal binning (MIP optimization).
     /// When enabled, uses mathematical optimization instead of greedy merging.
     /// Slower but produces globally optimal bin boundaries with optional monotonicity constraints.
     #[arg(long, default_value = "true")]
     pub use_solver: bool,
 
+    /// How the final bins are found when --use-solver is enabled.
+    /// Options: "mip" (HiGHS MIP model, default), "dp" (exact dynamic program in
+    /// pure Rust, same constraints, no solver dependency) or "greedy" (merge the
+    /// adjacent bins with the least IV loss, as with --use-solver false).
+    /// Builds without the `solver` feature run "mip" as "dp".
+    #[arg(long, default_value = "mip")]
+    pub binning_backend: String,
+
     /// Monotonicity constraint for WoE pattern in binning.
     /// Options: "none" (default), "ascending", "descending", "peak", "valley", "auto"
     /// Only applies when --use-solver is enabled.
@@ -89,7 +251,7 @@ pub struct Cli {
 
     /// Solver timeout in seconds per feature.
     /// Maximum time allowed for the optimization solver per feature.
-    /// Only applies when --use-solver is enabled.
+    /// 0 disables the limit. Only applies when --use-solver is enabled.
     #[arg(long, default_value = "30")]
     pub solver_timeout: u64,
 
@@ -99,6 +261,29 @@ pub struct Cli {
     #[arg(long, default_value = "0.01", value_parser = validate_solver_gap)]
     pub solver_gap: f64,
 
+    /// Minimum share of weighted samples in every solver bin, in percent (0-100).
+    /// Bins below it are not considered by the MIP model, which may then
+    /// return fewer than --gini-bins bins. Only applies when --use-solver is enabled.
+    #[arg(long, default_value = "0.0", value_parser = validate_bin_share_pct)]
+    pub min_bin_pct: f64,
+
+    /// Maximum share of weighted samples in any solver bin, in percent (0-100).
+    /// Only applies when --use-solver is enabled.
+    #[arg(long, default_value = "100.0", value_parser = validate_bin_share_pct)]
+    pub max_bin_pct: f64,
+
+    /// Minimum absolute WoE difference between adjacent solver bins.
+    /// Near-duplicate neighbours are merged instead, so the model may return
+    /// fewer than --gini-bins bins. 0 disables the check.
+    /// Only applies when --use-solver is enabled.
+    #[arg(long, default_value = "0.0", value_parser = validate_min_woe_delta)]
+    pub min_woe_delta: f64,
+
+    /// Stop the run (exit code 4) when the solver finds no binning for a
+    /// feature, instead of falling back to greedy merging for it
+    #[arg(long, default_value = "false")]
+    pub fail_on_solver_fallback: bool,
+
     /// Minimum samples per category for categorical features.
     /// Categories with fewer samples are merged into "OTHER".
     #[arg(long, default_value = "5")]
@@ -111,37 +296,341 @@ pub struct Cli {
     #[arg(long, default_value = "5.0", value_parser = validate_cart_min_bin_pct)]
     pub cart_min_bin_pct: f64,
 
+    /// Fast screen for a first pass over a new extract: decile binning with no
+    /// solver and correlations on a row sample. Overrides the binning settings;
+    /// results are marked as approximate in the report.
+    #[arg(long, default_value = "false")]
+    pub fast: bool,
+
+    /// Analyse a random sample of N rows instead of the whole dataset, to
+    /// iterate on thresholds quickly. The reduced dataset holds the sampled
+    /// rows only; `lophi apply` the report to the full file afterwards.
+    #[arg(long, value_name = "N", conflicts_with = "sample_frac")]
+    pub sample_rows: Option<usize>,
+
+    /// Analyse a random fraction (0.0-1.0] of the rows, like --sample-rows
+    #[arg(long, value_name = "FRACTION", value_parser = validate_sample_frac)]
+    pub sample_frac: Option<f64>,
+
+    /// Sample each target class in proportion to its share of the rows, so
+    /// the sample keeps the event rate
+    #[arg(long, default_value = "false")]
+    pub sample_stratify: bool,
+
+    /// Seed of the row sample, if it should differ from --seed; the same
+    /// seed draws the same rows
+    #[arg(long)]
+    pub sample_seed: Option<u64>,
+
+    /// Seed of every randomized step: the row sample, the cross-validation
+    /// folds and the random validation split of --iv-stability. Two runs with
+    /// the same seed and input produce the same analysis
+    #[arg(long, default_value = "42")]
+    pub seed: u64,
+
     /// Columns to drop before processing (comma-separated).
-    /// These columns will be removed from the dataset before any analysis.
+    /// These columns are never read from the file, so dropping wide blocks
+    /// of a large dataset also cuts the memory it takes to load.
     #[arg(long, value_delimiter = ',')]
     pub drop_columns: Vec<String>,
 
-    /// Skip interactive confirmation prompts
+    /// Columns to load (comma-separated); all other columns are never read.
+    /// The target, weight and split columns are loaded even when not listed.
+    /// --drop-columns still applies to the selection.
+    #[arg(long, value_delimiter = ',')]
+    pub select_columns: Vec<String>,
+
+    /// Leave out the columns built from Hive `key=value` partition folders
+    /// of a directory or glob input (they are read as features by default)
+    #[arg(long)]
+    pub drop_partition_columns: bool,
+
+    /// Features that are never dropped (comma-separated), whatever their
+    /// missing ratio, Gini or correlations. Each prevented drop is noted in
+    /// the report's decision log.
+    #[arg(long, value_delimiter = ',')]
+    pub keep_columns: Vec<String>,
+
+    /// How to handle NaN and infinite values in numeric features.
+    /// Options: "treat-as-missing" (default, counted as nulls), "clip" (±Inf replaced by the
+    /// column's finite max/min, NaN treated as missing), or "error" (abort if any are found).
+    #[arg(long, default_value = "treat-as-missing")]
+    pub non_finite_policy: String,
+
+    /// How to handle date and datetime features.
+    /// Options: "keep" (default, passed through unscored), "drop" (dropped before missing
+    /// analysis), or "derive" (replaced by numeric <name>_days, <name>_month and <name>_dow
+    /// features that go through every screening stage).
+    #[arg(long, default_value = "keep")]
+    pub date_policy: String,
+
+    /// Compare the loaded column names and dtypes against this schema file
+    /// (written by `lophi schema`) and report added, removed and re-typed
+    /// columns. Columns in --drop-columns are not reported as removed.
+    #[arg(long)]
+    pub expect_schema: Option<PathBuf>,
+
+    /// With --expect-schema, stop before any analysis if the schema drifted
+    #[arg(long, default_value = "false")]
+    pub fail_on_schema_drift: bool,
+
+    /// Memory budget for the loaded dataset in GB. A dataset estimated over
+    /// the budget stops the run with ways to shrink it; one that only fits
+    /// without the reader's extra copy is loaded with the streaming engine
+    #[arg(long, value_name = "GB")]
+    pub max_memory_gb: Option<f64>,
+
+    /// Write the Gini analysis as one compact JSON file per feature plus an
+    /// index.json (in a `{input}_gini_analysis/` directory inside the report zip)
+    /// instead of a single gini_analysis.json. Recommended for very wide datasets.
+    #[arg(long, default_value = "false")]
+    pub split_gini_export: bool,
+
+    /// Also write a WoE-encoded copy of the reduced dataset (`{output}_woe.{ext}`)
+    /// where each retained feature is replaced by the WoE of its Gini analysis bin
+    #[arg(long, default_value = "false")]
+    pub export_woe: bool,
+
+    /// Compression of Parquet outputs: "zstd" (default), "snappy", "lz4" or
+    /// "uncompressed"
+    #[arg(long, default_value = "zstd", value_parser = validate_parquet_compression)]
+    pub parquet_compression: String,
+
+    /// Rows per row group of Parquet outputs (default 262,144). Smaller groups
+    /// let readers skip more data on filtered scans; larger ones compress better
+    #[arg(long, value_name = "ROWS", value_parser = validate_row_group_size)]
+    pub row_group_size: Option<usize>,
+
+    /// Column statistics in Parquet outputs: "basic" (min, max and null count;
+    /// default), "full" (adds distinct counts) or "none"
+    #[arg(long, default_value = "basic", value_parser = validate_parquet_statistics)]
+    pub parquet_statistics: String,
+
+    /// With --event-value/--non-event-value, write the mapped 0/1 target to
+    /// the output in place of the raw values, which are kept in a
+    /// `{target}_raw` column next to it
+    #[arg(long, default_value = "false")]
+    pub output_include_target_mapping: bool,
+
+    /// With --binning-strategy cart, also write the fitted CART prebinning tree
+    /// of each numeric feature (split values, node counts, impurity) to
+    /// `{input}_cart_trees.json` in the report zip
+    #[arg(long, default_value = "false")]
+    pub export_cart_trees: bool,
+
+    /// Add a weighted 50-bucket histogram of each retained numeric feature to
+    /// the Gini analysis export, for distribution charts in report viewers
+    #[arg(long, default_value = "false")]
+    pub export_distributions: bool,
+
+    /// Chart the WoE and event rate per bin of the --plots-top features with
+    /// the highest IV into a `{input}_plots/` directory, with an index.html
+    /// showing each chart next to the feature's IV and Gini. Binary targets only.
+    #[arg(long, default_value = "false")]
+    pub plots: bool,
+
+    /// Number of features --plots charts, highest IV first
+    #[arg(long, default_value = "20", value_name = "N")]
+    pub plots_top: usize,
+
+    /// Image format of the --plots charts: "svg" (default) or "png"
+    #[arg(long, default_value = "svg", value_parser = validate_plot_format)]
+    pub plot_format: String,
+
+    /// Also write the missing ratio of every analysed feature to this path as
+    /// a standalone table (.csv or .feather)
+    #[arg(long, value_parser = validate_table_path)]
+    pub export_missing_ratios: Option<PathBuf>,
+
+    /// Also write the IV, Gini, KS and AUC of every feature of the Gini/IV
+    /// stage to this path as a standalone table (.csv or .feather)
+    #[arg(long, value_parser = validate_table_path)]
+    pub export_iv_table: Option<PathBuf>,
+
+    /// Also write the correlated pairs, with the member dropped for each and
+    /// the rule that chose it, to this path as a standalone table (.csv or .feather)
+    #[arg(long, value_parser = validate_table_path)]
+    pub export_correlated_pairs: Option<PathBuf>,
+
+    /// CSV data dictionary with a `feature` column and `description` and/or
+    /// `owner` columns. The Gini export, the reduction report and the review
+    /// screens show each feature's description and owner next to its name.
+    #[arg(long, value_name = "PATH")]
+    pub dictionary: Option<PathBuf>,
+
+    /// In interactive mode, pause after correlation analysis to review each
+    /// correlated pair (IVs and missing rates) and choose which member to drop.
+    /// Overrides are recorded in the report's decision log.
+    #[arg(long, default_value = "false")]
+    pub review_correlations: bool,
+
+    /// In interactive mode, pause before saving to review every proposed drop
+    /// with its reason and un-check features to keep. Kept features stay in the
+    /// output and are reported as "user-retained".
+    #[arg(long, default_value = "false")]
+    pub review_drops: bool,
+
+    /// After the run, open a results browser with the drops of each step, a
+    /// sortable IV table, the correlated pairs and each feature's bins. In
+    /// interactive mode it replaces the completed progress screen; with
+    /// --no-confirm it opens when stdout is a terminal.
+    #[arg(long, default_value = "false")]
+    pub browse_results: bool,
+
+    /// Resume an interrupted run: load the missing, Gini/IV and correlation
+    /// results saved in `.lophi_checkpoint/` next to the input instead of
+    /// recomputing them. Checkpoints from runs with other settings are ignored.
+    #[arg(long, default_value = "false")]
+    pub resume: bool,
+
+    /// Run every analysis step and write the reports, but not the reduced
+    /// dataset. Prints the features each step would drop. The stage results
+    /// are kept, so a following run with --resume only has to save.
+    #[arg(long, default_value = "false")]
+    pub dry_run: bool,
+
+    /// Column marking the train/test split already present in the data.
+    /// Missing, Gini/IV and correlation statistics are computed on the rows
+    /// where it equals --train-value; the drops are applied to every row.
+    #[arg(long)]
+    pub split_column: Option<String>,
+
+    /// Value of --split-column that marks training rows (e.g. "TRAIN")
+    #[arg(long)]
+    pub train_value: Option<String>,
+
+    /// Compute IV again on validation rows and flag features whose IV value
+    /// or rank moves between the halves. Validation rows are the ones outside
+    /// --train-value of --split-column, or a random --validation-frac.
+    #[arg(long, default_value = "false")]
+    pub iv_stability: bool,
+
+    /// Share of the rows held out for --iv-stability without a split column
+    #[arg(long, default_value = "0.3", value_parser = validate_validation_frac)]
+    pub validation_frac: f64,
+
+    /// Drop features whose relative IV change or rank shift between train and
+    /// validation rows exceeds this (0.0-1.0); implies --iv-stability
+    #[arg(long, value_name = "THRESHOLD", value_parser = validate_iv_stability_threshold)]
+    pub iv_stability_threshold: Option<f64>,
+
+    /// Column whose values split the rows into segments, e.g. a region or a
+    /// product. Missing ratios and IV are also computed per segment; features
+    /// fully missing in a segment, or whose IV differs widely between
+    /// segments, are flagged in the report.
+    #[arg(long)]
+    pub segment_column: Option<String>,
+
+    /// Date or datetime column to slice the rows into periods by. Each
+    /// surviving feature gets its missing rate and IV per period, and features
+    /// whose fill rate or IV trends over time are flagged in the report.
+    #[arg(long)]
+    pub time_column: Option<String>,
+
+    /// Period length for --time-column: "month" (default), "quarter" or "year"
+    #[arg(long, default_value = "month", value_parser = validate_time_freq)]
+    pub time_freq: String,
+
+    /// Comma-separated protected attributes, e.g. gender or age band. Features
+    /// that track one of them (association or IV above the proxy thresholds)
+    /// are flagged as likely proxies in the report.
+    #[arg(long, value_delimiter = ',')]
+    pub protected_columns: Vec<String>,
+
+    /// Cramér's V or η between a feature and a protected column above which
+    /// the feature is flagged as a proxy (0.0-1.0)
+    #[arg(long, default_value = "0.3", value_parser = validate_threshold)]
+    pub proxy_threshold: f64,
+
+    /// Fit a PCA on the standardized numeric features left after screening
+    /// and report the cumulative explained variance and the groups of
+    /// features that load highly on the same component. Informational; no
+    /// feature is dropped.
+    #[arg(long, default_value = "false")]
+    pub pca: bool,
+
+    /// Absolute loading from which --pca lists a feature with a component
+    /// (0.0-1.0)
+    #[arg(long, default_value_t = DEFAULT_PCA_LOADING_THRESHOLD, value_parser = validate_threshold)]
+    pub pca_loading_threshold: f64,
+
+    /// Skip interactive confirmation prompts. The run never opens the TUI or
+    /// waits for input; a missing --input or --target is an error.
     #[arg(long, default_value = "false")]
     pub no_confirm: bool,
 
+    /// Run the reduction printing nothing but errors (to stderr).
+    /// Implies --no-confirm.
+    #[arg(short, long, default_value = "false")]
+    pub quiet: bool,
+
+    /// Run without prompts or spinners and write progress as JSON lines
+    /// (stage started/finished, counts, timings, dropped features) to PATH,
+    /// or to stdout when PATH is omitted or "-". Implies --no-confirm.
+    #[arg(long, value_name = "PATH", num_args = 0..=1, default_missing_value = "-")]
+    pub json_output: Option<PathBuf>,
+
+    /// Show debug detail on stderr (per-feature timings, solver outcomes).
+    /// Repeat (-vv) for trace detail.
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Write a log of the run to PATH: the console messages plus per-feature
+    /// timings, solver outcomes and warnings, with timestamps
+    #[arg(long, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+
     /// Launch expert dashboard mode instead of the guided wizard.
     /// Use this flag for full control over all settings in a single screen.
     #[arg(long, default_value = "false")]
     pub manual: bool,
 
-    /// Number of rows to use for schema inference (CSV only).
+    /// Number of rows to use for schema inference (CSV and JSON Lines).
     /// Higher values improve type detection for ambiguous columns but may be slower.
     /// Use 0 for full table scan (very slow for large files).
     #[arg(long, default_value = "10000")]
     pub infer_schema_length: usize,
+
+    /// Field separator of CSV input and output: one character such as ";", or "tab"
+    #[arg(long, default_value = ",", value_parser = validate_csv_separator)]
+    pub csv_separator: String,
+
+    /// Quote character of CSV input and output, or "none" to turn quoting off
+    #[arg(long, default_value = "\"", value_parser = validate_csv_quote)]
+    pub csv_quote: String,
+
+    /// CSV numbers use "," as the decimal separator (needs another --csv-separator)
+    #[arg(long, default_value = "false")]
+    pub csv_decimal_comma: bool,
+
+    /// strftime format of dates in CSV input and output, e.g. "%d.%m.%Y".
+    /// Text columns whose inferred rows all match it are read as dates
+    #[arg(long, value_name = "FORMAT", value_parser = validate_date_format)]
+    pub csv_date_format: Option<String>,
+
+    /// Text standing for a missing value in CSV input and output, e.g. "NA"
+    #[arg(long, value_name = "TEXT")]
+    pub csv_null: Option<String>,
 }
 
-#[derive(Subcommand, Debug)]
+#[derive(Subcommand, Debug, Clone)]
 pub enum Commands {
-    /// Convert between CSV, Parquet, and SAS7BDAT formats
+    /// Convert between CSV, Parquet, Feather (Arrow IPC), and SAS7BDAT formats,
+    /// from JSON Lines, Excel, SPSS and Stata, or to SAS transport (XPT)
     Convert {
-        /// Input file path (CSV, Parquet, or SAS7BDAT)
+        /// Input file path (CSV, also as .csv.gz/.csv.zst/.zip, Parquet, Feather, JSON Lines,
+        /// SAS7BDAT, Excel, SPSS or Stata)
         input: PathBuf,
 
-        /// Output file path (optional, auto-detected: CSV->Parquet, Parquet->CSV, SAS7BDAT->Parquet)
+        /// Output file path; its extension (.csv, .parquet, .feather, .xpt) selects the format.
+        /// Defaults: CSV->Parquet, Parquet->CSV, Feather->Parquet, JSON Lines->Parquet,
+        /// SAS7BDAT->Parquet, Excel->Parquet, SPSS->Parquet, Stata->Parquet
         output: Option<PathBuf>,
 
+        /// Worksheet to convert when the input is an Excel workbook (defaults to the first sheet)
+        #[arg(long)]
+        sheet: Option<String>,
+
         /// Number of rows to use for schema inference.
         /// Higher values improve type detection for ambiguous columns but may be slower.
         /// Use 0 for full table scan (very slow for large files).
@@ -153,23 +642,224 @@ pub enum Commands {
         /// Without this flag, uses memory-efficient streaming (single-threaded but low RAM).
         #[arg(long, default_value = "false")]
         fast: bool,
+
+        /// Compression of a Parquet output: "snappy", "zstd", "lz4" or "uncompressed"
+        #[arg(long, default_value = "snappy", value_parser = validate_parquet_compression)]
+        parquet_compression: String,
+
+        /// Rows per row group of a Parquet output
+        #[arg(long, default_value = "100000", value_name = "ROWS", value_parser = validate_row_group_size)]
+        row_group_size: usize,
+
+        /// Column statistics in a Parquet output: "full" (min, max, null and
+        /// distinct counts), "basic" (without distinct counts) or "none"
+        #[arg(long, default_value = "full", value_parser = validate_parquet_statistics)]
+        parquet_statistics: String,
+
+        /// Field separator of CSV input and output: one character such as ";", or "tab"
+        #[arg(long, default_value = ",", value_parser = validate_csv_separator)]
+        csv_separator: String,
+
+        /// Quote character of CSV input and output, or "none" to turn quoting off
+        #[arg(long, default_value = "\"", value_parser = validate_csv_quote)]
+        csv_quote: String,
+
+        /// CSV numbers use "," as the decimal separator (needs another --csv-separator)
+        #[arg(long, default_value = "false")]
+        csv_decimal_comma: bool,
+
+        /// strftime format of dates in CSV input and output, e.g. "%d.%m.%Y"
+        #[arg(long, value_name = "FORMAT", value_parser = validate_date_format)]
+        csv_date_format: Option<String>,
+
+        /// Text standing for a missing value in CSV input and output, e.g. "NA"
+        #[arg(long, value_name = "TEXT")]
+        csv_null: Option<String>,
+    },
+
+    /// Apply the column drops of a saved reduction report to another dataset
+    Apply {
+        /// Reduction report from a previous run: the report .zip bundle or its
+        /// reduction_report.json
+        #[arg(long)]
+        report: PathBuf,
+
+        /// Dataset to reduce (CSV, Parquet, Feather, JSON Lines, SAS7BDAT, Excel, SPSS or Stata).
+        /// Excel workbooks are read from their first sheet.
+        #[arg(short, long)]
+        input: PathBuf,
+
+        /// Output file path (optional, defaults to {input}_reduced.{ext})
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Replace kept features with their WoE values using the saved bins.
+        /// Needs the report .zip bundle or --gini-analysis.
+        #[arg(long, default_value = "false")]
+        woe: bool,
+
+        /// Gini analysis JSON to read WoE bins from when --report is a bare JSON file
+        #[arg(long)]
+        gini_analysis: Option<PathBuf>,
+
+        /// Number of rows to use for schema inference (CSV and JSON Lines)
+        #[arg(long, default_value = "10000")]
+        infer_schema_length: usize,
+    },
+
+    /// Apply stricter missing/Gini thresholds to a reduced output using the
+    /// metrics in its reduction report, without re-running the analysis
+    Tighten {
+        /// Reduction report of the run that produced --input: the report .zip
+        /// bundle or its reduction_report.json
+        #[arg(long)]
+        report: PathBuf,
+
+        /// Reduced dataset written by that run (CSV, Parquet, Feather, or JSON Lines)
+        #[arg(short, long)]
+        input: PathBuf,
+
+        /// Output file path (optional, defaults to {input}_tightened.{ext})
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// New missing threshold; must not exceed the report's
+        #[arg(long)]
+        missing_threshold: Option<f64>,
+
+        /// New Gini threshold (mutual information or R² when the report used
+        /// those); must not be below the report's
+        #[arg(long)]
+        gini_threshold: Option<f64>,
+
+        /// Number of rows to use for schema inference (CSV and JSON Lines)
+        #[arg(long, default_value = "10000")]
+        infer_schema_length: usize,
+    },
+
+    /// Check a reduction report's structure and internal consistency
+    /// (schema version, thresholds, dropped counts against the feature list)
+    /// before a downstream job relies on it
+    ValidateReport {
+        /// Reduction report: the report .zip bundle or its reduction_report.json
+        report: PathBuf,
+    },
+
+    /// Compare two runs: IV changes per feature, features dropped in one run
+    /// but not the other, and threshold differences
+    Diff {
+        /// First run: a reduction report (.zip bundle or reduction_report.json)
+        /// or a Gini analysis export
+        report_a: PathBuf,
+
+        /// Second run, in any of the same forms
+        report_b: PathBuf,
+
+        /// Output file path (optional, defaults to {report_b}_diff.json)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Number of changed features shown in the table (all are written to
+        /// the JSON)
+        #[arg(long, default_value = "25")]
+        top: usize,
+    },
+
+    /// Write the column names and dtypes of a dataset to a schema file for
+    /// --expect-schema
+    Schema {
+        /// Input file path (CSV, also as .csv.gz/.csv.zst/.zip, Parquet, Feather, JSON Lines,
+        /// SAS7BDAT, Excel, SPSS or Stata).
+        /// Excel workbooks are read from their first sheet.
+        input: PathBuf,
+
+        /// Output file path (optional, defaults to {input}_schema.json)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Number of rows to use for schema inference (CSV and JSON Lines)
+        #[arg(long, default_value = "10000")]
+        infer_schema_length: usize,
+    },
+
+    /// Time each pipeline stage (load, missing, binning per strategy and
+    /// backend, correlation, save) on a dataset and print a timing/memory table
+    Bench {
+        /// Input file path (CSV, also as .csv.gz/.csv.zst/.zip, Parquet, Feather, JSON Lines,
+        /// SAS7BDAT, Excel, SPSS or Stata)
+        #[arg(short, long)]
+        input: PathBuf,
+
+        /// Binary (0/1) target column
+        #[arg(short, long)]
+        target: String,
+
+        /// Weight column (optional)
+        #[arg(short = 'w', long)]
+        weight_column: Option<String>,
+
+        /// Number of times each stage runs
+        #[arg(long, default_value = "3")]
+        runs: usize,
+
+        /// Binning strategies to time, comma-separated (quantile, cart, equal_width)
+        #[arg(long, default_value = "quantile,cart")]
+        strategies: String,
+
+        /// Binning backends to time with each strategy, comma-separated
+        /// (greedy, dp, mip)
+        #[arg(long, default_value = "greedy,dp,mip")]
+        backends: String,
+
+        /// Number of bins for the binning stages
+        #[arg(long, default_value = "10")]
+        gini_bins: usize,
+
+        /// Number of prebins for the binning stages
+        #[arg(long, default_value = "20")]
+        prebins: usize,
+
+        /// Correlation threshold for the correlation stage
+        #[arg(long, default_value = "0.40", value_parser = validate_threshold)]
+        correlation_threshold: f64,
+
+        /// Number of rows to use for schema inference (CSV and JSON Lines)
+        #[arg(long, default_value = "10000")]
+        infer_schema_length: usize,
+
+        /// Worksheet to load when the input is an Excel workbook (defaults to the first sheet)
+        #[arg(long)]
+        sheet: Option<String>,
+    },
+
+    /// Write a commented pipeline config template for use with --config
+    InitConfig {
+        /// Output path; the extension selects the format (.toml, .yaml, .yml)
+        #[arg(default_value = "pipeline.toml")]
+        output: PathBuf,
+
+        /// Overwrite the file if it already exists
+        #[arg(long, default_value = "false")]
+        force: bool,
     },
 
     /// Sample a dataset with inverse probability weights
     Sample {
-        /// Input file path (CSV, Parquet, or SAS7BDAT)
+        /// Input file path (CSV, also as .csv.gz/.csv.zst/.zip, Parquet, Feather, JSON Lines,
+        /// SAS7BDAT, Excel, SPSS or Stata)
         input: PathBuf,
 
         /// Output file path (optional, defaults to {input}_sampled.{ext})
         #[arg(short, long)]
         output: Option<PathBuf>,
 
-        /// Sampling method: "random", "stratified", or "equal"
-        #[arg(short, long, default_value = "random")]
-        method: String,
+        /// Sampling method: "random", "stratified", or "equal".
+        /// Defaults to "stratified" when --strata-column is given, else "random".
+        #[arg(short, long)]
+        method: Option<String>,
 
         /// Stratification column (required for stratified and equal methods)
-        #[arg(long)]
+        #[arg(long, alias = "stratify")]
         strata_column: Option<String>,
 
         /// Absolute sample count (mutually exclusive with --fraction).
@@ -178,21 +868,57 @@ pub enum Commands {
         count: Option<usize>,
 
         /// Sample fraction 0.0-1.0 (mutually exclusive with --count).
-        /// For random: fraction of total rows.
-        #[arg(short, long)]
+        /// For random: fraction of total rows. For stratified without
+        /// --strata-sizes: the same fraction of every stratum.
+        #[arg(short, long, alias = "frac")]
         fraction: Option<f64>,
 
         /// Per-stratum sample sizes for stratified method (format: "value1:100,value2:200")
         #[arg(long)]
         strata_sizes: Option<String>,
 
+        /// Existing row weight column; sampling_weight becomes this weight
+        /// times the inverse sampling probability
+        #[arg(long)]
+        weight_column: Option<String>,
+
         /// Random seed for reproducibility
         #[arg(long)]
         seed: Option<u64>,
 
-        /// Number of rows to use for schema inference (CSV only)
+        /// Number of rows to use for schema inference (CSV and JSON Lines)
         #[arg(long, default_value = "10000")]
         infer_schema_length: usize,
+
+        /// Worksheet to sample when the input is an Excel workbook (defaults to the first sheet)
+        #[arg(long)]
+        sheet: Option<String>,
+    },
+
+    /// Run the full reduction on every dataset in a folder and write a
+    /// summary CSV comparing the dropped features across files.
+    /// Pipeline settings come from --config or the flags before `batch`.
+    Batch {
+        /// Folder holding the datasets
+        #[arg(long)]
+        input_dir: PathBuf,
+
+        /// File name pattern, e.g. "*.parquet" (defaults to every dataset file)
+        #[arg(long)]
+        pattern: Option<String>,
+
+        /// Folder for the reduced datasets and reports
+        /// (optional, defaults to {input_dir}_reduced next to the input folder)
+        #[arg(short, long)]
+        output_dir: Option<PathBuf>,
+
+        /// Number of files reduced at the same time
+        #[arg(short, long, default_value = "1", value_parser = validate_jobs)]
+        jobs: usize,
+
+        /// Summary CSV path (optional, defaults to {output_dir}/batch_summary.csv)
+        #[arg(long)]
+        summary: Option<PathBuf>,
     },
 }
 
@@ -205,18 +931,21 @@ impl Cli {
 
     /// Get the output path, deriving from input if not explicitly provided.
     /// The derived path will be in the same directory as the input with a '_reduced' suffix.
+    /// A glob or directory input is named after the folder holding its files.
     pub fn output_path(&self) -> Option<PathBuf> {
-        let input = self.input.as_ref()?;
+        let input = &output_anchor(self.input.as_ref()?);
         Some(self.output.clone().unwrap_or_else(|| {
             let parent = input.parent().unwrap_or_else(|| std::path::Path::new("."));
-            let stem = input
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or("output");
-            let extension = input
-                .extension()
-                .and_then(|e| e.to_str())
-                .unwrap_or("parquet");
+            let stem = dataset_stem(input).unwrap_or("output");
+            // Compressed CSV is written back as plain CSV
+            let extension = if is_compressed_csv(input) {
+                "csv"
+            } else {
+                input
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .unwrap_or("parquet")
+            };
             parent.join(format!("{}_reduced.{}", stem, extension))
         }))
     }
@@ -224,11 +953,221 @@ impl Cli {
     /// Get the Gini analysis output path, derived from the input file.
     /// The derived path will be in the same directory as the input with a '_gini_analysis.json' suffix.
     pub fn gini_analysis_path(&self) -> Option<PathBuf> {
-        let input = self.input.as_ref()?;
+        let input = &output_anchor(self.input.as_ref()?);
         let parent = input.parent().unwrap_or_else(|| std::path::Path::new("."));
-        let stem = input.file_stem().and_then(|s| s.to_str())?;
+        let stem = dataset_stem(input)?;
         Some(parent.join(format!("{}_gini_analysis.json", stem)))
     }
+
+    /// The row sample to analyse (`--sample-rows` / `--sample-frac`), if any
+    pub fn screening_sample(&self) -> anyhow::Result<Option<ScreeningSample>> {
+        let size = match (self.sample_rows, self.sample_frac) {
+            (Some(_), Some(_)) => {
+                anyhow::bail!("Use either --sample-rows or --sample-frac, not both")
+            }
+            (Some(rows), None) => SampleSize::Count(rows),
+            (None, Some(fraction)) => SampleSize::Fraction(fraction),
+            (None, None) => return Ok(None),
+        };
+        Ok(Some(ScreeningSample {
+            size,
+            stratify: self.sample_stratify,
+            seed: self.sample_seed.unwrap_or(self.seed),
+        }))
+    }
+
+    /// The reserved values of `--special-values`, if any were listed
+    pub fn special_values(&self) -> anyhow::Result<Option<SpecialValues>> {
+        let special = SpecialValues::parse(&self.special_values)?;
+        Ok((!special.is_empty()).then_some(special))
+    }
+
+    /// The breakpoints of `--bin-breaks`, which go with `--binning-strategy manual`
+    pub fn bin_breaks(&self) -> anyhow::Result<Option<BinBreaks>> {
+        let breaks = BinBreaks::parse(&self.bin_breaks)?;
+        let manual = self.binning_strategy.parse() == Ok(BinningStrategy::Manual);
+        match (manual, breaks.is_empty()) {
+            (true, true) => anyhow::bail!("--binning-strategy manual needs --bin-breaks"),
+            (false, false) => {
+                anyhow::bail!("--bin-breaks only applies to --binning-strategy manual")
+            }
+            _ => Ok((!breaks.is_empty()).then_some(breaks)),
+        }
+    }
+
+    /// The k-fold IV cross-validation (`--cv-folds` / `--cv-min-iv`), if requested
+    pub fn cross_validation(&self) -> Option<CrossValidation> {
+        if self.cv_folds.is_none() && self.cv_min_iv.is_none() {
+            return None;
+        }
+        Some(CrossValidation {
+            folds: self.cv_folds.unwrap_or(DEFAULT_CV_FOLDS),
+            min_iv: self.cv_min_iv,
+        })
+    }
+
+    /// The train/validation IV comparison (`--iv-stability`), if requested
+    pub fn iv_stability_check(&self) -> Option<IvStabilityCheck> {
+        (self.iv_stability || self.iv_stability_threshold.is_some()).then_some(IvStabilityCheck {
+            validation_frac: self.validation_frac,
+            drop_threshold: self.iv_stability_threshold,
+        })
+    }
+
+    /// The per-period stability check (`--time-column`), if requested
+    pub fn time_stability_check(&self) -> Option<TimeStabilityCheck> {
+        self.time_column.clone().map(|column| TimeStabilityCheck {
+            column,
+            freq: self.time_freq.parse().unwrap_or_default(),
+        })
+    }
+
+    /// The screening steps of `--steps`, without the Gini step under
+    /// `--skip-gini` or `--no-target`
+    pub fn step_plan(&self) -> anyhow::Result<StepPlan> {
+        let mut plan = if self.steps.is_empty() {
+            StepPlan::default()
+        } else {
+            StepPlan::parse(&self.steps)?
+        };
+        if self.skip_gini || self.no_target {
+            plan.skip(ScreeningStep::Gini)?;
+        }
+        Ok(plan)
+    }
+
+    /// `--dominance-threshold`, which `--no-target` defaults to
+    /// [`NO_TARGET_DOMINANCE_THRESHOLD`]
+    pub fn dominance_threshold(&self) -> Option<f64> {
+        self.dominance_threshold
+            .or(self.no_target.then_some(NO_TARGET_DOMINANCE_THRESHOLD))
+    }
+
+    /// The proxy screen of the protected attributes (`--protected-columns`),
+    /// if requested
+    pub fn fairness_check(&self) -> Option<FairnessCheck> {
+        (!self.protected_columns.is_empty()).then(|| FairnessCheck {
+            columns: self.protected_columns.clone(),
+            threshold: self.proxy_threshold,
+        })
+    }
+
+    /// The PCA report of the retained features (`--pca`), if requested
+    pub fn pca_settings(&self) -> Option<PcaSettings> {
+        self.pca.then_some(PcaSettings {
+            loading_threshold: self.pca_loading_threshold,
+        })
+    }
+
+    /// How Parquet outputs are written (`--parquet-compression` etc.)
+    pub fn parquet_options(&self) -> ParquetOptions {
+        ParquetOptions {
+            compression: self.parquet_compression.parse().unwrap_or_default(),
+            row_group_size: self.row_group_size,
+            statistics: self.parquet_statistics.parse().unwrap_or_default(),
+        }
+    }
+
+    /// The CSV dialect of `--csv-separator`, `--csv-quote` etc.
+    pub fn csv_dialect(&self) -> anyhow::Result<CsvDialect> {
+        build_csv_dialect(
+            &self.csv_separator,
+            &self.csv_quote,
+            self.csv_decimal_comma,
+            self.csv_date_format.as_deref(),
+            self.csv_null.as_deref(),
+        )
+    }
+
+    /// The WoE charts (`--plots`), if requested
+    pub fn plot_settings(&self) -> Option<PlotSettings> {
+        self.plots.then(|| PlotSettings {
+            top_n: self.plots_top,
+            format: self.plot_format.parse().unwrap_or_default(),
+        })
+    }
+
+    /// Paths of the standalone stage tables (`--export-missing-ratios` etc.)
+    pub fn table_exports(&self) -> TableExports {
+        TableExports {
+            missing_ratios: self.export_missing_ratios.clone(),
+            iv_table: self.export_iv_table.clone(),
+            correlated_pairs: self.export_correlated_pairs.clone(),
+        }
+    }
+}
+
+/// Build a [`CsvDialect`] from the `--csv-*` flags, rejecting ambiguous combinations
+pub fn build_csv_dialect(
+    separator: &str,
+    quote: &str,
+    decimal_comma: bool,
+    date_format: Option<&str>,
+    null: Option<&str>,
+) -> anyhow::Result<CsvDialect> {
+    let dialect = CsvDialect {
+        separator: parse_csv_separator(separator).map_err(anyhow::Error::msg)?,
+        quote_char: parse_csv_quote(quote).map_err(anyhow::Error::msg)?,
+        decimal_comma,
+        date_format: date_format.map(String::from),
+        null_value: null.map(String::from),
+    };
+    dialect.validate().map_err(anyhow::Error::msg)?;
+    Ok(dialect)
+}
+
+/// Validator for --csv-separator
+fn validate_csv_separator(s: &str) -> Result<String, String> {
+    parse_csv_separator(s)?;
+    Ok(s.to_string())
+}
+
+/// Validator for --csv-quote
+fn validate_csv_quote(s: &str) -> Result<String, String> {
+    parse_csv_quote(s)?;
+    Ok(s.to_string())
+}
+
+/// Validator for --csv-date-format
+fn validate_date_format(s: &str) -> Result<String, String> {
+    validate_csv_date_format(s)?;
+    Ok(s.to_string())
+}
+
+/// Validator for --parquet-compression
+fn validate_parquet_compression(s: &str) -> Result<String, String> {
+    s.parse::<ParquetCodec>()?;
+    Ok(s.to_string())
+}
+
+/// Validator for --parquet-statistics
+fn validate_parquet_statistics(s: &str) -> Result<String, String> {
+    s.parse::<ParquetStatistics>()?;
+    Ok(s.to_string())
+}
+
+/// Validator for --row-group-size
+fn validate_row_group_size(s: &str) -> Result<usize, String> {
+    match s.parse::<usize>() {
+        Ok(0) => Err("Row group size must be at least 1".to_string()),
+        Ok(rows) => Ok(rows),
+        Err(_) => Err(format!("Invalid row group size '{}'", s)),
+    }
+}
+
+/// Validator for `lophi batch --jobs`
+fn validate_jobs(s: &str) -> Result<usize, String> {
+    match s.parse::<usize>() {
+        Ok(0) => Err("--jobs must be at least 1".to_string()),
+        Ok(jobs) => Ok(jobs),
+        Err(_) => Err(format!("Invalid number of jobs '{}'", s)),
+    }
+}
+
+/// Validator for --plot-format
+fn validate_plot_format(s: &str) -> Result<String, String> {
+    s.parse::<PlotFormat>()?;
+    Ok(s.to_string())
 }
 
 /// Validator for threshold parameters (missing, gini, correlation)
@@ -261,6 +1200,38 @@ fn validate_cart_min_bin_pct(s: &str) -> Result<f64, String> {
     }
 }
 
+/// Validator for min_bin_pct and max_bin_pct
+fn validate_bin_share_pct(s: &str) -> Result<f64, String> {
+    let value: f64 = s
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
+
+    if !(0.0..=100.0).contains(&value) {
+        Err(format!(
+            "bin share must be between 0.0 and 100.0 percent, got {}",
+            value
+        ))
+    } else {
+        Ok(value)
+    }
+}
+
+/// Validator for min_woe_delta: a non-negative WoE difference
+fn validate_min_woe_delta(s: &str) -> Result<f64, String> {
+    let value: f64 = s
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
+
+    if value.is_finite() && value >= 0.0 {
+        Ok(value)
+    } else {
+        Err(format!(
+            "min_woe_delta must be a non-negative number, got {}",
+            value
+        ))
+    }
+}
+
 /// Validator for solver_gap parameter
 fn validate_solver_gap(s: &str) -> Result<f64, String> {
     let value: f64 = s
@@ -276,3 +1247,76 @@ fn validate_solver_gap(s: &str) -> Result<f64, String> {
         Ok(value)
     }
 }
+
+/// Validator for `--sample-frac`: a fraction of the rows in (0.0, 1.0]
+fn validate_sample_frac(s: &str) -> Result<f64, String> {
+    let value: f64 = s
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
+    if value > 0.0 && value <= 1.0 {
+        Ok(value)
+    } else {
+        Err(format!(
+            "sample fraction must be above 0.0 and at most 1.0, got {}",
+            value
+        ))
+    }
+}
+
+/// Validator for `--cv-folds`: at least two folds
+fn validate_cv_folds(s: &str) -> Result<usize, String> {
+    let folds: usize = s
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number of folds", s))?;
+    if folds >= 2 {
+        Ok(folds)
+    } else {
+        Err(format!(
+            "cross-validation needs at least 2 folds, got {}",
+            folds
+        ))
+    }
+}
+
+/// Validator for `--validation-frac`: a share of the rows strictly between 0 and 1
+fn validate_validation_frac(s: &str) -> Result<f64, String> {
+    let value: f64 = s
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
+    if value > 0.0 && value < 1.0 {
+        Ok(value)
+    } else {
+        Err(format!(
+            "validation fraction must be between 0.0 and 1.0, got {}",
+            value
+        ))
+    }
+}
+
+/// Validator for `--iv-stability-threshold`: a relative change in [0.0, 1.0]
+fn validate_iv_stability_threshold(s: &str) -> Result<f64, String> {
+    let value: f64 = s
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
+    if (0.0..=1.0).contains(&value) {
+        Ok(value)
+    } else {
+        Err(format!(
+            "IV stability threshold must be between 0.0 and 1.0, got {}",
+            value
+        ))
+    }
+}
+
+/// Validator for --time-freq
+fn validate_time_freq(s: &str) -> Result<String, String> {
+    s.parse::<TimeFreq>()?;
+    Ok(s.to_string())
+}
+
+/// Validator for standalone table paths: the extension selects CSV or Feather
+fn validate_table_path(s: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(s);
+    TableFormat::from_path(&path).map_err(|e| e.to_string())?;
+    Ok(path)
+}
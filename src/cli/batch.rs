@@ -0,0 +1,259 @@
+//! Batch mode (`lophi batch`): the reduction run on every dataset in a folder
+//!
+//! Each file is reduced on its own with the same settings. The reduced
+//! datasets and their reports go to one output folder, and a summary CSV
+//! lines up what happened to every feature in every file, so drops can be
+//! compared across e.g. monthly extracts.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use comfy_table::{presets::UTF8_FULL_CONDENSED, Attribute, Cell, CellAlignment, Table};
+
+use crate::pipeline::is_dataset_path;
+use crate::report::ReductionSummary;
+use crate::utils::format_duration;
+
+/// File name of the summary CSV in the output folder
+pub const BATCH_SUMMARY_FILE: &str = "batch_summary.csv";
+
+/// Status of a feature its run kept, in the summary CSV
+pub const KEPT: &str = "kept";
+
+/// The datasets in `dir` whose file names match `pattern` (every dataset
+/// file when `None`), sorted by name. Hidden files are skipped.
+///
+/// # Errors
+/// When `dir` is not a folder, the pattern is invalid or nothing matches.
+pub fn batch_inputs(dir: &Path, pattern: Option<&str>) -> Result<Vec<PathBuf>> {
+    if !dir.is_dir() {
+        anyhow::bail!("{} is not a folder", dir.display());
+    }
+    let pattern = pattern
+        .map(|p| glob::Pattern::new(p).with_context(|| format!("Invalid file pattern: {}", p)))
+        .transpose()?;
+
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read folder: {}", dir.display()))?
+    {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let matches = match &pattern {
+            Some(pattern) => pattern.matches(name),
+            None => is_dataset_path(&path),
+        };
+        if matches && !name.starts_with('.') && path.is_file() {
+            files.push(path);
+        }
+    }
+    files.sort();
+
+    if files.is_empty() {
+        anyhow::bail!(
+            "No datasets in {}{}",
+            dir.display(),
+            pattern
+                .map(|p| format!(" match {}", p.as_str()))
+                .unwrap_or_default()
+        );
+    }
+    Ok(files)
+}
+
+/// Output folder of a batch without `--output-dir`: `{dir}_reduced` next to `dir`
+pub fn default_output_dir(dir: &Path) -> PathBuf {
+    let name = dir.file_name().and_then(|n| n.to_str()).unwrap_or("batch");
+    dir.with_file_name(format!("{}_reduced", name))
+}
+
+/// What happened to one file of a batch
+#[derive(Debug, Clone)]
+pub struct BatchFileResult {
+    pub input: PathBuf,
+    /// Every feature of the file with `kept` or the stage that dropped it,
+    /// kept features first
+    pub features: Vec<(String, String)>,
+    /// Why the run failed; the file then has no features
+    pub error: Option<String>,
+    pub elapsed: Duration,
+}
+
+impl BatchFileResult {
+    /// A finished run: `kept` from its report, drops from its summary
+    pub fn reduced(
+        input: PathBuf,
+        summary: &ReductionSummary,
+        kept: &[String],
+        elapsed: Duration,
+    ) -> Self {
+        let dropped = [
+            ("constant", &summary.dropped_constant),
+            ("identifier", &summary.dropped_identifier),
+            ("missing", &summary.dropped_missing),
+            ("gini", &summary.dropped_gini),
+            ("correlation", &summary.dropped_correlation),
+        ];
+        let features = kept
+            .iter()
+            .map(|f| (f.clone(), KEPT.to_string()))
+            .chain(dropped.into_iter().flat_map(|(stage, features)| {
+                features.iter().map(move |f| (f.clone(), stage.to_string()))
+            }))
+            .collect();
+        Self {
+            input,
+            features,
+            error: None,
+            elapsed,
+        }
+    }
+
+    pub fn failed(input: PathBuf, error: &anyhow::Error, elapsed: Duration) -> Self {
+        Self {
+            input,
+            features: Vec::new(),
+            error: Some(format!("{:#}", error)),
+            elapsed,
+        }
+    }
+
+    pub fn kept_count(&self) -> usize {
+        self.features.iter().filter(|(_, s)| s == KEPT).count()
+    }
+
+    fn file_name(&self) -> String {
+        self.input
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| self.input.display().to_string())
+    }
+}
+
+/// Write the summary CSV: one row per feature seen in any file, one column
+/// per file holding `kept`, the dropping stage, or nothing when the file
+/// lacks the feature (or its run failed), and `dropped_in`, the number of
+/// files that dropped it.
+pub fn write_batch_summary(path: &Path, results: &[BatchFileResult]) -> Result<()> {
+    let mut features: Vec<&str> = Vec::new();
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut statuses: Vec<HashMap<&str, &str>> = Vec::with_capacity(results.len());
+    for result in results {
+        let mut by_feature = HashMap::new();
+        for (feature, status) in &result.features {
+            if seen.insert(feature) {
+                features.push(feature);
+            }
+            by_feature.insert(feature.as_str(), status.as_str());
+        }
+        statuses.push(by_feature);
+    }
+
+    let mut file = std::fs::File::create(path)
+        .with_context(|| format!("Failed to create batch summary: {}", path.display()))?;
+    let header: Vec<String> = std::iter::once("feature".to_string())
+        .chain(results.iter().map(|r| csv_field(&r.file_name())))
+        .chain(std::iter::once("dropped_in".to_string()))
+        .collect();
+    writeln!(file, "{}", header.join(","))?;
+    for feature in features {
+        let cells: Vec<&str> = statuses
+            .iter()
+            .map(|s| s.get(feature).copied().unwrap_or(""))
+            .collect();
+        let dropped_in = cells
+            .iter()
+            .filter(|s| !s.is_empty() && **s != KEPT)
+            .count();
+        writeln!(
+            file,
+            "{},{},{}",
+            csv_field(feature),
+            cells.join(","),
+            dropped_in
+        )?;
+    }
+    Ok(())
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Per-file table printed at the end of a batch
+pub fn batch_table(results: &[BatchFileResult]) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_header(
+        ["File", "Features", "Kept", "Dropped", "Time", "Status"]
+            .into_iter()
+            .map(|h| Cell::new(h).add_attribute(Attribute::Bold)),
+    );
+    for result in results {
+        let (features, kept, dropped) = if result.error.is_some() {
+            ("-".to_string(), "-".to_string(), "-".to_string())
+        } else {
+            let kept = result.kept_count();
+            (
+                result.features.len().to_string(),
+                kept.to_string(),
+                (result.features.len() - kept).to_string(),
+            )
+        };
+        table.add_row(vec![
+            Cell::new(result.file_name()),
+            Cell::new(features).set_alignment(CellAlignment::Right),
+            Cell::new(kept).set_alignment(CellAlignment::Right),
+            Cell::new(dropped).set_alignment(CellAlignment::Right),
+            Cell::new(format_duration(result.elapsed)).set_alignment(CellAlignment::Right),
+            Cell::new(result.error.as_deref().unwrap_or("ok")),
+        ]);
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_output_dir_is_a_sibling() {
+        assert_eq!(
+            default_output_dir(Path::new("/data/monthly")),
+            PathBuf::from("/data/monthly_reduced")
+        );
+    }
+
+    #[test]
+    fn test_reduced_lists_kept_then_drops() {
+        let summary = ReductionSummary {
+            dropped_missing: vec!["b".to_string()],
+            dropped_correlation: vec!["c".to_string()],
+            ..Default::default()
+        };
+        let result = BatchFileResult::reduced(
+            PathBuf::from("jan.csv"),
+            &summary,
+            &["a".to_string()],
+            Duration::ZERO,
+        );
+        assert_eq!(
+            result.features,
+            [
+                ("a".to_string(), "kept".to_string()),
+                ("b".to_string(), "missing".to_string()),
+                ("c".to_string(), "correlation".to_string()),
+            ]
+        );
+        assert_eq!(result.kept_count(), 1);
+    }
+}
@@ -0,0 +1,301 @@
+//! Stage-by-stage benchmark of the reduction pipeline (`lophi bench`)
+//!
+//! Times loading, missing analysis, binning under each strategy/backend
+//! combination, correlation and saving on one dataset, so configurations can
+//! be compared on the hardware the real runs use. Every stage works on the
+//! full feature set; nothing is dropped between stages.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use comfy_table::{presets::UTF8_FULL_CONDENSED, Attribute, Cell, CellAlignment, Table};
+use console::style;
+use polars::prelude::*;
+
+use crate::pipeline::{
+    analyze_features_iv_with_progress, analyze_missing_values, analyze_target_column,
+    create_progress_channel, find_correlated_pairs_auto_with_progress, get_weights,
+    load_dataset_from_sheet, AssociationThresholds, BinningBackend, BinningStrategy,
+    MissingBinPolicy, SolverConfig, TargetAnalysis, SOLVER_AVAILABLE,
+};
+use crate::utils::{create_spinner, format_duration};
+
+/// Settings of a `lophi bench` run
+#[derive(Debug, Clone)]
+pub struct BenchConfig {
+    pub input: PathBuf,
+    pub target: String,
+    pub weight_column: Option<String>,
+    /// Times each stage runs
+    pub runs: usize,
+    /// Binning strategies to time, each with every backend in `backends`
+    pub strategies: Vec<BinningStrategy>,
+    pub backends: Vec<BinningBackend>,
+    pub gini_bins: usize,
+    pub prebins: usize,
+    pub correlation_threshold: f64,
+    pub infer_schema_length: usize,
+    pub sheet: Option<String>,
+}
+
+/// Timings of one stage over all its runs
+#[derive(Debug, Clone)]
+pub struct StageTiming {
+    pub stage: String,
+    pub runs: Vec<Duration>,
+    /// Resident memory of the process after the last run, in MB (Linux only)
+    pub rss_mb: Option<f64>,
+}
+
+impl StageTiming {
+    pub fn mean(&self) -> Duration {
+        self.runs.iter().sum::<Duration>() / self.runs.len().max(1) as u32
+    }
+
+    pub fn min(&self) -> Duration {
+        self.runs.iter().min().copied().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.runs.iter().max().copied().unwrap_or_default()
+    }
+}
+
+/// Parse a comma-separated `--strategies` list
+pub fn parse_strategies(list: &str) -> Result<Vec<BinningStrategy>> {
+    let strategies = parse_list(list)?;
+    if strategies.contains(&BinningStrategy::Manual) {
+        anyhow::bail!("The manual strategy needs --bin-breaks and cannot be benchmarked");
+    }
+    Ok(strategies)
+}
+
+/// Parse a comma-separated `--backends` list. MIP is timed as the dynamic
+/// program in builds without the solver, like a reduction run.
+pub fn parse_backends(list: &str) -> Result<Vec<BinningBackend>> {
+    Ok(parse_list::<BinningBackend>(list)?
+        .into_iter()
+        .map(|backend| match backend {
+            BinningBackend::Mip if !SOLVER_AVAILABLE => BinningBackend::Dp,
+            backend => backend,
+        })
+        .fold(Vec::new(), |mut unique, backend| {
+            if !unique.contains(&backend) {
+                unique.push(backend);
+            }
+            unique
+        }))
+}
+
+fn parse_list<T: std::str::FromStr<Err = String>>(list: &str) -> Result<Vec<T>> {
+    let items = list
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().map_err(|e: String| anyhow::anyhow!(e)))
+        .collect::<Result<Vec<T>>>()?;
+    if items.is_empty() {
+        anyhow::bail!("Expected at least one value in '{}'", list);
+    }
+    Ok(items)
+}
+
+/// Run the benchmark and print the timing table
+pub fn run_bench(config: &BenchConfig) -> Result<Vec<StageTiming>> {
+    if config.runs == 0 {
+        anyhow::bail!("--runs must be at least 1");
+    }
+    println!(
+        "\n    {} {} ({} run{} per stage)\n",
+        style("⏱").cyan(),
+        style(format!("Benchmarking {}", config.input.display()))
+            .white()
+            .bold(),
+        config.runs,
+        if config.runs == 1 { "" } else { "s" }
+    );
+
+    let timings = bench_stages(config)?;
+
+    for line in timing_table(&timings).to_string().lines() {
+        println!("    {}", line);
+    }
+    if let Some(peak) = read_proc_status_mb("VmHWM:") {
+        println!("    Peak resident memory: {:.1} MB", peak);
+    }
+    Ok(timings)
+}
+
+fn bench_stages(config: &BenchConfig) -> Result<Vec<StageTiming>> {
+    // Events go nowhere; this keeps the stages from drawing progress bars
+    let tx = create_progress_channel().0;
+    let mut timings = Vec::new();
+
+    let mut df = DataFrame::empty();
+    timings.push(time_stage("load", config.runs, || {
+        df = load_dataset_from_sheet(
+            &config.input,
+            config.infer_schema_length,
+            config.sheet.as_deref(),
+            Some(&tx),
+        )?
+        .0;
+        Ok(())
+    })?);
+
+    if df.column(&config.target).is_err() {
+        anyhow::bail!("Target column '{}' not found in dataset", config.target);
+    }
+    if let TargetAnalysis::NeedsMapping { .. } = analyze_target_column(&df, &config.target)? {
+        anyhow::bail!(
+            "Target column '{}' is not binary (0/1); bench needs a 0/1 target",
+            config.target
+        );
+    }
+    let weight_column = config.weight_column.as_deref();
+    let weights = get_weights(&df, weight_column)?;
+
+    timings.push(time_stage("missing", config.runs, || {
+        analyze_missing_values(&df, &weights, weight_column).map(|_| ())
+    })?);
+
+    let mut feature_types = None;
+    for &strategy in &config.strategies {
+        for &backend in &config.backends {
+            let solver_config = (backend != BinningBackend::Greedy).then(|| SolverConfig {
+                backend,
+                ..SolverConfig::default()
+            });
+            let stage = format!("binning {}/{}", strategy, backend);
+            timings.push(time_stage(&stage, config.runs, || {
+                let analyses = analyze_features_iv_with_progress(
+                    &df,
+                    &config.target,
+                    config.gini_bins,
+                    config.prebins,
+                    None,
+                    strategy,
+                    Some(5),
+                    Some(5.0),
+                    &weights,
+                    weight_column,
+                    solver_config.as_ref(),
+                    None,
+                    MissingBinPolicy::Separate,
+                    None,
+                    &tx,
+                )?;
+                feature_types.get_or_insert_with(|| {
+                    analyses
+                        .iter()
+                        .map(|a| (a.feature_name.clone(), a.feature_type))
+                        .collect()
+                });
+                Ok(())
+            })?);
+        }
+    }
+
+    timings.push(time_stage("correlation", config.runs, || {
+        find_correlated_pairs_auto_with_progress(
+            &df,
+            AssociationThresholds::new(config.correlation_threshold, None),
+            &weights,
+            weight_column,
+            feature_types.as_ref(),
+            &tx,
+        )
+        .map(|_| ())
+    })?);
+
+    let scratch = std::env::temp_dir().join(format!("lophi_bench_{}.parquet", std::process::id()));
+    let saved = time_stage("save", config.runs, || save_parquet(&mut df, &scratch));
+    std::fs::remove_file(&scratch).ok();
+    timings.push(saved?);
+
+    Ok(timings)
+}
+
+/// Run `f` `runs` times behind a spinner and record each run's time
+fn time_stage(stage: &str, runs: usize, mut f: impl FnMut() -> Result<()>) -> Result<StageTiming> {
+    let spinner = create_spinner(&format!("Timing {}...", stage));
+    let mut durations = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        f().with_context(|| format!("Benchmark stage '{}' failed", stage))?;
+        durations.push(start.elapsed());
+    }
+    spinner.finish_and_clear();
+    tracing::debug!(stage, runs, ?durations, "Timed benchmark stage");
+    Ok(StageTiming {
+        stage: stage.to_string(),
+        runs: durations,
+        rss_mb: read_proc_status_mb("VmRSS:"),
+    })
+}
+
+fn save_parquet(df: &mut DataFrame, path: &Path) -> Result<()> {
+    let file = std::fs::File::create(path)
+        .with_context(|| format!("Failed to create output file: {}", path.display()))?;
+    ParquetWriter::new(file)
+        .finish(df)
+        .with_context(|| format!("Failed to write Parquet file: {}", path.display()))?;
+    Ok(())
+}
+
+fn timing_table(timings: &[StageTiming]) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_header(
+        ["Stage", "Mean", "Min", "Max", "RSS (MB)"]
+            .into_iter()
+            .map(|h| Cell::new(h).add_attribute(Attribute::Bold)),
+    );
+    for timing in timings {
+        let rss = timing
+            .rss_mb
+            .map_or_else(|| "-".to_string(), |mb| format!("{:.1}", mb));
+        table.add_row(vec![
+            Cell::new(&timing.stage),
+            Cell::new(format_duration(timing.mean())).set_alignment(CellAlignment::Right),
+            Cell::new(format_duration(timing.min())).set_alignment(CellAlignment::Right),
+            Cell::new(format_duration(timing.max())).set_alignment(CellAlignment::Right),
+            Cell::new(rss).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    table
+}
+
+/// A memory line of /proc/self/status (e.g. `VmRSS:`) in MB; `None` off Linux
+fn read_proc_status_mb(key: &str) -> Option<f64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kb: f64 = status
+        .lines()
+        .find_map(|line| line.strip_prefix(key))?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()?;
+    Some(kb / 1024.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bench_lists() {
+        assert_eq!(
+            parse_strategies("quantile, cart").unwrap(),
+            [BinningStrategy::Quantile, BinningStrategy::Cart]
+        );
+        assert!(parse_strategies("manual").is_err());
+        assert!(parse_strategies("").is_err());
+
+        let backends = parse_backends("greedy,dp,mip").unwrap();
+        assert_eq!(backends[..2], [BinningBackend::Greedy, BinningBackend::Dp]);
+        assert_eq!(backends.len(), if SOLVER_AVAILABLE { 3 } else { 2 });
+        assert!(parse_backends("simplex").is_err());
+    }
+}
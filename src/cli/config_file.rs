@@ -0,0 +1,748 @@
+//! Pipeline configuration files (`--config pipeline.toml` / `.yaml`)
+//!
+//! A config file holds the same settings as the reduction CLI flags so a run can
+//! be committed alongside the data and reproduced later. Keys use the flag
+//! names with underscores (e.g. `missing_threshold`, `drop_columns`). Every key
+//! is optional; values from the file replace the built-in defaults, and any
+//! flag given explicitly on the command line still wins over the file.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use clap::parser::ValueSource;
+use clap::ArgMatches;
+use serde::{Deserialize, Serialize};
+
+use super::args::{build_csv_dialect, Cli};
+use crate::pipeline::{
+    BinBreaks, ParquetCodec, ParquetStatistics, SpecialValues, StepPlan, TimeFreq,
+};
+use crate::report::PlotFormat;
+
+/// Settings that can be loaded from a pipeline config file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PipelineFileConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sheet: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub non_event_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub non_event_label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_target: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight_column: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dominance_threshold: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_threshold: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_action: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub steps: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_gini: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_threshold: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gini_threshold: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_threshold: Option<f64>,
+    pub cramers_v_threshold: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_strategy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub corr_keep_policy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub corr_keep_prefixes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gini_bins: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binning_strategy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bin_breaks: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bins_from: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub univariate_metric: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mi_threshold: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_insignificant_bins: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub special_values: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_bin_policy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cv_folds: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cv_min_iv: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prebins: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cart_min_bin_pct: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_category_samples: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fast: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_rows: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_frac: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_stratify: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_seed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_solver: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monotonicity: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solver_timeout: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solver_gap: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_bin_pct: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_bin_pct: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_woe_delta: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fail_on_solver_fallback: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binning_backend: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drop_columns: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub select_columns: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drop_partition_columns: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_columns: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub infer_schema_length: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub csv_separator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub csv_quote: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub csv_decimal_comma: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub csv_date_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub csv_null: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub non_finite_policy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_policy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expect_schema: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fail_on_schema_drift: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_memory_gb: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_gini_export: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_woe: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parquet_compression: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub row_group_size: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parquet_statistics: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_include_target_mapping: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_cart_trees: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_distributions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plots: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plots_top: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plot_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dictionary: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review_correlations: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review_drops: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub browse_results: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_column: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub train_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iv_stability: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation_frac: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iv_stability_threshold: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segment_column: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_column: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_freq: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protected_columns: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_threshold: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pca: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pca_loading_threshold: Option<f64>,
+}
+
+/// Config file syntax, chosen by file extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Toml,
+    Yaml,
+}
+
+impl ConfigFormat {
+    /// Detect the format from the file extension (`.toml`, `.yaml`, `.yml`)
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        match extension.as_str() {
+            "toml" => Ok(ConfigFormat::Toml),
+            "yaml" | "yml" => Ok(ConfigFormat::Yaml),
+            _ => anyhow::bail!(
+                "Unsupported config file format: '{}'. Supported formats: toml, yaml, yml",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl PipelineFileConfig {
+    /// Parse a config file, detecting TOML or YAML from its extension
+    pub fn load(path: &Path) -> Result<Self> {
+        let format = ConfigFormat::from_path(path)?;
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+        let config = match format {
+            ConfigFormat::Toml => toml::from_str(&content)
+                .with_context(|| format!("Invalid TOML in config file: {}", path.display()))?,
+            ConfigFormat::Yaml => {
+                // An empty YAML document deserializes to unit, not an empty map
+                if content.trim().is_empty() {
+                    Self::default()
+                } else {
+                    serde_yaml::from_str(&content).with_context(|| {
+                        format!("Invalid YAML in config file: {}", path.display())
+                    })?
+                }
+            }
+        };
+        Ok(config)
+    }
+
+    /// Check value ranges that clap's validators enforce for the equivalent flags
+    pub fn validate(&self) -> Result<()> {
+        for (name, value) in [
+            ("dominance_threshold", self.dominance_threshold),
+            ("id_threshold", self.id_threshold),
+            ("missing_threshold", self.missing_threshold),
+            ("gini_threshold", self.gini_threshold),
+            ("mi_threshold", self.mi_threshold),
+            ("correlation_threshold", self.correlation_threshold),
+            ("cramers_v_threshold", self.cramers_v_threshold),
+            ("merge_insignificant_bins", self.merge_insignificant_bins),
+            ("solver_gap", self.solver_gap),
+            ("iv_stability_threshold", self.iv_stability_threshold),
+            ("proxy_threshold", self.proxy_threshold),
+            ("pca_loading_threshold", self.pca_loading_threshold),
+        ] {
+            if let Some(v) = value {
+                if !(0.0..=1.0).contains(&v) {
+                    anyhow::bail!("{} must be between 0.0 and 1.0, got {}", name, v);
+                }
+            }
+        }
+        for (name, value) in [
+            ("cart_min_bin_pct", self.cart_min_bin_pct),
+            ("min_bin_pct", self.min_bin_pct),
+            ("max_bin_pct", self.max_bin_pct),
+        ] {
+            if let Some(pct) = value {
+                if !(0.0..=100.0).contains(&pct) {
+                    anyhow::bail!("{} must be between 0.0 and 100.0, got {}", name, pct);
+                }
+            }
+        }
+        if let Some(delta) = self.min_woe_delta {
+            if !(delta.is_finite() && delta >= 0.0) {
+                anyhow::bail!("min_woe_delta must be a non-negative number, got {}", delta);
+            }
+        }
+        if let (Some(min), Some(max)) = (self.min_bin_pct, self.max_bin_pct) {
+            if min > max {
+                anyhow::bail!("min_bin_pct ({}) cannot exceed max_bin_pct ({})", min, max);
+            }
+        }
+        if let Some(entries) = &self.bin_breaks {
+            BinBreaks::parse(entries).context("bin_breaks")?;
+        }
+        if let Some(entries) = &self.special_values {
+            SpecialValues::parse(entries).context("special_values")?;
+        }
+        if let Some(steps) = &self.steps {
+            StepPlan::parse(steps).context("steps")?;
+        }
+        if self.no_target == Some(true) && self.target.is_some() {
+            anyhow::bail!("target cannot be set together with no_target");
+        }
+        if self.cv_folds.is_some_and(|folds| folds < 2) {
+            anyhow::bail!("cv_folds must be at least 2");
+        }
+        if let Some(fraction) = self.validation_frac {
+            if !(fraction > 0.0 && fraction < 1.0) {
+                anyhow::bail!(
+                    "validation_frac must be between 0.0 and 1.0, got {}",
+                    fraction
+                );
+            }
+        }
+        if let Some(fraction) = self.sample_frac {
+            if !(fraction > 0.0 && fraction <= 1.0) {
+                anyhow::bail!(
+                    "sample_frac must be above 0.0 and at most 1.0, got {}",
+                    fraction
+                );
+            }
+        }
+        build_csv_dialect(
+            self.csv_separator.as_deref().unwrap_or(","),
+            self.csv_quote.as_deref().unwrap_or("\""),
+            self.csv_decimal_comma.unwrap_or(false),
+            self.csv_date_format.as_deref(),
+            self.csv_null.as_deref(),
+        )
+        .context("CSV dialect")?;
+        if let Some(codec) = &self.parquet_compression {
+            codec
+                .parse::<ParquetCodec>()
+                .map_err(|e| anyhow::anyhow!("parquet_compression: {}", e))?;
+        }
+        if self.row_group_size == Some(0) {
+            anyhow::bail!("row_group_size: must be at least 1");
+        }
+        if let Some(statistics) = &self.parquet_statistics {
+            statistics
+                .parse::<ParquetStatistics>()
+                .map_err(|e| anyhow::anyhow!("parquet_statistics: {}", e))?;
+        }
+        if let Some(format) = &self.plot_format {
+            format
+                .parse::<PlotFormat>()
+                .map_err(|e| anyhow::anyhow!("plot_format: {}", e))?;
+        }
+        if let Some(freq) = &self.time_freq {
+            freq.parse::<TimeFreq>()
+                .map_err(|e| anyhow::anyhow!("time_freq: {}", e))?;
+        }
+        Ok(())
+    }
+
+    /// Fill `cli` with values from the file, except where the user passed the
+    /// flag explicitly on the command line.
+    pub fn apply_to_cli(&self, cli: &mut Cli, matches: &ArgMatches) {
+        let from_cli = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+
+        macro_rules! merge {
+            ($field:ident) => {
+                if let Some(value) = &self.$field {
+                    if !from_cli(stringify!($field)) {
+                        cli.$field = value.clone();
+                    }
+                }
+            };
+            ($field:ident, optional) => {
+                if let Some(value) = &self.$field {
+                    if !from_cli(stringify!($field)) {
+                        cli.$field = Some(value.clone());
+                    }
+                }
+            };
+        }
+
+        merge!(input, optional);
+        merge!(sheet, optional);
+        merge!(output, optional);
+        merge!(target, optional);
+        merge!(event_value, optional);
+        merge!(non_event_value, optional);
+        merge!(event_label, optional);
+        merge!(non_event_label, optional);
+        merge!(target_type);
+        merge!(no_target);
+        merge!(weight_column, optional);
+        merge!(dominance_threshold, optional);
+        merge!(id_threshold, optional);
+        merge!(id_action);
+        merge!(steps);
+        merge!(skip_gini);
+        merge!(missing_threshold);
+        merge!(gini_threshold);
+        merge!(correlation_threshold);
+        merge!(cramers_v_threshold, optional);
+        merge!(correlation_strategy);
+        merge!(corr_keep_policy);
+        merge!(corr_keep_prefixes);
+        merge!(gini_bins);
+        merge!(binning_strategy);
+        merge!(bin_breaks);
+        merge!(bins_from, optional);
+        merge!(univariate_metric);
+        merge!(mi_threshold);
+        merge!(merge_insignificant_bins, optional);
+        merge!(special_values);
+        merge!(missing_bin_policy);
+        merge!(cv_folds, optional);
+        merge!(cv_min_iv, optional);
+        merge!(prebins);
+        merge!(cart_min_bin_pct);
+        merge!(min_category_samples);
+        merge!(fast);
+        merge!(sample_rows, optional);
+        merge!(sample_frac, optional);
+        merge!(sample_stratify);
+        merge!(sample_seed, optional);
+        merge!(seed);
+        merge!(use_solver);
+        merge!(monotonicity);
+        merge!(solver_timeout);
+        merge!(solver_gap);
+        merge!(min_bin_pct);
+        merge!(max_bin_pct);
+        merge!(min_woe_delta);
+        merge!(fail_on_solver_fallback);
+        merge!(binning_backend);
+        merge!(drop_columns);
+        merge!(select_columns);
+        merge!(drop_partition_columns);
+        merge!(keep_columns);
+        merge!(infer_schema_length);
+        merge!(csv_separator);
+        merge!(csv_quote);
+        merge!(csv_decimal_comma);
+        merge!(csv_date_format, optional);
+        merge!(csv_null, optional);
+        merge!(non_finite_policy);
+        merge!(date_policy);
+        merge!(expect_schema, optional);
+        merge!(fail_on_schema_drift);
+        merge!(max_memory_gb, optional);
+        merge!(split_gini_export);
+        merge!(export_woe);
+        merge!(parquet_compression);
+        merge!(row_group_size, optional);
+        merge!(parquet_statistics);
+        merge!(output_include_target_mapping);
+        merge!(export_cart_trees);
+        merge!(export_distributions);
+        merge!(plots);
+        merge!(plots_top);
+        merge!(plot_format);
+        merge!(dictionary, optional);
+        merge!(review_correlations);
+        merge!(review_drops);
+        merge!(browse_results);
+        merge!(split_column, optional);
+        merge!(train_value, optional);
+        merge!(iv_stability);
+        merge!(validation_frac);
+        merge!(iv_stability_threshold, optional);
+        merge!(segment_column, optional);
+        merge!(time_column, optional);
+        merge!(time_freq);
+        merge!(protected_columns);
+        merge!(proxy_threshold);
+        merge!(pca);
+        merge!(pca_loading_threshold);
+    }
+}
+
+/// Load `path` and merge it into `cli`; explicit command-line flags take precedence.
+pub fn apply_config_file(cli: &mut Cli, matches: &ArgMatches, path: &Path) -> Result<()> {
+    let config = PipelineFileConfig::load(path)?;
+    config
+        .validate()
+        .with_context(|| format!("Invalid value in config file: {}", path.display()))?;
+    config.apply_to_cli(cli, matches);
+    Ok(())
+}
+
+/// Commented TOML template listing every setting with its default value
+const TOML_TEMPLATE: &str = r#"# Lo-phi pipeline configuration
+# Use with: lophi --config pipeline.toml --no-confirm
+# Any flag passed on the command line overrides the value here.
+
+# Data
+# input = "data.parquet"
+# sheet = "Applications"  # worksheet of an Excel input (default: first sheet)
+# output = "data_reduced.parquet"
+# target = "target"
+target_type = "binary"  # binary | continuous (R² against a numeric target)
+no_target = false  # reduce without a target: no Gini/IV step
+# weight_column = "sample_weight"
+# drop_columns = ["id", "timestamp"]
+# select_columns = ["target", "income", "bureau_score"]  # load only these
+drop_partition_columns = false  # leave out key=value folder columns
+# keep_columns = ["bureau_score"]  # never dropped by any step
+# split_column = "sample"    # screen on training rows only...
+# train_value = "TRAIN"      # ...drops still apply to every row
+iv_stability = false  # compare IV on train and validation rows
+validation_frac = 0.3  # validation share without a split column
+# iv_stability_threshold = 0.5  # drop features whose IV moves more than this
+# segment_column = "region"  # missing ratios and IV per segment
+# time_column = "app_date"  # flag features whose fill rate or IV drifts over time
+time_freq = "month"  # month | quarter | year
+# protected_columns = ["gender", "age_band"]  # flag features that proxy them
+proxy_threshold = 0.3  # Cramér's V or eta above which a feature is a proxy
+pca = false  # report explained variance and high-loading groups of the retained features
+pca_loading_threshold = 0.7  # absolute loading that puts a feature in a component's group
+infer_schema_length = 10000
+csv_separator = ","  # CSV input and output; e.g. ";" or "tab"
+csv_quote = '"'  # or "none"
+csv_decimal_comma = false  # "1,5" numbers (needs a csv_separator other than ",")
+# csv_date_format = "%d.%m.%Y"  # text columns matching it are read as dates
+# csv_null = "NA"  # text standing for a missing value
+non_finite_policy = "treat-as-missing"  # treat-as-missing | clip | error
+date_policy = "keep"  # keep | drop | derive
+# expect_schema = "schema.json"  # report added/removed/re-typed columns (lophi schema)
+fail_on_schema_drift = false  # stop the run when the schema drifted
+# max_memory_gb = 8.0  # stream, or refuse, datasets over this size
+
+# Target mapping for non-binary targets (both values required together)
+# event_value = "bad"
+# non_event_value = "good"
+# Class names used in reports instead of 1/0 (both required together)
+# event_label = "default"
+# non_event_label = "good"
+
+# Thresholds
+# dominance_threshold = 0.995  # drop constant and near-constant features first
+# id_threshold = 0.95  # flag likely ID columns by their share of distinct values
+id_action = "flag"  # flag | drop
+# steps = ["missing", "gini", "correlation"]  # steps to run, in order
+skip_gini = false  # skip the Gini/IV step and the checks built on it
+missing_threshold = 0.3
+gini_threshold = 0.05
+correlation_threshold = 0.4
+# cramers_v_threshold = 0.5  # categorical pairs; defaults to correlation_threshold
+correlation_strategy = "pairwise"  # pairwise | cluster
+corr_keep_policy = "higher_iv"  # higher_iv | lower_missing | first_alphabetical | custom_list
+# corr_keep_prefixes = ["bureau_", "internal_"]  # custom_list; earlier prefixes are kept
+
+# Binning
+binning_strategy = "cart"  # cart | quantile | equal_width | manual
+# bin_breaks = ["age:25,35,50", "income:1000,5000"]  # manual; B1,B2 alone for every feature
+# bins_from = "scoring_spec.json"  # score IV on the bins of an earlier run's spec or Gini export
+univariate_metric = "iv"  # iv | mutual_info
+mi_threshold = 0.001      # mutual_info: drop features below this MI (nats)
+# merge_insignificant_bins = 0.05  # merge adjacent bins with chi-square p above this
+# special_values = ["-999997", "-999998", "age:-1"]  # own bins; FEATURE:VALUE for one feature
+missing_bin_policy = "separate"  # separate | merge_worst | impute
+# cv_folds = 5     # k-fold cross-validated IV in the Gini export...
+# cv_min_iv = 0.02 # ...and drop features whose CV mean IV is below this
+gini_bins = 10
+prebins = 20
+cart_min_bin_pct = 5.0
+min_category_samples = 5
+fast = false  # decile bins, no solver, sampled correlations (approximate)
+# sample_rows = 50000      # analyse a row sample (or sample_frac = 0.1)...
+sample_stratify = false  # ...drawn per target class
+# sample_seed = 7  # defaults to seed
+seed = 42  # row sample, CV folds and random validation split
+
+# Solver
+use_solver = true
+monotonicity = "none"  # none | ascending | descending | peak | valley | auto
+solver_timeout = 30
+solver_gap = 0.01
+min_bin_pct = 0.0    # every solver bin holds at least this % of weighted rows
+max_bin_pct = 100.0  # ...and at most this %
+min_woe_delta = 0.0  # adjacent solver bins differ in WoE by at least this
+fail_on_solver_fallback = false  # stop the run when the solver finds no binning
+binning_backend = "mip"  # mip | dp (pure Rust, no HiGHS) | greedy
+
+# Reports
+split_gini_export = false  # one JSON per feature + index.json (wide datasets)
+export_woe = false  # also write {output}_woe with WoE-encoded features
+parquet_compression = "zstd"  # snappy | zstd | lz4 | uncompressed (Parquet outputs)
+# row_group_size = 262144  # rows per Parquet row group
+parquet_statistics = "basic"  # basic (min/max/nulls) | full (+ distinct counts) | none
+output_include_target_mapping = false  # write the mapped 0/1 target, raw values in {target}_raw
+export_cart_trees = false  # CART prebinning trees per feature (debugging)
+export_distributions = false  # weighted histograms of retained numeric features in the Gini export
+plots = false  # WoE/event-rate charts of the top features by IV in {input}_plots/
+plots_top = 20
+plot_format = "svg"  # svg | png
+# dictionary = "dictionary.csv"  # feature,description,owner shown next to feature names
+
+# Interactive mode
+review_correlations = false  # review correlated-pair drops before they apply
+review_drops = false  # review every proposed drop before saving
+browse_results = false  # browse drops, IV, pairs and bins after the run
+"#;
+
+/// Commented YAML template listing every setting with its default value
+const YAML_TEMPLATE: &str = r#"# Lo-phi pipeline configuration
+# Use with: lophi --config pipeline.yaml --no-confirm
+# Any flag passed on the command line overrides the value here.
+
+# Data
+# input: data.parquet
+# sheet: Applications  # worksheet of an Excel input (default: first sheet)
+# output: data_reduced.parquet
+# target: target
+target_type: binary  # binary | continuous (R² against a numeric target)
+no_target: false  # reduce without a target: no Gini/IV step
+# weight_column: sample_weight
+# drop_columns: [id, timestamp]
+# select_columns: [target, income, bureau_score]  # load only these
+drop_partition_columns: false  # leave out key=value folder columns
+# keep_columns: [bureau_score]  # never dropped by any step
+# split_column: sample    # screen on training rows only...
+# train_value: TRAIN      # ...drops still apply to every row
+iv_stability: false  # compare IV on train and validation rows
+validation_frac: 0.3  # validation share without a split column
+# iv_stability_threshold: 0.5  # drop features whose IV moves more than this
+# segment_column: region  # missing ratios and IV per segment
+# time_column: app_date  # flag features whose fill rate or IV drifts over time
+time_freq: month  # month | quarter | year
+# protected_columns: [gender, age_band]  # flag features that proxy them
+proxy_threshold: 0.3  # Cramér's V or eta above which a feature is a proxy
+pca: false  # report explained variance and high-loading groups of the retained features
+pca_loading_threshold: 0.7  # absolute loading that puts a feature in a component's group
+infer_schema_length: 10000
+csv_separator: ","  # CSV input and output; e.g. ";" or tab
+csv_quote: '"'  # or none
+csv_decimal_comma: false  # "1,5" numbers (needs a csv_separator other than ",")
+# csv_date_format: "%d.%m.%Y"  # text columns matching it are read as dates
+# csv_null: NA  # text standing for a missing value
+non_finite_policy: treat-as-missing  # treat-as-missing | clip | error
+date_policy: keep  # keep | drop | derive
+# expect_schema: schema.json  # report added/removed/re-typed columns (lophi schema)
+fail_on_schema_drift: false  # stop the run when the schema drifted
+# max_memory_gb: 8.0  # stream, or refuse, datasets over this size
+
+# Target mapping for non-binary targets (both values required together)
+# event_value: bad
+# non_event_value: good
+# Class names used in reports instead of 1/0 (both required together)
+# event_label: default
+# non_event_label: good
+
+# Thresholds
+# dominance_threshold: 0.995  # drop constant and near-constant features first
+# id_threshold: 0.95  # flag likely ID columns by their share of distinct values
+id_action: flag  # flag | drop
+# steps: [missing, gini, correlation]  # steps to run, in order
+skip_gini: false  # skip the Gini/IV step and the checks built on it
+missing_threshold: 0.3
+gini_threshold: 0.05
+correlation_threshold: 0.4
+# cramers_v_threshold: 0.5  # categorical pairs; defaults to correlation_threshold
+correlation_strategy: pairwise  # pairwise | cluster
+corr_keep_policy: higher_iv  # higher_iv | lower_missing | first_alphabetical | custom_list
+# corr_keep_prefixes: [bureau_, internal_]  # custom_list; earlier prefixes are kept
+
+# Binning
+binning_strategy: cart  # cart | quantile | equal_width | manual
+# bin_breaks: ["age:25,35,50", "income:1000,5000"]  # manual; B1,B2 alone for every feature
+# bins_from: scoring_spec.json  # score IV on the bins of an earlier run's spec or Gini export
+univariate_metric: iv  # iv | mutual_info
+mi_threshold: 0.001    # mutual_info: drop features below this MI (nats)
+# merge_insignificant_bins: 0.05  # merge adjacent bins with chi-square p above this
+# special_values: ["-999997", "-999998", "age:-1"]  # quoted; FEATURE:VALUE for one feature
+missing_bin_policy: separate  # separate | merge_worst | impute
+# cv_folds: 5     # k-fold cross-validated IV in the Gini export...
+# cv_min_iv: 0.02 # ...and drop features whose CV mean IV is below this
+gini_bins: 10
+prebins: 20
+cart_min_bin_pct: 5.0
+min_category_samples: 5
+fast: false  # decile bins, no solver, sampled correlations (approximate)
+# sample_rows: 50000      # analyse a row sample (or sample_frac: 0.1)...
+sample_stratify: false  # ...drawn per target class
+# sample_seed: 7  # defaults to seed
+seed: 42  # row sample, CV folds and random validation split
+
+# Solver
+use_solver: true
+monotonicity: none  # none | ascending | descending | peak | valley | auto
+solver_timeout: 30
+solver_gap: 0.01
+min_bin_pct: 0.0    # every solver bin holds at least this % of weighted rows
+max_bin_pct: 100.0  # ...and at most this %
+min_woe_delta: 0.0  # adjacent solver bins differ in WoE by at least this
+fail_on_solver_fallback: false  # stop the run when the solver finds no binning
+binning_backend: mip  # mip | dp (pure Rust, no HiGHS) | greedy
+
+# Reports
+split_gini_export: false  # one JSON per feature + index.json (wide datasets)
+export_woe: false  # also write {output}_woe with WoE-encoded features
+parquet_compression: zstd  # snappy | zstd | lz4 | uncompressed (Parquet outputs)
+# row_group_size: 262144  # rows per Parquet row group
+parquet_statistics: basic  # basic (min/max/nulls) | full (+ distinct counts) | none
+output_include_target_mapping: false  # write the mapped 0/1 target, raw values in {target}_raw
+export_cart_trees: false  # CART prebinning trees per feature (debugging)
+export_distributions: false  # weighted histograms of retained numeric features in the Gini export
+plots: false  # WoE/event-rate charts of the top features by IV in {input}_plots/
+plots_top: 20
+plot_format: svg  # svg | png
+# dictionary: dictionary.csv  # feature,description,owner shown next to feature names
+
+# Interactive mode
+review_correlations: false  # review correlated-pair drops before they apply
+review_drops: false  # review every proposed drop before saving
+browse_results: false  # browse drops, IV, pairs and bins after the run
+"#;
+
+/// Return the template text for the given format
+pub fn config_template(format: ConfigFormat) -> &'static str {
+    match format {
+        ConfigFormat::Toml => TOML_TEMPLATE,
+        ConfigFormat::Yaml => YAML_TEMPLATE,
+    }
+}
+
+/// Write a commented config template (`init-config` subcommand)
+pub fn write_config_template(path: &Path, force: bool) -> Result<()> {
+    let format = ConfigFormat::from_path(path)?;
+    if path.exists() && !force {
+        anyhow::bail!(
+            "Config file already exists: {}. Use --force to overwrite.",
+            path.display()
+        );
+    }
+    std::fs::write(path, config_template(format))
+        .with_context(|| format!("Failed to write config file: {}", path.display()))?;
+    Ok(())
+}
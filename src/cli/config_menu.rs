@@ -9,7 +9,7 @@ use std::path::PathBuf;
 
 use anyhow::Result;
 use crossterm::{
-    event::{self, Event, KeyCode, KeyEventKind},
+    event::{self, DisableBracketedPaste, EnableBracketedPaste, Event, KeyCode, KeyEventKind},
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
     ExecutableCommand,
 };
@@ -25,26 +25,73 @@ use super::shared::{
     check_terminal_size, draw_too_small_overlay, render_logo, themed, MIN_COLS, MIN_ROWS,
 };
 use super::theme;
-use crate::pipeline::TargetMapping;
+use crate::pipeline::{
+    BinBreaks, CrossValidation, CsvDialect, EventLabels, FairnessCheck, IvStabilityCheck,
+    ParquetOptions, PcaSettings, ScreeningSample, SpecialValues, StepPlan, TargetMapping,
+    TimeStabilityCheck,
+};
+use crate::report::{PlotSettings, TableExports};
 
 /// Configuration values that can be customized
 #[derive(Clone, Debug)]
 pub struct Config {
     pub input: PathBuf,
+    /// Worksheet of an Excel input; `None` reads the first sheet
+    pub sheet: Option<String>,
     pub target: Option<String>,
+    /// Reduce without a target (`--no-target`); the menu then offers no target picker
+    pub no_target: bool,
     pub output: PathBuf,
     pub missing_threshold: f64,
     pub gini_threshold: f64,
     pub correlation_threshold: f64,
+    /// Cramér's V threshold for categorical pairs (None = correlation_threshold)
+    pub cramers_v_threshold: Option<f64>,
+    /// Near-constant pre-filter threshold (`--dominance-threshold`), off when None
+    pub dominance_threshold: Option<f64>,
+    /// Distinct-value ratio above which a column looks like an ID (`--id-threshold`)
+    pub id_threshold: Option<f64>,
+    /// "flag" or "drop" (`--id-action`)
+    pub id_action: String,
+    /// "pairwise" or "cluster"
+    pub correlation_strategy: String,
+    /// Which member of a correlated pair is kept (`--corr-keep-policy`)
+    pub corr_keep_policy: String,
+    /// Preferred name prefixes for the `custom_list` keep policy
+    pub corr_keep_prefixes: Vec<String>,
     pub columns_to_drop: Vec<String>,
+    /// Columns to load (`--select-columns`), all when empty
+    pub select_columns: Vec<String>,
+    /// Leave out Hive partition columns (`--drop-partition-columns`)
+    pub drop_partition_columns: bool,
+    /// Features protected from every drop (`--keep-columns`)
+    pub keep_columns: Vec<String>,
+    /// "binary" or "continuous"
+    pub target_type: String,
     /// Optional mapping for non-binary target columns
     pub target_mapping: Option<TargetMapping>,
     /// Optional column containing sample weights
     pub weight_column: Option<String>,
 
     // Binning parameters
-    /// Binning strategy: "cart" or "quantile"
+    /// Binning strategy: "cart", "quantile", "equal_width" or "manual"
     pub binning_strategy: String,
+    /// Breakpoints for manual binning (`--bin-breaks`)
+    pub bin_breaks: Option<BinBreaks>,
+    /// Scoring spec or Gini export whose bins the Gini step evaluates (`--bins-from`)
+    pub bins_from: Option<PathBuf>,
+    /// Univariate filter metric: "iv" or "mutual_info"
+    pub univariate_metric: String,
+    /// Mutual information threshold (nats) under "mutual_info"
+    pub mi_threshold: f64,
+    /// Significance level for merging adjacent bins after binning (None = off)
+    pub merge_insignificant_bins: Option<f64>,
+    /// Values of numeric features binned on their own (`--special-values`)
+    pub special_values: Option<SpecialValues>,
+    /// Missing values of numeric features: "separate", "merge_worst" or "impute"
+    pub missing_bin_policy: String,
+    /// K-fold cross-validated IV (`--cv-folds` / `--cv-min-iv`)
+    pub cross_validation: Option<CrossValidation>,
     /// Number of final bins for IV calculation
     pub gini_bins: usize,
     /// Number of pre-bins before optimization
@@ -53,6 +100,12 @@ pub struct Config {
     pub cart_min_bin_pct: f64,
     /// Minimum samples per category for categorical features
     pub min_category_samples: usize,
+    /// Fast screen: decile bins, no solver, sampled correlations
+    pub fast: bool,
+    /// Row sample to analyse instead of the whole dataset
+    pub sample: Option<ScreeningSample>,
+    /// Seed of the randomized steps
+    pub seed: u64,
 
     // Solver options
     /// Whether to use MIP solver for optimal binning
@@ -63,10 +116,115 @@ pub struct Config {
     pub solver_timeout: u64,
     /// Solver MIP gap tolerance
     pub solver_gap: f64,
+    /// Smallest share of weighted samples per solver bin (%)
+    pub min_bin_pct: f64,
+    /// Largest share of weighted samples per solver bin (%)
+    pub max_bin_pct: f64,
+    /// Smallest WoE difference between adjacent solver bins
+    pub min_woe_delta: f64,
+    /// Stop the run when the solver finds no binning for a feature
+    pub fail_on_solver_fallback: bool,
+    /// Binning backend: "mip", "dp" or "greedy"
+    pub binning_backend: String,
 
     // Data handling
     /// Number of rows to use for schema inference
     pub infer_schema_length: usize,
+    /// Policy for NaN/±Inf values in numeric features
+    pub non_finite_policy: String,
+    /// Policy for date and datetime features
+    pub date_policy: String,
+    /// Expected schema to compare the loaded columns against
+    pub expect_schema: Option<PathBuf>,
+    /// Fail the run when the loaded schema differs from the expected one
+    pub fail_on_schema_drift: bool,
+    /// Memory budget for the loaded dataset in GB
+    pub max_memory_gb: Option<f64>,
+
+    // Reporting
+    /// Write one Gini analysis file per feature instead of a single JSON
+    pub split_gini_export: bool,
+    /// Write a WoE-encoded copy of the reduced dataset
+    pub export_woe: bool,
+    /// Write the mapped 0/1 target and keep the raw values in `{target}_raw`
+    pub output_include_target_mapping: bool,
+    /// Write the fitted CART prebinning trees
+    pub export_cart_trees: bool,
+    /// Add value histograms to the Gini export
+    pub export_distributions: bool,
+    /// WoE/event-rate charts of the top features by IV (`--plots`)
+    pub plots: Option<PlotSettings>,
+    /// How Parquet outputs are written (`--parquet-compression` etc.)
+    pub parquet: ParquetOptions,
+    /// How CSV input is read and CSV output written (`--csv-separator` etc.)
+    pub csv: CsvDialect,
+    /// Feature descriptions and owners shown next to feature names (`--dictionary`)
+    pub dictionary: Option<PathBuf>,
+    /// Review correlated-pair drops before they are applied
+    pub review_correlations: bool,
+    /// Review every proposed drop before saving
+    pub review_drops: bool,
+    /// Open the results browser after the run
+    pub browse_results: bool,
+    /// Load finished stages from the checkpoint of an interrupted run
+    pub resume: bool,
+    /// Write the reports only, not the reduced dataset
+    pub dry_run: bool,
+    /// Paths of the standalone stage tables
+    pub table_exports: TableExports,
+
+    // Train/test split
+    /// Column marking the train/test split (statistics use training rows only)
+    pub split_column: Option<String>,
+    /// Value of the split column that marks training rows
+    pub train_value: Option<String>,
+    /// Train/validation IV comparison (`--iv-stability`)
+    pub iv_stability: Option<IvStabilityCheck>,
+    /// Column splitting the rows into segments (`--segment-column`)
+    pub segment_column: Option<String>,
+    /// Per-period fill rate and IV drift (`--time-column`)
+    pub time_stability: Option<TimeStabilityCheck>,
+    /// Proxy screen of the protected attributes (`--protected-columns`)
+    pub fairness: Option<FairnessCheck>,
+    /// PCA report of the retained features (`--pca`)
+    pub pca: Option<PcaSettings>,
+    /// Screening steps to run, in order (`--steps`, `--skip-gini`)
+    pub steps: StepPlan,
+
+    /// Class names for reports (`--event-label` / `--non-event-label`)
+    pub event_labels: Option<EventLabels>,
+}
+
+impl Config {
+    /// Whether the pipeline can start: a target is selected, or none is needed
+    fn ready(&self) -> bool {
+        self.target.is_some() || self.no_target
+    }
+}
+
+/// Which column list a multi-select popup edits
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ColumnList {
+    /// Columns removed before analysis
+    Drop,
+    /// Features no step may drop (`--keep-columns`)
+    Keep,
+}
+
+impl ColumnList {
+    fn title(self) -> &'static str {
+        match self {
+            ColumnList::Drop => "Select Columns to Drop",
+            ColumnList::Keep => "Select Columns to Keep",
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            ColumnList::Drop => theme::ERROR,
+            ColumnList::Keep => theme::SUCCESS,
+        }
+    }
 }
 
 /// The current state of the menu
@@ -78,7 +236,8 @@ enum MenuState {
         filtered: Vec<usize>,
         selected: usize,
     },
-    SelectColumnsToDrop {
+    SelectColumns {
+        list: ColumnList,
         search: String,
         columns: Vec<String>,
         filtered: Vec<usize>,
@@ -275,33 +434,27 @@ fn run_mapping_loop(
                     unique_values,
                     selected,
                 } => match key.code {
-                    KeyCode::Enter => {
-                        if !unique_values.is_empty() {
-                            let event_value = unique_values[*selected].clone();
-                            let remaining: Vec<String> = unique_values
-                                .iter()
-                                .filter(|v| *v != &event_value)
-                                .cloned()
-                                .collect();
-                            state = MappingState::SelectNonEvent {
-                                unique_values: remaining,
-                                selected: 0,
-                                event_value,
-                            };
-                        }
+                    KeyCode::Enter if !unique_values.is_empty() => {
+                        let event_value = unique_values[*selected].clone();
+                        let remaining: Vec<String> = unique_values
+                            .iter()
+                            .filter(|v| *v != &event_value)
+                            .cloned()
+                            .collect();
+                        state = MappingState::SelectNonEvent {
+                            unique_values: remaining,
+                            selected: 0,
+                            event_value,
+                        };
                     }
                     KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => {
                         return Ok(TargetMappingResult::Cancelled);
                     }
-                    KeyCode::Up => {
-                        if *selected > 0 {
-                            *selected -= 1;
-                        }
+                    KeyCode::Up if *selected > 0 => {
+                        *selected -= 1;
                     }
-                    KeyCode::Down => {
-                        if *selected + 1 < unique_values.len() {
-                            *selected += 1;
-                        }
+                    KeyCode::Down if *selected + 1 < unique_values.len() => {
+                        *selected += 1;
                     }
                     _ => {}
                 },
@@ -310,14 +463,12 @@ fn run_mapping_loop(
                     selected,
                     event_value,
                 } => match key.code {
-                    KeyCode::Enter => {
-                        if !unique_values.is_empty() {
-                            let non_event_value = unique_values[*selected].clone();
-                            return Ok(TargetMappingResult::Selected(TargetMapping::new(
-                                event_value.clone(),
-                                non_event_value,
-                            )));
-                        }
+                    KeyCode::Enter if !unique_values.is_empty() => {
+                        let non_event_value = unique_values[*selected].clone();
+                        return Ok(TargetMappingResult::Selected(TargetMapping::new(
+                            event_value.clone(),
+                            non_event_value,
+                        )));
                     }
                     KeyCode::Esc => {
                         // Go back to event selection
@@ -329,15 +480,11 @@ fn run_mapping_loop(
                             selected: 0,
                         };
                     }
-                    KeyCode::Up => {
-                        if *selected > 0 {
-                            *selected -= 1;
-                        }
+                    KeyCode::Up if *selected > 0 => {
+                        *selected -= 1;
                     }
-                    KeyCode::Down => {
-                        if *selected + 1 < unique_values.len() {
-                            *selected += 1;
-                        }
+                    KeyCode::Down if *selected + 1 < unique_values.len() => {
+                        *selected += 1;
                     }
                     _ => {}
                 },
@@ -637,13 +784,11 @@ fn run_menu_loop(
 
                 match &mut state {
                     MenuState::Main => match key.code {
-                        KeyCode::Enter => {
-                            // Only proceed if target is selected
-                            if config.target.is_some() {
-                                return Ok(ConfigResult::Proceed(Box::new(config)));
-                            }
+                        KeyCode::Enter if config.ready() => {
+                            // Only proceed if target is selected or not needed
+                            return Ok(ConfigResult::Proceed(Box::new(config)));
                         }
-                        KeyCode::Char('t') | KeyCode::Char('T') => {
+                        KeyCode::Char('t') | KeyCode::Char('T') if !config.no_target => {
                             let filtered: Vec<usize> = (0..columns.len()).collect();
                             state = MenuState::SelectTarget {
                                 search: String::new(),
@@ -653,21 +798,18 @@ fn run_menu_loop(
                             };
                         }
                         KeyCode::Char('d') | KeyCode::Char('D') => {
-                            let filtered: Vec<usize> = (0..columns.len()).collect();
-                            // Pre-check columns that are already marked for dropping
-                            let checked: HashSet<usize> = columns
-                                .iter()
-                                .enumerate()
-                                .filter(|(_, col)| config.columns_to_drop.contains(col))
-                                .map(|(i, _)| i)
-                                .collect();
-                            state = MenuState::SelectColumnsToDrop {
-                                search: String::new(),
-                                columns: columns.clone(),
-                                filtered,
-                                selected: 0,
-                                checked,
-                            };
+                            state = column_list_selector(
+                                ColumnList::Drop,
+                                &columns,
+                                &config.columns_to_drop,
+                            );
+                        }
+                        KeyCode::Char('p') | KeyCode::Char('P') => {
+                            state = column_list_selector(
+                                ColumnList::Keep,
+                                &columns,
+                                &config.keep_columns,
+                            );
                         }
                         KeyCode::Char('c') | KeyCode::Char('C') => {
                             state = MenuState::EditMissing {
@@ -734,15 +876,11 @@ fn run_menu_loop(
                         KeyCode::Esc => {
                             state = MenuState::Main;
                         }
-                        KeyCode::Up => {
-                            if *selected > 0 {
-                                *selected -= 1;
-                            }
+                        KeyCode::Up if *selected > 0 => {
+                            *selected -= 1;
                         }
-                        KeyCode::Down => {
-                            if *selected + 1 < filtered.len() {
-                                *selected += 1;
-                            }
+                        KeyCode::Down if *selected + 1 < filtered.len() => {
+                            *selected += 1;
                         }
                         KeyCode::Backspace => {
                             search.pop();
@@ -756,7 +894,8 @@ fn run_menu_loop(
                         }
                         _ => {}
                     },
-                    MenuState::SelectColumnsToDrop {
+                    MenuState::SelectColumns {
+                        list,
                         search,
                         columns,
                         filtered,
@@ -765,8 +904,11 @@ fn run_menu_loop(
                     } => match key.code {
                         KeyCode::Enter => {
                             // Confirm selection - convert checked indices to column names
-                            config.columns_to_drop =
-                                checked.iter().map(|&idx| columns[idx].clone()).collect();
+                            let names = checked.iter().map(|&idx| columns[idx].clone()).collect();
+                            match list {
+                                ColumnList::Drop => config.columns_to_drop = names,
+                                ColumnList::Keep => config.keep_columns = names,
+                            }
                             state = MenuState::Main;
                         }
                         KeyCode::Esc => {
@@ -774,25 +916,19 @@ fn run_menu_loop(
                             state = MenuState::Main;
                         }
                         KeyCode::Char(' ') => {
-                            // Toggle selection of current item
-                            if !filtered.is_empty() {
-                                let idx = filtered[*selected];
-                                if checked.contains(&idx) {
-                                    checked.remove(&idx);
-                                } else {
+                            // Toggle selection of current item; a space never
+                            // reaches the search, even with nothing listed
+                            if let Some(&idx) = filtered.get(*selected) {
+                                if !checked.remove(&idx) {
                                     checked.insert(idx);
                                 }
                             }
                         }
-                        KeyCode::Up => {
-                            if *selected > 0 {
-                                *selected -= 1;
-                            }
+                        KeyCode::Up if *selected > 0 => {
+                            *selected -= 1;
                         }
-                        KeyCode::Down => {
-                            if *selected + 1 < filtered.len() {
-                                *selected += 1;
-                            }
+                        KeyCode::Down if *selected + 1 < filtered.len() => {
+                            *selected += 1;
                         }
                         KeyCode::Backspace => {
                             search.pop();
@@ -810,36 +946,30 @@ fn run_menu_loop(
                         unique_values,
                         selected,
                     } => match key.code {
-                        KeyCode::Enter => {
-                            if !unique_values.is_empty() {
-                                let event_value = unique_values[*selected].clone();
-                                // Move to non-event selection, excluding the chosen event value
-                                let remaining: Vec<String> = unique_values
-                                    .iter()
-                                    .filter(|v| *v != &event_value)
-                                    .cloned()
-                                    .collect();
-                                state = MenuState::SelectNonEventValue {
-                                    unique_values: remaining,
-                                    selected: 0,
-                                    event_value,
-                                };
-                            }
+                        KeyCode::Enter if !unique_values.is_empty() => {
+                            let event_value = unique_values[*selected].clone();
+                            // Move to non-event selection, excluding the chosen event value
+                            let remaining: Vec<String> = unique_values
+                                .iter()
+                                .filter(|v| *v != &event_value)
+                                .cloned()
+                                .collect();
+                            state = MenuState::SelectNonEventValue {
+                                unique_values: remaining,
+                                selected: 0,
+                                event_value,
+                            };
                         }
                         KeyCode::Esc => {
                             // Cancel - clear target mapping and go back to main
                             config.target_mapping = None;
                             state = MenuState::Main;
                         }
-                        KeyCode::Up => {
-                            if *selected > 0 {
-                                *selected -= 1;
-                            }
+                        KeyCode::Up if *selected > 0 => {
+                            *selected -= 1;
                         }
-                        KeyCode::Down => {
-                            if *selected + 1 < unique_values.len() {
-                                *selected += 1;
-                            }
+                        KeyCode::Down if *selected + 1 < unique_values.len() => {
+                            *selected += 1;
                         }
                         _ => {}
                     },
@@ -848,14 +978,12 @@ fn run_menu_loop(
                         selected,
                         event_value,
                     } => match key.code {
-                        KeyCode::Enter => {
-                            if !unique_values.is_empty() {
-                                let non_event_value = unique_values[*selected].clone();
-                                // Create the target mapping
-                                config.target_mapping =
-                                    Some(TargetMapping::new(event_value.clone(), non_event_value));
-                                state = MenuState::Main;
-                            }
+                        KeyCode::Enter if !unique_values.is_empty() => {
+                            let non_event_value = unique_values[*selected].clone();
+                            // Create the target mapping
+                            config.target_mapping =
+                                Some(TargetMapping::new(event_value.clone(), non_event_value));
+                            state = MenuState::Main;
                         }
                         KeyCode::Esc => {
                             // Go back to event selection
@@ -867,15 +995,11 @@ fn run_menu_loop(
                                 selected: 0,
                             };
                         }
-                        KeyCode::Up => {
-                            if *selected > 0 {
-                                *selected -= 1;
-                            }
+                        KeyCode::Up if *selected > 0 => {
+                            *selected -= 1;
                         }
-                        KeyCode::Down => {
-                            if *selected + 1 < unique_values.len() {
-                                *selected += 1;
-                            }
+                        KeyCode::Down if *selected + 1 < unique_values.len() => {
+                            *selected += 1;
                         }
                         _ => {}
                     },
@@ -1004,15 +1128,11 @@ fn run_menu_loop(
                             };
                             state = MenuState::Main;
                         }
-                        KeyCode::Up => {
-                            if *selected > 0 {
-                                *selected -= 1;
-                            }
+                        KeyCode::Up if *selected > 0 => {
+                            *selected -= 1;
                         }
-                        KeyCode::Down => {
-                            if *selected < 5 {
-                                *selected += 1;
-                            }
+                        KeyCode::Down if *selected < 5 => {
+                            *selected += 1;
                         }
                         KeyCode::Esc => {
                             state = MenuState::Main;
@@ -1040,15 +1160,11 @@ fn run_menu_loop(
                         KeyCode::Esc => {
                             state = MenuState::Main;
                         }
-                        KeyCode::Up => {
-                            if *selected > 0 {
-                                *selected -= 1;
-                            }
+                        KeyCode::Up if *selected > 0 => {
+                            *selected -= 1;
                         }
-                        KeyCode::Down => {
-                            if *selected < filtered.len() {
-                                *selected += 1;
-                            }
+                        KeyCode::Down if *selected < filtered.len() => {
+                            *selected += 1;
                         }
                         KeyCode::Backspace => {
                             search.pop();
@@ -1244,14 +1360,15 @@ fn draw_ui(
         } => {
             draw_target_selector(frame, search, columns, filtered, *selected);
         }
-        MenuState::SelectColumnsToDrop {
+        MenuState::SelectColumns {
+            list,
             search,
             columns,
             filtered,
             selected,
             checked,
         } => {
-            draw_columns_to_drop_selector(frame, search, columns, filtered, *selected, checked);
+            draw_column_list_selector(frame, *list, search, columns, filtered, *selected, checked);
         }
         MenuState::SelectEventValue {
             unique_values,
@@ -1328,11 +1445,12 @@ fn build_content(
     ]));
 
     // Target with highlighting if not selected
-    let target_display = config
-        .target
-        .clone()
-        .unwrap_or_else(|| "⚠ Not selected".to_string());
-    let target_style = if config.target.is_some() {
+    let target_display = match &config.target {
+        Some(target) => target.clone(),
+        None if config.no_target => "None (--no-target)".to_string(),
+        None => "⚠ Not selected".to_string(),
+    };
+    let target_style = if config.ready() {
         Style::default().fg(theme::TEXT)
     } else {
         Style::default().fg(theme::WARNING).bold()
@@ -1426,6 +1544,11 @@ fn build_content(
     } else {
         Style::default().fg(theme::ERROR)
     };
+    let keep_style = if config.keep_columns.is_empty() {
+        Style::default().fg(theme::MUTED)
+    } else {
+        Style::default().fg(theme::SUCCESS)
+    };
     let weight_style = match state {
         MenuState::SelectWeightColumn { .. } => Style::default().fg(theme::WARNING).bold(),
         _ => Style::default().fg(theme::SUCCESS),
@@ -1441,6 +1564,11 @@ fn build_content(
     } else {
         format!("{} col", config.columns_to_drop.len())
     };
+    let keep_display = if config.keep_columns.is_empty() {
+        "None".to_string()
+    } else {
+        format!("{} col", config.keep_columns.len())
+    };
     let weight_display = config
         .weight_column
         .clone()
@@ -1482,14 +1610,15 @@ fn build_content(
         Span::styled(weight_display, weight_style),
     ]));
 
-    // Row 3: Correlation | (empty) | Schema
+    // Row 3: Correlation | Keep | Schema
     let corr_val = format!("{:.2}", config.correlation_threshold);
     lines.push(Line::from(vec![
         Span::styled("  Correlation:", Style::default().fg(theme::MUTED)),
         Span::styled(corr_val, corr_style),
         Span::styled("  ", Style::default()),
         Span::styled("│ ", Style::default().fg(theme::DIVIDER)),
-        Span::styled("                ", Style::default()),
+        Span::styled("Keep:   ", Style::default().fg(theme::MUTED)),
+        Span::styled(format!("{:<8}", keep_display), keep_style),
         Span::styled("│ ", Style::default().fg(theme::DIVIDER)),
         Span::styled("Schema: ", Style::default().fg(theme::MUTED)),
         Span::styled(schema_display, schema_style),
@@ -1506,7 +1635,7 @@ fn build_content(
 
     if in_main {
         // Full shortcut list shown only on Main screen
-        let enter_style = if config.target.is_some() {
+        let enter_style = if config.ready() {
             themed(Style::default().fg(theme::KEYS).bold())
         } else {
             // No target yet: dim Enter to signal it won't work
@@ -1517,7 +1646,7 @@ fn build_content(
             Span::styled("Enter", enter_style),
             Span::styled(
                 "] Run with these settings",
-                if config.target.is_some() {
+                if config.ready() {
                     Style::default().fg(theme::TEXT)
                 } else {
                     Style::default().fg(theme::MUTED)
@@ -1526,7 +1655,14 @@ fn build_content(
         ]));
         lines.push(Line::from(vec![
             Span::styled("  [", Style::default().fg(theme::MUTED)),
-            Span::styled("T", themed(Style::default().fg(theme::KEYS).bold())),
+            Span::styled(
+                "T",
+                if config.no_target {
+                    Style::default().fg(theme::MUTED)
+                } else {
+                    themed(Style::default().fg(theme::KEYS).bold())
+                },
+            ),
             Span::styled("] Select target ", Style::default().fg(theme::TEXT)),
             Span::styled("[", Style::default().fg(theme::MUTED)),
             Span::styled("F", themed(Style::default().fg(theme::KEYS).bold())),
@@ -1538,7 +1674,10 @@ fn build_content(
             Span::styled("] Drop columns  ", Style::default().fg(theme::TEXT)),
             Span::styled("[", Style::default().fg(theme::MUTED)),
             Span::styled("C", themed(Style::default().fg(theme::KEYS).bold())),
-            Span::styled("] Thresholds", Style::default().fg(theme::TEXT)),
+            Span::styled("] Thresholds  ", Style::default().fg(theme::TEXT)),
+            Span::styled("[", Style::default().fg(theme::MUTED)),
+            Span::styled("P", themed(Style::default().fg(theme::KEYS).bold())),
+            Span::styled("] Keep columns", Style::default().fg(theme::TEXT)),
         ]));
         lines.push(Line::from(vec![
             Span::styled("  [", Style::default().fg(theme::MUTED)),
@@ -1566,13 +1705,13 @@ fn build_content(
             MenuState::EditUseSolver { .. } => ("Space", " toggle  "),
             MenuState::SelectMonotonicity { .. }
             | MenuState::SelectTarget { .. }
-            | MenuState::SelectColumnsToDrop { .. }
+            | MenuState::SelectColumns { .. }
             | MenuState::SelectWeightColumn { .. }
             | MenuState::SelectEventValue { .. }
             | MenuState::SelectNonEventValue { .. } => ("Enter", " select  "),
             MenuState::Main => ("Enter", " run  "),
         };
-        let has_space_toggle = matches!(state, MenuState::SelectColumnsToDrop { .. });
+        let has_space_toggle = matches!(state, MenuState::SelectColumns { .. });
         let mut row = vec![
             Span::styled("  ", Style::default()),
             Span::styled(confirm_label, themed(Style::default().fg(theme::KEYS))),
@@ -1702,8 +1841,27 @@ fn draw_target_selector(
     }
 }
 
-fn draw_columns_to_drop_selector(
+/// Multi-select popup over `columns` with the entries of `current` pre-checked
+fn column_list_selector(list: ColumnList, columns: &[String], current: &[String]) -> MenuState {
+    let checked: HashSet<usize> = columns
+        .iter()
+        .enumerate()
+        .filter(|(_, col)| current.contains(col))
+        .map(|(i, _)| i)
+        .collect();
+    MenuState::SelectColumns {
+        list,
+        search: String::new(),
+        columns: columns.to_vec(),
+        filtered: (0..columns.len()).collect(),
+        selected: 0,
+        checked,
+    }
+}
+
+fn draw_column_list_selector(
     frame: &mut Frame,
+    list: ColumnList,
     search: &str,
     columns: &[String],
     filtered: &[usize],
@@ -1726,12 +1884,13 @@ fn draw_columns_to_drop_selector(
 
     frame.render_widget(Clear, popup_area);
 
-    let title = format!(" Select Columns to Drop ({} selected) ", checked.len());
+    let color = list.color();
+    let title = format!(" {} ({} selected) ", list.title(), checked.len());
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(theme::ERROR))
+        .border_style(Style::default().fg(color))
         .title(title)
-        .title_style(Style::default().fg(theme::ERROR).bold());
+        .title_style(Style::default().fg(color).bold());
 
     let inner = block.inner(popup_area);
     frame.render_widget(block, popup_area);
@@ -1756,7 +1915,7 @@ fn draw_columns_to_drop_selector(
     let search_text = search.to_string();
     let search_para = Paragraph::new(Line::from(vec![
         Span::styled(search_text, Style::default().fg(theme::TEXT)),
-        Span::styled("▌", Style::default().fg(theme::ERROR)),
+        Span::styled("▌", Style::default().fg(color)),
     ]))
     .block(search_block);
 
@@ -1781,9 +1940,9 @@ fn draw_columns_to_drop_selector(
             let checkbox = if is_checked { "[x]" } else { "[ ]" };
 
             let style = if i == selected {
-                Style::default().fg(theme::BASE).bg(theme::ERROR).bold()
+                Style::default().fg(theme::BASE).bg(color).bold()
             } else if is_checked {
-                Style::default().fg(theme::ERROR)
+                Style::default().fg(color)
             } else {
                 Style::default().fg(theme::TEXT)
             };
@@ -2374,6 +2533,87 @@ fn draw_non_event_value_selector(
     }
 }
 
+// ============================================================================
+// Sheet Selector TUI
+// ============================================================================
+
+/// Result of the worksheet selector interaction
+pub enum SheetSelectResult {
+    /// User picked a worksheet
+    Selected(String),
+    /// User cancelled
+    Cancelled,
+}
+
+/// Run the worksheet picker for an Excel workbook with several sheets as a
+/// standalone TUI
+pub fn run_sheet_selector(sheets: Vec<String>) -> Result<SheetSelectResult> {
+    // Install panic hook for clean terminal restoration
+    let original_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        teardown_terminal_menu();
+        original_hook(info);
+    }));
+
+    enable_raw_mode()?;
+    if let Err(e) = stdout().execute(EnterAlternateScreen) {
+        teardown_terminal_menu();
+        return Err(e.into());
+    }
+    let mut terminal = match Terminal::new(CrosstermBackend::new(stdout())) {
+        Ok(t) => t,
+        Err(e) => {
+            teardown_terminal_menu();
+            return Err(e.into());
+        }
+    };
+
+    let result = run_sheet_selector_loop(&mut terminal, &sheets);
+
+    teardown_terminal_menu();
+
+    result
+}
+
+fn run_sheet_selector_loop(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    sheets: &[String],
+) -> Result<SheetSelectResult> {
+    let options: Vec<&str> = sheets.iter().map(String::as_str).collect();
+    let mut selected = 0;
+
+    loop {
+        terminal.draw(|frame| {
+            draw_selection_popup(
+                frame,
+                " Select Worksheet ",
+                &options,
+                selected,
+                theme::PRIMARY,
+            );
+        })?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Enter => return Ok(SheetSelectResult::Selected(sheets[selected].clone())),
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => {
+                    return Ok(SheetSelectResult::Cancelled);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    selected = selected.saturating_sub(1);
+                }
+                KeyCode::Down | KeyCode::Char('j') if selected + 1 < sheets.len() => {
+                    selected += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
 // ============================================================================
 // File Selector TUI
 // ============================================================================
@@ -2393,6 +2633,16 @@ struct FileEntry {
     is_dir: bool,
 }
 
+/// Outcome of reading the filter text as a path
+enum PathJump {
+    /// Not an absolute path to something that exists
+    NotAPath,
+    /// Moved to the directory (or the directory of an unsupported file)
+    Opened,
+    /// An existing data file, ready to return
+    Selected(PathBuf),
+}
+
 /// State for the file selector
 struct FileSelectorState {
     current_dir: PathBuf,
@@ -2427,6 +2677,24 @@ impl FileSelectorState {
         self.refresh();
     }
 
+    /// Follow the filter text when it holds a pasted or dragged-in path
+    fn jump_to_typed_path(&mut self) -> PathJump {
+        let Some(path) = parse_dropped_path(&self.search) else {
+            return PathJump::NotAPath;
+        };
+        if path.is_dir() {
+            self.navigate_to(path);
+            PathJump::Opened
+        } else if path.is_file() && crate::pipeline::is_dataset_path(&path) {
+            PathJump::Selected(path)
+        } else if let Some(parent) = path.parent().filter(|p| path.is_file() && p.is_dir()) {
+            self.navigate_to(parent.to_path_buf());
+            PathJump::Opened
+        } else {
+            PathJump::NotAPath
+        }
+    }
+
     fn update_filter(&mut self) {
         let search_lower = self.search.to_lowercase();
         self.filtered = self
@@ -2466,9 +2734,13 @@ pub fn run_file_selector() -> Result<FileSelectResult> {
         }
     };
 
+    // Lets a dragged-in path arrive as one paste event where the terminal supports it
+    let _ = stdout().execute(EnableBracketedPaste);
+
     let result = run_file_selector_loop(&mut terminal, start_dir);
 
     // Restore terminal
+    let _ = stdout().execute(DisableBracketedPaste);
     teardown_terminal_menu();
 
     result
@@ -2485,82 +2757,170 @@ fn run_file_selector_loop(
             draw_file_selector(frame, &state);
         })?;
 
-        if let Event::Key(key) = event::read()? {
-            if key.kind != KeyEventKind::Press {
+        let key = match event::read()? {
+            Event::Key(key) => key,
+            Event::Paste(text) => {
+                state.search.push_str(text.trim_end_matches(['\r', '\n']));
+                match state.jump_to_typed_path() {
+                    PathJump::Selected(path) => return Ok(FileSelectResult::Selected(path)),
+                    PathJump::Opened => {}
+                    PathJump::NotAPath => state.update_filter(),
+                }
                 continue;
             }
+            _ => continue,
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
 
-            match key.code {
-                KeyCode::Enter => {
-                    if !state.filtered.is_empty() {
-                        let idx = state.filtered[state.selected];
-                        let entry = &state.entries[idx];
-                        if entry.is_dir {
-                            // Navigate into directory
-                            state.navigate_to(entry.path.clone());
-                        } else {
-                            // Select file
-                            return Ok(FileSelectResult::Selected(entry.path.clone()));
-                        }
-                    }
+        match key.code {
+            KeyCode::Enter => {
+                // A path typed or dropped without bracketed paste (e.g. Windows consoles)
+                match state.jump_to_typed_path() {
+                    PathJump::Selected(path) => return Ok(FileSelectResult::Selected(path)),
+                    PathJump::Opened => continue,
+                    PathJump::NotAPath => {}
                 }
-                KeyCode::Backspace => {
-                    if state.search.is_empty() {
-                        // Navigate to parent directory
-                        if let Some(parent) = state.current_dir.parent() {
-                            state.navigate_to(parent.to_path_buf());
-                        }
-                    } else {
-                        // Remove last character from search
-                        state.search.pop();
-                        state.update_filter();
-                    }
-                }
-                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => {
-                    // Only quit if search is empty, otherwise clear search
-                    if state.search.is_empty() {
-                        return Ok(FileSelectResult::Cancelled);
+                if !state.filtered.is_empty() {
+                    let idx = state.filtered[state.selected];
+                    let entry = &state.entries[idx];
+                    if entry.is_dir {
+                        // Navigate into directory
+                        state.navigate_to(entry.path.clone());
                     } else {
-                        state.search.clear();
-                        state.update_filter();
-                    }
-                }
-                KeyCode::Up | KeyCode::Char('k') => {
-                    if state.selected > 0 {
-                        state.selected -= 1;
+                        // Select file
+                        return Ok(FileSelectResult::Selected(entry.path.clone()));
                     }
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    if state.selected + 1 < state.filtered.len() {
-                        state.selected += 1;
+            }
+            KeyCode::Backspace => {
+                if state.search.is_empty() {
+                    // Navigate to parent directory
+                    if let Some(parent) = state.current_dir.parent() {
+                        state.navigate_to(parent.to_path_buf());
                     }
+                } else {
+                    // Remove last character from search
+                    state.search.pop();
+                    state.update_filter();
                 }
-                KeyCode::PageUp => {
-                    state.selected = state.selected.saturating_sub(10);
-                }
-                KeyCode::PageDown => {
-                    state.selected =
-                        (state.selected + 10).min(state.filtered.len().saturating_sub(1));
-                }
-                KeyCode::Home => {
-                    state.selected = 0;
-                }
-                KeyCode::End => {
-                    state.selected = state.filtered.len().saturating_sub(1);
-                }
-                KeyCode::Char(c) if !c.is_control() => {
-                    // Add to search filter (but not for j/k when not in search mode)
-                    if c != 'j' && c != 'k' || !state.search.is_empty() {
-                        state.search.push(c);
-                        state.update_filter();
-                    }
+            }
+            KeyCode::Esc => {
+                // Only quit if search is empty, otherwise clear search
+                if state.search.is_empty() {
+                    return Ok(FileSelectResult::Cancelled);
+                } else {
+                    state.search.clear();
+                    state.update_filter();
                 }
-                _ => {}
             }
+            // Letters act as keys only before a search starts, so typed paths keep them
+            KeyCode::Char('q') | KeyCode::Char('Q') if state.search.is_empty() => {
+                return Ok(FileSelectResult::Cancelled);
+            }
+            KeyCode::Up if state.selected > 0 => {
+                state.selected -= 1;
+            }
+            KeyCode::Char('k') if state.search.is_empty() => {
+                state.selected = state.selected.saturating_sub(1);
+            }
+            KeyCode::Down if state.selected + 1 < state.filtered.len() => {
+                state.selected += 1;
+            }
+            KeyCode::Char('j') if state.search.is_empty() => {
+                state.selected = (state.selected + 1).min(state.filtered.len().saturating_sub(1));
+            }
+            KeyCode::PageUp => {
+                state.selected = state.selected.saturating_sub(10);
+            }
+            KeyCode::PageDown => {
+                state.selected = (state.selected + 10).min(state.filtered.len().saturating_sub(1));
+            }
+            KeyCode::Home => {
+                state.selected = 0;
+            }
+            KeyCode::End => {
+                state.selected = state.filtered.len().saturating_sub(1);
+            }
+            KeyCode::Char(c) if !c.is_control() => {
+                state.search.push(c);
+                state.update_filter();
+            }
+            _ => {}
         }
     }
 }
 
+/// Read filter text as a path dropped or pasted into the terminal.
+///
+/// Terminals deliver a dropped file as text. Windows Terminal wraps it in
+/// double quotes, PowerShell in single quotes with a leading `& `, macOS and
+/// Linux terminals quote it or backslash-escape spaces, and some send a
+/// `file://` URL. Returns the path only when it is absolute, so ordinary
+/// filter text never matches.
+fn parse_dropped_path(input: &str) -> Option<PathBuf> {
+    let mut text = input.trim();
+    if let Some(rest) = text.strip_prefix("& ") {
+        text = rest.trim_start();
+    }
+    for quote in ['"', '\''] {
+        if text.len() >= 2 && text.starts_with(quote) && text.ends_with(quote) {
+            text = &text[1..text.len() - 1];
+            break;
+        }
+    }
+
+    let path = if let Some(url_path) = text.strip_prefix("file://") {
+        let decoded = percent_decode(url_path);
+        // file:///C:/data.csv on Windows
+        match decoded.strip_prefix('/') {
+            Some(rest) if cfg!(windows) && rest.get(1..2) == Some(":") => rest.to_string(),
+            _ => decoded,
+        }
+    } else if cfg!(windows) {
+        // Backslash is the separator, never an escape
+        text.to_string()
+    } else {
+        let mut unescaped = String::with_capacity(text.len());
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => unescaped.extend(chars.next()),
+                c => unescaped.push(c),
+            }
+        }
+        unescaped
+    };
+
+    let path = PathBuf::from(path);
+    path.is_absolute().then_some(path)
+}
+
+/// Decode `%XX` escapes in a `file://` URL path
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
 /// List directory contents, filtered for CSV/Parquet files and directories
 fn list_directory(path: &std::path::Path) -> Vec<FileEntry> {
     let mut entries = Vec::new();
@@ -2589,7 +2949,7 @@ fn list_directory(path: &std::path::Path) -> Vec<FileEntry> {
             }
 
             // Filter: directories or CSV/Parquet files
-            if is_dir || is_valid_data_file(&entry_path) {
+            if is_dir || crate::pipeline::is_dataset_path(&entry_path) {
                 entries.push(FileEntry {
                     name,
                     path: entry_path,
@@ -2619,18 +2979,6 @@ fn list_directory(path: &std::path::Path) -> Vec<FileEntry> {
     entries
 }
 
-/// Check if a file is a valid data file (CSV, Parquet, or SAS7BDAT)
-fn is_valid_data_file(path: &std::path::Path) -> bool {
-    path.extension()
-        .and_then(|e| e.to_str())
-        .map(|e| {
-            e.eq_ignore_ascii_case("csv")
-                || e.eq_ignore_ascii_case("parquet")
-                || e.eq_ignore_ascii_case("sas7bdat")
-        })
-        .unwrap_or(false)
-}
-
 /// Draw the file selector UI
 fn draw_file_selector(frame: &mut Frame, state: &FileSelectorState) {
     let area = frame.area();
@@ -2702,7 +3050,10 @@ fn draw_file_selector(frame: &mut Frame, state: &FileSelectorState) {
 
     let search_content = if state.search.is_empty() {
         Line::from(vec![
-            Span::styled("Type to filter...", Style::default().fg(theme::MUTED)),
+            Span::styled(
+                "Type to filter, or paste/drop a path...",
+                Style::default().fg(theme::MUTED),
+            ),
             Span::styled("▌", Style::default().fg(theme::PRIMARY)),
         ])
     } else {
@@ -2806,3 +3157,88 @@ fn draw_file_selector(frame: &mut Frame, state: &FileSelectorState) {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_filter_text_is_not_a_path() {
+        assert!(parse_dropped_path("data").is_none());
+        assert!(parse_dropped_path("data/train.csv").is_none());
+        assert!(parse_dropped_path("").is_none());
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("/my%20data/a.csv"), "/my data/a.csv");
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz"), "%zz");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_dropped_unix_paths() {
+        let expected = Some(PathBuf::from("/home/ana/my data.csv"));
+        assert_eq!(parse_dropped_path("'/home/ana/my data.csv'"), expected);
+        assert_eq!(parse_dropped_path("\"/home/ana/my data.csv\""), expected);
+        assert_eq!(parse_dropped_path("/home/ana/my\\ data.csv "), expected);
+        assert_eq!(
+            parse_dropped_path("file:///home/ana/my%20data.csv"),
+            expected
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_dropped_windows_paths() {
+        let expected = Some(PathBuf::from(r"C:\Users\ana\my data.csv"));
+        assert_eq!(
+            parse_dropped_path(r#""C:\Users\ana\my data.csv""#),
+            expected
+        );
+        assert_eq!(
+            parse_dropped_path(r"& 'C:\Users\ana\my data.csv'"),
+            expected
+        );
+        assert_eq!(
+            parse_dropped_path("file:///C:/Users/ana/my%20data.csv"),
+            Some(PathBuf::from("C:/Users/ana/my data.csv"))
+        );
+    }
+
+    #[test]
+    fn test_jump_to_typed_path() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let sub = dir.path().join("extracts");
+        std::fs::create_dir(&sub).unwrap();
+        let data = sub.join("train.csv");
+        std::fs::write(&data, "a\n1\n").unwrap();
+        let notes = sub.join("notes.txt");
+        std::fs::write(&notes, "").unwrap();
+
+        let mut state = FileSelectorState::new(dir.path().to_path_buf());
+
+        state.search = format!("\"{}\"", sub.display());
+        assert!(matches!(state.jump_to_typed_path(), PathJump::Opened));
+        assert_eq!(state.current_dir, sub);
+        assert!(state.search.is_empty());
+
+        state.search = format!("\"{}\"", data.display());
+        match state.jump_to_typed_path() {
+            PathJump::Selected(path) => assert_eq!(path, data),
+            _ => panic!("expected the data file to be selected"),
+        }
+
+        // Unsupported files open their directory
+        state.navigate_to(dir.path().to_path_buf());
+        state.search = notes.display().to_string();
+        assert!(matches!(state.jump_to_typed_path(), PathJump::Opened));
+        assert_eq!(state.current_dir, sub);
+
+        state.search = dir.path().join("missing.csv").display().to_string();
+        assert!(matches!(state.jump_to_typed_path(), PathJump::NotAPath));
+        state.search = "train".to_string();
+        assert!(matches!(state.jump_to_typed_path(), PathJump::NotAPath));
+    }
+}
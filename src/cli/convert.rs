@@ -1,4 +1,6 @@
-//! Bidirectional conversion utility between CSV, Parquet, and SAS7BDAT formats
+//! Bidirectional conversion utility between CSV, Parquet, Arrow IPC (Feather),
+//! and SAS7BDAT formats, plus JSON Lines, Excel, SPSS and Stata input and SAS
+//! transport (XPT) output
 
 use std::path::Path;
 use std::time::Instant;
@@ -6,9 +8,22 @@ use std::time::Instant;
 use anyhow::{Context, Result};
 use chrono::Local;
 use console::style;
+use indicatif::{ProgressBar, ProgressStyle};
 use polars::prelude::*;
 
-use crate::pipeline::sas7bdat::load_sas7bdat;
+#[cfg(feature = "xlsx")]
+use crate::pipeline::excel::load_excel;
+#[cfg(feature = "sas7bdat")]
+use crate::pipeline::sas7bdat::{load_sas7bdat, Sas7bdatReader, DEFAULT_BATCH_ROWS};
+#[cfg(feature = "sav")]
+use crate::pipeline::spss::load_sav;
+#[cfg(feature = "dta")]
+use crate::pipeline::stata::load_dta;
+use crate::pipeline::xpt::{write_xpt, XptVersion};
+use crate::pipeline::{
+    dataset_stem, is_compressed_csv, load_dataset_columns, scan_ndjson, ChunkedOutput, CsvChunker,
+    CsvDialect, ParquetOptions, DEFAULT_CHUNK_BYTES,
+};
 use crate::utils::create_spinner;
 
 /// Get current timestamp as HH:MM:SS
@@ -30,25 +45,169 @@ fn format_duration(duration: std::time::Duration) -> String {
     }
 }
 
+/// Format of a conversion's output file, from its extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Csv,
+    Parquet,
+    /// Arrow IPC / Feather v2 (`.feather`, `.arrow`, `.ipc`)
+    Ipc,
+    /// SAS transport file (`.xpt`), version 5 or 8 depending on the data
+    Xpt,
+}
+
+impl OutputFormat {
+    fn from_path(path: &Path) -> Result<Self> {
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        match ext.as_str() {
+            "csv" => Ok(OutputFormat::Csv),
+            "parquet" => Ok(OutputFormat::Parquet),
+            "feather" | "arrow" | "ipc" => Ok(OutputFormat::Ipc),
+            "xpt" => Ok(OutputFormat::Xpt),
+            _ => anyhow::bail!(
+                "Unsupported output format: .{}. Supported: .csv, .parquet, .feather, .xpt",
+                ext
+            ),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            OutputFormat::Csv => "CSV",
+            OutputFormat::Parquet => "Parquet",
+            OutputFormat::Ipc => "Feather",
+            OutputFormat::Xpt => "XPT",
+        }
+    }
+}
+
+/// Input formats that are converted in memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InMemoryInput {
+    Parquet,
+    Ipc,
+    /// JSON Lines (`.jsonl`, `.ndjson`), one object per line
+    JsonLines,
+    /// One worksheet of an Excel workbook (`.xlsx`, `.xlsm`, `.xls`)
+    Excel,
+    /// SPSS system file (`.sav`)
+    Spss,
+    /// Stata dataset (`.dta`)
+    Stata,
+    /// Gzip / Zstandard compressed or zipped CSV, decompressed while loading
+    CompressedCsv,
+}
+
+impl InMemoryInput {
+    fn name(self) -> &'static str {
+        match self {
+            InMemoryInput::Parquet => "Parquet",
+            InMemoryInput::Ipc => "Feather",
+            InMemoryInput::JsonLines => "JSON Lines",
+            InMemoryInput::Excel => "Excel",
+            InMemoryInput::Spss => "SPSS",
+            InMemoryInput::Stata => "Stata",
+            InMemoryInput::CompressedCsv => "compressed CSV",
+        }
+    }
+
+    /// Output extension when no output path is given
+    fn default_output_extension(self) -> &'static str {
+        match self {
+            InMemoryInput::Parquet => "csv",
+            InMemoryInput::Ipc
+            | InMemoryInput::JsonLines
+            | InMemoryInput::Excel
+            | InMemoryInput::Spss
+            | InMemoryInput::Stata
+            | InMemoryInput::CompressedCsv => "parquet",
+        }
+    }
+}
+
+/// Write `df` to `path` in `format`
+fn write_output(
+    df: &mut DataFrame,
+    path: &Path,
+    format: OutputFormat,
+    parquet: &ParquetOptions,
+    csv: &CsvDialect,
+) -> Result<()> {
+    if format == OutputFormat::Xpt {
+        return write_xpt(df, path, XptVersion::for_dataframe(df));
+    }
+    let file = std::fs::File::create(path)
+        .with_context(|| format!("Failed to create output file: {}", path.display()))?;
+    match format {
+        OutputFormat::Csv => csv.write(df, file),
+        OutputFormat::Parquet => parquet.writer(file).finish(df).map(|_| ()),
+        OutputFormat::Ipc => IpcWriter::new(file).finish(df),
+        OutputFormat::Xpt => unreachable!("XPT is written above"),
+    }
+    .with_context(|| format!("Failed to write {} file: {}", format.name(), path.display()))
+}
+
 /// Run file format conversion
 ///
 /// # Arguments
-/// * `input` - Path to the input file (CSV, Parquet, or SAS7BDAT)
-/// * `output` - Optional output path. If not provided, auto-generates based on input format
-/// * `infer_schema_length` - Number of rows to use for schema inference (CSV only)
+/// * `input` - Path to the input file (CSV, Parquet, Arrow IPC / Feather, JSON Lines,
+///   SAS7BDAT, Excel, SPSS or Stata)
+/// * `output` - Optional output path. If not provided, auto-generates based on input format.
+///   The output format follows its extension.
+/// * `infer_schema_length` - Number of rows to use for schema inference (CSV and JSON Lines)
+/// * `sheet` - Worksheet of an Excel input (first sheet when `None`); ignored otherwise
 /// * `fast` - If true, uses in-memory conversion (more RAM, all CPU cores).
 ///   If false, uses streaming conversion (low RAM, single-threaded).
-///   Note: SAS7BDAT and Parquet always use in-memory mode.
+///   Note: every input except plain CSV and SAS7BDAT (to Parquet), and every XPT output,
+///   is converted in memory.
 ///
 /// # Supported Conversions
-/// - CSV -> Parquet (streaming or fast mode)
-/// - Parquet -> CSV (always in-memory)
-/// - SAS7BDAT -> Parquet or CSV (always in-memory)
+/// - CSV -> Parquet (default) or Feather (streaming or fast mode), or XPT (in-memory)
+/// - Parquet -> CSV (default) or Feather (always in-memory)
+/// - Feather -> Parquet (default) or CSV (always in-memory)
+/// - JSON Lines -> Parquet (default), CSV or Feather (always in-memory)
+/// - SAS7BDAT -> Parquet (default, streamed in row groups), CSV or Feather (in-memory)
+/// - Excel -> Parquet (default), CSV or Feather (always in-memory)
+/// - SPSS / Stata -> Parquet (default), CSV or Feather (always in-memory)
+/// - Compressed CSV (`.csv.gz`, `.csv.zst`, single-file `.zip`) -> Parquet (default),
+///   CSV or Feather (decompressed in memory)
+/// - Any input -> SAS XPT when the output path ends in `.xpt`
+///
+/// Parquet output uses the converter defaults ([`ParquetOptions::CONVERT`]) and
+/// CSV the standard dialect; see [`run_convert_with`] to choose the codec, row
+/// groups and statistics, or the CSV separator, quoting and formats.
 pub fn run_convert(
     input: &Path,
     output: Option<&Path>,
     infer_schema_length: usize,
+    sheet: Option<&str>,
     fast: bool,
+) -> Result<()> {
+    run_convert_with(
+        input,
+        output,
+        infer_schema_length,
+        sheet,
+        fast,
+        &ParquetOptions::CONVERT,
+        &CsvDialect::default(),
+    )
+}
+
+/// Run file format conversion, writing Parquet output with `parquet` and
+/// reading and writing CSV in dialect `csv`
+pub fn run_convert_with(
+    input: &Path,
+    output: Option<&Path>,
+    infer_schema_length: usize,
+    sheet: Option<&str>,
+    fast: bool,
+    parquet: &ParquetOptions,
+    csv: &CsvDialect,
 ) -> Result<()> {
     let input_ext = input
         .extension()
@@ -56,12 +215,48 @@ pub fn run_convert(
         .unwrap_or("")
         .to_lowercase();
 
+    if is_compressed_csv(input) {
+        return run_convert_in_memory(
+            input,
+            output,
+            InMemoryInput::CompressedCsv,
+            infer_schema_length,
+            None,
+            parquet,
+            csv,
+        );
+    }
+
     match input_ext.as_str() {
-        "sas7bdat" => return run_convert_sas7bdat(input, output),
-        "parquet" => return run_convert_parquet(input, output),
-        "csv" => {} // Fall through to existing CSV-to-Parquet logic below
+        #[cfg(feature = "sas7bdat")]
+        "sas7bdat" => return run_convert_sas7bdat(input, output, parquet, csv),
+        #[cfg(not(feature = "sas7bdat"))]
+        "sas7bdat" => anyhow::bail!(crate::pipeline::SAS7BDAT_DISABLED),
+        "parquet" => {
+            return run_convert_in_memory(input, output, InMemoryInput::Parquet, 0, None, parquet, csv);
+        }
+        "feather" | "arrow" | "ipc" => {
+            return run_convert_in_memory(input, output, InMemoryInput::Ipc, 0, None, parquet, csv);
+        }
+        "jsonl" | "ndjson" => {
+            return run_convert_in_memory(
+                input,
+                output,
+                InMemoryInput::JsonLines,
+                infer_schema_length,
+                None,
+                parquet,
+                csv,
+            );
+        }
+        "xlsx" | "xlsm" | "xls" => {
+            return run_convert_in_memory(input, output, InMemoryInput::Excel, 0, sheet, parquet, csv);
+        }
+        "sav" => return run_convert_in_memory(input, output, InMemoryInput::Spss, 0, None, parquet, csv),
+        "dta" => return run_convert_in_memory(input, output, InMemoryInput::Stata, 0, None, parquet, csv),
+        "csv" => {} // Fall through to the CSV logic below
         _ => anyhow::bail!(
-            "Unsupported input format: .{}. Supported: .csv, .parquet, .sas7bdat",
+            "Unsupported input format: .{}. Supported: .csv (also .csv.gz, .csv.zst, .zip), .parquet, .feather, .jsonl, .sas7bdat, .xlsx, .sav, .dta",
             input_ext
         ),
     }
@@ -80,16 +275,24 @@ pub fn run_convert(
             parent.join(format!("{}.parquet", stem))
         }
     };
+    let output_format = OutputFormat::from_path(&output_path)?;
+    if output_format == OutputFormat::Csv {
+        anyhow::bail!("Input is already CSV; use a .parquet, .feather or .xpt output path");
+    }
+    // XPT has no streaming writer
+    let fast = fast || output_format == OutputFormat::Xpt;
+    let format_name = output_format.name();
 
     let mode_str = if fast {
         "fast (in-memory, multi-core)"
     } else {
-        "streaming (low memory)"
+        "streaming (chunked, bounded memory)"
     };
 
     println!(
-        "\n {} Converting CSV to Parquet  {}",
+        "\n {} Converting CSV to {}  {}",
         style("◆").cyan().bold(),
+        format_name,
         style(format!("[started {}]", timestamp())).dim()
     );
     println!("   Input:  {}", style(input.display()).dim());
@@ -119,6 +322,7 @@ pub fn run_convert(
     let load_time;
     let write_time;
     let num_cols;
+    let row_count;
 
     if fast {
         // === FAST MODE: In-memory conversion with parallelization ===
@@ -131,11 +335,15 @@ pub fn run_convert(
         );
         let step_start = Instant::now();
         let spinner = create_spinner("Initializing CSV reader...");
-        let lf = LazyCsvReader::new(input)
+        let lf = csv
+            .scan(input)
             .with_infer_schema_length(schema_length)
             .with_rechunk(true) // Rechunk for better parallel performance
             .finish()
             .with_context(|| format!("Failed to read CSV file: {}", input.display()))?;
+        let lf = csv
+            .parse_dates_lazy(lf, schema_length)
+            .with_context(|| format!("Failed to parse CSV dates: {}", input.display()))?;
         init_time = step_start.elapsed();
         spinner.finish_with_message(format!(
             "{} [{}] CSV reader initialized ({})",
@@ -182,34 +390,29 @@ pub fn run_convert(
             style(format_duration(load_time)).cyan()
         ));
 
-        // Step 4: Write to Parquet (parallelized column encoding!)
+        // Step 4: Write the output (parallelized column encoding!)
         println!(
-            "   {} [{}] Writing Parquet (parallel column encoding)...",
+            "   {} [{}] Writing {} (parallel column encoding)...",
             style("→").blue(),
-            style(timestamp()).dim()
+            style(timestamp()).dim(),
+            format_name
         );
         let step_start = Instant::now();
-        let spinner = create_spinner("Writing Parquet file...");
+        let spinner = create_spinner(&format!("Writing {} file...", format_name));
 
-        let file = std::fs::File::create(&output_path)
-            .with_context(|| format!("Failed to create output file: {}", output_path.display()))?;
-
-        ParquetWriter::new(file)
-            .with_compression(ParquetCompression::Snappy)
-            .with_statistics(StatisticsOptions::full())
-            .with_row_group_size(Some(100_000))
-            .finish(&mut df)
-            .with_context(|| format!("Failed to write Parquet file: {}", output_path.display()))?;
+        write_output(&mut df, &output_path, output_format, parquet, csv)?;
+        row_count = df.height();
 
         write_time = step_start.elapsed();
         spinner.finish_with_message(format!(
-            "{} [{}] Parquet written ({})",
+            "{} [{}] {} written ({})",
             style("✓").green(),
             style(timestamp()).dim(),
+            format_name,
             style(format_duration(write_time)).cyan()
         ));
     } else {
-        // === STREAMING MODE: Memory-efficient but single-threaded ===
+        // === STREAMING MODE: chunk by chunk with bounded memory ===
         load_time = std::time::Duration::ZERO; // No separate load step in streaming mode
 
         // Step 1: Create LazyFrame from CSV
@@ -220,7 +423,8 @@ pub fn run_convert(
         );
         let step_start = Instant::now();
         let spinner = create_spinner("Initializing CSV reader...");
-        let lf = LazyCsvReader::new(input)
+        let lf = csv
+            .scan(input)
             .with_infer_schema_length(schema_length)
             .with_low_memory(true) // Reduces memory pressure for large files
             .with_rechunk(false) // No rechunking needed for streaming
@@ -253,34 +457,51 @@ pub fn run_convert(
             style(format_duration(schema_time)).cyan()
         ));
 
-        // Step 3: Stream directly to Parquet without collecting into memory
+        // Step 3: Parse and write one chunk at a time
         println!(
-            "   {} [{}] Streaming to Parquet (single-threaded)...",
+            "   {} [{}] Streaming to {} in {} MB chunks...",
             style("→").blue(),
-            style(timestamp()).dim()
+            style(timestamp()).dim(),
+            format_name,
+            DEFAULT_CHUNK_BYTES / (1024 * 1024)
         );
         let step_start = Instant::now();
-        let spinner =
-            create_spinner("Streaming to Parquet (this may take a while for large files)...");
-
-        // Configure Parquet write options for optimal performance
-        let parquet_options = ParquetWriteOptions {
-            compression: ParquetCompression::Snappy,
-            statistics: StatisticsOptions::full(),
-            row_group_size: Some(100_000),
-            ..Default::default()
+        let bar = ProgressBar::new(std::fs::metadata(input).map(|m| m.len()).unwrap_or(0));
+        bar.set_style(
+            ProgressStyle::default_bar()
+                .template(
+                    "   Converting CSV [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({percent}%) [{eta}]",
+                )
+                .unwrap()
+                .progress_chars("=>-"),
+        );
+        let chunked_output = match output_format {
+            OutputFormat::Ipc => ChunkedOutput::Ipc,
+            _ => ChunkedOutput::Parquet(*parquet),
         };
-
-        lf.sink_parquet(&output_path, parquet_options, None)
-            .with_context(|| format!("Failed to write Parquet file: {}", output_path.display()))?;
+        let written = CsvChunker::new(csv, schema, schema_length)
+            .convert(input, &output_path, chunked_output, |read, _| {
+                bar.set_position(read)
+            })
+            .with_context(|| {
+                format!(
+                    "Failed to write {} file: {}",
+                    format_name,
+                    output_path.display()
+                )
+            })?;
+        bar.finish_and_clear();
+        row_count = written.rows;
 
         write_time = step_start.elapsed();
-        spinner.finish_with_message(format!(
-            "{} [{}] Parquet written ({})",
+        println!(
+            "   {} [{}] {} written in {} chunks ({})",
             style("✓").green(),
             style(timestamp()).dim(),
+            format_name,
+            written.chunks,
             style(format_duration(write_time)).cyan()
-        ));
+        );
     }
 
     // Show file size comparison
@@ -291,9 +512,6 @@ pub fn run_convert(
     let input_size = input_size_bytes as f64 / (1024.0 * 1024.0);
     let output_size = output_size_bytes as f64 / (1024.0 * 1024.0);
 
-    // Get row count from the output file (Parquet metadata is fast to read)
-    let row_count = get_parquet_row_count(&output_path).unwrap_or(0);
-
     let total_time = total_start.elapsed();
     let throughput_mb_s = input_size / total_time.as_secs_f64();
 
@@ -305,7 +523,11 @@ pub fn run_convert(
     );
     println!("   {} File sizes:", style("✧").cyan());
     println!("      CSV:     {:.2} MB", input_size);
-    println!("      Parquet: {:.2} MB", output_size);
+    println!(
+        "      {:<8} {:.2} MB",
+        format!("{}:", format_name),
+        output_size
+    );
 
     if output_size < input_size {
         let reduction = ((input_size - output_size) / input_size) * 100.0;
@@ -335,51 +557,100 @@ pub fn run_convert(
     Ok(())
 }
 
-/// Run Parquet to CSV conversion
+/// Run Parquet, Arrow IPC or JSON Lines conversion
 ///
-/// Loads the Parquet file and writes to CSV.
-fn run_convert_parquet(input: &Path, output: Option<&Path>) -> Result<()> {
+/// Loads the file into memory and writes it in the output path's format.
+/// Without an output path, Parquet converts to CSV and the others to Parquet.
+/// `infer_schema_length` only applies to JSON Lines.
+fn run_convert_in_memory(
+    input: &Path,
+    output: Option<&Path>,
+    input_format: InMemoryInput,
+    infer_schema_length: usize,
+    sheet: Option<&str>,
+    parquet: &ParquetOptions,
+    csv: &CsvDialect,
+) -> Result<()> {
     let total_start = Instant::now();
+    let input_name = input_format.name();
 
     // Determine output path
     let output_path = match output {
         Some(p) => p.to_path_buf(),
         None => {
             let parent = input.parent().unwrap_or_else(|| Path::new("."));
-            let stem = input
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or("output");
-            parent.join(format!("{}.csv", stem))
+            let stem = dataset_stem(input).unwrap_or("output");
+            parent.join(format!(
+                "{}.{}",
+                stem,
+                input_format.default_output_extension()
+            ))
         }
     };
+    let output_format = OutputFormat::from_path(&output_path)?;
+    let format_name = output_format.name();
+    if format_name == input_name {
+        anyhow::bail!(
+            "Input is already {}; choose a different output format",
+            input_name
+        );
+    }
 
     println!(
-        "\n {} Converting Parquet to CSV  {}",
+        "\n {} Converting {} to {}  {}",
         style("◆").cyan().bold(),
+        input_name,
+        format_name,
         style(format!("[started {}]", timestamp())).dim()
     );
     println!("   Input:  {}", style(input.display()).dim());
     println!("   Output: {}", style(output_path.display()).dim());
     println!();
 
-    // Step 1: Load Parquet file
+    // Step 1: Open the input file
     println!(
-        "   {} [{}] Loading Parquet file...",
+        "   {} [{}] Loading {} file...",
         style("→").blue(),
-        style(timestamp()).dim()
+        style(timestamp()).dim(),
+        input_name
     );
     let step_start = Instant::now();
-    let spinner = create_spinner("Loading Parquet file...");
-    let lf = LazyFrame::scan_parquet(input, Default::default())
-        .with_context(|| format!("Failed to read Parquet file: {}", input.display()))?;
+    let spinner = create_spinner(&format!("Loading {} file...", input_name));
+    let lf = match input_format {
+        InMemoryInput::Parquet => {
+            LazyFrame::scan_parquet(input, Default::default()).map_err(Into::into)
+        }
+        InMemoryInput::Ipc => LazyFrame::scan_ipc(input, Default::default()).map_err(Into::into),
+        InMemoryInput::JsonLines => scan_ndjson(input, infer_schema_length),
+        #[cfg(feature = "xlsx")]
+        InMemoryInput::Excel => load_excel(input, sheet).map(IntoLazy::lazy),
+        #[cfg(not(feature = "xlsx"))]
+        InMemoryInput::Excel => {
+            let _ = sheet;
+            anyhow::bail!(crate::pipeline::XLSX_DISABLED)
+        }
+        #[cfg(feature = "sav")]
+        InMemoryInput::Spss => load_sav(input).map(IntoLazy::lazy),
+        #[cfg(not(feature = "sav"))]
+        InMemoryInput::Spss => anyhow::bail!(crate::pipeline::SAV_DISABLED),
+        #[cfg(feature = "dta")]
+        InMemoryInput::Stata => load_dta(input).map(IntoLazy::lazy),
+        #[cfg(not(feature = "dta"))]
+        InMemoryInput::Stata => anyhow::bail!(crate::pipeline::DTA_DISABLED),
+        // The loader draws its own decompression progress bar
+        InMemoryInput::CompressedCsv => spinner
+            .suspend(|| load_dataset_columns(input, infer_schema_length, None, None, csv, None))
+            .map(|(df, _, _, _)| df.lazy()),
+    }
+    .with_context(|| format!("Failed to read {} file: {}", input_name, input.display()))?;
     let schema = lf.clone().collect_schema()?;
     let num_cols = schema.len();
     let init_time = step_start.elapsed();
     spinner.finish_with_message(format!(
-        "{} [{}] Parquet reader initialized: {} columns ({})",
+        "{} [{}] {} reader initialized: {} columns ({})",
         style("✓").green(),
         style(timestamp()).dim(),
+        input_name,
         style(num_cols).yellow(),
         style(format_duration(init_time)).cyan()
     ));
@@ -394,7 +665,7 @@ fn run_convert_parquet(input: &Path, output: Option<&Path>) -> Result<()> {
     let spinner = create_spinner("Loading dataset into memory...");
     let mut df = lf
         .collect()
-        .with_context(|| "Failed to load Parquet into memory")?;
+        .with_context(|| format!("Failed to load {} into memory", input_name))?;
     let row_count = df.height();
     let load_time = step_start.elapsed();
     spinner.finish_with_message(format!(
@@ -405,24 +676,22 @@ fn run_convert_parquet(input: &Path, output: Option<&Path>) -> Result<()> {
         style(format_duration(load_time)).cyan()
     ));
 
-    // Step 3: Write CSV
+    // Step 3: Write the output
     println!(
-        "   {} [{}] Writing CSV...",
+        "   {} [{}] Writing {}...",
         style("→").blue(),
-        style(timestamp()).dim()
+        style(timestamp()).dim(),
+        format_name
     );
     let step_start = Instant::now();
-    let spinner = create_spinner("Writing CSV file...");
-    let file = std::fs::File::create(&output_path)
-        .with_context(|| format!("Failed to create output file: {}", output_path.display()))?;
-    CsvWriter::new(file)
-        .finish(&mut df)
-        .with_context(|| format!("Failed to write CSV file: {}", output_path.display()))?;
+    let spinner = create_spinner(&format!("Writing {} file...", format_name));
+    write_output(&mut df, &output_path, output_format, parquet, csv)?;
     let write_time = step_start.elapsed();
     spinner.finish_with_message(format!(
-        "{} [{}] CSV written ({})",
+        "{} [{}] {} written ({})",
         style("✓").green(),
         style(timestamp()).dim(),
+        format_name,
         style(format_duration(write_time)).cyan()
     ));
 
@@ -443,8 +712,16 @@ fn run_convert_parquet(input: &Path, output: Option<&Path>) -> Result<()> {
         style(num_cols).yellow()
     );
     println!("   {} File sizes:", style("✧").cyan());
-    println!("      Parquet: {:.2} MB", input_size);
-    println!("      CSV:     {:.2} MB", output_size);
+    println!(
+        "      {:<8} {:.2} MB",
+        format!("{}:", input_name),
+        input_size
+    );
+    println!(
+        "      {:<8} {:.2} MB",
+        format!("{}:", format_name),
+        output_size
+    );
 
     if output_size < input_size {
         let reduction = ((input_size - output_size) / input_size) * 100.0;
@@ -473,9 +750,16 @@ fn run_convert_parquet(input: &Path, output: Option<&Path>) -> Result<()> {
 
 /// Run SAS7BDAT to Parquet/CSV conversion
 ///
-/// Loads the SAS7BDAT file using the pure Rust parser, then writes to
-/// Parquet (default) or CSV (if output path has .csv extension).
-fn run_convert_sas7bdat(input: &Path, output: Option<&Path>) -> Result<()> {
+/// Parquet output (the default) is streamed: rows are decoded and written one
+/// row group at a time, so memory stays bounded however large the file is.
+/// CSV, Feather and XPT outputs load the whole file first.
+#[cfg(feature = "sas7bdat")]
+fn run_convert_sas7bdat(
+    input: &Path,
+    output: Option<&Path>,
+    parquet: &ParquetOptions,
+    csv: &CsvDialect,
+) -> Result<()> {
     let total_start = Instant::now();
 
     // Determine output path and format
@@ -491,17 +775,8 @@ fn run_convert_sas7bdat(input: &Path, output: Option<&Path>) -> Result<()> {
         }
     };
 
-    let output_ext = output_path
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("parquet")
-        .to_lowercase();
-
-    let format_name = if output_ext == "csv" {
-        "CSV"
-    } else {
-        "Parquet"
-    };
+    let output_format = OutputFormat::from_path(&output_path)?;
+    let format_name = output_format.name();
 
     println!(
         "\n {} Converting SAS7BDAT to {}  {}",
@@ -513,53 +788,15 @@ fn run_convert_sas7bdat(input: &Path, output: Option<&Path>) -> Result<()> {
     println!("   Output: {}", style(output_path.display()).dim());
     println!();
 
-    // Step 1: Load SAS7BDAT file
     // NOTE: This function assumes the terminal has already been restored to normal
     // mode before being called (i.e., ratatui alternate screen has been exited).
     // It uses indicatif spinners directly, which would corrupt the TUI if called
     // while ratatui still owns the screen.
-    let spinner = create_spinner("Loading SAS7BDAT file...");
-    let (mut df, rows, cols, _) = load_sas7bdat(input).context("Failed to load SAS7BDAT file")?;
-    let load_time = total_start.elapsed();
-    spinner.finish_with_message(format!(
-        "{} [{}] SAS7BDAT loaded: {} rows x {} columns ({})",
-        style("✓").green(),
-        style(timestamp()).dim(),
-        style(rows).yellow(),
-        style(cols).yellow(),
-        style(format_duration(load_time)).cyan()
-    ));
-
-    // Step 2: Write output
-    let write_start = Instant::now();
-    let msg = format!("Writing {} file...", format_name);
-    let spinner = create_spinner(&msg);
-
-    if output_ext == "csv" {
-        let file = std::fs::File::create(&output_path)
-            .with_context(|| format!("Failed to create output file: {}", output_path.display()))?;
-        CsvWriter::new(file)
-            .finish(&mut df)
-            .with_context(|| format!("Failed to write CSV file: {}", output_path.display()))?;
+    let (rows, cols) = if output_format == OutputFormat::Parquet {
+        stream_sas7bdat_to_parquet(input, &output_path, parquet)?
     } else {
-        let file = std::fs::File::create(&output_path)
-            .with_context(|| format!("Failed to create output file: {}", output_path.display()))?;
-        ParquetWriter::new(file)
-            .with_compression(ParquetCompression::Snappy)
-            .with_statistics(StatisticsOptions::full())
-            .with_row_group_size(Some(100_000))
-            .finish(&mut df)
-            .with_context(|| format!("Failed to write Parquet file: {}", output_path.display()))?;
-    }
-
-    let write_time = write_start.elapsed();
-    spinner.finish_with_message(format!(
-        "{} [{}] {} written ({})",
-        style("✓").green(),
-        style(timestamp()).dim(),
-        format_name,
-        style(format_duration(write_time)).cyan()
-    ));
+        load_and_write_sas7bdat(input, &output_path, output_format, parquet, csv)?
+    };
 
     // Show file sizes
     let input_size_bytes = std::fs::metadata(input).map(|m| m.len()).unwrap_or(0);
@@ -599,16 +836,97 @@ fn run_convert_sas7bdat(input: &Path, output: Option<&Path>) -> Result<()> {
     Ok(())
 }
 
-/// Get row count from a Parquet file using metadata (fast, no full scan)
-fn get_parquet_row_count(path: &Path) -> Result<usize> {
-    let lf = LazyFrame::scan_parquet(path, Default::default())?;
-    let df = lf.select([len()]).collect()?;
-    let count = df.column("len")?.get(0)?;
-    match count {
-        AnyValue::UInt32(n) => Ok(n as usize),
-        AnyValue::UInt64(n) => Ok(n as usize),
-        AnyValue::Int32(n) => Ok(n as usize),
-        AnyValue::Int64(n) => Ok(n as usize),
-        _ => Ok(0),
+/// Load a whole SAS7BDAT file, then write it in `format`. Returns rows and columns.
+#[cfg(feature = "sas7bdat")]
+fn load_and_write_sas7bdat(
+    input: &Path,
+    output_path: &Path,
+    format: OutputFormat,
+    parquet: &ParquetOptions,
+    csv: &CsvDialect,
+) -> Result<(usize, usize)> {
+    // Step 1: Load SAS7BDAT file
+    let load_start = Instant::now();
+    let spinner = create_spinner("Loading SAS7BDAT file...");
+    let (mut df, rows, cols, _) = load_sas7bdat(input).context("Failed to load SAS7BDAT file")?;
+    spinner.finish_with_message(format!(
+        "{} [{}] SAS7BDAT loaded: {} rows x {} columns ({})",
+        style("✓").green(),
+        style(timestamp()).dim(),
+        style(rows).yellow(),
+        style(cols).yellow(),
+        style(format_duration(load_start.elapsed())).cyan()
+    ));
+
+    // Step 2: Write output
+    let write_start = Instant::now();
+    let msg = format!("Writing {} file...", format.name());
+    let spinner = create_spinner(&msg);
+
+    write_output(&mut df, output_path, format, parquet, csv)?;
+
+    spinner.finish_with_message(format!(
+        "{} [{}] {} written ({})",
+        style("✓").green(),
+        style(timestamp()).dim(),
+        format.name(),
+        style(format_duration(write_start.elapsed())).cyan()
+    ));
+
+    Ok((rows, cols))
+}
+
+/// Convert a SAS7BDAT file to Parquet one row group at a time.
+///
+/// Only one batch of [`DEFAULT_BATCH_ROWS`] rows is decoded at any moment.
+/// Returns rows and columns.
+#[cfg(feature = "sas7bdat")]
+fn stream_sas7bdat_to_parquet(
+    input: &Path,
+    output_path: &Path,
+    parquet: &ParquetOptions,
+) -> Result<(usize, usize)> {
+    let start = Instant::now();
+    let mut reader = Sas7bdatReader::open(input).context("Failed to load SAS7BDAT file")?;
+    let total_rows = reader.row_count();
+    let spinner = create_spinner("Converting SAS7BDAT to Parquet...");
+
+    let file = std::fs::File::create(output_path)
+        .with_context(|| format!("Failed to create output file: {}", output_path.display()))?;
+    let write_error = || format!("Failed to write Parquet file: {}", output_path.display());
+
+    let read_error = "Failed to read SAS7BDAT rows";
+    let first = match reader.next_batch(DEFAULT_BATCH_ROWS).context(read_error)? {
+        Some(batch) => batch,
+        None => reader.empty_frame()?,
+    };
+    let cols = first.width();
+    let mut writer = parquet
+        .writer(file)
+        .batched(first.schema())
+        .with_context(write_error)?;
+    writer.write_batch(&first).with_context(write_error)?;
+    let mut rows = first.height();
+    drop(first);
+
+    while let Some(batch) = reader.next_batch(DEFAULT_BATCH_ROWS).context(read_error)? {
+        writer.write_batch(&batch).with_context(write_error)?;
+        rows += batch.height();
+        spinner.set_message(format!(
+            "Converting SAS7BDAT to Parquet... {}/{} rows",
+            rows, total_rows
+        ));
     }
+    writer.finish().with_context(write_error)?;
+
+    spinner.finish_with_message(format!(
+        "{} [{}] Parquet written: {} rows x {} columns ({})",
+        style("✓").green(),
+        style(timestamp()).dim(),
+        style(rows).yellow(),
+        style(cols).yellow(),
+        style(format_duration(start.elapsed())).cyan()
+    ));
+
+    Ok((rows, cols))
 }
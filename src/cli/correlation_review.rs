@@ -0,0 +1,240 @@
+//! Correlated-pair review screen shown inside the progress overlay.
+//!
+//! With `--review-correlations`, the pipeline thread pauses after correlation
+//! analysis and sends the pairs it resolved. Each pair is listed with both
+//! features' IV and missing rate and the member the automatic rules chose to
+//! drop; the user can swap that choice before the drops are applied. With
+//! `--dictionary`, each feature's description follows its line.
+//!
+//! ```text
+//!   3 correlated pairs · 1 reversed
+//!
+//!   ▸ Pearson 0.9412
+//!       ✗ income_monthly       IV 0.2104   miss  1.2%   drop  Declared monthly income
+//!       ✓ income_annual        IV 0.2311   miss  0.4%   keep  Declared annual income
+//! ```
+
+use std::sync::mpsc;
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Alignment, Rect},
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use super::shared::themed;
+use super::theme;
+use crate::pipeline::progress::{CorrelationReviewItem, CorrelationReviewRequest};
+
+/// Feature names longer than this are truncated in the table
+const NAME_WIDTH: usize = 28;
+
+/// Lines used per pair: header plus one line per feature
+const LINES_PER_PAIR: usize = 3;
+
+/// State of an open correlated-pair review.
+pub struct CorrelationReview {
+    items: Vec<CorrelationReviewItem>,
+    reversed: Vec<bool>,
+    selected: usize,
+    reply: mpsc::Sender<Vec<usize>>,
+}
+
+impl CorrelationReview {
+    pub fn new(request: CorrelationReviewRequest) -> Self {
+        Self {
+            reversed: vec![false; request.items.len()],
+            items: request.items,
+            selected: 0,
+            reply: request.reply,
+        }
+    }
+
+    /// Indices of the pairs whose proposed drop the user has swapped
+    pub fn reversed_indices(&self) -> Vec<usize> {
+        self.reversed
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &r)| r.then_some(i))
+            .collect()
+    }
+
+    /// The member of pair `idx` that will be dropped with the current choices
+    pub fn dropped(&self, idx: usize) -> &str {
+        let item = &self.items[idx];
+        let proposed_first = item.proposed_drop == item.feature1;
+        if proposed_first != self.reversed[idx] {
+            &item.feature1
+        } else {
+            &item.feature2
+        }
+    }
+
+    /// Handle a key press. Returns `true` once the review is finished and the
+    /// decisions have been sent back to the pipeline.
+    ///
+    /// Enter confirms the current choices; Esc discards any swaps and keeps the
+    /// automatic choices.
+    pub fn handle_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.selected + 1 < self.items.len() => {
+                self.selected += 1;
+            }
+            KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right => {
+                if let Some(r) = self.reversed.get_mut(self.selected) {
+                    *r = !*r;
+                }
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.reversed.iter_mut().for_each(|r| *r = false);
+            }
+            KeyCode::Enter => {
+                // The pipeline may already be gone (e.g. it failed); nothing to do then
+                let _ = self.reply.send(self.reversed_indices());
+                return true;
+            }
+            KeyCode::Esc => {
+                let _ = self.reply.send(Vec::new());
+                return true;
+            }
+            _ => {}
+        }
+        false
+    }
+
+    /// Render the review table into `area` (the overlay box).
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(themed(Style::default().fg(theme::ACCENT)))
+            .title(" Review Correlated Pairs ")
+            .title_style(themed(Style::default().fg(theme::ACCENT).bold()))
+            .title_alignment(Alignment::Center);
+        let inner = block.inner(area);
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let reversed_count = self.reversed.iter().filter(|&&r| r).count();
+        let mut lines: Vec<Line> = vec![
+            Line::from(vec![
+                Span::styled(
+                    format!("  {} correlated pairs", self.items.len()),
+                    themed(Style::default().fg(theme::TEXT).bold()),
+                ),
+                Span::styled(" · ", themed(Style::default().fg(theme::MUTED))),
+                Span::styled(
+                    format!("{} reversed", reversed_count),
+                    themed(Style::default().fg(if reversed_count > 0 {
+                        theme::WARNING
+                    } else {
+                        theme::MUTED
+                    })),
+                ),
+            ]),
+            Line::from(""),
+        ];
+
+        // Keep the selected pair in view
+        let visible_pairs = (inner.height as usize)
+            .saturating_sub(lines.len())
+            .max(LINES_PER_PAIR)
+            / LINES_PER_PAIR;
+        let start = (self.selected + 1).saturating_sub(visible_pairs);
+
+        for (idx, item) in self
+            .items
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(visible_pairs)
+        {
+            let is_selected = idx == self.selected;
+            let marker = if is_selected { "  ▸ " } else { "    " };
+            let header_style = if is_selected {
+                themed(Style::default().fg(theme::ACCENT).bold())
+            } else {
+                themed(Style::default().fg(theme::SUBTEXT))
+            };
+            let mut header = vec![Span::styled(
+                format!("{}{} {:.4}", marker, item.measure, item.correlation),
+                header_style,
+            )];
+            if self.reversed[idx] {
+                header.push(Span::styled(
+                    "  (reversed)",
+                    themed(Style::default().fg(theme::WARNING)),
+                ));
+            }
+            lines.push(Line::from(header));
+
+            let dropped = self.dropped(idx);
+            for (name, iv, missing, description) in [
+                (&item.feature1, item.iv1, item.missing1, &item.description1),
+                (&item.feature2, item.iv2, item.missing2, &item.description2),
+            ] {
+                lines.push(feature_line(
+                    name,
+                    iv,
+                    missing,
+                    description.as_deref(),
+                    name == dropped,
+                ));
+            }
+        }
+
+        f.render_widget(Paragraph::new(lines), inner);
+    }
+}
+
+fn feature_line<'a>(
+    name: &'a str,
+    iv: Option<f64>,
+    missing: Option<f64>,
+    description: Option<&'a str>,
+    dropped: bool,
+) -> Line<'a> {
+    let (glyph, color, action) = if dropped {
+        ("✗", theme::ERROR, "drop")
+    } else {
+        ("✓", theme::SUCCESS, "keep")
+    };
+    let iv_text = iv.map_or_else(|| "   -  ".to_string(), |v| format!("{:.4}", v));
+    let missing_text =
+        missing.map_or_else(|| "   - ".to_string(), |v| format!("{:4.1}%", v * 100.0));
+
+    let mut spans = vec![
+        Span::styled(
+            format!("      {} ", glyph),
+            themed(Style::default().fg(color)),
+        ),
+        Span::styled(
+            format!("{:<width$}", truncate_name(name), width = NAME_WIDTH),
+            themed(Style::default().fg(theme::TEXT)),
+        ),
+        Span::styled(
+            format!(" IV {}  miss {}  ", iv_text, missing_text),
+            themed(Style::default().fg(theme::SUBTEXT)),
+        ),
+        Span::styled(action, themed(Style::default().fg(color).bold())),
+    ];
+    if let Some(description) = description {
+        spans.push(Span::styled(
+            format!("  {}", description),
+            themed(Style::default().fg(theme::MUTED)),
+        ));
+    }
+    Line::from(spans)
+}
+
+fn truncate_name(name: &str) -> String {
+    if name.chars().count() <= NAME_WIDTH {
+        name.to_string()
+    } else {
+        let head: String = name.chars().take(NAME_WIDTH - 1).collect();
+        format!("{}…", head)
+    }
+}
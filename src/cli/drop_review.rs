@@ -0,0 +1,225 @@
+//! Review screen for all proposed drops, shown inside the progress overlay.
+//!
+//! With `--review-drops`, the pipeline thread pauses after correlation analysis
+//! and sends every feature it is about to drop, with the stage and reason.
+//! Drops start checked; un-checking a feature keeps it in the output, and the
+//! report records it as `user-retained`. With `--dictionary`, each feature's
+//! description follows its row.
+//!
+//! ```text
+//!   14 proposed drops · 2 kept
+//!
+//!   ▸ [ ] postal_code                  missing      keep  Residential postcode
+//!     [x] customer_segment             gini               Marketing segment
+//!     [x] salary                       correlation
+//!
+//!   Missing ratio 0.67 exceeded threshold 0.30
+//! ```
+
+use std::sync::mpsc;
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+use super::shared::themed;
+use super::theme;
+use crate::pipeline::progress::{DropReviewItem, DropReviewRequest};
+
+/// Feature names longer than this are truncated in the list
+const NAME_WIDTH: usize = 28;
+
+/// Lines below the list for the selected feature's drop reason
+const REASON_HEIGHT: u16 = 3;
+
+/// State of an open drop review.
+pub struct DropReview {
+    items: Vec<DropReviewItem>,
+    kept: Vec<bool>,
+    selected: usize,
+    reply: mpsc::Sender<Vec<usize>>,
+}
+
+impl DropReview {
+    pub fn new(request: DropReviewRequest) -> Self {
+        Self {
+            kept: vec![false; request.items.len()],
+            items: request.items,
+            selected: 0,
+            reply: request.reply,
+        }
+    }
+
+    /// Indices of the features the user un-checked
+    pub fn kept_indices(&self) -> Vec<usize> {
+        self.kept
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &k)| k.then_some(i))
+            .collect()
+    }
+
+    /// Whether feature `idx` will be dropped with the current choices
+    pub fn is_dropped(&self, idx: usize) -> bool {
+        !self.kept[idx]
+    }
+
+    /// Handle a key press. Returns `true` once the review is finished and the
+    /// decisions have been sent back to the pipeline.
+    ///
+    /// Enter confirms the current choices; Esc discards them and applies every
+    /// proposed drop.
+    pub fn handle_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.selected + 1 < self.items.len() => {
+                self.selected += 1;
+            }
+            KeyCode::Char(' ') => {
+                if let Some(k) = self.kept.get_mut(self.selected) {
+                    *k = !*k;
+                }
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                self.kept.iter_mut().for_each(|k| *k = false);
+            }
+            KeyCode::Enter => {
+                // The pipeline may already be gone (e.g. it failed); nothing to do then
+                let _ = self.reply.send(self.kept_indices());
+                return true;
+            }
+            KeyCode::Esc => {
+                let _ = self.reply.send(Vec::new());
+                return true;
+            }
+            _ => {}
+        }
+        false
+    }
+
+    /// Render the review list into `area` (the overlay box).
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(themed(Style::default().fg(theme::ACCENT)))
+            .title(" Review Proposed Drops ")
+            .title_style(themed(Style::default().fg(theme::ACCENT).bold()))
+            .title_alignment(Alignment::Center);
+        let inner = block.inner(area);
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(REASON_HEIGHT)])
+            .split(inner);
+        let (list_area, reason_area) = (chunks[0], chunks[1]);
+
+        let kept_count = self.kept.iter().filter(|&&k| k).count();
+        let mut lines: Vec<Line> = vec![
+            Line::from(vec![
+                Span::styled(
+                    format!("  {} proposed drops", self.items.len()),
+                    themed(Style::default().fg(theme::TEXT).bold()),
+                ),
+                Span::styled(" · ", themed(Style::default().fg(theme::MUTED))),
+                Span::styled(
+                    format!("{} kept", kept_count),
+                    themed(Style::default().fg(if kept_count > 0 {
+                        theme::WARNING
+                    } else {
+                        theme::MUTED
+                    })),
+                ),
+            ]),
+            Line::from(""),
+        ];
+
+        // Keep the selected feature in view
+        let visible = (list_area.height as usize)
+            .saturating_sub(lines.len())
+            .max(1);
+        let start = (self.selected + 1).saturating_sub(visible);
+
+        for (idx, item) in self.items.iter().enumerate().skip(start).take(visible) {
+            lines.push(self.item_line(idx, item));
+        }
+        f.render_widget(Paragraph::new(lines), list_area);
+
+        if let Some(item) = self.items.get(self.selected) {
+            let reason = Paragraph::new(Line::from(Span::styled(
+                format!("  {}", item.reason),
+                themed(Style::default().fg(theme::SUBTEXT)),
+            )))
+            .wrap(Wrap { trim: false });
+            // Blank line between the list and the reason
+            let reason_area = Rect {
+                y: reason_area.y + 1,
+                height: reason_area.height.saturating_sub(1),
+                ..reason_area
+            };
+            f.render_widget(reason, reason_area);
+        }
+    }
+
+    fn item_line<'a>(&self, idx: usize, item: &'a DropReviewItem) -> Line<'a> {
+        let is_selected = idx == self.selected;
+        let marker = if is_selected { "  ▸ " } else { "    " };
+        let (checkbox, color) = if self.is_dropped(idx) {
+            ("[x]", theme::ERROR)
+        } else {
+            ("[ ]", theme::SUCCESS)
+        };
+        let name_style = if is_selected {
+            themed(Style::default().fg(theme::ACCENT).bold())
+        } else {
+            themed(Style::default().fg(theme::TEXT))
+        };
+
+        let mut spans = vec![
+            Span::styled(marker, name_style),
+            Span::styled(format!("{} ", checkbox), themed(Style::default().fg(color))),
+            Span::styled(
+                format!(
+                    "{:<width$}",
+                    truncate_name(&item.feature),
+                    width = NAME_WIDTH
+                ),
+                name_style,
+            ),
+            Span::styled(
+                format!(" {:<12}", item.stage),
+                themed(Style::default().fg(theme::SUBTEXT)),
+            ),
+        ];
+        if !self.is_dropped(idx) {
+            spans.push(Span::styled(
+                "keep",
+                themed(Style::default().fg(theme::SUCCESS).bold()),
+            ));
+        } else if item.description.is_some() {
+            spans.push(Span::raw("    "));
+        }
+        if let Some(description) = &item.description {
+            spans.push(Span::styled(
+                format!("  {}", description),
+                themed(Style::default().fg(theme::MUTED)),
+            ));
+        }
+        Line::from(spans)
+    }
+}
+
+fn truncate_name(name: &str) -> String {
+    if name.chars().count() <= NAME_WIDTH {
+        name.to_string()
+    } else {
+        let head: String = name.chars().take(NAME_WIDTH - 1).collect();
+        format!("{}…", head)
+    }
+}
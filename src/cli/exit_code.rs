@@ -0,0 +1,110 @@
+//! Process exit codes for scripted runs
+//!
+//! CI jobs and schedulers branch on the exit code, so a failed run exits with
+//! a code that names the kind of failure:
+//!
+//! | Code | Meaning |
+//! |------|---------|
+//! | 0 | Success |
+//! | 1 | Any other failure |
+//! | 2 | Validation: a setting or the data failed a check (unknown column, non-binary target, schema drift, bad flag value) |
+//! | 3 | I/O: an input, report or output file could not be read or written |
+//! | 4 | Solver: `--fail-on-solver-fallback` stopped the run |
+//!
+//! Argument errors reported by clap also exit with 2.
+
+use polars::prelude::PolarsError;
+
+use crate::pipeline::solver::SolverTimedOut;
+
+/// Exit code for failures without a more specific code
+pub const EXIT_FAILURE: u8 = 1;
+/// Exit code for a setting or dataset that failed a check
+pub const EXIT_VALIDATION: u8 = 2;
+/// Exit code for a file that could not be read or written
+pub const EXIT_IO: u8 = 3;
+/// Exit code for a run stopped by a solver failure
+pub const EXIT_SOLVER: u8 = 4;
+
+/// Error of a run stopped by `--fail-on-solver-fallback`: the solver found no
+/// binning for these features, which would have fallen back to greedy merging
+#[derive(Debug)]
+pub struct SolverFallback {
+    pub features: Vec<String>,
+}
+
+impl std::fmt::Display for SolverFallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Solver found no solution for {} feature(s): {}. Raise --solver-timeout, relax \
+             the bin constraints, or drop --fail-on-solver-fallback to accept greedy merging.",
+            self.features.len(),
+            self.features.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for SolverFallback {}
+
+/// The exit code for a run that failed with `err`.
+///
+/// Solver and I/O errors are recognised by type anywhere in the error chain.
+/// Errors raised by lophi's own checks are plain messages (`bail!`), so a
+/// message error, or a polars error about a missing or duplicate column,
+/// counts as a validation failure.
+pub fn exit_code(err: &anyhow::Error) -> u8 {
+    for cause in err.chain() {
+        if cause.is::<SolverFallback>() || cause.is::<SolverTimedOut>() {
+            return EXIT_SOLVER;
+        }
+        if cause.is::<std::io::Error>() || cause.is::<zip::result::ZipError>() {
+            return EXIT_IO;
+        }
+        #[cfg(feature = "sas7bdat")]
+        if cause.is::<crate::pipeline::sas7bdat::SasError>() {
+            return EXIT_IO;
+        }
+        if let Some(polars_err) = cause.downcast_ref::<PolarsError>() {
+            return match polars_err {
+                PolarsError::IO { .. } => EXIT_IO,
+                PolarsError::ColumnNotFound(_)
+                | PolarsError::SchemaFieldNotFound(_)
+                | PolarsError::Duplicate(_) => EXIT_VALIDATION,
+                _ => EXIT_FAILURE,
+            };
+        }
+    }
+    if err.downcast_ref::<String>().is_some() || err.downcast_ref::<&str>().is_some() {
+        EXIT_VALIDATION
+    } else {
+        EXIT_FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_exit_code_by_cause() {
+        let io = std::fs::File::open("/no/such/file")
+            .context("Failed to open CSV file")
+            .unwrap_err();
+        assert_eq!(exit_code(&io), EXIT_IO);
+
+        let check = anyhow::anyhow!("Target column '{}' not found", "y");
+        assert_eq!(exit_code(&check), EXIT_VALIDATION);
+        assert_eq!(exit_code(&anyhow::anyhow!("literal")), EXIT_VALIDATION);
+
+        let solver = anyhow::Error::new(SolverFallback {
+            features: vec!["a".to_string()],
+        })
+        .context("Gini analysis");
+        assert_eq!(exit_code(&solver), EXIT_SOLVER);
+
+        let compute = anyhow::Error::new(PolarsError::ComputeError("overflow".into()));
+        assert_eq!(exit_code(&compute), EXIT_FAILURE);
+    }
+}
@@ -0,0 +1,149 @@
+//! JSON-lines progress output for `--json-output`
+//!
+//! Orchestration tools such as Airflow cannot read spinners. With
+//! `--json-output` the reduction pipeline runs without prompts or terminal
+//! output, and every progress event becomes one JSON object per line, written
+//! to stdout or to a log file. The last line is either `pipeline_finished`,
+//! with the final counts, stage timings and dropped features, or
+//! `pipeline_failed` with the error that stopped the run.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+
+use crate::pipeline::progress::{PipelineStage, ProgressEvent};
+use crate::report::ReductionSummary;
+
+/// `--json-output` value that selects stdout instead of a file
+pub const STDOUT_PATH: &str = "-";
+
+/// Writes pipeline events as JSON lines, flushing after each one so a
+/// follower of the log sees events as they happen
+pub struct JsonEventWriter {
+    out: Box<dyn Write>,
+}
+
+impl JsonEventWriter {
+    /// Write to `path`, or to stdout when it is [`STDOUT_PATH`]. An existing
+    /// file is replaced.
+    pub fn create(path: &Path) -> Result<Self> {
+        let out: Box<dyn Write> = if path.as_os_str() == STDOUT_PATH {
+            Box::new(std::io::stdout())
+        } else {
+            let file = File::create(path)
+                .with_context(|| format!("Failed to create JSON output {}", path.display()))?;
+            Box::new(BufWriter::new(file))
+        };
+        Ok(Self { out })
+    }
+
+    /// Write a progress event from the pipeline thread. Review requests and
+    /// the final `Complete` event are skipped: reviews need a person, and the
+    /// run's outcome is written by [`Self::write_finished`] instead.
+    pub fn write_event(&mut self, event: &ProgressEvent) -> Result<()> {
+        if event.stage == PipelineStage::Complete
+            || event.correlation_review.is_some()
+            || event.drop_review.is_some()
+        {
+            return Ok(());
+        }
+        let (kind, extra) = if event.is_complete {
+            (
+                "stage_finished",
+                json!({ "elapsed_secs": event.elapsed_secs }),
+            )
+        } else if let Some(detail) = &event.detail {
+            ("stage_update", json!({ "detail": detail }))
+        } else {
+            ("stage_started", json!({}))
+        };
+        let mut line = self.line(kind);
+        line["stage"] = json!(stage_name(&event.stage));
+        line["message"] = json!(event.message);
+        merge(&mut line, extra);
+        self.write_line(&line)
+    }
+
+    /// Final line of a successful run. `output` is `None` for a dry run.
+    pub fn write_finished(
+        &mut self,
+        summary: &ReductionSummary,
+        output: Option<&Path>,
+        elapsed_secs: f64,
+    ) -> Result<()> {
+        let secs = |d: std::time::Duration| d.as_secs_f64();
+        let mut line = self.line("pipeline_finished");
+        merge(
+            &mut line,
+            json!({
+                "elapsed_secs": elapsed_secs,
+                "output": output.map(|p| p.display().to_string()),
+                "initial_features": summary.initial_features,
+                "final_features": summary.final_features,
+                "dropped_count": summary.dropped_count(),
+                "dropped": {
+                    "constant": summary.dropped_constant,
+                    "identifier": summary.dropped_identifier,
+                    "missing": summary.dropped_missing,
+                    "gini": summary.dropped_gini,
+                    "correlation": summary.dropped_correlation,
+                },
+                "timings": {
+                    "load_secs": secs(summary.load_time),
+                    "constant_secs": summary.constant_time.map(secs),
+                    "identifier_secs": summary.identifier_time.map(secs),
+                    "missing_secs": secs(summary.missing_time.unwrap_or_default()),
+                    "gini_secs": secs(summary.gini_time.unwrap_or_default()),
+                    "correlation_secs": secs(summary.correlation_time.unwrap_or_default()),
+                    "save_secs": secs(summary.save_time),
+                },
+            }),
+        );
+        self.write_line(&line)
+    }
+
+    /// Final line of a run that stopped with `error`
+    pub fn write_failed(&mut self, error: &anyhow::Error) -> Result<()> {
+        let mut line = self.line("pipeline_failed");
+        line["error"] = json!(format!("{:#}", error));
+        self.write_line(&line)
+    }
+
+    fn line(&self, event: &str) -> Value {
+        json!({
+            "timestamp": chrono::Utc::now().to_rfc3339(),
+            "event": event,
+        })
+    }
+
+    fn write_line(&mut self, line: &Value) -> Result<()> {
+        serde_json::to_writer(&mut self.out, line)?;
+        self.out.write_all(b"\n")?;
+        self.out.flush()?;
+        Ok(())
+    }
+}
+
+fn merge(line: &mut Value, extra: Value) {
+    if let (Some(line), Value::Object(extra)) = (line.as_object_mut(), extra) {
+        line.extend(extra);
+    }
+}
+
+fn stage_name(stage: &PipelineStage) -> &'static str {
+    match stage {
+        PipelineStage::Loading => "loading",
+        PipelineStage::Validating => "validating",
+        PipelineStage::MissingAnalysis => "missing_analysis",
+        PipelineStage::GiniAnalysis => "gini_analysis",
+        PipelineStage::CorrelationAnalysis => "correlation_analysis",
+        PipelineStage::Sampling => "sampling",
+        PipelineStage::Converting => "converting",
+        PipelineStage::Saving => "saving",
+        PipelineStage::Reports => "reports",
+        PipelineStage::Complete => "complete",
+    }
+}
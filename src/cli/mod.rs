@@ -1,17 +1,40 @@
 //! CLI module - argument parsing and interactive prompts
 
+//!
+//! The ratatui screens (configuration menu, wizard, progress overlay,
+//! correlation review, drop review and results browser) are compiled only with
+//! the `tui` feature.
+
 mod args;
+pub mod batch;
+pub mod bench;
+pub mod config_file;
+#[cfg(feature = "tui")]
 mod config_menu;
 pub mod convert;
+#[cfg(feature = "tui")]
+pub mod correlation_review;
+#[cfg(feature = "tui")]
+pub mod drop_review;
+pub mod exit_code;
+pub mod json_output;
+#[cfg(feature = "tui")]
 pub mod progress_overlay;
+#[cfg(feature = "tui")]
+pub mod results_browser;
+#[cfg(feature = "tui")]
 pub mod shared;
+#[cfg(feature = "tui")]
 pub mod theme;
+#[cfg(feature = "tui")]
 pub mod wizard;
 
-pub use args::{Cli, Commands};
+pub use args::{build_csv_dialect, Cli, Commands};
+#[cfg(feature = "tui")]
 pub use config_menu::{
-    run_config_menu_keep_tui, run_file_selector, run_target_mapping_selector, Config, ConfigResult,
-    FileSelectResult, TargetMappingResult,
+    run_config_menu_keep_tui, run_file_selector, run_sheet_selector, run_target_mapping_selector,
+    Config, ConfigResult, FileSelectResult, SheetSelectResult, TargetMappingResult,
 };
+#[cfg(feature = "tui")]
 #[allow(unused_imports)]
 pub use wizard::{run_wizard, run_wizard_keep_tui, ConversionConfig, WizardResult, WizardTask};
@@ -21,6 +21,12 @@
 //!     └──────────────────────────────────────┘
 //!       [Q] quit (aborts pipeline)
 //! ```
+//!
+//! With `--review-correlations` the box is temporarily replaced by the
+//! correlated-pair review screen (see [`super::correlation_review`]), and with
+//! `--review-drops` by the proposed-drop review (see [`super::drop_review`]).
+//! With `--browse-results` the completed box opens the results browser (see
+//! [`super::results_browser`]).
 
 use std::io::Stdout;
 use std::sync::mpsc::TryRecvError;
@@ -36,6 +42,9 @@ use ratatui::{
     Terminal,
 };
 
+use super::correlation_review::CorrelationReview;
+use super::drop_review::DropReview;
+use super::results_browser::ResultsBrowser;
 use super::shared::{draw_too_small_overlay, render_logo, themed, MIN_COLS, MIN_ROWS};
 use super::theme;
 use crate::pipeline::progress::{
@@ -85,6 +94,14 @@ pub struct ProgressOverlay {
     is_conversion: bool,
     /// Set to true when the user presses Q during the pipeline run.
     pub abort_requested: bool,
+    /// Open correlated-pair review; the pipeline thread waits while this is set.
+    review: Option<CorrelationReview>,
+    /// Open review of all proposed drops; the pipeline thread waits while this is set.
+    drop_review: Option<DropReview>,
+    /// Results of the finished run (`--browse-results`)
+    results: Option<ResultsBrowser>,
+    /// Whether the results browser is shown in place of the completed box
+    browsing: bool,
 }
 
 impl ProgressOverlay {
@@ -150,6 +167,10 @@ impl ProgressOverlay {
             is_sampling: false,
             is_conversion: false,
             abort_requested: false,
+            review: None,
+            drop_review: None,
+            results: None,
+            browsing: false,
         }
     }
 
@@ -191,6 +212,10 @@ impl ProgressOverlay {
             is_sampling: true,
             is_conversion: false,
             abort_requested: false,
+            review: None,
+            drop_review: None,
+            results: None,
+            browsing: false,
         }
     }
 
@@ -232,11 +257,28 @@ impl ProgressOverlay {
             is_sampling: false,
             is_conversion: true,
             abort_requested: false,
+            review: None,
+            drop_review: None,
+            results: None,
+            browsing: false,
         }
     }
 
     /// Process incoming progress events.
     pub fn handle_event(&mut self, event: ProgressEvent) {
+        if let Some(request) = event.correlation_review {
+            self.review = Some(CorrelationReview::new(request));
+            return;
+        }
+        if let Some(request) = event.drop_review {
+            self.drop_review = Some(DropReview::new(request));
+            return;
+        }
+        if let Some(results) = event.results {
+            self.results = Some(ResultsBrowser::new(*results));
+            self.browsing = true;
+            return;
+        }
         if event.is_complete {
             // Stage finished — prefer the pipeline-measured elapsed time over our
             // local wall-clock to avoid race conditions when start+complete events
@@ -406,7 +448,11 @@ impl ProgressOverlay {
 
         if self.complete {
             if let Some(ref sd) = self.summary_data {
-                let total_dropped = sd.dropped_missing + sd.dropped_gini + sd.dropped_correlation;
+                let total_dropped = sd.dropped_constant
+                    + sd.dropped_identifier
+                    + sd.dropped_missing
+                    + sd.dropped_gini
+                    + sd.dropped_correlation;
                 let pct = if sd.initial_features > 0 {
                     (total_dropped as f64 / sd.initial_features as f64) * 100.0
                 } else {
@@ -453,6 +499,20 @@ impl ProgressOverlay {
                 };
 
                 let mut drop_line: Vec<Span> = vec![Span::styled("  ", Style::default())];
+                if sd.dropped_constant > 0 {
+                    drop_line.extend(drop_span(sd.dropped_constant, "constant"));
+                    drop_line.push(Span::styled(
+                        " · ",
+                        themed(Style::default().fg(theme::MUTED)),
+                    ));
+                }
+                if sd.dropped_identifier > 0 {
+                    drop_line.extend(drop_span(sd.dropped_identifier, "ID"));
+                    drop_line.push(Span::styled(
+                        " · ",
+                        themed(Style::default().fg(theme::MUTED)),
+                    ));
+                }
                 drop_line.extend(drop_span(sd.dropped_missing, "missing"));
                 drop_line.push(Span::styled(
                     " · ",
@@ -660,13 +720,55 @@ pub fn run_progress_overlay(
                 box_width.min(area.width),
                 box_height.max(10),
             );
-            overlay.render(f, box_area);
+            if let Some(review) = &overlay.review {
+                review.render(f, box_area);
+            } else if let Some(review) = &overlay.drop_review {
+                review.render(f, box_area);
+            } else if let Some(browser) = overlay.results.as_ref().filter(|_| overlay.browsing) {
+                browser.render(f, box_area);
+            } else {
+                overlay.render(f, box_area);
+            }
 
             // Help bar
             let hint_y = y + logo_height + box_height;
             if hint_y < area.height {
                 let hint_area = Rect::new(x, hint_y, box_width.min(area.width), 1);
-                let hint = if overlay.complete {
+                let hint = if overlay.review.is_some() {
+                    Line::from(vec![
+                        Span::styled(" ↑↓ ", themed(Style::default().fg(theme::KEYS))),
+                        Span::styled("move  ", themed(Style::default().fg(theme::MUTED))),
+                        Span::styled("Space ", themed(Style::default().fg(theme::KEYS))),
+                        Span::styled("swap  ", themed(Style::default().fg(theme::MUTED))),
+                        Span::styled("Enter ", themed(Style::default().fg(theme::KEYS))),
+                        Span::styled("apply  ", themed(Style::default().fg(theme::MUTED))),
+                        Span::styled("Esc ", themed(Style::default().fg(theme::KEYS))),
+                        Span::styled("use automatic", themed(Style::default().fg(theme::MUTED))),
+                    ])
+                } else if overlay.drop_review.is_some() {
+                    Line::from(vec![
+                        Span::styled(" ↑↓ ", themed(Style::default().fg(theme::KEYS))),
+                        Span::styled("move  ", themed(Style::default().fg(theme::MUTED))),
+                        Span::styled("Space ", themed(Style::default().fg(theme::KEYS))),
+                        Span::styled("keep/drop  ", themed(Style::default().fg(theme::MUTED))),
+                        Span::styled("Enter ", themed(Style::default().fg(theme::KEYS))),
+                        Span::styled("apply  ", themed(Style::default().fg(theme::MUTED))),
+                        Span::styled("Esc ", themed(Style::default().fg(theme::KEYS))),
+                        Span::styled("drop all", themed(Style::default().fg(theme::MUTED))),
+                    ])
+                } else if overlay.browsing {
+                    ResultsBrowser::hint_line()
+                } else if overlay.complete && overlay.results.is_some() {
+                    Line::from(vec![
+                        Span::styled(" B ", themed(Style::default().fg(theme::KEYS))),
+                        Span::styled(
+                            "browse results  ",
+                            themed(Style::default().fg(theme::MUTED)),
+                        ),
+                        Span::styled("Esc ", themed(Style::default().fg(theme::KEYS))),
+                        Span::styled("exit", themed(Style::default().fg(theme::MUTED))),
+                    ])
+                } else if overlay.complete {
                     Line::from(vec![
                         Span::styled(" Esc ", themed(Style::default().fg(theme::KEYS))),
                         Span::styled("exit", themed(Style::default().fg(theme::MUTED))),
@@ -684,10 +786,34 @@ pub fn run_progress_overlay(
         // Poll for key events (short timeout to keep spinner live)
         if event::poll(Duration::from_millis(50))? {
             if let Event::Key(key) = event::read()? {
-                if overlay.complete {
+                if let Some(review) = overlay.review.as_mut() {
+                    if matches!(key.code, KeyCode::Char('q') | KeyCode::Char('Q')) {
+                        overlay.abort_requested = true;
+                        return Ok(());
+                    }
+                    if review.handle_key(key.code) {
+                        overlay.review = None;
+                    }
+                } else if let Some(review) = overlay.drop_review.as_mut() {
+                    if matches!(key.code, KeyCode::Char('q') | KeyCode::Char('Q')) {
+                        overlay.abort_requested = true;
+                        return Ok(());
+                    }
+                    if review.handle_key(key.code) {
+                        overlay.drop_review = None;
+                    }
+                } else if let Some(browser) = overlay.results.as_mut().filter(|_| overlay.browsing)
+                {
+                    if browser.handle_key(key.code) {
+                        overlay.browsing = false;
+                    }
+                } else if overlay.complete {
                     if matches!(key.code, KeyCode::Esc | KeyCode::Enter | KeyCode::Char(' ')) {
                         return Ok(());
                     }
+                    if matches!(key.code, KeyCode::Char('b') | KeyCode::Char('B')) {
+                        overlay.browsing = overlay.results.is_some();
+                    }
                 } else if matches!(
                     key.code,
                     KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc
@@ -0,0 +1,607 @@
+//! Results browser, opened after a run with `--browse-results`.
+//!
+//! In interactive mode it replaces the completed progress box; after a
+//! `--no-confirm` run in a terminal it opens on its own screen once the
+//! summary is printed. Four tabs show the features dropped by each step, the
+//! IV table (sortable by IV, Gini or name), the correlated pairs, and the
+//! bins of one feature.
+//!
+//! ```text
+//!   Dropped · [IV] · Correlations · Bins          sorted by IV
+//!
+//!     Feature                   Type      IV     Gini  Status
+//!   ▸ income                    num   0.4127   0.3310  kept
+//!     region                    cat   0.0812   0.1204  correlation
+//!     postal_code               cat   0.0031   0.0102  gini
+//! ```
+
+use std::io::Stdout;
+use std::time::Duration;
+
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Alignment, Rect},
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Terminal,
+};
+
+use super::shared::{draw_too_small_overlay, themed, MIN_COLS, MIN_ROWS};
+use super::theme;
+use crate::pipeline::progress::{ResultsFeature, RunResults};
+
+/// Feature names longer than this are truncated in the IV and bins tabs
+const NAME_WIDTH: usize = 24;
+
+/// Pair member names longer than this are truncated in the correlations tab
+const PAIR_NAME_WIDTH: usize = 20;
+
+/// Bin labels longer than this are truncated in the bins tab
+const BIN_WIDTH: usize = 22;
+
+/// Rows skipped by PageUp/PageDown
+const PAGE: usize = 10;
+
+/// The browser's tabs, in display order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultsTab {
+    Dropped,
+    Iv,
+    Correlations,
+    Bins,
+}
+
+impl ResultsTab {
+    const ALL: [ResultsTab; 4] = [
+        ResultsTab::Dropped,
+        ResultsTab::Iv,
+        ResultsTab::Correlations,
+        ResultsTab::Bins,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|&t| t == self).unwrap_or(0)
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            ResultsTab::Dropped => "Dropped",
+            ResultsTab::Iv => "IV",
+            ResultsTab::Correlations => "Correlations",
+            ResultsTab::Bins => "Bins",
+        }
+    }
+}
+
+/// Order of the IV table and of the features the bins tab steps through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IvSort {
+    /// Highest IV first
+    Iv,
+    /// Highest Gini first
+    Gini,
+    /// Alphabetical
+    Name,
+}
+
+impl IvSort {
+    fn next(self) -> Self {
+        match self {
+            IvSort::Iv => IvSort::Gini,
+            IvSort::Gini => IvSort::Name,
+            IvSort::Name => IvSort::Iv,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            IvSort::Iv => "IV",
+            IvSort::Gini => "Gini",
+            IvSort::Name => "name",
+        }
+    }
+}
+
+/// State of an open results browser.
+pub struct ResultsBrowser {
+    results: RunResults,
+    tab: ResultsTab,
+    /// Cursor of each tab, indexed by [`ResultsTab::index`]. The bins tab's
+    /// cursor is the position of its feature in the IV order.
+    selected: [usize; 4],
+    sort: IvSort,
+    /// Indices into `results.features` in the current sort order
+    order: Vec<usize>,
+}
+
+impl ResultsBrowser {
+    pub fn new(results: RunResults) -> Self {
+        let mut browser = Self {
+            order: (0..results.features.len()).collect(),
+            results,
+            tab: ResultsTab::Dropped,
+            selected: [0; 4],
+            sort: IvSort::Iv,
+        };
+        browser.sort_features();
+        browser
+    }
+
+    // Accessors only read by tests, not the binary crate
+    #[allow(dead_code)]
+    pub fn tab(&self) -> ResultsTab {
+        self.tab
+    }
+
+    #[allow(dead_code)]
+    pub fn sort(&self) -> IvSort {
+        self.sort
+    }
+
+    /// Scored features in the IV table's order
+    pub fn iv_rows(&self) -> impl Iterator<Item = &ResultsFeature> {
+        self.order.iter().map(|&i| &self.results.features[i])
+    }
+
+    /// Feature shown in the bins tab
+    pub fn bins_feature(&self) -> Option<&ResultsFeature> {
+        self.order
+            .get(self.selected[ResultsTab::Bins.index()])
+            .map(|&i| &self.results.features[i])
+    }
+
+    /// Handle a key press. Returns `true` when the user closes the browser.
+    pub fn handle_key(&mut self, code: KeyCode) -> bool {
+        let tab = self.tab.index();
+        match code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => return true,
+            KeyCode::Tab | KeyCode::Right | KeyCode::Char('l') => {
+                self.tab = ResultsTab::ALL[(tab + 1) % ResultsTab::ALL.len()];
+            }
+            KeyCode::BackTab | KeyCode::Left | KeyCode::Char('h') => {
+                self.tab =
+                    ResultsTab::ALL[(tab + ResultsTab::ALL.len() - 1) % ResultsTab::ALL.len()];
+            }
+            KeyCode::Char(c @ '1'..='4') => {
+                self.tab = ResultsTab::ALL[c as usize - '1' as usize];
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.move_cursor(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_cursor(1),
+            KeyCode::PageUp => self.move_cursor(-(PAGE as isize)),
+            KeyCode::PageDown => self.move_cursor(PAGE as isize),
+            KeyCode::Home => self.selected[tab] = 0,
+            KeyCode::End => self.selected[tab] = self.row_count().saturating_sub(1),
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                // Keep the IV and bins cursors on their features
+                let iv = self
+                    .order
+                    .get(self.selected[ResultsTab::Iv.index()])
+                    .copied();
+                let bins = self
+                    .order
+                    .get(self.selected[ResultsTab::Bins.index()])
+                    .copied();
+                self.sort = self.sort.next();
+                self.sort_features();
+                for (tab, feature) in [(ResultsTab::Iv, iv), (ResultsTab::Bins, bins)] {
+                    if let Some(pos) = feature.and_then(|f| self.order.iter().position(|&i| i == f))
+                    {
+                        self.selected[tab.index()] = pos;
+                    }
+                }
+            }
+            KeyCode::Enter if self.tab == ResultsTab::Iv => {
+                self.selected[ResultsTab::Bins.index()] = self.selected[tab];
+                self.tab = ResultsTab::Bins;
+            }
+            _ => {}
+        }
+        false
+    }
+
+    fn sort_features(&mut self) {
+        let features = &self.results.features;
+        match self.sort {
+            IvSort::Iv => self
+                .order
+                .sort_by(|&a, &b| features[b].iv.total_cmp(&features[a].iv)),
+            IvSort::Gini => self
+                .order
+                .sort_by(|&a, &b| features[b].gini.total_cmp(&features[a].gini)),
+            IvSort::Name => self
+                .order
+                .sort_by(|&a, &b| features[a].name.cmp(&features[b].name)),
+        }
+    }
+
+    /// Rows the current tab's cursor moves over
+    fn row_count(&self) -> usize {
+        match self.tab {
+            ResultsTab::Dropped => self.dropped_lines().len(),
+            ResultsTab::Iv | ResultsTab::Bins => self.order.len(),
+            ResultsTab::Correlations => self.results.correlated_pairs.len(),
+        }
+    }
+
+    fn move_cursor(&mut self, delta: isize) {
+        let last = self.row_count().saturating_sub(1);
+        let cursor = &mut self.selected[self.tab.index()];
+        *cursor = cursor.saturating_add_signed(delta).min(last);
+    }
+
+    /// Render the browser into `area`.
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(themed(Style::default().fg(theme::PRIMARY)))
+            .title(" Results ")
+            .title_style(themed(Style::default().fg(theme::PRIMARY).bold()))
+            .title_alignment(Alignment::Center);
+        let inner = block.inner(area);
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let mut lines = vec![self.tab_line(), Line::from("")];
+        let visible = (inner.height as usize).saturating_sub(lines.len()).max(1);
+        match self.tab {
+            ResultsTab::Dropped => self.dropped_tab(&mut lines, visible),
+            ResultsTab::Iv => self.iv_tab(&mut lines, visible),
+            ResultsTab::Correlations => self.correlations_tab(&mut lines, visible),
+            ResultsTab::Bins => self.bins_tab(&mut lines, visible),
+        }
+        f.render_widget(Paragraph::new(lines), inner);
+    }
+
+    /// Hint line for the help bar below the box
+    pub fn hint_line() -> Line<'static> {
+        Line::from(vec![
+            Span::styled(" ←→ ", themed(Style::default().fg(theme::KEYS))),
+            Span::styled("tab  ", themed(Style::default().fg(theme::MUTED))),
+            Span::styled("↑↓ ", themed(Style::default().fg(theme::KEYS))),
+            Span::styled("move  ", themed(Style::default().fg(theme::MUTED))),
+            Span::styled("S ", themed(Style::default().fg(theme::KEYS))),
+            Span::styled("sort  ", themed(Style::default().fg(theme::MUTED))),
+            Span::styled("Enter ", themed(Style::default().fg(theme::KEYS))),
+            Span::styled("bins  ", themed(Style::default().fg(theme::MUTED))),
+            Span::styled("Esc ", themed(Style::default().fg(theme::KEYS))),
+            Span::styled("close", themed(Style::default().fg(theme::MUTED))),
+        ])
+    }
+
+    fn tab_line(&self) -> Line<'static> {
+        let mut spans = vec![Span::raw("  ")];
+        for (i, tab) in ResultsTab::ALL.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(
+                    " · ",
+                    themed(Style::default().fg(theme::MUTED)),
+                ));
+            }
+            if *tab == self.tab {
+                spans.push(Span::styled(
+                    format!("[{}]", tab.label()),
+                    themed(Style::default().fg(theme::ACCENT).bold()),
+                ));
+            } else {
+                spans.push(Span::styled(
+                    tab.label(),
+                    themed(Style::default().fg(theme::SUBTEXT)),
+                ));
+            }
+        }
+        if matches!(self.tab, ResultsTab::Iv | ResultsTab::Bins) {
+            spans.push(Span::styled(
+                format!("    sorted by {}", self.sort.label()),
+                themed(Style::default().fg(theme::MUTED)),
+            ));
+        }
+        Line::from(spans)
+    }
+
+    /// The dropped tab as (text, is_stage_header) lines
+    fn dropped_lines(&self) -> Vec<(String, bool)> {
+        let mut lines = Vec::new();
+        for (stage, features) in &self.results.dropped_by_stage {
+            lines.push((format!("{} ({})", stage, features.len()), true));
+            lines.extend(features.iter().map(|f| (f.clone(), false)));
+        }
+        lines
+    }
+
+    fn dropped_tab(&self, lines: &mut Vec<Line>, visible: usize) {
+        let results = &self.results;
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {} features", results.initial_features),
+                themed(Style::default().fg(theme::TEXT).bold()),
+            ),
+            Span::styled(" -> ", themed(Style::default().fg(theme::MUTED))),
+            Span::styled(
+                format!("{} kept", results.final_features),
+                themed(Style::default().fg(theme::TEXT).bold()),
+            ),
+        ]));
+        let dropped = self.dropped_lines();
+        if dropped.is_empty() {
+            lines.push(muted_line("No screening step ran"));
+            return;
+        }
+        let selected = self.selected[self.tab.index()];
+        let visible = visible.saturating_sub(1).max(1);
+        for (idx, (text, is_stage)) in window(&dropped, selected, visible) {
+            let marker = if idx == selected { "  ▸ " } else { "    " };
+            let line = if *is_stage {
+                Line::from(Span::styled(
+                    format!("{}{}", marker, text),
+                    themed(Style::default().fg(theme::ACCENT).bold()),
+                ))
+            } else {
+                Line::from(vec![
+                    Span::styled(marker, themed(Style::default().fg(theme::ACCENT))),
+                    Span::styled(
+                        format!("  {}", text),
+                        themed(Style::default().fg(theme::TEXT)),
+                    ),
+                ])
+            };
+            lines.push(line);
+        }
+    }
+
+    fn iv_tab(&self, lines: &mut Vec<Line>, visible: usize) {
+        if self.order.is_empty() {
+            lines.push(muted_line("No features were scored by the Gini/IV step"));
+            return;
+        }
+        lines.push(Line::from(Span::styled(
+            format!(
+                "    {:<width$} {:<4} {:>8} {:>8}  {}",
+                "Feature",
+                "Type",
+                "IV",
+                "Gini",
+                "Status",
+                width = NAME_WIDTH
+            ),
+            themed(Style::default().fg(theme::SUBTEXT).bold()),
+        )));
+        let selected = self.selected[self.tab.index()];
+        let rows: Vec<_> = self.iv_rows().collect();
+        for (idx, feature) in window(&rows, selected, visible.saturating_sub(1).max(1)) {
+            let is_selected = idx == selected;
+            let (status, color) = status(feature);
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!(
+                        "{}{:<width$} {:<4} {:>8.4} {:>8.4}  ",
+                        if is_selected { "  ▸ " } else { "    " },
+                        truncate(&feature.name, NAME_WIDTH),
+                        short_type(&feature.feature_type),
+                        feature.iv,
+                        feature.gini,
+                        width = NAME_WIDTH
+                    ),
+                    row_style(is_selected),
+                ),
+                Span::styled(status, themed(Style::default().fg(color))),
+            ]));
+        }
+    }
+
+    fn correlations_tab(&self, lines: &mut Vec<Line>, visible: usize) {
+        let pairs = &self.results.correlated_pairs;
+        if pairs.is_empty() {
+            lines.push(muted_line("No correlated pairs above the threshold"));
+            return;
+        }
+        lines.push(Line::from(Span::styled(
+            format!(
+                "    {:<width$} {:<width$} {:>7}  {}",
+                "Feature 1",
+                "Feature 2",
+                "r",
+                "Measure",
+                width = PAIR_NAME_WIDTH
+            ),
+            themed(Style::default().fg(theme::SUBTEXT).bold()),
+        )));
+        let selected = self.selected[self.tab.index()];
+        for (idx, pair) in window(pairs, selected, visible.saturating_sub(1).max(1)) {
+            let is_selected = idx == selected;
+            // The dropped member is shown in the error colour
+            let member = |name: &str| {
+                let style = if pair.dropped.as_deref() == Some(name) {
+                    themed(Style::default().fg(theme::ERROR))
+                } else {
+                    row_style(is_selected)
+                };
+                Span::styled(
+                    format!(
+                        "{:<width$} ",
+                        truncate(name, PAIR_NAME_WIDTH),
+                        width = PAIR_NAME_WIDTH
+                    ),
+                    style,
+                )
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    if is_selected { "  ▸ " } else { "    " },
+                    row_style(is_selected),
+                ),
+                member(&pair.feature1),
+                member(&pair.feature2),
+                Span::styled(
+                    format!("{:>7.4}  {}", pair.correlation, pair.measure),
+                    row_style(is_selected),
+                ),
+            ]));
+        }
+    }
+
+    fn bins_tab(&self, lines: &mut Vec<Line>, visible: usize) {
+        let Some(feature) = self.bins_feature() else {
+            lines.push(muted_line("No features were scored by the Gini/IV step"));
+            return;
+        };
+        let (status, color) = status(feature);
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  ▸ {}", feature.name),
+                themed(Style::default().fg(theme::ACCENT).bold()),
+            ),
+            Span::styled(
+                format!(
+                    "  {} · IV {:.4} · Gini {:.4} · ",
+                    feature.feature_type, feature.iv, feature.gini
+                ),
+                themed(Style::default().fg(theme::SUBTEXT)),
+            ),
+            Span::styled(status, themed(Style::default().fg(color))),
+        ]));
+        lines.push(Line::from(Span::styled(
+            format!(
+                "    {:<width$} {:>9} {:>7} {:>8} {:>8}",
+                "Bin",
+                "Count",
+                "Rate",
+                "WoE",
+                "IV",
+                width = BIN_WIDTH
+            ),
+            themed(Style::default().fg(theme::SUBTEXT).bold()),
+        )));
+        let room = visible.saturating_sub(2).max(1);
+        let shown = if feature.bins.len() > room {
+            room.saturating_sub(1)
+        } else {
+            room
+        };
+        for bin in feature.bins.iter().take(shown) {
+            let woe_color = if bin.woe >= 0.0 {
+                theme::SUCCESS
+            } else {
+                theme::ERROR
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!(
+                        "    {:<width$} {:>9.0} {:>6.1}%",
+                        truncate(&bin.label, BIN_WIDTH),
+                        bin.count,
+                        bin.event_rate * 100.0,
+                        width = BIN_WIDTH
+                    ),
+                    themed(Style::default().fg(theme::TEXT)),
+                ),
+                Span::styled(
+                    format!(" {:>8.4}", bin.woe),
+                    themed(Style::default().fg(woe_color)),
+                ),
+                Span::styled(
+                    format!(" {:>8.4}", bin.iv_contribution),
+                    themed(Style::default().fg(theme::TEXT)),
+                ),
+            ]));
+        }
+        if feature.bins.len() > shown {
+            lines.push(muted_line(&format!(
+                "… {} more bins",
+                feature.bins.len() - shown
+            )));
+        }
+    }
+}
+
+/// Open the browser on its own screen and return when the user closes it
+/// (after a `--no-confirm` run).
+pub fn browse_results(results: RunResults) -> Result<()> {
+    let mut terminal = super::wizard::setup_terminal()?;
+    let outcome = run_results_browser(&mut terminal, ResultsBrowser::new(results));
+    super::wizard::teardown_terminal();
+    outcome
+}
+
+/// Run the browser's event loop in `terminal` until the user closes it.
+pub fn run_results_browser(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    mut browser: ResultsBrowser,
+) -> Result<()> {
+    loop {
+        let (cols, rows) = crossterm::terminal::size().unwrap_or((0, 0));
+        let too_small = cols < MIN_COLS || rows < MIN_ROWS;
+
+        terminal.draw(|f| {
+            if too_small {
+                draw_too_small_overlay(f);
+                return;
+            }
+            let area = f.area();
+            let box_width = 66u16.min(area.width);
+            let box_height = 30u16.min(area.height.saturating_sub(1));
+            let x = area.width.saturating_sub(box_width) / 2;
+            let y = area.height.saturating_sub(box_height + 1) / 2;
+            browser.render(f, Rect::new(x, y, box_width, box_height));
+            f.render_widget(
+                Paragraph::new(ResultsBrowser::hint_line()).alignment(Alignment::Center),
+                Rect::new(x, y + box_height, box_width, 1),
+            );
+        })?;
+
+        if event::poll(Duration::from_millis(100))? {
+            if let Event::Key(key) = event::read()? {
+                if browser.handle_key(key.code) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+/// The rows of `items` that fit in `visible` lines with `selected` in view,
+/// with their indices
+fn window<T>(items: &[T], selected: usize, visible: usize) -> impl Iterator<Item = (usize, &T)> {
+    let start = (selected + 1).saturating_sub(visible);
+    items.iter().enumerate().skip(start).take(visible)
+}
+
+fn status(feature: &ResultsFeature) -> (String, Color) {
+    match &feature.dropped_at {
+        Some(stage) => (stage.clone(), theme::ERROR),
+        None => ("kept".to_string(), theme::SUCCESS),
+    }
+}
+
+fn short_type(feature_type: &str) -> &'static str {
+    if feature_type == "Categorical" {
+        "cat"
+    } else {
+        "num"
+    }
+}
+
+fn row_style(is_selected: bool) -> Style {
+    if is_selected {
+        themed(Style::default().fg(theme::ACCENT).bold())
+    } else {
+        themed(Style::default().fg(theme::TEXT))
+    }
+}
+
+fn muted_line(text: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        format!("  {}", text),
+        themed(Style::default().fg(theme::MUTED)),
+    ))
+}
+
+fn truncate(name: &str, width: usize) -> String {
+    if name.chars().count() <= width {
+        name.to_string()
+    } else {
+        let head: String = name.chars().take(width - 1).collect();
+        format!("{}…", head)
+    }
+}
@@ -3,8 +3,6 @@
 //! Contains reusable rendering helpers used across both the wizard and the
 //! dashboard configuration menu.
 
-use std::sync::OnceLock;
-
 use ratatui::{
     layout::{Alignment, Rect},
     prelude::*,
@@ -12,27 +10,12 @@ use ratatui::{
 };
 
 use super::theme;
+pub use crate::utils::no_color_mode;
 
 /// Minimum terminal dimensions required to render the TUI correctly.
 pub const MIN_COLS: u16 = 80;
 pub const MIN_ROWS: u16 = 24;
 
-static NO_COLOR: OnceLock<bool> = OnceLock::new();
-
-/// Returns true when color output should be suppressed.
-///
-/// Checks for:
-/// - `NO_COLOR` env var (any value, per <https://no-color.org>)
-/// - `TERM=dumb` env var (terminals that cannot render ANSI sequences)
-///
-/// The result is cached via [`OnceLock`] so the environment is only read once.
-pub fn no_color_mode() -> bool {
-    *NO_COLOR.get_or_init(|| {
-        std::env::var("NO_COLOR").is_ok()
-            || std::env::var("TERM").map(|t| t == "dumb").unwrap_or(false)
-    })
-}
-
 /// Wrap a ratatui `Style` to strip colors in no-color mode.
 ///
 /// Pass any fully-styled `Style` value. In normal mode it is returned unchanged.
@@ -64,8 +64,11 @@ use super::shared::{
 };
 use super::theme;
 use crate::pipeline::{
-    SampleSize, SamplingConfig, SamplingMethod, StratumSpec, TargetAnalysis, TargetMapping,
+    CrossValidation, CsvDialect, EventLabels, FairnessCheck, IvStabilityCheck, ParquetOptions,
+    PcaSettings, SampleSize, SamplingConfig, SamplingMethod, ScreeningSample, SpecialValues,
+    StepPlan, StratumSpec, TargetAnalysis, TargetMapping, TimeStabilityCheck, DEFAULT_SEED,
 };
+use crate::report::{PlotSettings, TableExports};
 // ============================================================================
 // Core Result Types
 // ============================================================================
@@ -94,8 +97,12 @@ pub struct ConversionConfig {
     pub output: PathBuf,
     /// Number of rows for schema inference (0 = full scan)
     pub infer_schema_length: usize,
+    /// Worksheet of an Excel input; `None` converts the first sheet
+    pub sheet: Option<String>,
     /// Use fast in-memory conversion
     pub fast: bool,
+    /// How CSV input is read and CSV output written
+    pub csv: CsvDialect,
 }
 
 // ============================================================================
@@ -114,6 +121,72 @@ pub enum WizardTask {
     Sampling,
 }
 
+// ============================================================================
+// Data Preview
+// ============================================================================
+
+/// Rows read for the data preview step
+pub const PREVIEW_ROWS: usize = 20;
+
+/// First rows of the input and the dtypes they were read as, shown after
+/// file selection so a wrong delimiter or decimal mark is caught early
+#[derive(Debug, Clone, Default)]
+pub struct DataPreview {
+    /// (column name, dtype) in file order
+    pub columns: Vec<(String, String)>,
+    /// Cell text of each row, aligned with `columns`
+    pub rows: Vec<Vec<String>>,
+    /// Why the rows could not be read; the step then shows this instead
+    pub error: Option<String>,
+}
+
+impl DataPreview {
+    /// Preview of an already loaded frame
+    pub fn from_frame(df: &DataFrame) -> Self {
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|c| (c.name().to_string(), c.dtype().to_string()))
+            .collect();
+        let rows = (0..df.height())
+            .map(|i| {
+                df.get_columns()
+                    .iter()
+                    .map(|c| match c.get(i) {
+                        Ok(AnyValue::Null) | Err(_) => String::new(),
+                        Ok(value) => value
+                            .get_str()
+                            .map(str::to_string)
+                            .unwrap_or_else(|| value.to_string()),
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            columns,
+            rows,
+            error: None,
+        }
+    }
+
+    /// Read the first [`PREVIEW_ROWS`] rows of the wizard's input
+    fn load(data: &WizardData, path: &std::path::Path) -> Self {
+        match crate::pipeline::preview_dataset(
+            path,
+            data.infer_schema_length,
+            data.sheet.as_deref(),
+            &data.csv,
+            PREVIEW_ROWS,
+        ) {
+            Ok(df) => Self::from_frame(&df),
+            Err(e) => Self {
+                error: Some(format!("{:#}", e)),
+                ..Self::default()
+            },
+        }
+    }
+}
+
 // ============================================================================
 // Step Definitions
 // ============================================================================
@@ -125,6 +198,12 @@ pub enum WizardStep {
     /// Task selection (reduction vs conversion)
     TaskSelection,
 
+    /// First rows and dtypes of the selected file, scrolled by row and column
+    DataPreview {
+        row_offset: usize,
+        col_offset: usize,
+    },
+
     /// Target column selection with search/filter
     TargetSelection {
         search: String,
@@ -251,6 +330,7 @@ impl WizardStep {
     pub fn title(&self) -> &'static str {
         match self {
             WizardStep::TaskSelection => "Task Selection",
+            WizardStep::DataPreview { .. } => "Data Preview",
             WizardStep::TargetSelection { .. } => "Target Column",
             WizardStep::TargetMapping { .. } => "Target Mapping",
             WizardStep::MissingThreshold { .. } => "Missing Threshold",
@@ -305,18 +385,69 @@ pub struct WizardData {
     // Common fields
     pub task: Option<WizardTask>,
     pub input: Option<PathBuf>,
+    /// Worksheet of an Excel input; `None` reads the first sheet
+    pub sheet: Option<String>,
 
     // Reduction-specific fields
     pub target: Option<String>,
+    /// "binary" or "continuous"; a continuous target skips the mapping step
+    pub target_type: String,
     pub target_mapping: Option<TargetMapping>,
     pub missing_threshold: f64,
     pub gini_threshold: f64,
     pub correlation_threshold: f64,
+    pub cramers_v_threshold: Option<f64>,
+    pub correlation_strategy: String,
+    /// "iv" or "mutual_info"
+    pub univariate_metric: String,
+    pub mi_threshold: f64,
+    pub merge_insignificant_bins: Option<f64>,
+    pub special_values: Option<SpecialValues>,
+    pub missing_bin_policy: String,
+    pub cross_validation: Option<CrossValidation>,
     pub use_solver: bool,
     pub monotonicity: String,
     pub weight_column: Option<String>,
     pub columns_to_drop: Vec<String>,
+    pub keep_columns: Vec<String>,
     pub infer_schema_length: usize,
+    /// CLI-only settings carried through to the reduction config unchanged
+    pub non_finite_policy: String,
+    pub date_policy: String,
+    pub select_columns: Vec<String>,
+    pub drop_partition_columns: bool,
+    pub expect_schema: Option<PathBuf>,
+    pub fail_on_schema_drift: bool,
+    pub max_memory_gb: Option<f64>,
+    pub fail_on_solver_fallback: bool,
+    pub split_gini_export: bool,
+    pub export_woe: bool,
+    pub output_include_target_mapping: bool,
+    pub export_cart_trees: bool,
+    pub export_distributions: bool,
+    pub plots: Option<PlotSettings>,
+    pub parquet: ParquetOptions,
+    pub csv: CsvDialect,
+    pub dictionary: Option<PathBuf>,
+    pub bins_from: Option<PathBuf>,
+    pub fast: bool,
+    pub sample: Option<ScreeningSample>,
+    pub seed: u64,
+    pub review_correlations: bool,
+    pub review_drops: bool,
+    pub browse_results: bool,
+    pub resume: bool,
+    pub dry_run: bool,
+    pub table_exports: TableExports,
+    pub split_column: Option<String>,
+    pub train_value: Option<String>,
+    pub iv_stability: Option<IvStabilityCheck>,
+    pub segment_column: Option<String>,
+    pub time_stability: Option<TimeStabilityCheck>,
+    pub fairness: Option<FairnessCheck>,
+    pub pca: Option<PcaSettings>,
+    pub steps: StepPlan,
+    pub event_labels: Option<EventLabels>,
 
     // Conversion-specific fields
     pub conversion_output: Option<PathBuf>,
@@ -334,8 +465,13 @@ pub struct WizardData {
     // Temporary state for multi-step processes
     pub available_columns: Vec<String>,
     pub target_unique_values: Vec<String>,
+    /// First rows of the input, read after file selection; `None` skips the
+    /// preview step
+    pub preview: Option<DataPreview>,
     /// True when target column is already binary 0/1 (mapping step is skipped)
     pub target_is_binary: bool,
+    /// Why the target's values could not be sampled for the mapping step
+    pub target_error: Option<String>,
 }
 
 impl Default for WizardData {
@@ -343,16 +479,63 @@ impl Default for WizardData {
         Self {
             task: None,
             input: None,
+            sheet: None,
             target: None,
+            target_type: "binary".to_string(),
             target_mapping: None,
             missing_threshold: 0.30,
             gini_threshold: 0.05,
             correlation_threshold: 0.40,
+            cramers_v_threshold: None,
+            correlation_strategy: "pairwise".to_string(),
+            univariate_metric: "iv".to_string(),
+            mi_threshold: 0.001,
+            merge_insignificant_bins: None,
+            special_values: None,
+            missing_bin_policy: "separate".to_string(),
+            cross_validation: None,
             use_solver: true,
             monotonicity: "none".to_string(),
             weight_column: None,
             columns_to_drop: Vec::new(),
+            keep_columns: Vec::new(),
             infer_schema_length: 10000,
+            non_finite_policy: "treat-as-missing".to_string(),
+            date_policy: "keep".to_string(),
+            select_columns: Vec::new(),
+            drop_partition_columns: false,
+            expect_schema: None,
+            fail_on_schema_drift: false,
+            max_memory_gb: None,
+            fail_on_solver_fallback: false,
+            split_gini_export: false,
+            export_woe: false,
+            output_include_target_mapping: false,
+            export_cart_trees: false,
+            export_distributions: false,
+            plots: None,
+            parquet: ParquetOptions::default(),
+            csv: CsvDialect::default(),
+            dictionary: None,
+            bins_from: None,
+            fast: false,
+            sample: None,
+            seed: DEFAULT_SEED,
+            review_correlations: false,
+            review_drops: false,
+            browse_results: false,
+            resume: false,
+            dry_run: false,
+            table_exports: TableExports::default(),
+            split_column: None,
+            train_value: None,
+            iv_stability: None,
+            segment_column: None,
+            time_stability: None,
+            fairness: None,
+            pca: None,
+            steps: StepPlan::default(),
+            event_labels: None,
             conversion_output: None,
             conversion_fast: true,
             sampling_method: None,
@@ -363,7 +546,9 @@ impl Default for WizardData {
             sampling_strata_info: Vec::new(),
             available_columns: Vec::new(),
             target_unique_values: Vec::new(),
+            preview: None,
             target_is_binary: false,
+            target_error: None,
         }
     }
 }
@@ -432,8 +617,9 @@ impl WizardState {
                 // Initialize filtered list with all columns for target selection
                 let all_indices: Vec<usize> = (0..self.data.available_columns.len()).collect();
 
-                let mut steps = vec![
-                    WizardStep::TaskSelection,
+                let mut steps = vec![WizardStep::TaskSelection];
+                steps.extend(self.preview_step());
+                steps.extend([
                     WizardStep::TargetSelection {
                         search: String::new(),
                         filtered: all_indices.clone(),
@@ -458,7 +644,7 @@ impl WizardState {
                         error: None,
                     },
                     WizardStep::OptionalSettingsPrompt,
-                ];
+                ]);
 
                 // If user said "Yes" to optional settings, insert those steps before Summary
                 if self.optional_yes {
@@ -501,7 +687,16 @@ impl WizardState {
                 // Always show OutputFormat step for all conversion inputs
                 steps.push(WizardStep::OutputFormat { selected: 0 });
 
+                let compressed = self
+                    .data
+                    .input
+                    .as_deref()
+                    .is_some_and(crate::pipeline::is_compressed_csv);
                 match ext.as_str() {
+                    _ if compressed => {
+                        // Compressed CSV: decompressed in memory
+                        self.data.conversion_fast = true;
+                    }
                     "sas7bdat" => {
                         // SAS7BDAT: always fast (in-memory), output chosen in OutputFormat step
                         self.data.conversion_fast = true;
@@ -510,8 +705,10 @@ impl WizardState {
                         // CSV->Parquet: show conversion mode (fast vs streaming)
                         steps.push(WizardStep::ConversionMode { selected: 0 });
                     }
-                    "parquet" => {
-                        // Parquet->CSV: always fast
+                    "parquet" | "feather" | "arrow" | "ipc" | "jsonl" | "ndjson" | "xlsx"
+                    | "xlsm" | "xls" | "sav" | "dta" => {
+                        // Parquet / Arrow IPC / JSON Lines / Excel / SPSS / Stata:
+                        // always fast (in-memory)
                         self.data.conversion_fast = true;
                     }
                     _ => {
@@ -525,10 +722,9 @@ impl WizardState {
             }
             WizardTask::Sampling => {
                 let all_indices: Vec<usize> = (0..self.data.available_columns.len()).collect();
-                let mut steps = vec![
-                    WizardStep::TaskSelection,
-                    WizardStep::SamplingMethodSelection { selected: 0 },
-                ];
+                let mut steps = vec![WizardStep::TaskSelection];
+                steps.extend(self.preview_step());
+                steps.push(WizardStep::SamplingMethodSelection { selected: 0 });
 
                 let method = self
                     .data
@@ -590,10 +786,102 @@ impl WizardState {
         }
     }
 
+    /// The data preview step, when a preview was read
+    fn preview_step(&self) -> Option<WizardStep> {
+        self.data.preview.as_ref().map(|_| WizardStep::DataPreview {
+            row_offset: 0,
+            col_offset: 0,
+        })
+    }
+
+    /// Index of the step after the first one matching `is_step`, for jumping
+    /// past a step whose choice rebuilt the sequence
+    fn index_after(&self, is_step: impl Fn(&WizardStep) -> bool) -> usize {
+        self.steps
+            .iter()
+            .position(is_step)
+            .map_or(self.current_index, |i| i + 1)
+    }
+
+    /// Prepare the target mapping step from the sampled target column.
+    ///
+    /// A non-binary target fills the step with its values, preselecting a
+    /// mapping given with `--event-value`/`--non-event-value` (or chosen
+    /// before going back) when both values occur; a mapping that no longer
+    /// fits the target is cleared, as is any but a 0/1 one for a binary target. A target that cannot be read, or that has
+    /// a single value, leaves an error for the step to show instead.
+    pub fn apply_target_analysis(&mut self, analysis: Result<TargetAnalysis>) {
+        self.data.target_error = None;
+        self.data.target_unique_values = Vec::new();
+        self.data.target_is_binary = false;
+
+        let unique_values = match analysis {
+            Ok(TargetAnalysis::AlreadyBinary) => {
+                self.data.target_is_binary = true;
+                // Only a 0/1 mapping (which may swap the classes) still applies
+                let is_zero_one = self.data.target_mapping.as_ref().is_some_and(|m| {
+                    matches!(
+                        (m.event_value.as_str(), m.non_event_value.as_str()),
+                        ("0", "1") | ("1", "0")
+                    )
+                });
+                if !is_zero_one {
+                    self.data.target_mapping = None;
+                }
+                return;
+            }
+            Ok(TargetAnalysis::NeedsMapping { unique_values }) if unique_values.len() < 2 => {
+                self.data.target_error = Some(format!(
+                    "Only one value ('{}') was found in the sampled rows; \
+                     an event and a non-event value are needed",
+                    unique_values.first().map(String::as_str).unwrap_or("")
+                ));
+                self.data.target_mapping = None;
+                return;
+            }
+            Ok(TargetAnalysis::NeedsMapping { unique_values }) => unique_values,
+            Err(e) => {
+                // A mapping from the command line is still applied by the run
+                self.data.target_error = Some(format!("{:#}", e));
+                return;
+            }
+        };
+
+        let position = |value: &str| unique_values.iter().position(|v| v == value);
+        let preselected = self.data.target_mapping.as_ref().and_then(|mapping| {
+            let event = position(&mapping.event_value)?;
+            let non_event = position(&mapping.non_event_value)?;
+            // The non-event list leaves out the event value
+            (event != non_event).then(|| (event, non_event - usize::from(non_event > event)))
+        });
+        if preselected.is_none() {
+            self.data.target_mapping = None;
+        }
+        let (event, non_event) = preselected.unwrap_or((0, 0));
+
+        for step in self.steps.iter_mut() {
+            if let WizardStep::TargetMapping {
+                unique_values: values,
+                event_selected,
+                non_event_selected,
+                focus,
+            } = step
+            {
+                *values = unique_values.clone();
+                *event_selected = Some(event);
+                *non_event_selected = Some(non_event);
+                *focus = TargetMappingFocus::Event;
+            }
+        }
+        self.data.target_unique_values = unique_values;
+    }
+
     /// Set conversion output path by replacing the input file's extension
+    /// (both extensions of a compressed CSV such as `.csv.gz`)
     fn auto_generate_conversion_output(&mut self, ext: &str) {
         if let Some(input) = &self.data.input {
-            self.data.conversion_output = Some(input.with_extension(ext));
+            let stem = crate::pipeline::dataset_stem(input).unwrap_or("output");
+            self.data.conversion_output = Some(input.with_file_name(format!("{}.{}", stem, ext)));
         }
     }
 
@@ -695,22 +983,71 @@ fn run_wizard_impl(
     if let Some(input) = &cli.input {
         wizard.data.input = Some(input.clone());
     }
+    wizard.data.sheet = cli.sheet.clone();
     if let Some(target) = &cli.target {
         wizard.data.target = Some(target.clone());
     }
     if let (Some(event), Some(non_event)) = (&cli.event_value, &cli.non_event_value) {
         wizard.data.target_mapping = Some(TargetMapping::new(event.clone(), non_event.clone()));
     }
+    if let (Some(event), Some(non_event)) = (&cli.event_label, &cli.non_event_label) {
+        wizard.data.event_labels = Some(EventLabels::new(event.clone(), non_event.clone()));
+    }
     if let Some(weight) = &cli.weight_column {
         wizard.data.weight_column = Some(weight.clone());
     }
+    wizard.data.target_type = cli.target_type.clone();
     wizard.data.missing_threshold = cli.missing_threshold;
     wizard.data.gini_threshold = cli.gini_threshold;
     wizard.data.correlation_threshold = cli.correlation_threshold;
+    wizard.data.cramers_v_threshold = cli.cramers_v_threshold;
+    wizard.data.correlation_strategy = cli.correlation_strategy.clone();
+    wizard.data.univariate_metric = cli.univariate_metric.clone();
+    wizard.data.mi_threshold = cli.mi_threshold;
+    wizard.data.merge_insignificant_bins = cli.merge_insignificant_bins;
+    wizard.data.special_values = cli.special_values()?;
+    wizard.data.missing_bin_policy = cli.missing_bin_policy.clone();
+    wizard.data.cross_validation = cli.cross_validation();
     wizard.data.use_solver = cli.use_solver;
     wizard.data.monotonicity = cli.monotonicity.clone();
     wizard.data.infer_schema_length = cli.infer_schema_length;
     wizard.data.columns_to_drop = cli.drop_columns.clone();
+    wizard.data.keep_columns = cli.keep_columns.clone();
+    wizard.data.non_finite_policy = cli.non_finite_policy.clone();
+    wizard.data.date_policy = cli.date_policy.clone();
+    wizard.data.select_columns = cli.select_columns.clone();
+    wizard.data.drop_partition_columns = cli.drop_partition_columns;
+    wizard.data.expect_schema = cli.expect_schema.clone();
+    wizard.data.fail_on_schema_drift = cli.fail_on_schema_drift;
+    wizard.data.max_memory_gb = cli.max_memory_gb;
+    wizard.data.fail_on_solver_fallback = cli.fail_on_solver_fallback;
+    wizard.data.split_gini_export = cli.split_gini_export;
+    wizard.data.export_woe = cli.export_woe;
+    wizard.data.output_include_target_mapping = cli.output_include_target_mapping;
+    wizard.data.export_cart_trees = cli.export_cart_trees;
+    wizard.data.export_distributions = cli.export_distributions;
+    wizard.data.plots = cli.plot_settings();
+    wizard.data.parquet = cli.parquet_options();
+    wizard.data.csv = cli.csv_dialect()?;
+    wizard.data.dictionary = cli.dictionary.clone();
+    wizard.data.bins_from = cli.bins_from.clone();
+    wizard.data.fast = cli.fast;
+    wizard.data.sample = cli.screening_sample()?;
+    wizard.data.seed = cli.seed;
+    wizard.data.review_correlations = cli.review_correlations;
+    wizard.data.review_drops = cli.review_drops;
+    wizard.data.browse_results = cli.browse_results;
+    wizard.data.resume = cli.resume;
+    wizard.data.dry_run = cli.dry_run;
+    wizard.data.table_exports = cli.table_exports();
+    wizard.data.split_column = cli.split_column.clone();
+    wizard.data.train_value = cli.train_value.clone();
+    wizard.data.iv_stability = cli.iv_stability_check();
+    wizard.data.segment_column = cli.segment_column.clone();
+    wizard.data.time_stability = cli.time_stability_check();
+    wizard.data.fairness = cli.fairness_check();
+    wizard.data.pca = cli.pca_settings();
+    wizard.data.steps = cli.step_plan()?;
 
     // Check terminal size before entering TUI
     if let Err(msg) = check_terminal_size() {
@@ -855,6 +1192,7 @@ fn handle_step_event(wizard: &mut WizardState, key: KeyEvent) -> Result<StepActi
     let step = wizard.current_step().cloned();
     match step {
         Some(WizardStep::TaskSelection) => handle_task_selection(wizard, key),
+        Some(WizardStep::DataPreview { .. }) => handle_data_preview(wizard, key),
         Some(WizardStep::TargetSelection { .. }) => handle_target_selection(wizard, key),
         Some(WizardStep::TargetMapping { .. }) => handle_target_mapping(wizard, key),
         Some(WizardStep::MissingThreshold { .. }) => handle_missing_threshold(wizard, key),
@@ -902,15 +1240,36 @@ fn generate_result(wizard: &WizardState) -> Result<StepAction> {
 
             let config = Config {
                 input: input.clone(),
+                sheet: wizard.data.sheet.clone(),
                 target: Some(target),
+                no_target: false,
+                target_type: wizard.data.target_type.clone(),
                 output,
                 missing_threshold: wizard.data.missing_threshold,
                 gini_threshold: wizard.data.gini_threshold,
                 correlation_threshold: wizard.data.correlation_threshold,
+                cramers_v_threshold: wizard.data.cramers_v_threshold,
+                dominance_threshold: None,
+                id_threshold: None,
+                id_action: "flag".to_string(),
+                correlation_strategy: wizard.data.correlation_strategy.clone(),
+                corr_keep_policy: "higher_iv".to_string(),
+                corr_keep_prefixes: Vec::new(),
                 columns_to_drop: wizard.data.columns_to_drop.clone(),
+                select_columns: wizard.data.select_columns.clone(),
+                drop_partition_columns: wizard.data.drop_partition_columns,
+                keep_columns: wizard.data.keep_columns.clone(),
                 target_mapping: wizard.data.target_mapping.clone(),
                 weight_column: wizard.data.weight_column.clone(),
                 binning_strategy: "cart".to_string(),
+                bin_breaks: None,
+                bins_from: wizard.data.bins_from.clone(),
+                univariate_metric: wizard.data.univariate_metric.clone(),
+                mi_threshold: wizard.data.mi_threshold,
+                merge_insignificant_bins: wizard.data.merge_insignificant_bins,
+                special_values: wizard.data.special_values.clone(),
+                missing_bin_policy: wizard.data.missing_bin_policy.clone(),
+                cross_validation: wizard.data.cross_validation.clone(),
                 gini_bins: 10,
                 prebins: 20,
                 cart_min_bin_pct: 5.0,
@@ -919,7 +1278,44 @@ fn generate_result(wizard: &WizardState) -> Result<StepAction> {
                 monotonicity: wizard.data.monotonicity.clone(),
                 solver_timeout: 30,
                 solver_gap: 0.01,
+                min_bin_pct: 0.0,
+                max_bin_pct: 100.0,
+                min_woe_delta: 0.0,
+                fail_on_solver_fallback: wizard.data.fail_on_solver_fallback,
+                binning_backend: "mip".to_string(),
                 infer_schema_length: wizard.data.infer_schema_length,
+                non_finite_policy: wizard.data.non_finite_policy.clone(),
+                date_policy: wizard.data.date_policy.clone(),
+                expect_schema: wizard.data.expect_schema.clone(),
+                fail_on_schema_drift: wizard.data.fail_on_schema_drift,
+                max_memory_gb: wizard.data.max_memory_gb,
+                split_gini_export: wizard.data.split_gini_export,
+                export_woe: wizard.data.export_woe,
+                output_include_target_mapping: wizard.data.output_include_target_mapping,
+                export_cart_trees: wizard.data.export_cart_trees,
+                export_distributions: wizard.data.export_distributions,
+                plots: wizard.data.plots.clone(),
+                parquet: wizard.data.parquet,
+                csv: wizard.data.csv.clone(),
+                dictionary: wizard.data.dictionary.clone(),
+                fast: wizard.data.fast,
+                sample: wizard.data.sample.clone(),
+                seed: wizard.data.seed,
+                review_correlations: wizard.data.review_correlations,
+                review_drops: wizard.data.review_drops,
+                browse_results: wizard.data.browse_results,
+                resume: wizard.data.resume,
+                dry_run: wizard.data.dry_run,
+                table_exports: wizard.data.table_exports.clone(),
+                split_column: wizard.data.split_column.clone(),
+                event_labels: wizard.data.event_labels.clone(),
+                train_value: wizard.data.train_value.clone(),
+                iv_stability: wizard.data.iv_stability.clone(),
+                segment_column: wizard.data.segment_column.clone(),
+                time_stability: wizard.data.time_stability.clone(),
+                fairness: wizard.data.fairness.clone(),
+                pca: wizard.data.pca.clone(),
+                steps: wizard.data.steps.clone(),
             };
 
             Ok(StepAction::Complete(WizardResult::RunReduction(Box::new(
@@ -942,7 +1338,9 @@ fn generate_result(wizard: &WizardState) -> Result<StepAction> {
                 input,
                 output,
                 infer_schema_length: 0, // Full scan for conversion
+                sheet: wizard.data.sheet.clone(),
                 fast: wizard.data.conversion_fast,
+                csv: wizard.data.csv.clone(),
             };
 
             Ok(StepAction::Complete(WizardResult::RunConversion(Box::new(
@@ -970,8 +1368,10 @@ fn generate_result(wizard: &WizardState) -> Result<StepAction> {
                 strata_column: wizard.data.sampling_strata_column.clone(),
                 sample_size: wizard.data.sampling_size.clone(),
                 strata_specs: wizard.data.sampling_strata_specs.clone(),
+                weight_column: None,
                 seed: wizard.data.sampling_seed,
                 infer_schema_length: wizard.data.infer_schema_length,
+                sheet: wizard.data.sheet.clone(),
             };
 
             Ok(StepAction::Complete(WizardResult::RunSampling(Box::new(
@@ -984,21 +1384,30 @@ fn generate_result(wizard: &WizardState) -> Result<StepAction> {
 
 /// Generate output path with suffix
 fn generate_output_path(input: &std::path::Path, suffix: &str) -> Result<PathBuf> {
-    let stem = input
-        .file_stem()
+    let stem = crate::pipeline::dataset_stem(input)
         .ok_or_else(|| anyhow::anyhow!("Invalid input filename"))?;
     let extension = input
         .extension()
         .ok_or_else(|| anyhow::anyhow!("Input file has no extension"))?;
 
-    let output_name = format!("{}{}", stem.to_string_lossy(), suffix);
+    let output_name = format!("{}{}", stem, suffix);
 
     let mut output = input
         .parent()
         .map(|p| p.to_path_buf())
         .unwrap_or_else(|| PathBuf::from("."));
     output.push(output_name);
-    output.set_extension(extension);
+    // Workbooks, SPSS and Stata files cannot be written back; reduced and
+    // sampled data go to Parquet
+    let read_only = extension.eq_ignore_ascii_case("sav") || extension.eq_ignore_ascii_case("dta");
+    if crate::pipeline::is_excel_path(input) || read_only {
+        output.set_extension("parquet");
+    } else if crate::pipeline::is_compressed_csv(input) {
+        // Compressed CSV is written back uncompressed
+        output.set_extension("csv");
+    } else {
+        output.set_extension(extension);
+    }
 
     Ok(output)
 }
@@ -1028,6 +1437,7 @@ fn step_color(step: &WizardStep) -> Color {
         | WizardStep::WeightColumn { .. }
         | WizardStep::Summary => theme::SUCCESS,
         WizardStep::TaskSelection
+        | WizardStep::DataPreview { .. }
         | WizardStep::OptionalSettingsPrompt
         | WizardStep::OutputFormat { .. }
         | WizardStep::ConversionMode { .. }
@@ -1132,6 +1542,12 @@ fn render_wizard(f: &mut Frame, wizard: &WizardState) {
                 None
             }
         }
+        Some(WizardStep::DataPreview { col_offset, .. }) => wizard
+            .data
+            .preview
+            .as_ref()
+            .filter(|p| !p.columns.is_empty())
+            .map(|p| format!(" {}/{} columns ", col_offset + 1, p.columns.len())),
         Some(WizardStep::OutputFormat { selected }) => {
             let total = output_format_options(wizard.data.input.as_deref()).len();
             Some(format!(" {}/{} formats ", selected + 1, total))
@@ -1215,6 +1631,7 @@ fn render_step(f: &mut Frame, area: Rect, wizard: &WizardState) {
     // Dispatch to step-specific renderer
     match step {
         WizardStep::TaskSelection => render_task_selection(f, area, wizard),
+        WizardStep::DataPreview { .. } => render_data_preview(f, area, wizard),
         WizardStep::TargetSelection { .. } => render_target_selection(f, area, wizard),
         WizardStep::TargetMapping { .. } => render_target_mapping(f, area, wizard),
         WizardStep::MissingThreshold { .. } => render_missing_threshold(f, area, wizard),
@@ -1261,6 +1678,7 @@ fn render_help_bar(f: &mut Frame, area: Rect, wizard: &WizardState) {
             | Some(WizardStep::StrataColumnSelection { .. })
     );
     let is_stratum_config = matches!(step, Some(WizardStep::StratumSizeConfig { .. }));
+    let is_preview = matches!(step, Some(WizardStep::DataPreview { .. }));
     let is_target_mapping_non_binary = matches!(step, Some(WizardStep::TargetMapping { .. }))
         && !wizard.data.target_is_binary
         && !wizard.data.target_unique_values.is_empty();
@@ -1322,6 +1740,11 @@ fn render_help_bar(f: &mut Frame, area: Rect, wizard: &WizardState) {
             spans.push(Span::styled(" navigate  ", desc_style));
         }
 
+        if is_preview {
+            spans.push(Span::styled("↑/↓/←/→", key_style));
+            spans.push(Span::styled(" scroll  ", desc_style));
+        }
+
         if is_stratum_config {
             spans.push(Span::styled("Type", key_style));
             spans.push(Span::styled(" digits  ", desc_style));
@@ -1427,7 +1850,7 @@ fn render_threshold_content(
 fn render_task_selection(f: &mut Frame, area: Rect, wizard: &WizardState) {
     let options = [
         "Reduce features",
-        "Convert format (csv, parquet, sas7bdat)",
+        "Convert format (csv, parquet, feather, jsonl, sas7bdat, xlsx, sav, dta)",
         "Sample dataset",
     ];
     let color = theme::PRIMARY;
@@ -1465,6 +1888,105 @@ fn render_task_selection(f: &mut Frame, area: Rect, wizard: &WizardState) {
     f.render_stateful_widget(list, chunks[1], &mut list_state);
 }
 
+/// Width of a data preview column, including the gap after it
+const PREVIEW_COL_WIDTH: usize = 15;
+
+/// Data rows the preview box has room for
+const PREVIEW_VISIBLE_ROWS: usize = 15;
+
+fn render_data_preview(f: &mut Frame, area: Rect, wizard: &WizardState) {
+    let (row_offset, col_offset) = match wizard.current_step() {
+        Some(WizardStep::DataPreview {
+            row_offset,
+            col_offset,
+        }) => (*row_offset, *col_offset),
+        _ => return,
+    };
+    let Some(preview) = &wizard.data.preview else {
+        return;
+    };
+    let color = theme::PRIMARY;
+
+    let mut lines = vec![Line::from("")];
+    if let Some(error) = &preview.error {
+        lines.push(Line::from(Span::styled(
+            "  The file could not be read:",
+            Style::default().fg(theme::SUBTEXT).bold(),
+        )));
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("  {}", error),
+            Style::default().fg(theme::ERROR),
+        )));
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "  Check the CSV separator and decimal options, or go back",
+            Style::default().fg(theme::MUTED),
+        )));
+        let paragraph = Paragraph::new(lines).wrap(ratatui::widgets::Wrap { trim: false });
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    lines.push(Line::from(Span::styled(
+        format!(
+            "  First {} rows \u{00b7} check columns and types were read right",
+            preview.rows.len()
+        ),
+        Style::default().fg(theme::SUBTEXT).bold(),
+    )));
+    lines.push(Line::from(""));
+
+    // Row numbers take 4 columns; the rest is split into fixed-width columns
+    let visible_cols = (area.width as usize).saturating_sub(4) / PREVIEW_COL_WIDTH;
+    let shown: Vec<usize> = (col_offset..preview.columns.len())
+        .take(visible_cols.max(1))
+        .collect();
+    let cell = |text: &str| {
+        let width = PREVIEW_COL_WIDTH - 1;
+        if text.chars().count() > width {
+            let cut: String = text.chars().take(width - 1).collect();
+            format!("{}\u{2026} ", cut)
+        } else {
+            format!("{:<width$} ", text)
+        }
+    };
+
+    let mut header = vec![Span::raw("    ")];
+    let mut dtypes = vec![Span::raw("    ")];
+    for &c in &shown {
+        let (name, dtype) = &preview.columns[c];
+        header.push(Span::styled(
+            cell(name),
+            themed(Style::default().fg(color)).bold(),
+        ));
+        dtypes.push(Span::styled(cell(dtype), Style::default().fg(theme::MUTED)));
+    }
+    lines.push(Line::from(header));
+    lines.push(Line::from(dtypes));
+
+    let room = (area.height as usize).saturating_sub(lines.len());
+    for (i, row) in preview
+        .rows
+        .iter()
+        .enumerate()
+        .skip(row_offset)
+        .take(room.min(PREVIEW_VISIBLE_ROWS))
+    {
+        let mut spans = vec![Span::styled(
+            format!("{:>3} ", i + 1),
+            Style::default().fg(theme::MUTED),
+        )];
+        for &c in &shown {
+            let text = row.get(c).map(String::as_str).unwrap_or("");
+            spans.push(Span::styled(cell(text), Style::default().fg(theme::TEXT)));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    f.render_widget(Paragraph::new(lines), area);
+}
+
 fn render_target_selection(f: &mut Frame, area: Rect, wizard: &WizardState) {
     let (search, filtered, selected) = match wizard.current_step() {
         Some(WizardStep::TargetSelection {
@@ -1533,14 +2055,45 @@ fn render_target_selection(f: &mut Frame, area: Rect, wizard: &WizardState) {
 fn render_target_mapping(f: &mut Frame, area: Rect, wizard: &WizardState) {
     let color = theme::ACCENT;
 
+    if let Some(error) = &wizard.data.target_error {
+        let content = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                "  The target values could not be sampled:",
+                Style::default().fg(theme::SUBTEXT).bold(),
+            )),
+            Line::from(""),
+            Line::from(Span::styled(
+                format!("  {}", error),
+                Style::default().fg(theme::ERROR),
+            )),
+            Line::from(""),
+            Line::from(Span::styled(
+                match &wizard.data.target_mapping {
+                    Some(_) => "  The mapping given on the command line is used as is",
+                    None => "  Go back for another target, or map it once the file is loaded",
+                },
+                Style::default().fg(theme::MUTED),
+            )),
+        ];
+        let paragraph = Paragraph::new(content).wrap(ratatui::widgets::Wrap { trim: false });
+        f.render_widget(paragraph, area);
+        return;
+    }
+
     // Binary target: no mapping needed
     if wizard.data.target_is_binary || wizard.data.target_unique_values.is_empty() {
+        let message = if wizard.data.target_type == "continuous" {
+            "  Continuous target - no mapping required"
+        } else {
+            "  Binary target detected - no mapping required"
+        };
         let content = vec![
             Line::from(""),
             Line::from(""),
             Line::from(""),
             Line::from(Span::styled(
-                "  Binary target detected - no mapping required",
+                message,
                 themed(Style::default().fg(color).bold()),
             )),
             Line::from(""),
@@ -2316,9 +2869,11 @@ fn output_format_options(input: Option<&std::path::Path>) -> Vec<&'static str> {
         .to_lowercase();
 
     match ext.as_str() {
-        "csv" => vec!["Parquet (.parquet)"],
-        "parquet" => vec!["CSV (.csv)"],
-        _ => vec!["Parquet (.parquet)", "CSV (.csv)"], // SAS7BDAT and unknown
+        "csv" => vec!["Parquet (.parquet)", "Feather (.feather)"],
+        "parquet" => vec!["CSV (.csv)", "Feather (.feather)"],
+        "feather" | "arrow" | "ipc" => vec!["Parquet (.parquet)", "CSV (.csv)"],
+        // SAS7BDAT, JSON Lines, Excel, SPSS, Stata and unknown
+        _ => vec!["Parquet (.parquet)", "CSV (.csv)", "Feather (.feather)"],
     }
 }
 
@@ -2326,6 +2881,8 @@ fn output_format_options(input: Option<&std::path::Path>) -> Vec<&'static str> {
 fn output_format_extension(option: &str) -> &str {
     if option.contains("Parquet") {
         "parquet"
+    } else if option.contains("Feather") {
+        "feather"
     } else {
         "csv"
     }
@@ -2426,6 +2983,8 @@ fn render_conversion_mode(f: &mut Frame, area: Rect, wizard: &WizardState) {
 /// fast even on very large / wide files (e.g. 4 GB, 5 000+ columns).
 fn load_target_column_for_analysis(
     path: &std::path::Path,
+    sheet: Option<&str>,
+    csv: &CsvDialect,
     target_col: &str,
 ) -> Result<polars::prelude::DataFrame> {
     let extension = path
@@ -2438,7 +2997,13 @@ fn load_target_column_for_analysis(
     const SAMPLE_ROWS: u32 = 10_000;
 
     let df = match extension.as_str() {
-        "csv" => LazyCsvReader::new(path)
+        // Compressed CSV has to be decompressed whole
+        _ if crate::pipeline::is_compressed_csv(path) => {
+            let columns = [target_col.to_string()];
+            crate::pipeline::load_dataset_columns(path, 100, None, Some(&columns), csv, None)?.0
+        }
+        "csv" => csv
+            .scan(path)
             .with_infer_schema_length(Some(100))
             .with_n_rows(Some(SAMPLE_ROWS as usize))
             .finish()?
@@ -2448,12 +3013,38 @@ fn load_target_column_for_analysis(
             .select([col(target_col)])
             .limit(SAMPLE_ROWS)
             .collect()?,
+        "feather" | "arrow" | "ipc" => LazyFrame::scan_ipc(path, Default::default())?
+            .select([col(target_col)])
+            .limit(SAMPLE_ROWS)
+            .collect()?,
+        "jsonl" | "ndjson" => crate::pipeline::scan_ndjson(path, 100)?
+            .select([col(target_col)])
+            .limit(SAMPLE_ROWS)
+            .collect()?,
+        #[cfg(not(feature = "sas7bdat"))]
+        "sas7bdat" => anyhow::bail!(crate::pipeline::SAS7BDAT_DISABLED),
+        #[cfg(feature = "sas7bdat")]
         "sas7bdat" => {
             // SAS7BDAT must load the full file; filter to target column after
             use crate::pipeline::sas7bdat::load_sas7bdat_silent;
             let (full_df, _, _, _) = load_sas7bdat_silent(path)?;
             full_df.select([target_col])?
         }
+        #[cfg(not(feature = "xlsx"))]
+        "xlsx" | "xlsm" | "xls" => {
+            let _ = sheet;
+            anyhow::bail!(crate::pipeline::XLSX_DISABLED)
+        }
+        #[cfg(feature = "xlsx")]
+        "xlsx" | "xlsm" | "xls" => {
+            // Worksheets are small enough to load whole
+            crate::pipeline::excel::load_excel(path, sheet)?.select([target_col])?
+        }
+        // SPSS and Stata files are read whole, like SAS7BDAT
+        "sav" | "dta" => {
+            let (full_df, _, _, _) = crate::pipeline::load_dataset_with_progress(path, 0)?;
+            full_df.select([target_col])?
+        }
         _ => anyhow::bail!("Unsupported file format: {}", extension),
     };
 
@@ -2496,7 +3087,16 @@ fn handle_task_selection(wizard: &mut WizardState, key: KeyEvent) -> Result<Step
 
                 match result {
                     super::config_menu::FileSelectResult::Selected(path) => {
-                        wizard.data.available_columns = crate::pipeline::get_column_names(&path)?;
+                        if !choose_sheet(wizard, &path)? {
+                            wizard.data.task = None;
+                            return Ok(StepAction::Stay);
+                        }
+                        wizard.data.available_columns =
+                            crate::pipeline::get_column_names_from_sheet(
+                                &path,
+                                wizard.data.sheet.as_deref(),
+                                &wizard.data.csv,
+                            )?;
                         wizard.data.input = Some(path);
                     }
                     super::config_menu::FileSelectResult::Cancelled => {
@@ -2505,11 +3105,25 @@ fn handle_task_selection(wizard: &mut WizardState, key: KeyEvent) -> Result<Step
                         return Ok(StepAction::Stay);
                     }
                 }
-            } else if let Some(input) = &wizard.data.input {
+            } else if let Some(input) = wizard.data.input.clone() {
                 // File pre-populated from CLI, just load columns
-                wizard.data.available_columns = crate::pipeline::get_column_names(input)?;
+                if !choose_sheet(wizard, &input)? {
+                    wizard.data.task = None;
+                    return Ok(StepAction::Stay);
+                }
+                wizard.data.available_columns = crate::pipeline::get_column_names_from_sheet(
+                    &input,
+                    wizard.data.sheet.as_deref(),
+                    &wizard.data.csv,
+                )?;
             }
 
+            // Conversion copies the file as-is, so only the tasks that read
+            // the data get a preview
+            wizard.data.preview = match (&wizard.data.task, &wizard.data.input) {
+                (Some(WizardTask::Conversion), _) | (_, None) => None,
+                (_, Some(input)) => Some(DataPreview::load(&wizard.data, input)),
+            };
             wizard.build_steps();
             Ok(StepAction::NextStep)
         }
@@ -2518,6 +3132,32 @@ fn handle_task_selection(wizard: &mut WizardState, key: KeyEvent) -> Result<Step
     }
 }
 
+/// Ask for the worksheet when `path` is an Excel workbook with several sheets
+/// and none was given with `--sheet`. Returns `false` when the user cancelled.
+fn choose_sheet(wizard: &mut WizardState, path: &std::path::Path) -> Result<bool> {
+    if wizard.data.sheet.is_some() {
+        return Ok(true);
+    }
+    let sheets = crate::pipeline::get_sheet_names(path)?;
+    if sheets.len() < 2 {
+        return Ok(true);
+    }
+
+    teardown_terminal();
+    let result = super::config_menu::run_sheet_selector(sheets)?;
+    enable_raw_mode()?;
+    stdout().execute(EnterAlternateScreen)?;
+    wizard.needs_redraw = true;
+
+    match result {
+        super::config_menu::SheetSelectResult::Selected(sheet) => {
+            wizard.data.sheet = Some(sheet);
+            Ok(true)
+        }
+        super::config_menu::SheetSelectResult::Cancelled => Ok(false),
+    }
+}
+
 fn handle_target_selection(wizard: &mut WizardState, key: KeyEvent) -> Result<StepAction> {
     // Clone the available columns to avoid borrow checker issues
     let available_columns = wizard.data.available_columns.clone();
@@ -2582,46 +3222,20 @@ fn handle_target_selection(wizard: &mut WizardState, key: KeyEvent) -> Result<St
 
                 // Analyze target to determine if mapping is needed.
                 // Load only the target column quietly (no progress bars).
-                if let Some(input_path) = &wizard.data.input.clone() {
-                    match load_target_column_for_analysis(input_path, &target_col) {
-                        Ok(df) => {
-                            match crate::pipeline::analyze_target_column(&df, &target_col) {
-                                Ok(TargetAnalysis::AlreadyBinary) => {
-                                    wizard.data.target_is_binary = true;
-                                    wizard.data.target_unique_values = Vec::new();
-                                }
-                                Ok(TargetAnalysis::NeedsMapping { unique_values }) => {
-                                    wizard.data.target_is_binary = false;
-                                    wizard.data.target_unique_values = unique_values.clone();
-                                    // Populate the TargetMapping step's unique_values
-                                    for step in wizard.steps.iter_mut() {
-                                        if let WizardStep::TargetMapping {
-                                            unique_values: ref mut uv,
-                                            ref mut event_selected,
-                                            ref mut non_event_selected,
-                                            ref mut focus,
-                                        } = step
-                                        {
-                                            *uv = unique_values.clone();
-                                            *event_selected = Some(0);
-                                            *non_event_selected = Some(0);
-                                            *focus = TargetMappingFocus::Event;
-                                        }
-                                    }
-                                }
-                                Err(_) => {
-                                    // If analysis fails, treat as needing mapping with empty list
-                                    wizard.data.target_is_binary = false;
-                                    wizard.data.target_unique_values = Vec::new();
-                                }
-                            }
-                        }
-                        Err(_) => {
-                            // On load failure, fall through — mapping step will show binary message
-                            wizard.data.target_is_binary = true;
-                            wizard.data.target_unique_values = Vec::new();
-                        }
-                    }
+                if wizard.data.target_type == "continuous" {
+                    // Regression target: values are used as-is
+                    wizard.data.target_is_binary = true;
+                    wizard.data.target_unique_values = Vec::new();
+                    wizard.data.target_error = None;
+                } else if let Some(input_path) = &wizard.data.input.clone() {
+                    let analysis = load_target_column_for_analysis(
+                        input_path,
+                        wizard.data.sheet.as_deref(),
+                        &wizard.data.csv,
+                        &target_col,
+                    )
+                    .and_then(|df| crate::pipeline::analyze_target_column(&df, &target_col));
+                    wizard.apply_target_analysis(analysis);
                 }
 
                 Ok(StepAction::NextStep)
@@ -2858,6 +3472,32 @@ fn handle_correlation_threshold(wizard: &mut WizardState, key: KeyEvent) -> Resu
     }
 }
 
+fn handle_data_preview(wizard: &mut WizardState, key: KeyEvent) -> Result<StepAction> {
+    let (rows, columns) = wizard
+        .data
+        .preview
+        .as_ref()
+        .map_or((0, 0), |p| (p.rows.len(), p.columns.len()));
+    let Some(WizardStep::DataPreview {
+        row_offset,
+        col_offset,
+    }) = wizard.current_step_mut()
+    else {
+        return Ok(StepAction::Stay);
+    };
+
+    match key.code {
+        KeyCode::Up => *row_offset = row_offset.saturating_sub(1),
+        KeyCode::Down if *row_offset + PREVIEW_VISIBLE_ROWS < rows => *row_offset += 1,
+        KeyCode::Left => *col_offset = col_offset.saturating_sub(1),
+        KeyCode::Right if *col_offset + 1 < columns => *col_offset += 1,
+        KeyCode::Enter => return Ok(StepAction::NextStep),
+        KeyCode::Backspace => return Ok(StepAction::PrevStep),
+        _ => {}
+    }
+    Ok(StepAction::Stay)
+}
+
 fn handle_optional_settings_prompt(wizard: &mut WizardState, key: KeyEvent) -> Result<StepAction> {
     match key.code {
         KeyCode::Up | KeyCode::Down => {
@@ -3546,8 +4186,8 @@ fn handle_sampling_method_selection(wizard: &mut WizardState, key: KeyEvent) ->
             };
             wizard.data.sampling_method = Some(method);
             wizard.build_steps();
-            // Move to step after SamplingMethodSelection (index 2)
-            wizard.current_index = 2;
+            wizard.current_index = wizard
+                .index_after(|step| matches!(step, WizardStep::SamplingMethodSelection { .. }));
             Ok(StepAction::Stay)
         }
         KeyCode::Backspace => Ok(StepAction::PrevStep),
@@ -3692,7 +4332,12 @@ fn handle_strata_column_selection(wizard: &mut WizardState, key: KeyEvent) -> Re
 
                 // Load strata info from the dataset
                 if let Some(input_path) = &wizard.data.input.clone() {
-                    let df = load_target_column_for_analysis(input_path, &col_name)?;
+                    let df = load_target_column_for_analysis(
+                        input_path,
+                        wizard.data.sheet.as_deref(),
+                        &wizard.data.csv,
+                        &col_name,
+                    )?;
                     let strata_info = crate::pipeline::analyze_strata(&df, &col_name)?;
                     wizard.data.sampling_strata_info = strata_info;
                 }
@@ -3700,8 +4345,9 @@ fn handle_strata_column_selection(wizard: &mut WizardState, key: KeyEvent) -> Re
                 // Rebuild steps to include StratumSizeConfig if stratified
                 wizard.build_steps();
 
-                // Navigate to the step after StrataColumnSelection (index 3)
-                wizard.current_index = 3;
+                // Navigate to the step after StrataColumnSelection
+                wizard.current_index = wizard
+                    .index_after(|step| matches!(step, WizardStep::StrataColumnSelection { .. }));
                 Ok(StepAction::Stay)
             } else {
                 Ok(StepAction::Stay)
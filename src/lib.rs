@@ -2,8 +2,14 @@
 //!
 //! A library for reducing features in datasets using
 //! missing value analysis, Gini/IV analysis, and correlation-based reduction.
+//!
+//! Use [`Reducer`] to run the full pipeline programmatically; the individual
+//! stages are available under [`pipeline`].
 
 pub mod cli;
 pub mod pipeline;
+pub mod reducer;
 pub mod report;
 pub mod utils;
+
+pub use reducer::{Reducer, ReductionResult};
@@ -3,37 +3,76 @@
 //! A command-line tool for reducing features in datasets using
 //! missing value analysis and correlation-based reduction.
 
+// Without the `tui` feature the progress-overlay (`_bg`) pipeline is unused
+#![cfg_attr(not(feature = "tui"), allow(dead_code, unused_imports))]
+
 mod cli;
 mod pipeline;
 mod report;
 mod utils;
 
+#[cfg(feature = "tui")]
+use std::io::IsTerminal;
+#[cfg(feature = "tui")]
 use std::io::Stdout;
+use std::process::ExitCode;
 use std::time::Instant;
 
 use anyhow::Result;
-use clap::Parser;
+use clap::{CommandFactory, FromArgMatches};
 use console::style;
+#[cfg(feature = "tui")]
 use ratatui::{backend::CrosstermBackend, Terminal};
 
+#[cfg(feature = "tui")]
 use cli::{
-    run_config_menu_keep_tui, run_file_selector, run_target_mapping_selector, run_wizard_keep_tui,
-    Cli, Commands, Config, ConfigResult, FileSelectResult, TargetMappingResult, WizardResult,
+    run_config_menu_keep_tui, run_file_selector, run_sheet_selector, run_target_mapping_selector,
+    run_wizard_keep_tui, Config, ConfigResult, FileSelectResult, SheetSelectResult,
+    TargetMappingResult, WizardResult,
 };
+use cli::{Cli, Commands};
 use pipeline::{
-    analyze_features_iv, analyze_features_iv_with_progress, analyze_missing_values,
-    analyze_target_column, create_progress_channel, execute_sampling, find_correlated_pairs_auto,
-    find_correlated_pairs_auto_with_progress, get_column_names, get_features_above_threshold,
-    get_low_gini_features, get_weights, load_dataset_with_progress,
-    load_dataset_with_progress_channel, select_features_to_drop, BinningStrategy,
-    ConversionSummaryData, FeatureMetadata, FeatureToDrop, MonotonicityConstraint, PipelineStage,
-    ProgressEvent, ProgressSender, SampleSize, SamplingConfig, SamplingMethod, SamplingSummaryData,
-    SolverConfig, StratumSpec, TargetAnalysis, TargetMapping,
+    analyze_cardinality, analyze_dominance, analyze_features_iv, analyze_features_iv_with_progress,
+    analyze_features_r2, analyze_features_r2_with_progress, analyze_features_with_bins,
+    analyze_missing_by_segment, analyze_missing_values, analyze_target_column, apply_date_policy,
+    apply_non_finite_policy, apply_saved_reduction, apply_tightening, apply_woe_transform,
+    assess_drift, assess_segment_iv, binning_limited_features, check_loaded_size,
+    check_target_classes, compare_iv, compute_concordance, compute_distributions,
+    compute_extreme_values, count_target_classes, create_progress_channel, create_train_mask,
+    cross_validate_iv, exclude_target_pairs, execute_sampling, export_tighten_report,
+    fast_screen_sample, fast_screen_sample_rows, find_correlated_pairs_auto,
+    find_correlated_pairs_auto_with_progress, fit_cart_trees, fold_assignments,
+    get_column_names_from_sheet, get_dominated_features_with_keep,
+    get_features_above_threshold_with_keep, get_id_like_features_with_keep, get_low_cv_iv_features,
+    get_low_r2_features_with_keep, get_low_univariate_features_with_keep, get_sheet_names,
+    get_weights, is_excel_path, load_dataset_columns, load_dataset_from_sheet,
+    load_dataset_streaming, load_dataset_with_progress, merge_insignificant_bins, plan_load,
+    plan_tightening, random_validation_mask, resolve_correlated_pairs, resolved_pair_decisions,
+    scan_ndjson, select_rows, train_partition, write_mapped_target, AssociationMeasure,
+    AssociationThresholds, BinBreaks, BinningBackend, BinningStrategy, Checkpoint, CheckpointStage,
+    ColumnProjection, ConversionSummaryData, CorrKeepPolicy, CorrelationCheckpoint,
+    CorrelationReviewItem, CorrelationReviewRequest, CorrelationStrategy, CrossValidatedIv,
+    CrossValidation, DatePolicy, DropReviewItem, DropReviewRequest, EventLabels, ExpectedSchema,
+    ExternalBins, FairnessCheck, FeatureDictionary, FeatureMetadata, FeatureToDrop, GiniCheckpoint,
+    IdAction, IvStabilityCheck, LoadMode, MissingBinPolicy, MissingCheckpoint,
+    MonotonicityConstraint, NonFiniteCounts, NonFinitePolicy, PcaSettings, PipelineStage,
+    PriorReduction, ProgressEvent, ProgressSender, ProxyScreen, SampleSize, SamplingConfig,
+    SamplingMethod, SamplingSummaryData, SavedReduction, SchemaDrift, ScreeningSample,
+    ScreeningStep, SegmentIvAnalysis, SegmentMissingAnalysis, Segments, SolverConfig, SolverStatus,
+    SpecialValues, StepPlan, StratumSpec, TargetAnalysis, TargetMapping, TargetType,
+    TemporalColumn, TightenMetric, TightenThresholds, TimePeriods, TimeStabilityCheck,
+    UnivariateMetric, DISTRIBUTION_BUCKETS, FAST_SCREEN_BINS, RAW_TARGET_SUFFIX, SOLVER_AVAILABLE,
 };
 use report::{
-    export_gini_analysis_enhanced, export_reduction_report, export_reduction_report_csv,
-    package_reduction_reports, ExportParams, ReductionReportBuilder, ReductionSummary,
-    ReportBuilderParams,
+    diff_runs, diff_table, export_cart_trees, export_gini_analysis_enhanced,
+    export_gini_analysis_split, export_imputation_plan, export_r2_analysis,
+    export_reduction_report, export_reduction_report_csv, export_reduction_report_long_csv,
+    export_run_diff, export_scoring_spec, export_segment_missing_csv, export_selected_features,
+    export_woe_transform_sql, package_reduction_reports_with, retained_features,
+    validate_report_file, DecisionLogEntry, DropStage, ExportParams, FastScreenSettings,
+    IvStabilitySection, PlotSettings, ReductionReportBuilder, ReductionSummary,
+    ReportBuilderParams, RescuedDrop, RunSnapshot, SampleSettings, TableExports, TimePeriodSummary,
+    TimeStabilitySection, TrainPartitionSettings,
 };
 use utils::{
     create_spinner, finish_with_success, print_banner, print_completion, print_config, print_count,
@@ -43,34 +82,110 @@ use utils::{
 /// Derive an output path from an input path by appending a suffix and changing the extension.
 ///
 /// For example, `derive_output_path("/data/foo.csv", "reduced", "parquet")` returns
-/// `/data/foo_reduced.parquet`.
+/// `/data/foo_reduced.parquet`. A glob or directory input is named after the
+/// folder holding its files, next to that folder.
 fn derive_output_path(input: &std::path::Path, suffix: &str, ext: &str) -> std::path::PathBuf {
+    let input = pipeline::output_anchor(input);
     let parent = input.parent().unwrap_or_else(|| std::path::Path::new("."));
-    let stem = input
-        .file_stem()
-        .and_then(|s| s.to_str())
-        .unwrap_or("output");
+    let stem = pipeline::dataset_stem(&input).unwrap_or("output");
     parent.join(format!("{}_{}.{}", stem, suffix, ext))
 }
 
+/// [`derive_output_path`] for files written locally first: the reports that
+/// are zipped and then removed, and the plots. For a cloud input they are
+/// staged in the temporary directory.
+fn derive_local_path(input: &std::path::Path, suffix: &str, ext: &str) -> std::path::PathBuf {
+    pipeline::staging_anchor(&derive_output_path(input, suffix, ext))
+}
+
+/// Path the report file names are derived from: the input, or a file of
+/// the same name in `report_dir` when batch mode collects the reports there
+fn report_anchor(config: &PipelineConfig) -> std::path::PathBuf {
+    match (&config.report_dir, config.input.file_name()) {
+        (Some(dir), Some(name)) => dir.join(name),
+        _ => config.input.clone(),
+    }
+}
+
+/// Extension of a dataset written next to `input`: the input's own format,
+/// or Parquet for formats that can only be read (SAS7BDAT, Excel, SPSS and Stata).
+/// Compressed CSV is written back as plain CSV.
+fn writable_extension(input: &std::path::Path) -> String {
+    const READ_ONLY: [&str; 3] = ["sas7bdat", "sav", "dta"];
+    let input = pipeline::output_anchor(input);
+    match input.extension().and_then(|e| e.to_str()) {
+        Some(_) if pipeline::is_compressed_csv(&input) => "csv",
+        Some(ext) if READ_ONLY.iter().any(|r| ext.eq_ignore_ascii_case(r)) => "parquet",
+        Some(_) if is_excel_path(&input) => "parquet",
+        Some(ext) => ext,
+        None => "parquet",
+    }
+    .to_string()
+}
+
 /// Configuration parameters for the reduction pipeline
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 struct PipelineConfig {
     /// Input file path
     input: std::path::PathBuf,
+    /// Worksheet of an Excel input; `None` reads the first sheet
+    sheet: Option<String>,
     /// Output file path
     output: std::path::PathBuf,
+    /// Folder for the reports instead of next to the input (`lophi batch`)
+    report_dir: Option<std::path::PathBuf>,
+    /// Empty with `no_target`
     target: String,
+    /// Reduce without a target (`--no-target`)
+    no_target: bool,
     missing_threshold: f64,
     gini_threshold: f64,
     gini_bins: usize,
     correlation_threshold: f64,
+    /// Threshold for categorical pairs; `None` reuses `correlation_threshold`
+    cramers_v_threshold: Option<f64>,
+    /// Near-constant pre-filter threshold; the pre-filter is skipped when `None`
+    dominance_threshold: Option<f64>,
+    /// Distinct-value ratio above which a column looks like an ID; the check
+    /// is skipped when `None`
+    id_threshold: Option<f64>,
+    /// "flag" or "drop"
+    id_action: String,
+    /// "pairwise" or "cluster"
+    correlation_strategy: String,
+    /// "higher_iv", "lower_missing", "first_alphabetical" or "custom_list"
+    corr_keep_policy: String,
+    corr_keep_prefixes: Vec<String>,
     columns_to_drop: Vec<String>,
+    /// Columns to load, all when empty; the target, weight and split columns are always loaded
+    select_columns: Vec<String>,
+    /// Leave out the Hive partition columns of a multi-file input
+    drop_partition_columns: bool,
+    /// Features never dropped by any step
+    keep_columns: Vec<String>,
+    /// "binary" or "continuous"
+    target_type: String,
     target_mapping: Option<TargetMapping>,
     weight_column: Option<String>,
 
     // Binning parameters
     binning_strategy: String,
+    /// Breakpoints for manual binning (`--bin-breaks`)
+    bin_breaks: Option<BinBreaks>,
+    /// Bins the Gini step evaluates instead of searching for its own (`--bins-from`)
+    bins_from: Option<ExternalBins>,
+    /// "iv" or "mutual_info"
+    univariate_metric: String,
+    /// Mutual information threshold (nats) under "mutual_info"
+    mi_threshold: f64,
+    /// Significance level for the post-binning chi-square merge
+    merge_insignificant_bins: Option<f64>,
+    /// Values of numeric features binned on their own (`--special-values`)
+    special_values: Option<SpecialValues>,
+    /// "separate", "merge_worst" or "impute"
+    missing_bin_policy: String,
+    /// K-fold cross-validated IV (`--cv-folds` / `--cv-min-iv`)
+    cross_validation: Option<CrossValidation>,
     prebins: usize,
     cart_min_bin_pct: f64,
     min_category_samples: usize,
@@ -80,13 +195,99 @@ struct PipelineConfig {
     monotonicity: String,
     solver_timeout: u64,
     solver_gap: f64,
+    min_bin_pct: f64,
+    max_bin_pct: f64,
+    min_woe_delta: f64,
+    /// Stop with exit code 4 when the solver finds no binning for a feature
+    fail_on_solver_fallback: bool,
+    /// "mip", "dp" or "greedy" (`--binning-backend`)
+    binning_backend: String,
 
     // Data handling
     infer_schema_length: usize,
+    non_finite_policy: String,
+    /// "keep", "drop" or "derive"
+    date_policy: String,
+    /// Schema file the loaded columns are compared against
+    expect_schema: Option<std::path::PathBuf>,
+    /// Fail the run when the schema drifted
+    fail_on_schema_drift: bool,
+    /// Budget for the loaded dataset in GB (`--max-memory-gb`)
+    max_memory_gb: Option<f64>,
+
+    // Reporting
+    split_gini_export: bool,
+    export_woe: bool,
+    /// Write the mapped 0/1 target, keeping the raw values as `{target}_raw`
+    output_include_target_mapping: bool,
+    export_cart_trees: bool,
+    export_distributions: bool,
+    /// WoE/event-rate charts of the top features by IV (`--plots`)
+    plots: Option<PlotSettings>,
+    /// How the Parquet outputs are written
+    parquet: pipeline::ParquetOptions,
+    /// How CSV input is read and CSV output written
+    csv: pipeline::CsvDialect,
+    /// Feature descriptions and owners for the reports (`--dictionary`)
+    dictionary: Option<FeatureDictionary>,
+    /// Fast screen: decile bins, no solver, sampled correlations
+    fast: bool,
+    /// Row sample analysed instead of the whole dataset
+    sample: Option<ScreeningSample>,
+    /// Seed of the CV folds and the random validation split (`--seed`)
+    seed: u64,
+    review_correlations: bool,
+    /// Review every proposed drop in the progress overlay before saving
+    review_drops: bool,
+    /// Open the results browser after the run (`--browse-results`)
+    browse_results: bool,
+    /// Load finished stages from `.lophi_checkpoint/` (`--resume`)
+    resume: bool,
+    /// Analyse and write the reports without saving the reduced dataset
+    dry_run: bool,
+    /// Standalone missing-ratio, IV and correlated-pair tables
+    table_exports: TableExports,
+    split_column: Option<String>,
+    train_value: Option<String>,
+    /// Train/validation IV comparison (`--iv-stability`)
+    iv_stability: Option<IvStabilityCheck>,
+    /// Column splitting the rows into segments (`--segment-column`)
+    segment_column: Option<String>,
+    /// Per-period fill rate and IV drift (`--time-column`)
+    time_stability: Option<TimeStabilityCheck>,
+    /// Proxy screen of the protected attributes (`--protected-columns`)
+    fairness: Option<FairnessCheck>,
+    /// PCA report of the retained features (`--pca`)
+    pca: Option<PcaSettings>,
+    /// Screening steps to run, in order (`--steps`, `--skip-gini`)
+    steps: StepPlan,
+    /// Class names for reports instead of 1/0
+    event_labels: Option<EventLabels>,
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {:?}", err);
+            ExitCode::from(cli::exit_code::exit_code(&err))
+        }
+    }
 }
 
-fn main() -> Result<()> {
-    let cli = Cli::parse();
+fn run() -> Result<()> {
+    let matches = Cli::command().get_matches();
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+
+    // Config file values replace defaults; explicit flags still take precedence
+    if let Some(config_path) = cli.config.clone() {
+        cli::config_file::apply_config_file(&mut cli, &matches, &config_path)?;
+    }
+
+    // The wizard and progress overlay own the terminal, so stderr logging is off there
+    let interactive =
+        cli.command.is_none() && !cli.no_confirm && !cli.quiet && cli.json_output.is_none();
+    utils::init_logging(cli.verbose, cli.quiet, interactive, cli.log_file.as_deref())?;
 
     // Handle subcommands
     if let Some(command) = &cli.command {
@@ -95,8 +296,132 @@ fn main() -> Result<()> {
                 input,
                 output,
                 infer_schema_length,
+                sheet,
                 fast,
-            } => cli::convert::run_convert(input, output.as_deref(), *infer_schema_length, *fast),
+                parquet_compression,
+                row_group_size,
+                parquet_statistics,
+                csv_separator,
+                csv_quote,
+                csv_decimal_comma,
+                csv_date_format,
+                csv_null,
+            } => cli::convert::run_convert_with(
+                input,
+                output.as_deref(),
+                *infer_schema_length,
+                sheet.as_deref(),
+                *fast,
+                &pipeline::ParquetOptions {
+                    compression: parquet_compression.parse().unwrap_or_default(),
+                    row_group_size: Some(*row_group_size),
+                    statistics: parquet_statistics.parse().unwrap_or_default(),
+                },
+                &cli::build_csv_dialect(
+                    csv_separator,
+                    csv_quote,
+                    *csv_decimal_comma,
+                    csv_date_format.as_deref(),
+                    csv_null.as_deref(),
+                )?,
+            ),
+            Commands::Apply {
+                report,
+                input,
+                output,
+                woe,
+                gini_analysis,
+                infer_schema_length,
+            } => {
+                let output_path = output.clone().unwrap_or_else(|| {
+                    derive_output_path(input, "reduced", &writable_extension(input))
+                });
+                run_apply_pipeline(
+                    report,
+                    gini_analysis.as_deref(),
+                    input,
+                    &output_path,
+                    *woe,
+                    *infer_schema_length,
+                )
+            }
+            Commands::Tighten {
+                report,
+                input,
+                output,
+                missing_threshold,
+                gini_threshold,
+                infer_schema_length,
+            } => {
+                let output_path = output.clone().unwrap_or_else(|| {
+                    derive_output_path(input, "tightened", &writable_extension(input))
+                });
+                run_tighten_pipeline(
+                    report,
+                    input,
+                    &output_path,
+                    TightenThresholds {
+                        missing: *missing_threshold,
+                        gini: *gini_threshold,
+                    },
+                    *infer_schema_length,
+                )
+            }
+            Commands::ValidateReport { report } => run_validate_report(report),
+            Commands::Diff {
+                report_a,
+                report_b,
+                output,
+                top,
+            } => {
+                let output_path = output
+                    .clone()
+                    .unwrap_or_else(|| derive_output_path(report_b, "diff", "json"));
+                run_diff(report_a, report_b, &output_path, *top)
+            }
+            Commands::Schema {
+                input,
+                output,
+                infer_schema_length,
+            } => {
+                let output_path = output
+                    .clone()
+                    .unwrap_or_else(|| derive_output_path(input, "schema", "json"));
+                run_schema_export(input, &output_path, *infer_schema_length)
+            }
+            Commands::Bench {
+                input,
+                target,
+                weight_column,
+                runs,
+                strategies,
+                backends,
+                gini_bins,
+                prebins,
+                correlation_threshold,
+                infer_schema_length,
+                sheet,
+            } => {
+                let config = cli::bench::BenchConfig {
+                    input: input.clone(),
+                    target: target.clone(),
+                    weight_column: weight_column.clone(),
+                    runs: *runs,
+                    strategies: cli::bench::parse_strategies(strategies)?,
+                    backends: cli::bench::parse_backends(backends)?,
+                    gini_bins: *gini_bins,
+                    prebins: *prebins,
+                    correlation_threshold: *correlation_threshold,
+                    infer_schema_length: *infer_schema_length,
+                    sheet: sheet.clone(),
+                };
+                cli::bench::run_bench(&config).map(|_| ())
+            }
+            Commands::InitConfig { output, force } => {
+                cli::config_file::write_config_template(output, *force)?;
+                println!("Wrote config template to {}", output.display());
+                Ok(())
+            }
             Commands::Sample {
                 input,
                 output,
@@ -105,9 +430,18 @@ fn main() -> Result<()> {
                 count,
                 fraction,
                 strata_sizes,
+                weight_column,
                 seed,
                 infer_schema_length,
+                sheet,
             } => {
+                let method = method.clone().unwrap_or_else(|| {
+                    if strata_column.is_some() {
+                        "stratified".to_string()
+                    } else {
+                        "random".to_string()
+                    }
+                });
                 let sampling_method = match method.to_lowercase().as_str() {
                     "random" => SamplingMethod::Random,
                     "stratified" => SamplingMethod::Stratified,
@@ -155,14 +489,7 @@ fn main() -> Result<()> {
                 };
 
                 let output_path = output.clone().unwrap_or_else(|| {
-                    derive_output_path(
-                        input,
-                        "sampled",
-                        input
-                            .extension()
-                            .and_then(|e| e.to_str())
-                            .unwrap_or("parquet"),
-                    )
+                    derive_output_path(input, "sampled", &writable_extension(input))
                 });
 
                 let config = SamplingConfig {
@@ -172,15 +499,49 @@ fn main() -> Result<()> {
                     strata_column: strata_column.clone(),
                     sample_size,
                     strata_specs,
+                    weight_column: weight_column.clone(),
                     seed: *seed,
                     infer_schema_length: *infer_schema_length,
+                    sheet: sheet.clone(),
                 };
 
                 run_sampling_pipeline(config)
             }
+            Commands::Batch {
+                input_dir,
+                pattern,
+                output_dir,
+                jobs,
+                summary,
+            } => {
+                let output_dir = output_dir
+                    .clone()
+                    .unwrap_or_else(|| cli::batch::default_output_dir(input_dir));
+                let summary = summary
+                    .clone()
+                    .unwrap_or_else(|| output_dir.join(cli::batch::BATCH_SUMMARY_FILE));
+                run_batch(
+                    &cli,
+                    input_dir,
+                    pattern.as_deref(),
+                    &output_dir,
+                    *jobs,
+                    &summary,
+                )
+            }
         };
     }
 
+    // --json-output: no prompts or spinners, JSON lines for orchestration tools
+    if let Some(path) = &cli.json_output {
+        return run_pipeline_json(&cli, path);
+    }
+
+    // --quiet: the background pipeline with nobody listening to its events
+    if cli.quiet {
+        return run_pipeline_quiet(&cli);
+    }
+
     // --no-confirm: pure CLI mode, existing indicatif-based output
     if cli.no_confirm {
         let Some(config) = setup_configuration_no_tui(&cli)? else {
@@ -189,17 +550,42 @@ fn main() -> Result<()> {
         return run_pipeline_no_tui(config);
     }
 
-    // Interactive mode (wizard or dashboard): keep TUI alive for progress overlay
-    let (pipeline_config, terminal_opt) = setup_configuration_interactive(&cli)?;
+    #[cfg(not(feature = "tui"))]
+    anyhow::bail!(
+        "Interactive mode is not available in this build (enable the `tui` feature). \
+         Run with --input, --target and --no-confirm instead."
+    );
+
+    // Without a terminal the wizard would fail on its first draw
+    #[cfg(feature = "tui")]
+    if !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
+        anyhow::bail!(
+            "Interactive mode needs a terminal. \
+             Run with --input, --target and --no-confirm instead."
+        );
+    }
+
+    #[cfg(feature = "tui")]
+    run_interactive(&cli)
+}
+
+/// Interactive mode (wizard or dashboard): keep TUI alive for progress overlay
+#[cfg(feature = "tui")]
+fn run_interactive(cli: &Cli) -> Result<()> {
+    let (pipeline_config, terminal_opt) = setup_configuration_interactive(cli)?;
     let Some(pipeline_config) = pipeline_config else {
         return Ok(());
     };
 
     if let Some(mut terminal) = terminal_opt {
         // TUI is still active — run pipeline with in-TUI progress overlay
-        run_pipeline_with_tui(pipeline_config, &mut terminal)?;
+        let dry_run = pipeline_config.dry_run;
+        let summary = run_pipeline_with_tui(pipeline_config, &mut terminal)?;
         // Tear down after overlay exits
         cli::wizard::teardown_terminal();
+        if dry_run {
+            summary.display_drop_candidates();
+        }
     } else {
         // Interactive setup completed but terminal was torn down (conversion path, etc.)
         run_pipeline_no_tui(pipeline_config)?;
@@ -217,6 +603,7 @@ fn main() -> Result<()> {
 fn resolve_paths(cli: &Cli) -> Result<Option<(std::path::PathBuf, std::path::PathBuf)>> {
     let input = match cli.input() {
         Some(path) => path.clone(),
+        #[cfg(feature = "tui")]
         None => {
             // Launch interactive file selector
             match run_file_selector()? {
@@ -227,43 +614,65 @@ fn resolve_paths(cli: &Cli) -> Result<Option<(std::path::PathBuf, std::path::Pat
                 }
             }
         }
+        #[cfg(not(feature = "tui"))]
+        None => anyhow::bail!("Input file is required in this build. Use -i/--input to specify."),
     };
 
-    let output_path = cli.output.clone().unwrap_or_else(|| {
-        let extension = input
-            .extension()
-            .and_then(|e| e.to_str())
-            .unwrap_or("parquet");
-        // SAS7BDAT input defaults to Parquet output (no SAS7BDAT write support)
-        let output_ext = if extension.eq_ignore_ascii_case("sas7bdat") {
-            "parquet"
-        } else {
-            extension
-        };
-        derive_output_path(&input, "reduced", output_ext)
-    });
+    let output_path = cli
+        .output
+        .clone()
+        .unwrap_or_else(|| derive_output_path(&input, "reduced", &writable_extension(&input)));
 
     Ok(Some((input, output_path)))
 }
 
 /// Convert a Config to PipelineConfig
+#[cfg(feature = "tui")]
 fn config_to_pipeline_config(cfg: Config) -> Result<Option<PipelineConfig>> {
-    let target = cfg
-        .target
-        .ok_or_else(|| anyhow::anyhow!("Target column must be selected before proceeding"))?;
+    let target = match cfg.target {
+        Some(target) => target,
+        None if cfg.no_target => String::new(),
+        None => anyhow::bail!("Target column must be selected before proceeding"),
+    };
+
+    let dictionary = load_dictionary(cfg.dictionary.as_deref(), &cfg.input)?;
 
     Ok(Some(PipelineConfig {
         input: cfg.input,
+        sheet: cfg.sheet,
         output: cfg.output,
         target,
+        no_target: cfg.no_target,
         missing_threshold: cfg.missing_threshold,
         gini_threshold: cfg.gini_threshold,
         gini_bins: cfg.gini_bins,
         correlation_threshold: cfg.correlation_threshold,
+        cramers_v_threshold: cfg.cramers_v_threshold,
+        dominance_threshold: cfg.dominance_threshold,
+        id_threshold: cfg.id_threshold,
+        id_action: cfg.id_action,
+        correlation_strategy: cfg.correlation_strategy,
+        corr_keep_policy: cfg.corr_keep_policy,
+        corr_keep_prefixes: cfg.corr_keep_prefixes,
         columns_to_drop: cfg.columns_to_drop,
+        select_columns: cfg.select_columns,
+        drop_partition_columns: cfg.drop_partition_columns,
+        keep_columns: cfg.keep_columns,
         target_mapping: cfg.target_mapping,
         weight_column: cfg.weight_column,
         binning_strategy: cfg.binning_strategy,
+        bin_breaks: cfg.bin_breaks,
+        bins_from: cfg
+            .bins_from
+            .as_deref()
+            .map(ExternalBins::load)
+            .transpose()?,
+        univariate_metric: cfg.univariate_metric,
+        mi_threshold: cfg.mi_threshold,
+        merge_insignificant_bins: cfg.merge_insignificant_bins,
+        special_values: cfg.special_values,
+        missing_bin_policy: cfg.missing_bin_policy,
+        cross_validation: cfg.cross_validation,
         prebins: cfg.prebins,
         cart_min_bin_pct: cfg.cart_min_bin_pct,
         min_category_samples: cfg.min_category_samples,
@@ -271,20 +680,67 @@ fn config_to_pipeline_config(cfg: Config) -> Result<Option<PipelineConfig>> {
         monotonicity: cfg.monotonicity,
         solver_timeout: cfg.solver_timeout,
         solver_gap: cfg.solver_gap,
+        min_bin_pct: cfg.min_bin_pct,
+        max_bin_pct: cfg.max_bin_pct,
+        min_woe_delta: cfg.min_woe_delta,
+        fail_on_solver_fallback: cfg.fail_on_solver_fallback,
+        binning_backend: cfg.binning_backend,
         infer_schema_length: cfg.infer_schema_length,
+        non_finite_policy: cfg.non_finite_policy,
+        date_policy: cfg.date_policy,
+        expect_schema: cfg.expect_schema,
+        fail_on_schema_drift: cfg.fail_on_schema_drift,
+        max_memory_gb: cfg.max_memory_gb,
+        split_gini_export: cfg.split_gini_export,
+        export_woe: cfg.export_woe,
+        output_include_target_mapping: cfg.output_include_target_mapping,
+        export_cart_trees: cfg.export_cart_trees,
+        export_distributions: cfg.export_distributions,
+        plots: cfg.plots.clone(),
+        parquet: cfg.parquet,
+        csv: cfg.csv,
+        dictionary,
+        fast: cfg.fast,
+        sample: cfg.sample,
+        seed: cfg.seed,
+        target_type: cfg.target_type.clone(),
+        review_correlations: cfg.review_correlations,
+        review_drops: cfg.review_drops,
+        browse_results: cfg.browse_results,
+        resume: cfg.resume,
+        dry_run: cfg.dry_run,
+        table_exports: cfg.table_exports,
+        split_column: cfg.split_column,
+        train_value: cfg.train_value,
+        iv_stability: cfg.iv_stability,
+        segment_column: cfg.segment_column,
+        time_stability: cfg.time_stability,
+        fairness: cfg.fairness,
+        pca: cfg.pca,
+        steps: cfg.steps,
+        event_labels: cfg.event_labels,
+        report_dir: None,
     }))
 }
 
 /// Setup configuration for `--no-confirm` mode (pure CLI, no TUI involved).
 fn setup_configuration_no_tui(cli: &Cli) -> Result<Option<PipelineConfig>> {
+    // resolve_paths() would fall back to the interactive file selector
+    if cli.input().is_none() {
+        anyhow::bail!("Input file is required when using --no-confirm. Use -i/--input to specify.");
+    }
     let Some((input, output_path)) = resolve_paths(cli)? else {
         return Ok(None);
     };
-    let target = cli.target.clone().ok_or_else(|| {
-        anyhow::anyhow!(
-            "Target column is required when using --no-confirm. Use -t/--target to specify."
-        )
-    })?;
+    let target = match &cli.target {
+        Some(_) if cli.no_target => anyhow::bail!("--target cannot be used with --no-target"),
+        Some(target) => target.clone(),
+        None if cli.no_target => String::new(),
+        None => anyhow::bail!(
+            "Target column is required when using --no-confirm. Use -t/--target to specify, \
+             or --no-target to reduce without one."
+        ),
+    };
 
     let cli_target_mapping = match (&cli.event_value, &cli.non_event_value) {
         (Some(event), Some(non_event)) => {
@@ -295,19 +751,45 @@ fn setup_configuration_no_tui(cli: &Cli) -> Result<Option<PipelineConfig>> {
         }
         (None, None) => None,
     };
+    let cli_event_labels = event_labels_from_cli(cli)?;
+    let dictionary = load_dictionary(cli.dictionary.as_deref(), &input)?;
 
     Ok(Some(PipelineConfig {
         input,
+        sheet: cli.sheet.clone(),
         output: output_path,
         target,
+        no_target: cli.no_target,
         missing_threshold: cli.missing_threshold,
         gini_threshold: cli.gini_threshold,
         gini_bins: cli.gini_bins,
         correlation_threshold: cli.correlation_threshold,
+        cramers_v_threshold: cli.cramers_v_threshold,
+        dominance_threshold: cli.dominance_threshold(),
+        id_threshold: cli.id_threshold,
+        id_action: cli.id_action.clone(),
+        correlation_strategy: cli.correlation_strategy.clone(),
+        corr_keep_policy: cli.corr_keep_policy.clone(),
+        corr_keep_prefixes: cli.corr_keep_prefixes.clone(),
         columns_to_drop: cli.drop_columns.clone(),
+        select_columns: cli.select_columns.clone(),
+        drop_partition_columns: cli.drop_partition_columns,
+        keep_columns: cli.keep_columns.clone(),
         target_mapping: cli_target_mapping,
         weight_column: cli.weight_column.clone(),
         binning_strategy: cli.binning_strategy.clone(),
+        bin_breaks: cli.bin_breaks()?,
+        bins_from: cli
+            .bins_from
+            .as_deref()
+            .map(ExternalBins::load)
+            .transpose()?,
+        univariate_metric: cli.univariate_metric.clone(),
+        mi_threshold: cli.mi_threshold,
+        merge_insignificant_bins: cli.merge_insignificant_bins,
+        special_values: cli.special_values()?,
+        missing_bin_policy: cli.missing_bin_policy.clone(),
+        cross_validation: cli.cross_validation(),
         prebins: cli.prebins,
         cart_min_bin_pct: cli.cart_min_bin_pct,
         min_category_samples: cli.min_category_samples,
@@ -315,7 +797,46 @@ fn setup_configuration_no_tui(cli: &Cli) -> Result<Option<PipelineConfig>> {
         monotonicity: cli.monotonicity.clone(),
         solver_timeout: cli.solver_timeout,
         solver_gap: cli.solver_gap,
+        min_bin_pct: cli.min_bin_pct,
+        max_bin_pct: cli.max_bin_pct,
+        min_woe_delta: cli.min_woe_delta,
+        fail_on_solver_fallback: cli.fail_on_solver_fallback,
+        binning_backend: cli.binning_backend.clone(),
         infer_schema_length: cli.infer_schema_length,
+        non_finite_policy: cli.non_finite_policy.clone(),
+        date_policy: cli.date_policy.clone(),
+        expect_schema: cli.expect_schema.clone(),
+        fail_on_schema_drift: cli.fail_on_schema_drift,
+        max_memory_gb: cli.max_memory_gb,
+        split_gini_export: cli.split_gini_export,
+        export_woe: cli.export_woe,
+        output_include_target_mapping: cli.output_include_target_mapping,
+        export_cart_trees: cli.export_cart_trees,
+        export_distributions: cli.export_distributions,
+        plots: cli.plot_settings(),
+        parquet: cli.parquet_options(),
+        csv: cli.csv_dialect()?,
+        dictionary,
+        fast: cli.fast,
+        sample: cli.screening_sample()?,
+        seed: cli.seed,
+        target_type: cli.target_type.clone(),
+        review_correlations: cli.review_correlations,
+        review_drops: cli.review_drops,
+        browse_results: cli.browse_results,
+        resume: cli.resume,
+        dry_run: cli.dry_run,
+        table_exports: cli.table_exports(),
+        split_column: cli.split_column.clone(),
+        train_value: cli.train_value.clone(),
+        iv_stability: cli.iv_stability_check(),
+        segment_column: cli.segment_column.clone(),
+        time_stability: cli.time_stability_check(),
+        fairness: cli.fairness_check(),
+        pca: cli.pca_settings(),
+        steps: cli.step_plan()?,
+        event_labels: cli_event_labels,
+        report_dir: None,
     }))
 }
 
@@ -324,6 +845,7 @@ fn setup_configuration_no_tui(cli: &Cli) -> Result<Option<PipelineConfig>> {
 /// Returns `(Option<PipelineConfig>, Option<Terminal>)`.
 /// When `Terminal` is `Some`, the TUI is still active and the caller must
 /// display the progress overlay and then call `teardown_terminal()`.
+#[cfg(feature = "tui")]
 #[allow(clippy::type_complexity)]
 fn setup_configuration_interactive(
     cli: &Cli,
@@ -340,6 +862,10 @@ fn setup_configuration_interactive(
         }
         (None, None) => None,
     };
+    let cli_event_labels = event_labels_from_cli(cli)?;
+    if cli.no_target && cli.target.is_some() {
+        anyhow::bail!("--target cannot be used with --no-target");
+    }
 
     // Branch: --manual (Dashboard)
     if cli.manual {
@@ -347,19 +873,55 @@ fn setup_configuration_interactive(
             return Ok((None, None));
         };
         let mut current_input = input;
-        let mut columns = get_column_names(&current_input)?;
+
+        // A workbook with several sheets needs one picked before columns are listed
+        let mut sheet = cli.sheet.clone();
+        if sheet.is_none() {
+            let sheets = get_sheet_names(&current_input)?;
+            if sheets.len() > 1 {
+                match run_sheet_selector(sheets)? {
+                    SheetSelectResult::Selected(name) => sheet = Some(name),
+                    SheetSelectResult::Cancelled => {
+                        println!("Cancelled by user.");
+                        return Ok((None, None));
+                    }
+                }
+            }
+        }
+        let csv = cli.csv_dialect()?;
+        let mut columns = get_column_names_from_sheet(&current_input, sheet.as_deref(), &csv)?;
 
         let mut config = Config {
             input: current_input.clone(),
+            sheet,
             target: cli.target.clone(),
+            no_target: cli.no_target,
             output: output_path,
             missing_threshold: cli.missing_threshold,
             gini_threshold: cli.gini_threshold,
             correlation_threshold: cli.correlation_threshold,
+            cramers_v_threshold: cli.cramers_v_threshold,
+            dominance_threshold: cli.dominance_threshold(),
+            id_threshold: cli.id_threshold,
+            id_action: cli.id_action.clone(),
+            correlation_strategy: cli.correlation_strategy.clone(),
+            corr_keep_policy: cli.corr_keep_policy.clone(),
+            corr_keep_prefixes: cli.corr_keep_prefixes.clone(),
             columns_to_drop: cli.drop_columns.clone(),
+            select_columns: cli.select_columns.clone(),
+            drop_partition_columns: cli.drop_partition_columns,
+            keep_columns: cli.keep_columns.clone(),
             target_mapping: cli_target_mapping,
             weight_column: cli.weight_column.clone(),
             binning_strategy: cli.binning_strategy.clone(),
+            bin_breaks: cli.bin_breaks()?,
+            bins_from: cli.bins_from.clone(),
+            univariate_metric: cli.univariate_metric.clone(),
+            mi_threshold: cli.mi_threshold,
+            merge_insignificant_bins: cli.merge_insignificant_bins,
+            special_values: cli.special_values()?,
+            missing_bin_policy: cli.missing_bin_policy.clone(),
+            cross_validation: cli.cross_validation(),
             gini_bins: cli.gini_bins,
             prebins: cli.prebins,
             cart_min_bin_pct: cli.cart_min_bin_pct,
@@ -368,7 +930,45 @@ fn setup_configuration_interactive(
             monotonicity: cli.monotonicity.clone(),
             solver_timeout: cli.solver_timeout,
             solver_gap: cli.solver_gap,
+            min_bin_pct: cli.min_bin_pct,
+            max_bin_pct: cli.max_bin_pct,
+            min_woe_delta: cli.min_woe_delta,
+            fail_on_solver_fallback: cli.fail_on_solver_fallback,
+            binning_backend: cli.binning_backend.clone(),
             infer_schema_length: cli.infer_schema_length,
+            non_finite_policy: cli.non_finite_policy.clone(),
+            date_policy: cli.date_policy.clone(),
+            expect_schema: cli.expect_schema.clone(),
+            fail_on_schema_drift: cli.fail_on_schema_drift,
+            max_memory_gb: cli.max_memory_gb,
+            split_gini_export: cli.split_gini_export,
+            export_woe: cli.export_woe,
+            output_include_target_mapping: cli.output_include_target_mapping,
+            export_cart_trees: cli.export_cart_trees,
+            export_distributions: cli.export_distributions,
+            plots: cli.plot_settings(),
+            parquet: cli.parquet_options(),
+            csv,
+            dictionary: cli.dictionary.clone(),
+            fast: cli.fast,
+            sample: cli.screening_sample()?,
+            seed: cli.seed,
+            target_type: cli.target_type.clone(),
+            review_correlations: cli.review_correlations,
+            review_drops: cli.review_drops,
+            browse_results: cli.browse_results,
+            resume: cli.resume,
+            dry_run: cli.dry_run,
+            table_exports: cli.table_exports(),
+            split_column: cli.split_column.clone(),
+            train_value: cli.train_value.clone(),
+            iv_stability: cli.iv_stability_check(),
+            segment_column: cli.segment_column.clone(),
+            time_stability: cli.time_stability_check(),
+            fairness: cli.fairness_check(),
+            pca: cli.pca_settings(),
+            steps: cli.step_plan()?,
+            event_labels: cli_event_labels.clone(),
         };
 
         loop {
@@ -380,11 +980,14 @@ fn setup_configuration_interactive(
                 (ConfigResult::Convert(boxed_cfg), _) => {
                     let cfg = *boxed_cfg;
                     // Run file format conversion (TUI is torn down at this point)
-                    cli::convert::run_convert(
+                    cli::convert::run_convert_with(
                         &cfg.input,
                         None, // Auto-generate output path
                         cfg.infer_schema_length,
+                        cfg.sheet.as_deref(),
                         true, // Use fast mode
+                        &pipeline::ParquetOptions::CONVERT,
+                        &cfg.csv,
                     )?;
 
                     // Determine the converted file's path based on input format
@@ -401,7 +1004,7 @@ fn setup_configuration_interactive(
                     };
                     let converted_path = cfg.input.with_extension(converted_ext);
                     current_input = converted_path.clone();
-                    columns = get_column_names(&current_input)?;
+                    columns = get_column_names_from_sheet(&current_input, None, &cfg.csv)?;
 
                     let new_output = {
                         let out_ext = current_input
@@ -413,15 +1016,36 @@ fn setup_configuration_interactive(
 
                     config = Config {
                         input: current_input.clone(),
+                        // The converted file holds the chosen sheet only
+                        sheet: None,
                         output: new_output,
                         target: cfg.target,
+                        no_target: cfg.no_target,
                         missing_threshold: cfg.missing_threshold,
                         gini_threshold: cfg.gini_threshold,
                         correlation_threshold: cfg.correlation_threshold,
+                        cramers_v_threshold: cfg.cramers_v_threshold,
+                        dominance_threshold: cfg.dominance_threshold,
+                        id_threshold: cfg.id_threshold,
+                        id_action: cfg.id_action,
+                        correlation_strategy: cfg.correlation_strategy,
+                        corr_keep_policy: cfg.corr_keep_policy,
+                        corr_keep_prefixes: cfg.corr_keep_prefixes,
                         columns_to_drop: cfg.columns_to_drop,
+                        select_columns: cfg.select_columns,
+                        drop_partition_columns: cfg.drop_partition_columns,
+                        keep_columns: cfg.keep_columns,
                         target_mapping: cfg.target_mapping,
                         weight_column: cfg.weight_column,
                         binning_strategy: cfg.binning_strategy,
+                        bin_breaks: cfg.bin_breaks,
+                        bins_from: cfg.bins_from,
+                        univariate_metric: cfg.univariate_metric,
+                        mi_threshold: cfg.mi_threshold,
+                        merge_insignificant_bins: cfg.merge_insignificant_bins,
+                        special_values: cfg.special_values,
+                        missing_bin_policy: cfg.missing_bin_policy,
+                        cross_validation: cfg.cross_validation,
                         gini_bins: cfg.gini_bins,
                         prebins: cfg.prebins,
                         cart_min_bin_pct: cfg.cart_min_bin_pct,
@@ -430,7 +1054,45 @@ fn setup_configuration_interactive(
                         monotonicity: cfg.monotonicity,
                         solver_timeout: cfg.solver_timeout,
                         solver_gap: cfg.solver_gap,
+                        min_bin_pct: cfg.min_bin_pct,
+                        max_bin_pct: cfg.max_bin_pct,
+                        min_woe_delta: cfg.min_woe_delta,
+                        fail_on_solver_fallback: cfg.fail_on_solver_fallback,
+                        binning_backend: cfg.binning_backend,
                         infer_schema_length: cfg.infer_schema_length,
+                        non_finite_policy: cfg.non_finite_policy,
+                        date_policy: cfg.date_policy,
+                        expect_schema: cfg.expect_schema,
+                        fail_on_schema_drift: cfg.fail_on_schema_drift,
+                        max_memory_gb: cfg.max_memory_gb,
+                        split_gini_export: cfg.split_gini_export,
+                        export_woe: cfg.export_woe,
+                        output_include_target_mapping: cfg.output_include_target_mapping,
+                        export_cart_trees: cfg.export_cart_trees,
+                        export_distributions: cfg.export_distributions,
+                        plots: cfg.plots.clone(),
+                        parquet: cfg.parquet,
+                        csv: cfg.csv,
+                        dictionary: cfg.dictionary,
+                        fast: cfg.fast,
+                        sample: cfg.sample,
+                        seed: cfg.seed,
+                        target_type: cfg.target_type.clone(),
+                        review_correlations: cfg.review_correlations,
+                        review_drops: cfg.review_drops,
+                        browse_results: cfg.browse_results,
+                        resume: cfg.resume,
+                        dry_run: cfg.dry_run,
+                        table_exports: cfg.table_exports,
+                        split_column: cfg.split_column,
+                        train_value: cfg.train_value,
+                        iv_stability: cfg.iv_stability,
+                        segment_column: cfg.segment_column,
+                        time_stability: cfg.time_stability,
+                        fairness: cfg.fairness,
+                        pca: cfg.pca,
+                        steps: cfg.steps,
+                        event_labels: cfg.event_labels,
                     };
 
                     println!("\nPress any key to continue...");
@@ -445,6 +1107,11 @@ fn setup_configuration_interactive(
     }
 
     // Default: Wizard
+    if cli.no_target {
+        anyhow::bail!(
+            "--no-target is not available in the wizard. Use --manual or --no-confirm instead."
+        );
+    }
     match run_wizard_keep_tui(cli)? {
         (WizardResult::RunReduction(boxed_cfg), terminal_opt) => {
             let cfg_opt = config_to_pipeline_config(*boxed_cfg)?;
@@ -459,6 +1126,7 @@ fn setup_configuration_interactive(
                     &conversion_config.input,
                     Some(&conversion_config.output),
                     conversion_config.infer_schema_length,
+                    conversion_config.sheet.as_deref(),
                     conversion_config.fast,
                 )?;
             }
@@ -488,10 +1156,11 @@ fn setup_configuration_interactive(
 ///
 /// Spawns the pipeline in a background thread and drives a progress overlay
 /// in the foreground event loop.
+#[cfg(feature = "tui")]
 fn run_pipeline_with_tui(
     config: PipelineConfig,
     terminal: &mut Terminal<CrosstermBackend<Stdout>>,
-) -> Result<()> {
+) -> Result<ReductionSummary> {
     let (tx, rx) = create_progress_channel();
     let config_clone = config.clone();
 
@@ -504,11 +1173,10 @@ fn run_pipeline_with_tui(
     // Collect pipeline result (propagate errors)
     handle
         .join()
-        .map_err(|_| anyhow::anyhow!("Pipeline thread panicked"))??;
-
-    Ok(())
+        .map_err(|_| anyhow::anyhow!("Pipeline thread panicked"))?
 }
 
+#[cfg(feature = "tui")]
 fn run_sampling_pipeline_with_tui(
     config: SamplingConfig,
     terminal: &mut Terminal<CrosstermBackend<Stdout>>,
@@ -529,6 +1197,7 @@ fn run_sampling_pipeline_with_tui(
     Ok(())
 }
 
+#[cfg(feature = "tui")]
 fn run_conversion_with_tui(
     config: cli::wizard::ConversionConfig,
     terminal: &mut Terminal<CrosstermBackend<Stdout>>,
@@ -547,6 +1216,7 @@ fn run_conversion_with_tui(
     Ok(())
 }
 
+#[cfg(feature = "tui")]
 fn run_conversion_bg(config: cli::wizard::ConversionConfig, tx: ProgressSender) -> Result<()> {
     use anyhow::Context;
     use polars::prelude::*;
@@ -568,12 +1238,19 @@ fn run_conversion_bg(config: cli::wizard::ConversionConfig, tx: ProgressSender)
     let input_format = match input_ext.as_str() {
         "sas7bdat" => "SAS7BDAT",
         "parquet" => "Parquet",
+        "feather" | "arrow" | "ipc" => "Arrow IPC",
+        "jsonl" | "ndjson" => "JSON Lines",
+        "xlsx" | "xlsm" | "xls" => "Excel",
+        "sav" => "SPSS",
+        "dta" => "Stata",
         "csv" => "CSV",
+        _ if pipeline::is_compressed_csv(input) => "compressed CSV",
         other => other,
     }
     .to_string();
     let output_format = match output_ext.as_str() {
         "parquet" => "Parquet",
+        "feather" | "arrow" | "ipc" => "Arrow IPC",
         "csv" => "CSV",
         other => other,
     }
@@ -588,6 +1265,20 @@ fn run_conversion_bg(config: cli::wizard::ConversionConfig, tx: ProgressSender)
 
     let stage_start = Instant::now();
     let (mut df, row_count, col_count) = match input_ext.as_str() {
+        _ if pipeline::is_compressed_csv(input) => {
+            let (df, rows, cols, _) = pipeline::load_dataset_columns(
+                input,
+                config.infer_schema_length,
+                None,
+                None,
+                &config.csv,
+                Some(&tx),
+            )?;
+            (df, rows, cols)
+        }
+        #[cfg(not(feature = "sas7bdat"))]
+        "sas7bdat" => anyhow::bail!(pipeline::SAS7BDAT_DISABLED),
+        #[cfg(feature = "sas7bdat")]
         "sas7bdat" => {
             let (df, rows, cols, _elapsed) = pipeline::sas7bdat::load_sas7bdat_silent(input)
                 .map_err(|e| anyhow::anyhow!("Failed to load SAS7BDAT: {}", e))?;
@@ -603,17 +1294,54 @@ fn run_conversion_bg(config: cli::wizard::ConversionConfig, tx: ProgressSender)
             let cols = df.width();
             (df, rows, cols)
         }
+        "feather" | "arrow" | "ipc" => {
+            let lf = LazyFrame::scan_ipc(input, Default::default())
+                .with_context(|| format!("Failed to read Arrow IPC: {}", input.display()))?;
+            let df = lf
+                .collect()
+                .with_context(|| "Failed to load Arrow IPC into memory")?;
+            let rows = df.height();
+            let cols = df.width();
+            (df, rows, cols)
+        }
+        "jsonl" | "ndjson" => {
+            let df = scan_ndjson(input, config.infer_schema_length)?
+                .collect()
+                .with_context(|| "Failed to load JSON Lines into memory")?;
+            let rows = df.height();
+            let cols = df.width();
+            (df, rows, cols)
+        }
+        #[cfg(not(feature = "xlsx"))]
+        "xlsx" | "xlsm" | "xls" => anyhow::bail!(pipeline::XLSX_DISABLED),
+        #[cfg(feature = "xlsx")]
+        "xlsx" | "xlsm" | "xls" => {
+            let df = pipeline::excel::load_excel(input, config.sheet.as_deref())?;
+            let rows = df.height();
+            let cols = df.width();
+            (df, rows, cols)
+        }
+        "sav" | "dta" => {
+            let (df, rows, cols, _) = pipeline::load_dataset_with_progress(input, 0)?;
+            (df, rows, cols)
+        }
         "csv" => {
             let schema_length = if config.infer_schema_length == 0 {
                 None
             } else {
                 Some(config.infer_schema_length)
             };
-            let lf = LazyCsvReader::new(input)
+            let lf = config
+                .csv
+                .scan(input)
                 .with_infer_schema_length(schema_length)
                 .with_rechunk(config.fast)
                 .finish()
                 .with_context(|| format!("Failed to read CSV: {}", input.display()))?;
+            let lf = config
+                .csv
+                .parse_dates_lazy(lf, schema_length)
+                .with_context(|| format!("Failed to parse CSV dates: {}", input.display()))?;
             let df = lf
                 .collect()
                 .with_context(|| "Failed to load CSV into memory")?;
@@ -643,20 +1371,26 @@ fn run_conversion_bg(config: cli::wizard::ConversionConfig, tx: ProgressSender)
         "parquet" => {
             let file = std::fs::File::create(output)
                 .with_context(|| format!("Failed to create: {}", output.display()))?;
-            ParquetWriter::new(file)
-                .with_compression(ParquetCompression::Snappy)
-                .with_statistics(StatisticsOptions::full())
-                .with_row_group_size(Some(100_000))
+            pipeline::ParquetOptions::CONVERT
+                .writer(file)
                 .finish(&mut df)
                 .with_context(|| format!("Failed to write Parquet: {}", output.display()))?;
         }
         "csv" => {
             let file = std::fs::File::create(output)
                 .with_context(|| format!("Failed to create: {}", output.display()))?;
-            CsvWriter::new(file)
-                .finish(&mut df)
+            config
+                .csv
+                .write(&mut df, file)
                 .with_context(|| format!("Failed to write CSV: {}", output.display()))?;
         }
+        "feather" | "arrow" | "ipc" => {
+            let file = std::fs::File::create(output)
+                .with_context(|| format!("Failed to create: {}", output.display()))?;
+            IpcWriter::new(file)
+                .finish(&mut df)
+                .with_context(|| format!("Failed to write Arrow IPC: {}", output.display()))?;
+        }
         _ => anyhow::bail!("Unsupported output format: .{}", output_ext),
     }
 
@@ -709,9 +1443,13 @@ fn run_conversion_bg(config: cli::wizard::ConversionConfig, tx: ProgressSender)
 
 /// Run the full reduction pipeline, sending progress events over `tx`.
 /// This is designed to run in a background thread.
-fn run_pipeline_bg(mut config: PipelineConfig, tx: ProgressSender) -> Result<()> {
+fn run_pipeline_bg(mut config: PipelineConfig, tx: ProgressSender) -> Result<ReductionSummary> {
     let input = config.input.clone();
+    let reports = report_anchor(&config);
     let output_path = config.output.clone();
+    if config.fast {
+        apply_fast_screen(&mut config);
+    }
     let pipeline_start = Instant::now();
 
     // ── Stage: Loading ────────────────────────────────────────────────────
@@ -724,10 +1462,22 @@ fn run_pipeline_bg(mut config: PipelineConfig, tx: ProgressSender) -> Result<()>
     let stage_start = Instant::now();
     let (mut df, _initial_features, mut summary) = load_and_prepare_dataset_with_tx(
         &input,
-        &config.columns_to_drop,
+        config.sheet.as_deref(),
+        &column_projection(&config),
         config.infer_schema_length,
+        &config.csv,
+        config.max_memory_gb,
         &tx,
     )?;
+    let sample = take_screening_sample(&mut df, &config)?;
+    if let Some(sample) = &sample {
+        tx.send(ProgressEvent::update(
+            PipelineStage::Loading,
+            "Sampled rows",
+            format!("{} of {}", sample.sampled_rows, sample.total_rows),
+        ))
+        .ok();
+    }
 
     tx.send(ProgressEvent::stage_complete(
         PipelineStage::Loading,
@@ -744,7 +1494,20 @@ fn run_pipeline_bg(mut config: PipelineConfig, tx: ProgressSender) -> Result<()>
     .ok();
 
     let stage_start = Instant::now();
-    let weights = validate_target_and_weights_headless(&df, &mut config)?;
+    let schema_drift = check_expected_schema(&df, &config)?;
+    if let Some(drift) = schema_drift.as_ref().filter(|d| !d.is_empty()) {
+        tx.send(ProgressEvent::update(
+            PipelineStage::Validating,
+            "Schema drift",
+            drift.to_string(),
+        ))
+        .ok();
+    }
+    let target_type = parse_target_type(&config)?;
+    let mut weights = validate_target_and_weights_headless(&df, &mut config)?;
+    let non_finite_counts = handle_non_finite_values_bg(&mut df, &config)?;
+    let (date_policy, temporal_columns) = handle_date_policy_bg(&mut df, &config, &mut summary)?;
+    check_grouping_columns(&df, &config)?;
 
     tx.send(ProgressEvent::stage_complete(
         PipelineStage::Validating,
@@ -754,10 +1517,14 @@ fn run_pipeline_bg(mut config: PipelineConfig, tx: ProgressSender) -> Result<()>
     .ok();
 
     // Parse binning strategy
-    let binning_strategy: BinningStrategy = config
+    let mut binning_strategy: BinningStrategy = config
         .binning_strategy
         .parse()
         .map_err(|e: String| anyhow::anyhow!(e))?;
+    if target_type == TargetType::Continuous {
+        // R² always uses equal-frequency bins
+        binning_strategy = BinningStrategy::Quantile;
+    }
 
     // Build report
     let mut report_builder = ReductionReportBuilder::new(ReportBuilderParams {
@@ -768,81 +1535,342 @@ fn run_pipeline_bg(mut config: PipelineConfig, tx: ProgressSender) -> Result<()>
         binning_strategy: binning_strategy.to_string(),
         num_bins: config.gini_bins,
         missing_threshold: config.missing_threshold,
-        gini_threshold: config.gini_threshold,
+        gini_threshold: univariate_threshold(&config),
         correlation_threshold: config.correlation_threshold,
     });
+    report_builder.set_target_type(&target_label(&config, target_type));
+    if let Some(labels) = &config.event_labels {
+        report_builder.set_event_labels(labels);
+    }
+    if let Some(threshold) = config.cramers_v_threshold {
+        report_builder.set_cramers_v_threshold(threshold);
+    }
+    report_builder.set_correlation_strategy(parse_correlation_strategy(&config)?);
+    report_builder.set_corr_keep_policy(&parse_corr_keep_policy(&config)?);
+    report_builder.set_non_finite_results(&non_finite_counts, &config.non_finite_policy);
+    report_builder.set_temporal_results(&temporal_columns, date_policy);
+    report_builder.set_keep_columns(&config.keep_columns);
+    report_builder.set_seed(config.seed);
+    if let Some(files) = pipeline::input_files(&input)? {
+        report_builder.set_input_files(files.names());
+    }
+    if let Some(dictionary) = &config.dictionary {
+        report_builder.set_dictionary(dictionary);
+    }
+    if let Some(drift) = schema_drift {
+        report_builder.set_schema_drift(drift);
+    }
+    if let Some(sample) = sample {
+        report_builder.set_sample(sample);
+    }
 
-    // ── Stage: Missing ────────────────────────────────────────────────────
-    tx.send(ProgressEvent::stage_start(
-        PipelineStage::MissingAnalysis,
-        "Missing value analysis",
-    ))
-    .ok();
-
-    let stage_start = Instant::now();
-    let (missing_ratios, features_to_drop_missing) =
-        run_missing_analysis_bg(&mut df, &config, &weights, &mut summary)?;
-    report_builder.set_missing_results(&missing_ratios, &features_to_drop_missing);
+    // With a split column, statistics use the training rows; drops reach all rows at save time
+    let mut full_df = None;
+    if let Some((train_df, train_weights)) =
+        train_partition_for_screening(&df, &weights, &config, &mut report_builder)?
+    {
+        full_df = Some(std::mem::replace(&mut df, train_df));
+        weights = train_weights;
+    }
+    // Kept so features the user keeps in the drop review can be written out
+    if config.review_drops && full_df.is_none() {
+        full_df = Some(df.clone());
+    }
+    if config.fast {
+        report_builder.set_fast_screen(fast_screen_settings(df.height()));
+    }
+    if target_type == TargetType::Binary && !config.no_target {
+        check_target_classes(&df, &config.target, config.target_mapping.as_ref())?;
+    }
+    let checkpoint = open_checkpoint(&config)?;
 
-    tx.send(ProgressEvent::stage_complete(
-        PipelineStage::MissingAnalysis,
-        "Missing value analysis complete",
-        stage_start.elapsed(),
-    ))
-    .ok();
+    // Cheap enough to rerun on resume; the missing checkpoint follows it
+    run_dominance_filter_bg(
+        &mut df,
+        &config,
+        &weights,
+        &mut summary,
+        &mut report_builder,
+    )?;
+    run_id_check_bg(&mut df, &config, &mut summary, &mut report_builder)?;
+    report_builder.set_step_plan(&config.steps);
+    if config.steps.steps()[0] != ScreeningStep::Missing {
+        report_builder.set_screened_features(&screened_features(&df, &config));
+    }
+    for step in config.steps.skipped() {
+        tx.send(ProgressEvent::stage_complete(
+            progress_stage(step),
+            format!("Skipped {} step (--steps)", step),
+            std::time::Duration::ZERO,
+        ))
+        .ok();
+    }
 
-    // ── Stage: Gini/IV ────────────────────────────────────────────────────
-    tx.send(ProgressEvent::stage_start(
-        PipelineStage::GiniAnalysis,
-        "Gini/IV analysis",
-    ))
-    .ok();
+    let mut missing_ratios = Vec::new();
+    let mut gini_analyses = Vec::new();
+    let mut r2_analyses = Vec::new();
+    let mut correlated_pairs = Vec::new();
+    for &step in config.steps.steps() {
+        match step {
+            ScreeningStep::Missing => {
+                // ── Stage: Missing ─────────────────────────────────────────
+                tx.send(ProgressEvent::stage_start(
+                    PipelineStage::MissingAnalysis,
+                    "Missing value analysis",
+                ))
+                .ok();
+
+                let stage_start = Instant::now();
+                let (ratios, features_to_drop_missing, segment_missing) =
+                    match checkpoint.load::<MissingCheckpoint>(CheckpointStage::Missing) {
+                        Some(stage) => resume_missing_stage(&mut df, stage, &mut summary),
+                        None => {
+                            let segments = analyze_segment_missing(&df, &config, &weights)?;
+                            let (missing_ratios, dropped) =
+                                run_missing_analysis_bg(&mut df, &config, &weights, &mut summary)?;
+                            let stage = MissingCheckpoint {
+                                missing_ratios,
+                                dropped,
+                                segments,
+                            };
+                            checkpoint.save(CheckpointStage::Missing, &stage)?;
+                            (stage.missing_ratios, stage.dropped, stage.segments)
+                        }
+                    };
+                report_builder.set_missing_results(&ratios, &features_to_drop_missing);
+                missing_ratios = ratios;
+                if let Some(segments) = segment_missing {
+                    tx.send(ProgressEvent::update(
+                        PipelineStage::MissingAnalysis,
+                        "Fully missing in a segment",
+                        format!(
+                            "{} of {} features",
+                            segments.flagged().len(),
+                            segments.features.len()
+                        ),
+                    ))
+                    .ok();
+                    report_builder.set_segment_missing(segments);
+                }
 
-    let stage_start = Instant::now();
-    let (gini_analyses, features_to_drop_gini) =
-        run_gini_analysis_bg(&df, &config, &input, &weights, &mut summary, &tx)?;
-    report_builder.set_gini_results(&gini_analyses, &features_to_drop_gini);
+                tx.send(ProgressEvent::stage_complete(
+                    PipelineStage::MissingAnalysis,
+                    "Missing value analysis complete",
+                    stage_start.elapsed(),
+                ))
+                .ok();
+            }
+            ScreeningStep::Gini => {
+                // ── Stage: Gini/IV (R² for a continuous target) ────────────
+                let stage_label = match target_type {
+                    TargetType::Binary => "Gini/IV analysis",
+                    TargetType::Continuous => "R² analysis",
+                };
+                tx.send(ProgressEvent::stage_start(
+                    PipelineStage::GiniAnalysis,
+                    stage_label,
+                ))
+                .ok();
+
+                let stage_start = Instant::now();
+                (gini_analyses, r2_analyses) = match checkpoint.load(CheckpointStage::Gini) {
+                    Some(stage) => resume_gini_stage(
+                        stage,
+                        &df,
+                        &config,
+                        &reports,
+                        &weights,
+                        &mut summary,
+                        &mut report_builder,
+                    )?,
+                    None => {
+                        let stage = match target_type {
+                            TargetType::Binary => {
+                                let (analyses, dropped, cross_validation, segment_iv) =
+                                    run_gini_analysis_bg(
+                                        &df,
+                                        &config,
+                                        &reports,
+                                        &weights,
+                                        &mut summary,
+                                        &tx,
+                                    )?;
+                                let concordance = run_concordance_analysis(&df, &config, &weights)?;
+                                report_builder.set_gini_results(&analyses, &dropped);
+                                report_builder.set_concordance_results(&concordance);
+                                report_builder.set_cross_validation(
+                                    &cross_validation,
+                                    config.cross_validation.as_ref().and_then(|cv| cv.min_iv),
+                                );
+                                if let Some(analysis) = &segment_iv {
+                                    report_builder.set_segment_iv(analysis.clone());
+                                }
+                                GiniCheckpoint {
+                                    iv_analyses: analyses,
+                                    r2_analyses: Vec::new(),
+                                    concordance,
+                                    dropped,
+                                    cross_validation,
+                                    segment_iv,
+                                }
+                            }
+                            TargetType::Continuous => {
+                                let (analyses, dropped) = run_r2_analysis_bg(
+                                    &df,
+                                    &config,
+                                    &reports,
+                                    &weights,
+                                    &mut summary,
+                                    &tx,
+                                )?;
+                                report_builder.set_r2_results(&analyses, &dropped);
+                                GiniCheckpoint {
+                                    iv_analyses: Vec::new(),
+                                    r2_analyses: analyses,
+                                    concordance: Vec::new(),
+                                    dropped,
+                                    cross_validation: Vec::new(),
+                                    segment_iv: None,
+                                }
+                            }
+                        };
+                        checkpoint.save(CheckpointStage::Gini, &stage)?;
+                        (stage.iv_analyses, stage.r2_analyses)
+                    }
+                };
 
-    if !summary.dropped_gini.is_empty() {
-        df = df.drop_many(&summary.dropped_gini);
-    }
+                if !summary.dropped_gini.is_empty() {
+                    df = df.drop_many(&summary.dropped_gini);
+                }
 
-    // Build metadata maps for IV-first correlation drop logic
-    let (feature_metadata, feature_types) =
-        build_correlation_metadata(&gini_analyses, &missing_ratios);
+                if let Some((section, unstable)) = run_iv_stability(
+                    &df,
+                    full_df.as_ref(),
+                    &gini_analyses,
+                    &weights,
+                    &config,
+                    Some(&tx),
+                )? {
+                    let flagged = section.features.iter().filter(|f| f.unstable).count();
+                    tx.send(ProgressEvent::update(
+                        PipelineStage::GiniAnalysis,
+                        "Unstable IV",
+                        format!("{} of {} features", flagged, section.features.len()),
+                    ))
+                    .ok();
+                    if !unstable.is_empty() {
+                        df = df.drop_many(&unstable);
+                        summary.add_gini_drops(unstable.clone());
+                    }
+                    report_builder.set_iv_stability(section, &unstable);
+                }
 
-    tx.send(ProgressEvent::stage_complete(
-        PipelineStage::GiniAnalysis,
-        "Gini/IV analysis complete",
-        stage_start.elapsed(),
-    ))
-    .ok();
+                if let Some(section) =
+                    run_time_stability(&df, &gini_analyses, &weights, &config, Some(&tx))?
+                {
+                    let drifting = section.features.iter().filter(|f| f.drifting).count();
+                    tx.send(ProgressEvent::update(
+                        PipelineStage::GiniAnalysis,
+                        "Drifting over time",
+                        format!(
+                            "{} of {} features over {} periods",
+                            drifting,
+                            section.features.len(),
+                            section.periods.len()
+                        ),
+                    ))
+                    .ok();
+                    report_builder.set_time_stability(section);
+                }
 
-    // ── Stage: Correlation ────────────────────────────────────────────────
-    tx.send(ProgressEvent::stage_start(
-        PipelineStage::CorrelationAnalysis,
-        "Correlation analysis",
-    ))
-    .ok();
+                if let Some(screen) = run_proxy_screen(&df, &weights, &config, Some(&tx))? {
+                    tx.send(ProgressEvent::update(
+                        PipelineStage::GiniAnalysis,
+                        "Protected-attribute proxies",
+                        format!("{} features", screen.proxies().len()),
+                    ))
+                    .ok();
+                    report_builder.set_fairness(screen);
+                }
 
-    let stage_start = Instant::now();
-    let (correlated_pairs, features_to_drop_corr) = run_correlation_analysis_bg(
-        &mut df,
-        &config,
-        &weights,
-        &mut summary,
-        &tx,
-        &feature_metadata,
-        &feature_types,
-    )?;
-    report_builder.set_correlation_results(&correlated_pairs, &features_to_drop_corr);
+                tx.send(ProgressEvent::stage_complete(
+                    PipelineStage::GiniAnalysis,
+                    format!("{} complete", stage_label),
+                    stage_start.elapsed(),
+                ))
+                .ok();
+            }
+            ScreeningStep::Correlation => {
+                // ── Stage: Correlation ─────────────────────────────────────
+                tx.send(ProgressEvent::stage_start(
+                    PipelineStage::CorrelationAnalysis,
+                    "Correlation analysis",
+                ))
+                .ok();
+
+                // Build metadata maps for IV-first correlation drop logic
+                let (feature_metadata, feature_types) =
+                    build_correlation_metadata(&gini_analyses, &r2_analyses, &missing_ratios);
+
+                let stage_start = Instant::now();
+                let stage = match checkpoint
+                    .load::<CorrelationCheckpoint>(CheckpointStage::Correlation)
+                {
+                    Some(stage) => {
+                        apply_correlation_drops(
+                            &mut df,
+                            &stage.correlated_pairs,
+                            &stage.dropped,
+                            &mut summary,
+                        );
+                        stage
+                    }
+                    None => {
+                        let (correlated_pairs, dropped, decisions) = run_correlation_analysis_bg(
+                            &mut df,
+                            &config,
+                            &weights,
+                            &mut summary,
+                            &tx,
+                            &feature_metadata,
+                            &feature_types,
+                        )?;
+                        let stage = CorrelationCheckpoint {
+                            correlated_pairs,
+                            dropped,
+                            decisions,
+                        };
+                        checkpoint.save(CheckpointStage::Correlation, &stage)?;
+                        stage
+                    }
+                };
+                report_builder.set_correlation_results(&stage.correlated_pairs, &stage.dropped);
+                report_builder.set_protected_correlation_drops(&protected_correlation_drops(
+                    &stage.correlated_pairs,
+                    &config,
+                    &feature_metadata,
+                ));
+                report_builder.add_decisions(&stage.decisions);
+                correlated_pairs = stage.correlated_pairs;
+
+                tx.send(ProgressEvent::stage_complete(
+                    PipelineStage::CorrelationAnalysis,
+                    "Correlation analysis complete",
+                    stage_start.elapsed(),
+                ))
+                .ok();
+            }
+        }
+    }
 
-    tx.send(ProgressEvent::stage_complete(
-        PipelineStage::CorrelationAnalysis,
-        "Correlation analysis complete",
-        stage_start.elapsed(),
-    ))
-    .ok();
+    if config.review_drops {
+        let retained = review_drops_bg(&report_builder, config.dictionary.as_ref(), &tx);
+        if !retained.is_empty() {
+            summary.retain_features(&retained);
+            report_builder.set_user_retained(&retained);
+        }
+    }
+    let imputation_plan = plan_imputation(&df, &weights, &config, &missing_ratios, &gini_analyses)?;
 
     // ── Stage: Saving ─────────────────────────────────────────────────────
     tx.send(ProgressEvent::stage_start(
@@ -851,12 +1879,42 @@ fn run_pipeline_bg(mut config: PipelineConfig, tx: ProgressSender) -> Result<()>
     ))
     .ok();
 
+    if let Some(pca) = run_pca(&df, &weights, &config)? {
+        tx.send(ProgressEvent::update(
+            PipelineStage::Saving,
+            "PCA of retained features",
+            format!("{} components for 95%", pca.components_for_95),
+        ))
+        .ok();
+        report_builder.set_pca(pca);
+    }
+
     let stage_start = Instant::now();
-    save_results_bg(&mut df, &output_path, &mut summary)?;
+    if !config.dry_run {
+        if let Some(full) = full_df {
+            df = restore_full_rows(full, &summary);
+        }
+        if let Some(mapping) = output_target_mapping(&config) {
+            write_mapped_target(&mut df, &config.target, mapping)?;
+        }
+        let woe_analyses = config.export_woe.then_some(gini_analyses.as_slice());
+        save_results_bg(
+            &mut df,
+            &output_path,
+            woe_analyses,
+            &config.parquet,
+            &config.csv,
+            &mut summary,
+        )?;
+    }
 
     tx.send(ProgressEvent::stage_complete(
         PipelineStage::Saving,
-        "Results saved",
+        if config.dry_run {
+            "Dry run: output not written"
+        } else {
+            "Results saved"
+        },
         stage_start.elapsed(),
     ))
     .ok();
@@ -872,20 +1930,67 @@ fn run_pipeline_bg(mut config: PipelineConfig, tx: ProgressSender) -> Result<()>
     report_builder.set_timing(&summary);
     let report = report_builder.build();
 
-    let report_path = derive_output_path(&input, "reduction_report", "json");
+    let report_path = derive_local_path(&reports, "reduction_report", "json");
     export_reduction_report(&report, &report_path)?;
 
-    let csv_report_path = derive_output_path(&input, "reduction_report", "csv");
+    let csv_report_path = derive_local_path(&reports, "reduction_report", "csv");
     export_reduction_report_csv(&report, &csv_report_path)?;
 
-    let gini_analysis_path = derive_output_path(&input, "gini_analysis", "json");
-    let zip_path = derive_output_path(&input, "reduction_report", "zip");
-    package_reduction_reports(
-        &gini_analysis_path,
+    let long_csv_path = derive_local_path(&reports, "reduction_report_long", "csv");
+    export_reduction_report_long_csv(&report, &long_csv_path)?;
+
+    let segment_missing_path = match &report.segment_missing {
+        Some(segments) => {
+            let path = derive_local_path(&reports, "segment_missing", "csv");
+            export_segment_missing_csv(segments, &path)?;
+            Some(path)
+        }
+        None => None,
+    };
+
+    let imputation_plan_path = derive_local_path(&reports, "imputation_plan", "json");
+    export_imputation_plan(&imputation_plan, &imputation_plan_path)?;
+
+    let selected = retained_features(&report);
+    let selected_feature_paths = export_selected_features(&selected, |ext| {
+        derive_local_path(&reports, "selected_features", ext)
+    })?;
+
+    let woe_scoring_paths = export_woe_scoring(&reports, &config, &gini_analyses, &selected)?;
+
+    let gini_analysis_path = config
+        .steps
+        .runs(ScreeningStep::Gini)
+        .then(|| gini_analysis_output_path(&reports, &config));
+    let extra_reports: Vec<_> = [long_csv_path, imputation_plan_path]
+        .into_iter()
+        .chain(selected_feature_paths)
+        .chain(woe_scoring_paths)
+        .chain(segment_missing_path)
+        .chain(cart_trees_output_path(&reports, &config, binning_strategy))
+        .collect();
+    let zip_path = derive_output_path(&reports, "reduction_report", "zip");
+    let staged_zip = pipeline::StagedOutput::new(&zip_path)?;
+    package_reduction_reports_with(
+        gini_analysis_path.as_deref(),
         &report_path,
         &csv_report_path,
-        &zip_path,
+        &extra_reports,
+        staged_zip.path(),
+    )?;
+    staged_zip.finish()?;
+    export_stage_tables(
+        &config.table_exports,
+        &missing_ratios,
+        &gini_analyses,
+        &correlated_pairs,
+        &summary,
     )?;
+    export_plots(&config, &reports, &gini_analyses, &summary)?;
+
+    if !config.dry_run {
+        checkpoint.remove()?;
+    }
 
     tx.send(ProgressEvent::stage_complete(
         PipelineStage::Reports,
@@ -896,31 +2001,110 @@ fn run_pipeline_bg(mut config: PipelineConfig, tx: ProgressSender) -> Result<()>
 
     // ── Complete ──────────────────────────────────────────────────────────
     let pipeline_elapsed = pipeline_start.elapsed();
-    let total_dropped = summary.dropped_missing.len()
-        + summary.dropped_gini.len()
-        + summary.dropped_correlation.len();
+    let total_dropped = summary.dropped_count();
 
     // Split into message + detail so the path doesn't get truncated
     // in the 66-wide progress overlay box.
+    let (message, detail) = if config.dry_run {
+        (
+            format!("Dry run: {} features would be dropped", total_dropped),
+            format!("Reports: {}", zip_path.display()),
+        )
+    } else {
+        (
+            format!("Done: {} features dropped", total_dropped),
+            format!("Output: {}", output_path.display()),
+        )
+    };
     tx.send(ProgressEvent {
         stage: PipelineStage::Complete,
-        message: format!("Done: {} features dropped", total_dropped),
-        detail: Some(format!("Output: {}", output_path.display())),
+        message,
+        detail: Some(detail),
         is_complete: true,
         elapsed_secs: Some(pipeline_elapsed.as_secs_f64()),
         summary: Some(crate::pipeline::progress::SummaryData {
             initial_features: summary.initial_features,
             final_features: summary.final_features,
+            dropped_constant: summary.dropped_constant.len(),
+            dropped_identifier: summary.dropped_identifier.len(),
             dropped_missing: summary.dropped_missing.len(),
             dropped_gini: summary.dropped_gini.len(),
             dropped_correlation: summary.dropped_correlation.len(),
         }),
         sampling_summary: None,
         conversion_summary: None,
+        correlation_review: None,
+        drop_review: None,
+        results: None,
     })
     .ok();
+    if config.browse_results {
+        tx.send(ProgressEvent::results(
+            summary.run_results(&gini_analyses, &correlated_pairs),
+        ))
+        .ok();
+    }
 
-    Ok(())
+    Ok(summary)
+}
+
+// ============================================================================
+// Pipeline execution: headless (--json-output, --quiet)
+// ============================================================================
+
+/// Run the reduction pipeline in the background and write its progress
+/// events to `path` as JSON lines. Setup and pipeline errors are written as a
+/// final `pipeline_failed` line and then returned, so the exit code still
+/// reports the failure.
+fn run_pipeline_json(cli: &Cli, path: &std::path::Path) -> Result<()> {
+    let mut writer = cli::json_output::JsonEventWriter::create(path)?;
+    let pipeline_start = Instant::now();
+
+    let result = run_pipeline_json_inner(cli, &mut writer);
+    match &result {
+        Ok(Some((summary, output))) => writer.write_finished(
+            summary,
+            output.as_deref(),
+            pipeline_start.elapsed().as_secs_f64(),
+        )?,
+        Ok(None) => {}
+        Err(e) => writer.write_failed(e)?,
+    }
+    result.map(|_| ())
+}
+
+fn run_pipeline_json_inner(
+    cli: &Cli,
+    writer: &mut cli::json_output::JsonEventWriter,
+) -> Result<Option<(ReductionSummary, Option<std::path::PathBuf>)>> {
+    let Some(config) = setup_configuration_no_tui(cli)? else {
+        return Ok(None);
+    };
+    let output = (!config.dry_run).then(|| config.output.clone());
+
+    let (tx, rx) = create_progress_channel();
+    let handle = std::thread::spawn(move || run_pipeline_bg(config, tx));
+    // Dropping review requests unanswered keeps the automatic choices
+    for event in rx {
+        writer.write_event(&event)?;
+    }
+
+    let summary = handle
+        .join()
+        .map_err(|_| anyhow::anyhow!("Pipeline thread panicked"))??;
+    Ok(Some((summary, output)))
+}
+
+/// Run the reduction pipeline with nothing on stdout (`--quiet`). Its
+/// progress events are discarded, and review requests go unanswered so the
+/// automatic choices stand.
+fn run_pipeline_quiet(cli: &Cli) -> Result<()> {
+    let Some(config) = setup_configuration_no_tui(cli)? else {
+        return Ok(());
+    };
+    let (tx, rx) = create_progress_channel();
+    drop(rx);
+    run_pipeline_bg(config, tx).map(|_| ())
 }
 
 // ============================================================================
@@ -929,7 +2113,11 @@ fn run_pipeline_bg(mut config: PipelineConfig, tx: ProgressSender) -> Result<()>
 
 fn run_pipeline_no_tui(mut config: PipelineConfig) -> Result<()> {
     let input = config.input.clone();
+    let reports = report_anchor(&config);
     let output_path = config.output.clone();
+    if config.fast {
+        apply_fast_screen(&mut config);
+    }
 
     // Print styled banner
     print_banner(env!("CARGO_PKG_VERSION"));
@@ -940,24 +2128,75 @@ fn run_pipeline_no_tui(mut config: PipelineConfig) -> Result<()> {
         &config.target,
         &output_path,
         config.missing_threshold,
-        config.gini_threshold,
+        univariate_threshold(&config),
+        parse_univariate_metric(&config)? == UnivariateMetric::MutualInfo,
         config.correlation_threshold,
     );
 
-    // Load dataset and apply initial drops
-    let (mut df, _initial_features, mut summary) =
-        load_and_prepare_dataset(&input, &config.columns_to_drop, config.infer_schema_length)?;
-
-    // Validate target and setup weights (returns None if user cancelled)
-    let Some(weights) = validate_target_and_weights(&df, &mut config, true)? else {
-        return Ok(());
-    };
+    if config.fast {
+        print_info("Fast screen: decile bins without the solver, correlations on a row sample");
+    }
 
-    // Parse binning strategy for report
-    let binning_strategy: BinningStrategy = config
-        .binning_strategy
-        .parse()
-        .map_err(|e: String| anyhow::anyhow!(e))?;
+    // Load dataset without the dropped columns
+    let (mut df, _initial_features, mut summary) = load_and_prepare_dataset(
+        &input,
+        config.sheet.as_deref(),
+        &column_projection(&config),
+        config.infer_schema_length,
+        &config.csv,
+        config.max_memory_gb,
+    )?;
+    let sample = take_screening_sample(&mut df, &config)?;
+    if let Some(sample) = &sample {
+        print_info(&format!(
+            "Analysing a sample of {} of {} rows{}",
+            sample.sampled_rows,
+            sample.total_rows,
+            if sample.stratified_on.is_some() {
+                " (stratified on the target)"
+            } else {
+                ""
+            }
+        ));
+    }
+
+    // Catch upstream extract changes before any statistic is computed
+    let schema_drift = check_expected_schema(&df, &config)?;
+    if let Some(drift) = &schema_drift {
+        if drift.is_empty() {
+            print_success("Columns match the expected schema");
+        } else {
+            print_info(&format!("Schema drift: {}", drift));
+            for line in drift.lines() {
+                println!("      {}", line);
+            }
+        }
+    }
+
+    let target_type = parse_target_type(&config)?;
+
+    // Validate target and setup weights (returns None if user cancelled)
+    let Some(mut weights) = validate_target_and_weights(&df, &mut config, true)? else {
+        return Ok(());
+    };
+
+    // Sanitise NaN/±Inf before any statistics are computed
+    let non_finite_counts = handle_non_finite_values(&mut df, &config)?;
+    let (date_policy, temporal_columns) = handle_date_policy(&mut df, &config, &mut summary)?;
+    check_grouping_columns(&df, &config)?;
+
+    // Parse binning strategy for report
+    let mut binning_strategy: BinningStrategy = config
+        .binning_strategy
+        .parse()
+        .map_err(|e: String| anyhow::anyhow!(e))?;
+    if target_type == TargetType::Continuous {
+        // R² always uses equal-frequency bins
+        binning_strategy = BinningStrategy::Quantile;
+    }
+    if config.export_cart_trees && binning_strategy != BinningStrategy::Cart {
+        print_info("--export-cart-trees only applies to CART binning of a binary target, skipping");
+    }
 
     // Create report builder
     let mut report_builder = ReductionReportBuilder::new(ReportBuilderParams {
@@ -968,69 +2207,491 @@ fn run_pipeline_no_tui(mut config: PipelineConfig) -> Result<()> {
         binning_strategy: binning_strategy.to_string(),
         num_bins: config.gini_bins,
         missing_threshold: config.missing_threshold,
-        gini_threshold: config.gini_threshold,
+        gini_threshold: univariate_threshold(&config),
         correlation_threshold: config.correlation_threshold,
     });
-
-    // Run missing value analysis
-    let (missing_ratios, features_to_drop_missing) =
-        run_missing_analysis(&mut df, &config, &weights, &mut summary)?;
-    report_builder.set_missing_results(&missing_ratios, &features_to_drop_missing);
-
-    // Run Gini/IV analysis
-    let (gini_analyses, features_to_drop_gini) =
-        run_gini_analysis(&df, &config, &input, &weights, &mut summary)?;
-    report_builder.set_gini_results(&gini_analyses, &features_to_drop_gini);
-
-    // Update df after Gini drops
-    if !summary.dropped_gini.is_empty() {
-        df = df.drop_many(&summary.dropped_gini);
+    report_builder.set_target_type(&target_label(&config, target_type));
+    if let Some(labels) = &config.event_labels {
+        report_builder.set_event_labels(labels);
+    }
+    if let Some(threshold) = config.cramers_v_threshold {
+        report_builder.set_cramers_v_threshold(threshold);
+    }
+    report_builder.set_correlation_strategy(parse_correlation_strategy(&config)?);
+    report_builder.set_corr_keep_policy(&parse_corr_keep_policy(&config)?);
+    report_builder.set_non_finite_results(&non_finite_counts, &config.non_finite_policy);
+    report_builder.set_temporal_results(&temporal_columns, date_policy);
+    report_builder.set_keep_columns(&config.keep_columns);
+    report_builder.set_seed(config.seed);
+    if let Some(files) = pipeline::input_files(&input)? {
+        report_builder.set_input_files(files.names());
+    }
+    if let Some(dictionary) = &config.dictionary {
+        report_builder.set_dictionary(dictionary);
+    }
+    if let Some(drift) = schema_drift {
+        report_builder.set_schema_drift(drift);
+    }
+    if let Some(sample) = sample {
+        report_builder.set_sample(sample);
     }
 
-    // Build metadata maps for IV-first correlation drop logic
-    let (feature_metadata, feature_types) =
-        build_correlation_metadata(&gini_analyses, &missing_ratios);
+    // With a split column, statistics use the training rows; drops reach all rows at save time
+    let mut full_df = None;
+    if let Some((train_df, train_weights)) =
+        train_partition_for_screening(&df, &weights, &config, &mut report_builder)?
+    {
+        print_info(&format!(
+            "Screening on {} of {} rows ({} = {})",
+            train_df.height(),
+            df.height(),
+            config.split_column.as_deref().unwrap_or_default(),
+            config.train_value.as_deref().unwrap_or_default()
+        ));
+        full_df = Some(std::mem::replace(&mut df, train_df));
+        weights = train_weights;
+    }
+    if config.fast {
+        report_builder.set_fast_screen(fast_screen_settings(df.height()));
+    }
+    if target_type == TargetType::Binary && !config.no_target {
+        check_target_classes(&df, &config.target, config.target_mapping.as_ref())?;
+    }
+    let checkpoint = open_checkpoint(&config)?;
+    if checkpoint.resumed() {
+        print_info(&format!(
+            "Resuming from checkpoint in {}",
+            checkpoint.dir().display()
+        ));
+    }
 
-    // Run correlation analysis
-    let (correlated_pairs, features_to_drop_corr) = run_correlation_analysis(
+    // Drop constant and near-constant features; cheap enough to rerun on resume
+    run_dominance_filter(
         &mut df,
         &config,
         &weights,
         &mut summary,
-        &feature_metadata,
-        &feature_types,
+        &mut report_builder,
     )?;
-    report_builder.set_correlation_results(&correlated_pairs, &features_to_drop_corr);
+    run_id_check(&mut df, &config, &mut summary, &mut report_builder)?;
+
+    report_builder.set_step_plan(&config.steps);
+    if config.steps.steps()[0] != ScreeningStep::Missing {
+        report_builder.set_screened_features(&screened_features(&df, &config));
+    }
+    for step in config.steps.skipped() {
+        print_info(&format!("Skipping the {} step (--steps)", step));
+    }
+
+    let mut missing_ratios = Vec::new();
+    let mut gini_analyses = Vec::new();
+    let mut r2_analyses = Vec::new();
+    let mut correlated_pairs = Vec::new();
+    for &step in config.steps.steps() {
+        match step {
+            ScreeningStep::Missing => {
+                // Run missing value analysis
+                let (ratios, features_to_drop_missing, segment_missing) =
+                    match checkpoint.load::<MissingCheckpoint>(CheckpointStage::Missing) {
+                        Some(stage) => {
+                            print_info("Missing value analysis loaded from checkpoint");
+                            resume_missing_stage(&mut df, stage, &mut summary)
+                        }
+                        None => {
+                            let segments = analyze_segment_missing(&df, &config, &weights)?;
+                            let (missing_ratios, dropped) =
+                                run_missing_analysis(&mut df, &config, &weights, &mut summary)?;
+                            let stage = MissingCheckpoint {
+                                missing_ratios,
+                                dropped,
+                                segments,
+                            };
+                            checkpoint.save(CheckpointStage::Missing, &stage)?;
+                            (stage.missing_ratios, stage.dropped, stage.segments)
+                        }
+                    };
+                report_builder.set_missing_results(&ratios, &features_to_drop_missing);
+                if let Some(segments) = segment_missing {
+                    let flagged = segments.flagged();
+                    print_info(&format!(
+                        "Segment missing: {} of {} feature(s) fully missing in a segment of '{}' ({} segments)",
+                        flagged.len(),
+                        segments.features.len(),
+                        segments.column,
+                        segments.segments.len()
+                    ));
+                    if !flagged.is_empty() {
+                        print_info(&format!(
+                            "Fully missing in a segment: {}",
+                            flagged.join(", ")
+                        ));
+                    }
+                    report_builder.set_segment_missing(segments);
+                }
+                missing_ratios = ratios;
+            }
+            ScreeningStep::Gini => {
+                // Run Gini/IV analysis, or R² analysis for a continuous target
+                (gini_analyses, r2_analyses) = match checkpoint.load(CheckpointStage::Gini) {
+                    Some(stage) => {
+                        print_info("Univariate analysis loaded from checkpoint");
+                        resume_gini_stage(
+                            stage,
+                            &df,
+                            &config,
+                            &reports,
+                            &weights,
+                            &mut summary,
+                            &mut report_builder,
+                        )?
+                    }
+                    None => {
+                        let stage = match target_type {
+                            TargetType::Binary => {
+                                let (analyses, dropped, cross_validation, segment_iv) =
+                                    run_gini_analysis(
+                                        &df,
+                                        &config,
+                                        &reports,
+                                        &weights,
+                                        &mut summary,
+                                    )?;
+                                report_builder.set_gini_results(&analyses, &dropped);
+
+                                let concordance = run_concordance_analysis(&df, &config, &weights)?;
+                                let limited = binning_limited_features(
+                                    &analyses,
+                                    &concordance,
+                                    config.gini_threshold,
+                                );
+                                if !limited.is_empty() {
+                                    print_count(
+                                        "low-Gini feature(s) with raw Somers' D above threshold",
+                                        limited.len(),
+                                        Some("(binning may be losing signal)"),
+                                    );
+                                }
+                                report_builder.set_concordance_results(&concordance);
+                                report_builder.set_cross_validation(
+                                    &cross_validation,
+                                    config.cross_validation.as_ref().and_then(|cv| cv.min_iv),
+                                );
+                                if let Some(analysis) = &segment_iv {
+                                    report_builder.set_segment_iv(analysis.clone());
+                                }
+                                GiniCheckpoint {
+                                    iv_analyses: analyses,
+                                    r2_analyses: Vec::new(),
+                                    concordance,
+                                    dropped,
+                                    cross_validation,
+                                    segment_iv,
+                                }
+                            }
+                            TargetType::Continuous => {
+                                let (analyses, dropped) = run_r2_analysis(
+                                    &df,
+                                    &config,
+                                    &reports,
+                                    &weights,
+                                    &mut summary,
+                                )?;
+                                report_builder.set_r2_results(&analyses, &dropped);
+                                GiniCheckpoint {
+                                    iv_analyses: Vec::new(),
+                                    r2_analyses: analyses,
+                                    concordance: Vec::new(),
+                                    dropped,
+                                    cross_validation: Vec::new(),
+                                    segment_iv: None,
+                                }
+                            }
+                        };
+                        checkpoint.save(CheckpointStage::Gini, &stage)?;
+                        (stage.iv_analyses, stage.r2_analyses)
+                    }
+                };
+
+                // Update df after Gini drops
+                if !summary.dropped_gini.is_empty() {
+                    df = df.drop_many(&summary.dropped_gini);
+                }
+
+                if let Some((section, unstable)) = run_iv_stability(
+                    &df,
+                    full_df.as_ref(),
+                    &gini_analyses,
+                    &weights,
+                    &config,
+                    None,
+                )? {
+                    let flagged = section.features.iter().filter(|f| f.unstable).count();
+                    print_info(&format!(
+                        "IV stability: {} of {} feature(s) unstable between {} training and {} validation rows",
+                        flagged,
+                        section.features.len(),
+                        section.train_rows,
+                        section.validation_rows
+                    ));
+                    if !unstable.is_empty() {
+                        print_count(
+                            "feature(s) with unstable IV",
+                            unstable.len(),
+                            Some(&format!("(>{:.2})", section.threshold)),
+                        );
+                        df = df.drop_many(&unstable);
+                        summary.add_gini_drops(unstable.clone());
+                    }
+                    report_builder.set_iv_stability(section, &unstable);
+                }
+
+                if let Some(section) =
+                    run_time_stability(&df, &gini_analyses, &weights, &config, None)?
+                {
+                    let drifting: Vec<&str> = section
+                        .features
+                        .iter()
+                        .filter(|f| f.drifting)
+                        .map(|f| f.feature.as_str())
+                        .collect();
+                    print_info(&format!(
+                        "Time stability: {} of {} feature(s) drifting over {} {} periods of '{}'",
+                        drifting.len(),
+                        section.features.len(),
+                        section.periods.len(),
+                        section.freq,
+                        section.column
+                    ));
+                    if !drifting.is_empty() {
+                        print_info(&format!("Drifting: {}", drifting.join(", ")));
+                    }
+                    report_builder.set_time_stability(section);
+                }
+
+                if let Some(screen) = run_proxy_screen(&df, &weights, &config, None)? {
+                    let proxies = screen.proxies();
+                    print_info(&format!(
+                        "Proxy screen: {} feature(s) track a protected column ({})",
+                        proxies.len(),
+                        screen.columns.join(", ")
+                    ));
+                    if !proxies.is_empty() {
+                        print_info(&format!("Proxies: {}", proxies.join(", ")));
+                    }
+                    report_builder.set_fairness(screen);
+                }
+            }
+            ScreeningStep::Correlation => {
+                // Build metadata maps for IV-first correlation drop logic
+                let (feature_metadata, feature_types) =
+                    build_correlation_metadata(&gini_analyses, &r2_analyses, &missing_ratios);
+
+                // Run correlation analysis
+                let stage =
+                    match checkpoint.load::<CorrelationCheckpoint>(CheckpointStage::Correlation) {
+                        Some(stage) => {
+                            print_info("Correlation analysis loaded from checkpoint");
+                            apply_correlation_drops(
+                                &mut df,
+                                &stage.correlated_pairs,
+                                &stage.dropped,
+                                &mut summary,
+                            );
+                            stage
+                        }
+                        None => {
+                            let (correlated_pairs, dropped) = run_correlation_analysis(
+                                &mut df,
+                                &config,
+                                &weights,
+                                &mut summary,
+                                &feature_metadata,
+                                &feature_types,
+                            )?;
+                            let stage = CorrelationCheckpoint {
+                                correlated_pairs,
+                                dropped,
+                                decisions: Vec::new(),
+                            };
+                            checkpoint.save(CheckpointStage::Correlation, &stage)?;
+                            stage
+                        }
+                    };
+                report_builder.set_correlation_results(&stage.correlated_pairs, &stage.dropped);
+                report_builder.set_protected_correlation_drops(&protected_correlation_drops(
+                    &stage.correlated_pairs,
+                    &config,
+                    &feature_metadata,
+                ));
+                correlated_pairs = stage.correlated_pairs;
+            }
+        }
+    }
+    let imputation_plan = plan_imputation(&df, &weights, &config, &missing_ratios, &gini_analyses)?;
+
+    if let Some(pca) = run_pca(&df, &weights, &config)? {
+        print_info(&format!(
+            "PCA of {} feature(s): {} component(s) explain 95% of the variance",
+            pca.features.len(),
+            pca.components_for_95
+        ));
+        for group in pca.groups() {
+            let features: Vec<&str> = group
+                .high_loadings
+                .iter()
+                .map(|l| l.feature.as_str())
+                .collect();
+            print_info(&format!(
+                "PC{} ({:.1}%): {}",
+                group.component,
+                group.explained_variance * 100.0,
+                features.join(", ")
+            ));
+        }
+        report_builder.set_pca(pca);
+    } else if config.pca.is_some() {
+        print_info(&format!(
+            "PCA skipped: it needs 2 to {} non-constant numeric features",
+            pipeline::MAX_PCA_FEATURES
+        ));
+    }
 
     // Save results
-    save_results(&mut df, &output_path, &mut summary)?;
+    if config.dry_run {
+        print_info("Dry run: the reduced dataset is not written");
+    } else {
+        if let Some(full) = full_df {
+            df = restore_full_rows(full, &summary);
+        }
+        if let Some(mapping) = output_target_mapping(&config) {
+            write_mapped_target(&mut df, &config.target, mapping)?;
+            print_info(&format!(
+                "Target '{}' written as 0/1; raw values kept in '{}{}'",
+                config.target, config.target, RAW_TARGET_SUFFIX
+            ));
+        }
+        let woe_analyses = config.export_woe.then_some(gini_analyses.as_slice());
+        save_results(
+            &mut df,
+            &output_path,
+            woe_analyses,
+            &config.parquet,
+            &config.csv,
+            &mut summary,
+        )?;
+    }
 
     // Build and export reduction report
     report_builder.set_timing(&summary);
     let report = report_builder.build();
-    let report_path = derive_output_path(&input, "reduction_report", "json");
+    let report_path = derive_local_path(&reports, "reduction_report", "json");
     export_reduction_report(&report, &report_path)?;
 
     // Also export CSV summary for easy viewing
-    let csv_report_path = derive_output_path(&input, "reduction_report", "csv");
+    let csv_report_path = derive_local_path(&reports, "reduction_report", "csv");
     export_reduction_report_csv(&report, &csv_report_path)?;
 
-    // Package all three reports into a zip file
-    let gini_analysis_path = derive_output_path(&input, "gini_analysis", "json");
-    let zip_path = derive_output_path(&input, "reduction_report", "zip");
-    package_reduction_reports(
-        &gini_analysis_path,
+    // Long-format CSV for BI dashboards
+    let long_csv_path = derive_local_path(&reports, "reduction_report_long", "csv");
+    export_reduction_report_long_csv(&report, &long_csv_path)?;
+
+    let segment_missing_path = match &report.segment_missing {
+        Some(segments) => {
+            let path = derive_local_path(&reports, "segment_missing", "csv");
+            export_segment_missing_csv(segments, &path)?;
+            Some(path)
+        }
+        None => None,
+    };
+
+    // Recommended imputation for downstream training code
+    let imputation_plan_path = derive_local_path(&reports, "imputation_plan", "json");
+    export_imputation_plan(&imputation_plan, &imputation_plan_path)?;
+
+    // Retained feature lists for model pipelines and warehouse views
+    let selected = retained_features(&report);
+    let selected_feature_paths = export_selected_features(&selected, |ext| {
+        derive_local_path(&reports, "selected_features", ext)
+    })?;
+
+    // WoE encoding of the retained features for warehouse and service scoring
+    let woe_scoring_paths = export_woe_scoring(&reports, &config, &gini_analyses, &selected)?;
+
+    // Package the reports into a zip file
+    let gini_analysis_path = config
+        .steps
+        .runs(ScreeningStep::Gini)
+        .then(|| gini_analysis_output_path(&reports, &config));
+    let extra_reports: Vec<_> = [long_csv_path, imputation_plan_path]
+        .into_iter()
+        .chain(selected_feature_paths)
+        .chain(woe_scoring_paths)
+        .chain(segment_missing_path)
+        .chain(cart_trees_output_path(&reports, &config, binning_strategy))
+        .collect();
+    let zip_path = derive_output_path(&reports, "reduction_report", "zip");
+    let staged_zip = pipeline::StagedOutput::new(&zip_path)?;
+    package_reduction_reports_with(
+        gini_analysis_path.as_deref(),
         &report_path,
         &csv_report_path,
-        &zip_path,
+        &extra_reports,
+        staged_zip.path(),
     )?;
+    staged_zip.finish()?;
 
     print_success(&format!("Reduction report saved to {}", zip_path.display()));
+    for path in export_stage_tables(
+        &config.table_exports,
+        &missing_ratios,
+        &gini_analyses,
+        &correlated_pairs,
+        &summary,
+    )? {
+        print_success(&format!("Table saved to {}", path.display()));
+    }
+    if let Some(index) = export_plots(&config, &reports, &gini_analyses, &summary)? {
+        print_success(&format!("WoE plots saved to {}", index.display()));
+    }
 
     // Display summary and completion
     summary.display();
+    if config.dry_run {
+        summary.display_drop_candidates();
+        print_info(&format!(
+            "Stage results kept in {}; rerun with --resume to write {} without recomputing them",
+            checkpoint.dir().display(),
+            output_path.display()
+        ));
+    } else {
+        checkpoint.remove()?;
+    }
+    if config.fast {
+        print_info(
+            "Fast screen results are approximate; rerun without --fast to confirm the drops",
+        );
+    }
     print_completion();
+    if config.browse_results {
+        browse_results(summary.run_results(&gini_analyses, &correlated_pairs))?;
+    }
+
+    Ok(())
+}
+
+/// Open the results browser after a `--no-confirm` run, when stdout is a terminal
+#[cfg(feature = "tui")]
+fn browse_results(results: pipeline::RunResults) -> Result<()> {
+    if !std::io::stdout().is_terminal() {
+        print_info("Results browser skipped: stdout is not a terminal");
+        return Ok(());
+    }
+    cli::results_browser::browse_results(results)
+}
 
+#[cfg(not(feature = "tui"))]
+fn browse_results(_: pipeline::RunResults) -> Result<()> {
+    print_info("Results browser skipped: this build has no `tui` feature");
     Ok(())
 }
 
@@ -1038,15 +2699,40 @@ fn run_pipeline_no_tui(mut config: PipelineConfig) -> Result<()> {
 // Shared stage helpers (used by both paths)
 // ============================================================================
 
-/// Load dataset and apply initial column drops (indicatif terminal path)
+/// Load dataset, skipping dropped and unselected columns (indicatif terminal path)
 fn load_and_prepare_dataset(
     input: &std::path::Path,
-    columns_to_drop: &[String],
+    sheet: Option<&str>,
+    projection: &ColumnProjection,
     infer_schema_length: usize,
+    csv: &pipeline::CsvDialect,
+    max_memory_gb: Option<f64>,
 ) -> Result<(polars::prelude::DataFrame, usize, ReductionSummary)> {
     let step_start = Instant::now();
-    println!(); // Blank line before progress bar
-    let (mut df, rows, cols, memory_mb) = load_dataset_with_progress(input, infer_schema_length)?;
+    let (columns, skipped) = resolve_projection(input, sheet, csv, projection)?;
+    let mode = plan_load_mode(
+        input,
+        infer_schema_length,
+        columns.as_deref(),
+        max_memory_gb,
+    )?;
+    let (df, rows, cols, memory_mb) = if mode == LoadMode::Streaming {
+        print_info("Dataset is close to --max-memory-gb; loading it with the streaming engine");
+        load_dataset_streaming(input, infer_schema_length, columns.as_deref(), csv, None)?
+    } else {
+        println!(); // Blank line before progress bar
+        load_dataset_columns(
+            input,
+            infer_schema_length,
+            sheet,
+            columns.as_deref(),
+            csv,
+            None,
+        )?
+    };
+    if let Some(max_memory_gb) = max_memory_gb {
+        check_loaded_size(&df, input, max_memory_gb)?;
+    }
     print_success("Dataset loaded");
 
     // Display statistics
@@ -1055,16 +2741,14 @@ fn load_and_prepare_dataset(
     println!("      Columns: {}", cols);
     println!("      Estimated memory: {:.2} MB", memory_mb);
 
-    // Apply user-specified column drops
-    let dropped_count = apply_initial_drops(&mut df, columns_to_drop);
-    if dropped_count > 0 {
+    if skipped > 0 {
         print_success(&format!(
-            "Dropped {} user-specified column(s)",
-            dropped_count
+            "Skipped {} user-specified column(s) while loading",
+            skipped
         ));
     }
 
-    let initial_features = cols - dropped_count;
+    let initial_features = cols;
     let mut summary = ReductionSummary::new(initial_features);
     let load_elapsed = step_start.elapsed();
     summary.set_load_time(load_elapsed);
@@ -1073,21 +2757,47 @@ fn load_and_prepare_dataset(
     Ok((df, initial_features, summary))
 }
 
-/// Load dataset and apply initial column drops (TUI / channel path)
+/// Load dataset, skipping dropped and unselected columns (TUI / channel path)
 fn load_and_prepare_dataset_with_tx(
     input: &std::path::Path,
-    columns_to_drop: &[String],
+    sheet: Option<&str>,
+    projection: &ColumnProjection,
     infer_schema_length: usize,
+    csv: &pipeline::CsvDialect,
+    max_memory_gb: Option<f64>,
     tx: &ProgressSender,
 ) -> Result<(polars::prelude::DataFrame, usize, ReductionSummary)> {
     let step_start = Instant::now();
-    let (mut df, _rows, cols, _memory_mb) =
-        load_dataset_with_progress_channel(input, infer_schema_length, tx)?;
-
-    // Apply user-specified column drops
-    let dropped_count = apply_initial_drops(&mut df, columns_to_drop);
+    let (columns, _skipped) = resolve_projection(input, sheet, csv, projection)?;
+    let mode = plan_load_mode(
+        input,
+        infer_schema_length,
+        columns.as_deref(),
+        max_memory_gb,
+    )?;
+    let (df, _rows, cols, _memory_mb) = if mode == LoadMode::Streaming {
+        load_dataset_streaming(
+            input,
+            infer_schema_length,
+            columns.as_deref(),
+            csv,
+            Some(tx),
+        )?
+    } else {
+        load_dataset_columns(
+            input,
+            infer_schema_length,
+            sheet,
+            columns.as_deref(),
+            csv,
+            Some(tx),
+        )?
+    };
+    if let Some(max_memory_gb) = max_memory_gb {
+        check_loaded_size(&df, input, max_memory_gb)?;
+    }
 
-    let initial_features = cols - dropped_count;
+    let initial_features = cols;
     let mut summary = ReductionSummary::new(initial_features);
     let load_elapsed = step_start.elapsed();
     summary.set_load_time(load_elapsed);
@@ -1095,26 +2805,159 @@ fn load_and_prepare_dataset_with_tx(
     Ok((df, initial_features, summary))
 }
 
-fn apply_initial_drops(df: &mut polars::prelude::DataFrame, columns_to_drop: &[String]) -> usize {
-    if columns_to_drop.is_empty() {
-        return 0;
+/// How to load `input` within `--max-memory-gb`; in memory without a budget
+fn plan_load_mode(
+    input: &std::path::Path,
+    infer_schema_length: usize,
+    columns: Option<&[String]>,
+    max_memory_gb: Option<f64>,
+) -> Result<LoadMode> {
+    let Some(max_memory_gb) = max_memory_gb else {
+        return Ok(LoadMode::InMemory);
+    };
+    if max_memory_gb <= 0.0 {
+        anyhow::bail!("--max-memory-gb must be positive, got {}", max_memory_gb);
     }
-    let column_names: Vec<String> = df
-        .get_column_names()
-        .iter()
-        .map(|s| s.to_string())
-        .collect();
-    let valid_columns: Vec<String> = columns_to_drop
+    let plan = plan_load(input, infer_schema_length, columns, max_memory_gb)?;
+    tracing::debug!(
+        mode = ?plan.mode,
+        estimated_bytes = ?plan.estimated_bytes,
+        max_memory_gb,
+        "Planned dataset load"
+    );
+    Ok(plan.mode)
+}
+
+/// Read the `--dictionary` file, if one is given, and add the column labels
+/// and formats of a SAS7BDAT input.
+///
+/// Returns `None` without a dictionary file or any SAS label or format.
+fn load_dictionary(
+    path: Option<&std::path::Path>,
+    input: &std::path::Path,
+) -> Result<Option<FeatureDictionary>> {
+    let dictionary = path.map(FeatureDictionary::load).transpose()?;
+    #[cfg(feature = "sas7bdat")]
+    if input
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("sas7bdat"))
+    {
+        // An unreadable file is reported when the data is loaded
+        if let Ok(columns) = pipeline::sas7bdat::get_sas7bdat_column_metadata(input) {
+            let mut dictionary = dictionary.unwrap_or_default();
+            dictionary.add_sas_metadata(&columns);
+            return Ok((!dictionary.entries.is_empty()).then_some(dictionary));
+        }
+    }
+    #[cfg(not(feature = "sas7bdat"))]
+    let _ = input;
+    Ok(dictionary)
+}
+
+/// Compare the loaded columns against `--expect-schema`.
+///
+/// Returns `None` without an expected schema. With `--fail-on-schema-drift`,
+/// any difference is an error listing every added, removed and re-typed column.
+fn check_expected_schema(
+    df: &polars::prelude::DataFrame,
+    config: &PipelineConfig,
+) -> Result<Option<SchemaDrift>> {
+    let Some(path) = &config.expect_schema else {
+        return Ok(None);
+    };
+    let expected = ExpectedSchema::load(path)?;
+    // Columns left unread on purpose have not gone missing upstream
+    let projection = column_projection(config);
+    let not_loaded: Vec<String> = expected
+        .columns
         .iter()
-        .filter(|col| column_names.contains(col))
-        .cloned()
+        .map(|c| c.name.clone())
+        .filter(|name| projection.excludes(name))
         .collect();
-    let count = valid_columns.len();
-    if count > 0 {
-        let taken = std::mem::take(df);
-        *df = taken.drop_many(&valid_columns);
+    let drift = expected.compare(df, &not_loaded, path);
+    if config.fail_on_schema_drift && !drift.is_empty() {
+        anyhow::bail!("Schema drift: {}\n  {}", drift, drift.lines().join("\n  "));
+    }
+    Ok(Some(drift))
+}
+
+/// Replace `df` by the `--sample-rows` / `--sample-frac` sample, if any,
+/// returning what was drawn for the report
+fn take_screening_sample(
+    df: &mut polars::prelude::DataFrame,
+    config: &PipelineConfig,
+) -> Result<Option<SampleSettings>> {
+    let Some(sample) = &config.sample else {
+        return Ok(None);
+    };
+    let stratify_on = if sample.stratify {
+        if config.target_type == "continuous" {
+            anyhow::bail!("--sample-stratify needs a binary target");
+        }
+        Some(config.target.as_str())
+    } else {
+        None
+    };
+    let total_rows = df.height();
+    *df = pipeline::sample_for_screening(df, &sample.size, stratify_on, sample.seed)?;
+    Ok(Some(SampleSettings {
+        requested_rows: match sample.size {
+            SampleSize::Count(rows) => Some(rows),
+            SampleSize::Fraction(_) => None,
+        },
+        fraction: match sample.size {
+            SampleSize::Fraction(fraction) => Some(fraction),
+            SampleSize::Count(_) => None,
+        },
+        stratified_on: stratify_on.map(String::from),
+        seed: sample.seed,
+        sampled_rows: df.height(),
+        total_rows,
+    }))
+}
+
+/// Columns to load for `config`: `--select-columns` minus `--drop-columns`,
+/// keeping the target, weight and split columns unless they are dropped.
+/// `--drop-partition-columns` adds the input's Hive partition keys to the
+/// dropped columns.
+fn column_projection(config: &PipelineConfig) -> ColumnProjection {
+    let mut drop = config.columns_to_drop.clone();
+    if config.drop_partition_columns {
+        if let Ok(Some(files)) = pipeline::input_files(&config.input) {
+            drop.extend(files.partition_keys);
+        }
+    }
+    ColumnProjection::new(&config.select_columns, &drop).with_always_loaded(
+        (!config.no_target)
+            .then_some(config.target.as_str())
+            .into_iter()
+            .chain(config.weight_column.as_deref())
+            .chain(config.split_column.as_deref())
+            .chain(config.segment_column.as_deref())
+            .chain(config.time_stability.as_ref().map(|t| t.column.as_str()))
+            .chain(
+                config
+                    .fairness
+                    .iter()
+                    .flat_map(|f| f.columns.iter().map(String::as_str)),
+            ),
+    )
+}
+
+/// The columns of `input` to load (`None` for all) and how many are skipped
+fn resolve_projection(
+    input: &std::path::Path,
+    sheet: Option<&str>,
+    csv: &pipeline::CsvDialect,
+    projection: &ColumnProjection,
+) -> Result<(Option<Vec<String>>, usize)> {
+    if projection.is_all() {
+        return Ok((None, 0));
     }
-    count
+    let available = get_column_names_from_sheet(input, sheet, csv)?;
+    let columns = projection.resolve(&available)?;
+    let skipped = columns.as_ref().map_or(0, |c| available.len() - c.len());
+    Ok((columns, skipped))
 }
 
 /// Validate target column (headless version for TUI path — does NOT show interactive prompts).
@@ -1123,6 +2966,9 @@ fn validate_target_and_weights_headless(
     df: &polars::prelude::DataFrame,
     config: &mut PipelineConfig,
 ) -> Result<Vec<f64>> {
+    if config.no_target {
+        return get_weights(df, config.weight_column.as_deref());
+    }
     let column_names: Vec<String> = df
         .get_column_names()
         .iter()
@@ -1139,7 +2985,8 @@ fn validate_target_and_weights_headless(
     let weights = get_weights(df, config.weight_column.as_deref())?;
 
     // If target_mapping was already supplied (by wizard), skip binary check.
-    if config.target_mapping.is_none() {
+    // A continuous target is used as-is.
+    if config.target_mapping.is_none() && parse_target_type(config)? == TargetType::Binary {
         match analyze_target_column(df, &config.target)? {
             TargetAnalysis::AlreadyBinary => {}
             TargetAnalysis::NeedsMapping { unique_values } => {
@@ -1164,6 +3011,14 @@ fn validate_target_and_weights(
     config: &mut PipelineConfig,
     no_confirm: bool,
 ) -> Result<Option<Vec<f64>>> {
+    if config.no_target {
+        let weights = get_weights(df, config.weight_column.as_deref())?;
+        if let Some(weight_col) = &config.weight_column {
+            print_success(&format!("Using weight column: '{}'", weight_col));
+        }
+        print_success("No target: reducing on missingness, dominance and correlation");
+        return Ok(Some(weights));
+    }
     // Verify target column exists
     let column_names: Vec<String> = df
         .get_column_names()
@@ -1184,6 +3039,11 @@ fn validate_target_and_weights(
         print_success(&format!("Using weight column: '{}'", weight_col));
     }
 
+    if parse_target_type(config)? == TargetType::Continuous {
+        print_success("Continuous target: features are scored by binned R²");
+        return Ok(Some(weights));
+    }
+
     // Analyze target column to determine if mapping is needed
     if config.target_mapping.is_none() {
         match analyze_target_column(df, &config.target)? {
@@ -1191,7 +3051,8 @@ fn validate_target_and_weights(
                 // No mapping needed - target is already 0/1
             }
             TargetAnalysis::NeedsMapping { unique_values } => {
-                if no_confirm {
+                // Without the TUI there is no selector to fall back on
+                if no_confirm || !cfg!(feature = "tui") {
                     anyhow::bail!(
                         "Target column '{}' is not binary (0/1). Found {} unique values: {:?}\n\
                          Use --event-value and --non-event-value to specify which values map to 1 and 0.",
@@ -1218,6 +3079,7 @@ fn validate_target_and_weights(
                 }
                 println!();
 
+                #[cfg(feature = "tui")]
                 match run_target_mapping_selector(unique_values)? {
                     TargetMappingResult::Selected(mapping) => {
                         println!(
@@ -1248,134 +3110,713 @@ fn validate_target_and_weights(
     Ok(Some(weights))
 }
 
-/// Run missing value analysis (indicatif path)
-#[allow(clippy::type_complexity)]
-fn run_missing_analysis(
+/// Apply the configured non-finite policy to numeric features (indicatif path)
+fn handle_non_finite_values(
     df: &mut polars::prelude::DataFrame,
     config: &PipelineConfig,
-    weights: &[f64],
-    summary: &mut ReductionSummary,
-) -> Result<(Vec<(String, f64)>, Vec<String>)> {
-    print_step_header(1, "Missing Value Analysis");
-
-    let step_start = Instant::now();
-    let spinner = create_spinner("Analyzing missing values...");
-    let missing_ratios = analyze_missing_values(df, weights, config.weight_column.as_deref())?;
-    let features_to_drop_missing =
-        get_features_above_threshold(&missing_ratios, config.missing_threshold, &config.target);
-    finish_with_success(&spinner, "Missing value analysis complete");
+) -> Result<Vec<NonFiniteCounts>> {
+    let counts = handle_non_finite_values_bg(df, config)?;
+    if !counts.is_empty() {
+        let total: usize = counts.iter().map(|c| c.total()).sum();
+        print_info(&format!(
+            "{} non-finite value(s) in {} feature(s) handled with policy '{}'",
+            total,
+            counts.len(),
+            config.non_finite_policy
+        ));
+    }
+    Ok(counts)
+}
 
-    if features_to_drop_missing.is_empty() {
-        print_info("No features exceed the missing value threshold");
-    } else {
-        print_count(
-            "feature(s) with high missing values",
-            features_to_drop_missing.len(),
-            Some(&format!("(>{:.1}%)", config.missing_threshold * 100.0)),
-        );
+/// Apply the configured non-finite policy to numeric features (background path)
+fn handle_non_finite_values_bg(
+    df: &mut polars::prelude::DataFrame,
+    config: &PipelineConfig,
+) -> Result<Vec<NonFiniteCounts>> {
+    let policy: NonFinitePolicy = config
+        .non_finite_policy
+        .parse()
+        .map_err(|e: String| anyhow::anyhow!(e))?;
 
-        let taken = std::mem::take(df);
-        *df = taken.drop_many(&features_to_drop_missing);
-        summary.add_missing_drops(features_to_drop_missing.clone());
-        print_success("Dropped features with high missing values");
+    let mut exclude = vec![config.target.as_str()];
+    if let Some(weight_col) = config.weight_column.as_deref() {
+        exclude.push(weight_col);
+    }
+    if let Some(split_col) = config.split_column.as_deref() {
+        exclude.push(split_col);
     }
 
-    let missing_elapsed = step_start.elapsed();
-    summary.set_missing_time(missing_elapsed);
-    print_step_time(missing_elapsed);
-
-    Ok((missing_ratios, features_to_drop_missing))
+    apply_non_finite_policy(df, policy, &exclude)
 }
 
-/// Run missing value analysis (background / channel path)
-#[allow(clippy::type_complexity)]
-fn run_missing_analysis_bg(
+/// Apply the configured date policy to temporal features (indicatif path)
+fn handle_date_policy(
     df: &mut polars::prelude::DataFrame,
     config: &PipelineConfig,
-    weights: &[f64],
     summary: &mut ReductionSummary,
-) -> Result<(Vec<(String, f64)>, Vec<String>)> {
-    let step_start = Instant::now();
-    let missing_ratios = analyze_missing_values(df, weights, config.weight_column.as_deref())?;
-    let features_to_drop_missing =
-        get_features_above_threshold(&missing_ratios, config.missing_threshold, &config.target);
-
-    if !features_to_drop_missing.is_empty() {
-        let taken = std::mem::take(df);
-        *df = taken.drop_many(&features_to_drop_missing);
-        summary.add_missing_drops(features_to_drop_missing.clone());
+) -> Result<(DatePolicy, Vec<TemporalColumn>)> {
+    let (policy, temporal) = handle_date_policy_bg(df, config, summary)?;
+    if !temporal.is_empty() {
+        let names: Vec<&str> = temporal.iter().map(|t| t.feature.as_str()).collect();
+        match policy {
+            DatePolicy::Keep => print_info(&format!(
+                "{} date column(s) kept unscored: {} (use --date-policy derive to score them)",
+                temporal.len(),
+                names.join(", ")
+            )),
+            DatePolicy::Drop => print_info(&format!(
+                "Dropped {} date column(s): {}",
+                temporal.len(),
+                names.join(", ")
+            )),
+            DatePolicy::Derive => {
+                for t in temporal.iter().filter(|t| !t.derived.is_empty()) {
+                    print_info(&format!(
+                        "Derived {} from {}",
+                        t.derived.join(", "),
+                        t.feature
+                    ));
+                }
+            }
+        }
     }
-
-    let missing_elapsed = step_start.elapsed();
-    summary.set_missing_time(missing_elapsed);
-
-    Ok((missing_ratios, features_to_drop_missing))
+    Ok((policy, temporal))
 }
 
-/// Run Gini/IV analysis (indicatif path)
-fn run_gini_analysis(
-    df: &polars::prelude::DataFrame,
+/// Apply the configured date policy to temporal features (background path).
+/// Runs before any screening stage, so the feature count starts over from the
+/// columns the policy left.
+fn handle_date_policy_bg(
+    df: &mut polars::prelude::DataFrame,
     config: &PipelineConfig,
-    input: &std::path::Path,
-    weights: &[f64],
     summary: &mut ReductionSummary,
-) -> Result<(Vec<pipeline::IvAnalysis>, Vec<String>)> {
-    print_step_header(2, "Univariate Gini Analysis");
-
-    // Parse binning strategy
-    let binning_strategy: BinningStrategy = config
-        .binning_strategy
+) -> Result<(DatePolicy, Vec<TemporalColumn>)> {
+    let policy: DatePolicy = config
+        .date_policy
         .parse()
         .map_err(|e: String| anyhow::anyhow!(e))?;
 
-    // Parse solver config if solver is enabled
-    let solver_config = build_solver_config(config)?;
-
-    let step_start = Instant::now();
-    let gini_analyses = analyze_features_iv(
-        df,
-        &config.target,
-        config.gini_bins,
-        config.prebins,
-        config.target_mapping.as_ref(),
-        binning_strategy,
-        Some(config.min_category_samples),
-        Some(config.cart_min_bin_pct),
-        weights,
-        config.weight_column.as_deref(),
-        solver_config.as_ref(),
-    )?;
-    let features_to_drop_gini = get_low_gini_features(&gini_analyses, config.gini_threshold);
+    let mut exclude = vec![config.target.as_str()];
+    exclude.extend(config.weight_column.as_deref());
+    exclude.extend(config.split_column.as_deref());
+    // The time stability check slices the rows by the date itself
+    exclude.extend(config.time_stability.as_ref().map(|t| t.column.as_str()));
+
+    let temporal = apply_date_policy(df, policy, &exclude)?;
+    let removed = match policy {
+        DatePolicy::Keep => 0,
+        DatePolicy::Drop => temporal.len(),
+        DatePolicy::Derive => temporal.iter().filter(|t| !t.derived.is_empty()).count(),
+    };
+    let added: usize = temporal.iter().map(|t| t.derived.len()).sum();
+    summary.reset_initial_features(summary.initial_features + added - removed);
+    Ok((policy, temporal))
+}
 
-    export_gini(
-        &gini_analyses,
-        &features_to_drop_gini,
-        config,
+/// Fail before the screening stages when the `--segment-column` or
+/// `--time-column` is missing or unusable, rather than after them
+fn check_grouping_columns(df: &polars::prelude::DataFrame, config: &PipelineConfig) -> Result<()> {
+    if let Some(segment_column) = &config.segment_column {
+        if segment_column == &config.target {
+            anyhow::bail!("Segment column '{}' cannot be the target", segment_column);
+        }
+        let column = df.column(segment_column).map_err(|_| {
+            anyhow::anyhow!("Segment column '{}' not found in dataset", segment_column)
+        })?;
+        Segments::from_column(column)?;
+    }
+    if let Some(check) = &config.time_stability {
+        let column = df
+            .column(&check.column)
+            .map_err(|_| anyhow::anyhow!("Time column '{}' not found in dataset", check.column))?;
+        TimePeriods::from_column(column, check.freq)?;
+    }
+    for protected in config.fairness.iter().flat_map(|f| &f.columns) {
+        if protected == &config.target {
+            anyhow::bail!("Protected column '{}' cannot be the target", protected);
+        }
+        let column = df.column(protected).map_err(|_| {
+            anyhow::anyhow!("Protected column '{}' not found in dataset", protected)
+        })?;
+        Segments::from_column(column)?;
+    }
+    Ok(())
+}
+
+/// Restrict the screening statistics to the training rows of `--split-column`.
+///
+/// Returns `None` when no split column is configured. Otherwise returns the
+/// training rows (without the split column) and their weights, and records the
+/// partition in the report. The caller keeps the full frame and applies the
+/// drops to it before saving (see [`restore_full_rows`]).
+fn train_partition_for_screening(
+    df: &polars::prelude::DataFrame,
+    weights: &[f64],
+    config: &PipelineConfig,
+    report_builder: &mut ReductionReportBuilder,
+) -> Result<Option<(polars::prelude::DataFrame, Vec<f64>)>> {
+    let (split_column, train_value) = match (&config.split_column, &config.train_value) {
+        (None, None) => return Ok(None),
+        (Some(column), Some(value)) => (column, value),
+        _ => anyhow::bail!("Both --split-column and --train-value must be provided together"),
+    };
+    if split_column == &config.target || config.weight_column.as_ref() == Some(split_column) {
+        anyhow::bail!(
+            "Split column '{}' cannot be the target or weight column",
+            split_column
+        );
+    }
+
+    let (train_df, train_weights) = train_partition(df, weights, split_column, train_value)?;
+    report_builder.set_train_partition(TrainPartitionSettings {
+        split_column: split_column.clone(),
+        train_value: train_value.clone(),
+        train_rows: train_df.height(),
+        total_rows: df.height(),
+    });
+
+    Ok(Some((train_df, train_weights)))
+}
+
+/// Apply every stage's drops to the full dataset after screening on the training rows
+fn restore_full_rows(
+    full_df: polars::prelude::DataFrame,
+    summary: &ReductionSummary,
+) -> polars::prelude::DataFrame {
+    let dropped: Vec<String> = summary
+        .dropped_constant
+        .iter()
+        .chain(&summary.dropped_identifier)
+        .chain(&summary.dropped_missing)
+        .chain(&summary.dropped_gini)
+        .chain(&summary.dropped_correlation)
+        .cloned()
+        .collect();
+    full_df.drop_many(&dropped)
+}
+
+/// Mapping to apply to the target column of the output, when
+/// `--output-include-target-mapping` is set and the target was mapped
+fn output_target_mapping(config: &PipelineConfig) -> Option<&TargetMapping> {
+    config
+        .target_mapping
+        .as_ref()
+        .filter(|_| config.output_include_target_mapping)
+}
+
+/// Open the stage checkpoint of this run. A change to any setting other than
+/// the output path, `--dry-run`, the `--fail-on-*` switches, the memory budget
+/// or the dictionary, or to the input file(s), starts a fresh checkpoint, so a
+/// full run can resume from a dry run.
+fn open_checkpoint(config: &PipelineConfig) -> Result<Checkpoint> {
+    let files = pipeline::input_files(&config.input)?;
+    let (size, modified) = match &files {
+        Some(input) => (input.total_bytes(), input.last_modified()),
+        // Polars expands a cloud glob; only single objects are fingerprinted
+        None if pipeline::is_cloud_uri(&config.input) => {
+            if pipeline::multi_file::is_glob(&config.input) {
+                (0, None)
+            } else {
+                pipeline::object_metadata(&config.input)?
+            }
+        }
+        None => {
+            let metadata = std::fs::metadata(&config.input)?;
+            (metadata.len(), metadata.modified().ok())
+        }
+    };
+    let mut settings = format!(
+        "{:?} size={} modified={:?}",
+        PipelineConfig {
+            output: std::path::PathBuf::new(),
+            report_dir: None,
+            resume: false,
+            dry_run: false,
+            table_exports: TableExports::default(),
+            expect_schema: None,
+            fail_on_schema_drift: false,
+            fail_on_solver_fallback: false,
+            max_memory_gb: None,
+            dictionary: None,
+            ..config.clone()
+        },
+        size,
+        modified
+    );
+    // A file added to or removed from a multi-file input changes the data
+    if let Some(input) = files {
+        settings.push_str(&format!(" files={:?}", input.files));
+    }
+    Checkpoint::open(&config.input, &settings, config.resume)
+}
+
+/// Apply the missing-value drops of a checkpointed stage
+#[allow(clippy::type_complexity)]
+fn resume_missing_stage(
+    df: &mut polars::prelude::DataFrame,
+    stage: MissingCheckpoint,
+    summary: &mut ReductionSummary,
+) -> (
+    Vec<(String, f64)>,
+    Vec<String>,
+    Option<SegmentMissingAnalysis>,
+) {
+    if !stage.dropped.is_empty() {
+        let taken = std::mem::take(df);
+        *df = taken.drop_many(&stage.dropped);
+        summary.add_missing_drops(stage.dropped.clone());
+    }
+    (stage.missing_ratios, stage.dropped, stage.segments)
+}
+
+/// Record a checkpointed Gini/IV (or R²) stage and rewrite its analysis export
+#[allow(clippy::type_complexity)]
+fn resume_gini_stage(
+    stage: GiniCheckpoint,
+    df: &polars::prelude::DataFrame,
+    config: &PipelineConfig,
+    input: &std::path::Path,
+    weights: &[f64],
+    summary: &mut ReductionSummary,
+    report_builder: &mut ReductionReportBuilder,
+) -> Result<(Vec<pipeline::IvAnalysis>, Vec<pipeline::RSquaredAnalysis>)> {
+    match parse_target_type(config)? {
+        TargetType::Binary => {
+            let binning_strategy: BinningStrategy = config
+                .binning_strategy
+                .parse()
+                .map_err(|e: String| anyhow::anyhow!(e))?;
+            report_builder.set_gini_results(&stage.iv_analyses, &stage.dropped);
+            report_builder.set_concordance_results(&stage.concordance);
+            report_builder.set_cross_validation(
+                &stage.cross_validation,
+                config.cross_validation.as_ref().and_then(|cv| cv.min_iv),
+            );
+            if let Some(analysis) = &stage.segment_iv {
+                report_builder.set_segment_iv(analysis.clone());
+            }
+            export_gini(
+                &stage.iv_analyses,
+                &stage.dropped,
+                df,
+                weights,
+                config,
+                input,
+                &stage.cross_validation,
+                stage.segment_iv.as_ref(),
+            )?;
+            if let Some(path) = cart_trees_output_path(input, config, binning_strategy) {
+                export_cart_tree_diagnostics(df, config, weights, &path)?;
+            }
+        }
+        TargetType::Continuous => {
+            report_builder.set_r2_results(&stage.r2_analyses, &stage.dropped);
+            export_r2(&stage.r2_analyses, &stage.dropped, config, input)?;
+        }
+    }
+    if !stage.dropped.is_empty() {
+        summary.add_gini_drops(stage.dropped);
+    }
+    Ok((stage.iv_analyses, stage.r2_analyses))
+}
+
+/// Drop constant and near-constant features before missing analysis
+/// (indicatif path). Does nothing without `--dominance-threshold`.
+fn run_dominance_filter(
+    df: &mut polars::prelude::DataFrame,
+    config: &PipelineConfig,
+    weights: &[f64],
+    summary: &mut ReductionSummary,
+    report_builder: &mut ReductionReportBuilder,
+) -> Result<()> {
+    let Some(threshold) = config.dominance_threshold else {
+        return Ok(());
+    };
+    print_step_header(0, "Near-constant Pre-filter");
+
+    let step_start = Instant::now();
+    let spinner = create_spinner("Scanning for constant features...");
+    let stats = analyze_dominance(df, weights, config.weight_column.as_deref())?;
+    let dropped =
+        get_dominated_features_with_keep(&stats, threshold, &config.target, &keep_columns(config));
+    finish_with_success(&spinner, "Near-constant scan complete");
+
+    if dropped.is_empty() {
+        print_info("No constant or near-constant features");
+    } else {
+        print_count(
+            "constant or near-constant feature(s)",
+            dropped.len(),
+            Some(&format!("(one value >{:.1}%)", threshold * 100.0)),
+        );
+        let taken = std::mem::take(df);
+        *df = taken.drop_many(&dropped);
+        print_success("Dropped constant and near-constant features");
+    }
+    report_builder.set_dominance_results(&stats, &dropped, threshold);
+    summary.add_constant_drops(dropped);
+
+    let elapsed = step_start.elapsed();
+    summary.set_constant_time(elapsed);
+    print_step_time(elapsed);
+    Ok(())
+}
+
+/// Drop constant and near-constant features before missing analysis
+/// (background / channel path)
+fn run_dominance_filter_bg(
+    df: &mut polars::prelude::DataFrame,
+    config: &PipelineConfig,
+    weights: &[f64],
+    summary: &mut ReductionSummary,
+    report_builder: &mut ReductionReportBuilder,
+) -> Result<()> {
+    let Some(threshold) = config.dominance_threshold else {
+        return Ok(());
+    };
+    let step_start = Instant::now();
+    let stats = analyze_dominance(df, weights, config.weight_column.as_deref())?;
+    let dropped =
+        get_dominated_features_with_keep(&stats, threshold, &config.target, &keep_columns(config));
+    if !dropped.is_empty() {
+        let taken = std::mem::take(df);
+        *df = taken.drop_many(&dropped);
+    }
+    report_builder.set_dominance_results(&stats, &dropped, threshold);
+    summary.add_constant_drops(dropped);
+    summary.set_constant_time(step_start.elapsed());
+    Ok(())
+}
+
+/// Find likely ID columns by their share of distinct values and flag or
+/// drop them per `--id-action` (indicatif path). Does nothing without
+/// `--id-threshold`.
+fn run_id_check(
+    df: &mut polars::prelude::DataFrame,
+    config: &PipelineConfig,
+    summary: &mut ReductionSummary,
+    report_builder: &mut ReductionReportBuilder,
+) -> Result<()> {
+    let Some(threshold) = config.id_threshold else {
+        return Ok(());
+    };
+    let action = parse_id_action(config)?;
+    print_step_header(0, "ID Column Check");
+
+    let step_start = Instant::now();
+    let spinner = create_spinner("Counting distinct values...");
+    let stats = analyze_cardinality(df, config.weight_column.as_deref())?;
+    let id_like =
+        get_id_like_features_with_keep(&stats, threshold, &config.target, &keep_columns(config));
+    finish_with_success(&spinner, "Distinct value count complete");
+
+    if id_like.is_empty() {
+        print_info("No likely ID columns");
+    } else {
+        print_count(
+            "likely ID column(s)",
+            id_like.len(),
+            Some(&format!("(>{:.1}% distinct values)", threshold * 100.0)),
+        );
+        if action == IdAction::Drop {
+            let taken = std::mem::take(df);
+            *df = taken.drop_many(&id_like);
+            print_success("Dropped likely ID columns");
+        } else {
+            println!(
+                "   {} Kept: {} (use --id-action drop to drop them)",
+                style("⚠").yellow(),
+                id_like.join(", ")
+            );
+        }
+    }
+    report_builder.set_cardinality_results(&stats, &id_like, threshold, action);
+    if action == IdAction::Drop {
+        summary.add_identifier_drops(id_like);
+    }
+
+    let elapsed = step_start.elapsed();
+    summary.set_identifier_time(elapsed);
+    print_step_time(elapsed);
+    Ok(())
+}
+
+/// Find likely ID columns and flag or drop them (background / channel path)
+fn run_id_check_bg(
+    df: &mut polars::prelude::DataFrame,
+    config: &PipelineConfig,
+    summary: &mut ReductionSummary,
+    report_builder: &mut ReductionReportBuilder,
+) -> Result<()> {
+    let Some(threshold) = config.id_threshold else {
+        return Ok(());
+    };
+    let action = parse_id_action(config)?;
+    let step_start = Instant::now();
+    let stats = analyze_cardinality(df, config.weight_column.as_deref())?;
+    let id_like =
+        get_id_like_features_with_keep(&stats, threshold, &config.target, &keep_columns(config));
+    if action == IdAction::Drop && !id_like.is_empty() {
+        let taken = std::mem::take(df);
+        *df = taken.drop_many(&id_like);
+    }
+    report_builder.set_cardinality_results(&stats, &id_like, threshold, action);
+    if action == IdAction::Drop {
+        summary.add_identifier_drops(id_like);
+    }
+    summary.set_identifier_time(step_start.elapsed());
+    Ok(())
+}
+
+/// Run missing value analysis (indicatif path)
+#[allow(clippy::type_complexity)]
+fn run_missing_analysis(
+    df: &mut polars::prelude::DataFrame,
+    config: &PipelineConfig,
+    weights: &[f64],
+    summary: &mut ReductionSummary,
+) -> Result<(Vec<(String, f64)>, Vec<String>)> {
+    print_step_header(1, "Missing Value Analysis");
+
+    let step_start = Instant::now();
+    let spinner = create_spinner("Analyzing missing values...");
+    let missing_ratios = analyze_missing_values(df, weights, config.weight_column.as_deref())?;
+    let features_to_drop_missing = get_features_above_threshold_with_keep(
+        &missing_ratios,
+        config.missing_threshold,
+        &config.target,
+        &keep_columns(config),
+    );
+    finish_with_success(&spinner, "Missing value analysis complete");
+
+    if features_to_drop_missing.is_empty() {
+        print_info("No features exceed the missing value threshold");
+    } else {
+        print_count(
+            "feature(s) with high missing values",
+            features_to_drop_missing.len(),
+            Some(&format!("(>{:.1}%)", config.missing_threshold * 100.0)),
+        );
+
+        let taken = std::mem::take(df);
+        *df = taken.drop_many(&features_to_drop_missing);
+        summary.add_missing_drops(features_to_drop_missing.clone());
+        print_success("Dropped features with high missing values");
+    }
+
+    let missing_elapsed = step_start.elapsed();
+    summary.set_missing_time(missing_elapsed);
+    print_step_time(missing_elapsed);
+
+    Ok((missing_ratios, features_to_drop_missing))
+}
+
+/// Missing ratios per segment of `--segment-column`, before the missing drops
+fn analyze_segment_missing(
+    df: &polars::prelude::DataFrame,
+    config: &PipelineConfig,
+    weights: &[f64],
+) -> Result<Option<SegmentMissingAnalysis>> {
+    let Some(segment_column) = &config.segment_column else {
+        return Ok(None);
+    };
+    let mut exclude = vec![config.target.as_str()];
+    exclude.extend(config.split_column.as_deref());
+    exclude.extend(config.time_stability.as_ref().map(|t| t.column.as_str()));
+    analyze_missing_by_segment(
+        df,
+        weights,
+        config.weight_column.as_deref(),
+        segment_column,
+        &exclude,
+    )
+    .map(Some)
+}
+
+/// Run missing value analysis (background / channel path)
+#[allow(clippy::type_complexity)]
+fn run_missing_analysis_bg(
+    df: &mut polars::prelude::DataFrame,
+    config: &PipelineConfig,
+    weights: &[f64],
+    summary: &mut ReductionSummary,
+) -> Result<(Vec<(String, f64)>, Vec<String>)> {
+    let step_start = Instant::now();
+    let missing_ratios = analyze_missing_values(df, weights, config.weight_column.as_deref())?;
+    let features_to_drop_missing = get_features_above_threshold_with_keep(
+        &missing_ratios,
+        config.missing_threshold,
+        &config.target,
+        &keep_columns(config),
+    );
+
+    if !features_to_drop_missing.is_empty() {
+        let taken = std::mem::take(df);
+        *df = taken.drop_many(&features_to_drop_missing);
+        summary.add_missing_drops(features_to_drop_missing.clone());
+    }
+
+    let missing_elapsed = step_start.elapsed();
+    summary.set_missing_time(missing_elapsed);
+
+    Ok((missing_ratios, features_to_drop_missing))
+}
+
+/// Run Gini/IV analysis (indicatif path)
+#[allow(clippy::type_complexity)]
+fn run_gini_analysis(
+    df: &polars::prelude::DataFrame,
+    config: &PipelineConfig,
+    input: &std::path::Path,
+    weights: &[f64],
+    summary: &mut ReductionSummary,
+) -> Result<(
+    Vec<pipeline::IvAnalysis>,
+    Vec<String>,
+    Vec<CrossValidatedIv>,
+    Option<SegmentIvAnalysis>,
+)> {
+    print_step_header(2, "Univariate Gini Analysis");
+
+    // Parse binning strategy
+    let binning_strategy: BinningStrategy = config
+        .binning_strategy
+        .parse()
+        .map_err(|e: String| anyhow::anyhow!(e))?;
+
+    // Parse solver config if solver is enabled
+    let solver_config = build_solver_config(config)?;
+    if config.use_solver
+        && !SOLVER_AVAILABLE
+        && parse_binning_backend(config)? == BinningBackend::Mip
+    {
+        print_info("Solver support is not compiled into this build, using the dp backend");
+    }
+
+    if let Some(bins) = &config.bins_from {
+        print_info(&format!(
+            "Evaluating the bins of {} feature(s) from {}",
+            bins.features.len(),
+            bins.path.display()
+        ));
+    }
+
+    let step_start = Instant::now();
+    let mut gini_analyses = match &config.bins_from {
+        Some(bins) => analyze_features_with_bins(
+            df,
+            &config.target,
+            config.target_mapping.as_ref(),
+            weights,
+            config.weight_column.as_deref(),
+            bins,
+        )?,
+        None => analyze_features_iv(
+            df,
+            &config.target,
+            config.gini_bins,
+            config.prebins,
+            config.target_mapping.as_ref(),
+            binning_strategy,
+            Some(config.min_category_samples),
+            Some(config.cart_min_bin_pct),
+            weights,
+            config.weight_column.as_deref(),
+            solver_config.as_ref(),
+            config.special_values.as_ref(),
+            parse_missing_bin_policy(config)?,
+            config.bin_breaks.as_ref(),
+        )?,
+    };
+    report_solver_status(&gini_analyses);
+    check_solver_fallbacks(&gini_analyses, config)?;
+    if let Some(alpha) = config.merge_insignificant_bins {
+        let merged = merge_insignificant_bins(&mut gini_analyses, alpha);
+        if merged > 0 {
+            print_info(&format!(
+                "Merged {} adjacent bin(s) with no significant difference (p > {})",
+                merged, alpha
+            ));
+        }
+    }
+    let metric = parse_univariate_metric(config)?;
+    let mut features_to_drop_gini = get_low_univariate_features_with_keep(
+        &mut gini_analyses,
+        metric,
+        univariate_threshold(config),
+        &keep_columns(config),
+    );
+
+    let cross_validation = run_cross_validation(df, weights, config, None)?;
+    let low_cv = low_cv_iv_drops(&cross_validation, &features_to_drop_gini, config);
+    if let Some(cv) = &config.cross_validation {
+        print_info(&format!(
+            "Cross-validated IV of {} feature(s) over {} folds",
+            cross_validation.len(),
+            cv.folds
+        ));
+    }
+    if !low_cv.is_empty() {
+        print_count("feature(s) with low cross-validated IV", low_cv.len(), None);
+    }
+    features_to_drop_gini.extend(low_cv);
+
+    let segment_iv = run_segment_iv(df, &gini_analyses, weights, config, None)?;
+    if let Some(analysis) = &segment_iv {
+        let divergent = analysis.divergent();
+        print_info(&format!(
+            "Segment IV: {} of {} feature(s) diverge across the {} segments of '{}'",
+            divergent.len(),
+            analysis.features.len(),
+            analysis.segments.len(),
+            analysis.column
+        ));
+        if !divergent.is_empty() {
+            print_info(&format!("  {}", divergent.join(", ")));
+        }
+    }
+
+    export_gini(
+        &gini_analyses,
+        &features_to_drop_gini,
+        df,
+        weights,
+        config,
         input,
-        binning_strategy,
+        &cross_validation,
+        segment_iv.as_ref(),
     )?;
+    if let Some(path) = cart_trees_output_path(input, config, binning_strategy) {
+        export_cart_tree_diagnostics(df, config, weights, &path)?;
+    }
 
     if features_to_drop_gini.is_empty() {
-        print_info("No features below Gini threshold");
+        print_info(&format!("No features below {} threshold", metric.label()));
     } else {
         print_count(
-            "feature(s) with low Gini",
+            &format!("feature(s) with low {}", metric.label()),
             features_to_drop_gini.len(),
-            Some(&format!("(<{:.2})", config.gini_threshold)),
+            Some(&format!("(<{})", univariate_threshold(config))),
         );
 
         summary.add_gini_drops(features_to_drop_gini.clone());
-        print_success("Dropped low Gini features");
+        print_success(&format!("Dropped low {} features", metric.label()));
     }
 
     let gini_elapsed = step_start.elapsed();
     summary.set_gini_time(gini_elapsed);
     print_step_time(gini_elapsed);
 
-    Ok((gini_analyses, features_to_drop_gini))
+    Ok((
+        gini_analyses,
+        features_to_drop_gini,
+        cross_validation,
+        segment_iv,
+    ))
 }
 
 /// Run Gini/IV analysis (background / channel path)
+#[allow(clippy::type_complexity)]
 fn run_gini_analysis_bg(
     df: &polars::prelude::DataFrame,
     config: &PipelineConfig,
@@ -1383,7 +3824,12 @@ fn run_gini_analysis_bg(
     weights: &[f64],
     summary: &mut ReductionSummary,
     tx: &ProgressSender,
-) -> Result<(Vec<pipeline::IvAnalysis>, Vec<String>)> {
+) -> Result<(
+    Vec<pipeline::IvAnalysis>,
+    Vec<String>,
+    Vec<CrossValidatedIv>,
+    Option<SegmentIvAnalysis>,
+)> {
     let binning_strategy: BinningStrategy = config
         .binning_strategy
         .parse()
@@ -1392,44 +3838,725 @@ fn run_gini_analysis_bg(
     let solver_config = build_solver_config(config)?;
 
     let step_start = Instant::now();
-    let gini_analyses = analyze_features_iv_with_progress(
+    let mut gini_analyses = match &config.bins_from {
+        Some(bins) => analyze_features_with_bins(
+            df,
+            &config.target,
+            config.target_mapping.as_ref(),
+            weights,
+            config.weight_column.as_deref(),
+            bins,
+        )?,
+        None => analyze_features_iv_with_progress(
+            df,
+            &config.target,
+            config.gini_bins,
+            config.prebins,
+            config.target_mapping.as_ref(),
+            binning_strategy,
+            Some(config.min_category_samples),
+            Some(config.cart_min_bin_pct),
+            weights,
+            config.weight_column.as_deref(),
+            solver_config.as_ref(),
+            config.special_values.as_ref(),
+            parse_missing_bin_policy(config)?,
+            config.bin_breaks.as_ref(),
+            tx,
+        )?,
+    };
+    check_solver_fallbacks(&gini_analyses, config)?;
+    if let Some(alpha) = config.merge_insignificant_bins {
+        merge_insignificant_bins(&mut gini_analyses, alpha);
+    }
+    let metric = parse_univariate_metric(config)?;
+    let mut features_to_drop_gini = get_low_univariate_features_with_keep(
+        &mut gini_analyses,
+        metric,
+        univariate_threshold(config),
+        &keep_columns(config),
+    );
+
+    let cross_validation = run_cross_validation(df, weights, config, Some(tx))?;
+    let low_cv = low_cv_iv_drops(&cross_validation, &features_to_drop_gini, config);
+    features_to_drop_gini.extend(low_cv);
+
+    let segment_iv = run_segment_iv(df, &gini_analyses, weights, config, Some(tx))?;
+    if let Some(analysis) = &segment_iv {
+        tx.send(ProgressEvent::update(
+            PipelineStage::GiniAnalysis,
+            "Segment IV divergent",
+            format!(
+                "{} of {} features",
+                analysis.divergent().len(),
+                analysis.features.len()
+            ),
+        ))
+        .ok();
+    }
+
+    export_gini(
+        &gini_analyses,
+        &features_to_drop_gini,
+        df,
+        weights,
+        config,
+        input,
+        &cross_validation,
+        segment_iv.as_ref(),
+    )?;
+    if let Some(path) = cart_trees_output_path(input, config, binning_strategy) {
+        export_cart_tree_diagnostics(df, config, weights, &path)?;
+    }
+
+    if !features_to_drop_gini.is_empty() {
+        summary.add_gini_drops(features_to_drop_gini.clone());
+    }
+
+    let gini_elapsed = step_start.elapsed();
+    summary.set_gini_time(gini_elapsed);
+
+    Ok((
+        gini_analyses,
+        features_to_drop_gini,
+        cross_validation,
+        segment_iv,
+    ))
+}
+
+/// Cross-validated IV of every feature of `df` (`--cv-folds`), binned with the
+/// settings of the Gini stage on each training split. Empty when not requested.
+fn run_cross_validation(
+    df: &polars::prelude::DataFrame,
+    weights: &[f64],
+    config: &PipelineConfig,
+    tx: Option<&ProgressSender>,
+) -> Result<Vec<CrossValidatedIv>> {
+    let Some(cv) = &config.cross_validation else {
+        return Ok(Vec::new());
+    };
+    let assignment = fold_assignments(df.height(), cv.folds, config.seed)?;
+    cross_validate_iv(
         df,
         &config.target,
-        config.gini_bins,
-        config.prebins,
         config.target_mapping.as_ref(),
-        binning_strategy,
+        weights,
+        &assignment,
+        |train_df, train_weights| fit_iv_bins(train_df, train_weights, config, tx),
+    )
+}
+
+/// Features `--cv-min-iv` drops: a low mean holdout IV, not in `--keep-columns`
+/// and not already dropped by the univariate filter
+fn low_cv_iv_drops(
+    results: &[CrossValidatedIv],
+    already_dropped: &[String],
+    config: &PipelineConfig,
+) -> Vec<String> {
+    let Some(min_iv) = config.cross_validation.as_ref().and_then(|cv| cv.min_iv) else {
+        return Vec::new();
+    };
+    let keep = keep_columns(config);
+    get_low_cv_iv_features(results, min_iv)
+        .into_iter()
+        .filter(|f| !keep.contains(f) && !already_dropped.contains(f))
+        .collect()
+}
+
+/// Compare the IV of the surviving features on training and validation rows
+/// (`--iv-stability`). Returns the report section and the unstable features
+/// `--iv-stability-threshold` drops.
+///
+/// With a split column the training IVs are those of the Gini stage and the
+/// validation rows are the rest of `full_df`; otherwise `df` is split at random
+/// and both halves are scored.
+fn run_iv_stability(
+    df: &polars::prelude::DataFrame,
+    full_df: Option<&polars::prelude::DataFrame>,
+    gini_analyses: &[pipeline::IvAnalysis],
+    weights: &[f64],
+    config: &PipelineConfig,
+    tx: Option<&ProgressSender>,
+) -> Result<Option<(IvStabilitySection, Vec<String>)>> {
+    let Some(check) = &config.iv_stability else {
+        return Ok(None);
+    };
+    if config.target_type == "continuous" {
+        anyhow::bail!("--iv-stability needs a binary target");
+    }
+
+    let (split, validation_fraction, train_rows, validation_rows, train, validation) =
+        match (full_df, &config.split_column, &config.train_value) {
+            (Some(full), Some(split_column), Some(train_value)) => {
+                let mask = !create_train_mask(full, split_column, train_value)?;
+                let validation_df = full.filter(&mask)?.select(df.get_column_names_str())?;
+                if validation_df.height() == 0 {
+                    anyhow::bail!(
+                        "--iv-stability found no validation rows: every row of '{}' is '{}'",
+                        split_column,
+                        train_value
+                    );
+                }
+                let validation_weights =
+                    get_weights(&validation_df, config.weight_column.as_deref())?;
+                let train: Vec<pipeline::IvAnalysis> = gini_analyses
+                    .iter()
+                    .filter(|a| df.column(&a.feature_name).is_ok())
+                    .cloned()
+                    .collect();
+                let validation = fit_iv_bins(&validation_df, &validation_weights, config, tx)?;
+                (
+                    "split_column",
+                    None,
+                    df.height(),
+                    validation_df.height(),
+                    train,
+                    validation,
+                )
+            }
+            _ => {
+                let mask = random_validation_mask(df.height(), check.validation_frac, config.seed)?;
+                let (train_df, train_weights) = select_rows(df, weights, &!&mask)?;
+                let (validation_df, validation_weights) = select_rows(df, weights, &mask)?;
+                (
+                    "random",
+                    Some(check.validation_frac),
+                    train_df.height(),
+                    validation_df.height(),
+                    fit_iv_bins(&train_df, &train_weights, config, tx)?,
+                    fit_iv_bins(&validation_df, &validation_weights, config, tx)?,
+                )
+            }
+        };
+
+    let features = compare_iv(&train, &validation, check.threshold());
+    let dropped = match check.drop_threshold {
+        Some(_) => {
+            let keep = keep_columns(config);
+            features
+                .iter()
+                .filter(|f| f.unstable && !keep.contains(&f.feature))
+                .map(|f| f.feature.clone())
+                .collect()
+        }
+        None => Vec::new(),
+    };
+    let section = IvStabilitySection {
+        split: split.to_string(),
+        validation_fraction,
+        train_rows,
+        validation_rows,
+        threshold: check.threshold(),
+        drop_unstable: check.drop_threshold.is_some(),
+        features,
+    };
+    Ok(Some((section, dropped)))
+}
+
+/// IV of every feature of the Gini stage within each segment of
+/// `--segment-column`, binned with the settings of the Gini stage.
+///
+/// A segment with a single target class, or nearly so, is logged as a warning
+/// with its class counts and left unscored. `None` without a segment column or
+/// for a continuous target.
+fn run_segment_iv(
+    df: &polars::prelude::DataFrame,
+    gini_analyses: &[pipeline::IvAnalysis],
+    weights: &[f64],
+    config: &PipelineConfig,
+    tx: Option<&ProgressSender>,
+) -> Result<Option<SegmentIvAnalysis>> {
+    let Some(segment_column) = &config.segment_column else {
+        return Ok(None);
+    };
+    if config.target_type == "continuous" {
+        return Ok(None);
+    }
+    let segments = Segments::from_column(df.column(segment_column)?)?;
+
+    let features: Vec<&pipeline::IvAnalysis> = gini_analyses
+        .iter()
+        .filter(|a| a.feature_name != *segment_column && df.column(&a.feature_name).is_ok())
+        .collect();
+    let columns: Vec<&str> = features
+        .iter()
+        .map(|a| a.feature_name.as_str())
+        .chain(std::iter::once(config.target.as_str()))
+        .chain(config.weight_column.as_deref())
+        .collect();
+    let scored_df = df.select(columns)?;
+
+    let mut summaries = Vec::with_capacity(segments.labels.len());
+    let mut series = vec![Vec::with_capacity(segments.labels.len()); features.len()];
+    for (index, label) in segments.labels.iter().enumerate() {
+        let (segment_df, segment_weights) =
+            select_rows(&scored_df, weights, &segments.mask(index))?;
+        let counts =
+            count_target_classes(&segment_df, &config.target, config.target_mapping.as_ref())?;
+        let single_class = counts.events == 0 || counts.non_events == 0;
+        if single_class || counts.is_nearly_single_class() {
+            tracing::warn!(
+                "Segment '{}' of '{}' is {}single-class ({} event, {} non-event); \
+                 its IVs are left out of the segment comparison",
+                label,
+                segment_column,
+                if single_class { "" } else { "nearly " },
+                counts.events,
+                counts.non_events
+            );
+        }
+        let scored = !single_class && !counts.is_nearly_single_class();
+        let ivs: std::collections::HashMap<String, f64> = if scored {
+            fit_iv_bins(&segment_df, &segment_weights, config, tx)?
+                .into_iter()
+                .map(|a| (a.feature_name, a.iv))
+                .collect()
+        } else {
+            std::collections::HashMap::new()
+        };
+
+        for (feature, series) in features.iter().zip(series.iter_mut()) {
+            series.push(pipeline::SegmentFeatureIv {
+                segment: label.clone(),
+                // A feature the segment could not bin (e.g. constant there) has no information
+                iv: scored.then(|| ivs.get(&feature.feature_name).copied().unwrap_or(0.0)),
+            });
+        }
+        summaries.push(pipeline::SegmentSummary {
+            segment: label.clone(),
+            rows: segment_df.height(),
+            events: counts.events,
+            non_events: counts.non_events,
+            scored,
+        });
+    }
+
+    Ok(Some(SegmentIvAnalysis {
+        column: segment_column.clone(),
+        segments: summaries,
+        unsegmented_rows: segments.unsegmented_rows(),
+        divergence_threshold: pipeline::SEGMENT_IV_DIVERGENCE_THRESHOLD,
+        features: features
+            .into_iter()
+            .zip(series)
+            .map(|(analysis, segments)| {
+                assess_segment_iv(&analysis.feature_name, analysis.iv, segments)
+            })
+            .collect(),
+    }))
+}
+
+/// Slice the rows by `--time-column` and fit the fill-rate and IV trend of
+/// every feature of `gini_analyses` still in `df` over the periods. Drifting
+/// features are only flagged.
+///
+/// A period whose target is (nearly) single-class gets no IV; its missing
+/// rates still count.
+fn run_time_stability(
+    df: &polars::prelude::DataFrame,
+    gini_analyses: &[pipeline::IvAnalysis],
+    weights: &[f64],
+    config: &PipelineConfig,
+    tx: Option<&ProgressSender>,
+) -> Result<Option<TimeStabilitySection>> {
+    let Some(check) = &config.time_stability else {
+        return Ok(None);
+    };
+    if config.target_type == "continuous" {
+        anyhow::bail!("--time-column needs a binary target");
+    }
+    let periods = TimePeriods::from_column(df.column(&check.column)?, check.freq)?;
+    if periods.labels.len() < 2 {
+        anyhow::bail!(
+            "--time-column '{}' spans {} {} period(s); a trend needs at least 2",
+            check.column,
+            periods.labels.len(),
+            check.freq
+        );
+    }
+
+    let features: Vec<&str> = gini_analyses
+        .iter()
+        .map(|a| a.feature_name.as_str())
+        .filter(|f| df.column(f).is_ok())
+        .collect();
+    let columns: Vec<&str> = features
+        .iter()
+        .copied()
+        .chain(std::iter::once(config.target.as_str()))
+        .chain(config.weight_column.as_deref())
+        .collect();
+    let scored_df = df.select(columns)?;
+
+    let mut summaries = Vec::with_capacity(periods.labels.len());
+    let mut series = vec![Vec::with_capacity(periods.labels.len()); features.len()];
+    for (index, label) in periods.labels.iter().enumerate() {
+        let (period_df, period_weights) = select_rows(&scored_df, weights, &periods.mask(index))?;
+        let counts =
+            count_target_classes(&period_df, &config.target, config.target_mapping.as_ref())?;
+        let scored = counts.events > 0 && counts.non_events > 0 && !counts.is_nearly_single_class();
+        let ivs: std::collections::HashMap<String, f64> = if scored {
+            fit_iv_bins(&period_df, &period_weights, config, tx)?
+                .into_iter()
+                .map(|a| (a.feature_name, a.iv))
+                .collect()
+        } else {
+            std::collections::HashMap::new()
+        };
+        let missing: std::collections::HashMap<String, f64> =
+            analyze_missing_values(&period_df, &period_weights, config.weight_column.as_deref())?
+                .into_iter()
+                .collect();
+
+        for (feature, series) in features.iter().zip(series.iter_mut()) {
+            series.push(pipeline::FeaturePeriod {
+                period: label.clone(),
+                missing_ratio: missing.get(*feature).copied().unwrap_or(0.0),
+                // A feature the period could not bin (e.g. constant there) has no information
+                iv: scored.then(|| ivs.get(*feature).copied().unwrap_or(0.0)),
+            });
+        }
+        summaries.push(TimePeriodSummary {
+            period: label.clone(),
+            rows: period_df.height(),
+            events: counts.events,
+            non_events: counts.non_events,
+            scored,
+        });
+    }
+
+    Ok(Some(TimeStabilitySection {
+        column: check.column.clone(),
+        freq: check.freq,
+        periods: summaries,
+        undated_rows: periods.undated_rows(),
+        iv_threshold: pipeline::IV_DRIFT_THRESHOLD,
+        fill_rate_threshold: pipeline::FILL_RATE_DRIFT_THRESHOLD,
+        features: features
+            .into_iter()
+            .zip(series)
+            .map(|(feature, periods)| assess_drift(feature, periods))
+            .collect(),
+    }))
+}
+
+/// Compare every feature still in `df` with each `--protected-columns` column
+/// and flag the likely proxies. Features are only flagged.
+///
+/// The IV is the highest over the protected classes, each binned against the
+/// rest with the settings of the Gini stage; a two-class attribute is binned
+/// once, as both classes give the same IV. Rows with a null protected value
+/// are left out of its comparison.
+fn run_proxy_screen(
+    df: &polars::prelude::DataFrame,
+    weights: &[f64],
+    config: &PipelineConfig,
+    tx: Option<&ProgressSender>,
+) -> Result<Option<ProxyScreen>> {
+    use polars::prelude::*;
+
+    let Some(check) = &config.fairness else {
+        return Ok(None);
+    };
+    let grouping = grouping_columns(config);
+    let features: Vec<&str> = df
+        .get_columns()
+        .iter()
+        .filter(|c| !c.dtype().is_temporal() && !grouping.contains(&c.name().as_str()))
+        .map(|c| c.name().as_str())
+        .collect();
+    let columns: Vec<&str> = features
+        .iter()
+        .copied()
+        .chain(config.weight_column.as_deref())
+        .collect();
+
+    let mut associations = Vec::with_capacity(features.len() * check.columns.len());
+    for protected in &check.columns {
+        let column = df.column(protected)?;
+        let classes = Segments::from_column(column)?;
+        let labelled: Vec<bool> = classes.rows.iter().map(Option::is_some).collect();
+        let labelled = BooleanChunked::from_slice("labelled".into(), &labelled);
+
+        // One class against the rest, scored like a binary target
+        let class_config = PipelineConfig {
+            target: protected.clone(),
+            target_mapping: None,
+            ..config.clone()
+        };
+        let scored_classes = match classes.labels.len() {
+            0 | 1 => 0,
+            2 => 1,
+            n => n,
+        };
+        let mut ivs: std::collections::HashMap<String, f64> = std::collections::HashMap::new();
+        for class in 0..scored_classes {
+            let indicator: Vec<Option<i32>> = classes
+                .rows
+                .iter()
+                .map(|r| r.map(|r| i32::from(r == class)))
+                .collect();
+            let mut class_df = df.select(columns.iter().copied())?;
+            class_df.with_column(Column::new(protected.as_str().into(), indicator))?;
+            let (class_df, class_weights) = select_rows(&class_df, weights, &labelled)?;
+            for analysis in fit_iv_bins(&class_df, &class_weights, &class_config, tx)? {
+                let best = ivs.entry(analysis.feature_name).or_insert(0.0);
+                *best = best.max(analysis.iv);
+            }
+        }
+
+        let text = column.cast(&DataType::String)?;
+        for feature in &features {
+            let association = pipeline::protected_association(df.column(feature)?, &text, weights)?;
+            associations.push(pipeline::assess_proxy(
+                feature,
+                protected,
+                association,
+                ivs.get(*feature).copied(),
+                check.threshold,
+            ));
+        }
+    }
+
+    Ok(Some(ProxyScreen {
+        columns: check.columns.clone(),
+        association_threshold: check.threshold,
+        iv_threshold: pipeline::PROXY_IV_THRESHOLD,
+        associations,
+    }))
+}
+
+/// Columns the first screening step sees as features (all but the target and
+/// weight column, as in missing value analysis); recorded in the report when
+/// `--steps` starts with another step
+fn screened_features(df: &polars::prelude::DataFrame, config: &PipelineConfig) -> Vec<String> {
+    df.get_column_names()
+        .iter()
+        .filter(|c| {
+            c.as_str() != config.target && Some(c.as_str()) != config.weight_column.as_deref()
+        })
+        .map(|c| c.to_string())
+        .collect()
+}
+
+/// Target, weight and grouping columns: the columns of a run that are never
+/// features
+fn grouping_columns(config: &PipelineConfig) -> Vec<&str> {
+    let mut grouping: Vec<&str> = vec![config.target.as_str()];
+    grouping.extend(config.weight_column.as_deref());
+    grouping.extend(config.split_column.as_deref());
+    grouping.extend(config.segment_column.as_deref());
+    grouping.extend(config.time_stability.as_ref().map(|t| t.column.as_str()));
+    grouping.extend(
+        config
+            .fairness
+            .iter()
+            .flat_map(|f| f.columns.iter().map(String::as_str)),
+    );
+    grouping
+}
+
+/// Recommended imputation of the features left in `df` after the correlation
+/// stage, from the rows the statistics were computed on
+fn plan_imputation(
+    df: &polars::prelude::DataFrame,
+    weights: &[f64],
+    config: &PipelineConfig,
+    missing_ratios: &[(String, f64)],
+    gini_analyses: &[pipeline::IvAnalysis],
+) -> Result<pipeline::ImputationPlan> {
+    let grouping = grouping_columns(config);
+    let features: Vec<String> = df
+        .get_columns()
+        .iter()
+        .filter(|c| !c.dtype().is_temporal() && !grouping.contains(&c.name().as_str()))
+        .map(|c| c.name().to_string())
+        .collect();
+    pipeline::build_imputation_plan(
+        df,
+        &features,
+        weights,
+        missing_ratios,
+        gini_analyses,
+        &config.target,
+    )
+}
+
+/// PCA of the numeric features left in `df` (`--pca`), without the target,
+/// weight and grouping columns; `None` without `--pca` or when too few
+/// features are left
+fn run_pca(
+    df: &polars::prelude::DataFrame,
+    weights: &[f64],
+    config: &PipelineConfig,
+) -> Result<Option<pipeline::PcaSummary>> {
+    let Some(settings) = &config.pca else {
+        return Ok(None);
+    };
+    let grouping = grouping_columns(config);
+    let features: Vec<String> = df
+        .get_column_names()
+        .iter()
+        .filter(|c| !grouping.contains(&c.as_str()))
+        .map(|c| c.to_string())
+        .collect();
+    pipeline::analyze_pca(df, &features, weights, settings)
+}
+
+/// Bin every feature of `df` with the binning settings of the Gini stage
+fn fit_iv_bins(
+    df: &polars::prelude::DataFrame,
+    weights: &[f64],
+    config: &PipelineConfig,
+    tx: Option<&ProgressSender>,
+) -> Result<Vec<pipeline::IvAnalysis>> {
+    let binning_strategy: BinningStrategy = config
+        .binning_strategy
+        .parse()
+        .map_err(|e: String| anyhow::anyhow!(e))?;
+    let solver_config = build_solver_config(config)?;
+    let min_category_samples = Some(config.min_category_samples);
+    let cart_min_bin_pct = Some(config.cart_min_bin_pct);
+    let weight_column = config.weight_column.as_deref();
+    let missing_policy = parse_missing_bin_policy(config)?;
+
+    let mut analyses = match tx {
+        Some(tx) => analyze_features_iv_with_progress(
+            df,
+            &config.target,
+            config.gini_bins,
+            config.prebins,
+            config.target_mapping.as_ref(),
+            binning_strategy,
+            min_category_samples,
+            cart_min_bin_pct,
+            weights,
+            weight_column,
+            solver_config.as_ref(),
+            config.special_values.as_ref(),
+            missing_policy,
+            config.bin_breaks.as_ref(),
+            tx,
+        )?,
+        None => analyze_features_iv(
+            df,
+            &config.target,
+            config.gini_bins,
+            config.prebins,
+            config.target_mapping.as_ref(),
+            binning_strategy,
+            min_category_samples,
+            cart_min_bin_pct,
+            weights,
+            weight_column,
+            solver_config.as_ref(),
+            config.special_values.as_ref(),
+            missing_policy,
+            config.bin_breaks.as_ref(),
+        )?,
+    };
+    if let Some(alpha) = config.merge_insignificant_bins {
+        merge_insignificant_bins(&mut analyses, alpha);
+    }
+    Ok(analyses)
+}
+
+/// Somers' D / concordance on the raw feature values, for the report
+fn run_concordance_analysis(
+    df: &polars::prelude::DataFrame,
+    config: &PipelineConfig,
+    weights: &[f64],
+) -> Result<Vec<pipeline::ConcordanceStats>> {
+    compute_concordance(
+        df,
+        &config.target,
+        config.target_mapping.as_ref(),
+        weights,
+        config.weight_column.as_deref(),
+    )
+}
+
+/// Run binned R² analysis for a continuous target (indicatif path)
+fn run_r2_analysis(
+    df: &polars::prelude::DataFrame,
+    config: &PipelineConfig,
+    input: &std::path::Path,
+    weights: &[f64],
+    summary: &mut ReductionSummary,
+) -> Result<(Vec<pipeline::RSquaredAnalysis>, Vec<String>)> {
+    print_step_header(2, "Univariate R² Analysis");
+
+    let step_start = Instant::now();
+    let r2_analyses = analyze_features_r2(
+        df,
+        &config.target,
+        config.gini_bins,
         Some(config.min_category_samples),
-        Some(config.cart_min_bin_pct),
         weights,
         config.weight_column.as_deref(),
-        solver_config.as_ref(),
-        tx,
     )?;
-    let features_to_drop_gini = get_low_gini_features(&gini_analyses, config.gini_threshold);
+    let features_to_drop_r2 =
+        get_low_r2_features_with_keep(&r2_analyses, config.gini_threshold, &keep_columns(config));
 
-    export_gini(
-        &gini_analyses,
-        &features_to_drop_gini,
-        config,
-        input,
-        binning_strategy,
+    export_r2(&r2_analyses, &features_to_drop_r2, config, input)?;
+
+    if features_to_drop_r2.is_empty() {
+        print_info("No features below R² threshold");
+    } else {
+        print_count(
+            "feature(s) with low R²",
+            features_to_drop_r2.len(),
+            Some(&format!("(<{:.2})", config.gini_threshold)),
+        );
+
+        summary.add_gini_drops(features_to_drop_r2.clone());
+        print_success("Dropped low R² features");
+    }
+
+    let elapsed = step_start.elapsed();
+    summary.set_gini_time(elapsed);
+    print_step_time(elapsed);
+
+    Ok((r2_analyses, features_to_drop_r2))
+}
+
+/// Run binned R² analysis for a continuous target (background / channel path)
+fn run_r2_analysis_bg(
+    df: &polars::prelude::DataFrame,
+    config: &PipelineConfig,
+    input: &std::path::Path,
+    weights: &[f64],
+    summary: &mut ReductionSummary,
+    tx: &ProgressSender,
+) -> Result<(Vec<pipeline::RSquaredAnalysis>, Vec<String>)> {
+    let step_start = Instant::now();
+    let r2_analyses = analyze_features_r2_with_progress(
+        df,
+        &config.target,
+        config.gini_bins,
+        Some(config.min_category_samples),
+        weights,
+        config.weight_column.as_deref(),
+        tx,
     )?;
+    let features_to_drop_r2 =
+        get_low_r2_features_with_keep(&r2_analyses, config.gini_threshold, &keep_columns(config));
 
-    if !features_to_drop_gini.is_empty() {
-        summary.add_gini_drops(features_to_drop_gini.clone());
-    }
+    export_r2(&r2_analyses, &features_to_drop_r2, config, input)?;
 
-    let gini_elapsed = step_start.elapsed();
-    summary.set_gini_time(gini_elapsed);
+    if !features_to_drop_r2.is_empty() {
+        summary.add_gini_drops(features_to_drop_r2.clone());
+    }
+    summary.set_gini_time(step_start.elapsed());
 
-    Ok((gini_analyses, features_to_drop_gini))
+    Ok((r2_analyses, features_to_drop_r2))
 }
 
-/// Build `FeatureMetadata` and `FeatureType` maps from the Gini/IV and missing
-/// analysis stages.  These are consumed by the correlation drop logic.
+/// Build `FeatureMetadata` and `FeatureType` maps from the Gini/IV (or R²) and
+/// missing analysis stages.  These are consumed by the correlation drop logic.
 fn build_correlation_metadata(
     gini_analyses: &[pipeline::IvAnalysis],
+    r2_analyses: &[pipeline::RSquaredAnalysis],
     missing_ratios: &[(String, f64)],
 ) -> (
     std::collections::HashMap<String, FeatureMetadata>,
@@ -1442,8 +4569,9 @@ fn build_correlation_metadata(
 
     // Single pass over gini_analyses to build both maps (avoids double iteration
     // and halves String clone count).
-    let mut feature_metadata = std::collections::HashMap::with_capacity(gini_analyses.len());
-    let mut feature_types = std::collections::HashMap::with_capacity(gini_analyses.len());
+    let capacity = gini_analyses.len() + r2_analyses.len();
+    let mut feature_metadata = std::collections::HashMap::with_capacity(capacity);
+    let mut feature_types = std::collections::HashMap::with_capacity(capacity);
 
     for a in gini_analyses {
         feature_types.insert(a.feature_name.clone(), a.feature_type);
@@ -1451,6 +4579,18 @@ fn build_correlation_metadata(
             a.feature_name.clone(),
             FeatureMetadata {
                 iv: Some(a.iv),
+                r_squared: None,
+                missing_ratio: missing_lookup.get(a.feature_name.as_str()).copied(),
+            },
+        );
+    }
+    for a in r2_analyses {
+        feature_types.insert(a.feature_name.clone(), a.feature_type);
+        feature_metadata.insert(
+            a.feature_name.clone(),
+            FeatureMetadata {
+                iv: None,
+                r_squared: Some(a.r_squared),
                 missing_ratio: missing_lookup.get(a.feature_name.as_str()).copied(),
             },
         );
@@ -1471,15 +4611,34 @@ fn run_correlation_analysis(
     print_step_header(3, "Correlation Analysis");
 
     let step_start = Instant::now();
-    let correlated_pairs = find_correlated_pairs_auto(
-        df,
-        config.correlation_threshold,
-        weights,
+    // --fast: pairs come from a row sample, the drops still apply to every row
+    let sample = if config.fast {
+        fast_screen_sample(df, weights)?
+    } else {
+        None
+    };
+    let (corr_df, corr_weights) = match &sample {
+        Some((sampled, sampled_weights)) => (sampled, sampled_weights.as_slice()),
+        None => (&*df, weights),
+    };
+    let mut correlated_pairs = find_correlated_pairs_auto(
+        corr_df,
+        AssociationThresholds::new(config.correlation_threshold, config.cramers_v_threshold),
+        corr_weights,
         config.weight_column.as_deref(),
         Some(feature_types),
     )?;
-    let features_to_drop_corr =
-        select_features_to_drop(&correlated_pairs, &config.target, Some(feature_metadata));
+    if parse_target_type(config)? == TargetType::Continuous {
+        correlated_pairs = exclude_target_pairs(correlated_pairs, &config.target);
+    }
+    let features_to_drop_corr = resolve_correlated_pairs(
+        &mut correlated_pairs,
+        &config.target,
+        Some(feature_metadata),
+        &keep_columns(config),
+        parse_correlation_strategy(config)?,
+        &parse_corr_keep_policy(config)?,
+    );
     print_success("Correlation analysis complete");
 
     apply_correlation_drops(df, &correlated_pairs, &features_to_drop_corr, summary);
@@ -1500,25 +4659,251 @@ fn run_correlation_analysis_bg(
     tx: &ProgressSender,
     feature_metadata: &std::collections::HashMap<String, FeatureMetadata>,
     feature_types: &std::collections::HashMap<String, pipeline::FeatureType>,
-) -> Result<(Vec<pipeline::CorrelatedPair>, Vec<FeatureToDrop>)> {
+) -> Result<(
+    Vec<pipeline::CorrelatedPair>,
+    Vec<FeatureToDrop>,
+    Vec<DecisionLogEntry>,
+)> {
     let step_start = Instant::now();
-    let correlated_pairs = find_correlated_pairs_auto_with_progress(
-        df,
-        config.correlation_threshold,
-        weights,
+    let sample = if config.fast {
+        fast_screen_sample(df, weights)?
+    } else {
+        None
+    };
+    let (corr_df, corr_weights) = match &sample {
+        Some((sampled, sampled_weights)) => (sampled, sampled_weights.as_slice()),
+        None => (&*df, weights),
+    };
+    let mut correlated_pairs = find_correlated_pairs_auto_with_progress(
+        corr_df,
+        AssociationThresholds::new(config.correlation_threshold, config.cramers_v_threshold),
+        corr_weights,
         config.weight_column.as_deref(),
         Some(feature_types),
         tx,
     )?;
-    let features_to_drop_corr =
-        select_features_to_drop(&correlated_pairs, &config.target, Some(feature_metadata));
+    if parse_target_type(config)? == TargetType::Continuous {
+        correlated_pairs = exclude_target_pairs(correlated_pairs, &config.target);
+    }
+    let keep = keep_columns(config);
+    let strategy = parse_correlation_strategy(config)?;
+    let policy = parse_corr_keep_policy(config)?;
+    let mut features_to_drop_corr = resolve_correlated_pairs(
+        &mut correlated_pairs,
+        &config.target,
+        Some(feature_metadata),
+        &keep,
+        strategy,
+        &policy,
+    );
+
+    let mut decisions = Vec::new();
+    if config.review_correlations && !features_to_drop_corr.is_empty() {
+        (features_to_drop_corr, decisions) = review_correlation_drops_bg(
+            &mut correlated_pairs,
+            features_to_drop_corr,
+            config,
+            strategy,
+            &policy,
+            tx,
+            feature_metadata,
+        );
+    }
 
     apply_correlation_drops(df, &correlated_pairs, &features_to_drop_corr, summary);
 
     let correlation_elapsed = step_start.elapsed();
     summary.set_correlation_time(correlation_elapsed);
 
-    Ok((correlated_pairs, features_to_drop_corr))
+    Ok((correlated_pairs, features_to_drop_corr, decisions))
+}
+
+/// Let the user review the proposed correlation drops in the progress overlay.
+///
+/// Blocks until the overlay replies. Reversed proposals become keep pins and the
+/// selection is re-run with them, so pairs that involved the swapped features
+/// are still resolved. Each reversal is returned as a decision log entry.
+/// `--keep-columns` stays pinned in the re-run.
+fn review_correlation_drops_bg(
+    correlated_pairs: &mut [pipeline::CorrelatedPair],
+    proposed: Vec<FeatureToDrop>,
+    config: &PipelineConfig,
+    strategy: CorrelationStrategy,
+    policy: &CorrKeepPolicy,
+    tx: &ProgressSender,
+    feature_metadata: &std::collections::HashMap<String, FeatureMetadata>,
+) -> (Vec<FeatureToDrop>, Vec<DecisionLogEntry>) {
+    let thresholds =
+        AssociationThresholds::new(config.correlation_threshold, config.cramers_v_threshold);
+    let decisions = resolved_pair_decisions(correlated_pairs);
+    let iv = |name: &str| feature_metadata.get(name).and_then(|m| m.iv);
+    let missing = |name: &str| feature_metadata.get(name).and_then(|m| m.missing_ratio);
+    let description = |name: &str| {
+        config
+            .dictionary
+            .as_ref()
+            .and_then(|d| d.description(name))
+            .map(str::to_string)
+    };
+    let items = decisions
+        .iter()
+        .map(|d| CorrelationReviewItem {
+            feature1: d.pair.feature1.clone(),
+            feature2: d.pair.feature2.clone(),
+            iv1: iv(&d.pair.feature1),
+            iv2: iv(&d.pair.feature2),
+            missing1: missing(&d.pair.feature1),
+            missing2: missing(&d.pair.feature2),
+            description1: description(&d.pair.feature1),
+            description2: description(&d.pair.feature2),
+            correlation: d.pair.correlation,
+            measure: d.pair.measure.to_string(),
+            proposed_drop: d.dropped.clone(),
+        })
+        .collect();
+
+    let (reply_tx, reply_rx) = std::sync::mpsc::channel();
+    let request = CorrelationReviewRequest {
+        items,
+        reply: reply_tx,
+    };
+    if tx.send(ProgressEvent::correlation_review(request)).is_err() {
+        return (proposed, Vec::new());
+    }
+    // No reply means the overlay was closed; the automatic choices stand
+    let reversed: Vec<&pipeline::PairDecision> = reply_rx
+        .recv()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|i| decisions.get(i))
+        .collect();
+    if reversed.is_empty() {
+        return (proposed, Vec::new());
+    }
+
+    let keep: std::collections::HashSet<String> = reversed
+        .iter()
+        .map(|d| d.dropped.clone())
+        .chain(config.keep_columns.iter().cloned())
+        .collect();
+    let drops = resolve_correlated_pairs(
+        correlated_pairs,
+        &config.target,
+        Some(feature_metadata),
+        &keep,
+        strategy,
+        policy,
+    );
+    let dropped: std::collections::HashSet<&str> =
+        drops.iter().map(|d| d.feature.as_str()).collect();
+
+    let log = reversed
+        .iter()
+        .map(|d| DecisionLogEntry {
+            stage: DropStage::Correlation,
+            kept: d.dropped.clone(),
+            dropped: dropped.contains(d.kept.as_str()).then(|| d.kept.clone()),
+            source: "user".to_string(),
+            detail: format!(
+                "Reversed in correlation review: {} / {} ({} {:.4}); automatic rule dropped {}",
+                d.pair.feature1, d.pair.feature2, d.pair.measure, d.pair.correlation, d.dropped
+            ),
+            rescued: Some(RescuedDrop {
+                reason: proposed
+                    .iter()
+                    .find(|p| p.feature == d.dropped)
+                    .map(|p| p.reason.clone())
+                    .unwrap_or_default(),
+                metric: d.pair.measure.to_string(),
+                value: d.pair.correlation.abs(),
+                threshold: match d.pair.measure {
+                    AssociationMeasure::CramersV => thresholds.cramers_v,
+                    _ => thresholds.correlation,
+                },
+            }),
+        })
+        .collect();
+
+    (drops, log)
+}
+
+/// `--keep-columns` as a set for the `_with_keep` drop rules, plus the
+/// `--segment-column` and `--time-column` the later checks slice the rows by
+fn keep_columns(config: &PipelineConfig) -> std::collections::HashSet<String> {
+    config
+        .keep_columns
+        .iter()
+        .cloned()
+        .chain(config.segment_column.clone())
+        .chain(config.time_stability.as_ref().map(|t| t.column.clone()))
+        .chain(config.fairness.iter().flat_map(|f| f.columns.clone()))
+        .collect()
+}
+
+/// Correlation drops of `--keep-columns` features that the selection without
+/// the keep list makes, so the report can note them as prevented
+fn protected_correlation_drops(
+    correlated_pairs: &[pipeline::CorrelatedPair],
+    config: &PipelineConfig,
+    feature_metadata: &std::collections::HashMap<String, FeatureMetadata>,
+) -> Vec<FeatureToDrop> {
+    if config.keep_columns.is_empty() {
+        return Vec::new();
+    }
+    // Resolved on a copy so the rationales of the real run stay in place
+    let mut pairs = correlated_pairs.to_vec();
+    resolve_correlated_pairs(
+        &mut pairs,
+        &config.target,
+        Some(feature_metadata),
+        &std::collections::HashSet::new(),
+        parse_correlation_strategy(config).unwrap_or_default(),
+        &parse_corr_keep_policy(config).unwrap_or_default(),
+    )
+    .into_iter()
+    .filter(|d| config.keep_columns.contains(&d.feature))
+    .collect()
+}
+
+/// Let the user review every proposed drop in the progress overlay.
+///
+/// Blocks until the overlay replies and returns the features the user chose to
+/// keep. If the overlay is gone, every drop stands.
+fn review_drops_bg(
+    report_builder: &ReductionReportBuilder,
+    dictionary: Option<&FeatureDictionary>,
+    tx: &ProgressSender,
+) -> Vec<String> {
+    let proposed = report_builder.proposed_drops();
+    if proposed.is_empty() {
+        return Vec::new();
+    }
+    let items = proposed
+        .iter()
+        .map(|(feature, stage, reason)| DropReviewItem {
+            feature: feature.clone(),
+            stage: format!("{:?}", stage).to_lowercase(),
+            reason: reason.clone(),
+            description: dictionary
+                .and_then(|d| d.description(feature))
+                .map(str::to_string),
+        })
+        .collect();
+
+    let (reply_tx, reply_rx) = std::sync::mpsc::channel();
+    let request = DropReviewRequest {
+        items,
+        reply: reply_tx,
+    };
+    if tx.send(ProgressEvent::drop_review(request)).is_err() {
+        return Vec::new();
+    }
+    reply_rx
+        .recv()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|i| proposed.get(i).map(|(feature, _, _)| feature.clone()))
+        .collect()
 }
 
 fn apply_correlation_drops(
@@ -1542,18 +4927,33 @@ fn apply_correlation_drops(
 }
 
 /// Save results to output file (indicatif path)
+///
+/// With `woe_analyses`, a WoE-encoded copy is written next to the output as well.
 fn save_results(
     df: &mut polars::prelude::DataFrame,
     output_path: &std::path::Path,
+    woe_analyses: Option<&[pipeline::IvAnalysis]>,
+    parquet: &pipeline::ParquetOptions,
+    csv: &pipeline::CsvDialect,
     summary: &mut ReductionSummary,
 ) -> Result<()> {
     print_step_header(4, "Save Results");
 
     let step_start = Instant::now();
     let spinner = create_spinner("Writing output file...");
-    save_dataset(df, output_path)?;
+    save_dataset(df, output_path, parquet, csv)?;
     finish_with_success(&spinner, &format!("Saved to {}", output_path.display()));
 
+    if let Some(analyses) = woe_analyses {
+        let woe_path = woe_output_path(output_path);
+        let spinner = create_spinner("Writing WoE-encoded file...");
+        let encoded = save_woe_dataset(df, analyses, &woe_path, parquet, csv)?;
+        finish_with_success(
+            &spinner,
+            &format!("WoE-encoded {} features to {}", encoded, woe_path.display()),
+        );
+    }
+
     let save_elapsed = step_start.elapsed();
     summary.set_save_time(save_elapsed);
     print_step_time(save_elapsed);
@@ -1565,98 +4965,1126 @@ fn save_results(
 fn save_results_bg(
     df: &mut polars::prelude::DataFrame,
     output_path: &std::path::Path,
+    woe_analyses: Option<&[pipeline::IvAnalysis]>,
+    parquet: &pipeline::ParquetOptions,
+    csv: &pipeline::CsvDialect,
     summary: &mut ReductionSummary,
 ) -> Result<()> {
     let step_start = Instant::now();
-    save_dataset(df, output_path)?;
+    save_dataset(df, output_path, parquet, csv)?;
+    if let Some(analyses) = woe_analyses {
+        save_woe_dataset(df, analyses, &woe_output_path(output_path), parquet, csv)?;
+    }
     let save_elapsed = step_start.elapsed();
     summary.set_save_time(save_elapsed);
     Ok(())
 }
 
+/// Progress overlay row of a screening step
+fn progress_stage(step: ScreeningStep) -> PipelineStage {
+    match step {
+        ScreeningStep::Missing => PipelineStage::MissingAnalysis,
+        ScreeningStep::Gini => PipelineStage::GiniAnalysis,
+        ScreeningStep::Correlation => PipelineStage::CorrelationAnalysis,
+    }
+}
+
 // ============================================================================
 // Shared pure helpers
 // ============================================================================
 
+/// Parse `--target-type`, rejecting options that only make sense for a binary target
+fn parse_target_type(config: &PipelineConfig) -> Result<TargetType> {
+    if config.no_target {
+        check_no_target_options(config)?;
+    }
+    let target_type: TargetType = config
+        .target_type
+        .parse()
+        .map_err(|e: String| anyhow::anyhow!(e))?;
+    if target_type == TargetType::Continuous {
+        if config.target_mapping.is_some() {
+            anyhow::bail!(
+                "--event-value/--non-event-value cannot be used with --target-type continuous"
+            );
+        }
+        if config.export_woe {
+            anyhow::bail!(
+                "--export-woe needs WoE bins and is not available for a continuous target"
+            );
+        }
+        if config.event_labels.is_some() {
+            anyhow::bail!(
+                "--event-label/--non-event-label cannot be used with --target-type continuous"
+            );
+        }
+        if parse_univariate_metric(config)? != UnivariateMetric::Iv {
+            anyhow::bail!("--univariate-metric mutual_info requires a binary target");
+        }
+        if config.cross_validation.is_some() {
+            anyhow::bail!("--cv-folds/--cv-min-iv need a binary target");
+        }
+        if config.special_values.is_some() {
+            anyhow::bail!("--special-values needs WoE bins and a binary target");
+        }
+        if config.plots.is_some() {
+            anyhow::bail!("--plots charts WoE bins and needs a binary target");
+        }
+        if config.bin_breaks.is_some() {
+            anyhow::bail!("--bin-breaks needs WoE bins and a binary target");
+        }
+        if config.bins_from.is_some() {
+            anyhow::bail!("--bins-from needs WoE bins and a binary target");
+        }
+        if parse_missing_bin_policy(config)? != MissingBinPolicy::Separate {
+            anyhow::bail!(
+                "--missing-bin-policy merge_worst/impute needs WoE bins and a binary target"
+            );
+        }
+    }
+    check_bins_from_options(config)?;
+    Ok(target_type)
+}
+
+/// Reject binning options that `--bins-from` replaces
+fn check_bins_from_options(config: &PipelineConfig) -> Result<()> {
+    if config.bins_from.is_none() {
+        return Ok(());
+    }
+    let replaced = [
+        ("--bin-breaks", config.bin_breaks.is_some()),
+        ("--special-values", config.special_values.is_some()),
+        (
+            "--missing-bin-policy merge_worst/impute",
+            parse_missing_bin_policy(config)? != MissingBinPolicy::Separate,
+        ),
+        (
+            "--merge-insignificant-bins",
+            config.merge_insignificant_bins.is_some(),
+        ),
+    ];
+    if let Some((option, _)) = replaced.iter().find(|(_, set)| *set) {
+        anyhow::bail!(
+            "{} cannot be used with --bins-from, which takes the bins as defined",
+            option
+        );
+    }
+    Ok(())
+}
+
+/// Reject options that need a target in a `--no-target` run
+fn check_no_target_options(config: &PipelineConfig) -> Result<()> {
+    let needs_target = [
+        (
+            "--target-type continuous",
+            config.target_type == "continuous",
+        ),
+        (
+            "--event-label/--non-event-label",
+            config.event_labels.is_some(),
+        ),
+        ("--export-woe", config.export_woe),
+        ("--plots", config.plots.is_some()),
+        ("--iv-stability", config.iv_stability.is_some()),
+        ("--cv-folds/--cv-min-iv", config.cross_validation.is_some()),
+        ("--time-column", config.time_stability.is_some()),
+        ("--protected-columns", config.fairness.is_some()),
+        (
+            "--sample-stratify",
+            config.sample.as_ref().is_some_and(|s| s.stratify),
+        ),
+        ("--export-iv-table", config.table_exports.iv_table.is_some()),
+        ("--bins-from", config.bins_from.is_some()),
+    ];
+    if let Some((option, _)) = needs_target.iter().find(|(_, set)| *set) {
+        anyhow::bail!(
+            "{} needs a target and cannot be used with --no-target",
+            option
+        );
+    }
+    Ok(())
+}
+
+/// `settings.target_type` of the report: "none" for a `--no-target` run
+fn target_label(config: &PipelineConfig, target_type: TargetType) -> String {
+    if config.no_target {
+        "none".to_string()
+    } else {
+        target_type.to_string()
+    }
+}
+
+/// `--event-label` / `--non-event-label`, which are only meaningful together
+fn event_labels_from_cli(cli: &Cli) -> Result<Option<EventLabels>> {
+    match (&cli.event_label, &cli.non_event_label) {
+        (Some(event), Some(non_event)) => {
+            Ok(Some(EventLabels::new(event.clone(), non_event.clone())))
+        }
+        (Some(_), None) | (None, Some(_)) => {
+            anyhow::bail!("Both --event-label and --non-event-label must be provided together")
+        }
+        (None, None) => Ok(None),
+    }
+}
+
+/// Threshold of the univariate filter: `--mi-threshold` under mutual
+/// information, `--gini-threshold` otherwise
+fn univariate_threshold(config: &PipelineConfig) -> f64 {
+    match parse_univariate_metric(config) {
+        Ok(UnivariateMetric::MutualInfo) => config.mi_threshold,
+        _ => config.gini_threshold,
+    }
+}
+
+fn parse_univariate_metric(config: &PipelineConfig) -> Result<UnivariateMetric> {
+    config
+        .univariate_metric
+        .parse()
+        .map_err(|e: String| anyhow::anyhow!(e))
+}
+
+fn parse_missing_bin_policy(config: &PipelineConfig) -> Result<MissingBinPolicy> {
+    config
+        .missing_bin_policy
+        .parse()
+        .map_err(|e: String| anyhow::anyhow!(e))
+}
+
+fn parse_correlation_strategy(config: &PipelineConfig) -> Result<CorrelationStrategy> {
+    config
+        .correlation_strategy
+        .parse()
+        .map_err(|e: String| anyhow::anyhow!(e))
+}
+
+fn parse_id_action(config: &PipelineConfig) -> Result<IdAction> {
+    config
+        .id_action
+        .parse()
+        .map_err(|e: String| anyhow::anyhow!(e))
+}
+
+fn parse_corr_keep_policy(config: &PipelineConfig) -> Result<CorrKeepPolicy> {
+    CorrKeepPolicy::parse(&config.corr_keep_policy, &config.corr_keep_prefixes)
+}
+
+/// Override the binning settings for `--fast`: decile bins, no merging, no solver
+fn apply_fast_screen(config: &mut PipelineConfig) {
+    config.binning_strategy = BinningStrategy::Quantile.to_string();
+    config.bin_breaks = None;
+    config.gini_bins = FAST_SCREEN_BINS;
+    config.prebins = FAST_SCREEN_BINS;
+    config.use_solver = false;
+    config.merge_insignificant_bins = None;
+}
+
+fn fast_screen_settings(rows: usize) -> FastScreenSettings {
+    FastScreenSettings {
+        approximate: true,
+        bins: FAST_SCREEN_BINS,
+        correlation_rows: fast_screen_sample_rows(rows),
+        total_rows: rows,
+    }
+}
+
+fn parse_binning_backend(config: &PipelineConfig) -> Result<BinningBackend> {
+    config
+        .binning_backend
+        .parse()
+        .map_err(|e: String| anyhow::anyhow!(e))
+}
+
 fn build_solver_config(config: &PipelineConfig) -> Result<Option<SolverConfig>> {
-    if config.use_solver {
+    let backend = match parse_binning_backend(config)? {
+        // Builds without the `solver` feature run the same model as a dynamic program
+        BinningBackend::Mip if !SOLVER_AVAILABLE => BinningBackend::Dp,
+        backend => backend,
+    };
+    if config.use_solver && backend != BinningBackend::Greedy {
         let monotonicity: MonotonicityConstraint = config
             .monotonicity
             .parse()
             .map_err(|e: String| anyhow::anyhow!(e))?;
+        if config.min_bin_pct > config.max_bin_pct {
+            anyhow::bail!(
+                "--min-bin-pct ({}) cannot exceed --max-bin-pct ({})",
+                config.min_bin_pct,
+                config.max_bin_pct
+            );
+        }
         Ok(Some(SolverConfig {
+            backend,
             timeout_seconds: config.solver_timeout,
             gap_tolerance: config.solver_gap,
             monotonicity,
             min_bin_samples: 5,
+            min_bin_pct: config.min_bin_pct,
+            max_bin_pct: config.max_bin_pct,
+            min_woe_delta: config.min_woe_delta,
         }))
     } else {
-        Ok(None)
+        Ok(None)
+    }
+}
+
+/// Note the features whose solver run stopped at the time limit or fell back
+/// to greedy merging; each is marked in the Gini export's `solver_status`
+fn report_solver_status(analyses: &[pipeline::IvAnalysis]) {
+    let (mut at_limit, mut timed_out, mut failed) = (0, 0, 0);
+    for status in analyses.iter().filter_map(|a| a.solver_status) {
+        match status {
+            SolverStatus::Optimal => {}
+            SolverStatus::FeasibleGap { .. } => at_limit += 1,
+            SolverStatus::TimedOutFallback => timed_out += 1,
+            SolverStatus::FailedFallback => failed += 1,
+        }
+    }
+    if at_limit > 0 {
+        print_info(&format!(
+            "Solver stopped at the time limit for {} feature(s); their bins may not be optimal",
+            at_limit
+        ));
+    }
+    if timed_out > 0 {
+        print_info(&format!(
+            "Solver found no solution within the time limit for {} feature(s), using greedy merging",
+            timed_out
+        ));
+    }
+    if failed > 0 {
+        print_info(&format!(
+            "Solver found no solution for {} feature(s), using greedy merging",
+            failed
+        ));
+    }
+}
+
+/// With `--fail-on-solver-fallback`, stop the run when the solver found no
+/// binning for any feature
+fn check_solver_fallbacks(
+    analyses: &[pipeline::IvAnalysis],
+    config: &PipelineConfig,
+) -> Result<()> {
+    if !config.fail_on_solver_fallback {
+        return Ok(());
+    }
+    let features: Vec<String> = analyses
+        .iter()
+        .filter(|a| {
+            matches!(
+                a.solver_status,
+                Some(SolverStatus::TimedOutFallback | SolverStatus::FailedFallback)
+            )
+        })
+        .map(|a| a.feature_name.clone())
+        .collect();
+    if features.is_empty() {
+        return Ok(());
+    }
+    Err(cli::exit_code::SolverFallback { features }.into())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn export_gini(
+    gini_analyses: &[pipeline::IvAnalysis],
+    features_to_drop_gini: &[String],
+    df: &polars::prelude::DataFrame,
+    weights: &[f64],
+    config: &PipelineConfig,
+    input: &std::path::Path,
+    cross_validation: &[CrossValidatedIv],
+    segment_iv: Option<&SegmentIvAnalysis>,
+) -> Result<()> {
+    let binning_strategy: BinningStrategy = config
+        .binning_strategy
+        .parse()
+        .map_err(|e: String| anyhow::anyhow!(e))?;
+    let gini_output_path = gini_analysis_output_path(input, config);
+    let distributions = if config.export_distributions {
+        let retained: Vec<String> = gini_analyses
+            .iter()
+            .filter(|a| {
+                a.feature_type == pipeline::FeatureType::Numeric
+                    && !features_to_drop_gini.contains(&a.feature_name)
+            })
+            .map(|a| a.feature_name.clone())
+            .collect();
+        Some(compute_distributions(
+            df,
+            &retained,
+            weights,
+            DISTRIBUTION_BUCKETS,
+        )?)
+    } else {
+        None
+    };
+    let numeric: Vec<String> = gini_analyses
+        .iter()
+        .filter(|a| a.feature_type == pipeline::FeatureType::Numeric)
+        .map(|a| a.feature_name.clone())
+        .collect();
+    let extremes = compute_extreme_values(df, &numeric, weights)?;
+    let cross_validation: Option<std::collections::HashMap<String, CrossValidatedIv>> =
+        config.cross_validation.as_ref().map(|_| {
+            cross_validation
+                .iter()
+                .map(|r| (r.feature.clone(), r.clone()))
+                .collect()
+        });
+    let segment_ivs: Option<std::collections::HashMap<String, pipeline::FeatureSegmentIv>> =
+        segment_iv.map(|analysis| {
+            analysis
+                .features
+                .iter()
+                .map(|f| (f.feature.clone(), f.clone()))
+                .collect()
+        });
+    let mut export_params = export_params(config, binning_strategy);
+    export_params.distributions = distributions.as_ref();
+    export_params.extremes = Some(&extremes);
+    export_params.cv_folds = config.cross_validation.as_ref().map(|cv| cv.folds);
+    export_params.cross_validation = cross_validation.as_ref();
+    export_params.segment_column = segment_iv.map(|analysis| analysis.column.as_str());
+    export_params.segment_iv = segment_ivs.as_ref();
+    if config.split_gini_export {
+        export_gini_analysis_split(
+            gini_analyses,
+            features_to_drop_gini,
+            &gini_output_path,
+            &export_params,
+        )
+    } else {
+        export_gini_analysis_enhanced(
+            gini_analyses,
+            features_to_drop_gini,
+            &gini_output_path,
+            &export_params,
+        )
+    }
+}
+
+fn export_r2(
+    r2_analyses: &[pipeline::RSquaredAnalysis],
+    features_to_drop_r2: &[String],
+    config: &PipelineConfig,
+    input: &std::path::Path,
+) -> Result<()> {
+    // R² bins are always equal-frequency
+    let export_params = export_params(config, BinningStrategy::Quantile);
+    export_r2_analysis(
+        r2_analyses,
+        features_to_drop_r2,
+        &gini_analysis_output_path(input, config),
+        &export_params,
+    )
+}
+
+fn export_params(config: &PipelineConfig, binning_strategy: BinningStrategy) -> ExportParams<'_> {
+    ExportParams {
+        input_file: config.input.to_str().unwrap_or("unknown"),
+        target_column: &config.target,
+        weight_column: config.weight_column.as_deref(),
+        binning_strategy,
+        bin_breaks: config.bin_breaks.as_ref(),
+        bins_from: config.bins_from.as_ref().map(|b| b.path.as_path()),
+        num_bins: config.gini_bins,
+        gini_threshold: univariate_threshold(config),
+        min_category_samples: config.min_category_samples,
+        cart_min_bin_pct: if binning_strategy == BinningStrategy::Cart {
+            Some(config.cart_min_bin_pct)
+        } else {
+            None
+        },
+        univariate_metric: parse_univariate_metric(config).unwrap_or_default(),
+        merge_insignificant_bins: config.merge_insignificant_bins,
+        event_labels: config.event_labels.as_ref(),
+        distributions: None,
+        extremes: None,
+        cv_folds: None,
+        cross_validation: None,
+        segment_column: None,
+        segment_iv: None,
+        special_values: config.special_values.as_ref(),
+        missing_bin_policy: parse_missing_bin_policy(config).unwrap_or_default(),
+        dictionary: config.dictionary.as_ref(),
+    }
+}
+
+/// Write the standalone stage tables requested with `--export-missing-ratios`,
+/// `--export-iv-table` and `--export-correlated-pairs`. Returns the written paths.
+fn export_stage_tables(
+    exports: &TableExports,
+    missing_ratios: &[(String, f64)],
+    gini_analyses: &[pipeline::IvAnalysis],
+    correlated_pairs: &[pipeline::CorrelatedPair],
+    summary: &ReductionSummary,
+) -> Result<Vec<std::path::PathBuf>> {
+    let mut written = Vec::new();
+    if let Some(path) = &exports.missing_ratios {
+        let mut table = report::missing_ratio_table(missing_ratios, &summary.dropped_missing)?;
+        report::write_table(&mut table, path)?;
+        written.push(path.clone());
+    }
+    if let Some(path) = &exports.iv_table {
+        let mut table = report::iv_table(gini_analyses, &summary.dropped_gini)?;
+        report::write_table(&mut table, path)?;
+        written.push(path.clone());
+    }
+    if let Some(path) = &exports.correlated_pairs {
+        let mut table =
+            report::correlated_pairs_table(correlated_pairs, &summary.dropped_correlation)?;
+        report::write_table(&mut table, path)?;
+        written.push(path.clone());
+    }
+    Ok(written)
+}
+
+/// Write the `--plots` charts and their index page to `{stem}_plots/`, and
+/// upload them next to a cloud input. Returns the index page, or `None`
+/// without `--plots`.
+fn export_plots(
+    config: &PipelineConfig,
+    input: &std::path::Path,
+    gini_analyses: &[pipeline::IvAnalysis],
+    summary: &ReductionSummary,
+) -> Result<Option<std::path::PathBuf>> {
+    let Some(settings) = &config.plots else {
+        return Ok(None);
+    };
+    let dropped: std::collections::HashSet<&str> = summary
+        .dropped_gini
+        .iter()
+        .chain(&summary.dropped_correlation)
+        .map(String::as_str)
+        .collect();
+    let dir = derive_local_path(input, "plots", "html").with_extension("");
+    let index = report::write_plots(gini_analyses, &dropped, settings, &dir)?;
+    if !pipeline::is_cloud_uri(input) {
+        return Ok(Some(index));
+    }
+    let target = derive_output_path(input, "plots", "html").with_extension("");
+    pipeline::upload_dir(&dir, &target)?;
+    Ok(Some(
+        target.join(index.strip_prefix(&dir).unwrap_or(&index)),
+    ))
+}
+
+/// Gini export location: `{stem}_gini_analysis.json`, or a `{stem}_gini_analysis/`
+/// directory when the export is split per feature. A continuous target writes
+/// `{stem}_r2_analysis.json` instead.
+fn gini_analysis_output_path(
+    input: &std::path::Path,
+    config: &PipelineConfig,
+) -> std::path::PathBuf {
+    if matches!(config.target_type.parse(), Ok(TargetType::Continuous)) {
+        return derive_local_path(input, "r2_analysis", "json");
+    }
+    let path = derive_local_path(input, "gini_analysis", "json");
+    if config.split_gini_export {
+        path.with_extension("")
+    } else {
+        path
     }
 }
 
-fn export_gini(
-    gini_analyses: &[pipeline::IvAnalysis],
-    features_to_drop_gini: &[String],
+/// Write the WoE encoding of the `selected` features as `{stem}_woe_transform.sql`
+/// and `{stem}_scoring_spec.json`. Nothing is written for a continuous target
+/// or when the Gini step is skipped. Returns the paths written.
+fn export_woe_scoring(
+    input: &std::path::Path,
     config: &PipelineConfig,
+    gini_analyses: &[pipeline::IvAnalysis],
+    selected: &[String],
+) -> Result<Vec<std::path::PathBuf>> {
+    if !config.steps.runs(ScreeningStep::Gini)
+        || matches!(config.target_type.parse(), Ok(TargetType::Continuous))
+    {
+        return Ok(Vec::new());
+    }
+    let sql_path = derive_local_path(input, "woe_transform", "sql");
+    export_woe_transform_sql(gini_analyses, selected, &sql_path)?;
+
+    let spec = report::ScoringSpec::new(
+        &config.target,
+        config.event_labels.as_ref(),
+        gini_analyses,
+        selected,
+    );
+    let spec_path = derive_local_path(input, "scoring_spec", "json");
+    export_scoring_spec(&spec, &spec_path)?;
+    Ok(vec![sql_path, spec_path])
+}
+
+/// CART tree export location `{stem}_cart_trees.json`, or `None` when
+/// `--export-cart-trees` is off, the features are not CART-prebinned or the
+/// Gini step is skipped
+fn cart_trees_output_path(
     input: &std::path::Path,
+    config: &PipelineConfig,
     binning_strategy: BinningStrategy,
+) -> Option<std::path::PathBuf> {
+    (config.export_cart_trees
+        && binning_strategy == BinningStrategy::Cart
+        && config.steps.runs(ScreeningStep::Gini))
+    .then(|| derive_local_path(input, "cart_trees", "json"))
+}
+
+/// Refit the CART prebinning trees and write them to `path`
+fn export_cart_tree_diagnostics(
+    df: &polars::prelude::DataFrame,
+    config: &PipelineConfig,
+    weights: &[f64],
+    path: &std::path::Path,
 ) -> Result<()> {
-    let gini_output_path = derive_output_path(input, "gini_analysis", "json");
-    let export_params = ExportParams {
-        input_file: input.to_str().unwrap_or("unknown"),
-        target_column: &config.target,
-        weight_column: config.weight_column.as_deref(),
-        binning_strategy,
-        num_bins: config.gini_bins,
-        gini_threshold: config.gini_threshold,
-        min_category_samples: config.min_category_samples,
-        cart_min_bin_pct: if binning_strategy == BinningStrategy::Cart {
-            Some(config.cart_min_bin_pct)
-        } else {
-            None
-        },
-    };
-    export_gini_analysis_enhanced(
-        gini_analyses,
-        features_to_drop_gini,
-        &gini_output_path,
-        &export_params,
+    let trees = fit_cart_trees(
+        df,
+        &config.target,
+        config.prebins,
+        config.target_mapping.as_ref(),
+        Some(config.cart_min_bin_pct),
+        weights,
+        config.weight_column.as_deref(),
+    )?;
+    export_cart_trees(
+        &trees,
+        config.prebins,
+        path,
+        &export_params(config, BinningStrategy::Cart),
     )
 }
 
-/// Save dataset to file (CSV or Parquet based on extension)
-fn save_dataset(df: &mut polars::prelude::DataFrame, path: &std::path::Path) -> Result<()> {
+/// WoE export location: `{output_stem}_woe.{output_ext}`, next to the reduced dataset
+fn woe_output_path(output: &std::path::Path) -> std::path::PathBuf {
+    let ext = output
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("parquet");
+    derive_output_path(output, "woe", ext)
+}
+
+/// Write a copy of `df` with every analyzed feature replaced by its WoE value.
+/// Returns the number of encoded features.
+fn save_woe_dataset(
+    df: &polars::prelude::DataFrame,
+    analyses: &[pipeline::IvAnalysis],
+    path: &std::path::Path,
+    parquet: &pipeline::ParquetOptions,
+    csv: &pipeline::CsvDialect,
+) -> Result<usize> {
+    let mut encoded_df = df.clone();
+    let encoded = apply_woe_transform(&mut encoded_df, analyses)?;
+    save_dataset(&mut encoded_df, path, parquet, csv)?;
+    Ok(encoded.len())
+}
+
+/// Save dataset to file (CSV or Parquet based on extension). A cloud URI is
+/// written to a staging file and uploaded.
+fn save_dataset(
+    df: &mut polars::prelude::DataFrame,
+    target: &std::path::Path,
+    parquet: &pipeline::ParquetOptions,
+    csv: &pipeline::CsvDialect,
+) -> Result<()> {
     use anyhow::Context;
     use polars::prelude::*;
 
-    let extension = path
+    let extension = target
         .extension()
         .and_then(|e| e.to_str())
         .unwrap_or("")
         .to_lowercase();
+    let staged = pipeline::StagedOutput::new(target)?;
+    let path = staged.path();
 
     match extension.as_str() {
         "csv" => {
-            let mut file = std::fs::File::create(path)
+            let file = std::fs::File::create(path)
                 .with_context(|| format!("Failed to create output file: {}", path.display()))?;
-            CsvWriter::new(&mut file)
-                .finish(df)
+            csv.write(df, file)
                 .with_context(|| format!("Failed to write CSV file: {}", path.display()))?;
         }
         "parquet" => {
             let file = std::fs::File::create(path)
                 .with_context(|| format!("Failed to create output file: {}", path.display()))?;
-            ParquetWriter::new(file)
+            parquet
+                .writer(file)
                 .finish(df)
                 .with_context(|| format!("Failed to write Parquet file: {}", path.display()))?;
         }
+        "feather" | "arrow" | "ipc" => {
+            let file = std::fs::File::create(path)
+                .with_context(|| format!("Failed to create output file: {}", path.display()))?;
+            IpcWriter::new(file)
+                .finish(df)
+                .with_context(|| format!("Failed to write Arrow IPC file: {}", path.display()))?;
+        }
+        "jsonl" | "ndjson" => {
+            let file = std::fs::File::create(path)
+                .with_context(|| format!("Failed to create output file: {}", path.display()))?;
+            JsonWriter::new(file)
+                .with_json_format(JsonFormat::JsonLines)
+                .finish(df)
+                .with_context(|| format!("Failed to write JSON Lines file: {}", path.display()))?;
+        }
+        "xpt" => {
+            let version = pipeline::xpt::XptVersion::for_dataframe(df);
+            pipeline::xpt::write_xpt(df, path, version)?;
+        }
         _ => anyhow::bail!(
-            "Unsupported output format: {}. Supported formats: csv, parquet",
+            "Unsupported output format: {}. Supported formats: csv, parquet, feather, jsonl, xpt",
             extension
         ),
     }
 
+    staged.finish()
+}
+
+/// Run the apply subcommand: load a saved report and dataset, reduce, save.
+fn run_apply_pipeline(
+    report_path: &std::path::Path,
+    gini_analysis: Option<&std::path::Path>,
+    input: &std::path::Path,
+    output: &std::path::Path,
+    woe: bool,
+    infer_schema_length: usize,
+) -> Result<()> {
+    let start = Instant::now();
+
+    print_banner(env!("CARGO_PKG_VERSION"));
+    println!(
+        "  {} Reading report: {}",
+        style("[1/4]").bold().cyan(),
+        report_path.display()
+    );
+    let saved = SavedReduction::load(report_path, gini_analysis)?;
+    println!(
+        "  {} {} kept, {} dropped (target: {})",
+        style(">>").dim(),
+        saved.kept_features.len(),
+        saved.dropped_features.len(),
+        saved.target_column
+    );
+
+    println!(
+        "  {} Loading dataset: {}",
+        style("[2/4]").bold().cyan(),
+        input.display()
+    );
+    let spinner = create_spinner("Loading dataset...");
+    let (df, _rows, _cols, _elapsed) = load_dataset_with_progress(input, infer_schema_length)?;
+    finish_with_success(
+        &spinner,
+        &format!("Loaded {} rows x {} columns", df.height(), df.width()),
+    );
+
+    println!(
+        "  {} Applying reduction{}...",
+        style("[3/4]").bold().cyan(),
+        if woe { " with WoE encoding" } else { "" }
+    );
+    let (mut reduced, summary) = apply_saved_reduction(&df, &saved, woe)?;
+    if !summary.removed_unknown.is_empty() {
+        println!(
+            "  {} Removed {} column(s) not in the report: {}",
+            style("!").yellow(),
+            summary.removed_unknown.len(),
+            summary.removed_unknown.join(", ")
+        );
+    }
+    if woe {
+        println!(
+            "  {} WoE-encoded {} feature(s)",
+            style(">>").dim(),
+            summary.woe_encoded.len()
+        );
+    }
+
+    println!(
+        "  {} Saving to: {}",
+        style("[4/4]").bold().cyan(),
+        output.display()
+    );
+    let spinner = create_spinner("Writing output...");
+    save_dataset(
+        &mut reduced,
+        output,
+        &pipeline::ParquetOptions::default(),
+        &pipeline::CsvDialect::default(),
+    )?;
+    finish_with_success(&spinner, "Output saved");
+
+    println!();
+    println!(
+        "  {} Apply complete in {:.1}s",
+        style("done").green().bold(),
+        start.elapsed().as_secs_f64()
+    );
+    println!(
+        "  {} {} columns -> {} columns ({} dropped per report, output: {})",
+        style(">>").dim(),
+        summary.input_columns,
+        reduced.width(),
+        summary.dropped.len(),
+        output.display()
+    );
+
+    Ok(())
+}
+
+/// Run the tighten subcommand: re-cut a reduced output with stricter thresholds
+/// from its report, save it and write the delta report next to it.
+fn run_tighten_pipeline(
+    report_path: &std::path::Path,
+    input: &std::path::Path,
+    output: &std::path::Path,
+    thresholds: TightenThresholds,
+    infer_schema_length: usize,
+) -> Result<()> {
+    if thresholds.missing.is_none() && thresholds.gini.is_none() {
+        anyhow::bail!("Nothing to tighten: pass --missing-threshold and/or --gini-threshold");
+    }
+    let start = Instant::now();
+
+    print_banner(env!("CARGO_PKG_VERSION"));
+    println!(
+        "  {} Reading report: {}",
+        style("[1/3]").bold().cyan(),
+        report_path.display()
+    );
+    let prior = PriorReduction::load(report_path)?;
+    let delta = plan_tightening(&prior, thresholds, report_path)?;
+    println!(
+        "  {} {} kept features (target: {})",
+        style(">>").dim(),
+        prior.features.len(),
+        prior.target_column
+    );
+    println!(
+        "  {} missing {:.2} -> {:.2}, {} {:.4} -> {:.4}: {} feature(s) fail",
+        style(">>").dim(),
+        delta.missing_threshold.previous,
+        delta.missing_threshold.current,
+        match delta.metric {
+            TightenMetric::Gini => "gini",
+            TightenMetric::MutualInfo => "mutual info",
+            TightenMetric::RSquared => "R²",
+        },
+        delta.gini_threshold.previous,
+        delta.gini_threshold.current,
+        delta.dropped.len()
+    );
+
+    println!(
+        "  {} Loading dataset: {}",
+        style("[2/3]").bold().cyan(),
+        input.display()
+    );
+    let spinner = create_spinner("Loading dataset...");
+    let (df, _rows, _cols, _elapsed) = load_dataset_with_progress(input, infer_schema_length)?;
+    finish_with_success(
+        &spinner,
+        &format!("Loaded {} rows x {} columns", df.height(), df.width()),
+    );
+    let input_columns = df.width();
+    let mut tightened = apply_tightening(df, &delta);
+
+    println!(
+        "  {} Saving to: {}",
+        style("[3/3]").bold().cyan(),
+        output.display()
+    );
+    let spinner = create_spinner("Writing output...");
+    save_dataset(
+        &mut tightened,
+        output,
+        &pipeline::ParquetOptions::default(),
+        &pipeline::CsvDialect::default(),
+    )?;
+    let report_out = derive_output_path(output, "tighten_report", "json");
+    export_tighten_report(&delta, &report_out)?;
+    finish_with_success(&spinner, "Output saved");
+
+    println!();
+    println!(
+        "  {} Tighten complete in {:.1}s",
+        style("done").green().bold(),
+        start.elapsed().as_secs_f64()
+    );
+    println!(
+        "  {} {} columns -> {} columns (delta report: {})",
+        style(">>").dim(),
+        input_columns,
+        tightened.width(),
+        report_out.display()
+    );
+
+    Ok(())
+}
+
+/// Write the column names and dtypes of `input` for `--expect-schema`
+fn run_schema_export(
+    input: &std::path::Path,
+    output: &std::path::Path,
+    infer_schema_length: usize,
+) -> Result<()> {
+    print_banner(env!("CARGO_PKG_VERSION"));
+    let spinner = create_spinner("Loading dataset...");
+    let (df, _rows, _cols, _elapsed) = load_dataset_with_progress(input, infer_schema_length)?;
+    finish_with_success(
+        &spinner,
+        &format!("Loaded {} rows x {} columns", df.height(), df.width()),
+    );
+
+    ExpectedSchema::from_dataframe(&df).save(output)?;
+    println!(
+        "  {} Wrote schema of {} columns to {}",
+        style("done").green().bold(),
+        df.width(),
+        output.display()
+    );
+    Ok(())
+}
+
+/// `lophi batch`: reduce every dataset of `input_dir` with the settings of
+/// `cli`, `jobs` files at a time. A file that fails is reported and the
+/// batch goes on; the command fails at the end if any file did.
+fn run_batch(
+    cli: &Cli,
+    input_dir: &std::path::Path,
+    pattern: Option<&str>,
+    output_dir: &std::path::Path,
+    jobs: usize,
+    summary_path: &std::path::Path,
+) -> Result<()> {
+    use anyhow::Context;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    let table_exports = cli.table_exports();
+    if table_exports.missing_ratios.is_some()
+        || table_exports.iv_table.is_some()
+        || table_exports.correlated_pairs.is_some()
+    {
+        anyhow::bail!(
+            "--export-missing-ratios, --export-iv-table and --export-correlated-pairs name one \
+             file and cannot be used with batch"
+        );
+    }
+    let files = cli::batch::batch_inputs(input_dir, pattern)?;
+    if output_dir.canonicalize().ok() == input_dir.canonicalize().ok() {
+        anyhow::bail!("The batch output folder must differ from --input-dir");
+    }
+    std::fs::create_dir_all(output_dir)
+        .with_context(|| format!("Failed to create folder: {}", output_dir.display()))?;
+
+    if !cli.quiet {
+        println!(
+            "\n    {} {}\n",
+            style("▸").cyan(),
+            style(format!(
+                "Reducing {} file{} from {}",
+                files.len(),
+                if files.len() == 1 { "" } else { "s" },
+                input_dir.display()
+            ))
+            .white()
+            .bold()
+        );
+    }
+
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(files.len()));
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.min(files.len()) {
+            scope.spawn(|| {
+                while let Some(input) = files.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let start = Instant::now();
+                    let result = match reduce_batch_file(cli, input, output_dir) {
+                        Ok((summary, kept)) => cli::batch::BatchFileResult::reduced(
+                            input.clone(),
+                            &summary,
+                            &kept,
+                            start.elapsed(),
+                        ),
+                        Err(e) => {
+                            cli::batch::BatchFileResult::failed(input.clone(), &e, start.elapsed())
+                        }
+                    };
+                    if !cli.quiet {
+                        match &result.error {
+                            None => print_success(&format!(
+                                "{}: kept {} of {} features",
+                                input.display(),
+                                result.kept_count(),
+                                result.features.len()
+                            )),
+                            Some(error) => println!(
+                                "    {} {}: {}",
+                                style("⚠").yellow(),
+                                input.display(),
+                                error
+                            ),
+                        }
+                    }
+                    results
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .push(result);
+                }
+            });
+        }
+    });
+    let mut results = results.into_inner().unwrap_or_else(|e| e.into_inner());
+    results.sort_by(|a, b| a.input.cmp(&b.input));
+
+    cli::batch::write_batch_summary(summary_path, &results)?;
+    if !cli.quiet {
+        println!();
+        for line in cli::batch::batch_table(&results).to_string().lines() {
+            println!("    {}", line);
+        }
+        print_info(&format!("Batch summary: {}", summary_path.display()));
+    }
+
+    let failed = results.iter().filter(|r| r.error.is_some()).count();
+    if failed > 0 {
+        anyhow::bail!("{} of {} files failed", failed, results.len());
+    }
+    Ok(())
+}
+
+/// Reduce one batch file into `output_dir`: the settings of `cli` with this
+/// input, its output and reports in the folder. Returns the run's summary
+/// and the features its report kept.
+fn reduce_batch_file(
+    cli: &Cli,
+    input: &std::path::Path,
+    output_dir: &std::path::Path,
+) -> Result<(ReductionSummary, Vec<String>)> {
+    let mut cli = cli.clone();
+    cli.command = None;
+    cli.input = Some(input.to_path_buf());
+    let output = derive_output_path(input, "reduced", &writable_extension(input));
+    cli.output = output.file_name().map(|name| output_dir.join(name));
+
+    let Some(mut config) = setup_configuration_no_tui(&cli)? else {
+        anyhow::bail!("No configuration for {}", input.display());
+    };
+    config.report_dir = Some(output_dir.to_path_buf());
+    let report_zip = derive_output_path(&report_anchor(&config), "reduction_report", "zip");
+
+    // Nobody listens: review requests keep their automatic choices
+    let (tx, rx) = create_progress_channel();
+    drop(rx);
+    let summary = run_pipeline_bg(config, tx)?;
+    let kept = pipeline::SavedReduction::load(&report_zip, None)?.kept_features;
+    Ok((summary, kept))
+}
+
+/// Check a reduction report and list its problems; any error fails the command
+fn run_validate_report(report_path: &std::path::Path) -> Result<()> {
+    print_banner(env!("CARGO_PKG_VERSION"));
+    println!(
+        "  {} Validating report: {}",
+        style(">>").dim(),
+        report_path.display()
+    );
+    let validation = validate_report_file(report_path)?;
+    println!(
+        "  {} schema_version {}, {} features",
+        style(">>").dim(),
+        validation
+            .schema_version
+            .map_or_else(|| "absent".to_string(), |v| v.to_string()),
+        validation.features
+    );
+    for warning in &validation.warnings {
+        println!("  {} {}", style("warning").yellow().bold(), warning);
+    }
+    for error in &validation.errors {
+        println!("  {} {}", style("error").red().bold(), error);
+    }
+    if !validation.is_valid() {
+        anyhow::bail!(
+            "Report {} failed validation with {} error(s)",
+            report_path.display(),
+            validation.errors.len()
+        );
+    }
+    println!("  {} Report is valid", style("done").green().bold());
+    Ok(())
+}
+
+/// Compare two runs, print the changes and write them as JSON
+fn run_diff(
+    report_a: &std::path::Path,
+    report_b: &std::path::Path,
+    output_path: &std::path::Path,
+    top: usize,
+) -> Result<()> {
+    print_banner(env!("CARGO_PKG_VERSION"));
+    println!(
+        "  {} Comparing runs: {} (A) and {} (B)",
+        style(">>").dim(),
+        report_a.display(),
+        report_b.display()
+    );
+    let diff = diff_runs(&RunSnapshot::load(report_a)?, &RunSnapshot::load(report_b)?);
+
+    let value = |v: Option<f64>| v.map_or_else(|| "-".to_string(), |v| v.to_string());
+    if diff.threshold_changes.is_empty() {
+        println!("  {} Thresholds: no differences", style(">>").dim());
+    }
+    for change in &diff.threshold_changes {
+        println!(
+            "  {} Threshold {}: {} -> {}",
+            style(">>").dim(),
+            change.name,
+            value(change.a),
+            value(change.b)
+        );
+    }
+    for (label, names) in [
+        ("Dropped in A only", &diff.dropped_only_in_a),
+        ("Dropped in B only", &diff.dropped_only_in_b),
+        ("Only in A", &diff.only_in_a),
+        ("Only in B", &diff.only_in_b),
+    ] {
+        if !names.is_empty() {
+            println!(
+                "  {} {} ({}): {}",
+                style(">>").dim(),
+                label,
+                names.len(),
+                names.join(", ")
+            );
+        }
+    }
+
+    let changed = diff.features.iter().filter(|f| f.changed()).count();
+    if changed == 0 {
+        println!("  {} No feature changed", style(">>").dim());
+    } else {
+        println!(
+            "  {} {} of {} features changed{}",
+            style(">>").dim(),
+            changed,
+            diff.features.len(),
+            if changed > top {
+                format!(", top {} by IV change:", top)
+            } else {
+                ":".to_string()
+            }
+        );
+        println!("{}", diff_table(&diff, top));
+    }
+
+    export_run_diff(&diff, output_path)?;
+    println!(
+        "  {} Diff written to {}",
+        style("done").green().bold(),
+        output_path.display()
+    );
     Ok(())
 }
 
@@ -1673,8 +6101,12 @@ fn run_sampling_pipeline(mut config: SamplingConfig) -> Result<()> {
 
     // Load dataset
     let spinner = create_spinner("Loading dataset...");
-    let (df, _rows, _cols, _elapsed) =
-        load_dataset_with_progress(&config.input, config.infer_schema_length)?;
+    let (df, _rows, _cols, _elapsed) = load_dataset_from_sheet(
+        &config.input,
+        config.infer_schema_length,
+        config.sheet.as_deref(),
+        None,
+    )?;
     finish_with_success(
         &spinner,
         &format!("Loaded {} rows x {} columns", df.height(), df.width()),
@@ -1723,7 +6155,12 @@ fn run_sampling_pipeline(mut config: SamplingConfig) -> Result<()> {
         config.output.display()
     );
     let spinner = create_spinner("Writing output...");
-    save_dataset(&mut sampled, &config.output)?;
+    save_dataset(
+        &mut sampled,
+        &config.output,
+        &pipeline::ParquetOptions::default(),
+        &pipeline::CsvDialect::default(),
+    )?;
     finish_with_success(&spinner, "Output saved");
 
     let elapsed = start.elapsed();
@@ -1754,8 +6191,12 @@ fn run_sampling_pipeline_bg(mut config: SamplingConfig, tx: ProgressSender) -> R
     .ok();
 
     let stage_start = Instant::now();
-    let (df, _rows, _cols, _elapsed) =
-        load_dataset_with_progress_channel(&config.input, config.infer_schema_length, &tx)?;
+    let (df, _rows, _cols, _elapsed) = load_dataset_from_sheet(
+        &config.input,
+        config.infer_schema_length,
+        config.sheet.as_deref(),
+        Some(&tx),
+    )?;
     let input_rows = df.height();
 
     tx.send(ProgressEvent::stage_complete(
@@ -1811,7 +6252,12 @@ fn run_sampling_pipeline_bg(mut config: SamplingConfig, tx: ProgressSender) -> R
     .ok();
 
     let stage_start = Instant::now();
-    save_dataset(&mut sampled, &config.output)?;
+    save_dataset(
+        &mut sampled,
+        &config.output,
+        &pipeline::ParquetOptions::default(),
+        &pipeline::CsvDialect::default(),
+    )?;
 
     tx.send(ProgressEvent::stage_complete(
         PipelineStage::Saving,
@@ -0,0 +1,358 @@
+//! Re-apply a saved reduction to a new dataset
+//!
+//! After reducing a training set, scoring and validation sets need exactly the
+//! same columns. This module reads the reduction report written by a previous
+//! run (either the bare `*_reduction_report.json` or the `*_reduction_report.zip`
+//! bundle) and keeps the features that run kept, optionally WoE-encoding them
+//! with the bins stored in the bundled Gini analysis.
+
+use std::collections::HashSet;
+use std::io::Read;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+use serde::Deserialize;
+
+use super::iv::IvAnalysis;
+use super::temporal::{apply_date_policy, DatePolicy};
+use super::woe_transform::apply_woe_transform;
+use crate::report::{SPLIT_INDEX_FILE, USER_RETAINED};
+
+/// The parts of a saved reduction needed to reproduce it on another dataset
+#[derive(Debug, Clone)]
+pub struct SavedReduction {
+    /// Target column of the original run
+    pub target_column: String,
+    /// Weight column of the original run, if one was used
+    pub weight_column: Option<String>,
+    /// Train/test split column of the original run, if one was used
+    pub split_column: Option<String>,
+    /// Date policy of the original run; under [`DatePolicy::Derive`] the
+    /// derived date features are rebuilt before selecting
+    pub date_policy: DatePolicy,
+    /// Features kept by the original run, in report order
+    pub kept_features: Vec<String>,
+    /// Features dropped by the original run
+    pub dropped_features: Vec<String>,
+    /// WoE bins per feature, when a Gini analysis was available
+    pub iv_analyses: Vec<IvAnalysis>,
+}
+
+/// What [`apply_saved_reduction`] did to the dataset
+#[derive(Debug, Clone, Default)]
+pub struct ApplySummary {
+    /// Columns in the input before applying
+    pub input_columns: usize,
+    /// Features from the report's drop list that were present and removed
+    pub dropped: Vec<String>,
+    /// Columns unknown to the report (e.g. removed with --drop-columns) that were removed
+    pub removed_unknown: Vec<String>,
+    /// Kept features that were WoE-encoded
+    pub woe_encoded: Vec<String>,
+}
+
+// Minimal views of the report JSON; unknown fields are ignored so reports from
+// newer versions still load.
+#[derive(Deserialize)]
+struct ReportFile {
+    metadata: ReportFileMetadata,
+    features: Vec<ReportFileFeature>,
+}
+
+#[derive(Deserialize)]
+struct ReportFileMetadata {
+    settings: ReportFileSettings,
+}
+
+#[derive(Deserialize)]
+struct ReportFileSettings {
+    target_column: String,
+    #[serde(default)]
+    weight_column: Option<String>,
+    #[serde(default)]
+    train_partition: Option<ReportFileTrainPartition>,
+    #[serde(default)]
+    date_policy: Option<DatePolicy>,
+}
+
+#[derive(Deserialize)]
+struct ReportFileTrainPartition {
+    split_column: String,
+}
+
+#[derive(Deserialize)]
+struct ReportFileFeature {
+    name: String,
+    status: String,
+}
+
+#[derive(Deserialize)]
+struct GiniFile {
+    features: Vec<GiniFileEntry>,
+}
+
+#[derive(Deserialize)]
+struct GiniFileEntry {
+    #[serde(flatten)]
+    analysis: IvAnalysis,
+}
+
+#[derive(Deserialize)]
+struct GiniIndexFile {
+    features: Vec<GiniIndexFileEntry>,
+}
+
+#[derive(Deserialize)]
+struct GiniIndexFileEntry {
+    file: String,
+}
+
+impl SavedReduction {
+    /// Load a saved reduction from a report JSON or a report ZIP bundle.
+    ///
+    /// From a ZIP, the Gini analysis (single file or split export) is loaded
+    /// as well so the bins are available for WoE encoding. From a bare JSON
+    /// report, pass `gini_analysis` to load bins from a Gini analysis JSON.
+    pub fn load(report_path: &Path, gini_analysis: Option<&Path>) -> Result<Self> {
+        let extension = report_path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        let mut saved = match extension.as_str() {
+            "zip" => Self::load_zip(report_path)?,
+            "json" => {
+                let content = std::fs::read_to_string(report_path)
+                    .with_context(|| format!("Failed to read report: {}", report_path.display()))?;
+                Self::from_report_json(&content).with_context(|| {
+                    format!("Invalid reduction report: {}", report_path.display())
+                })?
+            }
+            _ => anyhow::bail!(
+                "Unsupported report format: {}. Expected a reduction report .json or .zip",
+                report_path.display()
+            ),
+        };
+
+        if let Some(path) = gini_analysis {
+            let content = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read Gini analysis: {}", path.display()))?;
+            saved.iv_analyses = parse_gini_json(&content)
+                .with_context(|| format!("Invalid Gini analysis: {}", path.display()))?;
+        }
+
+        Ok(saved)
+    }
+
+    fn from_report_json(content: &str) -> Result<Self> {
+        let report: ReportFile = serde_json::from_str(content)?;
+        let (kept, dropped): (Vec<_>, Vec<_>) = report
+            .features
+            .into_iter()
+            .partition(|f| f.status == "kept" || f.status == USER_RETAINED);
+
+        Ok(Self {
+            target_column: report.metadata.settings.target_column,
+            weight_column: report.metadata.settings.weight_column,
+            split_column: report
+                .metadata
+                .settings
+                .train_partition
+                .map(|p| p.split_column),
+            date_policy: report.metadata.settings.date_policy.unwrap_or_default(),
+            kept_features: kept.into_iter().map(|f| f.name).collect(),
+            dropped_features: dropped.into_iter().map(|f| f.name).collect(),
+            iv_analyses: Vec::new(),
+        })
+    }
+
+    fn load_zip(path: &Path) -> Result<Self> {
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("Failed to open report bundle: {}", path.display()))?;
+        let mut archive = ::zip::ZipArchive::new(file)
+            .with_context(|| format!("Invalid zip file: {}", path.display()))?;
+        let names: Vec<String> = archive.file_names().map(String::from).collect();
+        // Report files sit at the top level; a split Gini export is the only subdirectory
+        let top_level = |suffix: &str| {
+            names
+                .iter()
+                .find(|n| !n.contains('/') && n.ends_with(suffix))
+        };
+
+        let report_name = top_level("reduction_report.json").ok_or_else(|| {
+            anyhow::anyhow!("No reduction_report.json found in {}", path.display())
+        })?;
+        let mut saved = Self::from_report_json(&read_zip_entry(&mut archive, report_name)?)
+            .with_context(|| format!("Invalid reduction report in {}", path.display()))?;
+
+        if let Some(gini_name) = top_level("gini_analysis.json") {
+            saved.iv_analyses = parse_gini_json(&read_zip_entry(&mut archive, gini_name)?)?;
+        } else if let Some(index_name) = names
+            .iter()
+            .find(|n| n.ends_with(&format!("_gini_analysis/{}", SPLIT_INDEX_FILE)))
+        {
+            // Split export: the index lists per-feature files relative to itself
+            let prefix = index_name.trim_end_matches(SPLIT_INDEX_FILE).to_string();
+            let index: GiniIndexFile =
+                serde_json::from_str(&read_zip_entry(&mut archive, index_name)?)?;
+            for entry in index.features {
+                let content = read_zip_entry(&mut archive, &format!("{}{}", prefix, entry.file))?;
+                let parsed: GiniFileEntry = serde_json::from_str(&content)
+                    .with_context(|| format!("Invalid Gini analysis entry: {}", entry.file))?;
+                saved.iv_analyses.push(parsed.analysis);
+            }
+        }
+
+        Ok(saved)
+    }
+}
+
+/// Contents of the reduction report JSON, read from the bare file or from the
+/// report ZIP bundle
+pub(crate) fn read_report_json(report_path: &Path) -> Result<String> {
+    let extension = report_path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    match extension.as_str() {
+        "zip" => {
+            let file = std::fs::File::open(report_path).with_context(|| {
+                format!("Failed to open report bundle: {}", report_path.display())
+            })?;
+            let mut archive = ::zip::ZipArchive::new(file)
+                .with_context(|| format!("Invalid zip file: {}", report_path.display()))?;
+            let name = archive
+                .file_names()
+                .find(|n| !n.contains('/') && n.ends_with("reduction_report.json"))
+                .map(String::from)
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "No reduction_report.json found in {}",
+                        report_path.display()
+                    )
+                })?;
+            read_zip_entry(&mut archive, &name)
+        }
+        "json" => std::fs::read_to_string(report_path)
+            .with_context(|| format!("Failed to read report: {}", report_path.display())),
+        _ => anyhow::bail!(
+            "Unsupported report format: {}. Expected a reduction report .json or .zip",
+            report_path.display()
+        ),
+    }
+}
+
+fn parse_gini_json(content: &str) -> Result<Vec<IvAnalysis>> {
+    let gini: GiniFile = serde_json::from_str(content)?;
+    Ok(gini.features.into_iter().map(|e| e.analysis).collect())
+}
+
+fn read_zip_entry(archive: &mut ::zip::ZipArchive<std::fs::File>, name: &str) -> Result<String> {
+    let mut content = String::new();
+    archive
+        .by_name(name)
+        .with_context(|| format!("Missing {} in report bundle", name))?
+        .read_to_string(&mut content)
+        .with_context(|| format!("Failed to read {} from report bundle", name))?;
+    Ok(content)
+}
+
+/// Reduce `df` to the columns kept by a saved reduction.
+///
+/// The output holds the kept features plus the target, weight and split columns
+/// when present (scoring data often has no target), in the input's column order.
+/// Every other column is removed, including columns the original run never
+/// saw, so the result matches the original reduced dataset's layout.
+///
+/// With `woe` set, kept features are replaced by their WoE values using the
+/// saved bins. When the original run derived features from its date columns,
+/// they are derived again from `df` first.
+///
+/// # Errors
+/// Fails if a kept feature is missing from `df`, or if `woe` is requested but
+/// the saved reduction has no bins.
+pub fn apply_saved_reduction(
+    df: &DataFrame,
+    saved: &SavedReduction,
+    woe: bool,
+) -> Result<(DataFrame, ApplySummary)> {
+    let input_columns = df.width();
+    let derived;
+    let df = if saved.date_policy == DatePolicy::Derive {
+        let mut exclude = vec![saved.target_column.as_str()];
+        exclude.extend(saved.weight_column.as_deref());
+        exclude.extend(saved.split_column.as_deref());
+        let mut copy = df.clone();
+        apply_date_policy(&mut copy, DatePolicy::Derive, &exclude)?;
+        derived = copy;
+        &derived
+    } else {
+        df
+    };
+
+    let columns: HashSet<&str> = df
+        .get_column_names()
+        .into_iter()
+        .map(|c| c.as_str())
+        .collect();
+
+    let missing: Vec<&str> = saved
+        .kept_features
+        .iter()
+        .map(String::as_str)
+        .filter(|f| !columns.contains(f))
+        .collect();
+    if !missing.is_empty() {
+        anyhow::bail!(
+            "{} kept feature(s) from the report are missing in the input: {}",
+            missing.len(),
+            missing.join(", ")
+        );
+    }
+    if woe && saved.iv_analyses.is_empty() {
+        anyhow::bail!(
+            "WoE encoding needs the Gini analysis bins. Pass the report .zip bundle or \
+             provide --gini-analysis."
+        );
+    }
+
+    let kept: HashSet<&str> = saved.kept_features.iter().map(String::as_str).collect();
+    let dropped: HashSet<&str> = saved.dropped_features.iter().map(String::as_str).collect();
+    let mut summary = ApplySummary {
+        input_columns,
+        ..Default::default()
+    };
+
+    let mut selected = Vec::new();
+    for name in df.get_column_names() {
+        let name = name.as_str();
+        if kept.contains(name)
+            || name == saved.target_column
+            || saved.weight_column.as_deref() == Some(name)
+            || saved.split_column.as_deref() == Some(name)
+        {
+            selected.push(name.to_string());
+        } else if dropped.contains(name) {
+            summary.dropped.push(name.to_string());
+        } else {
+            summary.removed_unknown.push(name.to_string());
+        }
+    }
+
+    let mut result = df.select(selected)?;
+
+    if woe {
+        let kept_analyses: Vec<IvAnalysis> = saved
+            .iv_analyses
+            .iter()
+            .filter(|a| kept.contains(a.feature_name.as_str()))
+            .cloned()
+            .collect();
+        summary.woe_encoded = apply_woe_transform(&mut result, &kept_analyses)?;
+    }
+
+    Ok((result, summary))
+}
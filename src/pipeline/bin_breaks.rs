@@ -0,0 +1,123 @@
+//! Fixed breakpoints for manual binning (`--binning-strategy manual`)
+//!
+//! Scorecards built under a regulator's eye often have to use agreed bands,
+//! e.g. ages 18-25, 25-35, 35-50 and 50+. With [`BinningStrategy::Manual`]
+//! the numeric binning in [`crate::pipeline::iv`] cuts each feature at the
+//! given breakpoints instead of searching for splits; the bands are the
+//! prebins, so the solver and the greedy merge still apply on top of them.
+//!
+//! [`BinningStrategy::Manual`]: crate::pipeline::BinningStrategy::Manual
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use serde::Serialize;
+
+/// Breakpoints for every numeric feature or per feature
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct BinBreaks {
+    /// Breakpoints of every numeric feature without its own
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub global: Vec<f64>,
+    /// Breakpoints of one feature, replacing `global` for it
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub per_feature: BTreeMap<String, Vec<f64>>,
+}
+
+impl BinBreaks {
+    /// Parse `B1,B2,...` (every feature) and `FEATURE:B1,B2,...` entries.
+    ///
+    /// The breakpoints follow the last `:`, so feature names may contain
+    /// colons. They must be finite numbers and are sorted and deduplicated;
+    /// a value equal to a breakpoint falls into the band above it.
+    pub fn parse<S: AsRef<str>>(entries: &[S]) -> Result<Self> {
+        let mut breaks = BinBreaks::default();
+        for entry in entries {
+            let entry = entry.as_ref().trim();
+            if entry.is_empty() {
+                continue;
+            }
+            let (feature, list) = match entry.rsplit_once(':') {
+                Some((feature, list)) => (Some(feature.trim()), list),
+                None => (None, entry),
+            };
+            let mut points = Vec::new();
+            for point in list.split(',').map(str::trim) {
+                match point.parse::<f64>() {
+                    Ok(value) if value.is_finite() => points.push(value),
+                    _ => bail!(
+                        "Invalid bin breaks '{}': expected finite numbers like 25,35,50 \
+                         or FEATURE:25,35,50",
+                        entry
+                    ),
+                }
+            }
+            points.sort_by(f64::total_cmp);
+            points.dedup();
+            match feature {
+                Some("") => bail!("Invalid bin breaks '{}': empty feature name", entry),
+                Some(feature) => {
+                    if breaks.per_feature.contains_key(feature) {
+                        bail!("Bin breaks for '{}' are given more than once", feature);
+                    }
+                    breaks.per_feature.insert(feature.to_string(), points);
+                }
+                None if !breaks.global.is_empty() => {
+                    bail!("Bin breaks for every feature are given more than once")
+                }
+                None => breaks.global = points,
+            }
+        }
+        Ok(breaks)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.global.is_empty() && self.per_feature.is_empty()
+    }
+
+    /// The breakpoints of `feature`: its own, else the global ones; `None`
+    /// when neither is set
+    pub fn for_feature(&self, feature: &str) -> Option<&[f64]> {
+        match self.per_feature.get(feature) {
+            Some(points) => Some(points),
+            None => (!self.global.is_empty()).then_some(self.global.as_slice()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_global_and_per_feature_breaks() {
+        let breaks = BinBreaks::parse(&["0.5, 1.5", "age:50,25,35,25"]).unwrap();
+        assert_eq!(breaks.global, [0.5, 1.5]);
+        assert_eq!(breaks.per_feature["age"], [25.0, 35.0, 50.0]);
+
+        assert_eq!(breaks.for_feature("age"), Some(&[25.0, 35.0, 50.0][..]));
+        assert_eq!(breaks.for_feature("income"), Some(&[0.5, 1.5][..]));
+        assert_eq!(
+            BinBreaks::parse(&["age:25"]).unwrap().for_feature("income"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_feature_names_with_colons() {
+        let breaks = BinBreaks::parse(&["bureau:score:300,600"]).unwrap();
+        assert_eq!(breaks.per_feature["bureau:score"], [300.0, 600.0]);
+        assert!(breaks.global.is_empty());
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_breaks() {
+        assert!(BinBreaks::parse(&["1,abc"]).is_err());
+        assert!(BinBreaks::parse(&["age:"]).is_err());
+        assert!(BinBreaks::parse(&[":5"]).is_err());
+        assert!(BinBreaks::parse(&["1,inf"]).is_err());
+        assert!(BinBreaks::parse(&["age:1", "age:2"]).is_err());
+        assert!(BinBreaks::parse(&["1", "2"]).is_err());
+        assert!(BinBreaks::parse::<&str>(&[]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,147 @@
+//! Bounds-checked, endian-aware cursor shared by the SPSS and Stata readers
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+/// Bytes read up front when only a file's dictionary is needed
+const HEADER_PREFIX_BYTES: u64 = 1024 * 1024;
+
+pub(crate) struct ByteReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    big_endian: bool,
+}
+
+impl<'a> ByteReader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            pos: 0,
+            big_endian: false,
+        }
+    }
+
+    pub(crate) fn set_big_endian(&mut self, big_endian: bool) {
+        self.big_endian = big_endian;
+    }
+
+    pub(crate) fn position(&self) -> usize {
+        self.pos
+    }
+
+    pub(crate) fn seek(&mut self, pos: usize) -> Result<()> {
+        if pos > self.data.len() {
+            anyhow::bail!("Offset {} is past the end of the file", pos);
+        }
+        self.pos = pos;
+        Ok(())
+    }
+
+    pub(crate) fn is_at_end(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    pub(crate) fn bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|&end| end <= self.data.len())
+            .with_context(|| format!("Unexpected end of file at byte {}", self.pos))?;
+        let slice = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    pub(crate) fn skip(&mut self, n: usize) -> Result<()> {
+        self.bytes(n).map(|_| ())
+    }
+
+    /// Consume `tag`, failing if the file holds something else
+    pub(crate) fn expect(&mut self, tag: &str) -> Result<()> {
+        let at = self.pos;
+        if self.bytes(tag.len())? != tag.as_bytes() {
+            anyhow::bail!("Expected '{}' at byte {}", tag, at);
+        }
+        Ok(())
+    }
+
+    /// Unsigned integer of `width` bytes (1-8) in the file's byte order
+    pub(crate) fn uint(&mut self, width: usize) -> Result<u64> {
+        let bytes = self.bytes(width)?;
+        let fold = |acc: u64, b: &u8| (acc << 8) | u64::from(*b);
+        Ok(if self.big_endian {
+            bytes.iter().fold(0, fold)
+        } else {
+            bytes.iter().rev().fold(0, fold)
+        })
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    pub(crate) fn u16(&mut self) -> Result<u16> {
+        Ok(self.uint(2)? as u16)
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32> {
+        Ok(self.uint(4)? as u32)
+    }
+
+    pub(crate) fn u64(&mut self) -> Result<u64> {
+        self.uint(8)
+    }
+
+    pub(crate) fn i8(&mut self) -> Result<i8> {
+        Ok(self.u8()? as i8)
+    }
+
+    pub(crate) fn i16(&mut self) -> Result<i16> {
+        Ok(self.u16()? as i16)
+    }
+
+    pub(crate) fn i32(&mut self) -> Result<i32> {
+        Ok(self.u32()? as i32)
+    }
+
+    pub(crate) fn f32(&mut self) -> Result<f32> {
+        Ok(f32::from_bits(self.u32()?))
+    }
+
+    pub(crate) fn f64(&mut self) -> Result<f64> {
+        Ok(f64::from_bits(self.u64()?))
+    }
+}
+
+/// Decode a fixed-width text field: cut at the first NUL, read as UTF-8 and
+/// fall back to Latin-1 for files written in a legacy code page
+pub(crate) fn decode_text(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    let bytes = &bytes[..end];
+    match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_string(),
+        Err(_) => bytes.iter().map(|&b| char::from(b)).collect(),
+    }
+}
+
+/// Run `parse` on the start of `path`, re-reading the whole file when the
+/// dictionary turns out to be longer than the prefix
+pub(crate) fn parse_file_header<T>(path: &Path, parse: impl Fn(&[u8]) -> Result<T>) -> Result<T> {
+    let file = File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let len = file.metadata()?.len();
+    let mut prefix = Vec::new();
+    file.take(HEADER_PREFIX_BYTES)
+        .read_to_end(&mut prefix)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    match parse(&prefix) {
+        Err(_) if len > HEADER_PREFIX_BYTES => parse(&read_file(path)?),
+        result => result,
+    }
+}
+
+pub(crate) fn read_file(path: &Path) -> Result<Vec<u8>> {
+    std::fs::read(path).with_context(|| format!("Failed to read {}", path.display()))
+}
@@ -0,0 +1,152 @@
+//! ID and high-cardinality column detection
+//!
+//! Columns where almost every row holds a different value (customer IDs,
+//! free text, event timestamps) carry no reusable signal. As categoricals they
+//! get one bin per value, which inflates IV and slows the Gini stage down.
+//! `--id-threshold` finds the columns whose distinct-value ratio exceeds it,
+//! and `--id-action` decides whether they are flagged or dropped.
+
+use std::collections::HashSet;
+
+use anyhow::Result;
+use polars::prelude::*;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Columns with fewer non-null values than this are never flagged: a handful
+/// of distinct values says nothing about whether a column is an ID
+pub const ID_MIN_VALUES: usize = 20;
+
+/// What happens to the columns `--id-threshold` flags
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdAction {
+    /// Warn and list them in the report, but keep them (default)
+    #[default]
+    Flag,
+    /// Drop them before missing analysis
+    Drop,
+}
+
+impl std::fmt::Display for IdAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdAction::Flag => write!(f, "flag"),
+            IdAction::Drop => write!(f, "drop"),
+        }
+    }
+}
+
+impl std::str::FromStr for IdAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "flag" => Ok(IdAction::Flag),
+            "drop" => Ok(IdAction::Drop),
+            _ => Err(format!("Unknown ID action: '{}'. Use 'flag' or 'drop'.", s)),
+        }
+    }
+}
+
+/// Distinct-value count of a column
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardinalityStats {
+    pub feature: String,
+    /// Distinct non-null values
+    pub unique: usize,
+    pub non_null: usize,
+    /// `unique / non_null`, 0 for an all-null column
+    pub ratio: f64,
+}
+
+impl CardinalityStats {
+    /// Whether the column looks like an ID at `threshold`
+    pub fn is_id_like(&self, threshold: f64) -> bool {
+        self.non_null >= ID_MIN_VALUES && self.ratio > threshold
+    }
+}
+
+/// Whether a column of this type can hold IDs. Floats are left out: a
+/// continuous measurement is expected to take a different value on every row.
+fn may_hold_ids(dtype: &DataType) -> bool {
+    dtype.is_integer()
+        || dtype.is_temporal()
+        || matches!(dtype, DataType::String | DataType::Categorical(_, _))
+}
+
+/// Count the distinct values of every string, categorical, integer and
+/// date/time column. The weight column is skipped. Results are sorted by
+/// ratio descending, then by name.
+pub fn analyze_cardinality(
+    df: &DataFrame,
+    weight_column: Option<&str>,
+) -> Result<Vec<CardinalityStats>> {
+    let mut stats = df
+        .get_columns()
+        .par_iter()
+        .filter(|column| Some(column.name().as_str()) != weight_column)
+        .filter(|column| may_hold_ids(column.dtype()))
+        .map(|column| {
+            let series = column.as_materialized_series();
+            let non_null = series.len() - series.null_count();
+            let unique = series.drop_nulls().n_unique()?;
+            Ok(CardinalityStats {
+                feature: column.name().to_string(),
+                unique,
+                non_null,
+                ratio: if non_null == 0 {
+                    0.0
+                } else {
+                    unique as f64 / non_null as f64
+                },
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    stats.sort_by(|a, b| {
+        b.ratio
+            .total_cmp(&a.ratio)
+            .then_with(|| a.feature.cmp(&b.feature))
+    });
+    Ok(stats)
+}
+
+/// Features [`CardinalityStats::is_id_like`] at `threshold`; the target and
+/// `keep` are never returned
+pub fn get_id_like_features_with_keep(
+    stats: &[CardinalityStats],
+    threshold: f64,
+    target_column: &str,
+    keep: &HashSet<String>,
+) -> Vec<String> {
+    stats
+        .iter()
+        .filter(|s| s.is_id_like(threshold))
+        .filter(|s| s.feature != target_column && !keep.contains(&s.feature))
+        .map(|s| s.feature.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_floats_and_short_columns_are_not_ids() {
+        let df = df! {
+            "income" => (0..50).map(|i| i as f64 * 1.5).collect::<Vec<_>>(),
+            "customer_id" => (0..50).collect::<Vec<i64>>(),
+        }
+        .unwrap();
+        let stats = analyze_cardinality(&df, None).unwrap();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].feature, "customer_id");
+        assert!(stats[0].is_id_like(0.95));
+
+        let short = df.head(Some(ID_MIN_VALUES - 1));
+        let stats = analyze_cardinality(&short, None).unwrap();
+        assert_eq!(stats[0].ratio, 1.0);
+        assert!(!stats[0].is_id_like(0.95));
+    }
+}
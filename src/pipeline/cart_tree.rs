@@ -0,0 +1,203 @@
+//! Fitted CART prebinning trees, kept for diagnostics
+//!
+//! CART prebinning only keeps the split points it finds. With
+//! `--export-cart-trees`, the tree that produced them is rebuilt per numeric
+//! feature, with each node's row count, weighted events, impurity and either
+//! its split or the reason it stopped splitting. The tree is grown by the same
+//! routine and split budget as [`super::iv`], so its split values match the
+//! bounds of the CART prebins exactly.
+
+use anyhow::Result;
+use polars::prelude::*;
+use serde::Serialize;
+
+use super::iv::{
+    binary_target_values, cart_min_samples, find_best_split, gini_impurity, MIN_BIN_SAMPLES,
+};
+use super::target::TargetMapping;
+
+/// Why a node was not split further
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CartStopReason {
+    /// The node's share of the `prebins - 1` split budget was used up
+    SplitBudgetSpent,
+    /// Fewer than twice the minimum bin size rows
+    TooFewSamples,
+    /// No split between distinct values reduced impurity while leaving the
+    /// minimum bin size on both sides
+    NoImprovingSplit,
+}
+
+/// Split of a [`CartTreeNode`]: rows with a value below `threshold` go left
+#[derive(Debug, Clone, Serialize)]
+pub struct CartSplit {
+    pub threshold: f64,
+    /// Weighted Gini impurity reduction of the split
+    pub gain: f64,
+    pub left: Box<CartTreeNode>,
+    pub right: Box<CartTreeNode>,
+}
+
+/// One node of a fitted CART prebinning tree
+#[derive(Debug, Clone, Serialize)]
+pub struct CartTreeNode {
+    pub depth: usize,
+    /// Smallest and largest feature value in the node
+    pub min_value: f64,
+    pub max_value: f64,
+    /// Rows in the node (unweighted; the minimum bin size applies to this)
+    pub count: usize,
+    pub events: f64,
+    pub non_events: f64,
+    /// Gini impurity 2p(1-p) of the node's weighted event rate
+    pub impurity: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split: Option<CartSplit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_reason: Option<CartStopReason>,
+}
+
+impl CartTreeNode {
+    fn leaf_count(&self) -> usize {
+        self.split
+            .as_ref()
+            .map_or(1, |s| s.left.leaf_count() + s.right.leaf_count())
+    }
+
+    fn collect_thresholds(&self, out: &mut Vec<f64>) {
+        if let Some(split) = &self.split {
+            split.left.collect_thresholds(out);
+            out.push(split.threshold);
+            split.right.collect_thresholds(out);
+        }
+    }
+}
+
+/// Fitted CART prebinning tree of one numeric feature
+#[derive(Debug, Clone, Serialize)]
+pub struct CartTree {
+    pub feature_name: String,
+    /// Minimum rows per prebin used while growing the tree
+    pub min_bin_samples: usize,
+    /// Split thresholds in ascending order (the inner prebin boundaries)
+    pub splits: Vec<f64>,
+    /// Number of leaves, i.e. CART prebins
+    pub leaves: usize,
+    pub root: CartTreeNode,
+}
+
+/// Rebuild the CART prebinning tree of every numeric feature in `df`.
+///
+/// Arguments match [`super::analyze_features_iv`]. Features that would not be
+/// prebinned (fewer than 10 non-missing rows with a valid target) are skipped.
+/// Results follow the column order of `df`.
+#[allow(clippy::too_many_arguments)]
+pub fn fit_cart_trees(
+    df: &DataFrame,
+    target: &str,
+    prebins: usize,
+    target_mapping: Option<&TargetMapping>,
+    cart_min_bin_pct: Option<f64>,
+    weights: &[f64],
+    weight_column: Option<&str>,
+) -> Result<Vec<CartTree>> {
+    if df.height() == 0 {
+        return Ok(Vec::new());
+    }
+
+    let target_values = binary_target_values(df, target, target_mapping)?;
+    let min_samples = cart_min_samples(df.height(), cart_min_bin_pct);
+
+    let mut trees = Vec::new();
+    for col in df.get_columns() {
+        if !col.dtype().is_primitive_numeric()
+            || col.name() == target
+            || Some(col.name().as_str()) == weight_column
+        {
+            continue;
+        }
+
+        let float_col = col.cast(&DataType::Float64)?;
+        let mut pairs: Vec<(f64, i32, f64)> = float_col
+            .f64()?
+            .iter()
+            .zip(&target_values)
+            .zip(weights)
+            .filter_map(|((v, t), &w)| Some((v?, (*t)?, w)))
+            .collect();
+        if pairs.len() < MIN_BIN_SAMPLES * 2 {
+            continue;
+        }
+        pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+        let root = grow_node(&pairs, 0, prebins.saturating_sub(1), min_samples);
+        let mut splits = Vec::new();
+        root.collect_thresholds(&mut splits);
+        trees.push(CartTree {
+            feature_name: col.name().to_string(),
+            min_bin_samples: min_samples,
+            splits,
+            leaves: root.leaf_count(),
+            root,
+        });
+    }
+
+    Ok(trees)
+}
+
+/// Mirror of the CART split recursion in `iv.rs`, recording every node
+fn grow_node(
+    sorted_pairs: &[(f64, i32, f64)],
+    depth: usize,
+    max_splits: usize,
+    min_samples: usize,
+) -> CartTreeNode {
+    let (events, non_events) =
+        sorted_pairs.iter().fold(
+            (0.0, 0.0),
+            |(e, ne), &(_, t, w)| {
+                if t == 1 {
+                    (e + w, ne)
+                } else {
+                    (e, ne + w)
+                }
+            },
+        );
+    let mut node = CartTreeNode {
+        depth,
+        min_value: sorted_pairs.first().map_or(f64::NAN, |p| p.0),
+        max_value: sorted_pairs.last().map_or(f64::NAN, |p| p.0),
+        count: sorted_pairs.len(),
+        events,
+        non_events,
+        impurity: gini_impurity(events, non_events),
+        split: None,
+        stop_reason: None,
+    };
+
+    if max_splits == 0 {
+        node.stop_reason = Some(CartStopReason::SplitBudgetSpent);
+    } else if sorted_pairs.len() < 2 * min_samples {
+        node.stop_reason = Some(CartStopReason::TooFewSamples);
+    } else if let Some((split_idx, gain)) = find_best_split(sorted_pairs, min_samples) {
+        let (left, right) = sorted_pairs.split_at(split_idx);
+        let remaining = max_splits - 1;
+        let left_splits = remaining / 2;
+        node.split = Some(CartSplit {
+            threshold: right[0].0,
+            gain,
+            left: Box::new(grow_node(left, depth + 1, left_splits, min_samples)),
+            right: Box::new(grow_node(
+                right,
+                depth + 1,
+                remaining - left_splits,
+                min_samples,
+            )),
+        });
+    } else {
+        node.stop_reason = Some(CartStopReason::NoImprovingSplit);
+    }
+
+    node
+}
@@ -0,0 +1,195 @@
+//! Stage checkpoints for resuming an interrupted run (`--resume`)
+//!
+//! After the missing, Gini/IV and correlation stages the pipeline writes the
+//! stage results as JSON to `.lophi_checkpoint/{input stem}/` next to the
+//! input file. A later run with `--resume` loads the results of every stage
+//! that finished instead of recomputing it. Checkpoints written under other
+//! settings, another input file or another lo-phi version are discarded. The
+//! directory is removed once a run completes.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use super::cloud::local_anchor;
+use super::concordance::ConcordanceStats;
+use super::correlation::{CorrelatedPair, FeatureToDrop};
+use super::cross_validation::CrossValidatedIv;
+use super::iv::{IvAnalysis, RSquaredAnalysis};
+use super::missing::SegmentMissingAnalysis;
+use super::multi_file::output_anchor;
+use super::segment::SegmentIvAnalysis;
+use crate::report::DecisionLogEntry;
+
+/// Directory created next to the input file
+pub const CHECKPOINT_DIR: &str = ".lophi_checkpoint";
+
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Pipeline stages that leave a checkpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckpointStage {
+    Missing,
+    Gini,
+    Correlation,
+}
+
+impl CheckpointStage {
+    fn file_name(self) -> &'static str {
+        match self {
+            CheckpointStage::Missing => "missing.json",
+            CheckpointStage::Gini => "gini.json",
+            CheckpointStage::Correlation => "correlation.json",
+        }
+    }
+}
+
+/// Results of the missing value stage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissingCheckpoint {
+    pub missing_ratios: Vec<(String, f64)>,
+    pub dropped: Vec<String>,
+    /// Per-segment missing ratios, with `--segment-column`
+    #[serde(default)]
+    pub segments: Option<SegmentMissingAnalysis>,
+}
+
+/// Results of the Gini/IV stage (R² for a continuous target)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GiniCheckpoint {
+    pub iv_analyses: Vec<IvAnalysis>,
+    pub r2_analyses: Vec<RSquaredAnalysis>,
+    pub concordance: Vec<ConcordanceStats>,
+    pub dropped: Vec<String>,
+    /// Holdout IV per feature with `--cv-folds`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cross_validation: Vec<CrossValidatedIv>,
+    /// Per-segment IV with `--segment-column`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub segment_iv: Option<SegmentIvAnalysis>,
+}
+
+/// Results of the correlation stage, after any interactive review
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorrelationCheckpoint {
+    pub correlated_pairs: Vec<CorrelatedPair>,
+    pub dropped: Vec<FeatureToDrop>,
+    pub decisions: Vec<DecisionLogEntry>,
+}
+
+/// Identifies the run a checkpoint belongs to
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Manifest {
+    lophi_version: String,
+    settings: String,
+}
+
+/// Checkpoint directory of one input file
+#[derive(Debug)]
+pub struct Checkpoint {
+    dir: PathBuf,
+    resumed: bool,
+}
+
+/// `.lophi_checkpoint/{input stem}` in the directory of `input` (of the
+/// folder holding the files for a glob or directory input, the current
+/// directory for a cloud input)
+pub fn checkpoint_dir(input: &Path) -> PathBuf {
+    let input = &local_anchor(&output_anchor(input));
+    let parent = input.parent().unwrap_or_else(|| Path::new("."));
+    let stem = input
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("input");
+    parent.join(CHECKPOINT_DIR).join(stem)
+}
+
+impl Checkpoint {
+    /// Open the checkpoint of `input` for a run identified by `settings`.
+    ///
+    /// With `resume`, stages saved by an earlier run with the same settings
+    /// can be loaded. Otherwise, or when the settings differ, the earlier
+    /// checkpoint is discarded.
+    pub fn open(input: &Path, settings: &str, resume: bool) -> Result<Self> {
+        let dir = checkpoint_dir(input);
+        let manifest = Manifest {
+            lophi_version: env!("CARGO_PKG_VERSION").to_string(),
+            settings: settings.to_string(),
+        };
+
+        let resumed = resume
+            && fs::read_to_string(dir.join(MANIFEST_FILE))
+                .ok()
+                .and_then(|s| serde_json::from_str::<Manifest>(&s).ok())
+                .is_some_and(|saved| saved == manifest);
+        if !resumed {
+            if dir.exists() {
+                fs::remove_dir_all(&dir).with_context(|| {
+                    format!("Failed to clear checkpoint directory {}", dir.display())
+                })?;
+            }
+            fs::create_dir_all(&dir).with_context(|| {
+                format!("Failed to create checkpoint directory {}", dir.display())
+            })?;
+            write_json(&dir.join(MANIFEST_FILE), &manifest)?;
+        }
+
+        Ok(Self { dir, resumed })
+    }
+
+    /// Whether stages of an earlier run can be loaded
+    pub fn resumed(&self) -> bool {
+        self.resumed
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Results of `stage` from the earlier run, if it finished that stage.
+    ///
+    /// A missing or unreadable file yields `None`, so the stage is recomputed.
+    pub fn load<T: DeserializeOwned>(&self, stage: CheckpointStage) -> Option<T> {
+        if !self.resumed {
+            return None;
+        }
+        let contents = fs::read_to_string(self.dir.join(stage.file_name())).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    /// Record the results of a finished stage
+    pub fn save<T: Serialize>(&self, stage: CheckpointStage, value: &T) -> Result<()> {
+        write_json(&self.dir.join(stage.file_name()), value)
+    }
+
+    /// Delete the checkpoint after a completed run, and the parent
+    /// `.lophi_checkpoint` directory once it is empty
+    pub fn remove(self) -> Result<()> {
+        fs::remove_dir_all(&self.dir).with_context(|| {
+            format!(
+                "Failed to remove checkpoint directory {}",
+                self.dir.display()
+            )
+        })?;
+        if let Some(parent) = self.dir.parent() {
+            // Fails while other inputs still have checkpoints
+            fs::remove_dir(parent).ok();
+        }
+        Ok(())
+    }
+}
+
+/// Write through a temporary file so an interrupted write never leaves a
+/// truncated stage behind
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    let tmp = path.with_extension("json.tmp");
+    let json = serde_json::to_string(value).context("Failed to serialize checkpoint")?;
+    fs::write(&tmp, json)
+        .with_context(|| format!("Failed to write checkpoint {}", tmp.display()))?;
+    fs::rename(&tmp, path)
+        .with_context(|| format!("Failed to write checkpoint {}", path.display()))?;
+    Ok(())
+}
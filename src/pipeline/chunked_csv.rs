@@ -0,0 +1,343 @@
+//! Chunked CSV conversion, used by `lophi convert` in streaming mode
+//!
+//! The CSV file is read a chunk of whole records at a time, each chunk is
+//! parsed with the schema inferred up front and written out before the next
+//! one is read. Memory therefore stays at about two chunks plus one Parquet
+//! row group however large the file is. Chunks are cut at line ends outside
+//! quoted fields, so quoted values may span lines. Progress is reported in
+//! bytes of the file consumed.
+
+use std::fs::File;
+use std::io::{Cursor, Read};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+
+use super::csv_dialect::CsvDialect;
+use super::parquet_options::ParquetOptions;
+
+/// Bytes of CSV text parsed at a time
+pub const DEFAULT_CHUNK_BYTES: usize = 64 * 1024 * 1024;
+
+/// Rows per row group when the Parquet options leave it to Polars (512²)
+const POLARS_ROW_GROUP_ROWS: usize = 512 * 512;
+
+/// Format written by [`CsvChunker::convert`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkedOutput {
+    Parquet(ParquetOptions),
+    /// Arrow IPC (Feather)
+    Ipc,
+}
+
+/// What [`CsvChunker::convert`] wrote
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkedConversion {
+    pub rows: usize,
+    pub columns: usize,
+    /// Chunks the file was parsed in
+    pub chunks: usize,
+}
+
+/// Converts a CSV file chunk by chunk
+///
+/// # Example
+/// ```no_run
+/// use std::path::Path;
+/// use lophi::pipeline::{ChunkedOutput, CsvChunker, CsvDialect, ParquetOptions};
+/// use polars::prelude::*;
+///
+/// let csv = CsvDialect::default();
+/// let schema = csv
+///     .scan(Path::new("data.csv"))
+///     .finish()?
+///     .collect_schema()?;
+/// let written = CsvChunker::new(&csv, schema, Some(10_000)).convert(
+///     Path::new("data.csv"),
+///     Path::new("data.parquet"),
+///     ChunkedOutput::Parquet(ParquetOptions::CONVERT),
+///     |read, total| println!("{read}/{total} bytes"),
+/// )?;
+/// println!("{} rows", written.rows);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub struct CsvChunker<'a> {
+    csv: &'a CsvDialect,
+    schema: SchemaRef,
+    sample_rows: Option<usize>,
+    chunk_bytes: usize,
+}
+
+impl<'a> CsvChunker<'a> {
+    /// A chunker reading `csv` files of `schema` (as inferred by a scan).
+    /// Date columns of the dialect are picked from the first `sample_rows`
+    /// rows (the whole first chunk for `None`).
+    pub fn new(csv: &'a CsvDialect, schema: SchemaRef, sample_rows: Option<usize>) -> Self {
+        Self {
+            csv,
+            schema,
+            sample_rows,
+            chunk_bytes: DEFAULT_CHUNK_BYTES,
+        }
+    }
+
+    /// Parse about `bytes` of CSV text at a time (default 64 MiB). A single
+    /// record longer than this becomes a chunk of its own.
+    #[allow(dead_code)] // Library API; the binary uses the default
+    pub fn chunk_bytes(mut self, bytes: usize) -> Self {
+        self.chunk_bytes = bytes.max(1);
+        self
+    }
+
+    /// Convert `input` to `output`, calling `on_progress(bytes_read, file_size)`
+    /// after each chunk is written
+    pub fn convert(
+        &self,
+        input: &Path,
+        output: &Path,
+        format: ChunkedOutput,
+        mut on_progress: impl FnMut(u64, u64),
+    ) -> Result<ChunkedConversion> {
+        let file = File::open(input)
+            .with_context(|| format!("Failed to open CSV file: {}", input.display()))?;
+        let total = file.metadata().map(|m| m.len()).unwrap_or(0);
+        let mut chunks = RecordChunks::new(file, self.csv.quote_char, self.chunk_bytes);
+
+        let mut writer: Option<ChunkWriter> = None;
+        let mut date_columns = Vec::new();
+        let mut written = ChunkedConversion {
+            rows: 0,
+            columns: self.schema.len(),
+            chunks: 0,
+        };
+
+        while let Some(bytes) = chunks
+            .next_chunk()
+            .with_context(|| format!("Failed to read CSV file: {}", input.display()))?
+        {
+            let first = written.chunks == 0;
+            let df = self
+                .csv
+                .read_options()
+                .with_has_header(first)
+                .with_schema(Some(self.schema.clone()))
+                .into_reader_with_file_handle(Cursor::new(bytes))
+                .finish()
+                .with_context(|| {
+                    format!(
+                        "Failed to parse CSV rows from row {} of {}",
+                        written.rows + 1,
+                        input.display()
+                    )
+                })?;
+            if first {
+                date_columns = self.csv.date_columns(&df.head(self.sample_rows));
+            }
+            let df = self
+                .csv
+                .parse_date_columns(df, &date_columns)
+                .with_context(|| format!("Failed to parse CSV dates: {}", input.display()))?;
+
+            if writer.is_none() {
+                writer = Some(ChunkWriter::create(output, format, df.schema())?);
+            }
+            written.rows += df.height();
+            writer
+                .as_mut()
+                .expect("writer created above")
+                .write(df)
+                .with_context(|| format!("Failed to write {}", output.display()))?;
+            written.chunks += 1;
+            on_progress(chunks.consumed, total);
+        }
+
+        let writer = match writer {
+            Some(writer) => writer,
+            // An empty file still gets the header's columns
+            None => ChunkWriter::create(output, format, &self.schema)?,
+        };
+        writer
+            .finish()
+            .with_context(|| format!("Failed to write {}", output.display()))?;
+        Ok(written)
+    }
+}
+
+/// Batched writer of the output file
+enum ChunkWriter {
+    Parquet {
+        writer: Box<polars::io::parquet::write::BatchedWriter<File>>,
+        /// Rows waiting for a full row group
+        pending: DataFrame,
+        group_rows: usize,
+    },
+    Ipc(polars::io::ipc::BatchedWriter<File>),
+}
+
+impl ChunkWriter {
+    fn create(path: &Path, format: ChunkedOutput, schema: &Schema) -> Result<Self> {
+        let file = File::create(path)
+            .with_context(|| format!("Failed to create output file: {}", path.display()))?;
+        Ok(match format {
+            ChunkedOutput::Parquet(options) => ChunkWriter::Parquet {
+                writer: Box::new(options.writer(file).batched(schema)?),
+                pending: DataFrame::empty_with_schema(schema),
+                group_rows: options
+                    .row_group_size
+                    .unwrap_or(POLARS_ROW_GROUP_ROWS)
+                    .max(1),
+            },
+            ChunkedOutput::Ipc => ChunkWriter::Ipc(IpcWriter::new(file).batched(schema)?),
+        })
+    }
+
+    /// Write `df`; Parquet rows are held back until they fill a row group
+    fn write(&mut self, mut df: DataFrame) -> PolarsResult<()> {
+        match self {
+            ChunkWriter::Parquet {
+                writer,
+                pending,
+                group_rows,
+            } => {
+                pending.vstack_mut(&df)?;
+                while pending.height() >= *group_rows {
+                    let mut group = pending.slice(0, *group_rows);
+                    group.as_single_chunk_par();
+                    writer.write_batch(&group)?;
+                    *pending = pending.slice(*group_rows as i64, pending.height() - *group_rows);
+                }
+                Ok(())
+            }
+            ChunkWriter::Ipc(writer) => writer.write_batch(df.as_single_chunk_par()),
+        }
+    }
+
+    fn finish(self) -> PolarsResult<()> {
+        match self {
+            ChunkWriter::Parquet {
+                mut writer,
+                mut pending,
+                ..
+            } => {
+                if pending.height() > 0 {
+                    writer.write_batch(pending.as_single_chunk_par())?;
+                }
+                writer.finish().map(|_| ())
+            }
+            ChunkWriter::Ipc(mut writer) => writer.finish(),
+        }
+    }
+}
+
+/// Reads a CSV file in chunks of whole records of about `chunk_bytes`
+struct RecordChunks<R> {
+    reader: R,
+    quote: Option<u8>,
+    chunk_bytes: usize,
+    buffer: Vec<u8>,
+    /// Bytes of `buffer` already scanned for record ends
+    scanned: usize,
+    /// Whether the scan has stopped inside a quoted field
+    in_quotes: bool,
+    /// Where the next chunk ends: the last record end within `chunk_bytes`,
+    /// or the first one after it
+    record_end: usize,
+    /// Bytes handed out in chunks so far
+    consumed: u64,
+    eof: bool,
+}
+
+impl<R: Read> RecordChunks<R> {
+    fn new(reader: R, quote: Option<u8>, chunk_bytes: usize) -> Self {
+        Self {
+            reader,
+            quote,
+            chunk_bytes,
+            buffer: Vec::new(),
+            scanned: 0,
+            in_quotes: false,
+            record_end: 0,
+            consumed: 0,
+            eof: false,
+        }
+    }
+
+    /// The next chunk of whole records, or `None` at the end of the file.
+    /// The last chunk may end without a line break.
+    fn next_chunk(&mut self) -> std::io::Result<Option<Vec<u8>>> {
+        let mut block = [0u8; 65536]; // 64KB read blocks
+        while !self.eof && (self.record_end == 0 || self.buffer.len() < self.chunk_bytes) {
+            let bytes_read = self.reader.read(&mut block)?;
+            if bytes_read == 0 {
+                self.eof = true;
+            } else {
+                self.buffer.extend_from_slice(&block[..bytes_read]);
+                self.scan();
+            }
+        }
+
+        let end = if self.eof {
+            self.buffer.len()
+        } else {
+            self.record_end
+        };
+        if end == 0 {
+            return Ok(None);
+        }
+        let rest = self.buffer.split_off(end);
+        let chunk = std::mem::replace(&mut self.buffer, rest);
+        self.consumed += end as u64;
+        // The chunk ended outside quotes; find the record ends of the rest
+        self.scanned = 0;
+        self.in_quotes = false;
+        self.record_end = 0;
+        self.scan();
+        Ok(Some(chunk))
+    }
+
+    /// Track quotes over the newly read bytes and note the last record end
+    fn scan(&mut self) {
+        let start = self.scanned;
+        for (offset, &byte) in self.buffer[start..].iter().enumerate() {
+            if Some(byte) == self.quote {
+                // An escaped quote ("") toggles twice
+                self.in_quotes = !self.in_quotes;
+            } else if byte == b'\n' && !self.in_quotes {
+                let end = start + offset + 1;
+                if self.record_end == 0 || end <= self.chunk_bytes {
+                    self.record_end = end;
+                }
+            }
+        }
+        self.scanned = self.buffer.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks_of(text: &str, quote: Option<u8>, chunk_bytes: usize) -> Vec<String> {
+        let mut chunks = RecordChunks::new(text.as_bytes(), quote, chunk_bytes);
+        let mut out = Vec::new();
+        while let Some(chunk) = chunks.next_chunk().unwrap() {
+            out.push(String::from_utf8(chunk).unwrap());
+        }
+        assert_eq!(chunks.consumed, text.len() as u64);
+        out
+    }
+
+    #[test]
+    fn test_chunks_end_on_record_boundaries() {
+        let text = "a,b\n1,\"x\ny\"\n2,\"\"\"\"\n3,z";
+        assert_eq!(
+            chunks_of(text, Some(b'"'), 1),
+            vec!["a,b\n", "1,\"x\ny\"\n", "2,\"\"\"\"\n", "3,z"]
+        );
+        // Without quoting, every line break ends a record
+        assert_eq!(chunks_of("1,\"x\ny\"\n", None, 1), vec!["1,\"x\n", "y\"\n"]);
+        assert_eq!(chunks_of(text, Some(b'"'), 1 << 20), vec![text]);
+        assert!(chunks_of("", Some(b'"'), 1).is_empty());
+    }
+}
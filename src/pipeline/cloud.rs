@@ -0,0 +1,273 @@
+//! Cloud object storage: `s3://`, `gs://` and `az://` inputs and outputs
+//!
+//! Inputs are scanned lazily with Polars' cloud readers, so Parquet, Arrow
+//! IPC (Feather), plain CSV and JSON Lines objects are read without a local
+//! copy; a wildcard in the key is expanded by Polars. Outputs are written to
+//! a local staging file and uploaded once complete ([`StagedOutput`],
+//! [`upload_dir`] for the plots), so a failed run never leaves a partial
+//! object behind. Credentials come from
+//! the standard environment of each store (`AWS_ACCESS_KEY_ID`,
+//! `AWS_REGION`, `GOOGLE_APPLICATION_CREDENTIALS`, `AZURE_STORAGE_ACCOUNT_NAME`
+//! and so on), as Polars' `object_store` builders read them.
+//!
+//! Everything but URI detection needs the `cloud` feature.
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use super::csv_dialect::CsvDialect;
+
+/// Error for cloud URIs in builds without the `cloud` feature
+#[cfg(not(feature = "cloud"))]
+pub const CLOUD_DISABLED: &str =
+    "Cloud storage support is disabled in this build (enable the `cloud` feature)";
+
+/// URI schemes of the supported object stores
+const CLOUD_SCHEMES: [&str; 8] = ["s3", "s3a", "gs", "gcs", "az", "azure", "abfs", "abfss"];
+
+/// Whether `path` is an object store URI such as `s3://bucket/data.parquet`
+pub fn is_cloud_uri(path: &Path) -> bool {
+    path.to_str()
+        .and_then(|s| s.split_once("://"))
+        .is_some_and(|(scheme, _)| {
+            CLOUD_SCHEMES
+                .iter()
+                .any(|known| scheme.eq_ignore_ascii_case(known))
+        })
+}
+
+/// Where state kept across runs of `path` lives: the current directory for
+/// a cloud URI (keeping the object's name), `path` itself otherwise
+pub fn local_anchor(path: &Path) -> PathBuf {
+    if !is_cloud_uri(path) {
+        return path.to_path_buf();
+    }
+    PathBuf::from(".").join(path.file_name().unwrap_or("dataset".as_ref()))
+}
+
+/// Where a file derived from `path` is written before it is zipped or
+/// uploaded: a name of this process in the temporary directory for a cloud
+/// URI, so runs on same-named objects never share a file; `path` itself
+/// otherwise
+pub fn staging_anchor(path: &Path) -> PathBuf {
+    if !is_cloud_uri(path) {
+        return path.to_path_buf();
+    }
+    let name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("output");
+    std::env::temp_dir().join(format!("lophi-{}-{}", std::process::id(), name))
+}
+
+/// Lazily scan the object(s) at `uri`, by the extension of its key.
+///
+/// `infer_schema_length` applies to CSV and JSON Lines (0 scans the whole
+/// object); CSV is read in dialect `csv`.
+pub fn scan(uri: &Path, infer_schema_length: usize, csv: &CsvDialect) -> Result<LazyFrame> {
+    #[cfg(feature = "cloud")]
+    {
+        use anyhow::Context;
+
+        let schema_length = std::num::NonZeroUsize::new(infer_schema_length).map(usize::from);
+        let extension = uri
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        let scanned = match extension.as_str() {
+            "parquet" => LazyFrame::scan_parquet(uri, Default::default()),
+            "feather" | "arrow" | "ipc" => LazyFrame::scan_ipc(uri, Default::default()),
+            "csv" => csv
+                .scan(uri)
+                .with_infer_schema_length(schema_length)
+                .finish()
+                .and_then(|lf| csv.parse_dates_lazy(lf, schema_length)),
+            "jsonl" | "ndjson" => LazyJsonLineReader::new(uri)
+                .with_infer_schema_length(schema_length.and_then(std::num::NonZeroUsize::new))
+                .finish(),
+            _ => anyhow::bail!(
+                "Cloud input must be Parquet, Feather, plain CSV or JSON Lines, not .{} ({})",
+                extension,
+                uri.display()
+            ),
+        };
+        scanned.with_context(|| format!("Failed to scan {}", uri.display()))
+    }
+    #[cfg(not(feature = "cloud"))]
+    {
+        let _ = (uri, infer_schema_length, csv);
+        anyhow::bail!(CLOUD_DISABLED)
+    }
+}
+
+/// Size in bytes and last modification time of the object at `uri`
+pub fn object_metadata(uri: &Path) -> Result<(u64, Option<SystemTime>)> {
+    #[cfg(feature = "cloud")]
+    {
+        use anyhow::Context;
+        use polars::io::cloud::{build_object_store, object_path_from_str};
+        use polars::io::pl_async::get_runtime;
+
+        let url = uri.to_string_lossy().to_string();
+        let meta = get_runtime()
+            .block_on_potential_spawn(async {
+                let (location, store) = build_object_store(&url, None, false).await?;
+                store.head(&object_path_from_str(&location.prefix)?).await
+            })
+            .with_context(|| format!("Failed to read the metadata of {}", url))?;
+        Ok((meta.size as u64, Some(meta.last_modified.into())))
+    }
+    #[cfg(not(feature = "cloud"))]
+    {
+        let _ = uri;
+        anyhow::bail!(CLOUD_DISABLED)
+    }
+}
+
+/// A local file written in place of an output path, uploaded by
+/// [`StagedOutput::finish`] when the output is a cloud URI.
+///
+/// # Example
+/// ```no_run
+/// use std::path::Path;
+/// use lophi::pipeline::StagedOutput;
+///
+/// let staged = StagedOutput::new(Path::new("s3://bucket/out.csv"))?;
+/// std::fs::write(staged.path(), "a,b\n1,2\n")?;
+/// staged.finish()?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct StagedOutput {
+    target: PathBuf,
+    local: PathBuf,
+}
+
+impl StagedOutput {
+    /// Stage `target`: a cloud URI gets a file in the temporary directory,
+    /// a local path is written directly
+    pub fn new(target: &Path) -> Result<Self> {
+        let local = if is_cloud_uri(target) {
+            staging_path(target)?
+        } else {
+            target.to_path_buf()
+        };
+        Ok(Self {
+            target: target.to_path_buf(),
+            local,
+        })
+    }
+
+    /// The local path to write
+    pub fn path(&self) -> &Path {
+        &self.local
+    }
+
+    /// Upload the staged file to its cloud URI and remove it; nothing to do
+    /// for a local path
+    pub fn finish(self) -> Result<()> {
+        if self.local == self.target {
+            return Ok(());
+        }
+        let uploaded = upload(&self.local, &self.target);
+        std::fs::remove_file(&self.local).ok();
+        uploaded
+    }
+}
+
+#[cfg(feature = "cloud")]
+fn staging_path(target: &Path) -> Result<PathBuf> {
+    Ok(staging_anchor(target))
+}
+
+#[cfg(not(feature = "cloud"))]
+fn staging_path(_target: &Path) -> Result<PathBuf> {
+    anyhow::bail!(CLOUD_DISABLED)
+}
+
+/// Upload every file below the local directory `local` to the same relative
+/// key under `uri`, then remove `local`
+pub fn upload_dir(local: &Path, uri: &Path) -> Result<()> {
+    let mut pending = vec![local.to_path_buf()];
+    let mut uploaded = Ok(());
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            let relative = path.strip_prefix(local).unwrap_or(&path);
+            let key = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            uploaded = uploaded.and_then(|_| upload(&path, &uri.join(key)));
+        }
+    }
+    std::fs::remove_dir_all(local).ok();
+    uploaded
+}
+
+#[cfg(feature = "cloud")]
+fn upload(local: &Path, uri: &Path) -> Result<()> {
+    use anyhow::Context;
+    use polars::io::cloud::CloudWriter;
+    use polars::io::pl_async::get_runtime;
+
+    let url = uri.to_string_lossy().to_string();
+    let mut file = std::fs::File::open(local)
+        .with_context(|| format!("Failed to open staged file: {}", local.display()))?;
+    let mut writer = get_runtime()
+        .block_on_potential_spawn(CloudWriter::new(&url, None))
+        .with_context(|| format!("Failed to open {} for writing", url))?;
+    std::io::copy(&mut file, &mut writer).with_context(|| format!("Failed to upload {}", url))?;
+    writer
+        .close()
+        .with_context(|| format!("Failed to complete the upload of {}", url))?;
+    Ok(())
+}
+
+#[cfg(not(feature = "cloud"))]
+fn upload(_local: &Path, _uri: &Path) -> Result<()> {
+    anyhow::bail!(CLOUD_DISABLED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cloud_uri_detection() {
+        assert!(is_cloud_uri(Path::new("s3://bucket/data.parquet")));
+        assert!(is_cloud_uri(Path::new("GS://bucket/part_*.parquet")));
+        assert!(is_cloud_uri(Path::new("abfss://container@account/x.csv")));
+        assert!(!is_cloud_uri(Path::new("data/s3.parquet")));
+        assert!(!is_cloud_uri(Path::new("https://example.com/data.csv")));
+        assert_eq!(
+            local_anchor(Path::new("s3://bucket/lake/data.parquet")),
+            PathBuf::from("./data.parquet")
+        );
+        assert_eq!(
+            local_anchor(Path::new("data/x.csv")),
+            PathBuf::from("data/x.csv")
+        );
+        let staged = staging_anchor(Path::new("s3://bucket/lake/data_gini_analysis.json"));
+        assert!(staged.starts_with(std::env::temp_dir()));
+        assert!(staged
+            .to_string_lossy()
+            .ends_with("-data_gini_analysis.json"));
+    }
+
+    #[test]
+    fn test_local_output_is_not_staged() {
+        let staged = StagedOutput::new(Path::new("out/data.parquet")).unwrap();
+        assert_eq!(staged.path(), Path::new("out/data.parquet"));
+        staged.finish().unwrap();
+    }
+}
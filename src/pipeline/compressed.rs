@@ -0,0 +1,233 @@
+//! Transparent decompression of compressed CSV input
+//!
+//! `.csv.gz`, `.csv.zst` and single-file `.zip` archives are decompressed
+//! while they are read, so they load and convert like plain CSV without a
+//! temporary copy on disk. [`CsvStream`] counts the compressed bytes it has
+//! consumed, which lets progress bars advance against the file size even
+//! though the decompressed size is not known up front.
+
+use std::cell::Cell;
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+use std::rc::Rc;
+
+use anyhow::{Context, Result};
+use zip::{CompressionMethod, ZipArchive};
+
+/// Compression of a CSV file, from its extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvCompression {
+    /// `.csv.gz`
+    Gzip,
+    /// `.csv.zst`
+    Zstd,
+    /// `.zip` holding a single CSV file
+    Zip,
+}
+
+impl CsvCompression {
+    /// The compression of `path`, or `None` when it is not a compressed CSV.
+    /// Gzip and Zstandard files must be named `*.csv.gz` / `*.csv.zst` so
+    /// that other compressed files are not mistaken for CSV.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        let inner_is_csv = || {
+            Path::new(path.file_stem().unwrap_or_default())
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| e.eq_ignore_ascii_case("csv"))
+        };
+        match extension.as_str() {
+            "gz" | "gzip" if inner_is_csv() => Some(CsvCompression::Gzip),
+            "zst" | "zstd" if inner_is_csv() => Some(CsvCompression::Zstd),
+            "zip" => Some(CsvCompression::Zip),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            CsvCompression::Gzip => "gzip",
+            CsvCompression::Zstd => "zstd",
+            CsvCompression::Zip => "zip",
+        }
+    }
+}
+
+/// Whether `path` is a compressed CSV (`.csv.gz`, `.csv.zst` or `.zip`)
+pub fn is_compressed_csv(path: &Path) -> bool {
+    CsvCompression::from_path(path).is_some()
+}
+
+/// File name of `path` without its compression and `.csv` extensions:
+/// `loans.csv.gz` and `loans.zip` both give `loans`. Other paths give their
+/// usual file stem.
+pub fn dataset_stem(path: &Path) -> Option<&str> {
+    let stem = path.file_stem()?.to_str()?;
+    match CsvCompression::from_path(path) {
+        Some(CsvCompression::Gzip | CsvCompression::Zstd) => Path::new(stem).file_stem()?.to_str(),
+        _ => Some(stem),
+    }
+}
+
+/// Reader that counts the bytes taken from the file underneath a decoder
+struct CountingReader<R> {
+    inner: R,
+    count: Rc<Cell<u64>>,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.count.set(self.count.get() + n as u64);
+        Ok(n)
+    }
+}
+
+/// The CSV bytes of a plain or compressed CSV file
+pub struct CsvStream {
+    reader: Box<dyn Read>,
+    consumed: Rc<Cell<u64>>,
+    total: u64,
+    compression: Option<CsvCompression>,
+}
+
+impl CsvStream {
+    /// Open `path`, decompressing it when its extension says it is compressed
+    pub fn open(path: &Path) -> Result<Self> {
+        let mut file = File::open(path)
+            .with_context(|| format!("Failed to open CSV file: {}", path.display()))?;
+        let compression = CsvCompression::from_path(path);
+
+        // Zip entries are decoded straight from the entry's data in the
+        // archive file, so the counter sees only that entry's bytes
+        let mut zip_method = None;
+        let (file, total): (Box<dyn Read>, u64) = match compression {
+            Some(CsvCompression::Zip) => {
+                let (start, size, method) = zip_entry(&mut file, path)?;
+                file.seek(SeekFrom::Start(start))?;
+                zip_method = Some(method);
+                (Box::new(file.take(size)), size)
+            }
+            _ => {
+                let len = file
+                    .metadata()
+                    .context("Failed to get file metadata")?
+                    .len();
+                (Box::new(file), len)
+            }
+        };
+
+        let consumed = Rc::new(Cell::new(0));
+        let raw = CountingReader {
+            inner: BufReader::with_capacity(1024 * 1024, file), // 1MB buffer
+            count: Rc::clone(&consumed),
+        };
+        let reader: Box<dyn Read> = match compression {
+            // Concatenated gzip members (as written by `cat a.gz b.gz`) are one stream
+            Some(CsvCompression::Gzip) => Box::new(flate2::read::MultiGzDecoder::new(raw)),
+            Some(CsvCompression::Zstd) => Box::new(
+                zstd::Decoder::new(raw)
+                    .with_context(|| format!("Failed to open zstd file: {}", path.display()))?,
+            ),
+            Some(CsvCompression::Zip) if zip_method == Some(CompressionMethod::Deflated) => {
+                Box::new(flate2::read::DeflateDecoder::new(raw))
+            }
+            _ => Box::new(raw),
+        };
+        Ok(Self {
+            reader,
+            consumed,
+            total,
+            compression,
+        })
+    }
+
+    /// Bytes of the file (or zip entry) read so far
+    pub fn position(&self) -> u64 {
+        self.consumed.get()
+    }
+
+    /// Size of the file, or of the compressed entry of a zip archive
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    pub fn compression(&self) -> Option<CsvCompression> {
+        self.compression
+    }
+}
+
+impl Read for CsvStream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
+
+/// Data offset, compressed size and method of the single CSV in a zip archive
+fn zip_entry(file: &mut File, path: &Path) -> Result<(u64, u64, CompressionMethod)> {
+    let mut archive = ZipArchive::new(&mut *file)
+        .with_context(|| format!("Failed to read zip archive: {}", path.display()))?;
+    let entries: Vec<usize> = (0..archive.len())
+        .filter(|&i| {
+            archive
+                .name_for_index(i)
+                .is_some_and(|name| !name.ends_with('/') && !name.starts_with("__MACOSX/"))
+        })
+        .collect();
+    let [index] = entries[..] else {
+        anyhow::bail!(
+            "Zip archive {} must contain exactly one file, found {}",
+            path.display(),
+            entries.len()
+        );
+    };
+
+    let entry = archive.by_index_raw(index)?;
+    if !entry.name().to_lowercase().ends_with(".csv") {
+        anyhow::bail!(
+            "Zip archive {} holds '{}', not a CSV file",
+            path.display(),
+            entry.name()
+        );
+    }
+    if entry.encrypted() {
+        anyhow::bail!("Zip archive {} is encrypted", path.display());
+    }
+    let method = entry.compression();
+    if !matches!(
+        method,
+        CompressionMethod::Stored | CompressionMethod::Deflated
+    ) {
+        anyhow::bail!(
+            "Zip archive {} uses {} compression; only stored and deflated entries can be read",
+            path.display(),
+            method
+        );
+    }
+    Ok((entry.data_start(), entry.compressed_size(), method))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compression_from_path() {
+        let compression = |p: &str| CsvCompression::from_path(Path::new(p));
+        assert_eq!(compression("data.csv.gz"), Some(CsvCompression::Gzip));
+        assert_eq!(compression("DATA.CSV.GZ"), Some(CsvCompression::Gzip));
+        assert_eq!(compression("data.csv.zst"), Some(CsvCompression::Zstd));
+        assert_eq!(compression("export.zip"), Some(CsvCompression::Zip));
+        assert_eq!(compression("data.parquet.gz"), None);
+        assert_eq!(compression("data.csv"), None);
+    }
+
+    #[test]
+    fn test_dataset_stem() {
+        assert_eq!(dataset_stem(Path::new("/in/loans.csv.gz")), Some("loans"));
+        assert_eq!(dataset_stem(Path::new("loans.zip")), Some("loans"));
+        assert_eq!(dataset_stem(Path::new("loans.csv")), Some("loans"));
+    }
+}
@@ -0,0 +1,146 @@
+//! Binning-independent rank statistics: Somers' D and concordance
+//!
+//! Gini is computed on the WoE-binned feature, so a feature can score low
+//! because its bins are poor rather than because it carries little signal.
+//! Somers' D ranks events against non-events on the raw feature values
+//! instead. A low binned Gini next to a high |Somers' D| points at the binning.
+
+use anyhow::Result;
+use polars::prelude::*;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::iv::{binary_target_values, IvAnalysis};
+use super::target::TargetMapping;
+
+/// Rows used per feature; larger datasets are systematically sampled down to this
+pub const CONCORDANCE_SAMPLE_ROWS: usize = 100_000;
+
+/// Rank statistics of one numeric feature against a binary target
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConcordanceStats {
+    pub feature_name: String,
+    /// Weighted (concordant - discordant) / all event/non-event pairs, in
+    /// [-1, 1]. Positive when events tend to have higher values.
+    pub somers_d: f64,
+    /// Weighted share of event/non-event pairs where the event has the higher
+    /// value, ties counting half (the AUC of the raw feature)
+    pub concordance: f64,
+    /// Rows with a non-missing value and a valid target that entered the statistic
+    pub sample_rows: usize,
+}
+
+/// Somers' D and concordance of every numeric feature against a binary target.
+///
+/// Missing values and rows whose target maps to neither class are left out.
+/// Above [`CONCORDANCE_SAMPLE_ROWS`] rows every k-th row is used, so results
+/// are deterministic. Features without both classes among their rows are
+/// skipped. Results follow the column order of `df`.
+pub fn compute_concordance(
+    df: &DataFrame,
+    target: &str,
+    target_mapping: Option<&TargetMapping>,
+    weights: &[f64],
+    weight_column: Option<&str>,
+) -> Result<Vec<ConcordanceStats>> {
+    if df.height() == 0 {
+        return Ok(Vec::new());
+    }
+
+    let target_values = binary_target_values(df, target, target_mapping)?;
+    let step = df.height().div_ceil(CONCORDANCE_SAMPLE_ROWS);
+
+    let columns: Vec<&Column> = df
+        .get_columns()
+        .iter()
+        .filter(|col| {
+            col.dtype().is_primitive_numeric()
+                && col.name() != target
+                && Some(col.name().as_str()) != weight_column
+        })
+        .collect();
+
+    let results: Vec<Option<ConcordanceStats>> = columns
+        .par_iter()
+        .map(|col| -> Result<Option<ConcordanceStats>> {
+            let float_col = col.cast(&DataType::Float64)?;
+            let mut pairs: Vec<(f64, i32, f64)> = float_col
+                .f64()?
+                .iter()
+                .zip(&target_values)
+                .zip(weights)
+                .step_by(step)
+                .filter_map(|((v, t), &w)| Some((v?, (*t)?, w)))
+                .filter(|(v, _, _)| !v.is_nan())
+                .collect();
+            pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            Ok(
+                rank_statistics(&pairs).map(|(somers_d, concordance)| ConcordanceStats {
+                    feature_name: col.name().to_string(),
+                    somers_d,
+                    concordance,
+                    sample_rows: pairs.len(),
+                }),
+            )
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(results.into_iter().flatten().collect())
+}
+
+/// `(somers_d, concordance)` of value-sorted `(value, target, weight)` pairs,
+/// or `None` when either class has no weight
+fn rank_statistics(sorted_pairs: &[(f64, i32, f64)]) -> Option<(f64, f64)> {
+    let mut concordant = 0.0;
+    let mut tied = 0.0;
+    let mut non_events_below = 0.0;
+    let mut total_events = 0.0;
+
+    // Walk groups of equal values in ascending order: events in a group are
+    // concordant with every non-event below it and tied with those inside it
+    for group in sorted_pairs.chunk_by(|a, b| a.0 == b.0) {
+        let mut events = 0.0;
+        let mut non_events = 0.0;
+        for &(_, t, w) in group {
+            if t == 1 {
+                events += w;
+            } else {
+                non_events += w;
+            }
+        }
+        concordant += events * non_events_below;
+        tied += events * non_events;
+        non_events_below += non_events;
+        total_events += events;
+    }
+
+    let total_pairs = total_events * non_events_below;
+    if total_pairs <= 0.0 {
+        return None;
+    }
+    let discordant = total_pairs - concordant - tied;
+    Some((
+        (concordant - discordant) / total_pairs,
+        (concordant + 0.5 * tied) / total_pairs,
+    ))
+}
+
+/// Features whose binned Gini fell below `gini_threshold` while their raw
+/// |Somers' D| reaches it, i.e. candidates for rebinning rather than dropping
+pub fn binning_limited_features(
+    analyses: &[IvAnalysis],
+    stats: &[ConcordanceStats],
+    gini_threshold: f64,
+) -> Vec<String> {
+    analyses
+        .iter()
+        .filter(|a| a.gini < gini_threshold)
+        .filter(|a| {
+            stats
+                .iter()
+                .any(|s| s.feature_name == a.feature_name && s.somers_d.abs() >= gini_threshold)
+        })
+        .map(|a| a.feature_name.clone())
+        .collect()
+}
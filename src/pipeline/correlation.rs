@@ -4,14 +4,22 @@
 //! - **Pearson** (|r|) for numeric-numeric pairs
 //! - **Bias-corrected Cramér's V** for categorical-categorical pairs
 //! - **Eta** (correlation ratio) for categorical-numeric pairs
+//!
+//! Cramér's V can be given its own threshold (see [`AssociationThresholds`]),
+//! and categorical pairs above it also carry Theil's U in both directions.
+//!
+//! Correlated pairs are resolved into drops one pair at a time, or per cluster
+//! of connected features (see [`CorrelationStrategy`]). [`CorrKeepPolicy`]
+//! decides which member of a pair is kept.
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use faer::Mat;
 use indicatif::{ProgressBar, ProgressStyle};
 use polars::prelude::*;
 use rayon::prelude::*;
-use serde::Serialize;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use super::iv::FeatureType;
 use super::progress::{PipelineStage, ProgressEvent, ProgressSender};
@@ -22,7 +30,7 @@ use super::progress::{PipelineStage, ProgressEvent, ProgressSender};
 const MAX_CATEGORIES: usize = 100;
 
 /// The type of association measure used for a correlated pair.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AssociationMeasure {
     /// Absolute Pearson correlation coefficient (numeric-numeric)
     Pearson,
@@ -42,28 +50,224 @@ impl std::fmt::Display for AssociationMeasure {
     }
 }
 
+/// Thresholds above which a pair counts as highly associated.
+///
+/// A bare `f64` converts into thresholds that apply to every measure.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AssociationThresholds {
+    /// Applied to |Pearson r| (numeric-numeric) and Eta (categorical-numeric)
+    pub correlation: f64,
+    /// Applied to Cramér's V (categorical-categorical)
+    pub cramers_v: f64,
+}
+
+impl AssociationThresholds {
+    /// Thresholds with an optional Cramér's V override; `None` reuses `correlation`
+    pub fn new(correlation: f64, cramers_v: Option<f64>) -> Self {
+        Self {
+            correlation,
+            cramers_v: cramers_v.unwrap_or(correlation),
+        }
+    }
+}
+
+impl From<f64> for AssociationThresholds {
+    fn from(threshold: f64) -> Self {
+        Self::new(threshold, None)
+    }
+}
+
 /// Metadata about a feature used for IV-first drop tie-breaking.
 #[derive(Debug, Clone, Default)]
 pub struct FeatureMetadata {
     pub iv: Option<f64>,
     pub missing_ratio: Option<f64>,
+    /// R² against a continuous target; ranks features in place of IV
+    pub r_squared: Option<f64>,
 }
 
 /// A feature selected for dropping with its reason.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FeatureToDrop {
     pub feature: String,
     pub reason: String,
 }
 
 /// Represents a correlated pair of features
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CorrelatedPair {
     pub feature1: String,
     pub feature2: String,
     pub correlation: f64,
     /// The association measure used to compute `correlation`.
     pub measure: AssociationMeasure,
+    /// Theil's U as (U(feature1 | feature2), U(feature2 | feature1)); only
+    /// set for Cramér's V pairs
+    pub theils_u: Option<(f64, f64)>,
+    /// How the pair was resolved, set by [`resolve_correlated_pairs`]. `None`
+    /// until then, and for pairs skipped because a member was already dropped
+    /// or both members are kept.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rationale: Option<PairRationale>,
+}
+
+/// Rule of the drop waterfall that decided a correlated pair
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DropRule {
+    /// The other member is the target column
+    Target,
+    /// Kept member pinned by `--keep-columns` or a review swap
+    Override,
+    /// Higher IV kept
+    Iv,
+    /// Higher R² kept (continuous target)
+    RSquared,
+    /// Member in more correlated pairs dropped
+    Frequency,
+    /// Lower missing ratio kept
+    MissingRatio,
+    /// Member with the earlier `--corr-keep-prefixes` prefix kept
+    Prefix,
+    /// Alphabetically first member kept
+    Alphabetical,
+}
+
+impl std::fmt::Display for DropRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DropRule::Target => write!(f, "target"),
+            DropRule::Override => write!(f, "override"),
+            DropRule::Iv => write!(f, "iv"),
+            DropRule::RSquared => write!(f, "r_squared"),
+            DropRule::Frequency => write!(f, "frequency"),
+            DropRule::MissingRatio => write!(f, "missing_ratio"),
+            DropRule::Prefix => write!(f, "prefix"),
+            DropRule::Alphabetical => write!(f, "alphabetical"),
+        }
+    }
+}
+
+/// How correlated pairs are resolved into drops
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub enum CorrelationStrategy {
+    /// Resolve the pairs one at a time with the drop waterfall (default)
+    #[default]
+    Pairwise,
+    /// Group features linked by correlated pairs into clusters and keep one
+    /// representative of each: the protected members if any, otherwise the
+    /// member with the highest IV (R² for a continuous target)
+    Cluster,
+}
+
+impl std::fmt::Display for CorrelationStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CorrelationStrategy::Pairwise => write!(f, "pairwise"),
+            CorrelationStrategy::Cluster => write!(f, "cluster"),
+        }
+    }
+}
+
+impl std::str::FromStr for CorrelationStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pairwise" => Ok(CorrelationStrategy::Pairwise),
+            "cluster" => Ok(CorrelationStrategy::Cluster),
+            _ => Err(format!(
+                "Unknown correlation strategy: '{}'. Use 'pairwise' or 'cluster'.",
+                s
+            )),
+        }
+    }
+}
+
+/// Which member of a correlated pair is kept (`--corr-keep-policy`). The
+/// target and `--keep-columns` members are kept under every policy.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(tag = "policy", content = "prefixes", rename_all = "snake_case")]
+pub enum CorrKeepPolicy {
+    /// Higher IV (R² for a continuous target), then fewer correlated pairs,
+    /// lower missing ratio and alphabetical order (default)
+    #[default]
+    HigherIv,
+    /// Lower missing ratio, then the `HigherIv` order
+    LowerMissing,
+    /// Alphabetically first member
+    FirstAlphabetical,
+    /// The member whose name starts with the earliest of these prefixes;
+    /// members matching none rank last, and ties go by the `HigherIv` order
+    CustomList(Vec<String>),
+}
+
+impl CorrKeepPolicy {
+    /// Build a policy from its `--corr-keep-policy` name and the
+    /// `--corr-keep-prefixes` list, which only `custom_list` takes
+    pub fn parse<S: AsRef<str>>(name: &str, prefixes: &[S]) -> Result<Self> {
+        let prefixes: Vec<String> = prefixes
+            .iter()
+            .map(|p| p.as_ref().trim().to_string())
+            .filter(|p| !p.is_empty())
+            .collect();
+        let policy = match name.to_lowercase().as_str() {
+            "higher_iv" | "higher-iv" => CorrKeepPolicy::HigherIv,
+            "lower_missing" | "lower-missing" => CorrKeepPolicy::LowerMissing,
+            "first_alphabetical" | "first-alphabetical" => CorrKeepPolicy::FirstAlphabetical,
+            "custom_list" | "custom-list" => {
+                if prefixes.is_empty() {
+                    bail!("--corr-keep-policy custom_list needs --corr-keep-prefixes");
+                }
+                return Ok(CorrKeepPolicy::CustomList(prefixes));
+            }
+            _ => bail!(
+                "Unknown correlation keep policy: '{}'. Use 'higher_iv', 'lower_missing', \
+                 'first_alphabetical' or 'custom_list'.",
+                name
+            ),
+        };
+        if !prefixes.is_empty() {
+            bail!("--corr-keep-prefixes only applies to --corr-keep-policy custom_list");
+        }
+        Ok(policy)
+    }
+
+    /// Position of the first prefix `feature` starts with, and the prefix
+    fn prefix_of(&self, feature: &str) -> Option<(usize, &str)> {
+        match self {
+            CorrKeepPolicy::CustomList(prefixes) => prefixes
+                .iter()
+                .enumerate()
+                .find(|(_, p)| feature.starts_with(p.as_str()))
+                .map(|(i, p)| (i, p.as_str())),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for CorrKeepPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CorrKeepPolicy::HigherIv => write!(f, "higher_iv"),
+            CorrKeepPolicy::LowerMissing => write!(f, "lower_missing"),
+            CorrKeepPolicy::FirstAlphabetical => write!(f, "first_alphabetical"),
+            CorrKeepPolicy::CustomList(_) => write!(f, "custom_list"),
+        }
+    }
+}
+
+/// Decision recorded on a resolved [`CorrelatedPair`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PairRationale {
+    pub kept: String,
+    pub dropped: String,
+    pub rule: DropRule,
+    /// The deciding values of the kept and dropped member (IV, R², pair count
+    /// or missing ratio); absent for the target, override, prefix and
+    /// alphabetical rules
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub values: Option<(f64, f64)>,
 }
 
 /// Calculate correlations between numeric columns and find highly correlated pairs
@@ -120,9 +324,10 @@ fn find_correlated_pairs_impl(
             {
                 Ok(col) => Some((col_name.clone(), col)),
                 Err(e) => {
-                    eprintln!(
-                        "Warning: Excluding column '{}' from correlation analysis: {}",
-                        col_name, e
+                    tracing::warn!(
+                        "Excluding column '{}' from correlation analysis: {}",
+                        col_name,
+                        e
                     );
                     None
                 }
@@ -177,6 +382,8 @@ fn find_correlated_pairs_impl(
                         feature2: col2_name.clone(),
                         correlation: c,
                         measure: AssociationMeasure::Pearson,
+                        theils_u: None,
+                        rationale: None,
                     })
                 } else {
                     None
@@ -357,6 +564,66 @@ pub fn compute_cramers_v(col_a: &Column, col_b: &Column, weights: Option<&[f64]>
     Some((chi2_corr / denom).sqrt().clamp(0.0, 1.0))
 }
 
+/// Compute Theil's U (the uncertainty coefficient) U(x | y) for two
+/// categorical columns.
+///
+/// The share of the entropy of `x` explained by knowing `y`: 1 means `y`
+/// determines `x`, 0 means it says nothing about it.  Unlike Cramér's V it is
+/// asymmetric, so U(x | y) and U(y | x) can differ.
+///
+/// Returns `None` when the computation is undefined (no data); a single-category
+/// `x` has nothing to explain and returns 1.
+pub fn compute_theils_u(col_x: &Column, col_y: &Column, weights: Option<&[f64]>) -> Option<f64> {
+    let n_rows = col_x.len();
+    if n_rows == 0 || n_rows != col_y.len() {
+        return None;
+    }
+
+    let cx = col_x.str().ok()?;
+    let cy = col_y.str().ok()?;
+
+    let mut joint: HashMap<(&str, &str), f64> = HashMap::new();
+    let mut x_totals: HashMap<&str, f64> = HashMap::new();
+    let mut y_totals: HashMap<&str, f64> = HashMap::new();
+    let mut total = 0.0_f64;
+
+    for i in 0..n_rows {
+        if let (Some(x), Some(y)) = (cx.get(i), cy.get(i)) {
+            let w = weights.map_or(1.0, |ws| ws[i]);
+            if w <= 0.0 {
+                continue;
+            }
+            *joint.entry((x, y)).or_insert(0.0) += w;
+            *x_totals.entry(x).or_insert(0.0) += w;
+            *y_totals.entry(y).or_insert(0.0) += w;
+            total += w;
+        }
+    }
+
+    if total <= 0.0 {
+        return None;
+    }
+
+    let entropy_x: f64 = x_totals
+        .values()
+        .map(|&w| {
+            let p = w / total;
+            -p * p.ln()
+        })
+        .sum();
+    if entropy_x <= f64::EPSILON {
+        return Some(1.0);
+    }
+
+    // H(x | y) = -SUM p(x, y) * ln(p(x, y) / p(y))
+    let conditional: f64 = joint
+        .iter()
+        .map(|((_, y), &w)| -(w / total) * (w / y_totals[y]).ln())
+        .sum();
+
+    Some(((entropy_x - conditional) / entropy_x).clamp(0.0, 1.0))
+}
+
 /// Compute the correlation ratio η (eta) for a categorical-numeric pair.
 ///
 /// η measures how much of the numeric variance is explained by the categorical
@@ -464,15 +731,24 @@ fn classify_columns(
     (numeric, categorical)
 }
 
-/// Compute correlation matrix using matrix operations (much faster for many columns).
+/// Columns per block of the blocked correlation matrix.
+///
+/// The matrix path never holds the full `n x n` correlation matrix; it works
+/// through it one `CORRELATION_BLOCK_COLUMNS x CORRELATION_BLOCK_COLUMNS`
+/// block at a time (2 MB each), so 10,000 columns need a few blocks per thread
+/// rather than 800 MB.
+const CORRELATION_BLOCK_COLUMNS: usize = 512;
+
+/// Build the standardized data matrix Z, whose Gram matrix Z^T * Z is the
+/// weighted correlation matrix.
 ///
 /// Algorithm:
-/// 1. Build data matrix X (n_rows x n_cols) from numeric columns
-/// 2. Compute weighted means and standardize: Z = (X - mean) / std
-/// 3. Compute correlation matrix: R = Z^T * diag(W) * Z / sum(W)
+/// 1. Compute each column's weighted mean and standard deviation
+/// 2. Standardize: Z = sqrt(W / sum(W)) * (X - mean) / std
 ///
-/// Returns the correlation matrix and column names.
-fn compute_correlation_matrix_fast(
+/// Constant and all-null columns are left out. Returns Z and the names of its
+/// columns.
+pub(crate) fn standardize_columns(
     float_columns: &[(String, Column)],
     weights: &[f64],
 ) -> Result<(Mat<f64>, Vec<String>)> {
@@ -484,9 +760,6 @@ fn compute_correlation_matrix_fast(
         );
     }
 
-    // Extract column names
-    let col_names: Vec<String> = float_columns.iter().map(|(name, _)| name.clone()).collect();
-
     // Get row count from first column
     let n_rows = float_columns[0].1.len();
     if n_rows == 0 {
@@ -506,24 +779,21 @@ fn compute_correlation_matrix_fast(
         anyhow::bail!("Cannot compute correlation matrix: total weight is zero or negative");
     }
 
-    // Build data matrix and compute weighted statistics in parallel.
-    // For each column: extract values, compute weighted mean/std, standardize.
+    // Weighted mean and std of every column, in parallel.
     //
-    // IMPORTANT: Uses `sum_w` (total weight across ALL rows) as the common
+    // IMPORTANT: Z uses `sum_w` (total weight across ALL rows) as the common
     // normalizer for every column.  This matches the pairwise Welford path's
     // behaviour when there are no nulls (the caller ensures no nulls reach
     // the matrix path — see `has_any_nulls` guard in the auto-selection
     // logic).  Null entries are set to 0.0 in the standardized vector,
     // contributing nothing to the dot-product correlation.
-    let standardized_cols: Vec<Option<Vec<f64>>> = float_columns
+    let moments: Vec<Option<(f64, f64)>> = float_columns
         .par_iter()
         .map(|(_, col)| {
             let ca = col.f64().ok()?;
 
-            // Compute weighted mean using total weight sum
             let mut sum_wx = 0.0;
             let mut sum_w_valid = 0.0;
-
             for (val, &w) in ca.iter().zip(weights.iter()) {
                 if let Some(x) = val {
                     if w > 0.0 {
@@ -532,14 +802,11 @@ fn compute_correlation_matrix_fast(
                     }
                 }
             }
-
             if sum_w_valid <= 0.0 {
                 return None;
             }
-
             let mean = sum_wx / sum_w_valid;
 
-            // Compute weighted variance
             let mut sum_w_sq_dev = 0.0;
             for (val, &w) in ca.iter().zip(weights.iter()) {
                 if let Some(x) = val {
@@ -549,45 +816,28 @@ fn compute_correlation_matrix_fast(
                     }
                 }
             }
-
             let std = (sum_w_sq_dev / sum_w_valid).sqrt();
             if std.abs() < f64::EPSILON {
                 return None; // Constant column - skip
             }
-
-            // Standardize: z_i = sqrt(w_i / sum_w) * (x_i - mean) / std
-            // Using sum_w (not per-column sum_w_valid) so R = Z^T * Z
-            // produces the correct weighted Pearson correlation.
-            let standardized: Vec<f64> = ca
-                .iter()
-                .zip(weights.iter())
-                .map(|(val, &w)| {
-                    if let Some(x) = val {
-                        if w > 0.0 {
-                            (w.sqrt() / sum_w.sqrt()) * (x - mean) / std
-                        } else {
-                            0.0
-                        }
-                    } else {
-                        0.0
-                    }
-                })
-                .collect();
-
-            Some(standardized)
+            Some((mean, std))
         })
         .collect();
 
     // Filter out columns that failed (constant or all null)
-    let valid_cols: Vec<(usize, Vec<f64>)> = standardized_cols
-        .into_iter()
-        .enumerate()
-        .filter_map(|(i, opt)| opt.map(|v| (i, v)))
+    let valid_cols: Vec<(&Float64Chunked, f64, f64)> = float_columns
+        .iter()
+        .zip(&moments)
+        .filter_map(|((_, col), m)| {
+            let (mean, std) = (*m)?;
+            Some((col.f64().ok()?, mean, std))
+        })
         .collect();
-
-    let valid_col_names: Vec<String> = valid_cols
+    let valid_col_names: Vec<String> = float_columns
         .iter()
-        .map(|(i, _)| col_names[*i].clone())
+        .zip(&moments)
+        .filter(|(_, m)| m.is_some())
+        .map(|((name, _), _)| name.clone())
         .collect();
     let n_valid_cols = valid_cols.len();
 
@@ -598,61 +848,100 @@ fn compute_correlation_matrix_fast(
         );
     }
 
-    // Build the standardized data matrix Z (n_rows x n_valid_cols)
+    // Fill Z (n_rows x n_valid_cols) in place, one column per task, so the
+    // standardized values are never held twice.
+    // z_i = sqrt(w_i / sum_w) * (x_i - mean) / std; using sum_w (not the
+    // per-column sum_w_valid) so Z^T * Z is the weighted Pearson correlation.
+    let scale = 1.0 / sum_w.sqrt();
     let mut z = Mat::<f64>::zeros(n_rows, n_valid_cols);
-    for (col_idx, (_, col_data)) in valid_cols.iter().enumerate() {
-        for (row_idx, &val) in col_data.iter().enumerate() {
-            z[(row_idx, col_idx)] = val;
-        }
-    }
-
-    // Compute correlation matrix: R = Z^T * Z
-    // Since we pre-applied sqrt(w)/sqrt(sum_w) to Z, this gives us weighted correlation
-    let corr_matrix = z.transpose() * &z;
+    z.par_col_chunks_mut(1)
+        .zip(valid_cols.par_iter())
+        .for_each(|(mut z_col, (ca, mean, std))| {
+            for (row_idx, (val, &w)) in ca.iter().zip(weights.iter()).enumerate() {
+                if let Some(x) = val {
+                    if w > 0.0 {
+                        z_col[(row_idx, 0)] = w.sqrt() * scale * (x - mean) / std;
+                    }
+                }
+            }
+        });
 
-    Ok((corr_matrix, valid_col_names))
+    Ok((z, valid_col_names))
 }
 
-/// Extract correlated pairs from correlation matrix
-fn extract_correlated_pairs_from_matrix(
-    corr_matrix: &Mat<f64>,
+/// Extract the pairs above `threshold` from the correlation matrix Z^T * Z,
+/// computing it in column blocks of `block_size`.
+///
+/// Each pair of blocks is multiplied out, scanned for pairs above the
+/// threshold and discarded straight away, so at most one block per worker
+/// thread is alive at any time. `on_block` is called with the number of
+/// column pairs each finished block covered.
+///
+/// Pairs are sorted by absolute correlation descending.
+fn blocked_correlated_pairs(
+    z: &Mat<f64>,
     col_names: &[String],
     threshold: f64,
+    block_size: usize,
+    on_block: impl Fn(u64) + Sync,
 ) -> Vec<CorrelatedPair> {
-    let n = corr_matrix.nrows();
-    let mut pairs = Vec::new();
-
-    // Extract upper triangle
-    for i in 0..n {
-        for j in (i + 1)..n {
-            let corr = corr_matrix[(i, j)];
-            if corr.abs() > threshold && !corr.is_nan() {
-                pairs.push(CorrelatedPair {
-                    feature1: col_names[i].clone(),
-                    feature2: col_names[j].clone(),
-                    correlation: corr,
-                    measure: AssociationMeasure::Pearson,
-                });
+    let n = z.ncols();
+    let block_starts: Vec<usize> = (0..n).step_by(block_size.max(1)).collect();
+    let block_pairs: Vec<(usize, usize)> = (0..block_starts.len())
+        .flat_map(|a| (a..block_starts.len()).map(move |b| (a, b)))
+        .collect();
+
+    let mut found: Vec<(usize, usize, f64)> = block_pairs
+        .par_iter()
+        .flat_map_iter(|&(a, b)| {
+            let (i0, j0) = (block_starts[a], block_starts[b]);
+            let (wi, wj) = (block_size.min(n - i0), block_size.min(n - j0));
+            let block = z.subcols(i0, wi).transpose() * z.subcols(j0, wj);
+
+            let mut hits = Vec::new();
+            for bi in 0..wi {
+                // A diagonal block holds every pair twice; keep its upper triangle
+                let first = if a == b { bi + 1 } else { 0 };
+                for bj in first..wj {
+                    let corr = block[(bi, bj)];
+                    if corr.abs() > threshold && !corr.is_nan() {
+                        hits.push((i0 + bi, j0 + bj, corr));
+                    }
+                }
             }
-        }
-    }
+            let covered = if a == b { wi * (wi - 1) / 2 } else { wi * wj };
+            on_block(covered as u64);
+            hits
+        })
+        .collect();
 
-    // Sort by absolute correlation descending
-    pairs.sort_by(|a, b| {
-        b.correlation
-            .abs()
-            .partial_cmp(&a.correlation.abs())
+    // Sort by absolute correlation descending; ties keep column order
+    found.sort_by(|x, y| {
+        y.2.abs()
+            .partial_cmp(&x.2.abs())
             .unwrap_or(std::cmp::Ordering::Equal)
+            .then((x.0, x.1).cmp(&(y.0, y.1)))
     });
 
-    pairs
+    found
+        .into_iter()
+        .map(|(i, j, corr)| CorrelatedPair {
+            feature1: col_names[i].clone(),
+            feature2: col_names[j].clone(),
+            correlation: corr,
+            measure: AssociationMeasure::Pearson,
+            theils_u: None,
+            rationale: None,
+        })
+        .collect()
 }
 
 /// Find correlated pairs using matrix-based computation (optimized for many columns).
 ///
 /// This is significantly faster than pairwise computation when there are many columns
 /// because matrix multiplication is highly optimized (O(n²) operations in optimized BLAS
-/// vs O(n² * m) for pairwise where m is row count).
+/// vs O(n² * m) for pairwise where m is row count). The matrix is computed in column
+/// blocks, so memory stays bounded for very wide data.
 #[allow(dead_code)]
 pub fn find_correlated_pairs_matrix(
     df: &DataFrame,
@@ -660,7 +949,14 @@ pub fn find_correlated_pairs_matrix(
     weights: &[f64],
     weight_column: Option<&str>,
 ) -> Result<Vec<CorrelatedPair>> {
-    find_correlated_pairs_matrix_impl(df, threshold, weights, weight_column, false)
+    find_correlated_pairs_matrix_impl(
+        df,
+        threshold,
+        weights,
+        weight_column,
+        None,
+        CORRELATION_BLOCK_COLUMNS,
+    )
 }
 
 fn find_correlated_pairs_matrix_impl(
@@ -668,7 +964,8 @@ fn find_correlated_pairs_matrix_impl(
     threshold: f64,
     weights: &[f64],
     weight_column: Option<&str>,
-    silent: bool,
+    progress_tx: Option<&ProgressSender>,
+    block_size: usize,
 ) -> Result<Vec<CorrelatedPair>> {
     if df.height() == 0 {
         return Ok(Vec::new());
@@ -698,9 +995,10 @@ fn find_correlated_pairs_matrix_impl(
             {
                 Ok(col) => Some((col_name.clone(), col)),
                 Err(e) => {
-                    eprintln!(
-                        "Warning: Excluding column '{}' from correlation analysis: {}",
-                        col_name, e
+                    tracing::warn!(
+                        "Excluding column '{}' from correlation analysis: {}",
+                        col_name,
+                        e
                     );
                     None
                 }
@@ -708,33 +1006,48 @@ fn find_correlated_pairs_matrix_impl(
         })
         .collect();
 
-    // In TUI mode (silent), use a hidden progress bar so indicatif doesn't
-    // write to stdout — ratatui owns the alternate screen.
-    let pb = if silent {
+    let (z, col_names) = standardize_columns(&float_columns, weights)?;
+    // The Float64 copies are no longer needed once Z is built
+    drop(float_columns);
+
+    let n_cols = col_names.len();
+    let total_pairs = (n_cols * (n_cols - 1) / 2) as u64;
+
+    // In TUI mode, use a hidden progress bar so indicatif doesn't write to
+    // stdout — ratatui owns the alternate screen.
+    let pb = if progress_tx.is_some() {
         ProgressBar::hidden()
     } else {
-        let pb = ProgressBar::new_spinner();
+        let pb = ProgressBar::new(total_pairs);
         pb.set_style(
-            ProgressStyle::default_spinner()
-                .template("   {spinner:.cyan} Computing correlation matrix ({msg})")
-                .unwrap(),
+            ProgressStyle::default_bar()
+                .template(
+                    "   Correlation matrix [{bar:40.cyan/blue}] {pos}/{len} pairs ({percent}%) [{eta}]",
+                )
+                .unwrap()
+                .progress_chars("=>-"),
         );
         pb.enable_steady_tick(std::time::Duration::from_millis(100));
         pb
     };
-    pb.set_message(format!("{} columns", float_columns.len()));
 
-    // Compute correlation matrix
-    let (corr_matrix, col_names) = compute_correlation_matrix_fast(&float_columns, weights)?;
-
-    pb.set_message("extracting pairs");
-
-    // Extract pairs above threshold
-    let pairs = extract_correlated_pairs_from_matrix(&corr_matrix, &col_names, threshold);
+    let processed = AtomicU64::new(0);
+    let pairs = blocked_correlated_pairs(&z, &col_names, threshold, block_size, |covered| {
+        pb.inc(covered);
+        if let Some(tx) = progress_tx {
+            let done = processed.fetch_add(covered, Ordering::Relaxed) + covered;
+            tx.send(ProgressEvent::update(
+                PipelineStage::CorrelationAnalysis,
+                "Correlation analysis",
+                format!("{}/{} numeric pairs", done, total_pairs),
+            ))
+            .ok();
+        }
+    });
 
     pb.finish_with_message(format!(
-        "analyzed {} columns, found {} correlated pairs",
-        col_names.len(),
+        "   [OK] Analyzed {} column pairs, found {} correlated",
+        total_pairs,
         pairs.len()
     ));
 
@@ -752,21 +1065,29 @@ const MATRIX_METHOD_COLUMN_THRESHOLD: usize = 15;
 /// - Pairwise method: Used when numeric columns < 15 (lower overhead for few columns)
 ///
 /// Also computes cat-cat (Cramér's V) and cat-num (Eta) pairs when categorical
-/// columns are present.
+/// columns are present.  Cat-cat pairs are compared against
+/// `thresholds.cramers_v`, everything else against `thresholds.correlation`.
 pub fn find_correlated_pairs_auto(
     df: &DataFrame,
-    threshold: f64,
+    thresholds: impl Into<AssociationThresholds>,
     weights: &[f64],
     weight_column: Option<&str>,
     feature_types: Option<&HashMap<String, FeatureType>>,
 ) -> Result<Vec<CorrelatedPair>> {
-    find_correlated_pairs_auto_impl(df, threshold, weights, weight_column, feature_types, None)
+    find_correlated_pairs_auto_impl(
+        df,
+        thresholds.into(),
+        weights,
+        weight_column,
+        feature_types,
+        None,
+    )
 }
 
 /// Same as `find_correlated_pairs_auto` but sends progress events to the TUI overlay.
 pub fn find_correlated_pairs_auto_with_progress(
     df: &DataFrame,
-    threshold: f64,
+    thresholds: impl Into<AssociationThresholds>,
     weights: &[f64],
     weight_column: Option<&str>,
     feature_types: Option<&HashMap<String, FeatureType>>,
@@ -774,7 +1095,7 @@ pub fn find_correlated_pairs_auto_with_progress(
 ) -> Result<Vec<CorrelatedPair>> {
     find_correlated_pairs_auto_impl(
         df,
-        threshold,
+        thresholds.into(),
         weights,
         weight_column,
         feature_types,
@@ -784,7 +1105,7 @@ pub fn find_correlated_pairs_auto_with_progress(
 
 fn find_correlated_pairs_auto_impl(
     df: &DataFrame,
-    threshold: f64,
+    thresholds: AssociationThresholds,
     weights: &[f64],
     weight_column: Option<&str>,
     feature_types: Option<&HashMap<String, FeatureType>>,
@@ -815,8 +1136,8 @@ fn find_correlated_pairs_auto_impl(
                 seen.insert(val);
                 if seen.len() > MAX_CATEGORIES {
                     if !silent {
-                        eprintln!(
-                            "Warning: Skipping categorical column '{}' from association analysis (>{} unique categories)",
+                        tracing::warn!(
+                            "Skipping categorical column '{}' from association analysis (>{} unique categories)",
                             name, MAX_CATEGORIES
                         );
                     }
@@ -861,17 +1182,22 @@ fn find_correlated_pairs_auto_impl(
     // substitutes 0 for nulls, which is only equivalent to the pairwise
     // Welford path (pairwise deletion) when there are NO null values.
     // Fall back to pairwise when any numeric column contains nulls.
-    let has_any_nulls = numeric_cols.iter().any(|name| {
-        df.column(name)
-            .map(|c| c.null_count() > 0)
-            .unwrap_or(false)
-    });
+    let has_any_nulls = numeric_cols
+        .iter()
+        .any(|name| df.column(name).map(|c| c.null_count() > 0).unwrap_or(false));
 
     let mut all_pairs = if num_count >= 2 {
         if num_count >= MATRIX_METHOD_COLUMN_THRESHOLD && !has_any_nulls {
-            find_correlated_pairs_matrix_impl(df, threshold, weights, weight_column, silent)?
+            find_correlated_pairs_matrix_impl(
+                df,
+                thresholds.correlation,
+                weights,
+                weight_column,
+                progress_tx,
+                CORRELATION_BLOCK_COLUMNS,
+            )?
         } else {
-            find_correlated_pairs_impl(df, threshold, weights, weight_column, silent)?
+            find_correlated_pairs_impl(df, thresholds.correlation, weights, weight_column, silent)?
         }
     } else {
         Vec::new()
@@ -896,12 +1222,17 @@ fn find_correlated_pairs_auto_impl(
                     let (_, col_a) = &cat_str_columns[*i];
                     let (_, col_b) = &cat_str_columns[*j];
                     let v = compute_cramers_v(col_a, col_b, weights_opt)?;
-                    if v > threshold && !v.is_nan() {
+                    if v > thresholds.cramers_v && !v.is_nan() {
+                        // Only pairs that pass pay for the extra entropy pass
+                        let theils_u = compute_theils_u(col_a, col_b, weights_opt)
+                            .zip(compute_theils_u(col_b, col_a, weights_opt));
                         Some(CorrelatedPair {
                             feature1: cat_str_columns[*i].0.clone(),
                             feature2: cat_str_columns[*j].0.clone(),
                             correlation: v,
                             measure: AssociationMeasure::CramersV,
+                            theils_u,
+                            rationale: None,
                         })
                     } else {
                         None
@@ -924,12 +1255,14 @@ fn find_correlated_pairs_auto_impl(
                     let (_, cat_col) = &cat_str_columns[*ci];
                     let (_, num_col) = &num_f64_columns[*ni];
                     let eta = compute_eta(cat_col, num_col, weights_opt)?;
-                    if eta > threshold && !eta.is_nan() {
+                    if eta > thresholds.correlation && !eta.is_nan() {
                         Some(CorrelatedPair {
                             feature1: cat_str_columns[*ci].0.clone(),
                             feature2: num_f64_columns[*ni].0.clone(),
                             correlation: eta,
                             measure: AssociationMeasure::Eta,
+                            theils_u: None,
+                            rationale: None,
                         })
                     } else {
                         None
@@ -953,19 +1286,112 @@ fn find_correlated_pairs_auto_impl(
     Ok(all_pairs)
 }
 
+/// Remove the pairs that involve `target`.
+///
+/// With a continuous target, a feature's correlation with the target is the
+/// signal the R² stage screens for, not redundancy, so those pairs must not
+/// trigger a drop.
+pub fn exclude_target_pairs(pairs: Vec<CorrelatedPair>, target: &str) -> Vec<CorrelatedPair> {
+    pairs
+        .into_iter()
+        .filter(|p| p.feature1 != target && p.feature2 != target)
+        .collect()
+}
+
 /// Determine which features to drop from correlated pairs.
 ///
 /// Decision priority (IV-first / modeler_challenger pattern):
 /// 1. **Target protection** — never drop the target column
 /// 2. **Lower IV** — when metadata available and both features have IV, drop lower IV
+///    (lower R² for a continuous target)
 /// 3. **Higher frequency** — drop feature appearing in more correlated pairs
 /// 4. **Higher missing ratio** — when both have missing ratio, drop higher missingness
 /// 5. **Alphabetical** — deterministic fallback
+#[allow(dead_code)]
 pub fn select_features_to_drop(
     pairs: &[CorrelatedPair],
     target_column: &str,
     metadata: Option<&HashMap<String, FeatureMetadata>>,
 ) -> Vec<FeatureToDrop> {
+    select_features_to_drop_with_keep(pairs, target_column, metadata, &HashSet::new())
+}
+
+/// Like [`select_features_to_drop`], but features in `keep` are never dropped.
+///
+/// A pair with one kept member drops the other member; a pair where both
+/// members are kept is left unresolved and both stay. The usual waterfall
+/// decides every other pair.
+#[allow(dead_code)]
+pub fn select_features_to_drop_with_keep(
+    pairs: &[CorrelatedPair],
+    target_column: &str,
+    metadata: Option<&HashMap<String, FeatureMetadata>>,
+    keep: &HashSet<String>,
+) -> Vec<FeatureToDrop> {
+    resolve_pairs(
+        pairs,
+        target_column,
+        metadata,
+        keep,
+        &CorrKeepPolicy::default(),
+    )
+    .into_iter()
+    .map(|(_, rationale, reason)| FeatureToDrop {
+        feature: rationale.dropped,
+        reason,
+    })
+    .collect()
+}
+
+/// Resolve `pairs` into drops with `strategy`, keeping members by `policy`,
+/// and record how each pair was resolved in its [`CorrelatedPair::rationale`].
+///
+/// [`CorrelationStrategy::Pairwise`] with the default policy gives the drops
+/// of [`select_features_to_drop_with_keep`]. With
+/// [`CorrelationStrategy::Cluster`], a drop's rationale sits on the pair that
+/// links it to the rest of its cluster, and `kept` names the representative.
+/// The drops are in pair order either way.
+///
+/// Skipped pairs get `None`, so pairs can be resolved again (e.g. after a
+/// review swap) without stale rationales.
+pub fn resolve_correlated_pairs(
+    pairs: &mut [CorrelatedPair],
+    target_column: &str,
+    metadata: Option<&HashMap<String, FeatureMetadata>>,
+    keep: &HashSet<String>,
+    strategy: CorrelationStrategy,
+    policy: &CorrKeepPolicy,
+) -> Vec<FeatureToDrop> {
+    let resolved = match strategy {
+        CorrelationStrategy::Pairwise => {
+            resolve_pairs(pairs, target_column, metadata, keep, policy)
+        }
+        CorrelationStrategy::Cluster => {
+            resolve_clusters(pairs, target_column, metadata, keep, policy)
+        }
+    };
+    for pair in pairs.iter_mut() {
+        pair.rationale = None;
+    }
+    resolved
+        .into_iter()
+        .map(|(idx, rationale, reason)| {
+            let feature = rationale.dropped.clone();
+            pairs[idx].rationale = Some(rationale);
+            FeatureToDrop { feature, reason }
+        })
+        .collect()
+}
+
+/// Resolve `pairs` in order. Returns the index of every pair that decided a
+/// drop, with its rationale and the drop reason.
+fn resolve_pairs(
+    pairs: &[CorrelatedPair],
+    target_column: &str,
+    metadata: Option<&HashMap<String, FeatureMetadata>>,
+    keep: &HashSet<String>,
+    policy: &CorrKeepPolicy,
+) -> Vec<(usize, PairRationale, String)> {
     // Use &str borrows from CorrelatedPair fields to avoid per-pair String clones
     let mut frequency: HashMap<&str, usize> = HashMap::new();
 
@@ -974,42 +1400,372 @@ pub fn select_features_to_drop(
         *frequency.entry(&pair.feature2).or_insert(0) += 1;
     }
 
-    let mut to_drop = Vec::new();
-    let mut already_resolved: std::collections::HashSet<&str> = std::collections::HashSet::new();
+    let mut resolved = Vec::new();
+    let mut already_resolved: HashSet<&str> = HashSet::new();
 
-    for pair in pairs {
+    for (idx, pair) in pairs.iter().enumerate() {
         if already_resolved.contains(pair.feature1.as_str())
             || already_resolved.contains(pair.feature2.as_str())
         {
             continue;
         }
 
-        let (dropped, reason) = decide_feature_to_drop(
-            &pair.feature1,
-            &pair.feature2,
-            target_column,
-            &frequency,
-            metadata,
-            pair,
-        );
+        let (rationale, reason) =
+            match (keep.contains(&pair.feature1), keep.contains(&pair.feature2)) {
+                (true, true) => continue,
+                (true, false) => kept_by_override(pair, true),
+                (false, true) => kept_by_override(pair, false),
+                (false, false) => decide_feature_to_drop(
+                    &pair.feature1,
+                    &pair.feature2,
+                    target_column,
+                    &frequency,
+                    metadata,
+                    pair,
+                    policy,
+                ),
+            };
 
-        already_resolved.insert(if dropped == pair.feature1 {
+        already_resolved.insert(if rationale.dropped == pair.feature1 {
             &pair.feature1
         } else {
             &pair.feature2
         });
-        to_drop.push(FeatureToDrop {
-            feature: dropped,
-            reason,
+        resolved.push((idx, rationale, reason));
+    }
+
+    resolved
+}
+
+/// Resolve `pairs` per connected cluster of features.
+///
+/// Every feature of a cluster except its representatives is dropped, including
+/// members that are only correlated with the representative through other
+/// members. Each drop is attached to the pair through which a breadth-first
+/// walk from the representatives first reaches it.
+fn resolve_clusters(
+    pairs: &[CorrelatedPair],
+    target_column: &str,
+    metadata: Option<&HashMap<String, FeatureMetadata>>,
+    keep: &HashSet<String>,
+    policy: &CorrKeepPolicy,
+) -> Vec<(usize, PairRationale, String)> {
+    // Ordered so clusters and walks are visited the same way on every run
+    let mut adjacency: BTreeMap<&str, Vec<(usize, &str)>> = BTreeMap::new();
+    for (idx, pair) in pairs.iter().enumerate() {
+        adjacency
+            .entry(&pair.feature1)
+            .or_default()
+            .push((idx, &pair.feature2));
+        adjacency
+            .entry(&pair.feature2)
+            .or_default()
+            .push((idx, &pair.feature1));
+    }
+
+    let mut resolved = Vec::new();
+    let mut visited: HashSet<&str> = HashSet::new();
+    for &start in adjacency.keys() {
+        if !visited.insert(start) {
+            continue;
+        }
+        let mut members = vec![start];
+        let mut i = 0;
+        while i < members.len() {
+            for &(_, next) in &adjacency[members[i]] {
+                if visited.insert(next) {
+                    members.push(next);
+                }
+            }
+            i += 1;
+        }
+
+        let protected: Vec<&str> = members
+            .iter()
+            .copied()
+            .filter(|m| *m == target_column || keep.contains(*m))
+            .collect();
+        let representatives = if protected.is_empty() {
+            members
+                .iter()
+                .copied()
+                .min_by(|a, b| cluster_rank(a, b, metadata, policy))
+                .into_iter()
+                .collect()
+        } else {
+            protected
+        };
+
+        let mut reached: HashSet<&str> = representatives.iter().copied().collect();
+        let mut queue: VecDeque<(&str, &str)> = representatives.iter().map(|r| (*r, *r)).collect();
+        while let Some((member, representative)) = queue.pop_front() {
+            for &(idx, next) in &adjacency[member] {
+                if reached.insert(next) {
+                    let (rationale, reason) = cluster_drop(
+                        representative,
+                        next,
+                        members.len(),
+                        &pairs[idx],
+                        target_column,
+                        keep,
+                        metadata,
+                        policy,
+                    );
+                    resolved.push((idx, rationale, reason));
+                    queue.push_back((next, representative));
+                }
+            }
+        }
+    }
+
+    resolved.sort_by_key(|(idx, _, _)| *idx);
+    resolved
+}
+
+/// IV (or R²) of `feature`, whichever the metadata has
+fn cluster_score(
+    feature: &str,
+    metadata: Option<&HashMap<String, FeatureMetadata>>,
+) -> Option<f64> {
+    let meta = metadata?.get(feature)?;
+    meta.iv.or(meta.r_squared)
+}
+
+/// Order in which cluster members are preferred as representative. By default
+/// highest score, then lowest missing ratio, then alphabetical; `policy`
+/// reorders the rules as for pairs. Unknown values rank last.
+fn cluster_rank(
+    a: &str,
+    b: &str,
+    metadata: Option<&HashMap<String, FeatureMetadata>>,
+    policy: &CorrKeepPolicy,
+) -> std::cmp::Ordering {
+    let missing = |f: &str| {
+        metadata
+            .and_then(|m| m.get(f))
+            .and_then(|m| m.missing_ratio)
+    };
+    let score_order = match (cluster_score(a, metadata), cluster_score(b, metadata)) {
+        (Some(sa), Some(sb)) => sb.total_cmp(&sa),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    };
+    let missing_order = match (missing(a), missing(b)) {
+        (Some(ma), Some(mb)) => ma.total_cmp(&mb),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    };
+    keep_order(policy)
+        .iter()
+        .map(|rule| match rule {
+            DropRule::Prefix => prefix_rank(a, policy).cmp(&prefix_rank(b, policy)),
+            DropRule::Iv => score_order,
+            DropRule::MissingRatio => missing_order,
+            _ => std::cmp::Ordering::Equal,
+        })
+        .fold(std::cmp::Ordering::Equal, std::cmp::Ordering::then)
+        .then(a.cmp(b))
+}
+
+/// Rationale and reason for dropping `dropped` in favour of its cluster's
+/// `representative`; `pair` links `dropped` to the cluster
+#[allow(clippy::too_many_arguments)]
+fn cluster_drop(
+    representative: &str,
+    dropped: &str,
+    cluster_size: usize,
+    pair: &CorrelatedPair,
+    target_column: &str,
+    keep: &HashSet<String>,
+    metadata: Option<&HashMap<String, FeatureMetadata>>,
+    policy: &CorrKeepPolicy,
+) -> (PairRationale, String) {
+    let via = if pair.feature1 == dropped {
+        &pair.feature2
+    } else {
+        &pair.feature1
+    };
+    let context = format!(
+        "In correlation cluster of {} represented by {} ({} {:.4} with {})",
+        cluster_size, representative, pair.measure, pair.correlation, via
+    );
+
+    let meta = |f: &str| metadata.and_then(|m| m.get(f));
+    let differing = |kept: Option<f64>, dropped: Option<f64>| {
+        kept.zip(dropped)
+            .filter(|(k, d)| (k - d).abs() > f64::EPSILON)
+    };
+    let score_rule = if meta(representative).and_then(|m| m.iv).is_some() {
+        (DropRule::Iv, "IV")
+    } else {
+        (DropRule::RSquared, "R²")
+    };
+    let decided = if representative == target_column {
+        Some((
+            DropRule::Target,
+            None,
+            "dropped to protect target".to_string(),
+        ))
+    } else if keep.contains(representative) {
+        Some((
+            DropRule::Override,
+            None,
+            format!("{} kept by override", representative),
+        ))
+    } else {
+        // The first rule of the policy that separates the two explains the drop
+        keep_order(policy).iter().find_map(|rule| match rule {
+            DropRule::Prefix => {
+                (prefix_rank(representative, policy) != prefix_rank(dropped, policy)).then(|| {
+                    (
+                        DropRule::Prefix,
+                        None,
+                        prefix_detail(representative, dropped, policy),
+                    )
+                })
+            }
+            DropRule::Iv => differing(
+                cluster_score(representative, metadata),
+                cluster_score(dropped, metadata),
+            )
+            .map(|(kept_val, dropped_val)| {
+                (
+                    score_rule.0,
+                    Some((kept_val, dropped_val)),
+                    format!(
+                        "lower {} ({:.4} vs {:.4})",
+                        score_rule.1, dropped_val, kept_val
+                    ),
+                )
+            }),
+            DropRule::MissingRatio => differing(
+                meta(representative).and_then(|m| m.missing_ratio),
+                meta(dropped).and_then(|m| m.missing_ratio),
+            )
+            .map(|(kept_mr, dropped_mr)| {
+                (
+                    DropRule::MissingRatio,
+                    Some((kept_mr, dropped_mr)),
+                    format!("higher missing ratio ({:.4} vs {:.4})", dropped_mr, kept_mr),
+                )
+            }),
+            _ => None,
+        })
+    };
+    let (rule, values, detail) = decided.unwrap_or_else(|| {
+        (
+            DropRule::Alphabetical,
+            None,
+            "alphabetical tie-break".to_string(),
+        )
+    });
+
+    (
+        PairRationale {
+            kept: representative.to_string(),
+            dropped: dropped.to_string(),
+            rule,
+            values,
+        },
+        format!("{}; {}", context, detail),
+    )
+}
+
+fn kept_by_override(pair: &CorrelatedPair, first_kept: bool) -> (PairRationale, String) {
+    let (kept, dropped) = if first_kept {
+        (&pair.feature1, &pair.feature2)
+    } else {
+        (&pair.feature2, &pair.feature1)
+    };
+    (
+        PairRationale {
+            kept: kept.clone(),
+            dropped: dropped.clone(),
+            rule: DropRule::Override,
+            values: None,
+        },
+        format!(
+            "Correlated with {} ({} {:.4}); {} kept by override",
+            kept, pair.measure, pair.correlation, kept
+        ),
+    )
+}
+
+/// A correlated pair resolved by [`select_features_to_drop`], with its outcome.
+#[derive(Debug, Clone)]
+pub struct PairDecision {
+    pub pair: CorrelatedPair,
+    /// Member that was dropped
+    pub dropped: String,
+    /// Member that was kept
+    pub kept: String,
+}
+
+/// Match each drop to the pair that decided it.
+///
+/// `drops` must come from [`select_features_to_drop`] (or the `_with_keep`
+/// variant) on the same `pairs`; pairs skipped by the selection are skipped
+/// here too, so the result has one entry per drop, in the same order.
+#[allow(dead_code)]
+pub fn pair_decisions(pairs: &[CorrelatedPair], drops: &[FeatureToDrop]) -> Vec<PairDecision> {
+    let mut decisions = Vec::with_capacity(drops.len());
+    let mut remaining = drops.iter().peekable();
+    let mut already_resolved: HashSet<&str> = HashSet::new();
+
+    for pair in pairs {
+        let Some(next) = remaining.peek() else {
+            break;
+        };
+        if already_resolved.contains(pair.feature1.as_str())
+            || already_resolved.contains(pair.feature2.as_str())
+        {
+            continue;
+        }
+        let kept = if next.feature == pair.feature1 {
+            &pair.feature2
+        } else if next.feature == pair.feature2 {
+            &pair.feature1
+        } else {
+            // Both members were kept by override
+            continue;
+        };
+        already_resolved.insert(&next.feature);
+        decisions.push(PairDecision {
+            pair: pair.clone(),
+            dropped: next.feature.clone(),
+            kept: kept.clone(),
         });
+        remaining.next();
     }
 
-    to_drop
+    decisions
+}
+
+/// The pairs that decided a drop, read from the rationales set by
+/// [`resolve_correlated_pairs`], in pair order.
+///
+/// Unlike [`pair_decisions`], this holds for either [`CorrelationStrategy`].
+pub fn resolved_pair_decisions(pairs: &[CorrelatedPair]) -> Vec<PairDecision> {
+    pairs
+        .iter()
+        .filter_map(|pair| {
+            let rationale = pair.rationale.as_ref()?;
+            Some(PairDecision {
+                pair: pair.clone(),
+                dropped: rationale.dropped.clone(),
+                kept: rationale.kept.clone(),
+            })
+        })
+        .collect()
 }
 
 /// Waterfall logic for deciding which feature in a pair to drop.
 ///
-/// Returns (feature_to_drop, human-readable reason).
+/// The target is always kept; `policy` orders the remaining rules, with the
+/// alphabetical tie-break last. Returns the pair's rationale and the
+/// human-readable drop reason.
 fn decide_feature_to_drop(
     f1: &str,
     f2: &str,
@@ -1017,23 +1773,26 @@ fn decide_feature_to_drop(
     frequency: &HashMap<&str, usize>,
     metadata: Option<&HashMap<String, FeatureMetadata>>,
     pair: &CorrelatedPair,
-) -> (String, String) {
+    policy: &CorrKeepPolicy,
+) -> (PairRationale, String) {
     let measure_label = pair.measure.to_string();
     let coeff = pair.correlation;
+    let rationale = |kept: &str, dropped: &str, rule, values| PairRationale {
+        kept: kept.to_string(),
+        dropped: dropped.to_string(),
+        rule,
+        values,
+    };
 
     // 1. Target protection
-    if f1 == target_column {
-        return (
-            f2.to_string(),
-            format!(
-                "Correlated with target ({} {:.4}); dropped to protect target",
-                measure_label, coeff
-            ),
-        );
-    }
-    if f2 == target_column {
+    if f1 == target_column || f2 == target_column {
+        let (kept, dropped) = if f1 == target_column {
+            (f1, f2)
+        } else {
+            (f2, f1)
+        };
         return (
-            f1.to_string(),
+            rationale(kept, dropped, DropRule::Target, None),
             format!(
                 "Correlated with target ({} {:.4}); dropped to protect target",
                 measure_label, coeff
@@ -1041,100 +1800,235 @@ fn decide_feature_to_drop(
         );
     }
 
-    // 2. Lower IV (primary)
-    if let Some(meta) = metadata {
-        let iv1 = meta.get(f1).and_then(|m| m.iv);
-        let iv2 = meta.get(f2).and_then(|m| m.iv);
-        if let (Some(iv1_val), Some(iv2_val)) = (iv1, iv2) {
-            // Keep the higher-IV feature; drop the lower-IV one
-            if (iv1_val - iv2_val).abs() > f64::EPSILON {
-                return if iv1_val < iv2_val {
-                    (
-                        f1.to_string(),
-                        format!(
-                            "Correlated with {} ({} {:.4}); lower IV ({:.4} vs {:.4})",
-                            f2, measure_label, coeff, iv1_val, iv2_val
-                        ),
-                    )
+    for step in keep_order(policy) {
+        let decided = match step {
+            DropRule::Prefix => prefix_decision(f1, f2, policy),
+            // Lower IV; R² ranks features for a continuous target
+            DropRule::Iv => metadata.and_then(|meta| {
+                let (m1, m2) = (meta.get(f1), meta.get(f2));
+                let iv = m1.and_then(|m| m.iv).zip(m2.and_then(|m| m.iv));
+                let r_squared = m1
+                    .and_then(|m| m.r_squared)
+                    .zip(m2.and_then(|m| m.r_squared));
+                let (rule, label, (s1_val, s2_val)) = iv
+                    .map(|s| (DropRule::Iv, "IV", s))
+                    .or_else(|| r_squared.map(|s| (DropRule::RSquared, "R²", s)))?;
+                // Keep the higher-scoring feature; drop the lower one
+                if (s1_val - s2_val).abs() <= f64::EPSILON {
+                    return None;
+                }
+                let (kept, dropped, kept_val, dropped_val) = if s1_val < s2_val {
+                    (f2, f1, s2_val, s1_val)
                 } else {
+                    (f1, f2, s1_val, s2_val)
+                };
+                Some((
+                    kept,
+                    dropped,
+                    rule,
+                    Some((kept_val, dropped_val)),
+                    format!("lower {} ({:.4} vs {:.4})", label, dropped_val, kept_val),
+                ))
+            }),
+            DropRule::Frequency => {
+                let freq1 = *frequency.get(f1).unwrap_or(&0);
+                let freq2 = *frequency.get(f2).unwrap_or(&0);
+                (freq1 != freq2).then(|| {
+                    let (kept, dropped, kept_freq, dropped_freq) = if freq1 > freq2 {
+                        (f2, f1, freq2, freq1)
+                    } else {
+                        (f1, f2, freq1, freq2)
+                    };
                     (
-                        f2.to_string(),
-                        format!(
-                            "Correlated with {} ({} {:.4}); lower IV ({:.4} vs {:.4})",
-                            f1, measure_label, coeff, iv2_val, iv1_val
-                        ),
+                        kept,
+                        dropped,
+                        DropRule::Frequency,
+                        Some((kept_freq as f64, dropped_freq as f64)),
+                        format!("higher frequency ({} vs {})", dropped_freq, kept_freq),
                     )
-                };
+                })
             }
+            DropRule::MissingRatio => metadata.and_then(|meta| {
+                let mr1 = meta.get(f1).and_then(|m| m.missing_ratio)?;
+                let mr2 = meta.get(f2).and_then(|m| m.missing_ratio)?;
+                if (mr1 - mr2).abs() <= f64::EPSILON {
+                    return None;
+                }
+                let (kept, dropped, kept_mr, dropped_mr) = if mr1 > mr2 {
+                    (f2, f1, mr2, mr1)
+                } else {
+                    (f1, f2, mr1, mr2)
+                };
+                Some((
+                    kept,
+                    dropped,
+                    DropRule::MissingRatio,
+                    Some((kept_mr, dropped_mr)),
+                    format!("higher missing ratio ({:.4} vs {:.4})", dropped_mr, kept_mr),
+                ))
+            }),
+            _ => None,
+        };
+        if let Some((kept, dropped, rule, values, detail)) = decided {
+            return (
+                rationale(kept, dropped, rule, values),
+                format!(
+                    "Correlated with {} ({} {:.4}); {}",
+                    kept, measure_label, coeff, detail
+                ),
+            );
         }
     }
 
-    // 3. Higher frequency (secondary)
-    let freq1 = *frequency.get(f1).unwrap_or(&0);
-    let freq2 = *frequency.get(f2).unwrap_or(&0);
-    if freq1 != freq2 {
-        return if freq1 > freq2 {
-            (
-                f1.to_string(),
-                format!(
-                    "Correlated with {} ({} {:.4}); higher frequency ({} vs {})",
-                    f2, measure_label, coeff, freq1, freq2
-                ),
-            )
-        } else {
-            (
-                f2.to_string(),
-                format!(
-                    "Correlated with {} ({} {:.4}); higher frequency ({} vs {})",
-                    f1, measure_label, coeff, freq2, freq1
-                ),
-            )
-        };
+    // Alphabetical fallback
+    let (kept, dropped) = if f1 < f2 { (f1, f2) } else { (f2, f1) };
+    (
+        rationale(kept, dropped, DropRule::Alphabetical, None),
+        format!(
+            "Correlated with {} ({} {:.4}); alphabetical tie-break",
+            kept, measure_label, coeff
+        ),
+    )
+}
+
+/// The rules `policy` applies, in order, before the alphabetical tie-break.
+/// [`DropRule::Iv`] stands for the IV or R² comparison.
+fn keep_order(policy: &CorrKeepPolicy) -> &'static [DropRule] {
+    match policy {
+        CorrKeepPolicy::HigherIv => &[DropRule::Iv, DropRule::Frequency, DropRule::MissingRatio],
+        CorrKeepPolicy::LowerMissing => {
+            &[DropRule::MissingRatio, DropRule::Iv, DropRule::Frequency]
+        }
+        CorrKeepPolicy::FirstAlphabetical => &[],
+        CorrKeepPolicy::CustomList(_) => &[
+            DropRule::Prefix,
+            DropRule::Iv,
+            DropRule::Frequency,
+            DropRule::MissingRatio,
+        ],
     }
+}
 
-    // 4. Higher missing ratio (tertiary)
-    if let Some(meta) = metadata {
-        let mr1 = meta.get(f1).and_then(|m| m.missing_ratio);
-        let mr2 = meta.get(f2).and_then(|m| m.missing_ratio);
-        if let (Some(mr1_val), Some(mr2_val)) = (mr1, mr2) {
-            if (mr1_val - mr2_val).abs() > f64::EPSILON {
-                return if mr1_val > mr2_val {
-                    (
-                        f1.to_string(),
-                        format!(
-                            "Correlated with {} ({} {:.4}); higher missing ratio ({:.4} vs {:.4})",
-                            f2, measure_label, coeff, mr1_val, mr2_val
-                        ),
-                    )
-                } else {
-                    (
-                        f2.to_string(),
-                        format!(
-                            "Correlated with {} ({} {:.4}); higher missing ratio ({:.4} vs {:.4})",
-                            f1, measure_label, coeff, mr2_val, mr1_val
-                        ),
-                    )
-                };
-            }
+/// Rank of `feature` in the prefix list; features without a listed prefix
+/// rank after all that have one
+fn prefix_rank(feature: &str, policy: &CorrKeepPolicy) -> usize {
+    policy
+        .prefix_of(feature)
+        .map_or(usize::MAX, |(rank, _)| rank)
+}
+
+/// Kept and dropped member, deciding rule and values, and the reason detail
+type KeepDecision<'a> = (&'a str, &'a str, DropRule, Option<(f64, f64)>, String);
+
+/// Keeps the member of `f1`/`f2` with the earlier prefix, if they differ
+fn prefix_decision<'a>(
+    f1: &'a str,
+    f2: &'a str,
+    policy: &CorrKeepPolicy,
+) -> Option<KeepDecision<'a>> {
+    let (rank1, rank2) = (prefix_rank(f1, policy), prefix_rank(f2, policy));
+    if rank1 == rank2 {
+        return None;
+    }
+    let (kept, dropped) = if rank1 < rank2 { (f1, f2) } else { (f2, f1) };
+    Some((
+        kept,
+        dropped,
+        DropRule::Prefix,
+        None,
+        prefix_detail(kept, dropped, policy),
+    ))
+}
+
+fn prefix_detail(kept: &str, dropped: &str, policy: &CorrKeepPolicy) -> String {
+    let kept_prefix = policy.prefix_of(kept).map_or("", |(_, p)| p);
+    match policy.prefix_of(dropped) {
+        Some((_, p)) => format!("prefix '{}' preferred over '{}'", kept_prefix, p),
+        None => format!("prefix '{}' preferred over no listed prefix", kept_prefix),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_blocks_match_single_block() {
+        let x: Vec<f64> = (0..40).map(|i| i as f64).collect();
+        let noise: Vec<f64> = (0..40).map(|i| ((i * 7919) % 13) as f64).collect();
+        let columns: Vec<Column> = (0..7)
+            .map(|c| {
+                let values: Vec<f64> = x
+                    .iter()
+                    .zip(&noise)
+                    .map(|(v, n)| v * (c % 3) as f64 + n * (c + 1) as f64)
+                    .collect();
+                Column::new(format!("f{}", c).into(), values)
+            })
+            .collect();
+        let df = DataFrame::new(columns).unwrap();
+        let weights = vec![1.0; df.height()];
+
+        // f0, f3 and f6 are exact multiples of each other, so their |r| = 1
+        // ties can sort either way with rounding; compare by pair instead
+        let by_pair = |mut pairs: Vec<CorrelatedPair>| {
+            pairs.sort_by(|a, b| (&a.feature1, &a.feature2).cmp(&(&b.feature1, &b.feature2)));
+            pairs
+        };
+        let single = by_pair(
+            find_correlated_pairs_matrix_impl(&df, 0.3, &weights, None, None, 512).unwrap(),
+        );
+        // Blocks of 3 split the 7 columns into 3, 3 and 1
+        let blocked =
+            by_pair(find_correlated_pairs_matrix_impl(&df, 0.3, &weights, None, None, 3).unwrap());
+
+        assert!(!single.is_empty());
+        assert_eq!(single.len(), blocked.len());
+        for (a, b) in single.iter().zip(&blocked) {
+            assert_eq!((&a.feature1, &a.feature2), (&b.feature1, &b.feature2));
+            assert!((a.correlation - b.correlation).abs() < 1e-12);
         }
     }
 
-    // 5. Alphabetical fallback
-    if f1 < f2 {
-        (
-            f2.to_string(),
-            format!(
-                "Correlated with {} ({} {:.4}); alphabetical tie-break",
-                f1, measure_label, coeff
-            ),
-        )
-    } else {
-        (
-            f1.to_string(),
-            format!(
-                "Correlated with {} ({} {:.4}); alphabetical tie-break",
-                f2, measure_label, coeff
-            ),
-        )
+    #[test]
+    fn test_blocks_cover_every_pair_once() {
+        let z = Mat::<f64>::from_fn(5, 7, |i, j| ((i + 1) * (j + 2)) as f64);
+        let names: Vec<String> = (0..7).map(|c| format!("f{}", c)).collect();
+        let covered = AtomicU64::new(0);
+        blocked_correlated_pairs(&z, &names, 2.0, 3, |n| {
+            covered.fetch_add(n, Ordering::Relaxed);
+        });
+        assert_eq!(covered.into_inner(), 21);
+    }
+
+    #[test]
+    fn test_corr_keep_policy_parse() {
+        let none: [&str; 0] = [];
+        assert_eq!(
+            CorrKeepPolicy::parse("higher_iv", &none).unwrap(),
+            CorrKeepPolicy::default()
+        );
+        assert_eq!(
+            CorrKeepPolicy::parse("lower-missing", &none).unwrap(),
+            CorrKeepPolicy::LowerMissing
+        );
+        assert_eq!(
+            CorrKeepPolicy::parse("custom_list", &[" bureau_", "", "internal_"]).unwrap(),
+            CorrKeepPolicy::CustomList(vec!["bureau_".into(), "internal_".into()])
+        );
+        assert!(CorrKeepPolicy::parse("custom_list", &none).is_err());
+        assert!(CorrKeepPolicy::parse("higher_iv", &["bureau_"]).is_err());
+        assert!(CorrKeepPolicy::parse("newest", &none).is_err());
+    }
+
+    #[test]
+    fn test_prefix_rank_uses_the_first_matching_prefix() {
+        let policy = CorrKeepPolicy::CustomList(vec!["bureau_".into(), "bureau_x".into()]);
+        assert_eq!(prefix_rank("bureau_x1", &policy), 0);
+        assert_eq!(prefix_rank("internal_x1", &policy), usize::MAX);
+        assert_eq!(
+            prefix_rank("bureau_x1", &CorrKeepPolicy::HigherIv),
+            usize::MAX
+        );
     }
 }
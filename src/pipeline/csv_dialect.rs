@@ -0,0 +1,374 @@
+//! CSV dialect (`--csv-separator`, `--csv-quote`, `--csv-decimal-comma`,
+//! `--csv-date-format`, `--csv-null`) for reading CSV input and writing CSV output.
+//!
+//! Polars reads dates from CSV only in ISO form and cannot write decimal
+//! commas, so both are handled here: text columns whose inferred rows all
+//! match the date format become `Date` columns, and float columns are written
+//! as text with `,` in place of the decimal point.
+
+use std::io::Write;
+use std::path::Path;
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::NaiveDate;
+use polars::prelude::*;
+
+/// How CSV files are delimited, quoted and formatted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvDialect {
+    /// Field separator
+    pub separator: u8,
+    /// Quote character; `None` turns quoting off
+    pub quote_char: Option<u8>,
+    /// Numbers use `,` as the decimal separator
+    pub decimal_comma: bool,
+    /// strftime format of dates, e.g. `%d.%m.%Y`
+    pub date_format: Option<String>,
+    /// Text that stands for a missing value, e.g. `NA`
+    pub null_value: Option<String>,
+}
+
+impl Default for CsvDialect {
+    fn default() -> Self {
+        Self {
+            separator: b',',
+            quote_char: Some(b'"'),
+            decimal_comma: false,
+            date_format: None,
+            null_value: None,
+        }
+    }
+}
+
+/// Parse a `--csv-separator` value: one ASCII character, or `tab`
+pub fn parse_csv_separator(s: &str) -> Result<u8, String> {
+    match s {
+        "tab" | "\\t" | "\t" => Ok(b'\t'),
+        _ => match single_ascii(s) {
+            Some(b'\n' | b'\r') | None => Err(format!(
+                "Invalid CSV separator '{}'. Use a single ASCII character such as ';' or 'tab'",
+                s
+            )),
+            Some(byte) => Ok(byte),
+        },
+    }
+}
+
+/// Parse a `--csv-quote` value: one ASCII character, or `none` for no quoting
+pub fn parse_csv_quote(s: &str) -> Result<Option<u8>, String> {
+    if s.eq_ignore_ascii_case("none") {
+        return Ok(None);
+    }
+    match single_ascii(s) {
+        Some(byte) => Ok(Some(byte)),
+        None => Err(format!(
+            "Invalid CSV quote character '{}'. Use a single ASCII character or 'none'",
+            s
+        )),
+    }
+}
+
+/// Check a `--csv-date-format` value against chrono's strftime syntax
+pub fn validate_csv_date_format(s: &str) -> Result<(), String> {
+    if s.is_empty() || StrftimeItems::new(s).any(|item| matches!(item, Item::Error)) {
+        return Err(format!("Invalid date format '{}'", s));
+    }
+    Ok(())
+}
+
+fn single_ascii(s: &str) -> Option<u8> {
+    match s.as_bytes() {
+        [byte] if byte.is_ascii() => Some(*byte),
+        _ => None,
+    }
+}
+
+impl CsvDialect {
+    /// Reject combinations that cannot be read back unambiguously
+    pub fn validate(&self) -> Result<(), String> {
+        if self.quote_char == Some(self.separator) {
+            return Err("The CSV quote character cannot also be the separator".to_string());
+        }
+        if self.decimal_comma && self.separator == b',' {
+            return Err(
+                "Decimal commas need a separator other than ',' (e.g. --csv-separator ';')"
+                    .to_string(),
+            );
+        }
+        if let Some(format) = &self.date_format {
+            validate_csv_date_format(format)?;
+        }
+        Ok(())
+    }
+
+    fn apply_parse_options(&self, options: CsvParseOptions) -> CsvParseOptions {
+        options
+            .with_separator(self.separator)
+            .with_quote_char(self.quote_char)
+            .with_decimal_comma(self.decimal_comma)
+            .with_null_values(self.null_values())
+    }
+
+    fn null_values(&self) -> Option<NullValues> {
+        self.null_value
+            .as_deref()
+            .map(|value| NullValues::AllColumnsSingle(value.into()))
+    }
+
+    /// Eager read options with this dialect
+    pub fn read_options(&self) -> CsvReadOptions {
+        CsvReadOptions::default().map_parse_options(|options| self.apply_parse_options(options))
+    }
+
+    /// A lazy scan of `path` with this dialect
+    pub fn scan(&self, path: &Path) -> LazyCsvReader {
+        LazyCsvReader::new(path)
+            .with_separator(self.separator)
+            .with_quote_char(self.quote_char)
+            .with_decimal_comma(self.decimal_comma)
+            .with_null_values(self.null_values())
+    }
+
+    /// Write `df` to `writer` in this dialect
+    pub fn write<W: Write>(&self, df: &mut DataFrame, writer: W) -> PolarsResult<()> {
+        let mut csv = CsvWriter::new(writer)
+            .with_separator(self.separator)
+            .with_date_format(self.date_format.clone());
+        csv = match self.quote_char {
+            Some(quote) => csv.with_quote_char(quote),
+            None => csv.with_quote_style(QuoteStyle::Never),
+        };
+        if let Some(null) = &self.null_value {
+            csv = csv.with_null_value(null.clone());
+        }
+        if self.decimal_comma {
+            csv.finish(&mut with_decimal_commas(df)?)
+        } else {
+            csv.finish(df)
+        }
+    }
+
+    /// Turn text columns that hold dates in `date_format` into `Date` columns.
+    ///
+    /// A column is converted when its first `sample_rows` rows (all rows for
+    /// `None`) hold at least one value and every value matches; a later value
+    /// that does not match is then an error, as with polars' own inference.
+    /// Without a date format the frame is returned unchanged.
+    pub fn parse_dates(
+        &self,
+        df: DataFrame,
+        sample_rows: Option<usize>,
+    ) -> PolarsResult<DataFrame> {
+        if self.date_format.is_none() {
+            return Ok(df);
+        }
+        let columns = match sample_rows {
+            Some(rows) => self.date_columns(&df.head(Some(rows))),
+            None => self.date_columns(&df),
+        };
+        self.parse_date_columns(df, &columns)
+    }
+
+    /// Text columns of `sample` that hold values, all in `date_format`;
+    /// none without a date format
+    pub fn date_columns(&self, sample: &DataFrame) -> Vec<PlSmallStr> {
+        match &self.date_format {
+            Some(format) => matching_date_columns(sample, format),
+            None => Vec::new(),
+        }
+    }
+
+    /// Turn `columns` of `df` (from [`CsvDialect::date_columns`]) into `Date`
+    /// columns, failing on a value that does not match the date format
+    pub fn parse_date_columns(
+        &self,
+        mut df: DataFrame,
+        columns: &[PlSmallStr],
+    ) -> PolarsResult<DataFrame> {
+        let Some(format) = &self.date_format else {
+            return Ok(df);
+        };
+        for name in columns {
+            let dates = to_dates(df.column(name)?, format)?;
+            df.with_column(dates)?;
+        }
+        Ok(df)
+    }
+
+    /// [`CsvDialect::parse_dates`] for a lazy scan; the date columns are
+    /// chosen from the first `sample_rows` rows.
+    pub fn parse_dates_lazy(
+        &self,
+        lf: LazyFrame,
+        sample_rows: Option<usize>,
+    ) -> PolarsResult<LazyFrame> {
+        let Some(format) = &self.date_format else {
+            return Ok(lf);
+        };
+        let sample = match sample_rows {
+            Some(rows) => lf.clone().limit(rows as IdxSize).collect()?,
+            None => lf.clone().collect()?,
+        };
+        let exprs: Vec<Expr> = matching_date_columns(&sample, format)
+            .into_iter()
+            .map(|name| {
+                let format = format.clone();
+                col(name.as_str()).map(
+                    move |column| to_dates(&column, &format).map(Some),
+                    GetOutput::from_type(DataType::Date),
+                )
+            })
+            .collect();
+        Ok(if exprs.is_empty() {
+            lf
+        } else {
+            lf.with_columns(exprs)
+        })
+    }
+}
+
+/// Text columns of `sample` with values, all of which parse as `format`
+fn matching_date_columns(sample: &DataFrame, format: &str) -> Vec<PlSmallStr> {
+    sample
+        .get_columns()
+        .iter()
+        .filter_map(|column| {
+            let values = column.str().ok()?;
+            let mut present = values.into_iter().flatten().peekable();
+            present.peek()?;
+            present
+                .all(|value| NaiveDate::parse_from_str(value, format).is_ok())
+                .then(|| column.name().clone())
+        })
+        .collect()
+}
+
+/// Parse a text column as dates, failing on the first value that does not match
+fn to_dates(column: &Column, format: &str) -> PolarsResult<Column> {
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).expect("valid date");
+    let days = column
+        .str()?
+        .into_iter()
+        .map(|value| {
+            value
+                .map(|text| {
+                    NaiveDate::parse_from_str(text, format)
+                        .map(|date| (date - epoch).num_days() as i32)
+                        .map_err(|_| {
+                            polars_err!(ComputeError:
+                                "value '{}' in column '{}' does not match the date format '{}'",
+                                text, column.name(), format)
+                        })
+                })
+                .transpose()
+        })
+        .collect::<PolarsResult<Int32Chunked>>()?;
+    Ok(days
+        .with_name(column.name().clone())
+        .into_date()
+        .into_column())
+}
+
+/// A copy of `df` with float columns as text using `,` as the decimal separator
+fn with_decimal_commas(df: &DataFrame) -> PolarsResult<DataFrame> {
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|column| {
+            if !column.dtype().is_float() {
+                return Ok(column.clone());
+            }
+            let text = column.cast(&DataType::String)?;
+            let replaced: StringChunked = text
+                .str()?
+                .into_iter()
+                .map(|value| value.map(|s| s.replace('.', ",")))
+                .collect();
+            Ok(replaced.with_name(column.name().clone()).into_column())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    DataFrame::new(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_separator_and_quote() {
+        assert_eq!(parse_csv_separator(";"), Ok(b';'));
+        assert_eq!(parse_csv_separator("tab"), Ok(b'\t'));
+        assert!(parse_csv_separator(";;").is_err());
+        assert!(parse_csv_separator("\n").is_err());
+
+        assert_eq!(parse_csv_quote("'"), Ok(Some(b'\'')));
+        assert_eq!(parse_csv_quote("none"), Ok(None));
+        assert!(parse_csv_quote("").is_err());
+
+        assert!(validate_csv_date_format("%d.%m.%Y").is_ok());
+        assert!(validate_csv_date_format("%Q").is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_ambiguous_dialects() {
+        assert!(CsvDialect::default().validate().is_ok());
+        let comma_decimals = CsvDialect {
+            decimal_comma: true,
+            ..Default::default()
+        };
+        assert!(comma_decimals.validate().is_err());
+        let semicolon = CsvDialect {
+            separator: b';',
+            ..comma_decimals
+        };
+        assert!(semicolon.validate().is_ok());
+        let quote_is_separator = CsvDialect {
+            quote_char: Some(b','),
+            ..Default::default()
+        };
+        assert!(quote_is_separator.validate().is_err());
+    }
+
+    #[test]
+    fn test_parse_dates_converts_only_matching_columns() {
+        let df = df! {
+            "opened" => [Some("31.01.2024"), None, Some("29.02.2024")],
+            "code" => [Some("31.01.2024"), Some("n/a"), None],
+            "amount" => [1.5f64, 2.0, 3.25],
+        }
+        .unwrap();
+        let dialect = CsvDialect {
+            date_format: Some("%d.%m.%Y".to_string()),
+            ..Default::default()
+        };
+        let parsed = dialect.parse_dates(df, None).unwrap();
+        assert_eq!(parsed.column("opened").unwrap().dtype(), &DataType::Date);
+        assert_eq!(parsed.column("opened").unwrap().null_count(), 1);
+        assert_eq!(parsed.column("code").unwrap().dtype(), &DataType::String);
+
+        // A sample that only sees dates commits the column; later text fails
+        let df = df! { "opened" => ["31.01.2024", "oops"] }.unwrap();
+        assert!(dialect.parse_dates(df, Some(1)).is_err());
+    }
+
+    #[test]
+    fn test_write_decimal_commas_and_nulls() {
+        let mut df = df! {
+            "name" => [Some("a;b"), None],
+            "rate" => [Some(0.5f64), None],
+        }
+        .unwrap();
+        let dialect = CsvDialect {
+            separator: b';',
+            decimal_comma: true,
+            null_value: Some("NA".to_string()),
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        dialect.write(&mut df, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "name;rate\n\"a;b\";0,5\nNA;NA\n"
+        );
+    }
+}
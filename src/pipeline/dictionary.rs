@@ -0,0 +1,227 @@
+//! Business descriptions of features from a data dictionary (`--dictionary`)
+//!
+//! Technical column names such as `bur_inq_6m_cnt` mean little to the credit
+//! and business reviewers who sign off a reduction. A dictionary CSV maps each
+//! feature to a description and an owner; the Gini export, the reduction report
+//! and the review screens show them next to the column name.
+//!
+//! The file needs a `feature` column and at least one of `description` and
+//! `owner` (header names are case-insensitive; other columns are ignored):
+//!
+//! ```text
+//! feature,description,owner
+//! bur_inq_6m_cnt,Bureau enquiries in the last 6 months,Credit Risk
+//! income_monthly,Declared gross monthly income,Origination
+//! ```
+//!
+//! Features missing from the dictionary are reported without a description.
+//!
+//! A SAS7BDAT input carries its own dictionary: each column's label and format
+//! name are added to the entries, and the label stands in for the description
+//! on the review screens when the dictionary file gives none.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use polars::prelude::*;
+use serde::Serialize;
+
+/// Description and owner of one feature
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct FeatureDescription {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Column label from a SAS7BDAT input
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// SAS format name (e.g. `DATE`, `DOLLAR`) from a SAS7BDAT input
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+}
+
+/// Feature descriptions keyed by column name
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeatureDictionary {
+    pub entries: BTreeMap<String, FeatureDescription>,
+}
+
+impl FeatureDictionary {
+    /// Read a dictionary CSV.
+    ///
+    /// Blank cells count as unset, and rows without a feature name are skipped.
+    /// A feature listed more than once is an error.
+    pub fn load(path: &Path) -> Result<Self> {
+        let df = CsvReadOptions::default()
+            // Every cell as text, so numeric-looking owners keep their form
+            .with_infer_schema_length(Some(0))
+            .try_into_reader_with_file_path(Some(path.to_path_buf()))
+            .and_then(|reader| reader.finish())
+            .with_context(|| format!("Failed to read dictionary file: {}", path.display()))?;
+
+        let column = |name: &str| {
+            df.get_columns()
+                .iter()
+                .find(|c| c.name().trim().eq_ignore_ascii_case(name))
+                .map(|c| c.str())
+                .transpose()
+        };
+        let Some(features) = column("feature")? else {
+            bail!("Dictionary file {} has no 'feature' column", path.display());
+        };
+        let descriptions = column("description")?;
+        let owners = column("owner")?;
+        if descriptions.is_none() && owners.is_none() {
+            bail!(
+                "Dictionary file {} needs a 'description' or 'owner' column",
+                path.display()
+            );
+        }
+
+        let cell = |values: Option<&StringChunked>, row: usize| {
+            values
+                .and_then(|v| v.get(row))
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+        };
+        let mut dictionary = FeatureDictionary::default();
+        for row in 0..df.height() {
+            let Some(feature) = cell(Some(features), row) else {
+                continue;
+            };
+            if dictionary.entries.contains_key(&feature) {
+                bail!(
+                    "Dictionary file {} lists '{}' more than once",
+                    path.display(),
+                    feature
+                );
+            }
+            let entry = FeatureDescription {
+                description: cell(descriptions, row),
+                owner: cell(owners, row),
+                ..Default::default()
+            };
+            dictionary.entries.insert(feature, entry);
+        }
+        Ok(dictionary)
+    }
+
+    /// Description and owner of `feature`, if the dictionary lists it
+    pub fn get(&self, feature: &str) -> Option<&FeatureDescription> {
+        self.entries.get(feature)
+    }
+
+    /// Description of `feature`, falling back to its SAS label
+    pub fn description(&self, feature: &str) -> Option<&str> {
+        self.get(feature)
+            .and_then(|d| d.description.as_deref().or(d.label.as_deref()))
+    }
+
+    /// Add the labels and formats of SAS7BDAT columns.
+    ///
+    /// Columns with neither are left out; descriptions and owners already in
+    /// the dictionary are kept.
+    #[cfg(feature = "sas7bdat")]
+    pub fn add_sas_metadata(&mut self, columns: &[super::sas7bdat::SasColumn]) {
+        let text = |s: &str| Some(s.trim().to_string()).filter(|s| !s.is_empty());
+        for column in columns {
+            let (label, format) = (text(&column.label), text(&column.format));
+            if label.is_none() && format.is_none() {
+                continue;
+            }
+            let entry = self.entries.entry(column.name.clone()).or_default();
+            entry.label = label;
+            entry.format = format;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_dictionary(content: &str) -> (tempfile::TempDir, std::path::PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dictionary.csv");
+        std::fs::write(&path, content).unwrap();
+        (dir, path)
+    }
+
+    #[test]
+    fn test_load_descriptions_and_owners() {
+        let (_dir, path) = write_dictionary(
+            "Feature,Description,Owner,Source\n\
+             age,\"Applicant age, in years\",Origination,app\n\
+             income,,007,bureau\n\
+             ,orphan row,Nobody,\n",
+        );
+        let dictionary = FeatureDictionary::load(&path).unwrap();
+        assert_eq!(dictionary.entries.len(), 2);
+        assert_eq!(
+            dictionary.description("age"),
+            Some("Applicant age, in years")
+        );
+        assert_eq!(dictionary.description("income"), None);
+        assert_eq!(
+            dictionary.get("income").unwrap().owner.as_deref(),
+            Some("007")
+        );
+        assert!(dictionary.get("region").is_none());
+    }
+
+    #[test]
+    fn test_load_rejects_malformed_dictionaries() {
+        let (_dir, path) = write_dictionary("name,description\nage,Age\n");
+        assert!(FeatureDictionary::load(&path)
+            .unwrap_err()
+            .to_string()
+            .contains("no 'feature' column"));
+
+        let (_dir, path) = write_dictionary("feature,source\nage,app\n");
+        assert!(FeatureDictionary::load(&path).is_err());
+
+        let (_dir, path) = write_dictionary("feature,description\nage,Age\nage,Age again\n");
+        assert!(FeatureDictionary::load(&path)
+            .unwrap_err()
+            .to_string()
+            .contains("more than once"));
+    }
+
+    #[cfg(feature = "sas7bdat")]
+    #[test]
+    fn test_sas_labels_fill_in_missing_descriptions() {
+        use crate::pipeline::sas7bdat::{PolarsOutputType, SasColumn, SasDataType};
+
+        let column = |name: &str, label: &str, format: &str| SasColumn {
+            name: name.to_string(),
+            data_type: SasDataType::Numeric,
+            offset: 0,
+            length: 8,
+            format: format.to_string(),
+            label: label.to_string(),
+            polars_type: PolarsOutputType::Float64,
+        };
+        let (_dir, path) = write_dictionary(
+            "feature,description
+actual,Booked sales
+",
+        );
+        let mut dictionary = FeatureDictionary::load(&path).unwrap();
+        dictionary.add_sas_metadata(&[
+            column("actual", "Actual Sales", "DOLLAR"),
+            column("predict", "Predicted Sales ", ""),
+            column("year", "", ""),
+        ]);
+
+        assert_eq!(dictionary.description("actual"), Some("Booked sales"));
+        let actual = dictionary.get("actual").unwrap();
+        assert_eq!(actual.label.as_deref(), Some("Actual Sales"));
+        assert_eq!(actual.format.as_deref(), Some("DOLLAR"));
+        assert_eq!(dictionary.description("predict"), Some("Predicted Sales"));
+        assert_eq!(dictionary.get("predict").unwrap().format, None);
+        assert!(dictionary.get("year").is_none());
+    }
+}
@@ -0,0 +1,229 @@
+//! Weighted value distributions of numeric features (`--export-distributions`)
+//!
+//! Each retained numeric feature gets a compact equal-width histogram over its
+//! finite range, with bucket heights summed from the row weights. The
+//! histograms are stored next to the feature's bins in the Gini export so
+//! report viewers can chart distributions without reloading the dataset.
+//!
+//! Every numeric feature also gets a few weighted percentiles and the share
+//! of its values far outside the interquartile range, so a reviewer can tell
+//! whether a low IV comes from extreme outliers or bad capping.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use polars::prelude::*;
+use serde::Serialize;
+
+/// Default number of histogram buckets
+pub const DISTRIBUTION_BUCKETS: usize = 50;
+
+/// Equal-width weighted histogram of one numeric feature
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WeightedHistogram {
+    /// Smallest non-null value (lower edge of the first bucket)
+    pub min: f64,
+    /// Largest non-null value (upper edge of the last bucket)
+    pub max: f64,
+    /// Width of each bucket; 0 when every value is the same
+    pub bucket_width: f64,
+    /// Summed row weight per bucket. A constant feature has a single bucket.
+    pub weights: Vec<f64>,
+    /// Summed row weight of null values
+    pub missing_weight: f64,
+}
+
+/// Interquartile ranges beyond the quartiles past which a value is extreme
+pub const EXTREME_IQR_MULTIPLE: f64 = 5.0;
+
+/// Weighted percentiles of one numeric feature and its share of extreme values
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExtremeValueStats {
+    pub min: f64,
+    /// 1st percentile
+    pub p1: f64,
+    /// Median
+    pub p50: f64,
+    /// 99th percentile
+    pub p99: f64,
+    pub max: f64,
+    /// Percentage of the non-null weight more than [`EXTREME_IQR_MULTIPLE`]
+    /// interquartile ranges below the first or above the third quartile. With
+    /// an IQR of 0 every value off the quartiles counts.
+    pub pct_beyond_5_iqr: f64,
+}
+
+/// Histograms of the numeric `features` of `df`, keyed by feature name.
+///
+/// Features that are missing from `df`, not numeric, or entirely null are
+/// skipped. `weights` holds one weight per row.
+pub fn compute_distributions(
+    df: &DataFrame,
+    features: &[String],
+    weights: &[f64],
+    buckets: usize,
+) -> Result<HashMap<String, WeightedHistogram>> {
+    let mut distributions = HashMap::new();
+    for feature in features {
+        let Ok(column) = df.column(feature) else {
+            continue;
+        };
+        if !column.dtype().is_primitive_numeric() {
+            continue;
+        }
+        let values = column.cast(&DataType::Float64)?;
+        if let Some(histogram) = weighted_histogram(values.f64()?, weights, buckets) {
+            distributions.insert(feature.clone(), histogram);
+        }
+    }
+    Ok(distributions)
+}
+
+/// Percentiles and extreme-value shares of the numeric `features` of `df`,
+/// keyed by feature name.
+///
+/// Only finite values count. Features that are missing from `df`, not
+/// numeric, or without a finite value of positive weight are skipped.
+pub fn compute_extreme_values(
+    df: &DataFrame,
+    features: &[String],
+    weights: &[f64],
+) -> Result<HashMap<String, ExtremeValueStats>> {
+    let mut stats = HashMap::new();
+    for feature in features {
+        let Ok(column) = df.column(feature) else {
+            continue;
+        };
+        if !column.dtype().is_primitive_numeric() {
+            continue;
+        }
+        let values = column.cast(&DataType::Float64)?;
+        if let Some(extremes) = extreme_values(values.f64()?, weights) {
+            stats.insert(feature.clone(), extremes);
+        }
+    }
+    Ok(stats)
+}
+
+fn extreme_values(values: &Float64Chunked, weights: &[f64]) -> Option<ExtremeValueStats> {
+    let mut sorted: Vec<(f64, f64)> = values
+        .iter()
+        .zip(weights)
+        .filter_map(|(v, &w)| v.filter(|v| v.is_finite()).map(|v| (v, w.max(0.0))))
+        .collect();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let total: f64 = sorted.iter().map(|&(_, w)| w).sum();
+    if total <= 0.0 {
+        return None;
+    }
+
+    let quantile = |q: f64| weighted_quantile(&sorted, total, q);
+    let (q1, q3) = (quantile(0.25), quantile(0.75));
+    let reach = EXTREME_IQR_MULTIPLE * (q3 - q1);
+    let extreme: f64 = sorted
+        .iter()
+        .filter(|&&(v, _)| v < q1 - reach || v > q3 + reach)
+        .map(|&(_, w)| w)
+        .sum();
+
+    Some(ExtremeValueStats {
+        min: sorted[0].0,
+        p1: quantile(0.01),
+        p50: quantile(0.5),
+        p99: quantile(0.99),
+        max: sorted[sorted.len() - 1].0,
+        pct_beyond_5_iqr: 100.0 * extreme / total,
+    })
+}
+
+/// Smallest value of `sorted` (`(value, weight)` pairs sorted by value, with
+/// non-negative weights summing to `total > 0`) whose cumulative weight
+/// reaches the share `q`
+pub(crate) fn weighted_quantile(sorted: &[(f64, f64)], total: f64, q: f64) -> f64 {
+    // Tolerate rounding in the cumulative sum
+    let target = q * total - total * 1e-9;
+    let mut cumulative = 0.0;
+    for &(value, weight) in sorted {
+        cumulative += weight;
+        if cumulative >= target && weight > 0.0 {
+            return value;
+        }
+    }
+    sorted[sorted.len() - 1].0
+}
+
+fn weighted_histogram(
+    values: &Float64Chunked,
+    weights: &[f64],
+    buckets: usize,
+) -> Option<WeightedHistogram> {
+    let min = values.min()?;
+    let max = values.max()?;
+
+    let buckets = if max > min { buckets.max(1) } else { 1 };
+    let bucket_width = (max - min) / buckets as f64;
+    let mut histogram = WeightedHistogram {
+        min,
+        max,
+        bucket_width,
+        weights: vec![0.0; buckets],
+        missing_weight: 0.0,
+    };
+
+    for (value, &w) in values.iter().zip(weights) {
+        match value {
+            Some(v) if bucket_width > 0.0 => {
+                // The maximum belongs to the last bucket, not one past it
+                let idx = (((v - min) / bucket_width) as usize).min(buckets - 1);
+                histogram.weights[idx] += w;
+            }
+            Some(_) => histogram.weights[0] += w,
+            None => histogram.missing_weight += w,
+        }
+    }
+    Some(histogram)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_maximum_falls_in_last_bucket() {
+        let values = Float64Chunked::from_slice("x".into(), &[0.0, 1.0, 2.0, 4.0]);
+        let histogram = weighted_histogram(&values, &[1.0, 1.0, 1.0, 2.0], 4).unwrap();
+        assert_eq!(histogram.bucket_width, 1.0);
+        assert_eq!(histogram.weights, vec![1.0, 1.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_constant_feature_has_one_bucket() {
+        let values = Float64Chunked::from_slice_options("x".into(), &[Some(3.0), None, Some(3.0)]);
+        let histogram = weighted_histogram(&values, &[1.0, 0.5, 2.0], 10).unwrap();
+        assert_eq!(histogram.weights, vec![3.0]);
+        assert_eq!(histogram.bucket_width, 0.0);
+        assert_eq!(histogram.missing_weight, 0.5);
+    }
+
+    #[test]
+    fn test_extreme_values_follow_the_weights() {
+        // 0..=99 with one capping sentinel far above the rest
+        let mut values: Vec<Option<f64>> = (0..100).map(|v| Some(v as f64)).collect();
+        values.push(Some(999_999.0));
+        values.push(None);
+        let values = Float64Chunked::from_slice_options("x".into(), &values);
+        let mut weights = vec![1.0; 102];
+        weights[100] = 2.0;
+
+        let stats = extreme_values(&values, &weights).unwrap();
+        assert_eq!(stats.min, 0.0);
+        assert_eq!(stats.p1, 1.0);
+        assert_eq!(stats.p50, 50.0);
+        assert_eq!(stats.p99, 999_999.0);
+        assert_eq!(stats.max, 999_999.0);
+        assert!((stats.pct_beyond_5_iqr - 100.0 * 2.0 / 102.0).abs() < 1e-12);
+
+        let nulls = Float64Chunked::from_slice_options("x".into(), &[None, Some(f64::NAN)]);
+        assert!(extreme_values(&nulls, &[1.0, 1.0]).is_none());
+    }
+}
@@ -0,0 +1,131 @@
+//! Constant and near-constant feature pre-filter
+//!
+//! A feature whose rows (almost) all hold the same value cannot separate the
+//! target, yet it still costs a binning and a correlation pass. This cheap scan
+//! runs before missing analysis: it finds each feature's most common value and
+//! the weighted share of rows that value covers (`--dominance-threshold`).
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use polars::prelude::*;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Pre-filter threshold of `--no-target` runs without `--dominance-threshold`
+pub const NO_TARGET_DOMINANCE_THRESHOLD: f64 = 0.995;
+
+/// Most common value of a feature and the weighted share of rows it covers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DominanceStats {
+    pub feature: String,
+    /// Most common value as text; `None` when the most common "value" is null
+    pub value: Option<String>,
+    /// Weighted share of rows holding `value`, in [0, 1]
+    pub share: f64,
+}
+
+impl DominanceStats {
+    /// Whether every row holds the same value
+    pub fn is_constant(&self) -> bool {
+        1.0 - self.share < 1e-12
+    }
+
+    /// Whether the pre-filter drops the feature at `threshold`: constant, or
+    /// one non-null value covers more than `threshold` of the rows. Features
+    /// dominated by null are left to missing analysis.
+    pub fn is_dominated(&self, threshold: f64) -> bool {
+        self.value.is_some() && (self.is_constant() || self.share > threshold)
+    }
+}
+
+/// Find the most common value of every column with optional sample weights.
+///
+/// Null counts as a value of its own, so a feature that is either null or a
+/// single value is not constant. The weight column is skipped. Results are
+/// sorted by share descending, then by name.
+pub fn analyze_dominance(
+    df: &DataFrame,
+    weights: &[f64],
+    weight_column: Option<&str>,
+) -> Result<Vec<DominanceStats>> {
+    if df.height() == 0 {
+        return Ok(Vec::new());
+    }
+
+    let total_weight: f64 = weights.iter().sum();
+    if total_weight.abs() < f64::EPSILON {
+        anyhow::bail!("Total weight is zero - cannot compute value shares");
+    }
+
+    let mut stats = df
+        .get_columns()
+        .par_iter()
+        .filter(|column| Some(column.name().as_str()) != weight_column)
+        .map(|column| {
+            let text = column.as_materialized_series().cast(&DataType::String)?;
+            let mut totals: HashMap<Option<&str>, f64> = HashMap::new();
+            for (value, &w) in text.str()?.iter().zip(weights) {
+                *totals.entry(value).or_insert(0.0) += w;
+            }
+            // Ties go to the smaller value so repeated runs agree
+            let (value, weight) = totals
+                .into_iter()
+                .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)))
+                .unwrap_or((None, 0.0));
+            Ok(DominanceStats {
+                feature: column.name().to_string(),
+                value: value.map(str::to_string),
+                share: weight / total_weight,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    stats.sort_by(|a, b| {
+        b.share
+            .total_cmp(&a.share)
+            .then_with(|| a.feature.cmp(&b.feature))
+    });
+    Ok(stats)
+}
+
+/// Features [`DominanceStats::is_dominated`] at `threshold`; the target and
+/// `keep` are never dropped
+pub fn get_dominated_features_with_keep(
+    stats: &[DominanceStats],
+    threshold: f64,
+    target_column: &str,
+    keep: &HashSet<String>,
+) -> Vec<String> {
+    stats
+        .iter()
+        .filter(|s| s.is_dominated(threshold))
+        .filter(|s| s.feature != target_column && !keep.contains(&s.feature))
+        .map(|s| s.feature.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_null_counts_as_its_own_value() {
+        let df = df! {
+            "flag" => [Some(1i32), None, None, Some(1)],
+            "blank" => [None::<i32>, None, None, None],
+        }
+        .unwrap();
+        let stats = analyze_dominance(&df, &[1.0; 4], None).unwrap();
+
+        let blank = stats.iter().find(|s| s.feature == "blank").unwrap();
+        assert!(blank.is_constant());
+        assert_eq!(blank.value, None);
+        // Ties go to the smaller value, and null sorts first
+        let flag = stats.iter().find(|s| s.feature == "flag").unwrap();
+        assert_eq!((flag.value.as_deref(), flag.share), (None, 0.5));
+
+        let dropped = get_dominated_features_with_keep(&stats, 0.4, "target", &HashSet::new());
+        assert!(dropped.is_empty());
+    }
+}
@@ -0,0 +1,226 @@
+//! Excel workbook reader (`.xlsx`, `.xlsm`, `.xls`)
+//!
+//! A worksheet is read as a table: its first used row holds the column names
+//! and every following row is a record. Worksheets carry no declared column
+//! types, so each column's type is inferred from all of its cells:
+//!
+//! - numbers only: `i64` when every value is whole, otherwise `f64`
+//! - booleans only: `bool`
+//! - dates only: `datetime[ms]`
+//! - anything else: `str`, with numbers written as Excel displays them
+//!
+//! Empty cells and error cells (`#N/A`, `#DIV/0!`, ...) load as null, and rows
+//! with no value at all (spacer rows) are skipped.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use calamine::{open_workbook_auto, Data, Range, Reader, Sheets};
+use polars::prelude::*;
+
+/// Names of the worksheets in `path`, in workbook order
+pub fn excel_sheet_names(path: &Path) -> Result<Vec<String>> {
+    Ok(open(path)?.sheet_names())
+}
+
+/// Column names of a worksheet (the first sheet when `sheet` is `None`)
+pub fn get_excel_columns(path: &Path, sheet: Option<&str>) -> Result<Vec<String>> {
+    let range = read_sheet(path, sheet)?;
+    Ok(range.rows().next().map(column_names).unwrap_or_default())
+}
+
+/// Load a worksheet (the first sheet when `sheet` is `None`) into a DataFrame
+pub fn load_excel(path: &Path, sheet: Option<&str>) -> Result<DataFrame> {
+    let range = read_sheet(path, sheet)?;
+    let mut rows = range.rows();
+    let Some(header) = rows.next() else {
+        return Ok(DataFrame::empty());
+    };
+    let names = column_names(header);
+    let records: Vec<&[Data]> = rows
+        .filter(|row| row.iter().any(|cell| !is_null(cell)))
+        .collect();
+
+    let columns = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let cells: Vec<&Data> = records
+                .iter()
+                .map(|row| row.get(i).unwrap_or(&Data::Empty))
+                .collect();
+            build_column(name, &cells)
+        })
+        .collect::<Result<Vec<Column>>>()?;
+
+    DataFrame::new(columns)
+        .with_context(|| format!("Failed to build DataFrame from {}", path.display()))
+}
+
+fn open(path: &Path) -> Result<Sheets<BufReader<File>>> {
+    open_workbook_auto(path)
+        .with_context(|| format!("Failed to open Excel workbook: {}", path.display()))
+}
+
+/// Used cell range of `sheet`, or of the first sheet
+fn read_sheet(path: &Path, sheet: Option<&str>) -> Result<Range<Data>> {
+    let mut workbook = open(path)?;
+    let names = workbook.sheet_names();
+    let name = match sheet {
+        Some(name) => {
+            if !names.iter().any(|n| n == name) {
+                anyhow::bail!(
+                    "Sheet '{}' not found in {}. Available sheets: {}",
+                    name,
+                    path.display(),
+                    names.join(", ")
+                );
+            }
+            name.to_string()
+        }
+        None => names
+            .first()
+            .cloned()
+            .with_context(|| format!("Workbook has no worksheets: {}", path.display()))?,
+    };
+    workbook
+        .worksheet_range(&name)
+        .with_context(|| format!("Failed to read sheet '{}' of {}", name, path.display()))
+}
+
+/// Column names from the header row. Blank headers become `column_<n>`
+/// (1-based) and repeated names get a `_<k>` suffix, as a DataFrame needs
+/// unique names.
+fn column_names(header: &[Data]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::with_capacity(header.len());
+    for (i, cell) in header.iter().enumerate() {
+        let base = match cell_text(cell) {
+            Some(text) if !text.trim().is_empty() => text.trim().to_string(),
+            _ => format!("column_{}", i + 1),
+        };
+        let mut name = base.clone();
+        let mut k = 1;
+        while names.contains(&name) {
+            name = format!("{}_{}", base, k);
+            k += 1;
+        }
+        names.push(name);
+    }
+    names
+}
+
+fn is_null(cell: &Data) -> bool {
+    matches!(cell, Data::Empty | Data::Error(_))
+}
+
+/// Type of a worksheet column, widened as cells are seen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CellKind {
+    Int,
+    Float,
+    Bool,
+    DateTime,
+    Str,
+}
+
+fn cell_kind(cell: &Data) -> Option<CellKind> {
+    match cell {
+        Data::Empty | Data::Error(_) => None,
+        Data::Int(_) => Some(CellKind::Int),
+        Data::Float(v) if v.fract() == 0.0 && v.abs() < 9.0e15 => Some(CellKind::Int),
+        Data::Float(_) => Some(CellKind::Float),
+        Data::Bool(_) => Some(CellKind::Bool),
+        Data::DateTime(_) => Some(CellKind::DateTime),
+        Data::String(_) | Data::DateTimeIso(_) | Data::DurationIso(_) => Some(CellKind::Str),
+    }
+}
+
+fn widen(a: CellKind, b: CellKind) -> CellKind {
+    match (a, b) {
+        _ if a == b => a,
+        (CellKind::Int, CellKind::Float) | (CellKind::Float, CellKind::Int) => CellKind::Float,
+        _ => CellKind::Str,
+    }
+}
+
+/// Text of a cell as Excel displays it; `None` for empty and error cells
+fn cell_text(cell: &Data) -> Option<String> {
+    match cell {
+        Data::Empty | Data::Error(_) => None,
+        Data::Int(v) => Some(v.to_string()),
+        Data::Float(v) if v.fract() == 0.0 && v.abs() < 9.0e15 => Some((*v as i64).to_string()),
+        Data::Float(v) => Some(v.to_string()),
+        Data::Bool(v) => Some(if *v { "TRUE" } else { "FALSE" }.to_string()),
+        Data::DateTime(dt) => Some(match dt.as_datetime() {
+            Some(datetime) => datetime.to_string(),
+            None => dt.as_f64().to_string(),
+        }),
+        Data::String(s) | Data::DateTimeIso(s) | Data::DurationIso(s) => Some(s.clone()),
+    }
+}
+
+fn cell_f64(cell: &Data) -> Option<f64> {
+    match cell {
+        Data::Int(v) => Some(*v as f64),
+        Data::Float(v) => Some(*v),
+        _ => None,
+    }
+}
+
+fn build_column(name: &str, cells: &[&Data]) -> Result<Column> {
+    let kind = cells
+        .iter()
+        .filter_map(|cell| cell_kind(cell))
+        .reduce(widen)
+        // An all-empty column has no type to infer; keep it as text like CSV
+        .unwrap_or(CellKind::Str);
+
+    let name = PlSmallStr::from(name);
+    let series = match kind {
+        CellKind::Int => Series::new(
+            name,
+            cells
+                .iter()
+                .map(|cell| cell_f64(cell).map(|v| v as i64))
+                .collect::<Vec<Option<i64>>>(),
+        ),
+        CellKind::Float => Series::new(
+            name,
+            cells
+                .iter()
+                .map(|cell| cell_f64(cell))
+                .collect::<Vec<Option<f64>>>(),
+        ),
+        CellKind::Bool => Series::new(
+            name,
+            cells
+                .iter()
+                .map(|cell| match cell {
+                    Data::Bool(v) => Some(*v),
+                    _ => None,
+                })
+                .collect::<Vec<Option<bool>>>(),
+        ),
+        CellKind::DateTime => Series::new(
+            name,
+            cells
+                .iter()
+                .map(|cell| match cell {
+                    Data::DateTime(dt) => dt.as_datetime().map(|d| d.and_utc().timestamp_millis()),
+                    _ => None,
+                })
+                .collect::<Vec<Option<i64>>>(),
+        )
+        .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
+        CellKind::Str => Series::new(
+            name,
+            cells
+                .iter()
+                .map(|cell| cell_text(cell))
+                .collect::<Vec<Option<String>>>(),
+        ),
+    };
+    Ok(series.into_column())
+}
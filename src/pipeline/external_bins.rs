@@ -0,0 +1,631 @@
+//! Externally defined bins (`--bins-from`)
+//!
+//! A model team often re-runs the reduction on a new sample with the bins it
+//! already agreed on, or compares a vendor's binning with its own. With
+//! `--bins-from` the Gini step skips the search for splits: every feature with
+//! a definition in the file is binned exactly as defined and only the counts,
+//! WoE, IV and Gini are computed on the data.
+//!
+//! Two files are accepted, both written by lo-phi:
+//!
+//! - a scoring spec (`{stem}_scoring_spec.json`)
+//! - a Gini analysis export (`{stem}_gini_analysis.json`)
+//!
+//! Numeric values are binned by the rule of the scoring spec: nulls go to the
+//! missing bin, listed special values to their own bins, and any other value
+//! to the first bin whose upper bound is open or above it, else the last bin.
+//! Categories outside every group go to the `OTHER` group, which is added
+//! when the file has none. Features without a definition are not scored.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use polars::prelude::*;
+use rayon::prelude::*;
+use serde::Deserialize;
+
+use super::iv::{
+    annotate_adjacent_chi_square, binary_target_values, calculate_gini_on_bins_with_missing,
+    calculate_gini_on_categories_with_missing, calculate_woe_iv, ks_and_auc, CategoricalWoeBin,
+    FeatureType, IvAnalysis, MissingBin, SpecialBin, WoeBin,
+};
+use super::target::TargetMapping;
+
+/// Category group that takes the values no other group lists
+const OTHER_CATEGORY: &str = "OTHER";
+
+/// Bins of one feature
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureBins {
+    pub feature_type: FeatureType,
+    /// `(lower_bound, upper_bound)` of each numeric bin, sorted; open bounds
+    /// are infinite
+    pub bins: Vec<(f64, f64)>,
+    /// Values binned on their own, checked before the bins
+    pub special_values: Vec<f64>,
+    /// Values of each category group
+    pub categories: Vec<Vec<String>>,
+}
+
+/// Bins of every feature in a `--bins-from` file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalBins {
+    /// File the bins were read from
+    pub path: PathBuf,
+    pub features: BTreeMap<String, FeatureBins>,
+}
+
+// Fields shared by the scoring spec and the Gini export; the export's names
+// are accepted as aliases and everything else is ignored.
+#[derive(Deserialize)]
+struct BinsFile {
+    features: Vec<FeatureEntry>,
+}
+
+#[derive(Deserialize)]
+struct FeatureEntry {
+    #[serde(alias = "feature_name")]
+    feature: String,
+    feature_type: FeatureType,
+    #[serde(default)]
+    bins: Vec<BinEntry>,
+    #[serde(default, alias = "special_bins")]
+    special_values: Vec<SpecialEntry>,
+    #[serde(default)]
+    categories: Vec<CategoryEntry>,
+}
+
+#[derive(Deserialize)]
+struct BinEntry {
+    #[serde(default)]
+    lower_bound: Option<f64>,
+    #[serde(default)]
+    upper_bound: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct SpecialEntry {
+    value: f64,
+}
+
+#[derive(Deserialize)]
+struct CategoryEntry {
+    #[serde(default)]
+    category: String,
+    #[serde(default, alias = "values")]
+    categories: Vec<String>,
+}
+
+impl ExternalBins {
+    /// Read the bins from a scoring spec or Gini analysis export.
+    ///
+    /// A feature listed more than once, a numeric feature without bins, a
+    /// categorical feature without groups and numeric bins whose upper bounds
+    /// are not increasing are errors.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read bins file: {}", path.display()))?;
+        Self::parse(&content, path)
+            .with_context(|| format!("Invalid bins file: {}", path.display()))
+    }
+
+    fn parse(content: &str, path: &Path) -> Result<Self> {
+        let file: BinsFile = serde_json::from_str(content)
+            .context("Expected a scoring spec or Gini analysis JSON export")?;
+
+        let mut features = BTreeMap::new();
+        for entry in file.features {
+            let bins = feature_bins(&entry)
+                .with_context(|| format!("Bins of feature '{}'", entry.feature))?;
+            if features.insert(entry.feature.clone(), bins).is_some() {
+                bail!("Feature '{}' is defined more than once", entry.feature);
+            }
+        }
+        Ok(Self {
+            path: path.to_path_buf(),
+            features,
+        })
+    }
+
+    pub fn for_feature(&self, feature: &str) -> Option<&FeatureBins> {
+        self.features.get(feature)
+    }
+}
+
+fn feature_bins(entry: &FeatureEntry) -> Result<FeatureBins> {
+    let bins: Vec<(f64, f64)> = entry
+        .bins
+        .iter()
+        .map(|b| {
+            (
+                b.lower_bound.unwrap_or(f64::NEG_INFINITY),
+                b.upper_bound.unwrap_or(f64::INFINITY),
+            )
+        })
+        .collect();
+    let categories: Vec<Vec<String>> = entry
+        .categories
+        .iter()
+        .map(|c| {
+            let mut values = c.categories.clone();
+            if !c.category.is_empty() {
+                values.insert(0, c.category.clone());
+            }
+            values
+        })
+        .filter(|values| !values.is_empty())
+        .collect();
+
+    match entry.feature_type {
+        FeatureType::Numeric => {
+            if bins.is_empty() {
+                bail!("A numeric feature needs at least one bin");
+            }
+            // Every bin but the last needs an upper bound above the previous one
+            let inner = &bins[..bins.len() - 1];
+            if inner.iter().any(|(_, upper)| !upper.is_finite())
+                || bins.windows(2).any(|w| w[1].1 <= w[0].1)
+            {
+                bail!("Bin upper bounds must be increasing and only the last may be open");
+            }
+        }
+        FeatureType::Categorical => {
+            if categories.is_empty() {
+                bail!("A categorical feature needs at least one category group");
+            }
+        }
+    }
+
+    Ok(FeatureBins {
+        feature_type: entry.feature_type,
+        bins,
+        special_values: entry.special_values.iter().map(|s| s.value).collect(),
+        categories,
+    })
+}
+
+/// IV analysis of every feature with a definition in `external`, binned as
+/// defined, sorted by IV descending.
+///
+/// Features without a definition, and definitions whose type does not match
+/// the column (numeric bins for a text column or the reverse), are skipped
+/// with a warning.
+pub fn analyze_features_with_bins(
+    df: &DataFrame,
+    target: &str,
+    target_mapping: Option<&TargetMapping>,
+    weights: &[f64],
+    weight_column: Option<&str>,
+    external: &ExternalBins,
+) -> Result<Vec<IvAnalysis>> {
+    if df.height() == 0 {
+        return Ok(Vec::new());
+    }
+    let target_values = binary_target_values(df, target, target_mapping)?;
+
+    let mut undefined = Vec::new();
+    let mut jobs = Vec::new();
+    for col in df.get_columns() {
+        let name = col.name().as_str();
+        if name == target || Some(name) == weight_column {
+            continue;
+        }
+        let column_type = if col.dtype().is_primitive_numeric() {
+            FeatureType::Numeric
+        } else if matches!(col.dtype(), DataType::String | DataType::Categorical(_, _)) {
+            FeatureType::Categorical
+        } else {
+            continue;
+        };
+        match external.for_feature(name) {
+            Some(bins) if bins.feature_type == column_type => jobs.push((name, bins)),
+            Some(_) => tracing::warn!(
+                "Skipped feature '{}': its bins in {} are for a {:?} feature",
+                name,
+                external.path.display(),
+                column_type
+            ),
+            None => undefined.push(name),
+        }
+    }
+    if !undefined.is_empty() {
+        tracing::warn!(
+            "{} feature(s) have no bins in {} and are not scored: {}",
+            undefined.len(),
+            external.path.display(),
+            undefined.join(", ")
+        );
+    }
+
+    let results: Vec<(&str, Result<IvAnalysis>)> = jobs
+        .par_iter()
+        .map(|&(name, bins)| {
+            let result = match bins.feature_type {
+                FeatureType::Numeric => score_numeric(df, name, &target_values, weights, bins),
+                FeatureType::Categorical => {
+                    score_categorical(df, name, &target_values, weights, bins)
+                }
+            };
+            (name, result)
+        })
+        .collect();
+
+    let mut analyses = Vec::with_capacity(results.len());
+    for (name, result) in results {
+        match result {
+            Ok(analysis) => analyses.push(analysis),
+            Err(e) => tracing::warn!("Skipped feature '{}': {}", name, e),
+        }
+    }
+    analyses.sort_by(|a, b| b.iv.partial_cmp(&a.iv).unwrap_or(std::cmp::Ordering::Equal));
+    for analysis in &mut analyses {
+        (analysis.ks, analysis.auc) = ks_and_auc(analysis);
+    }
+    Ok(analyses)
+}
+
+/// Weighted events and non-events of one bin
+#[derive(Clone, Copy, Default)]
+struct Counts {
+    events: f64,
+    non_events: f64,
+}
+
+impl Counts {
+    fn add(&mut self, target: i32, weight: f64) {
+        if target == 1 {
+            self.events += weight;
+        } else {
+            self.non_events += weight;
+        }
+    }
+
+    fn count(&self) -> f64 {
+        self.events + self.non_events
+    }
+
+    fn event_rate(&self) -> f64 {
+        if self.count() > 0.0 {
+            self.events / self.count()
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Totals over every bin; fails when the target does not vary
+fn totals<'a>(name: &str, counts: impl Iterator<Item = &'a Counts>) -> Result<(f64, f64)> {
+    let (events, non_events) =
+        counts.fold((0.0, 0.0), |(e, n), c| (e + c.events, n + c.non_events));
+    if events <= 0.0 || non_events <= 0.0 {
+        bail!(
+            "Feature '{}' has no variation in target (all 0s or all 1s)",
+            name
+        );
+    }
+    Ok((events, non_events))
+}
+
+fn missing_bin(missing: Counts, total_events: f64, total_non_events: f64) -> Option<MissingBin> {
+    (missing.count() > 0.0).then(|| {
+        let (woe, iv_contribution) = calculate_woe_iv(
+            missing.events,
+            missing.non_events,
+            total_events,
+            total_non_events,
+        );
+        MissingBin {
+            events: missing.events,
+            non_events: missing.non_events,
+            woe,
+            iv_contribution,
+            count: missing.count(),
+            population_pct: missing.count() / (total_events + total_non_events) * 100.0,
+            event_rate: missing.event_rate(),
+            merged_into: None,
+            imputed_value: None,
+        }
+    })
+}
+
+fn score_numeric(
+    df: &DataFrame,
+    name: &str,
+    target_values: &[Option<i32>],
+    weights: &[f64],
+    bins: &FeatureBins,
+) -> Result<IvAnalysis> {
+    let float_col = df.column(name)?.cast(&DataType::Float64)?;
+    let values = float_col.f64()?;
+
+    let mut bin_counts = vec![Counts::default(); bins.bins.len()];
+    let mut special_counts = vec![Counts::default(); bins.special_values.len()];
+    let mut missing = Counts::default();
+    for ((value, target), &w) in values.iter().zip(target_values).zip(weights) {
+        let Some(target) = *target else { continue };
+        let Some(value) = value else {
+            missing.add(target, w);
+            continue;
+        };
+        if let Some(i) = bins.special_values.iter().position(|&s| s == value) {
+            special_counts[i].add(target, w);
+        } else {
+            let i = bins
+                .bins
+                .iter()
+                .position(|&(_, upper)| value < upper)
+                .unwrap_or(bins.bins.len() - 1);
+            bin_counts[i].add(target, w);
+        }
+    }
+
+    let all = bin_counts.iter().chain(&special_counts).chain([&missing]);
+    let (total_events, total_non_events) = totals(name, all)?;
+    let total = total_events + total_non_events;
+
+    let mut woe_bins: Vec<WoeBin> = bins
+        .bins
+        .iter()
+        .zip(&bin_counts)
+        .map(|(&(lower_bound, upper_bound), c)| {
+            let (woe, iv_contribution) =
+                calculate_woe_iv(c.events, c.non_events, total_events, total_non_events);
+            WoeBin {
+                lower_bound,
+                upper_bound,
+                events: c.events,
+                non_events: c.non_events,
+                woe,
+                iv_contribution,
+                count: c.count(),
+                population_pct: c.count() / total * 100.0,
+                event_rate: c.event_rate(),
+                chi_square: None,
+                chi_square_p_value: None,
+            }
+        })
+        .collect();
+    annotate_adjacent_chi_square(&mut woe_bins);
+
+    let special_bins: Vec<SpecialBin> = bins
+        .special_values
+        .iter()
+        .zip(&special_counts)
+        .filter(|(_, c)| c.count() > 0.0)
+        .map(|(&value, c)| {
+            let (woe, iv_contribution) =
+                calculate_woe_iv(c.events, c.non_events, total_events, total_non_events);
+            SpecialBin {
+                value,
+                events: c.events,
+                non_events: c.non_events,
+                woe,
+                iv_contribution,
+                count: c.count(),
+                population_pct: c.count() / total * 100.0,
+                event_rate: c.event_rate(),
+            }
+        })
+        .collect();
+    let missing_bin = missing_bin(missing, total_events, total_non_events);
+
+    let iv = woe_bins.iter().map(|b| b.iv_contribution).sum::<f64>()
+        + special_bins.iter().map(|b| b.iv_contribution).sum::<f64>()
+        + missing_bin.as_ref().map_or(0.0, |m| m.iv_contribution);
+    let gini = calculate_gini_on_bins_with_missing(&woe_bins, &missing_bin, &special_bins);
+
+    Ok(IvAnalysis {
+        feature_name: name.to_string(),
+        feature_type: FeatureType::Numeric,
+        bins: woe_bins,
+        categories: Vec::new(),
+        missing_bin,
+        special_bins,
+        iv,
+        gini,
+        ks: 0.0,
+        auc: 0.5,
+        mutual_info: None,
+        monotonicity: None,
+        solver_status: None,
+    })
+}
+
+fn score_categorical(
+    df: &DataFrame,
+    name: &str,
+    target_values: &[Option<i32>],
+    weights: &[f64],
+    bins: &FeatureBins,
+) -> Result<IvAnalysis> {
+    let string_col = df.column(name)?.cast(&DataType::String)?;
+    let values = string_col.str()?;
+
+    let mut groups = bins.categories.clone();
+    let mut group_of: HashMap<&str, usize> = HashMap::new();
+    for (i, group) in bins.categories.iter().enumerate() {
+        for value in group {
+            group_of.entry(value.as_str()).or_insert(i);
+        }
+    }
+    let other = group_of.get(OTHER_CATEGORY).copied();
+
+    let mut group_counts = vec![Counts::default(); groups.len()];
+    let mut unseen = Counts::default();
+    let mut missing = Counts::default();
+    for ((value, target), &w) in values.iter().zip(target_values).zip(weights) {
+        let Some(target) = *target else { continue };
+        match value.map(|v| group_of.get(v).copied().or(other)) {
+            None => missing.add(target, w),
+            Some(Some(i)) => group_counts[i].add(target, w),
+            Some(None) => unseen.add(target, w),
+        }
+    }
+    if unseen.count() > 0.0 {
+        groups.push(vec![OTHER_CATEGORY.to_string()]);
+        group_counts.push(unseen);
+    }
+
+    let all = group_counts.iter().chain([&missing]);
+    let (total_events, total_non_events) = totals(name, all)?;
+    let total = total_events + total_non_events;
+
+    let categories: Vec<CategoricalWoeBin> = groups
+        .into_iter()
+        .zip(&group_counts)
+        .map(|(mut values, c)| {
+            let (woe, iv_contribution) =
+                calculate_woe_iv(c.events, c.non_events, total_events, total_non_events);
+            // A lone category keeps the single-category form of the analysis
+            let (category, categories) = if values.len() == 1 {
+                (values.remove(0), Vec::new())
+            } else {
+                (String::new(), values)
+            };
+            CategoricalWoeBin {
+                category,
+                categories,
+                events: c.events,
+                non_events: c.non_events,
+                woe,
+                iv_contribution,
+                count: c.count(),
+                population_pct: c.count() / total * 100.0,
+                event_rate: c.event_rate(),
+            }
+        })
+        .collect();
+    let missing_bin = missing_bin(missing, total_events, total_non_events);
+
+    let iv = categories.iter().map(|c| c.iv_contribution).sum::<f64>()
+        + missing_bin.as_ref().map_or(0.0, |m| m.iv_contribution);
+    let gini = calculate_gini_on_categories_with_missing(
+        &categories,
+        &missing_bin,
+        total_events,
+        total_non_events,
+    );
+
+    Ok(IvAnalysis {
+        feature_name: name.to_string(),
+        feature_type: FeatureType::Categorical,
+        bins: Vec::new(),
+        categories,
+        missing_bin,
+        special_bins: Vec::new(),
+        iv,
+        gini,
+        ks: 0.0,
+        auc: 0.5,
+        mutual_info: None,
+        monotonicity: None,
+        solver_status: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r#"{
+        "spec_version": 1,
+        "features": [
+            {
+                "feature": "age",
+                "feature_type": "Numeric",
+                "missing_woe": 0.0,
+                "special_values": [{ "value": -1.0, "woe": 0.3 }],
+                "bins": [
+                    { "lower_bound": null, "upper_bound": 30.0, "woe": -0.2 },
+                    { "lower_bound": 30.0, "upper_bound": null, "woe": 0.4 }
+                ],
+                "default_woe": 0.0
+            },
+            {
+                "feature": "region",
+                "feature_type": "Categorical",
+                "missing_woe": 0.0,
+                "categories": [{ "values": ["north"], "woe": 0.1 }],
+                "default_woe": 0.0
+            }
+        ]
+    }"#;
+
+    fn load(content: &str) -> Result<ExternalBins> {
+        ExternalBins::parse(content, Path::new("bins.json"))
+    }
+
+    #[test]
+    fn test_parse_scoring_spec() {
+        let external = load(SPEC).unwrap();
+        let age = external.for_feature("age").unwrap();
+        assert_eq!(age.bins, [(f64::NEG_INFINITY, 30.0), (30.0, f64::INFINITY)]);
+        assert_eq!(age.special_values, [-1.0]);
+        let region = external.for_feature("region").unwrap();
+        assert_eq!(region.categories, [vec!["north".to_string()]]);
+    }
+
+    #[test]
+    fn test_parse_gini_export_names() {
+        let export = r#"{
+            "metadata": {},
+            "features": [{
+                "feature_name": "region",
+                "feature_type": "Categorical",
+                "categories": [
+                    { "category": "north", "woe": 0.1 },
+                    { "categories": ["south", "OTHER"], "woe": -0.1 }
+                ],
+                "iv": 0.1,
+                "gini": 0.1,
+                "dropped": false
+            }]
+        }"#;
+        let external = load(export).unwrap();
+        let region = external.for_feature("region").unwrap();
+        assert_eq!(region.categories[1], ["south", "OTHER"]);
+    }
+
+    #[test]
+    fn test_rejects_invalid_bins() {
+        let unsorted = SPEC.replace("\"upper_bound\": 30.0", "\"upper_bound\": null");
+        assert!(load(&unsorted).is_err());
+        let duplicate = SPEC.replace("\"region\"", "\"age\"");
+        assert!(load(&duplicate).is_err());
+        assert!(load("{\"rows\": []}").is_err());
+    }
+
+    #[test]
+    fn test_scores_rows_into_the_defined_bins() {
+        let df = df! {
+            "target" => [1, 0, 1, 0, 0, 1, 0, 0],
+            "age" => [Some(20.0), Some(25.0), Some(40.0), Some(50.0), None, Some(-1.0), Some(30.0), Some(60.0)],
+            "region" => [Some("north"), Some("north"), Some("south"), Some("east"), Some("north"), None, Some("south"), Some("north")],
+            "other" => [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+        }
+        .unwrap();
+        let external = load(SPEC).unwrap();
+        let analyses =
+            analyze_features_with_bins(&df, "target", None, &[1.0; 8], None, &external).unwrap();
+        assert_eq!(analyses.len(), 2);
+
+        let age = analyses.iter().find(|a| a.feature_name == "age").unwrap();
+        let counts: Vec<(f64, f64)> = age.bins.iter().map(|b| (b.events, b.non_events)).collect();
+        // 30 falls into the upper bin, -1 into its special bin, the null into the missing bin
+        assert_eq!(counts, [(1.0, 1.0), (1.0, 3.0)]);
+        assert_eq!(age.special_bins[0].events, 1.0);
+        assert_eq!(age.missing_bin.as_ref().unwrap().non_events, 1.0);
+        assert!(age.bins[1].chi_square.is_some());
+
+        let region = analyses
+            .iter()
+            .find(|a| a.feature_name == "region")
+            .unwrap();
+        // Values outside every group form an added OTHER group
+        assert_eq!(region.categories[0].category, "north");
+        assert_eq!(region.categories[1].category, "OTHER");
+        assert_eq!(region.categories[1].count, 3.0);
+        assert_eq!(region.missing_bin.as_ref().unwrap().events, 1.0);
+    }
+}
@@ -0,0 +1,157 @@
+//! Screening of the features for proxies of protected attributes
+//!
+//! A model that never sees a protected attribute such as gender or ethnicity
+//! can still discriminate through a feature that encodes it, e.g. a postcode
+//! or a first name. With `--protected-columns` every feature left after the
+//! Gini stage is compared with each protected column: by its association
+//! (Cramér's V for a categorical feature, η for a numeric one) and by the IV
+//! with which it predicts the protected class. [`assess_proxy`] flags the
+//! features above either threshold as likely proxies.
+
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::correlation::{compute_cramers_v, compute_eta, AssociationMeasure};
+
+/// IV predicting the protected class above which a feature is flagged; 0.1 is
+/// the usual lower bound of a medium predictor
+pub const PROXY_IV_THRESHOLD: f64 = 0.1;
+
+/// Settings of a proxy screen (`--protected-columns`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct FairnessCheck {
+    /// Columns holding the protected attributes
+    pub columns: Vec<String>,
+    /// Association above which a feature is flagged (`--proxy-threshold`)
+    pub threshold: f64,
+}
+
+/// How strongly one feature tracks one protected attribute
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProxyAssociation {
+    pub feature: String,
+    /// Protected column compared with
+    pub protected: String,
+    /// Cramér's V for a categorical feature, η for a numeric one
+    pub measure: AssociationMeasure,
+    /// `None` when undefined, e.g. no row has both values
+    pub association: Option<f64>,
+    /// Highest IV of the feature predicting one protected class against the
+    /// rest; `None` when the feature could not be scored
+    pub iv: Option<f64>,
+    /// `association` is above the association threshold or `iv` above
+    /// [`PROXY_IV_THRESHOLD`]
+    pub proxy: bool,
+}
+
+/// Proxy screen of the features left after the Gini stage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProxyScreen {
+    pub columns: Vec<String>,
+    pub association_threshold: f64,
+    pub iv_threshold: f64,
+    /// One entry per feature and protected column, by protected column
+    pub associations: Vec<ProxyAssociation>,
+}
+
+impl ProxyScreen {
+    /// Features flagged against at least one protected column, in first-seen
+    /// order
+    pub fn proxies(&self) -> Vec<&str> {
+        let mut proxies: Vec<&str> = Vec::new();
+        for entry in self.associations.iter().filter(|a| a.proxy) {
+            if !proxies.contains(&entry.feature.as_str()) {
+                proxies.push(&entry.feature);
+            }
+        }
+        proxies
+    }
+}
+
+/// Association of `feature` with `protected` (cast to text); η when the
+/// feature is numeric, Cramér's V otherwise.
+pub fn protected_association(
+    feature: &Column,
+    protected: &Column,
+    weights: &[f64],
+) -> PolarsResult<(AssociationMeasure, Option<f64>)> {
+    if feature.dtype().is_primitive_numeric() {
+        let numeric = feature.cast(&DataType::Float64)?;
+        Ok((
+            AssociationMeasure::Eta,
+            compute_eta(protected, &numeric, Some(weights)),
+        ))
+    } else {
+        let text = feature.cast(&DataType::String)?;
+        Ok((
+            AssociationMeasure::CramersV,
+            compute_cramers_v(&text, protected, Some(weights)),
+        ))
+    }
+}
+
+/// Flag the pair when its association exceeds `threshold` or its IV exceeds
+/// [`PROXY_IV_THRESHOLD`].
+pub fn assess_proxy(
+    feature: &str,
+    protected: &str,
+    (measure, association): (AssociationMeasure, Option<f64>),
+    iv: Option<f64>,
+    threshold: f64,
+) -> ProxyAssociation {
+    let proxy =
+        association.is_some_and(|a| a > threshold) || iv.is_some_and(|iv| iv > PROXY_IV_THRESHOLD);
+    ProxyAssociation {
+        feature: feature.to_string(),
+        protected: protected.to_string(),
+        measure,
+        association,
+        iv,
+        proxy,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_proxy_uses_eta() {
+        let gender = Column::new("gender".into(), ["f", "f", "m", "m", "f", "m"]);
+        let height = Column::new("height".into(), [160.0, 165.0, 180.0, 185.0, 162.0, 178.0]);
+        let weights = [1.0; 6];
+
+        let (measure, association) = protected_association(&height, &gender, &weights).unwrap();
+        assert_eq!(measure, AssociationMeasure::Eta);
+        let screened = assess_proxy("height", "gender", (measure, association), None, 0.3);
+        assert!(association.unwrap() > 0.9);
+        assert!(screened.proxy);
+
+        let shoe = Column::new(
+            "colour".into(),
+            ["red", "blue", "red", "blue", "blue", "red"],
+        );
+        let (measure, association) = protected_association(&shoe, &gender, &weights).unwrap();
+        assert_eq!(measure, AssociationMeasure::CramersV);
+        assert!(association.unwrap() < 0.3);
+    }
+
+    #[test]
+    fn test_iv_alone_flags_a_proxy() {
+        let weak = (AssociationMeasure::Eta, Some(0.05));
+        assert!(!assess_proxy("a", "gender", weak, Some(0.02), 0.3).proxy);
+        assert!(assess_proxy("a", "gender", weak, Some(0.25), 0.3).proxy);
+
+        let screen = ProxyScreen {
+            columns: vec!["gender".into(), "ethnicity".into()],
+            association_threshold: 0.3,
+            iv_threshold: PROXY_IV_THRESHOLD,
+            associations: vec![
+                assess_proxy("a", "gender", weak, Some(0.25), 0.3),
+                assess_proxy("b", "gender", weak, None, 0.3),
+                assess_proxy("a", "ethnicity", weak, Some(0.25), 0.3),
+            ],
+        };
+        assert_eq!(screen.proxies(), ["a"]);
+    }
+}
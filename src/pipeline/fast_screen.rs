@@ -0,0 +1,74 @@
+//! Fast screen mode (`--fast`)
+//!
+//! A first pass over a new extract: every feature gets plain decile bins with
+//! no solver and no merging, and correlations are computed on a systematic
+//! row sample. The drops are indicative only; rerun without `--fast` for the
+//! final selection.
+
+use anyhow::Result;
+use polars::prelude::*;
+
+/// Quantile bins per feature (deciles); prebins and final bins are the same
+pub const FAST_SCREEN_BINS: usize = 10;
+
+/// Rows used for correlation analysis; larger datasets are sampled down to this
+pub const FAST_SCREEN_CORRELATION_ROWS: usize = 50_000;
+
+/// Number of rows [`fast_screen_sample`] keeps out of `rows`
+pub fn fast_screen_sample_rows(rows: usize) -> usize {
+    rows.div_ceil(sample_step(rows))
+}
+
+/// Every k-th row of `df` and its weight, so that at most
+/// [`FAST_SCREEN_CORRELATION_ROWS`] remain. Returns `None` when the data is
+/// already small enough.
+pub fn fast_screen_sample(
+    df: &DataFrame,
+    weights: &[f64],
+) -> Result<Option<(DataFrame, Vec<f64>)>> {
+    let step = sample_step(df.height());
+    if step == 1 {
+        return Ok(None);
+    }
+
+    let indices: Vec<IdxSize> = (0..df.height())
+        .step_by(step)
+        .map(|i| i as IdxSize)
+        .collect();
+    let sampled_weights = indices.iter().map(|&i| weights[i as usize]).collect();
+    let sampled = df.take(&IdxCa::from_vec("idx".into(), indices))?;
+    Ok(Some((sampled, sampled_weights)))
+}
+
+fn sample_step(rows: usize) -> usize {
+    rows.div_ceil(FAST_SCREEN_CORRELATION_ROWS).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_data_is_not_sampled() {
+        let df = df! { "x" => [1.0f64, 2.0, 3.0] }.unwrap();
+        assert!(fast_screen_sample(&df, &[1.0; 3]).unwrap().is_none());
+        assert_eq!(fast_screen_sample_rows(3), 3);
+    }
+
+    #[test]
+    fn test_sample_takes_every_kth_row_with_weights() {
+        let n = FAST_SCREEN_CORRELATION_ROWS * 2 + 1;
+        let x: Vec<f64> = (0..n).map(|i| i as f64).collect();
+        let weights: Vec<f64> = (0..n).map(|i| (i % 7) as f64).collect();
+        let df = df! { "x" => x }.unwrap();
+
+        let (sampled, sampled_weights) = fast_screen_sample(&df, &weights).unwrap().unwrap();
+        assert_eq!(sampled.height(), fast_screen_sample_rows(n));
+        assert!(sampled.height() <= FAST_SCREEN_CORRELATION_ROWS);
+
+        let values = sampled.column("x").unwrap().f64().unwrap();
+        assert_eq!(values.get(1), Some(3.0));
+        assert_eq!(sampled_weights[1], 3.0);
+        assert_eq!(sampled_weights.len(), sampled.height());
+    }
+}
@@ -0,0 +1,243 @@
+//! Recommended imputation of the retained features
+//!
+//! Training code downstream of a reduction still has to fill the nulls of
+//! the features it keeps. [`recommend_imputation`] picks one strategy per
+//! feature from its type, its missing share and, for a binary target, the
+//! WoE of its missing bin:
+//!
+//! - `missing_bin` when the missing rows form a bin of their own whose WoE
+//!   shows that being missing predicts the target; the nulls are best kept as
+//!   a separate level or indicator
+//! - `median` for a numeric feature otherwise
+//! - `constant` ([`MISSING_CATEGORY`]) for a categorical feature with at
+//!   least [`MISSING_CATEGORY_SHARE`] missing, `mode` below it
+//!
+//! The plan is written to `{stem}_imputation_plan.json` in the report zip.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::distribution::weighted_quantile;
+use super::iv::{FeatureType, IvAnalysis};
+
+/// Absolute WoE of a separate missing bin from which missingness counts as
+/// predictive
+pub const MISSING_WOE_SIGNAL: f64 = 0.1;
+
+/// Missing share from which a categorical feature's nulls get a category of
+/// their own rather than the mode
+pub const MISSING_CATEGORY_SHARE: f64 = 0.05;
+
+/// Category the nulls of a categorical feature are filled with under
+/// [`ImputationStrategy::Constant`]
+pub const MISSING_CATEGORY: &str = "MISSING";
+
+/// How the nulls of a feature should be filled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImputationStrategy {
+    /// Weighted median of the non-null values
+    Median,
+    /// Most frequent category by weight
+    Mode,
+    /// Keep the nulls as a bin (or indicator) of their own
+    MissingBin,
+    /// A fixed value, [`MISSING_CATEGORY`] for a categorical feature
+    Constant,
+}
+
+/// Fill value of a feature: a number for a numeric feature, text otherwise
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImputedValue {
+    Number(f64),
+    Text(String),
+}
+
+/// Recommended imputation of one feature
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureImputation {
+    pub feature: String,
+    pub feature_type: FeatureType,
+    /// Weighted missing ratio from the missing-value stage
+    pub missing_ratio: f64,
+    pub strategy: ImputationStrategy,
+    /// Value to fill the nulls with; `None` under `missing_bin` and for a
+    /// feature without a non-null value
+    pub value: Option<ImputedValue>,
+    /// WoE of the separate missing bin (binary target)
+    pub missing_woe: Option<f64>,
+    /// Why the strategy was chosen
+    pub reason: String,
+}
+
+/// Recommended imputation of every retained feature (`{stem}_imputation_plan.json`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImputationPlan {
+    pub target: String,
+    pub features: Vec<FeatureImputation>,
+}
+
+/// Recommend how to fill the nulls of `column`. `analysis` is the feature's
+/// Gini-stage binning; `None` for a continuous target.
+pub fn recommend_imputation(
+    column: &Column,
+    weights: &[f64],
+    missing_ratio: f64,
+    analysis: Option<&IvAnalysis>,
+) -> PolarsResult<FeatureImputation> {
+    let feature_type = if column.dtype().is_primitive_numeric() {
+        FeatureType::Numeric
+    } else {
+        FeatureType::Categorical
+    };
+    let missing_woe = analysis
+        .and_then(|a| a.missing_bin.as_ref())
+        .filter(|bin| bin.is_separate())
+        .map(|bin| bin.woe);
+
+    let (strategy, value, reason) = match (missing_woe, feature_type) {
+        (Some(woe), _) if missing_ratio > 0.0 && woe.abs() >= MISSING_WOE_SIGNAL => (
+            ImputationStrategy::MissingBin,
+            None,
+            format!(
+                "missing rows have a WoE of {:.3}; being missing is predictive",
+                woe
+            ),
+        ),
+        (_, FeatureType::Numeric) => (
+            ImputationStrategy::Median,
+            weighted_median(column, weights)?.map(ImputedValue::Number),
+            "numeric; the median is robust to outliers".to_string(),
+        ),
+        (_, FeatureType::Categorical) if missing_ratio >= MISSING_CATEGORY_SHARE => (
+            ImputationStrategy::Constant,
+            Some(ImputedValue::Text(MISSING_CATEGORY.to_string())),
+            format!(
+                "categorical with {:.1}% missing; the nulls get a category of their own",
+                missing_ratio * 100.0
+            ),
+        ),
+        (_, FeatureType::Categorical) => (
+            ImputationStrategy::Mode,
+            weighted_mode(column, weights)?.map(ImputedValue::Text),
+            "categorical with few missing values".to_string(),
+        ),
+    };
+
+    Ok(FeatureImputation {
+        feature: column.name().to_string(),
+        feature_type,
+        missing_ratio,
+        strategy,
+        value,
+        missing_woe,
+        reason,
+    })
+}
+
+/// Imputation plan of the `features` of `df`. Missing ratios come from the
+/// missing-value stage; features without a Gini-stage analysis are planned
+/// without a missing-bin WoE.
+pub fn build_imputation_plan(
+    df: &DataFrame,
+    features: &[String],
+    weights: &[f64],
+    missing_ratios: &[(String, f64)],
+    analyses: &[IvAnalysis],
+    target: &str,
+) -> Result<ImputationPlan> {
+    let ratios: HashMap<&str, f64> = missing_ratios
+        .iter()
+        .map(|(feature, ratio)| (feature.as_str(), *ratio))
+        .collect();
+    let analyses: HashMap<&str, &IvAnalysis> = analyses
+        .iter()
+        .map(|a| (a.feature_name.as_str(), a))
+        .collect();
+
+    let mut plan = Vec::with_capacity(features.len());
+    for feature in features {
+        let column = df.column(feature)?;
+        plan.push(recommend_imputation(
+            column,
+            weights,
+            ratios.get(feature.as_str()).copied().unwrap_or(0.0),
+            analyses.get(feature.as_str()).copied(),
+        )?);
+    }
+    Ok(ImputationPlan {
+        target: target.to_string(),
+        features: plan,
+    })
+}
+
+fn weighted_median(column: &Column, weights: &[f64]) -> PolarsResult<Option<f64>> {
+    let values = column.cast(&DataType::Float64)?;
+    let mut sorted: Vec<(f64, f64)> = values
+        .f64()?
+        .iter()
+        .zip(weights)
+        .filter_map(|(v, &w)| v.filter(|v| v.is_finite()).map(|v| (v, w.max(0.0))))
+        .collect();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let total: f64 = sorted.iter().map(|&(_, w)| w).sum();
+    Ok((total > 0.0).then(|| weighted_quantile(&sorted, total, 0.5)))
+}
+
+/// Category with the most weight; ties go to the first in sort order
+fn weighted_mode(column: &Column, weights: &[f64]) -> PolarsResult<Option<String>> {
+    let values = column.cast(&DataType::String)?;
+    let mut totals: HashMap<&str, f64> = HashMap::new();
+    for (value, &w) in values.str()?.iter().zip(weights) {
+        if let Some(value) = value {
+            *totals.entry(value).or_insert(0.0) += w;
+        }
+    }
+    Ok(totals
+        .into_iter()
+        .max_by(|a, b| a.1.total_cmp(&b.1).then_with(|| b.0.cmp(a.0)))
+        .map(|(value, _)| value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strategy_follows_type_and_missing_share() {
+        let income = Column::new(
+            "income".into(),
+            [Some(1.0), Some(2.0), Some(100.0), None, Some(3.0)],
+        );
+        let weights = [1.0, 1.0, 1.0, 1.0, 2.0];
+        let plan = recommend_imputation(&income, &weights, 0.2, None).unwrap();
+        assert_eq!(plan.strategy, ImputationStrategy::Median);
+        assert_eq!(plan.value, Some(ImputedValue::Number(3.0)));
+        assert_eq!(plan.missing_woe, None);
+
+        let region = Column::new(
+            "region".into(),
+            [Some("b"), Some("a"), Some("b"), None, Some("a")],
+        );
+        let plan = recommend_imputation(&region, &weights, 0.01, None).unwrap();
+        assert_eq!(plan.strategy, ImputationStrategy::Mode);
+        assert_eq!(plan.value, Some(ImputedValue::Text("a".into())));
+
+        let plan = recommend_imputation(&region, &[1.0; 5], 0.2, None).unwrap();
+        assert_eq!(plan.strategy, ImputationStrategy::Constant);
+        assert_eq!(
+            plan.value,
+            Some(ImputedValue::Text(MISSING_CATEGORY.into()))
+        );
+
+        // Ties go to the first category in sort order
+        assert_eq!(
+            weighted_mode(&region, &[1.0; 5]).unwrap().as_deref(),
+            Some("a")
+        );
+    }
+}
@@ -2,17 +2,28 @@
 //!
 //! This module implements IV-optimal binning with greedy merging to calculate
 //! the predictive power of features against a binary target.
+//!
+//! For a continuous target (`--target-type continuous`) the same stage scores
+//! each binned feature by R², the share of target variance explained by the
+//! bin means, instead of Gini/IV.
 
 use anyhow::{Context, Result};
 use indicatif::{ProgressBar, ProgressStyle};
 use polars::prelude::*;
 use rayon::prelude::*;
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashSet;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 
+use super::bin_breaks::BinBreaks;
 use super::progress::{PipelineStage, ProgressEvent, ProgressSender};
-use super::solver::{reconstruct_bins_from_solution, solve_optimal_binning, SolverConfig};
+use super::solver::{
+    reconstruct_bins_from_solution, solve_optimal_binning, MonotonicityConstraint, SolverConfig,
+    SolverStatus,
+};
+use super::special_values::SpecialValues;
 use super::target::{create_target_mask, TargetMapping};
 
 /// Default number of initial pre-bins before merging (configurable via CLI)
@@ -20,7 +31,7 @@ use super::target::{create_target_mask, TargetMapping};
 const DEFAULT_PREBINS: usize = 20;
 
 /// Minimum samples per bin to avoid unstable WoE estimates
-const MIN_BIN_SAMPLES: usize = 5;
+pub(super) const MIN_BIN_SAMPLES: usize = 5;
 
 /// Smoothing constant to avoid log(0) in WoE calculation (Laplace smoothing)
 const SMOOTHING: f64 = 0.5;
@@ -39,6 +50,11 @@ pub enum BinningStrategy {
     /// CART-style decision tree binning (default) - splits maximize information gain
     #[default]
     Cart,
+    /// Equal-width binning - `prebins` intervals of the same length between the
+    /// smallest and largest value
+    EqualWidth,
+    /// Fixed breakpoints from `--bin-breaks`; features without any fall back to CART
+    Manual,
 }
 
 impl std::fmt::Display for BinningStrategy {
@@ -46,6 +62,8 @@ impl std::fmt::Display for BinningStrategy {
         match self {
             BinningStrategy::Quantile => write!(f, "quantile"),
             BinningStrategy::Cart => write!(f, "cart"),
+            BinningStrategy::EqualWidth => write!(f, "equal_width"),
+            BinningStrategy::Manual => write!(f, "manual"),
         }
     }
 }
@@ -57,8 +75,127 @@ impl std::str::FromStr for BinningStrategy {
         match s.to_lowercase().as_str() {
             "quantile" => Ok(BinningStrategy::Quantile),
             "cart" => Ok(BinningStrategy::Cart),
+            "equal_width" | "equal-width" => Ok(BinningStrategy::EqualWidth),
+            "manual" => Ok(BinningStrategy::Manual),
+            _ => Err(format!(
+                "Unknown binning strategy: '{}'. Use 'quantile', 'cart', 'equal_width' or 'manual'.",
+                s
+            )),
+        }
+    }
+}
+
+/// Kind of target the predictive-power stage scores features against
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub enum TargetType {
+    /// 0/1 target (or mapped to 0/1): Gini/IV on WoE bins (default)
+    #[default]
+    Binary,
+    /// Numeric target: R² of the target on the binned feature
+    Continuous,
+}
+
+impl std::fmt::Display for TargetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TargetType::Binary => write!(f, "binary"),
+            TargetType::Continuous => write!(f, "continuous"),
+        }
+    }
+}
+
+impl std::str::FromStr for TargetType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "binary" => Ok(TargetType::Binary),
+            "continuous" => Ok(TargetType::Continuous),
+            _ => Err(format!(
+                "Unknown target type: '{}'. Use 'binary' or 'continuous'.",
+                s
+            )),
+        }
+    }
+}
+
+/// Statistic the univariate (step 2) filter compares against the threshold
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub enum UnivariateMetric {
+    /// Gini of the WoE-encoded feature, reported alongside IV (default)
+    #[default]
+    Iv,
+    /// Weighted mutual information between the final bins and the target
+    MutualInfo,
+}
+
+impl UnivariateMetric {
+    /// Name used in console output and drop reasons
+    pub fn label(&self) -> &'static str {
+        match self {
+            UnivariateMetric::Iv => "Gini",
+            UnivariateMetric::MutualInfo => "mutual information",
+        }
+    }
+}
+
+impl std::fmt::Display for UnivariateMetric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnivariateMetric::Iv => write!(f, "iv"),
+            UnivariateMetric::MutualInfo => write!(f, "mutual_info"),
+        }
+    }
+}
+
+impl std::str::FromStr for UnivariateMetric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "iv" => Ok(UnivariateMetric::Iv),
+            "mutual_info" | "mutual-info" => Ok(UnivariateMetric::MutualInfo),
+            _ => Err(format!(
+                "Unknown univariate metric: '{}'. Use 'iv' or 'mutual_info'.",
+                s
+            )),
+        }
+    }
+}
+
+/// What happens to the missing values of a numeric feature
+/// (`--missing-bin-policy`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub enum MissingBinPolicy {
+    /// Missing values keep a bin of their own with its own WoE (default)
+    #[default]
+    Separate,
+    /// Missing values join the final bin with the highest event rate
+    MergeWorst,
+    /// Missing values take the weighted median and are binned with it
+    Impute,
+}
+
+impl std::fmt::Display for MissingBinPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MissingBinPolicy::Separate => write!(f, "separate"),
+            MissingBinPolicy::MergeWorst => write!(f, "merge_worst"),
+            MissingBinPolicy::Impute => write!(f, "impute"),
+        }
+    }
+}
+
+impl std::str::FromStr for MissingBinPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "separate" => Ok(MissingBinPolicy::Separate),
+            "merge_worst" | "merge-worst" => Ok(MissingBinPolicy::MergeWorst),
+            "impute" => Ok(MissingBinPolicy::Impute),
             _ => Err(format!(
-                "Unknown binning strategy: '{}'. Use 'quantile' or 'cart'.",
+                "Unknown missing bin policy: '{}'. Use 'separate', 'merge_worst' or 'impute'.",
                 s
             )),
         }
@@ -66,20 +203,20 @@ impl std::str::FromStr for BinningStrategy {
 }
 
 /// Feature type for IV analysis
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FeatureType {
     Numeric,
     Categorical,
 }
 
 /// A single bin with WoE statistics for categorical features
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CategoricalWoeBin {
     /// Category value (string) - used for single category bins (Quantile strategy)
-    #[serde(skip_serializing_if = "String::is_empty")]
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub category: String,
     /// Multiple category values - used for CART-merged bins
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub categories: Vec<String>,
     /// Weighted count of events (target = 1) in this category
     pub events: f64,
@@ -98,12 +235,14 @@ pub struct CategoricalWoeBin {
 }
 
 /// A single bin with WoE statistics
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)] // Fields may be used for reporting/debugging
 pub struct WoeBin {
     /// Lower bound (inclusive)
+    #[serde(deserialize_with = "deserialize_lower_bound")]
     pub lower_bound: f64,
     /// Upper bound (exclusive, except for last bin)
+    #[serde(deserialize_with = "deserialize_upper_bound")]
     pub upper_bound: f64,
     /// Weighted count of events (target = 1) in this bin
     pub events: f64,
@@ -119,10 +258,17 @@ pub struct WoeBin {
     pub population_pct: f64,
     /// Event rate (events / count)
     pub event_rate: f64,
+    /// Weighted chi-square statistic of this bin's event rate against the
+    /// previous bin (absent for the first bin)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chi_square: Option<f64>,
+    /// p-value of `chi_square` (1 degree of freedom)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chi_square_p_value: Option<f64>,
 }
 
 /// A bin for missing/null values with WoE statistics
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MissingBin {
     /// Weighted count of events (target = 1) with missing feature values
     pub events: f64,
@@ -138,10 +284,47 @@ pub struct MissingBin {
     pub population_pct: f64,
     /// Event rate (events / count)
     pub event_rate: f64,
+    /// Index of the numeric bin the missing rows were folded into
+    /// (`merge_worst` or `impute`). That bin's counts include them, `woe` is
+    /// its WoE and `iv_contribution` is 0.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merged_into: Option<usize>,
+    /// Value the missing rows were imputed with (`impute`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub imputed_value: Option<f64>,
+}
+
+impl MissingBin {
+    /// Whether the missing rows form a bin of their own rather than being
+    /// counted in a numeric bin
+    pub fn is_separate(&self) -> bool {
+        self.merged_into.is_none()
+    }
+}
+
+/// A bin for one special value (`--special-values`), kept out of the numeric bins
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecialBin {
+    /// The reserved value, e.g. -999997
+    pub value: f64,
+    /// Weighted count of events (target = 1) with this value
+    pub events: f64,
+    /// Weighted count of non-events (target = 0) with this value
+    pub non_events: f64,
+    /// Weight of Evidence for this value
+    pub woe: f64,
+    /// Contribution to total IV from this value
+    pub iv_contribution: f64,
+    /// Weighted total samples with this value
+    pub count: f64,
+    /// Percentage of total population with this value
+    pub population_pct: f64,
+    /// Event rate (events / count)
+    pub event_rate: f64,
 }
 
 /// Complete IV analysis results for a single feature
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)] // Fields may be used for reporting/debugging
 pub struct IvAnalysis {
     /// Name of the analyzed feature
@@ -149,18 +332,55 @@ pub struct IvAnalysis {
     /// Type of feature (Numeric or Categorical)
     pub feature_type: FeatureType,
     /// Bins with WoE statistics (for numeric features)
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub bins: Vec<WoeBin>,
     /// Categories with WoE statistics (for categorical features)
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub categories: Vec<CategoricalWoeBin>,
     /// Missing value bin (for features with null values)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub missing_bin: Option<MissingBin>,
+    /// One bin per special value found in a numeric feature, in the order
+    /// the values were listed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub special_bins: Vec<SpecialBin>,
     /// Total Information Value
     pub iv: f64,
     /// Gini coefficient calculated on WoE-encoded values
     pub gini: f64,
+    /// Kolmogorov-Smirnov statistic of the bins ranked by event rate
+    #[serde(default)]
+    pub ks: f64,
+    /// ROC AUC of the bins ranked by event rate (0.5-1.0)
+    #[serde(default = "default_auc")]
+    pub auc: f64,
+    /// Mutual information (nats) between the bins and the target; set when it
+    /// is the univariate filter metric (`--univariate-metric mutual_info`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mutual_info: Option<f64>,
+    /// WoE pattern the solver's bins follow; for `auto`, the pattern it chose.
+    /// Absent when the bins were not merged by the solver.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monotonicity: Option<MonotonicityConstraint>,
+    /// Whether the solver proved its bins optimal, stopped at the time limit
+    /// or fell back to greedy merging. Absent when the solver was not run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solver_status: Option<SolverStatus>,
+}
+
+/// Analyses saved before KS/AUC were added carry no discrimination
+fn default_auc() -> f64 {
+    0.5
+}
+
+// JSON has no representation for infinity, so serde_json writes the open-ended
+// outer bin bounds as `null`. Map them back when reading a saved analysis.
+fn deserialize_lower_bound<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    Ok(Option::<f64>::deserialize(deserializer)?.unwrap_or(f64::NEG_INFINITY))
+}
+
+fn deserialize_upper_bound<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    Ok(Option::<f64>::deserialize(deserializer)?.unwrap_or(f64::INFINITY))
 }
 
 // ============================================================================
@@ -172,7 +392,7 @@ pub struct IvAnalysis {
 /// Gini impurity measures the probability of incorrectly classifying a randomly
 /// chosen element. For binary classification: Gini = 2 * p * (1 - p)
 /// where p is the proportion of positive class (events).
-fn gini_impurity(events: f64, non_events: f64) -> f64 {
+pub(super) fn gini_impurity(events: f64, non_events: f64) -> f64 {
     let total = events + non_events;
     if total == 0.0 {
         return 0.0;
@@ -189,7 +409,7 @@ fn gini_impurity(events: f64, non_events: f64) -> f64 {
 ///
 /// # Returns
 /// Option of (split_index, information_gain) or None if no valid split found
-fn find_best_split(
+pub(super) fn find_best_split(
     sorted_pairs: &[(f64, i32, f64)], // (value, target, weight)
     min_samples: usize,
 ) -> Option<(usize, f64)> {
@@ -418,6 +638,8 @@ fn create_cart_prebins(
                 0.0
             },
             event_rate: if count > 0.0 { events / count } else { 0.0 },
+            chi_square: None,
+            chi_square_p_value: None,
         });
     }
 
@@ -475,6 +697,8 @@ fn create_woe_bin_from_pairs(
         count,
         population_pct: count / total_samples * 100.0,
         event_rate: if count > 0.0 { events / count } else { 0.0 },
+        chi_square: None,
+        chi_square_p_value: None,
     })
 }
 
@@ -657,6 +881,9 @@ fn create_categorical_cart_bins(
 /// * `min_category_samples` - Minimum samples per category before merging into "OTHER"
 /// * `weights` - Sample weights for weighted analysis
 /// * `weight_column` - Optional name of the weight column to exclude from analysis
+/// * `special_values` - Values of numeric features binned on their own, outside the numeric bins
+/// * `missing_policy` - Whether missing values of numeric features keep their own bin
+/// * `bin_breaks` - Breakpoints of numeric features for `BinningStrategy::Manual`
 ///
 /// # Returns
 /// Vector of IvAnalysis for each feature, sorted by IV descending
@@ -673,6 +900,9 @@ pub fn analyze_features_iv(
     weights: &[f64],
     weight_column: Option<&str>,
     solver_config: Option<&SolverConfig>,
+    special_values: Option<&SpecialValues>,
+    missing_policy: MissingBinPolicy,
+    bin_breaks: Option<&BinBreaks>,
 ) -> Result<Vec<IvAnalysis>> {
     analyze_features_iv_impl(
         df,
@@ -686,6 +916,9 @@ pub fn analyze_features_iv(
         weights,
         weight_column,
         solver_config,
+        special_values,
+        missing_policy,
+        bin_breaks,
         None,
     )
 }
@@ -705,6 +938,9 @@ pub fn analyze_features_iv_with_progress(
     weights: &[f64],
     weight_column: Option<&str>,
     solver_config: Option<&SolverConfig>,
+    special_values: Option<&SpecialValues>,
+    missing_policy: MissingBinPolicy,
+    bin_breaks: Option<&BinBreaks>,
     progress_tx: &ProgressSender,
 ) -> Result<Vec<IvAnalysis>> {
     analyze_features_iv_impl(
@@ -719,6 +955,9 @@ pub fn analyze_features_iv_with_progress(
         weights,
         weight_column,
         solver_config,
+        special_values,
+        missing_policy,
+        bin_breaks,
         Some(progress_tx),
     )
 }
@@ -736,6 +975,9 @@ fn analyze_features_iv_impl(
     weights: &[f64],
     weight_column: Option<&str>,
     solver_config: Option<&SolverConfig>,
+    special_values: Option<&SpecialValues>,
+    missing_policy: MissingBinPolicy,
+    bin_breaks: Option<&BinBreaks>,
     progress_tx: Option<&ProgressSender>,
 ) -> Result<Vec<IvAnalysis>> {
     if df.height() == 0 {
@@ -744,32 +986,9 @@ fn analyze_features_iv_impl(
 
     let min_cat_samples = min_category_samples.unwrap_or(DEFAULT_MIN_CATEGORY_SAMPLES);
 
-    // Calculate CART min bin samples from percentage
-    // Only used when binning_strategy == BinningStrategy::Cart
-    let cart_min_samples: usize = if let Some(pct) = cart_min_bin_pct {
-        let total_rows = df.height();
-        let calculated = (total_rows as f64 * pct / 100.0).floor() as usize;
-        // Floor of 1 to ensure at least 1 sample minimum
-        calculated.max(1)
-    } else {
-        MIN_BIN_SAMPLES
-    };
-
-    // Get target values based on whether we have a mapping
-    let target_values: Vec<Option<i32>> = if let Some(mapping) = target_mapping {
-        // Use the mapping to convert target values
-        create_target_mask(df, target, mapping)?
-    } else {
-        // Validate binary target and get values directly
-        validate_binary_target(df, target)?;
-
-        let target_col = df.column(target)?;
-        target_col
-            .cast(&DataType::Int32)?
-            .i32()?
-            .into_iter()
-            .collect()
-    };
+    // Only used for CART prebins (including manual binning without breakpoints)
+    let cart_min_samples = cart_min_samples(df.height(), cart_min_bin_pct);
+    let target_values = binary_target_values(df, target, target_mapping)?;
 
     // Get numeric columns (excluding target and weight column)
     let numeric_cols: Vec<String> = df
@@ -836,6 +1055,7 @@ fn analyze_features_iv_impl(
     let numeric_results: Vec<(String, Result<IvAnalysis>)> = numeric_cols
         .par_iter()
         .map(|col_name| {
+            let start = Instant::now();
             let result = analyze_single_numeric_feature(
                 df,
                 col_name,
@@ -846,7 +1066,11 @@ fn analyze_features_iv_impl(
                 cart_min_samples,
                 &weights_arc,
                 solver_config_arc.as_deref(),
+                &special_values.map_or_else(Vec::new, |s| s.for_feature(col_name)),
+                missing_policy,
+                bin_breaks.and_then(|b| b.for_feature(col_name)),
             );
+            log_feature_timing(&result, start);
 
             // Update progress
             let count = progress_counter.fetch_add(1, Ordering::Relaxed);
@@ -873,7 +1097,7 @@ fn analyze_features_iv_impl(
     for (col_name, result) in numeric_results {
         match result {
             Ok(analysis) => numeric_analyses.push(analysis),
-            Err(e) => eprintln!("Warning: Skipped feature '{}': {}", col_name, e),
+            Err(e) => tracing::warn!("Skipped feature '{}': {}", col_name, e),
         }
     }
 
@@ -885,6 +1109,7 @@ fn analyze_features_iv_impl(
     let categorical_results: Vec<(String, Result<IvAnalysis>)> = categorical_cols
         .par_iter()
         .map(|col_name| {
+            let start = Instant::now();
             let result = analyze_categorical_feature(
                 df,
                 col_name,
@@ -896,6 +1121,7 @@ fn analyze_features_iv_impl(
                 num_bins,
                 prebins,
             );
+            log_feature_timing(&result, start);
 
             // Update progress
             let count = progress_counter.fetch_add(1, Ordering::Relaxed);
@@ -922,7 +1148,7 @@ fn analyze_features_iv_impl(
     for (col_name, result) in categorical_results {
         match result {
             Ok(analysis) => categorical_analyses.push(analysis),
-            Err(e) => eprintln!("Warning: Skipped feature '{}': {}", col_name, e),
+            Err(e) => tracing::warn!("Skipped feature '{}': {}", col_name, e),
         }
     }
 
@@ -942,9 +1168,44 @@ fn analyze_features_iv_impl(
         .collect();
     all_analyses.sort_by(|a, b| b.iv.partial_cmp(&a.iv).unwrap_or(std::cmp::Ordering::Equal));
 
+    // KS and AUC only need the final bin counts
+    for analysis in &mut all_analyses {
+        (analysis.ks, analysis.auc) = ks_and_auc(analysis);
+    }
+
     Ok(all_analyses)
 }
 
+/// Minimum CART bin size in rows, from a percentage of `total_rows`
+pub(super) fn cart_min_samples(total_rows: usize, cart_min_bin_pct: Option<f64>) -> usize {
+    match cart_min_bin_pct {
+        // Floor of 1 to ensure at least 1 sample minimum
+        Some(pct) => ((total_rows as f64 * pct / 100.0).floor() as usize).max(1),
+        None => MIN_BIN_SAMPLES,
+    }
+}
+
+/// Target as 0/1 per row, through `target_mapping` when given; rows whose
+/// value matches neither mapped value are `None`
+pub(super) fn binary_target_values(
+    df: &DataFrame,
+    target: &str,
+    target_mapping: Option<&TargetMapping>,
+) -> Result<Vec<Option<i32>>> {
+    if let Some(mapping) = target_mapping {
+        return create_target_mask(df, target, mapping);
+    }
+
+    // Validate binary target and get values directly
+    validate_binary_target(df, target)?;
+    Ok(df
+        .column(target)?
+        .cast(&DataType::Int32)?
+        .i32()?
+        .into_iter()
+        .collect())
+}
+
 /// Validate that the target column is binary (contains only 0 and 1)
 ///
 /// This function handles edge cases from CSV/Parquet conversion:
@@ -998,9 +1259,27 @@ fn validate_binary_target(df: &DataFrame, target: &str) -> Result<()> {
     Ok(())
 }
 
+/// Log how long one feature took to bin and what the binning found
+fn log_feature_timing(result: &Result<IvAnalysis>, start: Instant) {
+    if let Ok(analysis) = result {
+        tracing::debug!(
+            feature = %analysis.feature_name,
+            bins = analysis.bins.len() + analysis.categories.len(),
+            iv = analysis.iv,
+            gini = analysis.gini,
+            solver_status = ?analysis.solver_status,
+            monotonicity = ?analysis.monotonicity,
+            elapsed_ms = start.elapsed().as_millis() as u64,
+            "Binned feature"
+        );
+    }
+}
+
 /// Analyze a single numeric feature and calculate its IV
 ///
-/// Missing feature values are placed in a dedicated MISSING bin rather than being dropped.
+/// Missing feature values are placed in a dedicated MISSING bin rather than being dropped
+/// (or folded into a numeric bin, per `missing_policy`), and each of `special_values`
+/// present gets a bin of its own.
 /// Only records with invalid/unmapped target values are excluded from the analysis.
 #[allow(clippy::too_many_arguments)]
 fn analyze_single_numeric_feature(
@@ -1013,6 +1292,9 @@ fn analyze_single_numeric_feature(
     cart_min_bin_samples: usize,
     weights: &[f64],
     solver_config: Option<&SolverConfig>,
+    special_values: &[f64],
+    missing_policy: MissingBinPolicy,
+    bin_breaks: Option<&[f64]>,
 ) -> Result<IvAnalysis> {
     let col = df.column(col_name)?;
     let float_col = col.cast(&DataType::Float64)?;
@@ -1023,13 +1305,26 @@ fn analyze_single_numeric_feature(
     let mut pairs: Vec<(f64, i32, f64)> = Vec::new(); // (value, target, weight)
     let mut missing_events: f64 = 0.0;
     let mut missing_non_events: f64 = 0.0;
+    // (target, weight) of the missing rows, binned at the median under `impute`
+    let mut missing_rows: Vec<(i32, f64)> = Vec::new();
+    // (events, non_events) per special value
+    let mut special_counts: Vec<(f64, f64)> = vec![(0.0, 0.0); special_values.len()];
     let mut valid_record_count: usize = 0;
 
     for ((v, t), &w) in values.iter().zip(target_values.iter()).zip(weights.iter()) {
         match (v, t) {
             (Some(val), Some(target)) => {
-                // Non-null feature value with valid target
-                pairs.push((val, *target, w));
+                if let Some(i) = special_values.iter().position(|&s| s == val) {
+                    // Special value -> its own bin, never mixed into the numeric ranges
+                    if *target == 1 {
+                        special_counts[i].0 += w;
+                    } else {
+                        special_counts[i].1 += w;
+                    }
+                } else {
+                    // Non-null feature value with valid target
+                    pairs.push((val, *target, w));
+                }
                 valid_record_count += 1;
             }
             (None, Some(target)) => {
@@ -1039,6 +1334,9 @@ fn analyze_single_numeric_feature(
                 } else {
                     missing_non_events += w;
                 }
+                if missing_policy == MissingBinPolicy::Impute {
+                    missing_rows.push((*target, w));
+                }
                 valid_record_count += 1;
             }
             (_, None) => {
@@ -1048,14 +1346,19 @@ fn analyze_single_numeric_feature(
     }
 
     let missing_count = missing_events + missing_non_events;
-    let total_valid_weight: f64 = pairs.iter().map(|(_, _, w)| w).sum::<f64>() + missing_count;
+    let special_events: f64 = special_counts.iter().map(|(e, _)| e).sum();
+    let special_non_events: f64 = special_counts.iter().map(|(_, n)| n).sum();
+    let total_valid_weight: f64 = pairs.iter().map(|(_, _, w)| w).sum::<f64>()
+        + missing_count
+        + special_events
+        + special_non_events;
 
     // Need at least some valid records to proceed (check raw count, not weighted)
     if valid_record_count < MIN_BIN_SAMPLES {
         anyhow::bail!("Insufficient valid records for feature '{}'", col_name);
     }
 
-    // Count total weighted events and non-events (including missing bin)
+    // Count total weighted events and non-events (including missing and special bins)
     let non_missing_events: f64 = pairs
         .iter()
         .filter(|(_, t, _)| *t == 1)
@@ -1067,8 +1370,8 @@ fn analyze_single_numeric_feature(
         .map(|(_, _, w)| w)
         .sum();
 
-    let total_events = non_missing_events + missing_events;
-    let total_non_events = non_missing_non_events + missing_non_events;
+    let total_events = non_missing_events + missing_events + special_events;
+    let total_non_events = non_missing_non_events + missing_non_events + special_non_events;
     let total_samples = total_valid_weight;
 
     if total_events <= 0.0 || total_non_events <= 0.0 {
@@ -1079,7 +1382,7 @@ fn analyze_single_numeric_feature(
     }
 
     // Create MISSING bin if there are missing values
-    let missing_bin = if missing_count > 0.0 {
+    let mut missing_bin = if missing_count > 0.0 {
         let (woe, iv_contrib) = calculate_woe_iv(
             missing_events,
             missing_non_events,
@@ -1102,21 +1405,43 @@ fn analyze_single_numeric_feature(
             } else {
                 0.0
             },
+            merged_into: None,
+            imputed_value: None,
         })
     } else {
         None
     };
 
-    // If all values are missing or too few non-missing records for binning,
-    // return early with just the missing bin (check raw pair count, not weighted)
+    let special_bins: Vec<SpecialBin> = special_values
+        .iter()
+        .zip(&special_counts)
+        .filter(|(_, (events, non_events))| events + non_events > 0.0)
+        .map(|(&value, &(events, non_events))| {
+            let (woe, iv_contribution) =
+                calculate_woe_iv(events, non_events, total_events, total_non_events);
+            let count = events + non_events;
+            SpecialBin {
+                value,
+                events,
+                non_events,
+                woe,
+                iv_contribution,
+                count,
+                population_pct: count / total_samples * 100.0,
+                event_rate: events / count,
+            }
+        })
+        .collect();
+    let special_iv: f64 = special_bins.iter().map(|b| b.iv_contribution).sum();
+
+    // If all values are missing or special, or too few remain for binning,
+    // return early with just those bins (check raw pair count, not weighted).
+    // With no numeric bin to join, the missing bin stays separate whatever the policy.
     if pairs.len() < MIN_BIN_SAMPLES * 2 {
-        let iv = missing_bin
-            .as_ref()
-            .map(|b| b.iv_contribution)
-            .unwrap_or(0.0);
-        // With only missing bin and insufficient non-missing values for binning,
-        // Gini is 0 as there's no discrimination possible
-        let gini = 0.0;
+        // Without numeric bins only the missing and special bins can discriminate;
+        // a lone missing bin gives a Gini of 0
+        let gini = calculate_gini_on_bins_with_missing(&[], &missing_bin, &special_bins);
+        let missing_iv = missing_bin.as_ref().map_or(0.0, |b| b.iv_contribution);
 
         return Ok(IvAnalysis {
             feature_name: col_name.to_string(),
@@ -1124,25 +1449,50 @@ fn analyze_single_numeric_feature(
             bins: Vec::new(),
             categories: Vec::new(),
             missing_bin,
-            iv,
+            special_bins,
+            iv: missing_iv + special_iv,
             gini,
+            mutual_info: None,
+            monotonicity: None,
+            solver_status: None,
+            ks: 0.0,
+            auc: 0.5,
         });
     }
 
     // Sort by value for binning
     pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
 
+    // Impute: missing rows take the weighted median and are binned like any other value
+    let imputed_value = (!missing_rows.is_empty()).then(|| {
+        let median = weighted_median(&pairs);
+        let at = pairs.partition_point(|(v, _, _)| *v < median);
+        pairs.splice(at..at, missing_rows.iter().map(|&(t, w)| (median, t, w)));
+        median
+    });
+
     // Phase 1: Create initial pre-bins based on strategy (for non-missing values)
-    // Both strategies now create `prebins` initial bins
-    let pre_bins = match binning_strategy {
-        BinningStrategy::Quantile => create_quantile_prebins(
+    // Quantile, CART and equal-width create up to `prebins` initial bins; manual
+    // binning creates one per band between the breakpoints
+    let pre_bins = match (binning_strategy, bin_breaks) {
+        (BinningStrategy::Quantile, _) => create_quantile_prebins(
             &pairs,
             prebins,
             total_events,
             total_non_events,
             total_samples,
         ),
-        BinningStrategy::Cart => create_cart_prebins(
+        (BinningStrategy::EqualWidth, _) => create_prebins_from_edges(
+            &pairs,
+            &equal_width_edges(&pairs, prebins),
+            total_events,
+            total_non_events,
+            total_samples,
+        ),
+        (BinningStrategy::Manual, Some(edges)) => {
+            create_prebins_from_edges(&pairs, edges, total_events, total_non_events, total_samples)
+        }
+        (BinningStrategy::Cart | BinningStrategy::Manual, _) => create_cart_prebins(
             &pairs,
             prebins,
             cart_min_bin_samples,
@@ -1153,66 +1503,109 @@ fn analyze_single_numeric_feature(
     };
 
     // Phase 2: Merge/optimize until target bin count
-    // Use solver if configured, otherwise use greedy merging
-    let final_bins = if pre_bins.len() > num_bins {
-        if let Some(config) = solver_config {
-            // Use solver-based optimal binning
-            match solve_optimal_binning(
-                &pre_bins,
-                num_bins,
-                config,
-                total_events,
-                total_non_events,
-                total_samples,
-            ) {
-                Ok(result) => reconstruct_bins_from_solution(
+    // Use solver if configured, otherwise use greedy merging. Bin share limits
+    // and a minimum WoE difference send the prebins to the solver even when
+    // there are few enough.
+    let mut monotonicity = None;
+    let mut solver_status = None;
+    let mut final_bins =
+        if pre_bins.len() > num_bins || solver_config.is_some_and(SolverConfig::limits_bin_count) {
+            if let Some(config) = solver_config {
+                // Use solver-based optimal binning
+                match solve_optimal_binning(
                     &pre_bins,
-                    &result,
+                    num_bins,
+                    config,
                     total_events,
                     total_non_events,
                     total_samples,
-                ),
-                Err(_) => {
-                    // Fall back to greedy if solver fails
-                    greedy_merge_bins(
-                        pre_bins,
-                        num_bins,
-                        total_events,
-                        total_non_events,
-                        total_samples,
-                    )
+                ) {
+                    Ok(result) => {
+                        monotonicity = Some(result.monotonicity_applied);
+                        solver_status = Some(result.status);
+                        reconstruct_bins_from_solution(
+                            &pre_bins,
+                            &result,
+                            total_events,
+                            total_non_events,
+                            total_samples,
+                        )
+                    }
+                    Err(err) => {
+                        // Fall back to greedy if solver fails
+                        solver_status = Some(SolverStatus::fallback_for(&err));
+                        greedy_merge_bins(
+                            pre_bins,
+                            num_bins,
+                            total_events,
+                            total_non_events,
+                            total_samples,
+                        )
+                    }
                 }
+            } else {
+                // Use greedy merging
+                greedy_merge_bins(
+                    pre_bins,
+                    num_bins,
+                    total_events,
+                    total_non_events,
+                    total_samples,
+                )
             }
         } else {
-            // Use greedy merging
-            greedy_merge_bins(
-                pre_bins,
-                num_bins,
+            // If prebins <= num_bins, no merging needed
+            pre_bins
+        };
+
+    if let Some(mb) = missing_bin.as_mut() {
+        if let Some(median) = imputed_value {
+            mb.merged_into = find_bin_for_value(median, &final_bins);
+            mb.imputed_value = Some(median);
+        } else if missing_policy == MissingBinPolicy::MergeWorst {
+            let worst = (0..final_bins.len())
+                .max_by(|&a, &b| {
+                    final_bins[a]
+                        .event_rate
+                        .total_cmp(&final_bins[b].event_rate)
+                })
+                .expect("at least one bin");
+            final_bins[worst] = merge_missing_into_bin(
+                &final_bins[worst],
+                mb,
                 total_events,
                 total_non_events,
                 total_samples,
-            )
+            );
+            mb.merged_into = Some(worst);
         }
-    } else {
-        // If prebins <= num_bins, no merging needed
-        pre_bins
-    };
+        if let Some(i) = mb.merged_into {
+            mb.woe = final_bins[i].woe;
+            mb.iv_contribution = 0.0;
+        }
+    }
+    annotate_adjacent_chi_square(&mut final_bins);
 
-    // Calculate total IV (including missing bin contribution)
+    // Calculate total IV (including missing and special bin contributions;
+    // a merged missing bin contributes through its host bin)
     let bins_iv: f64 = final_bins.iter().map(|b| b.iv_contribution).sum();
-    let missing_iv: f64 = missing_bin
-        .as_ref()
-        .map(|b| b.iv_contribution)
-        .unwrap_or(0.0);
-    let iv = bins_iv + missing_iv;
+    let missing_iv = missing_bin.as_ref().map_or(0.0, |b| b.iv_contribution);
+    let iv = bins_iv + missing_iv + special_iv;
 
-    // Calculate Gini on WoE-encoded values (including missing bin)
+    // Calculate Gini on WoE-encoded values (including missing and special bins).
+    // Imputed missing rows are already among the pairs.
+    let (missing_events, missing_non_events) = if imputed_value.is_some() {
+        (0.0, 0.0)
+    } else {
+        (missing_events, missing_non_events)
+    };
     let gini = calculate_gini_on_woe_with_missing(
         &pairs,
         &final_bins,
         &missing_bin,
         missing_events,
         missing_non_events,
+        &special_bins,
     );
 
     Ok(IvAnalysis {
@@ -1221,8 +1614,14 @@ fn analyze_single_numeric_feature(
         bins: final_bins,
         categories: Vec::new(),
         missing_bin,
+        special_bins,
         iv,
         gini,
+        mutual_info: None,
+        monotonicity,
+        solver_status,
+        ks: 0.0,
+        auc: 0.5,
     })
 }
 
@@ -1334,6 +1733,8 @@ fn analyze_categorical_feature(
             } else {
                 0.0
             },
+            merged_into: None,
+            imputed_value: None,
         })
     } else {
         None
@@ -1360,7 +1761,8 @@ fn analyze_categorical_feature(
 
     // Create CategoricalWoeBin based on binning strategy
     let mut categories: Vec<CategoricalWoeBin> = match binning_strategy {
-        BinningStrategy::Quantile => {
+        // Value ranges mean nothing for categories: one bin per category
+        BinningStrategy::Quantile | BinningStrategy::EqualWidth | BinningStrategy::Manual => {
             // Existing logic: one bin per category
             final_categories
                 .into_iter()
@@ -1496,12 +1898,84 @@ fn analyze_categorical_feature(
         bins: Vec::new(),
         categories,
         missing_bin,
+        special_bins: Vec::new(),
         iv,
         gini,
+        mutual_info: None,
+        monotonicity: None,
+        solver_status: None,
+        ks: 0.0,
+        auc: 0.5,
     })
 }
 
-/// Create initial quantile-based pre-bins
+/// Cut points that split weighted values into `n` quantile bins of about
+/// equal total weight, the prebins of `--binning-strategy quantile`.
+///
+/// `values` need not be sorted; NaN values and their weights are ignored.
+/// The cut points are strictly increasing values of `values`, at most
+/// `n - 1` of them, and a value equal to a cut point opens the next bin, so
+/// equal values always share a bin. The j-th cut point comes after at least
+/// j/n of the total weight, and every bin without its largest value weighs
+/// less than 1/n of it; a value heavier than that gets a bin of its own end
+/// and the bins may be fewer than `n`. Scaling every weight by the same
+/// factor leaves the cut points unchanged, and a weight of 2 acts like the
+/// value appearing twice. Empty when there is a single value, `n < 2` or no
+/// positive weight.
+///
+/// # Panics
+/// When `values` and `weights` differ in length.
+#[allow(dead_code)] // Library API; the binary bins through `create_quantile_prebins`
+pub fn compute_weighted_quantile_prebins(values: &[f64], weights: &[f64], n: usize) -> Vec<f64> {
+    assert_eq!(
+        values.len(),
+        weights.len(),
+        "values and weights must have the same length"
+    );
+    let mut sorted: Vec<(f64, f64)> = values
+        .iter()
+        .zip(weights)
+        .filter(|(v, _)| !v.is_nan())
+        .map(|(&v, &w)| (v, w))
+        .collect();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+    weighted_quantile_edges(&sorted, n)
+}
+
+/// [`compute_weighted_quantile_prebins`] over `(value, weight)` pairs sorted
+/// by value
+fn weighted_quantile_edges(sorted: &[(f64, f64)], n: usize) -> Vec<f64> {
+    let total: f64 = sorted.iter().map(|&(_, w)| w.max(0.0)).sum();
+    if n < 2 || total <= 0.0 || total.is_nan() {
+        return Vec::new();
+    }
+    // Tolerate rounding in the cumulative sum, e.g. ten rows of weight 0.1
+    let tolerance = total * 1e-9;
+    let mut edges = Vec::new();
+    let mut cumulative = 0.0;
+    let mut next_quantile = 1;
+    for (i, &(value, weight)) in sorted.iter().enumerate() {
+        cumulative += weight.max(0.0);
+        let Some(&(next_value, _)) = sorted.get(i + 1) else {
+            break;
+        };
+        if next_value == value || next_quantile >= n {
+            continue;
+        }
+        if cumulative >= next_quantile as f64 * total / n as f64 - tolerance {
+            edges.push(next_value);
+            // A heavy value may pass several quantiles at once
+            while next_quantile < n
+                && cumulative >= next_quantile as f64 * total / n as f64 - tolerance
+            {
+                next_quantile += 1;
+            }
+        }
+    }
+    edges
+}
+
+/// Create initial quantile-based pre-bins of about equal weight
 fn create_quantile_prebins(
     sorted_pairs: &[(f64, i32, f64)], // (value, target, weight)
     num_prebins: usize,
@@ -1509,58 +1983,72 @@ fn create_quantile_prebins(
     total_non_events: f64,
     total_samples: f64,
 ) -> Vec<WoeBin> {
-    let n = sorted_pairs.len();
-    let bin_size = n.div_ceil(num_prebins); // Ceiling division
+    let weighted: Vec<(f64, f64)> = sorted_pairs.iter().map(|&(v, _, w)| (v, w)).collect();
+    create_prebins_from_edges(
+        sorted_pairs,
+        &weighted_quantile_edges(&weighted, num_prebins),
+        total_events,
+        total_non_events,
+        total_samples,
+    )
+}
 
-    let mut bins = Vec::new();
-    let mut start_idx = 0;
+/// `num_prebins - 1` evenly spaced cut points between the smallest and largest
+/// value; none when all values are equal
+fn equal_width_edges(sorted_pairs: &[(f64, i32, f64)], num_prebins: usize) -> Vec<f64> {
+    let (Some(first), Some(last)) = (sorted_pairs.first(), sorted_pairs.last()) else {
+        return Vec::new();
+    };
+    let (min, max) = (first.0, last.0);
+    if max <= min {
+        return Vec::new();
+    }
+    let width = (max - min) / num_prebins.max(1) as f64;
+    (1..num_prebins).map(|k| min + k as f64 * width).collect()
+}
 
-    while start_idx < n {
-        let end_idx = (start_idx + bin_size).min(n);
-        let bin_pairs = &sorted_pairs[start_idx..end_idx];
+/// Pre-bins cut at `edges` (sorted): a value equal to an edge opens the next
+/// bin. Bands without values are folded into the bin below them so the bins
+/// still cover the whole range; the first bin starts at the smallest value.
+fn create_prebins_from_edges(
+    sorted_pairs: &[(f64, i32, f64)], // (value, target, weight)
+    edges: &[f64],
+    total_events: f64,
+    total_non_events: f64,
+    total_samples: f64,
+) -> Vec<WoeBin> {
+    let n = sorted_pairs.len();
+    let mut bins: Vec<WoeBin> = Vec::new();
+    let mut start_idx = 0;
 
-        let lower = bin_pairs
-            .first()
-            .map(|(v, _, _)| *v)
-            .unwrap_or(f64::NEG_INFINITY);
-        let upper = if end_idx < n {
-            sorted_pairs[end_idx].0
-        } else {
-            f64::INFINITY
+    for k in 0..=edges.len() {
+        let (end_idx, upper) = match edges.get(k) {
+            Some(&edge) => (sorted_pairs.partition_point(|(v, _, _)| *v < edge), edge),
+            None => (n, f64::INFINITY),
         };
-
-        // Weighted counts
-        let events: f64 = bin_pairs
-            .iter()
-            .filter(|(_, t, _)| *t == 1)
-            .map(|(_, _, w)| w)
-            .sum();
-        let non_events: f64 = bin_pairs
-            .iter()
-            .filter(|(_, t, _)| *t == 0)
-            .map(|(_, _, w)| w)
-            .sum();
-        let count = events + non_events;
-
-        let (woe, iv_contrib) =
-            calculate_woe_iv(events, non_events, total_events, total_non_events);
-
-        bins.push(WoeBin {
-            lower_bound: lower,
-            upper_bound: upper,
-            events,
-            non_events,
-            woe,
-            iv_contribution: iv_contrib,
-            count,
-            population_pct: if total_samples > 0.0 {
-                count / total_samples * 100.0
-            } else {
-                0.0
-            },
-            event_rate: if count > 0.0 { events / count } else { 0.0 },
-        });
-
+        if end_idx == start_idx {
+            if let Some(last) = bins.last_mut() {
+                last.upper_bound = upper;
+            }
+            continue;
+        }
+        let lower = bins
+            .last()
+            .map_or(sorted_pairs[start_idx].0, |b| b.upper_bound);
+        if let Some(mut bin) = create_woe_bin_from_pairs(
+            &sorted_pairs[start_idx..end_idx],
+            start_idx,
+            end_idx,
+            n,
+            sorted_pairs,
+            total_events,
+            total_non_events,
+            total_samples,
+        ) {
+            bin.lower_bound = lower;
+            bin.upper_bound = upper;
+            bins.push(bin);
+        }
         start_idx = end_idx;
     }
 
@@ -1574,7 +2062,7 @@ fn create_quantile_prebins(
 /// - WoE < 0 indicates lower risk (fewer events/defaults)
 ///
 ///   This is intuitive for credit scoring where higher WoE = higher risk.
-fn calculate_woe_iv(
+pub(super) fn calculate_woe_iv(
     events: f64,
     non_events: f64,
     total_events: f64,
@@ -1637,22 +2125,20 @@ fn greedy_merge_bins(
     bins
 }
 
-/// Merge two adjacent bins into one
-fn merge_two_bins(
-    bin1: &WoeBin,
-    bin2: &WoeBin,
+/// A numeric bin with the missing rows added to its counts
+fn merge_missing_into_bin(
+    bin: &WoeBin,
+    missing_bin: &MissingBin,
     total_events: f64,
     total_non_events: f64,
     total_samples: f64,
 ) -> WoeBin {
-    let events = bin1.events + bin2.events;
-    let non_events = bin1.non_events + bin2.non_events;
-    let count = bin1.count + bin2.count;
+    let events = bin.events + missing_bin.events;
+    let non_events = bin.non_events + missing_bin.non_events;
+    let count = bin.count + missing_bin.count;
     let (woe, iv_contrib) = calculate_woe_iv(events, non_events, total_events, total_non_events);
 
     WoeBin {
-        lower_bound: bin1.lower_bound,
-        upper_bound: bin2.upper_bound,
         events,
         non_events,
         woe,
@@ -1660,10 +2146,189 @@ fn merge_two_bins(
         count,
         population_pct: count / total_samples * 100.0,
         event_rate: if count > 0.0 { events / count } else { 0.0 },
+        ..bin.clone()
     }
 }
 
-/// Calculate Gini coefficient on WoE-encoded values including missing bin
+/// Weighted median of pairs sorted by value: the first value at which the
+/// cumulative weight reaches half the total
+fn weighted_median(sorted_pairs: &[(f64, i32, f64)]) -> f64 {
+    let half = sorted_pairs.iter().map(|(_, _, w)| w).sum::<f64>() / 2.0;
+    let mut cumulative = 0.0;
+    for &(value, _, weight) in sorted_pairs {
+        cumulative += weight;
+        if cumulative >= half {
+            return value;
+        }
+    }
+    sorted_pairs.last().map_or(0.0, |p| p.0)
+}
+
+/// Merge two adjacent bins into one
+fn merge_two_bins(
+    bin1: &WoeBin,
+    bin2: &WoeBin,
+    total_events: f64,
+    total_non_events: f64,
+    total_samples: f64,
+) -> WoeBin {
+    let events = bin1.events + bin2.events;
+    let non_events = bin1.non_events + bin2.non_events;
+    let count = bin1.count + bin2.count;
+    let (woe, iv_contrib) = calculate_woe_iv(events, non_events, total_events, total_non_events);
+
+    WoeBin {
+        lower_bound: bin1.lower_bound,
+        upper_bound: bin2.upper_bound,
+        events,
+        non_events,
+        woe,
+        iv_contribution: iv_contrib,
+        count,
+        population_pct: count / total_samples * 100.0,
+        event_rate: if count > 0.0 { events / count } else { 0.0 },
+        chi_square: None,
+        chi_square_p_value: None,
+    }
+}
+
+/// Weighted 2×2 chi-square test of two bins' event rates.
+///
+/// Returns `(statistic, p_value)`. A table with an empty row or column holds no
+/// evidence of a difference and gives `(0.0, 1.0)`.
+pub fn adjacent_bin_chi_square(a: &WoeBin, b: &WoeBin) -> (f64, f64) {
+    let total = a.events + a.non_events + b.events + b.non_events;
+    let margins = (a.events + a.non_events)
+        * (b.events + b.non_events)
+        * (a.events + b.events)
+        * (a.non_events + b.non_events);
+    if margins <= 0.0 {
+        return (0.0, 1.0);
+    }
+    let statistic = total * (a.events * b.non_events - b.events * a.non_events).powi(2) / margins;
+    (statistic, chi_square_p_value_1df(statistic))
+}
+
+/// Upper-tail probability of a chi-square statistic with 1 degree of freedom
+fn chi_square_p_value_1df(statistic: f64) -> f64 {
+    erfc((statistic / 2.0).sqrt()).clamp(0.0, 1.0)
+}
+
+/// Complementary error function for z >= 0 (Chebyshev fit from Numerical
+/// Recipes, relative error below 1.2e-7)
+fn erfc(z: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = 1.000_023_68
+        + t * (0.374_091_96
+            + t * (0.096_784_18
+                + t * (-0.186_288_06
+                    + t * (0.278_868_07
+                        + t * (-1.135_203_98
+                            + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77)))))));
+    t * (-z * z - 1.265_512_23 + t * poly).exp()
+}
+
+/// Fill the chi-square test against the previous bin for every bin but the first
+pub(super) fn annotate_adjacent_chi_square(bins: &mut [WoeBin]) {
+    if let Some(first) = bins.first_mut() {
+        first.chi_square = None;
+        first.chi_square_p_value = None;
+    }
+    for i in 1..bins.len() {
+        let (statistic, p_value) = adjacent_bin_chi_square(&bins[i - 1], &bins[i]);
+        bins[i].chi_square = Some(statistic);
+        bins[i].chi_square_p_value = Some(p_value);
+    }
+}
+
+/// Merge adjacent numeric bins whose event rates do not differ at level `alpha`.
+///
+/// Runs on finished analyses, after the solver or greedy merging: while some
+/// adjacent pair has a chi-square p-value above `alpha`, the pair with the
+/// highest p-value is merged. IV and Gini are recomputed from the bin counts
+/// (a separate missing bin is unchanged; a merged one follows its host bin)
+/// and the analyses are re-sorted by IV.
+/// Categorical features are left alone. Returns the number of bins removed.
+pub fn merge_insignificant_bins(analyses: &mut [IvAnalysis], alpha: f64) -> usize {
+    let mut removed = 0;
+    for analysis in analyses.iter_mut() {
+        if analysis.bins.len() < 2 {
+            continue;
+        }
+
+        let (fixed_events, fixed_non_events) = separate_missing_bin(&analysis.missing_bin)
+            .into_iter()
+            .map(|m| (m.events, m.non_events))
+            .chain(
+                analysis
+                    .special_bins
+                    .iter()
+                    .map(|s| (s.events, s.non_events)),
+            )
+            .fold((0.0, 0.0), |(e, n), (events, non_events)| {
+                (e + events, n + non_events)
+            });
+        let total_events = analysis.bins.iter().map(|b| b.events).sum::<f64>() + fixed_events;
+        let total_non_events =
+            analysis.bins.iter().map(|b| b.non_events).sum::<f64>() + fixed_non_events;
+        let total_samples = total_events + total_non_events;
+
+        let before = analysis.bins.len();
+        let mut host = analysis.missing_bin.as_ref().and_then(|m| m.merged_into);
+        let bins = &mut analysis.bins;
+        while bins.len() > 1 {
+            let (idx, p_value) = (1..bins.len())
+                .map(|i| (i, adjacent_bin_chi_square(&bins[i - 1], &bins[i]).1))
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .expect("at least two bins");
+            if p_value <= alpha {
+                break;
+            }
+            let merged = merge_two_bins(
+                &bins[idx - 1],
+                &bins[idx],
+                total_events,
+                total_non_events,
+                total_samples,
+            );
+            bins.remove(idx);
+            bins[idx - 1] = merged;
+            host = host.map(|h| if h >= idx { h - 1 } else { h });
+        }
+
+        if bins.len() == before {
+            continue;
+        }
+        removed += before - bins.len();
+        annotate_adjacent_chi_square(bins);
+        if let (Some(mb), Some(h)) = (analysis.missing_bin.as_mut(), host) {
+            mb.merged_into = Some(h);
+            mb.woe = analysis.bins[h].woe;
+        }
+        analysis.iv = analysis.bins.iter().map(|b| b.iv_contribution).sum::<f64>()
+            + analysis
+                .missing_bin
+                .as_ref()
+                .map(|b| b.iv_contribution)
+                .unwrap_or(0.0)
+            + analysis
+                .special_bins
+                .iter()
+                .map(|b| b.iv_contribution)
+                .sum::<f64>();
+        analysis.gini = calculate_gini_on_bins_with_missing(
+            &analysis.bins,
+            &analysis.missing_bin,
+            &analysis.special_bins,
+        );
+        (analysis.ks, analysis.auc) = ks_and_auc(analysis);
+    }
+
+    analyses.sort_by(|a, b| b.iv.partial_cmp(&a.iv).unwrap_or(std::cmp::Ordering::Equal));
+    removed
+}
+
+/// Calculate Gini coefficient on WoE-encoded values including missing and special bins
 /// Uses weighted AUC calculation for weighted samples
 fn calculate_gini_on_woe_with_missing(
     sorted_pairs: &[(f64, i32, f64)], // (value, target, weight)
@@ -1671,6 +2336,7 @@ fn calculate_gini_on_woe_with_missing(
     missing_bin: &Option<MissingBin>,
     missing_events: f64,
     missing_non_events: f64,
+    special_bins: &[SpecialBin],
 ) -> f64 {
     // Create weighted (woe, target, weight) tuples
     let mut woe_target_weight: Vec<(f64, i32, f64)> = sorted_pairs
@@ -1690,6 +2356,14 @@ fn calculate_gini_on_woe_with_missing(
             woe_target_weight.push((mb.woe, 0, missing_non_events));
         }
     }
+    for sb in special_bins {
+        if sb.events > 0.0 {
+            woe_target_weight.push((sb.woe, 1, sb.events));
+        }
+        if sb.non_events > 0.0 {
+            woe_target_weight.push((sb.woe, 0, sb.non_events));
+        }
+    }
 
     if woe_target_weight.is_empty() {
         return 0.0;
@@ -1705,9 +2379,38 @@ fn calculate_gini_on_woe_with_missing(
     2.0 * auc - 1.0
 }
 
+/// Gini of WoE-encoded values from the numeric bin counts alone. Every row of a
+/// bin shares its WoE, so this matches the row-level calculation.
+pub(super) fn calculate_gini_on_bins_with_missing(
+    bins: &[WoeBin],
+    missing_bin: &Option<MissingBin>,
+    special_bins: &[SpecialBin],
+) -> f64 {
+    let mut woe_target_weight: Vec<(f64, i32, f64)> = bins
+        .iter()
+        .map(|b| (b.woe, b.events, b.non_events))
+        .chain(separate_missing_bin(missing_bin).map(|m| (m.woe, m.events, m.non_events)))
+        .chain(special_bins.iter().map(|s| (s.woe, s.events, s.non_events)))
+        .flat_map(|(woe, events, non_events)| [(woe, 1, events), (woe, 0, non_events)])
+        .filter(|(_, _, w)| *w > 0.0)
+        .collect();
+    if woe_target_weight.is_empty() {
+        return 0.0;
+    }
+
+    woe_target_weight.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    2.0 * calculate_weighted_auc(&woe_target_weight) - 1.0
+}
+
+/// The missing bin when it stands on its own; a merged one is already counted
+/// in its host bin
+fn separate_missing_bin(missing_bin: &Option<MissingBin>) -> Option<&MissingBin> {
+    missing_bin.as_ref().filter(|m| m.is_separate())
+}
+
 /// Calculate Gini coefficient for categorical features including missing bin
 /// Uses weighted AUC calculation for weighted samples
-fn calculate_gini_on_categories_with_missing(
+pub(super) fn calculate_gini_on_categories_with_missing(
     categories: &[CategoricalWoeBin],
     missing_bin: &Option<MissingBin>,
     total_events: f64,
@@ -1761,9 +2464,20 @@ fn calculate_gini_on_categories_with_missing(
 ///
 /// Bins are sorted by lower_bound. Each bin covers [lower_bound, upper_bound),
 /// except the last bin which uses [lower_bound, +inf).
-fn find_woe_for_value(value: f64, bins: &[WoeBin]) -> f64 {
+pub(crate) fn find_woe_for_value(value: f64, bins: &[WoeBin]) -> f64 {
+    find_bin_for_value(value, bins).map_or(0.0, |i| bins[i].woe)
+}
+
+/// Index of the special bin holding `value`, if it is one of the special values
+pub(crate) fn find_special_bin(value: f64, special_bins: &[SpecialBin]) -> Option<usize> {
+    special_bins.iter().position(|b| b.value == value)
+}
+
+/// Index of the bin a feature value falls into, with the bin rules of
+/// [`find_woe_for_value`]; `None` only when there are no bins
+pub(crate) fn find_bin_for_value(value: f64, bins: &[WoeBin]) -> Option<usize> {
     if bins.is_empty() {
-        return 0.0;
+        return None;
     }
 
     // Binary search for the insertion point of `value` among lower_bounds.
@@ -1773,17 +2487,14 @@ fn find_woe_for_value(value: f64, bins: &[WoeBin]) -> f64 {
 
     if i == 0 {
         // value is below all bin lower_bounds — use first bin as fallback
-        return bins[0].woe;
+        return Some(0);
     }
 
-    let candidate = &bins[i - 1];
     // The last bin has no upper bound limit; all others require value < upper_bound
-    if i == bins.len() || value < candidate.upper_bound {
-        candidate.woe
-    } else if i < bins.len() {
-        bins[i].woe
+    if i == bins.len() || value < bins[i - 1].upper_bound {
+        Some(i - 1)
     } else {
-        bins[bins.len() - 1].woe
+        Some(i)
     }
 }
 
@@ -1791,7 +2502,7 @@ fn find_woe_for_value(value: f64, bins: &[WoeBin]) -> f64 {
 ///
 /// This extends the standard AUC calculation to handle weighted samples.
 /// For weighted data, instead of counting samples, we sum their weights.
-fn calculate_weighted_auc(sorted_pairs: &[(f64, i32, f64)]) -> f64 {
+pub(super) fn calculate_weighted_auc(sorted_pairs: &[(f64, i32, f64)]) -> f64 {
     if sorted_pairs.is_empty() {
         return 0.5;
     }
@@ -1856,10 +2567,678 @@ fn calculate_weighted_auc(sorted_pairs: &[(f64, i32, f64)]) -> f64 {
 }
 
 /// Get list of features with Gini below the threshold
+#[allow(dead_code)] // The binary always passes `--keep-columns`
 pub fn get_low_gini_features(analyses: &[IvAnalysis], threshold: f64) -> Vec<String> {
+    get_low_gini_features_with_keep(analyses, threshold, &HashSet::new())
+}
+
+/// Like [`get_low_gini_features`], but features in `keep` are never dropped
+pub fn get_low_gini_features_with_keep(
+    analyses: &[IvAnalysis],
+    threshold: f64,
+    keep: &HashSet<String>,
+) -> Vec<String> {
     analyses
         .iter()
-        .filter(|a| a.gini.abs() < threshold)
+        .filter(|a| a.gini.abs() < threshold && !keep.contains(&a.feature_name))
+        .map(|a| a.feature_name.clone())
+        .collect()
+}
+
+/// Weighted mutual information (in nats) between a feature's final bins and
+/// the binary target.
+///
+/// Every bin, category group and the missing bin is one cell of the
+/// bin × target table: I = Σ p(b, y) · ln(p(b, y) / (p(b) · p(y))).  Unlike
+/// Gini it ignores the ordering of the bins.  Returns 0 when either class is
+/// absent.
+pub fn mutual_information(analysis: &IvAnalysis) -> f64 {
+    let cells = bin_cells(analysis);
+
+    let total_events: f64 = cells.iter().map(|(e, _)| e).sum();
+    let total_non_events: f64 = cells.iter().map(|(_, n)| n).sum();
+    let total = total_events + total_non_events;
+    if total_events <= 0.0 || total_non_events <= 0.0 {
+        return 0.0;
+    }
+
+    cells
+        .iter()
+        .map(|&(events, non_events)| {
+            let count = events + non_events;
+            [(events, total_events), (non_events, total_non_events)]
+                .iter()
+                .filter(|(cell, _)| *cell > 0.0)
+                .map(|&(cell, class_total)| {
+                    (cell / total) * (cell * total / (count * class_total)).ln()
+                })
+                .sum::<f64>()
+        })
+        .sum::<f64>()
+        .max(0.0)
+}
+
+/// KS statistic and ROC AUC of a feature whose score is its bin's event rate.
+///
+/// Cells (bins, category groups and the missing bin) are ranked from the
+/// highest event rate down. KS is the largest gap between the cumulative event
+/// and non-event distributions; AUC counts cells with equal event rates as
+/// half-concordant. Both are computed from weighted counts and, being ranked by
+/// event rate, AUC is never below 0.5. Returns `(0.0, 0.5)` when either class
+/// is absent.
+pub fn ks_and_auc(analysis: &IvAnalysis) -> (f64, f64) {
+    let mut cells: Vec<(f64, f64)> = bin_cells(analysis)
+        .into_iter()
+        .filter(|(events, non_events)| events + non_events > 0.0)
+        .collect();
+    let total_events: f64 = cells.iter().map(|(e, _)| e).sum();
+    let total_non_events: f64 = cells.iter().map(|(_, n)| n).sum();
+    if total_events <= 0.0 || total_non_events <= 0.0 {
+        return (0.0, 0.5);
+    }
+
+    let rate = |&(events, non_events): &(f64, f64)| events / (events + non_events);
+    cells.sort_by(|a, b| rate(b).total_cmp(&rate(a)));
+
+    let mut ks: f64 = 0.0;
+    let mut concordant = 0.0;
+    let (mut cum_events, mut cum_non_events) = (0.0, 0.0);
+    let mut i = 0;
+    while i < cells.len() {
+        // Cells with the same event rate are one score level
+        let mut j = i;
+        let (mut events, mut non_events) = (0.0, 0.0);
+        while j < cells.len() && (rate(&cells[j]) - rate(&cells[i])).abs() < 1e-12 {
+            events += cells[j].0;
+            non_events += cells[j].1;
+            j += 1;
+        }
+        // Events at this level outrank the non-events still below it
+        concordant +=
+            events * (total_non_events - cum_non_events - non_events) + 0.5 * events * non_events;
+        cum_events += events;
+        cum_non_events += non_events;
+        ks = ks.max((cum_events / total_events - cum_non_events / total_non_events).abs());
+        i = j;
+    }
+
+    (ks, concordant / (total_events * total_non_events))
+}
+
+/// Weighted (events, non_events) of every bin, category group, the missing bin
+/// (unless merged into a bin) and the special bins
+fn bin_cells(analysis: &IvAnalysis) -> Vec<(f64, f64)> {
+    analysis
+        .bins
+        .iter()
+        .map(|b| (b.events, b.non_events))
+        .chain(analysis.categories.iter().map(|c| (c.events, c.non_events)))
+        .chain(separate_missing_bin(&analysis.missing_bin).map(|m| (m.events, m.non_events)))
+        .chain(
+            analysis
+                .special_bins
+                .iter()
+                .map(|s| (s.events, s.non_events)),
+        )
+        .collect()
+}
+
+/// Features the univariate filter drops under `metric`.
+///
+/// With [`UnivariateMetric::MutualInfo`] this also stores each feature's
+/// mutual information in `IvAnalysis::mutual_info` so that it is exported.
+#[allow(dead_code)] // The binary always passes `--keep-columns`
+pub fn get_low_univariate_features(
+    analyses: &mut [IvAnalysis],
+    metric: UnivariateMetric,
+    threshold: f64,
+) -> Vec<String> {
+    get_low_univariate_features_with_keep(analyses, metric, threshold, &HashSet::new())
+}
+
+/// Like [`get_low_univariate_features`], but features in `keep` are never
+/// dropped. Their mutual information is still stored.
+pub fn get_low_univariate_features_with_keep(
+    analyses: &mut [IvAnalysis],
+    metric: UnivariateMetric,
+    threshold: f64,
+    keep: &HashSet<String>,
+) -> Vec<String> {
+    match metric {
+        UnivariateMetric::Iv => get_low_gini_features_with_keep(analyses, threshold, keep),
+        UnivariateMetric::MutualInfo => analyses
+            .iter_mut()
+            .filter_map(|a| {
+                let mi = mutual_information(a);
+                a.mutual_info = Some(mi);
+                (mi < threshold && !keep.contains(&a.feature_name)).then(|| a.feature_name.clone())
+            })
+            .collect(),
+    }
+}
+
+// ============================================================================
+// Continuous Target (R²) Analysis
+// ============================================================================
+
+/// Label of the bin that absorbs rare categories in R² analysis
+const R2_OTHER_CATEGORY: &str = "OTHER";
+
+/// A numeric bin with the weighted mean of a continuous target
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeanBin {
+    /// Lower bound (inclusive)
+    #[serde(deserialize_with = "deserialize_lower_bound")]
+    pub lower_bound: f64,
+    /// Upper bound (exclusive, except for last bin)
+    #[serde(deserialize_with = "deserialize_upper_bound")]
+    pub upper_bound: f64,
+    /// Weighted total samples in this bin
+    pub count: f64,
+    /// Percentage of total population in this bin
+    pub population_pct: f64,
+    /// Weighted mean of the target in this bin
+    pub target_mean: f64,
+}
+
+/// A categorical bin (one category, or rare categories merged as OTHER) with
+/// the weighted mean of a continuous target
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoricalMeanBin {
+    pub categories: Vec<String>,
+    /// Weighted total samples in this bin
+    pub count: f64,
+    /// Percentage of total population in this bin
+    pub population_pct: f64,
+    /// Weighted mean of the target in this bin
+    pub target_mean: f64,
+}
+
+/// Rows with a missing feature value and their weighted target mean
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissingMeanBin {
+    pub count: f64,
+    pub population_pct: f64,
+    pub target_mean: f64,
+}
+
+/// R² analysis of one feature against a continuous target
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RSquaredAnalysis {
+    pub feature_name: String,
+    pub feature_type: FeatureType,
+    /// Equal-frequency bins (numeric features)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bins: Vec<MeanBin>,
+    /// Category bins sorted by target mean (categorical features)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<CategoricalMeanBin>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_bin: Option<MissingMeanBin>,
+    /// Share of target variance explained by the bin means (0..1)
+    pub r_squared: f64,
+    /// Weighted Pearson correlation of the raw values with the target
+    /// (numeric features only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation: Option<f64>,
+}
+
+/// Score every feature against a continuous target by R² of its bins
+///
+/// Numeric features are cut into `num_bins` equal-frequency bins (ties never
+/// straddle a boundary); categorical features use one bin per category, with
+/// categories seen fewer than `min_category_samples` times merged into `OTHER`.
+/// Missing feature values form their own bin. Rows with a null or NaN target
+/// are ignored.
+///
+/// # Returns
+/// Vector of RSquaredAnalysis for each feature, sorted by R² descending
+///
+/// # Errors
+/// Fails if the target is not numeric or has no variance.
+pub fn analyze_features_r2(
+    df: &DataFrame,
+    target: &str,
+    num_bins: usize,
+    min_category_samples: Option<usize>,
+    weights: &[f64],
+    weight_column: Option<&str>,
+) -> Result<Vec<RSquaredAnalysis>> {
+    analyze_features_r2_impl(
+        df,
+        target,
+        num_bins,
+        min_category_samples,
+        weights,
+        weight_column,
+        None,
+    )
+}
+
+/// Same as `analyze_features_r2` but sends progress events over a channel instead of
+/// rendering an indicatif bar to the terminal.
+pub fn analyze_features_r2_with_progress(
+    df: &DataFrame,
+    target: &str,
+    num_bins: usize,
+    min_category_samples: Option<usize>,
+    weights: &[f64],
+    weight_column: Option<&str>,
+    progress_tx: &ProgressSender,
+) -> Result<Vec<RSquaredAnalysis>> {
+    analyze_features_r2_impl(
+        df,
+        target,
+        num_bins,
+        min_category_samples,
+        weights,
+        weight_column,
+        Some(progress_tx),
+    )
+}
+
+fn analyze_features_r2_impl(
+    df: &DataFrame,
+    target: &str,
+    num_bins: usize,
+    min_category_samples: Option<usize>,
+    weights: &[f64],
+    weight_column: Option<&str>,
+    progress_tx: Option<&ProgressSender>,
+) -> Result<Vec<RSquaredAnalysis>> {
+    if df.height() == 0 {
+        return Ok(Vec::new());
+    }
+
+    let min_cat_samples = min_category_samples.unwrap_or(DEFAULT_MIN_CATEGORY_SAMPLES);
+    let target_values = continuous_target_values(df, target)?;
+
+    let (sum_w, sum_wy) = target_values
+        .iter()
+        .zip(weights)
+        .filter_map(|(t, &w)| t.map(|y| (w, w * y)))
+        .fold((0.0, 0.0), |(a, b), (w, wy)| (a + w, b + wy));
+    if sum_w <= 0.0 {
+        anyhow::bail!("Target column '{}' has no valid (non-null) values", target);
+    }
+    let grand_mean = sum_wy / sum_w;
+    let ss_total: f64 = target_values
+        .iter()
+        .zip(weights)
+        .filter_map(|(t, &w)| t.map(|y| w * (y - grand_mean).powi(2)))
+        .sum();
+    if ss_total <= f64::EPSILON {
+        anyhow::bail!(
+            "Target column '{}' is constant; R² is undefined for a target without variance",
+            target
+        );
+    }
+    let totals = R2Totals {
+        sum_w,
+        grand_mean,
+        ss_total,
+    };
+
+    let features: Vec<(String, bool)> = df
+        .get_columns()
+        .iter()
+        .filter(|col| col.name() != target && Some(col.name().as_str()) != weight_column)
+        .filter_map(|col| {
+            let dtype = col.dtype();
+            if dtype.is_primitive_numeric() {
+                Some((col.name().to_string(), true))
+            } else if matches!(dtype, DataType::String | DataType::Categorical(_, _)) {
+                Some((col.name().to_string(), false))
+            } else {
+                None
+            }
+        })
+        .collect();
+    let total_features = features.len();
+    if total_features == 0 {
+        return Ok(Vec::new());
+    }
+
+    let pb = if progress_tx.is_none() {
+        let bar = ProgressBar::new(total_features as u64);
+        bar.set_style(
+            ProgressStyle::default_bar()
+                .template(
+                    "   Calculating R² [{bar:40.cyan/blue}] {pos}/{len} features ({percent}%) [{eta}]",
+                )
+                .unwrap()
+                .progress_chars("=>-"),
+        );
+        Some(bar)
+    } else {
+        None
+    };
+    let progress_counter = AtomicU64::new(0);
+
+    let results: Vec<(String, Result<RSquaredAnalysis>)> = features
+        .par_iter()
+        .map(|(col_name, is_numeric)| {
+            let start = Instant::now();
+            let result = if *is_numeric {
+                analyze_numeric_feature_r2(df, col_name, &target_values, weights, num_bins, &totals)
+            } else {
+                analyze_categorical_feature_r2(
+                    df,
+                    col_name,
+                    &target_values,
+                    weights,
+                    min_cat_samples,
+                    &totals,
+                )
+            };
+            if let Ok(analysis) = &result {
+                tracing::debug!(
+                    feature = %analysis.feature_name,
+                    bins = analysis.bins.len() + analysis.categories.len(),
+                    r_squared = analysis.r_squared,
+                    elapsed_ms = start.elapsed().as_millis() as u64,
+                    "Binned feature"
+                );
+            }
+
+            let count = progress_counter.fetch_add(1, Ordering::Relaxed);
+            if count.is_multiple_of(PROGRESS_UPDATE_INTERVAL)
+                || count == (total_features as u64 - 1)
+            {
+                if let Some(bar) = &pb {
+                    bar.set_position(count + 1);
+                } else if let Some(tx) = progress_tx {
+                    tx.send(ProgressEvent::update(
+                        PipelineStage::GiniAnalysis,
+                        "R² analysis",
+                        format!("{}/{} features", count + 1, total_features),
+                    ))
+                    .ok();
+                }
+            }
+
+            (col_name.clone(), result)
+        })
+        .collect();
+
+    let mut analyses = Vec::with_capacity(results.len());
+    for (col_name, result) in results {
+        match result {
+            Ok(analysis) => analyses.push(analysis),
+            Err(e) => tracing::warn!("Skipped feature '{}': {}", col_name, e),
+        }
+    }
+
+    if let Some(bar) = &pb {
+        bar.finish_with_message(format!("   [OK] Analyzed {} features", analyses.len()));
+    }
+
+    analyses.sort_by(|a, b| {
+        b.r_squared
+            .partial_cmp(&a.r_squared)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    Ok(analyses)
+}
+
+/// Target-wide sums shared by every feature's R² computation
+struct R2Totals {
+    sum_w: f64,
+    grand_mean: f64,
+    ss_total: f64,
+}
+
+impl R2Totals {
+    fn population_pct(&self, count: f64) -> f64 {
+        count / self.sum_w * 100.0
+    }
+
+    /// Between-bin sum of squares contributed by a bin
+    fn ss_between(&self, count: f64, mean: f64) -> f64 {
+        count * (mean - self.grand_mean).powi(2)
+    }
+}
+
+/// Target values as f64; null and NaN become `None`
+fn continuous_target_values(df: &DataFrame, target: &str) -> Result<Vec<Option<f64>>> {
+    let target_col = df
+        .column(target)
+        .with_context(|| format!("Target column '{}' not found", target))?;
+    if !target_col.dtype().is_primitive_numeric() {
+        anyhow::bail!(
+            "Target column '{}' must be numeric for a continuous target, found {}",
+            target,
+            target_col.dtype()
+        );
+    }
+    Ok(target_col
+        .cast(&DataType::Float64)?
+        .f64()?
+        .iter()
+        .map(|v| v.filter(|y| !y.is_nan()))
+        .collect())
+}
+
+fn analyze_numeric_feature_r2(
+    df: &DataFrame,
+    col_name: &str,
+    target_values: &[Option<f64>],
+    weights: &[f64],
+    num_bins: usize,
+    totals: &R2Totals,
+) -> Result<RSquaredAnalysis> {
+    let float_col = df.column(col_name)?.cast(&DataType::Float64)?;
+    let values = float_col.f64()?;
+
+    let mut triples: Vec<(f64, f64, f64)> = Vec::new(); // (value, target, weight)
+    let (mut missing_w, mut missing_wy) = (0.0, 0.0);
+    for ((v, t), &w) in values.iter().zip(target_values).zip(weights) {
+        let Some(y) = *t else { continue };
+        match v {
+            Some(x) if !x.is_nan() => triples.push((x, y, w)),
+            _ => {
+                missing_w += w;
+                missing_wy += w * y;
+            }
+        }
+    }
+    triples.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    let n = triples.len();
+    // Bins of about equal weight; equal values never straddle a boundary
+    let weighted: Vec<(f64, f64)> = triples.iter().map(|&(v, _, w)| (v, w)).collect();
+    let mut ends: Vec<usize> = weighted_quantile_edges(&weighted, num_bins)
+        .iter()
+        .map(|&edge| triples.partition_point(|(v, _, _)| *v < edge))
+        .collect();
+    ends.push(n);
+    let mut bins = Vec::new();
+    let mut ss_between = 0.0;
+    let mut start = 0;
+    for end in ends {
+        if end == start {
+            continue;
+        }
+        let (count, wy) = triples[start..end]
+            .iter()
+            .fold((0.0, 0.0), |(c, s), (_, y, w)| (c + w, s + w * y));
+        let target_mean = if count > 0.0 { wy / count } else { 0.0 };
+        ss_between += totals.ss_between(count, target_mean);
+        bins.push(MeanBin {
+            lower_bound: if start == 0 {
+                f64::NEG_INFINITY
+            } else {
+                triples[start].0
+            },
+            upper_bound: if end < n {
+                triples[end].0
+            } else {
+                f64::INFINITY
+            },
+            count,
+            population_pct: totals.population_pct(count),
+            target_mean,
+        });
+        start = end;
+    }
+
+    let missing_bin = (missing_w > 0.0).then(|| {
+        let target_mean = missing_wy / missing_w;
+        ss_between += totals.ss_between(missing_w, target_mean);
+        MissingMeanBin {
+            count: missing_w,
+            population_pct: totals.population_pct(missing_w),
+            target_mean,
+        }
+    });
+
+    Ok(RSquaredAnalysis {
+        feature_name: col_name.to_string(),
+        feature_type: FeatureType::Numeric,
+        bins,
+        categories: Vec::new(),
+        missing_bin,
+        r_squared: (ss_between / totals.ss_total).clamp(0.0, 1.0),
+        correlation: weighted_pearson(&triples),
+    })
+}
+
+fn analyze_categorical_feature_r2(
+    df: &DataFrame,
+    col_name: &str,
+    target_values: &[Option<f64>],
+    weights: &[f64],
+    min_category_samples: usize,
+    totals: &R2Totals,
+) -> Result<RSquaredAnalysis> {
+    let str_col = df.column(col_name)?.cast(&DataType::String)?;
+    let values = str_col.str()?;
+
+    // category -> (rows, weighted count, weighted target sum)
+    let mut stats: std::collections::HashMap<&str, (usize, f64, f64)> =
+        std::collections::HashMap::new();
+    let (mut missing_w, mut missing_wy) = (0.0, 0.0);
+    for ((v, t), &w) in values.iter().zip(target_values).zip(weights) {
+        let Some(y) = *t else { continue };
+        match v {
+            Some(category) => {
+                let entry = stats.entry(category).or_insert((0, 0.0, 0.0));
+                entry.0 += 1;
+                entry.1 += w;
+                entry.2 += w * y;
+            }
+            None => {
+                missing_w += w;
+                missing_wy += w * y;
+            }
+        }
+    }
+
+    let mut categories: Vec<CategoricalMeanBin> = Vec::new();
+    let mut other = CategoricalMeanBin {
+        categories: Vec::new(),
+        count: 0.0,
+        population_pct: 0.0,
+        target_mean: 0.0,
+    };
+    let mut other_wy = 0.0;
+    let mut sorted: Vec<_> = stats.into_iter().collect();
+    sorted.sort_by(|a, b| a.0.cmp(b.0));
+    for (category, (rows, count, wy)) in sorted {
+        if rows < min_category_samples {
+            other.categories.push(category.to_string());
+            other.count += count;
+            other_wy += wy;
+        } else {
+            categories.push(CategoricalMeanBin {
+                categories: vec![category.to_string()],
+                count,
+                population_pct: totals.population_pct(count),
+                target_mean: if count > 0.0 { wy / count } else { 0.0 },
+            });
+        }
+    }
+    if !other.categories.is_empty() {
+        other.population_pct = totals.population_pct(other.count);
+        other.target_mean = if other.count > 0.0 {
+            other_wy / other.count
+        } else {
+            0.0
+        };
+        // A lone rare category keeps its own name
+        if other.categories.len() > 1 {
+            other.categories = vec![R2_OTHER_CATEGORY.to_string()];
+        }
+        categories.push(other);
+    }
+    categories.sort_by(|a, b| {
+        a.target_mean
+            .partial_cmp(&b.target_mean)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut ss_between: f64 = categories
+        .iter()
+        .map(|c| totals.ss_between(c.count, c.target_mean))
+        .sum();
+    let missing_bin = (missing_w > 0.0).then(|| {
+        let target_mean = missing_wy / missing_w;
+        ss_between += totals.ss_between(missing_w, target_mean);
+        MissingMeanBin {
+            count: missing_w,
+            population_pct: totals.population_pct(missing_w),
+            target_mean,
+        }
+    });
+
+    Ok(RSquaredAnalysis {
+        feature_name: col_name.to_string(),
+        feature_type: FeatureType::Categorical,
+        bins: Vec::new(),
+        categories,
+        missing_bin,
+        r_squared: (ss_between / totals.ss_total).clamp(0.0, 1.0),
+        correlation: None,
+    })
+}
+
+/// Weighted Pearson correlation of (value, target, weight) triples; `None`
+/// when either side has no variance
+fn weighted_pearson(triples: &[(f64, f64, f64)]) -> Option<f64> {
+    let sum_w: f64 = triples.iter().map(|(_, _, w)| w).sum();
+    if sum_w <= 0.0 {
+        return None;
+    }
+    let mean_x = triples.iter().map(|(x, _, w)| w * x).sum::<f64>() / sum_w;
+    let mean_y = triples.iter().map(|(_, y, w)| w * y).sum::<f64>() / sum_w;
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (x, y, w) in triples {
+        let (dx, dy) = (x - mean_x, y - mean_y);
+        cov += w * dx * dy;
+        var_x += w * dx * dx;
+        var_y += w * dy * dy;
+    }
+    if var_x <= f64::EPSILON || var_y <= f64::EPSILON {
+        return None;
+    }
+    Some((cov / (var_x * var_y).sqrt()).clamp(-1.0, 1.0))
+}
+
+/// Get list of features with R² below the threshold
+#[allow(dead_code)] // The binary always passes `--keep-columns`
+pub fn get_low_r2_features(analyses: &[RSquaredAnalysis], threshold: f64) -> Vec<String> {
+    get_low_r2_features_with_keep(analyses, threshold, &HashSet::new())
+}
+
+/// Like [`get_low_r2_features`], but features in `keep` are never dropped
+pub fn get_low_r2_features_with_keep(
+    analyses: &[RSquaredAnalysis],
+    threshold: f64,
+    keep: &HashSet<String>,
+) -> Vec<String> {
+    analyses
+        .iter()
+        .filter(|a| a.r_squared < threshold && !keep.contains(&a.feature_name))
         .map(|a| a.feature_name.clone())
         .collect()
 }
@@ -1911,6 +3290,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_erfc_known_values() {
+        assert!((erfc(0.0) - 1.0).abs() < 1e-6);
+        assert!((erfc(1.0) - 0.157_299_207).abs() < 1e-6);
+        assert!((erfc(2.5) - 4.069_520e-4).abs() < 1e-8);
+        // chi-square(1) critical value at 5%
+        assert!((chi_square_p_value_1df(3.841_459) - 0.05).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_gini_on_bins_matches_row_level_gini() {
+        let n = 300;
+        let feature: Vec<Option<f64>> = (0..n)
+            .map(|i| if i % 17 == 0 { None } else { Some(i as f64) })
+            .collect();
+        let target: Vec<i32> = (0..n).map(|i| i32::from((i * 7) % 10 < i / 40)).collect();
+        let df = df! { "feature" => feature, "target" => target }.unwrap();
+        let weights: Vec<f64> = (0..n).map(|i| 1.0 + (i % 3) as f64).collect();
+
+        let analyses = analyze_features_iv(
+            &df,
+            "target",
+            5,
+            20,
+            None,
+            BinningStrategy::Quantile,
+            None,
+            None,
+            &weights,
+            None,
+            None,
+            None,
+            MissingBinPolicy::Separate,
+            None,
+        )
+        .unwrap();
+        let analysis = &analyses[0];
+        assert!(analysis.bins.len() > 1 && analysis.missing_bin.is_some());
+
+        let from_bins = calculate_gini_on_bins_with_missing(
+            &analysis.bins,
+            &analysis.missing_bin,
+            &analysis.special_bins,
+        );
+        assert!(
+            (from_bins - analysis.gini).abs() < 1e-9,
+            "bin-level Gini {} should equal row-level Gini {}",
+            from_bins,
+            analysis.gini
+        );
+    }
+
     #[test]
     fn test_validate_binary_target_valid_int() {
         // Valid binary target with integers
@@ -2060,6 +3491,14 @@ mod tests {
             "CART".parse::<BinningStrategy>().unwrap(),
             BinningStrategy::Cart
         );
+        assert_eq!(
+            "equal-width".parse::<BinningStrategy>().unwrap(),
+            BinningStrategy::EqualWidth
+        );
+        assert_eq!(
+            "Manual".parse::<BinningStrategy>().unwrap(),
+            BinningStrategy::Manual
+        );
         assert!("invalid".parse::<BinningStrategy>().is_err());
     }
 
@@ -2067,6 +3506,8 @@ mod tests {
     fn test_binning_strategy_display() {
         assert_eq!(BinningStrategy::Quantile.to_string(), "quantile");
         assert_eq!(BinningStrategy::Cart.to_string(), "cart");
+        assert_eq!(BinningStrategy::EqualWidth.to_string(), "equal_width");
+        assert_eq!(BinningStrategy::Manual.to_string(), "manual");
     }
 
     #[test]
@@ -2138,6 +3579,39 @@ mod tests {
         assert!(analysis.iv >= 0.0, "IV should be non-negative");
     }
 
+    #[test]
+    fn test_quantile_prebins_follow_the_weights() {
+        // Rows 1-4 weigh 1 and rows 5-8 weigh 3: half the weight is reached
+        // at 6, where an unweighted split would stop at 4
+        let pairs: Vec<(f64, i32, f64)> = (1..=8)
+            .map(|v| {
+                (
+                    v as f64,
+                    i32::from(v % 2 == 0),
+                    if v <= 4 { 1.0 } else { 3.0 },
+                )
+            })
+            .collect();
+        let bins = create_quantile_prebins(&pairs, 2, 8.0, 8.0, 16.0);
+        assert_eq!(bins.len(), 2);
+        assert_eq!(bins[0].upper_bound, 7.0);
+        assert_eq!(bins[0].count, 10.0);
+        assert_eq!(bins[1].count, 6.0);
+    }
+
+    #[test]
+    fn test_quantile_prebins_keep_equal_values_together() {
+        let pairs: Vec<(f64, i32, f64)> = [1.0, 2.0, 2.0, 2.0, 2.0, 3.0]
+            .iter()
+            .map(|&v| (v, 0, 1.0))
+            .collect();
+        let bins = create_quantile_prebins(&pairs, 3, 0.0, 6.0, 6.0);
+        let counts: Vec<f64> = bins.iter().map(|b| b.count).collect();
+        assert_eq!(counts, [5.0, 1.0]);
+        assert_eq!(bins[0].upper_bound, 3.0);
+        assert_eq!(bins[1].lower_bound, 3.0);
+    }
+
     #[test]
     fn test_woebin_enhanced_fields() {
         // Create a simple bin and verify enhanced fields (with weight=1.0)
@@ -2198,6 +3672,9 @@ mod tests {
             5,
             &weights,
             None,
+            &[],
+            MissingBinPolicy::Separate,
+            None,
         );
         assert!(
             result.is_ok(),
@@ -2331,6 +3808,9 @@ mod tests {
             5,
             &weights,
             None,
+            &[],
+            MissingBinPolicy::Separate,
+            None,
         );
         assert!(
             result.is_ok(),
@@ -2346,6 +3826,266 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_numeric_feature_special_values_get_own_bins() {
+        // 30 ordinary values and 10 rows coded -999997, 8 of them events
+        let feature: Vec<f64> = (1..=30)
+            .map(f64::from)
+            .chain(std::iter::repeat_n(-999997.0, 10))
+            .collect();
+        let target_values: Vec<Option<i32>> = (0..40)
+            .map(|i| Some(if i < 30 { i % 2 } else { i32::from(i < 38) }))
+            .collect();
+        let df = df! { "feature" => feature }.unwrap();
+        let weights = vec![1.0; 40];
+
+        let analysis = analyze_single_numeric_feature(
+            &df,
+            "feature",
+            &target_values,
+            5,
+            10,
+            BinningStrategy::Quantile,
+            5,
+            &weights,
+            None,
+            &[-999997.0, -999998.0],
+            MissingBinPolicy::Separate,
+            None,
+        )
+        .unwrap();
+
+        // Only values present in the data get a bin
+        assert_eq!(analysis.special_bins.len(), 1);
+        let special = &analysis.special_bins[0];
+        assert_eq!(special.value, -999997.0);
+        assert_eq!(special.count, 10.0);
+        assert_eq!(special.events, 8.0);
+        assert!((special.population_pct - 25.0).abs() < 1e-9);
+        assert!(special.woe > 0.0, "Mostly events -> positive WoE");
+
+        // The sentinel rows stay out of the numeric ranges
+        let binned: f64 = analysis.bins.iter().map(|b| b.count).sum();
+        assert_eq!(binned, 30.0);
+
+        let bins_iv: f64 = analysis.bins.iter().map(|b| b.iv_contribution).sum();
+        assert!((analysis.iv - bins_iv - special.iv_contribution).abs() < 1e-12);
+        assert!(analysis.gini > 0.0);
+    }
+
+    fn unit_pairs(values: &[f64]) -> Vec<(f64, i32, f64)> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| (v, (i % 2) as i32, 1.0))
+            .collect()
+    }
+
+    #[test]
+    fn test_prebins_from_edges_use_the_breakpoints_as_bounds() {
+        let pairs = unit_pairs(&[1.0, 2.0, 5.0, 10.0, 11.0, 30.0, 31.0]);
+        let bins = create_prebins_from_edges(&pairs, &[5.0, 20.0], 3.0, 4.0, 7.0);
+
+        assert_eq!(bins.len(), 3);
+        assert_eq!(
+            bins.iter()
+                .map(|b| (b.lower_bound, b.upper_bound))
+                .collect::<Vec<_>>(),
+            [(1.0, 5.0), (5.0, 20.0), (20.0, f64::INFINITY)]
+        );
+        // A value on a breakpoint opens the band above it
+        assert_eq!(
+            bins.iter().map(|b| b.count).collect::<Vec<_>>(),
+            [2.0, 3.0, 2.0]
+        );
+        assert_eq!(find_bin_for_value(5.0, &bins), Some(1));
+    }
+
+    #[test]
+    fn test_prebins_from_edges_fold_empty_bands_into_the_bin_below() {
+        let pairs = unit_pairs(&[10.0, 11.0, 50.0, 51.0]);
+        // Nothing below 0, in [20, 30) or in [30, 40)
+        let bins = create_prebins_from_edges(&pairs, &[0.0, 20.0, 30.0, 40.0], 2.0, 2.0, 4.0);
+
+        assert_eq!(
+            bins.iter()
+                .map(|b| (b.lower_bound, b.upper_bound))
+                .collect::<Vec<_>>(),
+            [(10.0, 40.0), (40.0, f64::INFINITY)]
+        );
+        assert_eq!(find_bin_for_value(35.0, &bins), Some(0));
+    }
+
+    #[test]
+    fn test_equal_width_edges() {
+        let pairs = unit_pairs(&[0.0, 1.0, 2.0, 100.0]);
+        assert_eq!(equal_width_edges(&pairs, 4), [25.0, 50.0, 75.0]);
+        assert!(equal_width_edges(&unit_pairs(&[3.0, 3.0]), 4).is_empty());
+
+        // Skewed values leave the middle bands empty
+        let bins = create_prebins_from_edges(&pairs, &equal_width_edges(&pairs, 4), 2.0, 2.0, 4.0);
+        assert_eq!(bins.len(), 2);
+        assert_eq!(bins[0].count, 3.0);
+    }
+
+    #[test]
+    fn test_manual_binning_without_breaks_falls_back_to_cart() {
+        let feature: Vec<f64> = (0..200).map(f64::from).collect();
+        let target_values: Vec<Option<i32>> = (0..200).map(|i| Some(i32::from(i >= 120))).collect();
+        let df = df! { "feature" => feature }.unwrap();
+        let weights = vec![1.0; 200];
+        let analyze = |strategy, breaks| {
+            analyze_single_numeric_feature(
+                &df,
+                "feature",
+                &target_values,
+                5,
+                20,
+                strategy,
+                5,
+                &weights,
+                None,
+                &[],
+                MissingBinPolicy::Separate,
+                breaks,
+            )
+            .unwrap()
+        };
+
+        let manual = analyze(BinningStrategy::Manual, Some(&[50.0, 100.0, 150.0][..]));
+        assert_eq!(
+            manual
+                .bins
+                .iter()
+                .map(|b| b.upper_bound)
+                .collect::<Vec<_>>(),
+            [50.0, 100.0, 150.0, f64::INFINITY]
+        );
+
+        let fallback = analyze(BinningStrategy::Manual, None);
+        let cart = analyze(BinningStrategy::Cart, None);
+        assert_eq!(fallback.iv, cart.iv);
+    }
+
+    /// 40 values 1..=40 whose event rate rises with the value, then 10 nulls
+    /// with a 50% event rate
+    fn analyze_with_missing_policy(policy: MissingBinPolicy) -> IvAnalysis {
+        let feature: Vec<Option<f64>> = (1..=40)
+            .map(|v| Some(f64::from(v)))
+            .chain(std::iter::repeat_n(None, 10))
+            .collect();
+        let target_values: Vec<Option<i32>> = (0..50)
+            .map(|i| {
+                Some(if i < 40 {
+                    i32::from(i >= 28 || i % 5 == 0)
+                } else {
+                    i % 2
+                })
+            })
+            .collect();
+        let df = df! { "feature" => feature }.unwrap();
+        let weights = vec![1.0; 50];
+
+        analyze_single_numeric_feature(
+            &df,
+            "feature",
+            &target_values,
+            4,
+            8,
+            BinningStrategy::Quantile,
+            5,
+            &weights,
+            None,
+            &[],
+            policy,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_missing_bin_policy_separate_keeps_own_woe() {
+        let analysis = analyze_with_missing_policy(MissingBinPolicy::Separate);
+        let missing = analysis.missing_bin.as_ref().unwrap();
+        assert!(missing.is_separate());
+        assert_eq!(missing.count, 10.0);
+        assert!((missing.event_rate - 0.5).abs() < 1e-12);
+
+        let binned: f64 = analysis.bins.iter().map(|b| b.count).sum();
+        assert_eq!(binned, 40.0);
+        let bins_iv: f64 = analysis.bins.iter().map(|b| b.iv_contribution).sum();
+        assert!((analysis.iv - bins_iv - missing.iv_contribution).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_missing_bin_policy_merge_worst_joins_riskiest_bin() {
+        let separate = analyze_with_missing_policy(MissingBinPolicy::Separate);
+        let worst = (0..separate.bins.len())
+            .max_by(|&a, &b| {
+                separate.bins[a]
+                    .event_rate
+                    .total_cmp(&separate.bins[b].event_rate)
+            })
+            .unwrap();
+
+        let analysis = analyze_with_missing_policy(MissingBinPolicy::MergeWorst);
+        let missing = analysis.missing_bin.as_ref().unwrap();
+        assert_eq!(missing.merged_into, Some(worst));
+        // The missing rows keep their own counts for reporting...
+        assert_eq!(missing.count, 10.0);
+        assert!((missing.event_rate - 0.5).abs() < 1e-12);
+        // ...but are scored with the host bin, which now counts them
+        let host = &analysis.bins[worst];
+        assert_eq!(host.count, separate.bins[worst].count + 10.0);
+        assert_eq!(missing.woe, host.woe);
+        assert_eq!(missing.iv_contribution, 0.0);
+
+        let bins_iv: f64 = analysis.bins.iter().map(|b| b.iv_contribution).sum();
+        assert!((analysis.iv - bins_iv).abs() < 1e-12);
+        assert_eq!(bin_cells(&analysis).len(), analysis.bins.len());
+    }
+
+    #[test]
+    fn test_missing_bin_policy_impute_bins_at_median() {
+        let analysis = analyze_with_missing_policy(MissingBinPolicy::Impute);
+        let missing = analysis.missing_bin.as_ref().unwrap();
+        assert_eq!(missing.imputed_value, Some(20.0));
+
+        let host = missing.merged_into.unwrap();
+        assert_eq!(find_bin_for_value(20.0, &analysis.bins), Some(host));
+        assert_eq!(missing.woe, analysis.bins[host].woe);
+
+        let binned: f64 = analysis.bins.iter().map(|b| b.count).sum();
+        assert_eq!(binned, 50.0);
+        let bins_iv: f64 = analysis.bins.iter().map(|b| b.iv_contribution).sum();
+        assert!((analysis.iv - bins_iv).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_merge_insignificant_bins_follows_merged_missing_bin() {
+        let mut analyses = vec![analyze_with_missing_policy(MissingBinPolicy::MergeWorst)];
+        assert!(merge_insignificant_bins(&mut analyses, 0.0) > 0);
+
+        let analysis = &analyses[0];
+        let missing = analysis.missing_bin.as_ref().unwrap();
+        let host = missing.merged_into.unwrap();
+        assert!(host < analysis.bins.len());
+        assert_eq!(missing.woe, analysis.bins[host].woe);
+        let total: f64 = analysis.bins.iter().map(|b| b.count).sum();
+        assert_eq!(total, 50.0);
+    }
+
+    #[test]
+    fn test_missing_bin_policy_from_str() {
+        assert_eq!(
+            "merge-worst".parse::<MissingBinPolicy>(),
+            Ok(MissingBinPolicy::MergeWorst)
+        );
+        assert_eq!("IMPUTE".parse(), Ok(MissingBinPolicy::Impute));
+        assert!("drop".parse::<MissingBinPolicy>().is_err());
+        assert_eq!(MissingBinPolicy::MergeWorst.to_string(), "merge_worst");
+    }
+
     #[test]
     fn test_only_drops_records_with_invalid_target() {
         // Test that only records with invalid target (None) are dropped, not missing feature values
@@ -2382,6 +4122,9 @@ mod tests {
             5,
             &weights,
             None,
+            &[],
+            MissingBinPolicy::Separate,
+            None,
         );
         assert!(result.is_ok(), "Should analyze feature");
 
@@ -2421,6 +4164,9 @@ mod tests {
             5,
             &weights,
             None,
+            &[],
+            MissingBinPolicy::Separate,
+            None,
         );
         assert!(result.is_ok(), "Should handle all-missing feature values");
 
@@ -2479,6 +4225,9 @@ mod tests {
             5,
             &weights,
             None,
+            &[],
+            MissingBinPolicy::Separate,
+            None,
         );
         assert!(result.is_ok(), "Should analyze feature");
 
@@ -2535,6 +4284,9 @@ mod tests {
             5,
             &weights,
             None,
+            &[],
+            MissingBinPolicy::Separate,
+            None,
         );
         assert!(result.is_ok(), "Should analyze feature");
 
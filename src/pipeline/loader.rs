@@ -1,17 +1,94 @@
-//! Dataset loader for CSV and Parquet files
+//! Dataset loader for CSV (plain or compressed), Parquet, Arrow IPC (Feather),
+//! JSON Lines, Excel, SPSS and Stata files, for globs or directories of
+//! them (see [`super::multi_file`]) and for cloud objects (see [`super::cloud`])
 
 use anyhow::{Context, Result};
-use indicatif::{ProgressBar, ProgressStyle};
+use indicatif::{HumanBytes, ProgressBar, ProgressStyle};
 use polars::prelude::*;
-use std::fs::File;
-use std::io::{BufReader, Cursor, Read};
+use std::io::{BufRead, BufReader, Cursor, Read};
+use std::num::NonZeroUsize;
 use std::path::Path;
 
+use super::cloud::{self, is_cloud_uri};
+use super::compressed::{is_compressed_csv, CsvStream};
+use super::csv_dialect::CsvDialect;
+use super::multi_file::{input_files, is_multi_file_input, InputFiles};
 use super::progress::{PipelineStage, ProgressEvent, ProgressSender};
 
+/// Error for SAS7BDAT input in builds without the `sas7bdat` feature
+#[cfg(not(feature = "sas7bdat"))]
+pub const SAS7BDAT_DISABLED: &str =
+    "SAS7BDAT support is disabled in this build (enable the `sas7bdat` feature)";
+
+/// Error for Excel input in builds without the `xlsx` feature
+#[cfg(not(feature = "xlsx"))]
+pub const XLSX_DISABLED: &str =
+    "Excel support is disabled in this build (enable the `xlsx` feature)";
+
+/// Error for SPSS input in builds without the `sav` feature
+#[cfg(not(feature = "sav"))]
+pub const SAV_DISABLED: &str = "SPSS support is disabled in this build (enable the `sav` feature)";
+
+/// Error for Stata input in builds without the `dta` feature
+#[cfg(not(feature = "dta"))]
+pub const DTA_DISABLED: &str = "Stata support is disabled in this build (enable the `dta` feature)";
+
+/// Lines parsed per batch when reading JSON Lines
+const NDJSON_BATCH_ROWS: usize = 50_000;
+
+/// Whether `path` is an Excel workbook (`.xlsx`, `.xlsm`, `.xls`)
+pub fn is_excel_path(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| {
+            e.eq_ignore_ascii_case("xlsx")
+                || e.eq_ignore_ascii_case("xlsm")
+                || e.eq_ignore_ascii_case("xls")
+        })
+        .unwrap_or(false)
+}
+
+/// Worksheet names of an Excel workbook, in workbook order. Other formats
+/// hold a single table and return an empty list.
+pub fn get_sheet_names(path: &Path) -> Result<Vec<String>> {
+    if !is_excel_path(path) {
+        return Ok(Vec::new());
+    }
+    #[cfg(feature = "xlsx")]
+    {
+        super::excel::excel_sheet_names(path)
+    }
+    #[cfg(not(feature = "xlsx"))]
+    anyhow::bail!(XLSX_DISABLED)
+}
+
 /// Get column names from a dataset file without loading all data.
 /// Useful for interactive column selection.
+#[allow(dead_code)] // Library API; the binary passes the CSV dialect
 pub fn get_column_names(path: &Path) -> Result<Vec<String>> {
+    get_column_names_from_sheet(path, None, &CsvDialect::default())
+}
+
+/// [`get_column_names`] for a specific worksheet of an Excel workbook, or a
+/// CSV file in dialect `csv`. `sheet` is ignored for other formats; `None`
+/// reads the first sheet.
+pub fn get_column_names_from_sheet(
+    path: &Path,
+    sheet: Option<&str>,
+    csv: &CsvDialect,
+) -> Result<Vec<String>> {
+    if is_cloud_uri(path) {
+        let schema = cloud::scan(path, 100, csv)?
+            .collect_schema()
+            .with_context(|| format!("Failed to read the schema of {}", path.display()))?;
+        return Ok(schema.iter_names().map(|s| s.to_string()).collect());
+    }
+    if let Some(input) = input_files(path)? {
+        return input.column_names(csv);
+    }
+    if is_compressed_csv(path) {
+        return compressed_csv_columns(path, csv);
+    }
     let extension = path
         .extension()
         .and_then(|e| e.to_str())
@@ -20,7 +97,8 @@ pub fn get_column_names(path: &Path) -> Result<Vec<String>> {
 
     match extension.as_str() {
         "csv" => {
-            let mut lf = LazyCsvReader::new(path)
+            let mut lf = csv
+                .scan(path)
                 .with_infer_schema_length(Some(100))
                 .finish()
                 .with_context(|| format!("Failed to read CSV schema: {}", path.display()))?;
@@ -33,45 +111,165 @@ pub fn get_column_names(path: &Path) -> Result<Vec<String>> {
             let schema = lf.collect_schema()?;
             Ok(schema.iter_names().map(|s| s.to_string()).collect())
         }
+        "feather" | "arrow" | "ipc" => {
+            let mut lf = LazyFrame::scan_ipc(path, Default::default())
+                .with_context(|| format!("Failed to read Arrow IPC schema: {}", path.display()))?;
+            let schema = lf.collect_schema()?;
+            Ok(schema.iter_names().map(|s| s.to_string()).collect())
+        }
+        "jsonl" | "ndjson" => {
+            let mut lf = scan_ndjson(path, 100)?;
+            let schema = lf.collect_schema().with_context(|| {
+                format!("Failed to read JSON Lines schema: {}", path.display())
+            })?;
+            Ok(schema.iter_names().map(|s| s.to_string()).collect())
+        }
+        #[cfg(not(feature = "sas7bdat"))]
+        "sas7bdat" => anyhow::bail!(SAS7BDAT_DISABLED),
+        #[cfg(feature = "sas7bdat")]
         "sas7bdat" => {
             use super::sas7bdat::get_sas7bdat_columns;
             get_sas7bdat_columns(path).context("Failed to read SAS7BDAT columns")
         }
+        #[cfg(not(feature = "xlsx"))]
+        "xlsx" | "xlsm" | "xls" => {
+            let _ = sheet;
+            anyhow::bail!(XLSX_DISABLED)
+        }
+        #[cfg(feature = "xlsx")]
+        "xlsx" | "xlsm" | "xls" => super::excel::get_excel_columns(path, sheet),
+        #[cfg(not(feature = "sav"))]
+        "sav" => anyhow::bail!(SAV_DISABLED),
+        #[cfg(feature = "sav")]
+        "sav" => super::spss::get_sav_columns(path),
+        #[cfg(not(feature = "dta"))]
+        "dta" => anyhow::bail!(DTA_DISABLED),
+        #[cfg(feature = "dta")]
+        "dta" => super::stata::get_dta_columns(path),
         _ => anyhow::bail!(
-            "Unsupported file format: {}. Supported formats: csv, parquet, sas7bdat",
+            "Unsupported file format: {}. Supported formats: csv (also csv.gz, csv.zst, zip), parquet, feather, jsonl, sas7bdat, xlsx, sav, dta",
             extension
         ),
     }
 }
 
+/// Which columns of a dataset to load: an optional select-list (`--select-columns`)
+/// minus the drop-list (`--drop-columns`).
+///
+/// Columns the pipeline cannot run without (target, weight, split) are always
+/// loaded when selected-out, but a drop still removes them.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnProjection {
+    select: Vec<String>,
+    drop: Vec<String>,
+    always: Vec<String>,
+}
+
+impl ColumnProjection {
+    /// Load only `select` (every column when empty), minus `drop`
+    pub fn new(select: &[String], drop: &[String]) -> Self {
+        Self {
+            select: select.to_vec(),
+            drop: drop.to_vec(),
+            always: Vec::new(),
+        }
+    }
+
+    /// Also load `names` when a select-list leaves them out
+    pub fn with_always_loaded<'a>(mut self, names: impl IntoIterator<Item = &'a str>) -> Self {
+        self.always.extend(
+            names
+                .into_iter()
+                .filter(|n| !n.is_empty())
+                .map(String::from),
+        );
+        self
+    }
+
+    /// Whether every column is loaded
+    pub fn is_all(&self) -> bool {
+        self.select.is_empty() && self.drop.is_empty()
+    }
+
+    /// Whether column `name` is left out
+    pub fn excludes(&self, name: &str) -> bool {
+        let name = name.to_string();
+        self.drop.contains(&name)
+            || (!self.select.is_empty()
+                && !self.select.contains(&name)
+                && !self.always.contains(&name))
+    }
+
+    /// The columns to load out of `available` (the file's columns), in file
+    /// order, or `None` to load all of them.
+    ///
+    /// Selecting a column the file does not have is an error, as is a
+    /// projection that leaves nothing to load. Dropping an absent column is not.
+    pub fn resolve(&self, available: &[String]) -> Result<Option<Vec<String>>> {
+        if self.is_all() {
+            return Ok(None);
+        }
+        let missing: Vec<&str> = self
+            .select
+            .iter()
+            .filter(|c| !available.contains(c))
+            .map(String::as_str)
+            .collect();
+        if !missing.is_empty() {
+            anyhow::bail!(
+                "Selected column(s) not found in dataset: {}",
+                missing.join(", ")
+            );
+        }
+        let columns: Vec<String> = available
+            .iter()
+            .filter(|c| !self.excludes(c))
+            .cloned()
+            .collect();
+        if columns.is_empty() {
+            anyhow::bail!("No columns left to load after --select-columns and --drop-columns");
+        }
+        Ok(Some(columns))
+    }
+}
+
 /// Load a CSV file with a progress bar showing bytes read.
 /// When `progress_tx` is `Some`, sends `ProgressEvent::update` messages instead of
 /// writing to an indicatif bar.
+///
+/// Compressed files (see [`CsvStream`]) are decompressed as they are read; the
+/// bar then tracks the compressed bytes consumed and shows the decompressed size.
 fn load_csv_with_progress_inner(
     path: &Path,
     schema_length: Option<usize>,
+    columns: Option<&[String]>,
+    csv: &CsvDialect,
     progress_tx: Option<&ProgressSender>,
 ) -> Result<DataFrame> {
-    let file =
-        File::open(path).with_context(|| format!("Failed to open CSV file: {}", path.display()))?;
-    let file_size = file
-        .metadata()
-        .with_context(|| "Failed to get file metadata")?
-        .len();
+    let mut reader = CsvStream::open(path)?;
+    let file_size = reader.total();
+    let compression = reader.compression();
 
     // Read file with optional indicatif bar or channel updates
-    let mut reader = BufReader::with_capacity(1024 * 1024, file); // 1MB buffer
     let mut buffer = Vec::with_capacity(file_size as usize);
     let mut chunk = [0u8; 65536]; // 64KB read chunks
 
     // Only create indicatif bar in terminal-output mode
     let pb = if progress_tx.is_none() {
         let bar = ProgressBar::new(file_size);
+        let template = match compression {
+            Some(c) => format!(
+                "   Loading CSV ({}) [{{bar:40.cyan/blue}}] {{bytes}}/{{total_bytes}} ({{percent}}%) {{msg}} [{{eta}}]",
+                c.name()
+            ),
+            None => {
+                "   Loading CSV [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({percent}%) [{eta}]"
+                    .to_string()
+            }
+        };
         bar.set_style(
             ProgressStyle::default_bar()
-                .template(
-                    "   Loading CSV [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({percent}%) [{eta}]",
-                )
+                .template(&template)
                 .unwrap()
                 .progress_chars("=>-"),
         );
@@ -80,27 +278,44 @@ fn load_csv_with_progress_inner(
         None
     };
 
-    let mut bytes_read_total: u64 = 0;
     let update_interval = (file_size / 20).max(65536); // ~5% intervals
+    let mut last_update = 0;
 
     loop {
-        let bytes_read = reader.read(&mut chunk)?;
+        let bytes_read = reader
+            .read(&mut chunk)
+            .with_context(|| format!("Failed to read CSV file: {}", path.display()))?;
         if bytes_read == 0 {
             break;
         }
         buffer.extend_from_slice(&chunk[..bytes_read]);
-        bytes_read_total += bytes_read as u64;
+        let position = reader.position();
 
         if let Some(bar) = &pb {
-            bar.inc(bytes_read as u64);
+            bar.set_position(position);
+            if compression.is_some() {
+                bar.set_message(format!(
+                    "→ {} decompressed",
+                    HumanBytes(buffer.len() as u64)
+                ));
+            }
         } else if let Some(tx) = progress_tx {
             // Throttle channel updates to avoid flooding
-            if bytes_read_total % update_interval < bytes_read as u64 {
-                let pct = bytes_read_total * 100 / file_size.max(1);
+            if position / update_interval != last_update {
+                last_update = position / update_interval;
+                let pct = position * 100 / file_size.max(1);
+                let detail = match compression {
+                    Some(_) => format!(
+                        "{}% read, {} decompressed",
+                        pct,
+                        HumanBytes(buffer.len() as u64)
+                    ),
+                    None => format!("{}% read", pct),
+                };
                 tx.send(ProgressEvent::update(
                     PipelineStage::Loading,
                     "Loading dataset",
-                    format!("{}% read", pct),
+                    detail,
                 ))
                 .ok();
             }
@@ -128,44 +343,89 @@ fn load_csv_with_progress_inner(
         );
         parse_spinner.enable_steady_tick(std::time::Duration::from_millis(100));
 
-        let cursor = Cursor::new(buffer);
-        let df = CsvReadOptions::default()
-            .with_infer_schema_length(schema_length)
-            .with_rechunk(true)
-            .into_reader_with_file_handle(cursor)
-            .finish()
-            .with_context(|| format!("Failed to parse CSV file: {}", path.display()))?;
-
+        let df = parse_csv(buffer, path, schema_length, columns, csv)?;
         parse_spinner.finish_and_clear();
         return Ok(df);
     }
 
-    let cursor = Cursor::new(buffer);
-    let df = CsvReadOptions::default()
+    parse_csv(buffer, path, schema_length, columns, csv)
+}
+
+/// Parse CSV bytes, materializing only `columns` when given
+fn parse_csv(
+    buffer: Vec<u8>,
+    path: &Path,
+    schema_length: Option<usize>,
+    columns: Option<&[String]>,
+    csv: &CsvDialect,
+) -> Result<DataFrame> {
+    let df = csv
+        .read_options()
         .with_infer_schema_length(schema_length)
         .with_rechunk(true)
-        .into_reader_with_file_handle(cursor)
+        .with_columns(columns.map(|names| names.iter().map(PlSmallStr::from).collect()))
+        .into_reader_with_file_handle(Cursor::new(buffer))
         .finish()
         .with_context(|| format!("Failed to parse CSV file: {}", path.display()))?;
+    csv.parse_dates(df, schema_length)
+        .with_context(|| format!("Failed to parse CSV dates: {}", path.display()))
+}
 
-    Ok(df)
+/// Narrow a scan to `columns` so the reader skips the others
+fn project(lf: LazyFrame, columns: Option<&[String]>) -> LazyFrame {
+    match columns {
+        Some(names) => lf.select(
+            names
+                .iter()
+                .map(|name| col(name.as_str()))
+                .collect::<Vec<_>>(),
+        ),
+        None => lf,
+    }
+}
+
+/// Column names of a compressed CSV, from its header and first rows only
+fn compressed_csv_columns(path: &Path, csv: &CsvDialect) -> Result<Vec<String>> {
+    let df = compressed_csv_head(path, csv, 100)?;
+    Ok(df
+        .get_column_names()
+        .iter()
+        .map(|s| s.to_string())
+        .collect())
 }
 
-/// Load a CSV file with a progress bar showing bytes read (terminal / indicatif path).
-fn load_csv_with_progress(path: &Path, schema_length: Option<usize>) -> Result<DataFrame> {
-    load_csv_with_progress_inner(path, schema_length, None)
+/// The header and first `rows` rows of a compressed CSV, decompressing no
+/// further than needed
+fn compressed_csv_head(path: &Path, csv: &CsvDialect, rows: usize) -> Result<DataFrame> {
+    let mut reader = BufReader::new(CsvStream::open(path)?);
+    let mut sample = Vec::new();
+    for _ in 0..=rows {
+        if reader
+            .read_until(b'\n', &mut sample)
+            .with_context(|| format!("Failed to read CSV file: {}", path.display()))?
+            == 0
+        {
+            break;
+        }
+    }
+    csv.read_options()
+        .with_infer_schema_length(Some(rows.max(1)))
+        .into_reader_with_file_handle(Cursor::new(sample))
+        .finish()
+        .with_context(|| format!("Failed to read CSV schema: {}", path.display()))
 }
 
 /// Load a Parquet file (uses lazy scanning which is already fast)
-fn load_parquet(path: &Path) -> Result<DataFrame> {
+fn load_parquet(path: &Path, columns: Option<&[String]>) -> Result<DataFrame> {
     // Enable parallel row group reading for multi-core I/O
     let args = ScanArgsParquet {
         parallel: ParallelStrategy::Auto,
         ..Default::default()
     };
 
-    let mut df = LazyFrame::scan_parquet(path, args)
-        .with_context(|| format!("Failed to scan Parquet file: {}", path.display()))?
+    let lf = LazyFrame::scan_parquet(path, args)
+        .with_context(|| format!("Failed to scan Parquet file: {}", path.display()))?;
+    let mut df = project(lf, columns)
         .collect()
         .with_context(|| format!("Failed to collect Parquet file: {}", path.display()))?;
 
@@ -176,16 +436,61 @@ fn load_parquet(path: &Path) -> Result<DataFrame> {
     Ok(df)
 }
 
+/// Load an Arrow IPC / Feather v2 file (`.feather`, `.arrow`, `.ipc`)
+fn load_ipc(path: &Path, columns: Option<&[String]>) -> Result<DataFrame> {
+    let lf = LazyFrame::scan_ipc(path, Default::default())
+        .with_context(|| format!("Failed to scan Arrow IPC file: {}", path.display()))?;
+    let mut df = project(lf, columns)
+        .collect()
+        .with_context(|| format!("Failed to collect Arrow IPC file: {}", path.display()))?;
+
+    // IPC files written in record batches load as one chunk per batch
+    df.rechunk_mut();
+    Ok(df)
+}
+
+/// Lazily scan a JSON Lines file (`.jsonl`, `.ndjson`).
+///
+/// Column types are inferred from the first `infer_schema_length` lines (0
+/// scans the whole file), as for CSV. Lines are parsed in batches of
+/// `NDJSON_BATCH_ROWS`.
+pub fn scan_ndjson(path: &Path, infer_schema_length: usize) -> Result<LazyFrame> {
+    LazyJsonLineReader::new(path)
+        .with_infer_schema_length(NonZeroUsize::new(infer_schema_length))
+        .with_batch_size(NonZeroUsize::new(NDJSON_BATCH_ROWS))
+        .finish()
+        .with_context(|| format!("Failed to scan JSON Lines file: {}", path.display()))
+}
+
+/// Load a JSON Lines file into a single-chunk DataFrame
+fn load_ndjson(
+    path: &Path,
+    infer_schema_length: usize,
+    columns: Option<&[String]>,
+) -> Result<DataFrame> {
+    let mut df = project(scan_ndjson(path, infer_schema_length)?, columns)
+        .collect()
+        .with_context(|| format!("Failed to parse JSON Lines file: {}", path.display()))?;
+
+    // Each parsed batch is a separate chunk
+    df.rechunk_mut();
+    Ok(df)
+}
+
 /// Load dataset with progress bar and return DataFrame with statistics
 ///
 /// This is the preferred method for loading datasets as it:
 /// - Shows a progress bar for CSV files (based on bytes read)
 /// - Uses efficient parallel loading for Parquet files
+/// - Reads Arrow IPC / Feather files directly
+/// - Parses JSON Lines files in batches
+/// - Reads the first worksheet of Excel workbooks
+/// - Parses SPSS (`.sav`) and Stata (`.dta`) files natively
 /// - Returns the DataFrame directly along with statistics
 ///
 /// # Arguments
 /// * `path` - Path to the input file
-/// * `infer_schema_length` - Number of rows to use for schema inference (CSV only)
+/// * `infer_schema_length` - Number of rows to use for schema inference (CSV and JSON Lines)
 ///
 /// # Returns
 /// Tuple of (DataFrame, rows, columns, memory_mb)
@@ -193,29 +498,342 @@ pub fn load_dataset_with_progress(
     path: &Path,
     infer_schema_length: usize,
 ) -> Result<(DataFrame, usize, usize, f64)> {
-    load_dataset_impl(path, infer_schema_length, None)
+    load_dataset_impl(
+        path,
+        infer_schema_length,
+        None,
+        None,
+        &CsvDialect::default(),
+        None,
+    )
 }
 
 /// Load dataset and optionally send progress events over a channel instead of
 /// rendering indicatif bars to the terminal.
+#[allow(dead_code)]
 pub fn load_dataset_with_progress_channel(
     path: &Path,
     infer_schema_length: usize,
     progress_tx: &ProgressSender,
 ) -> Result<(DataFrame, usize, usize, f64)> {
-    load_dataset_impl(path, infer_schema_length, Some(progress_tx))
+    load_dataset_impl(
+        path,
+        infer_schema_length,
+        None,
+        None,
+        &CsvDialect::default(),
+        Some(progress_tx),
+    )
 }
 
-fn load_dataset_impl(
+/// Load dataset like [`load_dataset_with_progress`] (or its channel variant
+/// when `progress_tx` is `Some`), reading worksheet `sheet` of an Excel
+/// workbook. `sheet` is ignored for other formats; `None` reads the first sheet.
+pub fn load_dataset_from_sheet(
+    path: &Path,
+    infer_schema_length: usize,
+    sheet: Option<&str>,
+    progress_tx: Option<&ProgressSender>,
+) -> Result<(DataFrame, usize, usize, f64)> {
+    load_dataset_impl(
+        path,
+        infer_schema_length,
+        sheet,
+        None,
+        &CsvDialect::default(),
+        progress_tx,
+    )
+}
+
+/// [`load_dataset_from_sheet`] reading only `columns` (every column when
+/// `None`), typically resolved with [`ColumnProjection::resolve`], and CSV
+/// input in dialect `csv`.
+///
+/// The CSV, Parquet, Arrow IPC, JSON Lines and SAS7BDAT readers never
+/// materialize the other columns; Excel, SPSS and Stata files are read whole
+/// and narrowed afterwards.
+pub fn load_dataset_columns(
+    path: &Path,
+    infer_schema_length: usize,
+    sheet: Option<&str>,
+    columns: Option<&[String]>,
+    csv: &CsvDialect,
+    progress_tx: Option<&ProgressSender>,
+) -> Result<(DataFrame, usize, usize, f64)> {
+    load_dataset_impl(path, infer_schema_length, sheet, columns, csv, progress_tx)
+}
+
+/// The first `rows` rows of a dataset, typed as a full load would type them,
+/// for previewing the input before a run.
+///
+/// CSV, Parquet, Arrow IPC and JSON Lines files, globs, directories and cloud
+/// objects are read no further than `rows`; SAS7BDAT, Excel, SPSS and Stata
+/// files are loaded whole, without progress output, and cut down afterwards.
+pub fn preview_dataset(
+    path: &Path,
+    infer_schema_length: usize,
+    sheet: Option<&str>,
+    csv: &CsvDialect,
+    rows: usize,
+) -> Result<DataFrame> {
+    let limit = rows as IdxSize;
+    if is_cloud_uri(path) {
+        return Ok(cloud::scan(path, infer_schema_length, csv)?
+            .limit(limit)
+            .collect()?);
+    }
+    if let Some(input) = input_files(path)? {
+        return Ok(input
+            .scan(infer_schema_length, csv)?
+            .limit(limit)
+            .collect()?);
+    }
+    if is_compressed_csv(path) {
+        let df = compressed_csv_head(path, csv, rows)?;
+        return Ok(csv.parse_dates(df, None)?);
+    }
+    if !supports_streaming(path) {
+        // Nothing listens on the channel, so the load draws no progress bar
+        let (tx, _) = super::progress::create_progress_channel();
+        let (df, _, _, _) =
+            load_dataset_impl(path, infer_schema_length, sheet, None, csv, Some(&tx))?;
+        return Ok(df.head(Some(rows)));
+    }
+
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    let schema_length = NonZeroUsize::new(infer_schema_length).map(usize::from);
+    let df = match extension.as_str() {
+        "csv" => {
+            let df = csv
+                .scan(path)
+                .with_infer_schema_length(schema_length)
+                .with_n_rows(Some(rows))
+                .finish()
+                .with_context(|| format!("Failed to scan CSV file: {}", path.display()))?
+                .collect()
+                .with_context(|| format!("Failed to parse CSV file: {}", path.display()))?;
+            csv.parse_dates(df, None)?
+        }
+        "parquet" => LazyFrame::scan_parquet(path, Default::default())
+            .with_context(|| format!("Failed to scan Parquet file: {}", path.display()))?
+            .limit(limit)
+            .collect()?,
+        "jsonl" | "ndjson" => scan_ndjson(path, infer_schema_length)?
+            .limit(limit)
+            .collect()?,
+        _ => LazyFrame::scan_ipc(path, Default::default())
+            .with_context(|| format!("Failed to scan Arrow IPC file: {}", path.display()))?
+            .limit(limit)
+            .collect()?,
+    };
+    Ok(df)
+}
+
+/// Whether `path` names a dataset file lo-phi reads: CSV (plain or
+/// compressed), Parquet, Arrow IPC / Feather, JSON Lines, SAS7BDAT, Excel,
+/// SPSS or Stata
+pub fn is_dataset_path(path: &Path) -> bool {
+    const EXTENSIONS: [&str; 10] = [
+        "csv", "parquet", "feather", "arrow", "ipc", "jsonl", "ndjson", "sas7bdat", "sav", "dta",
+    ];
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| EXTENSIONS.iter().any(|ext| e.eq_ignore_ascii_case(ext)))
+        || is_excel_path(path)
+        || is_compressed_csv(path)
+}
+
+/// Whether [`load_dataset_streaming`] can read `path`: plain CSV, Parquet,
+/// Arrow IPC and JSON Lines, a glob or directory of them, or cloud objects
+pub fn supports_streaming(path: &Path) -> bool {
+    if is_multi_file_input(path) || is_cloud_uri(path) {
+        return true;
+    }
+    if is_compressed_csv(path) {
+        return false;
+    }
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    matches!(
+        extension.as_str(),
+        "csv" | "parquet" | "feather" | "arrow" | "ipc" | "jsonl" | "ndjson"
+    )
+}
+
+/// [`load_dataset_columns`] through polars' streaming engine.
+///
+/// The file is read in batches instead of whole, so the raw CSV bytes or all
+/// decoded Parquet row groups are never held next to the DataFrame. Only the
+/// formats of [`supports_streaming`] can be read this way; Excel workbooks
+/// are not, so there is no `sheet`.
+pub fn load_dataset_streaming(
     path: &Path,
     infer_schema_length: usize,
+    columns: Option<&[String]>,
+    csv: &CsvDialect,
     progress_tx: Option<&ProgressSender>,
 ) -> Result<(DataFrame, usize, usize, f64)> {
+    if is_cloud_uri(path) {
+        let lf = cloud::scan(path, infer_schema_length, csv)?;
+        return load_scan(lf, &path.display().to_string(), columns, progress_tx, true);
+    }
+    if let Some(input) = input_files(path)? {
+        return load_input_files(&input, infer_schema_length, columns, csv, progress_tx, true);
+    }
+    if !supports_streaming(path) {
+        anyhow::bail!(
+            "Streaming loads need plain CSV, Parquet, Feather or JSON Lines input, not {}",
+            path.display()
+        );
+    }
+    if let Some(tx) = progress_tx {
+        tx.send(ProgressEvent::update(
+            PipelineStage::Loading,
+            "Loading dataset",
+            "Streaming file in batches…",
+        ))
+        .ok();
+    }
     let extension = path
         .extension()
         .and_then(|e| e.to_str())
         .unwrap_or("")
         .to_lowercase();
+    let schema_length = NonZeroUsize::new(infer_schema_length).map(usize::from);
+    let lf = match extension.as_str() {
+        "csv" => {
+            let lf = csv
+                .scan(path)
+                .with_infer_schema_length(schema_length)
+                .finish()
+                .with_context(|| format!("Failed to scan CSV file: {}", path.display()))?;
+            csv.parse_dates_lazy(project(lf, columns), schema_length)
+                .with_context(|| format!("Failed to parse CSV dates: {}", path.display()))?
+        }
+        "parquet" => LazyFrame::scan_parquet(path, Default::default())
+            .with_context(|| format!("Failed to scan Parquet file: {}", path.display()))?,
+        "jsonl" | "ndjson" => scan_ndjson(path, infer_schema_length)?,
+        _ => LazyFrame::scan_ipc(path, Default::default())
+            .with_context(|| format!("Failed to scan Arrow IPC file: {}", path.display()))?,
+    };
+    // The CSV scan is already narrowed
+    let lf = if extension == "csv" {
+        lf
+    } else {
+        project(lf, columns)
+    };
+    let mut df = lf
+        .with_streaming(true)
+        .collect()
+        .with_context(|| format!("Failed to stream {}", path.display()))?;
+    // One chunk per batch; rechunked column by column
+    df.rechunk_mut();
+
+    let (rows, cols) = df.shape();
+    let memory_mb = df.estimated_size() as f64 / (1024.0 * 1024.0);
+    Ok((df, rows, cols, memory_mb))
+}
+
+/// Load the files of a glob or directory input as one DataFrame, through the
+/// streaming engine when `streaming` is set
+fn load_input_files(
+    input: &InputFiles,
+    infer_schema_length: usize,
+    columns: Option<&[String]>,
+    csv: &CsvDialect,
+    progress_tx: Option<&ProgressSender>,
+    streaming: bool,
+) -> Result<(DataFrame, usize, usize, f64)> {
+    let lf = input.scan(infer_schema_length, csv)?;
+    let source = format!("{} {} files", input.files.len(), input.format);
+    load_scan(lf, &source, columns, progress_tx, streaming)
+}
+
+/// Collect the projected scan of `source` (a cloud object or a set of input
+/// files), through the streaming engine when `streaming` is set
+fn load_scan(
+    lf: LazyFrame,
+    source: &str,
+    columns: Option<&[String]>,
+    progress_tx: Option<&ProgressSender>,
+    streaming: bool,
+) -> Result<(DataFrame, usize, usize, f64)> {
+    let message = format!("Reading {}…", source);
+    let spinner = match progress_tx {
+        Some(tx) => {
+            tx.send(ProgressEvent::update(
+                PipelineStage::Loading,
+                "Loading dataset",
+                message,
+            ))
+            .ok();
+            None
+        }
+        None => {
+            let spinner = ProgressBar::new_spinner();
+            spinner.set_style(
+                ProgressStyle::default_spinner()
+                    .template("   {spinner:.cyan} {msg}")
+                    .unwrap(),
+            );
+            spinner.set_message(message);
+            spinner.enable_steady_tick(std::time::Duration::from_millis(100));
+            Some(spinner)
+        }
+    };
+    let lf = project(lf, columns).with_streaming(streaming);
+    let mut df = lf
+        .collect()
+        .with_context(|| format!("Failed to load {}", source))?;
+    // One chunk per file (or batch); rechunked column by column
+    df.rechunk_mut();
+    if let Some(spinner) = spinner {
+        spinner.finish_and_clear();
+    }
+
+    let (rows, cols) = df.shape();
+    let memory_mb = df.estimated_size() as f64 / (1024.0 * 1024.0);
+    Ok((df, rows, cols, memory_mb))
+}
+
+fn load_dataset_impl(
+    path: &Path,
+    infer_schema_length: usize,
+    sheet: Option<&str>,
+    columns: Option<&[String]>,
+    csv: &CsvDialect,
+    progress_tx: Option<&ProgressSender>,
+) -> Result<(DataFrame, usize, usize, f64)> {
+    if is_cloud_uri(path) {
+        let lf = cloud::scan(path, infer_schema_length, csv)?;
+        return load_scan(lf, &path.display().to_string(), columns, progress_tx, false);
+    }
+    if let Some(input) = input_files(path)? {
+        return load_input_files(
+            &input,
+            infer_schema_length,
+            columns,
+            csv,
+            progress_tx,
+            false,
+        );
+    }
+    // Compressed CSV is decompressed by the CSV loader
+    let extension = if is_compressed_csv(path) {
+        "csv".to_string()
+    } else {
+        path.extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase()
+    };
 
     let schema_length = if infer_schema_length == 0 {
         None
@@ -223,25 +841,44 @@ fn load_dataset_impl(
         Some(infer_schema_length)
     };
 
-    let df = match extension.as_str() {
-        "csv" => {
+    let mut df = match extension.as_str() {
+        "csv" => load_csv_with_progress_inner(path, schema_length, columns, csv, progress_tx)?,
+        "parquet" => {
             if let Some(tx) = progress_tx {
-                load_csv_with_progress_inner(path, schema_length, Some(tx))?
-            } else {
-                load_csv_with_progress(path, schema_length)?
+                tx.send(ProgressEvent::update(
+                    PipelineStage::Loading,
+                    "Loading dataset",
+                    "Reading Parquet file…",
+                ))
+                .ok();
             }
+            load_parquet(path, columns)?
         }
-        "parquet" => {
+        "feather" | "arrow" | "ipc" => {
             if let Some(tx) = progress_tx {
                 tx.send(ProgressEvent::update(
                     PipelineStage::Loading,
                     "Loading dataset",
-                    "Reading Parquet file…",
+                    "Reading Arrow IPC file…",
+                ))
+                .ok();
+            }
+            load_ipc(path, columns)?
+        }
+        "jsonl" | "ndjson" => {
+            if let Some(tx) = progress_tx {
+                tx.send(ProgressEvent::update(
+                    PipelineStage::Loading,
+                    "Loading dataset",
+                    "Parsing JSON Lines file…",
                 ))
                 .ok();
             }
-            load_parquet(path)?
+            load_ndjson(path, infer_schema_length, columns)?
         }
+        #[cfg(not(feature = "sas7bdat"))]
+        "sas7bdat" => anyhow::bail!(SAS7BDAT_DISABLED),
+        #[cfg(feature = "sas7bdat")]
         "sas7bdat" => {
             // NOTE: schema_length is unused for SAS7BDAT files because column types are
             // encoded explicitly in the binary header (no schema inference needed).
@@ -256,26 +893,71 @@ fn load_dataset_impl(
             } else {
                 false
             };
-            if silent {
-                use super::sas7bdat::load_sas7bdat_silent;
-                let (mut df, _, _, _) =
-                    load_sas7bdat_silent(path).context("Failed to load SAS7BDAT file")?;
-                df.rechunk_mut();
-                df
-            } else {
-                use super::sas7bdat::load_sas7bdat;
-                let (mut df, _, _, _) =
-                    load_sas7bdat(path).context("Failed to load SAS7BDAT file")?;
-                df.rechunk_mut();
-                df
+            let (mut df, _, _, _) = super::sas7bdat::load_sas7bdat_columns(path, columns, silent)
+                .context("Failed to load SAS7BDAT file")?;
+            df.rechunk_mut();
+            df
+        }
+        #[cfg(not(feature = "xlsx"))]
+        "xlsx" | "xlsm" | "xls" => {
+            let _ = sheet;
+            anyhow::bail!(XLSX_DISABLED)
+        }
+        #[cfg(feature = "xlsx")]
+        "xlsx" | "xlsm" | "xls" => {
+            // Like SAS7BDAT, schema_length does not apply: every cell of a
+            // column is inspected to infer its type.
+            if let Some(tx) = progress_tx {
+                tx.send(ProgressEvent::update(
+                    PipelineStage::Loading,
+                    "Loading dataset",
+                    "Reading Excel workbook…",
+                ))
+                .ok();
             }
+            super::excel::load_excel(path, sheet).context("Failed to load Excel workbook")?
+        }
+        #[cfg(not(feature = "sav"))]
+        "sav" => anyhow::bail!(SAV_DISABLED),
+        #[cfg(feature = "sav")]
+        "sav" => {
+            if let Some(tx) = progress_tx {
+                tx.send(ProgressEvent::update(
+                    PipelineStage::Loading,
+                    "Loading dataset",
+                    "Reading SPSS file…",
+                ))
+                .ok();
+            }
+            super::spss::load_sav(path).context("Failed to load SPSS file")?
+        }
+        #[cfg(not(feature = "dta"))]
+        "dta" => anyhow::bail!(DTA_DISABLED),
+        #[cfg(feature = "dta")]
+        "dta" => {
+            if let Some(tx) = progress_tx {
+                tx.send(ProgressEvent::update(
+                    PipelineStage::Loading,
+                    "Loading dataset",
+                    "Reading Stata file…",
+                ))
+                .ok();
+            }
+            super::stata::load_dta(path).context("Failed to load Stata file")?
         }
         _ => anyhow::bail!(
-            "Unsupported file format: {}. Supported formats: csv, parquet, sas7bdat",
+            "Unsupported file format: {}. Supported formats: csv (also csv.gz, csv.zst, zip), parquet, feather, jsonl, sas7bdat, xlsx, sav, dta",
             extension
         ),
     };
 
+    // Formats read whole are narrowed here
+    if let Some(names) = columns {
+        if df.width() != names.len() {
+            df = df.select(names)?;
+        }
+    }
+
     let (rows, cols) = df.shape();
     let memory_mb = df.estimated_size() as f64 / (1024.0 * 1024.0);
 
@@ -0,0 +1,293 @@
+//! Memory budget for loading a dataset (`--max-memory-gb`)
+//!
+//! Before loading, the size of the DataFrame is estimated from the file: row
+//! count and column types for Parquet and Arrow IPC, the file size for the
+//! other formats. A dataset that would not fit the budget is rejected with
+//! ways to shrink it. One that fits, but not next to the extra copy the
+//! in-memory readers hold (the raw CSV bytes, or decoded Parquet batches),
+//! is loaded through polars' streaming engine instead.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+
+use super::cloud::{self, is_cloud_uri};
+use super::compressed::is_compressed_csv;
+use super::csv_dialect::CsvDialect;
+use super::loader::{scan_ndjson, supports_streaming};
+use super::multi_file::input_files;
+
+const BYTES_PER_GB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+/// Assumed size of one string value: the 16-byte view plus a short payload
+const STRING_BYTES_PER_VALUE: u64 = 32;
+
+/// How a dataset is read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadMode {
+    /// The format's regular reader
+    InMemory,
+    /// Polars' streaming engine, in batches
+    Streaming,
+}
+
+/// How to load a dataset within a memory budget
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoadPlan {
+    pub mode: LoadMode,
+    /// Estimated size of the loaded DataFrame in bytes; `None` when the file
+    /// gives no cheap estimate (compressed CSV, Excel)
+    pub estimated_bytes: Option<u64>,
+}
+
+/// Bytes in `gb` gigabytes
+pub fn gb_to_bytes(gb: f64) -> u64 {
+    (gb * BYTES_PER_GB) as u64
+}
+
+/// Gigabytes in `bytes`
+pub fn bytes_to_gb(bytes: u64) -> f64 {
+    bytes as f64 / BYTES_PER_GB
+}
+
+/// Estimate the size of `path` loaded as a DataFrame, counting only `columns`
+/// when given.
+///
+/// Parquet and Arrow IPC files are estimated from their row count and column
+/// types, strings at [`STRING_BYTES_PER_VALUE`] each. The other formats are
+/// taken at their file size, scaled to the share of columns loaded for CSV.
+/// A glob or directory input is estimated over all of its files. Compressed
+/// CSV and Excel files return `None`, as do cloud objects other than Parquet
+/// and Arrow IPC.
+pub fn estimate_dataset_bytes(
+    path: &Path,
+    infer_schema_length: usize,
+    columns: Option<&[String]>,
+) -> Result<Option<u64>> {
+    let files = input_files(path)?;
+    if files.is_none() && is_compressed_csv(path) {
+        return Ok(None);
+    }
+    let cloud = is_cloud_uri(path);
+    let (extension, file_size) = match &files {
+        Some(input) => (input.format.clone(), input.total_bytes()),
+        // Objects are only sized from Parquet and Arrow IPC metadata
+        None if cloud => (
+            path.extension()
+                .and_then(|e| e.to_str())
+                .unwrap_or("")
+                .to_lowercase(),
+            0,
+        ),
+        None => (
+            path.extension()
+                .and_then(|e| e.to_str())
+                .unwrap_or("")
+                .to_lowercase(),
+            std::fs::metadata(path)
+                .with_context(|| format!("Failed to read file metadata: {}", path.display()))?
+                .len(),
+        ),
+    };
+
+    let lf = match (extension.as_str(), &files) {
+        ("parquet" | "feather" | "arrow" | "ipc", None) if cloud => {
+            cloud::scan(path, infer_schema_length, &CsvDialect::default())?
+        }
+        (_, None) if cloud => return Ok(None),
+        ("parquet" | "feather" | "arrow" | "ipc", Some(input)) => {
+            input.scan(infer_schema_length, &CsvDialect::default())?
+        }
+        ("parquet", None) => LazyFrame::scan_parquet(path, Default::default())
+            .with_context(|| format!("Failed to scan Parquet file: {}", path.display()))?,
+        ("feather" | "arrow" | "ipc", None) => LazyFrame::scan_ipc(path, Default::default())
+            .with_context(|| format!("Failed to scan Arrow IPC file: {}", path.display()))?,
+        ("csv" | "jsonl" | "ndjson", _) => {
+            let Some(names) = columns else {
+                return Ok(Some(file_size));
+            };
+            let schema_rows = infer_schema_length.clamp(1, 100);
+            let mut lf = if let Some(input) = &files {
+                input.scan(schema_rows, &CsvDialect::default())?
+            } else if extension == "csv" {
+                LazyCsvReader::new(path)
+                    .with_infer_schema_length(Some(schema_rows))
+                    .finish()
+                    .with_context(|| format!("Failed to scan CSV file: {}", path.display()))?
+            } else {
+                scan_ndjson(path, schema_rows)?
+            };
+            let total = lf.collect_schema()?.len().max(1);
+            return Ok(Some(
+                file_size * names.len().min(total) as u64 / total as u64,
+            ));
+        }
+        ("xlsx" | "xlsm" | "xls", _) => return Ok(None),
+        _ => return Ok(Some(file_size)),
+    };
+
+    let mut lf = match columns {
+        Some(names) => lf.select(names.iter().map(|n| col(n.as_str())).collect::<Vec<_>>()),
+        None => lf,
+    };
+    let schema = lf.collect_schema()?;
+    let rows = lf
+        .select([len()])
+        .collect()?
+        .column("len")?
+        .cast(&DataType::UInt64)?
+        .u64()?
+        .get(0)
+        .unwrap_or(0);
+    let row_bytes: u64 = schema.iter_values().map(bytes_per_value).sum();
+    Ok(Some(rows * row_bytes))
+}
+
+/// Decide how to load `path` within `max_memory_gb`.
+///
+/// # Errors
+/// When the DataFrame alone would exceed the budget, or when the in-memory
+/// reader would and the format has no streaming reader.
+pub fn plan_load(
+    path: &Path,
+    infer_schema_length: usize,
+    columns: Option<&[String]>,
+    max_memory_gb: f64,
+) -> Result<LoadPlan> {
+    let budget = gb_to_bytes(max_memory_gb);
+    let Some(frame) = estimate_dataset_bytes(path, infer_schema_length, columns)? else {
+        return Ok(LoadPlan {
+            mode: LoadMode::InMemory,
+            estimated_bytes: None,
+        });
+    };
+    if frame > budget {
+        anyhow::bail!(over_budget_message(path, frame, max_memory_gb, "Estimated"));
+    }
+
+    let mode = if frame.saturating_add(reader_overhead(path, frame)) <= budget {
+        LoadMode::InMemory
+    } else if supports_streaming(path) {
+        LoadMode::Streaming
+    } else {
+        anyhow::bail!(
+            "Loading {} takes an estimated {:.2} GB next to the file's own data, over the \
+             --max-memory-gb budget of {:.2} GB, and this format has no streaming reader. \
+             Convert it to Parquet with `lophi convert` first, or raise --max-memory-gb.",
+            path.display(),
+            bytes_to_gb(frame.saturating_mul(2)),
+            max_memory_gb
+        );
+    };
+    Ok(LoadPlan {
+        mode,
+        estimated_bytes: Some(frame),
+    })
+}
+
+/// Fail when a loaded DataFrame is larger than `max_memory_gb`, for formats
+/// that could not be estimated beforehand or were underestimated
+pub fn check_loaded_size(df: &DataFrame, path: &Path, max_memory_gb: f64) -> Result<()> {
+    let size = df.estimated_size() as u64;
+    if size > gb_to_bytes(max_memory_gb) {
+        anyhow::bail!(over_budget_message(path, size, max_memory_gb, "Loaded"));
+    }
+    Ok(())
+}
+
+fn over_budget_message(path: &Path, bytes: u64, max_memory_gb: f64, kind: &str) -> String {
+    format!(
+        "{} size of {} is {:.2} GB, over the --max-memory-gb budget of {:.2} GB. \
+         Load fewer columns with --select-columns or --drop-columns, reduce a smaller \
+         extract from `lophi sample` and `lophi apply` its report to the full file, \
+         or raise --max-memory-gb.",
+        kind,
+        path.display(),
+        bytes_to_gb(bytes),
+        max_memory_gb
+    )
+}
+
+/// Memory the in-memory reader of `path` holds next to the DataFrame: the raw
+/// bytes of a CSV file, decoded batches before the rechunk for Parquet, Arrow
+/// IPC and JSON Lines. The other readers build the columns directly.
+fn reader_overhead(path: &Path, frame: u64) -> u64 {
+    if let Ok(Some(input)) = input_files(path) {
+        return match input.format.as_str() {
+            "csv" => input.total_bytes(),
+            _ => frame,
+        };
+    }
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    match extension.as_str() {
+        "csv" => std::fs::metadata(path).map_or(frame, |m| m.len()),
+        "parquet" | "feather" | "arrow" | "ipc" | "jsonl" | "ndjson" => frame,
+        _ => 0,
+    }
+}
+
+fn bytes_per_value(dtype: &DataType) -> u64 {
+    match dtype {
+        DataType::Boolean | DataType::Int8 | DataType::UInt8 => 1,
+        DataType::Int16 | DataType::UInt16 => 2,
+        DataType::Int32
+        | DataType::UInt32
+        | DataType::Float32
+        | DataType::Date
+        | DataType::Categorical(..)
+        | DataType::Enum(..) => 4,
+        DataType::Int64
+        | DataType::UInt64
+        | DataType::Float64
+        | DataType::Datetime(..)
+        | DataType::Duration(..)
+        | DataType::Time => 8,
+        _ => STRING_BYTES_PER_VALUE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_load_by_budget() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.parquet");
+        let mut df = df! {
+            "target" => vec![0i32; 10_000],
+            "x" => vec![1.5f64; 10_000],
+            "s" => vec!["abc"; 10_000],
+        }
+        .unwrap();
+        ParquetWriter::new(std::fs::File::create(&path).unwrap())
+            .finish(&mut df)
+            .unwrap();
+
+        // 10,000 rows of 4 + 8 + 32 bytes
+        let estimate = estimate_dataset_bytes(&path, 100, None).unwrap();
+        assert_eq!(estimate, Some(440_000));
+        let projected = ["x".to_string()];
+        assert_eq!(
+            estimate_dataset_bytes(&path, 100, Some(&projected)).unwrap(),
+            Some(80_000)
+        );
+
+        let gb = |bytes: u64| bytes_to_gb(bytes);
+        assert_eq!(
+            plan_load(&path, 100, None, gb(1_000_000)).unwrap().mode,
+            LoadMode::InMemory
+        );
+        assert_eq!(
+            plan_load(&path, 100, None, gb(600_000)).unwrap().mode,
+            LoadMode::Streaming
+        );
+        let err = plan_load(&path, 100, None, gb(400_000)).unwrap_err();
+        assert!(err.to_string().contains("--select-columns"));
+    }
+}
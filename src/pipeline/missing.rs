@@ -1,7 +1,13 @@
 //! Missing value analysis and reduction
 
+use std::collections::{BTreeMap, HashSet};
+
 use anyhow::Result;
 use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::segment::Segments;
+use super::stability::select_rows;
 
 /// Analyze missing values in the dataset with optional sample weights.
 ///
@@ -44,7 +50,8 @@ pub fn analyze_missing_values(
             .iter()
             .zip(weights.iter())
             .filter_map(|(val, &w)| if val.is_null() { Some(w) } else { None })
-            .sum();
+            // fold from +0.0; an empty f64 sum is -0.0, printed as "-0"
+            .fold(0.0, |acc, w| acc + w);
 
         let missing_ratio = weighted_null_count / total_weight;
         missing_ratios.push((col_name.to_string(), missing_ratio));
@@ -57,14 +64,127 @@ pub fn analyze_missing_values(
 }
 
 /// Get features to drop based on missing value threshold
+#[allow(dead_code)] // The binary always passes `--keep-columns`
 pub fn get_features_above_threshold(
     missing_ratios: &[(String, f64)],
     threshold: f64,
     target_column: &str,
+) -> Vec<String> {
+    get_features_above_threshold_with_keep(
+        missing_ratios,
+        threshold,
+        target_column,
+        &HashSet::new(),
+    )
+}
+
+/// Like [`get_features_above_threshold`], but features in `keep` are never dropped
+pub fn get_features_above_threshold_with_keep(
+    missing_ratios: &[(String, f64)],
+    threshold: f64,
+    target_column: &str,
+    keep: &HashSet<String>,
 ) -> Vec<String> {
     missing_ratios
         .iter()
-        .filter(|(name, ratio)| *ratio > threshold && name != target_column)
+        .filter(|(name, ratio)| *ratio > threshold && name != target_column && !keep.contains(name))
         .map(|(name, _)| name.clone())
         .collect()
 }
+
+/// Rows of one segment of `--segment-column`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentRows {
+    pub segment: String,
+    pub rows: usize,
+}
+
+/// Missing ratios of one feature in every segment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentMissingFeature {
+    pub feature: String,
+    /// Weighted missing ratio per segment
+    pub missing_ratios: BTreeMap<String, f64>,
+    /// Segments where every row of the feature is missing
+    pub fully_missing_in: Vec<String>,
+}
+
+/// Missing ratios per segment of `--segment-column`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentMissingAnalysis {
+    pub column: String,
+    /// Segments in label order
+    pub segments: Vec<SegmentRows>,
+    /// Rows with a null segment value, left out of every segment
+    pub unsegmented_rows: usize,
+    /// Every feature but `exclude` and the segment column, in column order
+    pub features: Vec<SegmentMissingFeature>,
+}
+
+impl SegmentMissingAnalysis {
+    /// Features fully missing in at least one segment
+    pub fn flagged(&self) -> Vec<&str> {
+        self.features
+            .iter()
+            .filter(|f| !f.fully_missing_in.is_empty())
+            .map(|f| f.feature.as_str())
+            .collect()
+    }
+}
+
+/// [`analyze_missing_values`] within each segment of `segment_column`.
+///
+/// Columns in `exclude` (e.g. the target) and the segment column itself are
+/// left out, as is `weight_column`. A feature is fully missing in a segment
+/// when its weighted missing ratio there is 1.
+pub fn analyze_missing_by_segment(
+    df: &DataFrame,
+    weights: &[f64],
+    weight_column: Option<&str>,
+    segment_column: &str,
+    exclude: &[&str],
+) -> Result<SegmentMissingAnalysis> {
+    let segments = Segments::from_column(df.column(segment_column)?)?;
+    let features: Vec<&str> = df
+        .get_column_names_str()
+        .into_iter()
+        .filter(|c| *c != segment_column && Some(*c) != weight_column && !exclude.contains(c))
+        .collect();
+    let mut entries: Vec<SegmentMissingFeature> = features
+        .iter()
+        .map(|f| SegmentMissingFeature {
+            feature: f.to_string(),
+            missing_ratios: BTreeMap::new(),
+            fully_missing_in: Vec::new(),
+        })
+        .collect();
+
+    let feature_df = df.select(features.iter().copied())?;
+    let mut rows = Vec::with_capacity(segments.labels.len());
+    for (index, label) in segments.labels.iter().enumerate() {
+        let (segment_df, segment_weights) =
+            select_rows(&feature_df, weights, &segments.mask(index))?;
+        let ratios: std::collections::HashMap<String, f64> =
+            analyze_missing_values(&segment_df, &segment_weights, None)?
+                .into_iter()
+                .collect();
+        for entry in &mut entries {
+            let ratio = ratios.get(&entry.feature).copied().unwrap_or(0.0);
+            if ratio >= 1.0 {
+                entry.fully_missing_in.push(label.clone());
+            }
+            entry.missing_ratios.insert(label.clone(), ratio);
+        }
+        rows.push(SegmentRows {
+            segment: label.clone(),
+            rows: segment_df.height(),
+        });
+    }
+
+    Ok(SegmentMissingAnalysis {
+        column: segment_column.to_string(),
+        segments: rows,
+        unsegmented_rows: segments.unsegmented_rows(),
+        features: entries,
+    })
+}
@@ -1,42 +1,234 @@
 //! Pipeline module - orchestrates the reduction steps
 
+pub mod apply;
+pub mod bin_breaks;
+#[cfg(any(feature = "sav", feature = "dta"))]
+mod byte_reader;
+pub mod cardinality;
+pub mod cart_tree;
+pub mod checkpoint;
+pub mod chunked_csv;
+pub mod cloud;
+pub mod compressed;
+pub mod concordance;
 pub mod correlation;
+pub mod cross_validation;
+pub mod csv_dialect;
+pub mod dictionary;
+pub mod distribution;
+pub mod dominance;
+#[cfg(feature = "xlsx")]
+pub mod excel;
+pub mod external_bins;
+pub mod fairness;
+pub mod fast_screen;
+pub mod imputation;
 pub mod iv;
 pub mod loader;
+pub mod memory;
 pub mod missing;
+pub mod multi_file;
+pub mod non_finite;
+pub mod parquet_options;
+pub mod pca;
 pub mod progress;
 pub mod sampling;
+#[cfg(feature = "sas7bdat")]
 pub mod sas7bdat;
+pub mod schema;
+pub mod segment;
 pub mod solver;
+pub mod special_values;
+pub mod split;
+#[cfg(feature = "sav")]
+pub mod spss;
+pub mod stability;
+#[cfg(feature = "dta")]
+pub mod stata;
+#[allow(dead_code)] // Library API; the binary only plans the order of its inline stages
+pub mod step;
 pub mod target;
+pub mod temporal;
+pub mod tighten;
+pub mod time_stability;
 pub mod weights;
+pub mod woe_transform;
+pub mod xpt;
 
 // Re-exports: some items only consumed by tests/benchmarks, not the binary crate
 #[allow(unused_imports)]
+pub use apply::{apply_saved_reduction, ApplySummary, SavedReduction};
+#[allow(unused_imports)]
+pub use bin_breaks::BinBreaks;
+#[allow(unused_imports)]
+pub use cardinality::{
+    analyze_cardinality, get_id_like_features_with_keep, CardinalityStats, IdAction, ID_MIN_VALUES,
+};
+#[allow(unused_imports)]
+pub use cart_tree::{fit_cart_trees, CartSplit, CartStopReason, CartTree, CartTreeNode};
+#[allow(unused_imports)]
+pub use checkpoint::{
+    checkpoint_dir, Checkpoint, CheckpointStage, CorrelationCheckpoint, GiniCheckpoint,
+    MissingCheckpoint, CHECKPOINT_DIR,
+};
+#[allow(unused_imports)]
+pub use chunked_csv::{ChunkedConversion, ChunkedOutput, CsvChunker, DEFAULT_CHUNK_BYTES};
+#[cfg(not(feature = "cloud"))]
+#[allow(unused_imports)]
+pub use cloud::CLOUD_DISABLED;
+#[allow(unused_imports)]
+pub use cloud::{
+    is_cloud_uri, local_anchor, object_metadata, staging_anchor, upload_dir, StagedOutput,
+};
+#[allow(unused_imports)]
+pub use compressed::{dataset_stem, is_compressed_csv, CsvCompression, CsvStream};
+#[allow(unused_imports)]
+pub use concordance::{
+    binning_limited_features, compute_concordance, ConcordanceStats, CONCORDANCE_SAMPLE_ROWS,
+};
+#[allow(unused_imports)]
 pub use correlation::{
-    compute_cramers_v, compute_eta, find_correlated_pairs, find_correlated_pairs_auto,
-    find_correlated_pairs_auto_with_progress, find_correlated_pairs_matrix,
-    select_features_to_drop, AssociationMeasure, CorrelatedPair, FeatureMetadata, FeatureToDrop,
+    compute_cramers_v, compute_eta, compute_theils_u, exclude_target_pairs, find_correlated_pairs,
+    find_correlated_pairs_auto, find_correlated_pairs_auto_with_progress,
+    find_correlated_pairs_matrix, pair_decisions, resolve_correlated_pairs,
+    resolved_pair_decisions, select_features_to_drop, select_features_to_drop_with_keep,
+    AssociationMeasure, AssociationThresholds, CorrKeepPolicy, CorrelatedPair, CorrelationStrategy,
+    DropRule, FeatureMetadata, FeatureToDrop, PairDecision, PairRationale,
+};
+#[allow(unused_imports)]
+pub use cross_validation::{
+    cross_validate_iv, fold_assignments, get_low_cv_iv_features, CrossValidatedIv, CrossValidation,
+    DEFAULT_CV_FOLDS,
+};
+#[allow(unused_imports)]
+pub use csv_dialect::{parse_csv_quote, parse_csv_separator, validate_csv_date_format, CsvDialect};
+#[allow(unused_imports)]
+pub use dictionary::{FeatureDescription, FeatureDictionary};
+#[allow(unused_imports)]
+pub use distribution::{
+    compute_distributions, compute_extreme_values, ExtremeValueStats, WeightedHistogram,
+    DISTRIBUTION_BUCKETS, EXTREME_IQR_MULTIPLE,
+};
+#[allow(unused_imports)]
+pub use dominance::{
+    analyze_dominance, get_dominated_features_with_keep, DominanceStats,
+    NO_TARGET_DOMINANCE_THRESHOLD,
+};
+#[allow(unused_imports)]
+pub use external_bins::{analyze_features_with_bins, ExternalBins, FeatureBins};
+#[allow(unused_imports)]
+pub use fairness::{
+    assess_proxy, protected_association, FairnessCheck, ProxyAssociation, ProxyScreen,
+    PROXY_IV_THRESHOLD,
+};
+#[allow(unused_imports)]
+pub use fast_screen::{
+    fast_screen_sample, fast_screen_sample_rows, FAST_SCREEN_BINS, FAST_SCREEN_CORRELATION_ROWS,
+};
+#[allow(unused_imports)]
+pub use imputation::{
+    build_imputation_plan, recommend_imputation, FeatureImputation, ImputationPlan,
+    ImputationStrategy, ImputedValue, MISSING_CATEGORY, MISSING_CATEGORY_SHARE, MISSING_WOE_SIGNAL,
 };
 #[allow(unused_imports)]
 pub use iv::{
-    analyze_features_iv, analyze_features_iv_with_progress, get_low_gini_features, BinningStrategy,
-    CategoricalWoeBin, FeatureType, IvAnalysis, MissingBin, WoeBin,
+    adjacent_bin_chi_square, analyze_features_iv, analyze_features_iv_with_progress,
+    analyze_features_r2, analyze_features_r2_with_progress, get_low_gini_features,
+    get_low_gini_features_with_keep, get_low_r2_features, get_low_r2_features_with_keep,
+    get_low_univariate_features, get_low_univariate_features_with_keep, ks_and_auc,
+    merge_insignificant_bins, mutual_information, BinningStrategy, CategoricalMeanBin,
+    CategoricalWoeBin, FeatureType, IvAnalysis, MeanBin, MissingBin, MissingBinPolicy,
+    MissingMeanBin, RSquaredAnalysis, SpecialBin, TargetType, UnivariateMetric, WoeBin,
 };
+#[cfg(not(feature = "dta"))]
+pub use loader::DTA_DISABLED;
+#[cfg(not(feature = "sas7bdat"))]
+pub use loader::SAS7BDAT_DISABLED;
+#[cfg(not(feature = "sav"))]
+pub use loader::SAV_DISABLED;
+#[cfg(not(feature = "xlsx"))]
+pub use loader::XLSX_DISABLED;
+#[allow(unused_imports)]
 pub use loader::{
-    get_column_names, load_dataset_with_progress, load_dataset_with_progress_channel,
+    get_column_names, get_column_names_from_sheet, get_sheet_names, is_dataset_path, is_excel_path,
+    load_dataset_columns, load_dataset_from_sheet, load_dataset_streaming,
+    load_dataset_with_progress, load_dataset_with_progress_channel, preview_dataset, scan_ndjson,
+    supports_streaming, ColumnProjection,
+};
+#[allow(unused_imports)]
+pub use memory::{check_loaded_size, estimate_dataset_bytes, plan_load, LoadMode, LoadPlan};
+#[allow(unused_imports)]
+pub use missing::{
+    analyze_missing_by_segment, analyze_missing_values, get_features_above_threshold,
+    get_features_above_threshold_with_keep, SegmentMissingAnalysis, SegmentMissingFeature,
+    SegmentRows,
+};
+#[allow(unused_imports)]
+pub use multi_file::{input_files, is_multi_file_input, output_anchor, InputFiles};
+#[allow(unused_imports)]
+pub use non_finite::{
+    apply_non_finite_policy, detect_non_finite, NonFiniteCounts, NonFinitePolicy,
+};
+#[allow(unused_imports)]
+pub use parquet_options::{ParquetCodec, ParquetOptions, ParquetStatistics};
+#[allow(unused_imports)]
+pub use pca::{
+    analyze_pca, FeatureLoading, PcaSettings, PcaSummary, PrincipalComponent,
+    DEFAULT_PCA_LOADING_THRESHOLD, MAX_PCA_FEATURES,
 };
-pub use missing::{analyze_missing_values, get_features_above_threshold};
 pub use progress::{
-    create_progress_channel, ConversionSummaryData, PipelineStage, ProgressEvent, ProgressSender,
-    SamplingSummaryData,
+    create_progress_channel, ConversionSummaryData, CorrelationReviewItem,
+    CorrelationReviewRequest, DropReviewItem, DropReviewRequest, PipelineStage, ProgressEvent,
+    ProgressSender, ResultsBin, ResultsFeature, ResultsPair, RunResults, SamplingSummaryData,
 };
 pub use sampling::{
-    analyze_strata, execute_sampling, SampleSize, SamplingConfig, SamplingMethod, StratumSpec,
+    analyze_strata, execute_sampling, sample_for_screening, SampleSize, SamplingConfig,
+    SamplingMethod, ScreeningSample, StratumSpec, DEFAULT_SEED,
+};
+#[allow(unused_imports)]
+pub use schema::{ExpectedSchema, RetypedColumn, SchemaColumn, SchemaDrift};
+#[allow(unused_imports)]
+pub use segment::{
+    assess_segment_iv, FeatureSegmentIv, SegmentFeatureIv, SegmentIvAnalysis, SegmentSummary,
+    Segments, MAX_SEGMENTS, MIN_DIVERGENT_SEGMENT_IV, SEGMENT_IV_DIVERGENCE_THRESHOLD,
+};
+pub use solver::{
+    BinningBackend, MonotonicityConstraint, SolverConfig, SolverStatus, SOLVER_AVAILABLE,
+};
+#[allow(unused_imports)]
+pub use special_values::SpecialValues;
+#[allow(unused_imports)]
+pub use split::{create_train_mask, train_partition};
+#[allow(unused_imports)]
+pub use stability::{
+    compare_iv, random_validation_mask, select_rows, IvStability, IvStabilityCheck,
+    DEFAULT_IV_STABILITY_THRESHOLD,
+};
+#[allow(unused_imports)]
+pub use step::{
+    run_step, ReductionStep, ScreeningStep, StepContext, StepOutcome, StepPlan, StepPosition,
 };
-pub use solver::{MonotonicityConstraint, SolverConfig};
 #[allow(unused_imports)]
 pub use target::{
-    analyze_target_column, count_mapped_records, create_target_mask, TargetAnalysis, TargetMapping,
+    analyze_target_column, check_target_classes, count_mapped_records, count_target_classes,
+    create_target_mask, write_mapped_target, EventLabels, TargetAnalysis, TargetClassCounts,
+    TargetMapping, RAW_TARGET_SUFFIX,
+};
+#[allow(unused_imports)]
+pub use temporal::{
+    apply_date_policy, calendar_dates, find_temporal_columns, DatePolicy, TemporalColumn,
+    DERIVED_DATE_SUFFIXES,
+};
+#[allow(unused_imports)]
+pub use tighten::{
+    apply_tightening, export_tighten_report, plan_tightening, PriorFeature, PriorReduction,
+    ThresholdChange, TightenDrop, TightenMetric, TightenReport, TightenThresholds,
+};
+#[allow(unused_imports)]
+pub use time_stability::{
+    assess_drift, FeatureDrift, FeaturePeriod, TimeFreq, TimePeriods, TimeStabilityCheck,
+    FILL_RATE_DRIFT_THRESHOLD, IV_DRIFT_THRESHOLD,
 };
 pub use weights::get_weights;
+#[allow(unused_imports)]
+pub use woe_transform::apply_woe_transform;
@@ -0,0 +1,517 @@
+//! Multi-file input: a glob (`--input 'data/data_part_*.parquet'`) or a
+//! directory read as one dataset
+//!
+//! The files must share a format that polars scans lazily: Parquet, Arrow
+//! IPC (Feather), plain CSV or JSON Lines. Each file is scanned on its own,
+//! the schemas are unified and the scans are concatenated in file-name order,
+//! so the loaders see a single lazy dataset. A column missing from a file is
+//! null for its rows; a column typed differently across files takes a type
+//! every file fits (the wider integer, a float, or text).
+//!
+//! Directories written with Hive partitioning (`lake/year=2024/region=north/
+//! part-0.parquet`) are read through their `key=value` folders, and each key
+//! becomes a column holding the folder's value for the rows of its files.
+
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+
+use super::cloud::is_cloud_uri;
+use super::csv_dialect::CsvDialect;
+use super::loader::scan_ndjson;
+
+/// Formats that can be combined from several files
+const MULTI_FILE_FORMATS: [&str; 7] = [
+    "parquet", "feather", "arrow", "ipc", "csv", "jsonl", "ndjson",
+];
+
+/// Whether `path` contains glob wildcards (`*`, `?` or `[`)
+pub fn is_glob(path: &Path) -> bool {
+    path.to_str().is_some_and(|s| s.contains(['*', '?', '[']))
+}
+
+/// Whether `path` names several local files: a glob or a directory. Globs
+/// in cloud URIs are expanded by Polars' cloud readers instead.
+pub fn is_multi_file_input(path: &Path) -> bool {
+    !is_cloud_uri(path) && (is_glob(path) || path.is_dir())
+}
+
+/// Folder value Hive writes for a null partition key
+const HIVE_NULL_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// The files of a glob or directory input, sorted by path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputFiles {
+    pub files: Vec<PathBuf>,
+    /// Lowercase extension shared by the files
+    pub format: String,
+    /// Hive partition keys of the `key=value` folders, outermost first
+    pub partition_keys: Vec<String>,
+    /// Partition values of each file in `partition_keys` order; `None` for
+    /// Hive's null partition
+    pub partition_values: Vec<Vec<Option<String>>>,
+}
+
+/// Expand a glob or directory input. Returns `None` for a single file.
+///
+/// A directory contributes its data files and those of its Hive `key=value`
+/// subdirectories (other subdirectories are skipped); hidden files and
+/// marker files starting with `_` (such as Spark's `_SUCCESS`) are skipped.
+/// The `key=value` folders between the input folder and each file are its
+/// partitions, for directories and globs alike.
+///
+/// # Errors
+/// When nothing matches, the files mix formats, the format cannot be
+/// combined (SAS7BDAT, Excel, SPSS, Stata, compressed CSV), or the files
+/// are not all partitioned by the same keys.
+pub fn input_files(path: &Path) -> Result<Option<InputFiles>> {
+    if !is_multi_file_input(path) {
+        return Ok(None);
+    }
+    let mut files: Vec<PathBuf> = if path.is_dir() {
+        let mut files = Vec::new();
+        directory_files(path, &mut files)?;
+        files
+    } else {
+        let pattern = path.to_string_lossy();
+        glob::glob(&pattern)
+            .with_context(|| format!("Invalid glob pattern: {}", pattern))?
+            .filter_map(|entry| entry.ok())
+            .filter(|file| file.is_file())
+            .collect()
+    };
+    files.sort();
+
+    let Some(first) = files.first() else {
+        anyhow::bail!("No data files match {}", path.display());
+    };
+    let format = extension(first);
+    if let Some(other) = files.iter().find(|file| extension(file) != format) {
+        anyhow::bail!(
+            "{} mixes .{} and .{} files ({} and {}). Narrow the input to one format, e.g. {}",
+            path.display(),
+            format,
+            extension(other),
+            first.display(),
+            other.display(),
+            example_glob(path, &format)
+        );
+    }
+    if !MULTI_FILE_FORMATS.contains(&format.as_str()) {
+        anyhow::bail!(
+            "Only Parquet, Feather, plain CSV and JSON Lines files can be read as one dataset, \
+             not .{} files ({}). Convert them with `lophi convert` first.",
+            format,
+            path.display()
+        );
+    }
+
+    let folder = input_folder(path);
+    let partitions: Vec<Vec<(String, Option<String>)>> = files
+        .iter()
+        .map(|file| file_partitions(file, &folder))
+        .collect();
+    let partition_keys: Vec<String> = partitions[0].iter().map(|(key, _)| key.clone()).collect();
+    for (file, partition) in files.iter().zip(&partitions) {
+        let keys: Vec<&str> = partition.iter().map(|(key, _)| key.as_str()).collect();
+        if keys != partition_keys {
+            anyhow::bail!(
+                "{} is partitioned by [{}] but {} by [{}]; every file needs the same \
+                 key=value folders",
+                first.display(),
+                partition_keys.join(", "),
+                file.display(),
+                keys.join(", ")
+            );
+        }
+    }
+    let partition_values = partitions
+        .into_iter()
+        .map(|partition| partition.into_iter().map(|(_, value)| value).collect())
+        .collect();
+    Ok(Some(InputFiles {
+        files,
+        format,
+        partition_keys,
+        partition_values,
+    }))
+}
+
+/// The single-file path that outputs derived from `input` are named after.
+///
+/// For a glob or directory this is `<folder>.<format>` next to the folder
+/// holding the files (the directory itself, or the last directory of the
+/// glob before its first wildcard), so default outputs never land among
+/// the inputs; a glob in a cloud URI is named the same way. Other paths are
+/// returned unchanged.
+pub fn output_anchor(input: &Path) -> PathBuf {
+    let cloud_glob = is_cloud_uri(input) && is_glob(input);
+    if !is_multi_file_input(input) && !cloud_glob {
+        return input.to_path_buf();
+    }
+    let folder = input_folder(input);
+    let folder = if folder.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        folder
+    };
+    // `.` and `..` have no name of their own
+    let folder = folder.canonicalize().unwrap_or(folder);
+    let format = input_files(input)
+        .ok()
+        .flatten()
+        .map(|input| input.format)
+        .unwrap_or_else(|| match extension(input) {
+            ext if ext.is_empty() => "parquet".to_string(),
+            ext => ext,
+        });
+    let name = folder
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("dataset");
+    folder
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join(format!("{}.{}", name, format))
+}
+
+impl InputFiles {
+    /// Combined size of the files in bytes
+    pub fn total_bytes(&self) -> u64 {
+        self.files
+            .iter()
+            .filter_map(|file| std::fs::metadata(file).ok())
+            .map(|m| m.len())
+            .sum()
+    }
+
+    /// Latest modification time of the files
+    pub fn last_modified(&self) -> Option<SystemTime> {
+        self.files
+            .iter()
+            .filter_map(|file| std::fs::metadata(file).ok()?.modified().ok())
+            .max()
+    }
+
+    /// The file paths as text, for reports
+    pub fn names(&self) -> Vec<String> {
+        self.files
+            .iter()
+            .map(|file| file.to_string_lossy().to_string())
+            .collect()
+    }
+
+    /// Column names of the combined dataset, in order of first appearance
+    pub fn column_names(&self, csv: &CsvDialect) -> Result<Vec<String>> {
+        let mut lf = self.scan(100, csv)?;
+        let schema = lf.collect_schema()?;
+        Ok(schema.iter_names().map(|s| s.to_string()).collect())
+    }
+
+    /// Lazily scan all files as one dataset with a unified schema.
+    ///
+    /// `infer_schema_length` applies to each CSV and JSON Lines file (0 scans
+    /// whole files); CSV files are read in dialect `csv`. Partition keys
+    /// follow the file columns, typed by `partition_dtype()`; a file that
+    /// stores a key as a column of its own keeps its values.
+    pub fn scan(&self, infer_schema_length: usize, csv: &CsvDialect) -> Result<LazyFrame> {
+        let schema_length = NonZeroUsize::new(infer_schema_length).map(usize::from);
+        let mut scans = Vec::with_capacity(self.files.len());
+        for file in &self.files {
+            let mut lf = match self.format.as_str() {
+                "csv" => {
+                    let lf = csv
+                        .scan(file)
+                        .with_infer_schema_length(schema_length)
+                        .finish()
+                        .with_context(|| format!("Failed to scan CSV file: {}", file.display()))?;
+                    csv.parse_dates_lazy(lf, schema_length)
+                        .with_context(|| format!("Failed to parse CSV dates: {}", file.display()))?
+                }
+                "parquet" => LazyFrame::scan_parquet(file, Default::default())
+                    .with_context(|| format!("Failed to scan Parquet file: {}", file.display()))?,
+                "jsonl" | "ndjson" => scan_ndjson(file, infer_schema_length)?,
+                _ => LazyFrame::scan_ipc(file, Default::default()).with_context(|| {
+                    format!("Failed to scan Arrow IPC file: {}", file.display())
+                })?,
+            };
+            let schema = lf
+                .collect_schema()
+                .with_context(|| format!("Failed to read the schema of {}", file.display()))?;
+            scans.push((lf, schema));
+        }
+
+        let mut unified = Schema::default();
+        for (_, schema) in &scans {
+            for (name, dtype) in schema.iter() {
+                let dtype = match unified.get(name) {
+                    Some(existing) => unify_dtypes(existing, dtype),
+                    None => dtype.clone(),
+                };
+                unified.with_column(name.clone(), dtype);
+            }
+        }
+        for (index, key) in self.partition_keys.iter().enumerate() {
+            let values = self.partition_values.iter().map(|v| v[index].as_deref());
+            let dtype = partition_dtype(values);
+            let dtype = match unified.get(key.as_str()) {
+                Some(existing) => unify_dtypes(existing, &dtype),
+                None => dtype,
+            };
+            unified.with_column(key.into(), dtype);
+        }
+
+        let aligned: Vec<LazyFrame> = scans
+            .into_iter()
+            .zip(&self.partition_values)
+            .map(|((lf, schema), values)| {
+                let columns: Vec<Expr> = unified
+                    .iter()
+                    .map(|(name, dtype)| {
+                        let key = self.partition_keys.iter().position(|key| key == name);
+                        if schema.contains(name) {
+                            col(name.clone()).cast(dtype.clone())
+                        } else if let Some(index) = key {
+                            partition_literal(values[index].as_deref(), dtype).alias(name.clone())
+                        } else {
+                            lit(NULL).cast(dtype.clone()).alias(name.clone())
+                        }
+                    })
+                    .collect();
+                lf.select(columns)
+            })
+            .collect();
+        concat(aligned, UnionArgs::default()).context("Failed to combine the input files")
+    }
+}
+
+/// A type that values of both `a` and `b` fit
+fn unify_dtypes(a: &DataType, b: &DataType) -> DataType {
+    match (a, b) {
+        _ if a == b => a.clone(),
+        (DataType::Null, other) | (other, DataType::Null) => other.clone(),
+        _ if a.is_integer() && b.is_integer() => DataType::Int64,
+        _ if a.is_primitive_numeric() && b.is_primitive_numeric() => DataType::Float64,
+        _ => DataType::String,
+    }
+}
+
+/// Type of a partition key: integers, floats or ISO dates when every non-null
+/// value parses as one, text otherwise
+fn partition_dtype<'a>(values: impl Iterator<Item = Option<&'a str>> + Clone) -> DataType {
+    let mut present = values.flatten();
+    if present.clone().next().is_none() {
+        DataType::String
+    } else if present.clone().all(|v| v.parse::<i64>().is_ok()) {
+        DataType::Int64
+    } else if present.clone().all(|v| v.parse::<f64>().is_ok()) {
+        DataType::Float64
+    } else if present.all(|v| chrono::NaiveDate::parse_from_str(v, "%Y-%m-%d").is_ok()) {
+        DataType::Date
+    } else {
+        DataType::String
+    }
+}
+
+/// A partition value as a literal of `dtype` (as picked by `partition_dtype()`)
+fn partition_literal(value: Option<&str>, dtype: &DataType) -> Expr {
+    let Some(value) = value else {
+        return lit(NULL).cast(dtype.clone());
+    };
+    let literal = match dtype {
+        DataType::Int64 => value.parse::<i64>().map(lit).ok(),
+        DataType::Float64 => value.parse::<f64>().map(lit).ok(),
+        DataType::Date => chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map(lit)
+            .ok(),
+        _ => None,
+    };
+    // Values of a key that a file also stores may need a cast
+    literal.unwrap_or_else(|| lit(value)).cast(dtype.clone())
+}
+
+/// The folder a glob or directory input starts from: the directory itself,
+/// or the last directory of the glob before its first wildcard
+fn input_folder(input: &Path) -> PathBuf {
+    if input.is_dir() {
+        input.to_path_buf()
+    } else {
+        input
+            .ancestors()
+            .skip(1)
+            .find(|dir| !is_glob(dir))
+            .map(Path::to_path_buf)
+            .unwrap_or_default()
+    }
+}
+
+/// Collect the data files of `dir` and its `key=value` subdirectories
+fn directory_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    let entries = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to list directory: {}", dir.display()))?;
+    for path in entries.filter_map(|entry| entry.ok().map(|e| e.path())) {
+        if path.is_dir() {
+            let partition = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .and_then(partition);
+            if partition.is_some() {
+                directory_files(&path, files)?;
+            }
+        } else if path.is_file() && is_data_file(&path) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// The `key=value` folders of `file` below `folder`, outermost first
+fn file_partitions(file: &Path, folder: &Path) -> Vec<(String, Option<String>)> {
+    let parent = file.parent().unwrap_or_else(|| Path::new(""));
+    parent
+        .strip_prefix(folder)
+        .unwrap_or(parent)
+        .components()
+        .filter_map(|c| c.as_os_str().to_str().and_then(partition))
+        .collect()
+}
+
+/// Parse a Hive folder name `key=value`. Values are percent-decoded, and
+/// Hive's default partition is null.
+fn partition(folder: &str) -> Option<(String, Option<String>)> {
+    if folder.starts_with(['.', '_']) {
+        return None;
+    }
+    let (key, value) = folder.split_once('=')?;
+    if key.is_empty() {
+        return None;
+    }
+    let value = (value != HIVE_NULL_PARTITION).then(|| percent_decode(value));
+    Some((percent_decode(key), value))
+}
+
+/// Undo the `%XX` escapes Hive and Spark write for special characters
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = (bytes[i] == b'%')
+            .then(|| text.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match hex {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn extension(path: &Path) -> String {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase()
+}
+
+/// Files of a directory input: not hidden, not markers, with an extension
+fn is_data_file(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+    !name.starts_with('.') && !name.starts_with('_') && path.extension().is_some()
+}
+
+fn example_glob(path: &Path, format: &str) -> String {
+    let folder = if path.is_dir() {
+        path
+    } else {
+        path.parent().unwrap_or_else(|| Path::new("."))
+    };
+    folder.join(format!("*.{}", format)).display().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unify_dtypes() {
+        use DataType::*;
+        assert_eq!(unify_dtypes(&Int32, &Int32), Int32);
+        assert_eq!(unify_dtypes(&Null, &Float32), Float32);
+        assert_eq!(unify_dtypes(&Int32, &UInt8), Int64);
+        assert_eq!(unify_dtypes(&Int64, &Float32), Float64);
+        assert_eq!(unify_dtypes(&Int64, &String), String);
+        assert_eq!(unify_dtypes(&Boolean, &Int8), String);
+    }
+
+    #[test]
+    fn test_glob_detection_and_output_anchor() {
+        assert!(is_glob(Path::new("data/part_*.parquet")));
+        assert!(is_glob(Path::new("data/part_[0-9].csv")));
+        assert!(!is_glob(Path::new("data/part_1.parquet")));
+        assert_eq!(
+            output_anchor(Path::new("data/part_1.parquet")),
+            PathBuf::from("data/part_1.parquet")
+        );
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let parts = dir.path().join("landing");
+        std::fs::create_dir(&parts).unwrap();
+        std::fs::write(parts.join("part_1.csv"), "a\n1\n").unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let expected = root.join("landing.csv");
+        assert_eq!(output_anchor(&parts), expected);
+        assert_eq!(output_anchor(&parts.join("part_*.csv")), expected);
+        assert_eq!(
+            output_anchor(Path::new("s3://bucket/landing/part_*.csv")),
+            PathBuf::from("s3://bucket/landing.csv")
+        );
+    }
+
+    #[test]
+    fn test_hive_partition_folders() {
+        assert_eq!(
+            partition("year=2024"),
+            Some(("year".to_string(), Some("2024".to_string())))
+        );
+        assert_eq!(
+            partition("city=New%20York"),
+            Some(("city".to_string(), Some("New York".to_string())))
+        );
+        assert_eq!(
+            partition("region=__HIVE_DEFAULT_PARTITION__"),
+            Some(("region".to_string(), None))
+        );
+        assert_eq!(partition("landing"), None);
+        assert_eq!(partition("_temporary=1"), None);
+        assert_eq!(
+            file_partitions(
+                Path::new("lake/year=2024/month=1/part-0.parquet"),
+                Path::new("lake")
+            ),
+            vec![
+                ("year".to_string(), Some("2024".to_string())),
+                ("month".to_string(), Some("1".to_string()))
+            ]
+        );
+
+        let dtype = |values: &[Option<&str>]| partition_dtype(values.iter().copied());
+        assert_eq!(dtype(&[Some("2024"), None]), DataType::Int64);
+        assert_eq!(dtype(&[Some("1"), Some("1.5")]), DataType::Float64);
+        assert_eq!(dtype(&[Some("2024-01-31")]), DataType::Date);
+        assert_eq!(dtype(&[Some("north"), Some("2")]), DataType::String);
+        assert_eq!(dtype(&[None]), DataType::String);
+    }
+}
@@ -0,0 +1,181 @@
+//! Detection and handling of non-finite values (NaN, +Inf, -Inf) in numeric features
+//!
+//! Floating-point columns can carry NaN and infinite values that polars does not
+//! treat as null. Left alone they flow into binning (where they sort to the
+//! extremes or break comparisons) and into correlation (where a single NaN
+//! poisons the whole coefficient). This module scans float columns once after
+//! loading and rewrites them according to a [`NonFinitePolicy`] so that every
+//! downstream stage only ever sees finite values or nulls.
+
+use anyhow::Result;
+use polars::prelude::*;
+use serde::Serialize;
+
+/// How non-finite values in numeric features should be handled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub enum NonFinitePolicy {
+    /// Replace NaN and ±Inf with null so they are counted as missing (default)
+    #[default]
+    TreatAsMissing,
+    /// Replace +Inf/-Inf with the column's largest/smallest finite value; NaN becomes null
+    Clip,
+    /// Abort the pipeline if any feature contains a non-finite value
+    Error,
+}
+
+impl std::fmt::Display for NonFinitePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NonFinitePolicy::TreatAsMissing => write!(f, "treat-as-missing"),
+            NonFinitePolicy::Clip => write!(f, "clip"),
+            NonFinitePolicy::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl std::str::FromStr for NonFinitePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "treat-as-missing" | "missing" => Ok(NonFinitePolicy::TreatAsMissing),
+            "clip" => Ok(NonFinitePolicy::Clip),
+            "error" => Ok(NonFinitePolicy::Error),
+            _ => Err(format!(
+                "Unknown non-finite policy: '{}'. Use 'treat-as-missing', 'clip', or 'error'.",
+                s
+            )),
+        }
+    }
+}
+
+/// Per-feature counts of non-finite values found before the policy was applied
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct NonFiniteCounts {
+    pub feature: String,
+    pub nan_count: usize,
+    pub pos_inf_count: usize,
+    pub neg_inf_count: usize,
+}
+
+impl NonFiniteCounts {
+    /// Total number of non-finite values in the feature
+    pub fn total(&self) -> usize {
+        self.nan_count + self.pos_inf_count + self.neg_inf_count
+    }
+}
+
+/// Scan float columns for NaN/±Inf without modifying the DataFrame.
+///
+/// Only features with at least one non-finite value are returned, in column order.
+/// Columns listed in `exclude` (typically the target and weight columns) are skipped.
+pub fn detect_non_finite(df: &DataFrame, exclude: &[&str]) -> Result<Vec<NonFiniteCounts>> {
+    let mut results = Vec::new();
+
+    for column in df.get_columns() {
+        let name = column.name().as_str();
+        if exclude.contains(&name) || !column.dtype().is_float() {
+            continue;
+        }
+
+        let series = column.as_materialized_series().cast(&DataType::Float64)?;
+        let counts = count_non_finite(name, series.f64()?);
+        if counts.total() > 0 {
+            results.push(counts);
+        }
+    }
+
+    Ok(results)
+}
+
+/// Detect non-finite values in float features and rewrite them according to `policy`.
+///
+/// Returns the per-feature counts observed *before* rewriting, so the report can
+/// show how much of each feature was affected. Integer, string and boolean
+/// columns cannot hold non-finite values and are left untouched.
+///
+/// # Errors
+/// With [`NonFinitePolicy::Error`], fails if any feature contains a non-finite value.
+pub fn apply_non_finite_policy(
+    df: &mut DataFrame,
+    policy: NonFinitePolicy,
+    exclude: &[&str],
+) -> Result<Vec<NonFiniteCounts>> {
+    let counts = detect_non_finite(df, exclude)?;
+
+    if counts.is_empty() {
+        return Ok(counts);
+    }
+
+    if policy == NonFinitePolicy::Error {
+        let details: Vec<String> = counts
+            .iter()
+            .map(|c| {
+                format!(
+                    "{} (NaN: {}, +Inf: {}, -Inf: {})",
+                    c.feature, c.nan_count, c.pos_inf_count, c.neg_inf_count
+                )
+            })
+            .collect();
+        anyhow::bail!(
+            "Found non-finite values in {} feature(s): {}\n\
+             Use --non-finite-policy treat-as-missing or clip to handle them.",
+            counts.len(),
+            details.join(", ")
+        );
+    }
+
+    for entry in &counts {
+        let column = df.column(&entry.feature)?;
+        let original_dtype = column.dtype().clone();
+        let series = column.as_materialized_series().cast(&DataType::Float64)?;
+        let ca = series.f64()?;
+
+        let cleaned = match policy {
+            NonFinitePolicy::Clip => {
+                let finite = ca.iter().flatten().filter(|v| v.is_finite());
+                let (min, max) =
+                    finite.fold((None, None), |(lo, hi): (Option<f64>, Option<f64>), v| {
+                        (
+                            Some(lo.map_or(v, |lo| lo.min(v))),
+                            Some(hi.map_or(v, |hi| hi.max(v))),
+                        )
+                    });
+                Float64Chunked::from_iter_options(
+                    ca.name().clone(),
+                    ca.iter().map(|opt| match opt {
+                        Some(v) if v == f64::INFINITY => max,
+                        Some(v) if v == f64::NEG_INFINITY => min,
+                        Some(v) if v.is_nan() => None,
+                        other => other,
+                    }),
+                )
+            }
+            _ => Float64Chunked::from_iter_options(
+                ca.name().clone(),
+                ca.iter().map(|opt| opt.filter(|v| v.is_finite())),
+            ),
+        };
+
+        df.with_column(cleaned.into_series().cast(&original_dtype)?)?;
+    }
+
+    Ok(counts)
+}
+
+fn count_non_finite(name: &str, ca: &Float64Chunked) -> NonFiniteCounts {
+    let mut counts = NonFiniteCounts {
+        feature: name.to_string(),
+        ..Default::default()
+    };
+    for v in ca.iter().flatten() {
+        if v.is_nan() {
+            counts.nan_count += 1;
+        } else if v == f64::INFINITY {
+            counts.pos_inf_count += 1;
+        } else if v == f64::NEG_INFINITY {
+            counts.neg_inf_count += 1;
+        }
+    }
+    counts
+}
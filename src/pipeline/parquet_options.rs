@@ -0,0 +1,156 @@
+//! Parquet write settings (`--parquet-compression`, `--row-group-size`,
+//! `--parquet-statistics`) shared by the reduction outputs and `lophi convert`.
+//!
+//! The two start from different defaults: the reduction outputs use the Polars
+//! writer defaults (zstd, min/max/null-count statistics, 512² rows per row
+//! group), the converter Snappy with full statistics and 100,000-row groups.
+
+use std::fs::File;
+use std::str::FromStr;
+
+use polars::prelude::{ParquetCompression, ParquetWriteOptions, ParquetWriter, StatisticsOptions};
+
+/// Compression codec of Parquet output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParquetCodec {
+    Snappy,
+    /// Zstandard at its default level
+    #[default]
+    Zstd,
+    /// LZ4 raw block compression
+    Lz4,
+    Uncompressed,
+}
+
+impl FromStr for ParquetCodec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "snappy" => Ok(ParquetCodec::Snappy),
+            "zstd" => Ok(ParquetCodec::Zstd),
+            "lz4" => Ok(ParquetCodec::Lz4),
+            "uncompressed" | "none" => Ok(ParquetCodec::Uncompressed),
+            _ => Err(format!(
+                "Unknown Parquet compression '{}'. Use snappy, zstd, lz4 or uncompressed",
+                s
+            )),
+        }
+    }
+}
+
+impl ParquetCodec {
+    fn compression(self) -> ParquetCompression {
+        match self {
+            ParquetCodec::Snappy => ParquetCompression::Snappy,
+            ParquetCodec::Zstd => ParquetCompression::Zstd(None),
+            ParquetCodec::Lz4 => ParquetCompression::Lz4Raw,
+            ParquetCodec::Uncompressed => ParquetCompression::Uncompressed,
+        }
+    }
+}
+
+/// Column statistics written to each Parquet row group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParquetStatistics {
+    /// Min, max, null count and distinct count
+    Full,
+    /// Min, max and null count
+    #[default]
+    Basic,
+    /// No statistics: smaller files, but readers cannot skip row groups
+    None,
+}
+
+impl FromStr for ParquetStatistics {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "full" => Ok(ParquetStatistics::Full),
+            "basic" => Ok(ParquetStatistics::Basic),
+            "none" => Ok(ParquetStatistics::None),
+            _ => Err(format!(
+                "Unknown Parquet statistics '{}'. Use full, basic or none",
+                s
+            )),
+        }
+    }
+}
+
+impl ParquetStatistics {
+    fn options(self) -> StatisticsOptions {
+        match self {
+            ParquetStatistics::Full => StatisticsOptions::full(),
+            ParquetStatistics::Basic => StatisticsOptions::default(),
+            ParquetStatistics::None => StatisticsOptions::empty(),
+        }
+    }
+}
+
+/// How Parquet files are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParquetOptions {
+    pub compression: ParquetCodec,
+    /// Rows per row group (Polars' 512² when `None`)
+    pub row_group_size: Option<usize>,
+    pub statistics: ParquetStatistics,
+}
+
+impl ParquetOptions {
+    /// Defaults of `lophi convert`: Snappy, full statistics, 100,000-row groups
+    pub const CONVERT: ParquetOptions = ParquetOptions {
+        compression: ParquetCodec::Snappy,
+        row_group_size: Some(100_000),
+        statistics: ParquetStatistics::Full,
+    };
+
+    /// An eager writer with these settings
+    pub fn writer(&self, file: File) -> ParquetWriter<File> {
+        ParquetWriter::new(file)
+            .with_compression(self.compression.compression())
+            .with_statistics(self.statistics.options())
+            .with_row_group_size(self.row_group_size)
+    }
+
+    /// Options for `LazyFrame::sink_parquet` with these settings
+    #[allow(dead_code)] // Library API; the converter writes row groups itself
+    pub fn write_options(&self) -> ParquetWriteOptions {
+        ParquetWriteOptions {
+            compression: self.compression.compression(),
+            statistics: self.statistics.options(),
+            row_group_size: self.row_group_size,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_codec_and_statistics() {
+        assert_eq!("ZSTD".parse::<ParquetCodec>(), Ok(ParquetCodec::Zstd));
+        assert_eq!("lz4".parse::<ParquetCodec>(), Ok(ParquetCodec::Lz4));
+        assert_eq!(
+            "none".parse::<ParquetCodec>(),
+            Ok(ParquetCodec::Uncompressed)
+        );
+        assert!("gzip".parse::<ParquetCodec>().is_err());
+
+        assert_eq!(
+            "basic".parse::<ParquetStatistics>(),
+            Ok(ParquetStatistics::Basic)
+        );
+        assert!("some".parse::<ParquetStatistics>().is_err());
+    }
+
+    #[test]
+    fn test_default_matches_the_polars_writer() {
+        let options = ParquetOptions::default().write_options();
+        assert_eq!(options.compression, ParquetCompression::default());
+        assert_eq!(options.statistics, StatisticsOptions::default());
+        assert_eq!(options.row_group_size, None);
+    }
+}
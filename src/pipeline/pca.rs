@@ -0,0 +1,241 @@
+//! Principal component analysis of the retained numeric features
+//!
+//! Informational only: with `--pca` the numeric features left after the
+//! screening steps are standardized and a PCA is fitted on their weighted
+//! correlation matrix. The report lists how much of the variance the leading
+//! components explain and which features load highly on the same component,
+//! as a starting point for a discussion about further dimensionality
+//! reduction. No feature is dropped.
+
+use anyhow::Result;
+use faer::{Mat, Side};
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::correlation::standardize_columns;
+
+/// Absolute loading from which a feature counts towards a component's group
+pub const DEFAULT_PCA_LOADING_THRESHOLD: f64 = 0.7;
+
+/// Share of the variance up to which components are listed in the report
+const REPORTED_VARIANCE: f64 = 0.95;
+
+/// Most features the PCA is fitted on; the eigendecomposition is cubic in the
+/// number of features
+pub const MAX_PCA_FEATURES: usize = 2000;
+
+/// Settings of the PCA report (`--pca`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PcaSettings {
+    /// Absolute loading from which a feature is listed with a component
+    /// (`--pca-loading-threshold`)
+    pub loading_threshold: f64,
+}
+
+/// Loading of one feature on a component: its correlation with the component
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureLoading {
+    pub feature: String,
+    pub loading: f64,
+}
+
+/// One principal component
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrincipalComponent {
+    /// 1-based rank of the component by explained variance
+    pub component: usize,
+    pub eigenvalue: f64,
+    /// Share of the total variance explained by this component
+    pub explained_variance: f64,
+    /// Share explained by this and all higher-ranked components
+    pub cumulative_variance: f64,
+    /// Features whose absolute loading reaches the loading threshold, by
+    /// absolute loading descending
+    pub high_loadings: Vec<FeatureLoading>,
+}
+
+/// PCA of the numeric features left after the screening steps
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PcaSummary {
+    /// Features the PCA was fitted on
+    pub features: Vec<String>,
+    pub loading_threshold: f64,
+    /// Components needed to explain 90% of the variance
+    pub components_for_90: usize,
+    /// Components needed to explain 95% of the variance
+    pub components_for_95: usize,
+    /// Components with an eigenvalue above the average (the Kaiser criterion)
+    pub kaiser_components: usize,
+    /// Leading components, up to the one that reaches 95% of the variance
+    pub components: Vec<PrincipalComponent>,
+}
+
+impl PcaSummary {
+    /// Components on which at least two features load highly: groups of
+    /// features that largely carry the same information
+    pub fn groups(&self) -> impl Iterator<Item = &PrincipalComponent> {
+        self.components
+            .iter()
+            .filter(|c| c.high_loadings.len() >= 2)
+    }
+}
+
+/// Fit a PCA on the standardized numeric `features` of `df`.
+///
+/// Nulls are replaced by the feature's weighted mean. Returns `None` when
+/// fewer than two non-constant numeric features are left, or more than
+/// [`MAX_PCA_FEATURES`].
+pub fn analyze_pca(
+    df: &DataFrame,
+    features: &[String],
+    weights: &[f64],
+    settings: &PcaSettings,
+) -> Result<Option<PcaSummary>> {
+    let float_columns: Vec<(String, Column)> = features
+        .iter()
+        .filter_map(|name| df.column(name).ok())
+        .filter(|col| col.dtype().is_primitive_numeric())
+        .map(|col| Ok((col.name().to_string(), col.cast(&DataType::Float64)?)))
+        .collect::<PolarsResult<_>>()?;
+    if !(2..=MAX_PCA_FEATURES).contains(&float_columns.len()) || df.height() == 0 {
+        return Ok(None);
+    }
+    // Fails only when fewer than two non-constant columns remain
+    let Ok((z, names)) = standardize_columns(&float_columns, weights) else {
+        return Ok(None);
+    };
+    drop(float_columns);
+
+    let correlation: Mat<f64> = z.transpose() * &z;
+    drop(z);
+    Ok(Some(summarize(
+        &correlation,
+        names,
+        settings.loading_threshold,
+    )))
+}
+
+/// Eigendecomposition of the correlation matrix of `names`, components by
+/// eigenvalue descending
+fn summarize(correlation: &Mat<f64>, names: Vec<String>, loading_threshold: f64) -> PcaSummary {
+    let p = names.len();
+    let evd = correlation.selfadjoint_eigendecomposition(Side::Lower);
+    let (u, s) = (evd.u(), evd.s().column_vector());
+    // Eigenvalues come in ascending order; tiny negative ones are rounding
+    let eigenvalues: Vec<f64> = (0..p).rev().map(|k| s.read(k).max(0.0)).collect();
+    let total: f64 = eigenvalues.iter().sum();
+
+    let mut cumulative = 0.0;
+    let mut components = Vec::new();
+    let (mut components_for_90, mut components_for_95) = (p, p);
+    for (rank, &eigenvalue) in eigenvalues.iter().enumerate() {
+        let explained = if total > 0.0 { eigenvalue / total } else { 0.0 };
+        cumulative += explained;
+        if cumulative >= 0.90 - 1e-9 {
+            components_for_90 = components_for_90.min(rank + 1);
+        }
+        if cumulative >= 0.95 - 1e-9 {
+            components_for_95 = components_for_95.min(rank + 1);
+        }
+
+        let col = p - 1 - rank;
+        let mut loadings: Vec<FeatureLoading> = names
+            .iter()
+            .enumerate()
+            .map(|(j, feature)| FeatureLoading {
+                feature: feature.clone(),
+                loading: u.read(j, col) * eigenvalue.sqrt(),
+            })
+            .collect();
+        // The sign of an eigenvector is arbitrary: make the largest loading
+        // positive so the output is stable
+        loadings.sort_by(|a, b| b.loading.abs().total_cmp(&a.loading.abs()));
+        if loadings.first().is_some_and(|l| l.loading < 0.0) {
+            loadings.iter_mut().for_each(|l| l.loading = -l.loading);
+        }
+        loadings.retain(|l| l.loading.abs() >= loading_threshold);
+
+        components.push(PrincipalComponent {
+            component: rank + 1,
+            eigenvalue,
+            explained_variance: explained,
+            cumulative_variance: cumulative.min(1.0),
+            high_loadings: loadings,
+        });
+        if cumulative >= REPORTED_VARIANCE - 1e-9 {
+            break;
+        }
+    }
+
+    let average = total / p as f64;
+    PcaSummary {
+        features: names,
+        loading_threshold,
+        components_for_90,
+        components_for_95,
+        kaiser_components: eigenvalues.iter().filter(|&&e| e > average).count(),
+        components,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> PcaSettings {
+        PcaSettings {
+            loading_threshold: DEFAULT_PCA_LOADING_THRESHOLD,
+        }
+    }
+
+    #[test]
+    fn test_correlated_features_share_a_component() {
+        let n = 200;
+        let a: Vec<f64> = (0..n).map(|i| (i % 17) as f64).collect();
+        let df = df! {
+            "a" => &a,
+            "a_scaled" => a.iter().map(|x| 3.0 * x + 1.0).collect::<Vec<_>>(),
+            "b" => (0..n).map(|i| ((i * 7) % 11) as f64).collect::<Vec<_>>(),
+            "label" => (0..n).map(|i| format!("c{}", i % 3)).collect::<Vec<_>>(),
+        }
+        .unwrap();
+        let features: Vec<String> = ["a", "a_scaled", "b", "label"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        let summary = analyze_pca(&df, &features, &vec![1.0; n], &settings())
+            .unwrap()
+            .unwrap();
+        assert_eq!(summary.features, ["a", "a_scaled", "b"]);
+        let first = &summary.components[0];
+        assert!((first.eigenvalue - 2.0).abs() < 0.1, "{:?}", first);
+        let mut group: Vec<&str> = first
+            .high_loadings
+            .iter()
+            .map(|l| l.feature.as_str())
+            .collect();
+        group.sort();
+        assert_eq!(group, ["a", "a_scaled"]);
+        assert!(first.high_loadings.iter().all(|l| l.loading > 0.99));
+        assert_eq!(summary.groups().count(), 1);
+
+        // The copy adds nothing: two components explain all of the variance
+        assert_eq!(summary.components_for_95, 2);
+        assert_eq!(summary.components.len(), 2);
+        assert!((summary.components[1].cumulative_variance - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_too_few_numeric_features() {
+        let df = df! {
+            "a" => [1.0, 2.0, 3.0],
+            "constant" => [5.0, 5.0, 5.0],
+        }
+        .unwrap();
+        let features = vec!["a".to_string(), "constant".to_string()];
+        assert!(analyze_pca(&df, &features, &[1.0; 3], &settings())
+            .unwrap()
+            .is_none());
+    }
+}
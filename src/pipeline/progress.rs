@@ -25,6 +25,8 @@ pub enum PipelineStage {
 pub struct SummaryData {
     pub initial_features: usize,
     pub final_features: usize,
+    pub dropped_constant: usize,
+    pub dropped_identifier: usize,
     pub dropped_missing: usize,
     pub dropped_gini: usize,
     pub dropped_correlation: usize,
@@ -51,6 +53,105 @@ pub struct ConversionSummaryData {
     pub output_path: String,
 }
 
+/// One correlated pair offered for review, with the automatic drop choice.
+#[derive(Debug, Clone)]
+pub struct CorrelationReviewItem {
+    pub feature1: String,
+    pub feature2: String,
+    pub iv1: Option<f64>,
+    pub iv2: Option<f64>,
+    pub missing1: Option<f64>,
+    pub missing2: Option<f64>,
+    /// Dictionary descriptions of `feature1` and `feature2` (`--dictionary`)
+    pub description1: Option<String>,
+    pub description2: Option<String>,
+    pub correlation: f64,
+    pub measure: String,
+    /// The member the automatic rules chose to drop (`feature1` or `feature2`).
+    pub proposed_drop: String,
+}
+
+/// Request for the overlay to review correlated-pair drops.
+///
+/// The pipeline thread blocks until the overlay sends back the indices of the
+/// items whose drop the user reversed (empty to accept every proposal). If the
+/// overlay goes away without replying, the automatic choices stand.
+#[derive(Debug, Clone)]
+pub struct CorrelationReviewRequest {
+    pub items: Vec<CorrelationReviewItem>,
+    pub reply: mpsc::Sender<Vec<usize>>,
+}
+
+/// One feature the pipeline proposes to drop, offered for review.
+#[derive(Debug, Clone)]
+pub struct DropReviewItem {
+    pub feature: String,
+    /// Stage that proposed the drop: "missing", "gini" or "correlation"
+    pub stage: String,
+    /// Drop reason as written to the report
+    pub reason: String,
+    /// Dictionary description of the feature (`--dictionary`)
+    pub description: Option<String>,
+}
+
+/// Request for the overlay to review every proposed drop before saving.
+///
+/// The pipeline thread blocks until the overlay sends back the indices of the
+/// items the user chose to keep (empty to apply every drop). If the overlay
+/// goes away without replying, all drops are applied.
+#[derive(Debug, Clone)]
+pub struct DropReviewRequest {
+    pub items: Vec<DropReviewItem>,
+    pub reply: mpsc::Sender<Vec<usize>>,
+}
+
+/// One bin of a feature in the results browser.
+#[derive(Debug, Clone)]
+pub struct ResultsBin {
+    /// Bin range, category group, special value or "Missing"
+    pub label: String,
+    pub count: f64,
+    pub event_rate: f64,
+    pub woe: f64,
+    pub iv_contribution: f64,
+}
+
+/// One feature scored by the Gini/IV step, as shown in the results browser.
+#[derive(Debug, Clone)]
+pub struct ResultsFeature {
+    pub name: String,
+    /// "Numeric" or "Categorical"
+    pub feature_type: String,
+    pub iv: f64,
+    pub gini: f64,
+    /// Stage that dropped the feature, `None` if it was kept
+    pub dropped_at: Option<String>,
+    pub bins: Vec<ResultsBin>,
+}
+
+/// One correlated pair found by the correlation step.
+#[derive(Debug, Clone)]
+pub struct ResultsPair {
+    pub feature1: String,
+    pub feature2: String,
+    pub correlation: f64,
+    pub measure: String,
+    /// The member the pair dropped, `None` when it dropped neither
+    pub dropped: Option<String>,
+}
+
+/// Results of a finished reduction for the results browser (`--browse-results`).
+#[derive(Debug, Clone, Default)]
+pub struct RunResults {
+    pub initial_features: usize,
+    pub final_features: usize,
+    /// Features dropped by each stage that ran, in pipeline order
+    pub dropped_by_stage: Vec<(String, Vec<String>)>,
+    /// Features scored by the Gini/IV step, empty when it did not run
+    pub features: Vec<ResultsFeature>,
+    pub correlated_pairs: Vec<ResultsPair>,
+}
+
 /// A single progress event emitted by the pipeline.
 #[derive(Debug, Clone)]
 pub struct ProgressEvent {
@@ -71,6 +172,12 @@ pub struct ProgressEvent {
     pub sampling_summary: Option<SamplingSummaryData>,
     /// Conversion summary data, attached only to the `Complete` event.
     pub conversion_summary: Option<ConversionSummaryData>,
+    /// Correlated pairs awaiting user review (interactive `--review-correlations`).
+    pub correlation_review: Option<CorrelationReviewRequest>,
+    /// Proposed drops awaiting user review (interactive `--review-drops`).
+    pub drop_review: Option<DropReviewRequest>,
+    /// Results to browse once the run is complete (`--browse-results`).
+    pub results: Option<Box<RunResults>>,
 }
 
 pub type ProgressSender = mpsc::Sender<ProgressEvent>;
@@ -93,6 +200,9 @@ impl ProgressEvent {
             summary: None,
             sampling_summary: None,
             conversion_summary: None,
+            correlation_review: None,
+            drop_review: None,
+            results: None,
         }
     }
 
@@ -111,6 +221,9 @@ impl ProgressEvent {
             summary: None,
             sampling_summary: None,
             conversion_summary: None,
+            correlation_review: None,
+            drop_review: None,
+            results: None,
         }
     }
 
@@ -129,6 +242,60 @@ impl ProgressEvent {
             summary: None,
             sampling_summary: None,
             conversion_summary: None,
+            correlation_review: None,
+            drop_review: None,
+            results: None,
+        }
+    }
+
+    /// Ask the overlay to review correlated-pair drops before they are applied.
+    pub fn correlation_review(request: CorrelationReviewRequest) -> Self {
+        Self {
+            stage: PipelineStage::CorrelationAnalysis,
+            message: "Review correlated pairs".to_string(),
+            detail: None,
+            is_complete: false,
+            elapsed_secs: None,
+            summary: None,
+            sampling_summary: None,
+            conversion_summary: None,
+            correlation_review: Some(request),
+            drop_review: None,
+            results: None,
+        }
+    }
+
+    /// Ask the overlay to review the proposed drops before the output is saved.
+    pub fn drop_review(request: DropReviewRequest) -> Self {
+        Self {
+            stage: PipelineStage::Saving,
+            message: "Review proposed drops".to_string(),
+            detail: None,
+            is_complete: false,
+            elapsed_secs: None,
+            summary: None,
+            sampling_summary: None,
+            conversion_summary: None,
+            correlation_review: None,
+            drop_review: Some(request),
+            results: None,
+        }
+    }
+
+    /// Hand the results of the finished run to the overlay's results browser.
+    pub fn results(results: RunResults) -> Self {
+        Self {
+            stage: PipelineStage::Complete,
+            message: "Browse results".to_string(),
+            detail: None,
+            is_complete: false,
+            elapsed_secs: None,
+            summary: None,
+            sampling_summary: None,
+            conversion_summary: None,
+            correlation_review: None,
+            drop_review: None,
+            results: Some(Box::new(results)),
         }
     }
 }
@@ -6,13 +6,22 @@
 //! - **EqualAllocation** – same sample size for every stratum
 //!
 //! All strategies append a `sampling_weight` column (N_h / n_h) to the
-//! sampled DataFrame so that weighted estimators remain unbiased.
+//! sampled DataFrame so that weighted estimators remain unbiased. When the
+//! dataset already carries row weights, `sampling_weight` is that weight times
+//! N_h / n_h.
+//!
+//! [`sample_for_screening`] draws the row subset a reduction run analyses
+//! with `--sample-rows` / `--sample-frac`. It keeps the dataset's columns and
+//! row order and appends no weight: random and proportional stratified samples
+//! leave the relative row weights unchanged.
 
 use std::path::PathBuf;
 
 use anyhow::{bail, Result};
 use polars::prelude::*;
 
+use super::weights::get_weights;
+
 // ---------------------------------------------------------------------------
 // Public types
 // ---------------------------------------------------------------------------
@@ -22,14 +31,15 @@ use polars::prelude::*;
 pub enum SamplingMethod {
     /// Simple random sample without replacement.
     Random,
-    /// Stratified sample using caller-supplied stratum sizes.
+    /// Stratified sample using caller-supplied stratum sizes, or a
+    /// proportional allocation of `sample_size` when none are given.
     Stratified,
     /// Equal-allocation stratified sample (same n per stratum).
     EqualAllocation,
 }
 
 /// How the desired sample size is expressed.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum SampleSize {
     /// Absolute number of rows.
     Count(usize),
@@ -60,15 +70,35 @@ pub struct SamplingConfig {
     /// Column used to partition rows into strata (required for Stratified /
     /// EqualAllocation, ignored for Random).
     pub strata_column: Option<String>,
-    /// Desired sample size (used for Random and EqualAllocation).
+    /// Desired sample size (used for Random and EqualAllocation, and for
+    /// Stratified when `strata_specs` is empty).
     pub sample_size: Option<SampleSize>,
     /// Per-stratum allocation (used for Stratified; built internally for
     /// EqualAllocation).
     pub strata_specs: Vec<StratumSpec>,
+    /// Existing row weight column. Its values multiply the appended
+    /// `sampling_weight`; the column itself is kept as is.
+    pub weight_column: Option<String>,
     /// Optional RNG seed for reproducibility.
     pub seed: Option<u64>,
     /// Number of rows used when inferring the CSV/Parquet schema.
     pub infer_schema_length: usize,
+    /// Worksheet of an Excel input; `None` reads the first sheet.
+    pub sheet: Option<String>,
+}
+
+/// Seed of every randomized step of a reduction run (row sample, CV folds,
+/// random validation split) when `--seed` is not given
+pub const DEFAULT_SEED: u64 = 42;
+
+/// Row sample a reduction run is screened on (`--sample-rows` / `--sample-frac`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScreeningSample {
+    /// Rows to keep, or the fraction of rows, in `(0.0, 1.0]`
+    pub size: SampleSize,
+    /// Sample each target class in proportion to its share of the rows
+    pub stratify: bool,
+    pub seed: u64,
 }
 
 // ---------------------------------------------------------------------------
@@ -100,7 +130,8 @@ pub fn analyze_strata(df: &DataFrame, column: &str) -> Result<Vec<(String, usize
         .column(column)
         .map_err(|_| anyhow::anyhow!("Strata column '{}' not found in DataFrame", column))?;
 
-    let series = col.as_materialized_series();
+    // Series::iter needs a single chunk (stacked or chunked-CSV frames have more)
+    let series = col.as_materialized_series().rechunk();
 
     // Build a frequency map in one pass.
     let mut counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
@@ -120,7 +151,9 @@ pub fn analyze_strata(df: &DataFrame, column: &str) -> Result<Vec<(String, usize
 /// Execute the sampling operation described by `config` against `df`.
 ///
 /// Returns a new [`DataFrame`] containing only the sampled rows plus an
-/// appended `sampling_weight` column (Float64).
+/// appended `sampling_weight` column (Float64). With `config.weight_column`,
+/// `sampling_weight` is the row's weight times the inverse inclusion
+/// probability, so weighted totals of the sample estimate those of `df`.
 ///
 /// # Errors
 /// - `"Column 'sampling_weight' already exists in dataset"` when the column
@@ -130,6 +163,8 @@ pub fn analyze_strata(df: &DataFrame, column: &str) -> Result<Vec<(String, usize
 /// - `"Sample size ({n}) exceeds population size ({N})"` when n > N.
 /// - `"strata_column is required for Stratified / EqualAllocation sampling"`
 ///   when the method requires a strata column but none is provided.
+/// - `"Weight column '{w}' not found in DataFrame"` (and the other
+///   [`get_weights`] errors) for an invalid `weight_column`.
 pub fn execute_sampling(df: &DataFrame, config: &SamplingConfig) -> Result<DataFrame> {
     // Guard: sampling_weight column must not already exist.
     if df
@@ -140,7 +175,7 @@ pub fn execute_sampling(df: &DataFrame, config: &SamplingConfig) -> Result<DataF
         bail!("Column 'sampling_weight' already exists in dataset");
     }
 
-    match config.method {
+    let mut sampled = match config.method {
         SamplingMethod::Random => {
             let n = resolve_count(df.height(), config.sample_size.as_ref())?;
             random_sample(df, n, config.seed)
@@ -151,7 +186,13 @@ pub fn execute_sampling(df: &DataFrame, config: &SamplingConfig) -> Result<DataF
                     "strata_column is required for Stratified / EqualAllocation sampling"
                 )
             })?;
-            stratified_sample(df, strata_col, &config.strata_specs, config.seed)
+            match config.sample_size.as_ref() {
+                Some(size) if config.strata_specs.is_empty() => {
+                    let specs = proportional_specs(df, strata_col, size)?;
+                    stratified_sample(df, strata_col, &specs, config.seed)
+                }
+                _ => stratified_sample(df, strata_col, &config.strata_specs, config.seed),
+            }
         }
         SamplingMethod::EqualAllocation => {
             let strata_col = config.strata_column.as_deref().ok_or_else(|| {
@@ -175,13 +216,79 @@ pub fn execute_sampling(df: &DataFrame, config: &SamplingConfig) -> Result<DataF
             };
             equal_allocation_sample(df, strata_col, n, config.seed)
         }
+    }?;
+
+    if let Some(weight_col) = config.weight_column.as_deref() {
+        apply_row_weights(&mut sampled, weight_col)?;
+    }
+    Ok(sampled)
+}
+
+/// Draw the rows of a sampled reduction run from `df`.
+///
+/// With `stratify_on`, every value of that column contributes the same share
+/// of its rows (at least one), so rare target classes keep their rate. The
+/// sampled rows stay in their original order. A count at or above the row
+/// count returns every row.
+///
+/// # Errors
+/// Returns an error for a zero or out-of-range size, or when `stratify_on`
+/// is not a column of `df`.
+pub fn sample_for_screening(
+    df: &DataFrame,
+    size: &SampleSize,
+    stratify_on: Option<&str>,
+    seed: u64,
+) -> Result<DataFrame> {
+    let population = df.height();
+    match size {
+        SampleSize::Count(0) => bail!("Sample size must be positive"),
+        SampleSize::Count(n) if *n >= population => return Ok(df.clone()),
+        SampleSize::Fraction(f) if !(*f > 0.0 && *f <= 1.0) => {
+            bail!("Sample fraction must be in (0.0, 1.0], got {}", f)
+        }
+        SampleSize::Fraction(f) if *f == 1.0 => return Ok(df.clone()),
+        _ => {}
     }
+
+    let mut rows: Vec<IdxSize> = match stratify_on {
+        None => {
+            let n = resolve_count(population, Some(size))?;
+            sample_indices((0..population as IdxSize).collect(), n, seed)?
+        }
+        Some(column) => {
+            let specs = proportional_specs(df, column, size)?;
+            let labels = df.column(column)?.as_materialized_series().rechunk();
+            let mut members: std::collections::HashMap<String, Vec<IdxSize>> =
+                std::collections::HashMap::new();
+            for (i, value) in labels.iter().enumerate() {
+                members
+                    .entry(anyvalue_to_label(&value))
+                    .or_default()
+                    .push(i as IdxSize);
+            }
+            let mut rows = Vec::with_capacity(specs.iter().map(|s| s.sample_size).sum());
+            for spec in specs {
+                let stratum = members.remove(&spec.value).unwrap_or_default();
+                rows.extend(sample_indices(stratum, spec.sample_size, seed)?);
+            }
+            rows
+        }
+    };
+    rows.sort_unstable();
+    Ok(df.take(&IdxCa::from_vec("rows".into(), rows))?)
 }
 
 // ---------------------------------------------------------------------------
 // Internal helpers
 // ---------------------------------------------------------------------------
 
+/// `n` of `rows`, drawn without replacement
+fn sample_indices(rows: Vec<IdxSize>, n: usize, seed: u64) -> Result<Vec<IdxSize>> {
+    let picked = IdxCa::from_vec("rows".into(), rows).sample_n(n, false, false, Some(seed))?;
+    Ok(picked.into_no_null_iter().collect())
+}
+
 /// Resolve an optional [`SampleSize`] into a concrete row count.
 fn resolve_count(population: usize, size: Option<&SampleSize>) -> Result<usize> {
     match size {
@@ -247,7 +354,7 @@ fn stratified_sample(
         .column(strata_column)
         .map_err(|_| anyhow::anyhow!("Strata column '{}' not found in DataFrame", strata_column))?;
 
-    let strata_series = df.column(strata_column)?.as_materialized_series().clone();
+    let strata_series = df.column(strata_column)?.as_materialized_series().rechunk();
     let mut parts: Vec<DataFrame> = Vec::with_capacity(specs.len());
 
     for spec in specs {
@@ -256,8 +363,8 @@ fn stratified_sample(
 
         // Empty stratum: skip with a warning.
         if n_pop == 0 {
-            eprintln!(
-                "Warning: stratum '{}' has population_count = 0, skipping",
+            tracing::warn!(
+                "Stratum '{}' has population_count = 0, skipping",
                 spec.value
             );
             continue;
@@ -285,10 +392,7 @@ fn stratified_sample(
 
         let actual_pop = stratum_df.height();
         if actual_pop == 0 {
-            eprintln!(
-                "Warning: stratum '{}' is empty in DataFrame, skipping",
-                spec.value
-            );
+            tracing::warn!("Stratum '{}' is empty in DataFrame, skipping", spec.value);
             continue;
         }
 
@@ -321,6 +425,57 @@ fn stratified_sample(
     Ok(combined)
 }
 
+/// Proportional allocation of `size` over the strata of `strata_column`.
+///
+/// A fraction is applied to every stratum; a count is split in proportion to
+/// the stratum sizes. Each stratum gets at least one row so none vanishes
+/// from the sample.
+fn proportional_specs(
+    df: &DataFrame,
+    strata_column: &str,
+    size: &SampleSize,
+) -> Result<Vec<StratumSpec>> {
+    let strata = analyze_strata(df, strata_column)?;
+    let population = df.height();
+    let fraction = resolve_count(population, Some(size))? as f64 / population as f64;
+    if let SampleSize::Count(n) = size {
+        if *n > population {
+            bail!("Sample size ({n}) exceeds population size ({population})");
+        }
+    }
+
+    Ok(strata
+        .into_iter()
+        .map(|(value, population_count)| StratumSpec {
+            sample_size: match size {
+                SampleSize::Fraction(f) => (f * population_count as f64).round() as usize,
+                SampleSize::Count(_) => (fraction * population_count as f64).round() as usize,
+            }
+            .clamp(1, population_count),
+            value,
+            population_count,
+        })
+        .collect())
+}
+
+/// Multiply `sampling_weight` by the row weights in `weight_column`.
+///
+/// Weights are read and validated as in the pipeline (nulls count as 1.0).
+fn apply_row_weights(sampled: &mut DataFrame, weight_column: &str) -> Result<()> {
+    let row_weights = get_weights(sampled, Some(weight_column))?;
+    let combined: Vec<f64> = sampled
+        .column("sampling_weight")?
+        .f64()?
+        .into_no_null_iter()
+        .zip(&row_weights)
+        .map(|(design, w)| design * w)
+        .collect();
+    sampled
+        .with_column(Series::new("sampling_weight".into(), combined))
+        .map_err(|e| anyhow::anyhow!("Failed to append sampling_weight column: {}", e))?;
+    Ok(())
+}
+
 /// Equal-allocation stratified sample: `n` rows from every stratum.
 ///
 /// Builds a [`StratumSpec`] for each unique value in `strata_column` and
@@ -370,8 +525,10 @@ mod tests {
             strata_column: None,
             sample_size: None,
             strata_specs: vec![],
+            weight_column: None,
             seed: Some(42),
             infer_schema_length: 10_000,
+            sheet: None,
         }
     }
 
@@ -628,4 +785,47 @@ mod tests {
 
         assert_eq!(ids1, ids2);
     }
+
+    // --- sample_for_screening ---
+
+    #[test]
+    fn test_screening_sample_keeps_row_order_and_columns() {
+        let df = make_df();
+        let sampled = sample_for_screening(&df, &SampleSize::Count(4), None, 7).unwrap();
+        assert_eq!(sampled.get_column_names(), df.get_column_names());
+        let ids: Vec<i64> = sampled
+            .column("id")
+            .unwrap()
+            .i64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(ids.len(), 4);
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+
+        // Same seed, same rows
+        let again = sample_for_screening(&df, &SampleSize::Count(4), None, 7).unwrap();
+        assert!(sampled.equals(&again));
+    }
+
+    #[test]
+    fn test_screening_sample_stratified_keeps_every_stratum() {
+        let df = make_df();
+        let sampled =
+            sample_for_screening(&df, &SampleSize::Fraction(0.5), Some("group"), 1).unwrap();
+        let strata = analyze_strata(&sampled, "group").unwrap();
+        // A=4 → 2, B=3 → 2 (rounded), C=3 → 2
+        assert_eq!(strata.len(), 3);
+        assert_eq!(sampled.height(), 6);
+    }
+
+    #[test]
+    fn test_screening_sample_sizes() {
+        let df = make_df();
+        let all = sample_for_screening(&df, &SampleSize::Count(50), None, 1).unwrap();
+        assert_eq!(all.height(), 10);
+        assert!(sample_for_screening(&df, &SampleSize::Count(0), None, 1).is_err());
+        assert!(sample_for_screening(&df, &SampleSize::Fraction(1.5), None, 1).is_err());
+        assert!(sample_for_screening(&df, &SampleSize::Count(3), Some("nope"), 1).is_err());
+    }
 }
@@ -117,9 +117,6 @@ fn extract_text_from_blocks(
 
 /// Decodes bytes to string using the specified encoding.
 ///
-/// Uses `encoding_rs` for all non-UTF-8 encodings, consistent with how
-/// `data.rs` decodes character column values.
-///
 /// # Arguments
 /// * `bytes` - Raw bytes to decode
 /// * `encoding` - Character encoding
@@ -127,21 +124,7 @@ fn extract_text_from_blocks(
 /// # Returns
 /// * `String` - Decoded text, trimmed of surrounding whitespace
 fn decode_text(bytes: &[u8], encoding: &SasEncoding) -> String {
-    let decoded = match encoding {
-        SasEncoding::Utf8 | SasEncoding::Ascii => String::from_utf8_lossy(bytes).into_owned(),
-        SasEncoding::Latin1 | SasEncoding::Unspecified | SasEncoding::Windows1252 => {
-            // Windows-1252 is a superset of Latin-1; use it for all three variants
-            encoding_rs::WINDOWS_1252.decode(bytes).0.into_owned()
-        }
-        SasEncoding::Other { name, .. } => {
-            if let Some(enc) = encoding_rs::Encoding::for_label(name.as_bytes()) {
-                enc.decode(bytes).0.into_owned()
-            } else {
-                String::from_utf8_lossy(bytes).into_owned()
-            }
-        }
-    };
-    decoded.trim().to_string()
+    encoding.decode(bytes).trim().to_string()
 }
 
 /// Infers the Polars output type based on SAS format string and data type.
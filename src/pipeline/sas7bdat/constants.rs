@@ -262,6 +262,21 @@ pub const PAGE_TYPE_META2: u16 = 0x4000;
 /// Page type for compressed data pages.
 pub const PAGE_TYPE_COMP: u16 = 0x9000;
 
+/// Flag added to the DATA and MIX page types (0x0180, 0x0280) when the page
+/// holds deleted rows, e.g. after `PROC SQL; DELETE`.
+///
+/// Such a page carries a bitmap with one bit per row slot, set for deleted rows.
+pub const PAGE_FLAG_DELETED_ROWS: u16 = 0x0080;
+
+/// Offset of the deleted-row pointer within a page, for 32-bit files.
+///
+/// The bitmap starts this many bytes past the end of the page's row slots
+/// (counted without the MIX page 8-byte alignment).
+pub const PAGE_DELETED_POINTER_OFFSET_32: usize = 12;
+
+/// Offset of the deleted-row pointer within a page, for 64-bit files.
+pub const PAGE_DELETED_POINTER_OFFSET_64: usize = 24;
+
 // ============================================================================
 // T010: Compression Identifier Constants
 // ============================================================================
@@ -294,23 +309,44 @@ pub const COMPRESSION_SIGNATURE_RDC: [u8; 8] = [b'S', b'A', b'S', b'Y', b'Z', b'
 /// - 28: US-ASCII
 /// - 29: ISO-8859-1 (Latin-1)
 /// - 62: Windows-1252 (Western European)
-/// - 125: EUC-CN (Simplified Chinese)
+/// - 125: GB18030 (Simplified Chinese)
 /// - 134: EUC-JP (Japanese)
 /// - 138: Shift_JIS (Japanese)
 /// - 140: EUC-KR (Korean)
+///
+/// IDs follow the table used by ReadStat and pandas. Names are `encoding_rs`
+/// labels, or `IBMnnn` for the DOS code pages decoded from built-in tables
+/// (see `encoding`). IDs with no available decoder return `None`.
 pub fn encoding_name(id: u16) -> Option<&'static str> {
     match id {
         20 => Some("UTF-8"),
         28 => Some("US-ASCII"),
         29 => Some("ISO-8859-1"),
-        33 => Some("ISO-8859-2"),
-        34 => Some("ISO-8859-3"),
-        35 => Some("ISO-8859-4"),
-        36 => Some("ISO-8859-5"),
-        37 => Some("ISO-8859-6"),
-        38 => Some("ISO-8859-7"),
-        39 => Some("ISO-8859-8"),
-        40 => Some("ISO-8859-9"),
+        30 => Some("ISO-8859-2"),
+        31 => Some("ISO-8859-3"),
+        32 => Some("ISO-8859-4"),
+        33 => Some("ISO-8859-5"),
+        34 => Some("ISO-8859-6"),
+        35 => Some("ISO-8859-7"),
+        36 => Some("ISO-8859-8"),
+        37 => Some("ISO-8859-9"),
+        39 => Some("ISO-8859-11"),
+        40 => Some("ISO-8859-15"),
+        41 => Some("IBM437"),
+        42 => Some("IBM850"),
+        43 => Some("IBM852"),
+        44 => Some("IBM857"),
+        45 => Some("IBM858"),
+        46 => Some("IBM862"),
+        48 => Some("IBM865"),
+        49 => Some("IBM866"),
+        50 => Some("IBM869"),
+        51 => Some("windows-874"),
+        55 => Some("IBM720"),
+        56 => Some("IBM737"),
+        57 => Some("IBM775"),
+        58 => Some("IBM860"),
+        59 => Some("IBM863"),
         60 => Some("Windows-1250"),
         61 => Some("Windows-1251"),
         62 => Some("Windows-1252"),
@@ -320,11 +356,17 @@ pub fn encoding_name(id: u16) -> Option<&'static str> {
         66 => Some("Windows-1256"),
         67 => Some("Windows-1257"),
         68 => Some("Windows-1258"),
-        123 => Some("Big5"),
-        125 => Some("EUC-CN"),
+        69 => Some("macintosh"),
+        76 | 246 => Some("x-mac-cyrillic"),
+        118 | 123 => Some("Big5"),
+        125 | 205 => Some("GB18030"),
+        126 => Some("GBK"),
         134 => Some("EUC-JP"),
-        138 => Some("Shift_JIS"),
-        140 => Some("EUC-KR"),
+        136 | 138 => Some("Shift_JIS"),
+        140 | 141 => Some("EUC-KR"),
+        167 => Some("ISO-2022-JP"),
+        227 => Some("ISO-8859-14"),
+        242 => Some("ISO-8859-13"),
         _ => None,
     }
 }
@@ -409,6 +451,12 @@ pub const MISSING_STANDARD_PATTERN_LE: [u8; 8] = [0x2E, 0x00, 0x00, 0x00, 0x00,
 /// SAS missing values are 7 zero bytes + sentinel byte at the high end (BE position 7).
 pub const MISSING_STANDARD_PATTERN_BE: [u8; 8] = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2E];
 
+/// Shortest numeric storage length SAS writes (`LENGTH x 3;`).
+pub const NUMERIC_LENGTH_MIN: u32 = 3;
+
+/// Longest numeric storage length: a full IEEE 754 double.
+pub const NUMERIC_LENGTH_MAX: u32 = 8;
+
 /// Checks if an 8-byte double value represents a SAS missing value.
 ///
 /// # Arguments
@@ -2,6 +2,7 @@
 //!
 //! This module handles:
 //! - Extracting rows from data pages, mix pages, and compressed pages
+//! - Skipping rows marked deleted in a page's deleted-row bitmap
 //! - Converting numeric values (full 8-byte and truncated 3-7 byte)
 //! - Detecting SAS missing value sentinels
 //! - Converting SAS dates/datetimes/times to Unix epoch-based representations
@@ -10,7 +11,7 @@
 
 use super::constants::*;
 use super::error::SasError;
-use super::page::{is_page_data, is_page_mix, parse_page_header};
+use super::page::{has_deleted_rows, is_page_data, is_page_mix, parse_page_header};
 use super::{Compression, PolarsOutputType, SasColumn, SasDataType, SasEncoding, SasHeader};
 use polars::prelude::*;
 
@@ -57,6 +58,9 @@ pub enum ColumnValue {
 /// - **Data pages**: Rows start after page header (offset 24 for 64-bit, 8 for 32-bit)
 /// - **Mix pages**: Rows start after subheader pointer table
 ///
+/// Rows marked in a page's deleted-row bitmap (page types 0x0180 and 0x0280)
+/// are skipped and do not count toward `total_rows`.
+///
 /// Note: COMP pages (0x9000) and compressed subheader rows are handled by the
 /// caller in `mod.rs`. This function only handles uncompressed DATA and MIX pages.
 #[allow(dead_code)]
@@ -80,13 +84,13 @@ pub fn extract_rows_from_page(
     let page_bit_offset: usize = if header.is_64bit { 32 } else { 16 };
     let page_header_size = page_bit_offset + 8; // prefix + type(2) + block_count(2) + subheader_count(2) + pad(2)
 
+    let pointer_size: usize = if header.is_64bit { 24 } else { 12 };
     let (data_start, rows_on_page) = if is_page_data(page_header.page_type) {
         // Data page: rows start after page header (no subheader pointers)
         let rows = page_header.block_count as u64;
         (page_header_size, rows)
     } else if is_page_mix(page_header.page_type) {
         // Mix page: rows start after subheader pointer table, aligned to 8 bytes
-        let pointer_size: usize = if header.is_64bit { 24 } else { 12 };
         let raw_offset = page_header_size + (page_header.subheader_count as usize * pointer_size);
         // Align to 8-byte boundary (required by SAS7BDAT spec)
         let offset = (raw_offset + 7) & !7;
@@ -102,15 +106,34 @@ pub fn extract_rows_from_page(
         return Ok(Vec::new());
     };
 
-    // Don't exceed total row count
+    let row_length = header.row_length as usize;
+    let deleted = if has_deleted_rows(page_header.page_type) {
+        deleted_rows_bitmap(
+            page_data,
+            header,
+            page_header.block_count,
+            page_header.subheader_count,
+            pointer_size,
+            page_header_size,
+        )
+    } else {
+        None
+    };
+
+    // Don't exceed total row count (which excludes deleted rows)
     let remaining_rows = total_rows.saturating_sub(rows_collected);
-    let rows_to_extract = std::cmp::min(rows_on_page, remaining_rows);
 
     // Extract rows
-    let mut rows = Vec::with_capacity(rows_to_extract as usize);
-    let row_length = header.row_length as usize;
+    let mut rows = Vec::with_capacity(std::cmp::min(rows_on_page, remaining_rows) as usize);
+
+    for row_idx in 0..rows_on_page {
+        if rows.len() as u64 >= remaining_rows {
+            break;
+        }
+        if deleted.is_some_and(|bitmap| is_row_deleted(bitmap, row_idx as usize)) {
+            continue;
+        }
 
-    for row_idx in 0..rows_to_extract {
         let row_stride = (row_idx as usize)
             .checked_mul(row_length)
             .ok_or_else(|| SasError::InvalidHeader("Row offset overflow".into()))?;
@@ -132,6 +155,51 @@ pub fn extract_rows_from_page(
     Ok(rows)
 }
 
+/// Locates the deleted-row bitmap of a DATA or MIX page.
+///
+/// The bitmap holds one bit per row slot (`block_count - subheader_count`),
+/// most significant bit first, and starts at the page's deleted-row pointer
+/// past the end of the row slots. Returns `None` when the bitmap would fall
+/// outside the page, in which case every row is kept.
+fn deleted_rows_bitmap<'a>(
+    page_data: &'a [u8],
+    header: &SasHeader,
+    block_count: u16,
+    subheader_count: u16,
+    pointer_size: usize,
+    page_header_size: usize,
+) -> Option<&'a [u8]> {
+    let pointer_offset = if header.is_64bit {
+        PAGE_DELETED_POINTER_OFFSET_64
+    } else {
+        PAGE_DELETED_POINTER_OFFSET_32
+    };
+    let pointer_bytes: [u8; 4] = page_data
+        .get(pointer_offset..pointer_offset + 4)?
+        .try_into()
+        .ok()?;
+    let pointer = if header.is_little_endian {
+        u32::from_le_bytes(pointer_bytes)
+    } else {
+        u32::from_be_bytes(pointer_bytes)
+    } as usize;
+
+    let slots = block_count.saturating_sub(subheader_count) as usize;
+    let start = (subheader_count as usize)
+        .checked_mul(pointer_size)?
+        .checked_add(slots.checked_mul(header.row_length as usize)?)?
+        .checked_add(page_header_size)?
+        .checked_add(pointer)?;
+    page_data.get(start..start.checked_add(slots.div_ceil(8))?)
+}
+
+/// Checks the bit for `slot` in a deleted-row bitmap.
+fn is_row_deleted(bitmap: &[u8], slot: usize) -> bool {
+    bitmap
+        .get(slot / 8)
+        .is_some_and(|byte| byte & (0x80 >> (slot % 8)) != 0)
+}
+
 /// Extracts values for all columns from a single row.
 ///
 /// # Arguments
@@ -178,10 +246,38 @@ pub fn extract_row_values(
     Ok(values)
 }
 
+/// Checks if SAS can store a numeric column in `length` bytes.
+fn is_valid_numeric_length(length: usize) -> bool {
+    (NUMERIC_LENGTH_MIN as usize..=NUMERIC_LENGTH_MAX as usize).contains(&length)
+}
+
+/// Rejects numeric columns whose storage length SAS cannot write.
+///
+/// SAS stores a numeric in 3 to 8 bytes, keeping the most significant bytes
+/// of the double. A length outside that range means a damaged or misparsed
+/// column definition, whose values would decode to garbage.
+///
+/// # Errors
+///
+/// * `SasError::InvalidNumericLength` - For the first such column
+pub fn check_numeric_lengths(columns: &[SasColumn]) -> Result<(), SasError> {
+    match columns.iter().find(|c| {
+        c.data_type == SasDataType::Numeric && !is_valid_numeric_length(c.length as usize)
+    }) {
+        Some(col) => Err(SasError::InvalidNumericLength {
+            column: col.name.clone(),
+            length: col.length,
+        }),
+        None => Ok(()),
+    }
+}
+
 /// Extracts a numeric value from raw bytes.
 ///
 /// Handles:
-/// - Zero-length columns (null)
+/// - Lengths SAS cannot write (0-2 or over 8 bytes) as null rather than a
+///   reconstruction from too few or too many bytes; [`check_numeric_lengths`]
+///   rejects such columns up front in strict mode
 /// - SAS missing value sentinels (standard `.` and special `.A`-`.Z`, `._`)
 /// - Truncated numerics (3-7 bytes) with zero-padding
 /// - Full 8-byte IEEE 754 doubles
@@ -189,7 +285,7 @@ pub fn extract_row_values(
 ///
 /// # Arguments
 ///
-/// * `bytes` - Raw column bytes (length 0-8)
+/// * `bytes` - Raw column bytes (length 3-8 for a valid column)
 /// * `polars_type` - Target Polars type
 /// * `is_little_endian` - Byte order
 ///
@@ -202,8 +298,7 @@ fn extract_numeric_value(
     polars_type: &PolarsOutputType,
     is_little_endian: bool,
 ) -> Result<ColumnValue, SasError> {
-    // Zero-length numeric = null
-    if bytes.is_empty() {
+    if !is_valid_numeric_length(bytes.len()) {
         return Ok(ColumnValue::Null);
     }
 
@@ -303,23 +398,7 @@ fn extract_numeric_value(
 /// * `ColumnValue::Utf8(String)` - Decoded string
 /// * `ColumnValue::Null` - If empty after trimming
 fn extract_character_value(bytes: &[u8], encoding: &SasEncoding) -> ColumnValue {
-    let decoded = match encoding {
-        SasEncoding::Utf8 | SasEncoding::Ascii => String::from_utf8_lossy(bytes).into_owned(),
-        SasEncoding::Latin1 | SasEncoding::Unspecified => {
-            // Use Windows-1252 which is a superset of Latin-1
-            encoding_rs::WINDOWS_1252.decode(bytes).0.into_owned()
-        }
-        SasEncoding::Windows1252 => encoding_rs::WINDOWS_1252.decode(bytes).0.into_owned(),
-        SasEncoding::Other { name, .. } => {
-            // Try to find the encoding by name
-            if let Some(enc) = encoding_rs::Encoding::for_label(name.as_bytes()) {
-                enc.decode(bytes).0.into_owned()
-            } else {
-                // Fallback to UTF-8 lossy
-                String::from_utf8_lossy(bytes).into_owned()
-            }
-        }
-    };
+    let decoded = encoding.decode(bytes);
 
     // Trim trailing spaces (SAS pads character columns)
     let trimmed = decoded.trim_end();
@@ -439,6 +518,16 @@ pub fn build_series_from_column_values(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_deleted_row_bitmap_is_msb_first() {
+        // Slots 0, 5 and 9 deleted
+        let bitmap = [0b1000_0100u8, 0b0100_0000];
+        let deleted: Vec<usize> = (0..16).filter(|&i| is_row_deleted(&bitmap, i)).collect();
+        assert_eq!(deleted, vec![0, 5, 9]);
+        // Slots past the bitmap are kept
+        assert!(!is_row_deleted(&bitmap, 40));
+    }
+
     #[test]
     fn test_truncated_numeric_le() {
         // SAS truncated numerics store the most-significant bytes.
@@ -512,6 +601,94 @@ mod tests {
         }
     }
 
+    /// `value` stored in `len` bytes: the most significant bytes of the double
+    fn truncate(value: f64, len: usize, is_little_endian: bool) -> Vec<u8> {
+        if is_little_endian {
+            value.to_le_bytes()[8 - len..].to_vec()
+        } else {
+            value.to_be_bytes()[..len].to_vec()
+        }
+    }
+
+    #[test]
+    fn test_truncated_numeric_all_lengths_both_endiannesses() {
+        for len in 3..=8 {
+            // Truncation zeroes the low mantissa bits
+            let mask = u64::MAX << (8 * (8 - len));
+            for value in [1.0, -42.5, 1234.5678, 21_915.0, 1.0e-300, f64::MAX] {
+                let expected = f64::from_bits(value.to_bits() & mask);
+                for le in [true, false] {
+                    let bytes = truncate(value, len, le);
+                    match extract_numeric_value(&bytes, &PolarsOutputType::Float64, le).unwrap() {
+                        ColumnValue::Float64(f) => assert_eq!(
+                            f.to_bits(),
+                            expected.to_bits(),
+                            "{value} in {len} bytes, little endian: {le}"
+                        ),
+                        other => panic!("Expected Float64 for {value} in {len} bytes: {other:?}"),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_truncated_missing_values_are_null() {
+        // SAS missing values are NaNs whose tag lives in the high bytes, so
+        // they survive truncation: `.` is 0xFFFFFE..., `.A` 0xFFFFBE...
+        for tag in [0xFEu8, 0xBE] {
+            let missing = f64::from_bits(u64::from_be_bytes([0xFF, 0xFF, tag, 0, 0, 0, 0, 0]));
+            for len in 3..=7 {
+                for le in [true, false] {
+                    let bytes = truncate(missing, len, le);
+                    let result =
+                        extract_numeric_value(&bytes, &PolarsOutputType::Float64, le).unwrap();
+                    assert!(matches!(result, ColumnValue::Null), "{len} bytes");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_impossible_numeric_lengths_are_null() {
+        // 2 bytes would reconstruct 8.0; 9 bytes cannot be a double at all
+        for bytes in [vec![], vec![0x20u8, 0x40], vec![0u8; 9]] {
+            for le in [true, false] {
+                let result = extract_numeric_value(&bytes, &PolarsOutputType::Float64, le).unwrap();
+                assert!(matches!(result, ColumnValue::Null), "{} bytes", bytes.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_check_numeric_lengths() {
+        let column = |name: &str, data_type, length| SasColumn {
+            name: name.to_string(),
+            data_type,
+            offset: 0,
+            length,
+            format: String::new(),
+            label: String::new(),
+            polars_type: PolarsOutputType::Float64,
+        };
+        let mut columns = vec![
+            column("full", SasDataType::Numeric, 8),
+            column("short", SasDataType::Numeric, 3),
+            // Character columns may be any length
+            column("code", SasDataType::Character, 1),
+        ];
+        assert!(check_numeric_lengths(&columns).is_ok());
+
+        columns.push(column("broken", SasDataType::Numeric, 2));
+        match check_numeric_lengths(&columns) {
+            Err(SasError::InvalidNumericLength { column, length }) => {
+                assert_eq!(column, "broken");
+                assert_eq!(length, 2);
+            }
+            other => panic!("Expected InvalidNumericLength, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_missing_value_detection() {
         // Standard missing: 0x2E sentinel at first byte (LE), remaining 7 bytes all zero.
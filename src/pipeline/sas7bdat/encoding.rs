@@ -0,0 +1,507 @@
+//! Character decoding for SAS7BDAT text.
+//!
+//! Multi-byte and most single-byte encodings are decoded with `encoding_rs`.
+//! Code pages it does not ship -- the DOS (IBM PC) pages and the EBCDIC pages
+//! written by mainframe SAS -- are decoded from the tables below.
+
+use std::borrow::Cow;
+
+use super::constants::encoding_name;
+use super::SasEncoding;
+
+impl SasEncoding {
+    /// Decode raw column or metadata bytes to text.
+    ///
+    /// Bytes that are invalid in the encoding become U+FFFD. Names that no
+    /// decoder recognises fall back to lossy UTF-8.
+    pub fn decode<'a>(&self, bytes: &'a [u8]) -> Cow<'a, str> {
+        match self {
+            SasEncoding::Utf8 | SasEncoding::Ascii => String::from_utf8_lossy(bytes),
+            // Windows-1252 is a superset of Latin-1; use it for all three variants
+            SasEncoding::Latin1 | SasEncoding::Windows1252 | SasEncoding::Unspecified => {
+                encoding_rs::WINDOWS_1252.decode(bytes).0
+            }
+            SasEncoding::Other { name, .. } => {
+                if let Some(table) = code_page(name) {
+                    Cow::Owned(table.decode(bytes))
+                } else if let Some(enc) = encoding_rs::Encoding::for_label(name.as_bytes()) {
+                    enc.decode(bytes).0
+                } else {
+                    String::from_utf8_lossy(bytes)
+                }
+            }
+        }
+    }
+
+    /// Look up an encoding by its SAS or IANA name.
+    ///
+    /// Accepts SAS session encoding names (`wlatin1`, `shift-jis`, `euc-cn`,
+    /// `ebcdic1047`, `open_ed-1047`), code page names (`cp037`, `ibm850`) and any
+    /// label `encoding_rs` understands. Case, `-` and `_` are ignored. Use this
+    /// for files whose header carries no usable encoding ID, such as EBCDIC
+    /// datasets transferred from z/OS.
+    #[allow(dead_code)] // Library API; the binary trusts the header
+    pub fn from_name(name: &str) -> Option<SasEncoding> {
+        let key: String = name
+            .chars()
+            .filter(|c| !matches!(c, '-' | '_' | ' '))
+            .collect::<String>()
+            .to_ascii_lowercase();
+
+        let id = match key.as_str() {
+            "utf8" => return Some(SasEncoding::Utf8),
+            "usascii" | "ascii" => return Some(SasEncoding::Ascii),
+            "latin1" | "iso88591" => return Some(SasEncoding::Latin1),
+            "wlatin1" | "windows1252" | "cp1252" => return Some(SasEncoding::Windows1252),
+            "latin2" => 30,
+            "latin3" => 31,
+            "latin4" => 32,
+            "cyrillic" => 33,
+            "arabic" => 34,
+            "greek" => 35,
+            "hebrew" => 36,
+            "latin5" => 37,
+            "thai" => 39,
+            "latin9" => 40,
+            "wlatin2" => 60,
+            "wcyrillic" => 61,
+            "wgreek" => 63,
+            "wturkish" => 64,
+            "whebrew" => 65,
+            "warabic" => 66,
+            "wbaltic" => 67,
+            "wvietnamese" => 68,
+            "shiftjis" | "sjis" | "cp932" => 138,
+            "euccn" | "gb2312" | "gb18030" => 125,
+            "gbk" | "cp936" => 126,
+            "big5" | "cp950" => 123,
+            "eucjp" => 134,
+            "euckr" | "cp949" => 140,
+            _ => {
+                let digits = ["ebcdic", "opened", "ibm", "cp"]
+                    .iter()
+                    .find_map(|prefix| key.strip_prefix(prefix))
+                    .and_then(|rest| rest.parse::<u16>().ok());
+                if let Some(page) = digits {
+                    if let Some(name) = CODE_PAGE_NAMES
+                        .iter()
+                        .find(|(number, _)| *number == page)
+                        .map(|(_, name)| *name)
+                    {
+                        return Some(SasEncoding::Other { id: 0, name });
+                    }
+                }
+                return encoding_rs::Encoding::for_label(name.trim().as_bytes()).map(|enc| {
+                    SasEncoding::Other {
+                        id: 0,
+                        name: enc.name(),
+                    }
+                });
+            }
+        };
+        encoding_name(id).map(|name| SasEncoding::Other { id, name })
+    }
+}
+
+/// Code pages decoded from the built-in tables, by number
+#[allow(dead_code)]
+const CODE_PAGE_NAMES: &[(u16, &str)] = &[
+    (37, "IBM037"),
+    (273, "IBM273"),
+    (437, "IBM437"),
+    (500, "IBM500"),
+    (720, "IBM720"),
+    (737, "IBM737"),
+    (775, "IBM775"),
+    (850, "IBM850"),
+    (852, "IBM852"),
+    (857, "IBM857"),
+    (858, "IBM858"),
+    (860, "IBM860"),
+    (862, "IBM862"),
+    (863, "IBM863"),
+    (865, "IBM865"),
+    (869, "IBM869"),
+    (1047, "IBM1047"),
+    (1140, "IBM1140"),
+];
+
+/// A single-byte code page
+enum CodePage {
+    /// ASCII in 0x00-0x7F, the table in 0x80-0xFF (DOS code pages)
+    AsciiHigh(&'static [char; 128]),
+    /// Every byte mapped (EBCDIC code pages)
+    Full(&'static [char; 256]),
+}
+
+impl CodePage {
+    fn decode(&self, bytes: &[u8]) -> String {
+        match self {
+            CodePage::AsciiHigh(high) => bytes
+                .iter()
+                .map(|&b| {
+                    if b < 0x80 {
+                        b as char
+                    } else {
+                        high[(b - 0x80) as usize]
+                    }
+                })
+                .collect(),
+            CodePage::Full(table) => bytes.iter().map(|&b| table[b as usize]).collect(),
+        }
+    }
+}
+
+/// The built-in table for an encoding name, if there is one
+fn code_page(name: &str) -> Option<CodePage> {
+    Some(match name {
+        "IBM437" => CodePage::AsciiHigh(&IBM437_HIGH),
+        "IBM720" => CodePage::AsciiHigh(&IBM720_HIGH),
+        "IBM737" => CodePage::AsciiHigh(&IBM737_HIGH),
+        "IBM775" => CodePage::AsciiHigh(&IBM775_HIGH),
+        "IBM850" => CodePage::AsciiHigh(&IBM850_HIGH),
+        "IBM852" => CodePage::AsciiHigh(&IBM852_HIGH),
+        "IBM857" => CodePage::AsciiHigh(&IBM857_HIGH),
+        "IBM858" => CodePage::AsciiHigh(&IBM858_HIGH),
+        "IBM860" => CodePage::AsciiHigh(&IBM860_HIGH),
+        "IBM862" => CodePage::AsciiHigh(&IBM862_HIGH),
+        "IBM863" => CodePage::AsciiHigh(&IBM863_HIGH),
+        "IBM865" => CodePage::AsciiHigh(&IBM865_HIGH),
+        "IBM869" => CodePage::AsciiHigh(&IBM869_HIGH),
+        "IBM037" => CodePage::Full(&IBM037),
+        "IBM273" => CodePage::Full(&IBM273),
+        "IBM500" => CodePage::Full(&IBM500),
+        "IBM1047" => CodePage::Full(&IBM1047),
+        "IBM1140" => CodePage::Full(&IBM1140),
+        _ => return None,
+    })
+}
+
+/// DOS United States (IBM437), bytes 0x80-0xFF
+const IBM437_HIGH: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å', 'É', 'æ', 'Æ',
+    'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', '¢', '£', '¥', '₧', 'ƒ', 'á', 'í', 'ó', 'ú', 'ñ', 'Ñ',
+    'ª', 'º', '¿', '⌐', '¬', '½', '¼', '¡', '«', '»', '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕',
+    '╣', '║', '╗', '╝', '╜', '╛', '┐', '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦',
+    '╠', '═', '╬', '╧', '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐',
+    '▀', 'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ', 'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩', '≡', '±',
+    '≥', '≤', '⌠', '⌡', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{a0}',
+];
+
+/// DOS Arabic (IBM720), bytes 0x80-0xFF
+const IBM720_HIGH: [char; 128] = [
+    '\u{80}', '\u{81}', 'é', 'â', '\u{84}', 'à', '\u{86}', 'ç', 'ê', 'ë', 'è', 'ï', 'î', '\u{8d}',
+    '\u{8e}', '\u{8f}', '\u{90}', 'ّ', 'ْ', 'ô', '¤', 'ـ', 'û', 'ù', 'ء', 'آ', 'أ', 'ؤ', '£', 'إ',
+    'ئ', 'ا', 'ب', 'ة', 'ت', 'ث', 'ج', 'ح', 'خ', 'د', 'ذ', 'ر', 'ز', 'س', 'ش', 'ص', '«', '»', '░',
+    '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐', '└', '┴', '┬', '├',
+    '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧', '╨', '╤', '╥', '╙', '╘', '╒', '╓',
+    '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀', 'ض', 'ط', 'ظ', 'ع', 'غ', 'ف', 'µ', 'ق', 'ك', 'ل',
+    'م', 'ن', 'ه', 'و', 'ى', 'ي', '≡', 'ً', 'ٌ', 'ٍ', 'َ', 'ُ', 'ِ', '≈', '°', '∙', '·', '√', 'ⁿ', '²',
+    '■', '\u{a0}',
+];
+
+/// DOS Greek (IBM737), bytes 0x80-0xFF
+const IBM737_HIGH: [char; 128] = [
+    'Α', 'Β', 'Γ', 'Δ', 'Ε', 'Ζ', 'Η', 'Θ', 'Ι', 'Κ', 'Λ', 'Μ', 'Ν', 'Ξ', 'Ο', 'Π', 'Ρ', 'Σ', 'Τ',
+    'Υ', 'Φ', 'Χ', 'Ψ', 'Ω', 'α', 'β', 'γ', 'δ', 'ε', 'ζ', 'η', 'θ', 'ι', 'κ', 'λ', 'μ', 'ν', 'ξ',
+    'ο', 'π', 'ρ', 'σ', 'ς', 'τ', 'υ', 'φ', 'χ', 'ψ', '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕',
+    '╣', '║', '╗', '╝', '╜', '╛', '┐', '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦',
+    '╠', '═', '╬', '╧', '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐',
+    '▀', 'ω', 'ά', 'έ', 'ή', 'ϊ', 'ί', 'ό', 'ύ', 'ϋ', 'ώ', 'Ά', 'Έ', 'Ή', 'Ί', 'Ό', 'Ύ', 'Ώ', '±',
+    '≥', '≤', 'Ϊ', 'Ϋ', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{a0}',
+];
+
+/// DOS Baltic Rim (IBM775), bytes 0x80-0xFF
+const IBM775_HIGH: [char; 128] = [
+    'Ć', 'ü', 'é', 'ā', 'ä', 'ģ', 'å', 'ć', 'ł', 'ē', 'Ŗ', 'ŗ', 'ī', 'Ź', 'Ä', 'Å', 'É', 'æ', 'Æ',
+    'ō', 'ö', 'Ģ', '¢', 'Ś', 'ś', 'Ö', 'Ü', 'ø', '£', 'Ø', '×', '¤', 'Ā', 'Ī', 'ó', 'Ż', 'ż', 'ź',
+    '”', '¦', '©', '®', '¬', '½', '¼', 'Ł', '«', '»', '░', '▒', '▓', '│', '┤', 'Ą', 'Č', 'Ę', 'Ė',
+    '╣', '║', '╗', '╝', 'Į', 'Š', '┐', '└', '┴', '┬', '├', '─', '┼', 'Ų', 'Ū', '╚', '╔', '╩', '╦',
+    '╠', '═', '╬', 'Ž', 'ą', 'č', 'ę', 'ė', 'į', 'š', 'ų', 'ū', 'ž', '┘', '┌', '█', '▄', '▌', '▐',
+    '▀', 'Ó', 'ß', 'Ō', 'Ń', 'õ', 'Õ', 'µ', 'ń', 'Ķ', 'ķ', 'Ļ', 'ļ', 'ņ', 'Ē', 'Ņ', '’', '\u{ad}',
+    '±', '“', '¾', '¶', '§', '÷', '„', '°', '∙', '·', '¹', '³', '²', '■', '\u{a0}',
+];
+
+/// DOS Western Europe (IBM850), bytes 0x80-0xFF
+const IBM850_HIGH: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å', 'É', 'æ', 'Æ',
+    'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', 'ø', '£', 'Ø', '×', 'ƒ', 'á', 'í', 'ó', 'ú', 'ñ', 'Ñ',
+    'ª', 'º', '¿', '®', '¬', '½', '¼', '¡', '«', '»', '░', '▒', '▓', '│', '┤', 'Á', 'Â', 'À', '©',
+    '╣', '║', '╗', '╝', '¢', '¥', '┐', '└', '┴', '┬', '├', '─', '┼', 'ã', 'Ã', '╚', '╔', '╩', '╦',
+    '╠', '═', '╬', '¤', 'ð', 'Ð', 'Ê', 'Ë', 'È', 'ı', 'Í', 'Î', 'Ï', '┘', '┌', '█', '▄', '¦', 'Ì',
+    '▀', 'Ó', 'ß', 'Ô', 'Ò', 'õ', 'Õ', 'µ', 'þ', 'Þ', 'Ú', 'Û', 'Ù', 'ý', 'Ý', '¯', '´', '\u{ad}',
+    '±', '‗', '¾', '¶', '§', '÷', '¸', '°', '¨', '·', '¹', '³', '²', '■', '\u{a0}',
+];
+
+/// DOS Central Europe (IBM852), bytes 0x80-0xFF
+const IBM852_HIGH: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'ů', 'ć', 'ç', 'ł', 'ë', 'Ő', 'ő', 'î', 'Ź', 'Ä', 'Ć', 'É', 'Ĺ', 'ĺ',
+    'ô', 'ö', 'Ľ', 'ľ', 'Ś', 'ś', 'Ö', 'Ü', 'Ť', 'ť', 'Ł', '×', 'č', 'á', 'í', 'ó', 'ú', 'Ą', 'ą',
+    'Ž', 'ž', 'Ę', 'ę', '¬', 'ź', 'Č', 'ş', '«', '»', '░', '▒', '▓', '│', '┤', 'Á', 'Â', 'Ě', 'Ş',
+    '╣', '║', '╗', '╝', 'Ż', 'ż', '┐', '└', '┴', '┬', '├', '─', '┼', 'Ă', 'ă', '╚', '╔', '╩', '╦',
+    '╠', '═', '╬', '¤', 'đ', 'Đ', 'Ď', 'Ë', 'ď', 'Ň', 'Í', 'Î', 'ě', '┘', '┌', '█', '▄', 'Ţ', 'Ů',
+    '▀', 'Ó', 'ß', 'Ô', 'Ń', 'ń', 'ň', 'Š', 'š', 'Ŕ', 'Ú', 'ŕ', 'Ű', 'ý', 'Ý', 'ţ', '´', '\u{ad}',
+    '˝', '˛', 'ˇ', '˘', '§', '÷', '¸', '°', '¨', '˙', 'ű', 'Ř', 'ř', '■', '\u{a0}',
+];
+
+/// DOS Turkish (IBM857), bytes 0x80-0xFF
+const IBM857_HIGH: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ı', 'Ä', 'Å', 'É', 'æ', 'Æ',
+    'ô', 'ö', 'ò', 'û', 'ù', 'İ', 'Ö', 'Ü', 'ø', '£', 'Ø', 'Ş', 'ş', 'á', 'í', 'ó', 'ú', 'ñ', 'Ñ',
+    'Ğ', 'ğ', '¿', '®', '¬', '½', '¼', '¡', '«', '»', '░', '▒', '▓', '│', '┤', 'Á', 'Â', 'À', '©',
+    '╣', '║', '╗', '╝', '¢', '¥', '┐', '└', '┴', '┬', '├', '─', '┼', 'ã', 'Ã', '╚', '╔', '╩', '╦',
+    '╠', '═', '╬', '¤', 'º', 'ª', 'Ê', 'Ë', 'È', '\u{fffd}', 'Í', 'Î', 'Ï', '┘', '┌', '█', '▄',
+    '¦', 'Ì', '▀', 'Ó', 'ß', 'Ô', 'Ò', 'õ', 'Õ', 'µ', '\u{fffd}', '×', 'Ú', 'Û', 'Ù', 'ì', 'ÿ',
+    '¯', '´', '\u{ad}', '±', '\u{fffd}', '¾', '¶', '§', '÷', '¸', '°', '¨', '·', '¹', '³', '²',
+    '■', '\u{a0}',
+];
+
+/// DOS Western Europe with euro (IBM858), bytes 0x80-0xFF
+const IBM858_HIGH: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å', 'É', 'æ', 'Æ',
+    'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', 'ø', '£', 'Ø', '×', 'ƒ', 'á', 'í', 'ó', 'ú', 'ñ', 'Ñ',
+    'ª', 'º', '¿', '®', '¬', '½', '¼', '¡', '«', '»', '░', '▒', '▓', '│', '┤', 'Á', 'Â', 'À', '©',
+    '╣', '║', '╗', '╝', '¢', '¥', '┐', '└', '┴', '┬', '├', '─', '┼', 'ã', 'Ã', '╚', '╔', '╩', '╦',
+    '╠', '═', '╬', '¤', 'ð', 'Ð', 'Ê', 'Ë', 'È', '€', 'Í', 'Î', 'Ï', '┘', '┌', '█', '▄', '¦', 'Ì',
+    '▀', 'Ó', 'ß', 'Ô', 'Ò', 'õ', 'Õ', 'µ', 'þ', 'Þ', 'Ú', 'Û', 'Ù', 'ý', 'Ý', '¯', '´', '\u{ad}',
+    '±', '‗', '¾', '¶', '§', '÷', '¸', '°', '¨', '·', '¹', '³', '²', '■', '\u{a0}',
+];
+
+/// DOS Portuguese (IBM860), bytes 0x80-0xFF
+const IBM860_HIGH: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ã', 'à', 'Á', 'ç', 'ê', 'Ê', 'è', 'Í', 'Ô', 'ì', 'Ã', 'Â', 'É', 'À', 'È',
+    'ô', 'õ', 'ò', 'Ú', 'ù', 'Ì', 'Õ', 'Ü', '¢', '£', 'Ù', '₧', 'Ó', 'á', 'í', 'ó', 'ú', 'ñ', 'Ñ',
+    'ª', 'º', '¿', 'Ò', '¬', '½', '¼', '¡', '«', '»', '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕',
+    '╣', '║', '╗', '╝', '╜', '╛', '┐', '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦',
+    '╠', '═', '╬', '╧', '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐',
+    '▀', 'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ', 'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩', '≡', '±',
+    '≥', '≤', '⌠', '⌡', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{a0}',
+];
+
+/// DOS Hebrew (IBM862), bytes 0x80-0xFF
+const IBM862_HIGH: [char; 128] = [
+    'א', 'ב', 'ג', 'ד', 'ה', 'ו', 'ז', 'ח', 'ט', 'י', 'ך', 'כ', 'ל', 'ם', 'מ', 'ן', 'נ', 'ס', 'ע',
+    'ף', 'פ', 'ץ', 'צ', 'ק', 'ר', 'ש', 'ת', '¢', '£', '¥', '₧', 'ƒ', 'á', 'í', 'ó', 'ú', 'ñ', 'Ñ',
+    'ª', 'º', '¿', '⌐', '¬', '½', '¼', '¡', '«', '»', '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕',
+    '╣', '║', '╗', '╝', '╜', '╛', '┐', '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦',
+    '╠', '═', '╬', '╧', '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐',
+    '▀', 'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ', 'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩', '≡', '±',
+    '≥', '≤', '⌠', '⌡', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{a0}',
+];
+
+/// DOS Canadian French (IBM863), bytes 0x80-0xFF
+const IBM863_HIGH: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'Â', 'à', '¶', 'ç', 'ê', 'ë', 'è', 'ï', 'î', '‗', 'À', '§', 'É', 'È', 'Ê',
+    'ô', 'Ë', 'Ï', 'û', 'ù', '¤', 'Ô', 'Ü', '¢', '£', 'Ù', 'Û', 'ƒ', '¦', '´', 'ó', 'ú', '¨', '¸',
+    '³', '¯', 'Î', '⌐', '¬', '½', '¼', '¾', '«', '»', '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕',
+    '╣', '║', '╗', '╝', '╜', '╛', '┐', '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦',
+    '╠', '═', '╬', '╧', '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐',
+    '▀', 'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ', 'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩', '≡', '±',
+    '≥', '≤', '⌠', '⌡', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{a0}',
+];
+
+/// DOS Nordic (IBM865), bytes 0x80-0xFF
+const IBM865_HIGH: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å', 'É', 'æ', 'Æ',
+    'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', 'ø', '£', 'Ø', '₧', 'ƒ', 'á', 'í', 'ó', 'ú', 'ñ', 'Ñ',
+    'ª', 'º', '¿', '⌐', '¬', '½', '¼', '¡', '«', '¤', '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕',
+    '╣', '║', '╗', '╝', '╜', '╛', '┐', '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦',
+    '╠', '═', '╬', '╧', '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐',
+    '▀', 'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ', 'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩', '≡', '±',
+    '≥', '≤', '⌠', '⌡', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{a0}',
+];
+
+/// DOS Greek 2 (IBM869), bytes 0x80-0xFF
+const IBM869_HIGH: [char; 128] = [
+    '\u{fffd}', '\u{fffd}', '\u{fffd}', '\u{fffd}', '\u{fffd}', '\u{fffd}', 'Ά', '\u{fffd}', '·',
+    '¬', '¦', '‘', '’', 'Έ', '―', 'Ή', 'Ί', 'Ϊ', 'Ό', '\u{fffd}', '\u{fffd}', 'Ύ', 'Ϋ', '©', 'Ώ',
+    '²', '³', 'ά', '£', 'έ', 'ή', 'ί', 'ϊ', 'ΐ', 'ό', 'ύ', 'Α', 'Β', 'Γ', 'Δ', 'Ε', 'Ζ', 'Η', '½',
+    'Θ', 'Ι', '«', '»', '░', '▒', '▓', '│', '┤', 'Κ', 'Λ', 'Μ', 'Ν', '╣', '║', '╗', '╝', 'Ξ', 'Ο',
+    '┐', '└', '┴', '┬', '├', '─', '┼', 'Π', 'Ρ', '╚', '╔', '╩', '╦', '╠', '═', '╬', 'Σ', 'Τ', 'Υ',
+    'Φ', 'Χ', 'Ψ', 'Ω', 'α', 'β', 'γ', '┘', '┌', '█', '▄', 'δ', 'ε', '▀', 'ζ', 'η', 'θ', 'ι', 'κ',
+    'λ', 'μ', 'ν', 'ξ', 'ο', 'π', 'ρ', 'σ', 'ς', 'τ', '΄', '\u{ad}', '±', 'υ', 'φ', 'χ', '§', 'ψ',
+    '΅', '°', '¨', 'ω', 'ϋ', 'ΰ', 'ώ', '■', '\u{a0}',
+];
+
+/// EBCDIC US/Canada (IBM037)
+const IBM037: [char; 256] = [
+    '\u{0}', '\u{1}', '\u{2}', '\u{3}', '\u{9c}', '\u{9}', '\u{86}', '\u{7f}', '\u{97}', '\u{8d}',
+    '\u{8e}', '\u{b}', '\u{c}', '\u{d}', '\u{e}', '\u{f}', '\u{10}', '\u{11}', '\u{12}', '\u{13}',
+    '\u{9d}', '\u{85}', '\u{8}', '\u{87}', '\u{18}', '\u{19}', '\u{92}', '\u{8f}', '\u{1c}',
+    '\u{1d}', '\u{1e}', '\u{1f}', '\u{80}', '\u{81}', '\u{82}', '\u{83}', '\u{84}', '\u{a}',
+    '\u{17}', '\u{1b}', '\u{88}', '\u{89}', '\u{8a}', '\u{8b}', '\u{8c}', '\u{5}', '\u{6}',
+    '\u{7}', '\u{90}', '\u{91}', '\u{16}', '\u{93}', '\u{94}', '\u{95}', '\u{96}', '\u{4}',
+    '\u{98}', '\u{99}', '\u{9a}', '\u{9b}', '\u{14}', '\u{15}', '\u{9e}', '\u{1a}', ' ', '\u{a0}',
+    'â', 'ä', 'à', 'á', 'ã', 'å', 'ç', 'ñ', '¢', '.', '<', '(', '+', '|', '&', 'é', 'ê', 'ë', 'è',
+    'í', 'î', 'ï', 'ì', 'ß', '!', '$', '*', ')', ';', '¬', '-', '/', 'Â', 'Ä', 'À', 'Á', 'Ã', 'Å',
+    'Ç', 'Ñ', '¦', ',', '%', '_', '>', '?', 'ø', 'É', 'Ê', 'Ë', 'È', 'Í', 'Î', 'Ï', 'Ì', '`', ':',
+    '#', '@', '\'', '=', '"', 'Ø', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', '«', '»', 'ð', 'ý',
+    'þ', '±', '°', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 'ª', 'º', 'æ', '¸', 'Æ', '¤', 'µ',
+    '~', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '¡', '¿', 'Ð', 'Ý', 'Þ', '®', '^', '£', '¥', '·',
+    '©', '§', '¶', '¼', '½', '¾', '[', ']', '¯', '¨', '´', '×', '{', 'A', 'B', 'C', 'D', 'E', 'F',
+    'G', 'H', 'I', '\u{ad}', 'ô', 'ö', 'ò', 'ó', 'õ', '}', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
+    'R', '¹', 'û', 'ü', 'ù', 'ú', 'ÿ', '\\', '÷', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '²', 'Ô',
+    'Ö', 'Ò', 'Ó', 'Õ', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '³', 'Û', 'Ü', 'Ù', 'Ú',
+    '\u{9f}',
+];
+
+/// EBCDIC Germany/Austria (IBM273)
+const IBM273: [char; 256] = [
+    '\u{0}', '\u{1}', '\u{2}', '\u{3}', '\u{9c}', '\u{9}', '\u{86}', '\u{7f}', '\u{97}', '\u{8d}',
+    '\u{8e}', '\u{b}', '\u{c}', '\u{d}', '\u{e}', '\u{f}', '\u{10}', '\u{11}', '\u{12}', '\u{13}',
+    '\u{9d}', '\u{85}', '\u{8}', '\u{87}', '\u{18}', '\u{19}', '\u{92}', '\u{8f}', '\u{1c}',
+    '\u{1d}', '\u{1e}', '\u{1f}', '\u{80}', '\u{81}', '\u{82}', '\u{83}', '\u{84}', '\u{a}',
+    '\u{17}', '\u{1b}', '\u{88}', '\u{89}', '\u{8a}', '\u{8b}', '\u{8c}', '\u{5}', '\u{6}',
+    '\u{7}', '\u{90}', '\u{91}', '\u{16}', '\u{93}', '\u{94}', '\u{95}', '\u{96}', '\u{4}',
+    '\u{98}', '\u{99}', '\u{9a}', '\u{9b}', '\u{14}', '\u{15}', '\u{9e}', '\u{1a}', ' ', '\u{a0}',
+    'â', '{', 'à', 'á', 'ã', 'å', 'ç', 'ñ', 'Ä', '.', '<', '(', '+', '!', '&', 'é', 'ê', 'ë', 'è',
+    'í', 'î', 'ï', 'ì', '~', 'Ü', '$', '*', ')', ';', '^', '-', '/', 'Â', '[', 'À', 'Á', 'Ã', 'Å',
+    'Ç', 'Ñ', 'ö', ',', '%', '_', '>', '?', 'ø', 'É', 'Ê', 'Ë', 'È', 'Í', 'Î', 'Ï', 'Ì', '`', ':',
+    '#', '§', '\'', '=', '"', 'Ø', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', '«', '»', 'ð', 'ý',
+    'þ', '±', '°', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 'ª', 'º', 'æ', '¸', 'Æ', '¤', 'µ',
+    'ß', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '¡', '¿', 'Ð', 'Ý', 'Þ', '®', '¢', '£', '¥', '·',
+    '©', '@', '¶', '¼', '½', '¾', '¬', '|', '‾', '¨', '´', '×', 'ä', 'A', 'B', 'C', 'D', 'E', 'F',
+    'G', 'H', 'I', '\u{ad}', 'ô', '¦', 'ò', 'ó', 'õ', 'ü', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
+    'R', '¹', 'û', '}', 'ù', 'ú', 'ÿ', 'Ö', '÷', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '²', 'Ô',
+    '\\', 'Ò', 'Ó', 'Õ', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '³', 'Û', ']', 'Ù', 'Ú',
+    '\u{9f}',
+];
+
+/// EBCDIC International (IBM500)
+const IBM500: [char; 256] = [
+    '\u{0}', '\u{1}', '\u{2}', '\u{3}', '\u{9c}', '\u{9}', '\u{86}', '\u{7f}', '\u{97}', '\u{8d}',
+    '\u{8e}', '\u{b}', '\u{c}', '\u{d}', '\u{e}', '\u{f}', '\u{10}', '\u{11}', '\u{12}', '\u{13}',
+    '\u{9d}', '\u{85}', '\u{8}', '\u{87}', '\u{18}', '\u{19}', '\u{92}', '\u{8f}', '\u{1c}',
+    '\u{1d}', '\u{1e}', '\u{1f}', '\u{80}', '\u{81}', '\u{82}', '\u{83}', '\u{84}', '\u{a}',
+    '\u{17}', '\u{1b}', '\u{88}', '\u{89}', '\u{8a}', '\u{8b}', '\u{8c}', '\u{5}', '\u{6}',
+    '\u{7}', '\u{90}', '\u{91}', '\u{16}', '\u{93}', '\u{94}', '\u{95}', '\u{96}', '\u{4}',
+    '\u{98}', '\u{99}', '\u{9a}', '\u{9b}', '\u{14}', '\u{15}', '\u{9e}', '\u{1a}', ' ', '\u{a0}',
+    'â', 'ä', 'à', 'á', 'ã', 'å', 'ç', 'ñ', '[', '.', '<', '(', '+', '!', '&', 'é', 'ê', 'ë', 'è',
+    'í', 'î', 'ï', 'ì', 'ß', ']', '$', '*', ')', ';', '^', '-', '/', 'Â', 'Ä', 'À', 'Á', 'Ã', 'Å',
+    'Ç', 'Ñ', '¦', ',', '%', '_', '>', '?', 'ø', 'É', 'Ê', 'Ë', 'È', 'Í', 'Î', 'Ï', 'Ì', '`', ':',
+    '#', '@', '\'', '=', '"', 'Ø', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', '«', '»', 'ð', 'ý',
+    'þ', '±', '°', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 'ª', 'º', 'æ', '¸', 'Æ', '¤', 'µ',
+    '~', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '¡', '¿', 'Ð', 'Ý', 'Þ', '®', '¢', '£', '¥', '·',
+    '©', '§', '¶', '¼', '½', '¾', '¬', '|', '¯', '¨', '´', '×', '{', 'A', 'B', 'C', 'D', 'E', 'F',
+    'G', 'H', 'I', '\u{ad}', 'ô', 'ö', 'ò', 'ó', 'õ', '}', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
+    'R', '¹', 'û', 'ü', 'ù', 'ú', 'ÿ', '\\', '÷', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '²', 'Ô',
+    'Ö', 'Ò', 'Ó', 'Õ', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '³', 'Û', 'Ü', 'Ù', 'Ú',
+    '\u{9f}',
+];
+
+/// EBCDIC Latin-1 open systems (z/OS UNIX) (IBM1047)
+const IBM1047: [char; 256] = [
+    '\u{0}', '\u{1}', '\u{2}', '\u{3}', '\u{9c}', '\u{9}', '\u{86}', '\u{7f}', '\u{97}', '\u{8d}',
+    '\u{8e}', '\u{b}', '\u{c}', '\u{d}', '\u{e}', '\u{f}', '\u{10}', '\u{11}', '\u{12}', '\u{13}',
+    '\u{9d}', '\u{85}', '\u{8}', '\u{87}', '\u{18}', '\u{19}', '\u{92}', '\u{8f}', '\u{1c}',
+    '\u{1d}', '\u{1e}', '\u{1f}', '\u{80}', '\u{81}', '\u{82}', '\u{83}', '\u{84}', '\u{a}',
+    '\u{17}', '\u{1b}', '\u{88}', '\u{89}', '\u{8a}', '\u{8b}', '\u{8c}', '\u{5}', '\u{6}',
+    '\u{7}', '\u{90}', '\u{91}', '\u{16}', '\u{93}', '\u{94}', '\u{95}', '\u{96}', '\u{4}',
+    '\u{98}', '\u{99}', '\u{9a}', '\u{9b}', '\u{14}', '\u{15}', '\u{9e}', '\u{1a}', ' ', '\u{a0}',
+    'â', 'ä', 'à', 'á', 'ã', 'å', 'ç', 'ñ', '¢', '.', '<', '(', '+', '|', '&', 'é', 'ê', 'ë', 'è',
+    'í', 'î', 'ï', 'ì', 'ß', '!', '$', '*', ')', ';', '^', '-', '/', 'Â', 'Ä', 'À', 'Á', 'Ã', 'Å',
+    'Ç', 'Ñ', '¦', ',', '%', '_', '>', '?', 'ø', 'É', 'Ê', 'Ë', 'È', 'Í', 'Î', 'Ï', 'Ì', '`', ':',
+    '#', '@', '\'', '=', '"', 'Ø', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', '«', '»', 'ð', 'ý',
+    'þ', '±', '°', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 'ª', 'º', 'æ', '¸', 'Æ', '¤', 'µ',
+    '~', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '¡', '¿', 'Ð', '[', 'Þ', '®', '¬', '£', '¥', '·',
+    '©', '§', '¶', '¼', '½', '¾', 'Ý', '¨', '¯', ']', '´', '×', '{', 'A', 'B', 'C', 'D', 'E', 'F',
+    'G', 'H', 'I', '\u{ad}', 'ô', 'ö', 'ò', 'ó', 'õ', '}', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
+    'R', '¹', 'û', 'ü', 'ù', 'ú', 'ÿ', '\\', '÷', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '²', 'Ô',
+    'Ö', 'Ò', 'Ó', 'Õ', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '³', 'Û', 'Ü', 'Ù', 'Ú',
+    '\u{9f}',
+];
+
+/// EBCDIC US/Canada with euro (IBM1140)
+const IBM1140: [char; 256] = [
+    '\u{0}', '\u{1}', '\u{2}', '\u{3}', '\u{9c}', '\u{9}', '\u{86}', '\u{7f}', '\u{97}', '\u{8d}',
+    '\u{8e}', '\u{b}', '\u{c}', '\u{d}', '\u{e}', '\u{f}', '\u{10}', '\u{11}', '\u{12}', '\u{13}',
+    '\u{9d}', '\u{85}', '\u{8}', '\u{87}', '\u{18}', '\u{19}', '\u{92}', '\u{8f}', '\u{1c}',
+    '\u{1d}', '\u{1e}', '\u{1f}', '\u{80}', '\u{81}', '\u{82}', '\u{83}', '\u{84}', '\u{a}',
+    '\u{17}', '\u{1b}', '\u{88}', '\u{89}', '\u{8a}', '\u{8b}', '\u{8c}', '\u{5}', '\u{6}',
+    '\u{7}', '\u{90}', '\u{91}', '\u{16}', '\u{93}', '\u{94}', '\u{95}', '\u{96}', '\u{4}',
+    '\u{98}', '\u{99}', '\u{9a}', '\u{9b}', '\u{14}', '\u{15}', '\u{9e}', '\u{1a}', ' ', '\u{a0}',
+    'â', 'ä', 'à', 'á', 'ã', 'å', 'ç', 'ñ', '¢', '.', '<', '(', '+', '|', '&', 'é', 'ê', 'ë', 'è',
+    'í', 'î', 'ï', 'ì', 'ß', '!', '$', '*', ')', ';', '¬', '-', '/', 'Â', 'Ä', 'À', 'Á', 'Ã', 'Å',
+    'Ç', 'Ñ', '¦', ',', '%', '_', '>', '?', 'ø', 'É', 'Ê', 'Ë', 'È', 'Í', 'Î', 'Ï', 'Ì', '`', ':',
+    '#', '@', '\'', '=', '"', 'Ø', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', '«', '»', 'ð', 'ý',
+    'þ', '±', '°', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 'ª', 'º', 'æ', '¸', 'Æ', '€', 'µ',
+    '~', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '¡', '¿', 'Ð', 'Ý', 'Þ', '®', '^', '£', '¥', '·',
+    '©', '§', '¶', '¼', '½', '¾', '[', ']', '¯', '¨', '´', '×', '{', 'A', 'B', 'C', 'D', 'E', 'F',
+    'G', 'H', 'I', '\u{ad}', 'ô', 'ö', 'ò', 'ó', 'õ', '}', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
+    'R', '¹', 'û', 'ü', 'ù', 'ú', 'ÿ', '\\', '÷', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '²', 'Ô',
+    'Ö', 'Ò', 'Ó', 'Õ', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '³', 'Û', 'Ü', 'Ù', 'Ú',
+    '\u{9f}',
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn other(id: u16) -> SasEncoding {
+        SasEncoding::Other {
+            id,
+            name: encoding_name(id).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_decode_shift_jis() {
+        // "日本" in Shift_JIS
+        let bytes = [0x93, 0xFA, 0x96, 0x7B];
+        assert_eq!(other(138).decode(&bytes), "日本");
+    }
+
+    #[test]
+    fn test_decode_gb18030() {
+        // "中文" in GB2312 / GB18030
+        let bytes = [0xD6, 0xD0, 0xCE, 0xC4];
+        assert_eq!(other(125).decode(&bytes), "中文");
+    }
+
+    #[test]
+    fn test_decode_iso_8859_5() {
+        // 0xD0 = "а" (Cyrillic small a)
+        assert_eq!(other(33).decode(&[0xD0]), "а");
+    }
+
+    #[test]
+    fn test_decode_dos_code_page() {
+        // 0x82 = é and 0xB0 = ░ in CP437; ASCII passes through
+        assert_eq!(other(41).decode(&[b'A', 0x82, 0xB0]), "Aé░");
+    }
+
+    #[test]
+    fn test_decode_ebcdic() {
+        // "SAS 9" in EBCDIC
+        let bytes = [0xE2, 0xC1, 0xE2, 0x40, 0xF9];
+        let cp037 = SasEncoding::from_name("ebcdic037").unwrap();
+        assert_eq!(cp037.decode(&bytes), "SAS 9");
+
+        // Brackets differ between CP037 and CP1047
+        let cp1047 = SasEncoding::from_name("open_ed-1047").unwrap();
+        assert_eq!(cp1047.decode(&[0xAD, 0xBD]), "[]");
+        assert_eq!(cp037.decode(&[0xBA, 0xBB]), "[]");
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(SasEncoding::from_name("UTF-8"), Some(SasEncoding::Utf8));
+        assert_eq!(
+            SasEncoding::from_name("wlatin1"),
+            Some(SasEncoding::Windows1252)
+        );
+        assert_eq!(SasEncoding::from_name("shift-jis"), Some(other(138)));
+        assert_eq!(
+            SasEncoding::from_name("cp850"),
+            Some(SasEncoding::Other {
+                id: 0,
+                name: "IBM850"
+            })
+        );
+        assert!(matches!(
+            SasEncoding::from_name("koi8-r"),
+            Some(SasEncoding::Other { name: "KOI8-R", .. })
+        ));
+        assert_eq!(SasEncoding::from_name("klingon"), None);
+    }
+}
@@ -89,6 +89,17 @@ pub enum SasError {
         message: String,
     },
 
+    /// Numeric column has a storage length SAS cannot write.
+    ///
+    /// SAS numerics occupy 3 to 8 bytes. Only returned in strict mode; otherwise
+    /// the column's values are read as nulls.
+    InvalidNumericLength {
+        /// Column name
+        column: String,
+        /// Storage length in bytes from the column attributes
+        length: u32,
+    },
+
     /// Header contains values that exceed safe processing limits.
     ///
     /// This error is returned when header fields (page size, row count, row length)
@@ -169,6 +180,13 @@ impl fmt::Display for SasError {
                     column, row, message
                 )
             }
+            SasError::InvalidNumericLength { column, length } => {
+                write!(
+                    f,
+                    "Numeric column '{}' has a storage length of {} bytes; SAS numerics are 3 to 8 bytes",
+                    column, length
+                )
+            }
             SasError::InvalidHeader(msg) => write!(f, "Invalid SAS7BDAT header: {}", msg),
             SasError::Io(err) => write!(f, "I/O error: {}", err),
         }
@@ -232,6 +250,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_numeric_length_display() {
+        let err = SasError::InvalidNumericLength {
+            column: "balance".to_string(),
+            length: 2,
+        };
+        assert_eq!(
+            err.to_string(),
+            "Numeric column 'balance' has a storage length of 2 bytes; SAS numerics are 3 to 8 bytes"
+        );
+    }
+
     #[test]
     fn test_invalid_page_type_display() {
         let err = SasError::InvalidPageType {
@@ -24,6 +24,18 @@ use super::{Compression, OsType, SasEncoding, SasError, SasHeader};
 /// * `SasError::TruncatedFile` - File size is less than header_length
 /// * `SasError::Io` - I/O errors during reading
 pub fn parse_header<R: Read + Seek>(reader: &mut R) -> Result<SasHeader, SasError> {
+    parse_header_with_encoding(reader, None)
+}
+
+/// Parses the SAS7BDAT file header, using `encoding` instead of the encoding
+/// ID stored at offset 70 when one is given.
+///
+/// The stored ID is not checked when overridden, so files with an ID this
+/// parser does not know can still be read.
+pub fn parse_header_with_encoding<R: Read + Seek>(
+    reader: &mut R,
+    encoding: Option<SasEncoding>,
+) -> Result<SasHeader, SasError> {
     // Step 1: Validate magic number
     // Only bytes 12-31 are constant across all SAS7BDAT variants; bytes 0-11
     // are zero in most files but can vary between SAS versions and platforms.
@@ -70,7 +82,10 @@ pub fn parse_header<R: Read + Seek>(reader: &mut R) -> Result<SasHeader, SasErro
     let mut encoding_byte = [0u8; 1];
     reader.read_exact(&mut encoding_byte)?;
     let encoding_id = encoding_byte[0] as u16;
-    let encoding = parse_encoding(encoding_id)?;
+    let encoding = match encoding {
+        Some(encoding) => encoding,
+        None => parse_encoding(encoding_id)?,
+    };
 
     // Step 6: Read timestamps (use pad1 only, not total_align)
     let created = read_f64(
@@ -286,12 +301,23 @@ mod tests {
         match parse_encoding(125) {
             Ok(SasEncoding::Other { id, name }) => {
                 assert_eq!(id, 125);
-                assert_eq!(name, "EUC-CN");
+                assert_eq!(name, "GB18030");
             }
             _ => panic!("Expected Other encoding"),
         }
     }
 
+    #[test]
+    fn test_parse_encoding_iso_8859_ids() {
+        // IDs 30-40 are ISO-8859-2..15, with 38 (ISO-8859-10) unassigned
+        for (id, expected) in [(30, "ISO-8859-2"), (33, "ISO-8859-5"), (40, "ISO-8859-15")] {
+            match parse_encoding(id) {
+                Ok(SasEncoding::Other { name, .. }) => assert_eq!(name, expected),
+                other => panic!("Expected {expected}, got {other:?}"),
+            }
+        }
+    }
+
     #[test]
     fn test_parse_encoding_unsupported() {
         assert!(matches!(
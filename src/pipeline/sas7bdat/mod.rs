@@ -12,19 +12,24 @@
 //! - `subheader` - Subheader parsing (column metadata, row size, etc.)
 //! - `column` - Column metadata and type definitions
 //! - `decompress` - RLE and RDC decompression algorithms
+//! - `encoding` - Character decoding, including DOS and EBCDIC code pages
 //! - `data` - Data extraction and conversion to Polars
+//! - `reader` - Incremental row reading in DataFrame batches
 
 pub mod column;
 pub mod constants;
 pub mod data;
 pub mod decompress;
+pub mod encoding;
 pub mod error;
 pub mod header;
 pub mod page;
+pub mod reader;
 pub mod subheader;
 
 // Re-export public API types
 pub use error::SasError;
+pub use reader::{Sas7bdatReader, DEFAULT_BATCH_ROWS};
 
 use std::fs::File;
 use std::io::{BufReader, Read, Seek, SeekFrom};
@@ -34,10 +39,6 @@ use indicatif::{ProgressBar, ProgressStyle};
 use polars::prelude::*;
 
 use self::column::build_columns;
-use self::data::{
-    build_series_from_column_values, extract_row_values, extract_rows_from_page, ColumnValue,
-};
-use self::decompress::{decompress_rdc, decompress_rle};
 use self::header::parse_header;
 use self::page::{is_page_data, is_page_meta, is_page_mix, parse_page_header};
 use self::subheader::{parse_subheader_pointers, process_subheader, SubheaderState};
@@ -47,9 +48,8 @@ use self::subheader::{parse_subheader_pointers, process_subheader, SubheaderStat
 /// This is the main entry point for SAS7BDAT file loading. It:
 /// 1. Parses the file header for metadata
 /// 2. Iterates metadata pages to extract column definitions
-/// 3. Iterates data/mix pages to extract row values
-/// 4. Builds Polars Series with appropriate dtypes
-/// 5. Assembles the final DataFrame
+/// 3. Reads the data/mix pages in batches through [`Sas7bdatReader`]
+/// 4. Stacks the batches into the final DataFrame
 ///
 /// # Arguments
 /// * `path` - Path to the `.sas7bdat` file
@@ -63,34 +63,55 @@ use self::subheader::{parse_subheader_pointers, process_subheader, SubheaderStat
 /// * `SasError::UnsupportedEncoding` - Unknown character encoding
 /// * `SasError::TruncatedFile` - File is shorter than expected
 pub fn load_sas7bdat(path: &Path) -> Result<(DataFrame, usize, usize, f64), SasError> {
-    load_sas7bdat_impl(path, false)
+    load_sas7bdat_impl(path, None, false)
 }
 
 /// Load a SAS7BDAT file without indicatif progress output (for TUI mode).
 pub fn load_sas7bdat_silent(path: &Path) -> Result<(DataFrame, usize, usize, f64), SasError> {
-    load_sas7bdat_impl(path, true)
+    load_sas7bdat_impl(path, None, true)
 }
 
-fn load_sas7bdat_impl(
+/// Load only the named columns of a SAS7BDAT file (all when `None`).
+///
+/// Values of the other columns are never decoded, so a narrow selection from
+/// a wide file needs memory for the selected columns only. Names that are not
+/// in the file are ignored.
+pub fn load_sas7bdat_columns(
     path: &Path,
+    columns: Option<&[String]>,
     silent: bool,
 ) -> Result<(DataFrame, usize, usize, f64), SasError> {
-    let file = File::open(path)?;
-    let mut reader = BufReader::new(file);
+    load_sas7bdat_impl(path, columns, silent)
+}
 
-    // Step 1: Parse file header
-    let mut sas_header = parse_header(&mut reader)?;
+fn load_sas7bdat_impl(
+    path: &Path,
+    projection: Option<&[String]>,
+    silent: bool,
+) -> Result<(DataFrame, usize, usize, f64), SasError> {
+    // Step 1: Parse the header and metadata pages
+    let mut reader = Sas7bdatReader::open(path)?;
+    if let Some(keep) = projection {
+        reader = reader.with_columns(keep);
+    }
 
-    // Step 2: Iterate metadata pages to extract column definitions
-    let mut state = SubheaderState::default();
-    reader.seek(SeekFrom::Start(sas_header.header_length))?;
+    // Sanity-check total cell count before committing to a full in-memory load
+    let total_cells = (reader.row_count() as u128) * (reader.columns().len() as u128);
+    if total_cells > 2_000_000_000 {
+        return Err(SasError::InvalidHeader(format!(
+            "Dataset too large: {} rows x {} columns = {} cells exceeds 2B cell limit",
+            reader.row_count(),
+            reader.columns().len(),
+            total_cells
+        )));
+    }
 
     // In TUI mode (silent), use a hidden progress bar so indicatif doesn't
     // write to stdout — ratatui owns the alternate screen.
     let pb = if silent {
         ProgressBar::hidden()
     } else {
-        let pb = ProgressBar::new(sas_header.page_count);
+        let pb = ProgressBar::new(reader.header().page_count);
         pb.set_style(
             ProgressStyle::default_bar()
                 .template(
@@ -102,262 +123,26 @@ fn load_sas7bdat_impl(
         pb
     };
 
-    // Sanity-check page_size before allocating to prevent absurd allocations
-    if sas_header.page_size > 268_435_456 {
-        return Err(SasError::InvalidHeader(format!(
-            "Page size {} exceeds 256MB limit",
-            sas_header.page_size
-        )));
-    }
-
-    let mut page_buf = vec![0u8; sas_header.page_size as usize];
-
-    // First pass: process metadata pages to get column definitions
-    for page_idx in 0..sas_header.page_count {
-        match reader.read_exact(&mut page_buf) {
-            Ok(()) => {}
-            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
-            Err(e) => return Err(SasError::Io(e)),
-        }
-
-        let page_header =
-            parse_page_header(&page_buf, sas_header.is_64bit, sas_header.is_little_endian)?;
-
-        // Process subheaders on metadata and mix pages
-        if is_page_meta(page_header.page_type) || is_page_mix(page_header.page_type) {
-            let pointers = parse_subheader_pointers(
-                &page_buf,
-                sas_header.is_64bit,
-                sas_header.is_little_endian,
-                page_header.subheader_count,
-            )?;
-
-            for pointer in &pointers {
-                // Skip compressed data subheaders (compression != 0 and type == 1 means data)
-                if pointer.compression != 0 && pointer.subheader_type == 1 {
-                    continue;
-                }
-                process_subheader(
-                    &page_buf,
-                    pointer,
-                    sas_header.is_64bit,
-                    sas_header.is_little_endian,
-                    &mut state,
-                )?;
-            }
-        }
-
-        pb.set_position(page_idx + 1);
-    }
-
-    // Update header with subheader-derived values
-    sas_header.row_count = state.row_count;
-    sas_header.row_length = state.row_length;
-    sas_header.column_count = state.column_count_from_size;
-    sas_header.max_rows_on_mix_page = state.max_rows_on_mix_page;
-    sas_header.compression = state.compression;
-
-    // Validate non-zero rows
-    if sas_header.row_count == 0 {
-        pb.finish_and_clear();
-        return Err(SasError::ZeroRows);
-    }
-
-    // Sanity-check row_count and row_length before pre-allocating accumulators
-    if sas_header.row_count > 500_000_000 {
-        pb.finish_and_clear();
-        return Err(SasError::InvalidHeader(format!(
-            "Row count {} exceeds 500M row limit",
-            sas_header.row_count
-        )));
-    }
-    if sas_header.row_length > 1_048_576 {
-        pb.finish_and_clear();
-        return Err(SasError::InvalidHeader(format!(
-            "Row length {} exceeds 1MB per-row limit",
-            sas_header.row_length
-        )));
-    }
-
-    // Build column metadata
-    let columns = build_columns(&state, &sas_header.encoding);
-    if columns.is_empty() {
-        pb.finish_and_clear();
-        return Err(SasError::InvalidHeader(
-            "File contains zero columns".to_string(),
-        ));
-    }
-
-    // Sanity-check total cell count to prevent excessive pre-allocation
-    let total_cells = (sas_header.row_count as u128) * (columns.len() as u128);
-    if total_cells > 2_000_000_000 {
-        pb.finish_and_clear();
-        return Err(SasError::InvalidHeader(format!(
-            "Dataset too large: {} rows x {} columns = {} cells exceeds 2B cell limit",
-            sas_header.row_count,
-            columns.len(),
-            total_cells
-        )));
-    }
-
-    // Step 3: Second pass - extract data rows
-    reader.seek(SeekFrom::Start(sas_header.header_length))?;
-    pb.set_position(0);
-    pb.set_length(sas_header.page_count);
-
-    // Initialize per-column value accumulators
-    let mut column_values: Vec<Vec<ColumnValue>> = columns
-        .iter()
-        .map(|_| Vec::with_capacity(sas_header.row_count as usize))
-        .collect();
-    let mut rows_collected: u64 = 0;
-
-    for page_idx in 0..sas_header.page_count {
-        if rows_collected >= sas_header.row_count {
-            break;
-        }
-
-        match reader.read_exact(&mut page_buf) {
-            Ok(()) => {}
-            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
-            Err(e) => return Err(SasError::Io(e)),
-        }
-
-        let page_header =
-            parse_page_header(&page_buf, sas_header.is_64bit, sas_header.is_little_endian)?;
-
-        // In compressed SAS files, rows are stored as individually compressed
-        // subheader entries on META and MIX pages (compression != 0, subheader_type == 1).
-        // Each entry is decompressed to row_length bytes to recover one row.
-        // COMP pages (0x9000) are padding/marker pages with no useful data -- skip them.
-        let has_subheaders =
-            is_page_meta(page_header.page_type) || is_page_mix(page_header.page_type);
-
-        if has_subheaders && sas_header.compression != Compression::None {
-            let pointers = parse_subheader_pointers(
-                &page_buf,
-                sas_header.is_64bit,
-                sas_header.is_little_endian,
-                page_header.subheader_count,
-            )?;
-
-            for pointer in &pointers {
-                if rows_collected >= sas_header.row_count {
-                    break;
-                }
-
-                // Compressed row subheaders: compression == 4, subheader_type == 1.
-                // Skip truncated markers (compression == 1) and metadata subheaders.
-                if pointer.compression != 0 && pointer.subheader_type == 1 {
-                    let offset = match usize::try_from(pointer.offset) {
-                        Ok(v) => v,
-                        Err(_) => continue,
-                    };
-                    let length = match usize::try_from(pointer.length) {
-                        Ok(v) => v,
-                        Err(_) => continue,
-                    };
-
-                    if length == 0 || offset + length > page_buf.len() {
-                        continue;
-                    }
-
-                    // Truncated marker (compression == 1): not actual compressed data
-                    if pointer.compression == 1 {
-                        continue;
-                    }
-
-                    let compressed_data = &page_buf[offset..offset + length];
-                    let row_length = sas_header.row_length as usize;
-
-                    let decompressed = match sas_header.compression {
-                        Compression::Rle => decompress_rle(compressed_data, row_length, page_idx)?,
-                        Compression::Rdc => decompress_rdc(compressed_data, row_length, page_idx)?,
-                        Compression::None => {
-                            return Err(SasError::DecompressionError {
-                                page_index: page_idx,
-                                message: "Attempted to decompress row in a non-compressed file"
-                                    .to_string(),
-                            });
-                        }
-                    };
-
-                    let row_values = extract_row_values(
-                        &decompressed,
-                        &columns,
-                        &sas_header.encoding,
-                        sas_header.is_little_endian,
-                    )?;
-
-                    for (col_idx, value) in row_values.into_iter().enumerate() {
-                        if col_idx < column_values.len() {
-                            column_values[col_idx].push(value);
-                        }
-                    }
-                    rows_collected += 1;
-                }
-            }
-            // NOTE: For compressed files, ALL rows are in compressed subheaders above.
-            // extract_rows_from_page() must NOT be called for MIX pages here, as the
-            // trailing data area contains no valid uncompressed rows in compressed files.
-        } else if is_page_data(page_header.page_type) || is_page_mix(page_header.page_type) {
-            // Uncompressed DATA and MIX pages: extract rows directly from trailing area.
-            let page_rows = extract_rows_from_page(
-                &page_buf,
-                &sas_header,
-                &columns,
-                page_idx,
-                sas_header.compression,
-                rows_collected,
-                sas_header.row_count,
-            )?;
-
-            let page_row_count = page_rows.len();
-            for row in page_rows {
-                for (col_idx, value) in row.into_iter().enumerate() {
-                    if col_idx < column_values.len() {
-                        column_values[col_idx].push(value);
-                    }
-                }
+    // Step 2: Decode the rows batch by batch, so only one batch is ever held
+    // as decoded values alongside the growing DataFrame
+    let mut df: Option<DataFrame> = None;
+    while let Some(batch) = reader.next_batch(DEFAULT_BATCH_ROWS)? {
+        match df.as_mut() {
+            Some(df) => {
+                df.vstack_mut(&batch).map_err(reader::dataframe_error)?;
             }
-            rows_collected += page_row_count as u64;
+            None => df = Some(batch),
         }
-        // COMP pages (0x9000) are skipped -- they are padding/marker pages.
-
-        pb.set_position(page_idx + 1);
+        pb.set_position(reader.pages_read());
     }
-
     pb.finish_and_clear();
 
-    // Step 4: Build Polars Series for each column
-    let spinner = if silent {
-        ProgressBar::hidden()
-    } else {
-        let s = ProgressBar::new_spinner();
-        s.set_style(
-            ProgressStyle::default_spinner()
-                .template("   {spinner:.cyan} Building DataFrame...")
-                .unwrap(),
-        );
-        s.enable_steady_tick(std::time::Duration::from_millis(100));
-        s
+    // Step 3: Assemble the DataFrame
+    let mut df = match df {
+        Some(df) => df,
+        None => reader.empty_frame()?,
     };
-
-    let mut column_vec: Vec<Column> = Vec::with_capacity(columns.len());
-    for (col, values) in columns.iter().zip(column_values.into_iter()) {
-        let series = build_series_from_column_values(&col.name, &col.polars_type, values)?;
-        column_vec.push(series.into());
-    }
-
-    // Step 5: Assemble DataFrame
-    let df = DataFrame::new(column_vec).map_err(|e| {
-        SasError::Io(std::io::Error::new(
-            std::io::ErrorKind::InvalidData,
-            format!("Failed to build DataFrame: {}", e),
-        ))
-    })?;
-
-    spinner.finish_and_clear();
+    df.rechunk_mut();
 
     let (rows, cols) = df.shape();
     let memory_mb = df.estimated_size() as f64 / (1024.0 * 1024.0);
@@ -378,6 +163,19 @@ fn load_sas7bdat_impl(
 /// * `Ok(Vec<String>)` - Column names in file order
 /// * `Err(SasError)` - If header or metadata parsing fails
 pub fn get_sas7bdat_columns(path: &Path) -> Result<Vec<String>, SasError> {
+    let columns = get_sas7bdat_column_metadata(path)?;
+    Ok(columns.into_iter().map(|c| c.name).collect())
+}
+
+/// Gets the column definitions of a SAS7BDAT file without loading any rows.
+///
+/// Like [`get_sas7bdat_columns`], but keeps each column's format and label,
+/// which the loaded DataFrame has no place for.
+///
+/// # Returns
+/// * `Ok(Vec<SasColumn>)` - Column definitions in file order
+/// * `Err(SasError)` - If header or metadata parsing fails
+pub fn get_sas7bdat_column_metadata(path: &Path) -> Result<Vec<SasColumn>, SasError> {
     let file = File::open(path)?;
     let mut reader = BufReader::new(file);
 
@@ -444,8 +242,7 @@ pub fn get_sas7bdat_columns(path: &Path) -> Result<Vec<String>, SasError> {
         }
     }
 
-    let columns = build_columns(&state, &sas_header.encoding);
-    Ok(columns.into_iter().map(|c| c.name).collect())
+    Ok(build_columns(&state, &sas_header.encoding))
 }
 
 /// The native data type of a column in a SAS7BDAT file.
@@ -511,7 +308,8 @@ pub enum OsType {
 /// Character encoding used in the SAS7BDAT file.
 ///
 /// SAS encodes strings using various encodings. The encoding ID is stored in
-/// the file header and determines how to decode character columns.
+/// the file header and determines how to decode character columns; see
+/// [`SasEncoding::decode`] and [`SasEncoding::from_name`].
 #[derive(Debug, Clone, PartialEq)]
 pub enum SasEncoding {
     /// UTF-8 encoding (encoding ID 20).
@@ -524,9 +322,9 @@ pub enum SasEncoding {
     Windows1252,
     /// Other recognized encoding with ID and name.
     Other {
-        /// The numeric encoding ID from the SAS file header.
+        /// The numeric encoding ID from the SAS file header (0 when chosen by name).
         id: u16,
-        /// The human-readable encoding name.
+        /// The encoding name (an `encoding_rs` label or a built-in `IBMnnn` code page).
         name: &'static str,
     },
     /// Encoding ID 0 or unknown -- fall back to Latin-1 interpretation.
@@ -587,7 +385,7 @@ pub struct SasColumn {
     pub data_type: SasDataType,
     /// Byte offset of this column within each row.
     pub offset: u64,
-    /// Length of the column in bytes (8 for full numeric, 1-8 for truncated, N for character).
+    /// Length of the column in bytes (8 for full numeric, 3-7 for truncated, N for character).
     pub length: u32,
     /// SAS format code (e.g., "DATE9.", "DATETIME20.", "BEST12.") for display/interpretation.
     pub format: String,
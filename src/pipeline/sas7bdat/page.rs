@@ -96,16 +96,23 @@ pub fn is_page_meta(page_type: u16) -> bool {
 
 /// Checks if a page is a data-only page.
 ///
-/// Data pages contain only observation records, no metadata.
+/// Data pages contain only observation records, no metadata. Includes pages
+/// flagged as holding deleted rows.
 pub fn is_page_data(page_type: u16) -> bool {
-    page_type == PAGE_TYPE_DATA
+    page_type & !PAGE_FLAG_DELETED_ROWS == PAGE_TYPE_DATA
 }
 
 /// Checks if a page is a mixed page (MIX).
 ///
-/// Mixed pages contain both metadata and data records.
+/// Mixed pages contain both metadata and data records. Includes pages
+/// flagged as holding deleted rows.
 pub fn is_page_mix(page_type: u16) -> bool {
-    page_type == PAGE_TYPE_MIX
+    page_type & !PAGE_FLAG_DELETED_ROWS == PAGE_TYPE_MIX
+}
+
+/// Checks if a DATA or MIX page carries a deleted-row bitmap.
+pub fn has_deleted_rows(page_type: u16) -> bool {
+    page_type & PAGE_FLAG_DELETED_ROWS != 0 && (is_page_data(page_type) || is_page_mix(page_type))
 }
 
 /// Checks if a page is an AMD (attribute metadata) page.
@@ -198,6 +205,23 @@ mod tests {
         assert!(!is_page_comp(PAGE_TYPE_DATA));
     }
 
+    #[test]
+    fn test_deleted_rows_page_types() {
+        // 0x0180 and 0x0280: DATA and MIX pages with deleted rows
+        let data = PAGE_TYPE_DATA | PAGE_FLAG_DELETED_ROWS;
+        let mix = PAGE_TYPE_MIX | PAGE_FLAG_DELETED_ROWS;
+        assert!(is_page_data(data));
+        assert!(!is_page_mix(data));
+        assert!(is_page_mix(mix));
+        assert!(!is_page_meta(mix));
+
+        assert!(has_deleted_rows(data));
+        assert!(has_deleted_rows(mix));
+        assert!(!has_deleted_rows(PAGE_TYPE_DATA));
+        assert!(!has_deleted_rows(PAGE_TYPE_MIX));
+        assert!(!has_deleted_rows(PAGE_TYPE_META | PAGE_FLAG_DELETED_ROWS));
+    }
+
     #[test]
     fn test_parse_page_header_truncated() {
         let page_data = vec![0u8; 20]; // Too short for 32-bit (need 16 + 6 = 22)
@@ -0,0 +1,379 @@
+//! Incremental row reading for SAS7BDAT files.
+//!
+//! [`Sas7bdatReader`] parses the file metadata once, then decodes data pages on
+//! demand and hands rows out as DataFrames of a requested size. Only the rows of
+//! the current batch (plus the remainder of one page) are held as decoded values,
+//! so a file of any size can be converted or loaded with bounded memory.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use polars::prelude::*;
+
+use super::column::build_columns;
+use super::data::{
+    build_series_from_column_values, check_numeric_lengths, extract_row_values,
+    extract_rows_from_page, ColumnValue,
+};
+use super::decompress::{decompress_rdc, decompress_rle};
+use super::header::parse_header_with_encoding;
+use super::page::{is_page_data, is_page_meta, is_page_mix, parse_page_header};
+use super::subheader::{parse_subheader_pointers, process_subheader, SubheaderState};
+use super::{Compression, SasColumn, SasEncoding, SasError, SasHeader};
+
+/// Rows per batch used by the loader and the Parquet conversion.
+///
+/// Matches the Parquet row-group size of the converter, so each batch becomes
+/// one row group.
+pub const DEFAULT_BATCH_ROWS: usize = 100_000;
+
+/// Streaming reader over the rows of a SAS7BDAT file.
+///
+/// ```no_run
+/// # use lophi::pipeline::sas7bdat::Sas7bdatReader;
+/// let mut reader = Sas7bdatReader::open(std::path::Path::new("claims.sas7bdat"))?;
+/// while let Some(batch) = reader.next_batch(50_000)? {
+///     println!("{} rows", batch.height());
+/// }
+/// # Ok::<(), lophi::pipeline::sas7bdat::SasError>(())
+/// ```
+pub struct Sas7bdatReader {
+    reader: BufReader<File>,
+    header: SasHeader,
+    columns: Vec<SasColumn>,
+    page_buf: Vec<u8>,
+    /// Index of the next page to read
+    next_page: u64,
+    /// Rows decoded so far, including those still pending
+    rows_decoded: u64,
+    /// Decoded rows not yet returned by `next_batch`
+    pending: VecDeque<Vec<ColumnValue>>,
+}
+
+impl Sas7bdatReader {
+    /// Open a SAS7BDAT file and read its metadata.
+    ///
+    /// No rows are decoded until [`next_batch`](Self::next_batch) is called.
+    ///
+    /// # Errors
+    /// * `SasError::InvalidMagic` - Not a valid SAS7BDAT file
+    /// * `SasError::ZeroRows` - File contains no data rows
+    /// * `SasError::InvalidHeader` - Implausible page size, row count or row length,
+    ///   or no columns
+    pub fn open(path: &Path) -> Result<Self, SasError> {
+        Self::open_impl(path, None)
+    }
+
+    /// Open a SAS7BDAT file, decoding text with `encoding` instead of the
+    /// encoding named in the header.
+    ///
+    /// For files whose header ID is missing or wrong, such as EBCDIC datasets
+    /// transferred from a mainframe; see [`SasEncoding::from_name`]. Column
+    /// names and labels are decoded with the override too.
+    #[allow(dead_code)] // Library API; the binary trusts the header
+    pub fn open_with_encoding(path: &Path, encoding: SasEncoding) -> Result<Self, SasError> {
+        Self::open_impl(path, Some(encoding))
+    }
+
+    fn open_impl(path: &Path, encoding: Option<SasEncoding>) -> Result<Self, SasError> {
+        let file = File::open(path)?;
+        let mut reader = BufReader::new(file);
+
+        let mut header = parse_header_with_encoding(&mut reader, encoding)?;
+
+        // Sanity-check page_size before allocating to prevent absurd allocations
+        if header.page_size > 268_435_456 {
+            return Err(SasError::InvalidHeader(format!(
+                "Page size {} exceeds 256MB limit",
+                header.page_size
+            )));
+        }
+
+        let mut page_buf = vec![0u8; header.page_size as usize];
+        let mut state = SubheaderState::default();
+        reader.seek(SeekFrom::Start(header.header_length))?;
+
+        // Metadata pass: column definitions come from the META and MIX pages
+        for _ in 0..header.page_count {
+            match reader.read_exact(&mut page_buf) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
+                Err(e) => return Err(SasError::Io(e)),
+            }
+
+            let page_header =
+                parse_page_header(&page_buf, header.is_64bit, header.is_little_endian)?;
+
+            if is_page_meta(page_header.page_type) || is_page_mix(page_header.page_type) {
+                let pointers = parse_subheader_pointers(
+                    &page_buf,
+                    header.is_64bit,
+                    header.is_little_endian,
+                    page_header.subheader_count,
+                )?;
+
+                for pointer in &pointers {
+                    // Skip compressed data subheaders (compression != 0 and type == 1 means data)
+                    if pointer.compression != 0 && pointer.subheader_type == 1 {
+                        continue;
+                    }
+                    process_subheader(
+                        &page_buf,
+                        pointer,
+                        header.is_64bit,
+                        header.is_little_endian,
+                        &mut state,
+                    )?;
+                }
+            }
+        }
+
+        // Update header with subheader-derived values
+        header.row_count = state.row_count;
+        header.row_length = state.row_length;
+        header.column_count = state.column_count_from_size;
+        header.max_rows_on_mix_page = state.max_rows_on_mix_page;
+        header.compression = state.compression;
+
+        if header.row_count == 0 {
+            return Err(SasError::ZeroRows);
+        }
+        if header.row_count > 500_000_000 {
+            return Err(SasError::InvalidHeader(format!(
+                "Row count {} exceeds 500M row limit",
+                header.row_count
+            )));
+        }
+        if header.row_length > 1_048_576 {
+            return Err(SasError::InvalidHeader(format!(
+                "Row length {} exceeds 1MB per-row limit",
+                header.row_length
+            )));
+        }
+
+        let columns = build_columns(&state, &header.encoding);
+        if columns.is_empty() {
+            return Err(SasError::InvalidHeader(
+                "File contains zero columns".to_string(),
+            ));
+        }
+
+        // Data pass starts from the first page again
+        reader.seek(SeekFrom::Start(header.header_length))?;
+
+        Ok(Self {
+            reader,
+            header,
+            columns,
+            page_buf,
+            next_page: 0,
+            rows_decoded: 0,
+            pending: VecDeque::new(),
+        })
+    }
+
+    /// Decode only the named columns (names not in the file are ignored).
+    ///
+    /// Each column is decoded from its own offset within a row, so skipped
+    /// columns cost nothing. Call before the first batch.
+    pub fn with_columns(mut self, keep: &[String]) -> Self {
+        self.columns.retain(|c| keep.contains(&c.name));
+        self
+    }
+
+    /// Fail on numeric columns with a storage length SAS cannot write.
+    ///
+    /// By default such a column (0-2 or over 8 bytes, a sign of a damaged
+    /// file) is read as nulls. In strict mode it is an error instead. Only the
+    /// columns selected so far are checked, so call after
+    /// [`with_columns`](Self::with_columns).
+    ///
+    /// # Errors
+    /// * `SasError::InvalidNumericLength` - Names the first offending column
+    #[allow(dead_code)] // Library API; the binary reads such columns as nulls
+    pub fn strict(self) -> Result<Self, SasError> {
+        check_numeric_lengths(&self.columns)?;
+        Ok(self)
+    }
+
+    /// File-level metadata, with the row count and compression from the subheaders
+    pub fn header(&self) -> &SasHeader {
+        &self.header
+    }
+
+    /// Definitions of the columns each batch holds, in file order
+    pub fn columns(&self) -> &[SasColumn] {
+        &self.columns
+    }
+
+    /// Number of data rows in the file
+    pub fn row_count(&self) -> u64 {
+        self.header.row_count
+    }
+
+    /// Number of pages read by the data pass so far (for progress reporting)
+    pub fn pages_read(&self) -> u64 {
+        self.next_page
+    }
+
+    /// Read up to `n_rows` rows as a DataFrame.
+    ///
+    /// Returns `None` once every row has been returned. Every batch but the last
+    /// holds exactly `n_rows` rows (at least one is read even when `n_rows` is 0).
+    pub fn next_batch(&mut self, n_rows: usize) -> Result<Option<DataFrame>, SasError> {
+        let n_rows = n_rows.max(1);
+        while self.pending.len() < n_rows && self.read_page()? {}
+        if self.pending.is_empty() {
+            return Ok(None);
+        }
+
+        let take = n_rows.min(self.pending.len());
+        let mut column_values: Vec<Vec<ColumnValue>> = self
+            .columns
+            .iter()
+            .map(|_| Vec::with_capacity(take))
+            .collect();
+        for row in self.pending.drain(..take) {
+            for (values, value) in column_values.iter_mut().zip(row) {
+                values.push(value);
+            }
+        }
+        build_dataframe(&self.columns, column_values).map(Some)
+    }
+
+    /// An empty DataFrame with the reader's columns and their types
+    pub fn empty_frame(&self) -> Result<DataFrame, SasError> {
+        build_dataframe(
+            &self.columns,
+            self.columns.iter().map(|_| Vec::new()).collect(),
+        )
+    }
+
+    /// Decode the rows of the next page into `pending`.
+    ///
+    /// Returns `false` once every row has been decoded or the pages run out.
+    fn read_page(&mut self) -> Result<bool, SasError> {
+        let header = &self.header;
+        if self.rows_decoded >= header.row_count || self.next_page >= header.page_count {
+            return Ok(false);
+        }
+
+        match self.reader.read_exact(&mut self.page_buf) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(false),
+            Err(e) => return Err(SasError::Io(e)),
+        }
+        let page_idx = self.next_page;
+        self.next_page += 1;
+
+        let page_buf = &self.page_buf;
+        let page_header = parse_page_header(page_buf, header.is_64bit, header.is_little_endian)?;
+
+        // In compressed SAS files, rows are stored as individually compressed
+        // subheader entries on META and MIX pages (compression != 0, subheader_type == 1).
+        // Each entry is decompressed to row_length bytes to recover one row.
+        // COMP pages (0x9000) are padding/marker pages with no useful data -- skip them.
+        let has_subheaders =
+            is_page_meta(page_header.page_type) || is_page_mix(page_header.page_type);
+
+        if has_subheaders && header.compression != Compression::None {
+            let pointers = parse_subheader_pointers(
+                page_buf,
+                header.is_64bit,
+                header.is_little_endian,
+                page_header.subheader_count,
+            )?;
+
+            for pointer in &pointers {
+                if self.rows_decoded >= header.row_count {
+                    break;
+                }
+
+                // Compressed row subheaders: compression == 4, subheader_type == 1.
+                // Skip truncated markers (compression == 1) and metadata subheaders.
+                if pointer.compression == 0 || pointer.subheader_type != 1 {
+                    continue;
+                }
+                let (Ok(offset), Ok(length)) = (
+                    usize::try_from(pointer.offset),
+                    usize::try_from(pointer.length),
+                ) else {
+                    continue;
+                };
+                if length == 0 || offset + length > page_buf.len() {
+                    continue;
+                }
+                // Truncated marker (compression == 1): not actual compressed data
+                if pointer.compression == 1 {
+                    continue;
+                }
+
+                let compressed_data = &page_buf[offset..offset + length];
+                let row_length = header.row_length as usize;
+
+                let decompressed = match header.compression {
+                    Compression::Rle => decompress_rle(compressed_data, row_length, page_idx)?,
+                    Compression::Rdc => decompress_rdc(compressed_data, row_length, page_idx)?,
+                    Compression::None => {
+                        return Err(SasError::DecompressionError {
+                            page_index: page_idx,
+                            message: "Attempted to decompress row in a non-compressed file"
+                                .to_string(),
+                        });
+                    }
+                };
+
+                let row_values = extract_row_values(
+                    &decompressed,
+                    &self.columns,
+                    &header.encoding,
+                    header.is_little_endian,
+                )?;
+                self.pending.push_back(row_values);
+                self.rows_decoded += 1;
+            }
+            // NOTE: For compressed files, ALL rows are in compressed subheaders above.
+            // extract_rows_from_page() must NOT be called for MIX pages here, as the
+            // trailing data area contains no valid uncompressed rows in compressed files.
+        } else if is_page_data(page_header.page_type) || is_page_mix(page_header.page_type) {
+            // Uncompressed DATA and MIX pages: extract rows directly from trailing area.
+            let page_rows = extract_rows_from_page(
+                page_buf,
+                header,
+                &self.columns,
+                page_idx,
+                header.compression,
+                self.rows_decoded,
+                header.row_count,
+            )?;
+            self.rows_decoded += page_rows.len() as u64;
+            self.pending.extend(page_rows);
+        }
+        // COMP pages (0x9000) are skipped -- they are padding/marker pages.
+
+        Ok(true)
+    }
+}
+
+/// Build a DataFrame from per-column decoded values
+fn build_dataframe(
+    columns: &[SasColumn],
+    column_values: Vec<Vec<ColumnValue>>,
+) -> Result<DataFrame, SasError> {
+    let mut column_vec: Vec<Column> = Vec::with_capacity(columns.len());
+    for (col, values) in columns.iter().zip(column_values) {
+        let series = build_series_from_column_values(&col.name, &col.polars_type, values)?;
+        column_vec.push(series.into());
+    }
+    DataFrame::new(column_vec).map_err(dataframe_error)
+}
+
+/// Wrap a Polars error raised while assembling decoded rows
+pub(super) fn dataframe_error(e: PolarsError) -> SasError {
+    SasError::Io(std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("Failed to build DataFrame: {}", e),
+    ))
+}
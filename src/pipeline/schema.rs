@@ -0,0 +1,170 @@
+//! Schema drift checks against an expected schema file (`--expect-schema`)
+//!
+//! Scheduled runs on a fresh extract screen whatever columns the extract
+//! holds. When an upstream change adds, removes or re-types a column, the
+//! reduction silently works on different features. Comparing the loaded
+//! column names and dtypes to a schema saved from a known-good extract
+//! (`lophi schema`) surfaces these changes before any statistic is computed.
+//!
+//! The schema file is JSON with one entry per column, in dataset order:
+//!
+//! ```json
+//! { "columns": [ { "name": "age", "dtype": "i64" }, { "name": "region", "dtype": "str" } ] }
+//! ```
+//!
+//! Dtypes use polars' short names (`i64`, `f64`, `str`, `bool`, `date`, ...).
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Column names and dtypes a dataset is expected to have
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpectedSchema {
+    pub columns: Vec<SchemaColumn>,
+}
+
+/// A column and its dtype name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaColumn {
+    pub name: String,
+    pub dtype: String,
+}
+
+/// A column whose dtype differs from the expected one
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RetypedColumn {
+    pub name: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// Differences between a loaded dataset and its expected schema
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SchemaDrift {
+    /// Schema file the dataset was compared against
+    pub expected_schema: String,
+    /// Columns in the dataset but not in the schema
+    pub added: Vec<SchemaColumn>,
+    /// Columns in the schema but not in the dataset
+    pub removed: Vec<SchemaColumn>,
+    /// Columns in both with a different dtype
+    pub retyped: Vec<RetypedColumn>,
+}
+
+impl ExpectedSchema {
+    /// Schema of `df`, in column order
+    pub fn from_dataframe(df: &DataFrame) -> Self {
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|c| SchemaColumn {
+                name: c.name().to_string(),
+                dtype: c.dtype().to_string(),
+            })
+            .collect();
+        Self { columns }
+    }
+
+    /// Read a schema file written by [`ExpectedSchema::save`] or by hand
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read schema file: {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Invalid schema file: {}", path.display()))
+    }
+
+    /// Write the schema as pretty-printed JSON
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize schema")?;
+        std::fs::write(path, json)
+            .with_context(|| format!("Failed to write schema file: {}", path.display()))
+    }
+
+    /// Compare the columns of `df` against this schema.
+    ///
+    /// Columns listed in `ignore` (e.g. `--drop-columns`, already removed from
+    /// `df`) are not reported as removed. Added and removed columns keep the
+    /// order of the dataset and of the schema respectively.
+    pub fn compare(&self, df: &DataFrame, ignore: &[String], source: &Path) -> SchemaDrift {
+        let expected: HashMap<&str, &str> = self
+            .columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.dtype.as_str()))
+            .collect();
+        let actual = Self::from_dataframe(df);
+        let present: HashMap<&str, &str> = actual
+            .columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.dtype.as_str()))
+            .collect();
+
+        let mut added = Vec::new();
+        let mut retyped = Vec::new();
+        for column in &actual.columns {
+            match expected.get(column.name.as_str()) {
+                None => added.push(column.clone()),
+                Some(&dtype) if dtype != column.dtype => retyped.push(RetypedColumn {
+                    name: column.name.clone(),
+                    expected: dtype.to_string(),
+                    actual: column.dtype.clone(),
+                }),
+                Some(_) => {}
+            }
+        }
+        let removed = self
+            .columns
+            .iter()
+            .filter(|c| !present.contains_key(c.name.as_str()) && !ignore.contains(&c.name))
+            .cloned()
+            .collect();
+
+        SchemaDrift {
+            expected_schema: source.display().to_string(),
+            added,
+            removed,
+            retyped,
+        }
+    }
+}
+
+impl SchemaDrift {
+    /// Whether the dataset matches the expected schema
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.retyped.is_empty()
+    }
+
+    /// One line per difference, e.g. `retyped: income (i64 -> str)`
+    pub fn lines(&self) -> Vec<String> {
+        let added = self
+            .added
+            .iter()
+            .map(|c| format!("added: {} ({})", c.name, c.dtype));
+        let removed = self
+            .removed
+            .iter()
+            .map(|c| format!("removed: {} ({})", c.name, c.dtype));
+        let retyped = self
+            .retyped
+            .iter()
+            .map(|c| format!("retyped: {} ({} -> {})", c.name, c.expected, c.actual));
+        added.chain(removed).chain(retyped).collect()
+    }
+}
+
+impl fmt::Display for SchemaDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} added, {} removed, {} retyped column(s) against {}",
+            self.added.len(),
+            self.removed.len(),
+            self.retyped.len(),
+            self.expected_schema
+        )
+    }
+}
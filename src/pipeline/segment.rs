@@ -0,0 +1,220 @@
+//! Segments of the rows by the values of a column (`--segment-column`)
+//!
+//! A portfolio often mixes populations, e.g. regions or products, whose data
+//! is collected differently. The segment checks compare a feature across the
+//! values of one column so a problem confined to one population is not hidden
+//! by the pooled rows.
+//!
+//! The missing stage reports each feature's missing ratio per segment
+//! ([`super::missing::analyze_missing_by_segment`]); the Gini stage bins each
+//! segment on its own and [`assess_segment_iv`] flags the features whose IV
+//! differs widely between segments.
+
+use anyhow::{bail, Result};
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Most distinct values a segment column may hold; more usually means an
+/// identifier or a numeric column was named by mistake
+pub const MAX_SEGMENTS: usize = 50;
+
+/// IV spread between the strongest and the weakest segment, relative to the
+/// strongest, above which a feature is flagged as divergent
+pub const SEGMENT_IV_DIVERGENCE_THRESHOLD: f64 = 0.5;
+
+/// Segment IV a feature must reach somewhere to be flagged; below it every
+/// segment is weak and the spread is noise
+pub const MIN_DIVERGENT_SEGMENT_IV: f64 = 0.02;
+
+/// The segments of a column and the segment of every row
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segments {
+    /// Distinct values of the column as text, sorted
+    pub labels: Vec<String>,
+    /// Index into `labels` of each row; `None` for a null value
+    pub rows: Vec<Option<usize>>,
+}
+
+impl Segments {
+    /// Group the rows of `column` by value.
+    ///
+    /// # Errors
+    /// When the column holds more than [`MAX_SEGMENTS`] distinct values.
+    pub fn from_column(column: &Column) -> Result<Self> {
+        let text = column.cast(&DataType::String)?;
+        let values: Vec<Option<&str>> = text.str()?.into_iter().collect();
+        let mut labels: Vec<String> = values.iter().flatten().map(|v| v.to_string()).collect();
+        labels.sort();
+        labels.dedup();
+        if labels.len() > MAX_SEGMENTS {
+            bail!(
+                "Column '{}' has {} distinct values; at most {} are supported as groups",
+                column.name(),
+                labels.len(),
+                MAX_SEGMENTS
+            );
+        }
+        let rows = values
+            .iter()
+            .map(|v| v.and_then(|v| labels.binary_search_by(|l| l.as_str().cmp(v)).ok()))
+            .collect();
+        Ok(Self { labels, rows })
+    }
+
+    /// Rows with a null segment value, which belong to no segment
+    pub fn unsegmented_rows(&self) -> usize {
+        self.rows.iter().filter(|r| r.is_none()).count()
+    }
+
+    /// Mask of the rows in segment `segment`
+    pub fn mask(&self, segment: usize) -> BooleanChunked {
+        let rows: Vec<bool> = self.rows.iter().map(|r| *r == Some(segment)).collect();
+        BooleanChunked::from_slice("segment".into(), &rows)
+    }
+}
+
+/// Rows and target classes of one segment in the Gini stage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentSummary {
+    pub segment: String,
+    pub rows: usize,
+    pub events: usize,
+    pub non_events: usize,
+    /// Both classes are present and the segment is not nearly single-class
+    pub scored: bool,
+}
+
+/// IV of one feature in one segment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentFeatureIv {
+    pub segment: String,
+    /// `None` when the segment was not scored
+    pub iv: Option<f64>,
+}
+
+/// The per-segment IVs of one feature next to its pooled IV
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureSegmentIv {
+    pub feature: String,
+    /// IV on all the rows of the Gini stage
+    pub overall_iv: f64,
+    pub segments: Vec<SegmentFeatureIv>,
+    /// `(max - min) / max` of the scored segment IVs; `None` under two
+    /// scored segments
+    pub iv_spread: Option<f64>,
+    /// `iv_spread` is above [`SEGMENT_IV_DIVERGENCE_THRESHOLD`] and the
+    /// strongest segment IV reaches [`MIN_DIVERGENT_SEGMENT_IV`]
+    pub divergent: bool,
+}
+
+/// Per-segment IV comparison of the Gini stage (`--segment-column`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentIvAnalysis {
+    pub column: String,
+    /// Segments in label order
+    pub segments: Vec<SegmentSummary>,
+    /// Rows with a null segment value, left out of every segment
+    pub unsegmented_rows: usize,
+    pub divergence_threshold: f64,
+    /// Every feature of the Gini stage, in analysis order
+    pub features: Vec<FeatureSegmentIv>,
+}
+
+impl SegmentIvAnalysis {
+    /// Features whose IV diverges between segments
+    pub fn divergent(&self) -> Vec<&str> {
+        self.features
+            .iter()
+            .filter(|f| f.divergent)
+            .map(|f| f.feature.as_str())
+            .collect()
+    }
+}
+
+/// Compare the segment IVs of `feature` and flag it when they spread too far.
+pub fn assess_segment_iv(
+    feature: &str,
+    overall_iv: f64,
+    segments: Vec<SegmentFeatureIv>,
+) -> FeatureSegmentIv {
+    let ivs: Vec<f64> = segments.iter().filter_map(|s| s.iv).collect();
+    let max = ivs.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let min = ivs.iter().copied().fold(f64::INFINITY, f64::min);
+    let iv_spread = (ivs.len() >= 2).then(|| if max > 0.0 { (max - min) / max } else { 0.0 });
+    let divergent = max >= MIN_DIVERGENT_SEGMENT_IV
+        && iv_spread.is_some_and(|s| s > SEGMENT_IV_DIVERGENCE_THRESHOLD);
+
+    FeatureSegmentIv {
+        feature: feature.to_string(),
+        overall_iv,
+        segments,
+        iv_spread,
+        divergent,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segments_sorted_with_nulls_left_out() {
+        let column = Column::new(
+            "region".into(),
+            [Some("north"), None, Some("east"), Some("north")],
+        );
+        let segments = Segments::from_column(&column).unwrap();
+        assert_eq!(segments.labels, ["east", "north"]);
+        assert_eq!(segments.rows, [Some(1), None, Some(0), Some(1)]);
+        assert_eq!(segments.unsegmented_rows(), 1);
+        assert_eq!(segments.mask(1).sum(), Some(2));
+
+        let numeric = Column::new("branch".into(), [3i32, 1, 3]);
+        assert_eq!(Segments::from_column(&numeric).unwrap().labels, ["1", "3"]);
+    }
+
+    #[test]
+    fn test_assess_segment_iv_flags_wide_spread() {
+        let segment = |label: &str, iv: Option<f64>| SegmentFeatureIv {
+            segment: label.to_string(),
+            iv,
+        };
+
+        let even = assess_segment_iv(
+            "even",
+            0.30,
+            vec![segment("east", Some(0.28)), segment("north", Some(0.33))],
+        );
+        assert!(!even.divergent);
+
+        // 0.4 in north, 0.1 in south; the unscored segment is skipped
+        let split = assess_segment_iv(
+            "split",
+            0.25,
+            vec![
+                segment("east", None),
+                segment("north", Some(0.40)),
+                segment("south", Some(0.10)),
+            ],
+        );
+        assert!((split.iv_spread.unwrap() - 0.75).abs() < 1e-9);
+        assert!(split.divergent);
+
+        // Weak everywhere: the spread is large but not flagged
+        let weak = assess_segment_iv(
+            "weak",
+            0.01,
+            vec![segment("north", Some(0.012)), segment("south", Some(0.001))],
+        );
+        assert!(!weak.divergent);
+
+        let single = assess_segment_iv("single", 0.2, vec![segment("north", Some(0.2))]);
+        assert_eq!(single.iv_spread, None);
+    }
+
+    #[test]
+    fn test_too_many_segments_rejected() {
+        let column = Column::new("id".into(), (0..=MAX_SEGMENTS as i32).collect::<Vec<_>>());
+        assert!(Segments::from_column(&column).is_err());
+    }
+}
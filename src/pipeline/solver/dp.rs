@@ -0,0 +1,377 @@
+//! Exact optimal binning by dynamic programming
+//!
+//! Solves the same problem as the MIP model in `model.rs` (merge contiguous
+//! prebins into at most `target_bins` bins, maximizing total IV, subject to
+//! the bin size and share limits, monotonicity and the minimum WoE
+//! difference) without a solver dependency. Every constraint between bins
+//! involves only neighbours, so the best partition can be built left to right
+//! from the best partitions ending in each possible last bin.
+//!
+//! With `n` prebins and `k` bins this takes O(k·n³) time and O(k·n²) memory,
+//! a few milliseconds for the usual 20-50 prebins.
+
+use std::time::Instant;
+
+use anyhow::{bail, Result};
+
+use super::super::iv::WoeBin;
+use super::monotonicity::{auto_candidates, turning_point, violates_adjacent};
+use super::precompute::{
+    get_precomputed_bin, precompute_categorical_iv_matrix, precompute_iv_matrix, PrecomputedBin,
+};
+use super::{CategoryStats, MonotonicityConstraint, SolverConfig, SolverResult, SolverStatus};
+
+/// Inclusive `(start, end)` prebin ranges of the bins and their total IV
+type Partition = (Vec<(usize, usize)>, f64);
+
+/// Solve the optimal binning problem for numeric features
+pub fn solve_numeric_binning(
+    prebins: &[WoeBin],
+    target_bins: usize,
+    config: &SolverConfig,
+    total_events: f64,
+    total_non_events: f64,
+    total_samples: f64,
+) -> Result<SolverResult> {
+    let start_time = Instant::now();
+    if let Some(result) = unmerged(prebins.len(), target_bins, config, start_time, || {
+        prebins.iter().map(|b| b.iv_contribution).sum()
+    }) {
+        return Ok(result);
+    }
+    let iv_matrix = precompute_iv_matrix(prebins, total_events, total_non_events);
+    let woes: Vec<f64> = prebins.iter().map(|b| b.woe).collect();
+
+    let patterns = match config.monotonicity {
+        MonotonicityConstraint::Auto => auto_candidates(&woes),
+        pattern => vec![pattern],
+    };
+
+    // Auto: keep the pattern with the highest IV, like the MIP model
+    let mut best: Option<(Partition, MonotonicityConstraint)> = None;
+    for pattern in patterns {
+        let turn = turning_point(&woes, pattern);
+        let partition = best_partition(
+            &iv_matrix,
+            target_bins,
+            config,
+            total_samples,
+            |left, right| {
+                violates_adjacent(
+                    pattern,
+                    turn,
+                    left.end,
+                    left.woe,
+                    right.woe,
+                    config.min_woe_delta,
+                )
+            },
+        );
+        if let Some(partition) = partition {
+            if best
+                .as_ref()
+                .is_none_or(|((_, best_iv), _)| partition.1 > *best_iv)
+            {
+                best = Some((partition, pattern));
+            }
+        }
+    }
+
+    let Some(((bin_boundaries, total_iv), pattern)) = best else {
+        bail!("No binning of the prebins satisfies the constraints");
+    };
+    Ok(SolverResult {
+        bin_boundaries,
+        total_iv,
+        solve_time_ms: start_time.elapsed().as_millis() as u64,
+        gap: 0.0,
+        monotonicity_applied: pattern,
+        status: SolverStatus::Optimal,
+    })
+}
+
+/// Solve optimal binning for categorical features sorted by event rate
+#[allow(dead_code)]
+pub fn solve_categorical_binning(
+    sorted_categories: &[CategoryStats],
+    target_bins: usize,
+    config: &SolverConfig,
+    total_events: f64,
+    total_non_events: f64,
+    total_samples: f64,
+) -> Result<SolverResult> {
+    let start_time = Instant::now();
+    let iv_matrix =
+        precompute_categorical_iv_matrix(sorted_categories, total_events, total_non_events);
+    if let Some(result) = unmerged(
+        sorted_categories.len(),
+        target_bins,
+        config,
+        start_time,
+        || {
+            (0..iv_matrix.len())
+                .map(|i| get_precomputed_bin(&iv_matrix, i, i).iv)
+                .sum()
+        },
+    ) {
+        return Ok(result);
+    }
+
+    let partition = best_partition(
+        &iv_matrix,
+        target_bins,
+        config,
+        total_samples,
+        |left, right| (left.woe - right.woe).abs() < config.min_woe_delta,
+    );
+    let Some((bin_boundaries, total_iv)) = partition else {
+        bail!("No grouping of the categories satisfies the constraints");
+    };
+    Ok(SolverResult {
+        bin_boundaries,
+        total_iv,
+        solve_time_ms: start_time.elapsed().as_millis() as u64,
+        gap: 0.0,
+        monotonicity_applied: config.monotonicity,
+        status: SolverStatus::Optimal,
+    })
+}
+
+/// One bin per prebin when there are no more than `target_bins` of them and
+/// nothing else limits the bins, as in the MIP model
+fn unmerged(
+    n: usize,
+    target_bins: usize,
+    config: &SolverConfig,
+    start_time: Instant,
+    total_iv: impl FnOnce() -> f64,
+) -> Option<SolverResult> {
+    (n <= target_bins && !config.limits_bin_count()).then(|| SolverResult {
+        bin_boundaries: (0..n).map(|i| (i, i)).collect(),
+        total_iv: total_iv(),
+        solve_time_ms: start_time.elapsed().as_millis() as u64,
+        gap: 0.0,
+        monotonicity_applied: MonotonicityConstraint::None,
+        status: SolverStatus::Optimal,
+    })
+}
+
+/// The highest-IV partition of the prebins into bins allowed by
+/// [`SolverConfig::allows_bin`] in which no two neighbours `conflict`:
+/// exactly `k` bins, or at most `k` when [`SolverConfig::limits_bin_count`].
+/// `None` when no partition qualifies.
+fn best_partition(
+    iv_matrix: &[Vec<PrecomputedBin>],
+    k: usize,
+    config: &SolverConfig,
+    total_samples: f64,
+    conflict: impl Fn(&PrecomputedBin, &PrecomputedBin) -> bool,
+) -> Option<Partition> {
+    let n = iv_matrix.len();
+    if n == 0 || k == 0 {
+        return None;
+    }
+
+    let allowed = |i: usize, j: usize| {
+        config.allows_bin(get_precomputed_bin(iv_matrix, i, j).count, total_samples)
+    };
+    // best[m][i][j]: highest IV of prebins 0..=j in m + 1 bins, the last one
+    // being i..=j; from[m][i][j] is the start of the bin before it
+    let idx = |i: usize, j: usize| i * n + j;
+    let mut best = vec![vec![f64::NEG_INFINITY; n * n]; k];
+    let mut from = vec![vec![usize::MAX; n * n]; k];
+
+    for j in 0..n {
+        if allowed(0, j) {
+            best[0][idx(0, j)] = get_precomputed_bin(iv_matrix, 0, j).iv;
+        }
+    }
+    for m in 1..k {
+        for i in 0..n {
+            for j in i..n.saturating_sub(1) {
+                let prev = best[m - 1][idx(i, j)];
+                if !prev.is_finite() {
+                    continue;
+                }
+                let left = get_precomputed_bin(iv_matrix, i, j);
+                for j2 in j + 1..n {
+                    if !allowed(j + 1, j2) {
+                        continue;
+                    }
+                    let right = get_precomputed_bin(iv_matrix, j + 1, j2);
+                    if conflict(left, right) {
+                        continue;
+                    }
+                    let value = prev + right.iv;
+                    if value > best[m][idx(j + 1, j2)] {
+                        best[m][idx(j + 1, j2)] = value;
+                        from[m][idx(j + 1, j2)] = i;
+                    }
+                }
+            }
+        }
+    }
+
+    // Partitions covering every prebin; fewer than k bins only under limits
+    let counts = if config.limits_bin_count() {
+        0..k
+    } else {
+        k - 1..k
+    };
+    let (m, mut start, iv) = counts
+        .flat_map(|m| (0..n).map(move |i| (m, i)))
+        .map(|(m, i)| (m, i, best[m][idx(i, n - 1)]))
+        .filter(|(_, _, iv)| iv.is_finite())
+        .max_by(|a, b| a.2.total_cmp(&b.2))?;
+
+    let mut bins = vec![(start, n - 1)];
+    for level in (1..=m).rev() {
+        let end = start - 1;
+        start = from[level][idx(start, bins.last().unwrap().1)];
+        bins.push((start, end));
+    }
+    bins.reverse();
+    Some((bins, iv))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prebin(events: f64, non_events: f64) -> WoeBin {
+        let dist_events = (events + 0.5) / 60.5;
+        let dist_non_events = (non_events + 0.5) / 60.5;
+        WoeBin {
+            lower_bound: 0.0,
+            upper_bound: 0.0,
+            events,
+            non_events,
+            woe: (dist_events / dist_non_events).ln(),
+            iv_contribution: 0.0,
+            count: events + non_events,
+            population_pct: 0.0,
+            event_rate: events / (events + non_events),
+            chi_square: None,
+            chi_square_p_value: None,
+        }
+    }
+
+    /// 6 prebins of 20 rows, 60 events and 60 non-events; the event rate
+    /// rises, dips at the fourth prebin and rises again
+    fn prebins() -> Vec<WoeBin> {
+        vec![
+            prebin(2.0, 18.0),
+            prebin(6.0, 14.0),
+            prebin(12.0, 8.0),
+            prebin(9.0, 11.0),
+            prebin(14.0, 6.0),
+            prebin(17.0, 3.0),
+        ]
+    }
+
+    fn solve(config: &SolverConfig, k: usize) -> Result<SolverResult> {
+        solve_numeric_binning(&prebins(), k, config, 60.0, 60.0, 120.0)
+    }
+
+    /// Best IV over every partition into exactly `k` bins passing `ok`
+    fn brute_force(k: usize, ok: impl Fn(&[(usize, usize)]) -> bool) -> f64 {
+        let matrix = precompute_iv_matrix(&prebins(), 60.0, 60.0);
+        let n = matrix.len();
+        let mut best = f64::NEG_INFINITY;
+        // Each bit marks a cut after prebin i
+        for cuts in 0u32..(1 << (n - 1)) {
+            if cuts.count_ones() as usize != k - 1 {
+                continue;
+            }
+            let mut bins = Vec::new();
+            let mut start = 0;
+            for i in 0..n {
+                if i == n - 1 || cuts & (1 << i) != 0 {
+                    bins.push((start, i));
+                    start = i + 1;
+                }
+            }
+            if ok(&bins) {
+                let iv = bins
+                    .iter()
+                    .map(|&(i, j)| get_precomputed_bin(&matrix, i, j).iv)
+                    .sum();
+                best = best.max(iv);
+            }
+        }
+        best
+    }
+
+    fn woe(bin: (usize, usize)) -> f64 {
+        let matrix = precompute_iv_matrix(&prebins(), 60.0, 60.0);
+        get_precomputed_bin(&matrix, bin.0, bin.1).woe
+    }
+
+    #[test]
+    fn test_dp_matches_brute_force_without_constraints() {
+        for k in 1..=5 {
+            let res = solve(&SolverConfig::default(), k).unwrap();
+            assert_eq!(res.bin_boundaries.len(), k);
+            assert!((res.total_iv - brute_force(k, |_| true)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_dp_ascending_is_optimal_and_monotonic() {
+        let config = SolverConfig {
+            monotonicity: MonotonicityConstraint::Ascending,
+            ..SolverConfig::default()
+        };
+        let ascending = |bins: &[(usize, usize)]| bins.windows(2).all(|w| woe(w[0]) <= woe(w[1]));
+        for k in 2..=4 {
+            let res = solve(&config, k).unwrap();
+            assert!(ascending(&res.bin_boundaries));
+            assert!((res.total_iv - brute_force(k, ascending)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_dp_auto_records_the_chosen_pattern() {
+        let config = SolverConfig {
+            monotonicity: MonotonicityConstraint::Auto,
+            ..SolverConfig::default()
+        };
+        let res = solve(&config, 3).unwrap();
+        assert_eq!(res.monotonicity_applied, MonotonicityConstraint::Ascending);
+        assert_eq!(res.status, SolverStatus::Optimal);
+    }
+
+    #[test]
+    fn test_dp_limits_allow_fewer_bins() {
+        // 4 bins cannot each hold at least 30% of the rows
+        let config = SolverConfig {
+            min_bin_pct: 30.0,
+            ..SolverConfig::default()
+        };
+        let res = solve(&config, 4).unwrap();
+        assert!(res.bin_boundaries.len() <= 3);
+        let matrix = precompute_iv_matrix(&prebins(), 60.0, 60.0);
+        for &(i, j) in &res.bin_boundaries {
+            assert!(get_precomputed_bin(&matrix, i, j).count >= 36.0);
+        }
+
+        let config = SolverConfig {
+            min_woe_delta: 0.5,
+            ..SolverConfig::default()
+        };
+        let res = solve(&config, 6).unwrap();
+        assert!(res
+            .bin_boundaries
+            .windows(2)
+            .all(|w| (woe(w[0]) - woe(w[1])).abs() >= 0.5));
+    }
+
+    #[test]
+    fn test_dp_keeps_few_prebins_unmerged() {
+        let res = solve(&SolverConfig::default(), 8).unwrap();
+        assert_eq!(
+            res.bin_boundaries,
+            (0..6).map(|i| (i, i)).collect::<Vec<_>>()
+        );
+    }
+}
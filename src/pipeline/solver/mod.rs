@@ -1,25 +1,95 @@
-//! Solver-based optimal binning using Mixed Integer Programming (MIP)
+//! Solver-based optimal binning
 //!
-//! This module implements optimal binning using the HiGHS solver via good_lp.
-//! It finds globally optimal bin boundaries that maximize Information Value (IV)
-//! subject to constraints like bin count and optional monotonicity.
+//! This module finds globally optimal bin boundaries that maximize Information
+//! Value (IV) subject to constraints like bin count and optional monotonicity.
+//! Two backends solve the same problem: a Mixed Integer Programming (MIP)
+//! model run by the HiGHS solver via good_lp, and an exact dynamic program in
+//! pure Rust (see [`BinningBackend`]).
+//!
+//! The MIP model needs the `solver` feature. Without it the MIP backend
+//! returns an error and the CLI switches to the dynamic program.
 
+mod dp;
+#[cfg(feature = "solver")]
 mod model;
 mod monotonicity;
 mod precompute;
+mod reconstruct;
 
-use anyhow::Result;
-use serde::Serialize;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
 
 use super::iv::WoeBin;
 
 pub use monotonicity::MonotonicityConstraint;
 
+/// Whether this build includes the MIP solver (the `solver` cargo feature)
+pub const SOLVER_AVAILABLE: bool = cfg!(feature = "solver");
+
+#[cfg(not(feature = "solver"))]
+const SOLVER_DISABLED: &str =
+    "solver support is disabled in this build (enable the `solver` feature)";
+
+const GREEDY_IS_NOT_A_SOLVER: &str =
+    "the greedy backend merges bins without a solver; leave out the solver config";
+
+/// How the final bins are found (`--binning-backend`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BinningBackend {
+    /// MIP model solved by HiGHS (needs the `solver` feature)
+    Mip,
+    /// Exact dynamic program over the prebins, no solver dependency
+    Dp,
+    /// Greedy merging of the adjacent prebins with the least IV loss
+    Greedy,
+}
+
+impl Default for BinningBackend {
+    /// MIP when this build has the solver, else the dynamic program
+    fn default() -> Self {
+        if SOLVER_AVAILABLE {
+            BinningBackend::Mip
+        } else {
+            BinningBackend::Dp
+        }
+    }
+}
+
+impl std::fmt::Display for BinningBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BinningBackend::Mip => write!(f, "mip"),
+            BinningBackend::Dp => write!(f, "dp"),
+            BinningBackend::Greedy => write!(f, "greedy"),
+        }
+    }
+}
+
+impl std::str::FromStr for BinningBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "mip" => Ok(BinningBackend::Mip),
+            "dp" => Ok(BinningBackend::Dp),
+            "greedy" => Ok(BinningBackend::Greedy),
+            _ => Err(format!(
+                "Unknown binning backend: '{}'. Use 'mip', 'dp' or 'greedy'.",
+                s
+            )),
+        }
+    }
+}
+
 /// Configuration for the solver-based optimal binning
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
 pub struct SolverConfig {
-    /// Maximum time allowed for solver per feature (seconds)
+    /// Which backend solves the model; `Greedy` is not a solver and is
+    /// rejected by the solve functions
+    pub backend: BinningBackend,
+    /// Maximum time allowed for solver per feature (seconds, 0 = no limit)
     pub timeout_seconds: u64,
     /// MIP gap tolerance - solver stops when gap falls below this
     pub gap_tolerance: f64,
@@ -27,15 +97,52 @@ pub struct SolverConfig {
     pub monotonicity: MonotonicityConstraint,
     /// Minimum samples per bin
     pub min_bin_samples: usize,
+    /// Smallest share of the weighted samples a final bin may hold (percent)
+    pub min_bin_pct: f64,
+    /// Largest share of the weighted samples a final bin may hold (percent)
+    pub max_bin_pct: f64,
+    /// Smallest absolute WoE difference between adjacent final bins (0 = none)
+    pub min_woe_delta: f64,
+}
+
+impl SolverConfig {
+    /// Whether the bin share limits narrow the model (anything but 0-100%)
+    pub fn has_bin_share_limits(&self) -> bool {
+        self.min_bin_pct > 0.0 || self.max_bin_pct < 100.0
+    }
+
+    /// Whether any limit beyond the bin count and monotonicity is set. These
+    /// can rule out `target_bins` bins, so the model then settles for fewer.
+    pub fn limits_bin_count(&self) -> bool {
+        self.has_bin_share_limits() || self.min_woe_delta > 0.0
+    }
+
+    /// Whether a bin holding `count` of `total_samples` weighted samples
+    /// satisfies `min_bin_samples` and the bin share limits
+    pub(crate) fn allows_bin(&self, count: f64, total_samples: f64) -> bool {
+        if count < self.min_bin_samples as f64 {
+            return false;
+        }
+        if !self.has_bin_share_limits() || total_samples <= 0.0 {
+            return true;
+        }
+        let pct = count / total_samples * 100.0;
+        // Tolerate rounding at the limits, e.g. 4 bins of exactly 25%
+        pct >= self.min_bin_pct - 1e-9 && pct <= self.max_bin_pct + 1e-9
+    }
 }
 
 impl Default for SolverConfig {
     fn default() -> Self {
         Self {
+            backend: BinningBackend::default(),
             timeout_seconds: 30,
             gap_tolerance: 0.01,
             monotonicity: MonotonicityConstraint::None,
             min_bin_samples: 5,
+            min_bin_pct: 0.0,
+            max_bin_pct: 100.0,
+            min_woe_delta: 0.0,
         }
     }
 }
@@ -55,12 +162,61 @@ pub struct SolverResult {
     pub gap: f64,
     /// The monotonicity constraint that was applied
     pub monotonicity_applied: MonotonicityConstraint,
+    /// Whether the solve finished or stopped at the time limit
+    pub status: SolverStatus,
+}
+
+/// How the solver fared on one feature, recorded in `IvAnalysis.solver_status`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum SolverStatus {
+    /// Solved within the time limit, to within `gap_tolerance` of the best IV
+    Optimal,
+    /// Stopped at the time limit with a valid solution. `gap` is the relative
+    /// IV shortfall against an upper bound from the model without its
+    /// monotonicity and WoE difference constraints, so the true gap is at most this.
+    FeasibleGap { gap: f64 },
+    /// No valid solution by the time limit; the bins come from greedy merging
+    TimedOutFallback,
+    /// The model has no solution (e.g. bin limits that no grouping meets) or
+    /// the solve failed; the bins come from greedy merging
+    FailedFallback,
+}
+
+/// Error of a solve that reached `timeout_seconds` without a valid solution
+#[derive(Debug)]
+pub struct SolverTimedOut;
+
+impl std::fmt::Display for SolverTimedOut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "solver reached the time limit without a feasible solution"
+        )
+    }
+}
+
+impl std::error::Error for SolverTimedOut {}
+
+impl SolverStatus {
+    /// The status of a feature whose solve failed with `err`
+    pub fn fallback_for(err: &anyhow::Error) -> Self {
+        if err.is::<SolverTimedOut>() {
+            SolverStatus::TimedOutFallback
+        } else {
+            SolverStatus::FailedFallback
+        }
+    }
 }
 
 /// Solve optimal binning for numeric features using MIP
 ///
 /// Takes a vector of prebins and finds the optimal way to merge them
-/// into `target_bins` final bins that maximizes total IV.
+/// into `target_bins` final bins that maximizes total IV. With bin share
+/// limits (`min_bin_pct`/`max_bin_pct`) every final bin must hold a share of
+/// `total_samples` within them, and with `min_woe_delta` adjacent final bins
+/// must differ in WoE by at least that much. Under either the solution may
+/// have fewer bins; the solve fails when no grouping of the prebins fits.
 ///
 /// # Arguments
 /// * `prebins` - Vector of pre-computed bins from quantile or CART prebinning
@@ -80,14 +236,28 @@ pub fn solve_optimal_binning(
     total_non_events: f64,
     total_samples: f64,
 ) -> Result<SolverResult> {
-    model::solve_numeric_binning(
-        prebins,
-        target_bins,
-        config,
-        total_events,
-        total_non_events,
-        total_samples,
-    )
+    match config.backend {
+        #[cfg(feature = "solver")]
+        BinningBackend::Mip => model::solve_numeric_binning(
+            prebins,
+            target_bins,
+            config,
+            total_events,
+            total_non_events,
+            total_samples,
+        ),
+        #[cfg(not(feature = "solver"))]
+        BinningBackend::Mip => bail!(SOLVER_DISABLED),
+        BinningBackend::Dp => dp::solve_numeric_binning(
+            prebins,
+            target_bins,
+            config,
+            total_events,
+            total_non_events,
+            total_samples,
+        ),
+        BinningBackend::Greedy => bail!(GREEDY_IS_NOT_A_SOLVER),
+    }
 }
 
 /// Reconstruct final WoeBin vector from solver result
@@ -101,7 +271,7 @@ pub fn reconstruct_bins_from_solution(
     total_non_events: f64,
     total_samples: f64,
 ) -> Vec<WoeBin> {
-    model::reconstruct_bins(
+    reconstruct::reconstruct_bins(
         prebins,
         result,
         total_events,
@@ -133,12 +303,112 @@ pub fn solve_categorical_optimal_binning(
     total_non_events: f64,
     total_samples: f64,
 ) -> Result<SolverResult> {
-    model::solve_categorical_binning(
-        sorted_categories,
-        target_bins,
-        config,
-        total_events,
-        total_non_events,
-        total_samples,
-    )
+    match config.backend {
+        #[cfg(feature = "solver")]
+        BinningBackend::Mip => model::solve_categorical_binning(
+            sorted_categories,
+            target_bins,
+            config,
+            total_events,
+            total_non_events,
+            total_samples,
+        ),
+        #[cfg(not(feature = "solver"))]
+        BinningBackend::Mip => bail!(SOLVER_DISABLED),
+        BinningBackend::Dp => dp::solve_categorical_binning(
+            sorted_categories,
+            target_bins,
+            config,
+            total_events,
+            total_non_events,
+            total_samples,
+        ),
+        BinningBackend::Greedy => bail!(GREEDY_IS_NOT_A_SOLVER),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allows_bin_checks_samples_and_share() {
+        let config = SolverConfig {
+            min_bin_pct: 10.0,
+            max_bin_pct: 50.0,
+            ..SolverConfig::default()
+        };
+        assert!(config.has_bin_share_limits());
+        assert!(config.allows_bin(10.0, 100.0));
+        assert!(config.allows_bin(50.0, 100.0));
+        assert!(!config.allows_bin(9.5, 100.0));
+        assert!(!config.allows_bin(50.5, 100.0));
+        // min_bin_samples still applies
+        assert!(!config.allows_bin(4.0, 20.0));
+
+        let default = SolverConfig::default();
+        assert!(!default.has_bin_share_limits());
+        assert!(!default.limits_bin_count());
+        assert!(SolverConfig {
+            min_woe_delta: 0.1,
+            ..SolverConfig::default()
+        }
+        .limits_bin_count());
+        assert!(default.allows_bin(1000.0, 1000.0));
+    }
+
+    #[test]
+    fn test_solver_status_fallback_and_serialization() {
+        let err: anyhow::Error = SolverTimedOut.into();
+        assert_eq!(
+            SolverStatus::fallback_for(&err),
+            SolverStatus::TimedOutFallback
+        );
+        let err = anyhow::anyhow!("infeasible");
+        assert_eq!(
+            SolverStatus::fallback_for(&err),
+            SolverStatus::FailedFallback
+        );
+
+        let json = serde_json::to_value(SolverStatus::FeasibleGap { gap: 0.25 }).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"status": "feasible_gap", "gap": 0.25})
+        );
+        let json = serde_json::to_value(SolverStatus::TimedOutFallback).unwrap();
+        assert_eq!(json, serde_json::json!({"status": "timed_out_fallback"}));
+    }
+
+    #[test]
+    fn test_binning_backend_parse_and_default() {
+        for name in ["mip", "dp", "greedy"] {
+            let backend: BinningBackend = name.parse().unwrap();
+            assert_eq!(backend.to_string(), name);
+        }
+        assert_eq!("DP".parse::<BinningBackend>(), Ok(BinningBackend::Dp));
+        assert!("cplex".parse::<BinningBackend>().is_err());
+        assert_eq!(
+            BinningBackend::default() == BinningBackend::Mip,
+            SOLVER_AVAILABLE
+        );
+
+        let greedy = SolverConfig {
+            backend: BinningBackend::Greedy,
+            ..SolverConfig::default()
+        };
+        assert!(solve_optimal_binning(&[], 2, &greedy, 1.0, 1.0, 2.0).is_err());
+    }
+
+    #[cfg(not(feature = "solver"))]
+    #[test]
+    fn test_solve_without_solver_feature_errors() {
+        let config = SolverConfig {
+            backend: BinningBackend::Mip,
+            ..SolverConfig::default()
+        };
+        let err = solve_optimal_binning(&[], 2, &config, 1.0, 1.0, 2.0)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`solver` feature"));
+    }
 }
@@ -5,21 +5,25 @@
 
 use std::time::Instant;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use good_lp::{
     constraint, default_solver, variable, Expression, ProblemVariables, Solution, SolverModel,
     Variable,
 };
 
 use super::super::iv::WoeBin;
-use super::monotonicity::MonotonicityConstraint;
+use super::monotonicity::{
+    auto_candidates, turning_point, violates_adjacent, MonotonicityConstraint,
+};
 use super::precompute::{
     get_precomputed_bin, precompute_categorical_iv_matrix, precompute_iv_matrix, PrecomputedBin,
 };
-use super::{CategoryStats, SolverConfig, SolverResult};
+use super::reconstruct::calculate_woe_iv;
+use super::{CategoryStats, SolverConfig, SolverResult, SolverStatus, SolverTimedOut};
 
-/// Smoothing constant for WoE calculation
-const SMOOTHING: f64 = 0.5;
+/// Share of the time limit after which a solve counts as stopped by it;
+/// HiGHS checks the clock between nodes, so it may stop a little early
+const TIME_LIMIT_SHARE: f64 = 0.99;
 
 /// Solve the optimal binning problem for numeric features
 pub fn solve_numeric_binning(
@@ -33,8 +37,9 @@ pub fn solve_numeric_binning(
     let start_time = Instant::now();
     let n = prebins.len();
 
-    // Edge case: if prebins <= target_bins, no merging needed
-    if n <= target_bins {
+    // Edge case: if prebins <= target_bins, no merging needed (unless the
+    // bin limits rule out keeping every prebin)
+    if n <= target_bins && !config.limits_bin_count() {
         let boundaries: Vec<(usize, usize)> = (0..n).map(|i| (i, i)).collect();
         let total_iv: f64 = prebins.iter().map(|b| b.iv_contribution).sum();
         return Ok(SolverResult {
@@ -43,13 +48,14 @@ pub fn solve_numeric_binning(
             solve_time_ms: start_time.elapsed().as_millis() as u64,
             gap: 0.0,
             monotonicity_applied: MonotonicityConstraint::None,
+            status: SolverStatus::Optimal,
         });
     }
 
     // Precompute IV for all possible bin combinations
     let iv_matrix = precompute_iv_matrix(prebins, total_events, total_non_events);
 
-    // Handle Auto monotonicity by trying all patterns and selecting best
+    // Auto: solve for the candidate patterns and keep the best
     if config.monotonicity == MonotonicityConstraint::Auto {
         return solve_with_auto_monotonicity(
             prebins,
@@ -77,7 +83,9 @@ pub fn solve_numeric_binning(
     )
 }
 
-/// Solve with automatic monotonicity detection
+/// Solve with automatic monotonicity detection: ascending and descending,
+/// plus a peak or valley when the prebin WoE turns (see [`auto_candidates`]),
+/// keeping the solution with the highest IV
 #[allow(clippy::too_many_arguments)]
 fn solve_with_auto_monotonicity(
     prebins: &[WoeBin],
@@ -89,15 +97,11 @@ fn solve_with_auto_monotonicity(
     total_samples: f64,
     start_time: Instant,
 ) -> Result<SolverResult> {
-    let patterns = [
-        MonotonicityConstraint::None,
-        MonotonicityConstraint::Ascending,
-        MonotonicityConstraint::Descending,
-        MonotonicityConstraint::Peak,
-        MonotonicityConstraint::Valley,
-    ];
+    let woes: Vec<f64> = prebins.iter().map(|b| b.woe).collect();
+    let patterns = auto_candidates(&woes);
 
     let mut best_result: Option<SolverResult> = None;
+    let mut timed_out = false;
 
     for pattern in patterns {
         let result = solve_with_monotonicity(
@@ -112,17 +116,24 @@ fn solve_with_auto_monotonicity(
             start_time,
         );
 
-        if let Ok(res) = result {
-            if best_result
-                .as_ref()
-                .is_none_or(|best| res.total_iv > best.total_iv)
-            {
-                best_result = Some(res);
+        match result {
+            Ok(res) => {
+                if best_result
+                    .as_ref()
+                    .is_none_or(|best| res.total_iv > best.total_iv)
+                {
+                    best_result = Some(res);
+                }
             }
+            Err(err) => timed_out |= err.is::<SolverTimedOut>(),
         }
     }
 
-    best_result.context("No valid solution found with any monotonicity pattern")
+    match best_result {
+        Some(best) => Ok(best),
+        None if timed_out => Err(SolverTimedOut.into()),
+        None => bail!("No valid solution found with any monotonicity pattern"),
+    }
 }
 
 /// Solve the MIP model with a specific monotonicity constraint
@@ -135,7 +146,7 @@ fn solve_with_monotonicity(
     monotonicity: MonotonicityConstraint,
     _total_events: f64,
     _total_non_events: f64,
-    _total_samples: f64,
+    total_samples: f64,
     start_time: Instant,
 ) -> Result<SolverResult> {
     let n = prebins.len();
@@ -156,8 +167,9 @@ fn solve_with_monotonicity(
         let mut row = Vec::with_capacity(n - i);
         for j in i..n {
             let bin = get_precomputed_bin(iv_matrix, i, j);
-            // Only create variable if bin meets minimum sample requirement
-            if bin.count >= config.min_bin_samples as f64 {
+            // Only create variable if bin meets the minimum sample requirement
+            // and the bin share limits
+            if config.allows_bin(bin.count, total_samples) {
                 row.push(Some(vars.add(variable().binary())));
             } else {
                 row.push(None);
@@ -179,17 +191,20 @@ fn solve_with_monotonicity(
 
     let objective: Expression = objective_terms.into_iter().sum();
 
-    let mut problem = vars
-        .maximise(objective)
-        .using(default_solver)
-        .set_time_limit(config.timeout_seconds as f64)
+    let mut problem = vars.maximise(objective).using(default_solver);
+    // A zero timeout leaves HiGHS without a time limit
+    if config.timeout_seconds > 0 {
+        problem = problem.set_time_limit(config.timeout_seconds as f64);
+    }
+    let mut problem = problem
         // gap_tolerance is pre-validated to [0.0, 1.0] by CLI
         .set_mip_rel_gap(config.gap_tolerance as f32)
         .expect("gap_tolerance already validated");
 
-    // Constraint 1: Exactly K bins
+    // Constraint 1: Exactly K bins, or at most K under bin share limits or a
+    // minimum WoE difference, which can make K bins infeasible
     let bin_count: Expression = z.iter().flat_map(|row| row.iter().filter_map(|v| *v)).sum();
-    problem = problem.with(constraint!(bin_count == k as f64));
+    problem = problem.with(bin_count_constraint(bin_count, k, config));
 
     // Constraint 2: Each prebin must be in exactly one final bin
     for p in 0..n {
@@ -211,12 +226,12 @@ fn solve_with_monotonicity(
     // If z[i][j] = 1, then no other bin can use prebins i..=j
     // This is implicitly handled by coverage constraint
 
-    // Constraint 4: Monotonicity (if specified)
-    // For each pair of adjacent potential bins, forbid violating pairs
-    if monotonicity != MonotonicityConstraint::None
-        && monotonicity != MonotonicityConstraint::Peak
-        && monotonicity != MonotonicityConstraint::Valley
-    {
+    // Constraint 4: Monotonicity and minimum WoE difference (if specified)
+    // For each pair of adjacent potential bins, forbid violating pairs. A peak
+    // or valley turns at the prebin with the extreme WoE.
+    let woes: Vec<f64> = prebins.iter().map(|b| b.woe).collect();
+    let turn = turning_point(&woes, monotonicity);
+    if monotonicity != MonotonicityConstraint::None || config.min_woe_delta > 0.0 {
         for i1 in 0..n {
             for j1 in i1..n {
                 let i2 = j1 + 1;
@@ -232,13 +247,14 @@ fn solve_with_monotonicity(
                         let bin1 = get_precomputed_bin(iv_matrix, i1, j1);
                         let bin2 = get_precomputed_bin(iv_matrix, i2, j2);
 
-                        let violates = match monotonicity {
-                            MonotonicityConstraint::Ascending => bin1.woe > bin2.woe,
-                            MonotonicityConstraint::Descending => bin1.woe < bin2.woe,
-                            _ => false,
-                        };
-
-                        if violates {
+                        if violates_adjacent(
+                            monotonicity,
+                            turn,
+                            j1,
+                            bin1.woe,
+                            bin2.woe,
+                            config.min_woe_delta,
+                        ) {
                             // These two bins cannot both be selected
                             let sum: Expression = v1 + v2;
                             problem = problem.with(constraint!(sum <= 1.0));
@@ -250,7 +266,14 @@ fn solve_with_monotonicity(
     }
 
     // Solve the problem
-    let solution = problem.solve().context("Failed to solve MIP model")?;
+    let solve_start = Instant::now();
+    let solved = problem.solve();
+    let timed_out = hit_time_limit(config, solve_start);
+    let solution = match solved {
+        Ok(solution) => solution,
+        Err(_) if timed_out => return Err(SolverTimedOut.into()),
+        Err(err) => return Err(err).context("Failed to solve MIP model"),
+    };
 
     // Extract solution
     let mut bin_boundaries: Vec<(usize, usize)> = Vec::new();
@@ -268,79 +291,120 @@ fn solve_with_monotonicity(
     // Sort by start index
     bin_boundaries.sort_by_key(|(start, _)| *start);
 
+    // At the time limit HiGHS may hand back an incomplete incumbent
+    if !covers_all_prebins(&bin_boundaries, n) {
+        if timed_out {
+            return Err(SolverTimedOut.into());
+        }
+        bail!("MIP solution does not cover every prebin");
+    }
+
     // Calculate total IV
     let total_iv: f64 = bin_boundaries
         .iter()
         .map(|(start, end)| get_precomputed_bin(iv_matrix, *start, *end).iv)
         .sum();
 
+    // good_lp doesn't expose the MIP gap, so a timed-out solve is measured
+    // against an upper bound instead
+    let (status, gap) = solve_status(timed_out, total_iv, || {
+        iv_upper_bound(iv_matrix, k, config, total_samples)
+    });
+
     Ok(SolverResult {
         bin_boundaries,
         total_iv,
         solve_time_ms: start_time.elapsed().as_millis() as u64,
-        gap: 0.0, // good_lp doesn't expose gap directly
+        gap,
         monotonicity_applied: monotonicity,
+        status,
     })
 }
 
-/// Reconstruct WoeBin vector from solver solution
-pub fn reconstruct_bins(
-    prebins: &[WoeBin],
-    result: &SolverResult,
-    total_events: f64,
-    total_non_events: f64,
-    total_samples: f64,
-) -> Vec<WoeBin> {
-    result
-        .bin_boundaries
-        .iter()
-        .map(|(start, end)| {
-            // Merge prebins[start..=end] into one bin
-            let mut events = 0.0;
-            let mut non_events = 0.0;
-            let mut count = 0.0;
-
-            for prebin in &prebins[*start..=*end] {
-                events += prebin.events;
-                non_events += prebin.non_events;
-                count += prebin.count;
-            }
+/// Whether a solve started at `solve_start` ran until the time limit
+fn hit_time_limit(config: &SolverConfig, solve_start: Instant) -> bool {
+    config.timeout_seconds > 0
+        && solve_start.elapsed().as_secs_f64() >= config.timeout_seconds as f64 * TIME_LIMIT_SHARE
+}
 
-            let (woe, iv) = calculate_woe_iv(events, non_events, total_events, total_non_events);
-            let event_rate = if count > 0.0 { events / count } else { 0.0 };
-            let population_pct = if total_samples > 0.0 {
-                count / total_samples * 100.0
-            } else {
-                0.0
-            };
+/// Whether `bins`, sorted by start, cover prebins `0..n` once each
+fn covers_all_prebins(bins: &[(usize, usize)], n: usize) -> bool {
+    let mut next = 0;
+    for &(start, end) in bins {
+        if start != next || end < start {
+            return false;
+        }
+        next = end + 1;
+    }
+    next == n
+}
 
-            WoeBin {
-                lower_bound: prebins[*start].lower_bound,
-                upper_bound: prebins[*end].upper_bound,
-                events,
-                non_events,
-                woe,
-                iv_contribution: iv,
-                count,
-                population_pct,
-                event_rate,
+/// Status and gap of a solution with `total_iv`; `upper_bound` is only
+/// evaluated for a solve stopped by the time limit
+fn solve_status(
+    timed_out: bool,
+    total_iv: f64,
+    upper_bound: impl FnOnce() -> f64,
+) -> (SolverStatus, f64) {
+    if !timed_out {
+        return (SolverStatus::Optimal, 0.0);
+    }
+    let bound = upper_bound();
+    let gap = if bound > 0.0 {
+        ((bound - total_iv) / bound).max(0.0)
+    } else {
+        0.0
+    };
+    (SolverStatus::FeasibleGap { gap }, gap)
+}
+
+/// Highest total IV of any partition of the prebins into bins the model
+/// allows (`SolverConfig::allows_bin`), found by dynamic programming over the
+/// prebin prefixes. Ignoring the pairwise constraints (monotonicity, minimum
+/// WoE difference) makes it an upper bound on the MIP objective.
+fn iv_upper_bound(
+    iv_matrix: &[Vec<PrecomputedBin>],
+    k: usize,
+    config: &SolverConfig,
+    total_samples: f64,
+) -> f64 {
+    let n = iv_matrix.len();
+    // best[m][p]: highest IV of prebins 0..p in exactly m bins
+    let mut best = vec![vec![f64::NEG_INFINITY; n + 1]; k + 1];
+    best[0][0] = 0.0;
+    for m in 1..=k {
+        for p in 1..=n {
+            for start in 0..p {
+                let prev = best[m - 1][start];
+                let bin = get_precomputed_bin(iv_matrix, start, p - 1);
+                if prev.is_finite() && config.allows_bin(bin.count, total_samples) {
+                    best[m][p] = best[m][p].max(prev + bin.iv);
+                }
             }
-        })
-        .collect()
+        }
+    }
+    if config.limits_bin_count() {
+        (1..=k)
+            .map(|m| best[m][n])
+            .fold(f64::NEG_INFINITY, f64::max)
+    } else {
+        best[k][n]
+    }
 }
 
-/// Calculate WoE and IV
-fn calculate_woe_iv(
-    events: f64,
-    non_events: f64,
-    total_events: f64,
-    total_non_events: f64,
-) -> (f64, f64) {
-    let dist_events = (events + SMOOTHING) / (total_events + SMOOTHING);
-    let dist_non_events = (non_events + SMOOTHING) / (total_non_events + SMOOTHING);
-    let woe = (dist_events / dist_non_events).ln();
-    let iv = (dist_events - dist_non_events) * woe;
-    (woe, iv)
+/// The bin count constraint: exactly `k` bins, or at most `k` when bin share
+/// limits or a minimum WoE difference are set. Splitting a bin never lowers
+/// IV, so the relaxed model still uses `k` bins whenever the limits allow it.
+fn bin_count_constraint(
+    bin_count: Expression,
+    k: usize,
+    config: &SolverConfig,
+) -> good_lp::Constraint {
+    if config.limits_bin_count() {
+        constraint!(bin_count <= k as f64)
+    } else {
+        constraint!(bin_count == k as f64)
+    }
 }
 
 /// Solve optimal binning for categorical features
@@ -351,13 +415,13 @@ pub fn solve_categorical_binning(
     config: &SolverConfig,
     total_events: f64,
     total_non_events: f64,
-    _total_samples: f64,
+    total_samples: f64,
 ) -> Result<SolverResult> {
     let start_time = Instant::now();
     let n = sorted_categories.len();
 
     // Edge case: if categories <= target_bins, no merging needed
-    if n <= target_bins {
+    if n <= target_bins && !config.limits_bin_count() {
         let boundaries: Vec<(usize, usize)> = (0..n).map(|i| (i, i)).collect();
         let total_iv: f64 = sorted_categories
             .iter()
@@ -373,6 +437,7 @@ pub fn solve_categorical_binning(
             solve_time_ms: start_time.elapsed().as_millis() as u64,
             gap: 0.0,
             monotonicity_applied: MonotonicityConstraint::None,
+            status: SolverStatus::Optimal,
         });
     }
 
@@ -391,7 +456,7 @@ pub fn solve_categorical_binning(
         let mut row = Vec::with_capacity(n - i);
         for j in i..n {
             let bin = get_precomputed_bin(&iv_matrix, i, j);
-            if bin.count >= config.min_bin_samples as f64 {
+            if config.allows_bin(bin.count, total_samples) {
                 row.push(Some(vars.add(variable().binary())));
             } else {
                 row.push(None);
@@ -412,17 +477,19 @@ pub fn solve_categorical_binning(
     }
 
     let objective: Expression = objective_terms.into_iter().sum();
-    let mut problem = vars
-        .maximise(objective)
-        .using(default_solver)
-        .set_time_limit(config.timeout_seconds as f64)
+    let mut problem = vars.maximise(objective).using(default_solver);
+    // A zero timeout leaves HiGHS without a time limit
+    if config.timeout_seconds > 0 {
+        problem = problem.set_time_limit(config.timeout_seconds as f64);
+    }
+    let mut problem = problem
         // gap_tolerance is pre-validated to [0.0, 1.0] by CLI
         .set_mip_rel_gap(config.gap_tolerance as f32)
         .expect("gap_tolerance already validated");
 
-    // Constraint: Exactly K bins
+    // Constraint: Exactly K bins (at most K under bin limits)
     let bin_count: Expression = z.iter().flat_map(|row| row.iter().filter_map(|v| *v)).sum();
-    problem = problem.with(constraint!(bin_count == k as f64));
+    problem = problem.with(bin_count_constraint(bin_count, k, config));
 
     // Constraint: Coverage
     for p in 0..n {
@@ -440,10 +507,34 @@ pub fn solve_categorical_binning(
         }
     }
 
+    // Constraint: Adjacent groups differ in WoE by at least min_woe_delta
+    if config.min_woe_delta > 0.0 {
+        for i1 in 0..n {
+            for j1 in i1..n.saturating_sub(1) {
+                let i2 = j1 + 1;
+                for j2 in i2..n {
+                    if let (Some(v1), Some(v2)) = (z[i1][j1 - i1], z[i2][j2 - i2]) {
+                        let bin1 = get_precomputed_bin(&iv_matrix, i1, j1);
+                        let bin2 = get_precomputed_bin(&iv_matrix, i2, j2);
+                        if (bin1.woe - bin2.woe).abs() < config.min_woe_delta {
+                            let sum: Expression = v1 + v2;
+                            problem = problem.with(constraint!(sum <= 1.0));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     // Solve
-    let solution = problem
-        .solve()
-        .context("Failed to solve categorical MIP model")?;
+    let solve_start = Instant::now();
+    let solved = problem.solve();
+    let timed_out = hit_time_limit(config, solve_start);
+    let solution = match solved {
+        Ok(solution) => solution,
+        Err(_) if timed_out => return Err(SolverTimedOut.into()),
+        Err(err) => return Err(err).context("Failed to solve categorical MIP model"),
+    };
 
     // Extract solution
     let mut bin_boundaries: Vec<(usize, usize)> = Vec::new();
@@ -460,17 +551,29 @@ pub fn solve_categorical_binning(
 
     bin_boundaries.sort_by_key(|(start, _)| *start);
 
+    if !covers_all_prebins(&bin_boundaries, n) {
+        if timed_out {
+            return Err(SolverTimedOut.into());
+        }
+        bail!("Categorical MIP solution does not cover every category");
+    }
+
     let total_iv: f64 = bin_boundaries
         .iter()
         .map(|(start, end)| get_precomputed_bin(&iv_matrix, *start, *end).iv)
         .sum();
 
+    let (status, gap) = solve_status(timed_out, total_iv, || {
+        iv_upper_bound(&iv_matrix, k, config, total_samples)
+    });
+
     Ok(SolverResult {
         bin_boundaries,
         total_iv,
         solve_time_ms: start_time.elapsed().as_millis() as u64,
-        gap: 0.0,
+        gap,
         monotonicity_applied: config.monotonicity,
+        status,
     })
 }
 
@@ -490,6 +593,8 @@ mod tests {
                 count: 20.0,
                 population_pct: 33.3,
                 event_rate: 0.25,
+                chi_square: None,
+                chi_square_p_value: None,
             },
             WoeBin {
                 lower_bound: 10.0,
@@ -501,6 +606,8 @@ mod tests {
                 count: 20.0,
                 population_pct: 33.3,
                 event_rate: 0.5,
+                chi_square: None,
+                chi_square_p_value: None,
             },
             WoeBin {
                 lower_bound: 20.0,
@@ -512,6 +619,8 @@ mod tests {
                 count: 20.0,
                 population_pct: 33.3,
                 event_rate: 0.75,
+                chi_square: None,
+                chi_square_p_value: None,
             },
         ]
     }
@@ -561,30 +670,85 @@ mod tests {
     }
 
     #[test]
-    fn test_reconstruct_bins() {
+    fn test_min_bin_pct_can_force_fewer_bins() {
         let prebins = create_test_prebins();
-        let result = SolverResult {
-            bin_boundaries: vec![(0, 1), (2, 2)],
-            total_iv: 0.1,
-            solve_time_ms: 10,
-            gap: 0.0,
-            monotonicity_applied: MonotonicityConstraint::None,
+        // Each prebin holds a third of the rows and any two hold two thirds,
+        // so only the single merged bin clears 40%
+        let config = SolverConfig {
+            min_bin_pct: 40.0,
+            ..SolverConfig::default()
+        };
+
+        let res = solve_numeric_binning(&prebins, 3, &config, 30.0, 30.0, 60.0).unwrap();
+        assert_eq!(res.bin_boundaries, [(0, 2)]);
+    }
+
+    #[test]
+    fn test_max_bin_pct_limits_merging() {
+        let prebins = create_test_prebins();
+        let config = SolverConfig {
+            max_bin_pct: 70.0,
+            ..SolverConfig::default()
         };
 
-        let bins = reconstruct_bins(&prebins, &result, 30.0, 30.0, 60.0);
+        let res = solve_numeric_binning(&prebins, 2, &config, 30.0, 30.0, 60.0).unwrap();
+        assert_eq!(res.bin_boundaries.len(), 2);
+
+        // One bin would hold every row
+        assert!(solve_numeric_binning(&prebins, 1, &config, 30.0, 30.0, 60.0).is_err());
+    }
+
+    #[test]
+    fn test_covers_all_prebins() {
+        assert!(covers_all_prebins(&[(0, 1), (2, 2)], 3));
+        assert!(!covers_all_prebins(&[(0, 0), (2, 2)], 3));
+        assert!(!covers_all_prebins(&[(0, 1), (1, 2)], 3));
+        assert!(!covers_all_prebins(&[(0, 1)], 3));
+    }
+
+    #[test]
+    fn test_iv_upper_bound_is_best_partition() {
+        let prebins = create_test_prebins();
+        let matrix = precompute_iv_matrix(&prebins, 30.0, 30.0);
+        let config = SolverConfig::default();
+        let iv = |i, j| get_precomputed_bin(&matrix, i, j).iv;
+
+        assert!((iv_upper_bound(&matrix, 1, &config, 60.0) - iv(0, 2)).abs() < 1e-12);
+        let two = (iv(0, 0) + iv(1, 2)).max(iv(0, 1) + iv(2, 2));
+        assert!((iv_upper_bound(&matrix, 2, &config, 60.0) - two).abs() < 1e-12);
+        let three = iv(0, 0) + iv(1, 1) + iv(2, 2);
+        assert!((iv_upper_bound(&matrix, 3, &config, 60.0) - three).abs() < 1e-12);
+
+        // With share limits the bound takes the best of up to k bins that fit
+        let limited = SolverConfig {
+            min_bin_pct: 40.0,
+            ..SolverConfig::default()
+        };
+        assert!((iv_upper_bound(&matrix, 3, &limited, 60.0) - iv(0, 2)).abs() < 1e-12);
+    }
 
-        assert_eq!(bins.len(), 2);
+    #[test]
+    fn test_solve_status_measures_gap_only_at_time_limit() {
+        assert_eq!(
+            solve_status(false, 0.4, || unreachable!()),
+            (SolverStatus::Optimal, 0.0)
+        );
+        let (status, gap) = solve_status(true, 0.4, || 0.5);
+        assert!((gap - 0.2).abs() < 1e-12);
+        assert_eq!(status, SolverStatus::FeasibleGap { gap });
+    }
 
-        // First bin merges prebins 0 and 1
-        assert_eq!(bins[0].lower_bound, 0.0);
-        assert_eq!(bins[0].upper_bound, 20.0);
-        assert_eq!(bins[0].events, 15.0);
-        assert_eq!(bins[0].non_events, 25.0);
+    #[test]
+    fn test_min_woe_delta_merges_close_neighbours() {
+        let prebins = create_test_prebins();
+        // Adjacent prebins are 0.69 apart; merging either pair leaves two
+        // bins about 1.19 apart
+        let config = SolverConfig {
+            min_woe_delta: 1.0,
+            ..SolverConfig::default()
+        };
 
-        // Second bin is just prebin 2
-        assert_eq!(bins[1].lower_bound, 20.0);
-        assert_eq!(bins[1].upper_bound, 30.0);
-        assert_eq!(bins[1].events, 15.0);
-        assert_eq!(bins[1].non_events, 5.0);
+        let res = solve_numeric_binning(&prebins, 3, &config, 30.0, 30.0, 60.0).unwrap();
+        assert_eq!(res.bin_boundaries.len(), 2);
     }
 }
@@ -1,16 +1,30 @@
 //! Monotonicity constraints for optimal binning
 //!
 //! Defines the types of WoE monotonicity patterns that can be enforced
-//! during solver-based binning.
+//! during solver-based binning, and the prebin trend tests `auto` uses to
+//! pick the patterns worth solving for.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+
+/// Trend a prebin WoE sequence needs on each side of its extreme for `auto`
+/// to try a peak or valley there
+const MIN_SIDE_TREND: f64 = 0.5;
+
+/// Prebins needed on each side of a turning point, the turning prebin
+/// included: at least this many, and at least [`MIN_SIDE_SHARE`] of them
+const MIN_SIDE_PREBINS: usize = 3;
+
+/// Share of the prebins needed on each side of a turning point, so that a
+/// noisy first or last prebin does not pass for one
+const MIN_SIDE_SHARE: f64 = 0.25;
 
 /// Monotonicity constraint for WoE pattern in binning
 ///
 /// These constraints ensure the Weight of Evidence follows a specific
 /// pattern across bins, which is important for credit scoring and
 /// regulatory compliance.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum MonotonicityConstraint {
     /// No monotonicity constraint - WoE can vary freely
     #[default]
@@ -23,7 +37,8 @@ pub enum MonotonicityConstraint {
     Peak,
     /// WoE decreases then increases (single valley pattern)
     Valley,
-    /// Automatically detect the best monotonicity pattern
+    /// Solve for ascending and descending (plus peak or valley when the
+    /// prebins turn) and keep the pattern with the highest IV
     Auto,
 }
 
@@ -59,6 +74,112 @@ impl std::str::FromStr for MonotonicityConstraint {
     }
 }
 
+/// Kendall-style trend of `values` against their position: the share of
+/// increasing minus decreasing pairs, 1 for a strictly increasing sequence
+/// and -1 for a strictly decreasing one
+pub(super) fn trend(values: &[f64]) -> f64 {
+    let mut score = 0i64;
+    let mut pairs = 0i64;
+    for (i, a) in values.iter().enumerate() {
+        for b in &values[i + 1..] {
+            pairs += 1;
+            if b > a {
+                score += 1;
+            } else if b < a {
+                score -= 1;
+            }
+        }
+    }
+    if pairs == 0 {
+        0.0
+    } else {
+        score as f64 / pairs as f64
+    }
+}
+
+/// Prebin a peak or valley turns at: the one with the highest (peak) or
+/// lowest (valley) WoE. The other patterns have none.
+pub(super) fn turning_point(woes: &[f64], constraint: MonotonicityConstraint) -> Option<usize> {
+    let extreme = |better: fn(f64, f64) -> bool| {
+        woes.iter()
+            .enumerate()
+            .fold(None, |best: Option<(usize, f64)>, (i, &woe)| match best {
+                Some((_, b)) if !better(woe, b) => best,
+                _ => Some((i, woe)),
+            })
+            .map(|(i, _)| i)
+    };
+    match constraint {
+        MonotonicityConstraint::Peak => extreme(|a, b| a > b),
+        MonotonicityConstraint::Valley => extreme(|a, b| a < b),
+        _ => None,
+    }
+}
+
+/// Patterns `auto` solves for, given the WoE of the prebins: always ascending
+/// and descending, plus a peak (valley) when the WoE clearly rises (falls) up
+/// to its extreme and falls (rises) after it
+pub(super) fn auto_candidates(woes: &[f64]) -> Vec<MonotonicityConstraint> {
+    let mut candidates = vec![
+        MonotonicityConstraint::Ascending,
+        MonotonicityConstraint::Descending,
+    ];
+    for (pattern, sign) in [
+        (MonotonicityConstraint::Peak, 1.0),
+        (MonotonicityConstraint::Valley, -1.0),
+    ] {
+        let Some(turn) = turning_point(woes, pattern) else {
+            continue;
+        };
+        let min_side = MIN_SIDE_PREBINS.max((woes.len() as f64 * MIN_SIDE_SHARE).ceil() as usize);
+        if turn + 1 < min_side || woes.len() - turn < min_side {
+            continue;
+        }
+        let rises = sign * trend(&woes[..=turn]) >= MIN_SIDE_TREND;
+        let falls = -sign * trend(&woes[turn..]) >= MIN_SIDE_TREND;
+        if rises && falls {
+            candidates.push(pattern);
+        }
+    }
+    candidates
+}
+
+/// Direction the WoE must take from a bin ending at prebin `first_end` to the
+/// next bin: `Some(true)` for a rise (or tie), `Some(false)` for a fall, `None`
+/// when unconstrained. The bin holding the `turn` of a peak or valley is its
+/// extreme: bins rise (fall) up to it and fall (rise) from it on.
+pub(super) fn required_direction(
+    constraint: MonotonicityConstraint,
+    turn: Option<usize>,
+    first_end: usize,
+) -> Option<bool> {
+    match constraint {
+        MonotonicityConstraint::Ascending => Some(true),
+        MonotonicityConstraint::Descending => Some(false),
+        MonotonicityConstraint::Peak => turn.map(|t| first_end < t),
+        MonotonicityConstraint::Valley => turn.map(|t| first_end >= t),
+        MonotonicityConstraint::None | MonotonicityConstraint::Auto => None,
+    }
+}
+
+/// Whether two adjacent bins, the first ending at prebin `first_end`, break
+/// `constraint` or lie closer than `min_woe_delta` in WoE
+pub(super) fn violates_adjacent(
+    constraint: MonotonicityConstraint,
+    turn: Option<usize>,
+    first_end: usize,
+    first_woe: f64,
+    second_woe: f64,
+    min_woe_delta: f64,
+) -> bool {
+    let wrong_way = match required_direction(constraint, turn, first_end) {
+        Some(true) => first_woe > second_woe,
+        Some(false) => first_woe < second_woe,
+        None => false,
+    };
+    wrong_way || (first_woe - second_woe).abs() < min_woe_delta
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,4 +225,59 @@ mod tests {
         assert_eq!(MonotonicityConstraint::Valley.to_string(), "valley");
         assert_eq!(MonotonicityConstraint::Auto.to_string(), "auto");
     }
+
+    #[test]
+    fn test_trend() {
+        assert_eq!(trend(&[1.0, 2.0, 3.0]), 1.0);
+        assert_eq!(trend(&[3.0, 2.0, 1.0]), -1.0);
+        assert_eq!(trend(&[1.0, 1.0]), 0.0);
+        assert_eq!(trend(&[5.0]), 0.0);
+    }
+
+    #[test]
+    fn test_auto_candidates_detect_turning_prebins() {
+        let monotonic = [-1.0, -0.5, 0.0, 0.2, 0.6, 0.9];
+        assert_eq!(
+            auto_candidates(&monotonic),
+            [
+                MonotonicityConstraint::Ascending,
+                MonotonicityConstraint::Descending
+            ]
+        );
+
+        let peak = [-1.0, -0.2, 0.3, 0.8, 0.4, -0.1, -0.6];
+        assert_eq!(turning_point(&peak, MonotonicityConstraint::Peak), Some(3));
+        assert!(auto_candidates(&peak).contains(&MonotonicityConstraint::Peak));
+        assert!(!auto_candidates(&peak).contains(&MonotonicityConstraint::Valley));
+
+        let valley: Vec<f64> = peak.iter().map(|w| -w).collect();
+        assert!(auto_candidates(&valley).contains(&MonotonicityConstraint::Valley));
+
+        // An extreme one prebin from the edge is not a turn
+        let late_peak = [-1.0, -0.5, 0.0, 0.5, 1.0, 0.8];
+        assert_eq!(auto_candidates(&late_peak).len(), 2);
+    }
+
+    #[test]
+    fn test_required_direction() {
+        use MonotonicityConstraint as M;
+        assert_eq!(required_direction(M::Ascending, None, 3), Some(true));
+        assert_eq!(required_direction(M::Descending, None, 3), Some(false));
+        assert_eq!(required_direction(M::None, None, 3), None);
+
+        // Peak turning at prebin 4: rise into the bin holding it, fall after it
+        assert_eq!(required_direction(M::Peak, Some(4), 3), Some(true));
+        assert_eq!(required_direction(M::Peak, Some(4), 4), Some(false));
+        assert_eq!(required_direction(M::Peak, Some(4), 6), Some(false));
+        assert_eq!(required_direction(M::Valley, Some(4), 3), Some(false));
+        assert_eq!(required_direction(M::Valley, Some(4), 5), Some(true));
+    }
+
+    #[test]
+    fn test_monotonicity_serde_lowercase() {
+        let json = serde_json::to_string(&MonotonicityConstraint::Descending).unwrap();
+        assert_eq!(json, "\"descending\"");
+        let parsed: MonotonicityConstraint = serde_json::from_str("\"peak\"").unwrap();
+        assert_eq!(parsed, MonotonicityConstraint::Peak);
+    }
 }
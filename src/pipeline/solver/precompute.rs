@@ -173,6 +173,8 @@ mod tests {
                 count: 20.0,
                 population_pct: 20.0,
                 event_rate: 0.25,
+                chi_square: None,
+                chi_square_p_value: None,
             },
             WoeBin {
                 lower_bound: 10.0,
@@ -184,6 +186,8 @@ mod tests {
                 count: 20.0,
                 population_pct: 20.0,
                 event_rate: 0.5,
+                chi_square: None,
+                chi_square_p_value: None,
             },
             WoeBin {
                 lower_bound: 20.0,
@@ -195,6 +199,8 @@ mod tests {
                 count: 20.0,
                 population_pct: 20.0,
                 event_rate: 0.75,
+                chi_square: None,
+                chi_square_p_value: None,
             },
         ]
     }
@@ -0,0 +1,126 @@
+//! Turning a solver solution back into WoE bins
+//!
+//! Kept apart from the MIP model so it compiles without the `solver` feature.
+
+use super::super::iv::WoeBin;
+use super::SolverResult;
+
+/// Smoothing constant for WoE calculation
+const SMOOTHING: f64 = 0.5;
+
+/// Reconstruct WoeBin vector from solver solution
+pub fn reconstruct_bins(
+    prebins: &[WoeBin],
+    result: &SolverResult,
+    total_events: f64,
+    total_non_events: f64,
+    total_samples: f64,
+) -> Vec<WoeBin> {
+    result
+        .bin_boundaries
+        .iter()
+        .map(|(start, end)| {
+            // Merge prebins[start..=end] into one bin
+            let mut events = 0.0;
+            let mut non_events = 0.0;
+            let mut count = 0.0;
+
+            for prebin in &prebins[*start..=*end] {
+                events += prebin.events;
+                non_events += prebin.non_events;
+                count += prebin.count;
+            }
+
+            let (woe, iv) = calculate_woe_iv(events, non_events, total_events, total_non_events);
+            let event_rate = if count > 0.0 { events / count } else { 0.0 };
+            let population_pct = if total_samples > 0.0 {
+                count / total_samples * 100.0
+            } else {
+                0.0
+            };
+
+            WoeBin {
+                lower_bound: prebins[*start].lower_bound,
+                upper_bound: prebins[*end].upper_bound,
+                events,
+                non_events,
+                woe,
+                iv_contribution: iv,
+                count,
+                population_pct,
+                event_rate,
+                chi_square: None,
+                chi_square_p_value: None,
+            }
+        })
+        .collect()
+}
+
+/// Calculate WoE and IV
+pub(super) fn calculate_woe_iv(
+    events: f64,
+    non_events: f64,
+    total_events: f64,
+    total_non_events: f64,
+) -> (f64, f64) {
+    let dist_events = (events + SMOOTHING) / (total_events + SMOOTHING);
+    let dist_non_events = (non_events + SMOOTHING) / (total_non_events + SMOOTHING);
+    let woe = (dist_events / dist_non_events).ln();
+    let iv = (dist_events - dist_non_events) * woe;
+    (woe, iv)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{MonotonicityConstraint, SolverStatus};
+    use super::*;
+
+    fn prebin(lower_bound: f64, upper_bound: f64, events: f64, non_events: f64) -> WoeBin {
+        WoeBin {
+            lower_bound,
+            upper_bound,
+            events,
+            non_events,
+            woe: 0.0,
+            iv_contribution: 0.0,
+            count: events + non_events,
+            population_pct: 33.3,
+            event_rate: events / (events + non_events),
+            chi_square: None,
+            chi_square_p_value: None,
+        }
+    }
+
+    #[test]
+    fn test_reconstruct_bins() {
+        let prebins = vec![
+            prebin(0.0, 10.0, 5.0, 15.0),
+            prebin(10.0, 20.0, 10.0, 10.0),
+            prebin(20.0, 30.0, 15.0, 5.0),
+        ];
+        let result = SolverResult {
+            bin_boundaries: vec![(0, 1), (2, 2)],
+            total_iv: 0.1,
+            solve_time_ms: 10,
+            gap: 0.0,
+            monotonicity_applied: MonotonicityConstraint::None,
+            status: SolverStatus::Optimal,
+        };
+
+        let bins = reconstruct_bins(&prebins, &result, 30.0, 30.0, 60.0);
+
+        assert_eq!(bins.len(), 2);
+
+        // First bin merges prebins 0 and 1
+        assert_eq!(bins[0].lower_bound, 0.0);
+        assert_eq!(bins[0].upper_bound, 20.0);
+        assert_eq!(bins[0].events, 15.0);
+        assert_eq!(bins[0].non_events, 25.0);
+
+        // Second bin is just prebin 2
+        assert_eq!(bins[1].lower_bound, 20.0);
+        assert_eq!(bins[1].upper_bound, 30.0);
+        assert_eq!(bins[1].events, 15.0);
+        assert_eq!(bins[1].non_events, 5.0);
+    }
+}
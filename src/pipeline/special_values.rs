@@ -0,0 +1,108 @@
+//! Special (reserved) values of numeric features (`--special-values`)
+//!
+//! Credit bureau data codes the reason a value is unknown with sentinels such
+//! as -999997 ("no record") or -999998 ("not applicable"). Binned as numbers
+//! they fall into the lowest bin and blend with genuine low values, so the
+//! numeric binning in [`crate::pipeline::iv`] sets the listed values aside and
+//! gives each one a bin of its own, next to the missing bin.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use serde::Serialize;
+
+/// Values kept out of the numeric bins, for every feature or per feature
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SpecialValues {
+    /// Special in every numeric feature
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub global: Vec<f64>,
+    /// Special in one feature only, on top of `global`
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub per_feature: BTreeMap<String, Vec<f64>>,
+}
+
+impl SpecialValues {
+    /// Parse `VALUE` (every feature) and `FEATURE:VALUE` entries.
+    ///
+    /// The value follows the last `:`, so feature names may contain colons.
+    /// Values must be finite numbers; NaN is always treated as missing.
+    pub fn parse<S: AsRef<str>>(entries: &[S]) -> Result<Self> {
+        let mut special = SpecialValues::default();
+        for entry in entries {
+            let entry = entry.as_ref().trim();
+            if entry.is_empty() {
+                continue;
+            }
+            let (feature, value) = match entry.rsplit_once(':') {
+                Some((feature, value)) => (Some(feature.trim()), value.trim()),
+                None => (None, entry),
+            };
+            let value = match value.parse::<f64>() {
+                Ok(value) if value.is_finite() => value,
+                _ => bail!(
+                    "Invalid special value '{}': expected a finite number or FEATURE:NUMBER",
+                    entry
+                ),
+            };
+            let values = match feature {
+                Some("") => bail!("Invalid special value '{}': empty feature name", entry),
+                Some(feature) => special.per_feature.entry(feature.to_string()).or_default(),
+                None => &mut special.global,
+            };
+            if !values.contains(&value) {
+                values.push(value);
+            }
+        }
+        Ok(special)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.global.is_empty() && self.per_feature.is_empty()
+    }
+
+    /// The special values of `feature`: the global ones, then its own
+    pub fn for_feature(&self, feature: &str) -> Vec<f64> {
+        let mut values = self.global.clone();
+        for &value in self.per_feature.get(feature).into_iter().flatten() {
+            if !values.contains(&value) {
+                values.push(value);
+            }
+        }
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_global_and_per_feature_values() {
+        let special =
+            SpecialValues::parse(&["-999997", "-999998", "age:-1", "age:-999997", " -999997 "])
+                .unwrap();
+        assert_eq!(special.global, [-999997.0, -999998.0]);
+        assert_eq!(special.per_feature["age"], [-1.0, -999997.0]);
+
+        assert_eq!(special.for_feature("age"), [-999997.0, -999998.0, -1.0]);
+        assert_eq!(special.for_feature("income"), [-999997.0, -999998.0]);
+    }
+
+    #[test]
+    fn test_parse_feature_names_with_colons() {
+        let special = SpecialValues::parse(&["bureau:score:9999"]).unwrap();
+        assert_eq!(special.per_feature["bureau:score"], [9999.0]);
+        assert!(special.global.is_empty());
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_values() {
+        assert!(SpecialValues::parse(&["abc"]).is_err());
+        assert!(SpecialValues::parse(&["age:"]).is_err());
+        assert!(SpecialValues::parse(&[":5"]).is_err());
+        assert!(SpecialValues::parse(&["NaN"]).is_err());
+        assert!(SpecialValues::parse(&["inf"]).is_err());
+        assert!(SpecialValues::parse::<&str>(&[]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,67 @@
+//! Train/holdout split awareness
+//!
+//! Datasets often arrive with the modelling split already assigned, e.g. a
+//! `sample` column holding `TRAIN` / `TEST`. Screening statistics computed on
+//! every row let the holdout influence which features survive, so with a split
+//! column the missing, Gini/IV and correlation stages see only the training
+//! rows while the resulting drops are applied to the whole dataset.
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+
+/// Build a boolean mask that is true for rows whose `split_column` value equals
+/// `train_value`. Values are compared as strings, so numeric indicators such as
+/// `1` work too; nulls never match.
+pub fn create_train_mask(
+    df: &DataFrame,
+    split_column: &str,
+    train_value: &str,
+) -> Result<BooleanChunked> {
+    let column = df
+        .column(split_column)
+        .map_err(|_| anyhow::anyhow!("Split column '{}' not found in dataset", split_column))?;
+    let values = column
+        .as_materialized_series()
+        .cast(&DataType::String)
+        .with_context(|| format!("Failed to read split column '{}'", split_column))?;
+
+    Ok(values
+        .str()?
+        .iter()
+        .map(|v| Some(v == Some(train_value)))
+        .collect())
+}
+
+/// Select the training rows for screening.
+///
+/// Returns the training rows with `split_column` removed (it is constant there
+/// and must not be screened as a feature) together with the matching subset of
+/// `weights`.
+///
+/// # Errors
+/// Fails if the split column is missing or no row carries `train_value`.
+pub fn train_partition(
+    df: &DataFrame,
+    weights: &[f64],
+    split_column: &str,
+    train_value: &str,
+) -> Result<(DataFrame, Vec<f64>)> {
+    let mask = create_train_mask(df, split_column, train_value)?;
+    let train_rows = mask.sum().unwrap_or(0) as usize;
+    if train_rows == 0 {
+        anyhow::bail!(
+            "No rows in split column '{}' have the train value '{}'",
+            split_column,
+            train_value
+        );
+    }
+
+    let train_weights: Vec<f64> = weights
+        .iter()
+        .zip(mask.iter())
+        .filter_map(|(w, keep)| keep.unwrap_or(false).then_some(*w))
+        .collect();
+    let train_df = df.filter(&mask)?.drop(split_column)?;
+
+    Ok((train_df, train_weights))
+}
@@ -0,0 +1,499 @@
+//! SPSS system file reader (`.sav`)
+//!
+//! Reads uncompressed and bytecode-compressed system files in either byte
+//! order. Zlib-compressed files (`.zsav`) are not supported.
+//!
+//! - Numeric variables load as `f64`; those with a date format (`DATE`,
+//!   `ADATE`, `EDATE`, ...) load as `date` and `DATETIME` as `datetime[ms]`.
+//!   Time and duration formats stay numeric (seconds).
+//! - String variables load as `str`, trailing blanks removed. Very long
+//!   strings (over 255 bytes) are joined back from their segments.
+//! - System-missing values, user-missing values declared in the dictionary
+//!   and blank strings load as null.
+//! - Variables take their long names (record 7, subtype 13) when present.
+//!
+//! Text is decoded as UTF-8, falling back to Latin-1 for files written in a
+//! legacy code page.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+
+use super::byte_reader::{decode_text, parse_file_header, read_file, ByteReader};
+
+/// Days from the SPSS epoch (1582-10-14) to 1970-01-01
+const EPOCH_OFFSET_DAYS: i64 = 141_428;
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Width of a very long string segment in the data, and the bytes of it used
+const SEGMENT_WIDTH: usize = 255;
+const SEGMENT_USED_BYTES: usize = 252;
+
+/// Compression codes of bytecode-compressed data
+const CODE_PADDING: u8 = 0;
+const CODE_END_OF_DATA: u8 = 252;
+const CODE_RAW: u8 = 253;
+const CODE_SPACES: u8 = 254;
+const CODE_SYSMIS: u8 = 255;
+
+/// Column names of an SPSS system file, read from its dictionary
+pub fn get_sav_columns(path: &Path) -> Result<Vec<String>> {
+    let dictionary = parse_file_header(path, |data| Dictionary::parse(&mut ByteReader::new(data)))
+        .with_context(|| format!("Failed to read SPSS dictionary: {}", path.display()))?;
+    Ok(dictionary.columns().into_iter().map(|c| c.name).collect())
+}
+
+/// Load an SPSS system file into a DataFrame
+pub fn load_sav(path: &Path) -> Result<DataFrame> {
+    let data = read_file(path)?;
+    let mut reader = ByteReader::new(&data);
+    let dictionary = Dictionary::parse(&mut reader)
+        .with_context(|| format!("Failed to read SPSS dictionary: {}", path.display()))?;
+    let cases = read_cases(&mut reader, &dictionary)
+        .with_context(|| format!("Failed to read SPSS data: {}", path.display()))?;
+
+    let columns = dictionary
+        .columns()
+        .iter()
+        .map(|column| build_column(column, &dictionary, &cases))
+        .collect::<Result<Vec<Column>>>()?;
+    DataFrame::new(columns)
+        .with_context(|| format!("Failed to build DataFrame from {}", path.display()))
+}
+
+/// A variable record (type 2), excluding string continuation records
+struct Variable {
+    short_name: String,
+    /// 0 for numeric, otherwise the string width in bytes
+    width: usize,
+    /// Format type of the print format (e.g. 5 = F, 20 = DATE)
+    format_type: u8,
+    /// First 8-byte element of the variable within a case
+    offset: usize,
+    missing: MissingValues,
+}
+
+/// User-missing values of a variable, as raw 8-byte elements
+#[derive(Default)]
+struct MissingValues {
+    discrete: Vec<[u8; 8]>,
+    range: Option<([u8; 8], [u8; 8])>,
+}
+
+struct Dictionary {
+    big_endian: bool,
+    compressed: bool,
+    bias: f64,
+    /// Number of 8-byte elements per case
+    case_elements: usize,
+    /// Case count from the header; `None` when the writer left it unset
+    case_count: Option<usize>,
+    variables: Vec<Variable>,
+    /// Short name -> long name (record 7, subtype 13)
+    long_names: HashMap<String, String>,
+    /// Short name -> width of very long strings (record 7, subtype 14)
+    very_long_widths: HashMap<String, usize>,
+    sysmis: f64,
+}
+
+/// How a column is read from the case elements
+enum ColumnKind {
+    Numeric {
+        variable: usize,
+    },
+    /// String pieces: (first element, bytes taken from it)
+    Text {
+        variable: usize,
+        pieces: Vec<(usize, usize)>,
+    },
+}
+
+struct ColumnSpec {
+    name: String,
+    kind: ColumnKind,
+}
+
+impl Dictionary {
+    fn parse(reader: &mut ByteReader) -> Result<Self> {
+        let magic = reader.bytes(4)?;
+        match magic {
+            b"$FL2" => {}
+            b"$FL3" => anyhow::bail!("Zlib-compressed SPSS files (.zsav) are not supported"),
+            _ => anyhow::bail!("Not an SPSS system file (missing $FL2 signature)"),
+        }
+        reader.skip(60)?; // product name
+
+        // The layout code is 2 or 3; its byte order is the file's
+        let layout = reader.bytes(4)?;
+        let big_endian = !matches!(layout, [2 | 3, 0, 0, 0]);
+        if big_endian && !matches!(layout, [0, 0, 0, 2 | 3]) {
+            anyhow::bail!("Unrecognised SPSS layout code");
+        }
+        reader.set_big_endian(big_endian);
+
+        let nominal_case_size = reader.i32()?;
+        let compression = reader.i32()?;
+        match compression {
+            0 | 1 => {}
+            2 => anyhow::bail!("Zlib-compressed SPSS files are not supported"),
+            other => anyhow::bail!("Unknown SPSS compression code {}", other),
+        }
+        reader.skip(4)?; // weight index
+        let case_count = reader.i32()?;
+        let bias = reader.f64()?;
+        reader.skip(9 + 8 + 64 + 3)?; // creation date, time, file label, padding
+
+        let mut dictionary = Dictionary {
+            big_endian,
+            compressed: compression == 1,
+            bias,
+            case_elements: 0,
+            case_count: usize::try_from(case_count).ok(),
+            variables: Vec::new(),
+            long_names: HashMap::new(),
+            very_long_widths: HashMap::new(),
+            sysmis: -f64::MAX,
+        };
+
+        loop {
+            match reader.i32()? {
+                2 => dictionary.read_variable(reader)?,
+                3 => skip_value_labels(reader)?,
+                6 => {
+                    let lines = reader.i32()?;
+                    reader.skip(80 * usize::try_from(lines)?)?;
+                }
+                7 => dictionary.read_extension(reader)?,
+                999 => {
+                    reader.skip(4)?;
+                    break;
+                }
+                other => anyhow::bail!("Unknown SPSS record type {}", other),
+            }
+        }
+
+        if nominal_case_size > 0 && nominal_case_size as usize != dictionary.case_elements {
+            anyhow::bail!(
+                "SPSS header declares {} elements per case, dictionary has {}",
+                nominal_case_size,
+                dictionary.case_elements
+            );
+        }
+        Ok(dictionary)
+    }
+
+    fn read_variable(&mut self, reader: &mut ByteReader) -> Result<()> {
+        let kind = reader.i32()?;
+        let has_label = reader.i32()?;
+        let missing_count = reader.i32()?;
+        let print_format = reader.u32()?;
+        reader.skip(4)?; // write format
+        let short_name = decode_text(reader.bytes(8)?).trim_end().to_string();
+        if has_label == 1 {
+            let len = usize::try_from(reader.i32()?)?;
+            reader.skip(len.div_ceil(4) * 4)?;
+        }
+
+        let mut raw_values = Vec::new();
+        for _ in 0..missing_count.unsigned_abs() {
+            raw_values.push(<[u8; 8]>::try_from(reader.bytes(8)?)?);
+        }
+
+        let offset = self.case_elements;
+        self.case_elements += 1;
+        // Continuation records hold the rest of a string wider than 8 bytes
+        if kind == -1 {
+            return Ok(());
+        }
+
+        let missing = match missing_count {
+            -2 => MissingValues {
+                discrete: Vec::new(),
+                range: Some((raw_values[0], raw_values[1])),
+            },
+            -3 => MissingValues {
+                discrete: vec![raw_values[2]],
+                range: Some((raw_values[0], raw_values[1])),
+            },
+            _ => MissingValues {
+                discrete: raw_values,
+                range: None,
+            },
+        };
+        self.variables.push(Variable {
+            short_name,
+            width: usize::try_from(kind).context("Invalid SPSS variable type")?,
+            format_type: (print_format >> 16) as u8,
+            offset,
+            missing,
+        });
+        Ok(())
+    }
+
+    fn read_extension(&mut self, reader: &mut ByteReader) -> Result<()> {
+        let subtype = reader.i32()?;
+        let size = usize::try_from(reader.i32()?)?;
+        let count = usize::try_from(reader.i32()?)?;
+        let start = reader.position();
+        match subtype {
+            // Machine floating-point info: system-missing, highest, lowest
+            4 if size == 8 && count >= 1 => self.sysmis = reader.f64()?,
+            13 => {
+                let text = decode_text(reader.bytes(size * count)?);
+                for entry in text.split('\t') {
+                    if let Some((short, long)) = entry.split_once('=') {
+                        self.long_names.insert(short.to_string(), long.to_string());
+                    }
+                }
+            }
+            14 => {
+                let text = decode_text(reader.bytes(size * count)?);
+                for entry in text.split('\t') {
+                    let entry = entry.trim_matches('\0');
+                    if let Some((short, width)) = entry.split_once('=') {
+                        if let Ok(width) = width.trim_matches('\0').parse() {
+                            self.very_long_widths.insert(short.to_string(), width);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+        reader.seek(start)?;
+        reader.skip(size * count)
+    }
+
+    /// Columns of the file: one per variable, with very long string segments
+    /// joined into the variable they belong to
+    fn columns(&self) -> Vec<ColumnSpec> {
+        let mut columns = Vec::new();
+        let mut index = 0;
+        while index < self.variables.len() {
+            let variable = &self.variables[index];
+            let name = self
+                .long_names
+                .get(&variable.short_name)
+                .cloned()
+                .unwrap_or_else(|| variable.short_name.clone());
+
+            if variable.width == 0 {
+                columns.push(ColumnSpec {
+                    name,
+                    kind: ColumnKind::Numeric { variable: index },
+                });
+                index += 1;
+                continue;
+            }
+
+            let segments = match self.very_long_widths.get(&variable.short_name) {
+                Some(&width) => width.div_ceil(SEGMENT_USED_BYTES).max(1),
+                None => 1,
+            };
+            let last = (index + segments).min(self.variables.len());
+            let pieces = self.variables[index..last]
+                .iter()
+                .enumerate()
+                .map(|(k, segment)| {
+                    let used = if k + 1 < segments && segment.width == SEGMENT_WIDTH {
+                        SEGMENT_USED_BYTES
+                    } else {
+                        segment.width
+                    };
+                    (segment.offset, used)
+                })
+                .collect();
+            columns.push(ColumnSpec {
+                name,
+                kind: ColumnKind::Text {
+                    variable: index,
+                    pieces,
+                },
+            });
+            index = last;
+        }
+        columns
+    }
+
+    fn decode_f64(&self, element: &[u8]) -> f64 {
+        let bytes: [u8; 8] = element.try_into().unwrap_or([0; 8]);
+        if self.big_endian {
+            f64::from_be_bytes(bytes)
+        } else {
+            f64::from_le_bytes(bytes)
+        }
+    }
+
+    fn encode_f64(&self, value: f64) -> [u8; 8] {
+        if self.big_endian {
+            value.to_be_bytes()
+        } else {
+            value.to_le_bytes()
+        }
+    }
+}
+
+/// Skip a value label record (type 3) and the variable index record (type 4)
+/// that follows it
+fn skip_value_labels(reader: &mut ByteReader) -> Result<()> {
+    let count = reader.i32()?;
+    for _ in 0..count {
+        reader.skip(8)?;
+        let len = usize::from(reader.u8()?);
+        // The length byte and label are padded to a multiple of 8
+        reader.skip((len + 1).div_ceil(8) * 8 - 1)?;
+    }
+    if reader.i32()? != 4 {
+        anyhow::bail!("SPSS value labels are not followed by a variable index record");
+    }
+    let variables = usize::try_from(reader.i32()?)?;
+    reader.skip(4 * variables)
+}
+
+/// All cases as consecutive 8-byte elements
+fn read_cases(reader: &mut ByteReader, dictionary: &Dictionary) -> Result<Vec<u8>> {
+    let case_bytes = dictionary.case_elements * 8;
+    let mut cases = Vec::new();
+    if case_bytes == 0 {
+        return Ok(cases);
+    }
+
+    if !dictionary.compressed {
+        while dictionary
+            .case_count
+            .is_none_or(|n| cases.len() / case_bytes < n)
+        {
+            match reader.bytes(case_bytes) {
+                Ok(case) => cases.extend_from_slice(case),
+                Err(_) => break,
+            }
+        }
+        return Ok(cases);
+    }
+
+    let sysmis = dictionary.encode_f64(dictionary.sysmis);
+    let mut commands: &[u8] = &[];
+    let mut element = 0;
+    'cases: while dictionary
+        .case_count
+        .is_none_or(|n| cases.len() / case_bytes < n)
+    {
+        loop {
+            let Some((&code, rest)) = commands.split_first() else {
+                if reader.is_at_end() {
+                    break 'cases;
+                }
+                commands = reader.bytes(8)?;
+                continue;
+            };
+            commands = rest;
+            match code {
+                CODE_PADDING => continue,
+                CODE_END_OF_DATA => break 'cases,
+                CODE_RAW => cases.extend_from_slice(reader.bytes(8)?),
+                CODE_SPACES => cases.extend_from_slice(b"        "),
+                CODE_SYSMIS => cases.extend_from_slice(&sysmis),
+                _ => cases
+                    .extend_from_slice(&dictionary.encode_f64(f64::from(code) - dictionary.bias)),
+            }
+            element += 1;
+            if element == dictionary.case_elements {
+                element = 0;
+                break;
+            }
+        }
+    }
+    // Drop a partial case left by a truncated file
+    cases.truncate(cases.len() / case_bytes * case_bytes);
+    Ok(cases)
+}
+
+/// Date formats: DATE, ADATE, JDATE, MOYR, QYR, WKYR, EDATE, SDATE
+fn is_date_format(format_type: u8) -> bool {
+    matches!(format_type, 20 | 23 | 24 | 28 | 29 | 30 | 38 | 39)
+}
+
+/// Datetime formats: DATETIME, YMDHMS
+fn is_datetime_format(format_type: u8) -> bool {
+    matches!(format_type, 22 | 41)
+}
+
+fn build_column(spec: &ColumnSpec, dictionary: &Dictionary, cases: &[u8]) -> Result<Column> {
+    let case_bytes = dictionary.case_elements * 8;
+    let case_slices = || cases.chunks_exact(case_bytes);
+    let name = PlSmallStr::from(spec.name.as_str());
+
+    let series = match &spec.kind {
+        ColumnKind::Numeric { variable } => {
+            let variable = &dictionary.variables[*variable];
+            let discrete: Vec<f64> = variable
+                .missing
+                .discrete
+                .iter()
+                .map(|raw| dictionary.decode_f64(raw))
+                .collect();
+            let range = variable
+                .missing
+                .range
+                .map(|(low, high)| (dictionary.decode_f64(&low), dictionary.decode_f64(&high)));
+            let values: Vec<Option<f64>> = case_slices()
+                .map(|case| {
+                    let at = variable.offset * 8;
+                    let value = dictionary.decode_f64(&case[at..at + 8]);
+                    let user_missing = discrete.contains(&value)
+                        || range.is_some_and(|(low, high)| value >= low && value <= high);
+                    (value != dictionary.sysmis && !value.is_nan() && !user_missing)
+                        .then_some(value)
+                })
+                .collect();
+
+            if is_date_format(variable.format_type) {
+                let days: Vec<Option<i32>> = values
+                    .iter()
+                    .map(|v| {
+                        v.map(|s| ((s / SECONDS_PER_DAY).floor() as i64 - EPOCH_OFFSET_DAYS) as i32)
+                    })
+                    .collect();
+                Series::new(name, days).cast(&DataType::Date)?
+            } else if is_datetime_format(variable.format_type) {
+                let millis: Vec<Option<i64>> = values
+                    .iter()
+                    .map(|v| {
+                        v.map(|s| {
+                            ((s - EPOCH_OFFSET_DAYS as f64 * SECONDS_PER_DAY) * 1000.0).round()
+                                as i64
+                        })
+                    })
+                    .collect();
+                Series::new(name, millis).cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?
+            } else {
+                Series::new(name, values)
+            }
+        }
+        ColumnKind::Text { variable, pieces } => {
+            let variable = &dictionary.variables[*variable];
+            let missing: Vec<String> = variable
+                .missing
+                .discrete
+                .iter()
+                .map(|raw| decode_text(raw).trim_end().to_string())
+                .collect();
+            let values: Vec<Option<String>> = case_slices()
+                .map(|case| {
+                    let mut bytes = Vec::new();
+                    for &(element, used) in pieces {
+                        let at = element * 8;
+                        bytes.extend_from_slice(&case[at..at + used]);
+                    }
+                    let text = decode_text(&bytes);
+                    let text = text.trim_end();
+                    (!text.is_empty() && !missing.iter().any(|m| m == text))
+                        .then(|| text.to_string())
+                })
+                .collect();
+            Series::new(name, values)
+        }
+    };
+    Ok(series.into_column())
+}
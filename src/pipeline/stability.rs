@@ -0,0 +1,246 @@
+//! IV stability between training and validation rows
+//!
+//! A feature whose Information Value only holds up on the rows it was screened
+//! on makes a brittle model input. With `--iv-stability` the surviving features
+//! are scored again on a validation split, either the rows outside
+//! `--train-value` of the split column or a random `--validation-frac` of the
+//! dataset. [`compare_iv`] then compares each feature's IV on the two halves,
+//! as a value and as a rank, and flags the features that moved too far.
+
+use anyhow::{bail, Result};
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::iv::IvAnalysis;
+
+/// Relative IV change (or rank shift) above which a feature is flagged when
+/// `--iv-stability-threshold` is not given
+pub const DEFAULT_IV_STABILITY_THRESHOLD: f64 = 0.5;
+
+/// Settings of an IV stability check (`--iv-stability`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct IvStabilityCheck {
+    /// Share of the rows held out for validation when there is no split column
+    pub validation_frac: f64,
+    /// Drop unstable features above this threshold (`--iv-stability-threshold`);
+    /// `None` only flags them, at [`DEFAULT_IV_STABILITY_THRESHOLD`]
+    pub drop_threshold: Option<f64>,
+}
+
+impl IvStabilityCheck {
+    /// Threshold the features are flagged at
+    pub fn threshold(&self) -> f64 {
+        self.drop_threshold
+            .unwrap_or(DEFAULT_IV_STABILITY_THRESHOLD)
+    }
+}
+
+/// IV of one feature on the training and validation rows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IvStability {
+    pub feature: String,
+    pub train_iv: f64,
+    pub validation_iv: f64,
+    /// `|train_iv - validation_iv| / max(train_iv, validation_iv)`, from 0 to 1
+    pub relative_change: f64,
+    /// Position by IV among the compared features, 1 for the highest
+    pub train_rank: usize,
+    pub validation_rank: usize,
+    /// Places lost from training to validation rank, as a share of the
+    /// compared features (0 to 1). Gains are not counted: a feature only moves
+    /// up because another one fell.
+    pub rank_shift: f64,
+    /// `relative_change` or `rank_shift` is above the threshold
+    pub unstable: bool,
+}
+
+/// Compare the IV of every feature in `train` with its IV in `validation`.
+///
+/// A feature the validation rows could not score (e.g. constant there) counts
+/// as a validation IV of 0. Results follow the order of `train`.
+pub fn compare_iv(
+    train: &[IvAnalysis],
+    validation: &[IvAnalysis],
+    threshold: f64,
+) -> Vec<IvStability> {
+    let validation_iv = |name: &str| {
+        validation
+            .iter()
+            .find(|a| a.feature_name == name)
+            .map_or(0.0, |a| a.iv)
+    };
+    let pairs: Vec<(&str, f64, f64)> = train
+        .iter()
+        .map(|a| {
+            (
+                a.feature_name.as_str(),
+                a.iv,
+                validation_iv(&a.feature_name),
+            )
+        })
+        .collect();
+    let train_ranks = ranks(pairs.iter().map(|(name, iv, _)| (*name, *iv)));
+    let validation_ranks = ranks(pairs.iter().map(|(name, _, iv)| (*name, *iv)));
+    let max_shift = pairs.len().saturating_sub(1).max(1) as f64;
+
+    pairs
+        .iter()
+        .zip(train_ranks.into_iter().zip(validation_ranks))
+        .map(
+            |(&(name, train_iv, validation_iv), (train_rank, validation_rank))| {
+                let largest = train_iv.max(validation_iv);
+                let relative_change = if largest > 0.0 {
+                    (train_iv - validation_iv).abs() / largest
+                } else {
+                    0.0
+                };
+                let rank_shift = validation_rank.saturating_sub(train_rank) as f64 / max_shift;
+                IvStability {
+                    feature: name.to_string(),
+                    train_iv,
+                    validation_iv,
+                    relative_change,
+                    train_rank,
+                    validation_rank,
+                    rank_shift,
+                    unstable: relative_change > threshold || rank_shift > threshold,
+                }
+            },
+        )
+        .collect()
+}
+
+/// 1-based rank of each value, highest first; ties are broken by name
+fn ranks<'a>(values: impl Iterator<Item = (&'a str, f64)>) -> Vec<usize> {
+    let values: Vec<(&str, f64)> = values.collect();
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| {
+        values[b]
+            .1
+            .total_cmp(&values[a].1)
+            .then_with(|| values[a].0.cmp(values[b].0))
+    });
+    let mut ranks = vec![0; values.len()];
+    for (rank, index) in order.into_iter().enumerate() {
+        ranks[index] = rank + 1;
+    }
+    ranks
+}
+
+/// Mask marking a random `fraction` of `rows` as validation rows. Both halves
+/// keep at least one row.
+pub fn random_validation_mask(rows: usize, fraction: f64, seed: u64) -> Result<BooleanChunked> {
+    if rows < 2 {
+        bail!(
+            "At least 2 rows are needed for a validation split, found {}",
+            rows
+        );
+    }
+    let n = ((fraction * rows as f64).round() as usize).clamp(1, rows - 1);
+    let picked = IdxCa::from_vec("rows".into(), (0..rows as IdxSize).collect()).sample_n(
+        n,
+        false,
+        false,
+        Some(seed),
+    )?;
+    let mut validation = vec![false; rows];
+    for row in picked.into_no_null_iter() {
+        validation[row as usize] = true;
+    }
+    Ok(BooleanChunked::from_slice("validation".into(), &validation))
+}
+
+/// Rows of `df` and `weights` where `mask` is true
+pub fn select_rows(
+    df: &DataFrame,
+    weights: &[f64],
+    mask: &BooleanChunked,
+) -> Result<(DataFrame, Vec<f64>)> {
+    let selected: Vec<f64> = weights
+        .iter()
+        .zip(mask.iter())
+        .filter_map(|(w, keep)| keep.unwrap_or(false).then_some(*w))
+        .collect();
+    Ok((df.filter(mask)?, selected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::iv::FeatureType;
+
+    fn analysis(name: &str, iv: f64) -> IvAnalysis {
+        IvAnalysis {
+            feature_name: name.to_string(),
+            feature_type: FeatureType::Numeric,
+            bins: Vec::new(),
+            categories: Vec::new(),
+            missing_bin: None,
+            iv,
+            gini: 0.0,
+            ks: 0.0,
+            auc: 0.5,
+            mutual_info: None,
+            monotonicity: None,
+            solver_status: None,
+            special_bins: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_compare_iv_flags_large_changes() {
+        let train = [
+            analysis("steady", 0.40),
+            analysis("fading", 0.30),
+            analysis("weak", 0.05),
+        ];
+        let validation = [
+            analysis("steady", 0.38),
+            analysis("fading", 0.06),
+            analysis("weak", 0.05),
+        ];
+        let result = compare_iv(&train, &validation, 0.5);
+
+        assert_eq!(result[0].feature, "steady");
+        assert!(!result[0].unstable);
+        assert_eq!((result[0].train_rank, result[0].validation_rank), (1, 1));
+
+        // 0.30 -> 0.06 is an 80% drop
+        assert!((result[1].relative_change - 0.8).abs() < 1e-9);
+        assert!(result[1].unstable);
+        assert_eq!((result[1].train_rank, result[1].validation_rank), (2, 2));
+        assert!(!result[2].unstable);
+    }
+
+    #[test]
+    fn test_compare_iv_rank_shift_and_missing_feature() {
+        let train = [
+            analysis("a", 0.50),
+            analysis("b", 0.45),
+            analysis("c", 0.40),
+        ];
+        // `a` falls from first to last; `c` cannot be scored on validation
+        let validation = [analysis("a", 0.30), analysis("b", 0.45)];
+        let result = compare_iv(&train, &validation, 0.9);
+
+        assert_eq!((result[0].train_rank, result[0].validation_rank), (1, 2));
+        assert_eq!(result[2].validation_iv, 0.0);
+        assert_eq!(result[2].relative_change, 1.0);
+        assert!(result[2].unstable);
+        assert!(!result[0].unstable);
+    }
+
+    #[test]
+    fn test_random_validation_mask() {
+        let mask = random_validation_mask(100, 0.3, 7).unwrap();
+        assert_eq!(mask.len(), 100);
+        assert_eq!(mask.sum(), Some(30));
+        assert!(mask
+            .equal(&random_validation_mask(100, 0.3, 7).unwrap())
+            .all());
+
+        // Both halves keep a row
+        assert_eq!(random_validation_mask(3, 0.01, 1).unwrap().sum(), Some(1));
+        assert!(random_validation_mask(1, 0.5, 1).is_err());
+    }
+}
@@ -0,0 +1,411 @@
+//! Stata dataset reader (`.dta`)
+//!
+//! Reads format releases 113-115 (Stata 8-12) and 117-119 (Stata 13 and
+//! later) in either byte order.
+//!
+//! - `byte`, `int` and `long` load as `i8`, `i16` and `i32`; `float` and
+//!   `double` as `f32` and `f64`. Variables with a `%td` / `%d` format load as
+//!   `date` and `%tc` / `%tC` as `datetime[ms]`; other `%t` formats stay numeric.
+//! - Fixed-width `str#` and `strL` variables load as `str`. Empty strings
+//!   load as null, as in SAS7BDAT input.
+//! - Missing values (`.`, `.a` to `.z`) load as null.
+//!
+//! Releases 118 and later store UTF-8 text; older files are decoded as
+//! UTF-8 when valid and as Latin-1 otherwise.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+
+use super::byte_reader::{decode_text, parse_file_header, read_file, ByteReader};
+
+/// Days from the Stata epoch (1960-01-01) to 1970-01-01
+const EPOCH_OFFSET_DAYS: i32 = 3_653;
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// Largest non-missing values; anything above is one of the missing codes
+const BYTE_MAX: i8 = 100;
+const INT_MAX: i16 = 32_740;
+const LONG_MAX: i32 = 2_147_483_620;
+const FLOAT_MAX_BITS: u32 = 0x7eff_ffff;
+const DOUBLE_MAX_BITS: u64 = 0x7fdf_ffff_ffff_ffff;
+
+/// Column names of a Stata dataset, read from its header
+pub fn get_dta_columns(path: &Path) -> Result<Vec<String>> {
+    let header = parse_file_header(path, |data| Header::parse(&mut ByteReader::new(data)))
+        .with_context(|| format!("Failed to read Stata header: {}", path.display()))?;
+    Ok(header.names)
+}
+
+/// Load a Stata dataset into a DataFrame
+pub fn load_dta(path: &Path) -> Result<DataFrame> {
+    let data = read_file(path)?;
+    let mut reader = ByteReader::new(&data);
+    let header = Header::parse(&mut reader)
+        .with_context(|| format!("Failed to read Stata header: {}", path.display()))?;
+    let strls = match header.strls_offset {
+        Some(offset) => read_strls(&mut reader, offset, header.release)
+            .with_context(|| format!("Failed to read Stata strLs: {}", path.display()))?,
+        None => HashMap::new(),
+    };
+
+    reader.seek(header.data_offset)?;
+    let mut columns: Vec<ColumnValues> = header
+        .types
+        .iter()
+        .map(|t| ColumnValues::new(*t, header.observations))
+        .collect();
+    for _ in 0..header.observations {
+        for (column, &var_type) in columns.iter_mut().zip(&header.types) {
+            column
+                .push(&mut reader, var_type, header.release, &strls)
+                .with_context(|| format!("Failed to read Stata data: {}", path.display()))?;
+        }
+    }
+
+    let columns = columns
+        .into_iter()
+        .zip(header.names.iter().zip(&header.formats))
+        .map(|(values, (name, format))| values.into_column(name, format))
+        .collect::<Result<Vec<Column>>>()?;
+    DataFrame::new(columns)
+        .with_context(|| format!("Failed to build DataFrame from {}", path.display()))
+}
+
+/// Storage type of a variable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VarType {
+    /// Fixed-width string of this many bytes
+    Str(usize),
+    /// Long string stored in the strL section
+    StrL,
+    Byte,
+    Int,
+    Long,
+    Float,
+    Double,
+}
+
+impl VarType {
+    /// Type code of releases 113-115
+    fn from_old_code(code: u8) -> Result<Self> {
+        Ok(match code {
+            1..=244 => VarType::Str(usize::from(code)),
+            251 => VarType::Byte,
+            252 => VarType::Int,
+            253 => VarType::Long,
+            254 => VarType::Float,
+            255 => VarType::Double,
+            other => anyhow::bail!("Unknown Stata variable type {}", other),
+        })
+    }
+
+    /// Type code of releases 117-119
+    fn from_code(code: u16) -> Result<Self> {
+        Ok(match code {
+            1..=2045 => VarType::Str(usize::from(code)),
+            32768 => VarType::StrL,
+            65526 => VarType::Double,
+            65527 => VarType::Float,
+            65528 => VarType::Long,
+            65529 => VarType::Int,
+            65530 => VarType::Byte,
+            other => anyhow::bail!("Unknown Stata variable type {}", other),
+        })
+    }
+}
+
+struct Header {
+    release: u16,
+    observations: usize,
+    types: Vec<VarType>,
+    names: Vec<String>,
+    formats: Vec<String>,
+    data_offset: usize,
+    strls_offset: Option<usize>,
+}
+
+impl Header {
+    fn parse(reader: &mut ByteReader) -> Result<Self> {
+        let first = reader.u8()?;
+        reader.seek(0)?;
+        match first {
+            b'<' => Self::parse_tagged(reader),
+            113..=115 => Self::parse_old(reader),
+            _ => anyhow::bail!(
+                "Not a supported Stata file (release {}): only releases 113-115 and 117-119 are read",
+                first
+            ),
+        }
+    }
+
+    /// Releases 113-115: fixed-layout header
+    fn parse_old(reader: &mut ByteReader) -> Result<Self> {
+        let release = u16::from(reader.u8()?);
+        // 1 = HILO (big-endian), 2 = LOHI
+        let big_endian = reader.u8()? == 1;
+        reader.set_big_endian(big_endian);
+        reader.skip(2)?; // file type, unused
+        let variables = usize::from(reader.u16()?);
+        let observations = reader.u32()? as usize;
+        reader.skip(81 + 18)?; // data label, time stamp
+
+        let types = (0..variables)
+            .map(|_| VarType::from_old_code(reader.u8()?))
+            .collect::<Result<Vec<_>>>()?;
+        let names = read_fields(reader, variables, 33)?;
+        reader.skip(2 * (variables + 1))?; // sort list
+        let format_width = if release == 113 { 12 } else { 49 };
+        let formats = read_fields(reader, variables, format_width)?;
+        reader.skip(variables * (33 + 81))?; // value label names, variable labels
+
+        // Expansion fields end with a zero type and length
+        loop {
+            let kind = reader.u8()?;
+            let len = reader.u32()? as usize;
+            if kind == 0 && len == 0 {
+                break;
+            }
+            reader.skip(len)?;
+        }
+
+        Ok(Header {
+            release,
+            observations,
+            types,
+            names,
+            formats,
+            data_offset: reader.position(),
+            strls_offset: None,
+        })
+    }
+
+    /// Releases 117-119: XML-like tagged sections located through the map
+    fn parse_tagged(reader: &mut ByteReader) -> Result<Self> {
+        reader.expect("<stata_dta><header><release>")?;
+        let release: u16 = std::str::from_utf8(reader.bytes(3)?)?
+            .parse()
+            .context("Invalid Stata release")?;
+        if !(117..=119).contains(&release) {
+            anyhow::bail!(
+                "Unsupported Stata release {}: only releases 113-115 and 117-119 are read",
+                release
+            );
+        }
+        reader.expect("</release><byteorder>")?;
+        match reader.bytes(3)? {
+            b"MSF" => reader.set_big_endian(true),
+            b"LSF" => reader.set_big_endian(false),
+            _ => anyhow::bail!("Invalid Stata byte order"),
+        }
+        reader.expect("</byteorder><K>")?;
+        let variables = if release == 119 {
+            reader.u32()? as usize
+        } else {
+            usize::from(reader.u16()?)
+        };
+        reader.expect("</K><N>")?;
+        let observations = if release == 117 {
+            u64::from(reader.u32()?)
+        } else {
+            reader.u64()?
+        };
+        let observations = usize::try_from(observations)?;
+        reader.expect("</N><label>")?;
+        let label_len = if release == 117 {
+            usize::from(reader.u8()?)
+        } else {
+            usize::from(reader.u16()?)
+        };
+        reader.skip(label_len)?;
+        reader.expect("</label><timestamp>")?;
+        let timestamp_len = usize::from(reader.u8()?);
+        reader.skip(timestamp_len)?;
+        reader.expect("</timestamp></header><map>")?;
+        let map = (0..14)
+            .map(|_| reader.u64().map(|v| v as usize))
+            .collect::<Result<Vec<_>>>()?;
+        reader.expect("</map><variable_types>")?;
+        let types = (0..variables)
+            .map(|_| VarType::from_code(reader.u16()?))
+            .collect::<Result<Vec<_>>>()?;
+        reader.expect("</variable_types><varnames>")?;
+        let name_width = if release == 117 { 33 } else { 129 };
+        let names = read_fields(reader, variables, name_width)?;
+        reader.expect("</varnames><sortlist>")?;
+        let sort_width = if release == 119 { 4 } else { 2 };
+        reader.skip(sort_width * (variables + 1))?;
+        reader.expect("</sortlist><formats>")?;
+        let format_width = if release == 117 { 49 } else { 57 };
+        let formats = read_fields(reader, variables, format_width)?;
+        reader.expect("</formats>")?;
+
+        Ok(Header {
+            release,
+            observations,
+            types,
+            names,
+            formats,
+            data_offset: map[9] + "<data>".len(),
+            strls_offset: Some(map[10] + "<strls>".len()),
+        })
+    }
+}
+
+/// `count` NUL-padded text fields of `width` bytes
+fn read_fields(reader: &mut ByteReader, count: usize, width: usize) -> Result<Vec<String>> {
+    (0..count)
+        .map(|_| Ok(decode_text(reader.bytes(width)?)))
+        .collect()
+}
+
+/// (variable, observation) reference of a strL value
+type StrlKey = (u64, u64);
+
+/// Read the strL section into a lookup by (v, o)
+fn read_strls(
+    reader: &mut ByteReader,
+    offset: usize,
+    release: u16,
+) -> Result<HashMap<StrlKey, String>> {
+    reader.seek(offset)?;
+    let mut strls = HashMap::new();
+    while reader.bytes(3)? == b"GSO" {
+        let v = u64::from(reader.u32()?);
+        let o = if release == 117 {
+            u64::from(reader.u32()?)
+        } else {
+            reader.u64()?
+        };
+        let binary = reader.u8()? == 129;
+        let len = reader.u32()? as usize;
+        let bytes = reader.bytes(len)?;
+        // ASCII strLs carry a trailing NUL, which decode_text drops
+        let text = if binary {
+            String::from_utf8_lossy(bytes).into_owned()
+        } else {
+            decode_text(bytes)
+        };
+        strls.insert((v, o), text);
+    }
+    Ok(strls)
+}
+
+/// Values of one column, collected in their storage type
+enum ColumnValues {
+    Text(Vec<Option<String>>),
+    Byte(Vec<Option<i8>>),
+    Int(Vec<Option<i16>>),
+    Long(Vec<Option<i32>>),
+    Float(Vec<Option<f32>>),
+    Double(Vec<Option<f64>>),
+}
+
+impl ColumnValues {
+    fn new(var_type: VarType, capacity: usize) -> Self {
+        match var_type {
+            VarType::Str(_) | VarType::StrL => ColumnValues::Text(Vec::with_capacity(capacity)),
+            VarType::Byte => ColumnValues::Byte(Vec::with_capacity(capacity)),
+            VarType::Int => ColumnValues::Int(Vec::with_capacity(capacity)),
+            VarType::Long => ColumnValues::Long(Vec::with_capacity(capacity)),
+            VarType::Float => ColumnValues::Float(Vec::with_capacity(capacity)),
+            VarType::Double => ColumnValues::Double(Vec::with_capacity(capacity)),
+        }
+    }
+
+    fn push(
+        &mut self,
+        reader: &mut ByteReader,
+        var_type: VarType,
+        release: u16,
+        strls: &HashMap<StrlKey, String>,
+    ) -> Result<()> {
+        match (self, var_type) {
+            (ColumnValues::Text(values), VarType::Str(width)) => {
+                let text = decode_text(reader.bytes(width)?);
+                values.push((!text.is_empty()).then_some(text));
+            }
+            (ColumnValues::Text(values), VarType::StrL) => {
+                // Release 117 stores v and o as two 4-byte integers; later
+                // releases pack them into one 8-byte integer, v in the low
+                // 2 (118) or 3 (119) bytes
+                let (v, o) = if release == 117 {
+                    (u64::from(reader.u32()?), u64::from(reader.u32()?))
+                } else {
+                    let v_bits = if release == 118 { 16 } else { 24 };
+                    let packed = reader.u64()?;
+                    (packed & ((1 << v_bits) - 1), packed >> v_bits)
+                };
+                let text = strls.get(&(v, o)).filter(|s| !s.is_empty()).cloned();
+                values.push(text);
+            }
+            (ColumnValues::Byte(values), _) => {
+                let v = reader.i8()?;
+                values.push((v <= BYTE_MAX).then_some(v));
+            }
+            (ColumnValues::Int(values), _) => {
+                let v = reader.i16()?;
+                values.push((v <= INT_MAX).then_some(v));
+            }
+            (ColumnValues::Long(values), _) => {
+                let v = reader.i32()?;
+                values.push((v <= LONG_MAX).then_some(v));
+            }
+            (ColumnValues::Float(values), _) => {
+                let v = reader.f32()?;
+                values.push((v <= f32::from_bits(FLOAT_MAX_BITS)).then_some(v));
+            }
+            (ColumnValues::Double(values), _) => {
+                let v = reader.f64()?;
+                values.push((v <= f64::from_bits(DOUBLE_MAX_BITS)).then_some(v));
+            }
+            (_, var_type) => anyhow::bail!("Stata value does not match type {:?}", var_type),
+        }
+        Ok(())
+    }
+
+    fn into_column(self, name: &str, format: &str) -> Result<Column> {
+        let name = PlSmallStr::from(name);
+        let format = format.trim_start_matches('%').trim_start_matches('-');
+        let is_date = format.starts_with("td") || format.starts_with('d');
+        let is_datetime = format.starts_with("tc") || format.starts_with("tC");
+
+        let series = match self {
+            ColumnValues::Text(values) => Series::new(name, values),
+            ColumnValues::Byte(values) => Series::new(name, values),
+            ColumnValues::Int(values) => Series::new(name, values),
+            ColumnValues::Long(values) => Series::new(name, values),
+            ColumnValues::Float(values) => Series::new(name, values),
+            ColumnValues::Double(values) => Series::new(name, values),
+        };
+        if !series.dtype().is_primitive_numeric() {
+            return Ok(series.into_column());
+        }
+
+        let series = if is_date {
+            let days = series.cast(&DataType::Float64)?;
+            let days: Vec<Option<i32>> = days
+                .f64()?
+                .into_iter()
+                .map(|d| d.map(|d| d.floor() as i32 - EPOCH_OFFSET_DAYS))
+                .collect();
+            Series::new(series.name().clone(), days).cast(&DataType::Date)?
+        } else if is_datetime {
+            let millis = series.cast(&DataType::Float64)?;
+            let millis: Vec<Option<i64>> = millis
+                .f64()?
+                .into_iter()
+                .map(|ms| {
+                    ms.map(|ms| ms.round() as i64 - i64::from(EPOCH_OFFSET_DAYS) * MILLIS_PER_DAY)
+                })
+                .collect();
+            Series::new(series.name().clone(), millis)
+                .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?
+        } else {
+            series
+        };
+        Ok(series.into_column())
+    }
+}
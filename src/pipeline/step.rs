@@ -0,0 +1,374 @@
+//! Reduction steps as a trait, so library users can add their own
+//!
+//! Every stage of a [`Reducer`](crate::Reducer) run is a [`ReductionStep`]:
+//! it analyzes the current data, proposes drops from its analysis and
+//! describes the analysis as a JSON fragment for the report. The missing,
+//! Gini/IV and correlation stages are implemented this way; further steps,
+//! e.g. business-rule filters, are registered with
+//! [`Reducer::step`](crate::Reducer::step) and run in registration order at
+//! their [`StepPosition`]. Their outcomes are returned in
+//! `ReductionResult::custom_steps` and can be added to a reduction report
+//! with `ReductionReportBuilder::add_custom_step`.
+//!
+//! The CLI runs its stages inline, in the order of a [`StepPlan`]
+//! (`--steps`, `--skip-gini`).
+//!
+//! ```
+//! use anyhow::Result;
+//! use lophi::pipeline::{FeatureToDrop, ReductionStep, StepContext};
+//! use polars::prelude::*;
+//!
+//! /// Drops every feature the business has not approved for modelling
+//! struct BannedPrefix(&'static str);
+//!
+//! impl ReductionStep for BannedPrefix {
+//!     type Analysis = Vec<String>;
+//!
+//!     fn name(&self) -> &str {
+//!         "banned_prefix"
+//!     }
+//!
+//!     fn analyze(&self, df: &DataFrame, _ctx: &StepContext) -> Result<Vec<String>> {
+//!         Ok(df
+//!             .get_column_names()
+//!             .iter()
+//!             .filter(|c| c.starts_with(self.0))
+//!             .map(|c| c.to_string())
+//!             .collect())
+//!     }
+//!
+//!     fn propose_drops(&self, banned: &Vec<String>, _ctx: &StepContext) -> Vec<FeatureToDrop> {
+//!         banned
+//!             .iter()
+//!             .map(|feature| FeatureToDrop {
+//!                 feature: feature.clone(),
+//!                 reason: format!("Prefix '{}' is not approved", self.0),
+//!             })
+//!             .collect()
+//!     }
+//!
+//!     fn report_fragment(&self, banned: &Vec<String>) -> serde_json::Value {
+//!         serde_json::json!({ "prefix": self.0, "matched": banned })
+//!     }
+//! }
+//! ```
+
+use std::collections::HashSet;
+
+use anyhow::Result;
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::correlation::FeatureToDrop;
+
+/// What a step sees besides the data
+#[derive(Debug, Clone, Copy)]
+pub struct StepContext<'a> {
+    pub target: &'a str,
+    /// One weight per row of the frame passed to [`ReductionStep::analyze`]
+    pub weights: &'a [f64],
+    pub weight_column: Option<&'a str>,
+    /// Features the user protected; proposals to drop them are ignored
+    pub keep_columns: &'a HashSet<String>,
+}
+
+impl StepContext<'_> {
+    /// Whether `column` may be dropped: not the target, weight column or a
+    /// kept feature
+    pub fn droppable(&self, column: &str) -> bool {
+        column != self.target
+            && Some(column) != self.weight_column
+            && !self.keep_columns.contains(column)
+    }
+}
+
+/// One stage of a reduction: analyze → propose drops → report fragment
+pub trait ReductionStep: Send + Sync {
+    /// Result of [`ReductionStep::analyze`]
+    type Analysis;
+
+    /// Short name identifying the step in the results and the report
+    fn name(&self) -> &str;
+
+    /// Compute the step's statistics on the features left by earlier steps
+    fn analyze(&self, df: &DataFrame, ctx: &StepContext) -> Result<Self::Analysis>;
+
+    /// Features to drop, each with a reason. Proposals for the target, the
+    /// weight column, kept features or absent columns are ignored.
+    fn propose_drops(&self, analysis: &Self::Analysis, ctx: &StepContext) -> Vec<FeatureToDrop>;
+
+    /// The analysis as a JSON fragment for the report
+    fn report_fragment(&self, analysis: &Self::Analysis) -> serde_json::Value;
+}
+
+/// Where a registered step runs in a [`Reducer`](crate::Reducer) run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepPosition {
+    /// After the ID column check, before the missing-value step
+    BeforeMissing,
+    /// After the correlation step, on the features left at the end
+    #[default]
+    AfterCorrelation,
+}
+
+/// Outcome of a registered step
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepOutcome {
+    /// [`ReductionStep::name`]
+    pub step: String,
+    pub position: StepPosition,
+    /// The drops that were applied
+    pub dropped: Vec<FeatureToDrop>,
+    /// [`ReductionStep::report_fragment`]
+    pub report: serde_json::Value,
+}
+
+/// Run `step` on `df` and drop what it proposes. Returns the analysis and
+/// the applied drops, without proposals the context protects, repeats or
+/// absent columns.
+pub fn run_step<S: ReductionStep>(
+    step: &S,
+    df: &mut DataFrame,
+    ctx: &StepContext,
+) -> Result<(S::Analysis, Vec<FeatureToDrop>)> {
+    let analysis = step.analyze(df, ctx)?;
+    let mut dropped: Vec<FeatureToDrop> = Vec::new();
+    for proposal in step.propose_drops(&analysis, ctx) {
+        if ctx.droppable(&proposal.feature)
+            && df.column(&proposal.feature).is_ok()
+            && !dropped.iter().any(|d| d.feature == proposal.feature)
+        {
+            dropped.push(proposal);
+        }
+    }
+    if !dropped.is_empty() {
+        let names: Vec<&str> = dropped.iter().map(|d| d.feature.as_str()).collect();
+        *df = df.drop_many(names);
+    }
+    Ok((analysis, dropped))
+}
+
+/// Object-safe form of [`ReductionStep`], so steps with different analysis
+/// types can be registered side by side
+pub(crate) trait RegisteredStep: Send + Sync {
+    fn run_registered(
+        &self,
+        df: &mut DataFrame,
+        ctx: &StepContext,
+        position: StepPosition,
+    ) -> Result<StepOutcome>;
+}
+
+impl<S: ReductionStep> RegisteredStep for S {
+    fn run_registered(
+        &self,
+        df: &mut DataFrame,
+        ctx: &StepContext,
+        position: StepPosition,
+    ) -> Result<StepOutcome> {
+        let (analysis, dropped) = run_step(self, df, ctx)?;
+        Ok(StepOutcome {
+            step: self.name().to_string(),
+            position,
+            dropped,
+            report: self.report_fragment(&analysis),
+        })
+    }
+}
+
+/// A screening stage that `--steps` can select and order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScreeningStep {
+    Missing,
+    /// Gini/IV, or binned R² for a continuous target
+    Gini,
+    Correlation,
+}
+
+impl ScreeningStep {
+    /// Every step, in the default order
+    pub const ALL: [ScreeningStep; 3] = [
+        ScreeningStep::Missing,
+        ScreeningStep::Gini,
+        ScreeningStep::Correlation,
+    ];
+}
+
+impl std::fmt::Display for ScreeningStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScreeningStep::Missing => write!(f, "missing"),
+            ScreeningStep::Gini => write!(f, "gini"),
+            ScreeningStep::Correlation => write!(f, "correlation"),
+        }
+    }
+}
+
+impl std::str::FromStr for ScreeningStep {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "missing" => Ok(ScreeningStep::Missing),
+            "gini" => Ok(ScreeningStep::Gini),
+            "correlation" => Ok(ScreeningStep::Correlation),
+            _ => Err(format!(
+                "Unknown step: '{}'. Use 'missing', 'gini', or 'correlation'.",
+                s
+            )),
+        }
+    }
+}
+
+/// The screening steps to run, in order (`--steps`, `--skip-gini`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepPlan {
+    steps: Vec<ScreeningStep>,
+}
+
+impl Default for StepPlan {
+    fn default() -> Self {
+        Self {
+            steps: ScreeningStep::ALL.to_vec(),
+        }
+    }
+}
+
+impl StepPlan {
+    /// Plan of the step names in `entries`, e.g. `["missing", "correlation"]`;
+    /// the steps run in the order given and a step may appear once
+    pub fn parse<S: AsRef<str>>(entries: &[S]) -> Result<Self> {
+        let mut steps = Vec::new();
+        for entry in entries.iter().map(AsRef::as_ref) {
+            if entry.trim().is_empty() {
+                continue;
+            }
+            let step: ScreeningStep = entry.parse().map_err(anyhow::Error::msg)?;
+            if steps.contains(&step) {
+                anyhow::bail!("Step '{}' is listed more than once", step);
+            }
+            steps.push(step);
+        }
+        if steps.is_empty() {
+            anyhow::bail!("No steps to run: list at least one of missing, gini, correlation");
+        }
+        Ok(Self { steps })
+    }
+
+    /// Leave `step` out; fails when no step would be left
+    pub fn skip(&mut self, step: ScreeningStep) -> Result<()> {
+        self.steps.retain(|s| *s != step);
+        if self.steps.is_empty() {
+            anyhow::bail!("No steps left to run after skipping '{}'", step);
+        }
+        Ok(())
+    }
+
+    /// The steps to run, in order
+    pub fn steps(&self) -> &[ScreeningStep] {
+        &self.steps
+    }
+
+    pub fn runs(&self, step: ScreeningStep) -> bool {
+        self.steps.contains(&step)
+    }
+
+    /// The steps left out, in the default order
+    pub fn skipped(&self) -> Vec<ScreeningStep> {
+        ScreeningStep::ALL
+            .into_iter()
+            .filter(|s| !self.runs(*s))
+            .collect()
+    }
+
+    /// Whether every step runs in the default order
+    pub fn is_default(&self) -> bool {
+        self.steps == ScreeningStep::ALL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_plan_order_and_skips() {
+        let plan = StepPlan::parse(&["correlation", " missing"]).unwrap();
+        assert_eq!(
+            plan.steps(),
+            [ScreeningStep::Correlation, ScreeningStep::Missing]
+        );
+        assert_eq!(plan.skipped(), [ScreeningStep::Gini]);
+        assert!(!plan.is_default());
+
+        let mut plan = StepPlan::default();
+        assert!(plan.is_default());
+        plan.skip(ScreeningStep::Gini).unwrap();
+        assert_eq!(plan, StepPlan::parse(&["missing", "correlation"]).unwrap());
+
+        assert!(StepPlan::parse(&["missing", "missing"]).is_err());
+        assert!(StepPlan::parse(&["missing", "woe"]).is_err());
+        assert!(StepPlan::parse::<&str>(&[]).is_err());
+        assert!(StepPlan::parse(&["gini"])
+            .unwrap()
+            .skip(ScreeningStep::Gini)
+            .is_err());
+    }
+
+    struct DropAll;
+
+    impl ReductionStep for DropAll {
+        type Analysis = usize;
+
+        fn name(&self) -> &str {
+            "drop_all"
+        }
+
+        fn analyze(&self, df: &DataFrame, _ctx: &StepContext) -> Result<usize> {
+            Ok(df.width())
+        }
+
+        fn propose_drops(&self, _analysis: &usize, _ctx: &StepContext) -> Vec<FeatureToDrop> {
+            ["a", "b", "a", "target", "w", "absent"]
+                .iter()
+                .map(|f| FeatureToDrop {
+                    feature: f.to_string(),
+                    reason: "everything goes".into(),
+                })
+                .collect()
+        }
+
+        fn report_fragment(&self, analysis: &usize) -> serde_json::Value {
+            serde_json::json!({ "columns": analysis })
+        }
+    }
+
+    #[test]
+    fn test_protected_and_absent_columns_are_not_dropped() {
+        let mut df = df! {
+            "target" => [0, 1],
+            "w" => [1.0, 2.0],
+            "a" => [1.0, 2.0],
+            "b" => [3.0, 4.0],
+        }
+        .unwrap();
+        let keep: HashSet<String> = ["b".to_string()].into();
+        let ctx = StepContext {
+            target: "target",
+            weights: &[1.0, 2.0],
+            weight_column: Some("w"),
+            keep_columns: &keep,
+        };
+
+        let outcome = DropAll
+            .run_registered(&mut df, &ctx, StepPosition::AfterCorrelation)
+            .unwrap();
+        assert_eq!(outcome.step, "drop_all");
+        assert_eq!(outcome.report["columns"], 4);
+        let dropped: Vec<&str> = outcome.dropped.iter().map(|d| d.feature.as_str()).collect();
+        assert_eq!(dropped, ["a"]);
+        assert_eq!(df.get_column_names(), ["target", "w", "b"]);
+    }
+}
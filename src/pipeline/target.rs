@@ -10,6 +10,10 @@ use serde::{Deserialize, Serialize};
 /// Tolerance for floating point comparison when checking binary 0/1 values
 const TOLERANCE: f64 = 1e-9;
 
+/// Suffix of the column holding the raw target values when the mapped target
+/// is written to the output (`--output-include-target-mapping`)
+pub const RAW_TARGET_SUFFIX: &str = "_raw";
+
 /// Mapping configuration for converting target column values to binary 0/1
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TargetMapping {
@@ -29,6 +33,21 @@ impl TargetMapping {
     }
 }
 
+/// Names for the two target classes in reports (`--event-label` / `--non-event-label`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventLabels {
+    /// Name of the event class (target = 1)
+    pub event: String,
+    /// Name of the non-event class (target = 0)
+    pub non_event: String,
+}
+
+impl EventLabels {
+    pub fn new(event: String, non_event: String) -> Self {
+        Self { event, non_event }
+    }
+}
+
 /// Result of analyzing a target column
 #[derive(Debug, Clone)]
 pub enum TargetAnalysis {
@@ -218,7 +237,6 @@ fn column_to_string_vec(col: &Column) -> Result<Vec<Option<String>>> {
 }
 
 /// Count how many records match the event and non-event values
-#[allow(dead_code)]
 pub fn count_mapped_records(
     df: &DataFrame,
     target: &str,
@@ -233,6 +251,147 @@ pub fn count_mapped_records(
     Ok((events, non_events, ignored))
 }
 
+/// Fewest rows of the rarer target class, when it is also under
+/// [`IMBALANCED_SHARE`], before a run warns that WoE and IV rest on too few rows
+pub const MIN_MINORITY_ROWS: usize = 20;
+
+/// Share of the rarer target class below which a target counts as imbalanced
+pub const IMBALANCED_SHARE: f64 = 0.05;
+
+/// Smallest share of the rarer target class (of the rows of both classes)
+/// before a run warns about the imbalance
+pub const MIN_MINORITY_SHARE: f64 = 0.001;
+
+/// Row counts of each target class, after mapping
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TargetClassCounts {
+    pub events: usize,
+    pub non_events: usize,
+    /// Null rows, and rows matching neither mapped value
+    pub other: usize,
+}
+
+impl TargetClassCounts {
+    /// Whether the rarer class holds less than [`MIN_MINORITY_SHARE`] of the
+    /// classified rows, or fewer than [`MIN_MINORITY_ROWS`] rows and less than
+    /// [`IMBALANCED_SHARE`]. A small but balanced sample is not flagged.
+    pub fn is_nearly_single_class(&self) -> bool {
+        let minority = self.events.min(self.non_events) as f64;
+        let classified = (self.events + self.non_events) as f64;
+        minority < MIN_MINORITY_SHARE * classified
+            || (minority < MIN_MINORITY_ROWS as f64 && minority < IMBALANCED_SHARE * classified)
+    }
+}
+
+/// Count the rows of each target class, 1/0 or the mapped values
+pub fn count_target_classes(
+    df: &DataFrame,
+    target: &str,
+    mapping: Option<&TargetMapping>,
+) -> Result<TargetClassCounts> {
+    Ok(match mapping {
+        Some(mapping) => {
+            let (events, non_events, other) = count_mapped_records(df, target, mapping)?;
+            TargetClassCounts {
+                events,
+                non_events,
+                other,
+            }
+        }
+        None => {
+            let target_col = df
+                .column(target)
+                .with_context(|| format!("Target column '{}' not found", target))?
+                .cast(&DataType::Float64)?;
+            let mut counts = TargetClassCounts {
+                events: 0,
+                non_events: 0,
+                other: 0,
+            };
+            for value in target_col.f64()?.into_iter() {
+                match value {
+                    Some(v) if (v - 1.0).abs() < TOLERANCE => counts.events += 1,
+                    Some(v) if v.abs() < TOLERANCE => counts.non_events += 1,
+                    _ => counts.other += 1,
+                }
+            }
+            counts
+        }
+    })
+}
+
+/// Count the target classes and fail when only one of them is present.
+///
+/// A single-class target makes every WoE and IV undefined, so this stops the
+/// run with the class counts instead. A nearly single-class target
+/// ([`TargetClassCounts::is_nearly_single_class`]) is logged as a warning.
+pub fn check_target_classes(
+    df: &DataFrame,
+    target: &str,
+    mapping: Option<&TargetMapping>,
+) -> Result<TargetClassCounts> {
+    let counts = count_target_classes(df, target, mapping)?;
+
+    let (event_name, non_event_name) = match mapping {
+        Some(m) => (m.event_value.as_str(), m.non_event_value.as_str()),
+        None => ("1", "0"),
+    };
+    if counts.events == 0 || counts.non_events == 0 {
+        anyhow::bail!(
+            "Target column '{}' has a single class: {} event ('{}'), {} non-event ('{}'), \
+             {} null or unmapped row(s). WoE and IV need both classes; check the target \
+             mapping and any --split-column/--train-value filter",
+            target,
+            counts.events,
+            event_name,
+            counts.non_events,
+            non_event_name,
+            counts.other
+        );
+    }
+    if counts.is_nearly_single_class() {
+        tracing::warn!(
+            "Target column '{}' is nearly single-class: {} event ('{}'), {} non-event ('{}'). \
+             The WoE and IV of most bins rest on a handful of rows and will be unstable; \
+             consider more rows, fewer --gini-bins or a higher --min-bin-pct",
+            target,
+            counts.events,
+            event_name,
+            counts.non_events,
+            non_event_name
+        );
+    }
+
+    Ok(counts)
+}
+
+/// Replace the target column with its mapped 0/1 values (Int32), moving the
+/// raw values to `{target}_raw` directly after it.
+///
+/// Values matching neither side of the mapping become null. Fails when a
+/// `{target}_raw` column already exists.
+pub fn write_mapped_target(
+    df: &mut DataFrame,
+    target: &str,
+    mapping: &TargetMapping,
+) -> Result<()> {
+    let raw_name = format!("{}{}", target, RAW_TARGET_SUFFIX);
+    if df.column(&raw_name).is_ok() {
+        anyhow::bail!(
+            "Cannot keep the raw target values as '{}': a column with that name already exists",
+            raw_name
+        );
+    }
+
+    let mask = create_target_mask(df, target, mapping)?;
+    let index = df
+        .get_column_index(target)
+        .with_context(|| format!("Target column '{}' not found", target))?;
+    df.rename(target, raw_name.as_str().into())?;
+    df.insert_column(index, Column::new(target.into(), mask))?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -372,4 +531,62 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("null"));
     }
+
+    #[test]
+    fn test_check_target_classes_single_class() {
+        let df = df! {
+            "target" => [Some(0i32), Some(0), Some(0), None],
+        }
+        .unwrap();
+        let err = check_target_classes(&df, "target", None)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("single class"), "{}", err);
+        assert!(
+            err.contains("0 event ('1'), 3 non-event ('0'), 1 null"),
+            "{}",
+            err
+        );
+
+        let df = df! { "target" => ["G", "G", "X"] }.unwrap();
+        let mapping = TargetMapping::new("B".to_string(), "G".to_string());
+        let err = check_target_classes(&df, "target", Some(&mapping))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("0 event ('B'), 2 non-event ('G'), 1 null or unmapped"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_check_target_classes_counts_imbalanced_target() {
+        let mut target = vec![0i32; 9999];
+        target.push(1);
+        let df = df! { "target" => target }.unwrap();
+        let counts = check_target_classes(&df, "target", None).unwrap();
+        assert_eq!(
+            counts,
+            TargetClassCounts {
+                events: 1,
+                non_events: 9999,
+                other: 0
+            }
+        );
+        // 99.99% non-events: too few events by count and by share
+        assert!(counts.is_nearly_single_class());
+
+        let counts = |events, non_events| TargetClassCounts {
+            events,
+            non_events,
+            other: 0,
+        };
+        assert!(!counts(500, 9500).is_nearly_single_class());
+        assert!(counts(19, 1000).is_nearly_single_class());
+        assert!(!counts(19, 100).is_nearly_single_class());
+        assert!(!counts(10, 10).is_nearly_single_class());
+        assert!(counts(50, 100_000).is_nearly_single_class());
+        assert!(!counts(150, 100_000).is_nearly_single_class());
+    }
 }
@@ -0,0 +1,263 @@
+//! Handling of date and datetime features
+//!
+//! Date columns loaded from SAS, SPSS, Stata, Excel or Parquet are neither
+//! numeric nor categorical, so binning and correlation skip them and they ride
+//! through to the output unscored. `--date-policy` decides what happens to
+//! them before the screening stages: keep them as they are, drop them, or
+//! replace each one with numeric features the IV step can bin.
+
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate};
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Suffixes of the features derived from a date column `c`: `c_days` (days
+/// since 1970-01-01), `c_month` (1-12) and `c_dow` (ISO day of week, 1 =
+/// Monday)
+pub const DERIVED_DATE_SUFFIXES: [&str; 3] = ["days", "month", "dow"];
+
+/// Days from 0001-01-01 (day 1 of the common era) to 1970-01-01
+const UNIX_EPOCH_DAYS_FROM_CE: i64 = 719_163;
+
+/// What happens to date, datetime, time and duration columns
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DatePolicy {
+    /// Leave them as they are; the screening stages skip them (default)
+    #[default]
+    Keep,
+    /// Drop them before missing analysis
+    Drop,
+    /// Replace each date and datetime column with its day count, month and
+    /// day of week. Time and duration columns are kept as they are.
+    Derive,
+}
+
+impl std::fmt::Display for DatePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DatePolicy::Keep => write!(f, "keep"),
+            DatePolicy::Drop => write!(f, "drop"),
+            DatePolicy::Derive => write!(f, "derive"),
+        }
+    }
+}
+
+impl std::str::FromStr for DatePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "keep" => Ok(DatePolicy::Keep),
+            "drop" => Ok(DatePolicy::Drop),
+            "derive" => Ok(DatePolicy::Derive),
+            _ => Err(format!(
+                "Unknown date policy: '{}'. Use 'keep', 'drop', or 'derive'.",
+                s
+            )),
+        }
+    }
+}
+
+/// A temporal column and what the date policy made of it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemporalColumn {
+    pub feature: String,
+    /// Polars dtype as text, e.g. "date" or "datetime[ms]"
+    pub dtype: String,
+    /// Features derived from it, in [`DERIVED_DATE_SUFFIXES`] order; empty
+    /// unless [`DatePolicy::Derive`] replaced it
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub derived: Vec<String>,
+}
+
+/// Names of the temporal columns in column order, skipping `exclude`
+pub fn find_temporal_columns(df: &DataFrame, exclude: &[&str]) -> Vec<String> {
+    df.get_columns()
+        .iter()
+        .filter(|c| c.dtype().is_temporal() && !exclude.contains(&c.name().as_str()))
+        .map(|c| c.name().to_string())
+        .collect()
+}
+
+/// Apply `policy` to the temporal columns of `df`.
+///
+/// Derived features take the place of their source column, so the column order
+/// is kept. Values are taken in UTC; nulls stay null in every derived feature.
+///
+/// # Errors
+/// With [`DatePolicy::Derive`], fails if a derived name is already a column.
+pub fn apply_date_policy(
+    df: &mut DataFrame,
+    policy: DatePolicy,
+    exclude: &[&str],
+) -> Result<Vec<TemporalColumn>> {
+    let names = find_temporal_columns(df, exclude);
+    let mut temporal = Vec::with_capacity(names.len());
+    for name in &names {
+        temporal.push(TemporalColumn {
+            feature: name.clone(),
+            dtype: df.column(name)?.dtype().to_string(),
+            derived: Vec::new(),
+        });
+    }
+
+    match policy {
+        DatePolicy::Keep => {}
+        DatePolicy::Drop => {
+            if !names.is_empty() {
+                *df = df.drop_many(&names);
+            }
+        }
+        DatePolicy::Derive => {
+            let mut columns = Vec::with_capacity(df.width() + 2 * names.len());
+            for column in df.get_columns() {
+                let entry = temporal
+                    .iter_mut()
+                    .find(|t| t.feature == column.name().as_str());
+                match entry.and_then(|t| day_divisor(column.dtype()).map(|d| (t, d))) {
+                    Some((entry, divisor)) => {
+                        for derived in derive_date_features(column, divisor)? {
+                            if df.column(derived.name()).is_ok() {
+                                anyhow::bail!(
+                                    "Derived date feature '{}' clashes with an existing column. \
+                                     Rename it or use --date-policy keep or drop.",
+                                    derived.name()
+                                );
+                            }
+                            entry.derived.push(derived.name().to_string());
+                            columns.push(derived);
+                        }
+                    }
+                    None => columns.push(column.clone()),
+                }
+            }
+            *df = DataFrame::new(columns)?;
+        }
+    }
+
+    Ok(temporal)
+}
+
+/// Physical units per day of a date or datetime dtype; `None` for time and
+/// duration columns, which have no calendar date
+fn day_divisor(dtype: &DataType) -> Option<i64> {
+    match dtype {
+        DataType::Date => Some(1),
+        DataType::Datetime(TimeUnit::Milliseconds, _) => Some(86_400_000),
+        DataType::Datetime(TimeUnit::Microseconds, _) => Some(86_400_000_000),
+        DataType::Datetime(TimeUnit::Nanoseconds, _) => Some(86_400_000_000_000),
+        _ => None,
+    }
+}
+
+/// The calendar date (UTC) of every value of a date or datetime column;
+/// `None` for any other dtype
+pub fn calendar_dates(column: &Column) -> Result<Option<Vec<Option<NaiveDate>>>> {
+    let Some(divisor) = day_divisor(column.dtype()) else {
+        return Ok(None);
+    };
+    let physical = column
+        .as_materialized_series()
+        .to_physical_repr()
+        .cast(&DataType::Int64)?;
+    let dates = physical
+        .i64()?
+        .iter()
+        .map(|v| {
+            v.map(|v| v.div_euclid(divisor) + UNIX_EPOCH_DAYS_FROM_CE)
+                .and_then(|d| i32::try_from(d).ok())
+                .and_then(NaiveDate::from_num_days_from_ce_opt)
+        })
+        .collect();
+    Ok(Some(dates))
+}
+
+fn derive_date_features(column: &Column, divisor: i64) -> Result<[Column; 3]> {
+    let physical = column
+        .as_materialized_series()
+        .to_physical_repr()
+        .cast(&DataType::Int64)?;
+    let days: Vec<Option<i64>> = physical
+        .i64()?
+        .iter()
+        .map(|v| v.map(|v| v.div_euclid(divisor)))
+        .collect();
+
+    let name = column.name();
+    let derived = |suffix: &str, values: Vec<Option<i32>>| {
+        Column::new(format!("{}_{}", name, suffix).into(), values)
+    };
+    let month = days
+        .iter()
+        .map(|d| {
+            d.and_then(|d| i32::try_from(d + UNIX_EPOCH_DAYS_FROM_CE).ok())
+                .and_then(NaiveDate::from_num_days_from_ce_opt)
+                .map(|date| date.month() as i32)
+        })
+        .collect();
+    // 1970-01-01 was a Thursday (ISO day 4)
+    let dow = days
+        .iter()
+        .map(|d| d.map(|d| ((d + 3).rem_euclid(7) + 1) as i32))
+        .collect();
+    let days = days
+        .iter()
+        .map(|d| d.and_then(|d| i32::try_from(d).ok()))
+        .collect();
+
+    let [s_days, s_month, s_dow] = DERIVED_DATE_SUFFIXES;
+    Ok([
+        derived(s_days, days),
+        derived(s_month, month),
+        derived(s_dow, dow),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_derive_replaces_dates_in_place() {
+        // 2024-02-29 is a Thursday, 19782 days after the epoch
+        let opened = Series::new("opened".into(), [Some(19_782i32), None, Some(-1)])
+            .cast(&DataType::Date)
+            .unwrap();
+        let mut df = DataFrame::new(vec![
+            Column::new("target".into(), [0i32, 1, 0]),
+            opened.into(),
+            Column::new("income".into(), [1.0, 2.0, 3.0]),
+        ])
+        .unwrap();
+
+        let temporal = apply_date_policy(&mut df, DatePolicy::Derive, &["target"]).unwrap();
+        assert_eq!(temporal[0].dtype, "date");
+        assert_eq!(
+            temporal[0].derived,
+            ["opened_days", "opened_month", "opened_dow"]
+        );
+        assert_eq!(
+            df.get_column_names_str(),
+            [
+                "target",
+                "opened_days",
+                "opened_month",
+                "opened_dow",
+                "income"
+            ]
+        );
+        let values = |name: &str| -> Vec<Option<i32>> {
+            df.column(name)
+                .unwrap()
+                .i32()
+                .unwrap()
+                .into_iter()
+                .collect()
+        };
+        assert_eq!(values("opened_days"), [Some(19_782), None, Some(-1)]);
+        assert_eq!(values("opened_month"), [Some(2), None, Some(12)]);
+        // 1969-12-31 was a Wednesday
+        assert_eq!(values("opened_dow"), [Some(4), None, Some(3)]);
+    }
+}
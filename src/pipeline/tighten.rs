@@ -0,0 +1,308 @@
+//! Apply stricter thresholds to a finished reduction (`lophi tighten`)
+//!
+//! The reduction report stores every feature's missing ratio and univariate
+//! metric (Gini, mutual information or R²). A stricter missing or Gini cut can
+//! therefore be decided from the report alone and applied to the reduced
+//! output, without loading the raw data or recomputing any statistic. The
+//! features removed by the tighter cut are listed in a delta report.
+//!
+//! Correlation cannot be tightened this way: the report only holds the pairs
+//! above the original correlation threshold.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::apply::read_report_json;
+use crate::report::{DropStage, USER_RETAINED};
+
+/// Kept features of a saved reduction with the metrics needed to re-cut them
+#[derive(Debug, Clone)]
+pub struct PriorReduction {
+    pub target_column: String,
+    /// Missing threshold of the original run
+    pub missing_threshold: f64,
+    /// Gini (or mutual information / R²) threshold of the original run
+    pub gini_threshold: f64,
+    /// Metric compared against the Gini threshold
+    pub metric: TightenMetric,
+    /// Features kept by the original run, in report order
+    pub features: Vec<PriorFeature>,
+}
+
+/// A kept feature and its recorded metrics
+#[derive(Debug, Clone)]
+pub struct PriorFeature {
+    pub name: String,
+    pub missing_ratio: Option<f64>,
+    /// Value of [`PriorReduction::metric`]; absolute Gini for the Gini metric
+    pub univariate: Option<f64>,
+}
+
+/// Univariate metric the original run screened on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TightenMetric {
+    Gini,
+    MutualInfo,
+    RSquared,
+}
+
+impl TightenMetric {
+    fn label(self) -> &'static str {
+        match self {
+            TightenMetric::Gini => "Gini coefficient",
+            TightenMetric::MutualInfo => "Mutual information",
+            TightenMetric::RSquared => "R²",
+        }
+    }
+}
+
+/// New thresholds; `None` keeps the original one
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TightenThresholds {
+    pub missing: Option<f64>,
+    pub gini: Option<f64>,
+}
+
+/// Delta report of a `lophi tighten` run
+#[derive(Debug, Clone, Serialize)]
+pub struct TightenReport {
+    pub timestamp: String,
+    pub lophi_version: String,
+    /// Report the prior metrics were read from
+    pub source_report: String,
+    pub metric: TightenMetric,
+    pub missing_threshold: ThresholdChange,
+    pub gini_threshold: ThresholdChange,
+    /// Features that pass the tighter cut
+    pub kept: Vec<String>,
+    /// Features removed by the tighter cut
+    pub dropped: Vec<TightenDrop>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct ThresholdChange {
+    pub previous: f64,
+    pub current: f64,
+}
+
+/// A feature removed by the tighter cut
+#[derive(Debug, Clone, Serialize)]
+pub struct TightenDrop {
+    pub name: String,
+    pub stage: DropStage,
+    pub reason: String,
+}
+
+// Minimal views of the report JSON, as in `apply`
+#[derive(Deserialize)]
+struct ReportFile {
+    metadata: ReportFileMetadata,
+    features: Vec<ReportFileFeature>,
+}
+
+#[derive(Deserialize)]
+struct ReportFileMetadata {
+    thresholds: ReportFileThresholds,
+    settings: ReportFileSettings,
+}
+
+#[derive(Deserialize)]
+struct ReportFileThresholds {
+    missing_ratio: f64,
+    gini: f64,
+}
+
+#[derive(Deserialize)]
+struct ReportFileSettings {
+    target_column: String,
+    #[serde(default)]
+    univariate_metric: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ReportFileFeature {
+    name: String,
+    status: String,
+    #[serde(default)]
+    analysis: ReportFileAnalysis,
+}
+
+#[derive(Deserialize, Default)]
+struct ReportFileAnalysis {
+    #[serde(default)]
+    missing: Option<ReportFileMissing>,
+    #[serde(default)]
+    gini: Option<ReportFileGini>,
+    #[serde(default)]
+    r_squared: Option<ReportFileRSquared>,
+}
+
+#[derive(Deserialize)]
+struct ReportFileMissing {
+    ratio: f64,
+}
+
+#[derive(Deserialize)]
+struct ReportFileGini {
+    gini: f64,
+    #[serde(default)]
+    mutual_info: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct ReportFileRSquared {
+    r_squared: f64,
+}
+
+impl PriorReduction {
+    /// Load the kept features and their metrics from a report JSON or a report
+    /// ZIP bundle
+    pub fn load(report_path: &Path) -> Result<Self> {
+        let content = read_report_json(report_path)?;
+        Self::from_report_json(&content)
+            .with_context(|| format!("Invalid reduction report: {}", report_path.display()))
+    }
+
+    fn from_report_json(content: &str) -> Result<Self> {
+        let report: ReportFile = serde_json::from_str(content)?;
+        let kept: Vec<ReportFileFeature> = report
+            .features
+            .into_iter()
+            .filter(|f| f.status == "kept" || f.status == USER_RETAINED)
+            .collect();
+
+        let metric = if kept.iter().any(|f| f.analysis.r_squared.is_some()) {
+            TightenMetric::RSquared
+        } else if report.metadata.settings.univariate_metric.as_deref() == Some("mutual_info") {
+            TightenMetric::MutualInfo
+        } else {
+            TightenMetric::Gini
+        };
+
+        let features = kept
+            .into_iter()
+            .map(|f| {
+                let univariate = match metric {
+                    TightenMetric::Gini => f.analysis.gini.map(|g| g.gini.abs()),
+                    TightenMetric::MutualInfo => f.analysis.gini.and_then(|g| g.mutual_info),
+                    TightenMetric::RSquared => f.analysis.r_squared.map(|r| r.r_squared),
+                };
+                PriorFeature {
+                    name: f.name,
+                    missing_ratio: f.analysis.missing.map(|m| m.ratio),
+                    univariate,
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            target_column: report.metadata.settings.target_column,
+            missing_threshold: report.metadata.thresholds.missing_ratio,
+            gini_threshold: report.metadata.thresholds.gini,
+            metric,
+            features,
+        })
+    }
+}
+
+/// Decide which kept features fail the tighter `thresholds`.
+///
+/// The missing cut is applied first, as in the pipeline, so a feature failing
+/// both is reported as a missing drop. Features without a recorded metric
+/// pass that cut.
+///
+/// # Errors
+/// Fails when a threshold is looser than the original one: features the
+/// original run dropped are not in the reduced output and cannot be restored.
+pub fn plan_tightening(
+    prior: &PriorReduction,
+    thresholds: TightenThresholds,
+    source_report: &Path,
+) -> Result<TightenReport> {
+    let missing = thresholds.missing.unwrap_or(prior.missing_threshold);
+    let gini = thresholds.gini.unwrap_or(prior.gini_threshold);
+    if missing > prior.missing_threshold {
+        anyhow::bail!(
+            "--missing-threshold {} is looser than the original {}; rerun the full pipeline to restore dropped features",
+            missing,
+            prior.missing_threshold
+        );
+    }
+    if gini < prior.gini_threshold {
+        anyhow::bail!(
+            "--gini-threshold {} is looser than the original {}; rerun the full pipeline to restore dropped features",
+            gini,
+            prior.gini_threshold
+        );
+    }
+
+    let mut kept = Vec::new();
+    let mut dropped = Vec::new();
+    for feature in &prior.features {
+        if let Some(ratio) = feature.missing_ratio.filter(|r| *r > missing) {
+            dropped.push(TightenDrop {
+                name: feature.name.clone(),
+                stage: DropStage::Missing,
+                reason: format!(
+                    "Missing ratio {:.2} exceeded threshold {:.2}",
+                    ratio, missing
+                ),
+            });
+        } else if let Some(value) = feature.univariate.filter(|v| *v < gini) {
+            dropped.push(TightenDrop {
+                name: feature.name.clone(),
+                stage: DropStage::Gini,
+                reason: format!(
+                    "{} {:.4} below threshold {:.4}",
+                    prior.metric.label(),
+                    value,
+                    gini
+                ),
+            });
+        } else {
+            kept.push(feature.name.clone());
+        }
+    }
+
+    Ok(TightenReport {
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        lophi_version: env!("CARGO_PKG_VERSION").to_string(),
+        source_report: source_report.display().to_string(),
+        metric: prior.metric,
+        missing_threshold: ThresholdChange {
+            previous: prior.missing_threshold,
+            current: missing,
+        },
+        gini_threshold: ThresholdChange {
+            previous: prior.gini_threshold,
+            current: gini,
+        },
+        kept,
+        dropped,
+    })
+}
+
+/// Remove the features dropped by `report` from `df`.
+///
+/// Dropped features that are not in `df` are skipped; every other column,
+/// including the target, is left as is.
+pub fn apply_tightening(df: DataFrame, report: &TightenReport) -> DataFrame {
+    let names: Vec<&str> = report
+        .dropped
+        .iter()
+        .map(|d| d.name.as_str())
+        .filter(|name| df.column(name).is_ok())
+        .collect();
+    df.drop_many(names)
+}
+
+/// Write the delta report as pretty-printed JSON
+pub fn export_tighten_report(report: &TightenReport, path: &Path) -> Result<()> {
+    let json = serde_json::to_string_pretty(report).context("Failed to serialize report")?;
+    std::fs::write(path, json)
+        .with_context(|| format!("Failed to write report: {}", path.display()))
+}
@@ -0,0 +1,293 @@
+//! Stability of the features over time
+//!
+//! A feature can screen well on the pooled rows while its fill rate or its
+//! predictive power is drifting, e.g. a bureau field that stopped being
+//! supplied half way through the sample. With `--time-column` the rows are
+//! sliced into periods (`--time-freq` month, quarter or year) of a date
+//! column, and each surviving feature gets its missing rate and IV in every
+//! period. [`assess_drift`] fits a straight line through each series and flags
+//! the features whose fitted IV or fill rate moves too far from the first to
+//! the last period.
+
+use std::collections::{BTreeSet, HashMap};
+
+use anyhow::{bail, Result};
+use chrono::{Datelike, NaiveDate};
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::temporal::calendar_dates;
+
+/// Fitted IV change from the first to the last period, relative to the mean
+/// IV, above which a feature is flagged as drifting
+pub const IV_DRIFT_THRESHOLD: f64 = 0.5;
+
+/// Fitted fill-rate change from the first to the last period (a share of the
+/// rows, so 0.1 is ten percentage points) above which a feature is flagged
+pub const FILL_RATE_DRIFT_THRESHOLD: f64 = 0.1;
+
+/// Length of the periods the rows are sliced into
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeFreq {
+    #[default]
+    Month,
+    Quarter,
+    Year,
+}
+
+impl TimeFreq {
+    /// Sort key of the period holding `date`: the year and the month,
+    /// quarter or 0
+    fn key(self, date: NaiveDate) -> (i32, u32) {
+        match self {
+            TimeFreq::Month => (date.year(), date.month()),
+            TimeFreq::Quarter => (date.year(), date.month0() / 3 + 1),
+            TimeFreq::Year => (date.year(), 0),
+        }
+    }
+
+    /// Label of the period with sort key `key`, e.g. "2024-03", "2024-Q1" or "2024"
+    fn label(self, (year, part): (i32, u32)) -> String {
+        match self {
+            TimeFreq::Month => format!("{}-{:02}", year, part),
+            TimeFreq::Quarter => format!("{}-Q{}", year, part),
+            TimeFreq::Year => year.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for TimeFreq {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeFreq::Month => write!(f, "month"),
+            TimeFreq::Quarter => write!(f, "quarter"),
+            TimeFreq::Year => write!(f, "year"),
+        }
+    }
+}
+
+impl std::str::FromStr for TimeFreq {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "month" => Ok(TimeFreq::Month),
+            "quarter" => Ok(TimeFreq::Quarter),
+            "year" => Ok(TimeFreq::Year),
+            _ => Err(format!(
+                "Unknown time frequency: '{}'. Use 'month', 'quarter', or 'year'.",
+                s
+            )),
+        }
+    }
+}
+
+/// Settings of a time stability check (`--time-column`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeStabilityCheck {
+    /// Date or datetime column the periods are taken from
+    pub column: String,
+    pub freq: TimeFreq,
+}
+
+/// The periods of a date column and the period of every row
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimePeriods {
+    /// Period labels in time order
+    pub labels: Vec<String>,
+    /// Index into `labels` of each row; `None` for a null date
+    pub rows: Vec<Option<usize>>,
+}
+
+impl TimePeriods {
+    /// Slice the rows of `column` into periods of `freq`.
+    ///
+    /// # Errors
+    /// When `column` is not a date or datetime column.
+    pub fn from_column(column: &Column, freq: TimeFreq) -> Result<Self> {
+        let Some(dates) = calendar_dates(column)? else {
+            bail!(
+                "Time column '{}' must be a date or datetime column, found {}. \
+                 For CSV input, --csv-date-format reads text dates as dates.",
+                column.name(),
+                column.dtype()
+            );
+        };
+        let keys: Vec<Option<(i32, u32)>> = dates.iter().map(|d| d.map(|d| freq.key(d))).collect();
+        let periods: BTreeSet<(i32, u32)> = keys.iter().flatten().copied().collect();
+        let index: HashMap<(i32, u32), usize> =
+            periods.iter().enumerate().map(|(i, &k)| (k, i)).collect();
+        Ok(Self {
+            labels: periods.iter().map(|&k| freq.label(k)).collect(),
+            rows: keys.iter().map(|k| k.map(|k| index[&k])).collect(),
+        })
+    }
+
+    /// Rows without a date, which belong to no period
+    pub fn undated_rows(&self) -> usize {
+        self.rows.iter().filter(|r| r.is_none()).count()
+    }
+
+    /// Mask of the rows in period `period`
+    pub fn mask(&self, period: usize) -> BooleanChunked {
+        let rows: Vec<bool> = self.rows.iter().map(|r| *r == Some(period)).collect();
+        BooleanChunked::from_slice("period".into(), &rows)
+    }
+}
+
+/// One feature in one period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeaturePeriod {
+    pub period: String,
+    pub missing_ratio: f64,
+    /// `None` when the period has too few rows of a target class to score
+    pub iv: Option<f64>,
+}
+
+/// The per-period series of one feature and how far it drifts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureDrift {
+    pub feature: String,
+    pub periods: Vec<FeaturePeriod>,
+    /// Fitted fill-rate change from the first to the last period, in share of
+    /// the rows (-1 to 1)
+    pub fill_rate_change: f64,
+    /// Fitted IV change from the first to the last scored period, relative to
+    /// the feature's mean IV; `None` under two scored periods
+    pub iv_change: Option<f64>,
+    /// `|fill_rate_change|` is above [`FILL_RATE_DRIFT_THRESHOLD`] or
+    /// `|iv_change|` above [`IV_DRIFT_THRESHOLD`]
+    pub drifting: bool,
+}
+
+/// Fit the fill-rate and IV trend of `feature` over `periods` (in time order)
+/// and flag it when either moves too far.
+pub fn assess_drift(feature: &str, periods: Vec<FeaturePeriod>) -> FeatureDrift {
+    let fill_rates: Vec<(f64, f64)> = periods
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (i as f64, 1.0 - p.missing_ratio))
+        .collect();
+    let ivs: Vec<(f64, f64)> = periods
+        .iter()
+        .enumerate()
+        .filter_map(|(i, p)| p.iv.map(|iv| (i as f64, iv)))
+        .collect();
+
+    let fill_rate_change = fitted_change(&fill_rates).unwrap_or(0.0);
+    let iv_change = fitted_change(&ivs).map(|change| {
+        let mean = ivs.iter().map(|(_, iv)| iv).sum::<f64>() / ivs.len() as f64;
+        if mean > 0.0 {
+            change / mean
+        } else {
+            0.0
+        }
+    });
+    let drifting = fill_rate_change.abs() > FILL_RATE_DRIFT_THRESHOLD
+        || iv_change.is_some_and(|c| c.abs() > IV_DRIFT_THRESHOLD);
+
+    FeatureDrift {
+        feature: feature.to_string(),
+        periods,
+        fill_rate_change,
+        iv_change,
+        drifting,
+    }
+}
+
+/// Change of the least-squares line through `points` from the first to the
+/// last x; `None` under two distinct x
+fn fitted_change(points: &[(f64, f64)]) -> Option<f64> {
+    let (first, last) = (points.first()?.0, points.last()?.0);
+    if first == last {
+        return None;
+    }
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let (covariance, variance) = points.iter().fold((0.0, 0.0), |(c, v), (x, y)| {
+        (c + (x - mean_x) * (y - mean_y), v + (x - mean_x).powi(2))
+    });
+    Some(covariance / variance * (last - first))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn period(label: &str, missing_ratio: f64, iv: Option<f64>) -> FeaturePeriod {
+        FeaturePeriod {
+            period: label.to_string(),
+            missing_ratio,
+            iv,
+        }
+    }
+
+    #[test]
+    fn test_periods_in_time_order() {
+        // 2023-12-31, 2024-01-15, null, 2024-04-01 as days since the epoch
+        let dates = Series::new(
+            "app_date".into(),
+            [Some(19_722i32), Some(19_737), None, Some(19_814)],
+        )
+        .cast(&DataType::Date)
+        .unwrap();
+        let column = Column::from(dates);
+
+        let months = TimePeriods::from_column(&column, TimeFreq::Month).unwrap();
+        assert_eq!(months.labels, ["2023-12", "2024-01", "2024-04"]);
+        assert_eq!(months.rows, [Some(0), Some(1), None, Some(2)]);
+        assert_eq!(months.undated_rows(), 1);
+        assert_eq!(months.mask(1).sum(), Some(1));
+
+        let quarters = TimePeriods::from_column(&column, TimeFreq::Quarter).unwrap();
+        assert_eq!(quarters.labels, ["2023-Q4", "2024-Q1", "2024-Q2"]);
+        let years = TimePeriods::from_column(&column, TimeFreq::Year).unwrap();
+        assert_eq!(years.labels, ["2023", "2024"]);
+
+        let text = Column::new("app_date".into(), ["2024-01-15"]);
+        assert!(TimePeriods::from_column(&text, TimeFreq::Month).is_err());
+    }
+
+    #[test]
+    fn test_assess_drift_flags_fading_fill_rate_and_iv() {
+        let steady = assess_drift(
+            "steady",
+            vec![
+                period("2024-01", 0.10, Some(0.30)),
+                period("2024-02", 0.12, Some(0.28)),
+                period("2024-03", 0.10, Some(0.31)),
+            ],
+        );
+        assert!(!steady.drifting);
+
+        // Fill rate falls from 100% to 60%
+        let fading = assess_drift(
+            "fading",
+            vec![
+                period("2024-01", 0.0, Some(0.30)),
+                period("2024-02", 0.2, Some(0.30)),
+                period("2024-03", 0.4, Some(0.30)),
+            ],
+        );
+        assert!((fading.fill_rate_change + 0.4).abs() < 1e-9);
+        assert!(fading.drifting);
+
+        // IV 0.4 -> 0.1 around a mean of 0.25; the unscored period is skipped
+        let weakening = assess_drift(
+            "weakening",
+            vec![
+                period("2024-01", 0.0, Some(0.40)),
+                period("2024-02", 0.0, None),
+                period("2024-03", 0.0, Some(0.10)),
+            ],
+        );
+        assert!((weakening.iv_change.unwrap() + 1.2).abs() < 1e-9);
+        assert!(weakening.drifting);
+
+        let single = assess_drift("single", vec![period("2024-01", 0.5, Some(0.2))]);
+        assert_eq!(single.iv_change, None);
+        assert!(!single.drifting);
+    }
+}
@@ -0,0 +1,98 @@
+//! Weight of Evidence (WoE) encoding of features using fitted IV bins
+//!
+//! Replaces each binned feature with the WoE of the bin its value falls into,
+//! using the bins produced by [`crate::pipeline::iv`]. The same bins can come
+//! from a fresh analysis or from a saved Gini export, so a training set and a
+//! scoring set are encoded identically.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+
+use super::iv::{find_special_bin, find_woe_for_value, FeatureType, IvAnalysis};
+
+/// Category bin that absorbs rare categories during categorical binning
+const OTHER_CATEGORY: &str = "OTHER";
+
+/// Replace every analyzed feature present in `df` with its WoE value.
+///
+/// Encoded columns become `Float64` and keep their names. Values are mapped as
+/// follows:
+/// - numeric: the special bin of the value if it has one, otherwise the bin
+///   whose `[lower_bound, upper_bound)` contains the value
+/// - categorical: the bin listing the category; unseen categories fall back to
+///   the `OTHER` bin when one exists
+/// - null and NaN: the missing bin, whose WoE is its host bin's when
+///   `--missing-bin-policy` folded it into a numeric bin
+///
+/// Anything without a matching bin (e.g. nulls when training had none) encodes
+/// as 0.0, the neutral WoE.
+///
+/// Returns the names of the columns that were encoded, in `analyses` order.
+/// Analyses for columns not in `df` are skipped.
+pub fn apply_woe_transform(df: &mut DataFrame, analyses: &[IvAnalysis]) -> Result<Vec<String>> {
+    let mut encoded = Vec::new();
+
+    for analysis in analyses {
+        let name = analysis.feature_name.as_str();
+        let Ok(column) = df.column(name) else {
+            continue;
+        };
+        let missing_woe = analysis.missing_bin.as_ref().map_or(0.0, |mb| mb.woe);
+
+        let woe: Float64Chunked = match analysis.feature_type {
+            FeatureType::Numeric => {
+                let series = column
+                    .as_materialized_series()
+                    .cast(&DataType::Float64)
+                    .with_context(|| format!("Feature '{}' is not numeric", name))?;
+                series
+                    .f64()?
+                    .iter()
+                    .map(|opt| match opt {
+                        Some(v) if !v.is_nan() => {
+                            Some(match find_special_bin(v, &analysis.special_bins) {
+                                Some(i) => analysis.special_bins[i].woe,
+                                None => find_woe_for_value(v, &analysis.bins),
+                            })
+                        }
+                        _ => Some(missing_woe),
+                    })
+                    .collect()
+            }
+            FeatureType::Categorical => {
+                let lookup = category_woe_lookup(analysis);
+                let other_woe = lookup.get(OTHER_CATEGORY).copied().unwrap_or(0.0);
+                let series = column.as_materialized_series().cast(&DataType::String)?;
+                series
+                    .str()?
+                    .iter()
+                    .map(|opt| match opt {
+                        Some(cat) => Some(lookup.get(cat).copied().unwrap_or(other_woe)),
+                        None => Some(missing_woe),
+                    })
+                    .collect()
+            }
+        };
+
+        df.with_column(woe.with_name(name.into()).into_series())?;
+        encoded.push(analysis.feature_name.clone());
+    }
+
+    Ok(encoded)
+}
+
+/// Map every category (single or merged) to its bin's WoE
+fn category_woe_lookup(analysis: &IvAnalysis) -> HashMap<&str, f64> {
+    let mut lookup = HashMap::new();
+    for bin in &analysis.categories {
+        if !bin.category.is_empty() {
+            lookup.insert(bin.category.as_str(), bin.woe);
+        }
+        for category in &bin.categories {
+            lookup.insert(category.as_str(), bin.woe);
+        }
+    }
+    lookup
+}
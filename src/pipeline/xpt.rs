@@ -0,0 +1,507 @@
+//! SAS transport file writer (`.xpt`, versions 5 and 8)
+//!
+//! Transport files are how SAS-only teams receive data from other tools:
+//! `libname in xport "file.xpt";` reads them in any SAS release. Every
+//! record is 80 bytes; numbers are stored as 8-byte IBM mainframe floats.
+//!
+//! Column types map to SAS types as follows:
+//!
+//! - integers, floats and booleans: numeric (booleans as 0/1)
+//! - `date`: numeric SAS date (days since 1960-01-01) with format `DATE9.`
+//! - `datetime`: numeric SAS datetime (seconds since 1960-01-01) with
+//!   format `DATETIME20.`; `time`: seconds since midnight with `TIME8.`
+//! - `duration`: numeric seconds
+//! - `str`, categorical and enum: character, as wide as the longest value
+//!
+//! Nulls are written as the SAS missing value `.` (blank for character).
+//!
+//! Version 5 is the format every SAS release and regulators accept, but it
+//! limits names to 8 characters and character values to 200 bytes.
+//! Version 8 allows 32-character names and longer values.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use chrono::Local;
+use polars::prelude::*;
+
+/// Days from the SAS epoch (1960-01-01) to 1970-01-01
+const EPOCH_OFFSET_DAYS: i32 = 3_653;
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+const RECORD_LEN: usize = 80;
+const NAMESTR_LEN: usize = 140;
+
+/// Longest name and character value of each version
+const V5_NAME_LEN: usize = 8;
+const V8_NAME_LEN: usize = 32;
+const V5_CHAR_LEN: usize = 200;
+const V8_CHAR_LEN: usize = 32_767;
+
+/// SAS missing value `.` as an IBM float
+const MISSING: [u8; 8] = [0x2e, 0, 0, 0, 0, 0, 0, 0];
+
+/// Transport format version
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XptVersion {
+    V5,
+    V8,
+}
+
+impl XptVersion {
+    /// Version 5 when the column names and values fit its limits, otherwise 8
+    pub fn for_dataframe(df: &DataFrame) -> Self {
+        let short_names = df
+            .get_column_names()
+            .iter()
+            .all(|name| name.len() <= V5_NAME_LEN);
+        let short_values = df.get_columns().iter().all(|column| {
+            column
+                .cast(&DataType::String)
+                .ok()
+                .filter(|_| is_text(column.dtype()))
+                .map(|text| max_byte_len(&text) <= V5_CHAR_LEN)
+                .unwrap_or(true)
+        });
+        if short_names && short_values {
+            XptVersion::V5
+        } else {
+            XptVersion::V8
+        }
+    }
+
+    fn name_limit(self) -> usize {
+        match self {
+            XptVersion::V5 => V5_NAME_LEN,
+            XptVersion::V8 => V8_NAME_LEN,
+        }
+    }
+
+    fn char_limit(self) -> usize {
+        match self {
+            XptVersion::V5 => V5_CHAR_LEN,
+            XptVersion::V8 => V8_CHAR_LEN,
+        }
+    }
+}
+
+/// Write `df` as a SAS transport file. The member (dataset) name is the
+/// file stem, upper-cased and reduced to a valid SAS name.
+pub fn write_xpt(df: &DataFrame, path: &Path, version: XptVersion) -> Result<()> {
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|column| XptColumn::from_column(column, version))
+        .collect::<Result<Vec<_>>>()?;
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("DATA");
+    let member = member_name(stem, version.name_limit());
+
+    let file = File::create(path)
+        .with_context(|| format!("Failed to create output file: {}", path.display()))?;
+    let mut out = BufWriter::new(file);
+    write_members(&mut out, &columns, df.height(), &member, version)
+        .and_then(|_| out.flush().map_err(Into::into))
+        .with_context(|| format!("Failed to write XPT file: {}", path.display()))
+}
+
+fn write_members(
+    out: &mut impl Write,
+    columns: &[XptColumn],
+    rows: usize,
+    member: &str,
+    version: XptVersion,
+) -> Result<()> {
+    let stamp = Local::now()
+        .format("%d%b%y:%H:%M:%S")
+        .to_string()
+        .to_uppercase();
+    let (library, member_header, descriptor, namestr, obs) = match version {
+        XptVersion::V5 => ("LIBRARY", "MEMBER", "DSCRPTR", "NAMESTR", "OBS"),
+        XptVersion::V8 => ("LIBV8", "MEMBV8", "DSCPTV8", "NAMSTV8", "OBSV8"),
+    };
+
+    // Library header
+    out.write_all(&header_record(library, [0; 6]))?;
+    out.write_all(&record(&[
+        &pad("SAS", 8),
+        &pad("SAS", 8),
+        &pad("SASLIB", 8),
+        &pad("9.4", 8),
+        &pad("LOPHI", 8),
+        &pad("", 24),
+        &pad(&stamp, 16),
+    ]))?;
+    out.write_all(&record(&[&pad(&stamp, 16)]))?;
+
+    // Member header and descriptor
+    out.write_all(&header_record(
+        member_header,
+        [0, 0, 0, 160, 0, NAMESTR_LEN],
+    ))?;
+    out.write_all(&header_record(descriptor, [0; 6]))?;
+    let member_field = match version {
+        XptVersion::V5 => pad(member, 8),
+        XptVersion::V8 => pad(member, 32),
+    };
+    let mut descriptor_fields = vec![pad("SAS", 8), member_field, pad("SASDATA", 8)];
+    descriptor_fields.push(pad("9.4", 8));
+    descriptor_fields.push(pad("LOPHI", 8));
+    if version == XptVersion::V5 {
+        descriptor_fields.push(pad("", 24));
+    }
+    descriptor_fields.push(pad(&stamp, 16));
+    let fields: Vec<&[u8]> = descriptor_fields.iter().map(Vec::as_slice).collect();
+    out.write_all(&record(&fields))?;
+    out.write_all(&record(&[
+        &pad(&stamp, 16),
+        &pad("", 16),
+        &pad("", 40),
+        &pad("DATA", 8),
+    ]))?;
+
+    // One namestr per variable
+    out.write_all(&header_record(namestr, [0, columns.len(), 0, 0, 0, 0]))?;
+    let mut namestrs = Vec::with_capacity(columns.len() * NAMESTR_LEN);
+    let mut position = 0;
+    for (index, column) in columns.iter().enumerate() {
+        namestrs.extend_from_slice(&column.namestr(index + 1, position, version));
+        position += column.width();
+    }
+    out.write_all(&pad_to_record(namestrs))?;
+
+    // Observations, back to back, blank-padded to a whole record
+    out.write_all(&header_record(obs, [0; 6]))?;
+    let mut written = 0;
+    for row in 0..rows {
+        for column in columns {
+            let value = column.value(row);
+            out.write_all(&value)?;
+            written += value.len();
+        }
+    }
+    let tail = (RECORD_LEN - written % RECORD_LEN) % RECORD_LEN;
+    out.write_all(&vec![b' '; tail])?;
+    Ok(())
+}
+
+/// A column converted to its SAS representation
+enum XptColumn {
+    Numeric {
+        name: String,
+        values: Vec<Option<f64>>,
+        /// SAS format name and width, e.g. `DATE` 9
+        format: Option<(&'static str, i16)>,
+    },
+    Char {
+        name: String,
+        values: Vec<Option<String>>,
+        width: usize,
+    },
+}
+
+impl XptColumn {
+    fn from_column(column: &Column, version: XptVersion) -> Result<Self> {
+        let name = column.name().to_string();
+        if name.is_empty() || name.len() > version.name_limit() {
+            anyhow::bail!(
+                "Column '{}' cannot be written to XPT v{}: names must be 1-{} characters",
+                name,
+                if version == XptVersion::V5 { 5 } else { 8 },
+                version.name_limit()
+            );
+        }
+
+        let numeric = |values: Vec<Option<f64>>, format| {
+            Ok(XptColumn::Numeric {
+                name: name.clone(),
+                values,
+                format,
+            })
+        };
+        let as_f64 = |column: &Column| -> Result<Vec<Option<f64>>> {
+            Ok(column
+                .cast(&DataType::Float64)?
+                .f64()?
+                .into_iter()
+                .map(|v| v.filter(|v| v.is_finite()))
+                .collect())
+        };
+        let as_i64 = |column: &Column, dtype: &DataType| -> Result<Vec<Option<i64>>> {
+            Ok(column
+                .cast(dtype)?
+                .cast(&DataType::Int64)?
+                .i64()?
+                .into_iter()
+                .collect())
+        };
+
+        match column.dtype() {
+            dtype if dtype.is_primitive_numeric() || dtype == &DataType::Boolean => {
+                numeric(as_f64(column)?, None)
+            }
+            DataType::Date => {
+                let days = column
+                    .cast(&DataType::Int32)?
+                    .i32()?
+                    .into_iter()
+                    .map(|d| d.map(|d| f64::from(d + EPOCH_OFFSET_DAYS)))
+                    .collect();
+                numeric(days, Some(("DATE", 9)))
+            }
+            DataType::Datetime(_, _) => {
+                let millis = as_i64(column, &DataType::Datetime(TimeUnit::Milliseconds, None))?;
+                let seconds = millis
+                    .into_iter()
+                    .map(|ms| {
+                        ms.map(|ms| {
+                            ms as f64 / 1000.0 + f64::from(EPOCH_OFFSET_DAYS) * SECONDS_PER_DAY
+                        })
+                    })
+                    .collect();
+                numeric(seconds, Some(("DATETIME", 20)))
+            }
+            DataType::Time => {
+                let nanos = as_i64(column, &DataType::Time)?;
+                let seconds = nanos
+                    .into_iter()
+                    .map(|ns| ns.map(|ns| ns as f64 / 1e9))
+                    .collect();
+                numeric(seconds, Some(("TIME", 8)))
+            }
+            DataType::Duration(_) => {
+                let millis = as_i64(column, &DataType::Duration(TimeUnit::Milliseconds))?;
+                let seconds = millis
+                    .into_iter()
+                    .map(|ms| ms.map(|ms| ms as f64 / 1000.0))
+                    .collect();
+                numeric(seconds, None)
+            }
+            dtype if is_text(dtype) => {
+                let text = column.cast(&DataType::String)?;
+                let width = max_byte_len(&text).max(1);
+                if width > version.char_limit() {
+                    anyhow::bail!(
+                        "Column '{}' has values of {} bytes; XPT v{} allows at most {}",
+                        name,
+                        width,
+                        if version == XptVersion::V5 { 5 } else { 8 },
+                        version.char_limit()
+                    );
+                }
+                let values = text
+                    .str()?
+                    .into_iter()
+                    .map(|v| v.map(str::to_string))
+                    .collect();
+                Ok(XptColumn::Char {
+                    name,
+                    values,
+                    width,
+                })
+            }
+            other => anyhow::bail!(
+                "Column '{}' has type {}, which cannot be written to XPT",
+                name,
+                other
+            ),
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            XptColumn::Numeric { name, .. } | XptColumn::Char { name, .. } => name,
+        }
+    }
+
+    /// Bytes per observation
+    fn width(&self) -> usize {
+        match self {
+            XptColumn::Numeric { .. } => 8,
+            XptColumn::Char { width, .. } => *width,
+        }
+    }
+
+    fn value(&self, row: usize) -> Vec<u8> {
+        match self {
+            XptColumn::Numeric { values, .. } => match values[row] {
+                Some(v) => ieee_to_ibm(v).to_vec(),
+                None => MISSING.to_vec(),
+            },
+            XptColumn::Char { values, width, .. } => {
+                let mut bytes = values[row].as_deref().unwrap_or("").as_bytes().to_vec();
+                bytes.resize(*width, b' ');
+                bytes
+            }
+        }
+    }
+
+    /// The 140-byte variable descriptor
+    fn namestr(&self, number: usize, position: usize, version: XptVersion) -> Vec<u8> {
+        let (sas_type, format) = match self {
+            XptColumn::Numeric { format, .. } => (1i16, *format),
+            XptColumn::Char { .. } => (2i16, None),
+        };
+        let (format_name, format_width) = format.unwrap_or(("", 0));
+
+        let mut out = Vec::with_capacity(NAMESTR_LEN);
+        out.extend_from_slice(&sas_type.to_be_bytes());
+        out.extend_from_slice(&0i16.to_be_bytes()); // hash
+        out.extend_from_slice(&(self.width() as i16).to_be_bytes());
+        out.extend_from_slice(&(number as i16).to_be_bytes());
+        out.extend_from_slice(&pad(truncate(self.name(), V5_NAME_LEN), 8));
+        out.extend_from_slice(&pad("", 40)); // label
+        out.extend_from_slice(&pad(format_name, 8));
+        out.extend_from_slice(&format_width.to_be_bytes());
+        out.extend_from_slice(&0i16.to_be_bytes()); // format decimals
+        out.extend_from_slice(&0i16.to_be_bytes()); // left-justified
+        out.extend_from_slice(&[0; 2]);
+        out.extend_from_slice(&pad("", 8)); // informat
+        out.extend_from_slice(&0i16.to_be_bytes());
+        out.extend_from_slice(&0i16.to_be_bytes());
+        out.extend_from_slice(&(position as i32).to_be_bytes());
+        match version {
+            XptVersion::V5 => out.extend_from_slice(&[0; 52]),
+            XptVersion::V8 => {
+                out.extend_from_slice(&pad(self.name(), 32)); // long name
+                out.extend_from_slice(&0i16.to_be_bytes()); // label length
+                out.extend_from_slice(&[0; 18]);
+            }
+        }
+        out
+    }
+}
+
+fn is_text(dtype: &DataType) -> bool {
+    matches!(
+        dtype,
+        DataType::String | DataType::Categorical(_, _) | DataType::Enum(_, _)
+    )
+}
+
+fn max_byte_len(text: &Column) -> usize {
+    text.str()
+        .map(|s| s.into_iter().flatten().map(str::len).max().unwrap_or(0))
+        .unwrap_or(0)
+}
+
+/// Convert an IEEE 754 double to an IBM System/360 hexadecimal float.
+///
+/// IBM floats are `fraction * 16^(exponent - 64)` with a 56-bit fraction in
+/// [1/16, 1). Values too small for the format become 0 and values too large
+/// saturate at its largest magnitude.
+pub(crate) fn ieee_to_ibm(value: f64) -> [u8; 8] {
+    let bits = value.to_bits();
+    let sign = ((bits >> 63) as u8) << 7;
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    if biased == 0 {
+        // Zero and subnormals are far below the IBM range
+        return [0; 8];
+    }
+
+    // value = mantissa * 2^exponent with the 53-bit mantissa read as [0.5, 1)
+    let mantissa = (bits & 0x000f_ffff_ffff_ffff) | 0x0010_0000_0000_0000;
+    let exponent = biased - 1022;
+    // Smallest base-16 exponent with 16^hex_exponent >= 2^exponent
+    let hex_exponent = (exponent + 3).div_euclid(4);
+    let shift = 3 + exponent - 4 * hex_exponent;
+    let fraction = mantissa << shift;
+
+    let biased_hex = hex_exponent + 64;
+    if biased_hex < 0 {
+        return [0; 8];
+    }
+    if biased_hex > 127 {
+        return [sign | 0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff];
+    }
+    let mut out = [0u8; 8];
+    out[0] = sign | biased_hex as u8;
+    out[1..].copy_from_slice(&fraction.to_be_bytes()[1..]);
+    out
+}
+
+/// `HEADER RECORD*******<name>HEADER RECORD!!!!!!!` followed by six
+/// five-digit numbers
+fn header_record(name: &str, numbers: [usize; 6]) -> Vec<u8> {
+    let digits: String = numbers.iter().map(|n| format!("{:05}", n)).collect();
+    let text = format!(
+        "HEADER RECORD*******{:<8}HEADER RECORD!!!!!!!{}",
+        name, digits
+    );
+    pad(&text, RECORD_LEN)
+}
+
+/// Concatenate `fields` into one blank-padded record
+fn record(fields: &[&[u8]]) -> Vec<u8> {
+    pad_to_record(fields.concat())
+}
+
+fn pad_to_record(mut bytes: Vec<u8>) -> Vec<u8> {
+    let len = bytes.len().div_ceil(RECORD_LEN).max(1) * RECORD_LEN;
+    bytes.resize(len, b' ');
+    bytes
+}
+
+/// `text` blank-padded (or cut) to `width` bytes
+fn pad(text: &str, width: usize) -> Vec<u8> {
+    let mut bytes = text.as_bytes().to_vec();
+    bytes.resize(width, b' ');
+    bytes
+}
+
+fn truncate(text: &str, max: usize) -> &str {
+    let mut end = text.len().min(max);
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+/// SAS dataset name from a file stem: upper case, letters, digits and
+/// underscores, not starting with a digit
+fn member_name(stem: &str, max: usize) -> String {
+    let mut name: String = stem
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    truncate(&name, max).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ieee_to_ibm_known_values() {
+        assert_eq!(ieee_to_ibm(0.0), [0; 8]);
+        assert_eq!(ieee_to_ibm(1.0), [0x41, 0x10, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(ieee_to_ibm(-118.625), [0xc2, 0x76, 0xa0, 0, 0, 0, 0, 0]);
+        assert_eq!(ieee_to_ibm(0.5), [0x40, 0x80, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(ieee_to_ibm(100.0), [0x42, 0x64, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_member_name() {
+        assert_eq!(member_name("loans_2024-q1", 8), "LOANS_20");
+        assert_eq!(member_name("2024 book", 32), "_2024_BOOK");
+    }
+
+    #[test]
+    fn test_header_record_layout() {
+        let header = header_record("MEMBER", [0, 0, 0, 160, 0, 140]);
+        assert_eq!(
+            String::from_utf8(header).unwrap(),
+            "HEADER RECORD*******MEMBER  HEADER RECORD!!!!!!!000000000000000001600000000140  "
+        );
+    }
+}
@@ -0,0 +1,1098 @@
+//! Programmatic entry point to the reduction pipeline
+//!
+//! [`Reducer`] runs the same stages as the CLI (non-finite handling → date
+//! policy → near-constant pre-filter → ID column check → missing → Gini/IV →
+//! correlation) against a DataFrame or a file on disk and returns a
+//! [`ReductionResult`] instead of printing progress, writing output files or
+//! packaging reports. Terminal progress bars are suppressed by routing stage
+//! updates through a progress channel; pass your own sender with
+//! [`Reducer::progress`] to observe them.
+//!
+//! ```no_run
+//! use lophi::Reducer;
+//!
+//! let result = Reducer::from_path("data.parquet", "target")
+//!     .missing_threshold(0.3)
+//!     .gini_threshold(0.05)
+//!     .correlation_threshold(0.4)
+//!     .run()?;
+//!
+//! println!("kept: {:?}", result.kept_features());
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+
+use crate::pipeline::split::train_partition;
+use crate::pipeline::step::RegisteredStep;
+use crate::pipeline::{
+    analyze_cardinality, analyze_dominance, analyze_features_iv_with_progress,
+    analyze_features_r2_with_progress, analyze_features_with_bins, analyze_missing_values,
+    analyze_target_column, apply_date_policy, apply_non_finite_policy, apply_woe_transform,
+    check_target_classes, compute_concordance, create_progress_channel, exclude_target_pairs,
+    fast_screen_sample, find_correlated_pairs_auto_with_progress, fit_cart_trees,
+    get_dominated_features_with_keep, get_id_like_features_with_keep, get_weights,
+    load_dataset_columns, merge_insignificant_bins, mutual_information, resolve_correlated_pairs,
+    run_step, AssociationThresholds, BinBreaks, BinningStrategy, CardinalityStats, CartTree,
+    ConcordanceStats, CorrKeepPolicy, CorrelatedPair, CorrelationStrategy, CsvDialect, DatePolicy,
+    DominanceStats, ExternalBins, FeatureMetadata, FeatureToDrop, FeatureType, IdAction,
+    IvAnalysis, MissingBinPolicy, NonFiniteCounts, NonFinitePolicy, ProgressSender,
+    RSquaredAnalysis, ReductionStep, SolverConfig, SpecialValues, StepContext, StepOutcome,
+    StepPosition, TargetAnalysis, TargetMapping, TargetType, TemporalColumn, UnivariateMetric,
+    FAST_SCREEN_BINS,
+};
+
+/// Where the reducer reads its data from
+#[derive(Debug, Clone)]
+enum ReducerInput {
+    DataFrame(DataFrame),
+    Path(PathBuf),
+}
+
+/// Builder for a headless reduction run.
+///
+/// Defaults match the CLI defaults, except that the MIP solver is disabled
+/// unless [`Reducer::solver`] is called.
+#[derive(Debug, Clone)]
+pub struct Reducer {
+    input: ReducerInput,
+    target: String,
+    target_type: TargetType,
+    target_mapping: Option<TargetMapping>,
+    weight_column: Option<String>,
+    /// (split column, train value)
+    split: Option<(String, String)>,
+    columns_to_drop: Vec<String>,
+    keep_columns: HashSet<String>,
+    dominance_threshold: Option<f64>,
+    id_threshold: Option<f64>,
+    id_action: IdAction,
+    missing_threshold: f64,
+    gini_threshold: f64,
+    univariate_metric: UnivariateMetric,
+    correlation_threshold: f64,
+    cramers_v_threshold: Option<f64>,
+    correlation_strategy: CorrelationStrategy,
+    corr_keep_policy: CorrKeepPolicy,
+    gini_bins: usize,
+    prebins: usize,
+    binning_strategy: BinningStrategy,
+    bin_breaks: Option<BinBreaks>,
+    bins_from: Option<ExternalBins>,
+    merge_insignificant_bins: Option<f64>,
+    special_values: Option<SpecialValues>,
+    missing_bin_policy: MissingBinPolicy,
+    cart_min_bin_pct: f64,
+    cart_trees: bool,
+    min_category_samples: usize,
+    solver_config: Option<SolverConfig>,
+    fast: bool,
+    fixed_algorithms: bool,
+    non_finite_policy: NonFinitePolicy,
+    date_policy: DatePolicy,
+    infer_schema_length: usize,
+    sheet: Option<String>,
+    csv: CsvDialect,
+    progress_tx: Option<ProgressSender>,
+    steps: Vec<CustomStep>,
+}
+
+impl Reducer {
+    fn new(input: ReducerInput, target: &str) -> Self {
+        Self {
+            input,
+            target: target.to_string(),
+            target_type: TargetType::default(),
+            target_mapping: None,
+            weight_column: None,
+            split: None,
+            columns_to_drop: Vec::new(),
+            keep_columns: HashSet::new(),
+            dominance_threshold: None,
+            id_threshold: None,
+            id_action: IdAction::default(),
+            missing_threshold: 0.3,
+            gini_threshold: 0.05,
+            univariate_metric: UnivariateMetric::default(),
+            correlation_threshold: 0.40,
+            cramers_v_threshold: None,
+            correlation_strategy: CorrelationStrategy::default(),
+            corr_keep_policy: CorrKeepPolicy::default(),
+            gini_bins: 10,
+            prebins: 20,
+            binning_strategy: BinningStrategy::default(),
+            bin_breaks: None,
+            bins_from: None,
+            merge_insignificant_bins: None,
+            special_values: None,
+            missing_bin_policy: MissingBinPolicy::default(),
+            cart_min_bin_pct: 5.0,
+            cart_trees: false,
+            min_category_samples: 5,
+            solver_config: None,
+            fast: false,
+            fixed_algorithms: false,
+            non_finite_policy: NonFinitePolicy::default(),
+            date_policy: DatePolicy::default(),
+            infer_schema_length: 10000,
+            sheet: None,
+            csv: CsvDialect::default(),
+            progress_tx: None,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Reduce an in-memory DataFrame
+    pub fn from_dataframe(df: DataFrame, target: &str) -> Self {
+        Self::new(ReducerInput::DataFrame(df), target)
+    }
+
+    /// Reduce a CSV, Parquet or SAS7BDAT file
+    pub fn from_path(path: impl AsRef<Path>, target: &str) -> Self {
+        Self::new(ReducerInput::Path(path.as_ref().to_path_buf()), target)
+    }
+
+    /// Binary (IV/Gini) or continuous (R²) target.
+    ///
+    /// With a continuous target the Gini threshold is applied to each
+    /// feature's binned R², and no target mapping may be set.
+    pub fn target_type(mut self, target_type: TargetType) -> Self {
+        self.target_type = target_type;
+        self
+    }
+
+    /// Map a non-binary target column to 0/1
+    pub fn target_mapping(mut self, mapping: TargetMapping) -> Self {
+        self.target_mapping = Some(mapping);
+        self
+    }
+
+    /// Use a column of sample weights for all weighted statistics
+    pub fn weight_column(mut self, column: &str) -> Self {
+        self.weight_column = Some(column.to_string());
+        self
+    }
+
+    /// Compute statistics on the rows where `column` equals `train_value` only.
+    ///
+    /// The drops are still applied to every row, and the split column is kept in
+    /// the reduced dataset.
+    pub fn split_column(mut self, column: &str, train_value: &str) -> Self {
+        self.split = Some((column.to_string(), train_value.to_string()));
+        self
+    }
+
+    /// Columns removed before any analysis (unknown names are ignored)
+    pub fn drop_columns<I, S>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.columns_to_drop = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Features that no stage drops, whatever their missing ratio, Gini or
+    /// correlations. A correlated partner of a kept feature is dropped instead.
+    pub fn keep_columns<I, S>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.keep_columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Drop constant features and features where one value covers more than
+    /// `threshold` of the rows, before missing analysis. Off by default.
+    pub fn dominance_threshold(mut self, threshold: f64) -> Self {
+        self.dominance_threshold = Some(threshold);
+        self
+    }
+
+    /// Look for likely ID columns: string, categorical, integer and date
+    /// columns whose distinct values make up more than `threshold` of their
+    /// non-null values. Off by default.
+    pub fn id_threshold(mut self, threshold: f64) -> Self {
+        self.id_threshold = Some(threshold);
+        self
+    }
+
+    /// Whether the columns [`Reducer::id_threshold`] finds are only listed in
+    /// [`ReductionResult::id_like`] (default) or dropped
+    pub fn id_action(mut self, action: IdAction) -> Self {
+        self.id_action = action;
+        self
+    }
+
+    pub fn missing_threshold(mut self, threshold: f64) -> Self {
+        self.missing_threshold = threshold;
+        self
+    }
+
+    pub fn gini_threshold(mut self, threshold: f64) -> Self {
+        self.gini_threshold = threshold;
+        self
+    }
+
+    /// Metric compared against the Gini threshold for a binary target
+    pub fn univariate_metric(mut self, metric: UnivariateMetric) -> Self {
+        self.univariate_metric = metric;
+        self
+    }
+
+    pub fn correlation_threshold(mut self, threshold: f64) -> Self {
+        self.correlation_threshold = threshold;
+        self
+    }
+
+    /// Threshold for Cramér's V between categorical features; defaults to the
+    /// correlation threshold
+    pub fn cramers_v_threshold(mut self, threshold: f64) -> Self {
+        self.cramers_v_threshold = Some(threshold);
+        self
+    }
+
+    /// Resolve correlated features pair by pair (default) or keep one
+    /// representative per cluster
+    pub fn correlation_strategy(mut self, strategy: CorrelationStrategy) -> Self {
+        self.correlation_strategy = strategy;
+        self
+    }
+
+    /// Which member of a correlated pair is kept; higher IV by default
+    pub fn corr_keep_policy(mut self, policy: CorrKeepPolicy) -> Self {
+        self.corr_keep_policy = policy;
+        self
+    }
+
+    pub fn gini_bins(mut self, bins: usize) -> Self {
+        self.gini_bins = bins;
+        self
+    }
+
+    pub fn prebins(mut self, prebins: usize) -> Self {
+        self.prebins = prebins;
+        self
+    }
+
+    pub fn binning_strategy(mut self, strategy: BinningStrategy) -> Self {
+        self.binning_strategy = strategy;
+        self
+    }
+
+    /// Breakpoints for [`BinningStrategy::Manual`]; numeric features without
+    /// any use CART binning
+    pub fn bin_breaks(mut self, breaks: BinBreaks) -> Self {
+        self.bin_breaks = Some(breaks);
+        self
+    }
+
+    /// Evaluate IV, WoE and Gini on these bins instead of searching for bins;
+    /// features without a definition are not scored
+    pub fn bins_from(mut self, bins: ExternalBins) -> Self {
+        self.bins_from = Some(bins);
+        self
+    }
+
+    /// After binning, merge adjacent numeric bins whose chi-square p-value
+    /// exceeds `alpha`
+    pub fn merge_insignificant_bins(mut self, alpha: f64) -> Self {
+        self.merge_insignificant_bins = Some(alpha);
+        self
+    }
+
+    /// Give these values of numeric features a bin of their own instead of
+    /// binning them with the numeric ranges (`--special-values`). Only
+    /// applies to a binary target.
+    pub fn special_values(mut self, values: SpecialValues) -> Self {
+        self.special_values = Some(values);
+        self
+    }
+
+    /// Keep missing values of numeric features in a bin of their own
+    /// (default), fold them into the riskiest bin, or bin them at the median.
+    /// Only applies to a binary target.
+    pub fn missing_bin_policy(mut self, policy: MissingBinPolicy) -> Self {
+        self.missing_bin_policy = policy;
+        self
+    }
+
+    /// Minimum CART bin size as a percentage of rows (0-100)
+    pub fn cart_min_bin_pct(mut self, pct: f64) -> Self {
+        self.cart_min_bin_pct = pct;
+        self
+    }
+
+    /// Also return the fitted CART prebinning tree of each numeric feature
+    /// ([`ReductionResult::cart_trees`]). Only applies to CART binning of a
+    /// binary target.
+    pub fn cart_trees(mut self, enabled: bool) -> Self {
+        self.cart_trees = enabled;
+        self
+    }
+
+    pub fn min_category_samples(mut self, samples: usize) -> Self {
+        self.min_category_samples = samples;
+        self
+    }
+
+    /// Enable MIP-based optimal binning with the given solver settings
+    pub fn solver(mut self, config: SolverConfig) -> Self {
+        self.solver_config = Some(config);
+        self
+    }
+
+    /// Fast screen (`--fast`): decile bins without the solver and
+    /// correlations on a row sample. Takes precedence over the binning and
+    /// solver settings; the results are approximate.
+    pub fn fast(mut self, enabled: bool) -> Self {
+        self.fast = enabled;
+        self
+    }
+
+    /// Deterministic mode for tests: the solver runs without a time limit,
+    /// every stage runs on a single thread, and `--fast` computes correlations
+    /// on all rows instead of a sample. Results then depend only on the data
+    /// and settings, not on machine load.
+    pub fn with_fixed_algorithms(mut self) -> Self {
+        self.fixed_algorithms = true;
+        self
+    }
+
+    pub fn non_finite_policy(mut self, policy: NonFinitePolicy) -> Self {
+        self.non_finite_policy = policy;
+        self
+    }
+
+    /// Keep (default), drop, or derive numeric features from date and
+    /// datetime columns before the screening stages
+    pub fn date_policy(mut self, policy: DatePolicy) -> Self {
+        self.date_policy = policy;
+        self
+    }
+
+    /// Rows used for CSV schema inference when reading from a path (0 = full scan)
+    pub fn infer_schema_length(mut self, rows: usize) -> Self {
+        self.infer_schema_length = rows;
+        self
+    }
+
+    /// Worksheet to read when the input path is an Excel workbook (default: first sheet)
+    pub fn sheet(mut self, name: &str) -> Self {
+        self.sheet = Some(name.to_string());
+        self
+    }
+
+    /// Separator, quoting, decimal mark, date format and null text of a CSV input path
+    pub fn csv_dialect(mut self, dialect: CsvDialect) -> Self {
+        self.csv = dialect;
+        self
+    }
+
+    /// Receive stage progress events while the reducer runs
+    pub fn progress(mut self, tx: ProgressSender) -> Self {
+        self.progress_tx = Some(tx);
+        self
+    }
+
+    /// Run `step` at `position`, after the steps registered there before it.
+    ///
+    /// The step sees the training rows and the features left by the stages
+    /// before it; its drops are applied like those of the built-in stages and
+    /// its outcome is returned in [`ReductionResult::custom_steps`].
+    pub fn step<S: ReductionStep + 'static>(mut self, position: StepPosition, step: S) -> Self {
+        self.steps.push(CustomStep {
+            name: step.name().to_string(),
+            position,
+            step: Arc::new(step),
+        });
+        self
+    }
+
+    /// Run all stages and return the reduced data together with every intermediate result.
+    pub fn run(self) -> Result<ReductionResult> {
+        if self.fixed_algorithms {
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(1).build()?;
+            return pool.install(|| self.run_stages());
+        }
+        self.run_stages()
+    }
+
+    fn run_stages(mut self) -> Result<ReductionResult> {
+        if self.fixed_algorithms {
+            if let Some(config) = self.solver_config.as_mut() {
+                config.timeout_seconds = 0;
+            }
+        }
+        if self.fast {
+            self.binning_strategy = BinningStrategy::Quantile;
+            self.bin_breaks = None;
+            self.gini_bins = FAST_SCREEN_BINS;
+            self.prebins = FAST_SCREEN_BINS;
+            self.solver_config = None;
+            self.merge_insignificant_bins = None;
+        }
+
+        // Without a caller-supplied sender the events go nowhere, which keeps the
+        // pipeline functions from drawing indicatif bars on the terminal.
+        let tx = match self.progress_tx.clone() {
+            Some(tx) => tx,
+            None => create_progress_channel().0,
+        };
+
+        let mut df = match &self.input {
+            ReducerInput::DataFrame(df) => df.clone(),
+            ReducerInput::Path(path) => {
+                load_dataset_columns(
+                    path,
+                    self.infer_schema_length,
+                    self.sheet.as_deref(),
+                    None,
+                    &self.csv,
+                    Some(&tx),
+                )?
+                .0
+            }
+        };
+
+        let existing: Vec<String> = df
+            .get_column_names()
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let drops: Vec<String> = self
+            .columns_to_drop
+            .iter()
+            .filter(|c| existing.contains(c))
+            .cloned()
+            .collect();
+        if !drops.is_empty() {
+            df = df.drop_many(&drops);
+        }
+
+        if df.column(&self.target).is_err() {
+            anyhow::bail!("Target column '{}' not found in dataset", self.target);
+        }
+        let continuous = self.target_type == TargetType::Continuous;
+        if continuous && self.target_mapping.is_some() {
+            anyhow::bail!("A target mapping cannot be used with a continuous target");
+        }
+        if continuous && self.univariate_metric != UnivariateMetric::Iv {
+            anyhow::bail!("Mutual information requires a binary target");
+        }
+        if self.binning_strategy == BinningStrategy::Manual && self.bin_breaks.is_none() {
+            anyhow::bail!("BinningStrategy::Manual needs breakpoints from Reducer::bin_breaks");
+        }
+        if continuous && self.bin_breaks.is_some() {
+            anyhow::bail!("Bin breaks require a binary target");
+        }
+        if continuous && self.bins_from.is_some() {
+            anyhow::bail!("External bins require a binary target");
+        }
+        if self.bins_from.is_some()
+            && (self.bin_breaks.is_some()
+                || self.special_values.is_some()
+                || self.missing_bin_policy != MissingBinPolicy::Separate
+                || self.merge_insignificant_bins.is_some())
+        {
+            anyhow::bail!(
+                "External bins replace bin breaks, special values, the missing bin policy \
+                 and bin merging"
+            );
+        }
+        if continuous && self.missing_bin_policy != MissingBinPolicy::Separate {
+            anyhow::bail!("A missing bin policy other than 'separate' requires a binary target");
+        }
+        if !continuous && self.target_mapping.is_none() {
+            if let TargetAnalysis::NeedsMapping { unique_values } =
+                analyze_target_column(&df, &self.target)?
+            {
+                anyhow::bail!(
+                    "Target column '{}' is not binary (0/1). Found {} unique values; \
+                     provide a TargetMapping via Reducer::target_mapping",
+                    self.target,
+                    unique_values.len()
+                );
+            }
+        }
+
+        let weight_column = self.weight_column.as_deref();
+        let split_column = self.split.as_ref().map(|(column, _)| column.as_str());
+        let mut weights = get_weights(&df, weight_column)?;
+
+        // Non-finite values
+        let mut exclude = vec![self.target.as_str()];
+        exclude.extend(weight_column);
+        exclude.extend(split_column);
+        let non_finite = apply_non_finite_policy(&mut df, self.non_finite_policy, &exclude)?;
+
+        // Date and datetime features
+        let temporal = apply_date_policy(&mut df, self.date_policy, &exclude)?;
+        let initial_features = df.width().saturating_sub(
+            1 + usize::from(weight_column.is_some()) + usize::from(split_column.is_some()),
+        );
+
+        // Training rows only; `full_df` receives the drops at the end
+        let mut full_df = None;
+        if let Some((column, train_value)) = &self.split {
+            let (train_df, train_weights) = train_partition(&df, &weights, column, train_value)?;
+            full_df = Some(std::mem::replace(&mut df, train_df));
+            weights = train_weights;
+        }
+        if !continuous {
+            check_target_classes(&df, &self.target, self.target_mapping.as_ref())?;
+        }
+
+        // Constant and near-constant features
+        let mut dominance = Vec::new();
+        let mut dropped_constant = Vec::new();
+        if let Some(threshold) = self.dominance_threshold {
+            dominance = analyze_dominance(&df, &weights, weight_column)?;
+            dominance.retain(|s| !exclude.contains(&s.feature.as_str()));
+            dropped_constant = get_dominated_features_with_keep(
+                &dominance,
+                threshold,
+                &self.target,
+                &self.keep_columns,
+            );
+            if !dropped_constant.is_empty() {
+                df = df.drop_many(&dropped_constant);
+            }
+        }
+
+        // Likely ID columns
+        let mut cardinality = Vec::new();
+        let mut id_like = Vec::new();
+        let mut dropped_identifier = Vec::new();
+        if let Some(threshold) = self.id_threshold {
+            cardinality = analyze_cardinality(&df, weight_column)?;
+            cardinality.retain(|s| !exclude.contains(&s.feature.as_str()));
+            id_like = get_id_like_features_with_keep(
+                &cardinality,
+                threshold,
+                &self.target,
+                &self.keep_columns,
+            );
+            if self.id_action == IdAction::Drop && !id_like.is_empty() {
+                df = df.drop_many(&id_like);
+                dropped_identifier = id_like.clone();
+            }
+        }
+
+        let ctx = StepContext {
+            target: &self.target,
+            weights: &weights,
+            weight_column,
+            keep_columns: &self.keep_columns,
+        };
+        let mut custom_steps = Vec::new();
+        self.run_custom_steps(
+            StepPosition::BeforeMissing,
+            &mut df,
+            &ctx,
+            &mut custom_steps,
+        )?;
+
+        // Missing values
+        let missing = MissingStep {
+            threshold: self.missing_threshold,
+        };
+        let (missing_ratios, dropped) = run_step(&missing, &mut df, &ctx)?;
+        let dropped_missing = feature_names(dropped);
+
+        // Gini/IV, or binned R² for a continuous target
+        let univariate = UnivariateStep {
+            reducer: &self,
+            continuous,
+            tx: &tx,
+        };
+        let (univariate, dropped) = run_step(&univariate, &mut df, &ctx)?;
+        let dropped_gini = feature_names(dropped);
+        let UnivariateAnalysis {
+            iv_analyses,
+            r2_analyses,
+            concordance,
+            cart_trees,
+        } = univariate;
+
+        // Correlation (IV-first drop logic, same metadata as the CLI)
+        let missing_lookup: HashMap<&str, f64> = missing_ratios
+            .iter()
+            .map(|(n, r)| (n.as_str(), *r))
+            .collect();
+        let mut feature_metadata = HashMap::with_capacity(iv_analyses.len() + r2_analyses.len());
+        let mut feature_types: HashMap<String, FeatureType> =
+            HashMap::with_capacity(iv_analyses.len() + r2_analyses.len());
+        for a in &iv_analyses {
+            feature_types.insert(a.feature_name.clone(), a.feature_type);
+            feature_metadata.insert(
+                a.feature_name.clone(),
+                FeatureMetadata {
+                    iv: Some(a.iv),
+                    r_squared: None,
+                    missing_ratio: missing_lookup.get(a.feature_name.as_str()).copied(),
+                },
+            );
+        }
+        for a in &r2_analyses {
+            feature_types.insert(a.feature_name.clone(), a.feature_type);
+            feature_metadata.insert(
+                a.feature_name.clone(),
+                FeatureMetadata {
+                    iv: None,
+                    r_squared: Some(a.r_squared),
+                    missing_ratio: missing_lookup.get(a.feature_name.as_str()).copied(),
+                },
+            );
+        }
+        let correlation = CorrelationStep {
+            reducer: &self,
+            continuous,
+            feature_metadata: &feature_metadata,
+            feature_types: &feature_types,
+            tx: &tx,
+        };
+        let (correlation, dropped_correlation) = run_step(&correlation, &mut df, &ctx)?;
+        let correlated_pairs = correlation.pairs;
+
+        self.run_custom_steps(
+            StepPosition::AfterCorrelation,
+            &mut df,
+            &ctx,
+            &mut custom_steps,
+        )?;
+
+        if let Some(full) = full_df {
+            let dropped: Vec<String> = dropped_constant
+                .iter()
+                .chain(&dropped_identifier)
+                .chain(&dropped_missing)
+                .chain(&dropped_gini)
+                .cloned()
+                .chain(dropped_correlation.iter().map(|f| f.feature.clone()))
+                .chain(
+                    custom_steps
+                        .iter()
+                        .flat_map(|s| s.dropped.iter().map(|f| f.feature.clone())),
+                )
+                .collect();
+            df = full.drop_many(&dropped);
+        }
+
+        Ok(ReductionResult {
+            dataframe: df,
+            approximate: self.fast,
+            target: self.target,
+            weight_column: self.weight_column,
+            split_column: self.split.map(|(column, _)| column),
+            initial_features,
+            non_finite,
+            temporal,
+            dominance,
+            dropped_constant,
+            cardinality,
+            id_like,
+            dropped_identifier,
+            missing_ratios,
+            dropped_missing,
+            iv_analyses,
+            r2_analyses,
+            cart_trees,
+            concordance,
+            dropped_gini,
+            correlated_pairs,
+            dropped_correlation,
+            custom_steps,
+        })
+    }
+
+    /// Run the registered steps at `position` in registration order
+    fn run_custom_steps(
+        &self,
+        position: StepPosition,
+        df: &mut DataFrame,
+        ctx: &StepContext,
+        outcomes: &mut Vec<StepOutcome>,
+    ) -> Result<()> {
+        for custom in self.steps.iter().filter(|s| s.position == position) {
+            let outcome = custom
+                .step
+                .run_registered(df, ctx, position)
+                .with_context(|| format!("Step '{}' failed", custom.name))?;
+            outcomes.push(outcome);
+        }
+        Ok(())
+    }
+}
+
+fn feature_names(dropped: Vec<FeatureToDrop>) -> Vec<String> {
+    dropped.into_iter().map(|f| f.feature).collect()
+}
+
+/// A step added with [`Reducer::step`]
+#[derive(Clone)]
+struct CustomStep {
+    name: String,
+    position: StepPosition,
+    step: Arc<dyn RegisteredStep>,
+}
+
+impl std::fmt::Debug for CustomStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CustomStep")
+            .field("name", &self.name)
+            .field("position", &self.position)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Missing-value step: drops features above the missing threshold
+struct MissingStep {
+    threshold: f64,
+}
+
+impl ReductionStep for MissingStep {
+    /// Weighted missing ratio per feature, sorted descending
+    type Analysis = Vec<(String, f64)>;
+
+    fn name(&self) -> &str {
+        "missing"
+    }
+
+    fn analyze(&self, df: &DataFrame, ctx: &StepContext) -> Result<Self::Analysis> {
+        Ok(analyze_missing_values(df, ctx.weights, ctx.weight_column)?
+            .into_iter()
+            .filter(|(name, _)| name != ctx.target)
+            .collect())
+    }
+
+    fn propose_drops(&self, ratios: &Self::Analysis, _ctx: &StepContext) -> Vec<FeatureToDrop> {
+        ratios
+            .iter()
+            .filter(|(_, ratio)| *ratio > self.threshold)
+            .map(|(feature, ratio)| FeatureToDrop {
+                feature: feature.clone(),
+                reason: format!(
+                    "Missing ratio {:.1}% above {:.1}%",
+                    ratio * 100.0,
+                    self.threshold * 100.0
+                ),
+            })
+            .collect()
+    }
+
+    fn report_fragment(&self, ratios: &Self::Analysis) -> serde_json::Value {
+        serde_json::json!({ "threshold": self.threshold, "missing_ratios": ratios })
+    }
+}
+
+/// Results of the Gini/IV (or binned R²) step
+struct UnivariateAnalysis {
+    iv_analyses: Vec<IvAnalysis>,
+    r2_analyses: Vec<RSquaredAnalysis>,
+    concordance: Vec<ConcordanceStats>,
+    cart_trees: Vec<CartTree>,
+}
+
+/// Gini/IV step, or binned R² for a continuous target
+struct UnivariateStep<'a> {
+    reducer: &'a Reducer,
+    continuous: bool,
+    tx: &'a ProgressSender,
+}
+
+impl ReductionStep for UnivariateStep<'_> {
+    type Analysis = UnivariateAnalysis;
+
+    fn name(&self) -> &str {
+        "gini"
+    }
+
+    fn analyze(&self, df: &DataFrame, ctx: &StepContext) -> Result<Self::Analysis> {
+        let r = self.reducer;
+        let mut analysis = UnivariateAnalysis {
+            iv_analyses: Vec::new(),
+            r2_analyses: Vec::new(),
+            concordance: Vec::new(),
+            cart_trees: Vec::new(),
+        };
+        if self.continuous {
+            analysis.r2_analyses = analyze_features_r2_with_progress(
+                df,
+                ctx.target,
+                r.gini_bins,
+                Some(r.min_category_samples),
+                ctx.weights,
+                ctx.weight_column,
+                self.tx,
+            )?;
+            return Ok(analysis);
+        }
+
+        analysis.iv_analyses = match &r.bins_from {
+            Some(bins) => analyze_features_with_bins(
+                df,
+                ctx.target,
+                r.target_mapping.as_ref(),
+                ctx.weights,
+                ctx.weight_column,
+                bins,
+            )?,
+            None => analyze_features_iv_with_progress(
+                df,
+                ctx.target,
+                r.gini_bins,
+                r.prebins,
+                r.target_mapping.as_ref(),
+                r.binning_strategy,
+                Some(r.min_category_samples),
+                Some(r.cart_min_bin_pct),
+                ctx.weights,
+                ctx.weight_column,
+                r.solver_config.as_ref(),
+                r.special_values.as_ref(),
+                r.missing_bin_policy,
+                r.bin_breaks.as_ref(),
+                self.tx,
+            )?,
+        };
+        analysis.concordance = compute_concordance(
+            df,
+            ctx.target,
+            r.target_mapping.as_ref(),
+            ctx.weights,
+            ctx.weight_column,
+        )?;
+        if r.cart_trees && r.binning_strategy == BinningStrategy::Cart {
+            analysis.cart_trees = fit_cart_trees(
+                df,
+                ctx.target,
+                r.prebins,
+                r.target_mapping.as_ref(),
+                Some(r.cart_min_bin_pct),
+                ctx.weights,
+                ctx.weight_column,
+            )?;
+        }
+        if let Some(alpha) = r.merge_insignificant_bins {
+            merge_insignificant_bins(&mut analysis.iv_analyses, alpha);
+        }
+        if r.univariate_metric == UnivariateMetric::MutualInfo {
+            for a in &mut analysis.iv_analyses {
+                a.mutual_info = Some(mutual_information(a));
+            }
+        }
+        Ok(analysis)
+    }
+
+    fn propose_drops(&self, analysis: &Self::Analysis, _ctx: &StepContext) -> Vec<FeatureToDrop> {
+        let threshold = self.reducer.gini_threshold;
+        let drop = |feature: &str, metric: &str, value: f64| FeatureToDrop {
+            feature: feature.to_string(),
+            reason: format!("{} {:.4} below {:.4}", metric, value, threshold),
+        };
+        if self.continuous {
+            return analysis
+                .r2_analyses
+                .iter()
+                .filter(|a| a.r_squared < threshold)
+                .map(|a| drop(&a.feature_name, "R²", a.r_squared))
+                .collect();
+        }
+        analysis
+            .iv_analyses
+            .iter()
+            .filter_map(|a| match self.reducer.univariate_metric {
+                UnivariateMetric::Iv => {
+                    (a.gini.abs() < threshold).then(|| drop(&a.feature_name, "Gini", a.gini))
+                }
+                UnivariateMetric::MutualInfo => {
+                    let mi = a.mutual_info.unwrap_or(0.0);
+                    (mi < threshold).then(|| drop(&a.feature_name, "Mutual information", mi))
+                }
+            })
+            .collect()
+    }
+
+    fn report_fragment(&self, analysis: &Self::Analysis) -> serde_json::Value {
+        serde_json::json!({
+            "metric": self.reducer.univariate_metric.to_string(),
+            "threshold": self.reducer.gini_threshold,
+            "analyzed": analysis.iv_analyses.len() + analysis.r2_analyses.len(),
+        })
+    }
+}
+
+/// Correlated pairs, resolved, and the members dropped for them
+struct CorrelationAnalysis {
+    pairs: Vec<CorrelatedPair>,
+    dropped: Vec<FeatureToDrop>,
+}
+
+/// Correlation step: resolves each correlated pair by IV (or R²) first
+struct CorrelationStep<'a> {
+    reducer: &'a Reducer,
+    continuous: bool,
+    feature_metadata: &'a HashMap<String, FeatureMetadata>,
+    feature_types: &'a HashMap<String, FeatureType>,
+    tx: &'a ProgressSender,
+}
+
+impl ReductionStep for CorrelationStep<'_> {
+    type Analysis = CorrelationAnalysis;
+
+    fn name(&self) -> &str {
+        "correlation"
+    }
+
+    fn analyze(&self, df: &DataFrame, ctx: &StepContext) -> Result<Self::Analysis> {
+        let r = self.reducer;
+        let sample = if r.fast && !r.fixed_algorithms {
+            fast_screen_sample(df, ctx.weights)?
+        } else {
+            None
+        };
+        let (corr_df, corr_weights) = match &sample {
+            Some((sampled, sampled_weights)) => (sampled, sampled_weights.as_slice()),
+            None => (df, ctx.weights),
+        };
+        let mut pairs = find_correlated_pairs_auto_with_progress(
+            corr_df,
+            AssociationThresholds::new(r.correlation_threshold, r.cramers_v_threshold),
+            corr_weights,
+            ctx.weight_column,
+            Some(self.feature_types),
+            self.tx,
+        )?;
+        if self.continuous {
+            pairs = exclude_target_pairs(pairs, ctx.target);
+        }
+        let dropped = resolve_correlated_pairs(
+            &mut pairs,
+            ctx.target,
+            Some(self.feature_metadata),
+            ctx.keep_columns,
+            r.correlation_strategy,
+            &r.corr_keep_policy,
+        );
+        Ok(CorrelationAnalysis { pairs, dropped })
+    }
+
+    fn propose_drops(&self, analysis: &Self::Analysis, _ctx: &StepContext) -> Vec<FeatureToDrop> {
+        analysis.dropped.clone()
+    }
+
+    fn report_fragment(&self, analysis: &Self::Analysis) -> serde_json::Value {
+        serde_json::json!({
+            "threshold": self.reducer.correlation_threshold,
+            "correlated_pairs": analysis.pairs,
+        })
+    }
+}
+
+/// Outcome of a [`Reducer`] run
+#[derive(Debug, Clone)]
+pub struct ReductionResult {
+    /// Reduced dataset (target, weight and split columns retained)
+    pub dataframe: DataFrame,
+    pub target: String,
+    pub weight_column: Option<String>,
+    pub split_column: Option<String>,
+    /// True for a [`Reducer::fast`] run: bins and correlations are approximate
+    pub approximate: bool,
+    /// Feature count after user drops and the date policy, excluding target,
+    /// weight and split columns
+    pub initial_features: usize,
+    /// Features that contained NaN/±Inf before the policy was applied
+    pub non_finite: Vec<NonFiniteCounts>,
+    /// Temporal columns and the features [`DatePolicy::Derive`] replaced them with
+    pub temporal: Vec<TemporalColumn>,
+    /// Most common value and its weighted share per feature, sorted by share
+    /// descending (empty unless [`Reducer::dominance_threshold`] is set)
+    pub dominance: Vec<DominanceStats>,
+    pub dropped_constant: Vec<String>,
+    /// Distinct-value counts of the columns the ID check looked at, sorted by
+    /// ratio descending (empty unless [`Reducer::id_threshold`] is set)
+    pub cardinality: Vec<CardinalityStats>,
+    /// Likely ID columns, whether or not they were dropped
+    pub id_like: Vec<String>,
+    /// The `id_like` columns, when [`IdAction::Drop`] dropped them
+    pub dropped_identifier: Vec<String>,
+    /// Weighted missing ratio per feature, sorted descending
+    pub missing_ratios: Vec<(String, f64)>,
+    pub dropped_missing: Vec<String>,
+    /// WoE/IV tables for every feature that reached the Gini stage, sorted by IV descending
+    /// (empty for a continuous target)
+    pub iv_analyses: Vec<IvAnalysis>,
+    /// Binned R² per feature for a continuous target, sorted by R² descending
+    /// (empty for a binary target)
+    pub r2_analyses: Vec<RSquaredAnalysis>,
+    /// Fitted CART prebinning trees, when requested with [`Reducer::cart_trees`]
+    pub cart_trees: Vec<CartTree>,
+    /// Somers' D / concordance of each numeric feature on its raw values
+    /// (empty for a continuous target)
+    pub concordance: Vec<ConcordanceStats>,
+    pub dropped_gini: Vec<String>,
+    /// Pairs above the correlation threshold among features that reached that stage
+    pub correlated_pairs: Vec<CorrelatedPair>,
+    pub dropped_correlation: Vec<FeatureToDrop>,
+    /// Outcomes of the steps added with [`Reducer::step`], in the order they ran
+    pub custom_steps: Vec<StepOutcome>,
+}
+
+impl ReductionResult {
+    /// Names of the features still present in the reduced dataset
+    pub fn kept_features(&self) -> Vec<String> {
+        self.dataframe
+            .get_column_names()
+            .iter()
+            .map(|s| s.to_string())
+            .filter(|name| {
+                name != &self.target
+                    && Some(name) != self.weight_column.as_ref()
+                    && Some(name) != self.split_column.as_ref()
+            })
+            .collect()
+    }
+
+    /// All dropped feature names, in stage order (near-constant → ID →
+    /// custom steps before missing → missing → Gini → correlation → custom
+    /// steps after correlation)
+    pub fn dropped_features(&self) -> Vec<String> {
+        let custom = |position: StepPosition| {
+            self.custom_steps
+                .iter()
+                .filter(move |s| s.position == position)
+                .flat_map(|s| s.dropped.iter().map(|f| f.feature.clone()))
+        };
+        self.dropped_constant
+            .iter()
+            .chain(self.dropped_identifier.iter())
+            .cloned()
+            .chain(custom(StepPosition::BeforeMissing))
+            .chain(self.dropped_missing.iter().cloned())
+            .chain(self.dropped_gini.iter().cloned())
+            .chain(self.dropped_correlation.iter().map(|f| f.feature.clone()))
+            .chain(custom(StepPosition::AfterCorrelation))
+            .collect()
+    }
+
+    /// IV analysis for a single feature, if it reached the Gini stage
+    pub fn iv_analysis(&self, feature: &str) -> Option<&IvAnalysis> {
+        self.iv_analyses.iter().find(|a| a.feature_name == feature)
+    }
+
+    /// Copy of the reduced dataset with every kept feature replaced by the WoE
+    /// of its bin, the same encoding `--export-woe` writes
+    pub fn woe_dataframe(&self) -> Result<DataFrame> {
+        let mut df = self.dataframe.clone();
+        apply_woe_transform(&mut df, &self.iv_analyses)?;
+        Ok(df)
+    }
+}
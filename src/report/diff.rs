@@ -0,0 +1,379 @@
+//! Comparison of two runs (`lophi diff`)
+//!
+//! Re-running a reduction on a new sample, after a binning change or with
+//! other thresholds raises the same questions each time: which features moved
+//! in IV, which were dropped in one run only, and which thresholds differ.
+//! Each side is a reduction report (`.json` or the report `.zip`) or a Gini
+//! analysis export. Like [`super::validate`], the runs are read as raw JSON,
+//! so reports from older versions compare as they are.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use comfy_table::{presets::UTF8_FULL_CONDENSED, Attribute, Cell, CellAlignment, Color, Table};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::pipeline::apply::read_report_json;
+
+/// IV changes smaller than this are treated as equal (float noise)
+const IV_EPSILON: f64 = 1e-9;
+
+/// What a run's file holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunKind {
+    ReductionReport,
+    GiniAnalysis,
+}
+
+/// One feature as a run saw it
+#[derive(Debug, Clone, Default, PartialEq)]
+struct RunFeature {
+    iv: Option<f64>,
+    gini: Option<f64>,
+    /// Stage that dropped the feature
+    dropped_at: Option<String>,
+}
+
+/// The parts of a run the diff compares
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunSnapshot {
+    pub path: String,
+    pub kind: RunKind,
+    features: BTreeMap<String, RunFeature>,
+    thresholds: BTreeMap<String, f64>,
+}
+
+impl RunSnapshot {
+    /// Read a reduction report (`.json` or `.zip`) or a Gini analysis export
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = read_report_json(path)?;
+        let run: Value = serde_json::from_str(&content)
+            .with_context(|| format!("Run is not valid JSON: {}", path.display()))?;
+        Self::from_json(&run, &path.display().to_string())
+    }
+
+    fn from_json(run: &Value, path: &str) -> Result<Self> {
+        let Some(entries) = run.get("features").and_then(Value::as_array) else {
+            bail!("{} has no 'features' array", path);
+        };
+        let number = |value: &Value, pointer: &str| value.pointer(pointer).and_then(Value::as_f64);
+        let is_report = entries.iter().any(|f| f.get("name").is_some());
+
+        let mut features = BTreeMap::new();
+        let mut thresholds = BTreeMap::new();
+        if is_report {
+            for entry in entries {
+                let Some(name) = entry.get("name").and_then(Value::as_str) else {
+                    continue;
+                };
+                let dropped = entry.get("status").and_then(Value::as_str) == Some("dropped");
+                features.insert(
+                    name.to_string(),
+                    RunFeature {
+                        iv: number(entry, "/analysis/gini/iv"),
+                        gini: number(entry, "/analysis/gini/gini"),
+                        dropped_at: dropped.then(|| {
+                            entry
+                                .get("dropped_at_stage")
+                                .and_then(Value::as_str)
+                                .unwrap_or("unknown")
+                                .to_string()
+                        }),
+                    },
+                );
+            }
+            if let Some(map) = run
+                .pointer("/metadata/thresholds")
+                .and_then(Value::as_object)
+            {
+                thresholds.extend(
+                    map.iter()
+                        .filter_map(|(key, value)| Some((key.clone(), value.as_f64()?))),
+                );
+            }
+        } else {
+            for entry in entries {
+                let Some(name) = entry.get("feature_name").and_then(Value::as_str) else {
+                    continue;
+                };
+                let dropped = entry.get("dropped").and_then(Value::as_bool) == Some(true);
+                features.insert(
+                    name.to_string(),
+                    RunFeature {
+                        iv: number(entry, "/iv"),
+                        gini: number(entry, "/gini"),
+                        dropped_at: dropped.then(|| "gini".to_string()),
+                    },
+                );
+            }
+            if let Some(gini) = number(run, "/metadata/gini_threshold") {
+                thresholds.insert("gini".to_string(), gini);
+            }
+        }
+        if features.is_empty() && !entries.is_empty() {
+            bail!(
+                "{} is neither a reduction report nor a Gini analysis export",
+                path
+            );
+        }
+
+        Ok(Self {
+            path: path.to_string(),
+            kind: if is_report {
+                RunKind::ReductionReport
+            } else {
+                RunKind::GiniAnalysis
+            },
+            features,
+            thresholds,
+        })
+    }
+}
+
+/// A threshold set differently in the two runs
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ThresholdChange {
+    pub name: String,
+    pub a: Option<f64>,
+    pub b: Option<f64>,
+}
+
+/// One feature across the two runs; `None` where a run has no value
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FeatureDiff {
+    pub feature: String,
+    pub iv_a: Option<f64>,
+    pub iv_b: Option<f64>,
+    /// `iv_b - iv_a`, when both runs scored the feature
+    pub iv_delta: Option<f64>,
+    pub gini_a: Option<f64>,
+    pub gini_b: Option<f64>,
+    /// Stage that dropped the feature in run A
+    pub dropped_at_a: Option<String>,
+    /// Stage that dropped the feature in run B
+    pub dropped_at_b: Option<String>,
+}
+
+impl FeatureDiff {
+    /// Whether the IV or the drop stage differs between the runs
+    pub fn changed(&self) -> bool {
+        self.iv_delta.is_some_and(|d| d.abs() > IV_EPSILON)
+            || self.iv_a.is_some() != self.iv_b.is_some()
+            || self.dropped_at_a != self.dropped_at_b
+    }
+}
+
+/// Comparison of two runs (`lophi diff`), written as `{stem}_diff.json`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunDiff {
+    pub run_a: String,
+    pub run_a_kind: RunKind,
+    pub run_b: String,
+    pub run_b_kind: RunKind,
+    /// Thresholds that differ, or that only one run records
+    pub threshold_changes: Vec<ThresholdChange>,
+    /// Features dropped in run A but retained in run B
+    pub dropped_only_in_a: Vec<String>,
+    /// Features dropped in run B but retained in run A
+    pub dropped_only_in_b: Vec<String>,
+    /// Features present in one run only
+    pub only_in_a: Vec<String>,
+    pub only_in_b: Vec<String>,
+    /// Every feature of either run, largest IV change first
+    pub features: Vec<FeatureDiff>,
+}
+
+/// Compare run `a` with run `b`
+pub fn diff_runs(a: &RunSnapshot, b: &RunSnapshot) -> RunDiff {
+    let names: BTreeSet<&String> = a.features.keys().chain(b.features.keys()).collect();
+    let absent = RunFeature::default();
+
+    let mut features: Vec<FeatureDiff> = names
+        .iter()
+        .map(|&name| {
+            let fa = a.features.get(name).unwrap_or(&absent);
+            let fb = b.features.get(name).unwrap_or(&absent);
+            FeatureDiff {
+                feature: name.clone(),
+                iv_a: fa.iv,
+                iv_b: fb.iv,
+                iv_delta: fa.iv.zip(fb.iv).map(|(ia, ib)| ib - ia),
+                gini_a: fa.gini,
+                gini_b: fb.gini,
+                dropped_at_a: fa.dropped_at.clone(),
+                dropped_at_b: fb.dropped_at.clone(),
+            }
+        })
+        .collect();
+    features.sort_by(|x, y| {
+        let magnitude = |d: &FeatureDiff| d.iv_delta.map_or(-1.0, f64::abs);
+        magnitude(y).total_cmp(&magnitude(x))
+    });
+
+    // Drop differences only count features both runs have
+    let dropped_only_in = |one: &RunSnapshot, other: &RunSnapshot| -> Vec<String> {
+        one.features
+            .iter()
+            .filter(|(name, f)| {
+                f.dropped_at.is_some()
+                    && other
+                        .features
+                        .get(*name)
+                        .is_some_and(|o| o.dropped_at.is_none())
+            })
+            .map(|(name, _)| name.clone())
+            .collect()
+    };
+    let only_in = |one: &RunSnapshot, other: &RunSnapshot| -> Vec<String> {
+        one.features
+            .keys()
+            .filter(|name| !other.features.contains_key(*name))
+            .cloned()
+            .collect()
+    };
+
+    let threshold_names: BTreeSet<&String> =
+        a.thresholds.keys().chain(b.thresholds.keys()).collect();
+    let threshold_changes = threshold_names
+        .into_iter()
+        .map(|name| ThresholdChange {
+            name: name.clone(),
+            a: a.thresholds.get(name).copied(),
+            b: b.thresholds.get(name).copied(),
+        })
+        .filter(|t| t.a != t.b)
+        .collect();
+
+    RunDiff {
+        run_a: a.path.clone(),
+        run_a_kind: a.kind,
+        run_b: b.path.clone(),
+        run_b_kind: b.kind,
+        threshold_changes,
+        dropped_only_in_a: dropped_only_in(a, b),
+        dropped_only_in_b: dropped_only_in(b, a),
+        only_in_a: only_in(a, b),
+        only_in_b: only_in(b, a),
+        features,
+    }
+}
+
+/// Export the diff to JSON
+pub fn export_run_diff(diff: &RunDiff, output_path: &Path) -> Result<()> {
+    let json = serde_json::to_string_pretty(diff).context("Failed to serialize run diff")?;
+    std::fs::write(output_path, json)
+        .with_context(|| format!("Failed to write run diff to {}", output_path.display()))
+}
+
+/// Table of the `top` changed features, largest IV change first
+pub fn diff_table(diff: &RunDiff, top: usize) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_header(
+        ["Feature", "IV A", "IV B", "ΔIV", "Dropped A", "Dropped B"]
+            .into_iter()
+            .map(|h| Cell::new(h).add_attribute(Attribute::Bold)),
+    );
+    let number =
+        |value: Option<f64>| value.map_or_else(|| "-".to_string(), |v| format!("{:.4}", v));
+    let stage = |stage: &Option<String>| stage.clone().unwrap_or_else(|| "-".to_string());
+    for feature in diff.features.iter().filter(|f| f.changed()).take(top) {
+        let delta = match feature.iv_delta {
+            Some(d) if d > IV_EPSILON => Cell::new(format!("{:+.4}", d)).fg(Color::Green),
+            Some(d) if d < -IV_EPSILON => Cell::new(format!("{:+.4}", d)).fg(Color::Red),
+            other => Cell::new(number(other)),
+        };
+        table.add_row(vec![
+            Cell::new(&feature.feature),
+            Cell::new(number(feature.iv_a)).set_alignment(CellAlignment::Right),
+            Cell::new(number(feature.iv_b)).set_alignment(CellAlignment::Right),
+            delta.set_alignment(CellAlignment::Right),
+            Cell::new(stage(&feature.dropped_at_a)),
+            Cell::new(stage(&feature.dropped_at_b)),
+        ]);
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn report(features: Value, gini: f64) -> RunSnapshot {
+        let run = json!({
+            "metadata": { "thresholds": { "missing_ratio": 0.3, "gini": gini, "correlation": 0.4 } },
+            "features": features,
+        });
+        RunSnapshot::from_json(&run, "report.json").unwrap()
+    }
+
+    #[test]
+    fn test_diff_of_two_reports() {
+        let a = report(
+            json!([
+                { "name": "age", "status": "kept", "analysis": { "gini": { "iv": 0.30, "gini": 0.4 } } },
+                { "name": "noise", "status": "dropped", "dropped_at_stage": "gini",
+                  "analysis": { "gini": { "iv": 0.01, "gini": 0.02 } } },
+                { "name": "old", "status": "kept", "analysis": {} },
+            ]),
+            0.05,
+        );
+        let b = report(
+            json!([
+                { "name": "age", "status": "dropped", "dropped_at_stage": "correlation",
+                  "analysis": { "gini": { "iv": 0.25, "gini": 0.35 } } },
+                { "name": "noise", "status": "kept", "analysis": { "gini": { "iv": 0.01, "gini": 0.02 } } },
+                { "name": "new", "status": "kept", "analysis": {} },
+            ]),
+            0.01,
+        );
+        let diff = diff_runs(&a, &b);
+
+        assert_eq!(diff.dropped_only_in_a, ["noise"]);
+        assert_eq!(diff.dropped_only_in_b, ["age"]);
+        assert_eq!(diff.only_in_a, ["old"]);
+        assert_eq!(diff.only_in_b, ["new"]);
+        assert_eq!(
+            diff.threshold_changes,
+            [ThresholdChange {
+                name: "gini".to_string(),
+                a: Some(0.05),
+                b: Some(0.01),
+            }]
+        );
+        assert_eq!(diff.features[0].feature, "age");
+        assert!((diff.features[0].iv_delta.unwrap() + 0.05).abs() < 1e-12);
+        assert_eq!(
+            diff.features[0].dropped_at_b.as_deref(),
+            Some("correlation")
+        );
+        // Same IV, different drop: still a change
+        let noise = diff.features.iter().find(|f| f.feature == "noise").unwrap();
+        assert_eq!(noise.iv_delta, Some(0.0));
+        assert!(noise.changed());
+    }
+
+    #[test]
+    fn test_gini_export_side() {
+        let export = json!({
+            "metadata": { "gini_threshold": 0.05 },
+            "features": [
+                { "feature_name": "age", "iv": 0.3, "gini": 0.4, "dropped": false },
+                { "feature_name": "noise", "iv": 0.01, "gini": 0.02, "dropped": true },
+            ],
+        });
+        let run = RunSnapshot::from_json(&export, "gini.json").unwrap();
+        assert_eq!(run.kind, RunKind::GiniAnalysis);
+        let diff = diff_runs(&run, &run);
+        assert!(diff.threshold_changes.is_empty());
+        assert!(!diff.features.iter().any(FeatureDiff::changed));
+        assert_eq!(diff.features[1].dropped_at_a.as_deref(), Some("gini"));
+
+        let other = json!({ "features": [{ "column": "age" }] });
+        assert!(RunSnapshot::from_json(&other, "other.json").is_err());
+    }
+}
@@ -1,12 +1,17 @@
 //! Gini analysis export functionality
 
+use std::collections::HashMap;
 use std::path::Path;
 
 use anyhow::{Context, Result};
 use chrono::Utc;
 use serde::Serialize;
 
-use crate::pipeline::{BinningStrategy, FeatureType, IvAnalysis};
+use crate::pipeline::{
+    BinBreaks, BinningStrategy, CartTree, CrossValidatedIv, EventLabels, ExtremeValueStats,
+    FeatureDescription, FeatureDictionary, FeatureSegmentIv, FeatureType, IvAnalysis,
+    MissingBinPolicy, RSquaredAnalysis, SpecialValues, UnivariateMetric, WeightedHistogram,
+};
 
 /// Metadata about the analysis run
 #[derive(Serialize)]
@@ -24,15 +29,44 @@ pub struct AnalysisMetadata {
     pub weight_column: Option<String>,
     /// Binning strategy used
     pub binning_strategy: String,
+    /// Breakpoints of manual binning (`--bin-breaks`, if set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bin_breaks: Option<BinBreaks>,
+    /// File the bins were taken from instead of being searched (`--bins-from`, if set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bins_from: Option<String>,
     /// Number of bins
     pub num_bins: usize,
     /// Gini threshold for dropping features
     pub gini_threshold: f64,
+    /// Present when the filter used another metric than Gini; `gini_threshold`
+    /// then holds that metric's threshold
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub univariate_metric: Option<String>,
     /// Minimum samples per category
     pub min_category_samples: usize,
     /// CART minimum bin size percentage (if CART strategy used)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cart_min_bin_pct: Option<f64>,
+    /// Significance level of the post-binning chi-square merge (if enabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_insignificant_bins: Option<f64>,
+    /// Names of the classes counted by `events` / `non_events` (if set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_labels: Option<EventLabels>,
+    /// Folds of the cross-validated IV (`--cv-folds`, if run)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cv_folds: Option<usize>,
+    /// Column the per-segment IVs were computed over (`--segment-column`, if set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segment_column: Option<String>,
+    /// Values binned on their own (`--special-values`, if set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub special_values: Option<SpecialValues>,
+    /// Present when missing values were not kept in a bin of their own
+    /// (`merge_worst` or `impute`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_bin_policy: Option<String>,
 }
 
 /// Summary statistics of the analysis
@@ -62,6 +96,21 @@ pub struct GiniExportEntry {
     pub analysis: IvAnalysis,
     /// Whether this feature was dropped due to low Gini
     pub dropped: bool,
+    /// Weighted value histogram (`--export-distributions`, retained numeric features)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<WeightedHistogram>,
+    /// Percentiles and share of extreme values (numeric features)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extremes: Option<ExtremeValueStats>,
+    /// Holdout IV and Gini across the folds (`--cv-folds`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cross_validation: Option<CrossValidatedIv>,
+    /// IV in each segment next to the pooled IV (`--segment-column`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segments: Option<FeatureSegmentIv>,
+    /// Dictionary description and owner, SAS label and format (flattened into the JSON)
+    #[serde(flatten)]
+    pub dictionary: FeatureDescription,
 }
 
 /// Complete Gini analysis export with metadata
@@ -75,16 +124,109 @@ pub struct GiniAnalysisExport {
     pub features: Vec<GiniExportEntry>,
 }
 
+/// A single feature's R² analysis with dropped status
+#[derive(Serialize)]
+pub struct R2ExportEntry {
+    #[serde(flatten)]
+    pub analysis: RSquaredAnalysis,
+    /// Whether this feature was dropped due to low R²
+    pub dropped: bool,
+    /// Dictionary description and owner, SAS label and format (flattened into the JSON)
+    #[serde(flatten)]
+    pub dictionary: FeatureDescription,
+}
+
+/// R² analysis export, written in place of the Gini analysis for a continuous target
+#[derive(Serialize)]
+pub struct R2AnalysisExport {
+    /// Metadata about the analysis run; `gini_threshold` holds the R² threshold
+    pub metadata: AnalysisMetadata,
+    pub features: Vec<R2ExportEntry>,
+}
+
+/// CART prebinning tree export (`--export-cart-trees`)
+#[derive(Serialize)]
+pub struct CartTreesExport {
+    /// Metadata about the analysis run
+    pub metadata: AnalysisMetadata,
+    /// Prebin count the trees were grown for (at most `prebins - 1` splits)
+    pub prebins: usize,
+    pub features: Vec<CartTree>,
+}
+
+/// File name of the index written by [`export_gini_analysis_split`]
+pub const SPLIT_INDEX_FILE: &str = "index.json";
+
+/// Subdirectory holding the per-feature files of a split export
+pub const SPLIT_FEATURES_DIR: &str = "features";
+
+/// Index entry for one feature in a split Gini export
+#[derive(Serialize)]
+pub struct GiniIndexEntry {
+    /// Feature name
+    pub feature_name: String,
+    /// Numeric or categorical
+    pub feature_type: FeatureType,
+    /// Information Value
+    pub iv: f64,
+    /// Gini coefficient
+    pub gini: f64,
+    /// Kolmogorov-Smirnov statistic
+    pub ks: f64,
+    /// ROC AUC from the bin event rates
+    pub auc: f64,
+    /// Mutual information, when it was the filter metric
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mutual_info: Option<f64>,
+    /// Whether this feature was dropped due to low Gini
+    pub dropped: bool,
+    /// Dictionary description and owner, SAS label and format (flattened into the JSON)
+    #[serde(flatten)]
+    pub dictionary: FeatureDescription,
+    /// Path of the per-feature JSON file, relative to the index
+    pub file: String,
+}
+
+/// Index of a split Gini export (`index.json`)
+#[derive(Serialize)]
+pub struct GiniAnalysisIndex {
+    /// Metadata about the analysis run
+    pub metadata: AnalysisMetadata,
+    /// Summary statistics
+    pub summary: AnalysisSummary,
+    /// One entry per feature, in analysis order
+    pub features: Vec<GiniIndexEntry>,
+}
+
 /// Parameters for enhanced Gini analysis export
 pub struct ExportParams<'a> {
     pub input_file: &'a str,
     pub target_column: &'a str,
     pub weight_column: Option<&'a str>,
     pub binning_strategy: BinningStrategy,
+    pub bin_breaks: Option<&'a BinBreaks>,
+    pub bins_from: Option<&'a Path>,
     pub num_bins: usize,
     pub gini_threshold: f64,
     pub min_category_samples: usize,
     pub cart_min_bin_pct: Option<f64>,
+    pub univariate_metric: UnivariateMetric,
+    pub merge_insignificant_bins: Option<f64>,
+    pub event_labels: Option<&'a EventLabels>,
+    /// Histograms for `--export-distributions`, keyed by feature name
+    pub distributions: Option<&'a HashMap<String, WeightedHistogram>>,
+    /// Percentiles and extreme-value shares, keyed by feature name
+    pub extremes: Option<&'a HashMap<String, ExtremeValueStats>>,
+    pub cv_folds: Option<usize>,
+    /// Cross-validated IV for `--cv-folds`, keyed by feature name
+    pub cross_validation: Option<&'a HashMap<String, CrossValidatedIv>>,
+    pub segment_column: Option<&'a str>,
+    /// Per-segment IV for `--segment-column`, keyed by feature name
+    pub segment_iv: Option<&'a HashMap<String, FeatureSegmentIv>>,
+    pub special_values: Option<&'a SpecialValues>,
+    pub missing_bin_policy: MissingBinPolicy,
+    /// Feature descriptions and owners (`--dictionary`)
+    pub dictionary: Option<&'a FeatureDictionary>,
 }
 
 /// Export Gini analysis results to a JSON file with enhanced metadata
@@ -111,10 +253,227 @@ pub fn export_gini_analysis_enhanced(
             GiniExportEntry {
                 analysis: analysis.clone(),
                 dropped,
+                distribution: distribution(params, &analysis.feature_name),
+                extremes: extremes(params, &analysis.feature_name),
+                cross_validation: cross_validation(params, &analysis.feature_name),
+                segments: segment_iv(params, &analysis.feature_name),
+                dictionary: dictionary_entry(params, &analysis.feature_name),
             }
         })
         .collect();
 
+    // Build export structure
+    let export = GiniAnalysisExport {
+        metadata: build_metadata(params),
+        summary: build_summary(analyses, dropped_features),
+        features: entries,
+    };
+
+    let json = serde_json::to_string_pretty(&export)
+        .context("Failed to serialize Gini analysis to JSON")?;
+
+    std::fs::write(output_path, json)
+        .with_context(|| format!("Failed to write Gini analysis to {}", output_path.display()))?;
+
+    Ok(())
+}
+
+/// Export R² analysis results (continuous target) to a JSON file
+///
+/// Per-feature entries carry the bins with their target means, R² and, for
+/// numeric features, the Pearson correlation with the target.
+pub fn export_r2_analysis(
+    analyses: &[RSquaredAnalysis],
+    dropped_features: &[String],
+    output_path: &Path,
+    params: &ExportParams,
+) -> Result<()> {
+    let export = R2AnalysisExport {
+        metadata: build_metadata(params),
+        features: analyses
+            .iter()
+            .map(|analysis| R2ExportEntry {
+                dropped: dropped_features.contains(&analysis.feature_name),
+                dictionary: dictionary_entry(params, &analysis.feature_name),
+                analysis: analysis.clone(),
+            })
+            .collect(),
+    };
+
+    let json =
+        serde_json::to_string_pretty(&export).context("Failed to serialize R² analysis to JSON")?;
+    std::fs::write(output_path, json)
+        .with_context(|| format!("Failed to write R² analysis to {}", output_path.display()))?;
+
+    Ok(())
+}
+
+/// Export fitted CART prebinning trees to a JSON file
+///
+/// Debug output for `--export-cart-trees`: one tree per numeric feature with
+/// every node's counts, impurity and split or stop reason.
+pub fn export_cart_trees(
+    trees: &[CartTree],
+    prebins: usize,
+    output_path: &Path,
+    params: &ExportParams,
+) -> Result<()> {
+    let export = CartTreesExport {
+        metadata: build_metadata(params),
+        prebins,
+        features: trees.to_vec(),
+    };
+
+    let json =
+        serde_json::to_string_pretty(&export).context("Failed to serialize CART trees to JSON")?;
+    std::fs::write(output_path, json)
+        .with_context(|| format!("Failed to write CART trees to {}", output_path.display()))?;
+
+    Ok(())
+}
+
+/// Export Gini analysis as one compact JSON file per feature plus an `index.json`
+///
+/// Intended for very wide datasets where a single pretty-printed export grows
+/// to hundreds of MB. The index carries the same metadata and summary as
+/// [`export_gini_analysis_enhanced`] and lists each feature's headline metrics
+/// with the relative path of its detail file, so consumers can read the index
+/// alone and load individual features on demand.
+///
+/// Any existing directory at `output_dir` is replaced.
+///
+/// # Arguments
+/// * `analyses` - All feature analyses from the Gini step
+/// * `dropped_features` - List of feature names that were dropped
+/// * `output_dir` - Directory to create (e.g. `data_gini_analysis/`)
+/// * `params` - Export parameters for metadata
+pub fn export_gini_analysis_split(
+    analyses: &[IvAnalysis],
+    dropped_features: &[String],
+    output_dir: &Path,
+    params: &ExportParams,
+) -> Result<()> {
+    if output_dir.exists() {
+        std::fs::remove_dir_all(output_dir).with_context(|| {
+            format!(
+                "Failed to remove existing export directory {}",
+                output_dir.display()
+            )
+        })?;
+    }
+    let features_dir = output_dir.join(SPLIT_FEATURES_DIR);
+    std::fs::create_dir_all(&features_dir)
+        .with_context(|| format!("Failed to create directory {}", features_dir.display()))?;
+
+    let mut index_entries = Vec::with_capacity(analyses.len());
+    for (i, analysis) in analyses.iter().enumerate() {
+        let dropped = dropped_features.contains(&analysis.feature_name);
+        // Index prefix keeps names unique after sanitizing and preserves order
+        let file_name = format!(
+            "{:05}_{}.json",
+            i,
+            sanitize_file_name(&analysis.feature_name)
+        );
+        let relative = format!("{}/{}", SPLIT_FEATURES_DIR, file_name);
+
+        let entry = GiniExportEntry {
+            analysis: analysis.clone(),
+            dropped,
+            distribution: distribution(params, &analysis.feature_name),
+            extremes: extremes(params, &analysis.feature_name),
+            cross_validation: cross_validation(params, &analysis.feature_name),
+            segments: segment_iv(params, &analysis.feature_name),
+            dictionary: dictionary_entry(params, &analysis.feature_name),
+        };
+        let json = serde_json::to_string(&entry).with_context(|| {
+            format!(
+                "Failed to serialize Gini analysis for {}",
+                analysis.feature_name
+            )
+        })?;
+        let path = features_dir.join(&file_name);
+        std::fs::write(&path, json)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        index_entries.push(GiniIndexEntry {
+            feature_name: analysis.feature_name.clone(),
+            feature_type: analysis.feature_type,
+            iv: analysis.iv,
+            gini: analysis.gini,
+            ks: analysis.ks,
+            auc: analysis.auc,
+            mutual_info: analysis.mutual_info,
+            dropped,
+            dictionary: entry.dictionary.clone(),
+            file: relative,
+        });
+    }
+
+    let index = GiniAnalysisIndex {
+        metadata: build_metadata(params),
+        summary: build_summary(analyses, dropped_features),
+        features: index_entries,
+    };
+    let json = serde_json::to_string_pretty(&index)
+        .context("Failed to serialize Gini analysis index to JSON")?;
+    let index_path = output_dir.join(SPLIT_INDEX_FILE);
+    std::fs::write(&index_path, json)
+        .with_context(|| format!("Failed to write {}", index_path.display()))?;
+
+    Ok(())
+}
+
+fn distribution(params: &ExportParams, feature: &str) -> Option<WeightedHistogram> {
+    params.distributions?.get(feature).cloned()
+}
+
+fn extremes(params: &ExportParams, feature: &str) -> Option<ExtremeValueStats> {
+    params.extremes?.get(feature).cloned()
+}
+
+fn cross_validation(params: &ExportParams, feature: &str) -> Option<CrossValidatedIv> {
+    params.cross_validation?.get(feature).cloned()
+}
+
+fn segment_iv(params: &ExportParams, feature: &str) -> Option<FeatureSegmentIv> {
+    params.segment_iv?.get(feature).cloned()
+}
+
+fn dictionary_entry(params: &ExportParams, feature: &str) -> FeatureDescription {
+    params
+        .dictionary
+        .and_then(|d| d.get(feature))
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn build_metadata(params: &ExportParams) -> AnalysisMetadata {
+    AnalysisMetadata {
+        timestamp: Utc::now().to_rfc3339(),
+        lophi_version: env!("CARGO_PKG_VERSION").to_string(),
+        input_file: params.input_file.to_string(),
+        target_column: params.target_column.to_string(),
+        weight_column: params.weight_column.map(|s| s.to_string()),
+        binning_strategy: params.binning_strategy.to_string(),
+        bin_breaks: params.bin_breaks.cloned(),
+        bins_from: params.bins_from.map(|p| p.display().to_string()),
+        num_bins: params.num_bins,
+        gini_threshold: params.gini_threshold,
+        univariate_metric: (params.univariate_metric != UnivariateMetric::Iv)
+            .then(|| params.univariate_metric.to_string()),
+        min_category_samples: params.min_category_samples,
+        cart_min_bin_pct: params.cart_min_bin_pct,
+        merge_insignificant_bins: params.merge_insignificant_bins,
+        event_labels: params.event_labels.cloned(),
+        cv_folds: params.cv_folds,
+        segment_column: params.segment_column.map(|s| s.to_string()),
+        special_values: params.special_values.cloned(),
+        missing_bin_policy: (params.missing_bin_policy != MissingBinPolicy::Separate)
+            .then(|| params.missing_bin_policy.to_string()),
+    }
+}
+
+fn build_summary(analyses: &[IvAnalysis], dropped_features: &[String]) -> AnalysisSummary {
     // Count feature types
     let numeric_features = analyses
         .iter()
@@ -137,39 +496,35 @@ pub fn export_gini_analysis_enhanced(
         analyses.iter().map(|a| a.gini).sum::<f64>() / analyses.len() as f64
     };
 
-    // Build export structure
-    let export = GiniAnalysisExport {
-        metadata: AnalysisMetadata {
-            timestamp: Utc::now().to_rfc3339(),
-            lophi_version: env!("CARGO_PKG_VERSION").to_string(),
-            input_file: params.input_file.to_string(),
-            target_column: params.target_column.to_string(),
-            weight_column: params.weight_column.map(|s| s.to_string()),
-            binning_strategy: params.binning_strategy.to_string(),
-            num_bins: params.num_bins,
-            gini_threshold: params.gini_threshold,
-            min_category_samples: params.min_category_samples,
-            cart_min_bin_pct: params.cart_min_bin_pct,
-        },
-        summary: AnalysisSummary {
-            total_features_analyzed: analyses.len(),
-            numeric_features,
-            categorical_features,
-            features_dropped: dropped_features.len(),
-            features_kept: analyses.len() - dropped_features.len(),
-            avg_iv,
-            avg_gini,
-        },
-        features: entries,
-    };
-
-    let json = serde_json::to_string_pretty(&export)
-        .context("Failed to serialize Gini analysis to JSON")?;
-
-    std::fs::write(output_path, json)
-        .with_context(|| format!("Failed to write Gini analysis to {}", output_path.display()))?;
+    AnalysisSummary {
+        total_features_analyzed: analyses.len(),
+        numeric_features,
+        categorical_features,
+        features_dropped: dropped_features.len(),
+        features_kept: analyses.len() - dropped_features.len(),
+        avg_iv,
+        avg_gini,
+    }
+}
 
-    Ok(())
+/// Replace characters that are unsafe in file names with `_`
+pub(crate) fn sanitize_file_name(name: &str) -> String {
+    let sanitized: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .take(64)
+        .collect();
+    if sanitized.is_empty() {
+        "feature".to_string()
+    } else {
+        sanitized
+    }
 }
 
 /// Export Gini analysis results to a JSON file (legacy simple format)
@@ -194,6 +549,11 @@ pub fn export_gini_analysis(
             GiniExportEntry {
                 analysis: analysis.clone(),
                 dropped,
+                distribution: None,
+                extremes: None,
+                cross_validation: None,
+                segments: None,
+                dictionary: FeatureDescription::default(),
             }
         })
         .collect();
@@ -1,16 +1,59 @@
 //! Report module - summarizing reduction results
 
+pub mod diff;
 pub mod gini_export;
+pub mod plots;
 pub mod reduction_report;
+pub mod scoring_spec;
+pub mod selected_features;
 pub mod summary;
+pub mod tables;
+pub mod validate;
+pub mod woe_sql;
 
 // Re-exports: some items only consumed by tests, not the binary crate
 #[allow(unused_imports)]
-pub use gini_export::{export_gini_analysis, export_gini_analysis_enhanced, ExportParams};
+pub use diff::{
+    diff_runs, diff_table, export_run_diff, FeatureDiff, RunDiff, RunKind, RunSnapshot,
+    ThresholdChange,
+};
+#[allow(unused_imports)]
+pub use gini_export::{
+    export_cart_trees, export_gini_analysis, export_gini_analysis_enhanced,
+    export_gini_analysis_split, export_r2_analysis, CartTreesExport, ExportParams,
+    GiniAnalysisIndex, GiniIndexEntry, R2AnalysisExport, R2ExportEntry, SPLIT_FEATURES_DIR,
+    SPLIT_INDEX_FILE,
+};
+#[allow(unused_imports)]
+pub use plots::{
+    top_features_by_iv, write_plots, PlotFormat, PlotSettings, PLOTS_DISABLED, PLOTS_INDEX_FILE,
+};
 #[allow(unused_imports)]
 pub use reduction_report::{
-    export_reduction_report, export_reduction_report_csv, package_reduction_reports, ByStage,
-    DropStage, FeatureReportEntry, ReductionReport, ReductionReportBuilder, ReportBuilderParams,
-    ReportSummary, StageSummary, TimingInfo,
+    export_imputation_plan, export_reduction_report, export_reduction_report_csv,
+    export_reduction_report_long_csv, export_segment_missing_csv, package_reduction_reports,
+    package_reduction_reports_with, ByStage, DecisionLogEntry, DropStage, FastScreenSettings,
+    FeatureReportEntry, IvStabilitySection, NonFiniteEntry, RSquaredAnalysisEntry, ReductionReport,
+    ReductionReportBuilder, ReportBuilderParams, ReportSummary, RescuedDrop, SampleSettings,
+    StageSummary, StepSettings, TimePeriodSummary, TimeStabilitySection, TimingInfo,
+    TrainPartitionSettings, REPORT_SCHEMA_VERSION, USER_RETAINED,
+};
+#[allow(unused_imports)]
+pub use scoring_spec::{
+    export_scoring_spec, scored_features, CategoryGroupWoe, FeatureScoring, NumericBinWoe,
+    ScoringSpec, SpecialValueWoe, SCORING_SPEC_VERSION,
+};
+#[allow(unused_imports)]
+pub use selected_features::{
+    export_selected_features, retained_features, selected_features_python, selected_features_sql,
+    selected_features_text,
 };
 pub use summary::ReductionSummary;
+#[allow(unused_imports)]
+pub use tables::{
+    correlated_pairs_table, iv_table, missing_ratio_table, write_table, TableExports, TableFormat,
+};
+#[allow(unused_imports)]
+pub use validate::{validate_report, validate_report_file, ReportValidation};
+#[allow(unused_imports)]
+pub use woe_sql::{export_woe_transform_sql, woe_transform_sql};
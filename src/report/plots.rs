@@ -0,0 +1,552 @@
+//! Per-feature WoE and event-rate bar charts (`--plots`)
+//!
+//! The features with the highest IV get one chart each in a `{input}_plots/`
+//! directory: WoE per bin above, event rate per bin below, with the special
+//! value and missing bins after the regular ones. An `index.html` in the same
+//! directory shows the charts next to each feature's IV, Gini and outcome.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use super::gini_export::sanitize_file_name;
+use crate::pipeline::{IvAnalysis, WoeBin};
+
+#[cfg(feature = "plots")]
+use render::render_chart;
+
+/// Name of the HTML page listing the charts
+pub const PLOTS_INDEX_FILE: &str = "index.html";
+
+/// Error for `--plots` in builds without the `plots` feature
+pub const PLOTS_DISABLED: &str = "Plots are disabled in this build (enable the `plots` feature)";
+
+/// Longest bin label drawn under a bar; longer labels are cut with `…`
+const MAX_LABEL_CHARS: usize = 16;
+
+/// Image format of the charts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlotFormat {
+    #[default]
+    Svg,
+    Png,
+}
+
+impl PlotFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            PlotFormat::Svg => "svg",
+            PlotFormat::Png => "png",
+        }
+    }
+}
+
+impl std::str::FromStr for PlotFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "svg" => Ok(PlotFormat::Svg),
+            "png" => Ok(PlotFormat::Png),
+            _ => Err(format!(
+                "Invalid plot format: '{}'. Valid options: svg, png",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for PlotFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.extension())
+    }
+}
+
+/// Which features `--plots` charts, and how
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlotSettings {
+    /// Number of features charted, highest IV first
+    pub top_n: usize,
+    pub format: PlotFormat,
+}
+
+/// One bar of a chart
+#[derive(Debug, Clone, PartialEq)]
+struct PlotBar {
+    label: String,
+    woe: f64,
+    event_rate: f64,
+}
+
+/// The `top_n` analyses with the highest IV, highest first
+pub fn top_features_by_iv(analyses: &[IvAnalysis], top_n: usize) -> Vec<&IvAnalysis> {
+    let mut ranked: Vec<&IvAnalysis> = analyses.iter().collect();
+    ranked.sort_by(|a, b| b.iv.total_cmp(&a.iv));
+    ranked.truncate(top_n);
+    ranked
+}
+
+/// Chart the `settings.top_n` features with the highest IV into `dir` and
+/// write the index page. `dropped` names the features the run dropped.
+/// Returns the path of the index page.
+pub fn write_plots(
+    analyses: &[IvAnalysis],
+    dropped: &HashSet<&str>,
+    settings: &PlotSettings,
+    dir: &Path,
+) -> Result<PathBuf> {
+    if !cfg!(feature = "plots") {
+        anyhow::bail!(PLOTS_DISABLED);
+    }
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create plots directory: {}", dir.display()))?;
+    let features = top_features_by_iv(analyses, settings.top_n);
+    let mut charts = Vec::with_capacity(features.len());
+    for (rank, analysis) in features.iter().enumerate() {
+        let file_name = format!(
+            "{:02}_{}.{}",
+            rank + 1,
+            sanitize_file_name(&analysis.feature_name),
+            settings.format.extension()
+        );
+        render_chart(
+            analysis,
+            &chart_bars(analysis),
+            &dir.join(&file_name),
+            settings.format,
+        )
+        .with_context(|| format!("Failed to plot feature '{}'", analysis.feature_name))?;
+        charts.push(file_name);
+    }
+    tracing::debug!(charts = charts.len(), dir = %dir.display(), "Wrote WoE plots");
+
+    let index = dir.join(PLOTS_INDEX_FILE);
+    std::fs::write(&index, index_html(&features, &charts, dropped))
+        .with_context(|| format!("Failed to write plot index: {}", index.display()))?;
+    Ok(index)
+}
+
+#[cfg(not(feature = "plots"))]
+fn render_chart(_: &IvAnalysis, _: &[PlotBar], _: &Path, _: PlotFormat) -> Result<()> {
+    anyhow::bail!(PLOTS_DISABLED)
+}
+
+/// Bars of a feature: its numeric bins or categories, then each special
+/// value, then the missing bin when it stands on its own
+fn chart_bars(analysis: &IvAnalysis) -> Vec<PlotBar> {
+    let numeric = analysis.bins.iter().map(|bin| PlotBar {
+        label: interval_label(bin),
+        woe: bin.woe,
+        event_rate: bin.event_rate,
+    });
+    let categorical = analysis.categories.iter().map(|bin| PlotBar {
+        label: if bin.categories.is_empty() {
+            bin.category.clone()
+        } else {
+            bin.categories.join(", ")
+        },
+        woe: bin.woe,
+        event_rate: bin.event_rate,
+    });
+    let special = analysis.special_bins.iter().map(|bin| PlotBar {
+        label: format!("= {}", bin.value),
+        woe: bin.woe,
+        event_rate: bin.event_rate,
+    });
+    let missing = analysis
+        .missing_bin
+        .iter()
+        .filter(|bin| bin.is_separate())
+        .map(|bin| PlotBar {
+            label: "Missing".to_string(),
+            woe: bin.woe,
+            event_rate: bin.event_rate,
+        });
+    numeric
+        .chain(categorical)
+        .chain(special)
+        .chain(missing)
+        .map(|bar| PlotBar {
+            label: truncate_label(&bar.label),
+            ..bar
+        })
+        .collect()
+}
+
+/// `[lower, upper)`, with open ends shown as `< upper` and `>= lower`
+pub(crate) fn interval_label(bin: &WoeBin) -> String {
+    match (bin.lower_bound.is_finite(), bin.upper_bound.is_finite()) {
+        (false, false) => "All".to_string(),
+        (false, true) => format!("< {}", format_bound(bin.upper_bound)),
+        (true, false) => format!(">= {}", format_bound(bin.lower_bound)),
+        (true, true) => format!(
+            "[{}, {})",
+            format_bound(bin.lower_bound),
+            format_bound(bin.upper_bound)
+        ),
+    }
+}
+
+/// A bound rounded to a whole number from 1000 up, else to at most four
+/// decimals, without trailing zeros
+fn format_bound(value: f64) -> String {
+    if value.abs() >= 1000.0 {
+        return format!("{:.0}", value);
+    }
+    let text = format!("{:.4}", value);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+fn truncate_label(label: &str) -> String {
+    if label.chars().count() <= MAX_LABEL_CHARS {
+        return label.to_string();
+    }
+    let mut cut: String = label.chars().take(MAX_LABEL_CHARS - 1).collect();
+    cut.push('…');
+    cut
+}
+
+/// The index page: one section per chart with the feature's IV, Gini and
+/// whether it was kept
+fn index_html(features: &[&IvAnalysis], charts: &[String], dropped: &HashSet<&str>) -> String {
+    let mut html = String::from(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>WoE and event rate by bin</title>\n<style>\n\
+         body { font-family: sans-serif; margin: 2em; color: #222; }\n\
+         section { margin-bottom: 2.5em; }\n\
+         .kept { color: #2e7d32; }\n.dropped { color: #c62828; }\n\
+         img { max-width: 100%; border: 1px solid #ddd; }\n\
+         </style>\n</head>\n<body>\n<h1>WoE and event rate by bin</h1>\n",
+    );
+    html.push_str(&format!(
+        "<p>The {} feature{} with the highest IV. Bars above zero WoE mark bins with \
+         more events than the whole population.</p>\n",
+        features.len(),
+        if features.len() == 1 { "" } else { "s" }
+    ));
+    for (rank, (analysis, chart)) in features.iter().zip(charts).enumerate() {
+        let name = escape_html(&analysis.feature_name);
+        let outcome = if dropped.contains(analysis.feature_name.as_str()) {
+            "dropped"
+        } else {
+            "kept"
+        };
+        html.push_str(&format!(
+            "<section>\n<h2>{}. {}</h2>\n<p>IV {:.4} · Gini {:.4} · <span class=\"{}\">{}</span></p>\n\
+             <img src=\"{}\" alt=\"WoE and event rate by bin of {}\">\n</section>\n",
+            rank + 1,
+            name,
+            analysis.iv,
+            analysis.gini,
+            outcome,
+            outcome,
+            escape_html(chart),
+            name
+        ));
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(feature = "plots")]
+mod render {
+    //! Drawing with plotters. Text is laid out with a TrueType font found on
+    //! the system, which plotters needs for both the SVG and PNG backends.
+
+    use std::path::Path;
+    use std::sync::OnceLock;
+
+    use anyhow::Result;
+    use plotters::coord::Shift;
+    use plotters::prelude::*;
+    use plotters::style::{register_font, FontStyle};
+
+    use super::{PlotBar, PlotFormat};
+    use crate::pipeline::IvAnalysis;
+
+    const FONT: &str = "sans-serif";
+    /// Width given to each bar, so long bin labels stay apart
+    const PIXELS_PER_BAR: u32 = 96;
+    const MIN_WIDTH: u32 = 800;
+    const HEIGHT: u32 = 640;
+
+    /// Higher event rate than the population
+    const RISK_COLOR: RGBColor = RGBColor(198, 40, 40);
+    /// Lower event rate than the population
+    const SAFE_COLOR: RGBColor = RGBColor(46, 125, 50);
+    const RATE_COLOR: RGBColor = RGBColor(55, 98, 160);
+
+    /// Regular sans-serif fonts of common Linux distributions, macOS and Windows
+    const FONT_CANDIDATES: &[&str] = &[
+        "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+        "/usr/share/fonts/dejavu/DejaVuSans.ttf",
+        "/usr/share/fonts/TTF/DejaVuSans.ttf",
+        "/usr/share/fonts/dejavu-sans-fonts/DejaVuSans.ttf",
+        "/usr/share/fonts/truetype/liberation/LiberationSans-Regular.ttf",
+        "/usr/share/fonts/liberation-sans/LiberationSans-Regular.ttf",
+        "/usr/share/fonts/truetype/freefont/FreeSans.ttf",
+        "/System/Library/Fonts/Supplemental/Arial.ttf",
+        "/Library/Fonts/Arial.ttf",
+        "C:\\Windows\\Fonts\\arial.ttf",
+        "C:\\Windows\\Fonts\\segoeui.ttf",
+    ];
+
+    /// Register the first font of [`FONT_CANDIDATES`] that loads, once per process
+    fn ensure_font() -> Result<()> {
+        static REGISTERED: OnceLock<bool> = OnceLock::new();
+        let registered = *REGISTERED.get_or_init(|| {
+            FONT_CANDIDATES.iter().any(|path| {
+                let Ok(bytes) = std::fs::read(path) else {
+                    return false;
+                };
+                // plotters keeps a reference to the font for the life of the process
+                let bytes: &'static [u8] = Box::leak(bytes.into_boxed_slice());
+                let loaded = register_font(FONT, FontStyle::Normal, bytes).is_ok();
+                if loaded {
+                    tracing::debug!(font = path, "Registered plot font");
+                }
+                loaded
+            })
+        });
+        if !registered {
+            anyhow::bail!(
+                "--plots found no TrueType font to label the charts with. Install \
+                 DejaVu Sans or Liberation Sans (e.g. the fonts-dejavu-core package)"
+            );
+        }
+        Ok(())
+    }
+
+    pub(super) fn render_chart(
+        analysis: &IvAnalysis,
+        bars: &[PlotBar],
+        path: &Path,
+        format: PlotFormat,
+    ) -> Result<()> {
+        ensure_font()?;
+        let size = ((bars.len() as u32 * PIXELS_PER_BAR).max(MIN_WIDTH), HEIGHT);
+        match format {
+            PlotFormat::Svg => draw_chart(
+                SVGBackend::new(path, size).into_drawing_area(),
+                analysis,
+                bars,
+            ),
+            PlotFormat::Png => draw_chart(
+                BitMapBackend::new(path, size).into_drawing_area(),
+                analysis,
+                bars,
+            ),
+        }
+    }
+
+    fn draw_chart<DB: DrawingBackend>(
+        root: DrawingArea<DB, Shift>,
+        analysis: &IvAnalysis,
+        bars: &[PlotBar],
+    ) -> Result<()> {
+        root.fill(&WHITE).map_err(plot_error)?;
+        let title = format!(
+            "{}  (IV {:.4}, Gini {:.4})",
+            analysis.feature_name, analysis.iv, analysis.gini
+        );
+        let root = root.titled(&title, (FONT, 22)).map_err(plot_error)?;
+        let (upper, lower) = root.split_vertically(HEIGHT / 2 - 20);
+
+        let woe: Vec<f64> = bars.iter().map(|b| b.woe).collect();
+        draw_panel(&upper, "WoE", bars, &woe, |v| {
+            if v > 0.0 {
+                RISK_COLOR
+            } else {
+                SAFE_COLOR
+            }
+        })?;
+        let rates: Vec<f64> = bars.iter().map(|b| b.event_rate).collect();
+        draw_panel(&lower, "Event rate", bars, &rates, |_| RATE_COLOR)?;
+
+        root.present().map_err(plot_error)?;
+        Ok(())
+    }
+
+    /// One bar per bin from zero to `values[i]`, labelled with the bin
+    fn draw_panel<DB: DrawingBackend>(
+        area: &DrawingArea<DB, Shift>,
+        caption: &str,
+        bars: &[PlotBar],
+        values: &[f64],
+        color: impl Fn(f64) -> RGBColor,
+    ) -> Result<()> {
+        let finite = values.iter().copied().filter(|v| v.is_finite());
+        let low = finite.clone().fold(0.0, f64::min);
+        let high = finite.fold(0.0, f64::max);
+        let pad = if high > low { (high - low) * 0.1 } else { 1.0 };
+        let y_range = (if low < 0.0 { low - pad } else { 0.0 })..(high + pad);
+
+        // A segmented range of 0..n holds the n + 1 segments 0 to n
+        let last = bars.len().saturating_sub(1) as u32;
+        let mut chart = ChartBuilder::on(area)
+            .margin(12)
+            .x_label_area_size(36)
+            .y_label_area_size(64)
+            .build_cartesian_2d((0..last).into_segmented(), y_range)
+            .map_err(plot_error)?;
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .light_line_style(TRANSPARENT)
+            .x_labels(bars.len())
+            .x_label_style((FONT, 12))
+            .x_label_formatter(&|value| match value {
+                SegmentValue::CenterOf(i) => bars
+                    .get(*i as usize)
+                    .map(|bar| bar.label.clone())
+                    .unwrap_or_default(),
+                _ => String::new(),
+            })
+            .y_desc(caption)
+            .y_label_style((FONT, 12))
+            .draw()
+            .map_err(plot_error)?;
+
+        chart
+            .draw_series(
+                values
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, v)| v.is_finite())
+                    .map(|(i, &v)| {
+                        let i = i as u32;
+                        let mut bar = Rectangle::new(
+                            [
+                                (SegmentValue::Exact(i), 0.0),
+                                (SegmentValue::Exact(i + 1), v),
+                            ],
+                            color(v).filled(),
+                        );
+                        bar.set_margin(0, 0, 8, 8);
+                        bar
+                    }),
+            )
+            .map_err(plot_error)?;
+        Ok(())
+    }
+
+    fn plot_error<E: std::error::Error + Send + Sync>(
+        err: DrawingAreaErrorKind<E>,
+    ) -> anyhow::Error {
+        anyhow::anyhow!("Failed to draw chart: {}", err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::{FeatureType, MissingBin};
+
+    fn bin(lower: f64, upper: f64, woe: f64) -> WoeBin {
+        WoeBin {
+            lower_bound: lower,
+            upper_bound: upper,
+            events: 1.0,
+            non_events: 1.0,
+            woe,
+            iv_contribution: 0.0,
+            count: 2.0,
+            population_pct: 50.0,
+            event_rate: 0.5,
+            chi_square: None,
+            chi_square_p_value: None,
+        }
+    }
+
+    fn analysis(name: &str, iv: f64) -> IvAnalysis {
+        IvAnalysis {
+            feature_name: name.to_string(),
+            feature_type: FeatureType::Numeric,
+            bins: vec![
+                bin(f64::NEG_INFINITY, 1.5, -0.4),
+                bin(1.5, 20.25, 0.1),
+                bin(20.25, f64::INFINITY, 0.7),
+            ],
+            categories: vec![],
+            missing_bin: Some(MissingBin {
+                events: 1.0,
+                non_events: 3.0,
+                woe: -0.2,
+                iv_contribution: 0.01,
+                count: 4.0,
+                population_pct: 5.0,
+                event_rate: 0.25,
+                merged_into: None,
+                imputed_value: None,
+            }),
+            iv,
+            gini: iv / 2.0,
+            mutual_info: None,
+            monotonicity: None,
+            solver_status: None,
+            special_bins: Vec::new(),
+            ks: 0.0,
+            auc: 0.5,
+        }
+    }
+
+    #[test]
+    fn test_chart_bars_and_ranking() {
+        let bars = chart_bars(&analysis("income", 0.3));
+        let labels: Vec<&str> = bars.iter().map(|b| b.label.as_str()).collect();
+        assert_eq!(labels, ["< 1.5", "[1.5, 20.25)", ">= 20.25", "Missing"]);
+        assert_eq!(bars[3].woe, -0.2);
+        assert_eq!(
+            truncate_label("a_very_long_category_name").chars().count(),
+            16
+        );
+
+        let analyses = [
+            analysis("low", 0.1),
+            analysis("high", 0.5),
+            analysis("mid", 0.3),
+        ];
+        let names: Vec<&str> = top_features_by_iv(&analyses, 2)
+            .iter()
+            .map(|a| a.feature_name.as_str())
+            .collect();
+        assert_eq!(names, ["high", "mid"]);
+        assert_eq!("PNG".parse(), Ok(PlotFormat::Png));
+        assert!("jpg".parse::<PlotFormat>().is_err());
+    }
+
+    #[cfg(feature = "plots")]
+    #[test]
+    fn test_write_plots_with_a_single_bin_feature() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut single = analysis("flag & co", 0.2);
+        single.bins.truncate(1);
+        single.missing_bin = None;
+        let analyses = [analysis("income", 0.3), single];
+        let dropped = HashSet::from(["income"]);
+        let settings = PlotSettings {
+            top_n: 5,
+            format: PlotFormat::Png,
+        };
+
+        let index = write_plots(&analyses, &dropped, &settings, dir.path()).unwrap();
+        assert!(dir.path().join("01_income.png").exists());
+        assert!(dir.path().join("02_flag___co.png").exists());
+        let html = std::fs::read_to_string(index).unwrap();
+        assert!(html.contains("flag &amp; co"));
+        assert!(html.contains("<span class=\"dropped\">dropped</span>"));
+    }
+}
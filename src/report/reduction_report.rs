@@ -4,24 +4,78 @@
 //! and the reasons for dropping or keeping each feature.
 
 use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use chrono::Utc;
-use serde::Serialize;
-
-use crate::pipeline::{CorrelatedPair, FeatureToDrop, FeatureType, IvAnalysis};
+use serde::{Deserialize, Serialize};
+
+use crate::pipeline::{
+    AssociationMeasure, CardinalityStats, ConcordanceStats, CorrKeepPolicy, CorrelatedPair,
+    CorrelationStrategy, CrossValidatedIv, DatePolicy, DominanceStats, EventLabels,
+    FeatureDescription, FeatureDictionary, FeatureDrift, FeatureToDrop, FeatureType, IdAction,
+    ImputationPlan, IvAnalysis, IvStability, NonFiniteCounts, PairRationale, PcaSummary,
+    ProxyScreen, RSquaredAnalysis, SchemaDrift, ScreeningStep, SegmentIvAnalysis,
+    SegmentMissingAnalysis, StepOutcome, StepPlan, TemporalColumn, TimeFreq, UnivariateMetric,
+};
 use crate::report::ReductionSummary;
 
 /// Drop stage enum for tracking where feature was dropped
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum DropStage {
+    /// Near-constant pre-filter (`--dominance-threshold`)
+    Constant,
+    /// ID column check with `--id-action drop`
+    Identifier,
     Missing,
     Gini,
     Correlation,
 }
 
+/// Report status of a feature the user kept in the drop review (`--review-drops`)
+pub const USER_RETAINED: &str = "user-retained";
+
+/// Version of the reduction report layout, written as `schema_version`.
+/// Bumped when a field is removed, renamed or changes meaning; new optional
+/// fields keep the version.
+pub const REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Order of the stages in the pipeline, for sorting
+fn stage_order(stage: &DropStage) -> u8 {
+    match stage {
+        DropStage::Constant => 0,
+        DropStage::Identifier => 1,
+        DropStage::Missing => 2,
+        DropStage::Gini => 3,
+        DropStage::Correlation => 4,
+    }
+}
+
+/// Near-constant pre-filter result for a feature
+#[derive(Debug, Clone, Serialize)]
+pub struct DominanceAnalysisEntry {
+    /// Most common value; absent when it is null
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// Weighted share of rows holding the most common value
+    pub share: f64,
+    pub threshold: f64,
+    pub passed: bool,
+}
+
+/// ID column check result for a feature
+#[derive(Debug, Clone, Serialize)]
+pub struct CardinalityAnalysisEntry {
+    /// Distinct non-null values
+    pub unique: usize,
+    /// Distinct values as a share of the non-null values
+    pub ratio: f64,
+    pub threshold: f64,
+    /// `false` when the feature looks like an ID
+    pub passed: bool,
+}
+
 /// Missing analysis result for a feature
 #[derive(Debug, Clone, Serialize)]
 pub struct MissingAnalysisEntry {
@@ -30,6 +84,16 @@ pub struct MissingAnalysisEntry {
     pub passed: bool,
 }
 
+/// Non-finite (NaN/±Inf) values found in a feature before analysis
+#[derive(Debug, Clone, Serialize)]
+pub struct NonFiniteEntry {
+    pub nan_count: usize,
+    pub pos_inf_count: usize,
+    pub neg_inf_count: usize,
+    /// Policy that was applied to these values
+    pub policy: String,
+}
+
 /// Gini analysis result for a feature
 #[derive(Debug, Clone, Serialize)]
 pub struct GiniAnalysisEntry {
@@ -38,6 +102,30 @@ pub struct GiniAnalysisEntry {
     pub threshold: f64,
     pub passed: bool,
     pub feature_type: String,
+    /// Kolmogorov-Smirnov statistic of the WoE bins
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ks: Option<f64>,
+    /// Somers' D on the raw (unbinned) values (numeric features only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub somers_d: Option<f64>,
+    /// Concordance (AUC) on the raw values (numeric features only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub concordance: Option<f64>,
+    /// Mutual information with the target in nats (`--univariate-metric mutual_info`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mutual_info: Option<f64>,
+}
+
+/// R² analysis result for a feature (continuous target)
+#[derive(Debug, Clone, Serialize)]
+pub struct RSquaredAnalysisEntry {
+    pub r_squared: f64,
+    /// Pearson correlation with the target (numeric features only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation: Option<f64>,
+    pub threshold: f64,
+    pub passed: bool,
+    pub feature_type: String,
 }
 
 /// Single correlation entry
@@ -47,6 +135,12 @@ pub struct CorrelationEntry {
     pub correlation: f64,
     /// The association measure used (Pearson, CramersV, or Eta).
     pub measure: String,
+    /// Theil's U of this feature given the other one (CramersV pairs only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theils_u: Option<f64>,
+    /// Which member the pair kept and by which rule (pairs that decided a drop only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rationale: Option<PairRationale>,
 }
 
 /// Correlation analysis result for a feature
@@ -68,10 +162,22 @@ pub struct CorrelationAnalysisEntry {
 /// Complete analysis for a feature
 #[derive(Debug, Clone, Serialize)]
 pub struct FeatureAnalysis {
+    /// Only present when the feature contained NaN or infinite values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub non_finite: Option<NonFiniteEntry>,
+    /// Only present when the near-constant pre-filter ran
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dominance: Option<DominanceAnalysisEntry>,
+    /// Only present for the columns the ID check looked at
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardinality: Option<CardinalityAnalysisEntry>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub missing: Option<MissingAnalysisEntry>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gini: Option<GiniAnalysisEntry>,
+    /// Replaces `gini` when the target is continuous
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r_squared: Option<RSquaredAnalysisEntry>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub correlation: Option<CorrelationAnalysisEntry>,
 }
@@ -80,6 +186,9 @@ pub struct FeatureAnalysis {
 #[derive(Debug, Clone, Serialize)]
 pub struct FeatureReportEntry {
     pub name: String,
+    /// Dictionary description and owner, SAS label and format (flattened into the JSON)
+    #[serde(flatten)]
+    pub dictionary: FeatureDescription,
     pub status: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dropped_at_stage: Option<DropStage>,
@@ -91,9 +200,18 @@ pub struct FeatureReportEntry {
 /// Thresholds used in the analysis
 #[derive(Debug, Clone, Serialize)]
 pub struct ThresholdsConfig {
+    /// Share of rows one value may cover (`--dominance-threshold`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dominance: Option<f64>,
+    /// Distinct-value ratio above which a column looks like an ID (`--id-threshold`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_ratio: Option<f64>,
     pub missing_ratio: f64,
     pub gini: f64,
     pub correlation: f64,
+    /// Threshold for categorical pairs, when it differs from `correlation`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cramers_v: Option<f64>,
 }
 
 /// Settings used in the analysis
@@ -104,6 +222,143 @@ pub struct AnalysisSettings {
     pub weight_column: Option<String>,
     pub binning_strategy: String,
     pub num_bins: usize,
+    /// "binary" or "continuous"; absent in reports from before continuous targets
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_type: Option<String>,
+    /// Names of the target classes, when set with `--event-label`/`--non-event-label`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_labels: Option<EventLabels>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub non_finite_policy: Option<String>,
+    /// Present when statistics were computed on a training partition only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub train_partition: Option<TrainPartitionSettings>,
+    /// Present for a `--fast` run, whose statistics are approximate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fast_screen: Option<FastScreenSettings>,
+    /// Present when the run analysed a row sample (`--sample-rows` / `--sample-frac`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample: Option<SampleSettings>,
+    /// Seed of the randomized steps (row sample, CV folds, random validation
+    /// split); rerunning with it reproduces the analysis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// "mutual_info" when features were filtered on mutual information instead of Gini
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub univariate_metric: Option<String>,
+    /// "cluster" when correlated features were resolved per cluster
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_strategy: Option<String>,
+    /// Which member of a correlated pair was kept, when not the default
+    /// higher-IV rule; `custom_list` carries its prefixes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub corr_keep_policy: Option<CorrKeepPolicy>,
+    /// What the ID check did with the columns it found, when it ran
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_action: Option<IdAction>,
+    /// What happened to date and datetime features, when the dataset had any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_policy: Option<DatePolicy>,
+    /// Present when `--steps`/`--skip-gini` skipped or reordered screening steps
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub steps: Option<StepSettings>,
+}
+
+/// Screening steps of a run that did not run all of them in the default order
+#[derive(Debug, Clone, Serialize)]
+pub struct StepSettings {
+    /// Steps that ran, in order
+    pub order: Vec<ScreeningStep>,
+    /// Steps that did not run; their features carry no statistics of them
+    pub skipped: Vec<ScreeningStep>,
+}
+
+/// Split column used to restrict screening to the training rows
+#[derive(Debug, Clone, Serialize)]
+pub struct TrainPartitionSettings {
+    pub split_column: String,
+    pub train_value: String,
+    /// Rows used for the statistics
+    pub train_rows: usize,
+    /// Rows in the dataset (all receive the drops)
+    pub total_rows: usize,
+}
+
+/// Shortcuts taken by a `--fast` screening run
+#[derive(Debug, Clone, Serialize)]
+pub struct FastScreenSettings {
+    /// Always true: drops are indicative and should be confirmed by a full run
+    pub approximate: bool,
+    /// Quantile bins per feature, with no solver and no merging
+    pub bins: usize,
+    /// Rows the correlation analysis was computed on
+    pub correlation_rows: usize,
+    /// Rows available to the analysis
+    pub total_rows: usize,
+}
+
+/// Row sample drawn at load time for a quicker exploratory run
+#[derive(Debug, Clone, Serialize)]
+pub struct SampleSettings {
+    /// Rows requested with `--sample-rows`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_rows: Option<usize>,
+    /// Fraction requested with `--sample-frac`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fraction: Option<f64>,
+    /// Column whose classes were sampled proportionally, i.e. the target
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stratified_on: Option<String>,
+    pub seed: u64,
+    /// Rows in the sample, all the analysis saw
+    pub sampled_rows: usize,
+    /// Rows in the loaded dataset
+    pub total_rows: usize,
+}
+
+/// IV of the surviving features on training versus validation rows (`--iv-stability`)
+#[derive(Debug, Clone, Serialize)]
+pub struct IvStabilitySection {
+    /// "split_column" (rows outside `--train-value`) or "random"
+    pub split: String,
+    /// Share of the rows held out, for a random split
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation_fraction: Option<f64>,
+    pub train_rows: usize,
+    pub validation_rows: usize,
+    /// Relative IV change or rank shift above which a feature is unstable
+    pub threshold: f64,
+    /// Whether unstable features were dropped (`--iv-stability-threshold`)
+    pub drop_unstable: bool,
+    pub features: Vec<IvStability>,
+}
+
+/// Rows of one `--time-column` period
+#[derive(Debug, Clone, Serialize)]
+pub struct TimePeriodSummary {
+    pub period: String,
+    pub rows: usize,
+    pub events: usize,
+    pub non_events: usize,
+    /// Whether the period had enough rows of both classes for an IV
+    pub scored: bool,
+}
+
+/// Fill rate and IV of the surviving features per period of a date column
+/// (`--time-column`)
+#[derive(Debug, Clone, Serialize)]
+pub struct TimeStabilitySection {
+    pub column: String,
+    pub freq: TimeFreq,
+    /// Periods in time order
+    pub periods: Vec<TimePeriodSummary>,
+    /// Rows with a null date, left out of every period
+    pub undated_rows: usize,
+    /// Relative fitted IV change above which a feature is drifting
+    pub iv_threshold: f64,
+    /// Fitted fill-rate change above which a feature is drifting
+    pub fill_rate_threshold: f64,
+    pub features: Vec<FeatureDrift>,
 }
 
 /// Report metadata
@@ -112,9 +367,19 @@ pub struct ReportMetadata {
     pub timestamp: String,
     pub lophi_version: String,
     pub input_file: String,
+    /// The files a glob or directory `input_file` matched, in the order
+    /// they were read; absent for a single file
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub input_files: Vec<String>,
     pub output_file: String,
     pub thresholds: ThresholdsConfig,
     pub settings: AnalysisSettings,
+    /// Comparison against `--expect-schema`; absent when no schema was given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_drift: Option<SchemaDrift>,
+    /// Temporal columns `--date-policy` kept, dropped or derived features from
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub temporal_columns: Vec<TemporalColumn>,
 }
 
 /// Stage-level summary
@@ -127,18 +392,35 @@ pub struct StageSummary {
 /// By-stage breakdown
 #[derive(Debug, Clone, Serialize)]
 pub struct ByStage {
-    pub missing: StageSummary,
-    pub gini: StageSummary,
-    pub correlation: StageSummary,
+    /// Present when the near-constant pre-filter ran
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constant: Option<StageSummary>,
+    /// Present when the ID check ran with `--id-action drop`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<StageSummary>,
+    /// The screening stages are absent when `--steps` skipped them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing: Option<StageSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gini: Option<StageSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation: Option<StageSummary>,
 }
 
 /// Timing information in milliseconds
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct TimingInfo {
     pub load_ms: u64,
-    pub missing_ms: u64,
-    pub gini_ms: u64,
-    pub correlation_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constant_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gini_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_ms: Option<u64>,
     pub save_ms: u64,
     pub total_ms: u64,
 }
@@ -151,14 +433,74 @@ pub struct ReportSummary {
     pub dropped_count: usize,
     pub by_stage: ByStage,
     pub timing: TimingInfo,
+    /// Likely ID columns that `--id-action flag` kept, sorted by name
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub flagged_id_columns: Vec<String>,
+}
+
+/// A manual decision that changed the outcome the automatic rules chose
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionLogEntry {
+    pub stage: DropStage,
+    /// Feature the decision kept
+    pub kept: String,
+    /// Feature dropped in its place, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dropped: Option<String>,
+    /// Who made the decision, e.g. "user"
+    pub source: String,
+    pub detail: String,
+    /// The drop the decision prevented, so reviewers can see what keeping
+    /// `kept` costs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rescued: Option<RescuedDrop>,
+}
+
+/// Why a kept feature would otherwise have been dropped
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RescuedDrop {
+    /// Reason the automatic rules gave
+    pub reason: String,
+    /// Statistic that triggered the drop ("Pearson", "CramersV", "Eta", ...)
+    pub metric: String,
+    pub value: f64,
+    /// Threshold `value` crossed
+    pub threshold: f64,
 }
 
 /// Complete reduction report
 #[derive(Debug, Clone, Serialize)]
 pub struct ReductionReport {
+    /// [`REPORT_SCHEMA_VERSION`] of the writing lophi
+    pub schema_version: u32,
     pub metadata: ReportMetadata,
     pub summary: ReportSummary,
     pub features: Vec<FeatureReportEntry>,
+    /// Manual overrides applied during the run, in the order they were made
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub decision_log: Vec<DecisionLogEntry>,
+    /// Train/validation IV comparison, present with `--iv-stability`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iv_stability: Option<IvStabilitySection>,
+    /// Missing ratios per segment, present with `--segment-column`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segment_missing: Option<SegmentMissingAnalysis>,
+    /// IV per segment and the divergent features, present with `--segment-column`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segment_iv: Option<SegmentIvAnalysis>,
+    /// Per-period fill rate and IV drift, present with `--time-column`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_stability: Option<TimeStabilitySection>,
+    /// Features tracking a protected attribute, present with `--protected-columns`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fairness: Option<ProxyScreen>,
+    /// Explained variance and high-loading groups of the retained numeric
+    /// features, present with `--pca`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pca: Option<PcaSummary>,
+    /// Steps added through `Reducer::step`, in the order they ran
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub custom_steps: Vec<StepOutcome>,
 }
 
 /// Parameters for creating a ReductionReportBuilder
@@ -178,6 +520,7 @@ pub struct ReportBuilderParams {
 pub struct ReductionReportBuilder {
     // Metadata
     input_file: String,
+    input_files: Vec<String>,
     output_file: String,
     target_column: String,
     weight_column: Option<String>,
@@ -185,26 +528,70 @@ pub struct ReductionReportBuilder {
     num_bins: usize,
 
     // Thresholds
+    dominance_threshold: Option<f64>,
+    id_threshold: Option<f64>,
     missing_threshold: f64,
     gini_threshold: f64,
     correlation_threshold: f64,
+    cramers_v_threshold: Option<f64>,
 
     // Per-feature data collected during pipeline
+    target_type: Option<String>,
+    event_labels: Option<EventLabels>,
+    non_finite_policy: Option<String>,
+    train_partition: Option<TrainPartitionSettings>,
+    fast_screen: Option<FastScreenSettings>,
+    steps: Option<StepSettings>,
+    sample: Option<SampleSettings>,
+    seed: Option<u64>,
+    correlation_strategy: Option<String>,
+    corr_keep_policy: Option<CorrKeepPolicy>,
+    id_action: Option<IdAction>,
+    date_policy: Option<DatePolicy>,
+    temporal_columns: Vec<TemporalColumn>,
+    schema_drift: Option<SchemaDrift>,
+    dictionary: Option<FeatureDictionary>,
+    iv_stability: Option<IvStabilitySection>,
+    segment_missing: Option<SegmentMissingAnalysis>,
+    segment_iv: Option<SegmentIvAnalysis>,
+    time_stability: Option<TimeStabilitySection>,
+    fairness: Option<ProxyScreen>,
+    pca: Option<PcaSummary>,
+    custom_steps: Vec<StepOutcome>,
+    cv_iv_means: HashMap<String, f64>,
+    cv_min_iv: Option<f64>,
+    non_finite_counts: HashMap<String, NonFiniteCounts>,
+    dominance: HashMap<String, DominanceStats>,
+    cardinality: HashMap<String, CardinalityStats>,
+    /// Columns the ID check found, dropped or only flagged per `id_action`
+    id_like: HashSet<String>,
     missing_ratios: HashMap<String, f64>,
     gini_results: HashMap<String, (f64, f64, FeatureType)>, // (gini, iv, type)
+    r2_results: HashMap<String, (f64, Option<f64>, FeatureType)>, // (r², correlation, type)
+    concordance_results: HashMap<String, (f64, f64)>,       // (somers_d, concordance)
+    mutual_info_results: HashMap<String, f64>,
+    ks_results: HashMap<String, f64>,
     correlation_pairs: Vec<CorrelatedPair>,
 
     // Drop tracking
+    dropped_constant: HashSet<String>,
+    dropped_identifier: HashSet<String>,
     dropped_missing: HashSet<String>,
     dropped_gini: HashSet<String>,
     dropped_correlation: HashSet<String>,
     dropped_correlation_reasons: HashMap<String, String>, // feature -> human-readable drop reason
+    user_retained: HashSet<String>,
+    keep_columns: HashSet<String>,
+    protected_correlation_reasons: HashMap<String, String>,
+    decision_log: Vec<DecisionLogEntry>,
 
     // Timing
     timing: TimingInfo,
 
-    // Feature list (all features seen at missing analysis stage)
+    // Feature list (all features seen at the first stage that ran)
     all_features: Vec<String>,
+    /// `all_features` came from `set_screened_features`
+    features_screened: bool,
 }
 
 impl ReductionReportBuilder {
@@ -212,34 +599,234 @@ impl ReductionReportBuilder {
     pub fn new(params: ReportBuilderParams) -> Self {
         Self {
             input_file: params.input_file,
+            input_files: Vec::new(),
             output_file: params.output_file,
             target_column: params.target_column,
             weight_column: params.weight_column,
             binning_strategy: params.binning_strategy,
             num_bins: params.num_bins,
+            dominance_threshold: None,
+            id_threshold: None,
             missing_threshold: params.missing_threshold,
             gini_threshold: params.gini_threshold,
             correlation_threshold: params.correlation_threshold,
+            cramers_v_threshold: None,
+            target_type: None,
+            event_labels: None,
+            non_finite_policy: None,
+            train_partition: None,
+            fast_screen: None,
+            steps: None,
+            sample: None,
+            seed: None,
+            correlation_strategy: None,
+            corr_keep_policy: None,
+            id_action: None,
+            date_policy: None,
+            temporal_columns: Vec::new(),
+            schema_drift: None,
+            dictionary: None,
+            iv_stability: None,
+            segment_missing: None,
+            segment_iv: None,
+            time_stability: None,
+            fairness: None,
+            pca: None,
+            custom_steps: Vec::new(),
+            cv_iv_means: HashMap::new(),
+            cv_min_iv: None,
+            non_finite_counts: HashMap::new(),
+            dominance: HashMap::new(),
+            cardinality: HashMap::new(),
+            id_like: HashSet::new(),
             missing_ratios: HashMap::new(),
             gini_results: HashMap::new(),
+            r2_results: HashMap::new(),
+            concordance_results: HashMap::new(),
+            mutual_info_results: HashMap::new(),
+            ks_results: HashMap::new(),
             correlation_pairs: Vec::new(),
+            dropped_constant: HashSet::new(),
+            dropped_identifier: HashSet::new(),
             dropped_missing: HashSet::new(),
             dropped_gini: HashSet::new(),
             dropped_correlation: HashSet::new(),
             dropped_correlation_reasons: HashMap::new(),
+            user_retained: HashSet::new(),
+            keep_columns: HashSet::new(),
+            protected_correlation_reasons: HashMap::new(),
+            decision_log: Vec::new(),
             timing: TimingInfo::default(),
             all_features: Vec::new(),
+            features_screened: false,
         }
     }
 
-    /// Record missing analysis results
-    pub fn set_missing_results(&mut self, ratios: &[(String, f64)], dropped: &[String]) {
-        // Store all features seen at this stage (excluding target)
-        self.all_features = ratios
+    /// Record whether features were scored against a binary or continuous target
+    pub fn set_target_type(&mut self, target_type: &str) {
+        self.target_type = Some(target_type.to_string());
+    }
+
+    /// Record the class names that stand in for 1/0 in the report
+    pub fn set_event_labels(&mut self, labels: &EventLabels) {
+        self.event_labels = Some(labels.clone());
+    }
+
+    /// Record non-finite value counts and the policy used to handle them
+    pub fn set_non_finite_results(&mut self, counts: &[NonFiniteCounts], policy: &str) {
+        self.non_finite_policy = Some(policy.to_string());
+        for entry in counts {
+            self.non_finite_counts
+                .insert(entry.feature.clone(), entry.clone());
+        }
+    }
+
+    /// Record the temporal columns and what `policy` did with them; nothing is
+    /// recorded when the dataset had none
+    pub fn set_temporal_results(&mut self, temporal: &[TemporalColumn], policy: DatePolicy) {
+        if !temporal.is_empty() {
+            self.date_policy = Some(policy);
+            self.temporal_columns = temporal.to_vec();
+        }
+    }
+
+    /// Record that statistics come from the training rows of a split column
+    pub fn set_train_partition(&mut self, settings: TrainPartitionSettings) {
+        self.train_partition = Some(settings);
+    }
+
+    /// Record a separate Cramér's V threshold for categorical pairs
+    pub fn set_cramers_v_threshold(&mut self, threshold: f64) {
+        self.cramers_v_threshold = Some(threshold);
+    }
+
+    /// Record a correlation strategy other than the default pairwise one
+    pub fn set_correlation_strategy(&mut self, strategy: CorrelationStrategy) {
+        self.correlation_strategy =
+            (strategy != CorrelationStrategy::Pairwise).then(|| strategy.to_string());
+    }
+
+    /// Record a correlation keep policy other than the default higher-IV one
+    pub fn set_corr_keep_policy(&mut self, policy: &CorrKeepPolicy) {
+        self.corr_keep_policy = (*policy != CorrKeepPolicy::default()).then(|| policy.clone());
+    }
+
+    /// Mark the report as coming from an approximate `--fast` run
+    pub fn set_fast_screen(&mut self, settings: FastScreenSettings) {
+        self.fast_screen = Some(settings);
+    }
+
+    /// Record the row sample the run analysed
+    pub fn set_sample(&mut self, settings: SampleSettings) {
+        self.sample = Some(settings);
+    }
+
+    /// Record the seed of the run's randomized steps
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = Some(seed);
+    }
+
+    /// Record the files of a glob or directory input
+    pub fn set_input_files(&mut self, files: Vec<String>) {
+        self.input_files = files;
+    }
+
+    /// Record the comparison of the loaded columns against the expected schema
+    pub fn set_schema_drift(&mut self, drift: SchemaDrift) {
+        self.schema_drift = Some(drift);
+    }
+
+    /// Attach feature descriptions and owners to the report entries
+    pub fn set_dictionary(&mut self, dictionary: &FeatureDictionary) {
+        self.dictionary = Some(dictionary.clone());
+    }
+
+    /// Record the near-constant pre-filter, which runs before missing analysis
+    pub fn set_dominance_results(
+        &mut self,
+        stats: &[DominanceStats],
+        dropped: &[String],
+        threshold: f64,
+    ) {
+        self.dominance_threshold = Some(threshold);
+        self.all_features = stats
             .iter()
-            .filter(|(name, _)| name != &self.target_column)
-            .map(|(name, _)| name.clone())
+            .filter(|s| s.feature != self.target_column)
+            .map(|s| s.feature.clone())
             .collect();
+        for s in stats {
+            if s.feature != self.target_column {
+                self.dominance.insert(s.feature.clone(), s.clone());
+            }
+        }
+        self.dropped_constant.extend(dropped.iter().cloned());
+    }
+
+    /// Record the ID column check, which runs after the near-constant
+    /// pre-filter. `id_like` are the columns it found; they count as dropped
+    /// only under [`IdAction::Drop`].
+    pub fn set_cardinality_results(
+        &mut self,
+        stats: &[CardinalityStats],
+        id_like: &[String],
+        threshold: f64,
+        action: IdAction,
+    ) {
+        self.id_threshold = Some(threshold);
+        self.id_action = Some(action);
+        for s in stats {
+            if s.feature != self.target_column {
+                self.cardinality.insert(s.feature.clone(), s.clone());
+            }
+        }
+        self.id_like.extend(id_like.iter().cloned());
+        if action == IdAction::Drop {
+            for feature in id_like {
+                if !self.all_features.contains(feature) {
+                    self.all_features.push(feature.clone());
+                }
+            }
+            self.dropped_identifier.extend(id_like.iter().cloned());
+        }
+    }
+
+    /// Record the screening steps of `--steps`/`--skip-gini`; a plan running
+    /// every step in the default order is not recorded
+    pub fn set_step_plan(&mut self, plan: &StepPlan) {
+        self.steps = (!plan.is_default()).then(|| StepSettings {
+            order: plan.steps().to_vec(),
+            skipped: plan.skipped(),
+        });
+    }
+
+    /// Record the features at the first screening step. Only needed when
+    /// that step is not the missing value analysis, which records them itself.
+    pub fn set_screened_features(&mut self, features: &[String]) {
+        self.record_features(features);
+        self.features_screened = true;
+    }
+
+    /// Store all features seen at the first screening step (excluding
+    /// target), after those the pre-filters dropped before it
+    fn record_features<'a>(&mut self, names: impl IntoIterator<Item = &'a String>) {
+        let mut features = std::mem::take(&mut self.all_features);
+        features.retain(|name| {
+            self.dropped_constant.contains(name) || self.dropped_identifier.contains(name)
+        });
+        features.extend(
+            names
+                .into_iter()
+                .filter(|name| *name != &self.target_column)
+                .cloned(),
+        );
+        self.all_features = features;
+    }
+
+    /// Record missing analysis results
+    pub fn set_missing_results(&mut self, ratios: &[(String, f64)], dropped: &[String]) {
+        if !self.features_screened {
+            self.record_features(ratios.iter().map(|(name, _)| name));
+        }
 
         // Store missing ratios
         for (name, ratio) in ratios {
@@ -262,6 +849,12 @@ impl ReductionReportBuilder {
                 analysis.feature_name.clone(),
                 (analysis.gini, analysis.iv, analysis.feature_type),
             );
+            self.ks_results
+                .insert(analysis.feature_name.clone(), analysis.ks);
+            if let Some(mi) = analysis.mutual_info {
+                self.mutual_info_results
+                    .insert(analysis.feature_name.clone(), mi);
+            }
         }
 
         // Store dropped features
@@ -270,6 +863,82 @@ impl ReductionReportBuilder {
         }
     }
 
+    /// Record the train/validation IV comparison; `dropped` are the unstable
+    /// features removed at the Gini stage
+    pub fn set_iv_stability(&mut self, section: IvStabilitySection, dropped: &[String]) {
+        for feature in dropped {
+            self.dropped_gini.insert(feature.clone());
+        }
+        self.iv_stability = Some(section);
+    }
+
+    /// Record the missing ratios per segment (`--segment-column`)
+    pub fn set_segment_missing(&mut self, analysis: SegmentMissingAnalysis) {
+        self.segment_missing = Some(analysis);
+    }
+
+    /// Record the IV per segment of the Gini stage (`--segment-column`)
+    pub fn set_segment_iv(&mut self, analysis: SegmentIvAnalysis) {
+        self.segment_iv = Some(analysis);
+    }
+
+    /// Record the per-period drift of the surviving features (`--time-column`)
+    pub fn set_time_stability(&mut self, section: TimeStabilitySection) {
+        self.time_stability = Some(section);
+    }
+
+    /// Record the proxy screen of the protected attributes (`--protected-columns`)
+    pub fn set_fairness(&mut self, screen: ProxyScreen) {
+        self.fairness = Some(screen);
+    }
+
+    /// Record the PCA of the retained numeric features (`--pca`)
+    pub fn set_pca(&mut self, summary: PcaSummary) {
+        self.pca = Some(summary);
+    }
+
+    /// Record the outcome of a step registered with `Reducer::step`
+    #[allow(dead_code)] // Library API; the binary has no custom steps
+    pub fn add_custom_step(&mut self, outcome: StepOutcome) {
+        self.custom_steps.push(outcome);
+    }
+
+    /// Record the cross-validated IV (`--cv-folds`); with `min_iv` set, features
+    /// whose mean holdout IV is below it are reported as dropped on that rule
+    pub fn set_cross_validation(&mut self, results: &[CrossValidatedIv], min_iv: Option<f64>) {
+        self.cv_iv_means = results
+            .iter()
+            .map(|r| (r.feature.clone(), r.iv_mean))
+            .collect();
+        self.cv_min_iv = min_iv;
+    }
+
+    /// Record Somers' D and concordance on the raw feature values
+    pub fn set_concordance_results(&mut self, stats: &[ConcordanceStats]) {
+        for s in stats {
+            self.concordance_results
+                .insert(s.feature_name.clone(), (s.somers_d, s.concordance));
+        }
+    }
+
+    /// Record R² analysis results (continuous target); drops count toward the Gini stage
+    pub fn set_r2_results(&mut self, analyses: &[RSquaredAnalysis], dropped: &[String]) {
+        for analysis in analyses {
+            self.r2_results.insert(
+                analysis.feature_name.clone(),
+                (
+                    analysis.r_squared,
+                    analysis.correlation,
+                    analysis.feature_type,
+                ),
+            );
+        }
+
+        for feature in dropped {
+            self.dropped_gini.insert(feature.clone());
+        }
+    }
+
     /// Record correlation analysis results
     pub fn set_correlation_results(&mut self, pairs: &[CorrelatedPair], dropped: &[FeatureToDrop]) {
         // Store all correlation pairs
@@ -283,13 +952,81 @@ impl ReductionReportBuilder {
         }
     }
 
+    /// Record the `--keep-columns` list. Kept features the rules would have
+    /// dropped get the prevented drop in their reason and a decision log entry.
+    pub fn set_keep_columns(&mut self, columns: &[String]) {
+        self.keep_columns = columns.iter().cloned().collect();
+    }
+
+    /// Record the correlation drops that `--keep-columns` prevented, as the
+    /// selection without the keep list would have made them
+    pub fn set_protected_correlation_drops(&mut self, drops: &[FeatureToDrop]) {
+        for ftd in drops {
+            self.protected_correlation_reasons
+                .insert(ftd.feature.clone(), ftd.reason.clone());
+        }
+    }
+
+    /// Append manual decisions to the report's decision log
+    pub fn add_decisions(&mut self, entries: &[DecisionLogEntry]) {
+        self.decision_log.extend_from_slice(entries);
+    }
+
+    /// Every feature dropped so far with its stage and reason, in stage order
+    pub fn proposed_drops(&self) -> Vec<(String, DropStage, String)> {
+        let mut drops: Vec<(String, DropStage, String)> = self
+            .all_features
+            .iter()
+            .filter_map(|name| {
+                self.drop_status(name)
+                    .map(|(stage, reason)| (name.clone(), stage, reason))
+            })
+            .collect();
+        drops.sort_by(|a, b| {
+            stage_order(&a.1)
+                .cmp(&stage_order(&b.1))
+                .then(a.0.cmp(&b.0))
+        });
+        drops
+    }
+
+    /// Keep features the user un-checked in the drop review. They are reported
+    /// as `user-retained`, with a decision log entry recording the drop they
+    /// escaped.
+    pub fn set_user_retained(&mut self, features: &[String]) {
+        for feature in features {
+            let Some((stage, reason)) = self.drop_status(feature) else {
+                continue;
+            };
+            if !self.user_retained.insert(feature.clone()) {
+                continue;
+            }
+            let (metric, value, threshold) = self.drop_trigger(feature, &stage);
+            self.decision_log.push(DecisionLogEntry {
+                stage,
+                kept: feature.clone(),
+                dropped: None,
+                source: "user".to_string(),
+                detail: "Kept in drop review".to_string(),
+                rescued: Some(RescuedDrop {
+                    reason,
+                    metric,
+                    value,
+                    threshold,
+                }),
+            });
+        }
+    }
+
     /// Set timing information from the ReductionSummary
     pub fn set_timing(&mut self, summary: &ReductionSummary) {
         self.timing = TimingInfo {
             load_ms: summary.load_time.as_millis() as u64,
-            missing_ms: summary.missing_time.as_millis() as u64,
-            gini_ms: summary.gini_time.as_millis() as u64,
-            correlation_ms: summary.correlation_time.as_millis() as u64,
+            constant_ms: summary.constant_time.map(|t| t.as_millis() as u64),
+            identifier_ms: summary.identifier_time.map(|t| t.as_millis() as u64),
+            missing_ms: summary.missing_time.map(|t| t.as_millis() as u64),
+            gini_ms: summary.gini_time.map(|t| t.as_millis() as u64),
+            correlation_ms: summary.correlation_time.map(|t| t.as_millis() as u64),
             save_ms: summary.save_time.as_millis() as u64,
             total_ms: summary.total_time().as_millis() as u64,
         };
@@ -308,114 +1045,442 @@ impl ReductionReportBuilder {
         features.sort_by(|a, b| match (&a.dropped_at_stage, &b.dropped_at_stage) {
             (None, Some(_)) => std::cmp::Ordering::Less,
             (Some(_), None) => std::cmp::Ordering::Greater,
-            (Some(stage_a), Some(stage_b)) => {
-                let order_a = match stage_a {
-                    DropStage::Missing => 0,
-                    DropStage::Gini => 1,
-                    DropStage::Correlation => 2,
-                };
-                let order_b = match stage_b {
-                    DropStage::Missing => 0,
-                    DropStage::Gini => 1,
-                    DropStage::Correlation => 2,
-                };
-                order_a.cmp(&order_b).then(a.name.cmp(&b.name))
-            }
+            (Some(stage_a), Some(stage_b)) => stage_order(stage_a)
+                .cmp(&stage_order(stage_b))
+                .then(a.name.cmp(&b.name)),
             (None, None) => a.name.cmp(&b.name),
         });
 
-        let dropped_count =
-            self.dropped_missing.len() + self.dropped_gini.len() + self.dropped_correlation.len();
+        let protected_log: Vec<DecisionLogEntry> = self
+            .all_features
+            .iter()
+            .filter_map(|name| {
+                let (stage, reason) = self.protected_drop(name)?;
+                let (metric, value, threshold) = self.drop_trigger(name, &stage);
+                Some(DecisionLogEntry {
+                    stage,
+                    kept: name.clone(),
+                    dropped: None,
+                    source: "keep-columns".to_string(),
+                    detail: "Protected by --keep-columns".to_string(),
+                    rescued: Some(RescuedDrop {
+                        reason,
+                        metric,
+                        value,
+                        threshold,
+                    }),
+                })
+            })
+            .collect();
+
+        // User-retained features stay in the output
+        let stage_drops = |dropped: &HashSet<String>| {
+            dropped
+                .iter()
+                .filter(|f| !self.user_retained.contains(*f))
+                .count()
+        };
+        let dropped_constant = stage_drops(&self.dropped_constant);
+        let dropped_identifier = stage_drops(&self.dropped_identifier);
+        let dropped_missing = stage_drops(&self.dropped_missing);
+        let dropped_gini = stage_drops(&self.dropped_gini);
+        let dropped_correlation = stage_drops(&self.dropped_correlation);
+        let skipped = self
+            .steps
+            .as_ref()
+            .map(|steps| steps.skipped.clone())
+            .unwrap_or_default();
+        let ran = |step| !skipped.contains(&step);
+        let dropped_count = dropped_constant
+            + dropped_identifier
+            + dropped_missing
+            + dropped_gini
+            + dropped_correlation;
+        let mut flagged_id_columns: Vec<String> = self
+            .id_like
+            .difference(&self.dropped_identifier)
+            .cloned()
+            .collect();
+        flagged_id_columns.sort();
 
         ReductionReport {
+            schema_version: REPORT_SCHEMA_VERSION,
             metadata: ReportMetadata {
                 timestamp: Utc::now().to_rfc3339(),
                 lophi_version: env!("CARGO_PKG_VERSION").to_string(),
                 input_file: self.input_file,
+                input_files: self.input_files,
                 output_file: self.output_file,
                 thresholds: ThresholdsConfig {
+                    dominance: self.dominance_threshold,
+                    id_ratio: self.id_threshold,
                     missing_ratio: self.missing_threshold,
                     gini: self.gini_threshold,
                     correlation: self.correlation_threshold,
+                    cramers_v: self.cramers_v_threshold,
                 },
                 settings: AnalysisSettings {
                     target_column: self.target_column,
                     weight_column: self.weight_column,
                     binning_strategy: self.binning_strategy,
                     num_bins: self.num_bins,
+                    target_type: self.target_type,
+                    event_labels: self.event_labels,
+                    non_finite_policy: self.non_finite_policy,
+                    train_partition: self.train_partition,
+                    fast_screen: self.fast_screen,
+                    sample: self.sample,
+                    seed: self.seed,
+                    univariate_metric: (!self.mutual_info_results.is_empty())
+                        .then(|| UnivariateMetric::MutualInfo.to_string()),
+                    correlation_strategy: self.correlation_strategy,
+                    corr_keep_policy: self.corr_keep_policy,
+                    id_action: self.id_action,
+                    date_policy: self.date_policy,
+                    steps: self.steps,
                 },
+                schema_drift: self.schema_drift,
+                temporal_columns: self.temporal_columns,
             },
             summary: ReportSummary {
                 initial_features: self.all_features.len(),
                 final_features: self.all_features.len() - dropped_count,
                 dropped_count,
                 by_stage: ByStage {
-                    missing: StageSummary {
-                        dropped: self.dropped_missing.len(),
+                    constant: self.dominance_threshold.map(|threshold| StageSummary {
+                        dropped: dropped_constant,
+                        threshold_used: threshold,
+                    }),
+                    identifier: (self.id_action == Some(IdAction::Drop)).then(|| StageSummary {
+                        dropped: dropped_identifier,
+                        threshold_used: self.id_threshold.unwrap_or(1.0),
+                    }),
+                    missing: ran(ScreeningStep::Missing).then_some(StageSummary {
+                        dropped: dropped_missing,
                         threshold_used: self.missing_threshold,
-                    },
-                    gini: StageSummary {
-                        dropped: self.dropped_gini.len(),
+                    }),
+                    gini: ran(ScreeningStep::Gini).then_some(StageSummary {
+                        dropped: dropped_gini,
                         threshold_used: self.gini_threshold,
-                    },
-                    correlation: StageSummary {
-                        dropped: self.dropped_correlation.len(),
+                    }),
+                    correlation: ran(ScreeningStep::Correlation).then_some(StageSummary {
+                        dropped: dropped_correlation,
                         threshold_used: self.correlation_threshold,
-                    },
+                    }),
                 },
                 timing: self.timing,
+                flagged_id_columns,
             },
             features,
+            decision_log: self.decision_log.into_iter().chain(protected_log).collect(),
+            iv_stability: self.iv_stability,
+            segment_missing: self.segment_missing,
+            segment_iv: self.segment_iv,
+            time_stability: self.time_stability,
+            fairness: self.fairness,
+            pca: self.pca,
+            custom_steps: self.custom_steps,
         }
     }
 
-    /// Build a single feature entry
-    fn build_feature_entry(&self, feature_name: &str) -> FeatureReportEntry {
-        // Determine status and drop stage
-        let (status, dropped_at_stage, reason) = if self.dropped_missing.contains(feature_name) {
-            let ratio = self
-                .missing_ratios
-                .get(feature_name)
-                .copied()
-                .unwrap_or(0.0);
-            (
-                "dropped".to_string(),
-                Some(DropStage::Missing),
-                Some(format!(
-                    "Missing ratio {:.2} exceeded threshold {:.2}",
-                    ratio, self.missing_threshold
-                )),
-            )
+    /// Stage that dropped a feature, with the reason written to the report
+    fn drop_status(&self, feature_name: &str) -> Option<(DropStage, String)> {
+        if self.dropped_constant.contains(feature_name) {
+            Some((DropStage::Constant, self.constant_reason(feature_name)))
+        } else if self.dropped_identifier.contains(feature_name) {
+            Some((DropStage::Identifier, self.identifier_reason(feature_name)))
+        } else if self.dropped_missing.contains(feature_name) {
+            Some((DropStage::Missing, self.missing_reason(feature_name)))
         } else if self.dropped_gini.contains(feature_name) {
-            let gini = self
-                .gini_results
-                .get(feature_name)
-                .map(|(g, _, _)| *g)
-                .unwrap_or(0.0);
-            (
-                "dropped".to_string(),
-                Some(DropStage::Gini),
-                Some(format!(
-                    "Gini coefficient {:.4} below threshold {:.4}",
-                    gini, self.gini_threshold
-                )),
-            )
+            Some((DropStage::Gini, self.gini_reason(feature_name)))
         } else if self.dropped_correlation.contains(feature_name) {
             let reason = self
                 .dropped_correlation_reasons
                 .get(feature_name)
                 .cloned()
                 .unwrap_or_else(|| "Dropped due to high correlation".to_string());
-            (
-                "dropped".to_string(),
-                Some(DropStage::Correlation),
-                Some(reason),
+            Some((DropStage::Correlation, reason))
+        } else {
+            None
+        }
+    }
+
+    /// Drop that `--keep-columns` prevented, with its stage and reason. The
+    /// checks mirror the near-constant, ID, missing and univariate filters.
+    fn protected_drop(&self, feature_name: &str) -> Option<(DropStage, String)> {
+        if !self.keep_columns.contains(feature_name) {
+            return None;
+        }
+        let ratio = self.missing_ratios.get(feature_name).copied();
+        let dominated = self
+            .dominance
+            .get(feature_name)
+            .zip(self.dominance_threshold)
+            .is_some_and(|(stats, threshold)| stats.is_dominated(threshold));
+
+        let id_like = self.id_action == Some(IdAction::Drop)
+            && self
+                .cardinality
+                .get(feature_name)
+                .zip(self.id_threshold)
+                .is_some_and(|(stats, threshold)| stats.is_id_like(threshold));
+
+        if dominated {
+            Some((DropStage::Constant, self.constant_reason(feature_name)))
+        } else if id_like {
+            Some((DropStage::Identifier, self.identifier_reason(feature_name)))
+        } else if ratio.is_some_and(|r| r > self.missing_threshold) {
+            Some((DropStage::Missing, self.missing_reason(feature_name)))
+        } else if self.below_univariate_threshold(feature_name)
+            || self.unstable_iv(feature_name).is_some()
+            || self.low_cv_iv(feature_name).is_some()
+        {
+            Some((DropStage::Gini, self.gini_reason(feature_name)))
+        } else {
+            self.protected_correlation_reasons
+                .get(feature_name)
+                .map(|reason| (DropStage::Correlation, reason.clone()))
+        }
+    }
+
+    fn constant_reason(&self, feature_name: &str) -> String {
+        let Some(stats) = self.dominance.get(feature_name) else {
+            return "Constant or near-constant".to_string();
+        };
+        let value = stats.value.as_deref().unwrap_or("null");
+        if stats.is_constant() {
+            format!("Constant value '{}'", value)
+        } else {
+            format!(
+                "Value '{}' covers {:.2}% of rows, above threshold {:.2}%",
+                value,
+                stats.share * 100.0,
+                self.dominance_threshold.unwrap_or(1.0) * 100.0
+            )
+        }
+    }
+
+    fn identifier_reason(&self, feature_name: &str) -> String {
+        let Some(stats) = self.cardinality.get(feature_name) else {
+            return "Likely an ID column".to_string();
+        };
+        format!(
+            "Likely an ID column: {} distinct values in {} rows ({:.2}%), above threshold {:.2}%",
+            stats.unique,
+            stats.non_null,
+            stats.ratio * 100.0,
+            self.id_threshold.unwrap_or(1.0) * 100.0
+        )
+    }
+
+    fn missing_reason(&self, feature_name: &str) -> String {
+        let ratio = self
+            .missing_ratios
+            .get(feature_name)
+            .copied()
+            .unwrap_or(0.0);
+        format!(
+            "Missing ratio {:.2} exceeded threshold {:.2}",
+            ratio, self.missing_threshold
+        )
+    }
+
+    /// Whether the univariate filter (R², mutual information or Gini) rejects the feature
+    fn below_univariate_threshold(&self, feature_name: &str) -> bool {
+        let univariate = if let Some((r2, _, _)) = self.r2_results.get(feature_name) {
+            Some(*r2)
+        } else if let Some(mi) = self.mutual_info_results.get(feature_name) {
+            Some(*mi)
+        } else {
+            self.gini_results.get(feature_name).map(|(g, _, _)| g.abs())
+        };
+        univariate.is_some_and(|v| v < self.gini_threshold)
+    }
+
+    /// Stability entry of a feature the `--iv-stability-threshold` drops
+    fn unstable_iv(&self, feature_name: &str) -> Option<(&IvStability, f64)> {
+        let section = self.iv_stability.as_ref().filter(|s| s.drop_unstable)?;
+        section
+            .features
+            .iter()
+            .find(|f| f.feature == feature_name && f.unstable)
+            .map(|f| (f, section.threshold))
+    }
+
+    /// Mean holdout IV and threshold of a feature `--cv-min-iv` drops
+    fn low_cv_iv(&self, feature_name: &str) -> Option<(f64, f64)> {
+        let min_iv = self.cv_min_iv?;
+        let iv_mean = *self.cv_iv_means.get(feature_name)?;
+        (iv_mean < min_iv).then_some((iv_mean, min_iv))
+    }
+
+    fn gini_reason(&self, feature_name: &str) -> String {
+        let stability = self
+            .unstable_iv(feature_name)
+            .filter(|_| !self.below_univariate_threshold(feature_name));
+        if let Some((entry, threshold)) = stability {
+            return format!(
+                "IV unstable between train and validation rows: {:.4} vs {:.4} \
+                 (relative change {:.2}, rank {} -> {}, threshold {:.2})",
+                entry.train_iv,
+                entry.validation_iv,
+                entry.relative_change,
+                entry.train_rank,
+                entry.validation_rank,
+                threshold
+            );
+        }
+        let cross_validated = self
+            .low_cv_iv(feature_name)
+            .filter(|_| !self.below_univariate_threshold(feature_name));
+        if let Some((iv_mean, min_iv)) = cross_validated {
+            return format!(
+                "Cross-validated IV {:.4} below threshold {:.4}",
+                iv_mean, min_iv
+            );
+        }
+        if let Some((r2, _, _)) = self.r2_results.get(feature_name) {
+            format!("R² {:.4} below threshold {:.4}", r2, self.gini_threshold)
+        } else if let Some(mi) = self.mutual_info_results.get(feature_name) {
+            format!(
+                "Mutual information {:.4} below threshold {:.4}",
+                mi, self.gini_threshold
             )
         } else {
-            ("kept".to_string(), None, None)
+            let gini = self
+                .gini_results
+                .get(feature_name)
+                .map(|(g, _, _)| *g)
+                .unwrap_or(0.0);
+            let mut reason = format!(
+                "Gini coefficient {:.4} below threshold {:.4}",
+                gini, self.gini_threshold
+            );
+            // The raw ordering still separates the classes: flag the binning
+            if let Some((somers_d, _)) = self.concordance_results.get(feature_name) {
+                if somers_d.abs() >= self.gini_threshold {
+                    reason.push_str(&format!(
+                        " (raw Somers' D {:.4}: binning may be losing signal)",
+                        somers_d
+                    ));
+                }
+            }
+            reason
+        }
+    }
+
+    /// Statistic, value and threshold behind the drop of `feature_name`
+    fn drop_trigger(&self, feature_name: &str, stage: &DropStage) -> (String, f64, f64) {
+        match stage {
+            DropStage::Constant => (
+                "dominant_share".to_string(),
+                self.dominance
+                    .get(feature_name)
+                    .map(|s| s.share)
+                    .unwrap_or(1.0),
+                self.dominance_threshold.unwrap_or(1.0),
+            ),
+            DropStage::Identifier => (
+                "distinct_ratio".to_string(),
+                self.cardinality
+                    .get(feature_name)
+                    .map(|s| s.ratio)
+                    .unwrap_or(1.0),
+                self.id_threshold.unwrap_or(1.0),
+            ),
+            DropStage::Missing => (
+                "missing_ratio".to_string(),
+                self.missing_ratios
+                    .get(feature_name)
+                    .copied()
+                    .unwrap_or(0.0),
+                self.missing_threshold,
+            ),
+            DropStage::Gini => {
+                if !self.below_univariate_threshold(feature_name) {
+                    if let Some((entry, threshold)) = self.unstable_iv(feature_name) {
+                        let value = entry.relative_change.max(entry.rank_shift);
+                        return ("iv_stability".to_string(), value, threshold);
+                    }
+                    if let Some((iv_mean, min_iv)) = self.low_cv_iv(feature_name) {
+                        return ("cv_iv_mean".to_string(), iv_mean, min_iv);
+                    }
+                }
+                let (metric, value) = if let Some((r2, _, _)) = self.r2_results.get(feature_name) {
+                    ("r_squared", *r2)
+                } else if let Some(mi) = self.mutual_info_results.get(feature_name) {
+                    ("mutual_info", *mi)
+                } else {
+                    let gini = self.gini_results.get(feature_name).map(|(g, _, _)| *g);
+                    ("gini", gini.unwrap_or(0.0))
+                };
+                (metric.to_string(), value, self.gini_threshold)
+            }
+            DropStage::Correlation => self
+                .correlation_pairs
+                .iter()
+                .filter(|p| p.feature1 == feature_name || p.feature2 == feature_name)
+                .max_by(|a, b| a.correlation.abs().total_cmp(&b.correlation.abs()))
+                .map(|pair| {
+                    let threshold = match (pair.measure, self.cramers_v_threshold) {
+                        (AssociationMeasure::CramersV, Some(t)) => t,
+                        _ => self.correlation_threshold,
+                    };
+                    (pair.measure.to_string(), pair.correlation.abs(), threshold)
+                })
+                .unwrap_or_else(|| ("correlation".to_string(), 0.0, self.correlation_threshold)),
+        }
+    }
+
+    /// Build a single feature entry
+    fn build_feature_entry(&self, feature_name: &str) -> FeatureReportEntry {
+        // Determine status and drop stage
+        let (status, dropped_at_stage, reason) = match self.drop_status(feature_name) {
+            Some((_, reason)) if self.user_retained.contains(feature_name) => (
+                USER_RETAINED.to_string(),
+                None,
+                Some(format!("Kept in drop review ({})", reason)),
+            ),
+            Some((stage, reason)) => ("dropped".to_string(), Some(stage), Some(reason)),
+            None => (
+                "kept".to_string(),
+                None,
+                self.protected_drop(feature_name)
+                    .map(|(_, reason)| format!("Protected by --keep-columns ({})", reason)),
+            ),
         };
 
         // Build analysis section
+        let non_finite_analysis =
+            self.non_finite_counts
+                .get(feature_name)
+                .map(|counts| NonFiniteEntry {
+                    nan_count: counts.nan_count,
+                    pos_inf_count: counts.pos_inf_count,
+                    neg_inf_count: counts.neg_inf_count,
+                    policy: self.non_finite_policy.clone().unwrap_or_default(),
+                });
+
+        let dominance_analysis =
+            self.dominance
+                .get(feature_name)
+                .map(|stats| DominanceAnalysisEntry {
+                    value: stats.value.clone(),
+                    share: stats.share,
+                    threshold: self.dominance_threshold.unwrap_or(1.0),
+                    passed: !self.dropped_constant.contains(feature_name),
+                });
+
+        let cardinality_analysis =
+            self.cardinality
+                .get(feature_name)
+                .map(|stats| CardinalityAnalysisEntry {
+                    unique: stats.unique,
+                    ratio: stats.ratio,
+                    threshold: self.id_threshold.unwrap_or(1.0),
+                    passed: !self.id_like.contains(feature_name),
+                });
+
         let missing_analysis = self.missing_ratios.get(feature_name).map(|ratio| {
             let passed = !self.dropped_missing.contains(feature_name);
             MissingAnalysisEntry {
@@ -431,18 +1496,37 @@ impl ReductionReportBuilder {
                 .get(feature_name)
                 .map(|(gini, iv, feature_type)| {
                     let passed = !self.dropped_gini.contains(feature_name);
+                    let rank = self.concordance_results.get(feature_name);
                     GiniAnalysisEntry {
                         gini: *gini,
                         iv: *iv,
                         threshold: self.gini_threshold,
                         passed,
                         feature_type: format!("{:?}", feature_type),
+                        ks: self.ks_results.get(feature_name).copied(),
+                        somers_d: rank.map(|(d, _)| *d),
+                        concordance: rank.map(|(_, c)| *c),
+                        mutual_info: self.mutual_info_results.get(feature_name).copied(),
                     }
                 })
         } else {
             None
         };
 
+        let r_squared_analysis = if !self.dropped_missing.contains(feature_name) {
+            self.r2_results
+                .get(feature_name)
+                .map(|(r2, correlation, feature_type)| RSquaredAnalysisEntry {
+                    r_squared: *r2,
+                    correlation: *correlation,
+                    threshold: self.gini_threshold,
+                    passed: !self.dropped_gini.contains(feature_name),
+                    feature_type: format!("{:?}", feature_type),
+                })
+        } else {
+            None
+        };
+
         // Correlation analysis is only available if feature wasn't dropped at missing or gini stage
         let correlation_analysis = if !self.dropped_missing.contains(feature_name)
             && !self.dropped_gini.contains(feature_name)
@@ -457,12 +1541,16 @@ impl ReductionReportBuilder {
                             feature: pair.feature2.clone(),
                             correlation: pair.correlation,
                             measure: pair.measure.to_string(),
+                            theils_u: pair.theils_u.map(|(u1, _)| u1),
+                            rationale: pair.rationale.clone(),
                         })
                     } else if pair.feature2 == feature_name {
                         Some(CorrelationEntry {
                             feature: pair.feature1.clone(),
                             correlation: pair.correlation,
                             measure: pair.measure.to_string(),
+                            theils_u: pair.theils_u.map(|(_, u2)| u2),
+                            rationale: pair.rationale.clone(),
                         })
                     } else {
                         None
@@ -497,12 +1585,22 @@ impl ReductionReportBuilder {
 
         FeatureReportEntry {
             name: feature_name.to_string(),
+            dictionary: self
+                .dictionary
+                .as_ref()
+                .and_then(|d| d.get(feature_name))
+                .cloned()
+                .unwrap_or_default(),
             status,
             dropped_at_stage,
             reason,
             analysis: FeatureAnalysis {
+                non_finite: non_finite_analysis,
+                dominance: dominance_analysis,
+                cardinality: cardinality_analysis,
                 missing: missing_analysis,
                 gini: gini_analysis,
+                r_squared: r_squared_analysis,
                 correlation: correlation_analysis,
             },
         }
@@ -530,6 +1628,7 @@ pub fn export_reduction_report(report: &ReductionReport, output_path: &Path) ->
 /// - Feature name, status, drop stage, reason
 /// - Key metrics: missing ratio, Gini, IV, max correlation
 /// - All correlated features (semicolon-separated)
+/// - Description and owner from `--dictionary`, label and format from SAS7BDAT input
 pub fn export_reduction_report_csv(report: &ReductionReport, output_path: &Path) -> Result<()> {
     use std::io::Write;
 
@@ -539,7 +1638,7 @@ pub fn export_reduction_report_csv(report: &ReductionReport, output_path: &Path)
     // Write header
     writeln!(
         file,
-        "feature,status,dropped_at_stage,reason,missing_ratio,gini,iv,feature_type,max_correlation,measure,drop_reason,correlated_with"
+        "feature,status,dropped_at_stage,reason,missing_ratio,gini,iv,feature_type,max_correlation,measure,drop_reason,correlated_with,non_finite_count,r_squared,somers_d,description,owner,label,format"
     )?;
 
     // Write each feature
@@ -582,6 +1681,28 @@ pub fn export_reduction_report_csv(report: &ReductionReport, output_path: &Path)
             .gini
             .as_ref()
             .map(|g| g.feature_type.clone())
+            .or_else(|| {
+                feature
+                    .analysis
+                    .r_squared
+                    .as_ref()
+                    .map(|r| r.feature_type.clone())
+            })
+            .unwrap_or_default();
+
+        let r_squared = feature
+            .analysis
+            .r_squared
+            .as_ref()
+            .map(|r| format!("{:.4}", r.r_squared))
+            .unwrap_or_default();
+
+        let somers_d = feature
+            .analysis
+            .gini
+            .as_ref()
+            .and_then(|g| g.somers_d)
+            .map(|d| format!("{:.4}", d))
             .unwrap_or_default();
 
         let max_corr = feature
@@ -641,9 +1762,41 @@ pub fn export_reduction_report_csv(report: &ReductionReport, output_path: &Path)
             })
             .unwrap_or_default();
 
+        let non_finite_count = feature
+            .analysis
+            .non_finite
+            .as_ref()
+            .map(|nf| (nf.nan_count + nf.pos_inf_count + nf.neg_inf_count).to_string())
+            .unwrap_or_default();
+
+        let description = feature
+            .dictionary
+            .description
+            .as_deref()
+            .map(escape_csv_field)
+            .unwrap_or_default();
+        let owner = feature
+            .dictionary
+            .owner
+            .as_deref()
+            .map(escape_csv_field)
+            .unwrap_or_default();
+        let label = feature
+            .dictionary
+            .label
+            .as_deref()
+            .map(escape_csv_field)
+            .unwrap_or_default();
+        let format = feature
+            .dictionary
+            .format
+            .as_deref()
+            .map(escape_csv_field)
+            .unwrap_or_default();
+
         writeln!(
             file,
-            "{},{},{},{},{},{},{},{},{},{},{},{}",
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
             escape_csv_field(&feature.name),
             feature.status,
             stage,
@@ -655,10 +1808,149 @@ pub fn export_reduction_report_csv(report: &ReductionReport, output_path: &Path)
             max_corr,
             measure,
             drop_reason,
-            correlated_with
+            correlated_with,
+            non_finite_count,
+            r_squared,
+            somers_d,
+            description,
+            owner,
+            label,
+            format
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Export the missing ratios per segment (`--segment-column`) to CSV.
+///
+/// Writes one row per feature and segment: `feature,segment,rows,missing_ratio,fully_missing`.
+pub fn export_segment_missing_csv(
+    analysis: &SegmentMissingAnalysis,
+    output_path: &Path,
+) -> Result<()> {
+    use std::io::Write;
+
+    let file = std::fs::File::create(output_path)
+        .with_context(|| format!("Failed to create CSV file: {}", output_path.display()))?;
+    let mut file = std::io::BufWriter::new(file);
+
+    writeln!(file, "feature,segment,rows,missing_ratio,fully_missing")?;
+    for feature in &analysis.features {
+        for segment in &analysis.segments {
+            let ratio = feature
+                .missing_ratios
+                .get(&segment.segment)
+                .copied()
+                .unwrap_or(0.0);
+            writeln!(
+                file,
+                "{},{},{},{},{}",
+                escape_csv_field(&feature.feature),
+                escape_csv_field(&segment.segment),
+                segment.rows,
+                ratio,
+                feature.fully_missing_in.contains(&segment.segment)
+            )?;
+        }
+    }
+    file.flush()?;
+    Ok(())
+}
+
+/// Export the recommended imputation of the retained features to JSON
+pub fn export_imputation_plan(plan: &ImputationPlan, output_path: &Path) -> Result<()> {
+    let json = serde_json::to_string_pretty(plan).context("Failed to serialize imputation plan")?;
+    std::fs::write(output_path, json).with_context(|| {
+        format!(
+            "Failed to write imputation plan to {}",
+            output_path.display()
+        )
+    })
+}
+
+/// Export the report in long (tidy) form for BI dashboards
+///
+/// Writes one row per feature and metric, with the run timestamp, input file
+/// and target repeated on every row so exports from many runs can be appended
+/// into one table. Numeric metrics (`missing_ratio`, `iv`, `gini`, `ks`,
+/// `max_corr`, `r_squared`) fill `value` at full precision; `status` and
+/// `reason` fill `text`. Metrics a feature never reached are left out.
+pub fn export_reduction_report_long_csv(
+    report: &ReductionReport,
+    output_path: &Path,
+) -> Result<()> {
+    use std::io::Write;
+
+    let file = std::fs::File::create(output_path)
+        .with_context(|| format!("Failed to create CSV file: {}", output_path.display()))?;
+    let mut file = std::io::BufWriter::new(file);
+
+    writeln!(
+        file,
+        "timestamp,input_file,target,feature,metric,value,text"
+    )?;
+
+    let run = format!(
+        "{},{},{}",
+        escape_csv_field(&report.metadata.timestamp),
+        escape_csv_field(&report.metadata.input_file),
+        escape_csv_field(&report.metadata.settings.target_column)
+    );
+    for feature in &report.features {
+        let analysis = &feature.analysis;
+        let values = [
+            (
+                "dominant_share",
+                analysis.dominance.as_ref().map(|d| d.share),
+            ),
+            (
+                "distinct_ratio",
+                analysis.cardinality.as_ref().map(|c| c.ratio),
+            ),
+            ("missing_ratio", analysis.missing.as_ref().map(|m| m.ratio)),
+            ("iv", analysis.gini.as_ref().map(|g| g.iv)),
+            ("gini", analysis.gini.as_ref().map(|g| g.gini)),
+            ("ks", analysis.gini.as_ref().and_then(|g| g.ks)),
+            (
+                "max_corr",
+                analysis
+                    .correlation
+                    .as_ref()
+                    .and_then(|c| c.max_correlation),
+            ),
+            (
+                "r_squared",
+                analysis.r_squared.as_ref().map(|r| r.r_squared),
+            ),
+        ];
+        let name = escape_csv_field(&feature.name);
+
+        for (metric, value) in values {
+            if let Some(value) = value {
+                writeln!(file, "{},{},{},{},", run, name, metric, value)?;
+            }
+        }
+        writeln!(
+            file,
+            "{},{},status,,{}",
+            run,
+            name,
+            escape_csv_field(&feature.status)
         )?;
+        if let Some(reason) = &feature.reason {
+            writeln!(
+                file,
+                "{},{},reason,,{}",
+                run,
+                name,
+                escape_csv_field(reason)
+            )?;
+        }
     }
 
+    file.flush()
+        .with_context(|| format!("Failed to write CSV file: {}", output_path.display()))?;
     Ok(())
 }
 
@@ -683,63 +1975,208 @@ fn escape_csv_field(field: &str) -> String {
     }
 }
 
+/// Entries of at least this many bytes are deflated at the fastest level
+const FAST_DEFLATE_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Extensions of already-compressed files, which are stored without deflate
+const STORED_EXTENSIONS: &[&str] = &["parquet", "zip", "gz", "zst", "xlsx"];
+
 /// Package reduction report files into a zip archive
 ///
 /// Creates a zip file containing:
 /// - gini_analysis.json - Detailed WoE binning analysis
 /// - reduction_report.json - Full detailed reduction report
 /// - reduction_report.csv - Human-readable summary
+///
+/// `gini_analysis_path` may also be the directory written by a split export
+/// (`--split-gini-export`); its `index.json` and per-feature files are stored
+/// under the directory's name inside the archive.
+#[allow(dead_code)] // The binary packages through `package_reduction_reports_with`
 pub fn package_reduction_reports(
     gini_analysis_path: &Path,
     reduction_report_path: &Path,
     csv_path: &Path,
     zip_path: &Path,
 ) -> Result<()> {
-    use std::io::{Read, Write};
-    use zip::write::SimpleFileOptions;
+    package_reduction_reports_with(
+        Some(gini_analysis_path),
+        reduction_report_path,
+        csv_path,
+        &[],
+        zip_path,
+    )
+}
+
+/// [`package_reduction_reports`] with optional extra report files (such as the
+/// `--export-cart-trees` output), added under their file names and removed
+/// afterwards like the others. `gini_analysis_path` is `None` when the Gini
+/// step was skipped and wrote no export.
+///
+/// Files are streamed into the archive, so their size is not limited by
+/// memory, and entries of 4 GiB or more are written as zip64. After writing,
+/// every entry is read back and checked against its CRC and source size; the
+/// source files are only removed when the archive verifies.
+pub fn package_reduction_reports_with(
+    gini_analysis_path: Option<&Path>,
+    reduction_report_path: &Path,
+    csv_path: &Path,
+    extra_files: &[PathBuf],
+    zip_path: &Path,
+) -> Result<()> {
     use zip::ZipWriter;
 
     let zip_file = std::fs::File::create(zip_path)
         .with_context(|| format!("Failed to create zip file: {}", zip_path.display()))?;
 
-    let mut zip = ZipWriter::new(zip_file);
-    let options = SimpleFileOptions::default()
-        .compression_method(zip::CompressionMethod::Deflated)
-        .unix_permissions(0o644);
-
-    // Helper closure to add a file to the zip
-    let mut add_file_to_zip = |path: &Path, default_name: &str| -> Result<()> {
-        let filename = path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or(default_name);
-        zip.start_file(filename, options)
+    let mut zip = ZipWriter::new(std::io::BufWriter::new(zip_file));
+    let mut written: Vec<(String, u64)> = Vec::new();
+
+    // Helper closure to stream a file into the zip under `filename`
+    let mut add_file_to_zip = |path: &Path, filename: &str| -> Result<()> {
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("Failed to open file: {}", path.display()))?;
+        let size = file.metadata()?.len();
+        zip.start_file(filename, zip_entry_options(path, size))
             .with_context(|| format!("Failed to add {} to zip", filename))?;
-        let mut content = Vec::new();
-        std::fs::File::open(path)
-            .with_context(|| format!("Failed to open file: {}", path.display()))?
-            .read_to_end(&mut content)?;
-        zip.write_all(&content)?;
+        let copied = std::io::copy(&mut std::io::BufReader::new(file), &mut zip)
+            .with_context(|| format!("Failed to write {} to zip", filename))?;
+        written.push((filename.to_string(), copied));
         Ok(())
     };
 
-    // Add all three files
-    add_file_to_zip(gini_analysis_path, "gini_analysis.json")?;
-    add_file_to_zip(reduction_report_path, "reduction_report.json")?;
-    add_file_to_zip(csv_path, "reduction_report.csv")?;
+    // Add all three reports; a split Gini export is added file by file
+    match gini_analysis_path {
+        Some(dir) if dir.is_dir() => {
+            let dir_name = dir
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("gini_analysis");
+            for (path, relative) in collect_files(dir)? {
+                add_file_to_zip(&path, &format!("{}/{}", dir_name, relative))?;
+            }
+        }
+        Some(path) => add_file_to_zip(path, file_name_or(path, "gini_analysis.json"))?,
+        None => {}
+    }
+    add_file_to_zip(
+        reduction_report_path,
+        file_name_or(reduction_report_path, "reduction_report.json"),
+    )?;
+    add_file_to_zip(csv_path, file_name_or(csv_path, "reduction_report.csv"))?;
+    for path in extra_files {
+        add_file_to_zip(path, file_name_or(path, "extra_report.json"))?;
+    }
 
-    zip.finish().context("Failed to finalize zip file")?;
+    zip.finish()
+        .context("Failed to finalize zip file")?
+        .into_inner()
+        .map_err(|e| e.into_error())
+        .context("Failed to finalize zip file")?;
+    verify_zip(zip_path, &written)?;
 
     // Remove the individual files after packaging
-    for path in [gini_analysis_path, reduction_report_path, csv_path] {
-        if let Err(e) = std::fs::remove_file(path) {
-            eprintln!("Warning: Failed to clean up {}: {}", path.display(), e);
+    let packaged = gini_analysis_path
+        .into_iter()
+        .chain([reduction_report_path, csv_path])
+        .chain(extra_files.iter().map(PathBuf::as_path));
+    for path in packaged {
+        let removed = if path.is_dir() {
+            std::fs::remove_dir_all(path)
+        } else {
+            std::fs::remove_file(path)
+        };
+        if let Err(e) = removed {
+            tracing::warn!("Failed to clean up {}: {}", path.display(), e);
         }
     }
 
     Ok(())
 }
 
+/// Zip options for one entry: already-compressed formats are stored as-is,
+/// very large files get the fastest deflate level, and entries of 4 GiB or
+/// more need zip64 headers
+fn zip_entry_options(path: &Path, size: u64) -> zip::write::SimpleFileOptions {
+    let stored = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| STORED_EXTENSIONS.contains(&e.to_ascii_lowercase().as_str()));
+    let options = zip::write::SimpleFileOptions::default()
+        .unix_permissions(0o644)
+        .large_file(size >= u64::from(u32::MAX));
+    if stored {
+        options.compression_method(zip::CompressionMethod::Stored)
+    } else if size >= FAST_DEFLATE_BYTES {
+        options
+            .compression_method(zip::CompressionMethod::Deflated)
+            .compression_level(Some(1))
+    } else {
+        options.compression_method(zip::CompressionMethod::Deflated)
+    }
+}
+
+/// Read every entry of a finished archive back, so a CRC error or a size that
+/// differs from the source file fails the packaging
+fn verify_zip(zip_path: &Path, expected: &[(String, u64)]) -> Result<()> {
+    let file = std::fs::File::open(zip_path)
+        .with_context(|| format!("Failed to reopen zip file: {}", zip_path.display()))?;
+    let mut archive = zip::ZipArchive::new(std::io::BufReader::new(file))
+        .with_context(|| format!("Zip file {} is not readable", zip_path.display()))?;
+    if archive.len() != expected.len() {
+        anyhow::bail!(
+            "Zip file {} has {} entries, expected {}",
+            zip_path.display(),
+            archive.len(),
+            expected.len()
+        );
+    }
+    for (name, size) in expected {
+        let mut entry = archive
+            .by_name(name)
+            .with_context(|| format!("Zip file {} is missing {}", zip_path.display(), name))?;
+        // Reading to the end checks the CRC
+        let read = std::io::copy(&mut entry, &mut std::io::sink())
+            .with_context(|| format!("Zip entry {} failed verification", name))?;
+        if read != *size {
+            anyhow::bail!("Zip entry {} holds {} bytes, expected {}", name, read, size);
+        }
+    }
+    Ok(())
+}
+
+fn file_name_or<'a>(path: &'a Path, default_name: &'a str) -> &'a str {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(default_name)
+}
+
+/// List regular files under `dir` recursively as `(path, relative path with '/')`, sorted
+fn collect_files(dir: &Path) -> Result<Vec<(std::path::PathBuf, String)>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(current) = pending.pop() {
+        let entries = std::fs::read_dir(&current)
+            .with_context(|| format!("Failed to read directory: {}", current.display()))?;
+        for entry in entries {
+            let path = entry?.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else {
+                let relative = path
+                    .strip_prefix(dir)
+                    .unwrap_or(&path)
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                files.push((path, relative));
+            }
+        }
+    }
+    files.sort_by(|a, b| a.1.cmp(&b.1));
+    Ok(files)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -759,6 +2196,25 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_zip64_entry_is_readable() {
+        use std::io::Write;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let zip_path = dir.path().join("large.zip");
+        // Options chosen for a 5 GB entry: zip64 headers and fast deflate
+        let options = zip_entry_options(Path::new("gini.json"), 5_000_000_000);
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(&zip_path).unwrap());
+        zip.start_file("gini.json", options).unwrap();
+        zip.write_all(b"{}").unwrap();
+        zip.finish().unwrap();
+
+        verify_zip(&zip_path, &[("gini.json".to_string(), 2)]).unwrap();
+        let err = verify_zip(&zip_path, &[("gini.json".to_string(), 3)]).unwrap_err();
+        assert!(err.to_string().contains("expected 3"), "{}", err);
+        assert!(verify_zip(&zip_path, &[("other.json".to_string(), 2)]).is_err());
+    }
+
     #[test]
     fn test_builder_creation() {
         let builder = create_test_builder();
@@ -797,6 +2253,12 @@ mod tests {
             missing_bin: None,
             iv: 0.5,
             gini: 0.3,
+            mutual_info: None,
+            monotonicity: None,
+            solver_status: None,
+            special_bins: Vec::new(),
+            ks: 0.0,
+            auc: 0.5,
         }];
         let dropped: Vec<String> = vec![];
 
@@ -817,6 +2279,8 @@ mod tests {
             feature2: "feature_2".to_string(),
             correlation: 0.92,
             measure: AssociationMeasure::Pearson,
+            theils_u: None,
+            rationale: None,
         }];
         let dropped = vec![FeatureToDrop {
             feature: "feature_1".to_string(),
@@ -854,6 +2318,12 @@ mod tests {
                 missing_bin: None,
                 iv: 0.5,
                 gini: 0.3,
+                mutual_info: None,
+                monotonicity: None,
+                solver_status: None,
+                special_bins: Vec::new(),
+                ks: 0.0,
+                auc: 0.5,
             },
             IvAnalysis {
                 feature_name: "feature_3".to_string(),
@@ -863,6 +2333,12 @@ mod tests {
                 missing_bin: None,
                 iv: 0.05,
                 gini: 0.05,
+                mutual_info: None,
+                monotonicity: None,
+                solver_status: None,
+                special_bins: Vec::new(),
+                ks: 0.0,
+                auc: 0.5,
             },
         ];
         let dropped_gini = vec!["feature_3".to_string()];
@@ -876,9 +2352,18 @@ mod tests {
         assert_eq!(report.summary.initial_features, 3);
         assert_eq!(report.summary.final_features, 1);
         assert_eq!(report.summary.dropped_count, 2);
-        assert_eq!(report.summary.by_stage.missing.dropped, 1);
-        assert_eq!(report.summary.by_stage.gini.dropped, 1);
-        assert_eq!(report.summary.by_stage.correlation.dropped, 0);
+        assert_eq!(report.summary.by_stage.missing.as_ref().unwrap().dropped, 1);
+        assert_eq!(report.summary.by_stage.gini.as_ref().unwrap().dropped, 1);
+        assert_eq!(
+            report
+                .summary
+                .by_stage
+                .correlation
+                .as_ref()
+                .unwrap()
+                .dropped,
+            0
+        );
         assert_eq!(report.features.len(), 3);
     }
 
@@ -897,6 +2382,12 @@ mod tests {
             missing_bin: None,
             iv: 0.5,
             gini: 0.3,
+            mutual_info: None,
+            monotonicity: None,
+            solver_status: None,
+            special_bins: Vec::new(),
+            ks: 0.0,
+            auc: 0.5,
         }];
         builder.set_gini_results(&analyses, &[]);
         builder.set_correlation_results(&[], &[]);
@@ -912,6 +2403,49 @@ mod tests {
         assert!(feature.analysis.correlation.is_some());
     }
 
+    #[test]
+    fn test_non_finite_results() {
+        let mut builder = create_test_builder();
+
+        let counts = vec![NonFiniteCounts {
+            feature: "feature_1".to_string(),
+            nan_count: 2,
+            pos_inf_count: 1,
+            neg_inf_count: 0,
+        }];
+        builder.set_non_finite_results(&counts, "clip");
+        builder.set_missing_results(
+            &[
+                ("feature_1".to_string(), 0.2),
+                ("feature_2".to_string(), 0.0),
+            ],
+            &[],
+        );
+
+        let report = builder.build();
+        assert_eq!(
+            report.metadata.settings.non_finite_policy.as_deref(),
+            Some("clip")
+        );
+
+        let f1 = report
+            .features
+            .iter()
+            .find(|f| f.name == "feature_1")
+            .unwrap();
+        let nf = f1.analysis.non_finite.as_ref().unwrap();
+        assert_eq!(nf.nan_count, 2);
+        assert_eq!(nf.pos_inf_count, 1);
+        assert_eq!(nf.policy, "clip");
+
+        let f2 = report
+            .features
+            .iter()
+            .find(|f| f.name == "feature_2")
+            .unwrap();
+        assert!(f2.analysis.non_finite.is_none());
+    }
+
     #[test]
     fn test_feature_entry_dropped_missing() {
         let mut builder = create_test_builder();
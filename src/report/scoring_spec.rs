@@ -0,0 +1,251 @@
+//! Scoring specification of the final binning
+//!
+//! A versioned JSON document (`{stem}_scoring_spec.json`) holding, for every
+//! retained feature, just what a deployment service needs to WoE-encode a row:
+//! the bin bounds, special values, category groups and their WoE values. The
+//! encoding rules are those of
+//! [`apply_woe_transform`](crate::pipeline::apply_woe_transform); the WoE SQL
+//! in [`super::woe_sql`] is rendered from the same entries.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::pipeline::{EventLabels, FeatureType, IvAnalysis};
+
+/// Version of the scoring spec format, bumped only for breaking changes
+pub const SCORING_SPEC_VERSION: u32 = 1;
+
+/// Category bin that absorbs rare categories during categorical binning
+const OTHER_CATEGORY: &str = "OTHER";
+
+/// WoE of one `--special-values` code
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpecialValueWoe {
+    pub value: f64,
+    pub woe: f64,
+}
+
+/// WoE of a numeric bin `[lower_bound, upper_bound)`; `None` bounds are open
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NumericBinWoe {
+    pub lower_bound: Option<f64>,
+    pub upper_bound: Option<f64>,
+    pub woe: f64,
+}
+
+/// WoE shared by a group of categories
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CategoryGroupWoe {
+    pub values: Vec<String>,
+    pub woe: f64,
+}
+
+/// How to WoE-encode one feature
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureScoring {
+    pub feature: String,
+    pub feature_type: FeatureType,
+    /// WoE of a null value
+    pub missing_woe: f64,
+    /// Checked before the bins (numeric features)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub special_values: Vec<SpecialValueWoe>,
+    /// Sorted by bound; a value takes the first bin whose upper bound is
+    /// open or above it, and the last bin otherwise (numeric features)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bins: Vec<NumericBinWoe>,
+    /// Category groups (categorical features)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<CategoryGroupWoe>,
+    /// WoE of a non-null value no rule matches: the `OTHER` group's for an
+    /// unseen category, otherwise 0.0
+    pub default_woe: f64,
+}
+
+impl FeatureScoring {
+    pub fn from_analysis(analysis: &IvAnalysis) -> Self {
+        let finite = |bound: f64| bound.is_finite().then_some(bound);
+        let bins: Vec<NumericBinWoe> = analysis
+            .bins
+            .iter()
+            .map(|bin| NumericBinWoe {
+                lower_bound: finite(bin.lower_bound),
+                upper_bound: finite(bin.upper_bound),
+                woe: bin.woe,
+            })
+            .collect();
+        let categories: Vec<CategoryGroupWoe> = analysis
+            .categories
+            .iter()
+            .map(|bin| {
+                let mut values = bin.categories.clone();
+                if !bin.category.is_empty() {
+                    values.insert(0, bin.category.clone());
+                }
+                CategoryGroupWoe {
+                    values,
+                    woe: bin.woe,
+                }
+            })
+            .filter(|group| !group.values.is_empty())
+            .collect();
+        let default_woe = categories
+            .iter()
+            .find(|group| group.values.iter().any(|v| v == OTHER_CATEGORY))
+            .map_or(0.0, |group| group.woe);
+
+        Self {
+            feature: analysis.feature_name.clone(),
+            feature_type: analysis.feature_type,
+            missing_woe: analysis.missing_bin.as_ref().map_or(0.0, |mb| mb.woe),
+            special_values: analysis
+                .special_bins
+                .iter()
+                .map(|b| SpecialValueWoe {
+                    value: b.value,
+                    woe: b.woe,
+                })
+                .collect(),
+            bins,
+            categories,
+            default_woe,
+        }
+    }
+}
+
+/// Scoring spec of the retained features (`{stem}_scoring_spec.json`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoringSpec {
+    pub spec_version: u32,
+    pub lophi_version: String,
+    pub target: String,
+    /// Names of the classes the WoE compares (if set)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_labels: Option<EventLabels>,
+    pub features: Vec<FeatureScoring>,
+}
+
+impl ScoringSpec {
+    /// Entries for the features of `features` with an analysis, in
+    /// `features` order
+    pub fn new(
+        target: &str,
+        event_labels: Option<&EventLabels>,
+        analyses: &[IvAnalysis],
+        features: &[String],
+    ) -> Self {
+        Self {
+            spec_version: SCORING_SPEC_VERSION,
+            lophi_version: env!("CARGO_PKG_VERSION").to_string(),
+            target: target.to_string(),
+            event_labels: event_labels.cloned(),
+            features: scored_features(analyses, features),
+        }
+    }
+}
+
+/// [`FeatureScoring`] of each feature of `features` with an analysis
+pub fn scored_features(analyses: &[IvAnalysis], features: &[String]) -> Vec<FeatureScoring> {
+    features
+        .iter()
+        .filter_map(|name| analyses.iter().find(|a| &a.feature_name == name))
+        .map(FeatureScoring::from_analysis)
+        .collect()
+}
+
+/// Export the scoring spec to JSON
+pub fn export_scoring_spec(spec: &ScoringSpec, output_path: &Path) -> Result<()> {
+    let json = serde_json::to_string_pretty(spec).context("Failed to serialize scoring spec")?;
+    std::fs::write(output_path, json)
+        .with_context(|| format!("Failed to write scoring spec to {}", output_path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::{CategoricalWoeBin, WoeBin};
+
+    fn analysis(name: &str, feature_type: FeatureType) -> IvAnalysis {
+        IvAnalysis {
+            feature_name: name.to_string(),
+            feature_type,
+            bins: Vec::new(),
+            categories: Vec::new(),
+            missing_bin: None,
+            special_bins: Vec::new(),
+            iv: 0.0,
+            gini: 0.0,
+            ks: 0.0,
+            auc: 0.5,
+            mutual_info: None,
+            monotonicity: None,
+            solver_status: None,
+        }
+    }
+
+    #[test]
+    fn test_open_bounds_and_other_group() {
+        let bin = |lower_bound: f64, upper_bound: f64, woe: f64| WoeBin {
+            lower_bound,
+            upper_bound,
+            events: 0.0,
+            non_events: 0.0,
+            woe,
+            iv_contribution: 0.0,
+            count: 0.0,
+            population_pct: 0.0,
+            event_rate: 0.0,
+            chi_square: None,
+            chi_square_p_value: None,
+        };
+        let mut income = analysis("income", FeatureType::Numeric);
+        income.bins = vec![
+            bin(f64::NEG_INFINITY, 10.0, -0.5),
+            bin(10.0, f64::INFINITY, 0.5),
+        ];
+        let scoring = FeatureScoring::from_analysis(&income);
+        assert_eq!(scoring.bins[0].lower_bound, None);
+        assert_eq!(scoring.bins[0].upper_bound, Some(10.0));
+        assert_eq!(scoring.bins[1].upper_bound, None);
+        assert_eq!(scoring.default_woe, 0.0);
+
+        let group = |category: &str, categories: &[&str], woe: f64| CategoricalWoeBin {
+            category: category.to_string(),
+            categories: categories.iter().map(|c| c.to_string()).collect(),
+            events: 0.0,
+            non_events: 0.0,
+            woe,
+            iv_contribution: 0.0,
+            count: 0.0,
+            population_pct: 0.0,
+            event_rate: 0.0,
+        };
+        let mut region = analysis("region", FeatureType::Categorical);
+        region.categories = vec![
+            group("north", &[], -0.2),
+            group("", &["OTHER", "west"], 0.4),
+        ];
+        let scoring = FeatureScoring::from_analysis(&region);
+        assert_eq!(scoring.categories[0].values, ["north"]);
+        assert_eq!(scoring.categories[1].values, ["OTHER", "west"]);
+        assert_eq!(scoring.default_woe, 0.4);
+    }
+
+    #[test]
+    fn test_spec_lists_retained_features_in_order() {
+        let analyses = vec![
+            analysis("a", FeatureType::Numeric),
+            analysis("b", FeatureType::Numeric),
+            analysis("dropped", FeatureType::Numeric),
+        ];
+        let spec = ScoringSpec::new("target", None, &analyses, &["b".into(), "a".into()]);
+        assert_eq!(spec.spec_version, SCORING_SPEC_VERSION);
+        let names: Vec<&str> = spec.features.iter().map(|f| f.feature.as_str()).collect();
+        assert_eq!(names, ["b", "a"]);
+
+        let json = serde_json::to_string(&spec).unwrap();
+        assert_eq!(serde_json::from_str::<ScoringSpec>(&json).unwrap(), spec);
+    }
+}
@@ -0,0 +1,108 @@
+//! Lists of the retained features for downstream code
+//!
+//! Besides the reduced dataset, every run writes the features it kept in
+//! three forms that paste straight into other code: one name per line, a
+//! Python list and a SQL `SELECT` clause. The target, weight and grouping
+//! columns are not listed.
+
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+use super::ReductionReport;
+
+/// Features of `report` that are in the reduced dataset, in report order
+pub fn retained_features(report: &ReductionReport) -> Vec<String> {
+    report
+        .features
+        .iter()
+        .filter(|f| f.dropped_at_stage.is_none())
+        .map(|f| f.name.clone())
+        .collect()
+}
+
+/// One feature name per line
+pub fn selected_features_text(features: &[String]) -> String {
+    features.iter().map(|f| format!("{}\n", f)).collect()
+}
+
+/// A `selected_features` Python list, one string literal per line
+pub fn selected_features_python(features: &[String]) -> String {
+    let mut out = String::from("selected_features = [\n");
+    for feature in features {
+        // A JSON string literal is also a valid Python one
+        let literal = serde_json::to_string(feature).expect("strings always serialize");
+        out.push_str(&format!("    {},\n", literal));
+    }
+    out.push_str("]\n");
+    out
+}
+
+/// A SQL `SELECT` clause without `FROM`. Names are double-quoted so they
+/// keep their case and may hold spaces or reserved words.
+pub fn selected_features_sql(features: &[String]) -> String {
+    if features.is_empty() {
+        return "-- No features were retained\n".to_string();
+    }
+    let columns: Vec<String> = features
+        .iter()
+        .map(|f| format!("    {}", sql_identifier(f)))
+        .collect();
+    format!("SELECT\n{}\n", columns.join(",\n"))
+}
+
+/// Double-quoted SQL identifier, inner quotes doubled
+pub(crate) fn sql_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// Write the three lists to the path `path_for` gives each extension
+/// (`txt`, `py`, `sql`). Returns the paths written.
+pub fn export_selected_features(
+    features: &[String],
+    path_for: impl Fn(&str) -> PathBuf,
+) -> Result<Vec<PathBuf>> {
+    let mut written = Vec::new();
+    for (ext, content) in [
+        ("txt", selected_features_text(features)),
+        ("py", selected_features_python(features)),
+        ("sql", selected_features_sql(features)),
+    ] {
+        let path = path_for(ext);
+        std::fs::write(&path, content)
+            .with_context(|| format!("Failed to write selected features to {}", path.display()))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn features() -> Vec<String> {
+        vec!["income".into(), "days \"late\"".into()]
+    }
+
+    #[test]
+    fn test_python_and_sql_quote_names() {
+        assert_eq!(
+            selected_features_text(&features()),
+            "income\ndays \"late\"\n"
+        );
+        assert_eq!(
+            selected_features_python(&features()),
+            "selected_features = [\n    \"income\",\n    \"days \\\"late\\\"\",\n]\n"
+        );
+        assert_eq!(
+            selected_features_sql(&features()),
+            "SELECT\n    \"income\",\n    \"days \"\"late\"\"\"\n"
+        );
+    }
+
+    #[test]
+    fn test_no_retained_features() {
+        assert_eq!(selected_features_text(&[]), "");
+        assert_eq!(selected_features_python(&[]), "selected_features = [\n]\n");
+        assert!(selected_features_sql(&[]).starts_with("--"));
+    }
+}
@@ -5,19 +5,33 @@ use std::time::Duration;
 use comfy_table::{presets::UTF8_FULL_CONDENSED, Attribute, Cell, Color, Table};
 use console::style;
 
+use super::plots::interval_label;
+use crate::pipeline::{
+    CorrelatedPair, IvAnalysis, ResultsBin, ResultsFeature, ResultsPair, RunResults,
+};
+
 /// Summary of the feature reduction process
 #[derive(Debug, Default)]
 pub struct ReductionSummary {
     pub initial_features: usize,
     pub final_features: usize,
+    /// Constant and near-constant features (`--dominance-threshold`)
+    pub dropped_constant: Vec<String>,
+    /// Likely ID columns (`--id-threshold` with `--id-action drop`)
+    pub dropped_identifier: Vec<String>,
     pub dropped_missing: Vec<String>,
     pub dropped_gini: Vec<String>,
     pub dropped_correlation: Vec<String>,
     // Timing information
     pub load_time: Duration,
-    pub missing_time: Duration,
-    pub gini_time: Duration,
-    pub correlation_time: Duration,
+    /// `None` when the near-constant pre-filter did not run
+    pub constant_time: Option<Duration>,
+    /// `None` when the ID column check did not run
+    pub identifier_time: Option<Duration>,
+    /// The screening step times are `None` when `--steps` skipped the step
+    pub missing_time: Option<Duration>,
+    pub gini_time: Option<Duration>,
+    pub correlation_time: Option<Duration>,
     pub save_time: Duration,
 }
 
@@ -30,14 +44,32 @@ impl ReductionSummary {
         }
     }
 
+    /// Start the count over once the date policy dropped or replaced date
+    /// columns, before any stage dropped a feature
+    pub fn reset_initial_features(&mut self, features: usize) {
+        self.initial_features = features;
+        self.final_features = features;
+    }
+
+    pub fn add_constant_drops(&mut self, features: Vec<String>) {
+        self.final_features = self.final_features.saturating_sub(features.len());
+        self.dropped_constant = features;
+    }
+
+    pub fn add_identifier_drops(&mut self, features: Vec<String>) {
+        self.final_features = self.final_features.saturating_sub(features.len());
+        self.dropped_identifier = features;
+    }
+
     pub fn add_missing_drops(&mut self, features: Vec<String>) {
         self.final_features = self.final_features.saturating_sub(features.len());
         self.dropped_missing = features;
     }
 
+    /// Add Gini-stage drops; the IV stability check appends to the univariate drops
     pub fn add_gini_drops(&mut self, features: Vec<String>) {
         self.final_features = self.final_features.saturating_sub(features.len());
-        self.dropped_gini = features;
+        self.dropped_gini.extend(features);
     }
 
     pub fn add_correlation_drops(&mut self, features: Vec<String>) {
@@ -45,20 +77,43 @@ impl ReductionSummary {
         self.dropped_correlation = features;
     }
 
+    /// Take features the user kept in the drop review off the drop lists
+    pub fn retain_features(&mut self, features: &[String]) {
+        for dropped in [
+            &mut self.dropped_constant,
+            &mut self.dropped_identifier,
+            &mut self.dropped_missing,
+            &mut self.dropped_gini,
+            &mut self.dropped_correlation,
+        ] {
+            let before = dropped.len();
+            dropped.retain(|f| !features.contains(f));
+            self.final_features += before - dropped.len();
+        }
+    }
+
     pub fn set_load_time(&mut self, duration: Duration) {
         self.load_time = duration;
     }
 
+    pub fn set_constant_time(&mut self, duration: Duration) {
+        self.constant_time = Some(duration);
+    }
+
+    pub fn set_identifier_time(&mut self, duration: Duration) {
+        self.identifier_time = Some(duration);
+    }
+
     pub fn set_missing_time(&mut self, duration: Duration) {
-        self.missing_time = duration;
+        self.missing_time = Some(duration);
     }
 
     pub fn set_gini_time(&mut self, duration: Duration) {
-        self.gini_time = duration;
+        self.gini_time = Some(duration);
     }
 
     pub fn set_correlation_time(&mut self, duration: Duration) {
-        self.correlation_time = duration;
+        self.correlation_time = Some(duration);
     }
 
     pub fn set_save_time(&mut self, duration: Duration) {
@@ -75,7 +130,22 @@ impl ReductionSummary {
     }
 
     pub fn total_time(&self) -> Duration {
-        self.load_time + self.missing_time + self.gini_time + self.correlation_time + self.save_time
+        self.load_time
+            + self.constant_time.unwrap_or_default()
+            + self.identifier_time.unwrap_or_default()
+            + self.missing_time.unwrap_or_default()
+            + self.gini_time.unwrap_or_default()
+            + self.correlation_time.unwrap_or_default()
+            + self.save_time
+    }
+
+    /// Features dropped by every step together
+    pub fn dropped_count(&self) -> usize {
+        self.dropped_constant.len()
+            + self.dropped_identifier.len()
+            + self.dropped_missing.len()
+            + self.dropped_gini.len()
+            + self.dropped_correlation.len()
     }
 
     pub fn display(&self) {
@@ -100,32 +170,59 @@ impl ReductionSummary {
             Cell::new(self.initial_features),
         ]);
 
-        table.add_row(vec![
-            Cell::new("✗ Dropped (Missing)"),
-            Cell::new(self.dropped_missing.len()).fg(if self.dropped_missing.is_empty() {
-                Color::White
-            } else {
-                Color::Red
-            }),
-        ]);
+        if self.constant_time.is_some() {
+            table.add_row(vec![
+                Cell::new("≡ Dropped (Near-constant)"),
+                Cell::new(self.dropped_constant.len()).fg(if self.dropped_constant.is_empty() {
+                    Color::White
+                } else {
+                    Color::Red
+                }),
+            ]);
+        }
 
-        table.add_row(vec![
-            Cell::new("◈ Dropped (Low Gini)"),
-            Cell::new(self.dropped_gini.len()).fg(if self.dropped_gini.is_empty() {
-                Color::White
-            } else {
-                Color::Red
-            }),
-        ]);
+        // Only `--id-action drop` drops anything here
+        if !self.dropped_identifier.is_empty() {
+            table.add_row(vec![
+                Cell::new("⊘ Dropped (ID-like)"),
+                Cell::new(self.dropped_identifier.len()).fg(Color::Red),
+            ]);
+        }
 
-        table.add_row(vec![
-            Cell::new("⋈ Dropped (Correlation)"),
-            Cell::new(self.dropped_correlation.len()).fg(if self.dropped_correlation.is_empty() {
-                Color::White
-            } else {
-                Color::Red
-            }),
-        ]);
+        if self.missing_time.is_some() {
+            table.add_row(vec![
+                Cell::new("✗ Dropped (Missing)"),
+                Cell::new(self.dropped_missing.len()).fg(if self.dropped_missing.is_empty() {
+                    Color::White
+                } else {
+                    Color::Red
+                }),
+            ]);
+        }
+
+        if self.gini_time.is_some() {
+            table.add_row(vec![
+                Cell::new("◈ Dropped (Low Gini)"),
+                Cell::new(self.dropped_gini.len()).fg(if self.dropped_gini.is_empty() {
+                    Color::White
+                } else {
+                    Color::Red
+                }),
+            ]);
+        }
+
+        if self.correlation_time.is_some() {
+            table.add_row(vec![
+                Cell::new("⋈ Dropped (Correlation)"),
+                Cell::new(self.dropped_correlation.len()).fg(
+                    if self.dropped_correlation.is_empty() {
+                        Color::White
+                    } else {
+                        Color::Red
+                    },
+                ),
+            ]);
+        }
 
         table.add_row(vec![
             Cell::new("✓ Final Features"),
@@ -181,18 +278,36 @@ impl ReductionSummary {
             Cell::new("❮ Load Dataset"),
             Cell::new(Self::format_duration(self.load_time)).fg(Color::Cyan),
         ]);
-        timing_table.add_row(vec![
-            Cell::new("◈ Missing Analysis"),
-            Cell::new(Self::format_duration(self.missing_time)).fg(Color::Cyan),
-        ]);
-        timing_table.add_row(vec![
-            Cell::new("⌘ Gini Analysis"),
-            Cell::new(Self::format_duration(self.gini_time)).fg(Color::Cyan),
-        ]);
-        timing_table.add_row(vec![
-            Cell::new("⋈ Correlation Analysis"),
-            Cell::new(Self::format_duration(self.correlation_time)).fg(Color::Cyan),
-        ]);
+        if let Some(constant_time) = self.constant_time {
+            timing_table.add_row(vec![
+                Cell::new("≡ Near-constant Pre-filter"),
+                Cell::new(Self::format_duration(constant_time)).fg(Color::Cyan),
+            ]);
+        }
+        if let Some(identifier_time) = self.identifier_time {
+            timing_table.add_row(vec![
+                Cell::new("⊘ ID Column Check"),
+                Cell::new(Self::format_duration(identifier_time)).fg(Color::Cyan),
+            ]);
+        }
+        if let Some(missing_time) = self.missing_time {
+            timing_table.add_row(vec![
+                Cell::new("◈ Missing Analysis"),
+                Cell::new(Self::format_duration(missing_time)).fg(Color::Cyan),
+            ]);
+        }
+        if let Some(gini_time) = self.gini_time {
+            timing_table.add_row(vec![
+                Cell::new("⌘ Gini Analysis"),
+                Cell::new(Self::format_duration(gini_time)).fg(Color::Cyan),
+            ]);
+        }
+        if let Some(correlation_time) = self.correlation_time {
+            timing_table.add_row(vec![
+                Cell::new("⋈ Correlation Analysis"),
+                Cell::new(Self::format_duration(correlation_time)).fg(Color::Cyan),
+            ]);
+        }
         timing_table.add_row(vec![
             Cell::new("⊚ Save Results"),
             Cell::new(Self::format_duration(self.save_time)).fg(Color::Cyan),
@@ -208,6 +323,170 @@ impl ReductionSummary {
             println!("    {}", line);
         }
     }
+
+    /// Table of every dropped feature with the step that dropped it, in
+    /// pipeline order
+    pub fn drop_candidates_table(&self) -> Table {
+        let mut table = Table::new();
+        table.load_preset(UTF8_FULL_CONDENSED);
+        table.set_header(vec![
+            Cell::new("Step").add_attribute(Attribute::Bold),
+            Cell::new("Feature").add_attribute(Attribute::Bold),
+        ]);
+
+        let steps = [
+            ("≡ Near-constant", &self.dropped_constant),
+            ("⊘ ID-like", &self.dropped_identifier),
+            ("✗ Missing", &self.dropped_missing),
+            ("◈ Low Gini", &self.dropped_gini),
+            ("⋈ Correlation", &self.dropped_correlation),
+        ];
+        for (step, features) in steps {
+            for feature in features {
+                table.add_row(vec![Cell::new(step), Cell::new(feature).fg(Color::Red)]);
+            }
+        }
+        table
+    }
+
+    /// Drops by step, the scored features and the correlated pairs of the run,
+    /// for the results browser (`--browse-results`)
+    pub fn run_results(
+        &self,
+        analyses: &[IvAnalysis],
+        correlated_pairs: &[CorrelatedPair],
+    ) -> RunResults {
+        let steps = [
+            ("constant", &self.dropped_constant, self.constant_time),
+            ("identifier", &self.dropped_identifier, self.identifier_time),
+            ("missing", &self.dropped_missing, self.missing_time),
+            ("gini", &self.dropped_gini, self.gini_time),
+            (
+                "correlation",
+                &self.dropped_correlation,
+                self.correlation_time,
+            ),
+        ];
+        let dropped_by_stage: Vec<(String, Vec<String>)> = steps
+            .into_iter()
+            .filter(|(_, features, time)| time.is_some() || !features.is_empty())
+            .map(|(stage, features, _)| (stage.to_string(), features.clone()))
+            .collect();
+        let dropped_at = |name: &str| {
+            dropped_by_stage
+                .iter()
+                .find(|(_, features)| features.iter().any(|f| f == name))
+                .map(|(stage, _)| stage.clone())
+        };
+
+        let features = analyses
+            .iter()
+            .map(|analysis| {
+                let bin = |label: String, count, event_rate, woe, iv_contribution| ResultsBin {
+                    label,
+                    count,
+                    event_rate,
+                    woe,
+                    iv_contribution,
+                };
+                let numeric = analysis.bins.iter().map(|b| {
+                    bin(
+                        interval_label(b),
+                        b.count,
+                        b.event_rate,
+                        b.woe,
+                        b.iv_contribution,
+                    )
+                });
+                let categorical = analysis.categories.iter().map(|b| {
+                    let label = if b.categories.is_empty() {
+                        b.category.clone()
+                    } else {
+                        b.categories.join(", ")
+                    };
+                    bin(label, b.count, b.event_rate, b.woe, b.iv_contribution)
+                });
+                let special = analysis.special_bins.iter().map(|b| {
+                    bin(
+                        format!("= {}", b.value),
+                        b.count,
+                        b.event_rate,
+                        b.woe,
+                        b.iv_contribution,
+                    )
+                });
+                let missing = analysis
+                    .missing_bin
+                    .iter()
+                    .filter(|b| b.is_separate())
+                    .map(|b| {
+                        bin(
+                            "Missing".to_string(),
+                            b.count,
+                            b.event_rate,
+                            b.woe,
+                            b.iv_contribution,
+                        )
+                    });
+                ResultsFeature {
+                    name: analysis.feature_name.clone(),
+                    feature_type: format!("{:?}", analysis.feature_type),
+                    iv: analysis.iv,
+                    gini: analysis.gini,
+                    dropped_at: dropped_at(&analysis.feature_name),
+                    bins: numeric
+                        .chain(categorical)
+                        .chain(special)
+                        .chain(missing)
+                        .collect(),
+                }
+            })
+            .collect();
+
+        let correlated_pairs = correlated_pairs
+            .iter()
+            .map(|pair| ResultsPair {
+                feature1: pair.feature1.clone(),
+                feature2: pair.feature2.clone(),
+                correlation: pair.correlation,
+                measure: pair.measure.to_string(),
+                dropped: pair.rationale.as_ref().map(|r| r.dropped.clone()),
+            })
+            .collect();
+
+        RunResults {
+            initial_features: self.initial_features,
+            final_features: self.final_features,
+            dropped_by_stage,
+            features,
+            correlated_pairs,
+        }
+    }
+
+    /// Print the drops of a `--dry-run`, which were not applied to any output
+    pub fn display_drop_candidates(&self) {
+        let total = self.dropped_count();
+
+        println!();
+        println!(
+            "    {} {}",
+            style("◆").cyan(),
+            style("CANDIDATE DROPS (DRY RUN)").white().bold()
+        );
+        println!("    {}", style("─".repeat(50)).dim());
+
+        if total == 0 {
+            println!("    No features would be dropped");
+            return;
+        }
+        for line in self.drop_candidates_table().to_string().lines() {
+            println!("    {}", line);
+        }
+        println!(
+            "    {} of {} features would be dropped",
+            total, self.initial_features
+        );
+    }
 }
 
 #[cfg(test)]
@@ -257,6 +536,69 @@ mod tests {
         assert_eq!(summary.final_features, 7);
     }
 
+    #[test]
+    fn test_constant_drops_count_with_the_other_stages() {
+        let mut summary = ReductionSummary::new(10);
+        summary.add_constant_drops(vec!["flat".to_string()]); // 10 -> 9
+        summary.add_missing_drops(vec!["a".to_string()]); // 9 -> 8
+        assert_eq!(summary.final_features, 8);
+        assert_eq!(summary.dropped_count(), 2);
+
+        summary.retain_features(&["flat".to_string()]);
+        assert!(summary.dropped_constant.is_empty());
+        assert_eq!(summary.final_features, 9);
+    }
+
+    #[test]
+    fn test_retain_features_restores_final_count() {
+        let mut summary = ReductionSummary::new(10);
+        summary.add_missing_drops(vec!["a".to_string()]);
+        summary.add_gini_drops(vec!["b".to_string(), "c".to_string()]);
+        summary.add_correlation_drops(vec!["d".to_string()]);
+
+        summary.retain_features(&["b".to_string(), "d".to_string(), "zz".to_string()]);
+
+        assert_eq!(summary.dropped_gini, vec!["c".to_string()]);
+        assert!(summary.dropped_correlation.is_empty());
+        assert_eq!(summary.dropped_missing.len(), 1);
+        assert_eq!(summary.final_features, 8);
+    }
+
+    #[test]
+    fn test_run_results_lists_the_steps_that_ran() {
+        let mut summary = ReductionSummary::new(4);
+        summary.set_missing_time(Duration::from_millis(1));
+        summary.set_gini_time(Duration::from_millis(1));
+        summary.add_gini_drops(vec!["weak".to_string()]);
+        let analysis = |name: &str| IvAnalysis {
+            feature_name: name.to_string(),
+            ..serde_json::from_value(serde_json::json!({
+                "feature_name": "",
+                "feature_type": "Numeric",
+                "bins": [{
+                    "lower_bound": 0.0, "upper_bound": 10.0, "events": 1.0,
+                    "non_events": 3.0, "woe": 0.2, "iv_contribution": 0.01, "count": 4.0,
+                    "population_pct": 100.0, "event_rate": 0.25
+                }],
+                "iv": 0.01,
+                "gini": 0.02
+            }))
+            .unwrap()
+        };
+
+        let results = summary.run_results(&[analysis("weak"), analysis("age")], &[]);
+        let stages: Vec<&str> = results
+            .dropped_by_stage
+            .iter()
+            .map(|(stage, _)| stage.as_str())
+            .collect();
+        assert_eq!(stages, ["missing", "gini"]);
+        assert_eq!(results.final_features, 3);
+        assert_eq!(results.features[0].dropped_at.as_deref(), Some("gini"));
+        assert_eq!(results.features[1].dropped_at, None);
+        assert_eq!(results.features[1].bins[0].label, "[0, 10)");
+    }
+
     #[test]
     fn test_underflow_protection_does_not_panic() {
         // Drop more features than exist -- saturating_sub must prevent wrapping.
@@ -270,4 +612,27 @@ mod tests {
         // saturating_sub(4) on 3 must yield 0, not wrap to usize::MAX
         assert_eq!(summary.final_features, 0);
     }
+
+    #[test]
+    fn test_drop_candidates_table_lists_each_drop_with_its_step() {
+        let mut summary = ReductionSummary::new(5);
+        summary.add_missing_drops(vec!["sparse".to_string()]);
+        summary.add_correlation_drops(vec!["copy_a".to_string(), "copy_b".to_string()]);
+
+        let table = summary.drop_candidates_table();
+        assert_eq!(table.row_count(), 3);
+        let rendered = table.to_string();
+        let row = |feature: &str| {
+            rendered
+                .lines()
+                .find(|line| line.contains(feature))
+                .unwrap()
+                .to_string()
+        };
+        assert!(row("sparse").contains("Missing"));
+        assert!(!rendered.contains("Near-constant"));
+        assert!(row("copy_a").contains("Correlation"));
+        assert!(row("copy_b").contains("Correlation"));
+        assert!(!rendered.contains("Low Gini"));
+    }
 }
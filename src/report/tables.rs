@@ -0,0 +1,140 @@
+//! Standalone stage tables (`--export-missing-ratios`, `--export-iv-table`,
+//! `--export-correlated-pairs`)
+//!
+//! The same results are in the reduction report, nested per feature. These
+//! exports write one flat table per stage so it can be loaded directly into a
+//! dataframe or BI tool. The format follows the path's extension: `.csv`, or
+//! `.feather` / `.arrow` / `.ipc` for Arrow IPC (Feather v2).
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+
+use crate::pipeline::{CorrelatedPair, FeatureType, IvAnalysis, PairRationale};
+
+/// File format of a standalone table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    Csv,
+    Feather,
+}
+
+impl TableFormat {
+    /// Format for `path`, from its extension
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        match extension.as_str() {
+            "csv" => Ok(TableFormat::Csv),
+            "feather" | "arrow" | "ipc" => Ok(TableFormat::Feather),
+            _ => anyhow::bail!(
+                "Unsupported table format: '{}'. Use a .csv or .feather path",
+                path.display()
+            ),
+        }
+    }
+}
+
+/// Output paths of the standalone tables; `None` skips a table
+#[derive(Debug, Clone, Default)]
+pub struct TableExports {
+    pub missing_ratios: Option<PathBuf>,
+    pub iv_table: Option<PathBuf>,
+    pub correlated_pairs: Option<PathBuf>,
+}
+
+/// One row per analysed feature: `feature`, `missing_ratio`, `dropped`
+pub fn missing_ratio_table(ratios: &[(String, f64)], dropped: &[String]) -> Result<DataFrame> {
+    let dropped: HashSet<&str> = dropped.iter().map(String::as_str).collect();
+    let features: Vec<&str> = ratios.iter().map(|(name, _)| name.as_str()).collect();
+    let values: Vec<f64> = ratios.iter().map(|(_, ratio)| *ratio).collect();
+    let flags: Vec<bool> = features.iter().map(|f| dropped.contains(f)).collect();
+    Ok(df! {
+        "feature" => features,
+        "missing_ratio" => values,
+        "dropped" => flags,
+    }?)
+}
+
+/// One row per feature of the Gini/IV stage with its IV, Gini, KS, AUC,
+/// mutual information (null unless computed) and number of bins (including
+/// the special bins and a missing bin of its own)
+pub fn iv_table(analyses: &[IvAnalysis], dropped: &[String]) -> Result<DataFrame> {
+    let dropped: HashSet<&str> = dropped.iter().map(String::as_str).collect();
+    let bin_count = |a: &IvAnalysis| {
+        let bins = match a.feature_type {
+            FeatureType::Numeric => a.bins.len(),
+            FeatureType::Categorical => a.categories.len(),
+        };
+        let missing = a.missing_bin.as_ref().is_some_and(|m| m.is_separate());
+        (bins + usize::from(missing) + a.special_bins.len()) as u32
+    };
+    Ok(df! {
+        "feature" => analyses.iter().map(|a| a.feature_name.as_str()).collect::<Vec<_>>(),
+        "feature_type" => analyses
+            .iter()
+            .map(|a| match a.feature_type {
+                FeatureType::Numeric => "numeric",
+                FeatureType::Categorical => "categorical",
+            })
+            .collect::<Vec<_>>(),
+        "iv" => analyses.iter().map(|a| a.iv).collect::<Vec<_>>(),
+        "gini" => analyses.iter().map(|a| a.gini).collect::<Vec<_>>(),
+        "ks" => analyses.iter().map(|a| a.ks).collect::<Vec<_>>(),
+        "auc" => analyses.iter().map(|a| a.auc).collect::<Vec<_>>(),
+        "mutual_info" => analyses.iter().map(|a| a.mutual_info).collect::<Vec<_>>(),
+        "bins" => analyses.iter().map(bin_count).collect::<Vec<_>>(),
+        "dropped" => analyses
+            .iter()
+            .map(|a| dropped.contains(a.feature_name.as_str()))
+            .collect::<Vec<_>>(),
+    }?)
+}
+
+/// One row per pair above the correlation threshold.
+///
+/// `dropped` is the member removed for the pair and `rule` the waterfall rule
+/// that chose it; both are null for pairs that dropped nothing (a member was
+/// already gone, both were protected, or the drop was un-checked in review).
+pub fn correlated_pairs_table(pairs: &[CorrelatedPair], dropped: &[String]) -> Result<DataFrame> {
+    let dropped: HashSet<&str> = dropped.iter().map(String::as_str).collect();
+    let applied: Vec<Option<&PairRationale>> = pairs
+        .iter()
+        .map(|p| {
+            p.rationale
+                .as_ref()
+                .filter(|r| dropped.contains(r.dropped.as_str()))
+        })
+        .collect();
+    Ok(df! {
+        "feature1" => pairs.iter().map(|p| p.feature1.as_str()).collect::<Vec<_>>(),
+        "feature2" => pairs.iter().map(|p| p.feature2.as_str()).collect::<Vec<_>>(),
+        "correlation" => pairs.iter().map(|p| p.correlation).collect::<Vec<_>>(),
+        "measure" => pairs.iter().map(|p| p.measure.to_string()).collect::<Vec<_>>(),
+        "dropped" => applied
+            .iter()
+            .map(|r| r.map(|r| r.dropped.as_str()))
+            .collect::<Vec<_>>(),
+        "rule" => applied
+            .iter()
+            .map(|r| r.map(|r| r.rule.to_string()))
+            .collect::<Vec<_>>(),
+    }?)
+}
+
+/// Write `df` as CSV or Feather, depending on the extension of `path`
+pub fn write_table(df: &mut DataFrame, path: &Path) -> Result<()> {
+    let format = TableFormat::from_path(path)?;
+    let file = std::fs::File::create(path)
+        .with_context(|| format!("Failed to create table file: {}", path.display()))?;
+    match format {
+        TableFormat::Csv => CsvWriter::new(file).finish(df),
+        TableFormat::Feather => IpcWriter::new(file).finish(df),
+    }
+    .with_context(|| format!("Failed to write table: {}", path.display()))
+}
@@ -0,0 +1,461 @@
+//! Structural and consistency checks of a reduction report (`lophi validate-report`)
+//!
+//! Downstream jobs read the report JSON to find out what a run kept and why.
+//! The checks here work on the raw JSON rather than the report types, so
+//! reports from older versions and hand-edited files are checked as they are:
+//! required sections and thresholds are present, the summary counts agree with
+//! each other and with the feature list, and every dropped feature names its
+//! stage.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+
+use super::reduction_report::{REPORT_SCHEMA_VERSION, USER_RETAINED};
+use crate::pipeline::apply::read_report_json;
+
+/// Stages of `summary.by_stage`, with the `metadata.thresholds` key each
+/// stage's `threshold_used` comes from
+const STAGES: [(&str, &str); 5] = [
+    ("constant", "dominance"),
+    ("identifier", "id_ratio"),
+    ("missing", "missing_ratio"),
+    ("gini", "gini"),
+    ("correlation", "correlation"),
+];
+
+/// Stages every report has unless `metadata.settings.steps.skipped` lists
+/// them; the others appear only when their check ran
+const REQUIRED_STAGES: [&str; 3] = ["missing", "gini", "correlation"];
+
+/// Outcome of validating a reduction report
+#[derive(Debug, Clone, Default)]
+pub struct ReportValidation {
+    /// `schema_version` of the report; `None` for reports written before
+    /// the field existed
+    pub schema_version: Option<u64>,
+    /// Entries in `features`
+    pub features: usize,
+    /// Problems that make the report unreliable to consume
+    pub errors: Vec<String>,
+    /// Oddities a consumer may want to know about
+    pub warnings: Vec<String>,
+}
+
+impl ReportValidation {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Validate the reduction report at `path`, a report `.json` or `.zip` bundle.
+///
+/// # Errors
+/// Only when the file cannot be read or is not JSON; problems with the report
+/// itself are collected in the returned [`ReportValidation`].
+pub fn validate_report_file(path: &Path) -> Result<ReportValidation> {
+    let content = read_report_json(path)?;
+    let report: Value = serde_json::from_str(&content)
+        .with_context(|| format!("Report is not valid JSON: {}", path.display()))?;
+    Ok(validate_report(&report))
+}
+
+/// Check the structure and internal consistency of a reduction report
+pub fn validate_report(report: &Value) -> ReportValidation {
+    let mut validation = ReportValidation::default();
+    check_schema_version(report, &mut validation);
+
+    let metadata = section(report, "metadata", &mut validation.errors);
+    let summary = section(report, "summary", &mut validation.errors);
+    let Some(features) = report.get("features").and_then(Value::as_array) else {
+        validation
+            .errors
+            .push("Missing array 'features'".to_string());
+        return validation;
+    };
+    validation.features = features.len();
+
+    let thresholds = metadata.map(|m| check_metadata(m, &mut validation.errors));
+    let counts = check_features(features, &mut validation);
+    let skipped: Vec<&str> = metadata
+        .and_then(|m| m.pointer("/settings/steps/skipped"))
+        .and_then(Value::as_array)
+        .map(|steps| steps.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    if let Some(summary) = summary {
+        check_summary(
+            summary,
+            thresholds.flatten(),
+            &skipped,
+            &counts,
+            &mut validation,
+        );
+    }
+    validation
+}
+
+fn check_schema_version(report: &Value, validation: &mut ReportValidation) {
+    match report.get("schema_version") {
+        None => validation.warnings.push(format!(
+            "No 'schema_version': the report predates versioning and is checked as version {}",
+            REPORT_SCHEMA_VERSION
+        )),
+        Some(value) => match value.as_u64() {
+            Some(version) if version > u64::from(REPORT_SCHEMA_VERSION) => {
+                validation.schema_version = Some(version);
+                validation.errors.push(format!(
+                    "schema_version {} is newer than this lophi supports ({}); upgrade lophi",
+                    version, REPORT_SCHEMA_VERSION
+                ));
+            }
+            Some(version) => validation.schema_version = Some(version),
+            None => validation.errors.push(format!(
+                "'schema_version' must be an integer, found {}",
+                value
+            )),
+        },
+    }
+}
+
+/// The object `report[key]`, recording an error when it is missing
+fn section<'a>(report: &'a Value, key: &str, errors: &mut Vec<String>) -> Option<&'a Value> {
+    let value = report.get(key).filter(|v| v.is_object());
+    if value.is_none() {
+        errors.push(format!("Missing object '{}'", key));
+    }
+    value
+}
+
+/// Check `metadata` and return its thresholds when they are an object
+fn check_metadata<'a>(metadata: &'a Value, errors: &mut Vec<String>) -> Option<&'a Value> {
+    for key in ["timestamp", "lophi_version", "input_file", "output_file"] {
+        if !metadata.get(key).is_some_and(Value::is_string) {
+            errors.push(format!("Missing string 'metadata.{}'", key));
+        }
+    }
+    if !metadata
+        .pointer("/settings/target_column")
+        .is_some_and(Value::is_string)
+    {
+        errors.push("Missing string 'metadata.settings.target_column'".to_string());
+    }
+
+    let Some(thresholds) = metadata.get("thresholds").filter(|v| v.is_object()) else {
+        errors.push("Missing object 'metadata.thresholds'".to_string());
+        return None;
+    };
+    for key in ["missing_ratio", "gini", "correlation"] {
+        match thresholds.get(key).and_then(Value::as_f64) {
+            None => errors.push(format!("Missing number 'metadata.thresholds.{}'", key)),
+            Some(value) if !value.is_finite() || value < 0.0 => errors.push(format!(
+                "'metadata.thresholds.{}' must be a non-negative number, found {}",
+                key, value
+            )),
+            Some(_) => {}
+        }
+    }
+    Some(thresholds)
+}
+
+/// What the feature list says, for comparison with the summary
+#[derive(Debug, Default)]
+struct FeatureCounts {
+    kept: usize,
+    dropped: usize,
+    dropped_by_stage: HashMap<String, usize>,
+}
+
+fn check_features(features: &[Value], validation: &mut ReportValidation) -> FeatureCounts {
+    let mut counts = FeatureCounts::default();
+    let mut names = HashSet::new();
+
+    for (index, feature) in features.iter().enumerate() {
+        let Some(name) = feature.get("name").and_then(Value::as_str) else {
+            validation
+                .errors
+                .push(format!("features[{}] has no string 'name'", index));
+            continue;
+        };
+        if !names.insert(name) {
+            validation
+                .errors
+                .push(format!("Feature '{}' is listed more than once", name));
+        }
+        let stage = feature.get("dropped_at_stage").and_then(Value::as_str);
+
+        match feature.get("status").and_then(Value::as_str) {
+            Some("dropped") => {
+                counts.dropped += 1;
+                match stage {
+                    Some(stage) if STAGES.iter().any(|(s, _)| *s == stage) => {
+                        *counts
+                            .dropped_by_stage
+                            .entry(stage.to_string())
+                            .or_default() += 1;
+                    }
+                    Some(stage) => validation.errors.push(format!(
+                        "Feature '{}' was dropped at unknown stage '{}'",
+                        name, stage
+                    )),
+                    None => validation.errors.push(format!(
+                        "Feature '{}' is dropped but has no 'dropped_at_stage'",
+                        name
+                    )),
+                }
+                if !feature.get("reason").is_some_and(Value::is_string) {
+                    validation
+                        .warnings
+                        .push(format!("Dropped feature '{}' has no 'reason'", name));
+                }
+            }
+            Some(status) if status == "kept" || status == USER_RETAINED => {
+                counts.kept += 1;
+                if let Some(stage) = stage {
+                    validation.errors.push(format!(
+                        "Feature '{}' is {} but has 'dropped_at_stage' '{}'",
+                        name, status, stage
+                    ));
+                }
+            }
+            Some(status) => validation.errors.push(format!(
+                "Feature '{}' has unknown status '{}'",
+                name, status
+            )),
+            None => validation
+                .errors
+                .push(format!("Feature '{}' has no string 'status'", name)),
+        }
+    }
+    counts
+}
+
+fn check_summary(
+    summary: &Value,
+    thresholds: Option<&Value>,
+    skipped: &[&str],
+    counts: &FeatureCounts,
+    validation: &mut ReportValidation,
+) {
+    let errors = &mut validation.errors;
+    let mut count = |key: &str| {
+        let value = summary.get(key).and_then(Value::as_u64);
+        if value.is_none() {
+            errors.push(format!("Missing integer 'summary.{}'", key));
+        }
+        value
+    };
+    let initial = count("initial_features");
+    let final_features = count("final_features");
+    let dropped = count("dropped_count");
+
+    if let (Some(initial), Some(final_features), Some(dropped)) = (initial, final_features, dropped)
+    {
+        if initial != final_features + dropped {
+            errors.push(format!(
+                "summary.initial_features ({}) is not final_features ({}) + dropped_count ({})",
+                initial, final_features, dropped
+            ));
+        }
+    }
+    let mut agree = |key: &str, summary: Option<u64>, listed: usize, what: &str| {
+        if let Some(value) = summary {
+            if value != listed as u64 {
+                errors.push(format!(
+                    "summary.{} is {} but {} {} listed",
+                    key, value, listed, what
+                ));
+            }
+        }
+    };
+    agree(
+        "initial_features",
+        initial,
+        validation.features,
+        "features are",
+    );
+    agree(
+        "final_features",
+        final_features,
+        counts.kept,
+        "kept features are",
+    );
+    agree(
+        "dropped_count",
+        dropped,
+        counts.dropped,
+        "dropped features are",
+    );
+
+    let Some(by_stage) = summary.get("by_stage").filter(|v| v.is_object()) else {
+        validation
+            .errors
+            .push("Missing object 'summary.by_stage'".to_string());
+        return;
+    };
+    let mut stage_total = 0;
+    for (stage, threshold_key) in STAGES {
+        let listed = counts.dropped_by_stage.get(stage).copied().unwrap_or(0);
+        let Some(entry) = by_stage.get(stage).filter(|v| !v.is_null()) else {
+            if REQUIRED_STAGES.contains(&stage) && !skipped.contains(&stage) {
+                validation
+                    .errors
+                    .push(format!("Missing object 'summary.by_stage.{}'", stage));
+            } else if listed > 0 {
+                validation.errors.push(format!(
+                    "{} feature(s) were dropped at stage '{}', which has no summary.by_stage entry",
+                    listed, stage
+                ));
+            }
+            continue;
+        };
+
+        match entry.get("dropped").and_then(Value::as_u64) {
+            Some(stage_dropped) => {
+                stage_total += stage_dropped;
+                if stage_dropped != listed as u64 {
+                    validation.errors.push(format!(
+                        "summary.by_stage.{}.dropped is {} but {} feature(s) list that stage",
+                        stage, stage_dropped, listed
+                    ));
+                }
+            }
+            None => validation.errors.push(format!(
+                "Missing integer 'summary.by_stage.{}.dropped'",
+                stage
+            )),
+        }
+
+        let used = entry.get("threshold_used").and_then(Value::as_f64);
+        let configured = thresholds
+            .and_then(|t| t.get(threshold_key))
+            .and_then(Value::as_f64);
+        match (used, configured) {
+            (None, _) => validation.errors.push(format!(
+                "Missing number 'summary.by_stage.{}.threshold_used'",
+                stage
+            )),
+            (Some(used), Some(configured)) if used != configured => {
+                validation.errors.push(format!(
+                "summary.by_stage.{}.threshold_used ({}) differs from metadata.thresholds.{} ({})",
+                stage, used, threshold_key, configured
+            ))
+            }
+            _ => {}
+        }
+    }
+    if let Some(dropped) = dropped {
+        if stage_total != dropped {
+            validation.errors.push(format!(
+                "summary.by_stage drops add up to {} but dropped_count is {}",
+                stage_total, dropped
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn report() -> Value {
+        json!({
+            "schema_version": REPORT_SCHEMA_VERSION,
+            "metadata": {
+                "timestamp": "2026-01-01T00:00:00+00:00",
+                "lophi_version": "1.1.0",
+                "input_file": "data.csv",
+                "output_file": "data_reduced.csv",
+                "thresholds": {"missing_ratio": 0.3, "gini": 0.05, "correlation": 0.4},
+                "settings": {"target_column": "target"}
+            },
+            "summary": {
+                "initial_features": 3,
+                "final_features": 1,
+                "dropped_count": 2,
+                "by_stage": {
+                    "missing": {"dropped": 1, "threshold_used": 0.3},
+                    "gini": {"dropped": 1, "threshold_used": 0.05},
+                    "correlation": {"dropped": 0, "threshold_used": 0.4}
+                }
+            },
+            "features": [
+                {"name": "a", "status": "kept", "analysis": {}},
+                {"name": "b", "status": "dropped", "dropped_at_stage": "missing",
+                 "reason": "Missing ratio 0.50 exceeded threshold 0.30", "analysis": {}},
+                {"name": "c", "status": "dropped", "dropped_at_stage": "gini",
+                 "reason": "Gini 0.01 below threshold 0.05", "analysis": {}}
+            ]
+        })
+    }
+
+    #[test]
+    fn test_consistent_report_is_valid() {
+        let validation = validate_report(&report());
+        assert!(validation.is_valid(), "{:?}", validation.errors);
+        assert!(validation.warnings.is_empty());
+        assert_eq!(
+            validation.schema_version,
+            Some(u64::from(REPORT_SCHEMA_VERSION))
+        );
+        assert_eq!(validation.features, 3);
+    }
+
+    #[test]
+    fn test_inconsistent_counts_and_missing_fields() {
+        let mut report = report();
+        report["summary"]["dropped_count"] = json!(3);
+        report["summary"]["by_stage"]["gini"]["dropped"] = json!(0);
+        report["features"][1]
+            .as_object_mut()
+            .unwrap()
+            .remove("dropped_at_stage");
+        report["metadata"]["thresholds"]
+            .as_object_mut()
+            .unwrap()
+            .remove("gini");
+
+        let errors = validate_report(&report).errors;
+        let has = |text: &str| errors.iter().any(|e| e.contains(text));
+        assert!(has("is not final_features (1) + dropped_count (3)"));
+        assert!(has(
+            "summary.dropped_count is 3 but 2 dropped features are listed"
+        ));
+        assert!(has("summary.by_stage.gini.dropped is 0 but 1 feature(s)"));
+        assert!(has("Feature 'b' is dropped but has no 'dropped_at_stage'"));
+        assert!(has("Missing number 'metadata.thresholds.gini'"));
+    }
+
+    #[test]
+    fn test_skipped_stage_needs_no_summary() {
+        let mut report = report();
+        report["features"][2] = json!({"name": "c", "status": "kept", "analysis": {}});
+        report["summary"]["final_features"] = json!(2);
+        report["summary"]["dropped_count"] = json!(1);
+        report["summary"]["by_stage"]
+            .as_object_mut()
+            .unwrap()
+            .remove("gini");
+        let missing_gini = validate_report(&report).errors;
+        assert_eq!(missing_gini, ["Missing object 'summary.by_stage.gini'"]);
+
+        report["metadata"]["settings"]["steps"] =
+            json!({"order": ["missing", "correlation"], "skipped": ["gini"]});
+        let validation = validate_report(&report);
+        assert!(validation.is_valid(), "{:?}", validation.errors);
+    }
+
+    #[test]
+    fn test_schema_version_checks() {
+        let mut report = report();
+        report.as_object_mut().unwrap().remove("schema_version");
+        let validation = validate_report(&report);
+        assert!(validation.is_valid());
+        assert!(validation.warnings[0].contains("predates versioning"));
+
+        report["schema_version"] = json!(REPORT_SCHEMA_VERSION + 1);
+        assert!(validate_report(&report).errors[0].contains("newer than this lophi supports"));
+    }
+}
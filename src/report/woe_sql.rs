@@ -0,0 +1,212 @@
+//! SQL `CASE WHEN` expressions that WoE-encode the retained features
+//!
+//! Scoring in the warehouse needs the final bins without re-implementing the
+//! binning. Each retained feature becomes one `CASE` expression rendered from
+//! its [`FeatureScoring`] entry, so the SQL, the scoring spec and
+//! `--export-woe` encode a row the same way.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use super::scoring_spec::{scored_features, FeatureScoring};
+use super::selected_features::sql_identifier;
+use crate::pipeline::IvAnalysis;
+
+/// A `SELECT` clause without `FROM` holding one `CASE` expression per
+/// feature of `features` with an analysis, in `features` order. Each
+/// expression keeps the feature's name, like the columns of the WoE dataset.
+pub fn woe_transform_sql(analyses: &[IvAnalysis], features: &[String]) -> String {
+    let expressions: Vec<String> = scored_features(analyses, features)
+        .iter()
+        .map(woe_case_expression)
+        .collect();
+    if expressions.is_empty() {
+        return "-- No binned features were retained\n".to_string();
+    }
+    format!("SELECT\n{}\n", expressions.join(",\n"))
+}
+
+/// Write [`woe_transform_sql`] to `path`
+pub fn export_woe_transform_sql(
+    analyses: &[IvAnalysis],
+    features: &[String],
+    path: &Path,
+) -> Result<()> {
+    std::fs::write(path, woe_transform_sql(analyses, features))
+        .with_context(|| format!("Failed to write WoE SQL to {}", path.display()))
+}
+
+/// `CASE ... END AS "feature"` for one feature: nulls, then special values,
+/// then one arm per bin up to the first open one, which is the `ELSE` with
+/// the last bin (bins are sorted, so the first upper bound above the value
+/// picks the bin, gaps between bins included), or one arm per category group
+/// and the default WoE
+fn woe_case_expression(scoring: &FeatureScoring) -> String {
+    let column = sql_identifier(&scoring.feature);
+
+    let mut arms = vec![format!(
+        "WHEN {} IS NULL THEN {}",
+        column, scoring.missing_woe
+    )];
+    for special in &scoring.special_values {
+        arms.push(format!(
+            "WHEN {} = {} THEN {}",
+            column, special.value, special.woe
+        ));
+    }
+    let mut default_woe = scoring.default_woe;
+    for (i, bin) in scoring.bins.iter().enumerate() {
+        match bin.upper_bound {
+            Some(upper) if i + 1 < scoring.bins.len() => {
+                arms.push(format!("WHEN {} < {} THEN {}", column, upper, bin.woe))
+            }
+            _ => {
+                default_woe = bin.woe;
+                break;
+            }
+        }
+    }
+    for group in &scoring.categories {
+        let literals: Vec<String> = group.values.iter().map(|c| sql_string(c)).collect();
+        arms.push(format!(
+            "WHEN {} IN ({}) THEN {}",
+            column,
+            literals.join(", "),
+            group.woe
+        ));
+    }
+
+    let mut out = String::from("    CASE\n");
+    for arm in arms {
+        out.push_str(&format!("        {}\n", arm));
+    }
+    out.push_str(&format!(
+        "        ELSE {}\n    END AS {}",
+        default_woe, column
+    ));
+    out
+}
+
+/// Single-quoted SQL string literal
+fn sql_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::{CategoricalWoeBin, FeatureType, MissingBin, SpecialBin, WoeBin};
+
+    fn woe_bin(lower_bound: f64, upper_bound: f64, woe: f64) -> WoeBin {
+        WoeBin {
+            lower_bound,
+            upper_bound,
+            events: 0.0,
+            non_events: 0.0,
+            woe,
+            iv_contribution: 0.0,
+            count: 0.0,
+            population_pct: 0.0,
+            event_rate: 0.0,
+            chi_square: None,
+            chi_square_p_value: None,
+        }
+    }
+
+    fn category_bin(categories: &[&str], woe: f64) -> CategoricalWoeBin {
+        CategoricalWoeBin {
+            category: String::new(),
+            categories: categories.iter().map(|c| c.to_string()).collect(),
+            events: 0.0,
+            non_events: 0.0,
+            woe,
+            iv_contribution: 0.0,
+            count: 0.0,
+            population_pct: 0.0,
+            event_rate: 0.0,
+        }
+    }
+
+    fn analysis(name: &str, feature_type: FeatureType) -> IvAnalysis {
+        IvAnalysis {
+            feature_name: name.to_string(),
+            feature_type,
+            bins: Vec::new(),
+            categories: Vec::new(),
+            missing_bin: None,
+            special_bins: Vec::new(),
+            iv: 0.0,
+            gini: 0.0,
+            ks: 0.0,
+            auc: 0.5,
+            mutual_info: None,
+            monotonicity: None,
+            solver_status: None,
+        }
+    }
+
+    #[test]
+    fn test_numeric_case_uses_upper_bounds() {
+        let mut income = analysis("income", FeatureType::Numeric);
+        income.bins = vec![
+            woe_bin(f64::NEG_INFINITY, 1000.0, -0.5),
+            woe_bin(1000.0, 2500.5, 0.25),
+            woe_bin(2500.5, f64::INFINITY, 1.0),
+        ];
+        income.missing_bin = Some(MissingBin {
+            events: 0.0,
+            non_events: 0.0,
+            woe: 0.75,
+            iv_contribution: 0.0,
+            count: 0.0,
+            population_pct: 0.0,
+            event_rate: 0.0,
+            merged_into: None,
+            imputed_value: None,
+        });
+        income.special_bins = vec![SpecialBin {
+            value: -999.0,
+            events: 0.0,
+            non_events: 0.0,
+            woe: 2.0,
+            iv_contribution: 0.0,
+            count: 0.0,
+            population_pct: 0.0,
+            event_rate: 0.0,
+        }];
+
+        assert_eq!(
+            woe_transform_sql(&[income], &["income".to_string()]),
+            "SELECT\n    CASE\n        WHEN \"income\" IS NULL THEN 0.75\n        \
+             WHEN \"income\" = -999 THEN 2\n        WHEN \"income\" < 1000 THEN -0.5\n        \
+             WHEN \"income\" < 2500.5 THEN 0.25\n        ELSE 1\n    END AS \"income\"\n"
+        );
+    }
+
+    #[test]
+    fn test_categorical_case_falls_back_to_other() {
+        let mut region = analysis("region", FeatureType::Categorical);
+        region.categories = vec![
+            category_bin(&["north", "o'neil"], -0.2),
+            category_bin(&["OTHER"], 0.4),
+        ];
+
+        let sql = woe_transform_sql(&[region], &["region".to_string()]);
+        assert!(sql.contains("WHEN \"region\" IN ('north', 'o''neil') THEN -0.2\n"));
+        assert!(sql.contains("WHEN \"region\" IS NULL THEN 0\n"));
+        assert!(sql.contains("ELSE 0.4\n    END AS \"region\""));
+    }
+
+    #[test]
+    fn test_only_retained_features_are_encoded() {
+        let analyses = vec![
+            analysis("kept", FeatureType::Numeric),
+            analysis("dropped", FeatureType::Numeric),
+        ];
+        let sql = woe_transform_sql(&analyses, &["kept".to_string(), "date".to_string()]);
+        assert!(sql.contains("END AS \"kept\""));
+        assert!(!sql.contains("dropped"));
+        assert!(woe_transform_sql(&analyses, &[]).starts_with("--"));
+    }
+}
@@ -0,0 +1,81 @@
+//! Logging layer behind the console output
+//!
+//! The styled messages on stdout stay the human-facing output. Detail that
+//! would clutter them goes through `tracing` instead: per-feature binning
+//! times, solver outcomes, and warnings such as skipped features.
+//!
+//! - stderr shows warnings by default, debug events with `-v` and trace events
+//!   with `-vv`. `--quiet` leaves errors only, and nothing is written while the
+//!   TUI owns the terminal.
+//! - `--log-file` receives debug events and up (trace with `-vv`) with
+//!   timestamps, plus a copy of every console message.
+
+use std::fs::File;
+use std::path::Path;
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use tracing::level_filters::LevelFilter;
+use tracing::Metadata;
+use tracing_subscriber::filter::filter_fn;
+use tracing_subscriber::fmt;
+use tracing_subscriber::prelude::*;
+
+use super::styling::no_color_mode;
+
+/// Target of the events that mirror the styled console messages into the log
+/// file. stderr skips them, since stdout already shows them.
+pub const CONSOLE_TARGET: &str = "lophi::console";
+
+/// Install the global subscriber.
+///
+/// `verbosity` is the number of `-v` flags. `tui` turns the stderr layer off
+/// so it cannot draw over the wizard or progress overlay.
+pub fn init_logging(verbosity: u8, quiet: bool, tui: bool, log_file: Option<&Path>) -> Result<()> {
+    let console_level = match (verbosity, quiet) {
+        (0, true) => LevelFilter::ERROR,
+        (0, false) => LevelFilter::WARN,
+        (1, _) => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+    let file_level = if verbosity >= 2 {
+        LevelFilter::TRACE
+    } else {
+        LevelFilter::DEBUG
+    };
+
+    let console = (!tui).then(|| {
+        fmt::layer()
+            .with_writer(std::io::stderr)
+            .without_time()
+            .with_target(false)
+            .with_ansi(!no_color_mode())
+            .with_filter(filter_fn(move |meta| {
+                is_lophi(meta) && meta.target() != CONSOLE_TARGET && *meta.level() <= console_level
+            }))
+    });
+    let file = log_file
+        .map(|path| -> Result<_> {
+            let file = File::create(path)
+                .with_context(|| format!("Failed to create log file {}", path.display()))?;
+            Ok(fmt::layer()
+                .with_writer(Mutex::new(file))
+                .with_ansi(false)
+                .with_filter(filter_fn(move |meta| {
+                    is_lophi(meta) && *meta.level() <= file_level
+                })))
+        })
+        .transpose()?;
+
+    tracing_subscriber::registry()
+        .with(console)
+        .with(file)
+        .try_init()
+        .context("Failed to set up logging")?;
+    Ok(())
+}
+
+/// Events from lophi itself, not from its dependencies
+fn is_lophi(meta: &Metadata<'_>) -> bool {
+    meta.target() == "lophi" || meta.target().starts_with("lophi::")
+}
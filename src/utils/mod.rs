@@ -1,7 +1,9 @@
 //! Utility module
 
+pub mod logging;
 pub mod progress;
 pub mod styling;
 
+pub use logging::*;
 pub use progress::*;
 pub use styling::*;
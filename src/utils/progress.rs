@@ -3,7 +3,7 @@
 use console::style;
 use indicatif::{ProgressBar, ProgressStyle};
 
-use crate::cli::shared::no_color_mode;
+use super::styling::no_color_mode;
 
 /// Create a spinner for indeterminate progress with Braille animation.
 ///
@@ -2,8 +2,11 @@
 
 use console::{style, Emoji};
 use std::path::Path;
+use std::sync::OnceLock;
 use std::time::Duration;
 
+use super::logging::CONSOLE_TARGET;
+
 // Decorative dingbat icons with fallbacks
 pub static INFO: Emoji<'_, '_> = Emoji("✦ ", "[*] ");
 pub static ROCKET: Emoji<'_, '_> = Emoji("❯ ", ">> ");
@@ -13,6 +16,22 @@ pub static TARGET: Emoji<'_, '_> = Emoji("⊛ ", "");
 pub static SAVE: Emoji<'_, '_> = Emoji("⊚ ", "");
 pub static LINK: Emoji<'_, '_> = Emoji("⋈ ", "");
 
+static NO_COLOR: OnceLock<bool> = OnceLock::new();
+
+/// Returns true when color output should be suppressed.
+///
+/// Checks for:
+/// - `NO_COLOR` env var (any value, per <https://no-color.org>)
+/// - `TERM=dumb` env var (terminals that cannot render ANSI sequences)
+///
+/// The result is cached via [`OnceLock`] so the environment is only read once.
+pub fn no_color_mode() -> bool {
+    *NO_COLOR.get_or_init(|| {
+        std::env::var("NO_COLOR").is_ok()
+            || std::env::var("TERM").map(|t| t == "dumb").unwrap_or(false)
+    })
+}
+
 /// Print the application banner with ASCII art
 pub fn print_banner(version: &str) {
     let banner = r#"
@@ -36,15 +55,29 @@ pub fn print_banner(version: &str) {
     println!();
 }
 
-/// Print configuration card
+/// Print configuration card. `univariate_threshold` is the Gini threshold,
+/// or the MI threshold when `mutual_info` is set.
 pub fn print_config(
     input: &Path,
     target: &str,
     output: &Path,
     missing_threshold: f64,
-    gini_threshold: f64,
+    univariate_threshold: f64,
+    mutual_info: bool,
     correlation_threshold: f64,
 ) {
+    let univariate = if mutual_info { "MI" } else { "Gini" };
+    tracing::info!(
+        target: CONSOLE_TARGET,
+        "Input: {}, target: {}, output: {}, missing threshold: {}, {} threshold: {}, correlation threshold: {}",
+        input.display(),
+        target,
+        output.display(),
+        missing_threshold,
+        univariate.to_lowercase(),
+        univariate_threshold,
+        correlation_threshold
+    );
     let box_width = 56;
     let line = "─".repeat(box_width - 2);
 
@@ -72,10 +105,16 @@ pub fn print_config(
         CHART,
         style(format!("{:.1}%", missing_threshold * 100.0)).yellow()
     );
+    let (label, value) = if mutual_info {
+        ("MI threshold:  ", format!("{}", univariate_threshold))
+    } else {
+        ("Gini threshold:", format!("{:.2}", univariate_threshold))
+    };
     println!(
-        "    │  {} Gini threshold:        {:<24}│",
+        "    │  {} {}        {:<24}│",
         CHART,
-        style(format!("{:.2}", gini_threshold)).yellow()
+        label,
+        style(value).yellow()
     );
     println!(
         "    │  {} Correlation threshold: {:<24}│",
@@ -88,6 +127,7 @@ pub fn print_config(
 
 /// Print a step header with styling
 pub fn print_step_header(step_num: u8, title: &str) {
+    tracing::info!(target: CONSOLE_TARGET, "Step {}: {}", step_num, title);
     println!();
     println!(
         "    {} {} {}",
@@ -100,6 +140,7 @@ pub fn print_step_header(step_num: u8, title: &str) {
 
 /// Print a success message
 pub fn print_success(message: &str) {
+    tracing::info!(target: CONSOLE_TARGET, "{}", message);
     println!(
         "    {} {}",
         style("✓").green().bold(),
@@ -109,11 +150,13 @@ pub fn print_success(message: &str) {
 
 /// Print an info message
 pub fn print_info(message: &str) {
+    tracing::info!(target: CONSOLE_TARGET, "{}", message);
     println!("    {} {}", INFO, message);
 }
 
 /// Print the final completion message
 pub fn print_completion() {
+    tracing::info!(target: CONSOLE_TARGET, "Lo-phi reduction complete");
     println!();
     println!(
         "    {} {}",
@@ -126,6 +169,7 @@ pub fn print_completion() {
 /// Print a styled count message
 pub fn print_count(description: &str, count: usize, threshold_info: Option<&str>) {
     if let Some(info) = threshold_info {
+        tracing::info!(target: CONSOLE_TARGET, "Found {} {} {}", count, description, info);
         println!(
             "      Found {} {} {}",
             style(count).yellow().bold(),
@@ -133,6 +177,7 @@ pub fn print_count(description: &str, count: usize, threshold_info: Option<&str>
             style(info).dim()
         );
     } else {
+        tracing::info!(target: CONSOLE_TARGET, "Found {} {}", count, description);
         println!(
             "      Found {} {}",
             style(count).yellow().bold(),
@@ -143,6 +188,7 @@ pub fn print_count(description: &str, count: usize, threshold_info: Option<&str>
 
 /// Print step completion time
 pub fn print_step_time(duration: Duration) {
+    tracing::info!(target: CONSOLE_TARGET, "Step took {}", format_duration(duration));
     println!(
         "    {} {}",
         style("⏱").dim(),
@@ -2,7 +2,7 @@
 #![allow(dead_code)]
 
 use polars::prelude::*;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tempfile::TempDir;
 
 /// Create a simple test DataFrame with known characteristics for testing
@@ -93,6 +93,119 @@ pub fn create_temp_parquet(df: &mut DataFrame) -> (TempDir, PathBuf) {
     (temp_dir, parquet_path)
 }
 
+/// A cell of a worksheet written by [`write_xlsx`]
+#[derive(Debug, Clone)]
+pub enum XlsxCell {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Empty,
+}
+
+/// Text cell shorthand for [`write_xlsx`] rows
+pub fn text(value: &str) -> XlsxCell {
+    XlsxCell::Text(value.to_string())
+}
+
+/// Write a minimal .xlsx workbook with one worksheet per `(name, rows)` entry.
+/// Strings are stored inline, so the workbook needs no shared-strings part.
+pub fn write_xlsx(path: &Path, sheets: &[(&str, Vec<Vec<XlsxCell>>)]) {
+    use ::zip::write::SimpleFileOptions;
+    use std::io::Write;
+
+    let column_ref = |i: usize| (b'A' + i as u8) as char;
+    let mut zip = ::zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+    let options = SimpleFileOptions::default();
+    let mut put = |name: &str, content: String| {
+        zip.start_file(name, options).unwrap();
+        zip.write_all(content.as_bytes()).unwrap();
+    };
+
+    let overrides: String = (1..=sheets.len())
+        .map(|i| {
+            format!(
+                r#"<Override PartName="/xl/worksheets/sheet{}.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#,
+                i
+            )
+        })
+        .collect();
+    put(
+        "[Content_Types].xml",
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>{}</Types>"#,
+            overrides
+        ),
+    );
+    put(
+        "_rels/.rels",
+        r#"<?xml version="1.0" encoding="UTF-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#.to_string(),
+    );
+
+    let mut entries = String::new();
+    let mut relationships = String::new();
+    for (i, (name, _)) in sheets.iter().enumerate() {
+        entries.push_str(&format!(
+            r#"<sheet name="{}" sheetId="{}" r:id="rId{}"/>"#,
+            name,
+            i + 1,
+            i + 1
+        ));
+        relationships.push_str(&format!(
+            r#"<Relationship Id="rId{}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet{}.xml"/>"#,
+            i + 1,
+            i + 1
+        ));
+    }
+    put(
+        "xl/workbook.xml",
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?><workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets>{}</sheets></workbook>"#,
+            entries
+        ),
+    );
+    put(
+        "xl/_rels/workbook.xml.rels",
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">{}</Relationships>"#,
+            relationships
+        ),
+    );
+
+    for (i, (_, rows)) in sheets.iter().enumerate() {
+        let mut data = String::new();
+        for (r, row) in rows.iter().enumerate() {
+            data.push_str(&format!(r#"<row r="{}">"#, r + 1));
+            for (c, cell) in row.iter().enumerate() {
+                let at = format!("{}{}", column_ref(c), r + 1);
+                match cell {
+                    XlsxCell::Number(v) => {
+                        data.push_str(&format!(r#"<c r="{}"><v>{}</v></c>"#, at, v))
+                    }
+                    XlsxCell::Text(s) => data.push_str(&format!(
+                        r#"<c r="{}" t="inlineStr"><is><t>{}</t></is></c>"#,
+                        at, s
+                    )),
+                    XlsxCell::Bool(b) => data.push_str(&format!(
+                        r#"<c r="{}" t="b"><v>{}</v></c>"#,
+                        at,
+                        u8::from(*b)
+                    )),
+                    XlsxCell::Empty => {}
+                }
+            }
+            data.push_str("</row>");
+        }
+        put(
+            &format!("xl/worksheets/sheet{}.xml", i + 1),
+            format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>{}</sheetData></worksheet>"#,
+                data
+            ),
+        );
+    }
+    zip.finish().unwrap();
+}
+
 /// Assert that a DataFrame has expected shape
 pub fn assert_shape(df: &DataFrame, expected_rows: usize, expected_cols: usize) {
     let (rows, cols) = df.shape();
@@ -0,0 +1,457 @@
+//! Tests for the apply subcommand: re-applying a saved reduction report
+
+use lophi::pipeline::{
+    analyze_features_iv, apply_saved_reduction, apply_woe_transform, BinningStrategy,
+    CategoricalWoeBin, DatePolicy, FeatureType, IvAnalysis, MissingBin, MissingBinPolicy,
+    SavedReduction, UnivariateMetric, WoeBin,
+};
+use lophi::report::{
+    export_gini_analysis_enhanced, export_gini_analysis_split, export_reduction_report,
+    export_reduction_report_csv, package_reduction_reports, ExportParams, ReductionReportBuilder,
+    ReportBuilderParams, TrainPartitionSettings,
+};
+use polars::prelude::*;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+fn saved(kept: &[&str], dropped: &[&str]) -> SavedReduction {
+    SavedReduction {
+        target_column: "target".to_string(),
+        weight_column: None,
+        split_column: None,
+        date_policy: DatePolicy::Keep,
+        kept_features: kept.iter().map(|s| s.to_string()).collect(),
+        dropped_features: dropped.iter().map(|s| s.to_string()).collect(),
+        iv_analyses: Vec::new(),
+    }
+}
+
+fn woe_bin(lower: f64, upper: f64, woe: f64) -> WoeBin {
+    WoeBin {
+        lower_bound: lower,
+        upper_bound: upper,
+        events: 1.0,
+        non_events: 1.0,
+        woe,
+        iv_contribution: 0.0,
+        count: 2.0,
+        population_pct: 50.0,
+        event_rate: 0.5,
+        chi_square: None,
+        chi_square_p_value: None,
+    }
+}
+
+fn category_bin(category: &str, categories: &[&str], woe: f64) -> CategoricalWoeBin {
+    CategoricalWoeBin {
+        category: category.to_string(),
+        categories: categories.iter().map(|s| s.to_string()).collect(),
+        events: 1.0,
+        non_events: 1.0,
+        woe,
+        iv_contribution: 0.0,
+        count: 2.0,
+        population_pct: 50.0,
+        event_rate: 0.5,
+    }
+}
+
+fn missing_bin(woe: f64) -> MissingBin {
+    MissingBin {
+        events: 1.0,
+        non_events: 1.0,
+        woe,
+        iv_contribution: 0.0,
+        count: 2.0,
+        population_pct: 10.0,
+        event_rate: 0.5,
+        merged_into: None,
+        imputed_value: None,
+    }
+}
+
+fn f64_values(df: &DataFrame, name: &str) -> Vec<Option<f64>> {
+    df.column(name)
+        .unwrap()
+        .f64()
+        .unwrap()
+        .into_iter()
+        .collect()
+}
+
+/// Run the IV stage on a small training set and write a report bundle the way
+/// the pipeline does. Returns the zip path and the analyses.
+fn write_report_bundle(dir: &Path, split_gini: bool) -> (PathBuf, Vec<IvAnalysis>) {
+    let df = df! {
+        "target" => [0i32, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1],
+        "income" => [1.0f64, 8.0, 2.0, 9.0, 3.0, 10.0, 4.0, 11.0, 5.0, 12.0, 6.0, 13.0],
+        "region" => ["n", "s", "n", "s", "n", "s", "n", "s", "e", "s", "n", "e"],
+        "noise" => [1.0f64, 1.0, 2.0, 2.0, 1.0, 1.0, 2.0, 2.0, 1.0, 1.0, 2.0, 2.0],
+    }
+    .unwrap();
+    let weights = vec![1.0; df.height()];
+    let analyses = analyze_features_iv(
+        &df,
+        "target",
+        4,
+        8,
+        None,
+        BinningStrategy::Quantile,
+        Some(1),
+        None,
+        &weights,
+        None,
+        None,
+        None,
+        MissingBinPolicy::Separate,
+        None,
+    )
+    .unwrap();
+
+    let mut builder = ReductionReportBuilder::new(ReportBuilderParams {
+        input_file: "train.csv".to_string(),
+        output_file: "train_reduced.csv".to_string(),
+        target_column: "target".to_string(),
+        weight_column: None,
+        binning_strategy: "quantile".to_string(),
+        num_bins: 4,
+        missing_threshold: 0.3,
+        gini_threshold: 0.05,
+        correlation_threshold: 0.4,
+    });
+    let ratios = vec![
+        ("income".to_string(), 0.0),
+        ("region".to_string(), 0.0),
+        ("noise".to_string(), 0.0),
+    ];
+    builder.set_missing_results(&ratios, &[]);
+    let dropped = vec!["noise".to_string()];
+    builder.set_gini_results(&analyses, &dropped);
+    builder.set_correlation_results(&[], &[]);
+    let report = builder.build();
+
+    let params = ExportParams {
+        input_file: "train.csv",
+        target_column: "target",
+        weight_column: None,
+        binning_strategy: BinningStrategy::Quantile,
+        bin_breaks: None,
+        bins_from: None,
+        num_bins: 4,
+        gini_threshold: 0.05,
+        min_category_samples: 1,
+        cart_min_bin_pct: None,
+        univariate_metric: UnivariateMetric::Iv,
+        merge_insignificant_bins: None,
+        event_labels: None,
+        distributions: None,
+        extremes: None,
+        cv_folds: None,
+        cross_validation: None,
+        segment_column: None,
+        segment_iv: None,
+        special_values: None,
+        missing_bin_policy: MissingBinPolicy::Separate,
+        dictionary: None,
+    };
+    let gini_path = if split_gini {
+        let path = dir.join("train_gini_analysis");
+        export_gini_analysis_split(&analyses, &dropped, &path, &params).unwrap();
+        path
+    } else {
+        let path = dir.join("train_gini_analysis.json");
+        export_gini_analysis_enhanced(&analyses, &dropped, &path, &params).unwrap();
+        path
+    };
+    let report_path = dir.join("train_reduction_report.json");
+    let csv_path = dir.join("train_reduction_report.csv");
+    let zip_path = dir.join("train_reduction_report.zip");
+    export_reduction_report(&report, &report_path).unwrap();
+    export_reduction_report_csv(&report, &csv_path).unwrap();
+    package_reduction_reports(&gini_path, &report_path, &csv_path, &zip_path).unwrap();
+
+    (zip_path, analyses)
+}
+
+#[test]
+fn test_apply_keeps_report_columns_in_input_order() {
+    let df = df! {
+        "id" => [1i64, 2, 3],
+        "c" => [1.0f64, 2.0, 3.0],
+        "target" => [0i32, 1, 0],
+        "b" => [1.0f64, 2.0, 3.0],
+        "a" => [1.0f64, 2.0, 3.0],
+    }
+    .unwrap();
+
+    let (reduced, summary) =
+        apply_saved_reduction(&df, &saved(&["a", "c"], &["b"]), false).unwrap();
+
+    let columns: Vec<&str> = reduced
+        .get_column_names()
+        .iter()
+        .map(|c| c.as_str())
+        .collect();
+    assert_eq!(columns, vec!["c", "target", "a"]);
+    assert_eq!(summary.input_columns, 5);
+    assert_eq!(summary.dropped, vec!["b".to_string()]);
+    assert_eq!(summary.removed_unknown, vec!["id".to_string()]);
+    assert!(summary.woe_encoded.is_empty());
+}
+
+#[test]
+fn test_apply_without_target_column() {
+    // Scoring data usually has no target; the kept features are still produced
+    let df = df! {
+        "a" => [1.0f64, 2.0],
+        "b" => [3.0f64, 4.0],
+    }
+    .unwrap();
+
+    let (reduced, _) = apply_saved_reduction(&df, &saved(&["a"], &["b"]), false).unwrap();
+    assert_shape(&reduced, 2, 1);
+    assert_has_columns(&reduced, &["a"]);
+}
+
+#[test]
+fn test_apply_keeps_weight_column() {
+    let df = df! {
+        "a" => [1.0f64, 2.0],
+        "w" => [1.0f64, 2.0],
+    }
+    .unwrap();
+    let mut spec = saved(&["a"], &[]);
+    spec.weight_column = Some("w".to_string());
+
+    let (reduced, summary) = apply_saved_reduction(&df, &spec, false).unwrap();
+    assert_has_columns(&reduced, &["a", "w"]);
+    assert!(summary.removed_unknown.is_empty());
+}
+
+#[test]
+fn test_apply_keeps_split_column_from_report() {
+    let dir = TempDir::new().unwrap();
+    let mut builder = ReductionReportBuilder::new(ReportBuilderParams {
+        input_file: "train.csv".to_string(),
+        output_file: "train_reduced.csv".to_string(),
+        target_column: "target".to_string(),
+        weight_column: None,
+        binning_strategy: "cart".to_string(),
+        num_bins: 10,
+        missing_threshold: 0.3,
+        gini_threshold: 0.05,
+        correlation_threshold: 0.4,
+    });
+    builder.set_missing_results(
+        &[("a".to_string(), 0.0), ("b".to_string(), 0.9)],
+        &["b".to_string()],
+    );
+    builder.set_train_partition(TrainPartitionSettings {
+        split_column: "sample".to_string(),
+        train_value: "TRAIN".to_string(),
+        train_rows: 8,
+        total_rows: 10,
+    });
+    let report_path = dir.path().join("train_reduction_report.json");
+    export_reduction_report(&builder.build(), &report_path).unwrap();
+
+    let spec = SavedReduction::load(&report_path, None).unwrap();
+    assert_eq!(spec.split_column.as_deref(), Some("sample"));
+
+    let df = df! {
+        "a" => [1.0f64, 2.0],
+        "b" => [1.0f64, 2.0],
+        "sample" => ["TRAIN", "TEST"],
+    }
+    .unwrap();
+    let (reduced, summary) = apply_saved_reduction(&df, &spec, false).unwrap();
+    assert_has_columns(&reduced, &["a", "sample"]);
+    assert_eq!(summary.dropped, vec!["b".to_string()]);
+    assert!(summary.removed_unknown.is_empty());
+}
+
+#[test]
+fn test_apply_missing_kept_feature_is_error() {
+    let df = df! { "a" => [1.0f64, 2.0] }.unwrap();
+
+    let err = apply_saved_reduction(&df, &saved(&["a", "gone"], &[]), false).unwrap_err();
+    assert!(err.to_string().contains("gone"));
+}
+
+#[test]
+fn test_apply_woe_without_bins_is_error() {
+    let df = df! { "a" => [1.0f64, 2.0] }.unwrap();
+
+    let err = apply_saved_reduction(&df, &saved(&["a"], &[]), true).unwrap_err();
+    assert!(err.to_string().contains("Gini analysis"));
+}
+
+#[test]
+fn test_woe_transform_maps_bins_categories_and_missing() {
+    let analyses = vec![
+        IvAnalysis {
+            feature_name: "num".to_string(),
+            feature_type: FeatureType::Numeric,
+            bins: vec![
+                woe_bin(f64::NEG_INFINITY, 10.0, -0.5),
+                woe_bin(10.0, f64::INFINITY, 0.7),
+            ],
+            categories: vec![],
+            missing_bin: Some(missing_bin(0.2)),
+            iv: 0.1,
+            gini: 0.1,
+            mutual_info: None,
+            monotonicity: None,
+            solver_status: None,
+            special_bins: Vec::new(),
+            ks: 0.0,
+            auc: 0.5,
+        },
+        IvAnalysis {
+            feature_name: "cat".to_string(),
+            feature_type: FeatureType::Categorical,
+            bins: vec![],
+            categories: vec![
+                category_bin("a", &[], 0.3),
+                category_bin("", &["b", "c"], -0.4),
+                category_bin("OTHER", &[], 0.9),
+            ],
+            missing_bin: None,
+            iv: 0.1,
+            gini: 0.1,
+            mutual_info: None,
+            monotonicity: None,
+            solver_status: None,
+            special_bins: Vec::new(),
+            ks: 0.0,
+            auc: 0.5,
+        },
+    ];
+    let mut df = df! {
+        "num" => [Some(1.0f64), Some(10.0), None, Some(f64::NAN), Some(1e9)],
+        "cat" => [Some("a"), Some("b"), Some("c"), Some("unseen"), None],
+        "untouched" => [1i32, 2, 3, 4, 5],
+    }
+    .unwrap();
+
+    let encoded = apply_woe_transform(&mut df, &analyses).unwrap();
+
+    assert_eq!(encoded, vec!["num".to_string(), "cat".to_string()]);
+    assert_eq!(
+        f64_values(&df, "num"),
+        vec![Some(-0.5), Some(0.7), Some(0.2), Some(0.2), Some(0.7)]
+    );
+    // Unseen categories use the OTHER bin; nulls without a missing bin are neutral
+    assert_eq!(
+        f64_values(&df, "cat"),
+        vec![Some(0.3), Some(-0.4), Some(-0.4), Some(0.9), Some(0.0)]
+    );
+    assert_eq!(df.column("untouched").unwrap().dtype(), &DataType::Int32);
+}
+
+#[test]
+fn test_iv_analysis_json_round_trip_restores_infinite_bounds() {
+    let analysis = IvAnalysis {
+        feature_name: "num".to_string(),
+        feature_type: FeatureType::Numeric,
+        bins: vec![
+            woe_bin(f64::NEG_INFINITY, 3.5, -0.1),
+            woe_bin(3.5, f64::INFINITY, 0.1),
+        ],
+        categories: vec![],
+        missing_bin: None,
+        iv: 0.02,
+        gini: 0.05,
+        mutual_info: None,
+        monotonicity: None,
+        solver_status: None,
+        special_bins: Vec::new(),
+        ks: 0.0,
+        auc: 0.5,
+    };
+
+    let json = serde_json::to_string(&analysis).unwrap();
+    let parsed: IvAnalysis = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(parsed.bins[0].lower_bound, f64::NEG_INFINITY);
+    assert_eq!(parsed.bins[0].upper_bound, 3.5);
+    assert_eq!(parsed.bins[1].upper_bound, f64::INFINITY);
+    assert!(parsed.categories.is_empty());
+    assert!(parsed.missing_bin.is_none());
+}
+
+#[test]
+fn test_load_report_bundle_and_apply_woe() {
+    for split_gini in [false, true] {
+        let dir = TempDir::new().unwrap();
+        let (zip_path, analyses) = write_report_bundle(dir.path(), split_gini);
+
+        let saved = SavedReduction::load(&zip_path, None).unwrap();
+        assert_eq!(saved.target_column, "target");
+        assert_eq!(saved.dropped_features, vec!["noise".to_string()]);
+        assert_eq!(
+            saved.kept_features,
+            vec!["income".to_string(), "region".to_string()]
+        );
+        assert_eq!(saved.iv_analyses.len(), analyses.len());
+
+        let scoring = df! {
+            "income" => [2.0f64, 12.0],
+            "region" => ["n", "s"],
+            "noise" => [1.0f64, 2.0],
+        }
+        .unwrap();
+        let (reduced, summary) = apply_saved_reduction(&scoring, &saved, true).unwrap();
+
+        assert_has_columns(&reduced, &["income", "region"]);
+        assert_missing_columns(&reduced, &["noise"]);
+        assert_eq!(summary.woe_encoded.len(), 2);
+
+        // Encoding matches encoding the same rows with the in-memory analyses
+        // (up to JSON float round-tripping)
+        let mut expected = scoring.select(["income", "region"]).unwrap();
+        apply_woe_transform(&mut expected, &analyses).unwrap();
+        for name in ["income", "region"] {
+            let actual = f64_values(&reduced, name);
+            for (a, e) in actual.iter().zip(f64_values(&expected, name)) {
+                assert!(
+                    (a.unwrap() - e.unwrap()).abs() < 1e-12,
+                    "split_gini={} feature={}: {:?} vs {:?}",
+                    split_gini,
+                    name,
+                    a,
+                    e
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_load_bare_report_json_with_gini_analysis() {
+    let dir = TempDir::new().unwrap();
+    let (zip_path, _) = write_report_bundle(dir.path(), false);
+
+    // Unpack the bundle to get the bare JSON files
+    let mut archive = ::zip::ZipArchive::new(std::fs::File::open(&zip_path).unwrap()).unwrap();
+    archive.extract(dir.path()).unwrap();
+    let report_json = dir.path().join("train_reduction_report.json");
+    let gini_json = dir.path().join("train_gini_analysis.json");
+
+    let without_bins = SavedReduction::load(&report_json, None).unwrap();
+    assert_eq!(without_bins.kept_features.len(), 2);
+    assert!(without_bins.iv_analyses.is_empty());
+
+    let with_bins = SavedReduction::load(&report_json, Some(&gini_json)).unwrap();
+    assert_eq!(with_bins.iv_analyses.len(), 3);
+}
+
+#[test]
+fn test_load_unsupported_report_extension_is_error() {
+    assert!(SavedReduction::load(Path::new("report.csv"), None).is_err());
+}
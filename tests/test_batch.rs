@@ -0,0 +1,115 @@
+//! Integration tests for the `batch` subcommand
+
+use std::path::Path;
+
+use assert_cmd::Command;
+use lophi::cli::batch::batch_inputs;
+use polars::prelude::*;
+use tempfile::TempDir;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+/// A folder with two monthly CSV extracts, a note and a hidden file, plus a
+/// config file that turns the solver off
+fn monthly_folder() -> (TempDir, std::path::PathBuf, std::path::PathBuf) {
+    let temp_dir = TempDir::new().unwrap();
+    let input_dir = temp_dir.path().join("monthly");
+    std::fs::create_dir(&input_dir).unwrap();
+    for month in ["2024_01", "2024_02"] {
+        let mut df = create_binary_target_dataframe();
+        write_csv(&input_dir.join(format!("{}.csv", month)), &mut df);
+    }
+    std::fs::write(input_dir.join("notes.txt"), "not a dataset").unwrap();
+    std::fs::write(input_dir.join(".hidden.csv"), "a\n1\n").unwrap();
+
+    let config = temp_dir.path().join("pipeline.toml");
+    std::fs::write(&config, "target = \"target\"\nuse_solver = false\n").unwrap();
+    (temp_dir, input_dir, config)
+}
+
+fn write_csv(path: &Path, df: &mut DataFrame) {
+    let mut file = std::fs::File::create(path).unwrap();
+    CsvWriter::new(&mut file).finish(df).unwrap();
+}
+
+#[test]
+fn test_batch_inputs_skip_other_files() {
+    let (_dir, input_dir, _config) = monthly_folder();
+
+    let files = batch_inputs(&input_dir, None).unwrap();
+    let names: Vec<_> = files
+        .iter()
+        .map(|f| f.file_name().unwrap().to_str().unwrap())
+        .collect();
+    assert_eq!(names, ["2024_01.csv", "2024_02.csv"]);
+
+    let files = batch_inputs(&input_dir, Some("*_02.csv")).unwrap();
+    assert_eq!(files.len(), 1);
+    assert!(batch_inputs(&input_dir, Some("*.parquet")).is_err());
+}
+
+#[test]
+fn test_batch_reduces_every_file_and_writes_summary() {
+    let (dir, input_dir, config) = monthly_folder();
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("batch")
+        .arg("--input-dir")
+        .arg(&input_dir)
+        .arg("--config")
+        .arg(&config)
+        .args(["--jobs", "2"])
+        .assert()
+        .success();
+
+    let output_dir = dir.path().join("monthly_reduced");
+    for month in ["2024_01", "2024_02"] {
+        assert!(output_dir.join(format!("{}_reduced.csv", month)).exists());
+        assert!(output_dir
+            .join(format!("{}_reduction_report.zip", month))
+            .exists());
+        // Reports go to the output folder, not next to the inputs
+        assert!(!input_dir
+            .join(format!("{}_reduction_report.zip", month))
+            .exists());
+    }
+
+    let summary = std::fs::read_to_string(output_dir.join("batch_summary.csv")).unwrap();
+    let mut lines = summary.lines();
+    assert_eq!(
+        lines.next().unwrap(),
+        "feature,2024_01.csv,2024_02.csv,dropped_in"
+    );
+    let rows: Vec<&str> = lines.collect();
+    assert!(rows.contains(&"random_feature,kept,kept,0"));
+    assert!(rows.contains(&"predictive_feature,correlation,correlation,2"));
+}
+
+#[test]
+fn test_batch_continues_past_a_failing_file() {
+    let (dir, input_dir, config) = monthly_folder();
+    let mut no_target = df! { "a" => [1i32, 2, 3] }.unwrap();
+    write_csv(&input_dir.join("2024_03.csv"), &mut no_target);
+    let summary = dir.path().join("summary.csv");
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--config")
+        .arg(&config)
+        .arg("batch")
+        .arg("--input-dir")
+        .arg(&input_dir)
+        .arg("--output-dir")
+        .arg(dir.path().join("out"))
+        .arg("--summary")
+        .arg(&summary)
+        .assert()
+        .failure();
+
+    assert!(dir.path().join("out/2024_02_reduced.csv").exists());
+    let summary = std::fs::read_to_string(&summary).unwrap();
+    assert!(summary.starts_with("feature,2024_01.csv,2024_02.csv,2024_03.csv,dropped_in"));
+    assert!(summary.contains("random_feature,kept,kept,,0"));
+}
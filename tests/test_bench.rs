@@ -0,0 +1,90 @@
+//! Integration tests for the `bench` subcommand
+
+use assert_cmd::Command;
+use lophi::cli::bench::{run_bench, BenchConfig};
+use lophi::pipeline::{BinningBackend, BinningStrategy};
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+#[test]
+fn test_bench_times_every_stage() {
+    let mut df = create_binary_target_dataframe();
+    let (_dir, input) = create_temp_parquet(&mut df);
+
+    let timings = run_bench(&BenchConfig {
+        input,
+        target: "target".to_string(),
+        weight_column: None,
+        runs: 2,
+        strategies: vec![BinningStrategy::Quantile, BinningStrategy::Cart],
+        backends: vec![BinningBackend::Greedy, BinningBackend::Dp],
+        gini_bins: 5,
+        prebins: 10,
+        correlation_threshold: 0.4,
+        infer_schema_length: 10000,
+        sheet: None,
+    })
+    .unwrap();
+
+    let stages: Vec<&str> = timings.iter().map(|t| t.stage.as_str()).collect();
+    assert_eq!(
+        stages,
+        [
+            "load",
+            "missing",
+            "binning quantile/greedy",
+            "binning quantile/dp",
+            "binning cart/greedy",
+            "binning cart/dp",
+            "correlation",
+            "save",
+        ]
+    );
+    for timing in &timings {
+        assert_eq!(timing.runs.len(), 2);
+        assert!(timing.min() <= timing.mean() && timing.mean() <= timing.max());
+    }
+}
+
+#[test]
+fn test_bench_cli_prints_table() {
+    let mut df = create_binary_target_dataframe();
+    let (_dir, input) = create_temp_csv(&mut df);
+
+    let assert = Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("bench")
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target", "--runs", "1"])
+        .args(["--strategies", "quantile", "--backends", "greedy"])
+        .assert()
+        .success();
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout);
+    assert!(stdout.contains("binning quantile/greedy"));
+    assert!(stdout.contains("correlation"));
+    assert!(!stdout.contains("binning cart"));
+}
+
+#[test]
+fn test_bench_rejects_unknown_target_and_strategy() {
+    let mut df = create_binary_target_dataframe();
+    let (_dir, input) = create_temp_csv(&mut df);
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("bench")
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "missing_target", "--runs", "1"])
+        .assert()
+        .code(2);
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("bench")
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target", "--strategies", "manual"])
+        .assert()
+        .code(2);
+}
@@ -0,0 +1,191 @@
+//! Integration tests for equal-width and manual binning (`--binning-strategy
+//! equal_width|manual`, `--bin-breaks`)
+
+use assert_cmd::Command;
+use lophi::pipeline::{analyze_features_iv, BinBreaks, BinningStrategy, MissingBinPolicy};
+use polars::prelude::{df, DataFrame};
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 500 rows; `age` runs 18..=67 and the event rate falls with it
+fn age_df() -> DataFrame {
+    let n = 500;
+    df! {
+        "target" => (0..n).map(|i| i32::from((i * 7) % 10 < 5 - (i % 50) / 12)).collect::<Vec<_>>(),
+        "age" => (0..n).map(|i| 18.0 + (i % 50) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn lophi(dir: &std::path::Path, input: &std::path::Path, args: &[&str]) -> Command {
+    let config = dir.join("pipeline.toml");
+    std::fs::write(&config, "use_solver = false\n").unwrap();
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("--config")
+        .arg(&config)
+        .arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--gini-threshold", "0.0"])
+        .args(args);
+    cmd
+}
+
+fn read_gini_export(dir: &std::path::Path) -> serde_json::Value {
+    use std::io::Read;
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_gini_analysis.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+/// Upper bounds of the numeric bins of `age`; the open last bound is null
+fn age_upper_bounds(export: &serde_json::Value) -> Vec<Option<f64>> {
+    let age = export["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["feature_name"] == "age")
+        .expect("no entry for age");
+    age["bins"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|b| b["upper_bound"].as_f64())
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_manual_breaks_become_the_bin_bounds() {
+    let mut df = age_df();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    lophi(
+        dir.path(),
+        &input,
+        &[
+            "--binning-strategy",
+            "manual",
+            "--bin-breaks",
+            "age:25,35,50",
+        ],
+    )
+    .assert()
+    .success();
+
+    let export = read_gini_export(dir.path());
+    assert_eq!(export["metadata"]["binning_strategy"], "manual");
+    assert_eq!(
+        export["metadata"]["bin_breaks"]["per_feature"]["age"],
+        serde_json::json!([25.0, 35.0, 50.0])
+    );
+    assert_eq!(
+        age_upper_bounds(&export),
+        [Some(25.0), Some(35.0), Some(50.0), None]
+    );
+}
+
+#[test]
+fn test_equal_width_bins_split_the_range_evenly() {
+    let mut df = age_df();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    lophi(
+        dir.path(),
+        &input,
+        &[
+            "--binning-strategy",
+            "equal_width",
+            "--prebins",
+            "7",
+            "--gini-bins",
+            "7",
+        ],
+    )
+    .assert()
+    .success();
+
+    let export = read_gini_export(dir.path());
+    assert_eq!(export["metadata"]["binning_strategy"], "equal_width");
+    assert!(export["metadata"].get("bin_breaks").is_none());
+    // 18..=67 in 7 bands of width 7
+    assert_eq!(
+        age_upper_bounds(&export),
+        [
+            Some(25.0),
+            Some(32.0),
+            Some(39.0),
+            Some(46.0),
+            Some(53.0),
+            Some(60.0),
+            None
+        ]
+    );
+}
+
+#[test]
+fn test_merging_manual_bands_keeps_the_breakpoints() {
+    let df = age_df();
+    let weights = vec![1.0; df.height()];
+    let breaks = BinBreaks::parse(&["20,25,30,35,40,45,50,55,60"]).unwrap();
+
+    let analyses = analyze_features_iv(
+        &df,
+        "target",
+        3,
+        20,
+        None,
+        BinningStrategy::Manual,
+        None,
+        None,
+        &weights,
+        None,
+        None,
+        None,
+        MissingBinPolicy::Separate,
+        Some(&breaks),
+    )
+    .unwrap();
+
+    let bins = &analyses[0].bins;
+    assert!(bins.len() <= 3);
+    for bin in &bins[..bins.len() - 1] {
+        assert!(breaks.global.contains(&bin.upper_bound));
+    }
+}
+
+#[test]
+fn test_manual_binning_requires_breaks() {
+    let mut df = age_df();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    lophi(dir.path(), &input, &["--binning-strategy", "manual"])
+        .assert()
+        .failure();
+    lophi(dir.path(), &input, &["--bin-breaks", "25,35"])
+        .assert()
+        .failure();
+    lophi(
+        dir.path(),
+        &input,
+        &["--binning-strategy", "manual", "--bin-breaks", "age:25,old"],
+    )
+    .assert()
+    .failure();
+}
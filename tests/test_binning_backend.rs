@@ -0,0 +1,169 @@
+//! Integration tests for the binning backends (`--binning-backend mip|dp|greedy`)
+//!
+//! The dp backend needs no solver, so these run in builds without the
+//! `solver` feature as well.
+
+use assert_cmd::Command;
+use lophi::pipeline::{
+    analyze_features_iv, BinningBackend, BinningStrategy, MissingBinPolicy, MonotonicityConstraint,
+    SolverConfig, SolverStatus,
+};
+use polars::prelude::{df, DataFrame};
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 500 rows; `age` runs 18..=67 and the event rate falls with it, with a
+/// bump in the late thirties
+fn age_df() -> DataFrame {
+    let n = 500;
+    df! {
+        "target" => (0..n)
+            .map(|i| {
+                let age = i % 50;
+                let cutoff = if (18..22).contains(&age) { 6 } else { 5 - age / 12 };
+                i32::from((i * 7) % 10 < cutoff)
+            })
+            .collect::<Vec<_>>(),
+        "age" => (0..n).map(|i| 18.0 + (i % 50) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn dp_config(monotonicity: MonotonicityConstraint) -> SolverConfig {
+    SolverConfig {
+        backend: BinningBackend::Dp,
+        monotonicity,
+        ..SolverConfig::default()
+    }
+}
+
+fn analyze(df: &DataFrame, solver_config: Option<&SolverConfig>) -> Vec<f64> {
+    let weights = vec![1.0; df.height()];
+    let analyses = analyze_features_iv(
+        df,
+        "target",
+        4,
+        20,
+        None,
+        BinningStrategy::Quantile,
+        None,
+        None,
+        &weights,
+        None,
+        solver_config,
+        None,
+        MissingBinPolicy::Separate,
+        None,
+    )
+    .unwrap();
+    if solver_config.is_some() {
+        assert_eq!(analyses[0].solver_status, Some(SolverStatus::Optimal));
+    }
+    analyses[0].bins.iter().map(|b| b.woe).collect()
+}
+
+fn lophi(dir: &std::path::Path, input: &std::path::Path, args: &[&str]) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--gini-threshold", "0.0", "--gini-bins", "4"])
+        .arg("--output")
+        .arg(dir.join("reduced.csv"))
+        .args(args);
+    cmd
+}
+
+fn read_gini_export(dir: &std::path::Path) -> serde_json::Value {
+    use std::io::Read;
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_gini_analysis.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+fn age_analysis(export: &serde_json::Value) -> &serde_json::Value {
+    export["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["feature_name"] == "age")
+        .expect("no entry for age")
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_dp_backend_applies_monotonicity() {
+    let df = age_df();
+
+    let woes = analyze(&df, Some(&dp_config(MonotonicityConstraint::Descending)));
+    assert!(woes.len() <= 4);
+    assert!(woes.windows(2).all(|w| w[0] >= w[1]));
+
+    // Without the constraint the bump in the late thirties gets a bin of its own
+    let woes = analyze(&df, Some(&dp_config(MonotonicityConstraint::None)));
+    assert!(woes.windows(2).any(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_dp_backend_cli_records_solver_status() {
+    let mut df = age_df();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    lophi(
+        dir.path(),
+        &input,
+        &["--binning-backend", "dp", "--monotonicity", "descending"],
+    )
+    .assert()
+    .success();
+
+    let export = read_gini_export(dir.path());
+    let age = age_analysis(&export);
+    assert_eq!(age["solver_status"]["status"], "optimal");
+    let woes: Vec<f64> = age["bins"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|b| b["woe"].as_f64().unwrap())
+        .collect();
+    assert!(woes.windows(2).all(|w| w[0] >= w[1]));
+}
+
+#[test]
+fn test_greedy_backend_skips_the_solver() {
+    let mut df = age_df();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    lophi(dir.path(), &input, &["--binning-backend", "greedy"])
+        .assert()
+        .success();
+
+    let export = read_gini_export(dir.path());
+    assert!(age_analysis(&export).get("solver_status").is_none());
+}
+
+#[test]
+fn test_unknown_backend_is_rejected() {
+    let mut df = age_df();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    lophi(dir.path(), &input, &["--binning-backend", "cplex"])
+        .assert()
+        .failure();
+}
@@ -0,0 +1,184 @@
+//! Integration tests for `--bins-from`
+
+use std::io::Read;
+
+use assert_cmd::Command;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 400 rows with a 25% event rate. `age` (with nulls) and `region` separate
+/// the classes, `noise` is unrelated to the target.
+fn bins_df() -> DataFrame {
+    let n = 400;
+    let event = |i: usize| i.is_multiple_of(4);
+    df! {
+        "target" => (0..n).map(|i| i32::from(event(i))).collect::<Vec<_>>(),
+        "age" => (0..n)
+            .map(|i| (i % 10 != 1).then(|| 20.0 + ((i / 4) % 40) as f64 + if event(i) { 0.0 } else { 5.0 }))
+            .collect::<Vec<_>>(),
+        "region" => (0..n)
+            .map(|i| if event(i) == (i % 3 == 0) { "north" } else { "south" })
+            .collect::<Vec<_>>(),
+        "noise" => (0..n).map(|i| ((i / 4) % 7) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn read_gini_export(dir: &std::path::Path) -> serde_json::Value {
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut entry = archive.by_name("test_data_gini_analysis.json").unwrap();
+    let mut content = String::new();
+    entry.read_to_string(&mut content).unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+fn feature<'a>(export: &'a serde_json::Value, name: &str) -> Option<&'a serde_json::Value> {
+    export["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["feature_name"] == name)
+}
+
+fn lophi(input: &std::path::Path) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--no-confirm"]);
+    cmd
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_bins_from_a_gini_export_reproduce_its_iv() {
+    let mut df = bins_df();
+    let (first_dir, first_input) = create_temp_parquet(&mut df);
+    lophi(&first_input).assert().success();
+    let first = read_gini_export(first_dir.path());
+
+    let bins_file = first_dir.path().join("bins.json");
+    std::fs::write(&bins_file, first.to_string()).unwrap();
+    let (dir, input) = create_temp_parquet(&mut df);
+    lophi(&input)
+        .arg("--bins-from")
+        .arg(&bins_file)
+        .assert()
+        .success();
+    let second = read_gini_export(dir.path());
+
+    assert_eq!(
+        second["metadata"]["bins_from"],
+        bins_file.display().to_string()
+    );
+    for name in ["age", "region", "noise"] {
+        let (a, b) = (
+            feature(&first, name).unwrap(),
+            feature(&second, name).unwrap(),
+        );
+        assert!((a["iv"].as_f64().unwrap() - b["iv"].as_f64().unwrap()).abs() < 1e-12);
+        assert!((a["gini"].as_f64().unwrap() - b["gini"].as_f64().unwrap()).abs() < 1e-12);
+        assert_eq!(
+            a["bins"].as_array().map(Vec::len),
+            b["bins"].as_array().map(Vec::len)
+        );
+    }
+}
+
+#[test]
+fn test_bins_from_a_hand_written_spec() {
+    let mut df = bins_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    let bins_file = dir.path().join("bins.json");
+    std::fs::write(
+        &bins_file,
+        r#"{
+            "features": [
+                {
+                    "feature": "age",
+                    "feature_type": "Numeric",
+                    "bins": [
+                        { "lower_bound": null, "upper_bound": 30.0 },
+                        { "lower_bound": 30.0, "upper_bound": 45.0 },
+                        { "lower_bound": 45.0, "upper_bound": null }
+                    ]
+                },
+                {
+                    "feature": "region",
+                    "feature_type": "Categorical",
+                    "categories": [{ "values": ["north"] }]
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+    lophi(&input)
+        .arg("--bins-from")
+        .arg(&bins_file)
+        .args(["--gini-threshold", "0.05"])
+        .assert()
+        .success();
+
+    let export = read_gini_export(dir.path());
+    let age = feature(&export, "age").unwrap();
+    let upper: Vec<Option<f64>> = age["bins"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|b| b["upper_bound"].as_f64())
+        .collect();
+    assert_eq!(upper, [Some(30.0), Some(45.0), None]);
+    assert!(age["missing_bin"]["count"].as_f64().unwrap() > 0.0);
+    // Values outside every group form an OTHER group
+    let region = feature(&export, "region").unwrap();
+    assert_eq!(region["categories"][1]["category"], "OTHER");
+    // Without bins `noise` is not scored, and so not dropped
+    assert!(feature(&export, "noise").is_none());
+    let output = ParquetReader::new(
+        std::fs::File::open(dir.path().join("test_data_reduced.parquet")).unwrap(),
+    )
+    .finish()
+    .unwrap();
+    assert!(output.column("noise").is_ok());
+}
+
+#[test]
+fn test_bins_from_rejects_binning_options() {
+    let mut df = bins_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    let bins_file = dir.path().join("bins.json");
+    std::fs::write(&bins_file, r#"{"features": []}"#).unwrap();
+
+    lophi(&input)
+        .arg("--bins-from")
+        .arg(&bins_file)
+        .args(["--binning-strategy", "manual", "--bin-breaks", "age:30"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "--bin-breaks cannot be used with --bins-from",
+        ));
+    lophi(&input)
+        .arg("--bins-from")
+        .arg(&bins_file)
+        .args(["--target-type", "continuous"])
+        .assert()
+        .failure();
+    lophi(&input)
+        .arg("--bins-from")
+        .arg(dir.path().join("missing.json"))
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("Failed to read bins file"));
+}
@@ -0,0 +1,253 @@
+//! Tests for CART prebinning tree diagnostics (--export-cart-trees)
+
+use std::io::Read;
+
+use assert_cmd::Command;
+use lophi::pipeline::{
+    analyze_features_iv, fit_cart_trees, BinningStrategy, CartStopReason, CartTreeNode,
+    MissingBinPolicy,
+};
+use lophi::Reducer;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+/// 200 rows: the event rate of `score` steps up at 50, 100 and 150, `flat` is
+/// constant and `sparse` is mostly missing
+fn stepped_df() -> DataFrame {
+    let n = 200;
+    let score: Vec<f64> = (0..n).map(|i| i as f64).collect();
+    // Event every 10th, 4th, 2nd and 4-of-5 rows across the four quarters
+    let target: Vec<i32> = (0..n)
+        .map(|i| {
+            let hit = match i / 50 {
+                0 => i % 10 == 0,
+                1 => i % 4 == 0,
+                2 => i % 2 == 0,
+                _ => i % 5 != 0,
+            };
+            i32::from(hit)
+        })
+        .collect();
+    let flat = vec![1.0f64; n];
+    let sparse: Vec<Option<f64>> = (0..n).map(|i| (i % 40 == 0).then_some(i as f64)).collect();
+
+    df! {
+        "target" => target,
+        "score" => score,
+        "flat" => flat,
+        "sparse" => sparse,
+        "label" => vec!["a"; n],
+    }
+    .unwrap()
+}
+
+fn walk<'a>(node: &'a CartTreeNode, out: &mut Vec<&'a CartTreeNode>) {
+    out.push(node);
+    if let Some(split) = &node.split {
+        walk(&split.left, out);
+        walk(&split.right, out);
+    }
+}
+
+#[test]
+fn test_tree_splits_match_cart_prebins() {
+    let df = stepped_df();
+    let weights = vec![1.0; df.height()];
+    let trees = fit_cart_trees(&df, "target", 8, None, Some(5.0), &weights, None).unwrap();
+
+    // Without merging (num_bins == prebins, no solver) the IV bins are the prebins
+    let analyses = analyze_features_iv(
+        &df,
+        "target",
+        8,
+        8,
+        None,
+        BinningStrategy::Cart,
+        None,
+        Some(5.0),
+        &weights,
+        None,
+        None,
+        None,
+        MissingBinPolicy::Separate,
+        None,
+    )
+    .unwrap();
+    let score_iv = analyses.iter().find(|a| a.feature_name == "score").unwrap();
+    let bin_bounds: Vec<f64> = score_iv.bins[..score_iv.bins.len() - 1]
+        .iter()
+        .map(|b| b.upper_bound)
+        .collect();
+
+    let score = trees.iter().find(|t| t.feature_name == "score").unwrap();
+    assert_eq!(score.min_bin_samples, 10);
+    assert_eq!(score.splits, bin_bounds);
+    assert_eq!(score.leaves, score_iv.bins.len());
+    assert!(score.splits.windows(2).all(|w| w[0] < w[1]));
+    assert!(score.leaves <= 8);
+}
+
+#[test]
+fn test_tree_nodes_partition_their_parent() {
+    let df = stepped_df();
+    let trees = fit_cart_trees(&df, "target", 8, None, Some(5.0), &[1.0; 200], None).unwrap();
+    let score = trees.iter().find(|t| t.feature_name == "score").unwrap();
+
+    assert_eq!(score.root.depth, 0);
+    assert_eq!(score.root.count, 200);
+    assert_eq!(score.root.min_value, 0.0);
+    assert_eq!(score.root.max_value, 199.0);
+
+    let mut nodes = Vec::new();
+    walk(&score.root, &mut nodes);
+    for node in nodes {
+        assert!(node.count >= score.min_bin_samples);
+        match &node.split {
+            Some(split) => {
+                assert!(node.stop_reason.is_none());
+                assert!(split.gain > 0.0);
+                assert_eq!(split.left.count + split.right.count, node.count);
+                assert_eq!(split.left.events + split.right.events, node.events);
+                assert!(split.left.max_value < split.threshold);
+                assert_eq!(split.right.min_value, split.threshold);
+                assert_eq!(split.left.depth, node.depth + 1);
+            }
+            None => assert!(node.stop_reason.is_some()),
+        }
+    }
+}
+
+#[test]
+fn test_tree_stop_reasons() {
+    let df = stepped_df();
+    let weights = vec![1.0; df.height()];
+
+    // One prebin: no split budget at the root
+    let trees = fit_cart_trees(&df, "target", 1, None, None, &weights, None).unwrap();
+    let score = trees.iter().find(|t| t.feature_name == "score").unwrap();
+    assert!(score.splits.is_empty());
+    assert_eq!(
+        score.root.stop_reason,
+        Some(CartStopReason::SplitBudgetSpent)
+    );
+
+    // Minimum bin of 60% of rows: the root cannot hold two bins
+    let trees = fit_cart_trees(&df, "target", 8, None, Some(60.0), &weights, None).unwrap();
+    let score = trees.iter().find(|t| t.feature_name == "score").unwrap();
+    assert_eq!(score.root.stop_reason, Some(CartStopReason::TooFewSamples));
+
+    // A constant feature has no value to split between
+    let trees = fit_cart_trees(&df, "target", 8, None, None, &weights, None).unwrap();
+    let flat = trees.iter().find(|t| t.feature_name == "flat").unwrap();
+    assert_eq!(
+        flat.root.stop_reason,
+        Some(CartStopReason::NoImprovingSplit)
+    );
+    assert_eq!(flat.leaves, 1);
+}
+
+#[test]
+fn test_trees_cover_numeric_features_with_enough_values() {
+    let df = stepped_df();
+    let trees = fit_cart_trees(&df, "target", 8, None, None, &[1.0; 200], None).unwrap();
+    let names: Vec<&str> = trees.iter().map(|t| t.feature_name.as_str()).collect();
+
+    // `sparse` has 5 non-missing values, `label` is categorical
+    assert_eq!(names, vec!["score", "flat"]);
+}
+
+#[test]
+fn test_tree_uses_weights_for_event_counts() {
+    let df = stepped_df();
+    let weights: Vec<f64> = (0..200).map(|i| if i < 100 { 2.0 } else { 1.0 }).collect();
+    let trees = fit_cart_trees(&df, "target", 4, None, None, &weights, None).unwrap();
+    let root = &trees
+        .iter()
+        .find(|t| t.feature_name == "score")
+        .unwrap()
+        .root;
+
+    // Counts stay raw while events are weighted
+    assert_eq!(root.count, 200);
+    assert_eq!(root.events + root.non_events, 300.0);
+}
+
+#[test]
+fn test_reducer_returns_cart_trees_on_request() {
+    let result = Reducer::from_dataframe(stepped_df(), "target")
+        .prebins(8)
+        .run()
+        .unwrap();
+    assert!(result.cart_trees.is_empty());
+
+    let result = Reducer::from_dataframe(stepped_df(), "target")
+        .prebins(8)
+        .cart_trees(true)
+        .run()
+        .unwrap();
+    assert!(result.cart_trees.iter().any(|t| t.feature_name == "score"));
+
+    // Quantile prebinning has no tree
+    let result = Reducer::from_dataframe(stepped_df(), "target")
+        .binning_strategy(BinningStrategy::Quantile)
+        .cart_trees(true)
+        .run()
+        .unwrap();
+    assert!(result.cart_trees.is_empty());
+}
+
+#[test]
+fn test_cli_packages_cart_trees_in_report_zip() {
+    let mut df = stepped_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    let config = dir.path().join("pipeline.toml");
+    std::fs::write(&config, "use_solver = false\n").unwrap();
+
+    let zip_entries = |extra: &[&str]| -> Vec<String> {
+        Command::new(env!("CARGO_BIN_EXE_lophi"))
+            .arg("--config")
+            .arg(&config)
+            .arg("--input")
+            .arg(&input)
+            .args(["--target", "target", "--no-confirm"])
+            .args(extra)
+            .assert()
+            .success();
+        let file = std::fs::File::open(dir.path().join("test_data_reduction_report.zip")).unwrap();
+        let archive = ::zip::ZipArchive::new(file).unwrap();
+        archive.file_names().map(str::to_string).collect()
+    };
+
+    assert!(!zip_entries(&[])
+        .iter()
+        .any(|n| n == "test_data_cart_trees.json"));
+    assert!(zip_entries(&["--export-cart-trees"])
+        .iter()
+        .any(|n| n == "test_data_cart_trees.json"));
+    assert!(!dir.path().join("test_data_cart_trees.json").exists());
+
+    let file = std::fs::File::open(dir.path().join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_cart_trees.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    let export: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(export["prebins"], 20);
+    assert_eq!(export["metadata"]["binning_strategy"], "cart");
+    assert_eq!(export["features"][0]["feature_name"], "score");
+    assert!(export["features"][0]["root"]["split"]["threshold"].is_number());
+
+    // Not produced for quantile binning
+    assert!(
+        !zip_entries(&["--export-cart-trees", "--binning-strategy", "quantile"])
+            .iter()
+            .any(|n| n == "test_data_cart_trees.json")
+    );
+}
@@ -1,9 +1,10 @@
-//! Tests for categorical association measures (Cramér's V, Eta)
+//! Tests for categorical association measures (Cramér's V, Theil's U, Eta)
 //! and IV-first drop logic.
 
 use lophi::pipeline::{
-    compute_cramers_v, compute_eta, find_correlated_pairs_auto, select_features_to_drop,
-    AssociationMeasure, CorrelatedPair, FeatureMetadata,
+    compute_cramers_v, compute_eta, compute_theils_u, find_correlated_pairs_auto,
+    select_features_to_drop, AssociationMeasure, AssociationThresholds, CorrelatedPair,
+    FeatureMetadata,
 };
 use polars::prelude::*;
 use std::collections::HashMap;
@@ -124,6 +125,46 @@ fn test_cramers_v_bias_correction_reduces_inflation() {
     );
 }
 
+// ── Theil's U ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_theils_u_is_asymmetric() {
+    // `fine` determines `coarse`, but not the other way round
+    let fine = Column::new("fine".into(), vec!["a", "b", "c", "d", "a", "b", "c", "d"]);
+    let coarse = Column::new(
+        "coarse".into(),
+        vec!["x", "x", "y", "y", "x", "x", "y", "y"],
+    );
+
+    let coarse_given_fine = compute_theils_u(&coarse, &fine, None).unwrap();
+    let fine_given_coarse = compute_theils_u(&fine, &coarse, None).unwrap();
+    assert!((coarse_given_fine - 1.0).abs() < 1e-10);
+    // ln 2 of the ln 4 bits of `fine` are explained
+    assert!((fine_given_coarse - 0.5).abs() < 1e-10);
+}
+
+#[test]
+fn test_theils_u_independent_is_zero() {
+    let a = Column::new("a".into(), vec!["p", "p", "q", "q"]);
+    let b = Column::new("b".into(), vec!["r", "s", "r", "s"]);
+    let u = compute_theils_u(&a, &b, None).unwrap();
+    assert!(
+        u.abs() < 1e-10,
+        "Independent columns should have U = 0, got {}",
+        u
+    );
+}
+
+#[test]
+fn test_theils_u_respects_weights() {
+    // Zero weight on the rows that break the association
+    let a = Column::new("a".into(), vec!["p", "q", "p", "q"]);
+    let b = Column::new("b".into(), vec!["r", "s", "s", "r"]);
+    let weights = [1.0, 1.0, 0.0, 0.0];
+    let u = compute_theils_u(&a, &b, Some(&weights)).unwrap();
+    assert!((u - 1.0).abs() < 1e-10, "got {}", u);
+}
+
 // ── Eta (Correlation Ratio) ─────────────────────────────────────────────────
 
 #[test]
@@ -237,6 +278,8 @@ fn make_pair(f1: &str, f2: &str, corr: f64, measure: AssociationMeasure) -> Corr
         feature2: f2.to_string(),
         correlation: corr,
         measure,
+        theils_u: None,
+        rationale: None,
     }
 }
 
@@ -250,6 +293,7 @@ fn test_drop_lower_iv() {
         FeatureMetadata {
             iv: Some(0.50),
             missing_ratio: Some(0.0),
+            r_squared: None,
         },
     );
     metadata.insert(
@@ -257,6 +301,7 @@ fn test_drop_lower_iv() {
         FeatureMetadata {
             iv: Some(0.10),
             missing_ratio: Some(0.0),
+            r_squared: None,
         },
     );
 
@@ -270,6 +315,32 @@ fn test_drop_lower_iv() {
     );
 }
 
+#[test]
+fn test_drop_lower_r_squared_when_iv_unavailable() {
+    let pairs = vec![make_pair("a", "b", 0.95, AssociationMeasure::Pearson)];
+
+    let mut metadata = HashMap::new();
+    for (name, r2) in [("a", 0.12), ("b", 0.40)] {
+        metadata.insert(
+            name.to_string(),
+            FeatureMetadata {
+                iv: None,
+                missing_ratio: Some(0.0),
+                r_squared: Some(r2),
+            },
+        );
+    }
+
+    let drops = select_features_to_drop(&pairs, "target", Some(&metadata));
+    assert_eq!(drops.len(), 1);
+    assert_eq!(drops[0].feature, "a", "Should drop feature with lower R²");
+    assert!(
+        drops[0].reason.contains("lower R²"),
+        "Reason should mention R²: {}",
+        drops[0].reason
+    );
+}
+
 #[test]
 fn test_drop_equal_iv_uses_frequency() {
     // Both have equal IV, but "a" appears in 2 pairs -> should be dropped
@@ -285,6 +356,7 @@ fn test_drop_equal_iv_uses_frequency() {
             FeatureMetadata {
                 iv: Some(0.30),
                 missing_ratio: Some(0.0),
+                r_squared: None,
             },
         );
     }
@@ -312,6 +384,7 @@ fn test_drop_equal_iv_equal_freq_uses_missing_ratio() {
         FeatureMetadata {
             iv: Some(0.30),
             missing_ratio: Some(0.10),
+            r_squared: None,
         },
     );
     metadata.insert(
@@ -319,6 +392,7 @@ fn test_drop_equal_iv_equal_freq_uses_missing_ratio() {
         FeatureMetadata {
             iv: Some(0.30),
             missing_ratio: Some(0.05),
+            r_squared: None,
         },
     );
 
@@ -346,6 +420,7 @@ fn test_drop_full_tie_uses_alphabetical() {
             FeatureMetadata {
                 iv: Some(0.30),
                 missing_ratio: Some(0.05),
+                r_squared: None,
             },
         );
     }
@@ -504,6 +579,85 @@ fn test_auto_labels_eta_correctly() {
     );
 }
 
+#[test]
+fn test_auto_cramers_v_threshold_is_separate() {
+    let n = 60;
+    let num_a: Vec<f64> = (0..n).map(|i| i as f64).collect();
+    let num_b: Vec<f64> = num_a.iter().map(|x| x * 3.0).collect();
+    let cat_a: Vec<String> = (0..n).map(|i| format!("c{}", i % 3)).collect();
+    // Agrees with cat_a except on every fifth row
+    let cat_b: Vec<String> = (0..n)
+        .map(|i| format!("c{}", if i % 5 == 0 { (i + 1) % 3 } else { i % 3 }))
+        .collect();
+    let df = DataFrame::new(vec![
+        Column::new("num_a".into(), num_a),
+        Column::new("num_b".into(), num_b),
+        Column::new("cat_a".into(), cat_a),
+        Column::new("cat_b".into(), cat_b),
+    ])
+    .unwrap();
+    let weights = vec![1.0; n];
+
+    let v = compute_cramers_v(
+        df.column("cat_a").unwrap(),
+        df.column("cat_b").unwrap(),
+        None,
+    )
+    .unwrap();
+    assert!(v > 0.5 && v < 0.95, "got {}", v);
+
+    let thresholds = AssociationThresholds::new(0.5, Some(0.95));
+    let pairs = find_correlated_pairs_auto(&df, thresholds, &weights, None, None).unwrap();
+    assert!(pairs
+        .iter()
+        .any(|p| p.measure == AssociationMeasure::Pearson));
+    assert!(!pairs
+        .iter()
+        .any(|p| p.measure == AssociationMeasure::CramersV));
+
+    // And a strict Pearson threshold leaves the categorical pair in
+    let thresholds = AssociationThresholds::new(1.0, Some(0.5));
+    let pairs = find_correlated_pairs_auto(&df, thresholds, &weights, None, None).unwrap();
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0].measure, AssociationMeasure::CramersV);
+}
+
+#[test]
+fn test_auto_cramers_v_pairs_carry_theils_u() {
+    let n = 40;
+    let fine: Vec<String> = (0..n).map(|i| format!("f{}", i % 4)).collect();
+    let coarse: Vec<String> = (0..n)
+        .map(|i| if i % 4 < 2 { "low" } else { "high" }.to_string())
+        .collect();
+    let num: Vec<f64> = (0..n).map(|i| (i % 4) as f64).collect();
+    let df = DataFrame::new(vec![
+        Column::new("coarse".into(), coarse),
+        Column::new("fine".into(), fine),
+        Column::new("num".into(), num),
+    ])
+    .unwrap();
+    let weights = vec![1.0; n];
+
+    let pairs = find_correlated_pairs_auto(&df, 0.3, &weights, None, None).unwrap();
+    let cat_pair = pairs
+        .iter()
+        .find(|p| p.measure == AssociationMeasure::CramersV)
+        .expect("coarse/fine pair");
+    let (u1, u2) = cat_pair.theils_u.expect("Theil's U for a CramersV pair");
+    let (coarse_u, fine_u) = if cat_pair.feature1 == "coarse" {
+        (u1, u2)
+    } else {
+        (u2, u1)
+    };
+    assert!((coarse_u - 1.0).abs() < 1e-10);
+    assert!((fine_u - 0.5).abs() < 1e-10);
+
+    assert!(pairs
+        .iter()
+        .filter(|p| p.measure != AssociationMeasure::CramersV)
+        .all(|p| p.theils_u.is_none()));
+}
+
 // ── High-Cardinality Exclusion ──────────────────────────────────────────────
 
 #[test]
@@ -716,8 +870,8 @@ fn test_cramers_v_2x2_known_value() {
     let col_a = Column::new("a".into(), a_vals);
     let col_b = Column::new("b".into(), b_vals);
 
-    let v = compute_cramers_v(&col_a, &col_b, None)
-        .expect("Should return Some for a valid 2×2 table");
+    let v =
+        compute_cramers_v(&col_a, &col_b, None).expect("Should return Some for a valid 2×2 table");
 
     let expected = 0.4902_f64;
     assert!(
@@ -735,7 +889,10 @@ fn test_eta_known_reference_value() {
     //   group "b": [5, 5, 5]
     //   group "c": [10, 10, 10]
     // All variance is between-group; Eta should be exactly 1.0.
-    let cat = Column::new("cat".into(), vec!["a", "a", "a", "b", "b", "b", "c", "c", "c"]);
+    let cat = Column::new(
+        "cat".into(),
+        vec!["a", "a", "a", "b", "b", "b", "c", "c", "c"],
+    );
     let num = Column::new(
         "num".into(),
         vec![1.0f64, 1.0, 1.0, 5.0, 5.0, 5.0, 10.0, 10.0, 10.0],
@@ -826,10 +983,7 @@ fn test_high_cardinality_boundary_exactly_100() {
 fn test_eta_non_uniform_weights_change_result() {
     // Construct data where heavily-weighting the "separation" rows should
     // increase Eta compared to uniform weights.
-    let cat = Column::new(
-        "cat".into(),
-        vec!["a", "a", "a", "a", "b", "b", "b", "b"],
-    );
+    let cat = Column::new("cat".into(), vec!["a", "a", "a", "a", "b", "b", "b", "b"]);
     // Group "a" ~ 1.0, group "b" ~ 10.0 (well separated).
     // Noise rows are at positions 3 and 7 — they reduce separation.
     let num = Column::new(
@@ -872,6 +1026,7 @@ fn test_drop_logic_partial_metadata() {
         FeatureMetadata {
             iv: Some(0.80),
             missing_ratio: Some(0.0),
+            r_squared: None,
         },
     );
     // "b" intentionally absent from metadata.
@@ -892,7 +1047,12 @@ fn test_drop_logic_partial_metadata() {
 fn test_drop_no_metadata_equal_frequency_uses_alphabetical() {
     // No metadata provided; each feature appears in exactly one pair (freq=1 tie).
     // Must use alphabetical fallback: keep the lexicographically first, drop the latter.
-    let pairs = vec![make_pair("zebra", "apple", 0.91, AssociationMeasure::Pearson)];
+    let pairs = vec![make_pair(
+        "zebra",
+        "apple",
+        0.91,
+        AssociationMeasure::Pearson,
+    )];
 
     let drops = select_features_to_drop(&pairs, "target", None);
     assert_eq!(drops.len(), 1);
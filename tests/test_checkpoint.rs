@@ -0,0 +1,234 @@
+//! Tests for stage checkpoints, --resume and --dry-run
+
+use std::io::Read;
+
+use assert_cmd::Command;
+use lophi::pipeline::{
+    checkpoint_dir, Checkpoint, CheckpointStage, GiniCheckpoint, MissingCheckpoint,
+};
+use lophi::Reducer;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+fn rising_df() -> DataFrame {
+    let n = 300;
+    let x: Vec<f64> = (0..n).map(|i| i as f64).collect();
+    let noise: Vec<f64> = (0..n).map(|i| ((i * 37) % 11) as f64).collect();
+    let target: Vec<i32> = (0..n).map(|i| i32::from(i % 3 == 0 || i > 200)).collect();
+    df! { "target" => target, "x" => x, "noise" => noise }.unwrap()
+}
+
+#[test]
+fn test_checkpoint_round_trips_stages() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let input = dir.path().join("data.parquet");
+
+    let result = Reducer::from_dataframe(rising_df(), "target")
+        .gini_threshold(0.0)
+        .run()
+        .unwrap();
+    let checkpoint = Checkpoint::open(&input, "settings-a", false).unwrap();
+    assert!(!checkpoint.resumed());
+    checkpoint
+        .save(
+            CheckpointStage::Missing,
+            &MissingCheckpoint {
+                missing_ratios: result.missing_ratios.clone(),
+                dropped: vec!["noise".to_string()],
+                segments: None,
+            },
+        )
+        .unwrap();
+    checkpoint
+        .save(
+            CheckpointStage::Gini,
+            &GiniCheckpoint {
+                iv_analyses: result.iv_analyses.clone(),
+                r2_analyses: Vec::new(),
+                concordance: result.concordance.clone(),
+                dropped: Vec::new(),
+                cross_validation: Vec::new(),
+                segment_iv: None,
+            },
+        )
+        .unwrap();
+    // Nothing is loaded from a checkpoint opened without --resume
+    assert!(checkpoint
+        .load::<MissingCheckpoint>(CheckpointStage::Missing)
+        .is_none());
+
+    let resumed = Checkpoint::open(&input, "settings-a", true).unwrap();
+    assert!(resumed.resumed());
+    let missing: MissingCheckpoint = resumed.load(CheckpointStage::Missing).unwrap();
+    assert_eq!(missing.dropped, vec!["noise".to_string()]);
+    let gini: GiniCheckpoint = resumed.load(CheckpointStage::Gini).unwrap();
+    assert_eq!(gini.iv_analyses.len(), result.iv_analyses.len());
+    for (loaded, original) in gini.iv_analyses.iter().zip(&result.iv_analyses) {
+        assert_eq!(loaded.feature_name, original.feature_name);
+        assert_eq!(loaded.iv, original.iv);
+        // Open-ended bounds survive JSON's lack of infinities
+        let bounds = |a: &lophi::pipeline::IvAnalysis| {
+            a.bins
+                .iter()
+                .map(|b| (b.lower_bound, b.upper_bound))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(bounds(loaded), bounds(original));
+    }
+    assert!(resumed
+        .load::<GiniCheckpoint>(CheckpointStage::Correlation)
+        .is_none());
+
+    resumed.remove().unwrap();
+    assert!(!checkpoint_dir(&input).exists());
+    assert!(!dir.path().join(".lophi_checkpoint").exists());
+}
+
+#[test]
+fn test_checkpoint_with_other_settings_is_discarded() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let input = dir.path().join("data.csv");
+
+    let checkpoint = Checkpoint::open(&input, "settings-a", false).unwrap();
+    checkpoint
+        .save(
+            CheckpointStage::Missing,
+            &MissingCheckpoint {
+                missing_ratios: Vec::new(),
+                dropped: Vec::new(),
+                segments: None,
+            },
+        )
+        .unwrap();
+
+    let other = Checkpoint::open(&input, "settings-b", true).unwrap();
+    assert!(!other.resumed());
+    assert!(other
+        .load::<MissingCheckpoint>(CheckpointStage::Missing)
+        .is_none());
+    // Opening under the new settings cleared the old stages
+    let again = Checkpoint::open(&input, "settings-b", true).unwrap();
+    assert!(again.resumed());
+    assert!(again
+        .load::<MissingCheckpoint>(CheckpointStage::Missing)
+        .is_none());
+}
+
+#[test]
+fn test_cli_resume_loads_finished_stages() {
+    let mut df = rising_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    let config = dir.path().join("pipeline.toml");
+    std::fs::write(
+        &config,
+        "use_solver = false\ngini_threshold = 0.0\ncorrelation_threshold = 0.99\n",
+    )
+    .unwrap();
+    let checkpoint = dir.path().join(".lophi_checkpoint").join("test_data");
+
+    let run = |output: &std::path::Path, extra: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_lophi"))
+            .arg("--config")
+            .arg(&config)
+            .arg("--input")
+            .arg(&input)
+            .arg("--output")
+            .arg(output)
+            .args(["--target", "target", "--no-confirm"])
+            .args(extra)
+            .assert()
+    };
+
+    // Saving fails after every analysis stage has finished
+    run(&dir.path().join("missing_dir").join("out.csv"), &[]).failure();
+    for stage in ["missing.json", "gini.json", "correlation.json"] {
+        assert!(checkpoint.join(stage).exists(), "{} not saved", stage);
+    }
+
+    // Mark `noise` as a missing-value drop in the saved stage, and drop the
+    // later stages so they are recomputed without it
+    let missing_path = checkpoint.join("missing.json");
+    let mut missing: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&missing_path).unwrap()).unwrap();
+    missing["dropped"] = serde_json::json!(["noise"]);
+    std::fs::write(&missing_path, missing.to_string()).unwrap();
+    std::fs::remove_file(checkpoint.join("gini.json")).unwrap();
+    std::fs::remove_file(checkpoint.join("correlation.json")).unwrap();
+
+    let output = dir.path().join("out.csv");
+    run(&output, &["--resume"]).success();
+    assert!(!dir.path().join(".lophi_checkpoint").exists());
+
+    let reduced = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(output))
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert!(reduced.column("noise").is_err());
+    assert!(
+        reduced.column("x").is_ok(),
+        "{:?}",
+        reduced.get_column_names()
+    );
+
+    let file = std::fs::File::open(dir.path().join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_reduction_report.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    let report: serde_json::Value = serde_json::from_str(&content).unwrap();
+    let noise = report["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["name"] == "noise")
+        .unwrap()
+        .clone();
+    assert_eq!(noise["dropped_at_stage"], "missing");
+}
+
+#[test]
+fn test_cli_dry_run_writes_reports_and_resumes_into_full_run() {
+    let mut df = rising_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    let config = dir.path().join("pipeline.toml");
+    std::fs::write(
+        &config,
+        "use_solver = false\ngini_threshold = 0.0\ncorrelation_threshold = 0.99\n",
+    )
+    .unwrap();
+    let output = dir.path().join("out.csv");
+    let checkpoint = dir.path().join(".lophi_checkpoint").join("test_data");
+
+    let run = |extra: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_lophi"))
+            .arg("--config")
+            .arg(&config)
+            .arg("--input")
+            .arg(&input)
+            .arg("--output")
+            .arg(&output)
+            .args(["--target", "target", "--no-confirm"])
+            .args(extra)
+            .assert()
+    };
+
+    let dry = run(&["--dry-run"]).success();
+    let stdout = String::from_utf8_lossy(&dry.get_output().stdout).to_string();
+    assert!(stdout.contains("CANDIDATE DROPS"), "{}", stdout);
+    assert!(!output.exists());
+    assert!(dir.path().join("test_data_reduction_report.zip").exists());
+    // The stage results stay for a full run to pick up
+    assert!(checkpoint.join("correlation.json").exists());
+
+    run(&["--resume"]).success();
+    assert!(output.exists());
+    assert!(!dir.path().join(".lophi_checkpoint").exists());
+}
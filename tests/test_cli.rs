@@ -1,7 +1,12 @@
 //! Tests for CLI argument parsing
 
 use clap::Parser;
-use lophi::cli::Cli;
+use lophi::cli::{Cli, Commands};
+use lophi::pipeline::{
+    CsvDialect, ParquetCodec, ParquetOptions, ParquetStatistics, SampleSize, DEFAULT_CV_FOLDS,
+    DEFAULT_IV_STABILITY_THRESHOLD,
+};
+use lophi::report::PlotFormat;
 use std::path::PathBuf;
 
 #[test]
@@ -65,6 +70,20 @@ fn test_cli_output_path_derivation_parquet() {
     assert_eq!(output, PathBuf::from("/path/to/data_reduced.parquet"));
 }
 
+#[test]
+fn test_cli_output_path_derivation_compressed_csv() {
+    // Compressed CSV is reduced to plain CSV, named without both extensions
+    let cli = Cli::parse_from(["lophi", "-i", "/path/to/data.csv.gz", "-t", "target"]);
+    assert_eq!(
+        cli.output_path().unwrap(),
+        PathBuf::from("/path/to/data_reduced.csv")
+    );
+    assert_eq!(
+        cli.gini_analysis_path().unwrap(),
+        PathBuf::from("/path/to/data_gini_analysis.json")
+    );
+}
+
 #[test]
 fn test_cli_explicit_output_path() {
     let cli = Cli::parse_from([
@@ -73,49 +92,808 @@ fn test_cli_explicit_output_path() {
         "data.csv",
         "-t",
         "target",
-        "-o",
-        "custom_output.parquet",
+        "-o",
+        "custom_output.parquet",
+    ]);
+
+    let output = cli.output_path().unwrap();
+    assert_eq!(output, PathBuf::from("custom_output.parquet"));
+}
+
+#[test]
+fn test_cli_drop_columns() {
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--drop-columns",
+        "id,timestamp,uuid",
+    ]);
+
+    assert_eq!(cli.drop_columns, vec!["id", "timestamp", "uuid"]);
+}
+
+#[test]
+fn test_cli_single_drop_column() {
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--drop-columns",
+        "id",
+    ]);
+
+    assert_eq!(cli.drop_columns, vec!["id"]);
+}
+
+#[test]
+fn test_cli_no_drop_columns() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+
+    assert!(cli.drop_columns.is_empty());
+}
+
+#[test]
+fn test_cli_split_column() {
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--split-column",
+        "sample",
+        "--train-value",
+        "TRAIN",
+    ]);
+
+    assert_eq!(cli.split_column.as_deref(), Some("sample"));
+    assert_eq!(cli.train_value.as_deref(), Some("TRAIN"));
+
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(cli.split_column.is_none());
+    assert!(cli.train_value.is_none());
+}
+
+#[test]
+fn test_cli_output_include_target_mapping_flag() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(!cli.output_include_target_mapping);
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--output-include-target-mapping",
+    ]);
+    assert!(cli.output_include_target_mapping);
+}
+
+#[test]
+fn test_cli_export_woe_flag() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target", "--export-woe"]);
+    assert!(cli.export_woe);
+
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(!cli.export_woe);
+}
+
+#[test]
+fn test_cli_export_cart_trees_flag() {
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--export-cart-trees",
+    ]);
+    assert!(cli.export_cart_trees);
+
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(!cli.export_cart_trees);
+}
+
+#[test]
+fn test_cli_dominance_threshold() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(cli.dominance_threshold.is_none());
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--dominance-threshold",
+        "0.995",
+    ]);
+    assert_eq!(cli.dominance_threshold, Some(0.995));
+
+    let result = Cli::try_parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--dominance-threshold",
+        "1.5",
+    ]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_cli_id_threshold() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(cli.id_threshold.is_none());
+    assert_eq!(cli.id_action, "flag");
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--id-threshold",
+        "0.9",
+        "--id-action",
+        "drop",
+    ]);
+    assert_eq!(cli.id_threshold, Some(0.9));
+    assert_eq!(cli.id_action, "drop");
+}
+
+#[test]
+fn test_cli_date_policy() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert_eq!(cli.date_policy, "keep");
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--date-policy",
+        "derive",
+    ]);
+    assert_eq!(cli.date_policy, "derive");
+}
+
+#[test]
+fn test_cli_quiet_and_solver_fallback() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(!cli.quiet);
+    assert!(!cli.fail_on_solver_fallback);
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "-q",
+        "--fail-on-solver-fallback",
+    ]);
+    assert!(cli.quiet);
+    assert!(cli.fail_on_solver_fallback);
+}
+
+#[test]
+fn test_cli_json_output() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(cli.json_output.is_none());
+
+    // Without a path the events go to stdout
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target", "--json-output"]);
+    assert_eq!(cli.json_output, Some(PathBuf::from("-")));
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--json-output",
+        "run.jsonl",
+    ]);
+    assert_eq!(cli.json_output, Some(PathBuf::from("run.jsonl")));
+}
+
+#[test]
+fn test_cli_verbosity_and_log_file() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert_eq!(cli.verbose, 0);
+    assert!(cli.log_file.is_none());
+
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target", "-v"]);
+    assert_eq!(cli.verbose, 1);
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "-vv",
+        "--log-file",
+        "run.log",
+    ]);
+    assert_eq!(cli.verbose, 2);
+    assert_eq!(cli.log_file, Some(PathBuf::from("run.log")));
+}
+
+#[test]
+fn test_cli_cramers_v_threshold() {
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--cramers-v-threshold",
+        "0.6",
+    ]);
+    assert_eq!(cli.cramers_v_threshold, Some(0.6));
+
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(cli.cramers_v_threshold.is_none());
+
+    let result = Cli::try_parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--cramers-v-threshold",
+        "1.5",
+    ]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_cli_univariate_metric() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert_eq!(cli.univariate_metric, "iv");
+    // MI has its own threshold, on the nats scale
+    assert_eq!(cli.mi_threshold, 0.001);
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--univariate-metric",
+        "mutual_info",
+        "--mi-threshold",
+        "0.01",
+    ]);
+    assert_eq!(cli.univariate_metric, "mutual_info");
+    assert_eq!(cli.mi_threshold, 0.01);
+}
+
+#[test]
+fn test_cli_resume_flag() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(!cli.resume);
+
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target", "--resume"]);
+    assert!(cli.resume);
+}
+
+#[test]
+fn test_cli_export_distributions_flag() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(!cli.export_distributions);
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--export-distributions",
+    ]);
+    assert!(cli.export_distributions);
+}
+
+#[test]
+fn test_cli_export_table_paths() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    let exports = cli.table_exports();
+    assert!(exports.missing_ratios.is_none());
+    assert!(exports.iv_table.is_none());
+    assert!(exports.correlated_pairs.is_none());
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--export-missing-ratios",
+        "missing.csv",
+        "--export-iv-table",
+        "out/iv.feather",
+        "--export-correlated-pairs",
+        "pairs.arrow",
+    ]);
+    let exports = cli.table_exports();
+    assert_eq!(exports.missing_ratios, Some(PathBuf::from("missing.csv")));
+    assert_eq!(exports.iv_table, Some(PathBuf::from("out/iv.feather")));
+    assert_eq!(exports.correlated_pairs, Some(PathBuf::from("pairs.arrow")));
+
+    let result = Cli::try_parse_from(["lophi", "-i", "data.csv", "--export-iv-table", "iv.xlsx"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_cli_correlation_strategy() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert_eq!(cli.correlation_strategy, "pairwise");
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--correlation-strategy",
+        "cluster",
+    ]);
+    assert_eq!(cli.correlation_strategy, "cluster");
+}
+
+#[test]
+fn test_cli_corr_keep_policy() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert_eq!(cli.corr_keep_policy, "higher_iv");
+    assert!(cli.corr_keep_prefixes.is_empty());
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--corr-keep-policy",
+        "custom_list",
+        "--corr-keep-prefixes",
+        "bureau_,internal_",
+    ]);
+    assert_eq!(cli.corr_keep_policy, "custom_list");
+    assert_eq!(cli.corr_keep_prefixes, ["bureau_", "internal_"]);
+}
+
+#[test]
+fn test_cli_review_drops_flag() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(!cli.review_drops);
+
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target", "--review-drops"]);
+    assert!(cli.review_drops);
+}
+
+#[test]
+fn test_cli_browse_results_flag() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(!cli.browse_results);
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--browse-results",
+    ]);
+    assert!(cli.browse_results);
+}
+
+#[test]
+fn test_cli_expect_schema_flags() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(cli.expect_schema.is_none());
+    assert!(!cli.fail_on_schema_drift);
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--expect-schema",
+        "schema.json",
+        "--fail-on-schema-drift",
+    ]);
+    assert_eq!(cli.expect_schema, Some(PathBuf::from("schema.json")));
+    assert!(cli.fail_on_schema_drift);
+}
+
+#[test]
+fn test_cli_max_memory_gb_flag() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(cli.max_memory_gb.is_none());
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--max-memory-gb",
+        "12.5",
+    ]);
+    assert_eq!(cli.max_memory_gb, Some(12.5));
+}
+
+#[test]
+fn test_cli_select_columns_flag() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(cli.select_columns.is_empty());
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--select-columns",
+        "income,score",
+        "--drop-columns",
+        "score",
     ]);
+    assert_eq!(cli.select_columns, ["income", "score"]);
+    assert_eq!(cli.drop_columns, ["score"]);
+}
 
-    let output = cli.output_path().unwrap();
-    assert_eq!(output, PathBuf::from("custom_output.parquet"));
+#[test]
+fn test_cli_seed_flag() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert_eq!(cli.seed, 42);
+    assert!(cli.sample_seed.is_none());
+
+    // The row sample follows --seed unless --sample-seed is given
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--seed",
+        "7",
+        "--sample-rows",
+        "100",
+    ]);
+    assert_eq!(cli.seed, 7);
+    assert_eq!(cli.screening_sample().unwrap().unwrap().seed, 7);
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--seed",
+        "7",
+        "--sample-rows",
+        "100",
+        "--sample-seed",
+        "9",
+    ]);
+    assert_eq!(cli.screening_sample().unwrap().unwrap().seed, 9);
 }
 
 #[test]
-fn test_cli_drop_columns() {
+fn test_cli_sample_flags() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(cli.screening_sample().unwrap().is_none());
+
     let cli = Cli::parse_from([
         "lophi",
         "-i",
         "data.csv",
         "-t",
         "target",
-        "--drop-columns",
-        "id,timestamp,uuid",
+        "--sample-frac",
+        "0.1",
+        "--sample-stratify",
     ]);
+    let sample = cli.screening_sample().unwrap().unwrap();
+    assert_eq!(sample.size, SampleSize::Fraction(0.1));
+    assert!(sample.stratify);
+    assert_eq!(sample.seed, 42);
 
-    assert_eq!(cli.drop_columns, vec!["id", "timestamp", "uuid"]);
+    assert!(Cli::try_parse_from(["lophi", "-i", "d.csv", "--sample-frac", "1.5"]).is_err());
+    assert!(Cli::try_parse_from([
+        "lophi",
+        "-i",
+        "d.csv",
+        "--sample-rows",
+        "10",
+        "--sample-frac",
+        "0.5"
+    ])
+    .is_err());
 }
 
 #[test]
-fn test_cli_single_drop_column() {
+fn test_cli_iv_stability_flags() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(cli.iv_stability_check().is_none());
+
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "--iv-stability"]);
+    let check = cli.iv_stability_check().unwrap();
+    assert_eq!(check.validation_frac, 0.3);
+    assert_eq!(check.drop_threshold, None);
+    assert_eq!(check.threshold(), DEFAULT_IV_STABILITY_THRESHOLD);
+
+    // A drop threshold turns the check on by itself
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "--iv-stability-threshold",
+        "0.3",
+        "--validation-frac",
+        "0.2",
+    ]);
+    let check = cli.iv_stability_check().unwrap();
+    assert_eq!(check.validation_frac, 0.2);
+    assert_eq!(check.threshold(), 0.3);
+
+    assert!(Cli::try_parse_from(["lophi", "-i", "d.csv", "--validation-frac", "1.0"]).is_err());
+    assert!(
+        Cli::try_parse_from(["lophi", "-i", "d.csv", "--iv-stability-threshold", "2"]).is_err()
+    );
+}
+
+#[test]
+fn test_cli_cross_validation_flags() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(cli.cross_validation().is_none());
+
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "--cv-folds", "3"]);
+    let cv = cli.cross_validation().unwrap();
+    assert_eq!(cv.folds, 3);
+    assert_eq!(cv.min_iv, None);
+
+    // A minimum IV turns cross-validation on with the default folds
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "--cv-min-iv", "0.02"]);
+    let cv = cli.cross_validation().unwrap();
+    assert_eq!(cv.folds, DEFAULT_CV_FOLDS);
+    assert_eq!(cv.min_iv, Some(0.02));
+
+    assert!(Cli::try_parse_from(["lophi", "-i", "d.csv", "--cv-folds", "1"]).is_err());
+}
+
+#[test]
+fn test_cli_special_values_flag() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(cli.special_values().unwrap().is_none());
+
+    // Negative codes are values, not flags
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "--special-values",
+        "-999997,-999998",
+        "--special-values",
+        "age:-1",
+    ]);
+    let special = cli.special_values().unwrap().unwrap();
+    assert_eq!(special.global, [-999997.0, -999998.0]);
+    assert_eq!(special.for_feature("age"), [-999997.0, -999998.0, -1.0]);
+
+    let cli = Cli::parse_from(["lophi", "-i", "d.csv", "--special-values", "n/a"]);
+    assert!(cli.special_values().is_err());
+}
+
+#[test]
+fn test_cli_bin_share_limits() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv"]);
+    assert_eq!(cli.min_bin_pct, 0.0);
+    assert_eq!(cli.max_bin_pct, 100.0);
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "--min-bin-pct",
+        "5",
+        "--max-bin-pct",
+        "35.5",
+    ]);
+    assert_eq!(cli.min_bin_pct, 5.0);
+    assert_eq!(cli.max_bin_pct, 35.5);
+
+    assert!(Cli::try_parse_from(["lophi", "-i", "d.csv", "--min-bin-pct", "-1"]).is_err());
+    assert!(Cli::try_parse_from(["lophi", "-i", "d.csv", "--max-bin-pct", "101"]).is_err());
+}
+
+#[test]
+fn test_cli_min_woe_delta() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv"]);
+    assert_eq!(cli.min_woe_delta, 0.0);
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "--min-woe-delta", "0.1"]);
+    assert_eq!(cli.min_woe_delta, 0.1);
+    assert!(Cli::try_parse_from(["lophi", "-i", "d.csv", "--min-woe-delta", "-0.1"]).is_err());
+}
+
+#[test]
+fn test_cli_binning_backend() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv"]);
+    assert_eq!(cli.binning_backend, "mip");
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "--binning-backend", "dp"]);
+    assert_eq!(cli.binning_backend, "dp");
+}
+
+#[test]
+fn test_cli_bin_breaks_flag() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv"]);
+    assert!(cli.bin_breaks().unwrap().is_none());
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "--binning-strategy",
+        "manual",
+        "--bin-breaks",
+        "-1,0,1",
+        "--bin-breaks",
+        "age:25,35,50",
+    ]);
+    let breaks = cli.bin_breaks().unwrap().unwrap();
+    assert_eq!(breaks.global, [-1.0, 0.0, 1.0]);
+    assert_eq!(breaks.per_feature["age"], [25.0, 35.0, 50.0]);
+
+    // Breaks and the manual strategy go together
+    let cli = Cli::parse_from(["lophi", "-i", "d.csv", "--binning-strategy", "manual"]);
+    assert!(cli.bin_breaks().is_err());
+    let cli = Cli::parse_from(["lophi", "-i", "d.csv", "--bin-breaks", "1,2"]);
+    assert!(cli.bin_breaks().is_err());
+}
+
+#[test]
+fn test_cli_missing_bin_policy() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert_eq!(cli.missing_bin_policy, "separate");
+
     let cli = Cli::parse_from([
         "lophi",
         "-i",
         "data.csv",
+        "--missing-bin-policy",
+        "merge_worst",
+    ]);
+    assert_eq!(cli.missing_bin_policy, "merge_worst");
+}
+
+#[test]
+fn test_cli_sheet_flag() {
+    let cli = Cli::parse_from(["lophi", "-i", "book.xlsx", "-t", "target"]);
+    assert!(cli.sheet.is_none());
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "book.xlsx",
         "-t",
         "target",
-        "--drop-columns",
-        "id",
+        "--sheet",
+        "Applications",
     ]);
+    assert_eq!(cli.sheet.as_deref(), Some("Applications"));
+}
 
-    assert_eq!(cli.drop_columns, vec!["id"]);
+#[test]
+fn test_cli_keep_columns() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(cli.keep_columns.is_empty());
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--keep-columns",
+        "bureau_score,age",
+    ]);
+    assert_eq!(cli.keep_columns, vec!["bureau_score", "age"]);
 }
 
 #[test]
-fn test_cli_no_drop_columns() {
+fn test_cli_dry_run_flag() {
     let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(!cli.dry_run);
 
-    assert!(cli.drop_columns.is_empty());
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target", "--dry-run"]);
+    assert!(cli.dry_run);
+}
+
+#[test]
+fn test_cli_event_labels() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(cli.event_label.is_none());
+    assert!(cli.non_event_label.is_none());
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--event-label",
+        "default",
+        "--non-event-label",
+        "good",
+    ]);
+    assert_eq!(cli.event_label.as_deref(), Some("default"));
+    assert_eq!(cli.non_event_label.as_deref(), Some("good"));
+}
+
+#[test]
+fn test_cli_merge_insignificant_bins() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(cli.merge_insignificant_bins.is_none());
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--merge-insignificant-bins",
+        "0.05",
+    ]);
+    assert_eq!(cli.merge_insignificant_bins, Some(0.05));
+
+    let result = Cli::try_parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--merge-insignificant-bins",
+        "5",
+    ]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_cli_fast_flag() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target", "--fast"]);
+    assert!(cli.fast);
+
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(!cli.fast);
+}
+
+#[test]
+fn test_cli_target_type() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert_eq!(cli.target_type, "binary");
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--target-type",
+        "continuous",
+    ]);
+    assert_eq!(cli.target_type, "continuous");
 }
 
 #[test]
@@ -374,3 +1152,263 @@ fn test_cli_only_mode_with_all_flags() {
     assert_eq!(cli.correlation_threshold, 0.7);
     assert_eq!(cli.gini_threshold, 0.06);
 }
+
+#[test]
+fn test_apply_subcommand_parsing() {
+    let cli = Cli::parse_from([
+        "lophi",
+        "apply",
+        "--report",
+        "train_reduction_report.zip",
+        "-i",
+        "score.parquet",
+        "--woe",
+    ]);
+
+    match cli.command {
+        Some(Commands::Apply {
+            report,
+            input,
+            output,
+            woe,
+            gini_analysis,
+            infer_schema_length,
+        }) => {
+            assert_eq!(report, PathBuf::from("train_reduction_report.zip"));
+            assert_eq!(input, PathBuf::from("score.parquet"));
+            assert!(output.is_none());
+            assert!(woe);
+            assert!(gini_analysis.is_none());
+            assert_eq!(infer_schema_length, 10000);
+        }
+        other => panic!("expected Apply, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_bench_subcommand_parsing() {
+    let cli = Cli::parse_from([
+        "lophi",
+        "bench",
+        "--input",
+        "data.parquet",
+        "-t",
+        "target",
+        "--runs",
+        "5",
+        "--backends",
+        "greedy",
+    ]);
+
+    match cli.command {
+        Some(Commands::Bench {
+            input,
+            target,
+            runs,
+            strategies,
+            backends,
+            correlation_threshold,
+            ..
+        }) => {
+            assert_eq!(input, PathBuf::from("data.parquet"));
+            assert_eq!(target, "target");
+            assert_eq!(runs, 5);
+            assert_eq!(strategies, "quantile,cart");
+            assert_eq!(backends, "greedy");
+            assert_eq!(correlation_threshold, 0.40);
+        }
+        other => panic!("expected Bench, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_tighten_subcommand_parsing() {
+    let cli = Cli::parse_from([
+        "lophi",
+        "tighten",
+        "--report",
+        "train_reduction_report.zip",
+        "-i",
+        "train_reduced.parquet",
+        "--gini-threshold",
+        "0.1",
+    ]);
+
+    match cli.command {
+        Some(Commands::Tighten {
+            report,
+            input,
+            output,
+            missing_threshold,
+            gini_threshold,
+            infer_schema_length,
+        }) => {
+            assert_eq!(report, PathBuf::from("train_reduction_report.zip"));
+            assert_eq!(input, PathBuf::from("train_reduced.parquet"));
+            assert!(output.is_none());
+            assert!(missing_threshold.is_none());
+            assert_eq!(gini_threshold, Some(0.1));
+            assert_eq!(infer_schema_length, 10000);
+        }
+        other => panic!("expected Tighten, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_validate_report_subcommand_parsing() {
+    let cli = Cli::parse_from(["lophi", "validate-report", "train_reduction_report.json"]);
+
+    match cli.command {
+        Some(Commands::ValidateReport { report }) => {
+            assert_eq!(report, PathBuf::from("train_reduction_report.json"));
+        }
+        other => panic!("expected ValidateReport, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_cli_diff_subcommand() {
+    let cli = Cli::parse_from(["lophi", "diff", "a_report.zip", "b_report.zip"]);
+    match cli.command {
+        Some(Commands::Diff {
+            report_a,
+            report_b,
+            output,
+            top,
+        }) => {
+            assert_eq!(report_a, PathBuf::from("a_report.zip"));
+            assert_eq!(report_b, PathBuf::from("b_report.zip"));
+            assert!(output.is_none());
+            assert_eq!(top, 25);
+        }
+        other => panic!("expected Diff, got {:?}", other),
+    }
+
+    let cli = Cli::parse_from([
+        "lophi", "diff", "a.json", "b.json", "-o", "d.json", "--top", "5",
+    ]);
+    match cli.command {
+        Some(Commands::Diff { output, top, .. }) => {
+            assert_eq!(output, Some(PathBuf::from("d.json")));
+            assert_eq!(top, 5);
+        }
+        other => panic!("expected Diff, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_cli_plots_flags() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert!(!cli.plots);
+    assert!(cli.plot_settings().is_none());
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--plots",
+        "--plots-top",
+        "5",
+        "--plot-format",
+        "png",
+    ]);
+    let settings = cli.plot_settings().unwrap();
+    assert_eq!(settings.top_n, 5);
+    assert_eq!(settings.format, PlotFormat::Png);
+
+    assert!(Cli::try_parse_from(["lophi", "--plot-format", "jpg"]).is_err());
+}
+
+#[test]
+fn test_cli_parquet_flags() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert_eq!(cli.parquet_options(), ParquetOptions::default());
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--parquet-compression",
+        "lz4",
+        "--row-group-size",
+        "50000",
+        "--parquet-statistics",
+        "none",
+    ]);
+    let options = cli.parquet_options();
+    assert_eq!(options.compression, ParquetCodec::Lz4);
+    assert_eq!(options.row_group_size, Some(50_000));
+    assert_eq!(options.statistics, ParquetStatistics::None);
+
+    assert!(Cli::try_parse_from(["lophi", "--parquet-compression", "gzip"]).is_err());
+    assert!(Cli::try_parse_from(["lophi", "--row-group-size", "0"]).is_err());
+    assert!(Cli::try_parse_from(["lophi", "--parquet-statistics", "some"]).is_err());
+}
+
+#[test]
+fn test_convert_parquet_flags() {
+    let cli = Cli::parse_from(["lophi", "convert", "data.csv"]);
+    match cli.command {
+        Some(Commands::Convert {
+            parquet_compression,
+            row_group_size,
+            parquet_statistics,
+            ..
+        }) => {
+            assert_eq!(parquet_compression, "snappy");
+            assert_eq!(row_group_size, 100_000);
+            assert_eq!(parquet_statistics, "full");
+        }
+        _ => panic!("Expected Convert command"),
+    }
+
+    assert!(Cli::try_parse_from([
+        "lophi",
+        "convert",
+        "data.csv",
+        "--parquet-compression",
+        "brotli"
+    ])
+    .is_err());
+}
+
+#[test]
+fn test_cli_csv_dialect_flags() {
+    let cli = Cli::parse_from(["lophi", "-i", "data.csv", "-t", "target"]);
+    assert_eq!(cli.csv_dialect().unwrap(), CsvDialect::default());
+
+    let cli = Cli::parse_from([
+        "lophi",
+        "-i",
+        "data.csv",
+        "-t",
+        "target",
+        "--csv-separator",
+        "tab",
+        "--csv-quote",
+        "none",
+        "--csv-decimal-comma",
+        "--csv-date-format",
+        "%d/%m/%Y",
+        "--csv-null",
+        "NA",
+    ]);
+    let dialect = cli.csv_dialect().unwrap();
+    assert_eq!(dialect.separator, b'\t');
+    assert_eq!(dialect.quote_char, None);
+    assert!(dialect.decimal_comma);
+    assert_eq!(dialect.date_format.as_deref(), Some("%d/%m/%Y"));
+    assert_eq!(dialect.null_value.as_deref(), Some("NA"));
+
+    // Decimal commas need a separator other than ','
+    let cli = Cli::parse_from(["lophi", "--csv-decimal-comma"]);
+    assert!(cli.csv_dialect().is_err());
+
+    assert!(Cli::try_parse_from(["lophi", "--csv-separator", ";;"]).is_err());
+    assert!(Cli::try_parse_from(["lophi", "--csv-date-format", "%Q"]).is_err());
+    assert!(Cli::try_parse_from(["lophi", "convert", "in.csv", "--csv-quote", "ab"]).is_err());
+}
@@ -0,0 +1,106 @@
+//! Tests for cloud object storage input and output
+//!
+//! The round trip is ignored by default: it needs a bucket (or an
+//! S3-compatible server such as MinIO or moto) named by `LOPHI_TEST_S3_PREFIX`,
+//! e.g. `LOPHI_TEST_S3_PREFIX=s3://scratch/lophi-test cargo test --features cloud
+//! --test test_cloud -- --ignored`, with the usual `AWS_*` variables for
+//! credentials and endpoint.
+
+#[path = "common/mod.rs"]
+mod common;
+
+use std::path::Path;
+
+use assert_cmd::Command;
+use lophi::pipeline::{is_cloud_uri, output_anchor};
+
+#[test]
+fn test_cloud_uris_are_not_local_globs() {
+    let uri = Path::new("s3://lake/landing/part_*.parquet");
+    assert!(is_cloud_uri(uri));
+    assert!(!lophi::pipeline::is_multi_file_input(uri));
+    // Outputs of a cloud glob are named after its folder, in the bucket
+    assert_eq!(
+        output_anchor(uri),
+        Path::new("s3://lake/landing.parquet").to_path_buf()
+    );
+}
+
+#[cfg(not(feature = "cloud"))]
+#[test]
+fn test_cloud_input_needs_the_cloud_feature() {
+    let output = Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .args(["--input", "s3://lake/data.parquet", "--target", "target"])
+        .arg("--no-confirm")
+        .assert()
+        .code(2)
+        .get_output()
+        .clone();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("enable the `cloud` feature"), "{stderr}");
+}
+
+#[cfg(feature = "cloud")]
+#[test]
+fn test_cloud_input_rejects_unsupported_formats() {
+    let error =
+        lophi::pipeline::get_column_names(Path::new("gs://lake/data.sas7bdat")).unwrap_err();
+    assert!(
+        error.to_string().contains("must be Parquet, Feather"),
+        "{error}"
+    );
+}
+
+#[cfg(feature = "cloud")]
+#[test]
+#[ignore = "needs an S3 bucket or emulator in LOPHI_TEST_S3_PREFIX"]
+fn test_cloud_round_trip() {
+    use lophi::pipeline::{get_column_names, StagedOutput};
+    use polars::prelude::*;
+
+    let prefix = std::env::var("LOPHI_TEST_S3_PREFIX").expect("LOPHI_TEST_S3_PREFIX is not set");
+    let prefix = prefix.trim_end_matches('/');
+    let input = format!("{prefix}/data.parquet");
+
+    let staged = StagedOutput::new(Path::new(&input)).unwrap();
+    let file = std::fs::File::create(staged.path()).unwrap();
+    ParquetWriter::new(file)
+        .finish(&mut common::create_binary_target_dataframe())
+        .unwrap();
+    staged.finish().unwrap();
+
+    let mut command = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    command
+        .args(["--input", &input, "--target", "target", "--no-confirm"])
+        .args(["--output", &format!("{prefix}/out/reduced.csv")])
+        .args(["--gini-threshold", "0", "--binning-backend", "greedy"])
+        .args(["--correlation-threshold", "1"]);
+    if cfg!(feature = "plots") {
+        command.arg("--plots");
+    }
+    command.assert().success();
+
+    let columns = get_column_names(Path::new(&format!("{prefix}/out/reduced.csv"))).unwrap();
+    assert_eq!(
+        columns,
+        vec!["target", "predictive_feature", "random_feature"]
+    );
+    // The reports land next to the input
+    let report = format!("{prefix}/data_reduction_report.zip");
+    assert!(
+        lophi::pipeline::object_metadata(Path::new(&report))
+            .unwrap()
+            .0
+            > 0
+    );
+    // So do the plots, uploaded file by file
+    if cfg!(feature = "plots") {
+        let index = format!("{prefix}/data_plots/index.html");
+        assert!(
+            lophi::pipeline::object_metadata(Path::new(&index))
+                .unwrap()
+                .0
+                > 0
+        );
+    }
+}
@@ -0,0 +1,195 @@
+//! Tests for compressed CSV input (`.csv.gz`, `.csv.zst`, single-file `.zip`)
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+use lophi::cli::convert::run_convert;
+use lophi::pipeline::{get_column_names, load_dataset_with_progress};
+use polars::prelude::*;
+use tempfile::TempDir;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+const ROWS: usize = 60;
+
+/// CSV text with a binary target, a predictive feature and a region
+fn csv_text() -> String {
+    let mut csv = String::from("target,income,region\n");
+    for i in 0..ROWS {
+        let target = i % 3 == 1;
+        let income = if target { 900 + i } else { 100 + i };
+        let region = ["north", "south", "east"][i % 3];
+        csv.push_str(&format!("{},{},{}\n", u8::from(target), income, region));
+    }
+    csv
+}
+
+fn write_gzip(path: &Path, data: &[u8]) {
+    let file = std::fs::File::create(path).unwrap();
+    let mut encoder = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap();
+}
+
+fn write_zip(path: &Path, entries: &[(&str, &[u8])], method: ::zip::CompressionMethod) {
+    let file = std::fs::File::create(path).unwrap();
+    let mut zip = ::zip::ZipWriter::new(file);
+    let options = ::zip::write::SimpleFileOptions::default().compression_method(method);
+    for (name, data) in entries {
+        zip.start_file(*name, options).unwrap();
+        zip.write_all(data).unwrap();
+    }
+    zip.finish().unwrap();
+}
+
+/// The same CSV as plain, gzip, zstd, deflated zip and stored zip files
+fn write_variants(dir: &Path) -> Vec<PathBuf> {
+    let csv = csv_text();
+    let plain = dir.join("plain.csv");
+    std::fs::write(&plain, &csv).unwrap();
+
+    let gz = dir.join("loans.csv.gz");
+    write_gzip(&gz, csv.as_bytes());
+
+    let zst = dir.join("loans.csv.zst");
+    std::fs::write(&zst, zstd::encode_all(csv.as_bytes(), 3).unwrap()).unwrap();
+
+    let zipped = dir.join("deflated.zip");
+    write_zip(
+        &zipped,
+        &[("export/loans.csv", csv.as_bytes())],
+        ::zip::CompressionMethod::Deflated,
+    );
+    let stored = dir.join("stored.zip");
+    write_zip(
+        &stored,
+        &[("loans.csv", csv.as_bytes())],
+        ::zip::CompressionMethod::Stored,
+    );
+
+    vec![plain, gz, zst, zipped, stored]
+}
+
+#[test]
+fn test_compressed_csv_loads_like_plain_csv() {
+    let dir = TempDir::new().unwrap();
+    let paths = write_variants(dir.path());
+    let (expected, _, _, _) = load_dataset_with_progress(&paths[0], 1000).unwrap();
+
+    for path in &paths[1..] {
+        assert_eq!(
+            get_column_names(path).unwrap(),
+            vec!["target", "income", "region"],
+            "{}",
+            path.display()
+        );
+        let (df, rows, cols, _) = load_dataset_with_progress(path, 1000).unwrap();
+        assert_eq!((rows, cols), (ROWS, 3));
+        assert!(df.equals(&expected), "{} differs", path.display());
+    }
+}
+
+#[test]
+fn test_concatenated_gzip_members_are_read() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("parts.csv.gz");
+    let first = dir.path().join("a.gz");
+    let second = dir.path().join("b.gz");
+    write_gzip(&first, b"id,value\n1,10\n");
+    write_gzip(&second, b"2,20\n3,30\n");
+    let mut joined = std::fs::read(&first).unwrap();
+    joined.extend(std::fs::read(&second).unwrap());
+    std::fs::write(&path, joined).unwrap();
+
+    let (_, rows, _, _) = load_dataset_with_progress(&path, 1000).unwrap();
+    assert_eq!(rows, 3);
+}
+
+#[test]
+fn test_zip_must_hold_a_single_csv() {
+    let dir = TempDir::new().unwrap();
+    let two = dir.path().join("two.zip");
+    write_zip(
+        &two,
+        &[("a.csv", b"x\n1\n"), ("b.csv", b"x\n2\n")],
+        ::zip::CompressionMethod::Deflated,
+    );
+    let err = load_dataset_with_progress(&two, 1000).unwrap_err();
+    assert!(
+        format!("{:#}", err).contains("exactly one file"),
+        "{:#}",
+        err
+    );
+
+    let json = dir.path().join("json.zip");
+    write_zip(
+        &json,
+        &[("rows.json", b"[]")],
+        ::zip::CompressionMethod::Deflated,
+    );
+    let err = load_dataset_with_progress(&json, 1000).unwrap_err();
+    assert!(format!("{:#}", err).contains("not a CSV file"), "{:#}", err);
+
+    // Other gzip files are not taken for CSV
+    let parquet_gz = dir.path().join("data.parquet.gz");
+    write_gzip(&parquet_gz, b"PAR1");
+    assert!(load_dataset_with_progress(&parquet_gz, 1000).is_err());
+}
+
+#[test]
+fn test_convert_compressed_csv_to_parquet() {
+    let dir = TempDir::new().unwrap();
+    let paths = write_variants(dir.path());
+
+    // Without an output path, both extensions are replaced
+    run_convert(&paths[1], None, 1000, None, false).unwrap();
+    let parquet = dir.path().join("loans.parquet");
+    let df = LazyFrame::scan_parquet(&parquet, Default::default())
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_shape(&df, ROWS, 3);
+
+    // Decompressing to plain CSV is a conversion too
+    let csv = dir.path().join("unzipped.csv");
+    run_convert(&paths[3], Some(&csv), 1000, None, false).unwrap();
+    assert_eq!(std::fs::read_to_string(&csv).unwrap(), csv_text());
+}
+
+#[test]
+fn test_cli_reduces_gzipped_csv_to_plain_csv() {
+    let dir = TempDir::new().unwrap();
+    let paths = write_variants(dir.path());
+    let config = dir.path().join("pipeline.toml");
+    std::fs::write(&config, "use_solver = false\n").unwrap();
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--config")
+        .arg(&config)
+        .arg("--input")
+        .arg(&paths[1])
+        .args([
+            "--target",
+            "target",
+            "--no-confirm",
+            "--gini-threshold",
+            "0",
+        ])
+        .assert()
+        .success();
+
+    let reduced = dir.path().join("loans_reduced.csv");
+    let df = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(reduced))
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert_eq!(df.height(), ROWS);
+    assert_has_columns(&df, &["target"]);
+    // Report names drop both extensions too
+    assert!(dir.path().join("loans_reduction_report.zip").exists());
+}
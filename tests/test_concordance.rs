@@ -0,0 +1,130 @@
+//! Tests for Somers' D / concordance on raw feature values
+
+use lophi::pipeline::{
+    analyze_features_iv, binning_limited_features, compute_concordance, BinningStrategy,
+    MissingBinPolicy, TargetMapping,
+};
+use lophi::Reducer;
+use polars::prelude::*;
+
+fn stats_for(df: &DataFrame, weights: &[f64], feature: &str) -> (f64, f64) {
+    let stats = compute_concordance(df, "target", None, weights, None).unwrap();
+    let s = stats.iter().find(|s| s.feature_name == feature).unwrap();
+    (s.somers_d, s.concordance)
+}
+
+#[test]
+fn test_perfect_and_reversed_ordering() {
+    let df = df! {
+        "target" => [0i32, 0, 0, 1, 1, 1],
+        "up" => [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0],
+        "down" => [6i64, 5, 4, 3, 2, 1],
+    }
+    .unwrap();
+    let weights = [1.0; 6];
+
+    assert_eq!(stats_for(&df, &weights, "up"), (1.0, 1.0));
+    assert_eq!(stats_for(&df, &weights, "down"), (-1.0, 0.0));
+}
+
+#[test]
+fn test_pair_counting_with_ties_and_weights() {
+    // Events at 2 and 4, non-events at 1 and 3: 3 of 4 pairs concordant
+    let df = df! {
+        "target" => [0i32, 1, 0, 1],
+        "x" => [1.0f64, 2.0, 3.0, 4.0],
+        "tied" => [1.0f64, 1.0, 2.0, 2.0],
+    }
+    .unwrap();
+    assert_eq!(stats_for(&df, &[1.0; 4], "x"), (0.5, 0.75));
+
+    // Ties count half toward concordance and nothing toward Somers' D
+    assert_eq!(stats_for(&df, &[1.0; 4], "tied"), (0.0, 0.5));
+
+    // Weight 3 on the non-event at 3 makes the discordant pair heavier:
+    // concordant 1 + 1 + 3 = 5, discordant 3, of 8
+    let (d, c) = stats_for(&df, &[1.0, 1.0, 3.0, 1.0], "x");
+    assert!((d - 0.25).abs() < 1e-12);
+    assert!((c - 0.625).abs() < 1e-12);
+}
+
+#[test]
+fn test_missing_values_and_categoricals_are_skipped() {
+    let df = df! {
+        "target" => [0i32, 0, 1, 1, 0, 1],
+        "x" => [Some(1.0f64), Some(2.0), Some(3.0), Some(4.0), None, None],
+        "label" => ["a", "b", "a", "b", "a", "b"],
+    }
+    .unwrap();
+    let stats = compute_concordance(&df, "target", None, &[1.0; 6], None).unwrap();
+
+    assert_eq!(stats.len(), 1);
+    assert_eq!(stats[0].feature_name, "x");
+    assert_eq!(stats[0].sample_rows, 4);
+    assert_eq!(stats[0].somers_d, 1.0);
+}
+
+#[test]
+fn test_target_mapping_is_applied() {
+    let df = df! {
+        "target" => ["bad", "good", "bad", "good", "unknown"],
+        "x" => [5.0f64, 1.0, 4.0, 2.0, 3.0],
+    }
+    .unwrap();
+    let mapping = TargetMapping::new("bad".to_string(), "good".to_string());
+    let stats = compute_concordance(&df, "target", Some(&mapping), &[1.0; 5], None).unwrap();
+
+    // The unmapped row is ignored; higher values belong to "bad" events
+    assert_eq!(stats[0].sample_rows, 4);
+    assert_eq!(stats[0].somers_d, 1.0);
+}
+
+#[test]
+fn test_binning_limited_features() {
+    // A single bin has no Gini, while the raw ordering separates the classes
+    let n = 40;
+    let target: Vec<i32> = (0..n).map(|i| i32::from(i >= 20)).collect();
+    let x: Vec<f64> = (0..n).map(|i| i as f64).collect();
+    let df = df! { "target" => target, "x" => x }.unwrap();
+    let weights = vec![1.0; n];
+
+    let analyses = analyze_features_iv(
+        &df,
+        "target",
+        1,
+        1,
+        None,
+        BinningStrategy::Quantile,
+        None,
+        None,
+        &weights,
+        None,
+        None,
+        None,
+        MissingBinPolicy::Separate,
+        None,
+    )
+    .unwrap();
+    assert!(analyses[0].gini < 0.05);
+
+    let stats = compute_concordance(&df, "target", None, &weights, None).unwrap();
+    assert_eq!(
+        binning_limited_features(&analyses, &stats, 0.05),
+        vec!["x".to_string()]
+    );
+    assert!(binning_limited_features(&analyses, &stats, 0.0).is_empty());
+}
+
+#[test]
+fn test_reducer_reports_concordance() {
+    let df = df! {
+        "target" => [0i32, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 1],
+        "x" => [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 0.0],
+    }
+    .unwrap();
+    let result = Reducer::from_dataframe(df, "target").run().unwrap();
+
+    assert_eq!(result.concordance.len(), 1);
+    assert_eq!(result.concordance[0].feature_name, "x");
+    assert!(result.concordance[0].somers_d > 0.0);
+}
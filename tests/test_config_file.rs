@@ -0,0 +1,445 @@
+//! Tests for pipeline config files (--config) and the init-config template
+
+use clap::{CommandFactory, FromArgMatches};
+use lophi::cli::config_file::{
+    apply_config_file, config_template, write_config_template, ConfigFormat, PipelineFileConfig,
+};
+use lophi::cli::{Cli, Commands};
+use lophi::pipeline::ScreeningStep;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// Parse args and merge the config file referenced by --config, as main() does
+fn parse_with_config(args: &[&str]) -> anyhow::Result<Cli> {
+    let matches = Cli::command().try_get_matches_from(args)?;
+    let mut cli = Cli::from_arg_matches(&matches)?;
+    if let Some(path) = cli.config.clone() {
+        apply_config_file(&mut cli, &matches, &path)?;
+    }
+    Ok(cli)
+}
+
+fn write_file(dir: &TempDir, name: &str, content: &str) -> PathBuf {
+    let path = dir.path().join(name);
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+#[test]
+fn test_toml_config_sets_values() {
+    let dir = TempDir::new().unwrap();
+    let path = write_file(
+        &dir,
+        "pipeline.toml",
+        r#"
+input = "data.parquet"
+sheet = "Applications"
+target = "default_flag"
+target_type = "continuous"
+event_value = "bad"
+non_event_value = "good"
+event_label = "default"
+non_event_label = "repaid"
+weight_column = "w"
+dominance_threshold = 0.99
+id_threshold = 0.9
+id_action = "drop"
+missing_threshold = 0.5
+gini_threshold = 0.1
+correlation_threshold = 0.8
+cramers_v_threshold = 0.6
+correlation_strategy = "cluster"
+corr_keep_policy = "custom_list"
+corr_keep_prefixes = ["bureau_", "internal_"]
+gini_bins = 6
+binning_strategy = "quantile"
+univariate_metric = "mutual_info"
+mi_threshold = 0.02
+merge_insignificant_bins = 0.05
+prebins = 30
+cart_min_bin_pct = 2.5
+min_category_samples = 10
+use_solver = false
+monotonicity = "ascending"
+solver_timeout = 5
+solver_gap = 0.05
+min_bin_pct = 5.0
+max_bin_pct = 40.0
+min_woe_delta = 0.15
+binning_backend = "dp"
+drop_columns = ["id", "ts"]
+select_columns = ["target", "score", "income"]
+sample_frac = 0.25
+sample_stratify = true
+sample_seed = 9
+keep_columns = ["score"]
+infer_schema_length = 0
+non_finite_policy = "clip"
+date_policy = "derive"
+expect_schema = "schema.json"
+fail_on_schema_drift = true
+fail_on_solver_fallback = true
+split_gini_export = true
+export_woe = true
+output_include_target_mapping = true
+export_cart_trees = true
+export_distributions = true
+fast = true
+review_correlations = true
+review_drops = true
+browse_results = true
+split_column = "sample"
+train_value = "TRAIN"
+validation_frac = 0.2
+iv_stability_threshold = 0.4
+segment_column = "region"
+protected_columns = ["gender", "age_band"]
+proxy_threshold = 0.25
+steps = ["correlation", "missing"]
+skip_gini = true
+time_column = "app_date"
+time_freq = "quarter"
+cv_folds = 4
+cv_min_iv = 0.02
+special_values = ["-999997", "age:-1"]
+missing_bin_policy = "impute"
+bin_breaks = ["age:25,35,50"]
+"#,
+    );
+
+    let cli = parse_with_config(&["lophi", "--config", path.to_str().unwrap()]).unwrap();
+
+    assert_eq!(cli.input, Some(PathBuf::from("data.parquet")));
+    assert_eq!(cli.sheet.as_deref(), Some("Applications"));
+    assert_eq!(cli.target.as_deref(), Some("default_flag"));
+    assert_eq!(cli.target_type, "continuous");
+    assert_eq!(cli.event_value.as_deref(), Some("bad"));
+    assert_eq!(cli.non_event_value.as_deref(), Some("good"));
+    assert_eq!(cli.event_label.as_deref(), Some("default"));
+    assert_eq!(cli.non_event_label.as_deref(), Some("repaid"));
+    assert_eq!(cli.weight_column.as_deref(), Some("w"));
+    assert_eq!(cli.dominance_threshold, Some(0.99));
+    assert_eq!(cli.id_threshold, Some(0.9));
+    assert_eq!(cli.id_action, "drop");
+    assert_eq!(cli.missing_threshold, 0.5);
+    assert_eq!(cli.gini_threshold, 0.1);
+    assert_eq!(cli.correlation_threshold, 0.8);
+    assert_eq!(cli.cramers_v_threshold, Some(0.6));
+    assert_eq!(cli.correlation_strategy, "cluster");
+    assert_eq!(cli.corr_keep_policy, "custom_list");
+    assert_eq!(cli.corr_keep_prefixes, ["bureau_", "internal_"]);
+    assert_eq!(cli.gini_bins, 6);
+    assert_eq!(cli.binning_strategy, "quantile");
+    assert_eq!(cli.univariate_metric, "mutual_info");
+    assert_eq!(cli.mi_threshold, 0.02);
+    assert_eq!(cli.merge_insignificant_bins, Some(0.05));
+    assert_eq!(cli.prebins, 30);
+    assert_eq!(cli.cart_min_bin_pct, 2.5);
+    assert_eq!(cli.min_category_samples, 10);
+    assert!(!cli.use_solver);
+    assert_eq!(cli.monotonicity, "ascending");
+    assert_eq!(cli.solver_timeout, 5);
+    assert_eq!(cli.solver_gap, 0.05);
+    assert_eq!(cli.min_bin_pct, 5.0);
+    assert_eq!(cli.max_bin_pct, 40.0);
+    assert_eq!(cli.min_woe_delta, 0.15);
+    assert_eq!(cli.binning_backend, "dp");
+    assert_eq!(cli.drop_columns, vec!["id".to_string(), "ts".to_string()]);
+    assert_eq!(cli.select_columns, ["target", "score", "income"]);
+    assert_eq!(cli.sample_frac, Some(0.25));
+    assert!(cli.sample_stratify);
+    assert_eq!(cli.sample_seed, Some(9));
+    assert_eq!(cli.keep_columns, vec!["score".to_string()]);
+    assert_eq!(cli.infer_schema_length, 0);
+    assert_eq!(cli.non_finite_policy, "clip");
+    assert_eq!(cli.date_policy, "derive");
+    assert_eq!(cli.expect_schema, Some(PathBuf::from("schema.json")));
+    assert!(cli.fail_on_schema_drift);
+    assert!(cli.fail_on_solver_fallback);
+    assert!(cli.split_gini_export);
+    assert!(cli.export_woe);
+    assert!(cli.output_include_target_mapping);
+    assert!(cli.export_cart_trees);
+    assert!(cli.export_distributions);
+    assert!(cli.fast);
+    assert!(cli.review_correlations);
+    assert!(cli.review_drops);
+    assert!(cli.browse_results);
+    assert_eq!(cli.split_column.as_deref(), Some("sample"));
+    assert_eq!(cli.train_value.as_deref(), Some("TRAIN"));
+    assert_eq!(cli.validation_frac, 0.2);
+    assert_eq!(cli.iv_stability_threshold, Some(0.4));
+    assert_eq!(cli.segment_column.as_deref(), Some("region"));
+    assert_eq!(cli.protected_columns, ["gender", "age_band"]);
+    assert_eq!(cli.proxy_threshold, 0.25);
+    assert_eq!(cli.steps, ["correlation", "missing"]);
+    assert!(cli.skip_gini);
+    assert_eq!(cli.time_column.as_deref(), Some("app_date"));
+    assert_eq!(cli.time_freq, "quarter");
+    assert_eq!(cli.cv_folds, Some(4));
+    assert_eq!(cli.cv_min_iv, Some(0.02));
+    assert_eq!(cli.special_values, ["-999997", "age:-1"]);
+    assert_eq!(cli.missing_bin_policy, "impute");
+    assert_eq!(cli.bin_breaks, ["age:25,35,50"]);
+}
+
+#[test]
+fn test_cli_flags_override_config() {
+    let dir = TempDir::new().unwrap();
+    let path = write_file(
+        &dir,
+        "pipeline.toml",
+        "target = \"from_file\"\nmissing_threshold = 0.5\ngini_bins = 6\ndrop_columns = [\"a\"]\n",
+    );
+
+    let cli = parse_with_config(&[
+        "lophi",
+        "--config",
+        path.to_str().unwrap(),
+        "--target",
+        "from_cli",
+        "--missing-threshold",
+        "0.2",
+        "--drop-columns",
+        "b,c",
+    ])
+    .unwrap();
+
+    assert_eq!(cli.target.as_deref(), Some("from_cli"));
+    assert_eq!(cli.missing_threshold, 0.2);
+    assert_eq!(cli.drop_columns, vec!["b".to_string(), "c".to_string()]);
+    // Not given on the command line, so the file value applies
+    assert_eq!(cli.gini_bins, 6);
+}
+
+#[test]
+fn test_flag_equal_to_default_still_overrides_config() {
+    let dir = TempDir::new().unwrap();
+    let path = write_file(&dir, "pipeline.toml", "missing_threshold = 0.5\n");
+
+    let cli = parse_with_config(&[
+        "lophi",
+        "--config",
+        path.to_str().unwrap(),
+        "--missing-threshold",
+        "0.3",
+    ])
+    .unwrap();
+    assert_eq!(cli.missing_threshold, 0.3);
+}
+
+#[test]
+fn test_yaml_config_sets_values() {
+    let dir = TempDir::new().unwrap();
+    let path = write_file(
+        &dir,
+        "pipeline.yml",
+        "target: y\ncorrelation_threshold: 0.7\ndrop_columns: [id]\nuse_solver: false\n",
+    );
+
+    let cli = parse_with_config(&["lophi", "--config", path.to_str().unwrap()]).unwrap();
+    assert_eq!(cli.target.as_deref(), Some("y"));
+    assert_eq!(cli.correlation_threshold, 0.7);
+    assert_eq!(cli.drop_columns, vec!["id".to_string()]);
+    assert!(!cli.use_solver);
+    // Unset keys keep CLI defaults
+    assert_eq!(cli.missing_threshold, 0.3);
+}
+
+#[test]
+fn test_empty_config_keeps_defaults() {
+    let dir = TempDir::new().unwrap();
+    for name in ["empty.toml", "empty.yaml"] {
+        let path = write_file(&dir, name, "");
+        let cli = parse_with_config(&["lophi", "--config", path.to_str().unwrap()]).unwrap();
+        assert_eq!(cli.gini_threshold, 0.05);
+        assert_eq!(cli.binning_strategy, "cart");
+    }
+}
+
+#[test]
+fn test_unknown_key_is_rejected() {
+    let dir = TempDir::new().unwrap();
+    let path = write_file(&dir, "pipeline.toml", "missing_treshold = 0.5\n");
+    let err = parse_with_config(&["lophi", "--config", path.to_str().unwrap()]).unwrap_err();
+    assert!(format!("{:#}", err).contains("missing_treshold"));
+}
+
+#[test]
+fn test_out_of_range_value_is_rejected() {
+    let dir = TempDir::new().unwrap();
+    let path = write_file(&dir, "pipeline.toml", "gini_threshold = 1.5\n");
+    let err = parse_with_config(&["lophi", "--config", path.to_str().unwrap()]).unwrap_err();
+    assert!(format!("{:#}", err).contains("gini_threshold"));
+}
+
+#[test]
+fn test_bin_share_limits_are_checked() {
+    let dir = TempDir::new().unwrap();
+    let path = write_file(&dir, "range.toml", "max_bin_pct = 120.0\n");
+    let err = parse_with_config(&["lophi", "--config", path.to_str().unwrap()]).unwrap_err();
+    assert!(format!("{:#}", err).contains("max_bin_pct"));
+
+    let path = write_file(
+        &dir,
+        "order.toml",
+        "min_bin_pct = 30.0\nmax_bin_pct = 20.0\n",
+    );
+    let err = parse_with_config(&["lophi", "--config", path.to_str().unwrap()]).unwrap_err();
+    assert!(format!("{:#}", err).contains("cannot exceed"));
+}
+
+#[test]
+fn test_invalid_bin_breaks_are_rejected() {
+    let dir = TempDir::new().unwrap();
+    let path = write_file(&dir, "pipeline.toml", "bin_breaks = [\"age:25,,35\"]\n");
+    let err = parse_with_config(&["lophi", "--config", path.to_str().unwrap()]).unwrap_err();
+    assert!(format!("{:#}", err).contains("bin_breaks"));
+}
+
+#[test]
+fn test_no_target_config() {
+    let dir = TempDir::new().unwrap();
+    let path = write_file(&dir, "pipeline.yaml", "no_target: true\n");
+    let cli = parse_with_config(&["lophi", "--config", path.to_str().unwrap()]).unwrap();
+    assert!(cli.no_target);
+    assert_eq!(cli.dominance_threshold(), Some(0.995));
+    assert!(!cli.step_plan().unwrap().runs(ScreeningStep::Gini));
+
+    let path = write_file(&dir, "both.toml", "target = \"y\"\nno_target = true\n");
+    let err = parse_with_config(&["lophi", "--config", path.to_str().unwrap()]).unwrap_err();
+    assert!(format!("{:#}", err).contains("no_target"));
+}
+
+#[test]
+fn test_pca_config() {
+    let dir = TempDir::new().unwrap();
+    let path = write_file(
+        &dir,
+        "pipeline.toml",
+        "pca = true\npca_loading_threshold = 0.5\n",
+    );
+    let cli = parse_with_config(&["lophi", "--config", path.to_str().unwrap()]).unwrap();
+    assert_eq!(cli.pca_settings().unwrap().loading_threshold, 0.5);
+
+    let path = write_file(&dir, "bad.yaml", "pca_loading_threshold: 1.5\n");
+    let err = parse_with_config(&["lophi", "--config", path.to_str().unwrap()]).unwrap_err();
+    assert!(format!("{:#}", err).contains("pca_loading_threshold"));
+}
+
+#[test]
+fn test_invalid_special_value_is_rejected() {
+    let dir = TempDir::new().unwrap();
+    let path = write_file(&dir, "pipeline.toml", "special_values = [\"none\"]\n");
+    let err = parse_with_config(&["lophi", "--config", path.to_str().unwrap()]).unwrap_err();
+    assert!(format!("{:#}", err).contains("special_values"));
+}
+
+#[test]
+fn test_unsupported_extension_is_rejected() {
+    assert!(ConfigFormat::from_path(Path::new("pipeline.json")).is_err());
+    assert_eq!(
+        ConfigFormat::from_path(Path::new("a.YAML")).unwrap(),
+        ConfigFormat::Yaml
+    );
+}
+
+#[test]
+fn test_templates_parse_to_defaults() {
+    for format in [ConfigFormat::Toml, ConfigFormat::Yaml] {
+        let dir = TempDir::new().unwrap();
+        let name = match format {
+            ConfigFormat::Toml => "t.toml",
+            ConfigFormat::Yaml => "t.yaml",
+        };
+        let path = write_file(&dir, name, config_template(format));
+        let config = PipelineFileConfig::load(&path).unwrap();
+        config.validate().unwrap();
+
+        // The template mirrors the CLI defaults, so merging it changes nothing
+        let defaults = parse_with_config(&["lophi"]).unwrap();
+        let merged = parse_with_config(&["lophi", "--config", path.to_str().unwrap()]).unwrap();
+        assert_eq!(defaults.target_type, merged.target_type);
+        assert!(merged.dominance_threshold.is_none());
+        assert!(merged.id_threshold.is_none());
+        assert_eq!(defaults.id_action, merged.id_action);
+        assert_eq!(defaults.missing_threshold, merged.missing_threshold);
+        assert_eq!(defaults.gini_threshold, merged.gini_threshold);
+        assert_eq!(defaults.correlation_threshold, merged.correlation_threshold);
+        assert!(merged.cramers_v_threshold.is_none());
+        assert_eq!(defaults.correlation_strategy, merged.correlation_strategy);
+        assert_eq!(defaults.corr_keep_policy, merged.corr_keep_policy);
+        assert_eq!(defaults.corr_keep_prefixes, merged.corr_keep_prefixes);
+        assert_eq!(defaults.gini_bins, merged.gini_bins);
+        assert_eq!(defaults.binning_strategy, merged.binning_strategy);
+        assert_eq!(defaults.univariate_metric, merged.univariate_metric);
+        assert_eq!(defaults.mi_threshold, merged.mi_threshold);
+        assert_eq!(defaults.missing_bin_policy, merged.missing_bin_policy);
+        assert!(merged.merge_insignificant_bins.is_none());
+        assert!(merged.event_label.is_none());
+        assert!(merged.non_event_label.is_none());
+        assert_eq!(defaults.prebins, merged.prebins);
+        assert_eq!(defaults.cart_min_bin_pct, merged.cart_min_bin_pct);
+        assert_eq!(defaults.min_category_samples, merged.min_category_samples);
+        assert_eq!(defaults.use_solver, merged.use_solver);
+        assert_eq!(defaults.monotonicity, merged.monotonicity);
+        assert_eq!(defaults.solver_timeout, merged.solver_timeout);
+        assert_eq!(defaults.solver_gap, merged.solver_gap);
+        assert_eq!(defaults.min_bin_pct, merged.min_bin_pct);
+        assert_eq!(defaults.max_bin_pct, merged.max_bin_pct);
+        assert_eq!(defaults.min_woe_delta, merged.min_woe_delta);
+        assert_eq!(
+            defaults.fail_on_solver_fallback,
+            merged.fail_on_solver_fallback
+        );
+        assert_eq!(defaults.binning_backend, merged.binning_backend);
+        assert_eq!(defaults.infer_schema_length, merged.infer_schema_length);
+        assert_eq!(defaults.non_finite_policy, merged.non_finite_policy);
+        assert_eq!(defaults.date_policy, merged.date_policy);
+        assert!(merged.expect_schema.is_none());
+        assert!(merged.sheet.is_none());
+        assert_eq!(defaults.fail_on_schema_drift, merged.fail_on_schema_drift);
+        assert_eq!(defaults.split_gini_export, merged.split_gini_export);
+        assert_eq!(defaults.export_woe, merged.export_woe);
+        assert_eq!(
+            defaults.output_include_target_mapping,
+            merged.output_include_target_mapping
+        );
+        assert_eq!(defaults.export_cart_trees, merged.export_cart_trees);
+        assert_eq!(defaults.export_distributions, merged.export_distributions);
+        assert_eq!(defaults.fast, merged.fast);
+        assert_eq!(defaults.review_correlations, merged.review_correlations);
+        assert_eq!(defaults.review_drops, merged.review_drops);
+        assert_eq!(defaults.browse_results, merged.browse_results);
+        assert!(merged.target.is_none());
+    }
+}
+
+#[test]
+fn test_write_config_template_respects_force() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("pipeline.toml");
+
+    write_config_template(&path, false).unwrap();
+    assert!(path.exists());
+    assert!(write_config_template(&path, false).is_err());
+    write_config_template(&path, true).unwrap();
+}
+
+#[test]
+fn test_init_config_subcommand_parses() {
+    let cli = parse_with_config(&["lophi", "init-config"]).unwrap();
+    match cli.command {
+        Some(Commands::InitConfig { output, force }) => {
+            assert_eq!(output, PathBuf::from("pipeline.toml"));
+            assert!(!force);
+        }
+        other => panic!("expected InitConfig, got {:?}", other),
+    }
+
+    let cli = parse_with_config(&["lophi", "init-config", "cfg.yaml", "--force"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::InitConfig { force: true, .. })
+    ));
+}
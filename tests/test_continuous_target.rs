@@ -0,0 +1,202 @@
+//! Tests for continuous-target (regression) screening (--target-type continuous)
+
+use lophi::pipeline::{
+    analyze_features_r2, exclude_target_pairs, get_low_r2_features, AssociationMeasure,
+    CorrelatedPair, FeatureType, TargetMapping, TargetType,
+};
+use lophi::Reducer;
+use polars::prelude::*;
+
+/// 60 rows: `x` drives `y`, `x_twin` is a jittered copy of `x`, `group` shifts
+/// `y` by category and `noise` is unrelated
+fn regression_df() -> DataFrame {
+    let n = 60;
+    let x: Vec<f64> = (0..n).map(|i| i as f64).collect();
+    let x_twin: Vec<f64> = x
+        .iter()
+        .enumerate()
+        .map(|(i, v)| v + (i % 4) as f64 * 1.5)
+        .collect();
+    let group: Vec<&str> = (0..n).map(|i| ["A", "B", "C"][i % 3]).collect();
+    // Period 7 is unrelated to both the trend and the period-3 groups
+    let noise: Vec<f64> = (0..n).map(|i| ((i * 5) % 7) as f64).collect();
+    let y: Vec<f64> = (0..n)
+        .map(|i| x[i] + if group[i] == "A" { 10.0 } else { 0.0 })
+        .collect();
+
+    df! {
+        "y" => y,
+        "x" => x,
+        "x_twin" => x_twin,
+        "group" => group,
+        "noise" => noise,
+    }
+    .unwrap()
+}
+
+#[test]
+fn test_target_type_parses() {
+    assert_eq!("binary".parse::<TargetType>().unwrap(), TargetType::Binary);
+    assert_eq!(
+        "Continuous".parse::<TargetType>().unwrap(),
+        TargetType::Continuous
+    );
+    assert!("regression".parse::<TargetType>().is_err());
+    assert_eq!(TargetType::default(), TargetType::Binary);
+    assert_eq!(TargetType::Continuous.to_string(), "continuous");
+}
+
+#[test]
+fn test_r2_ranks_numeric_and_categorical_features() {
+    let df = regression_df();
+    let weights = vec![1.0; df.height()];
+    let analyses = analyze_features_r2(&df, "y", 5, Some(5), &weights, None).unwrap();
+
+    let names: Vec<&str> = analyses.iter().map(|a| a.feature_name.as_str()).collect();
+    assert_eq!(names.len(), 4);
+    assert!(!names.contains(&"y"));
+
+    let x = analyses.iter().find(|a| a.feature_name == "x").unwrap();
+    assert_eq!(x.feature_type, FeatureType::Numeric);
+    assert_eq!(x.bins.len(), 5);
+    assert!(x.r_squared > 0.8, "x R² = {}", x.r_squared);
+    assert!(x.correlation.unwrap() > 0.9);
+    // Bin means rise with x
+    assert!(x
+        .bins
+        .windows(2)
+        .all(|w| w[0].target_mean < w[1].target_mean));
+
+    let group = analyses.iter().find(|a| a.feature_name == "group").unwrap();
+    assert_eq!(group.feature_type, FeatureType::Categorical);
+    assert_eq!(group.categories.len(), 3);
+    assert!(group.correlation.is_none());
+    assert!(group.r_squared > 0.0 && group.r_squared < 1.0);
+
+    let noise = analyses.iter().find(|a| a.feature_name == "noise").unwrap();
+    assert!(noise.r_squared < x.r_squared);
+
+    // Sorted by R² descending
+    assert!(analyses
+        .windows(2)
+        .all(|w| w[0].r_squared >= w[1].r_squared));
+}
+
+#[test]
+fn test_r2_missing_values_get_their_own_bin() {
+    let df = df! {
+        "y" => [1.0f64, 2.0, 3.0, 4.0, 10.0, 10.0],
+        "x" => [Some(1.0f64), Some(2.0), Some(3.0), Some(4.0), None, None],
+    }
+    .unwrap();
+    let weights = vec![1.0; df.height()];
+    let analyses = analyze_features_r2(&df, "y", 2, Some(1), &weights, None).unwrap();
+
+    let missing = analyses[0].missing_bin.as_ref().unwrap();
+    assert_eq!(missing.count, 2.0);
+    assert!((missing.target_mean - 10.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_r2_respects_weights() {
+    let df = df! {
+        "y" => [0.0f64, 0.0, 1.0, 1.0, 5.0, -5.0],
+        "x" => [0.0f64, 0.0, 1.0, 1.0, 0.0, 1.0],
+    }
+    .unwrap();
+
+    // Unweighted, the last two rows break the relationship
+    let unweighted = analyze_features_r2(&df, "y", 2, None, &[1.0; 6], None).unwrap();
+    // Weighted to zero, x explains y exactly
+    let weights = [1.0, 1.0, 1.0, 1.0, 0.0, 0.0];
+    let weighted = analyze_features_r2(&df, "y", 2, None, &weights, None).unwrap();
+
+    assert!(unweighted[0].r_squared < 0.5);
+    assert!((weighted[0].r_squared - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_r2_rejects_unusable_targets() {
+    let constant = df! {
+        "y" => [3.0f64, 3.0, 3.0],
+        "x" => [1.0f64, 2.0, 3.0],
+    }
+    .unwrap();
+    let err = analyze_features_r2(&constant, "y", 2, None, &[1.0; 3], None).unwrap_err();
+    assert!(err.to_string().contains("constant"), "{}", err);
+
+    let text = df! {
+        "y" => ["a", "b", "c"],
+        "x" => [1.0f64, 2.0, 3.0],
+    }
+    .unwrap();
+    let err = analyze_features_r2(&text, "y", 2, None, &[1.0; 3], None).unwrap_err();
+    assert!(err.to_string().contains("numeric"), "{}", err);
+}
+
+#[test]
+fn test_get_low_r2_features_is_strict() {
+    let df = regression_df();
+    let analyses = analyze_features_r2(&df, "y", 5, Some(5), &[1.0; 60], None).unwrap();
+    let x_r2 = analyses
+        .iter()
+        .find(|a| a.feature_name == "x")
+        .unwrap()
+        .r_squared;
+
+    let low = get_low_r2_features(&analyses, x_r2);
+    assert!(!low.contains(&"x".to_string()));
+    assert!(low.contains(&"noise".to_string()));
+    assert!(get_low_r2_features(&analyses, 0.0).is_empty());
+}
+
+#[test]
+fn test_exclude_target_pairs() {
+    let pair = |a: &str, b: &str| CorrelatedPair {
+        feature1: a.to_string(),
+        feature2: b.to_string(),
+        correlation: 0.95,
+        measure: AssociationMeasure::Pearson,
+        theils_u: None,
+        rationale: None,
+    };
+    let pairs = vec![pair("x", "y"), pair("x", "x_twin"), pair("y", "z")];
+
+    let kept = exclude_target_pairs(pairs, "y");
+    assert_eq!(kept.len(), 1);
+    assert_eq!(kept[0].feature2, "x_twin");
+}
+
+#[test]
+fn test_reducer_continuous_target() {
+    let result = Reducer::from_dataframe(regression_df(), "y")
+        .target_type(TargetType::Continuous)
+        .gini_bins(5)
+        .gini_threshold(0.05)
+        .correlation_threshold(0.9)
+        .run()
+        .unwrap();
+
+    assert!(result.iv_analyses.is_empty());
+    assert_eq!(result.r2_analyses.len(), 4);
+    assert!(result.dropped_gini.contains(&"noise".to_string()));
+
+    // x and x_twin are redundant with each other, but their correlation with
+    // the target must not cost both of them their place: the twin goes on R²
+    let kept = result.kept_features();
+    assert_eq!(result.dropped_correlation.len(), 1);
+    assert_eq!(result.dropped_correlation[0].feature, "x_twin");
+    assert!(result.dropped_correlation[0].reason.contains("lower R²"));
+    assert!(kept.contains(&"x".to_string()));
+    assert!(kept.contains(&"group".to_string()));
+}
+
+#[test]
+fn test_reducer_continuous_rejects_target_mapping() {
+    let err = Reducer::from_dataframe(regression_df(), "y")
+        .target_type(TargetType::Continuous)
+        .target_mapping(TargetMapping::new("1".to_string(), "0".to_string()))
+        .run()
+        .unwrap_err();
+    assert!(err.to_string().contains("continuous"), "{}", err);
+}
@@ -1,8 +1,11 @@
-//! Tests for CSV to Parquet conversion functionality
+//! Tests for CSV / Parquet / Feather conversion functionality
 
 mod common;
 
-use lophi::cli::convert::run_convert;
+use lophi::cli::convert::{run_convert, run_convert_with};
+use lophi::pipeline::{
+    ChunkedOutput, CsvChunker, CsvDialect, ParquetCodec, ParquetOptions, ParquetStatistics,
+};
 use polars::prelude::*;
 use tempfile::TempDir;
 
@@ -29,7 +32,7 @@ fn test_basic_csv_to_parquet_conversion() {
     let parquet_path = temp_dir.path().join("test.parquet");
 
     // Convert CSV to Parquet
-    run_convert(&csv_path, Some(&parquet_path), 1000, false).unwrap();
+    run_convert(&csv_path, Some(&parquet_path), 1000, None, false).unwrap();
 
     // Verify the Parquet file exists and has correct shape
     assert!(parquet_path.exists(), "Parquet file should be created");
@@ -59,7 +62,7 @@ fn test_conversion_preserves_data_types() {
     let csv_path = create_test_csv(&temp_dir, "types_test.csv", &mut df);
     let parquet_path = temp_dir.path().join("types_test.parquet");
 
-    run_convert(&csv_path, Some(&parquet_path), 1000, false).unwrap();
+    run_convert(&csv_path, Some(&parquet_path), 1000, None, false).unwrap();
 
     let result_df = LazyFrame::scan_parquet(&parquet_path, Default::default())
         .unwrap()
@@ -90,7 +93,7 @@ fn test_conversion_with_binary_target_preserved() {
     let csv_path = create_test_csv(&temp_dir, "binary_target.csv", &mut df);
     let parquet_path = temp_dir.path().join("binary_target.parquet");
 
-    run_convert(&csv_path, Some(&parquet_path), 1000, false).unwrap();
+    run_convert(&csv_path, Some(&parquet_path), 1000, None, false).unwrap();
 
     let result_df = LazyFrame::scan_parquet(&parquet_path, Default::default())
         .unwrap()
@@ -127,7 +130,7 @@ fn test_conversion_auto_output_path() {
     let csv_path = create_test_csv(&temp_dir, "auto_output.csv", &mut df);
 
     // Convert without explicit output path
-    run_convert(&csv_path, None, 1000, false).unwrap();
+    run_convert(&csv_path, None, 1000, None, false).unwrap();
 
     // Should create parquet with same base name
     let expected_parquet = temp_dir.path().join("auto_output.parquet");
@@ -152,7 +155,7 @@ fn test_conversion_with_missing_values() {
     let csv_path = create_test_csv(&temp_dir, "nulls_test.csv", &mut df);
     let parquet_path = temp_dir.path().join("nulls_test.parquet");
 
-    run_convert(&csv_path, Some(&parquet_path), 1000, false).unwrap();
+    run_convert(&csv_path, Some(&parquet_path), 1000, None, false).unwrap();
 
     let result_df = LazyFrame::scan_parquet(&parquet_path, Default::default())
         .unwrap()
@@ -185,7 +188,7 @@ fn test_conversion_produces_valid_parquet() {
     let csv_path = create_test_csv(&temp_dir, "compression_test.csv", &mut df);
     let parquet_path = temp_dir.path().join("compression_test.parquet");
 
-    run_convert(&csv_path, Some(&parquet_path), 1000, false).unwrap();
+    run_convert(&csv_path, Some(&parquet_path), 1000, None, false).unwrap();
 
     // Verify the Parquet file is valid and readable
     let result_df = LazyFrame::scan_parquet(&parquet_path, Default::default())
@@ -225,7 +228,7 @@ fn test_conversion_with_many_columns() {
     let csv_path = create_test_csv(&temp_dir, "wide_test.csv", &mut df);
     let parquet_path = temp_dir.path().join("wide_test.parquet");
 
-    run_convert(&csv_path, Some(&parquet_path), 1000, false).unwrap();
+    run_convert(&csv_path, Some(&parquet_path), 1000, None, false).unwrap();
 
     let result_df = LazyFrame::scan_parquet(&parquet_path, Default::default())
         .unwrap()
@@ -254,7 +257,7 @@ fn test_fast_mode_conversion() {
     let parquet_path = temp_dir.path().join("fast_test.parquet");
 
     // Convert using fast mode (in-memory, multi-core)
-    run_convert(&csv_path, Some(&parquet_path), 1000, true).unwrap();
+    run_convert(&csv_path, Some(&parquet_path), 1000, None, true).unwrap();
 
     // Verify the Parquet file exists and has correct shape
     assert!(parquet_path.exists(), "Parquet file should be created");
@@ -266,3 +269,305 @@ fn test_fast_mode_conversion() {
 
     assert_eq!(result_df.shape(), (5, 3));
 }
+
+fn read_feather(path: &std::path::Path) -> DataFrame {
+    LazyFrame::scan_ipc(path, Default::default())
+        .unwrap()
+        .collect()
+        .unwrap()
+}
+
+#[test]
+fn test_csv_to_feather_conversion() {
+    let mut df = df! {
+        "id" => [1i64, 2, 3],
+        "value" => [1.5f64, 2.5, 3.5],
+    }
+    .unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let csv_path = create_test_csv(&temp_dir, "data.csv", &mut df);
+
+    // Streaming and in-memory modes both honour the .feather extension
+    for (fast, name) in [(false, "stream.feather"), (true, "fast.feather")] {
+        let feather_path = temp_dir.path().join(name);
+        run_convert(&csv_path, Some(&feather_path), 1000, None, fast).unwrap();
+        assert!(read_feather(&feather_path).equals(&df), "{}", name);
+    }
+}
+
+#[test]
+fn test_feather_round_trip_through_parquet() {
+    let mut df = df! {
+        "id" => [1i32, 2, 3],
+        "name" => [Some("a"), None, Some("c")],
+    }
+    .unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let feather_path = temp_dir.path().join("data.arrow");
+    IpcWriter::new(std::fs::File::create(&feather_path).unwrap())
+        .finish(&mut df)
+        .unwrap();
+
+    // Feather defaults to Parquet output
+    run_convert(&feather_path, None, 1000, None, false).unwrap();
+    let parquet_path = temp_dir.path().join("data.parquet");
+    let parquet = LazyFrame::scan_parquet(&parquet_path, Default::default())
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(parquet.equals_missing(&df));
+
+    // ...and Parquet converts back to Feather on request
+    let back = temp_dir.path().join("back.feather");
+    run_convert(&parquet_path, Some(&back), 1000, None, false).unwrap();
+    assert!(read_feather(&back).equals_missing(&df));
+
+    // Same format in and out is rejected
+    assert!(run_convert(&feather_path, Some(&back), 1000, None, false).is_err());
+}
+
+#[test]
+fn test_jsonl_to_parquet_conversion() {
+    let temp_dir = TempDir::new().unwrap();
+    let jsonl_path = temp_dir.path().join("events.ndjson");
+    std::fs::write(
+        &jsonl_path,
+        "{\"id\": 1, \"value\": 1.5}\n{\"id\": 2, \"value\": null}\n{\"id\": 3, \"value\": 3.5}\n",
+    )
+    .unwrap();
+    let expected = df! {
+        "id" => [1i64, 2, 3],
+        "value" => [Some(1.5f64), None, Some(3.5)],
+    }
+    .unwrap();
+
+    // JSON Lines defaults to Parquet output
+    run_convert(&jsonl_path, None, 1000, None, false).unwrap();
+    let parquet =
+        LazyFrame::scan_parquet(temp_dir.path().join("events.parquet"), Default::default())
+            .unwrap()
+            .collect()
+            .unwrap();
+    assert!(parquet.equals_missing(&expected));
+
+    let feather_path = temp_dir.path().join("events.feather");
+    run_convert(&jsonl_path, Some(&feather_path), 1000, None, false).unwrap();
+    assert!(read_feather(&feather_path).equals_missing(&expected));
+}
+
+/// Row group count, codec and whether the first column chunk has statistics
+fn parquet_layout(path: &std::path::Path) -> (usize, String, bool) {
+    let mut reader = ParquetReader::new(std::fs::File::open(path).unwrap());
+    let metadata = reader.get_metadata().unwrap();
+    let chunk = metadata.row_groups[0]
+        .columns_under_root_iter("id")
+        .unwrap()
+        .next()
+        .unwrap();
+    (
+        metadata.row_groups.len(),
+        format!("{:?}", chunk.compression()),
+        chunk.statistics().is_some(),
+    )
+}
+
+#[test]
+fn test_conversion_parquet_options() {
+    let mut df = df! {
+        "id" => (0..10i32).collect::<Vec<_>>(),
+        "value" => (0..10).map(|i| i as f64 / 2.0).collect::<Vec<_>>(),
+    }
+    .unwrap();
+    let temp_dir = TempDir::new().unwrap();
+    let csv_path = create_test_csv(&temp_dir, "options.csv", &mut df);
+
+    // Converter defaults: Snappy with statistics in one 100,000-row group
+    let default_path = temp_dir.path().join("default.parquet");
+    run_convert(&csv_path, Some(&default_path), 1000, None, true).unwrap();
+    assert_eq!(
+        parquet_layout(&default_path),
+        (1, "Snappy".to_string(), true)
+    );
+
+    let options = ParquetOptions {
+        compression: ParquetCodec::Lz4,
+        row_group_size: Some(4),
+        statistics: ParquetStatistics::None,
+    };
+    let custom_path = temp_dir.path().join("custom.parquet");
+    run_convert_with(
+        &csv_path,
+        Some(&custom_path),
+        1000,
+        None,
+        true,
+        &options,
+        &CsvDialect::default(),
+    )
+    .unwrap();
+    let (row_groups, codec, has_statistics) = parquet_layout(&custom_path);
+    assert!(row_groups > 1, "10 rows should span several row groups");
+    assert_eq!(codec, "Lz4Raw");
+    assert!(!has_statistics);
+
+    let result = LazyFrame::scan_parquet(&custom_path, Default::default())
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(result.equals_missing(&df));
+
+    // The streaming path takes the codec too
+    let options = ParquetOptions {
+        compression: ParquetCodec::Uncompressed,
+        ..ParquetOptions::CONVERT
+    };
+    let streamed_path = temp_dir.path().join("streamed.parquet");
+    run_convert_with(
+        &csv_path,
+        Some(&streamed_path),
+        1000,
+        None,
+        false,
+        &options,
+        &CsvDialect::default(),
+    )
+    .unwrap();
+    assert_eq!(parquet_layout(&streamed_path).1, "Uncompressed");
+}
+
+#[test]
+fn test_conversion_with_csv_dialect() {
+    let temp_dir = TempDir::new().unwrap();
+    let csv_path = temp_dir.path().join("european.csv");
+    std::fs::write(
+        &csv_path,
+        "id;rate;opened\n1;0,5;31.01.2024\n2;NA;01.02.2024\n3;2,25;NA\n",
+    )
+    .unwrap();
+    let dialect = CsvDialect {
+        separator: b';',
+        decimal_comma: true,
+        date_format: Some("%d.%m.%Y".to_string()),
+        null_value: Some("NA".to_string()),
+        ..Default::default()
+    };
+
+    // Streaming and fast mode read the dialect alike
+    let mut converted = Vec::new();
+    for (name, fast) in [("streamed.parquet", false), ("fast.parquet", true)] {
+        let parquet_path = temp_dir.path().join(name);
+        run_convert_with(
+            &csv_path,
+            Some(&parquet_path),
+            1000,
+            None,
+            fast,
+            &ParquetOptions::CONVERT,
+            &dialect,
+        )
+        .unwrap();
+        let df = LazyFrame::scan_parquet(&parquet_path, Default::default())
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert_eq!(df.shape(), (3, 3));
+        assert_eq!(df.column("rate").unwrap().dtype(), &DataType::Float64);
+        assert_eq!(df.column("opened").unwrap().dtype(), &DataType::Date);
+        converted.push(df);
+    }
+    assert!(converted[0].equals_missing(&converted[1]));
+
+    // ...and Parquet converts back to the same text
+    let back = temp_dir.path().join("back.csv");
+    run_convert_with(
+        &temp_dir.path().join("fast.parquet"),
+        Some(&back),
+        1000,
+        None,
+        false,
+        &ParquetOptions::CONVERT,
+        &dialect,
+    )
+    .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&back).unwrap(),
+        std::fs::read_to_string(&csv_path).unwrap()
+    );
+}
+
+#[test]
+fn test_chunked_conversion_matches_in_memory_read() {
+    let temp_dir = TempDir::new().unwrap();
+    let csv_path = temp_dir.path().join("chunked.csv");
+    let mut text = String::from("id,note,score\n");
+    for i in 0..50 {
+        // Quoted line breaks and escaped quotes must not split a record
+        text.push_str(&format!("{},\"line {}\nnext \"\"q\"\"\",{}.5\n", i, i, i));
+    }
+    std::fs::write(&csv_path, &text).unwrap();
+    let expected = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(csv_path.clone()))
+        .unwrap()
+        .finish()
+        .unwrap();
+
+    let csv = CsvDialect::default();
+    let schema = csv
+        .scan(&csv_path)
+        .finish()
+        .unwrap()
+        .collect_schema()
+        .unwrap();
+    let options = ParquetOptions {
+        row_group_size: Some(16),
+        ..ParquetOptions::CONVERT
+    };
+    let parquet_path = temp_dir.path().join("chunked.parquet");
+    let mut progress = Vec::new();
+    let written = CsvChunker::new(&csv, schema.clone(), Some(1000))
+        .chunk_bytes(200)
+        .convert(
+            &csv_path,
+            &parquet_path,
+            ChunkedOutput::Parquet(options),
+            |read, total| progress.push((read, total)),
+        )
+        .unwrap();
+    assert_eq!((written.rows, written.columns), (50, 3));
+    assert!(written.chunks > 5, "got {} chunks", written.chunks);
+    assert_eq!(progress.len(), written.chunks);
+    assert!(progress.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert_eq!(
+        progress.last(),
+        Some(&(text.len() as u64, text.len() as u64))
+    );
+
+    // Row groups are cut at the configured size, not at chunk edges
+    let mut reader = ParquetReader::new(std::fs::File::open(&parquet_path).unwrap());
+    let group_rows: Vec<usize> = reader
+        .get_metadata()
+        .unwrap()
+        .row_groups
+        .iter()
+        .map(|group| group.num_rows())
+        .collect();
+    assert_eq!(group_rows, vec![16, 16, 16, 2]);
+    let result = LazyFrame::scan_parquet(&parquet_path, Default::default())
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(result.equals_missing(&expected));
+
+    let feather_path = temp_dir.path().join("chunked.feather");
+    CsvChunker::new(&csv, schema, Some(1000))
+        .chunk_bytes(200)
+        .convert(&csv_path, &feather_path, ChunkedOutput::Ipc, |_, _| {})
+        .unwrap();
+    let result = LazyFrame::scan_ipc(&feather_path, Default::default())
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(result.equals_missing(&expected));
+}
@@ -2,9 +2,12 @@
 
 use lophi::pipeline::{
     find_correlated_pairs, find_correlated_pairs_auto, find_correlated_pairs_matrix,
-    select_features_to_drop, AssociationMeasure, CorrelatedPair,
+    pair_decisions, resolve_correlated_pairs, resolved_pair_decisions, select_features_to_drop,
+    select_features_to_drop_with_keep, AssociationMeasure, CorrKeepPolicy, CorrelatedPair,
+    CorrelationStrategy, DropRule, FeatureMetadata,
 };
 use polars::prelude::*;
+use std::collections::{HashMap, HashSet};
 
 #[path = "common/mod.rs"]
 mod common;
@@ -77,6 +80,8 @@ fn test_select_features_to_drop_protects_target() {
         feature2: "feature_a".to_string(),
         correlation: 0.98,
         measure: AssociationMeasure::Pearson,
+        theils_u: None,
+        rationale: None,
     }];
 
     let to_drop = select_features_to_drop(&pairs, "target", None);
@@ -94,6 +99,8 @@ fn test_select_features_to_drop_target_in_second_position() {
         feature2: "target".to_string(),
         correlation: 0.98,
         measure: AssociationMeasure::Pearson,
+        theils_u: None,
+        rationale: None,
     }];
 
     let to_drop = select_features_to_drop(&pairs, "target", None);
@@ -116,12 +123,16 @@ fn test_select_drops_more_frequent_feature() {
             feature2: "feature_b".to_string(),
             correlation: 0.96,
             measure: AssociationMeasure::Pearson,
+            theils_u: None,
+            rationale: None,
         },
         CorrelatedPair {
             feature1: "feature_a".to_string(),
             feature2: "feature_c".to_string(),
             correlation: 0.97,
             measure: AssociationMeasure::Pearson,
+            theils_u: None,
+            rationale: None,
         },
     ];
 
@@ -152,18 +163,24 @@ fn test_already_resolved_pairs_skipped() {
             feature2: "b".to_string(),
             correlation: 0.98,
             measure: AssociationMeasure::Pearson,
+            theils_u: None,
+            rationale: None,
         },
         CorrelatedPair {
             feature1: "a".to_string(),
             feature2: "c".to_string(),
             correlation: 0.97,
             measure: AssociationMeasure::Pearson,
+            theils_u: None,
+            rationale: None,
         },
         CorrelatedPair {
             feature1: "b".to_string(),
             feature2: "c".to_string(),
             correlation: 0.96,
             measure: AssociationMeasure::Pearson,
+            theils_u: None,
+            rationale: None,
         },
     ];
 
@@ -633,10 +650,8 @@ fn test_matrix_empty_dataframe_returns_empty() {
 #[test]
 fn test_all_null_numeric_columns() {
     // Two entirely-null Float64 columns — no valid data to correlate.
-    let col_a: Column =
-        Series::full_null("col_a".into(), 5, &DataType::Float64).into_column();
-    let col_b: Column =
-        Series::full_null("col_b".into(), 5, &DataType::Float64).into_column();
+    let col_a: Column = Series::full_null("col_a".into(), 5, &DataType::Float64).into_column();
+    let col_b: Column = Series::full_null("col_b".into(), 5, &DataType::Float64).into_column();
 
     let df = DataFrame::new(vec![col_a, col_b]).unwrap();
     let weights = vec![1.0; 5];
@@ -812,18 +827,24 @@ fn test_already_resolved_pairs_exact_drops() {
             feature2: "b".to_string(),
             correlation: 0.98,
             measure: AssociationMeasure::Pearson,
+            theils_u: None,
+            rationale: None,
         },
         CorrelatedPair {
             feature1: "a".to_string(),
             feature2: "c".to_string(),
             correlation: 0.97,
             measure: AssociationMeasure::Pearson,
+            theils_u: None,
+            rationale: None,
         },
         CorrelatedPair {
             feature1: "b".to_string(),
             feature2: "c".to_string(),
             correlation: 0.96,
             measure: AssociationMeasure::Pearson,
+            theils_u: None,
+            rationale: None,
         },
     ];
 
@@ -867,10 +888,7 @@ fn test_all_weights_zero_returns_empty() {
     let weights = vec![0.0; 5];
 
     let result = find_correlated_pairs(&df, 0.5, &weights, None);
-    assert!(
-        result.is_ok(),
-        "All-zero weights should not cause an error"
-    );
+    assert!(result.is_ok(), "All-zero weights should not cause an error");
     let pairs = result.unwrap();
     // No finite correlation can be derived from zero-weight data.
     for pair in &pairs {
@@ -940,8 +958,7 @@ fn test_auto_falls_back_to_pairwise_when_nulls_present() {
     );
 
     let weights = vec![1.0; n];
-    let auto_pairs =
-        find_correlated_pairs_auto(&df, 0.9, &weights, None, None).unwrap();
+    let auto_pairs = find_correlated_pairs_auto(&df, 0.9, &weights, None, None).unwrap();
     let pw_pairs = find_correlated_pairs(&df, 0.9, &weights, None).unwrap();
 
     assert_eq!(
@@ -952,3 +969,381 @@ fn test_auto_falls_back_to_pairwise_when_nulls_present() {
         pw_pairs.len()
     );
 }
+
+fn pearson_pair(f1: &str, f2: &str, correlation: f64) -> CorrelatedPair {
+    CorrelatedPair {
+        feature1: f1.to_string(),
+        feature2: f2.to_string(),
+        correlation,
+        measure: AssociationMeasure::Pearson,
+        theils_u: None,
+        rationale: None,
+    }
+}
+
+#[test]
+fn test_keep_pin_overrides_waterfall() {
+    // Frequency would drop "a" (it appears in both pairs); pinning it drops the partners
+    let pairs = vec![pearson_pair("a", "b", 0.96), pearson_pair("a", "c", 0.95)];
+    let keep: HashSet<String> = ["a".to_string()].into_iter().collect();
+
+    let to_drop = select_features_to_drop_with_keep(&pairs, "target", None, &keep);
+    let drop_names: Vec<&str> = to_drop.iter().map(|f| f.feature.as_str()).collect();
+
+    assert_eq!(drop_names, vec!["b", "c"]);
+    assert!(to_drop[0].reason.contains("kept by override"));
+}
+
+#[test]
+fn test_pair_with_both_members_kept_is_unresolved() {
+    let pairs = vec![pearson_pair("a", "b", 0.96), pearson_pair("b", "c", 0.95)];
+    let keep: HashSet<String> = ["a".to_string(), "b".to_string()].into_iter().collect();
+
+    let to_drop = select_features_to_drop_with_keep(&pairs, "target", None, &keep);
+    let drop_names: Vec<&str> = to_drop.iter().map(|f| f.feature.as_str()).collect();
+    assert_eq!(drop_names, vec!["c"]);
+
+    // The unresolved a/b pair is skipped when matching drops to pairs
+    let decisions = pair_decisions(&pairs, &to_drop);
+    assert_eq!(decisions.len(), 1);
+    assert_eq!(decisions[0].kept, "b");
+    assert_eq!(decisions[0].dropped, "c");
+}
+
+#[test]
+fn test_pair_decisions_follow_selection_order() {
+    let pairs = vec![
+        pearson_pair("a", "b", 0.98),
+        pearson_pair("a", "c", 0.97),
+        pearson_pair("d", "e", 0.96),
+    ];
+    let to_drop = select_features_to_drop(&pairs, "target", None);
+    let decisions = pair_decisions(&pairs, &to_drop);
+
+    assert_eq!(decisions.len(), to_drop.len());
+    for (decision, drop) in decisions.iter().zip(&to_drop) {
+        assert_eq!(decision.dropped, drop.feature);
+        assert_ne!(decision.kept, decision.dropped);
+        assert!(
+            [&decision.pair.feature1, &decision.pair.feature2].contains(&&decision.kept),
+            "kept feature must belong to the deciding pair"
+        );
+    }
+}
+
+#[test]
+fn test_empty_keep_matches_default_selection() {
+    let pairs = vec![
+        pearson_pair("a", "b", 0.98),
+        pearson_pair("b", "c", 0.97),
+        pearson_pair("c", "d", 0.96),
+    ];
+    let default = select_features_to_drop(&pairs, "target", None);
+    let with_keep = select_features_to_drop_with_keep(&pairs, "target", None, &HashSet::new());
+
+    let names = |v: &[lophi::pipeline::FeatureToDrop]| -> Vec<String> {
+        v.iter().map(|f| f.feature.clone()).collect()
+    };
+    assert_eq!(names(&default), names(&with_keep));
+}
+
+#[test]
+fn test_resolve_records_pair_rationale() {
+    let mut pairs = vec![
+        pearson_pair("a", "b", 0.98),
+        pearson_pair("b", "c", 0.97),
+        pearson_pair("c", "d", 0.96),
+    ];
+    let mut metadata = HashMap::new();
+    for (name, iv) in [("a", 0.40), ("b", 0.10), ("c", 0.20), ("d", 0.20)] {
+        metadata.insert(
+            name.to_string(),
+            FeatureMetadata {
+                iv: Some(iv),
+                missing_ratio: Some(0.0),
+                r_squared: None,
+            },
+        );
+    }
+
+    let to_drop = resolve_correlated_pairs(
+        &mut pairs,
+        "target",
+        Some(&metadata),
+        &HashSet::new(),
+        CorrelationStrategy::Pairwise,
+        &CorrKeepPolicy::default(),
+    );
+    let names: Vec<&str> = to_drop.iter().map(|f| f.feature.as_str()).collect();
+    assert_eq!(names, ["b", "c"]);
+
+    let ab = pairs[0].rationale.as_ref().unwrap();
+    assert_eq!((ab.kept.as_str(), ab.dropped.as_str()), ("a", "b"));
+    assert_eq!(ab.rule, DropRule::Iv);
+    assert_eq!(ab.values, Some((0.40, 0.10)));
+    // b was already dropped, so b/c decides nothing
+    assert!(pairs[1].rationale.is_none());
+    // Equal IV: c is in more pairs than d
+    let cd = pairs[2].rationale.as_ref().unwrap();
+    assert_eq!(cd.kept, "d");
+    assert_eq!(cd.rule, DropRule::Frequency);
+    assert_eq!(cd.values, Some((1.0, 2.0)));
+
+    // Resolving again with b pinned replaces the earlier rationales
+    let keep: HashSet<String> = ["b".to_string()].into();
+    resolve_correlated_pairs(
+        &mut pairs,
+        "target",
+        Some(&metadata),
+        &keep,
+        CorrelationStrategy::Pairwise,
+        &CorrKeepPolicy::default(),
+    );
+    let ab = pairs[0].rationale.as_ref().unwrap();
+    assert_eq!(ab.dropped, "a");
+    assert_eq!(ab.rule, DropRule::Override);
+    assert_eq!(pairs[1].rationale.as_ref().unwrap().dropped, "c");
+    assert!(pairs[2].rationale.is_none());
+}
+
+fn iv_metadata(ivs: &[(&str, f64)]) -> HashMap<String, FeatureMetadata> {
+    ivs.iter()
+        .map(|(name, iv)| {
+            (
+                name.to_string(),
+                FeatureMetadata {
+                    iv: Some(*iv),
+                    missing_ratio: Some(0.0),
+                    r_squared: None,
+                },
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_cluster_strategy_keeps_one_representative_per_cluster() {
+    // a-b-c-d is one chain; e-f a separate cluster
+    let mut pairs = vec![
+        pearson_pair("a", "b", 0.98),
+        pearson_pair("b", "c", 0.97),
+        pearson_pair("c", "d", 0.96),
+        pearson_pair("e", "f", 0.95),
+    ];
+    let metadata = iv_metadata(&[
+        ("a", 0.40),
+        ("b", 0.10),
+        ("c", 0.20),
+        ("d", 0.20),
+        ("e", 0.05),
+        ("f", 0.30),
+    ]);
+
+    let to_drop = resolve_correlated_pairs(
+        &mut pairs,
+        "target",
+        Some(&metadata),
+        &HashSet::new(),
+        CorrelationStrategy::Cluster,
+        &CorrKeepPolicy::default(),
+    );
+    let names: Vec<&str> = to_drop.iter().map(|f| f.feature.as_str()).collect();
+    // Pairwise resolution would keep d, whose only partner c is dropped
+    assert_eq!(names, ["b", "c", "d", "e"]);
+
+    // Each drop sits on the pair that links it to the cluster
+    let cd = pairs[2].rationale.as_ref().unwrap();
+    assert_eq!((cd.kept.as_str(), cd.dropped.as_str()), ("a", "d"));
+    assert_eq!(cd.rule, DropRule::Iv);
+    assert_eq!(cd.values, Some((0.40, 0.20)));
+    assert!(to_drop[2].reason.contains("cluster of 4 represented by a"));
+    assert_eq!(pairs[3].rationale.as_ref().unwrap().kept, "f");
+
+    let decisions = resolved_pair_decisions(&pairs);
+    let dropped: Vec<&str> = decisions.iter().map(|d| d.dropped.as_str()).collect();
+    assert_eq!(dropped, names);
+}
+
+#[test]
+fn test_cluster_strategy_keeps_protected_members() {
+    let mut pairs = vec![
+        pearson_pair("a", "b", 0.98),
+        pearson_pair("b", "c", 0.97),
+        pearson_pair("c", "target", 0.96),
+    ];
+    let metadata = iv_metadata(&[("a", 0.40), ("b", 0.10), ("c", 0.20)]);
+
+    // The target represents its cluster
+    let to_drop = resolve_correlated_pairs(
+        &mut pairs,
+        "target",
+        Some(&metadata),
+        &HashSet::new(),
+        CorrelationStrategy::Cluster,
+        &CorrKeepPolicy::default(),
+    );
+    let names: Vec<&str> = to_drop.iter().map(|f| f.feature.as_str()).collect();
+    assert_eq!(names, ["a", "b", "c"]);
+    assert!(to_drop
+        .iter()
+        .all(|d| d.reason.ends_with("dropped to protect target")));
+
+    // Every protected member is kept; the rest of the cluster is dropped
+    let mut pairs = vec![pearson_pair("a", "b", 0.98), pearson_pair("b", "c", 0.97)];
+    let keep: HashSet<String> = ["b".to_string(), "c".to_string()].into();
+    let to_drop = resolve_correlated_pairs(
+        &mut pairs,
+        "target",
+        Some(&metadata),
+        &keep,
+        CorrelationStrategy::Cluster,
+        &CorrKeepPolicy::default(),
+    );
+    assert_eq!(to_drop.len(), 1);
+    assert_eq!(to_drop[0].feature, "a");
+    let ab = pairs[0].rationale.as_ref().unwrap();
+    assert_eq!(ab.kept, "b");
+    assert_eq!(ab.rule, DropRule::Override);
+    assert!(pairs[1].rationale.is_none());
+}
+
+fn keep_metadata(features: &[(&str, f64, f64)]) -> HashMap<String, FeatureMetadata> {
+    features
+        .iter()
+        .map(|(name, iv, missing)| {
+            (
+                name.to_string(),
+                FeatureMetadata {
+                    iv: Some(*iv),
+                    missing_ratio: Some(*missing),
+                    r_squared: None,
+                },
+            )
+        })
+        .collect()
+}
+
+fn resolve_with_policy(
+    pairs: &mut [CorrelatedPair],
+    metadata: &HashMap<String, FeatureMetadata>,
+    strategy: CorrelationStrategy,
+    policy: &CorrKeepPolicy,
+) -> Vec<String> {
+    resolve_correlated_pairs(
+        pairs,
+        "target",
+        Some(metadata),
+        &HashSet::new(),
+        strategy,
+        policy,
+    )
+    .into_iter()
+    .map(|f| f.feature)
+    .collect()
+}
+
+#[test]
+fn test_keep_policy_orders_the_pair_rules() {
+    // b has the higher IV, a the lower missing ratio
+    let metadata = keep_metadata(&[("a", 0.10, 0.05), ("b", 0.30, 0.20)]);
+    let mut pairs = vec![pearson_pair("b", "a", 0.9)];
+
+    let run = |pairs: &mut [CorrelatedPair], policy| {
+        resolve_with_policy(pairs, &metadata, CorrelationStrategy::Pairwise, &policy)
+    };
+    assert_eq!(run(&mut pairs, CorrKeepPolicy::HigherIv), ["a"]);
+    assert_eq!(pairs[0].rationale.as_ref().unwrap().rule, DropRule::Iv);
+
+    assert_eq!(run(&mut pairs, CorrKeepPolicy::LowerMissing), ["b"]);
+    let rationale = pairs[0].rationale.as_ref().unwrap();
+    assert_eq!(rationale.rule, DropRule::MissingRatio);
+    assert_eq!(rationale.values, Some((0.05, 0.20)));
+
+    assert_eq!(run(&mut pairs, CorrKeepPolicy::FirstAlphabetical), ["b"]);
+    assert_eq!(
+        pairs[0].rationale.as_ref().unwrap().rule,
+        DropRule::Alphabetical
+    );
+}
+
+#[test]
+fn test_custom_list_prefers_earlier_prefixes() {
+    let metadata = keep_metadata(&[
+        ("bureau_score", 0.10, 0.0),
+        ("internal_score", 0.40, 0.0),
+        ("other_score", 0.50, 0.0),
+        ("bureau_limit", 0.20, 0.0),
+    ]);
+    let policy = CorrKeepPolicy::parse("custom_list", &["bureau_", "internal_"]).unwrap();
+
+    let mut pairs = vec![
+        pearson_pair("internal_score", "bureau_score", 0.9),
+        pearson_pair("other_score", "internal_score", 0.9),
+    ];
+    let dropped = resolve_with_policy(
+        &mut pairs,
+        &metadata,
+        CorrelationStrategy::Pairwise,
+        &policy,
+    );
+    // A listed prefix beats a higher IV, and unlisted names rank last
+    assert_eq!(dropped, ["internal_score"]);
+    let rationale = pairs[0].rationale.as_ref().unwrap();
+    assert_eq!(rationale.rule, DropRule::Prefix);
+    assert_eq!(rationale.values, None);
+
+    let mut pairs = vec![pearson_pair("other_score", "internal_score", 0.9)];
+    let dropped = resolve_with_policy(
+        &mut pairs,
+        &metadata,
+        CorrelationStrategy::Pairwise,
+        &policy,
+    );
+    assert_eq!(dropped, ["other_score"]);
+
+    // Within one prefix the default rules decide
+    let mut pairs = vec![pearson_pair("bureau_score", "bureau_limit", 0.9)];
+    let dropped = resolve_with_policy(
+        &mut pairs,
+        &metadata,
+        CorrelationStrategy::Pairwise,
+        &policy,
+    );
+    assert_eq!(dropped, ["bureau_score"]);
+    assert_eq!(pairs[0].rationale.as_ref().unwrap().rule, DropRule::Iv);
+}
+
+#[test]
+fn test_custom_list_picks_the_cluster_representative() {
+    let metadata = keep_metadata(&[
+        ("internal_a", 0.40, 0.0),
+        ("bureau_b", 0.10, 0.0),
+        ("bureau_c", 0.20, 0.0),
+    ]);
+    let policy = CorrKeepPolicy::parse("custom_list", &["bureau_", "internal_"]).unwrap();
+    let mut pairs = vec![
+        pearson_pair("internal_a", "bureau_b", 0.9),
+        pearson_pair("bureau_b", "bureau_c", 0.9),
+    ];
+
+    let dropped = resolve_with_policy(&mut pairs, &metadata, CorrelationStrategy::Cluster, &policy);
+    assert_eq!(dropped, ["internal_a", "bureau_b"]);
+    let ab = pairs[0].rationale.as_ref().unwrap();
+    assert_eq!((ab.kept.as_str(), ab.rule), ("bureau_c", DropRule::Prefix));
+    assert_eq!(pairs[1].rationale.as_ref().unwrap().rule, DropRule::Iv);
+}
+
+#[test]
+fn test_keep_policy_still_protects_the_target() {
+    let metadata = keep_metadata(&[("a_feature", 0.10, 0.0)]);
+    let mut pairs = vec![pearson_pair("a_feature", "target", 0.9)];
+
+    let dropped = resolve_with_policy(
+        &mut pairs,
+        &metadata,
+        CorrelationStrategy::Pairwise,
+        &CorrKeepPolicy::FirstAlphabetical,
+    );
+    assert_eq!(dropped, ["a_feature"]);
+    assert_eq!(pairs[0].rationale.as_ref().unwrap().rule, DropRule::Target);
+}
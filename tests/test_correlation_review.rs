@@ -0,0 +1,90 @@
+//! Tests for the interactive correlated-pair review screen
+
+#![cfg(feature = "tui")]
+
+use crossterm::event::KeyCode;
+use lophi::cli::correlation_review::CorrelationReview;
+use lophi::pipeline::{CorrelationReviewItem, CorrelationReviewRequest};
+use std::sync::mpsc;
+
+fn item(feature1: &str, feature2: &str, proposed_drop: &str) -> CorrelationReviewItem {
+    CorrelationReviewItem {
+        feature1: feature1.to_string(),
+        feature2: feature2.to_string(),
+        iv1: Some(0.2),
+        iv2: Some(0.3),
+        missing1: Some(0.0),
+        missing2: Some(0.1),
+        description1: None,
+        description2: None,
+        correlation: 0.95,
+        measure: "Pearson".to_string(),
+        proposed_drop: proposed_drop.to_string(),
+    }
+}
+
+fn open_review() -> (CorrelationReview, mpsc::Receiver<Vec<usize>>) {
+    let (reply, rx) = mpsc::channel();
+    let review = CorrelationReview::new(CorrelationReviewRequest {
+        items: vec![
+            item("a", "b", "a"),
+            item("c", "d", "d"),
+            item("e", "f", "e"),
+        ],
+        reply,
+    });
+    (review, rx)
+}
+
+#[test]
+fn test_swap_and_confirm_sends_reversed_indices() {
+    let (mut review, rx) = open_review();
+
+    assert!(!review.handle_key(KeyCode::Char(' ')));
+    assert_eq!(review.dropped(0), "b");
+    assert!(!review.handle_key(KeyCode::Down));
+    assert!(!review.handle_key(KeyCode::Down));
+    assert!(!review.handle_key(KeyCode::Right));
+    assert_eq!(review.dropped(2), "f");
+    // Untouched pair keeps the automatic choice
+    assert_eq!(review.dropped(1), "d");
+
+    assert!(review.handle_key(KeyCode::Enter));
+    assert_eq!(rx.recv().unwrap(), vec![0, 2]);
+}
+
+#[test]
+fn test_swapping_twice_restores_proposal() {
+    let (mut review, _rx) = open_review();
+    review.handle_key(KeyCode::Char(' '));
+    review.handle_key(KeyCode::Char(' '));
+    assert_eq!(review.dropped(0), "a");
+    assert!(review.reversed_indices().is_empty());
+}
+
+#[test]
+fn test_escape_keeps_automatic_choices() {
+    let (mut review, rx) = open_review();
+    review.handle_key(KeyCode::Char(' '));
+    assert!(review.handle_key(KeyCode::Esc));
+    assert!(rx.recv().unwrap().is_empty());
+}
+
+#[test]
+fn test_reset_and_cursor_bounds() {
+    let (mut review, _rx) = open_review();
+    // Moving up from the first pair stays on it
+    review.handle_key(KeyCode::Up);
+    review.handle_key(KeyCode::Char(' '));
+    assert_eq!(review.reversed_indices(), vec![0]);
+
+    // Moving past the last pair stays on it
+    for _ in 0..5 {
+        review.handle_key(KeyCode::Down);
+    }
+    review.handle_key(KeyCode::Char(' '));
+    assert_eq!(review.reversed_indices(), vec![0, 2]);
+
+    review.handle_key(KeyCode::Char('r'));
+    assert!(review.reversed_indices().is_empty());
+}
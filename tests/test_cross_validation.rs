@@ -0,0 +1,178 @@
+//! Integration tests for the k-fold cross-validated IV (`--cv-folds`, `--cv-min-iv`)
+
+use assert_cmd::Command;
+use polars::prelude::{df, DataFrame};
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 800 rows with a 25% event rate. `signal` separates the classes; `noise`
+/// is unrelated to the target, so its bins only fit the rows they were cut on.
+fn signal_noise_df() -> DataFrame {
+    let n = 800;
+    df! {
+        "target" => (0..n).map(|i| i32::from(i % 4 == 0)).collect::<Vec<_>>(),
+        "signal" => (0..n)
+            .map(|i| (i % 3) as f64 + if i % 4 == 0 { 2.0 } else { 0.0 })
+            .collect::<Vec<_>>(),
+        "noise" => (0..n).map(|i| ((i * 7919) % 101) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn lophi(dir: &std::path::Path, input: &std::path::Path, args: &[&str]) -> Command {
+    let config = dir.join("pipeline.toml");
+    std::fs::write(&config, "use_solver = false\n").unwrap();
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("--config")
+        .arg(&config)
+        .arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--gini-threshold", "0.0"])
+        .args(args);
+    cmd
+}
+
+/// A JSON file of the packaged report zip
+fn read_packaged_json(dir: &std::path::Path, name: &str) -> serde_json::Value {
+    use std::io::Read;
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name(name)
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+fn entry<'a>(entries: &'a serde_json::Value, key: &str, feature: &str) -> &'a serde_json::Value {
+    entries
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f[key] == feature)
+        .unwrap_or_else(|| panic!("no entry for {}", feature))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_cv_folds_adds_holdout_iv_to_gini_export() {
+    let mut df = signal_noise_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    lophi(dir.path(), &input, &["--cv-folds", "4"])
+        .assert()
+        .success();
+
+    let export = read_packaged_json(dir.path(), "test_data_gini_analysis.json");
+    assert_eq!(export["metadata"]["cv_folds"], 4);
+
+    let signal = &entry(&export["features"], "feature_name", "signal")["cross_validation"];
+    assert_eq!(signal["fold_ivs"].as_array().unwrap().len(), 4);
+    assert!(signal["gini_mean"].as_f64().unwrap() > 0.5);
+    assert!(signal["iv_std"].as_f64().unwrap() >= 0.0);
+
+    let noise = &entry(&export["features"], "feature_name", "noise")["cross_validation"];
+    assert!(noise["iv_mean"].as_f64().unwrap() < signal["iv_mean"].as_f64().unwrap() / 5.0);
+
+    // Reported only: nothing is dropped without --cv-min-iv
+    assert_eq!(export["summary"]["features_dropped"], 0);
+}
+
+#[test]
+fn test_cv_min_iv_drops_low_holdout_iv() {
+    let mut df = signal_noise_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    lophi(
+        dir.path(),
+        &input,
+        &["--cv-folds", "4", "--cv-min-iv", "0.1"],
+    )
+    .assert()
+    .success();
+
+    let export = read_packaged_json(dir.path(), "test_data_gini_analysis.json");
+    assert_eq!(
+        entry(&export["features"], "feature_name", "noise")["dropped"],
+        true
+    );
+    assert_eq!(
+        entry(&export["features"], "feature_name", "signal")["dropped"],
+        false
+    );
+
+    let report = read_packaged_json(dir.path(), "test_data_reduction_report.json");
+    let noise = entry(&report["features"], "name", "noise");
+    assert_eq!(noise["status"], "dropped");
+    assert_eq!(noise["dropped_at_stage"], "gini");
+    assert!(noise["reason"]
+        .as_str()
+        .unwrap()
+        .starts_with("Cross-validated IV"));
+}
+
+#[test]
+fn test_seed_reproduces_cv_and_is_recorded() {
+    let mut df = signal_noise_df();
+    let mut run = |seed: &str| {
+        let (dir, input) = create_temp_csv(&mut df);
+        lophi(dir.path(), &input, &["--cv-folds", "4", "--seed", seed])
+            .assert()
+            .success();
+        let export = read_packaged_json(dir.path(), "test_data_gini_analysis.json");
+        let report = read_packaged_json(dir.path(), "test_data_reduction_report.json");
+        (export["features"].clone(), report)
+    };
+
+    let (first, report) = run("7");
+    let (second, _) = run("7");
+    let (other, _) = run("8");
+    assert_eq!(report["metadata"]["settings"]["seed"], 7);
+
+    // Bit-identical holdout scores for the same seed, other folds for another
+    assert_eq!(first, second);
+    let fold_ivs = |features: &serde_json::Value| {
+        entry(features, "feature_name", "signal")["cross_validation"]["fold_ivs"].clone()
+    };
+    assert_ne!(fold_ivs(&first), fold_ivs(&other));
+}
+
+#[test]
+fn test_cv_without_flags_leaves_export_unchanged() {
+    let mut df = signal_noise_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    lophi(dir.path(), &input, &[]).assert().success();
+
+    let export = read_packaged_json(dir.path(), "test_data_gini_analysis.json");
+    assert!(export["metadata"].get("cv_folds").is_none());
+    assert!(export["features"][0].get("cross_validation").is_none());
+}
+
+#[test]
+fn test_cv_folds_rejects_continuous_target() {
+    let mut df = df! {
+        "target" => (0..50).map(|i| i as f64 * 1.5).collect::<Vec<_>>(),
+        "x" => (0..50).map(|i| (i % 7) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    lophi(
+        dir.path(),
+        &input,
+        &["--target-type", "continuous", "--cv-folds", "3"],
+    )
+    .assert()
+    .failure();
+}
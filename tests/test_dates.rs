@@ -0,0 +1,194 @@
+//! Integration tests for date and datetime features (`--date-policy`)
+
+use assert_cmd::Command;
+use lophi::pipeline::{apply_saved_reduction, DatePolicy, SavedReduction};
+use lophi::Reducer;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 400 rows with a 25% event rate. `opened` is a date one day apart per row,
+/// `last_seen` a millisecond datetime, and `income` a plain float.
+fn date_df() -> DataFrame {
+    let n = 400;
+    let opened = Series::new(
+        "opened".into(),
+        (0..n).map(|i| 18_000 + i).collect::<Vec<_>>(),
+    )
+    .cast(&DataType::Date)
+    .unwrap();
+    let last_seen = Series::new(
+        "last_seen".into(),
+        (0..n)
+            .map(|i| (i as i64 % 30) * 86_400_000 + 1_600_000_000_000)
+            .collect::<Vec<_>>(),
+    )
+    .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+    .unwrap();
+    DataFrame::new(vec![
+        Column::new(
+            "target".into(),
+            (0..n).map(|i| i32::from(i % 4 == 0)).collect::<Vec<_>>(),
+        ),
+        opened.into(),
+        last_seen.into(),
+        Column::new(
+            "income".into(),
+            (0..n).map(|i| 1000.0 + (i % 17) as f64).collect::<Vec<_>>(),
+        ),
+    ])
+    .unwrap()
+}
+
+fn run_lophi(input: &std::path::Path, args: &[&str]) {
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--binning-backend", "greedy"])
+        .args(["--gini-threshold", "0.0", "--correlation-threshold", "1.0"])
+        .args(args)
+        .assert()
+        .success();
+}
+
+fn read_report_json(dir: &std::path::Path) -> serde_json::Value {
+    use std::io::Read;
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_reduction_report.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+fn reduced(dir: &std::path::Path) -> DataFrame {
+    let file = std::fs::File::open(dir.join("test_data_reduced.parquet")).unwrap();
+    ParquetReader::new(file).finish().unwrap()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_dates_are_kept_unscored_by_default() {
+    let mut df = date_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    run_lophi(&input, &[]);
+
+    let output = reduced(dir.path());
+    assert_eq!(output.column("opened").unwrap().dtype(), &DataType::Date);
+    assert_has_columns(&output, &["last_seen", "income"]);
+
+    let report = read_report_json(dir.path());
+    assert_eq!(report["metadata"]["settings"]["date_policy"], "keep");
+    let temporal = report["metadata"]["temporal_columns"].as_array().unwrap();
+    assert_eq!(temporal.len(), 2);
+    assert_eq!(temporal[0]["feature"], "opened");
+    assert_eq!(temporal[0]["dtype"], "date");
+    assert!(temporal[0].get("derived").is_none());
+}
+
+#[test]
+fn test_date_policy_derive_replaces_dates_with_numeric_features() {
+    let mut df = date_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    run_lophi(&input, &["--date-policy", "derive"]);
+
+    let output = reduced(dir.path());
+    assert_missing_columns(&output, &["opened", "last_seen"]);
+    assert_has_columns(
+        &output,
+        &[
+            "opened_days",
+            "opened_month",
+            "opened_dow",
+            "last_seen_days",
+            "income",
+        ],
+    );
+
+    let report = read_report_json(dir.path());
+    assert_eq!(report["metadata"]["settings"]["date_policy"], "derive");
+    assert_eq!(
+        report["metadata"]["temporal_columns"][1]["derived"],
+        serde_json::json!(["last_seen_days", "last_seen_month", "last_seen_dow"])
+    );
+    // Derived features go through the screening stages like any other
+    let features = report["features"].as_array().unwrap();
+    let days = features
+        .iter()
+        .find(|f| f["name"] == "opened_days")
+        .unwrap();
+    assert!(days["analysis"]["gini"].is_object());
+    assert!(features.iter().all(|f| f["name"] != "opened"));
+}
+
+#[test]
+fn test_date_policy_drop_removes_dates() {
+    let mut df = date_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    run_lophi(&input, &["--date-policy", "drop"]);
+
+    let output = reduced(dir.path());
+    assert_missing_columns(&output, &["opened", "last_seen"]);
+    assert_has_columns(&output, &["target", "income"]);
+
+    let report = read_report_json(dir.path());
+    assert_eq!(report["metadata"]["settings"]["date_policy"], "drop");
+    assert_eq!(report["summary"]["initial_features"], 1);
+}
+
+#[test]
+fn test_unknown_date_policy_is_rejected() {
+    let mut df = date_df();
+    let (_dir, input) = create_temp_parquet(&mut df);
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--date-policy", "explode"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_reducer_date_policy_and_apply() {
+    let result = Reducer::from_dataframe(date_df(), "target")
+        .date_policy(DatePolicy::Derive)
+        .gini_threshold(0.0)
+        .correlation_threshold(1.0)
+        .run()
+        .unwrap();
+    assert_eq!(result.initial_features, 7);
+    assert_eq!(result.temporal[0].derived.len(), 3);
+    assert_has_columns(&result.dataframe, &["opened_month", "last_seen_dow"]);
+
+    // A saved derive run rebuilds the derived features on new data
+    let saved = SavedReduction {
+        target_column: "target".to_string(),
+        weight_column: None,
+        split_column: None,
+        date_policy: DatePolicy::Derive,
+        kept_features: vec!["opened_month".to_string(), "income".to_string()],
+        dropped_features: Vec::new(),
+        iv_analyses: Vec::new(),
+    };
+    let (applied, summary) = apply_saved_reduction(&date_df(), &saved, false).unwrap();
+    assert_eq!(summary.input_columns, 4);
+    assert_eq!(
+        applied.get_column_names_str(),
+        ["target", "opened_month", "income"]
+    );
+}
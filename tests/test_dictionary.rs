@@ -0,0 +1,155 @@
+//! Integration tests for feature descriptions from a data dictionary (`--dictionary`)
+//! and from SAS7BDAT column labels
+
+use assert_cmd::Command;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+fn lophi(input: &std::path::Path, dictionary: &std::path::Path) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--binning-backend", "greedy"])
+        .args(["--gini-threshold", "0.0", "--no-confirm", "--dictionary"])
+        .arg(dictionary)
+        .env("NO_COLOR", "1");
+    cmd
+}
+
+/// A file packaged in the reduction report zip
+fn zip_entry(zip: &std::path::Path, name: &str) -> String {
+    use std::io::Read;
+    let mut archive = ::zip::ZipArchive::new(std::fs::File::open(zip).unwrap()).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name(name)
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    content
+}
+
+#[test]
+fn test_dictionary_descriptions_reach_the_reports() {
+    let mut df = create_binary_target_dataframe();
+    let (dir, input) = create_temp_parquet(&mut df);
+    let dictionary = dir.path().join("dictionary.csv");
+    std::fs::write(
+        &dictionary,
+        "feature,description,owner\n\
+         predictive_feature,\"Months on book, capped\",Credit Risk\n\
+         retired_feature,No longer sourced,Data Office\n",
+    )
+    .unwrap();
+
+    lophi(&input, &dictionary).assert().success();
+
+    let zip = dir.path().join("test_data_reduction_report.zip");
+    let gini: serde_json::Value =
+        serde_json::from_str(&zip_entry(&zip, "test_data_gini_analysis.json")).unwrap();
+    let features = gini["features"].as_array().unwrap();
+    let predictive = features
+        .iter()
+        .find(|f| f["feature_name"] == "predictive_feature")
+        .unwrap();
+    assert_eq!(predictive["description"], "Months on book, capped");
+    assert_eq!(predictive["owner"], "Credit Risk");
+    let random = features
+        .iter()
+        .find(|f| f["feature_name"] == "random_feature")
+        .unwrap();
+    assert!(random.get("description").is_none());
+
+    let report: serde_json::Value =
+        serde_json::from_str(&zip_entry(&zip, "test_data_reduction_report.json")).unwrap();
+    let entry = report["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["name"] == "predictive_feature")
+        .unwrap();
+    assert_eq!(entry["description"], "Months on book, capped");
+    assert_eq!(entry["owner"], "Credit Risk");
+
+    let csv = zip_entry(&zip, "test_data_reduction_report.csv");
+    assert!(csv
+        .lines()
+        .next()
+        .unwrap()
+        .ends_with(",description,owner,label,format"));
+    assert!(csv.lines().any(|l| l.starts_with("predictive_feature,")
+        && l.ends_with(",\"Months on book, capped\",Credit Risk,,")));
+}
+
+#[test]
+fn test_dictionary_without_feature_column_is_rejected() {
+    let mut df = create_binary_target_dataframe();
+    let (dir, input) = create_temp_parquet(&mut df);
+    let dictionary = dir.path().join("dictionary.csv");
+    std::fs::write(&dictionary, "column,description\nage,Applicant age\n").unwrap();
+
+    let assert = lophi(&input, &dictionary).assert().code(2);
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(stderr.contains("no 'feature' column"));
+    assert!(!dir.path().join("test_data_reduction_report.zip").exists());
+}
+
+#[cfg(feature = "sas7bdat")]
+#[test]
+fn test_sas_labels_reach_the_reports() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("cars.sas7bdat");
+    std::fs::copy(
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/sas7bdat/cars.sas7bdat"
+        ),
+        &input,
+    )
+    .unwrap();
+    let dictionary = dir.path().join("dictionary.csv");
+    std::fs::write(&dictionary, "feature,owner\nWGT,Fleet Analytics\n").unwrap();
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "MPG", "--target-type", "continuous"])
+        .args([
+            "--binning-backend",
+            "greedy",
+            "--no-confirm",
+            "--dictionary",
+        ])
+        .arg(&dictionary)
+        .env("NO_COLOR", "1")
+        .assert()
+        .success();
+
+    let zip = dir.path().join("cars_reduction_report.zip");
+    let report: serde_json::Value =
+        serde_json::from_str(&zip_entry(&zip, "cars_reduction_report.json")).unwrap();
+    let features = report["features"].as_array().unwrap();
+    let feature = |name: &str| features.iter().find(|f| f["name"] == name).unwrap();
+    assert_eq!(feature("WGT")["label"], "vehicle weight in pounds");
+    assert_eq!(feature("WGT")["owner"], "Fleet Analytics");
+    assert_eq!(feature("CYL")["label"], "number of cylinders");
+    assert!(feature("CYL").get("owner").is_none());
+
+    let r2: serde_json::Value =
+        serde_json::from_str(&zip_entry(&zip, "cars_r2_analysis.json")).unwrap();
+    let wgt = r2["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["feature_name"] == "WGT")
+        .unwrap();
+    assert_eq!(wgt["label"], "vehicle weight in pounds");
+
+    let csv = zip_entry(&zip, "cars_reduction_report.csv");
+    assert!(csv.lines().any(
+        |l| l.starts_with("WGT,") && l.ends_with(",Fleet Analytics,vehicle weight in pounds,")
+    ));
+}
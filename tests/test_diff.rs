@@ -0,0 +1,85 @@
+//! Integration tests for the `diff` subcommand
+
+use assert_cmd::Command;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+/// Reduce the binary-target fixture with `gini_threshold`; returns the
+/// directory and the report zip
+fn run(gini_threshold: &str) -> (tempfile::TempDir, std::path::PathBuf) {
+    let mut df = create_binary_target_dataframe();
+    let (dir, input) = create_temp_parquet(&mut df);
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target", "--binning-backend", "greedy"])
+        .args(["--gini-threshold", gini_threshold])
+        .arg("--no-confirm")
+        .assert()
+        .success();
+    let zip = dir.path().join("test_data_reduction_report.zip");
+    assert!(zip.exists());
+    (dir, zip)
+}
+
+fn diff(a: &std::path::Path, b: &std::path::Path) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("diff").arg(a).arg(b).env("NO_COLOR", "1");
+    cmd
+}
+
+#[test]
+fn test_diff_of_two_runs() {
+    let (_dir_a, zip_a) = run("0.0");
+    let (dir_b, zip_b) = run("0.99");
+    let output = dir_b.path().join("runs_diff.json");
+
+    let assert = diff(&zip_a, &zip_b)
+        .arg("--output")
+        .arg(&output)
+        .assert()
+        .success();
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout);
+    assert!(stdout.contains("Threshold gini: 0 -> 0.99"), "{}", stdout);
+    assert!(stdout.contains("Dropped in B only"), "{}", stdout);
+
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+    assert_eq!(json["run_a_kind"], "reduction_report");
+    assert_eq!(json["threshold_changes"][0]["name"], "gini");
+    assert!(json["dropped_only_in_a"].as_array().unwrap().is_empty());
+    let dropped_b = json["dropped_only_in_b"].as_array().unwrap();
+    assert!(!dropped_b.is_empty());
+    // Same data, same bins: only the drop decisions differ
+    for feature in json["features"].as_array().unwrap() {
+        if let Some(delta) = feature["iv_delta"].as_f64() {
+            assert!(delta.abs() < 1e-9, "{}", feature);
+        }
+        if dropped_b.contains(&feature["feature"]) {
+            assert_eq!(feature["dropped_at_b"], "gini");
+        }
+    }
+}
+
+#[test]
+fn test_diff_default_output_and_identical_runs() {
+    let (dir, zip) = run("0.05");
+    let assert = diff(&zip, &zip).assert().success();
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout);
+    assert!(stdout.contains("Thresholds: no differences"));
+    assert!(stdout.contains("No feature changed"));
+    assert!(dir
+        .path()
+        .join("test_data_reduction_report_diff.json")
+        .exists());
+
+    let not_a_run = dir.path().join("other.json");
+    std::fs::write(&not_a_run, r#"{"columns": []}"#).unwrap();
+    diff(&zip, &not_a_run)
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("has no 'features' array"));
+}
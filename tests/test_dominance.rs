@@ -0,0 +1,184 @@
+//! Integration tests for the near-constant pre-filter (`--dominance-threshold`)
+
+use assert_cmd::Command;
+use lophi::pipeline::analyze_dominance;
+use lophi::Reducer;
+use polars::prelude::{df, CsvReadOptions, DataFrame, SerReader};
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 1000 rows with a 30% event rate. `region` holds a single value, `flag` is
+/// "N" on all but 3 rows, `score` separates the classes and `sparse` is null
+/// on 99.7% of rows.
+fn dominance_df() -> DataFrame {
+    let n = 1000;
+    df! {
+        "target" => (0..n).map(|i| i32::from(i % 10 < 3)).collect::<Vec<_>>(),
+        "region" => vec!["ZA"; n],
+        "flag" => (0..n).map(|i| if i < 3 { "Y" } else { "N" }).collect::<Vec<_>>(),
+        "score" => (0..n)
+            .map(|i| (i % 7) as f64 + if i % 10 < 3 { 4.0 } else { 0.0 })
+            .collect::<Vec<_>>(),
+        "sparse" => (0..n)
+            .map(|i| (i < 3).then_some((i % 2) as f64))
+            .collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn run_lophi(input: &std::path::Path, args: &[&str]) {
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--binning-backend", "greedy"])
+        .args(["--gini-threshold", "0.0", "--missing-threshold", "1.0"])
+        // `score` follows the target closely
+        .args(["--correlation-threshold", "0.99"])
+        .args(args)
+        .assert()
+        .success();
+}
+
+fn read_report_json(dir: &std::path::Path) -> serde_json::Value {
+    use std::io::Read;
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_reduction_report.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+fn feature_entry<'a>(report: &'a serde_json::Value, feature: &str) -> &'a serde_json::Value {
+    report["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["name"] == feature)
+        .unwrap_or_else(|| panic!("no report entry for {}", feature))
+}
+
+fn reduced_columns(dir: &std::path::Path) -> Vec<String> {
+    CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(dir.join("test_data_reduced.csv")))
+        .unwrap()
+        .finish()
+        .unwrap()
+        .get_column_names_str()
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_dominance_threshold_drops_constant_and_near_constant_features() {
+    let mut df = dominance_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    run_lophi(&input, &["--dominance-threshold", "0.995"]);
+
+    let columns = reduced_columns(dir.path());
+    assert!(!columns.contains(&"region".to_string()));
+    assert!(!columns.contains(&"flag".to_string()));
+    // Null-dominated features are left to missing analysis
+    assert!(columns.contains(&"sparse".to_string()));
+    assert!(columns.contains(&"score".to_string()));
+
+    let report = read_report_json(dir.path());
+    assert_eq!(report["metadata"]["thresholds"]["dominance"], 0.995);
+    assert_eq!(report["summary"]["by_stage"]["constant"]["dropped"], 2);
+
+    let region = feature_entry(&report, "region");
+    assert_eq!(region["dropped_at_stage"], "constant");
+    assert_eq!(region["reason"], "Constant value 'ZA'");
+    let flag = feature_entry(&report, "flag");
+    assert_eq!(flag["dropped_at_stage"], "constant");
+    assert_eq!(flag["analysis"]["dominance"]["value"], "N");
+    assert_eq!(flag["analysis"]["dominance"]["passed"], false);
+    assert!(flag["reason"]
+        .as_str()
+        .unwrap()
+        .starts_with("Value 'N' covers 99.70% of rows"));
+
+    let score = feature_entry(&report, "score");
+    assert_eq!(score["analysis"]["dominance"]["passed"], true);
+    assert!(score["analysis"]["missing"].is_object());
+}
+
+#[test]
+fn test_dominance_filter_is_off_by_default() {
+    let mut df = dominance_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    run_lophi(&input, &[]);
+
+    let report = read_report_json(dir.path());
+    assert!(report["metadata"]["thresholds"].get("dominance").is_none());
+    assert!(report["summary"]["by_stage"].get("constant").is_none());
+    let flag = feature_entry(&report, "flag");
+    assert!(flag["analysis"].get("dominance").is_none());
+    assert_ne!(flag["dropped_at_stage"], "constant");
+}
+
+#[test]
+fn test_keep_columns_survive_the_dominance_filter() {
+    let mut df = dominance_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    run_lophi(
+        &input,
+        &["--dominance-threshold", "0.995", "--keep-columns", "flag"],
+    );
+
+    let columns = reduced_columns(dir.path());
+    assert!(columns.contains(&"flag".to_string()));
+    assert!(!columns.contains(&"region".to_string()));
+}
+
+#[test]
+fn test_dominance_shares_use_sample_weights() {
+    let df = df! {
+        "flag" => ["Y", "N", "N", "N"],
+    }
+    .unwrap();
+
+    let stats = analyze_dominance(&df, &[1.0; 4], None).unwrap();
+    assert_eq!(stats[0].value.as_deref(), Some("N"));
+    assert_eq!(stats[0].share, 0.75);
+
+    // Weighting the single "Y" row 9 times over makes it cover 75%
+    let stats = analyze_dominance(&df, &[9.0, 1.0, 1.0, 1.0], None).unwrap();
+    assert_eq!(stats[0].value.as_deref(), Some("Y"));
+    assert_eq!(stats[0].share, 0.75);
+}
+
+#[test]
+fn test_reducer_drops_near_constant_features_first() {
+    let result = Reducer::from_dataframe(dominance_df(), "target")
+        .dominance_threshold(0.995)
+        .missing_threshold(1.0)
+        .gini_threshold(0.0)
+        .run()
+        .unwrap();
+
+    assert_eq!(result.dropped_constant, ["region", "flag"]);
+    assert_eq!(&result.dropped_features()[..2], ["region", "flag"]);
+    assert!(result.dominance.iter().all(|s| s.feature != "target"));
+    assert!(result
+        .missing_ratios
+        .iter()
+        .all(|(name, _)| name != "region" && name != "flag"));
+    assert_missing_columns(&result.dataframe, &["region", "flag"]);
+}
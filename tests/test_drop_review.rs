@@ -0,0 +1,72 @@
+//! Tests for the interactive review of all proposed drops
+
+#![cfg(feature = "tui")]
+
+use crossterm::event::KeyCode;
+use lophi::cli::drop_review::DropReview;
+use lophi::pipeline::{DropReviewItem, DropReviewRequest};
+use std::sync::mpsc;
+
+fn item(feature: &str, stage: &str) -> DropReviewItem {
+    DropReviewItem {
+        feature: feature.to_string(),
+        stage: stage.to_string(),
+        reason: format!("{} dropped at {}", feature, stage),
+        description: None,
+    }
+}
+
+fn open_review() -> (DropReview, mpsc::Receiver<Vec<usize>>) {
+    let (reply, rx) = mpsc::channel();
+    let review = DropReview::new(DropReviewRequest {
+        items: vec![
+            item("sparse", "missing"),
+            item("weak", "gini"),
+            item("copy", "correlation"),
+        ],
+        reply,
+    });
+    (review, rx)
+}
+
+#[test]
+fn test_uncheck_and_confirm_sends_kept_indices() {
+    let (mut review, rx) = open_review();
+    assert!((0..3).all(|i| review.is_dropped(i)));
+
+    assert!(!review.handle_key(KeyCode::Char(' ')));
+    assert!(!review.handle_key(KeyCode::Down));
+    assert!(!review.handle_key(KeyCode::Down));
+    assert!(!review.handle_key(KeyCode::Char(' ')));
+    assert!(!review.is_dropped(0));
+    assert!(review.is_dropped(1));
+    assert!(!review.is_dropped(2));
+
+    assert!(review.handle_key(KeyCode::Enter));
+    assert_eq!(rx.recv().unwrap(), vec![0, 2]);
+}
+
+#[test]
+fn test_escape_drops_everything() {
+    let (mut review, rx) = open_review();
+    review.handle_key(KeyCode::Char(' '));
+    assert!(review.handle_key(KeyCode::Esc));
+    assert!(rx.recv().unwrap().is_empty());
+}
+
+#[test]
+fn test_reset_and_cursor_bounds() {
+    let (mut review, _rx) = open_review();
+    review.handle_key(KeyCode::Up);
+    review.handle_key(KeyCode::Char(' '));
+    assert_eq!(review.kept_indices(), vec![0]);
+
+    for _ in 0..5 {
+        review.handle_key(KeyCode::Char('j'));
+    }
+    review.handle_key(KeyCode::Char(' '));
+    assert_eq!(review.kept_indices(), vec![0, 2]);
+
+    review.handle_key(KeyCode::Char('r'));
+    assert!(review.kept_indices().is_empty());
+}
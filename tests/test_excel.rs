@@ -0,0 +1,139 @@
+//! Tests for Excel workbook input (`--sheet`, sheet-aware loading, convert)
+
+#![cfg(feature = "xlsx")]
+
+use assert_cmd::Command;
+use lophi::cli::convert::run_convert;
+use lophi::pipeline::{
+    get_column_names_from_sheet, get_sheet_names, load_dataset_from_sheet, CsvDialect,
+};
+use polars::prelude::*;
+use tempfile::TempDir;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::XlsxCell::{Bool, Empty, Number};
+use common::*;
+
+/// A cover sheet followed by the data sheet, with a spacer row in the data
+fn write_workbook(dir: &TempDir) -> std::path::PathBuf {
+    let path = dir.path().join("book.xlsx");
+    let mut data = vec![vec![
+        text("target"),
+        text("income"),
+        text("count"),
+        text("region"),
+        text("active"),
+        text("mixed"),
+    ]];
+    for i in 0..40 {
+        if i == 10 {
+            data.push(vec![Empty, Empty, Empty, Empty, Empty, Empty]);
+        }
+        data.push(vec![
+            Number((i % 2) as f64),
+            Number(1000.0 + i as f64 * 12.5),
+            Number((i % 7) as f64),
+            text(["north", "south", "east"][i % 3]),
+            Bool(i % 3 == 0),
+            if i % 5 == 0 {
+                text("n/a")
+            } else {
+                Number(i as f64)
+            },
+        ]);
+    }
+    let summary = vec![vec![text("note")], vec![text("Loan book extract")]];
+    write_xlsx(&path, &[("Summary", summary), ("Data", data)]);
+    path
+}
+
+#[test]
+fn test_load_xlsx_sheets() {
+    let dir = TempDir::new().unwrap();
+    let path = write_workbook(&dir);
+
+    assert_eq!(get_sheet_names(&path).unwrap(), vec!["Summary", "Data"]);
+    assert_eq!(
+        get_column_names_from_sheet(&path, Some("Data"), &CsvDialect::default()).unwrap(),
+        vec!["target", "income", "count", "region", "active", "mixed"]
+    );
+
+    let (df, _, _, _) = load_dataset_from_sheet(&path, 100, Some("Data"), None).unwrap();
+    // The spacer row is skipped
+    assert_eq!(df.height(), 40);
+    assert_eq!(df.column("target").unwrap().dtype(), &DataType::Int64);
+    assert_eq!(df.column("income").unwrap().dtype(), &DataType::Float64);
+    assert_eq!(df.column("count").unwrap().dtype(), &DataType::Int64);
+    assert_eq!(df.column("region").unwrap().dtype(), &DataType::String);
+    assert_eq!(df.column("active").unwrap().dtype(), &DataType::Boolean);
+    // Numbers mixed with text load as text
+    let mixed = df.column("mixed").unwrap();
+    assert_eq!(mixed.dtype(), &DataType::String);
+    assert_eq!(mixed.str().unwrap().get(1), Some("1"));
+
+    // Without a sheet the first one is read
+    let (first, _, _, _) = load_dataset_from_sheet(&path, 100, None, None).unwrap();
+    assert_has_columns(&first, &["note"]);
+    assert_eq!(first.height(), 1);
+
+    let err = load_dataset_from_sheet(&path, 100, Some("Missing"), None).unwrap_err();
+    assert!(format!("{:#}", err).contains("Available sheets: Summary, Data"));
+
+    // Non-Excel files have no sheets
+    let mut csv_df = create_binary_target_dataframe();
+    let (_csv_dir, csv) = create_temp_csv(&mut csv_df);
+    assert!(get_sheet_names(&csv).unwrap().is_empty());
+}
+
+#[test]
+fn test_xlsx_to_parquet_conversion() {
+    let dir = TempDir::new().unwrap();
+    let path = write_workbook(&dir);
+
+    // Excel defaults to Parquet output
+    run_convert(&path, None, 1000, Some("Data"), false).unwrap();
+    let parquet = LazyFrame::scan_parquet(dir.path().join("book.parquet"), Default::default())
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(parquet.height(), 40);
+    assert_has_columns(&parquet, &["target", "income", "region"]);
+
+    assert!(run_convert(&path, None, 1000, Some("Missing"), false).is_err());
+}
+
+#[test]
+fn test_cli_reduces_xlsx_sheet_to_parquet() {
+    let dir = TempDir::new().unwrap();
+    let path = write_workbook(&dir);
+    let config = dir.path().join("pipeline.toml");
+    std::fs::write(&config, "use_solver = false\n").unwrap();
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--config")
+        .arg(&config)
+        .arg("--input")
+        .arg(&path)
+        .args([
+            "--sheet",
+            "Data",
+            "--target",
+            "target",
+            "--no-confirm",
+            "--gini-threshold",
+            "0",
+        ])
+        .assert()
+        .success();
+
+    // Workbooks cannot be written back, so the reduced output is Parquet
+    let reduced =
+        LazyFrame::scan_parquet(dir.path().join("book_reduced.parquet"), Default::default())
+            .unwrap()
+            .collect()
+            .unwrap();
+    assert_eq!(reduced.height(), 40);
+    assert!(reduced.column("target").is_ok());
+}
@@ -0,0 +1,116 @@
+//! Integration tests for scripted runs: `--quiet`, prompt-free `--no-confirm`
+//! and the exit code of each kind of failure
+
+use assert_cmd::Command;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+fn lophi() -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.env("RUST_BACKTRACE", "0");
+    cmd
+}
+
+#[test]
+fn test_quiet_run_prints_nothing() {
+    let mut df = create_binary_target_dataframe();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    lophi()
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target", "--quiet"])
+        .args(["--binning-backend", "greedy"])
+        .assert()
+        .success()
+        .stdout("");
+    assert!(dir.path().join("test_data_reduced.csv").exists());
+    assert!(dir.path().join("test_data_reduction_report.zip").exists());
+}
+
+#[test]
+fn test_no_confirm_without_input_fails_instead_of_prompting() {
+    // stdin is closed: a file selector would fail with an I/O error instead
+    let assert = lophi()
+        .args(["--target", "target", "--no-confirm"])
+        .assert()
+        .code(2);
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).to_string();
+    assert!(stderr.contains("Input file is required"), "{}", stderr);
+}
+
+#[cfg(feature = "tui")]
+#[test]
+fn test_interactive_mode_without_terminal_is_rejected() {
+    let mut df = create_binary_target_dataframe();
+    let (_dir, input) = create_temp_csv(&mut df);
+
+    let assert = lophi()
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target"])
+        .assert()
+        .code(2);
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).to_string();
+    assert!(stderr.contains("needs a terminal"), "{}", stderr);
+}
+
+#[test]
+fn test_exit_codes_by_failure_kind() {
+    let mut df = create_binary_target_dataframe();
+    let (dir, input) = create_temp_csv(&mut df);
+    let run = |args: &[&str]| {
+        lophi()
+            .arg("--input")
+            .arg(&input)
+            .args(["--no-confirm", "--binning-backend", "dp"])
+            .args(args)
+            .output()
+            .unwrap()
+            .status
+            .code()
+    };
+
+    // Validation: unknown target column, bad policy value
+    assert_eq!(run(&["--target", "no_such_column"]), Some(2));
+    assert_eq!(run(&["--target", "target", "--date-policy", "x"]), Some(2));
+
+    // I/O: output directory does not exist
+    let output = dir.path().join("missing_dir").join("out.csv");
+    assert_eq!(
+        run(&["--target", "target", "-o", output.to_str().unwrap()]),
+        Some(3)
+    );
+
+    // Solver: no binning puts 60-70% of the rows in every bin
+    let solver = [
+        "--target",
+        "target",
+        "--binning-strategy",
+        "quantile",
+        "--min-bin-pct",
+        "60",
+        "--max-bin-pct",
+        "70",
+    ];
+    assert_eq!(run(&solver), Some(0));
+    let strict = [&solver[..], &["--fail-on-solver-fallback"]].concat();
+    assert_eq!(run(&strict), Some(4));
+}
+
+#[test]
+fn test_missing_input_file_is_an_io_error() {
+    lophi()
+        .args([
+            "--input",
+            "does_not_exist.csv",
+            "--target",
+            "target",
+            "--quiet",
+        ])
+        .assert()
+        .code(3);
+}
@@ -0,0 +1,127 @@
+//! Integration tests for the proxy screen of protected attributes
+//! (`--protected-columns`)
+
+use std::io::Read;
+
+use assert_cmd::Command;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 400 rows with a 25% event rate in both genders. `gender` alternates in
+/// blocks of two; `height` tracks it closely, `income` separates the target
+/// but not `gender`, and `region` is unrelated to both.
+fn applicants_df() -> DataFrame {
+    let n = 400;
+    let female = |i: usize| (i / 2).is_multiple_of(2);
+    // One event per gender in every eight rows
+    let event = |i: usize| matches!(i % 8, 0 | 3);
+    df! {
+        "target" => (0..n).map(|i| i32::from(event(i))).collect::<Vec<_>>(),
+        "gender" => (0..n).map(|i| if female(i) { "f" } else { "m" }).collect::<Vec<_>>(),
+        "height" => (0..n)
+            .map(|i| ((i / 4) % 5) as f64 + if female(i) { 160.0 } else { 178.0 })
+            .collect::<Vec<_>>(),
+        "income" => (0..n)
+            .map(|i| ((i / 4) % 7) as f64 + if event(i) { 3.0 } else { 0.0 })
+            .collect::<Vec<_>>(),
+        "region" => (0..n)
+            .map(|i| ["north", "south", "east"][(i / 4) % 3])
+            .collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn lophi(input: &std::path::Path, args: &[&str]) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--binning-backend", "greedy"])
+        .args(["--missing-threshold", "0.9", "--gini-threshold", "0.0"])
+        .args(["--correlation-threshold", "1.0"])
+        .args(args);
+    cmd
+}
+
+fn read_report_json(dir: &std::path::Path) -> serde_json::Value {
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_reduction_report.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_protected_columns_flag_proxy_features() {
+    let mut df = applicants_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    lophi(&input, &["--protected-columns", "gender"])
+        .assert()
+        .success();
+
+    let report = read_report_json(dir.path());
+    let section = &report["fairness"];
+    assert_eq!(section["columns"], serde_json::json!(["gender"]));
+    assert_eq!(section["association_threshold"], 0.3);
+
+    let entry = |name: &str| {
+        section["associations"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|a| a["feature"] == name)
+            .unwrap_or_else(|| panic!("no proxy entry for {}", name))
+            .clone()
+    };
+    let height = entry("height");
+    assert_eq!(height["protected"], "gender");
+    assert_eq!(height["measure"], "Eta");
+    assert!(height["association"].as_f64().unwrap() > 0.9, "{}", height);
+    assert!(height["iv"].as_f64().unwrap() > 0.1, "{}", height);
+    assert_eq!(height["proxy"], true);
+
+    let region = entry("region");
+    assert_eq!(region["measure"], "CramersV");
+    assert_eq!(region["proxy"], false, "{}", region);
+    assert_eq!(entry("income")["proxy"], false);
+
+    // Flagged only; the protected column rides through untouched
+    let file = std::fs::File::open(dir.path().join("test_data_reduced.parquet")).unwrap();
+    let output = ParquetReader::new(file).finish().unwrap();
+    for column in ["gender", "height"] {
+        assert!(output.column(column).is_ok(), "{} missing", column);
+    }
+}
+
+#[test]
+fn test_unknown_protected_column_is_rejected() {
+    let mut df = applicants_df();
+    let (_dir, input) = create_temp_parquet(&mut df);
+    let output = lophi(&input, &["--protected-columns", "gender,ethnicity"])
+        .assert()
+        .failure()
+        .get_output()
+        .clone();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Protected column 'ethnicity' not found"),
+        "{}",
+        stderr
+    );
+}
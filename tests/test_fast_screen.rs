@@ -0,0 +1,86 @@
+//! Tests for the fast screen mode (--fast)
+
+use std::io::Read;
+
+use assert_cmd::Command;
+use lophi::Reducer;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+/// 300 rows where the event rate rises with `x`; `x_copy` duplicates it
+fn rising_df() -> DataFrame {
+    let n = 300;
+    let x: Vec<f64> = (0..n).map(|i| i as f64).collect();
+    let x_copy = x.clone();
+    let target: Vec<i32> = (0..n).map(|i| i32::from(i % 3 == 0 || i > 200)).collect();
+    df! { "target" => target, "x" => x, "x_copy" => x_copy }.unwrap()
+}
+
+#[test]
+fn test_fast_overrides_binning_settings() {
+    let result = Reducer::from_dataframe(rising_df(), "target")
+        .gini_bins(3)
+        .run()
+        .unwrap();
+    assert!(!result.approximate);
+    assert_eq!(result.iv_analysis("x").unwrap().bins.len(), 3);
+
+    // Decile bins regardless of the requested bin count
+    let result = Reducer::from_dataframe(rising_df(), "target")
+        .gini_bins(3)
+        .fast(true)
+        .run()
+        .unwrap();
+    assert!(result.approximate);
+    assert_eq!(result.iv_analysis("x").unwrap().bins.len(), 10);
+
+    // Correlation analysis still runs
+    assert!(result
+        .correlated_pairs
+        .iter()
+        .any(|p| p.feature1.starts_with('x') && p.feature2.starts_with('x')));
+}
+
+#[test]
+fn test_cli_marks_report_as_approximate() {
+    let mut df = rising_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    let config = dir.path().join("pipeline.toml");
+    std::fs::write(&config, "use_solver = false\n").unwrap();
+
+    let report = |extra: &[&str]| -> serde_json::Value {
+        Command::new(env!("CARGO_BIN_EXE_lophi"))
+            .arg("--config")
+            .arg(&config)
+            .arg("--input")
+            .arg(&input)
+            .args(["--target", "target", "--no-confirm"])
+            .args(extra)
+            .assert()
+            .success();
+        let file = std::fs::File::open(dir.path().join("test_data_reduction_report.zip")).unwrap();
+        let mut archive = ::zip::ZipArchive::new(file).unwrap();
+        let mut content = String::new();
+        archive
+            .by_name("test_data_reduction_report.json")
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        serde_json::from_str(&content).unwrap()
+    };
+
+    let full = report(&[]);
+    assert!(full["metadata"]["settings"]["fast_screen"].is_null());
+
+    let fast = report(&["--fast"]);
+    let settings = &fast["metadata"]["settings"];
+    assert_eq!(settings["binning_strategy"], "quantile");
+    assert_eq!(settings["num_bins"], 10);
+    assert_eq!(settings["fast_screen"]["approximate"], true);
+    assert_eq!(settings["fast_screen"]["correlation_rows"], 300);
+    assert_eq!(settings["fast_screen"]["total_rows"], 300);
+}
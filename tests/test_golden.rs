@@ -0,0 +1,196 @@
+//! Golden tests for the full pipeline
+//!
+//! Each scenario runs the `lophi` binary in `--no-confirm` mode on one of the
+//! shared fixture DataFrames and compares the reduction report (canonicalized)
+//! and the output schema to a checked-in file in `tests/fixtures/golden/`. Any
+//! change to step ordering, thresholds or drop logic shows up as a golden diff.
+//!
+//! After an intentional behavior change, regenerate the files and review the diff:
+//!
+//! ```text
+//! UPDATE_GOLDEN=1 cargo test --test test_golden
+//! ```
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+use polars::prelude::*;
+use serde_json::{json, Value};
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+/// Settings shared by every scenario. The solver is disabled so results do not
+/// depend on solver timeouts.
+const PIPELINE_CONFIG: &str = "use_solver = false\n";
+
+/// Decimal places kept for floats, so last-bit differences in floating-point
+/// summation don't fail the comparison
+const FLOAT_DECIMALS: i32 = 6;
+
+fn golden_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/golden")
+        .join(format!("{}.json", name))
+}
+
+/// Run the pipeline on `df` and return the canonical output snapshot
+fn run_pipeline(mut df: DataFrame, extra_args: &[&str]) -> Value {
+    let (dir, input) = create_temp_csv(&mut df);
+    let config = dir.path().join("pipeline.toml");
+    std::fs::write(&config, PIPELINE_CONFIG).unwrap();
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--config")
+        .arg(&config)
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(extra_args)
+        .assert()
+        .success();
+
+    let output = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(dir.path().join("test_data_reduced.csv")))
+        .unwrap()
+        .finish()
+        .unwrap();
+    let schema: Vec<Value> = output
+        .get_columns()
+        .iter()
+        .map(|c| json!([c.name().as_str(), c.dtype().to_string()]))
+        .collect();
+
+    let report = read_report(&dir.path().join("test_data_reduction_report.zip"));
+
+    json!({
+        "output_rows": output.height(),
+        "output_schema": schema,
+        "report": canonicalize_report(report),
+    })
+}
+
+fn read_report(zip_path: &Path) -> Value {
+    let file = std::fs::File::open(zip_path).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_reduction_report.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+/// Blank out run-specific fields (time, version, temp paths, durations) and
+/// round floats
+fn canonicalize_report(mut report: Value) -> Value {
+    let metadata = &mut report["metadata"];
+    metadata["timestamp"] = json!("<timestamp>");
+    metadata["lophi_version"] = json!("<version>");
+    for key in ["input_file", "output_file"] {
+        let name = metadata[key]
+            .as_str()
+            .and_then(|p| Path::new(p).file_name())
+            .and_then(|n| n.to_str())
+            .unwrap_or_default()
+            .to_string();
+        metadata[key] = json!(name);
+    }
+    report["summary"]["timing"] = json!("<timing>");
+    round_floats(&mut report);
+    report
+}
+
+fn round_floats(value: &mut Value) {
+    match value {
+        Value::Number(n) if n.is_f64() => {
+            let scale = 10f64.powi(FLOAT_DECIMALS);
+            // `+ 0.0` turns -0.0 into 0.0
+            let rounded = (n.as_f64().unwrap() * scale).round() / scale + 0.0;
+            *value = json!(rounded);
+        }
+        Value::Array(items) => items.iter_mut().for_each(round_floats),
+        Value::Object(map) => map.values_mut().for_each(round_floats),
+        _ => {}
+    }
+}
+
+/// Compare `actual` to the golden file, or rewrite it when UPDATE_GOLDEN is set
+fn assert_golden(name: &str, actual: &Value) {
+    let path = golden_path(name);
+    let actual = format!("{}\n", serde_json::to_string_pretty(actual).unwrap());
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "Missing golden file {}. Run with UPDATE_GOLDEN=1 to create it.",
+            path.display()
+        )
+    });
+    assert_eq!(
+        actual,
+        expected,
+        "Pipeline output differs from {}. If the change is intended, rerun with \
+         UPDATE_GOLDEN=1 and review the diff.",
+        path.display()
+    );
+}
+
+#[test]
+fn test_golden_standard_fixture() {
+    let actual = run_pipeline(create_test_dataframe(), &[]);
+    assert_golden("standard", &actual);
+}
+
+#[test]
+fn test_golden_missing_fixture() {
+    let actual = run_pipeline(create_missing_test_dataframe(), &[]);
+    assert_golden("missing", &actual);
+}
+
+#[test]
+fn test_golden_correlation_fixture() {
+    let actual = run_pipeline(
+        create_correlation_test_dataframe(),
+        &["--gini-threshold", "0.0", "--correlation-threshold", "0.9"],
+    );
+    assert_golden("correlation", &actual);
+}
+
+#[test]
+fn test_golden_binary_target_fixture() {
+    let actual = run_pipeline(
+        create_binary_target_dataframe(),
+        &["--binning-strategy", "quantile", "--gini-bins", "4"],
+    );
+    assert_golden("binary_target", &actual);
+}
+
+#[test]
+fn test_golden_continuous_target_fixture() {
+    // A 0/1 target is also a valid continuous target; R² is then the share of
+    // event-rate variance explained by the bins
+    let actual = run_pipeline(
+        create_binary_target_dataframe(),
+        &["--target-type", "continuous", "--gini-bins", "4"],
+    );
+    assert_golden("continuous_target", &actual);
+}
+
+#[test]
+fn test_golden_canonicalization_is_stable() {
+    // Two runs of the same scenario must canonicalize identically, otherwise
+    // the golden files would churn on every update
+    let first = run_pipeline(create_binary_target_dataframe(), &[]);
+    let second = run_pipeline(create_binary_target_dataframe(), &[]);
+    assert_eq!(first, second);
+}
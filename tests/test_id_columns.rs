@@ -0,0 +1,185 @@
+//! Integration tests for the ID column check (`--id-threshold`, `--id-action`)
+
+use assert_cmd::Command;
+use lophi::pipeline::{analyze_cardinality, IdAction};
+use lophi::Reducer;
+use polars::prelude::{df, CsvReadOptions, DataFrame, SerReader};
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 500 rows with a 30% event rate. `account_id` and `note` are unique per
+/// row, `income` is a float with a different value on every row, and `grade`
+/// has 5 values.
+fn id_df() -> DataFrame {
+    let n = 500;
+    df! {
+        "target" => (0..n).map(|i| i32::from(i % 10 < 3)).collect::<Vec<_>>(),
+        "account_id" => (0..n).map(|i| 100_000 + i as i64).collect::<Vec<_>>(),
+        "note" => (0..n).map(|i| format!("call {}", i)).collect::<Vec<_>>(),
+        "income" => (0..n).map(|i| 1000.0 + i as f64 * 3.7).collect::<Vec<_>>(),
+        "grade" => (0..n)
+            .map(|i| ["A", "B", "C", "D", "E"][i % 5])
+            .collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn run_lophi(input: &std::path::Path, args: &[&str]) {
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--binning-backend", "greedy"])
+        .args(["--gini-threshold", "0.0", "--correlation-threshold", "1.0"])
+        .args(args)
+        .assert()
+        .success();
+}
+
+fn read_report_json(dir: &std::path::Path) -> serde_json::Value {
+    use std::io::Read;
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_reduction_report.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+fn feature_entry<'a>(report: &'a serde_json::Value, feature: &str) -> &'a serde_json::Value {
+    report["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["name"] == feature)
+        .unwrap_or_else(|| panic!("no report entry for {}", feature))
+}
+
+fn reduced_columns(dir: &std::path::Path) -> Vec<String> {
+    CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(dir.join("test_data_reduced.csv")))
+        .unwrap()
+        .finish()
+        .unwrap()
+        .get_column_names_str()
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_id_columns_are_flagged_and_kept_by_default() {
+    let mut df = id_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    run_lophi(&input, &["--id-threshold", "0.95"]);
+
+    let columns = reduced_columns(dir.path());
+    assert!(columns.contains(&"account_id".to_string()));
+    assert!(columns.contains(&"note".to_string()));
+
+    let report = read_report_json(dir.path());
+    assert_eq!(report["metadata"]["thresholds"]["id_ratio"], 0.95);
+    assert_eq!(report["metadata"]["settings"]["id_action"], "flag");
+    assert_eq!(
+        report["summary"]["flagged_id_columns"],
+        serde_json::json!(["account_id", "note"])
+    );
+    assert!(report["summary"]["by_stage"].get("identifier").is_none());
+
+    let account = feature_entry(&report, "account_id");
+    assert_eq!(account["status"], "kept");
+    assert_eq!(account["analysis"]["cardinality"]["unique"], 500);
+    assert_eq!(account["analysis"]["cardinality"]["passed"], false);
+    let grade = feature_entry(&report, "grade");
+    assert_eq!(grade["analysis"]["cardinality"]["passed"], true);
+    // Floats are not checked
+    assert!(feature_entry(&report, "income")["analysis"]
+        .get("cardinality")
+        .is_none());
+}
+
+#[test]
+fn test_id_action_drop_removes_id_columns() {
+    let mut df = id_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    run_lophi(&input, &["--id-threshold", "0.95", "--id-action", "drop"]);
+
+    let columns = reduced_columns(dir.path());
+    assert!(!columns.contains(&"account_id".to_string()));
+    assert!(!columns.contains(&"note".to_string()));
+    assert!(columns.contains(&"income".to_string()));
+    assert!(columns.contains(&"grade".to_string()));
+
+    let report = read_report_json(dir.path());
+    assert_eq!(report["summary"]["by_stage"]["identifier"]["dropped"], 2);
+    assert!(report["summary"].get("flagged_id_columns").is_none());
+    let note = feature_entry(&report, "note");
+    assert_eq!(note["dropped_at_stage"], "identifier");
+    assert!(note["reason"]
+        .as_str()
+        .unwrap()
+        .starts_with("Likely an ID column: 500 distinct values in 500 rows"));
+    assert!(note["analysis"].get("missing").is_none());
+}
+
+#[test]
+fn test_unknown_id_action_is_rejected() {
+    let mut df = id_df();
+    let (_dir, input) = create_temp_csv(&mut df);
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--id-threshold", "0.95", "--id-action", "hide"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_distinct_ratio_ignores_nulls() {
+    // 40 distinct values and 60 nulls: every non-null value is different
+    let df = df! {
+        "ref" => (0..100).map(|i| (i < 40).then(|| format!("R{}", i))).collect::<Vec<_>>(),
+    }
+    .unwrap();
+    let stats = analyze_cardinality(&df, None).unwrap();
+    assert_eq!((stats[0].unique, stats[0].non_null), (40, 40));
+    assert!(stats[0].is_id_like(0.95));
+}
+
+#[test]
+fn test_reducer_id_action() {
+    let flagged = Reducer::from_dataframe(id_df(), "target")
+        .id_threshold(0.95)
+        .gini_threshold(0.0)
+        .run()
+        .unwrap();
+    assert_eq!(flagged.id_like, ["account_id", "note"]);
+    assert!(flagged.dropped_identifier.is_empty());
+    assert_has_columns(&flagged.dataframe, &["account_id", "note"]);
+
+    let dropped = Reducer::from_dataframe(id_df(), "target")
+        .id_threshold(0.95)
+        .id_action(IdAction::Drop)
+        .keep_columns(["note"])
+        .gini_threshold(0.0)
+        .run()
+        .unwrap();
+    assert_eq!(dropped.dropped_identifier, ["account_id"]);
+    assert_eq!(dropped.dropped_features()[0], "account_id");
+    assert_missing_columns(&dropped.dataframe, &["account_id"]);
+}
@@ -0,0 +1,112 @@
+//! Integration tests for the imputation plan in the report zip
+
+use std::io::Read;
+
+use assert_cmd::Command;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 400 rows with a 25% event rate. `bureau_score` is missing for most
+/// events, `income` is missing at random on one row in ten, `channel` is
+/// missing on one row in five and `region` is complete.
+fn applicants_df() -> DataFrame {
+    let n = 400;
+    let event = |i: usize| i.is_multiple_of(4);
+    df! {
+        "target" => (0..n).map(|i| i32::from(event(i))).collect::<Vec<_>>(),
+        "bureau_score" => (0..n)
+            .map(|i| (!event(i) || i.is_multiple_of(3)).then_some(((i / 4) % 9) as f64))
+            .collect::<Vec<_>>(),
+        "income" => (0..n)
+            .map(|i| {
+                let base = ((i / 4) % 5) as f64 * 10.0;
+                ((i / 4) % 10 != 9).then_some(base + if event(i) { 1.0 } else { 0.0 })
+            })
+            .collect::<Vec<_>>(),
+        "channel" => (0..n)
+            .map(|i| ((i / 4) % 5 != 2).then_some(["web", "branch"][(i / 4) % 2]))
+            .collect::<Vec<_>>(),
+        "region" => (0..n)
+            .map(|i| ["north", "south", "north", "east"][(i / 4) % 4])
+            .collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn read_plan(dir: &std::path::Path) -> serde_json::Value {
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_imputation_plan.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_imputation_plan_recommends_a_strategy_per_feature() {
+    let mut df = applicants_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--binning-backend", "greedy"])
+        .args(["--missing-threshold", "0.9", "--gini-threshold", "0.0"])
+        .args(["--correlation-threshold", "1.0"])
+        .assert()
+        .success();
+
+    let plan = read_plan(dir.path());
+    assert_eq!(plan["target"], "target");
+    let feature = |name: &str| {
+        plan["features"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|f| f["feature"] == name)
+            .unwrap_or_else(|| panic!("no plan for {}", name))
+            .clone()
+    };
+
+    let bureau = feature("bureau_score");
+    assert_eq!(bureau["strategy"], "missing_bin", "{}", bureau);
+    assert!(bureau["missing_woe"].as_f64().unwrap() > 0.1, "{}", bureau);
+    assert!(bureau["value"].is_null());
+
+    let income = feature("income");
+    assert_eq!(income["strategy"], "median", "{}", income);
+    assert_eq!(income["feature_type"], "Numeric");
+    assert!((income["missing_ratio"].as_f64().unwrap() - 0.1).abs() < 1e-9);
+    assert_eq!(income["value"], 20.0);
+
+    let channel = feature("channel");
+    assert_eq!(channel["strategy"], "constant", "{}", channel);
+    assert_eq!(channel["value"], "MISSING");
+
+    let region = feature("region");
+    assert_eq!(region["strategy"], "mode", "{}", region);
+    assert_eq!(region["value"], "north");
+    assert_eq!(region["missing_ratio"], 0.0);
+
+    // The target is not a feature
+    assert!(plan["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|f| f["feature"] != "target"));
+}
@@ -0,0 +1,83 @@
+//! Integration tests for JSON-lines progress output (`--json-output`)
+
+use assert_cmd::Command;
+use serde_json::Value;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+fn parse_lines(output: &str) -> Vec<Value> {
+    output
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line should be a JSON object"))
+        .collect()
+}
+
+#[test]
+fn test_json_output_to_stdout() {
+    let mut df = create_binary_target_dataframe();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    let assert = Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target", "--json-output"])
+        .args(["--binning-backend", "greedy", "--gini-threshold", "0.99"])
+        .args(["--correlation-threshold", "1.0"])
+        .assert()
+        .success();
+    let events = parse_lines(&String::from_utf8_lossy(&assert.get_output().stdout));
+
+    // Nothing but JSON on stdout: every stage starts and finishes
+    assert_eq!(events[0]["event"], "stage_started");
+    assert_eq!(events[0]["stage"], "loading");
+    for stage in ["loading", "missing_analysis", "gini_analysis", "reports"] {
+        let finished = events
+            .iter()
+            .find(|e| e["event"] == "stage_finished" && e["stage"] == stage)
+            .unwrap_or_else(|| panic!("no stage_finished for {}", stage));
+        assert!(finished["elapsed_secs"].as_f64().unwrap() >= 0.0);
+        assert!(finished["timestamp"].is_string());
+    }
+
+    let last = events.last().unwrap();
+    assert_eq!(last["event"], "pipeline_finished");
+    assert_eq!(last["initial_features"], 3);
+    assert_eq!(last["final_features"], 2);
+    assert_eq!(
+        last["dropped"]["gini"],
+        serde_json::json!(["random_feature"])
+    );
+    assert_eq!(last["dropped_count"], 1);
+    assert!(last["timings"]["gini_secs"].is_number());
+    assert!(last["output"]
+        .as_str()
+        .unwrap()
+        .ends_with("test_data_reduced.csv"));
+    assert!(dir.path().join("test_data_reduced.csv").exists());
+}
+
+#[test]
+fn test_json_output_to_file_records_failure() {
+    let mut df = create_binary_target_dataframe();
+    let (dir, input) = create_temp_csv(&mut df);
+    let log = dir.path().join("run.jsonl");
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "no_such_column", "--json-output"])
+        .arg(&log)
+        .assert()
+        .failure();
+
+    let events = parse_lines(&std::fs::read_to_string(&log).unwrap());
+    let last = events.last().unwrap();
+    assert_eq!(last["event"], "pipeline_failed");
+    assert!(last["error"]
+        .as_str()
+        .unwrap()
+        .contains("'no_such_column' not found"));
+}
@@ -1,6 +1,9 @@
 //! Unit tests for dataset loader
 
-use lophi::pipeline::{get_column_names, load_dataset_with_progress};
+use lophi::pipeline::{
+    get_column_names, get_column_names_from_sheet, load_dataset_columns, load_dataset_streaming,
+    load_dataset_with_progress, preview_dataset, ColumnProjection, CsvDialect,
+};
 use polars::prelude::*;
 use std::io::Write;
 use tempfile::TempDir;
@@ -48,6 +51,59 @@ fn test_load_parquet_file() {
     assert_eq!(loaded_df.get_column_names(), &["x", "y"]);
 }
 
+#[test]
+fn test_load_feather_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let feather_path = temp_dir.path().join("test.feather");
+
+    let mut df = df! {
+        "x" => [1i32, 2, 3],
+        "label" => ["a", "b", "c"],
+    }
+    .unwrap();
+
+    let file = std::fs::File::create(&feather_path).unwrap();
+    IpcWriter::new(file).finish(&mut df).unwrap();
+
+    let (loaded_df, rows, cols, _mem) = load_dataset_with_progress(&feather_path, 100).unwrap();
+    assert_eq!((rows, cols), (3, 2));
+    assert!(loaded_df.equals(&df));
+
+    // .arrow is the same format under another extension
+    let arrow_path = temp_dir.path().join("test.arrow");
+    std::fs::copy(&feather_path, &arrow_path).unwrap();
+    assert_eq!(get_column_names(&arrow_path).unwrap(), vec!["x", "label"]);
+}
+
+#[test]
+fn test_load_jsonl_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let jsonl_path = temp_dir.path().join("events.jsonl");
+
+    let mut file = std::fs::File::create(&jsonl_path).unwrap();
+    writeln!(file, r#"{{"id": 1, "score": 3, "channel": "web"}}"#).unwrap();
+    writeln!(file, r#"{{"id": 2, "score": null, "channel": "app"}}"#).unwrap();
+    writeln!(file, r#"{{"id": 3, "channel": "web"}}"#).unwrap();
+    writeln!(file, r#"{{"id": 4, "score": 2.5, "channel": "app"}}"#).unwrap();
+    drop(file);
+
+    // 0 infers the schema from every line, so the late float widens `score`
+    let (df, rows, cols, _mem) = load_dataset_with_progress(&jsonl_path, 0).unwrap();
+    assert_eq!((rows, cols), (4, 3));
+    assert_eq!(df.column("id").unwrap().dtype(), &DataType::Int64);
+    assert_eq!(df.column("score").unwrap().dtype(), &DataType::Float64);
+    assert_eq!(df.column("channel").unwrap().dtype(), &DataType::String);
+    // Absent keys load as nulls
+    assert_eq!(df.column("score").unwrap().null_count(), 2);
+
+    let ndjson_path = temp_dir.path().join("events.ndjson");
+    std::fs::copy(&jsonl_path, &ndjson_path).unwrap();
+    assert_eq!(
+        get_column_names(&ndjson_path).unwrap(),
+        vec!["id", "score", "channel"]
+    );
+}
+
 #[test]
 fn test_get_column_names_csv() {
     let temp_dir = TempDir::new().unwrap();
@@ -89,7 +145,7 @@ fn test_get_column_names_parquet() {
 #[test]
 fn test_unsupported_format() {
     let temp_dir = TempDir::new().unwrap();
-    let bad_path = temp_dir.path().join("test.xlsx");
+    let bad_path = temp_dir.path().join("test.docx");
     std::fs::File::create(&bad_path).unwrap();
 
     let result = load_dataset_with_progress(&bad_path, 100);
@@ -203,3 +259,310 @@ fn test_schema_inference_length() {
     assert_eq!(df_short.height(), 100);
     assert_eq!(df_long.height(), 100);
 }
+
+#[test]
+fn test_cli_reduces_feather_input_to_feather_output() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("data.feather");
+    let n = 60;
+    let mut df = df! {
+        "target" => (0..n).map(|i| i32::from(i % 2 == 0)).collect::<Vec<_>>(),
+        "x" => (0..n).map(|i| (i % 7) as f64).collect::<Vec<_>>(),
+        "y" => (0..n).map(|i| (i % 5) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap();
+    IpcWriter::new(std::fs::File::create(&input).unwrap())
+        .finish(&mut df)
+        .unwrap();
+    let config = temp_dir.path().join("pipeline.toml");
+    std::fs::write(&config, "use_solver = false\n").unwrap();
+
+    assert_cmd::Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--config")
+        .arg(&config)
+        .arg("--input")
+        .arg(&input)
+        .args([
+            "--target",
+            "target",
+            "--no-confirm",
+            "--gini-threshold",
+            "0",
+        ])
+        .assert()
+        .success();
+
+    // The reduced output keeps the input's format
+    let output = temp_dir.path().join("data_reduced.feather");
+    let reduced = LazyFrame::scan_ipc(&output, Default::default())
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(reduced.height(), n);
+    assert!(reduced.column("target").is_ok());
+}
+
+#[test]
+fn test_cli_reduces_jsonl_input_to_jsonl_output() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("data.jsonl");
+    let n = 60;
+    let mut df = df! {
+        "target" => (0..n).map(|i| i32::from(i % 2 == 0)).collect::<Vec<_>>(),
+        "x" => (0..n).map(|i| (i % 7) as f64 + 0.5).collect::<Vec<_>>(),
+        "y" => (0..n).map(|i| (i % 5) as f64 + 0.5).collect::<Vec<_>>(),
+    }
+    .unwrap();
+    JsonWriter::new(std::fs::File::create(&input).unwrap())
+        .with_json_format(JsonFormat::JsonLines)
+        .finish(&mut df)
+        .unwrap();
+    let config = temp_dir.path().join("pipeline.toml");
+    std::fs::write(&config, "use_solver = false\n").unwrap();
+
+    assert_cmd::Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--config")
+        .arg(&config)
+        .arg("--input")
+        .arg(&input)
+        .args([
+            "--target",
+            "target",
+            "--no-confirm",
+            "--gini-threshold",
+            "0",
+        ])
+        .assert()
+        .success();
+
+    // The reduced output keeps the input's format
+    let output = temp_dir.path().join("data_reduced.jsonl");
+    let reduced = LazyJsonLineReader::new(&output)
+        .finish()
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(reduced.height(), n);
+    assert!(reduced.column("target").is_ok());
+}
+
+/// A wide CSV whose `noise_*` columns can be left unread
+fn write_wide_csv(path: &std::path::Path, rows: usize) {
+    let mut csv = String::from("id,target,income,noise_a,noise_b,weight\n");
+    for i in 0..rows {
+        csv.push_str(&format!(
+            "{},{},{},{},{},1.0\n",
+            i,
+            i % 2,
+            100 + (i % 7) * 10 + (i % 2) * 50,
+            i * 3,
+            i % 5
+        ));
+    }
+    std::fs::write(path, csv).unwrap();
+}
+
+#[test]
+fn test_column_projection_resolve() {
+    let available: Vec<String> = ["id", "target", "income", "noise_a", "weight"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let names = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+    assert!(ColumnProjection::default()
+        .resolve(&available)
+        .unwrap()
+        .is_none());
+
+    // Drops may name absent columns; the file's order is kept
+    let projection = ColumnProjection::new(&[], &names(&["noise_a", "ts"]));
+    assert_eq!(
+        projection.resolve(&available).unwrap().unwrap(),
+        ["id", "target", "income", "weight"]
+    );
+
+    // Target and weight come along with a selection, unless dropped
+    let projection = ColumnProjection::new(&names(&["income", "id"]), &names(&["weight"]))
+        .with_always_loaded(["target", "weight"]);
+    assert_eq!(
+        projection.resolve(&available).unwrap().unwrap(),
+        ["id", "target", "income"]
+    );
+    assert!(projection.excludes("noise_a"));
+    assert!(projection.excludes("weight"));
+    assert!(!projection.excludes("target"));
+
+    let err = ColumnProjection::new(&names(&["income", "score"]), &[])
+        .resolve(&available)
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("not found in dataset: score"),
+        "{}",
+        err
+    );
+
+    let err = ColumnProjection::new(&names(&["income"]), &names(&["income"]))
+        .resolve(&available)
+        .unwrap_err();
+    assert!(err.to_string().contains("No columns left"), "{}", err);
+}
+
+#[test]
+fn test_load_dataset_columns_reads_only_projected_columns() {
+    let temp_dir = TempDir::new().unwrap();
+    let csv_path = temp_dir.path().join("wide.csv");
+    write_wide_csv(&csv_path, 20);
+    let (full, _, _, _) = load_dataset_with_progress(&csv_path, 100).unwrap();
+
+    let mut parquet = full.clone();
+    let parquet_path = temp_dir.path().join("wide.parquet");
+    ParquetWriter::new(std::fs::File::create(&parquet_path).unwrap())
+        .finish(&mut parquet)
+        .unwrap();
+    let mut ipc = full.clone();
+    let ipc_path = temp_dir.path().join("wide.feather");
+    IpcWriter::new(std::fs::File::create(&ipc_path).unwrap())
+        .finish(&mut ipc)
+        .unwrap();
+
+    let columns: Vec<String> = vec!["target".into(), "income".into()];
+    let expected = full.select(&columns).unwrap();
+    for path in [&csv_path, &parquet_path, &ipc_path] {
+        let (df, rows, cols, _) = load_dataset_columns(
+            path,
+            100,
+            None,
+            Some(&columns),
+            &CsvDialect::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!((rows, cols), (20, 2), "{}", path.display());
+        assert!(df.equals(&expected), "{} differs", path.display());
+    }
+}
+
+#[test]
+fn test_cli_select_and_drop_columns_are_not_loaded() {
+    let temp_dir = TempDir::new().unwrap();
+    let input = temp_dir.path().join("wide.csv");
+    write_wide_csv(&input, 60);
+    let config = temp_dir.path().join("pipeline.toml");
+    std::fs::write(&config, "use_solver = false\n").unwrap();
+
+    let run = assert_cmd::Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--config")
+        .arg(&config)
+        .arg("--input")
+        .arg(&input)
+        .args([
+            "--target",
+            "target",
+            "--weight-column",
+            "weight",
+            "--select-columns",
+            "id,income,noise_a",
+            "--drop-columns",
+            "id",
+            "--no-confirm",
+            "--gini-threshold",
+            "0",
+        ])
+        .assert()
+        .success();
+    let stdout = String::from_utf8_lossy(&run.get_output().stdout).to_string();
+    assert!(
+        stdout.contains("Skipped 2 user-specified column(s) while loading"),
+        "{}",
+        stdout
+    );
+
+    let output = temp_dir.path().join("wide_reduced.csv");
+    let reduced = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(output))
+        .unwrap()
+        .finish()
+        .unwrap();
+    // The weight column is loaded without being selected
+    common::assert_has_columns(&reduced, &["target", "weight"]);
+    assert!(reduced.column("id").is_err());
+    assert!(reduced.column("noise_b").is_err());
+}
+
+/// A `;`-separated CSV with decimal commas, day-first dates and `NA` nulls
+fn write_european_csv(dir: &TempDir) -> std::path::PathBuf {
+    let path = dir.path().join("european.csv");
+    std::fs::write(
+        &path,
+        "target;income;opened;region\n\
+         1;1234,5;31.01.2024;\"north;east\"\n\
+         0;NA;29.02.2024;south\n\
+         1;-7,25;NA;NA\n",
+    )
+    .unwrap();
+    path
+}
+
+fn european_dialect() -> CsvDialect {
+    CsvDialect {
+        separator: b';',
+        decimal_comma: true,
+        date_format: Some("%d.%m.%Y".to_string()),
+        null_value: Some("NA".to_string()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_csv_dialect_is_applied_by_every_csv_reader() {
+    let dir = TempDir::new().unwrap();
+    let path = write_european_csv(&dir);
+    let dialect = european_dialect();
+
+    assert_eq!(
+        get_column_names_from_sheet(&path, None, &dialect).unwrap(),
+        vec!["target", "income", "opened", "region"]
+    );
+    // The standard dialect sees one column
+    assert_eq!(get_column_names(&path).unwrap().len(), 1);
+
+    let (df, rows, cols, _) = load_dataset_columns(&path, 100, None, None, &dialect, None).unwrap();
+    assert_eq!((rows, cols), (3, 4));
+    let income = df.column("income").unwrap();
+    assert_eq!(income.dtype(), &DataType::Float64);
+    let income: Vec<Option<f64>> = income.f64().unwrap().into_iter().collect();
+    assert_eq!(income, vec![Some(1234.5), None, Some(-7.25)]);
+    assert_eq!(df.column("opened").unwrap().dtype(), &DataType::Date);
+    assert_eq!(df.column("opened").unwrap().null_count(), 1);
+    let region = df.column("region").unwrap();
+    assert_eq!(region.str().unwrap().get(0), Some("north;east"));
+    assert_eq!(region.null_count(), 1);
+
+    let (streamed, _, _, _) = load_dataset_streaming(&path, 100, None, &dialect, None).unwrap();
+    assert!(streamed.equals_missing(&df));
+}
+
+#[test]
+fn test_preview_dataset_reads_first_rows_in_dialect() {
+    let dir = TempDir::new().unwrap();
+    let path = write_european_csv(&dir);
+    let dialect = european_dialect();
+
+    let head = preview_dataset(&path, 100, None, &dialect, 2).unwrap();
+    assert_eq!(head.shape(), (2, 4));
+    assert_eq!(head.column("income").unwrap().dtype(), &DataType::Float64);
+    assert_eq!(head.column("opened").unwrap().dtype(), &DataType::Date);
+
+    // Asking for more rows than the file holds returns them all
+    let all = preview_dataset(&path, 100, None, &dialect, 20).unwrap();
+    assert_eq!(all.height(), 3);
+
+    let parquet = dir.path().join("european.parquet");
+    let mut df = all.clone();
+    ParquetWriter::new(std::fs::File::create(&parquet).unwrap())
+        .finish(&mut df)
+        .unwrap();
+    let head = preview_dataset(&parquet, 100, None, &CsvDialect::default(), 2).unwrap();
+    assert!(head.equals_missing(&all.head(Some(2))));
+}
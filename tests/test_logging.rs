@@ -0,0 +1,98 @@
+//! Integration tests for verbosity levels (`-v`/`-vv`) and `--log-file`
+
+use assert_cmd::Command;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+/// The binary-target fixture plus a weight column with two nulls, which the
+/// run warns about
+fn weighted_df() -> DataFrame {
+    let mut df = create_binary_target_dataframe();
+    let weights: Vec<Option<f64>> = (0..df.height())
+        .map(|i| if i % 10 == 0 { None } else { Some(1.0) })
+        .collect();
+    df.with_column(Column::new("weight".into(), weights))
+        .unwrap();
+    df
+}
+
+fn lophi(input: &std::path::Path) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--weight-column", "weight"])
+        .args(["--binning-backend", "greedy"])
+        .env("NO_COLOR", "1");
+    cmd
+}
+
+#[test]
+fn test_log_file_captures_console_timings_and_warnings() {
+    let mut df = weighted_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    let log_path = dir.path().join("run.log");
+
+    let assert = lophi(&input)
+        .arg("--no-confirm")
+        .arg("--log-file")
+        .arg(&log_path)
+        .assert()
+        .success();
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+
+    // Warnings reach stderr by default; debug detail does not
+    assert!(stderr.contains("Weight column 'weight' contains 2 null value(s)"));
+    assert!(!stderr.contains("Binned feature"));
+
+    let log = std::fs::read_to_string(&log_path).unwrap();
+    assert!(log.contains("Step 2: Univariate Gini Analysis"));
+    assert!(log.contains("WARN lophi::pipeline::weights"));
+    for feature in ["predictive_feature", "random_feature"] {
+        let line = log
+            .lines()
+            .find(|l| l.contains("Binned feature") && l.contains(&format!("feature={}", feature)))
+            .unwrap_or_else(|| panic!("no timing line for {}", feature));
+        assert!(line.contains("elapsed_ms="));
+        assert!(line.contains("solver_status="));
+    }
+}
+
+#[test]
+fn test_verbose_shows_debug_on_stderr() {
+    let mut df = weighted_df();
+    let (_dir, input) = create_temp_csv(&mut df);
+
+    let assert = lophi(&input)
+        .args(["--no-confirm", "-v"])
+        .assert()
+        .success();
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(stderr.contains("Binned feature"));
+    assert!(stderr.contains("feature=predictive_feature"));
+    // Console messages stay on stdout only
+    assert!(!stderr.contains("Step 2: Univariate Gini Analysis"));
+}
+
+#[test]
+fn test_quiet_silences_warnings_but_not_the_log_file() {
+    let mut df = weighted_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    let log_path = dir.path().join("run.log");
+
+    let assert = lophi(&input)
+        .arg("--quiet")
+        .arg("--log-file")
+        .arg(&log_path)
+        .assert()
+        .success();
+    assert!(assert.get_output().stdout.is_empty());
+    assert!(assert.get_output().stderr.is_empty());
+
+    let log = std::fs::read_to_string(&log_path).unwrap();
+    assert!(log.contains("null value(s); defaulting to 1.0"));
+    assert!(log.contains("Binned feature"));
+}
@@ -0,0 +1,97 @@
+//! Integration tests for `--max-memory-gb`
+
+use assert_cmd::Command;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+const BYTES_PER_GB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+fn lophi(input: &std::path::Path, max_memory_gb: f64) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--binning-backend", "greedy"])
+        .arg("--max-memory-gb")
+        .arg(max_memory_gb.to_string())
+        .args(["--no-confirm", "-v"])
+        .env("NO_COLOR", "1");
+    cmd
+}
+
+/// 5,000 rows of long decimals, whose CSV text is larger than the DataFrame
+fn decimal_df() -> DataFrame {
+    let rows = 5_000;
+    let target: Vec<i32> = (0..rows).map(|i| i % 2).collect();
+    let score: Vec<f64> = (0..rows)
+        .map(|i| (i % 2) as f64 + i as f64 / 7.0 / rows as f64)
+        .collect();
+    let noise: Vec<f64> = (0..rows).map(|i| (i as f64).sin() / 3.0).collect();
+    df! {
+        "target" => target,
+        "score" => score,
+        "noise" => noise,
+    }
+    .unwrap()
+}
+
+/// A budget of `factor` times the size of the file at `path`
+fn budget(path: &std::path::Path, factor: f64) -> f64 {
+    std::fs::metadata(path).unwrap().len() as f64 * factor / BYTES_PER_GB
+}
+
+#[test]
+fn test_budget_with_room_loads_in_memory() {
+    let mut df = decimal_df();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    let assert = lophi(&input, budget(&input, 4.0)).assert().success();
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(stderr.contains("mode=InMemory"));
+    assert!(dir.path().join("test_data_reduced.csv").exists());
+}
+
+#[test]
+fn test_tight_budget_streams_the_csv() {
+    let mut df = decimal_df();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    // Fits the DataFrame, not the DataFrame next to the raw CSV bytes
+    let assert = lophi(&input, budget(&input, 1.2)).assert().success();
+    let output = assert.get_output();
+    assert!(String::from_utf8_lossy(&output.stderr).contains("mode=Streaming"));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("streaming engine"));
+
+    let reduced = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(dir.path().join("test_data_reduced.csv")))
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert_eq!(reduced.height(), df.height());
+}
+
+#[test]
+fn test_budget_below_dataset_size_fails_with_suggestion() {
+    let mut df = decimal_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+
+    let assert = lophi(&input, budget(&input, 0.01)).assert().code(2);
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(stderr.contains("over the --max-memory-gb budget"));
+    assert!(stderr.contains("--select-columns"));
+    assert!(stderr.contains("lophi sample"));
+    assert!(!dir.path().join("test_data_reduced.parquet").exists());
+}
+
+#[test]
+fn test_non_positive_budget_is_rejected() {
+    let mut df = decimal_df();
+    let (_dir, input) = create_temp_csv(&mut df);
+
+    let assert = lophi(&input, 0.0).assert().code(2);
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(stderr.contains("--max-memory-gb must be positive"));
+}
@@ -1,6 +1,11 @@
 //! Unit tests for missing value analysis
 
-use lophi::pipeline::{analyze_missing_values, get_features_above_threshold};
+use std::collections::HashSet;
+
+use lophi::pipeline::{
+    analyze_missing_by_segment, analyze_missing_values, get_features_above_threshold,
+    get_features_above_threshold_with_keep,
+};
 use polars::prelude::*;
 
 #[path = "common/mod.rs"]
@@ -91,6 +96,19 @@ fn test_get_features_above_threshold() {
     );
 }
 
+#[test]
+fn test_get_features_above_threshold_with_keep() {
+    let ratios = vec![
+        ("feature_a".to_string(), 0.5),
+        ("feature_b".to_string(), 0.9),
+    ];
+    let keep: HashSet<String> = ["feature_b".to_string()].into();
+
+    let to_drop = get_features_above_threshold_with_keep(&ratios, 0.3, "target", &keep);
+
+    assert_eq!(to_drop, vec!["feature_a".to_string()]);
+}
+
 #[test]
 fn test_get_features_threshold_boundary() {
     let ratios = vec![
@@ -311,3 +329,49 @@ fn test_all_zero_weights_does_not_divide_by_zero() {
         "Error message should mention zero weight, got: {err_msg}"
     );
 }
+
+// ── Per-segment missing ratios (--segment-column) ──────────────────────────
+
+#[test]
+fn test_missing_by_segment_flags_fully_missing_segments() {
+    let df = df! {
+        "target" => [Some(1i32), Some(0), None, Some(0), Some(1)],
+        "region" => [Some("north"), Some("north"), Some("south"), Some("south"), None],
+        "bureau_score" => [Some(600.0f64), Some(640.0), None, None, Some(700.0)],
+        "income" => [Some(1.0f64), None, Some(3.0), Some(4.0), Some(5.0)],
+        "weight" => [1.0f64, 3.0, 1.0, 1.0, 1.0],
+    }
+    .unwrap();
+    let weights = vec![1.0, 3.0, 1.0, 1.0, 1.0];
+
+    let analysis =
+        analyze_missing_by_segment(&df, &weights, Some("weight"), "region", &["target"]).unwrap();
+
+    assert_eq!(analysis.column, "region");
+    assert_eq!(analysis.unsegmented_rows, 1);
+    let segments: Vec<(&str, usize)> = analysis
+        .segments
+        .iter()
+        .map(|s| (s.segment.as_str(), s.rows))
+        .collect();
+    assert_eq!(segments, [("north", 2), ("south", 2)]);
+
+    // Neither the target, the weight nor the segment column is reported
+    let features: Vec<&str> = analysis
+        .features
+        .iter()
+        .map(|f| f.feature.as_str())
+        .collect();
+    assert_eq!(features, ["bureau_score", "income"]);
+
+    let bureau = &analysis.features[0];
+    assert_eq!(bureau.missing_ratios["north"], 0.0);
+    assert_eq!(bureau.missing_ratios["south"], 1.0);
+    assert_eq!(bureau.fully_missing_in, ["south"]);
+
+    // Weighted: the missing north row carries 3 of 4
+    let income = &analysis.features[1];
+    assert!((income.missing_ratios["north"] - 0.75).abs() < 1e-12);
+    assert!(income.fully_missing_in.is_empty());
+    assert_eq!(analysis.flagged(), ["bureau_score"]);
+}
@@ -0,0 +1,179 @@
+//! Integration tests for the missing bin policy (`--missing-bin-policy`)
+
+use assert_cmd::Command;
+use polars::prelude::{df, CsvReadOptions, DataFrame, SerReader};
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 400 rows with a rising event rate in `x`; one row in eight of `x` is null,
+/// and half of those are events
+fn missing_df() -> DataFrame {
+    let n = 400;
+    df! {
+        "target" => (0..n)
+            .map(|i| if i % 8 == 0 { i32::from(i % 16 == 0) } else { i32::from(i % 100 >= 70 || i % 7 == 0) })
+            .collect::<Vec<_>>(),
+        "x" => (0..n)
+            .map(|i| (i % 8 != 0).then_some((i % 100) as f64))
+            .collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn lophi(dir: &std::path::Path, input: &std::path::Path, args: &[&str]) -> Command {
+    let config = dir.join("pipeline.toml");
+    std::fs::write(&config, "use_solver = false\n").unwrap();
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("--config")
+        .arg(&config)
+        .arg("--input")
+        .arg(input)
+        .arg("--output")
+        .arg(dir.join("reduced.csv"))
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--gini-threshold", "0.0"])
+        // x tracks the target closely; keep it past the leakage guard
+        .args(["--correlation-threshold", "0.95"])
+        .args(args);
+    cmd
+}
+
+fn read_gini_export(dir: &std::path::Path) -> serde_json::Value {
+    use std::io::Read;
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_gini_analysis.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+fn x_analysis(export: &serde_json::Value) -> &serde_json::Value {
+    export["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["feature_name"] == "x")
+        .expect("no entry for x")
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_default_policy_keeps_separate_missing_bin() {
+    let mut df = missing_df();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    lophi(dir.path(), &input, &[]).assert().success();
+
+    let export = read_gini_export(dir.path());
+    assert!(export["metadata"].get("missing_bin_policy").is_none());
+    let missing = &x_analysis(&export)["missing_bin"];
+    assert_eq!(missing["count"], 50.0);
+    assert_eq!(missing["event_rate"], 0.5);
+    assert!(missing.get("merged_into").is_none());
+}
+
+#[test]
+fn test_merge_worst_scores_missing_rows_with_host_bin() {
+    let mut df = missing_df();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    lophi(
+        dir.path(),
+        &input,
+        &["--missing-bin-policy", "merge_worst", "--export-woe"],
+    )
+    .assert()
+    .success();
+
+    let export = read_gini_export(dir.path());
+    assert_eq!(export["metadata"]["missing_bin_policy"], "merge_worst");
+    let x = x_analysis(&export);
+    let missing = &x["missing_bin"];
+    let host = missing["merged_into"].as_u64().unwrap() as usize;
+    let bins = x["bins"].as_array().unwrap();
+    assert_eq!(missing["woe"], bins[host]["woe"]);
+    let highest_rate = bins
+        .iter()
+        .map(|b| b["event_rate"].as_f64().unwrap())
+        .fold(f64::MIN, f64::max);
+    assert_eq!(bins[host]["event_rate"], highest_rate);
+    let binned: f64 = bins.iter().map(|b| b["count"].as_f64().unwrap()).sum();
+    assert_eq!(binned, 400.0);
+
+    // Null rows of the WoE export carry the host bin's WoE
+    let encoded = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(dir.path().join("reduced_woe.csv")))
+        .unwrap()
+        .finish()
+        .unwrap();
+    let woe = encoded.column("x").unwrap().f64().unwrap();
+    let host_woe = bins[host]["woe"].as_f64().unwrap();
+    for i in (0..400).step_by(8) {
+        assert!((woe.get(i).unwrap() - host_woe).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_impute_records_the_median() {
+    let mut df = missing_df();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    lophi(dir.path(), &input, &["--missing-bin-policy", "impute"])
+        .assert()
+        .success();
+
+    let export = read_gini_export(dir.path());
+    assert_eq!(export["metadata"]["missing_bin_policy"], "impute");
+    let missing = &x_analysis(&export)["missing_bin"];
+    let median = missing["imputed_value"].as_f64().unwrap();
+    assert!((0.0..100.0).contains(&median));
+    assert!(missing["merged_into"].is_u64());
+    assert_eq!(missing["iv_contribution"], 0.0);
+}
+
+#[test]
+fn test_unknown_policy_is_rejected() {
+    let mut df = missing_df();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    lophi(dir.path(), &input, &["--missing-bin-policy", "drop"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_missing_bin_policy_rejects_continuous_target() {
+    let mut df = df! {
+        "target" => (0..50).map(|i| i as f64 * 1.5).collect::<Vec<_>>(),
+        "x" => (0..50).map(|i| (i % 7) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap();
+    let (_dir, input) = create_temp_csv(&mut df);
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target", "--no-confirm"])
+        .args([
+            "--target-type",
+            "continuous",
+            "--missing-bin-policy",
+            "impute",
+        ])
+        .assert()
+        .failure();
+}
@@ -0,0 +1,232 @@
+//! Tests for glob and directory input read as one dataset
+
+use std::io::Read;
+use std::path::Path;
+
+use assert_cmd::Command;
+use lophi::pipeline::{
+    get_column_names, load_dataset_streaming, load_dataset_with_progress, CsvDialect,
+};
+use polars::prelude::*;
+use tempfile::TempDir;
+
+const ROWS_PER_PART: usize = 30;
+
+/// Rows `offset..offset + ROWS_PER_PART` with a binary target and a predictive income
+fn part(offset: usize) -> DataFrame {
+    let ids: Vec<i64> = (offset..offset + ROWS_PER_PART).map(|i| i as i64).collect();
+    let target: Vec<i32> = ids.iter().map(|i| i32::from(i % 3 == 1)).collect();
+    let income: Vec<f64> = ids
+        .iter()
+        .zip(&target)
+        .map(|(i, t)| (*i as f64) + f64::from(*t) * 1000.0)
+        .collect();
+    df! { "id" => ids, "target" => target, "income" => income }.unwrap()
+}
+
+fn write_parquet(path: &Path, df: &mut DataFrame) {
+    let file = std::fs::File::create(path).unwrap();
+    ParquetWriter::new(file).finish(df).unwrap();
+}
+
+/// Three Parquet parts in `dir/landing`: the second lacks `region` and stores
+/// `income` as integers
+fn write_parts(dir: &Path) -> std::path::PathBuf {
+    let landing = dir.join("landing");
+    std::fs::create_dir(&landing).unwrap();
+    for (index, offset) in [0, ROWS_PER_PART, 2 * ROWS_PER_PART]
+        .into_iter()
+        .enumerate()
+    {
+        let mut df = part(offset);
+        if index == 1 {
+            let income = df.column("income").unwrap().cast(&DataType::Int32).unwrap();
+            df.with_column(income).unwrap();
+        } else {
+            let region = Column::new("region".into(), vec!["north"; ROWS_PER_PART]);
+            df.with_column(region).unwrap();
+        }
+        write_parquet(
+            &landing.join(format!("data_part_{}.parquet", index)),
+            &mut df,
+        );
+    }
+    // Marker files are not data
+    std::fs::write(landing.join("_SUCCESS"), "").unwrap();
+    landing
+}
+
+#[test]
+fn test_glob_and_directory_load_as_one_dataset() {
+    let dir = TempDir::new().unwrap();
+    let landing = write_parts(dir.path());
+    let glob = landing.join("data_part_*.parquet");
+
+    assert_eq!(
+        get_column_names(&glob).unwrap(),
+        vec!["id", "target", "income", "region"]
+    );
+    let (df, rows, cols, _) = load_dataset_with_progress(&glob, 100).unwrap();
+    assert_eq!((rows, cols), (3 * ROWS_PER_PART, 4));
+    // Files are read in name order
+    let ids: Vec<i64> = df
+        .column("id")
+        .unwrap()
+        .i64()
+        .unwrap()
+        .into_no_null_iter()
+        .collect();
+    assert_eq!(ids, (0..3 * ROWS_PER_PART as i64).collect::<Vec<_>>());
+    // Integer and float incomes unify to floats; the missing region is null
+    assert_eq!(df.column("income").unwrap().dtype(), &DataType::Float64);
+    assert_eq!(df.column("region").unwrap().null_count(), ROWS_PER_PART);
+
+    let (from_dir, _, _, _) = load_dataset_with_progress(&landing, 100).unwrap();
+    assert!(from_dir.equals_missing(&df));
+    let (streamed, _, _, _) =
+        load_dataset_streaming(&glob, 100, None, &CsvDialect::default(), None).unwrap();
+    assert!(streamed.equals_missing(&df));
+}
+
+#[test]
+fn test_multi_file_input_errors() {
+    let dir = TempDir::new().unwrap();
+    let landing = write_parts(dir.path());
+
+    let error = load_dataset_with_progress(&landing.join("nothing_*.parquet"), 100).unwrap_err();
+    assert!(error.to_string().contains("No data files match"), "{error}");
+
+    std::fs::write(landing.join("extra.csv"), "id\n1\n").unwrap();
+    let error = load_dataset_with_progress(&landing, 100).unwrap_err();
+    assert!(
+        error.to_string().contains("mixes .parquet and .csv"),
+        "{error}"
+    );
+}
+
+#[test]
+fn test_cli_reduction_over_glob_records_the_files() {
+    let dir = TempDir::new().unwrap();
+    let landing = write_parts(dir.path());
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(landing.join("data_part_*.parquet"))
+        .args(["--target", "target", "--no-confirm", "--drop-columns", "id"])
+        .args(["--binning-backend", "greedy", "--gini-threshold", "0"])
+        .assert()
+        .success();
+
+    // Outputs are named after the folder and written next to it
+    let root = dir.path().canonicalize().unwrap();
+    let reduced = LazyFrame::scan_parquet(root.join("landing_reduced.parquet"), Default::default())
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(reduced.height(), 3 * ROWS_PER_PART);
+
+    let file = std::fs::File::open(root.join("landing_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("landing_reduction_report.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    let report: serde_json::Value = serde_json::from_str(&content).unwrap();
+    let files: Vec<String> = report["metadata"]["input_files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f.as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(files.len(), 3);
+    assert!(files[0].ends_with("data_part_0.parquet"));
+    assert!(files[2].ends_with("data_part_2.parquet"));
+}
+
+/// A Hive-partitioned lake: `year=2023|2024` / `region=north|null`
+fn write_hive_lake(dir: &Path) -> std::path::PathBuf {
+    let lake = dir.join("lake");
+    let folders = [
+        "year=2023/region=north",
+        "year=2023/region=__HIVE_DEFAULT_PARTITION__",
+        "year=2024/region=north",
+    ];
+    for (index, folder) in folders.into_iter().enumerate() {
+        let folder = lake.join(folder);
+        std::fs::create_dir_all(&folder).unwrap();
+        write_parquet(
+            &folder.join("part-0.parquet"),
+            &mut part(index * ROWS_PER_PART),
+        );
+    }
+    // Spark's scratch folder is not a partition
+    std::fs::create_dir(lake.join("_temporary")).unwrap();
+    write_parquet(&lake.join("_temporary/part-9.parquet"), &mut part(0));
+    lake
+}
+
+#[test]
+fn test_hive_partition_folders_become_columns() {
+    let dir = TempDir::new().unwrap();
+    let lake = write_hive_lake(dir.path());
+
+    assert_eq!(
+        get_column_names(&lake).unwrap(),
+        vec!["id", "target", "income", "year", "region"]
+    );
+    let (df, rows, _, _) = load_dataset_with_progress(&lake, 100).unwrap();
+    assert_eq!(rows, 3 * ROWS_PER_PART);
+    let year = df.column("year").unwrap();
+    assert_eq!(year.dtype(), &DataType::Int64);
+    assert_eq!(year.i64().unwrap().get(0), Some(2023));
+    assert_eq!(year.i64().unwrap().get(rows - 1), Some(2024));
+    let region = df.column("region").unwrap();
+    assert_eq!(region.dtype(), &DataType::String);
+    assert_eq!(region.null_count(), ROWS_PER_PART);
+
+    // A glob below the lake reads the same partitions
+    let (globbed, _, _, _) =
+        load_dataset_with_progress(&lake.join("year=*/region=*/*.parquet"), 100).unwrap();
+    assert!(globbed.equals_missing(&df));
+
+    // Files must share their partition keys
+    std::fs::create_dir(lake.join("year=2025")).unwrap();
+    write_parquet(&lake.join("year=2025/part-0.parquet"), &mut part(0));
+    let error = load_dataset_with_progress(&lake, 100).unwrap_err();
+    assert!(error.to_string().contains("partitioned by"), "{error}");
+}
+
+#[test]
+fn test_cli_drop_partition_columns() {
+    let dir = TempDir::new().unwrap();
+    let lake = write_hive_lake(dir.path());
+    let root = dir.path().canonicalize().unwrap();
+    let output_columns = |extra: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_lophi"))
+            .arg("--input")
+            .arg(&lake)
+            .args(["--target", "target", "--no-confirm", "--drop-columns", "id"])
+            .args(["--binning-backend", "greedy", "--gini-threshold", "0"])
+            .args(["--missing-threshold", "1", "--correlation-threshold", "1"])
+            .args(extra)
+            .assert()
+            .success();
+        let file = std::fs::File::open(root.join("lake_reduced.parquet")).unwrap();
+        let schema = ParquetReader::new(file).schema().unwrap();
+        schema
+            .iter_names()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        output_columns(&[]),
+        vec!["target", "income", "year", "region"]
+    );
+    assert_eq!(
+        output_columns(&["--drop-partition-columns"]),
+        vec!["target", "income"]
+    );
+}
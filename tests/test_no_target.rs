@@ -0,0 +1,167 @@
+//! Integration tests for unsupervised runs (`--no-target`)
+
+use std::io::Read;
+
+use assert_cmd::Command;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 300 rows and no target: `income` and its copy `income_copy`, an
+/// unrelated `tenure`, a constant `flag`, a `sparse` feature that is null on
+/// 60% of the rows and a `region` category.
+fn unlabeled_df() -> DataFrame {
+    let n = 300;
+    let income: Vec<f64> = (0..n).map(|i| (i % 10) as f64 * 1000.0).collect();
+    df! {
+        "income" => &income,
+        "income_copy" => &income,
+        "tenure" => (0..n).map(|i| ((i * 7) % 13) as f64).collect::<Vec<_>>(),
+        "flag" => vec![1.0; n],
+        "sparse" => (0..n)
+            .map(|i| (i % 5 >= 3).then_some(i as f64))
+            .collect::<Vec<_>>(),
+        "region" => (0..n)
+            .map(|i| ["north", "south", "east"][(i / 3) % 3])
+            .collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn lophi(input: &std::path::Path) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("--input")
+        .arg(input)
+        .args(["--no-target", "--no-confirm"]);
+    cmd
+}
+
+fn read_report_json(dir: &std::path::Path) -> serde_json::Value {
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_reduction_report.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+fn dropped_at(report: &serde_json::Value, feature: &str) -> serde_json::Value {
+    report["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["name"] == feature)
+        .unwrap_or_else(|| panic!("no report entry for {}", feature))["dropped_at_stage"]
+        .clone()
+}
+
+fn reduced_columns(dir: &std::path::Path) -> Vec<String> {
+    CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(dir.join("test_data_reduced.csv")))
+        .unwrap()
+        .finish()
+        .unwrap()
+        .get_column_names_str()
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_no_target_reduces_without_gini_step() {
+    let mut df = unlabeled_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    lophi(&input).assert().success();
+
+    let report = read_report_json(dir.path());
+    let settings = &report["metadata"]["settings"];
+    assert_eq!(settings["target_column"], "");
+    assert_eq!(settings["target_type"], "none");
+    assert_eq!(settings["steps"]["skipped"], serde_json::json!(["gini"]));
+    assert_eq!(report["metadata"]["thresholds"]["dominance"], 0.995);
+
+    assert_eq!(dropped_at(&report, "flag"), "constant");
+    assert_eq!(dropped_at(&report, "sparse"), "missing");
+    assert_eq!(dropped_at(&report, "income_copy"), "correlation");
+    assert!(dropped_at(&report, "income").is_null());
+    assert!(dropped_at(&report, "tenure").is_null());
+    assert!(report["summary"]["by_stage"].get("gini").is_none());
+    assert!(report["summary"]["timing"].get("gini_ms").is_none());
+    assert!(report["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|f| f["analysis"].get("gini").is_none()));
+
+    let file = std::fs::File::open(dir.path().join("test_data_reduction_report.zip")).unwrap();
+    let archive = ::zip::ZipArchive::new(file).unwrap();
+    assert!(!archive.file_names().any(|e| e.contains("gini_analysis")));
+
+    assert_eq!(reduced_columns(dir.path()), ["income", "tenure", "region"]);
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("validate-report")
+        .arg(dir.path().join("test_data_reduction_report.zip"))
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_no_target_keeps_explicit_dominance_threshold() {
+    let mut df = unlabeled_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    lophi(&input)
+        .args(["--dominance-threshold", "0.5"])
+        .assert()
+        .success();
+
+    let report = read_report_json(dir.path());
+    assert_eq!(report["metadata"]["thresholds"]["dominance"], 0.5);
+    // `sparse` is dominated by null, which is left to missing analysis
+    assert_eq!(dropped_at(&report, "sparse"), "missing");
+}
+
+#[test]
+fn test_no_target_rejects_target_options() {
+    let mut df = unlabeled_df();
+    let (_dir, input) = create_temp_csv(&mut df);
+    for (args, message) in [
+        (vec!["--target", "income"], "cannot be used with"),
+        (vec!["--export-woe"], "--export-woe needs a target"),
+        (vec!["--iv-stability"], "--iv-stability needs a target"),
+        (vec!["--steps", "gini"], "No steps left to run"),
+    ] {
+        let output = lophi(&input).args(&args).output().unwrap();
+        assert!(!output.status.success(), "{:?} should fail", args);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains(message), "{:?}: {}", args, stderr);
+    }
+}
+
+#[test]
+fn test_missing_target_suggests_no_target() {
+    let mut df = unlabeled_df();
+    let (_dir, input) = create_temp_csv(&mut df);
+    let output = Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .arg("--no-confirm")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--no-target"));
+}
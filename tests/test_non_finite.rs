@@ -0,0 +1,207 @@
+//! Tests for NaN/±Inf detection and the non-finite handling policies
+
+use lophi::pipeline::{
+    analyze_features_iv, analyze_missing_values, apply_non_finite_policy, detect_non_finite,
+    find_correlated_pairs_auto, BinningStrategy, MissingBinPolicy, NonFinitePolicy,
+};
+use polars::prelude::*;
+
+fn create_non_finite_dataframe() -> DataFrame {
+    df! {
+        "target" => [0i32, 1, 0, 1, 0, 1, 0, 1, 0, 1],
+        "clean" => [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0],
+        "with_nan" => [1.0f64, f64::NAN, 3.0, 4.0, 5.0, f64::NAN, 7.0, 8.0, 9.0, 10.0],
+        "with_inf" => [f64::NEG_INFINITY, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, f64::INFINITY],
+        "with_nulls" => [Some(1.0f64), None, Some(3.0), Some(4.0), Some(5.0), Some(6.0), Some(7.0), Some(8.0), Some(9.0), Some(f64::NAN)],
+        "as_f32" => [1.0f32, 2.0, f32::INFINITY, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0],
+        "category" => ["a", "b", "a", "b", "a", "b", "a", "b", "a", "b"],
+    }
+    .unwrap()
+}
+
+fn f64_values(df: &DataFrame, name: &str) -> Vec<Option<f64>> {
+    df.column(name)
+        .unwrap()
+        .as_materialized_series()
+        .cast(&DataType::Float64)
+        .unwrap()
+        .f64()
+        .unwrap()
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn test_policy_parse_and_display() {
+    for (input, expected) in [
+        ("treat-as-missing", NonFinitePolicy::TreatAsMissing),
+        ("CLIP", NonFinitePolicy::Clip),
+        ("error", NonFinitePolicy::Error),
+    ] {
+        let policy: NonFinitePolicy = input.parse().unwrap();
+        assert_eq!(policy, expected);
+        assert_eq!(
+            policy.to_string().parse::<NonFinitePolicy>().unwrap(),
+            policy
+        );
+    }
+    assert!("ignore".parse::<NonFinitePolicy>().is_err());
+    assert_eq!(NonFinitePolicy::default(), NonFinitePolicy::TreatAsMissing);
+}
+
+#[test]
+fn test_detect_counts_per_feature() {
+    let df = create_non_finite_dataframe();
+    let counts = detect_non_finite(&df, &["target"]).unwrap();
+
+    let names: Vec<&str> = counts.iter().map(|c| c.feature.as_str()).collect();
+    assert_eq!(names, vec!["with_nan", "with_inf", "with_nulls", "as_f32"]);
+
+    let with_nan = &counts[0];
+    assert_eq!(with_nan.nan_count, 2);
+    assert_eq!(with_nan.total(), 2);
+
+    let with_inf = &counts[1];
+    assert_eq!(with_inf.pos_inf_count, 1);
+    assert_eq!(with_inf.neg_inf_count, 1);
+    assert_eq!(with_inf.nan_count, 0);
+
+    // Nulls are not counted as non-finite
+    assert_eq!(counts[2].total(), 1);
+}
+
+#[test]
+fn test_detect_respects_exclusions() {
+    let df = create_non_finite_dataframe();
+    let counts = detect_non_finite(&df, &["target", "with_nan", "with_inf"]).unwrap();
+    assert!(counts.iter().all(|c| c.feature != "with_nan"));
+    assert!(counts.iter().all(|c| c.feature != "with_inf"));
+}
+
+#[test]
+fn test_treat_as_missing_replaces_with_null() {
+    let mut df = create_non_finite_dataframe();
+    let counts =
+        apply_non_finite_policy(&mut df, NonFinitePolicy::TreatAsMissing, &["target"]).unwrap();
+    assert_eq!(counts.len(), 4);
+
+    assert_eq!(df.column("with_nan").unwrap().null_count(), 2);
+    assert_eq!(df.column("with_inf").unwrap().null_count(), 2);
+    assert_eq!(df.column("with_nulls").unwrap().null_count(), 2);
+    assert_eq!(df.column("clean").unwrap().null_count(), 0);
+
+    // Original dtype is preserved
+    assert_eq!(df.column("as_f32").unwrap().dtype(), &DataType::Float32);
+    assert_eq!(df.column("as_f32").unwrap().null_count(), 1);
+
+    assert!(detect_non_finite(&df, &[]).unwrap().is_empty());
+}
+
+#[test]
+fn test_treat_as_missing_feeds_missing_ratio() {
+    let mut df = create_non_finite_dataframe();
+    apply_non_finite_policy(&mut df, NonFinitePolicy::TreatAsMissing, &["target"]).unwrap();
+
+    let weights = vec![1.0; df.height()];
+    let ratios: std::collections::HashMap<_, _> = analyze_missing_values(&df, &weights, None)
+        .unwrap()
+        .into_iter()
+        .collect();
+    assert!((ratios["with_nan"] - 0.2).abs() < 1e-9);
+    assert!((ratios["with_inf"] - 0.2).abs() < 1e-9);
+}
+
+#[test]
+fn test_clip_replaces_infinities_with_finite_extremes() {
+    let mut df = create_non_finite_dataframe();
+    apply_non_finite_policy(&mut df, NonFinitePolicy::Clip, &["target"]).unwrap();
+
+    let values = f64_values(&df, "with_inf");
+    assert_eq!(values[0], Some(2.0));
+    assert_eq!(values[9], Some(9.0));
+    assert_eq!(df.column("with_inf").unwrap().null_count(), 0);
+
+    // NaN has no natural clip value and becomes missing
+    assert_eq!(df.column("with_nan").unwrap().null_count(), 2);
+
+    let f32_values = f64_values(&df, "as_f32");
+    assert_eq!(f32_values[2], Some(10.0));
+}
+
+#[test]
+fn test_clip_all_infinite_column_becomes_null() {
+    let mut df = df! {
+        "target" => [0i32, 1],
+        "all_inf" => [f64::INFINITY, f64::NEG_INFINITY],
+    }
+    .unwrap();
+    apply_non_finite_policy(&mut df, NonFinitePolicy::Clip, &["target"]).unwrap();
+    assert_eq!(df.column("all_inf").unwrap().null_count(), 2);
+}
+
+#[test]
+fn test_error_policy_fails_with_feature_names() {
+    let mut df = create_non_finite_dataframe();
+    let err = apply_non_finite_policy(&mut df, NonFinitePolicy::Error, &["target"]).unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("with_nan"));
+    assert!(msg.contains("with_inf"));
+    assert!(!msg.contains("clean"));
+}
+
+#[test]
+fn test_error_policy_passes_clean_data() {
+    let mut df = df! {
+        "target" => [0i32, 1, 0],
+        "clean" => [1.0f64, 2.0, 3.0],
+    }
+    .unwrap();
+    let counts = apply_non_finite_policy(&mut df, NonFinitePolicy::Error, &["target"]).unwrap();
+    assert!(counts.is_empty());
+}
+
+#[test]
+fn test_sanitised_features_produce_finite_statistics() {
+    let mut df = create_non_finite_dataframe();
+    apply_non_finite_policy(&mut df, NonFinitePolicy::TreatAsMissing, &["target"]).unwrap();
+    let weights = vec![1.0; df.height()];
+
+    let analyses = analyze_features_iv(
+        &df,
+        "target",
+        5,
+        10,
+        None,
+        BinningStrategy::Quantile,
+        Some(1),
+        None,
+        &weights,
+        None,
+        None,
+        None,
+        MissingBinPolicy::Separate,
+        None,
+    )
+    .unwrap();
+    for analysis in &analyses {
+        assert!(
+            analysis.iv.is_finite(),
+            "{} IV not finite",
+            analysis.feature_name
+        );
+        assert!(
+            analysis.gini.is_finite(),
+            "{} Gini not finite",
+            analysis.feature_name
+        );
+        for bin in &analysis.bins {
+            assert!(bin.lower_bound.is_finite() || bin.lower_bound == f64::NEG_INFINITY);
+            assert!(bin.upper_bound.is_finite() || bin.upper_bound == f64::INFINITY);
+        }
+    }
+
+    let pairs = find_correlated_pairs_auto(&df, 0.0, &weights, None, None).unwrap();
+    for pair in &pairs {
+        assert!(pair.correlation.is_finite());
+    }
+}
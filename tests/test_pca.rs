@@ -0,0 +1,121 @@
+//! Integration tests for the PCA report of the retained features (`--pca`)
+
+use std::io::Read;
+
+use assert_cmd::Command;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 300 rows without a target: `balance` and `balance_noisy` move together
+/// (correlation about 0.96), `tenure` is unrelated to both and `region` is
+/// categorical.
+fn accounts_df() -> DataFrame {
+    let n = 300;
+    let balance = |i: usize| (i % 10) as f64;
+    df! {
+        "balance" => (0..n).map(balance).collect::<Vec<_>>(),
+        "balance_noisy" => (0..n)
+            .map(|i| balance(i) + ((i * 7) % 3) as f64)
+            .collect::<Vec<_>>(),
+        "tenure" => (0..n).map(|i| ((i / 10) % 13) as f64).collect::<Vec<_>>(),
+        "region" => (0..n)
+            .map(|i| ["north", "south", "east"][(i / 3) % 3])
+            .collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn lophi(input: &std::path::Path, args: &[&str]) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("--input")
+        .arg(input)
+        .args(["--no-target", "--no-confirm"])
+        .args(["--correlation-threshold", "0.99"])
+        .args(args);
+    cmd
+}
+
+fn read_report_json(dir: &std::path::Path) -> serde_json::Value {
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_reduction_report.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_pca_reports_variance_and_loading_groups() {
+    let mut df = accounts_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    lophi(&input, &["--pca"]).assert().success();
+
+    let report = read_report_json(dir.path());
+    let pca = &report["pca"];
+    assert_eq!(
+        pca["features"],
+        serde_json::json!(["balance", "balance_noisy", "tenure"])
+    );
+    assert_eq!(pca["loading_threshold"], 0.7);
+    assert_eq!(pca["components_for_95"], 2);
+    assert_eq!(pca["kaiser_components"], 1);
+
+    let components = pca["components"].as_array().unwrap();
+    assert_eq!(components.len(), 2);
+    let first = &components[0];
+    assert_eq!(first["component"], 1);
+    let group: Vec<&str> = first["high_loadings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|l| l["feature"].as_str().unwrap())
+        .collect();
+    assert_eq!(group.len(), 2);
+    assert!(group.contains(&"balance") && group.contains(&"balance_noisy"));
+    let cumulative = components[1]["cumulative_variance"].as_f64().unwrap();
+    assert!(cumulative >= 0.95, "{}", cumulative);
+
+    // Informational only: every feature is kept
+    assert!(report["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|f| f["dropped_at_stage"].is_null()));
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("validate-report")
+        .arg(dir.path().join("test_data_reduction_report.zip"))
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_pca_section_needs_the_flag_and_two_numeric_features() {
+    let mut df = accounts_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    lophi(&input, &[]).assert().success();
+    assert!(read_report_json(dir.path()).get("pca").is_none());
+
+    let mut df = accounts_df().select(["balance", "region"]).unwrap();
+    let (dir, input) = create_temp_parquet(&mut df);
+    lophi(&input, &["--pca"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("PCA skipped"));
+    assert!(read_report_json(dir.path()).get("pca").is_none());
+}
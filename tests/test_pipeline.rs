@@ -322,6 +322,9 @@ fn test_cart_categorical_binning() {
         &weights,
         None,
         None,
+        None,
+        MissingBinPolicy::Separate,
+        None,
     );
 
     assert!(result.is_ok(), "CART categorical binning should succeed");
@@ -404,6 +407,9 @@ fn test_cart_categorical_with_many_categories() {
         &weights,
         None,
         None,
+        None,
+        MissingBinPolicy::Separate,
+        None,
     );
 
     assert!(
@@ -493,6 +499,9 @@ fn test_cart_categorical_respects_min_bin_size() {
         &weights,
         None,
         None,
+        None,
+        MissingBinPolicy::Separate,
+        None,
     );
 
     assert!(
@@ -531,6 +540,12 @@ fn make_iv_analysis(name: &str, gini: f64) -> IvAnalysis {
         missing_bin: None,
         iv: 0.0,
         gini,
+        mutual_info: None,
+        monotonicity: None,
+        solver_status: None,
+        special_bins: Vec::new(),
+        ks: 0.0,
+        auc: 0.5,
     }
 }
 
@@ -600,3 +615,305 @@ fn test_get_low_gini_features_none_below_threshold() {
         "No features should be returned when all are above threshold"
     );
 }
+
+// ── get_low_univariate_features / mutual_information ────────────────────────
+
+/// Two-bin analysis with the given (events, non_events) per bin
+fn make_two_bin_analysis(name: &str, cells: [(f64, f64); 2]) -> IvAnalysis {
+    let bins = cells
+        .iter()
+        .enumerate()
+        .map(|(i, &(events, non_events))| WoeBin {
+            lower_bound: if i == 0 { f64::NEG_INFINITY } else { 0.0 },
+            upper_bound: if i == 0 { 0.0 } else { f64::INFINITY },
+            events,
+            non_events,
+            woe: 0.0,
+            iv_contribution: 0.0,
+            count: events + non_events,
+            population_pct: 50.0,
+            event_rate: events / (events + non_events),
+            chi_square: None,
+            chi_square_p_value: None,
+        })
+        .collect();
+    IvAnalysis {
+        bins,
+        ..make_iv_analysis(name, 0.0)
+    }
+}
+
+#[test]
+fn test_mutual_information_perfect_separation_is_ln2() {
+    let analysis = make_two_bin_analysis("perfect", [(50.0, 0.0), (0.0, 50.0)]);
+    let mi = mutual_information(&analysis);
+    assert!(
+        (mi - std::f64::consts::LN_2).abs() < 1e-12,
+        "A balanced, perfectly separating feature carries ln 2 nats, got {}",
+        mi
+    );
+}
+
+#[test]
+fn test_mutual_information_independent_bins_is_zero() {
+    let analysis = make_two_bin_analysis("noise", [(10.0, 40.0), (20.0, 80.0)]);
+    assert!(mutual_information(&analysis).abs() < 1e-12);
+}
+
+#[test]
+fn test_mutual_information_single_class_is_zero() {
+    let analysis = make_two_bin_analysis("no_events", [(0.0, 40.0), (0.0, 60.0)]);
+    assert_eq!(mutual_information(&analysis), 0.0);
+}
+
+#[test]
+fn test_get_low_univariate_features_mutual_info_records_values() {
+    let mut analyses = vec![
+        make_two_bin_analysis("signal", [(45.0, 5.0), (5.0, 45.0)]),
+        make_two_bin_analysis("noise", [(10.0, 40.0), (20.0, 80.0)]),
+    ];
+
+    let dropped = get_low_univariate_features(&mut analyses, UnivariateMetric::MutualInfo, 0.01);
+
+    assert_eq!(dropped, vec!["noise".to_string()]);
+    assert!(analyses[0].mutual_info.unwrap() > 0.01);
+    assert!(analyses[1].mutual_info.unwrap() < 1e-12);
+}
+
+#[test]
+fn test_get_low_univariate_features_iv_matches_gini_filter() {
+    let mut analyses = vec![
+        make_iv_analysis("high_gini", 0.20),
+        make_iv_analysis("low_gini", 0.02),
+    ];
+
+    let dropped = get_low_univariate_features(&mut analyses, UnivariateMetric::Iv, 0.05);
+
+    assert_eq!(dropped, get_low_gini_features(&analyses, 0.05));
+    assert!(
+        analyses.iter().all(|a| a.mutual_info.is_none()),
+        "The IV metric should not compute mutual information"
+    );
+}
+
+#[test]
+fn test_univariate_metric_parsing() {
+    assert_eq!("iv".parse::<UnivariateMetric>(), Ok(UnivariateMetric::Iv));
+    assert_eq!(
+        "mutual_info".parse::<UnivariateMetric>(),
+        Ok(UnivariateMetric::MutualInfo)
+    );
+    assert_eq!(
+        "Mutual-Info".parse::<UnivariateMetric>(),
+        Ok(UnivariateMetric::MutualInfo)
+    );
+    assert!("entropy".parse::<UnivariateMetric>().is_err());
+    assert_eq!(UnivariateMetric::MutualInfo.to_string(), "mutual_info");
+}
+
+// ── ks_and_auc ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_ks_and_auc_perfect_separation() {
+    let analysis = make_two_bin_analysis("perfect", [(0.0, 50.0), (50.0, 0.0)]);
+    assert_eq!(ks_and_auc(&analysis), (1.0, 1.0));
+}
+
+#[test]
+fn test_ks_and_auc_equal_event_rates() {
+    let analysis = make_two_bin_analysis("noise", [(10.0, 40.0), (20.0, 80.0)]);
+    let (ks, auc) = ks_and_auc(&analysis);
+    assert!(ks.abs() < 1e-12);
+    assert!((auc - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn test_ks_and_auc_known_values() {
+    // Ranked (30, 70) then (10, 90): KS = 30/40 - 70/160 = 0.3125,
+    // AUC = (30·90 + ½·30·70 + ½·10·90) / (40·160) = 0.65625
+    let analysis = make_two_bin_analysis("f", [(10.0, 90.0), (30.0, 70.0)]);
+    let (ks, auc) = ks_and_auc(&analysis);
+    assert!((ks - 0.3125).abs() < 1e-12, "got {}", ks);
+    assert!((auc - 0.65625).abs() < 1e-12, "got {}", auc);
+}
+
+#[test]
+fn test_analyze_features_iv_fills_ks_and_auc() {
+    let df = create_binary_target_dataframe();
+    let weights = vec![1.0; df.height()];
+    let analyses = analyze_features_iv(
+        &df,
+        "target",
+        5,
+        10,
+        None,
+        BinningStrategy::Quantile,
+        None,
+        None,
+        &weights,
+        None,
+        None,
+        None,
+        MissingBinPolicy::Separate,
+        None,
+    )
+    .unwrap();
+
+    for a in &analyses {
+        assert_eq!((a.ks, a.auc), ks_and_auc(a), "{}", a.feature_name);
+        assert!((0.0..=1.0).contains(&a.ks));
+        assert!((0.5..=1.0).contains(&a.auc));
+    }
+    assert!(
+        analyses.iter().any(|a| a.auc > 0.5),
+        "some feature should discriminate"
+    );
+}
+
+// ── adjacent-bin chi-square / merge_insignificant_bins ──────────────────────
+
+/// Numeric analysis with one bin per (events, non_events) pair and no missing bin
+fn make_binned_analysis(name: &str, cells: &[(f64, f64)]) -> IvAnalysis {
+    let total_events: f64 = cells.iter().map(|c| c.0).sum();
+    let total_non_events: f64 = cells.iter().map(|c| c.1).sum();
+    let total = total_events + total_non_events;
+    let bins = cells
+        .iter()
+        .enumerate()
+        .map(|(i, &(events, non_events))| {
+            let dist_events = (events + 0.5) / (total_events + 0.5);
+            let dist_non_events = (non_events + 0.5) / (total_non_events + 0.5);
+            let woe = (dist_events / dist_non_events).ln();
+            WoeBin {
+                lower_bound: if i == 0 { f64::NEG_INFINITY } else { i as f64 },
+                upper_bound: if i + 1 == cells.len() {
+                    f64::INFINITY
+                } else {
+                    (i + 1) as f64
+                },
+                events,
+                non_events,
+                woe,
+                iv_contribution: (dist_events - dist_non_events) * woe,
+                count: events + non_events,
+                population_pct: (events + non_events) / total * 100.0,
+                event_rate: events / (events + non_events),
+                chi_square: None,
+                chi_square_p_value: None,
+            }
+        })
+        .collect::<Vec<_>>();
+    IvAnalysis {
+        iv: bins.iter().map(|b| b.iv_contribution).sum(),
+        bins,
+        ..make_iv_analysis(name, 0.0)
+    }
+}
+
+#[test]
+fn test_adjacent_bin_chi_square_known_value() {
+    let analysis = make_binned_analysis("f", &[(10.0, 90.0), (30.0, 70.0)]);
+    let (statistic, p_value) = adjacent_bin_chi_square(&analysis.bins[0], &analysis.bins[1]);
+
+    // 200 · (10·70 − 30·90)² / (100 · 100 · 40 · 160) = 12.5
+    assert!((statistic - 12.5).abs() < 1e-9, "got {}", statistic);
+    assert!((p_value - 4.0695e-4).abs() < 1e-7, "got {}", p_value);
+}
+
+#[test]
+fn test_adjacent_bin_chi_square_equal_rates() {
+    let analysis = make_binned_analysis("f", &[(10.0, 90.0), (20.0, 180.0), (0.0, 50.0)]);
+    let (statistic, p_value) = adjacent_bin_chi_square(&analysis.bins[0], &analysis.bins[1]);
+    assert!(statistic.abs() < 1e-12);
+    assert!((p_value - 1.0).abs() < 1e-6);
+
+    // Two bins without events cannot differ
+    let empty = make_binned_analysis("g", &[(0.0, 40.0), (0.0, 60.0)]);
+    assert_eq!(
+        adjacent_bin_chi_square(&empty.bins[0], &empty.bins[1]),
+        (0.0, 1.0)
+    );
+}
+
+#[test]
+fn test_analyze_features_iv_exports_adjacent_chi_square() {
+    let df = create_binary_target_dataframe();
+    let weights = vec![1.0; df.height()];
+    let analyses = analyze_features_iv(
+        &df,
+        "target",
+        5,
+        10,
+        None,
+        BinningStrategy::Quantile,
+        None,
+        None,
+        &weights,
+        None,
+        None,
+        None,
+        MissingBinPolicy::Separate,
+        None,
+    )
+    .unwrap();
+
+    let numeric = analyses
+        .iter()
+        .find(|a| a.bins.len() > 1)
+        .expect("a numeric feature with several bins");
+    assert!(numeric.bins[0].chi_square.is_none());
+    for bin in &numeric.bins[1..] {
+        assert!(bin.chi_square.unwrap() >= 0.0);
+        let p = bin.chi_square_p_value.unwrap();
+        assert!((0.0..=1.0).contains(&p));
+    }
+}
+
+#[test]
+fn test_merge_insignificant_bins_merges_indistinguishable_neighbours() {
+    let mut analyses = vec![
+        make_binned_analysis("f", &[(10.0, 90.0), (11.0, 89.0), (40.0, 60.0)]),
+        make_binned_analysis("g", &[(5.0, 95.0), (50.0, 50.0)]),
+    ];
+    let iv_before = analyses[0].iv;
+
+    let removed = merge_insignificant_bins(&mut analyses, 0.05);
+
+    assert_eq!(removed, 1);
+    let f = analyses.iter().find(|a| a.feature_name == "f").unwrap();
+    assert_eq!(f.bins.len(), 2);
+    assert_eq!(f.bins[0].events, 21.0);
+    assert_eq!(f.bins[0].non_events, 179.0);
+    assert!(f.bins[0].lower_bound.is_infinite());
+    assert_eq!(f.bins[0].upper_bound, 2.0);
+    assert!(f.bins[0].chi_square.is_none());
+    assert!(f.bins[1].chi_square_p_value.unwrap() <= 0.05);
+    assert!(
+        (f.iv - iv_before).abs() < 0.05 * iv_before,
+        "merging indistinguishable bins barely changes IV: {} -> {}",
+        iv_before,
+        f.iv
+    );
+    assert!(f.gini > 0.0);
+
+    // Already significant everywhere: untouched
+    let g = analyses.iter().find(|a| a.feature_name == "g").unwrap();
+    assert_eq!(g.bins.len(), 2);
+
+    // Sorted by IV descending after the merge
+    assert!(analyses[0].iv >= analyses[1].iv);
+}
+
+#[test]
+fn test_merge_insignificant_bins_alpha_one_keeps_bins() {
+    let mut analyses = vec![make_binned_analysis(
+        "f",
+        &[(10.0, 90.0), (10.0, 90.0), (10.0, 90.0)],
+    )];
+    assert_eq!(merge_insignificant_bins(&mut analyses, 1.0), 0);
+    assert_eq!(analyses[0].bins.len(), 3);
+
+    // Any positive level collapses identical bins into one
+    assert_eq!(merge_insignificant_bins(&mut analyses, 0.5), 2);
+    assert_eq!(analyses[0].bins.len(), 1);
+}
@@ -0,0 +1,83 @@
+//! Integration tests for the WoE/event-rate charts (`--plots`)
+
+use assert_cmd::Command;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+fn lophi(input: &std::path::Path, args: &[&str]) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--binning-backend", "greedy"])
+        .args(["--gini-threshold", "0.0", "--no-confirm"])
+        .args(args)
+        .env("NO_COLOR", "1");
+    cmd
+}
+
+#[cfg(feature = "plots")]
+#[test]
+fn test_plots_chart_the_top_features_by_iv() {
+    let mut df = create_binary_target_dataframe();
+    let (dir, input) = create_temp_parquet(&mut df);
+
+    let assert = lophi(&input, &["--plots", "--plots-top", "1"])
+        .assert()
+        .success();
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout);
+    assert!(stdout.contains("WoE plots saved to"));
+
+    let plots = dir.path().join("test_data_plots");
+    let svg = std::fs::read_to_string(plots.join("01_predictive_feature.svg")).unwrap();
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains("predictive_feature"));
+    assert!(!plots.join("02_random_feature.svg").exists());
+
+    let html = std::fs::read_to_string(plots.join("index.html")).unwrap();
+    assert!(html.contains("<img src=\"01_predictive_feature.svg\""));
+}
+
+#[cfg(feature = "plots")]
+#[test]
+fn test_plots_as_png() {
+    let mut df = create_binary_target_dataframe();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    lophi(&input, &["--plots", "--plot-format", "png"])
+        .assert()
+        .success();
+    let png = std::fs::read(dir.path().join("test_data_plots/01_predictive_feature.png")).unwrap();
+    assert!(png.starts_with(b"\x89PNG"));
+    assert!(dir
+        .path()
+        .join("test_data_plots/02_random_feature.png")
+        .exists());
+}
+
+#[test]
+fn test_plots_need_a_binary_target() {
+    let mut df = create_binary_target_dataframe();
+    let (dir, input) = create_temp_parquet(&mut df);
+
+    let assert = lophi(&input, &["--target-type", "continuous", "--plots"])
+        .assert()
+        .code(2);
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(stderr.contains("--plots"));
+    assert!(!dir.path().join("test_data_plots").exists());
+}
+
+#[test]
+fn test_unknown_plot_format_is_rejected() {
+    let mut df = create_binary_target_dataframe();
+    let (_dir, input) = create_temp_parquet(&mut df);
+
+    let assert = lophi(&input, &["--plots", "--plot-format", "jpg"])
+        .assert()
+        .failure();
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(stderr.contains("Valid options: svg, png"));
+}
@@ -0,0 +1,498 @@
+//! Integration tests for the programmatic `Reducer` API
+
+use lophi::pipeline::{
+    BinningStrategy, CorrelationStrategy, FeatureToDrop, NonFinitePolicy, ReductionStep,
+    SolverConfig, StepContext, StepPosition, TargetMapping,
+};
+use lophi::Reducer;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+#[test]
+fn test_reducer_from_dataframe_drops_per_stage() {
+    let df = create_test_dataframe();
+
+    let result = Reducer::from_dataframe(df, "target")
+        .missing_threshold(0.3)
+        .gini_threshold(0.05)
+        .correlation_threshold(0.95)
+        .binning_strategy(BinningStrategy::Quantile)
+        .min_category_samples(1)
+        .run()
+        .unwrap();
+
+    assert_eq!(result.initial_features, 5);
+    assert!(result
+        .dropped_missing
+        .contains(&"feature_missing".to_string()));
+    assert!(result
+        .iv_analyses
+        .iter()
+        .all(|a| a.feature_name != "feature_missing"));
+
+    // Every dropped feature is gone from the data, the target is kept
+    let kept = result.kept_features();
+    for dropped in result.dropped_features() {
+        assert!(!kept.contains(&dropped), "{} should be dropped", dropped);
+    }
+    assert_has_columns(&result.dataframe, &["target"]);
+    assert_eq!(
+        kept.len() + result.dropped_features().len(),
+        result.initial_features
+    );
+}
+
+#[test]
+fn test_reducer_from_path_matches_dataframe_run() {
+    let mut df = create_test_dataframe();
+    let (_temp_dir, csv_path) = create_temp_csv(&mut df);
+
+    let from_path = Reducer::from_path(&csv_path, "target")
+        .correlation_threshold(0.95)
+        .run()
+        .unwrap();
+    let from_df = Reducer::from_dataframe(df, "target")
+        .correlation_threshold(0.95)
+        .run()
+        .unwrap();
+
+    assert_eq!(from_path.dropped_features(), from_df.dropped_features());
+    assert_eq!(from_path.kept_features(), from_df.kept_features());
+}
+
+#[test]
+fn test_reducer_exposes_iv_tables_and_pairs() {
+    let df = create_correlation_test_dataframe();
+
+    let result = Reducer::from_dataframe(df, "target")
+        .gini_threshold(0.0)
+        .correlation_threshold(0.9)
+        .run()
+        .unwrap();
+
+    assert!(!result.iv_analyses.is_empty());
+    let first = &result.iv_analyses[0];
+    assert!(result.iv_analysis(&first.feature_name).is_some());
+    assert!(result.iv_analysis("does_not_exist").is_none());
+
+    for pair in &result.correlated_pairs {
+        assert!(pair.correlation.abs() > 0.9);
+    }
+    for dropped in &result.dropped_correlation {
+        assert!(!dropped.reason.is_empty());
+    }
+}
+
+#[test]
+fn test_reducer_woe_dataframe_encodes_kept_features() {
+    let df = create_correlation_test_dataframe();
+
+    let result = Reducer::from_dataframe(df, "target")
+        .gini_threshold(0.0)
+        .correlation_threshold(1.0)
+        .run()
+        .unwrap();
+    let woe = result.woe_dataframe().unwrap();
+
+    assert_eq!(woe.shape(), result.dataframe.shape());
+    assert!(woe
+        .column("target")
+        .unwrap()
+        .equals(result.dataframe.column("target").unwrap()));
+    for feature in result.kept_features() {
+        let analysis = result.iv_analysis(&feature).unwrap();
+        let column = woe.column(&feature).unwrap();
+        assert_eq!(column.dtype(), &DataType::Float64);
+        for value in column.f64().unwrap().into_no_null_iter() {
+            assert!(
+                analysis.bins.iter().any(|b| b.woe == value),
+                "{} encodes to {} which is not a bin WoE",
+                feature,
+                value
+            );
+        }
+    }
+}
+
+#[test]
+fn test_reducer_drop_columns_and_weights() {
+    let df = df! {
+        "target" => [0i32, 1, 0, 1, 0, 1, 0, 1, 0, 1],
+        "id" => [1i64, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+        "w" => [1.0f64, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0],
+        "feature" => [1.0f64, 5.0, 2.0, 6.0, 3.0, 7.0, 4.0, 8.0, 5.0, 9.0],
+    }
+    .unwrap();
+
+    let result = Reducer::from_dataframe(df, "target")
+        .drop_columns(["id", "not_a_column"])
+        .weight_column("w")
+        .gini_threshold(0.0)
+        .correlation_threshold(1.0)
+        .run()
+        .unwrap();
+
+    assert_eq!(result.initial_features, 1);
+    assert_eq!(result.kept_features(), vec!["feature".to_string()]);
+    assert_has_columns(&result.dataframe, &["target", "w", "feature"]);
+}
+
+#[test]
+fn test_reducer_split_column_screens_train_rows_only() {
+    // "signal" separates the classes on the TRAIN rows, "leaky" only on the TEST rows
+    let n = 40;
+    let target: Vec<i32> = (0..n).map(|i| (i % 2) as i32).collect();
+    let sample: Vec<&str> = (0..n)
+        .map(|i| if i < n / 2 { "TRAIN" } else { "TEST" })
+        .collect();
+    let leaky: Vec<f64> = (0..n)
+        .map(|i| if i < n / 2 { 1.0 } else { (i % 2) as f64 * 9.0 })
+        .collect();
+    let signal: Vec<f64> = (0..n)
+        .map(|i| {
+            if i < n / 2 {
+                (i % 2) as f64 * 10.0 + (i % 3) as f64
+            } else {
+                5.0
+            }
+        })
+        .collect();
+    let df = df! {
+        "target" => target,
+        "sample" => sample,
+        "leaky" => leaky,
+        "signal" => signal,
+    }
+    .unwrap();
+
+    let result = Reducer::from_dataframe(df, "target")
+        .split_column("sample", "TRAIN")
+        .correlation_threshold(1.0)
+        .run()
+        .unwrap();
+
+    assert_eq!(result.initial_features, 2);
+    assert_eq!(result.dropped_gini, vec!["leaky".to_string()]);
+    assert_eq!(result.kept_features(), vec!["signal".to_string()]);
+    // Drops apply to every row and the split column is kept
+    assert_shape(&result.dataframe, n, 3);
+    assert_has_columns(&result.dataframe, &["target", "sample", "signal"]);
+}
+
+#[test]
+fn test_reducer_requires_mapping_for_non_binary_target() {
+    let df = df! {
+        "target" => ["yes", "no", "yes", "no", "yes", "no"],
+        "feature" => [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0],
+    }
+    .unwrap();
+
+    assert!(Reducer::from_dataframe(df.clone(), "target").run().is_err());
+
+    let result = Reducer::from_dataframe(df, "target")
+        .target_mapping(TargetMapping::new("yes".to_string(), "no".to_string()))
+        .gini_threshold(0.0)
+        .min_category_samples(1)
+        .run()
+        .unwrap();
+    assert_eq!(result.iv_analyses.len(), 1);
+}
+
+#[test]
+fn test_reducer_missing_target_is_error() {
+    let df = create_test_dataframe();
+    let err = Reducer::from_dataframe(df, "nope").run().unwrap_err();
+    assert!(err.to_string().contains("nope"));
+}
+
+#[test]
+fn test_reducer_applies_non_finite_policy() {
+    let df = df! {
+        "target" => [0i32, 1, 0, 1, 0, 1],
+        "feature" => [1.0f64, f64::INFINITY, 2.0, 3.0, f64::NAN, 4.0],
+    }
+    .unwrap();
+
+    let err = Reducer::from_dataframe(df.clone(), "target")
+        .non_finite_policy(NonFinitePolicy::Error)
+        .run();
+    assert!(err.is_err());
+
+    let result = Reducer::from_dataframe(df, "target")
+        .missing_threshold(1.0)
+        .run()
+        .unwrap();
+    assert_eq!(result.non_finite.len(), 1);
+    assert_eq!(result.non_finite[0].total(), 2);
+}
+
+#[test]
+fn test_reducer_fixed_algorithms_is_repeatable() {
+    let n = 400;
+    let df = df! {
+        "target" => (0..n).map(|i| i32::from(i % 7 < 3)).collect::<Vec<_>>(),
+        "x" => (0..n).map(|i| ((i * 37) % 101) as f64).collect::<Vec<_>>(),
+        "y" => (0..n).map(|i| ((i * 13) % 53) as f64 + (i % 7) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap();
+
+    let run = || {
+        Reducer::from_dataframe(df.clone(), "target")
+            .gini_threshold(0.0)
+            .solver(SolverConfig {
+                timeout_seconds: 1,
+                ..SolverConfig::default()
+            })
+            .with_fixed_algorithms()
+            .run()
+            .unwrap()
+    };
+    let first = run();
+    let second = run();
+
+    assert_eq!(first.dropped_features(), second.dropped_features());
+    assert_eq!(first.iv_analyses.len(), second.iv_analyses.len());
+    for (a, b) in first.iv_analyses.iter().zip(&second.iv_analyses) {
+        assert_eq!(a.feature_name, b.feature_name);
+        assert_eq!(a.iv, b.iv);
+        let edges = |bins: &[lophi::pipeline::WoeBin]| {
+            bins.iter()
+                .map(|bin| (bin.lower_bound, bin.upper_bound))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(edges(&a.bins), edges(&b.bins));
+    }
+}
+
+#[test]
+fn test_reducer_fixed_algorithms_skips_fast_sampling() {
+    let n = 60_000;
+    let df = df! {
+        "target" => (0..n).map(|i| i32::from(i % 3 == 0)).collect::<Vec<_>>(),
+        "a" => (0..n).map(|i| (i % 1000) as f64).collect::<Vec<_>>(),
+        "b" => (0..n).map(|i| (i % 1000) as f64 + (i % 17) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap();
+
+    let full = Reducer::from_dataframe(df.clone(), "target")
+        .gini_threshold(0.0)
+        .correlation_threshold(0.5)
+        .run()
+        .unwrap();
+    let fixed_fast = Reducer::from_dataframe(df, "target")
+        .gini_threshold(0.0)
+        .correlation_threshold(0.5)
+        .fast(true)
+        .with_fixed_algorithms()
+        .run()
+        .unwrap();
+
+    assert!(fixed_fast.approximate);
+    assert_eq!(full.correlated_pairs.len(), 1);
+    assert_eq!(fixed_fast.correlated_pairs.len(), 1);
+    assert!(
+        (full.correlated_pairs[0].correlation - fixed_fast.correlated_pairs[0].correlation).abs()
+            < 1e-12
+    );
+}
+
+#[test]
+fn test_reducer_single_class_target_is_error() {
+    let df = df! {
+        "target" => [0i32; 8],
+        "x" => [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+    }
+    .unwrap();
+    let err = Reducer::from_dataframe(df, "target")
+        .run()
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("single class"), "{}", err);
+    assert!(err.contains("8 non-event"), "{}", err);
+}
+
+#[test]
+fn test_reducer_single_class_in_train_rows_is_error() {
+    let df = df! {
+        "target" => [0i32, 0, 0, 0, 1, 1, 0, 1],
+        "x" => [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+        "sample" => ["train", "train", "train", "train", "test", "test", "test", "test"],
+    }
+    .unwrap();
+    let err = Reducer::from_dataframe(df, "target")
+        .split_column("sample", "train")
+        .run()
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("0 event ('1'), 4 non-event"), "{}", err);
+}
+
+#[test]
+fn test_reducer_extreme_imbalance_gives_finite_iv() {
+    let n = 10_000;
+    let df = df! {
+        "target" => (0..n).map(|i| i32::from(i == 17)).collect::<Vec<_>>(),
+        "x" => (0..n).map(|i| (i % 250) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap();
+    let result = Reducer::from_dataframe(df, "target")
+        .gini_threshold(0.0)
+        .binning_strategy(BinningStrategy::Quantile)
+        .run()
+        .unwrap();
+    let analysis = result.iv_analysis("x").unwrap();
+    assert!(analysis.iv.is_finite());
+    assert!(analysis.gini.is_finite());
+    assert!(analysis.bins.iter().all(|b| b.woe.is_finite()));
+}
+
+#[test]
+fn test_reducer_keep_columns_survive_every_stage() {
+    let run = |keep: Vec<String>| {
+        Reducer::from_dataframe(create_test_dataframe(), "target")
+            .missing_threshold(0.3)
+            .gini_threshold(0.05)
+            .correlation_threshold(0.95)
+            .binning_strategy(BinningStrategy::Quantile)
+            .min_category_samples(1)
+            .keep_columns(keep)
+            .run()
+            .unwrap()
+    };
+
+    let baseline = run(Vec::new());
+    let dropped = baseline.dropped_features();
+    assert!(dropped.contains(&"feature_missing".to_string()));
+
+    let protected = run(dropped.clone());
+    let kept = protected.kept_features();
+    for feature in &dropped {
+        assert!(kept.contains(feature), "{} should be kept", feature);
+    }
+    // Kept features are still analysed
+    assert!(protected
+        .iv_analyses
+        .iter()
+        .any(|a| a.feature_name == "feature_missing"));
+}
+
+#[test]
+fn test_reducer_cluster_strategy_keeps_one_feature_per_chain() {
+    // a~b and b~c are above the threshold, a~c is not
+    let n = 300;
+    let a: Vec<f64> = (0..n).map(|i| i as f64).collect();
+    let b: Vec<f64> = (0..n)
+        .map(|i| a[i] + (((i * 37) % 11) as f64 - 5.0) * 6.0)
+        .collect();
+    let c: Vec<f64> = (0..n)
+        .map(|i| b[i] + (((i * 53) % 13) as f64 - 6.0) * 6.0)
+        .collect();
+    let target: Vec<i32> = (0..n).map(|i| i32::from(i % 3 == 0 || i > 200)).collect();
+    let df = df! { "target" => target, "a" => a, "b" => b, "c" => c }.unwrap();
+
+    let run = |strategy| {
+        Reducer::from_dataframe(df.clone(), "target")
+            .gini_threshold(0.0)
+            .correlation_threshold(0.96)
+            .binning_strategy(BinningStrategy::Quantile)
+            .correlation_strategy(strategy)
+            .run()
+            .unwrap()
+    };
+
+    let pairwise = run(CorrelationStrategy::Pairwise);
+    assert_eq!(pairwise.correlated_pairs.len(), 2);
+    assert_eq!(pairwise.kept_features().len(), 2);
+
+    let cluster = run(CorrelationStrategy::Cluster);
+    assert_eq!(cluster.kept_features().len(), 1);
+    assert_eq!(cluster.dropped_correlation.len(), 2);
+    assert!(cluster.dropped_correlation[0]
+        .reason
+        .contains("correlation cluster of 3"));
+}
+
+/// Drops every feature whose name starts with the prefix
+struct PrefixRule(&'static str);
+
+impl ReductionStep for PrefixRule {
+    type Analysis = Vec<String>;
+
+    fn name(&self) -> &str {
+        "prefix_rule"
+    }
+
+    fn analyze(&self, df: &DataFrame, _ctx: &StepContext) -> anyhow::Result<Vec<String>> {
+        Ok(df
+            .get_column_names()
+            .iter()
+            .filter(|c| c.starts_with(self.0))
+            .map(|c| c.to_string())
+            .collect())
+    }
+
+    fn propose_drops(&self, matched: &Vec<String>, _ctx: &StepContext) -> Vec<FeatureToDrop> {
+        matched
+            .iter()
+            .map(|feature| FeatureToDrop {
+                feature: feature.clone(),
+                reason: format!("Prefix '{}' is not approved", self.0),
+            })
+            .collect()
+    }
+
+    fn report_fragment(&self, matched: &Vec<String>) -> serde_json::Value {
+        serde_json::json!({ "prefix": self.0, "matched": matched })
+    }
+}
+
+#[test]
+fn test_reducer_runs_registered_steps_at_their_position() {
+    let result = Reducer::from_dataframe(create_test_dataframe(), "target")
+        .missing_threshold(0.3)
+        .gini_threshold(0.0)
+        .correlation_threshold(1.0)
+        .binning_strategy(BinningStrategy::Quantile)
+        .min_category_samples(1)
+        .keep_columns(["feature_good"])
+        .step(StepPosition::AfterCorrelation, PrefixRule("feature_"))
+        .step(StepPosition::BeforeMissing, PrefixRule("feature_missing"))
+        .run()
+        .unwrap();
+
+    // The before-missing step ran first and took the mostly-missing feature
+    let steps: Vec<(&str, StepPosition)> = result
+        .custom_steps
+        .iter()
+        .map(|s| (s.step.as_str(), s.position))
+        .collect();
+    assert_eq!(
+        steps,
+        [
+            ("prefix_rule", StepPosition::BeforeMissing),
+            ("prefix_rule", StepPosition::AfterCorrelation)
+        ]
+    );
+    assert_eq!(result.custom_steps[0].dropped[0].feature, "feature_missing");
+    assert!(result.dropped_missing.is_empty());
+    assert!(result
+        .missing_ratios
+        .iter()
+        .all(|(f, _)| f != "feature_missing"));
+
+    // The after-correlation step matched every feature but the kept one stays
+    let last = &result.custom_steps[1];
+    assert_eq!(last.report["prefix"], "feature_");
+    assert!(last.report["matched"]
+        .as_array()
+        .unwrap()
+        .contains(&"feature_good".into()));
+    assert!(last.dropped.iter().all(|d| d.feature != "feature_good"));
+    assert_eq!(result.kept_features(), ["feature_good"]);
+    assert_eq!(result.dropped_features()[0], "feature_missing");
+    assert_eq!(result.dropped_features().len() + 1, result.initial_features);
+}
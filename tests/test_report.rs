@@ -4,10 +4,18 @@
 mod common;
 
 use lophi::cli::convert::run_convert;
-use lophi::pipeline::{BinningStrategy, IvAnalysis};
+use lophi::pipeline::{
+    compute_distributions, compute_extreme_values, AssociationMeasure, BinningStrategy,
+    ConcordanceStats, CorrKeepPolicy, CorrelatedPair, DropRule, EventLabels, FeatureToDrop,
+    FeatureType, IvAnalysis, MissingBinPolicy, PairRationale, RSquaredAnalysis, SavedReduction,
+    StepOutcome, StepPosition, UnivariateMetric,
+};
+use lophi::report::{correlated_pairs_table, iv_table, missing_ratio_table, write_table};
 use lophi::report::{
-    export_gini_analysis_enhanced, export_reduction_report, export_reduction_report_csv,
-    package_reduction_reports, ExportParams, ReductionReportBuilder, ReportBuilderParams,
+    export_gini_analysis_enhanced, export_gini_analysis_split, export_r2_analysis,
+    export_reduction_report, export_reduction_report_csv, export_reduction_report_long_csv,
+    package_reduction_reports, package_reduction_reports_with, DecisionLogEntry, DropStage,
+    ExportParams, ReductionReportBuilder, ReportBuilderParams, RescuedDrop, USER_RETAINED,
 };
 use polars::prelude::*;
 use tempfile::TempDir;
@@ -46,6 +54,12 @@ fn build_minimal_report() -> lophi::report::ReductionReport {
         missing_bin: None,
         iv: 0.5,
         gini: 0.30,
+        mutual_info: None,
+        monotonicity: None,
+        solver_status: None,
+        special_bins: Vec::new(),
+        ks: 0.0,
+        auc: 0.5,
     }];
     builder.set_gini_results(&gini_analyses, &[]);
 
@@ -65,6 +79,12 @@ fn build_minimal_gini_analyses() -> Vec<IvAnalysis> {
             missing_bin: None,
             iv: 0.5,
             gini: 0.30,
+            mutual_info: None,
+            monotonicity: None,
+            solver_status: None,
+            special_bins: Vec::new(),
+            ks: 0.0,
+            auc: 0.5,
         },
         IvAnalysis {
             feature_name: "weak_feature".to_string(),
@@ -74,6 +94,12 @@ fn build_minimal_gini_analyses() -> Vec<IvAnalysis> {
             missing_bin: None,
             iv: 0.01,
             gini: 0.02,
+            mutual_info: None,
+            monotonicity: None,
+            solver_status: None,
+            special_bins: Vec::new(),
+            ks: 0.0,
+            auc: 0.5,
         },
     ]
 }
@@ -124,6 +150,85 @@ fn test_export_reduction_report_json_reflects_drop_counts() {
     );
 }
 
+#[test]
+fn test_decision_log_serialized_only_when_present() {
+    let temp_dir = TempDir::new().unwrap();
+    let json_path = temp_dir.path().join("report.json");
+
+    export_reduction_report(&build_minimal_report(), &json_path).unwrap();
+    let parsed: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+    assert!(
+        parsed.get("decision_log").is_none(),
+        "Empty decision log should be omitted"
+    );
+
+    let mut report = build_minimal_report();
+    report.decision_log.push(DecisionLogEntry {
+        stage: DropStage::Correlation,
+        kept: "feature_a".to_string(),
+        dropped: Some("feature_b".to_string()),
+        source: "user".to_string(),
+        detail: "Reversed in correlation review".to_string(),
+        rescued: Some(RescuedDrop {
+            reason: "Correlated with feature_b (Pearson 0.9300); lower IV".to_string(),
+            metric: "Pearson".to_string(),
+            value: 0.93,
+            threshold: 0.4,
+        }),
+    });
+    export_reduction_report(&report, &json_path).unwrap();
+    let parsed: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+
+    let entry = &parsed["decision_log"][0];
+    assert_eq!(entry["stage"], "correlation");
+    assert_eq!(entry["kept"], "feature_a");
+    assert_eq!(entry["dropped"], "feature_b");
+    assert_eq!(entry["source"], "user");
+    assert_eq!(entry["rescued"]["metric"], "Pearson");
+    assert_eq!(entry["rescued"]["value"], 0.93);
+    assert_eq!(entry["rescued"]["threshold"], 0.4);
+    assert!(entry["rescued"]["reason"]
+        .as_str()
+        .unwrap()
+        .starts_with("Correlated with feature_b"));
+}
+
+#[test]
+fn test_custom_steps_serialized_when_recorded() {
+    let parsed = serde_json::to_value(build_minimal_report()).unwrap();
+    assert!(parsed.get("custom_steps").is_none());
+
+    let mut builder = ReductionReportBuilder::new(ReportBuilderParams {
+        input_file: "input.csv".to_string(),
+        output_file: "output.csv".to_string(),
+        target_column: "target".to_string(),
+        weight_column: None,
+        binning_strategy: "quantile".to_string(),
+        num_bins: 10,
+        missing_threshold: 0.30,
+        gini_threshold: 0.05,
+        correlation_threshold: 0.40,
+    });
+    builder.add_custom_step(StepOutcome {
+        step: "prefix_rule".to_string(),
+        position: StepPosition::BeforeMissing,
+        dropped: vec![FeatureToDrop {
+            feature: "tmp_score".to_string(),
+            reason: "Prefix 'tmp_' is not approved".to_string(),
+        }],
+        report: serde_json::json!({ "prefix": "tmp_" }),
+    });
+    let parsed = serde_json::to_value(builder.build()).unwrap();
+
+    let step = &parsed["custom_steps"][0];
+    assert_eq!(step["step"], "prefix_rule");
+    assert_eq!(step["position"], "before_missing");
+    assert_eq!(step["dropped"][0]["feature"], "tmp_score");
+    assert_eq!(step["report"]["prefix"], "tmp_");
+}
+
 // ── T-C3: export_reduction_report_csv ───────────────────────────────────────
 
 #[test]
@@ -184,6 +289,85 @@ fn test_export_reduction_report_csv_has_expected_row_count() {
     );
 }
 
+#[test]
+fn test_export_reduction_report_long_csv_has_one_row_per_metric() {
+    let report = build_minimal_report();
+    let temp_dir = TempDir::new().unwrap();
+    let csv_path = temp_dir.path().join("report_long.csv");
+
+    export_reduction_report_long_csv(&report, &csv_path).unwrap();
+
+    let df = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(csv_path))
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert_eq!(
+        df.get_column_names(),
+        [
+            "timestamp",
+            "input_file",
+            "target",
+            "feature",
+            "metric",
+            "value",
+            "text"
+        ]
+    );
+    // The value column stays numeric for BI tools
+    assert_eq!(df.column("value").unwrap().dtype(), &DataType::Float64);
+
+    let rows: Vec<(String, String, Option<f64>, Option<String>)> = (0..df.height())
+        .map(|i| {
+            let get = |name: &str| df.column(name).unwrap().get(i).unwrap();
+            let text = |v: AnyValue| v.get_str().map(str::to_string);
+            (
+                text(get("feature")).unwrap(),
+                text(get("metric")).unwrap(),
+                get("value").extract::<f64>(),
+                text(get("text")),
+            )
+        })
+        .collect();
+    let metrics = |feature: &str| {
+        rows.iter()
+            .filter(|r| r.0 == feature)
+            .map(|r| r.1.as_str())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        metrics("feature_good"),
+        ["missing_ratio", "iv", "gini", "ks", "status"]
+    );
+    // A feature dropped for missing values has no later metrics
+    assert_eq!(
+        metrics("feature_missing"),
+        ["missing_ratio", "status", "reason"]
+    );
+
+    let row = |feature: &str, metric: &str| {
+        rows.iter()
+            .find(|r| r.0 == feature && r.1 == metric)
+            .unwrap()
+            .clone()
+    };
+    assert_eq!(row("feature_good", "iv").2, Some(0.5));
+    assert_eq!(row("feature_good", "status").3.as_deref(), Some("kept"));
+    assert_eq!(row("feature_missing", "missing_ratio").2, Some(0.8));
+    assert!(row("feature_missing", "reason")
+        .3
+        .unwrap()
+        .starts_with("Missing ratio"));
+    assert!(df
+        .column("target")
+        .unwrap()
+        .str()
+        .unwrap()
+        .into_iter()
+        .all(|t| t == Some("target")));
+}
+
 // ── T-C3: package_reduction_reports ─────────────────────────────────────────
 
 #[test]
@@ -278,10 +462,24 @@ fn test_export_gini_analysis_enhanced_creates_valid_json() {
         target_column: "target",
         weight_column: None,
         binning_strategy: BinningStrategy::Quantile,
+        bin_breaks: None,
+        bins_from: None,
         num_bins: 10,
         gini_threshold: 0.05,
         min_category_samples: 5,
         cart_min_bin_pct: None,
+        univariate_metric: UnivariateMetric::Iv,
+        merge_insignificant_bins: None,
+        event_labels: None,
+        distributions: None,
+        extremes: None,
+        cv_folds: None,
+        cross_validation: None,
+        segment_column: None,
+        segment_iv: None,
+        special_values: None,
+        missing_bin_policy: MissingBinPolicy::Separate,
+        dictionary: None,
     };
 
     export_gini_analysis_enhanced(&analyses, &dropped, &json_path, &params).unwrap();
@@ -297,6 +495,922 @@ fn test_export_gini_analysis_enhanced_creates_valid_json() {
 
     let features = parsed["features"].as_array().unwrap();
     assert_eq!(features.len(), 2, "Should have 2 feature entries");
+    for feature in features {
+        assert!(feature["ks"].is_number(), "KS missing: {}", feature);
+        assert!(feature["auc"].is_number(), "AUC missing: {}", feature);
+    }
+}
+
+// ── Continuous target (R²) ──────────────────────────────────────────────────
+
+fn build_r2_analyses() -> Vec<RSquaredAnalysis> {
+    vec![
+        RSquaredAnalysis {
+            feature_name: "income".to_string(),
+            feature_type: FeatureType::Numeric,
+            bins: vec![],
+            categories: vec![],
+            missing_bin: None,
+            r_squared: 0.42,
+            correlation: Some(0.63),
+        },
+        RSquaredAnalysis {
+            feature_name: "region".to_string(),
+            feature_type: FeatureType::Categorical,
+            bins: vec![],
+            categories: vec![],
+            missing_bin: None,
+            r_squared: 0.01,
+            correlation: None,
+        },
+    ]
+}
+
+#[test]
+fn test_report_records_r2_results() {
+    let mut builder = ReductionReportBuilder::new(ReportBuilderParams {
+        input_file: "input.csv".to_string(),
+        output_file: "output.csv".to_string(),
+        target_column: "amount".to_string(),
+        weight_column: None,
+        binning_strategy: "quantile".to_string(),
+        num_bins: 10,
+        missing_threshold: 0.30,
+        gini_threshold: 0.05,
+        correlation_threshold: 0.40,
+    });
+    builder.set_target_type("continuous");
+    builder.set_missing_results(
+        &[("income".to_string(), 0.0), ("region".to_string(), 0.0)],
+        &[],
+    );
+    builder.set_r2_results(&build_r2_analyses(), &["region".to_string()]);
+    builder.set_correlation_results(&[], &[]);
+    let report = builder.build();
+
+    let temp_dir = TempDir::new().unwrap();
+    let json_path = temp_dir.path().join("report.json");
+    export_reduction_report(&report, &json_path).unwrap();
+    let parsed: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+
+    assert_eq!(parsed["metadata"]["settings"]["target_type"], "continuous");
+    assert_eq!(parsed["summary"]["by_stage"]["gini"]["dropped"], 1);
+
+    let features = parsed["features"].as_array().unwrap();
+    let income = features.iter().find(|f| f["name"] == "income").unwrap();
+    assert_eq!(income["status"], "kept");
+    assert_eq!(income["analysis"]["r_squared"]["r_squared"], 0.42);
+    assert_eq!(income["analysis"]["r_squared"]["correlation"], 0.63);
+    assert!(income["analysis"].get("gini").is_none());
+
+    let region = features.iter().find(|f| f["name"] == "region").unwrap();
+    assert_eq!(region["dropped_at_stage"], "gini");
+    assert!(region["reason"].as_str().unwrap().starts_with("R² 0.0100"));
+    assert_eq!(region["analysis"]["r_squared"]["passed"], false);
+
+    let csv_path = temp_dir.path().join("report.csv");
+    export_reduction_report_csv(&report, &csv_path).unwrap();
+    let csv = std::fs::read_to_string(&csv_path).unwrap();
+    assert!(csv
+        .lines()
+        .next()
+        .unwrap()
+        .ends_with(",r_squared,somers_d,description,owner,label,format"));
+    assert!(csv
+        .lines()
+        .any(|l| l.starts_with("income,kept") && l.ends_with(",0.4200,,,,,")));
+}
+
+#[test]
+fn test_report_records_cramers_v_threshold_and_theils_u() {
+    let mut builder = ReductionReportBuilder::new(ReportBuilderParams {
+        input_file: "input.csv".to_string(),
+        output_file: "output.csv".to_string(),
+        target_column: "target".to_string(),
+        weight_column: None,
+        binning_strategy: "cart".to_string(),
+        num_bins: 10,
+        missing_threshold: 0.30,
+        gini_threshold: 0.05,
+        correlation_threshold: 0.40,
+    });
+    builder.set_cramers_v_threshold(0.6);
+    builder.set_missing_results(
+        &[("branch".to_string(), 0.0), ("region".to_string(), 0.0)],
+        &[],
+    );
+    builder.set_correlation_results(
+        &[CorrelatedPair {
+            feature1: "branch".to_string(),
+            feature2: "region".to_string(),
+            correlation: 0.8,
+            measure: AssociationMeasure::CramersV,
+            theils_u: Some((0.5, 1.0)),
+            rationale: None,
+        }],
+        &[],
+    );
+    let report = builder.build();
+    let parsed = serde_json::to_value(&report).unwrap();
+
+    assert_eq!(parsed["metadata"]["thresholds"]["correlation"], 0.4);
+    assert_eq!(parsed["metadata"]["thresholds"]["cramers_v"], 0.6);
+
+    // Each side reports how much of itself the other feature explains
+    let features = parsed["features"].as_array().unwrap();
+    let entry = |name: &str| {
+        features.iter().find(|f| f["name"] == name).unwrap()["analysis"]["correlation"]
+            ["all_correlations"][0]
+            .clone()
+    };
+    assert_eq!(entry("branch")["measure"], "CramersV");
+    assert_eq!(entry("branch")["theils_u"], 0.5);
+    assert_eq!(entry("region")["theils_u"], 1.0);
+}
+
+#[test]
+fn test_report_records_corr_keep_policy() {
+    let params = || ReportBuilderParams {
+        input_file: "input.csv".to_string(),
+        output_file: "output.csv".to_string(),
+        target_column: "target".to_string(),
+        weight_column: None,
+        binning_strategy: "cart".to_string(),
+        num_bins: 10,
+        missing_threshold: 0.30,
+        gini_threshold: 0.05,
+        correlation_threshold: 0.40,
+    };
+    let settings = |policy: CorrKeepPolicy| {
+        let mut builder = ReductionReportBuilder::new(params());
+        builder.set_corr_keep_policy(&policy);
+        serde_json::to_value(builder.build()).unwrap()["metadata"]["settings"].clone()
+    };
+
+    assert!(settings(CorrKeepPolicy::HigherIv)
+        .get("corr_keep_policy")
+        .is_none());
+    assert_eq!(
+        settings(CorrKeepPolicy::LowerMissing)["corr_keep_policy"],
+        serde_json::json!({"policy": "lower_missing"})
+    );
+    assert_eq!(
+        settings(CorrKeepPolicy::CustomList(vec!["bureau_".into()]))["corr_keep_policy"],
+        serde_json::json!({"policy": "custom_list", "prefixes": ["bureau_"]})
+    );
+}
+
+#[test]
+fn test_report_records_mutual_information() {
+    let mut builder = ReductionReportBuilder::new(ReportBuilderParams {
+        input_file: "input.csv".to_string(),
+        output_file: "output.csv".to_string(),
+        target_column: "target".to_string(),
+        weight_column: None,
+        binning_strategy: "cart".to_string(),
+        num_bins: 10,
+        missing_threshold: 0.30,
+        gini_threshold: 0.05,
+        correlation_threshold: 0.40,
+    });
+    builder.set_missing_results(
+        &[
+            ("good_feature".to_string(), 0.0),
+            ("weak_feature".to_string(), 0.0),
+        ],
+        &[],
+    );
+    let mut analyses = build_minimal_gini_analyses();
+    analyses[0].mutual_info = Some(0.12);
+    analyses[1].mutual_info = Some(0.001);
+    builder.set_gini_results(&analyses, &["weak_feature".to_string()]);
+    builder.set_correlation_results(&[], &[]);
+    let report = builder.build();
+    let parsed = serde_json::to_value(&report).unwrap();
+
+    assert_eq!(
+        parsed["metadata"]["settings"]["univariate_metric"],
+        "mutual_info"
+    );
+    let features = parsed["features"].as_array().unwrap();
+    let feature = |name: &str| features.iter().find(|f| f["name"] == name).unwrap().clone();
+    assert_eq!(
+        feature("good_feature")["analysis"]["gini"]["mutual_info"],
+        0.12
+    );
+    let reason = feature("weak_feature")["reason"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert!(
+        reason.starts_with("Mutual information 0.0010 below threshold 0.0500"),
+        "{}",
+        reason
+    );
+}
+
+#[test]
+fn test_user_retained_features_are_kept_in_report() {
+    let mut builder = ReductionReportBuilder::new(ReportBuilderParams {
+        input_file: "input.csv".to_string(),
+        output_file: "output.csv".to_string(),
+        target_column: "target".to_string(),
+        weight_column: None,
+        binning_strategy: "quantile".to_string(),
+        num_bins: 10,
+        missing_threshold: 0.30,
+        gini_threshold: 0.05,
+        correlation_threshold: 0.40,
+    });
+    builder.set_missing_results(
+        &[
+            ("good_feature".to_string(), 0.0),
+            ("weak_feature".to_string(), 0.0),
+            ("sparse".to_string(), 0.8),
+            ("copy".to_string(), 0.0),
+        ],
+        &["sparse".to_string()],
+    );
+    let mut analyses = build_minimal_gini_analyses();
+    let mut copy = analyses[0].clone();
+    copy.feature_name = "copy".to_string();
+    analyses.push(copy);
+    builder.set_gini_results(&analyses, &["weak_feature".to_string()]);
+    builder.set_correlation_results(
+        &[CorrelatedPair {
+            feature1: "good_feature".to_string(),
+            feature2: "copy".to_string(),
+            correlation: -0.93,
+            measure: AssociationMeasure::Pearson,
+            theils_u: None,
+            rationale: None,
+        }],
+        &[FeatureToDrop {
+            feature: "copy".to_string(),
+            reason: "Correlated with good_feature (Pearson 0.9300)".to_string(),
+        }],
+    );
+
+    let proposed: Vec<(String, DropStage)> = builder
+        .proposed_drops()
+        .into_iter()
+        .map(|(name, stage, _)| (name, stage))
+        .collect();
+    let names: Vec<&str> = proposed.iter().map(|(n, _)| n.as_str()).collect();
+    assert_eq!(names, ["sparse", "weak_feature", "copy"]);
+    assert!(matches!(proposed[2].1, DropStage::Correlation));
+
+    builder.set_user_retained(&["sparse".to_string(), "copy".to_string()]);
+    let report = builder.build();
+
+    assert_eq!(report.summary.dropped_count, 1);
+    assert_eq!(report.summary.final_features, 3);
+    assert_eq!(report.summary.by_stage.missing.as_ref().unwrap().dropped, 0);
+    assert_eq!(
+        report
+            .summary
+            .by_stage
+            .correlation
+            .as_ref()
+            .unwrap()
+            .dropped,
+        0
+    );
+    let feature = |name: &str| report.features.iter().find(|f| f.name == name).unwrap();
+    assert_eq!(feature("sparse").status, USER_RETAINED);
+    assert!(feature("sparse").dropped_at_stage.is_none());
+    assert!(feature("sparse")
+        .reason
+        .as_deref()
+        .unwrap()
+        .starts_with("Kept in drop review (Missing ratio 0.80"));
+    assert_eq!(feature("weak_feature").status, "dropped");
+
+    assert_eq!(report.decision_log.len(), 2);
+    let copy = report
+        .decision_log
+        .iter()
+        .find(|d| d.kept == "copy")
+        .unwrap();
+    assert!(matches!(copy.stage, DropStage::Correlation));
+    let rescued = copy.rescued.as_ref().unwrap();
+    assert_eq!(rescued.metric, "Pearson");
+    assert_eq!(rescued.value, 0.93);
+    assert_eq!(rescued.threshold, 0.40);
+    let sparse = &report.decision_log[0];
+    assert_eq!(sparse.rescued.as_ref().unwrap().metric, "missing_ratio");
+
+    // `lophi apply` keeps user-retained features
+    let temp_dir = TempDir::new().unwrap();
+    let json_path = temp_dir.path().join("report.json");
+    export_reduction_report(&report, &json_path).unwrap();
+    let saved = SavedReduction::load(&json_path, None).unwrap();
+    assert!(saved.kept_features.contains(&"sparse".to_string()));
+    assert_eq!(saved.dropped_features, vec!["weak_feature".to_string()]);
+}
+
+#[test]
+fn test_keep_columns_record_prevented_drops() {
+    let mut builder = ReductionReportBuilder::new(ReportBuilderParams {
+        input_file: "input.csv".to_string(),
+        output_file: "output.csv".to_string(),
+        target_column: "target".to_string(),
+        weight_column: None,
+        binning_strategy: "quantile".to_string(),
+        num_bins: 10,
+        missing_threshold: 0.30,
+        gini_threshold: 0.05,
+        correlation_threshold: 0.40,
+    });
+    builder.set_missing_results(
+        &[
+            ("good_feature".to_string(), 0.0),
+            ("weak_feature".to_string(), 0.0),
+            ("sparse".to_string(), 0.8),
+            ("copy".to_string(), 0.0),
+        ],
+        &[],
+    );
+    let mut analyses = build_minimal_gini_analyses();
+    let mut copy = analyses[0].clone();
+    copy.feature_name = "copy".to_string();
+    analyses.push(copy);
+    let mut sparse = analyses[0].clone();
+    sparse.feature_name = "sparse".to_string();
+    analyses.push(sparse);
+    builder.set_gini_results(&analyses, &[]);
+    builder.set_correlation_results(&[], &[]);
+    builder.set_keep_columns(&[
+        "sparse".to_string(),
+        "weak_feature".to_string(),
+        "copy".to_string(),
+    ]);
+    builder.set_protected_correlation_drops(&[FeatureToDrop {
+        feature: "copy".to_string(),
+        reason: "Correlated with good_feature (Pearson 0.9300)".to_string(),
+    }]);
+    let report = builder.build();
+
+    assert_eq!(report.summary.dropped_count, 0);
+    let feature = |name: &str| report.features.iter().find(|f| f.name == name).unwrap();
+    for name in ["sparse", "weak_feature", "copy"] {
+        assert_eq!(feature(name).status, "kept");
+        assert!(feature(name)
+            .reason
+            .as_deref()
+            .unwrap()
+            .starts_with("Protected by --keep-columns ("));
+    }
+    assert!(feature("sparse")
+        .reason
+        .as_deref()
+        .unwrap()
+        .contains("Missing ratio 0.80"));
+    assert!(feature("good_feature").reason.is_none());
+
+    let stages: Vec<(&str, &DropStage)> = report
+        .decision_log
+        .iter()
+        .filter(|d| d.source == "keep-columns")
+        .map(|d| (d.kept.as_str(), &d.stage))
+        .collect();
+    assert_eq!(stages.len(), 3);
+    for (name, stage) in stages {
+        match name {
+            "sparse" => assert!(matches!(stage, DropStage::Missing)),
+            "weak_feature" => assert!(matches!(stage, DropStage::Gini)),
+            _ => assert!(matches!(stage, DropStage::Correlation)),
+        }
+    }
+}
+
+#[test]
+fn test_event_labels_recorded_in_report_and_gini_export() {
+    let labels = EventLabels::new("default".to_string(), "good".to_string());
+    let mut builder = ReductionReportBuilder::new(ReportBuilderParams {
+        input_file: "input.csv".to_string(),
+        output_file: "output.csv".to_string(),
+        target_column: "target".to_string(),
+        weight_column: None,
+        binning_strategy: "cart".to_string(),
+        num_bins: 10,
+        missing_threshold: 0.30,
+        gini_threshold: 0.05,
+        correlation_threshold: 0.40,
+    });
+    builder.set_event_labels(&labels);
+    builder.set_missing_results(&[("good_feature".to_string(), 0.0)], &[]);
+    builder.set_gini_results(&build_minimal_gini_analyses()[..1], &[]);
+    builder.set_correlation_results(&[], &[]);
+    let parsed = serde_json::to_value(builder.build()).unwrap();
+    assert_eq!(
+        parsed["metadata"]["settings"]["event_labels"]["event"],
+        "default"
+    );
+    assert_eq!(
+        parsed["metadata"]["settings"]["event_labels"]["non_event"],
+        "good"
+    );
+
+    let temp_dir = TempDir::new().unwrap();
+    let json_path = temp_dir.path().join("gini.json");
+    let params = ExportParams {
+        event_labels: Some(&labels),
+        ..quantile_export_params()
+    };
+    export_gini_analysis_enhanced(&build_minimal_gini_analyses(), &[], &json_path, &params)
+        .unwrap();
+    let parsed: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+    assert_eq!(parsed["metadata"]["event_labels"]["event"], "default");
+    assert_eq!(parsed["metadata"]["event_labels"]["non_event"], "good");
+
+    let unlabelled = temp_dir.path().join("plain.json");
+    export_gini_analysis_enhanced(
+        &build_minimal_gini_analyses(),
+        &[],
+        &unlabelled,
+        &quantile_export_params(),
+    )
+    .unwrap();
+    let parsed: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&unlabelled).unwrap()).unwrap();
+    assert!(parsed["metadata"].get("event_labels").is_none());
+}
+
+#[test]
+fn test_report_records_concordance_and_flags_binning() {
+    let mut builder = ReductionReportBuilder::new(ReportBuilderParams {
+        input_file: "input.csv".to_string(),
+        output_file: "output.csv".to_string(),
+        target_column: "target".to_string(),
+        weight_column: None,
+        binning_strategy: "cart".to_string(),
+        num_bins: 10,
+        missing_threshold: 0.30,
+        gini_threshold: 0.05,
+        correlation_threshold: 0.40,
+    });
+    builder.set_missing_results(
+        &[
+            ("good_feature".to_string(), 0.0),
+            ("weak_feature".to_string(), 0.0),
+        ],
+        &[],
+    );
+    builder.set_gini_results(
+        &build_minimal_gini_analyses(),
+        &["weak_feature".to_string()],
+    );
+    builder.set_concordance_results(&[
+        ConcordanceStats {
+            feature_name: "good_feature".to_string(),
+            somers_d: 0.32,
+            concordance: 0.66,
+            sample_rows: 100,
+        },
+        ConcordanceStats {
+            feature_name: "weak_feature".to_string(),
+            somers_d: -0.2,
+            concordance: 0.4,
+            sample_rows: 100,
+        },
+    ]);
+    builder.set_correlation_results(&[], &[]);
+    let report = builder.build();
+
+    let good = report
+        .features
+        .iter()
+        .find(|f| f.name == "good_feature")
+        .unwrap();
+    let gini = good.analysis.gini.as_ref().unwrap();
+    assert_eq!(gini.somers_d, Some(0.32));
+    assert_eq!(gini.concordance, Some(0.66));
+
+    // Dropped on binned Gini 0.02 although the raw ordering gives |D| = 0.2
+    let weak = report
+        .features
+        .iter()
+        .find(|f| f.name == "weak_feature")
+        .unwrap();
+    let reason = weak.reason.as_deref().unwrap();
+    assert!(reason.starts_with("Gini coefficient 0.0200"), "{}", reason);
+    assert!(reason.contains("Somers' D -0.2000"), "{}", reason);
+
+    let temp_dir = TempDir::new().unwrap();
+    let csv_path = temp_dir.path().join("report.csv");
+    export_reduction_report_csv(&report, &csv_path).unwrap();
+    let csv = std::fs::read_to_string(&csv_path).unwrap();
+    assert!(csv
+        .lines()
+        .any(|l| l.starts_with("good_feature,kept") && l.ends_with(",0.3200,,,,")));
+}
+
+#[test]
+fn test_export_r2_analysis_marks_dropped_features() {
+    let temp_dir = TempDir::new().unwrap();
+    let json_path = temp_dir.path().join("r2.json");
+
+    export_r2_analysis(
+        &build_r2_analyses(),
+        &["region".to_string()],
+        &json_path,
+        &quantile_export_params(),
+    )
+    .unwrap();
+
+    let parsed: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+    let features = parsed["features"].as_array().unwrap();
+    assert_eq!(features.len(), 2);
+    assert_eq!(features[0]["feature_name"], "income");
+    assert_eq!(features[0]["dropped"], false);
+    assert_eq!(features[1]["dropped"], true);
+    assert!(features[1].get("correlation").is_none());
+}
+
+// ── Split Gini export (--split-gini-export) ─────────────────────────────────
+
+fn quantile_export_params() -> ExportParams<'static> {
+    ExportParams {
+        input_file: "input.csv",
+        target_column: "target",
+        weight_column: None,
+        binning_strategy: BinningStrategy::Quantile,
+        bin_breaks: None,
+        bins_from: None,
+        num_bins: 10,
+        gini_threshold: 0.05,
+        min_category_samples: 5,
+        cart_min_bin_pct: None,
+        univariate_metric: UnivariateMetric::Iv,
+        merge_insignificant_bins: None,
+        event_labels: None,
+        distributions: None,
+        extremes: None,
+        cv_folds: None,
+        cross_validation: None,
+        segment_column: None,
+        segment_iv: None,
+        special_values: None,
+        missing_bin_policy: MissingBinPolicy::Separate,
+        dictionary: None,
+    }
+}
+
+#[test]
+fn test_export_gini_analysis_split_writes_index_and_feature_files() {
+    let analyses = build_minimal_gini_analyses();
+    let dropped = vec!["weak_feature".to_string()];
+    let temp_dir = TempDir::new().unwrap();
+    let out_dir = temp_dir.path().join("data_gini_analysis");
+
+    export_gini_analysis_split(&analyses, &dropped, &out_dir, &quantile_export_params()).unwrap();
+
+    let index: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(out_dir.join("index.json")).unwrap())
+            .unwrap();
+    assert!(index.get("metadata").is_some());
+    assert_eq!(index["summary"]["features_dropped"], 1);
+
+    let entries = index["features"].as_array().unwrap();
+    assert_eq!(entries.len(), analyses.len());
+    for (entry, analysis) in entries.iter().zip(&analyses) {
+        assert_eq!(entry["feature_name"], analysis.feature_name.as_str());
+        assert!(entry.get("bins").is_none(), "index should not carry bins");
+
+        // Each detail file is compact JSON holding the full analysis
+        let file = out_dir.join(entry["file"].as_str().unwrap());
+        let contents = std::fs::read_to_string(&file).unwrap();
+        assert!(!contents.contains('\n'), "detail files should be compact");
+        let detail: serde_json::Value = serde_json::from_str(&contents).unwrap();
+        assert_eq!(detail["feature_name"], analysis.feature_name.as_str());
+        assert_eq!(detail["dropped"], entry["dropped"]);
+    }
+}
+
+#[test]
+fn test_export_gini_analysis_with_distributions() {
+    let df = df! {
+        "good_feature" => [Some(0.0), Some(5.0), None, Some(10.0)],
+        "weak_feature" => [1.0, 2.0, 3.0, 4.0],
+        "segment" => ["a", "b", "a", "b"],
+    }
+    .unwrap();
+    let features = [
+        "good_feature".to_string(),
+        "segment".to_string(),
+        "absent".to_string(),
+    ];
+    let distributions = compute_distributions(&df, &features, &[1.0, 2.0, 0.5, 1.5], 4).unwrap();
+    // Categorical and unknown columns get no histogram
+    assert_eq!(distributions.len(), 1);
+    let histogram = &distributions["good_feature"];
+    assert_eq!(histogram.weights, vec![1.0, 0.0, 2.0, 1.5]);
+    assert_eq!(histogram.missing_weight, 0.5);
+
+    let temp_dir = TempDir::new().unwrap();
+    let json_path = temp_dir.path().join("gini.json");
+    let params = ExportParams {
+        distributions: Some(&distributions),
+        ..quantile_export_params()
+    };
+    export_gini_analysis_enhanced(
+        &build_minimal_gini_analyses(),
+        &["weak_feature".to_string()],
+        &json_path,
+        &params,
+    )
+    .unwrap();
+    let parsed: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+    let good = &parsed["features"][0];
+    assert_eq!(good["feature_name"], "good_feature");
+    assert_eq!(good["distribution"]["min"], 0.0);
+    assert_eq!(good["distribution"]["bucket_width"], 2.5);
+    assert_eq!(good["distribution"]["weights"].as_array().unwrap().len(), 4);
+    assert!(parsed["features"][1].get("distribution").is_none());
+
+    // Without --export-distributions the field is left out entirely
+    export_gini_analysis_enhanced(
+        &build_minimal_gini_analyses(),
+        &[],
+        &json_path,
+        &quantile_export_params(),
+    )
+    .unwrap();
+    assert!(!std::fs::read_to_string(&json_path)
+        .unwrap()
+        .contains("distribution"));
+}
+
+#[test]
+fn test_export_gini_analysis_with_extreme_values() {
+    // A 9999 capping code on one row in ten
+    let df = df! {
+        "good_feature" => (0..100)
+            .map(|i| if i % 10 == 9 { 9999.0 } else { i as f64 })
+            .collect::<Vec<_>>(),
+        "segment" => (0..100).map(|i| if i < 50 { "a" } else { "b" }).collect::<Vec<_>>(),
+    }
+    .unwrap();
+    let features = ["good_feature".to_string(), "segment".to_string()];
+    let extremes = compute_extreme_values(&df, &features, &[1.0; 100]).unwrap();
+    assert_eq!(extremes.len(), 1);
+
+    let temp_dir = TempDir::new().unwrap();
+    let json_path = temp_dir.path().join("gini.json");
+    let params = ExportParams {
+        extremes: Some(&extremes),
+        ..quantile_export_params()
+    };
+    export_gini_analysis_enhanced(&build_minimal_gini_analyses(), &[], &json_path, &params)
+        .unwrap();
+    let parsed: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+    let good = &parsed["features"][0]["extremes"];
+    assert_eq!(good["min"], 0.0);
+    assert_eq!(good["p50"], 54.0);
+    assert_eq!(good["p99"], 9999.0);
+    assert_eq!(good["max"], 9999.0);
+    assert_eq!(good["pct_beyond_5_iqr"], 10.0);
+    assert!(parsed["features"][1].get("extremes").is_none());
+}
+
+#[test]
+fn test_export_gini_analysis_split_sanitizes_file_names() {
+    let mut analyses = build_minimal_gini_analyses();
+    analyses[0].feature_name = "../income/monthly".to_string();
+    analyses[1].feature_name = "../income/monthly".to_string();
+    let temp_dir = TempDir::new().unwrap();
+    let out_dir = temp_dir.path().join("split");
+
+    export_gini_analysis_split(&analyses, &[], &out_dir, &quantile_export_params()).unwrap();
+
+    let files: Vec<_> = std::fs::read_dir(out_dir.join("features"))
+        .unwrap()
+        .map(|e| e.unwrap().file_name().into_string().unwrap())
+        .collect();
+    assert_eq!(
+        files.len(),
+        2,
+        "duplicate names must not overwrite each other"
+    );
+    assert!(files
+        .iter()
+        .all(|f| !f.contains('/') && f.ends_with(".json")));
+    assert!(!temp_dir.path().join("income").exists());
+}
+
+#[test]
+fn test_package_reduction_reports_includes_split_gini_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let gini_dir = temp_dir.path().join("data_gini_analysis");
+    let report_path = temp_dir.path().join("data_reduction_report.json");
+    let csv_path = temp_dir.path().join("data_reduction_report.csv");
+    let zip_path = temp_dir.path().join("data_reduction_report.zip");
+
+    export_gini_analysis_split(
+        &build_minimal_gini_analyses(),
+        &[],
+        &gini_dir,
+        &quantile_export_params(),
+    )
+    .unwrap();
+    std::fs::write(&report_path, "{}").unwrap();
+    std::fs::write(&csv_path, "a,b\n").unwrap();
+
+    package_reduction_reports(&gini_dir, &report_path, &csv_path, &zip_path).unwrap();
+
+    let mut archive = ::zip::ZipArchive::new(std::fs::File::open(&zip_path).unwrap()).unwrap();
+    let names: Vec<String> = archive.file_names().map(String::from).collect();
+    assert!(names.contains(&"data_gini_analysis/index.json".to_string()));
+    assert_eq!(
+        names
+            .iter()
+            .filter(|n| n.starts_with("data_gini_analysis/features/"))
+            .count(),
+        2
+    );
+    assert!(names.contains(&"data_reduction_report.json".to_string()));
+    assert!(archive.by_name("data_gini_analysis/index.json").is_ok());
+    assert!(!gini_dir.exists(), "split directory should be removed");
+}
+
+#[test]
+fn test_package_reduction_reports_with_extra_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let gini_path = temp_dir.path().join("data_gini_analysis.json");
+    let report_path = temp_dir.path().join("data_reduction_report.json");
+    let csv_path = temp_dir.path().join("data_reduction_report.csv");
+    let trees_path = temp_dir.path().join("data_cart_trees.json");
+    let zip_path = temp_dir.path().join("data_reduction_report.zip");
+
+    for path in [&gini_path, &report_path, &trees_path] {
+        std::fs::write(path, "{}").unwrap();
+    }
+    std::fs::write(&csv_path, "a,b\n").unwrap();
+
+    package_reduction_reports_with(
+        Some(&gini_path),
+        &report_path,
+        &csv_path,
+        std::slice::from_ref(&trees_path),
+        &zip_path,
+    )
+    .unwrap();
+
+    let archive = ::zip::ZipArchive::new(std::fs::File::open(&zip_path).unwrap()).unwrap();
+    assert_eq!(archive.len(), 4);
+    assert!(archive.file_names().any(|n| n == "data_cart_trees.json"));
+    assert!(!trees_path.exists(), "extra files should be removed");
+}
+
+#[test]
+fn test_package_reduction_reports_stores_compressed_files_and_round_trips() {
+    use std::io::Read;
+
+    let temp_dir = TempDir::new().unwrap();
+    let gini_path = temp_dir.path().join("gini.json");
+    let report_path = temp_dir.path().join("report.json");
+    let csv_path = temp_dir.path().join("report.csv");
+    let data_path = temp_dir.path().join("data.parquet");
+    let zip_path = temp_dir.path().join("output.zip");
+
+    let gini = format!(r#"{{"features":[{}]}}"#, vec!["{}"; 500].join(","));
+    std::fs::write(&gini_path, &gini).unwrap();
+    std::fs::write(&report_path, "{}").unwrap();
+    std::fs::write(&csv_path, "a,b\n").unwrap();
+    let mut df = df! { "x" => (0..1000).map(|i| i as f64).collect::<Vec<_>>() }.unwrap();
+    ParquetWriter::new(std::fs::File::create(&data_path).unwrap())
+        .finish(&mut df)
+        .unwrap();
+    let data = std::fs::read(&data_path).unwrap();
+
+    package_reduction_reports_with(
+        Some(&gini_path),
+        &report_path,
+        &csv_path,
+        std::slice::from_ref(&data_path),
+        &zip_path,
+    )
+    .unwrap();
+
+    let mut archive = ::zip::ZipArchive::new(std::fs::File::open(&zip_path).unwrap()).unwrap();
+    {
+        let mut entry = archive.by_name("data.parquet").unwrap();
+        assert_eq!(entry.compression(), ::zip::CompressionMethod::Stored);
+        let mut content = Vec::new();
+        entry.read_to_end(&mut content).unwrap();
+        assert_eq!(content, data);
+    }
+    let mut entry = archive.by_name("gini.json").unwrap();
+    assert_eq!(entry.compression(), ::zip::CompressionMethod::Deflated);
+    assert!(entry.compressed_size() < entry.size());
+    let mut content = String::new();
+    entry.read_to_string(&mut content).unwrap();
+    assert_eq!(content, gini);
+}
+
+// ── Standalone stage tables (--export-missing-ratios etc.) ──────────────────
+
+fn resolved_pair(feature1: &str, feature2: &str, dropped: &str) -> CorrelatedPair {
+    CorrelatedPair {
+        feature1: feature1.to_string(),
+        feature2: feature2.to_string(),
+        correlation: 0.93,
+        measure: AssociationMeasure::Pearson,
+        theils_u: None,
+        rationale: Some(PairRationale {
+            kept: if dropped == feature1 {
+                feature2
+            } else {
+                feature1
+            }
+            .to_string(),
+            dropped: dropped.to_string(),
+            rule: DropRule::Iv,
+            values: Some((0.5, 0.01)),
+        }),
+    }
+}
+
+#[test]
+fn test_stage_tables_round_trip_through_csv() {
+    let temp_dir = TempDir::new().unwrap();
+    let ratios = vec![("a".to_string(), 0.1), ("b".to_string(), 0.8)];
+    let mut table = missing_ratio_table(&ratios, &["b".to_string()]).unwrap();
+    let path = temp_dir.path().join("missing.csv");
+    write_table(&mut table, &path).unwrap();
+
+    let loaded = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(path))
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert_eq!(
+        loaded.get_column_names_str(),
+        vec!["feature", "missing_ratio", "dropped"]
+    );
+    let dropped: Vec<Option<bool>> = loaded
+        .column("dropped")
+        .unwrap()
+        .bool()
+        .unwrap()
+        .into_iter()
+        .collect();
+    assert_eq!(dropped, vec![Some(false), Some(true)]);
+
+    // A pair whose drop was undone in review has no dropped member
+    let pairs = vec![
+        resolved_pair("good_feature", "weak_feature", "weak_feature"),
+        resolved_pair("x", "y", "y"),
+    ];
+    let mut table = correlated_pairs_table(&pairs, &["weak_feature".to_string()]).unwrap();
+    let path = temp_dir.path().join("pairs.csv");
+    write_table(&mut table, &path).unwrap();
+    let loaded = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(path))
+        .unwrap()
+        .finish()
+        .unwrap();
+    let dropped = loaded.column("dropped").unwrap().str().unwrap().clone();
+    assert_eq!(dropped.get(0), Some("weak_feature"));
+    assert_eq!(dropped.get(1), None);
+    assert_eq!(
+        loaded.column("rule").unwrap().str().unwrap().get(0),
+        Some("iv")
+    );
+}
+
+#[test]
+fn test_iv_table_round_trips_through_feather() {
+    let temp_dir = TempDir::new().unwrap();
+    let analyses = build_minimal_gini_analyses();
+    let mut table = iv_table(&analyses, &["weak_feature".to_string()]).unwrap();
+    let path = temp_dir.path().join("iv.feather");
+    write_table(&mut table, &path).unwrap();
+
+    let loaded = IpcReader::new(std::fs::File::open(&path).unwrap())
+        .finish()
+        .unwrap();
+    assert!(loaded.equals_missing(&table));
+    assert_eq!(loaded.height(), 2);
+    let iv = loaded.column("iv").unwrap().f64().unwrap().clone();
+    assert_eq!(iv.get(0), Some(0.5));
+    assert_eq!(
+        loaded.column("dropped").unwrap().bool().unwrap().get(1),
+        Some(true)
+    );
+    assert_eq!(
+        loaded.column("feature_type").unwrap().str().unwrap().get(0),
+        Some("numeric")
+    );
+
+    // Other extensions are rejected
+    assert!(write_table(&mut table, &temp_dir.path().join("iv.xlsx")).is_err());
 }
 
 // ── T-C5: Parquet-to-CSV conversion (run_convert with .parquet input) ────────
@@ -321,7 +1435,7 @@ fn test_parquet_to_csv_round_trip() {
     let parquet_path = create_test_parquet(&temp_dir, "data.parquet", &mut df);
     let csv_path = temp_dir.path().join("data.csv");
 
-    run_convert(&parquet_path, Some(&csv_path), 1000, false).unwrap();
+    run_convert(&parquet_path, Some(&csv_path), 1000, None, false).unwrap();
 
     assert!(csv_path.exists(), "CSV output file should be created");
 
@@ -348,7 +1462,7 @@ fn test_parquet_to_csv_preserves_column_names() {
     let parquet_path = create_test_parquet(&temp_dir, "cols.parquet", &mut df);
     let csv_path = temp_dir.path().join("cols.csv");
 
-    run_convert(&parquet_path, Some(&csv_path), 1000, false).unwrap();
+    run_convert(&parquet_path, Some(&csv_path), 1000, None, false).unwrap();
 
     let result_df = CsvReadOptions::default()
         .with_infer_schema_length(Some(100))
@@ -381,7 +1495,7 @@ fn test_parquet_to_csv_preserves_null_values() {
     let parquet_path = create_test_parquet(&temp_dir, "nulls.parquet", &mut df);
     let csv_path = temp_dir.path().join("nulls.csv");
 
-    run_convert(&parquet_path, Some(&csv_path), 1000, false).unwrap();
+    run_convert(&parquet_path, Some(&csv_path), 1000, None, false).unwrap();
 
     let result_df = CsvReadOptions::default()
         .with_infer_schema_length(Some(100))
@@ -408,7 +1522,7 @@ fn test_parquet_to_csv_auto_output_path() {
     let parquet_path = create_test_parquet(&temp_dir, "auto.parquet", &mut df);
 
     // No explicit output path
-    run_convert(&parquet_path, None, 1000, false).unwrap();
+    run_convert(&parquet_path, None, 1000, None, false).unwrap();
 
     let expected_csv = temp_dir.path().join("auto.csv");
     assert!(
@@ -0,0 +1,142 @@
+//! Tests for the results browser opened with `--browse-results`
+
+#![cfg(feature = "tui")]
+
+use crossterm::event::KeyCode;
+use lophi::cli::results_browser::{IvSort, ResultsBrowser, ResultsTab};
+use lophi::pipeline::{ResultsBin, ResultsFeature, ResultsPair, RunResults};
+use ratatui::{backend::TestBackend, Terminal};
+
+fn feature(name: &str, iv: f64, gini: f64, dropped_at: Option<&str>) -> ResultsFeature {
+    ResultsFeature {
+        name: name.to_string(),
+        feature_type: "Numeric".to_string(),
+        iv,
+        gini,
+        dropped_at: dropped_at.map(str::to_string),
+        bins: vec![
+            ResultsBin {
+                label: format!("< 10 ({})", name),
+                count: 60.0,
+                event_rate: 0.4,
+                woe: 0.5,
+                iv_contribution: iv / 2.0,
+            },
+            ResultsBin {
+                label: ">= 10".to_string(),
+                count: 40.0,
+                event_rate: 0.1,
+                woe: -0.6,
+                iv_contribution: iv / 2.0,
+            },
+        ],
+    }
+}
+
+fn open_browser() -> ResultsBrowser {
+    ResultsBrowser::new(RunResults {
+        initial_features: 5,
+        final_features: 2,
+        dropped_by_stage: vec![
+            ("missing".to_string(), vec!["sparse".to_string()]),
+            ("gini".to_string(), vec!["weak".to_string()]),
+            ("correlation".to_string(), vec!["copy".to_string()]),
+        ],
+        features: vec![
+            feature("weak", 0.01, 0.30, Some("gini")),
+            feature("age", 0.40, 0.20, None),
+            feature("copy", 0.20, 0.25, Some("correlation")),
+            feature("income", 0.30, 0.10, None),
+        ],
+        correlated_pairs: vec![ResultsPair {
+            feature1: "income".to_string(),
+            feature2: "copy".to_string(),
+            correlation: 0.91,
+            measure: "Pearson".to_string(),
+            dropped: Some("copy".to_string()),
+        }],
+    })
+}
+
+fn names(browser: &ResultsBrowser) -> Vec<&str> {
+    browser.iv_rows().map(|f| f.name.as_str()).collect()
+}
+
+fn screen(browser: &ResultsBrowser) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(66, 20)).unwrap();
+    terminal.draw(|f| browser.render(f, f.area())).unwrap();
+    terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect()
+}
+
+#[test]
+fn test_tabs_and_closing() {
+    let mut browser = open_browser();
+    assert_eq!(browser.tab(), ResultsTab::Dropped);
+    let text = screen(&browser);
+    assert!(text.contains("5 features -> 2 kept"));
+    assert!(text.contains("missing (1)") && text.contains("sparse"));
+
+    assert!(!browser.handle_key(KeyCode::Right));
+    assert_eq!(browser.tab(), ResultsTab::Iv);
+    assert!(!browser.handle_key(KeyCode::Tab));
+    assert_eq!(browser.tab(), ResultsTab::Correlations);
+    let text = screen(&browser);
+    assert!(text.contains("income") && text.contains("0.9100"));
+    browser.handle_key(KeyCode::Left);
+    browser.handle_key(KeyCode::Left);
+    browser.handle_key(KeyCode::Left);
+    assert_eq!(browser.tab(), ResultsTab::Bins);
+    browser.handle_key(KeyCode::Char('1'));
+    assert_eq!(browser.tab(), ResultsTab::Dropped);
+
+    assert!(browser.handle_key(KeyCode::Esc));
+}
+
+#[test]
+fn test_iv_table_sorts_and_keeps_the_cursor() {
+    let mut browser = open_browser();
+    browser.handle_key(KeyCode::Char('2'));
+    assert_eq!(browser.sort(), IvSort::Iv);
+    assert_eq!(names(&browser), ["age", "income", "copy", "weak"]);
+    let text = screen(&browser);
+    assert!(text.contains("sorted by IV"));
+    assert!(text.contains("correlation"));
+
+    // Cursor on "income", which stays selected through the sorts
+    browser.handle_key(KeyCode::Down);
+    browser.handle_key(KeyCode::Char('s'));
+    assert_eq!(browser.sort(), IvSort::Gini);
+    assert_eq!(names(&browser), ["weak", "copy", "age", "income"]);
+    browser.handle_key(KeyCode::Char('s'));
+    assert_eq!(browser.sort(), IvSort::Name);
+    assert_eq!(names(&browser), ["age", "copy", "income", "weak"]);
+
+    browser.handle_key(KeyCode::Enter);
+    assert_eq!(browser.tab(), ResultsTab::Bins);
+    assert_eq!(browser.bins_feature().unwrap().name, "income");
+    let text = screen(&browser);
+    assert!(text.contains("< 10 (income)"));
+    assert!(text.contains("-0.6000"));
+}
+
+#[test]
+fn test_cursor_bounds() {
+    let mut browser = open_browser();
+    browser.handle_key(KeyCode::Char('4'));
+    browser.handle_key(KeyCode::Up);
+    assert_eq!(browser.bins_feature().unwrap().name, "age");
+    browser.handle_key(KeyCode::PageDown);
+    assert_eq!(browser.bins_feature().unwrap().name, "weak");
+    browser.handle_key(KeyCode::Home);
+    assert_eq!(browser.bins_feature().unwrap().name, "age");
+
+    let empty = ResultsBrowser::new(RunResults::default());
+    assert!(empty.bins_feature().is_none());
+    assert!(screen(&empty).contains("No screening step ran"));
+}
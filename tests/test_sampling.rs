@@ -6,6 +6,8 @@ use lophi::pipeline::{
 use polars::prelude::{df, CsvReadOptions, DataFrame, LazyFrame, NamedFrom, SerReader, Series};
 use std::path::PathBuf;
 
+use assert_cmd::Command;
+
 #[path = "common/mod.rs"]
 mod common;
 
@@ -23,8 +25,10 @@ fn base_config(method: SamplingMethod) -> SamplingConfig {
         strata_column: None,
         sample_size: None,
         strata_specs: vec![],
+        weight_column: None,
         seed: Some(42),
         infer_schema_length: 10_000,
+        sheet: None,
     }
 }
 
@@ -278,6 +282,126 @@ fn stratified_single_stratum() {
     assert_has_columns(&result, &["region", "value", "category", "sampling_weight"]);
 }
 
+fn region_counts(df: &DataFrame) -> Vec<(String, usize)> {
+    let mut counts = analyze_strata(df, "region").unwrap();
+    counts.sort();
+    counts
+}
+
+#[test]
+fn stratified_proportional_fraction() {
+    let df = create_stratified_test_dataframe(); // North=30, South=25, East=25, West=20
+    let mut cfg = base_config(SamplingMethod::Stratified);
+    cfg.strata_column = Some("region".to_string());
+    cfg.sample_size = Some(SampleSize::Fraction(0.2));
+
+    let result = execute_sampling(&df, &cfg).unwrap();
+
+    // round(0.2 * N_h): 6 + 5 + 5 + 4
+    assert_eq!(
+        region_counts(&result),
+        vec![
+            ("East".to_string(), 5),
+            ("North".to_string(), 6),
+            ("South".to_string(), 5),
+            ("West".to_string(), 4),
+        ]
+    );
+    let total: f64 = result
+        .column("sampling_weight")
+        .unwrap()
+        .f64()
+        .unwrap()
+        .into_no_null_iter()
+        .sum();
+    assert!((total - 100.0).abs() < 1e-9, "weights should sum to N");
+}
+
+#[test]
+fn stratified_proportional_count() {
+    let df = create_stratified_test_dataframe();
+    let mut cfg = base_config(SamplingMethod::Stratified);
+    cfg.strata_column = Some("region".to_string());
+    cfg.sample_size = Some(SampleSize::Count(40));
+
+    let result = execute_sampling(&df, &cfg).unwrap();
+    assert_eq!(result.height(), 40, "12 + 10 + 10 + 8");
+
+    cfg.sample_size = Some(SampleSize::Count(101));
+    let err = execute_sampling(&df, &cfg).unwrap_err().to_string();
+    assert!(err.contains("exceeds population size"), "{err}");
+}
+
+#[test]
+fn stratified_weighted_sample_scales_row_weights() {
+    let mut df = create_stratified_test_dataframe();
+    let w: Vec<f64> = (0..df.height()).map(|i| 1.0 + (i % 3) as f64).collect();
+    df.with_column(Series::new("w".into(), w)).unwrap();
+
+    let mut cfg = base_config(SamplingMethod::Stratified);
+    cfg.strata_column = Some("region".to_string());
+    cfg.sample_size = Some(SampleSize::Fraction(0.2));
+    cfg.weight_column = Some("w".to_string());
+
+    let result = execute_sampling(&df, &cfg).unwrap();
+    let row_weights = result.column("w").unwrap().f64().unwrap();
+    let sampling = result.column("sampling_weight").unwrap().f64().unwrap();
+    // Every stratum size is a multiple of 5, so N_h / n_h = 5
+    for (w, s) in row_weights
+        .into_no_null_iter()
+        .zip(sampling.into_no_null_iter())
+    {
+        assert!((s - 5.0 * w).abs() < 1e-10, "{s} != 5 * {w}");
+    }
+
+    cfg.weight_column = Some("missing".to_string());
+    let err = execute_sampling(&df, &cfg).unwrap_err().to_string();
+    assert!(err.contains("Weight column 'missing' not found"), "{err}");
+}
+
+#[test]
+fn cli_sample_stratify_fraction_with_weights() {
+    let mut df = create_stratified_test_dataframe();
+    df.with_column(Series::new("w".into(), vec![2.0f64; df.height()]))
+        .unwrap();
+    let (dir, input) = create_temp_csv(&mut df);
+    let output = dir.path().join("sample.parquet");
+
+    let run = || {
+        Command::new(env!("CARGO_BIN_EXE_lophi"))
+            .arg("sample")
+            .arg(&input)
+            .arg("--output")
+            .arg(&output)
+            .args(["--frac", "0.2", "--stratify", "region"])
+            .args(["--weight-column", "w", "--seed", "7"])
+            .assert()
+            .success();
+        LazyFrame::scan_parquet(&output, Default::default())
+            .unwrap()
+            .collect()
+            .unwrap()
+    };
+
+    let first = run();
+    assert_eq!(first.height(), 20);
+    let total: f64 = first
+        .column("sampling_weight")
+        .unwrap()
+        .f64()
+        .unwrap()
+        .into_no_null_iter()
+        .sum();
+    assert!(
+        (total - 200.0).abs() < 1e-9,
+        "weighted total should be kept"
+    );
+
+    // Same seed, same sample
+    let second = run();
+    assert!(first.equals(&second));
+}
+
 // ---------------------------------------------------------------------------
 // Equal allocation
 // ---------------------------------------------------------------------------
@@ -480,3 +604,102 @@ fn parquet_round_trip() {
         "Parquet round-trip should preserve shape"
     );
 }
+
+// ---------------------------------------------------------------------------
+// Sampled reduction runs (--sample-rows / --sample-frac)
+// ---------------------------------------------------------------------------
+
+fn read_report_json(dir: &std::path::Path) -> serde_json::Value {
+    use std::io::Read;
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_reduction_report.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+#[test]
+fn test_cli_sample_rows_stratified_on_target() {
+    // 400 rows with a 10% event rate
+    let n = 400;
+    let mut df = df! {
+        "target" => (0..n).map(|i| i32::from(i % 10 == 0)).collect::<Vec<_>>(),
+        "x" => (0..n).map(|i| (i % 13) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap();
+    let (dir, input) = create_temp_csv(&mut df);
+    let config = dir.path().join("pipeline.toml");
+    std::fs::write(&config, "use_solver = false\nsample_seed = 7\n").unwrap();
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--config")
+        .arg(&config)
+        .arg("--input")
+        .arg(&input)
+        .args([
+            "--target",
+            "target",
+            "--no-confirm",
+            "--gini-threshold",
+            "0",
+            "--sample-rows",
+            "100",
+            "--sample-stratify",
+        ])
+        .assert()
+        .success();
+
+    // The run saw the sample only, with the event rate intact
+    let reduced = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(dir.path().join("test_data_reduced.csv")))
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert_eq!(reduced.height(), 100);
+    let events: i64 = reduced
+        .column("target")
+        .unwrap()
+        .i64()
+        .unwrap()
+        .into_no_null_iter()
+        .sum();
+    assert_eq!(events, 10);
+
+    let report = read_report_json(dir.path());
+    let sample = &report["metadata"]["settings"]["sample"];
+    assert_eq!(sample["requested_rows"], 100);
+    assert!(sample["fraction"].is_null());
+    assert_eq!(sample["stratified_on"], "target");
+    assert_eq!(sample["seed"], 7);
+    assert_eq!(sample["sampled_rows"], 100);
+    assert_eq!(sample["total_rows"], 400);
+}
+
+#[test]
+fn test_cli_sample_frac_and_rows_conflict() {
+    let mut df = df! {
+        "target" => [0i32, 1, 0, 1],
+        "x" => [1.0f64, 2.0, 3.0, 4.0],
+    }
+    .unwrap();
+    let (_dir, input) = create_temp_csv(&mut df);
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args([
+            "--target",
+            "target",
+            "--no-confirm",
+            "--sample-rows",
+            "2",
+            "--sample-frac",
+            "0.5",
+        ])
+        .assert()
+        .failure();
+}
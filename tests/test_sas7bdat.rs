@@ -14,6 +14,13 @@
 //!
 //! 4. **Round-trip** – load a SAS7BDAT file, persist it as Parquet, reload and
 //!    verify the shape is preserved.
+//!
+//! Later sections compare cell values against pandas and check column
+//! projection, the batched `Sas7bdatReader` (including the streamed Parquet
+//! conversion and the encoding override), skipping of rows marked in a page's
+//! deleted-row bitmap, and truncated numeric lengths (including strict mode).
+
+#![cfg(feature = "sas7bdat")]
 
 use lophi::pipeline::sas7bdat::{load_sas7bdat_silent, SasError};
 use polars::prelude::*;
@@ -984,3 +991,214 @@ fn exact_match_datetime_strict() {
         mismatches.join("\n")
     );
 }
+
+// ---------------------------------------------------------------------------
+// Column projection
+// ---------------------------------------------------------------------------
+
+#[test]
+fn projected_load_decodes_only_selected_columns() {
+    use lophi::pipeline::sas7bdat::load_sas7bdat_columns;
+
+    let path = fixture_path("many_columns.sas7bdat");
+    let (full, rows, _, _) = load_sas7bdat_silent(&path).expect("load many_columns.sas7bdat");
+    let names: Vec<String> = full
+        .get_column_names()
+        .iter()
+        .step_by(50)
+        .map(|s| s.to_string())
+        .collect();
+
+    let (df, projected_rows, cols, _) =
+        load_sas7bdat_columns(&path, Some(&names), true).expect("projected load");
+    assert_eq!(projected_rows, rows);
+    assert_eq!(cols, names.len());
+    assert!(df.equals_missing(&full.select(&names).unwrap()));
+}
+
+// ---------------------------------------------------------------------------
+// Batched reading
+// ---------------------------------------------------------------------------
+
+#[test]
+fn reader_batches_stack_to_the_full_load() {
+    use lophi::pipeline::sas7bdat::Sas7bdatReader;
+
+    // Uncompressed, RLE and RDC variants of the same data, plus a wider file
+    for name in ["test1", "test2", "test3", "airline", "productsales"] {
+        let path = fixture_path(&format!("{name}.sas7bdat"));
+        let (full, rows, _, _) = load_sas7bdat_silent(&path).expect("full load");
+
+        let mut reader = Sas7bdatReader::open(&path).expect("open reader");
+        assert_eq!(reader.row_count() as usize, rows, "{name}");
+        let mut heights = Vec::new();
+        let mut stacked: Option<DataFrame> = None;
+        while let Some(batch) = reader.next_batch(7).expect("next batch") {
+            heights.push(batch.height());
+            match stacked.as_mut() {
+                Some(df) => {
+                    df.vstack_mut(&batch).unwrap();
+                }
+                None => stacked = Some(batch),
+            }
+        }
+        assert!(reader.next_batch(7).unwrap().is_none(), "{name}");
+
+        assert_eq!(heights.iter().sum::<usize>(), rows, "{name}");
+        assert!(
+            heights[..heights.len() - 1].iter().all(|&h| h == 7),
+            "{name}"
+        );
+        assert!(stacked.unwrap().equals_missing(&full), "{name}");
+    }
+}
+
+#[test]
+fn convert_streams_sas7bdat_to_parquet() {
+    use lophi::cli::convert::run_convert;
+
+    let sas_path = fixture_path("productsales.sas7bdat");
+    let (full, rows, cols, _) = load_sas7bdat_silent(&sas_path).expect("load productsales SAS");
+
+    let dir = tempfile::tempdir().unwrap();
+    let parquet_path = dir.path().join("productsales.parquet");
+    run_convert(&sas_path, Some(&parquet_path), 1000, None, false).expect("convert");
+
+    let df_pq = read_parquet(&parquet_path);
+    assert_eq!(df_pq.shape(), (rows, cols));
+    assert!(df_pq.equals_missing(&full));
+}
+
+#[test]
+fn reader_encoding_override_replaces_the_header_encoding() {
+    use lophi::pipeline::sas7bdat::{Sas7bdatReader, SasEncoding};
+
+    let path = fixture_path("productsales.sas7bdat");
+    let default = Sas7bdatReader::open(&path).expect("open reader");
+    let names: Vec<&str> = default.columns().iter().map(|c| c.name.as_str()).collect();
+
+    let ebcdic = SasEncoding::from_name("ebcdic1047").expect("known encoding");
+    let mut reader = Sas7bdatReader::open_with_encoding(&path, ebcdic.clone()).expect("open");
+    assert_eq!(reader.header().encoding, ebcdic);
+    assert_eq!(reader.row_count(), default.row_count());
+
+    // ASCII names read as EBCDIC decode to different text
+    let decoded: Vec<&str> = reader.columns().iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(decoded.len(), names.len());
+    assert_ne!(decoded, names);
+    assert!(reader.next_batch(10).expect("next batch").is_some());
+}
+
+// ---------------------------------------------------------------------------
+// Deleted rows
+// ---------------------------------------------------------------------------
+
+// deleted_rows.sas7bdat is cars.sas7bdat with rows marked deleted the way
+// `PROC SQL; DELETE` leaves them: its MIX page (type 0x0280, slots 3 and 110)
+// and last DATA page (type 0x0180, slots 0, 5 and 104) carry a deleted-row
+// bitmap, and the row count excludes the five deleted rows.
+const CARS_DELETED_ROWS: [u32; 5] = [3, 110, 287, 292, 391];
+
+fn cars_without_deleted_rows() -> DataFrame {
+    let (cars, _, _, _) = load_sas7bdat_silent(&fixture_path("cars.sas7bdat")).unwrap();
+    let keep: Vec<u32> = (0..cars.height() as u32)
+        .filter(|i| !CARS_DELETED_ROWS.contains(i))
+        .collect();
+    cars.take(&IdxCa::from_vec("idx".into(), keep)).unwrap()
+}
+
+#[test]
+fn deleted_rows_are_skipped() {
+    let (df, rows, cols, _) =
+        load_sas7bdat_silent(&fixture_path("deleted_rows.sas7bdat")).expect("load");
+    assert_eq!(rows, 387);
+    assert_eq!(cols, 4);
+    assert!(df.equals_missing(&cars_without_deleted_rows()));
+}
+
+#[test]
+fn reader_batches_skip_deleted_rows() {
+    use lophi::pipeline::sas7bdat::Sas7bdatReader;
+
+    let mut reader =
+        Sas7bdatReader::open(&fixture_path("deleted_rows.sas7bdat")).expect("open reader");
+    assert_eq!(reader.row_count(), 387);
+    let mut stacked = reader.next_batch(100).unwrap().unwrap();
+    while let Some(batch) = reader.next_batch(100).unwrap() {
+        stacked.vstack_mut(&batch).unwrap();
+    }
+    assert!(stacked.equals_missing(&cars_without_deleted_rows()));
+}
+
+// ---------------------------------------------------------------------------
+// Truncated numeric lengths
+// ---------------------------------------------------------------------------
+
+/// Copy of cars.sas7bdat with the storage length of its second column (a
+/// 3-byte truncated numeric) rewritten to 2 bytes, which SAS cannot write.
+fn cars_with_two_byte_numeric(dir: &Path) -> PathBuf {
+    let mut bytes = std::fs::read(fixture_path("cars.sas7bdat")).unwrap();
+    // ColumnAttributes subheader on the first page (32-bit LE): 12-byte
+    // entries from byte 12, each starting with the offset then the length
+    let length_at = 1024 + 2992 + 12 + 12 + 4;
+    assert_eq!(bytes[length_at..length_at + 4], 3u32.to_le_bytes());
+    bytes[length_at..length_at + 4].copy_from_slice(&2u32.to_le_bytes());
+    let path = dir.join("cars_two_byte.sas7bdat");
+    std::fs::write(&path, bytes).unwrap();
+    path
+}
+
+#[test]
+fn truncated_numerics_match_full_width_values() {
+    // cars stores its columns in 8, 3, 8 and 4 bytes; every truncated value is
+    // a whole number, which survives truncation exactly
+    let reader = lophi::pipeline::sas7bdat::Sas7bdatReader::open(&fixture_path("cars.sas7bdat"))
+        .expect("open reader");
+    let lengths: Vec<u32> = reader.columns().iter().map(|c| c.length).collect();
+    assert_eq!(lengths, vec![8, 3, 8, 4]);
+
+    let (df, _, _, _) = load_sas7bdat_silent(&fixture_path("cars.sas7bdat")).unwrap();
+    for col in [&df.get_columns()[1], &df.get_columns()[3]] {
+        let values = col.f64().unwrap();
+        assert!(values.null_count() < values.len());
+        assert!(values
+            .into_no_null_iter()
+            .all(|v| v == v.trunc() && v > 0.0));
+    }
+}
+
+#[test]
+fn impossible_numeric_length_reads_as_null_or_fails_in_strict_mode() {
+    use lophi::pipeline::sas7bdat::Sas7bdatReader;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = cars_with_two_byte_numeric(dir.path());
+
+    let (df, _, _, _) = load_sas7bdat_silent(&path).expect("lenient load");
+    let (cars, _, _, _) = load_sas7bdat_silent(&fixture_path("cars.sas7bdat")).unwrap();
+    assert_eq!(df.get_columns()[1].null_count(), df.height());
+    assert!(df.get_columns()[2].equals_missing(&cars.get_columns()[2]));
+
+    let name = cars.get_column_names()[1].to_string();
+    match Sas7bdatReader::open(&path).unwrap().strict() {
+        Err(SasError::InvalidNumericLength { column, length }) => {
+            assert_eq!(column, name);
+            assert_eq!(length, 2);
+        }
+        Err(e) => panic!("Expected InvalidNumericLength, got {e}"),
+        Ok(_) => panic!("Expected strict mode to reject a 2-byte numeric"),
+    }
+
+    // Projecting the column away leaves nothing for strict mode to reject
+    let others: Vec<String> = cars
+        .get_column_names()
+        .iter()
+        .filter(|n| n.as_str() != name)
+        .map(|n| n.to_string())
+        .collect();
+    assert!(Sas7bdatReader::open(&path)
+        .unwrap()
+        .with_columns(&others)
+        .strict()
+        .is_ok());
+}
@@ -0,0 +1,154 @@
+//! Tests for schema drift checks (`--expect-schema`, `lophi schema`)
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+use lophi::pipeline::{ExpectedSchema, RetypedColumn, SchemaColumn};
+use polars::prelude::*;
+use tempfile::TempDir;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+fn column(name: &str, dtype: &str) -> SchemaColumn {
+    SchemaColumn {
+        name: name.to_string(),
+        dtype: dtype.to_string(),
+    }
+}
+
+/// Run the reduction on `input` with `extra` flags
+fn reduce(input: &Path, extra: &[&str]) -> assert_cmd::assert::Assert {
+    let dir = input.parent().unwrap();
+    let config = dir.join("pipeline.toml");
+    std::fs::write(&config, "use_solver = false\n").unwrap();
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--config")
+        .arg(&config)
+        .arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(extra)
+        .assert()
+}
+
+fn report_json(dir: &Path) -> serde_json::Value {
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_reduction_report.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+#[test]
+fn test_compare_reports_added_removed_and_retyped_columns() {
+    let expected = ExpectedSchema {
+        columns: vec![
+            column("target", "i32"),
+            column("income", "f64"),
+            column("region", "str"),
+            column("legacy_id", "i64"),
+        ],
+    };
+    let df = df! {
+        "target" => [0i32, 1],
+        "income" => ["low", "high"],
+        "region" => ["north", "south"],
+        "new_score" => [0.5f64, 0.7],
+    }
+    .unwrap();
+
+    let drift = expected.compare(&df, &[], Path::new("schema.json"));
+    assert!(!drift.is_empty());
+    assert_eq!(drift.added, vec![column("new_score", "f64")]);
+    assert_eq!(drift.removed, vec![column("legacy_id", "i64")]);
+    assert_eq!(
+        drift.retyped,
+        vec![RetypedColumn {
+            name: "income".to_string(),
+            expected: "f64".to_string(),
+            actual: "str".to_string(),
+        }]
+    );
+    assert_eq!(drift.lines().len(), 3);
+
+    // Columns dropped by the user are not reported as removed
+    let drift = expected.compare(&df, &["legacy_id".to_string()], Path::new("schema.json"));
+    assert!(drift.removed.is_empty());
+
+    // A dataset compared against its own schema has no drift
+    let own = ExpectedSchema::from_dataframe(&df);
+    assert!(own.compare(&df, &[], Path::new("own.json")).is_empty());
+}
+
+#[test]
+fn test_schema_file_round_trip() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("schema.json");
+    let schema = ExpectedSchema::from_dataframe(&create_binary_target_dataframe());
+    schema.save(&path).unwrap();
+
+    let loaded = ExpectedSchema::load(&path).unwrap();
+    assert_eq!(loaded, schema);
+    assert_eq!(loaded.columns[0], column("target", "i32"));
+
+    std::fs::write(&path, "{\"cols\": []}").unwrap();
+    assert!(ExpectedSchema::load(&path).is_err());
+}
+
+#[test]
+fn test_cli_expect_schema_reports_and_fails_on_drift() {
+    let mut df = create_binary_target_dataframe();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    // Schema of the known-good extract
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("schema")
+        .arg(&input)
+        .assert()
+        .success();
+    let schema: PathBuf = dir.path().join("test_data_schema.json");
+    assert!(schema.exists());
+    let schema_arg = schema.to_str().unwrap();
+
+    reduce(&input, &["--expect-schema", schema_arg]).success();
+    let drift = &report_json(dir.path())["metadata"]["schema_drift"];
+    assert_eq!(drift["added"].as_array().unwrap().len(), 0);
+    assert_eq!(drift["removed"].as_array().unwrap().len(), 0);
+    assert_eq!(drift["retyped"].as_array().unwrap().len(), 0);
+
+    // Next month's extract: random_feature became text, new_feature appeared
+    let n = df.height();
+    let text: Vec<String> = (0..n).map(|i| format!("v{}", i % 3)).collect();
+    let mut drifted = df
+        .clone()
+        .with_column(Series::new("random_feature".into(), text))
+        .unwrap()
+        .with_column(Series::new("new_feature".into(), vec![1.5f64; n]))
+        .unwrap()
+        .clone();
+    let mut file = std::fs::File::create(&input).unwrap();
+    CsvWriter::new(&mut file).finish(&mut drifted).unwrap();
+
+    reduce(&input, &["--expect-schema", schema_arg]).success();
+    let drift = &report_json(dir.path())["metadata"]["schema_drift"];
+    assert_eq!(drift["added"][0]["name"], "new_feature");
+    assert_eq!(drift["retyped"][0]["name"], "random_feature");
+    assert_eq!(drift["retyped"][0]["expected"], "f64");
+    assert_eq!(drift["retyped"][0]["actual"], "str");
+
+    let output = reduce(
+        &input,
+        &["--expect-schema", schema_arg, "--fail-on-schema-drift"],
+    )
+    .failure();
+    let stderr = String::from_utf8_lossy(&output.get_output().stderr).to_string();
+    assert!(stderr.contains("retyped: random_feature (f64 -> str)"));
+}
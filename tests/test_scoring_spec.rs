@@ -0,0 +1,144 @@
+//! Integration tests for the scoring spec in the report zip
+
+use std::io::Read;
+
+use assert_cmd::Command;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 400 rows with a 25% event rate. `age` (with nulls and a -1 sentinel) and
+/// `region` separate the classes, `noise` is unrelated to the target.
+fn scoring_df() -> DataFrame {
+    let n = 400;
+    let event = |i: usize| i.is_multiple_of(4);
+    df! {
+        "target" => (0..n).map(|i| i32::from(event(i))).collect::<Vec<_>>(),
+        "age" => (0..n)
+            .map(|i| match i % 10 {
+                1 => None,
+                3 => Some(-1.0),
+                _ => Some(20.0 + ((i / 4) % 40) as f64 + if event(i) { 0.0 } else { 5.0 }),
+            })
+            .collect::<Vec<_>>(),
+        "region" => (0..n)
+            .map(|i| if event(i) == (i % 3 == 0) { "north" } else { "south" })
+            .collect::<Vec<_>>(),
+        "noise" => (0..n).map(|i| ((i / 4) % 7) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn read_scoring_spec(dir: &std::path::Path) -> Option<serde_json::Value> {
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut entry = archive.by_name("test_data_scoring_spec.json").ok()?;
+    let mut content = String::new();
+    entry.read_to_string(&mut content).unwrap();
+    Some(serde_json::from_str(&content).unwrap())
+}
+
+/// WoE of a numeric value under the documented rules of the spec
+fn score_numeric(feature: &serde_json::Value, value: Option<f64>) -> f64 {
+    let Some(value) = value else {
+        return feature["missing_woe"].as_f64().unwrap();
+    };
+    if let Some(special) = feature["special_values"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|s| s["value"].as_f64() == Some(value))
+    {
+        return special["woe"].as_f64().unwrap();
+    }
+    let bins = feature["bins"].as_array().unwrap();
+    bins.iter()
+        .find(|b| b["upper_bound"].as_f64().is_none_or(|upper| value < upper))
+        .unwrap_or(bins.last().unwrap())["woe"]
+        .as_f64()
+        .unwrap()
+}
+
+/// WoE of a category under the documented rules of the spec
+fn score_category(feature: &serde_json::Value, value: &str) -> f64 {
+    feature["categories"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|g| g["values"].as_array().unwrap().iter().any(|v| v == value))
+        .map_or(feature["default_woe"].as_f64().unwrap(), |g| {
+            g["woe"].as_f64().unwrap()
+        })
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_scoring_spec_reproduces_the_woe_export() {
+    let mut df = scoring_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target", "--no-confirm", "--export-woe"])
+        .args(["--binning-backend", "greedy", "--special-values", "age:-1"])
+        .args(["--gini-threshold", "0.05"])
+        .assert()
+        .success();
+
+    let spec = read_scoring_spec(dir.path()).unwrap();
+    assert_eq!(spec["spec_version"], 1);
+    assert_eq!(spec["target"], "target");
+    let features = spec["features"].as_array().unwrap();
+    let names: Vec<&str> = features
+        .iter()
+        .map(|f| f["feature"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["age", "region"]);
+
+    let age = &features[0];
+    assert_eq!(age["feature_type"], "Numeric");
+    assert!(age["bins"].as_array().unwrap().last().unwrap()["upper_bound"].is_null());
+    assert_eq!(age["special_values"][0]["value"], -1.0);
+    let region = &features[1];
+    assert_eq!(region["feature_type"], "Categorical");
+
+    let encoded = ParquetReader::new(
+        std::fs::File::open(dir.path().join("test_data_reduced_woe.parquet")).unwrap(),
+    )
+    .finish()
+    .unwrap();
+    let ages = df.column("age").unwrap().f64().unwrap();
+    let regions = df.column("region").unwrap().str().unwrap();
+    let age_woe = encoded.column("age").unwrap().f64().unwrap();
+    let region_woe = encoded.column("region").unwrap().f64().unwrap();
+    for i in 0..df.height() {
+        assert!((score_numeric(age, ages.get(i)) - age_woe.get(i).unwrap()).abs() < 1e-12);
+        let category = regions.get(i).unwrap();
+        assert!((score_category(region, category) - region_woe.get(i).unwrap()).abs() < 1e-12);
+    }
+    assert_eq!(score_category(region, "unseen"), region["default_woe"]);
+}
+
+#[test]
+fn test_no_scoring_spec_without_the_gini_step() {
+    let mut df = scoring_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target", "--no-confirm", "--skip-gini"])
+        .assert()
+        .success();
+
+    assert!(read_scoring_spec(dir.path()).is_none());
+}
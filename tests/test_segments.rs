@@ -0,0 +1,224 @@
+//! Integration tests for the per-segment checks (`--segment-column`)
+
+use std::io::Read;
+
+use assert_cmd::Command;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 600 rows over three regions of 200 with a 25% event rate. `bureau_score`
+/// separates the classes but is never supplied in `east`; `income` is
+/// complete everywhere and separates them alike in every region;
+/// `branch_score` separates them in `north` only.
+fn regional_df() -> DataFrame {
+    let n = 600;
+    let regions = ["north", "south", "east"];
+    let region = |i: usize| regions[i / 200];
+    let event = |i: usize| i.is_multiple_of(4);
+    df! {
+        "target" => (0..n).map(|i| i32::from(event(i))).collect::<Vec<_>>(),
+        "region" => (0..n).map(region).collect::<Vec<_>>(),
+        "bureau_score" => (0..n)
+            .map(|i| {
+                (region(i) != "east").then(|| (i % 5) as f64 + if event(i) { 3.0 } else { 0.0 })
+            })
+            .collect::<Vec<_>>(),
+        "income" => (0..n)
+            .map(|i| (i % 7) as f64 + if event(i) { 2.0 } else { 0.0 })
+            .collect::<Vec<_>>(),
+        "branch_score" => (0..n)
+            .map(|i| {
+                let signal = if region(i) == "north" { 6.0 } else { 0.0 };
+                ((i / 4) % 6) as f64 + if event(i) { signal } else { 0.0 }
+            })
+            .collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn lophi(input: &std::path::Path, args: &[&str]) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--binning-backend", "greedy"])
+        .args(["--missing-threshold", "0.9", "--gini-threshold", "0.0"])
+        .args(["--correlation-threshold", "1.0"])
+        .args(args);
+    cmd
+}
+
+fn read_zip_entry(dir: &std::path::Path, name: &str) -> String {
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name(name)
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    content
+}
+
+fn read_report_json(dir: &std::path::Path) -> serde_json::Value {
+    serde_json::from_str(&read_zip_entry(dir, "test_data_reduction_report.json")).unwrap()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_segment_column_flags_features_missing_in_a_segment() {
+    let mut df = regional_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    lophi(&input, &["--segment-column", "region"])
+        .assert()
+        .success();
+
+    let report = read_report_json(dir.path());
+    let section = &report["segment_missing"];
+    assert_eq!(section["column"], "region");
+    assert_eq!(section["unsegmented_rows"], 0);
+    let segments: Vec<&str> = section["segments"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["segment"].as_str().unwrap())
+        .collect();
+    assert_eq!(segments, ["east", "north", "south"]);
+
+    let feature = |name: &str| {
+        section["features"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|f| f["feature"] == name)
+            .unwrap_or_else(|| panic!("no segment entry for {}", name))
+            .clone()
+    };
+    let bureau = feature("bureau_score");
+    assert_eq!(bureau["fully_missing_in"], serde_json::json!(["east"]));
+    assert_eq!(bureau["missing_ratios"]["north"], 0.0);
+    assert_eq!(feature("income")["fully_missing_in"], serde_json::json!([]));
+
+    // The per-segment table travels in the report zip
+    let table = read_zip_entry(dir.path(), "test_data_segment_missing.csv");
+    let mut lines = table.lines();
+    assert_eq!(
+        lines.next().unwrap(),
+        "feature,segment,rows,missing_ratio,fully_missing"
+    );
+    let rows: Vec<&str> = lines.collect();
+    assert!(rows.contains(&"bureau_score,east,200,1,true"), "{:?}", rows);
+    assert!(
+        rows.contains(&"bureau_score,north,200,0,false"),
+        "{:?}",
+        rows
+    );
+
+    // Flagged only; the segment column rides through
+    let file = std::fs::File::open(dir.path().join("test_data_reduced.parquet")).unwrap();
+    let output = ParquetReader::new(file).finish().unwrap();
+    for column in ["region", "bureau_score"] {
+        assert!(output.column(column).is_ok(), "{} missing", column);
+    }
+}
+
+#[test]
+fn test_segment_column_compares_iv_across_segments() {
+    let mut df = regional_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    lophi(&input, &["--segment-column", "region"])
+        .assert()
+        .success();
+
+    let report = read_report_json(dir.path());
+    let section = &report["segment_iv"];
+    assert_eq!(section["column"], "region");
+    assert!(section["segments"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|s| s["rows"] == 200 && s["events"] == 50 && s["scored"] == true));
+
+    let gini: serde_json::Value =
+        serde_json::from_str(&read_zip_entry(dir.path(), "test_data_gini_analysis.json")).unwrap();
+    assert_eq!(gini["metadata"]["segment_column"], "region");
+    let segments = |name: &str| {
+        gini["features"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|f| f["feature_name"] == name)
+            .unwrap_or_else(|| panic!("no Gini entry for {}", name))["segments"]
+            .clone()
+    };
+
+    let branch = segments("branch_score");
+    assert_eq!(branch["divergent"], true);
+    let ivs: Vec<&serde_json::Value> = branch["segments"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| &s["iv"])
+        .collect();
+    assert!(ivs[1].as_f64().unwrap() > 0.5, "{:?}", ivs);
+    assert!(ivs[0].as_f64().unwrap() < 0.1, "{:?}", ivs);
+    assert_eq!(segments("income")["divergent"], false);
+    // Unscored where the feature is never supplied: constant null, no IV
+    assert_eq!(segments("bureau_score")["segments"][0]["iv"], 0.0);
+    // The segment column itself is not compared across its own values
+    assert!(segments("region").is_null());
+}
+
+#[test]
+fn test_single_class_segment_is_left_unscored() {
+    let mut df = regional_df();
+    // No events at all in east
+    let target: Vec<i32> = (0..600).map(|i| i32::from(i % 4 == 0 && i < 400)).collect();
+    df.with_column(Column::new("target".into(), target))
+        .unwrap();
+    let (dir, input) = create_temp_parquet(&mut df);
+    let output = lophi(&input, &["--segment-column", "region"])
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Segment 'east' of 'region' is single-class (0 event, 200 non-event)"),
+        "{}",
+        stderr
+    );
+
+    let report = read_report_json(dir.path());
+    let east = &report["segment_iv"]["segments"][0];
+    assert_eq!(east["segment"], "east");
+    assert_eq!(east["scored"], false);
+}
+
+#[test]
+fn test_unknown_segment_column_is_rejected() {
+    let mut df = regional_df();
+    let (_dir, input) = create_temp_parquet(&mut df);
+    let output = lophi(&input, &["--segment-column", "branch"])
+        .assert()
+        .failure()
+        .get_output()
+        .clone();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Segment column 'branch' not found"),
+        "{}",
+        stderr
+    );
+}
@@ -0,0 +1,92 @@
+//! Integration tests for the selected-feature lists in the report zip
+
+use std::io::Read;
+
+use assert_cmd::Command;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 400 rows with a 25% event rate. `score` and `days late` separate the
+/// classes, `score_copy` is a copy of `score` and `noise` is unrelated to the
+/// target.
+fn selection_df() -> DataFrame {
+    let n = 400;
+    let event = |i: usize| i.is_multiple_of(4);
+    let score = |i: usize| ((i / 4) % 9) as f64 + if event(i) { 2.0 } else { 0.0 };
+    df! {
+        "target" => (0..n).map(|i| i32::from(event(i))).collect::<Vec<_>>(),
+        "score" => (0..n).map(score).collect::<Vec<_>>(),
+        "score_copy" => (0..n).map(|i| score(i) * 2.0).collect::<Vec<_>>(),
+        "days late" => (0..n)
+            .map(|i| if event(i) == (i % 3 == 0) { "none" } else { "some" })
+            .collect::<Vec<_>>(),
+        "noise" => (0..n).map(|i| ((i / 4) % 7) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn read_zip_entry(dir: &std::path::Path, name: &str) -> String {
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name(name)
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    content
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_selected_features_match_the_reduced_dataset() {
+    let mut df = selection_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--binning-backend", "greedy", "--gini-threshold", "0.05"])
+        .assert()
+        .success();
+
+    let reduced = ParquetReader::new(
+        std::fs::File::open(dir.path().join("test_data_reduced.parquet")).unwrap(),
+    )
+    .finish()
+    .unwrap();
+    let mut expected: Vec<String> = reduced
+        .get_column_names_str()
+        .into_iter()
+        .filter(|c| *c != "target")
+        .map(String::from)
+        .collect();
+    expected.sort();
+    assert_eq!(expected, ["days late", "score"]);
+
+    let text = read_zip_entry(dir.path(), "test_data_selected_features.txt");
+    assert_eq!(text, "days late\nscore\n");
+
+    let python = read_zip_entry(dir.path(), "test_data_selected_features.py");
+    assert_eq!(
+        python,
+        "selected_features = [\n    \"days late\",\n    \"score\",\n]\n"
+    );
+
+    let sql = read_zip_entry(dir.path(), "test_data_selected_features.sql");
+    assert_eq!(sql, "SELECT\n    \"days late\",\n    \"score\"\n");
+
+    // Only the zip is left next to the input
+    assert!(!dir.path().join("test_data_selected_features.txt").exists());
+}
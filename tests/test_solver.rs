@@ -1,8 +1,13 @@
 //! Tests for solver-based optimal binning
 
+#![cfg(feature = "solver")]
+
 use polars::prelude::*;
 
-use lophi::pipeline::{analyze_features_iv, BinningStrategy, MonotonicityConstraint, SolverConfig};
+use lophi::pipeline::{
+    analyze_features_iv, BinningBackend, BinningStrategy, MissingBinPolicy, MonotonicityConstraint,
+    SolverConfig, SolverStatus,
+};
 
 /// Create test dataframe with numeric feature that has clear event rate separation
 fn create_numeric_test_dataframe() -> DataFrame {
@@ -26,6 +31,10 @@ fn test_solver_produces_expected_bin_count() {
         gap_tolerance: 0.01,
         monotonicity: MonotonicityConstraint::None,
         min_bin_samples: 5,
+        min_bin_pct: 0.0,
+        max_bin_pct: 100.0,
+        min_woe_delta: 0.0,
+        backend: BinningBackend::Mip,
     };
 
     let result = analyze_features_iv(
@@ -40,6 +49,9 @@ fn test_solver_produces_expected_bin_count() {
         &weights,
         None,
         Some(&solver_config),
+        None,
+        MissingBinPolicy::Separate,
+        None,
     );
 
     assert!(result.is_ok(), "Solver analysis should succeed");
@@ -70,6 +82,10 @@ fn test_solver_with_ascending_monotonicity() {
         gap_tolerance: 0.01,
         monotonicity: MonotonicityConstraint::Ascending,
         min_bin_samples: 5,
+        min_bin_pct: 0.0,
+        max_bin_pct: 100.0,
+        min_woe_delta: 0.0,
+        backend: BinningBackend::Mip,
     };
 
     let result = analyze_features_iv(
@@ -84,6 +100,9 @@ fn test_solver_with_ascending_monotonicity() {
         &weights,
         None,
         Some(&solver_config),
+        None,
+        MissingBinPolicy::Separate,
+        None,
     );
 
     assert!(
@@ -130,6 +149,10 @@ fn test_solver_with_descending_monotonicity() {
         gap_tolerance: 0.01,
         monotonicity: MonotonicityConstraint::Descending,
         min_bin_samples: 5,
+        min_bin_pct: 0.0,
+        max_bin_pct: 100.0,
+        min_woe_delta: 0.0,
+        backend: BinningBackend::Mip,
     };
 
     let result = analyze_features_iv(
@@ -144,6 +167,9 @@ fn test_solver_with_descending_monotonicity() {
         &weights,
         None,
         Some(&solver_config),
+        None,
+        MissingBinPolicy::Separate,
+        None,
     );
 
     assert!(
@@ -181,6 +207,10 @@ fn test_solver_auto_monotonicity() {
         gap_tolerance: 0.01,
         monotonicity: MonotonicityConstraint::Auto,
         min_bin_samples: 5,
+        min_bin_pct: 0.0,
+        max_bin_pct: 100.0,
+        min_woe_delta: 0.0,
+        backend: BinningBackend::Mip,
     };
 
     let result = analyze_features_iv(
@@ -195,6 +225,9 @@ fn test_solver_auto_monotonicity() {
         &weights,
         None,
         Some(&solver_config),
+        None,
+        MissingBinPolicy::Separate,
+        None,
     );
 
     assert!(
@@ -230,6 +263,9 @@ fn test_greedy_vs_solver_produces_valid_output() {
         &weights,
         None,
         None,
+        None,
+        MissingBinPolicy::Separate,
+        None,
     );
 
     assert!(greedy_result.is_ok(), "Greedy analysis should succeed");
@@ -242,6 +278,10 @@ fn test_greedy_vs_solver_produces_valid_output() {
         gap_tolerance: 0.01,
         monotonicity: MonotonicityConstraint::None,
         min_bin_samples: 5,
+        min_bin_pct: 0.0,
+        max_bin_pct: 100.0,
+        min_woe_delta: 0.0,
+        backend: BinningBackend::Mip,
     };
 
     let solver_result = analyze_features_iv(
@@ -256,6 +296,9 @@ fn test_greedy_vs_solver_produces_valid_output() {
         &weights,
         None,
         Some(&solver_config),
+        None,
+        MissingBinPolicy::Separate,
+        None,
     );
 
     assert!(solver_result.is_ok(), "Solver analysis should succeed");
@@ -295,6 +338,10 @@ fn test_solver_bins_cover_all_data() {
         gap_tolerance: 0.01,
         monotonicity: MonotonicityConstraint::None,
         min_bin_samples: 5,
+        min_bin_pct: 0.0,
+        max_bin_pct: 100.0,
+        min_woe_delta: 0.0,
+        backend: BinningBackend::Mip,
     };
 
     let result = analyze_features_iv(
@@ -309,6 +356,9 @@ fn test_solver_bins_cover_all_data() {
         &weights,
         None,
         Some(&solver_config),
+        None,
+        MissingBinPolicy::Separate,
+        None,
     );
 
     assert!(result.is_ok(), "Solver analysis should succeed");
@@ -325,3 +375,200 @@ fn test_solver_bins_cover_all_data() {
         total_count
     );
 }
+
+/// 2000 rows whose event rate rises to a peak at feature value 50 and falls after it
+fn create_peaked_dataframe() -> DataFrame {
+    let mut state: u64 = 7;
+    let mut uniform = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) as f64 / (1u64 << 31) as f64
+    };
+    let feature: Vec<f64> = (0..2000).map(|i| (i % 100) as f64).collect();
+    let target: Vec<i32> = feature
+        .iter()
+        .map(|x| {
+            let rate = 0.05 + 0.5 * (-((x - 50.0) / 18.0).powi(2)).exp();
+            i32::from(uniform() < rate)
+        })
+        .collect();
+    df! { "target" => target, "feature" => feature }.unwrap()
+}
+
+#[test]
+fn test_solver_auto_monotonicity_detects_peak() {
+    let df = create_peaked_dataframe();
+    let weights = vec![1.0; df.height()];
+    let solver_config = SolverConfig {
+        monotonicity: MonotonicityConstraint::Auto,
+        ..SolverConfig::default()
+    };
+
+    let analyses = analyze_features_iv(
+        &df,
+        "target",
+        6,
+        20,
+        None,
+        BinningStrategy::Quantile,
+        None,
+        None,
+        &weights,
+        None,
+        Some(&solver_config),
+        None,
+        MissingBinPolicy::Separate,
+        None,
+    )
+    .unwrap();
+    let analysis = &analyses[0];
+    assert_eq!(analysis.monotonicity, Some(MonotonicityConstraint::Peak));
+
+    // WoE rises to a single maximum and falls after it
+    let woes: Vec<f64> = analysis.bins.iter().map(|b| b.woe).collect();
+    let top = woes
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .unwrap()
+        .0;
+    assert!(top > 0 && top < woes.len() - 1, "peak inside: {:?}", woes);
+    assert!(woes[..=top].windows(2).all(|w| w[0] <= w[1]), "{:?}", woes);
+    assert!(woes[top..].windows(2).all(|w| w[0] >= w[1]), "{:?}", woes);
+}
+
+#[test]
+fn test_solver_auto_monotonicity_prefers_a_trend_for_monotonic_data() {
+    let df = create_peaked_dataframe();
+    // Keep only the rising half of the peak
+    let df = df
+        .lazy()
+        .filter(col("feature").lt(lit(50.0)))
+        .collect()
+        .unwrap();
+    let weights = vec![1.0; df.height()];
+    let solver_config = SolverConfig {
+        monotonicity: MonotonicityConstraint::Auto,
+        ..SolverConfig::default()
+    };
+
+    let analyses = analyze_features_iv(
+        &df,
+        "target",
+        5,
+        20,
+        None,
+        BinningStrategy::Quantile,
+        None,
+        None,
+        &weights,
+        None,
+        Some(&solver_config),
+        None,
+        MissingBinPolicy::Separate,
+        None,
+    )
+    .unwrap();
+    let analysis = &analyses[0];
+    assert_eq!(
+        analysis.monotonicity,
+        Some(MonotonicityConstraint::Ascending)
+    );
+    assert!(analysis.bins.windows(2).all(|w| w[0].woe <= w[1].woe));
+}
+
+#[test]
+fn test_solver_records_requested_monotonicity() {
+    let df = create_peaked_dataframe();
+    let weights = vec![1.0; df.height()];
+    let solver_config = SolverConfig {
+        monotonicity: MonotonicityConstraint::Descending,
+        ..SolverConfig::default()
+    };
+
+    let analyses = analyze_features_iv(
+        &df,
+        "target",
+        4,
+        20,
+        None,
+        BinningStrategy::Quantile,
+        None,
+        None,
+        &weights,
+        None,
+        Some(&solver_config),
+        None,
+        MissingBinPolicy::Separate,
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        analyses[0].monotonicity,
+        Some(MonotonicityConstraint::Descending)
+    );
+
+    // Greedy merging records no constraint
+    let greedy = analyze_features_iv(
+        &df,
+        "target",
+        4,
+        20,
+        None,
+        BinningStrategy::Quantile,
+        None,
+        None,
+        &weights,
+        None,
+        None,
+        None,
+        MissingBinPolicy::Separate,
+        None,
+    )
+    .unwrap();
+    assert_eq!(greedy[0].monotonicity, None);
+}
+
+#[test]
+fn test_solver_records_status_and_fallback() {
+    let df = create_peaked_dataframe();
+    let weights = vec![1.0; df.height()];
+    let analyze = |solver_config: Option<&SolverConfig>| {
+        analyze_features_iv(
+            &df,
+            "target",
+            4,
+            20,
+            None,
+            BinningStrategy::Quantile,
+            None,
+            None,
+            &weights,
+            None,
+            solver_config,
+            None,
+            MissingBinPolicy::Separate,
+            None,
+        )
+        .unwrap()
+    };
+
+    let solved = analyze(Some(&SolverConfig::default()));
+    assert_eq!(solved[0].solver_status, Some(SolverStatus::Optimal));
+
+    // No grouping puts 60-70% of the rows in every bin: greedy merging takes over
+    let infeasible = SolverConfig {
+        min_bin_pct: 60.0,
+        max_bin_pct: 70.0,
+        ..SolverConfig::default()
+    };
+    let fallback = analyze(Some(&infeasible));
+    assert_eq!(
+        fallback[0].solver_status,
+        Some(SolverStatus::FailedFallback)
+    );
+    assert!(!fallback[0].bins.is_empty());
+
+    assert_eq!(analyze(None)[0].solver_status, None);
+}
@@ -0,0 +1,176 @@
+//! Integration tests for special-value bins (`--special-values`)
+
+use assert_cmd::Command;
+use lophi::pipeline::{
+    analyze_features_iv, apply_woe_transform, BinningStrategy, MissingBinPolicy, SpecialValues,
+};
+use polars::prelude::{df, DataFrame};
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 600 rows with a 25% event rate. One row in six of `bureau` is the "no
+/// record" code -999997, mostly events; `age` codes a refusal as -1.
+fn sentinel_df() -> DataFrame {
+    let n = 600;
+    df! {
+        "target" => (0..n).map(|i| i32::from(i % 4 == 0)).collect::<Vec<_>>(),
+        "bureau" => (0..n)
+            .map(|i| if i % 6 == 0 { -999997.0 } else { (i % 50) as f64 })
+            .collect::<Vec<_>>(),
+        "age" => (0..n)
+            .map(|i| if i % 10 == 3 { -1.0 } else { 20.0 + (i % 40) as f64 })
+            .collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn lophi(dir: &std::path::Path, input: &std::path::Path, args: &[&str]) -> Command {
+    let config = dir.join("pipeline.toml");
+    std::fs::write(&config, "use_solver = false\n").unwrap();
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("--config")
+        .arg(&config)
+        .arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--gini-threshold", "0.0"])
+        .args(args);
+    cmd
+}
+
+fn read_gini_export(dir: &std::path::Path) -> serde_json::Value {
+    use std::io::Read;
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_gini_analysis.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+fn feature<'a>(export: &'a serde_json::Value, name: &str) -> &'a serde_json::Value {
+    export["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["feature_name"] == name)
+        .unwrap_or_else(|| panic!("no entry for {}", name))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_special_values_get_their_own_bins_in_gini_export() {
+    let mut df = sentinel_df();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    lophi(dir.path(), &input, &["--special-values", "-999997,age:-1"])
+        .assert()
+        .success();
+
+    let export = read_gini_export(dir.path());
+    assert_eq!(export["metadata"]["special_values"]["global"][0], -999997.0);
+    assert_eq!(
+        export["metadata"]["special_values"]["per_feature"]["age"][0],
+        -1.0
+    );
+
+    let bureau = feature(&export, "bureau");
+    let special = bureau["special_bins"].as_array().unwrap();
+    assert_eq!(special.len(), 1);
+    assert_eq!(special[0]["value"], -999997.0);
+    assert_eq!(special[0]["count"], 100.0);
+    // The numeric bins start at the lowest ordinary value, not the sentinel
+    let binned: f64 = bureau["bins"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|b| b["count"].as_f64().unwrap())
+        .sum();
+    assert_eq!(binned, 500.0);
+
+    let age = feature(&export, "age");
+    assert_eq!(age["special_bins"][0]["value"], -1.0);
+    assert_eq!(age["special_bins"][0]["count"], 60.0);
+}
+
+#[test]
+fn test_special_values_absent_leave_export_unchanged() {
+    let mut df = sentinel_df();
+    let (dir, input) = create_temp_csv(&mut df);
+
+    lophi(dir.path(), &input, &[]).assert().success();
+
+    let export = read_gini_export(dir.path());
+    assert!(export["metadata"].get("special_values").is_none());
+    assert!(feature(&export, "bureau").get("special_bins").is_none());
+}
+
+#[test]
+fn test_special_values_encode_with_their_bin_woe() {
+    let df = sentinel_df();
+    let weights = vec![1.0; df.height()];
+    let special = SpecialValues::parse(&["-999997"]).unwrap();
+
+    let analyses = analyze_features_iv(
+        &df,
+        "target",
+        5,
+        20,
+        None,
+        BinningStrategy::Quantile,
+        None,
+        None,
+        &weights,
+        None,
+        None,
+        Some(&special),
+        MissingBinPolicy::Separate,
+        None,
+    )
+    .unwrap();
+    let bureau = analyses
+        .iter()
+        .find(|a| a.feature_name == "bureau")
+        .unwrap();
+    let special_woe = bureau.special_bins[0].woe;
+    assert!(bureau.bins.iter().all(|b| b.woe != special_woe));
+
+    let mut encoded = df.clone();
+    apply_woe_transform(&mut encoded, &analyses).unwrap();
+    let woe = encoded.column("bureau").unwrap().f64().unwrap();
+    for i in (0..df.height()).step_by(6) {
+        assert_eq!(woe.get(i), Some(special_woe));
+    }
+    assert_ne!(woe.get(1), Some(special_woe));
+}
+
+#[test]
+fn test_special_values_reject_continuous_target() {
+    let mut df = df! {
+        "target" => (0..50).map(|i| i as f64 * 1.5).collect::<Vec<_>>(),
+        "x" => (0..50).map(|i| (i % 7) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap();
+    let (_dir, input) = create_temp_csv(&mut df);
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--target-type", "continuous", "--special-values", "-1"])
+        .assert()
+        .failure();
+}
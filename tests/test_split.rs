@@ -0,0 +1,68 @@
+//! Tests for train/test split awareness (--split-column / --train-value)
+
+use lophi::pipeline::{create_train_mask, train_partition};
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+fn split_df() -> DataFrame {
+    df! {
+        "target" => [0i32, 1, 0, 1, 0, 1],
+        "sample" => ["TRAIN", "TEST", "TRAIN", "TRAIN", "TEST", "TRAIN"],
+        "x" => [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0],
+    }
+    .unwrap()
+}
+
+#[test]
+fn test_train_mask_matches_train_value() {
+    let mask = create_train_mask(&split_df(), "sample", "TRAIN").unwrap();
+    let values: Vec<bool> = mask.into_iter().map(|v| v.unwrap()).collect();
+    assert_eq!(values, vec![true, false, true, true, false, true]);
+}
+
+#[test]
+fn test_train_mask_numeric_indicator_and_nulls() {
+    let df = df! {
+        "is_train" => [Some(1i32), Some(0), None, Some(1)],
+    }
+    .unwrap();
+    let mask = create_train_mask(&df, "is_train", "1").unwrap();
+    let values: Vec<bool> = mask.into_iter().map(|v| v.unwrap()).collect();
+    assert_eq!(values, vec![true, false, false, true]);
+}
+
+#[test]
+fn test_train_partition_filters_rows_and_weights() {
+    let df = split_df();
+    let weights = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+
+    let (train, train_weights) = train_partition(&df, &weights, "sample", "TRAIN").unwrap();
+
+    assert_shape(&train, 4, 2);
+    assert_missing_columns(&train, &["sample"]);
+    assert_eq!(train_weights, vec![1.0, 3.0, 4.0, 6.0]);
+    let x: Vec<f64> = train
+        .column("x")
+        .unwrap()
+        .f64()
+        .unwrap()
+        .into_no_null_iter()
+        .collect();
+    assert_eq!(x, vec![1.0, 3.0, 4.0, 6.0]);
+}
+
+#[test]
+fn test_train_partition_errors() {
+    let df = split_df();
+    let weights = vec![1.0; df.height()];
+
+    let err = train_partition(&df, &weights, "missing", "TRAIN").unwrap_err();
+    assert!(err.to_string().contains("not found"));
+
+    let err = train_partition(&df, &weights, "sample", "train").unwrap_err();
+    assert!(err.to_string().contains("No rows"));
+}
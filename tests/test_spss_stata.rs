@@ -0,0 +1,400 @@
+//! Tests for the SPSS (`.sav`) and Stata (`.dta`) readers
+
+#![cfg(all(feature = "sav", feature = "dta"))]
+
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+use lophi::cli::convert::run_convert;
+use lophi::pipeline::{get_column_names, load_dataset_with_progress};
+use polars::prelude::*;
+use tempfile::TempDir;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+const ROWS: usize = 40;
+const REGIONS: [&str; 3] = ["north", "south", "central-east"];
+
+/// Days since 1970-01-01 of row `i`'s account opening date
+fn opened_day(i: usize) -> i32 {
+    19_000 + i as i32
+}
+
+/// Income of row `i`; every 7th row is missing
+fn income(i: usize) -> Option<f64> {
+    (i % 7 != 3).then_some(1_000.0 + i as f64 * 12.5)
+}
+
+// ============================================================================
+// SPSS writer
+// ============================================================================
+
+fn put_i32(out: &mut Vec<u8>, v: i32) {
+    out.extend_from_slice(&v.to_le_bytes());
+}
+
+fn put_f64(out: &mut Vec<u8>, v: f64) {
+    out.extend_from_slice(&v.to_le_bytes());
+}
+
+fn padded(text: &str, width: usize) -> Vec<u8> {
+    let mut bytes = text.as_bytes().to_vec();
+    bytes.resize(width, b' ');
+    bytes
+}
+
+/// SPSS variable record; `width` 0 is numeric
+fn put_variable(out: &mut Vec<u8>, name: &str, width: i32, format: u32, missing: &[f64]) {
+    put_i32(out, 2);
+    put_i32(out, width);
+    put_i32(out, 0); // no label
+    put_i32(out, missing.len() as i32);
+    put_i32(out, format as i32);
+    put_i32(out, format as i32);
+    out.extend_from_slice(&padded(name, 8));
+    for &m in missing {
+        put_f64(out, m);
+    }
+}
+
+/// Cases as 8-byte elements: target, income, region (2 elements), opened,
+/// segment. Income -99 is declared user-missing; `None` is system-missing.
+fn sav_elements(i: usize) -> Vec<Option<[u8; 8]>> {
+    let income = if i == 5 { Some(-99.0) } else { income(i) };
+    let region = padded(REGIONS[i % 3], 16);
+    let opened = (f64::from(opened_day(i)) + 141_428.0) * 86_400.0;
+    let segment = padded(if i % 2 == 1 { "" } else { "A" }, 8);
+    vec![
+        Some(((i % 2) as f64).to_le_bytes()),
+        income.map(f64::to_le_bytes),
+        Some(region[..8].try_into().unwrap()),
+        Some(region[8..].try_into().unwrap()),
+        Some(opened.to_le_bytes()),
+        Some(segment[..].try_into().unwrap()),
+    ]
+}
+
+fn write_sav(path: &Path, compressed: bool) {
+    let mut out = b"$FL2".to_vec();
+    out.extend_from_slice(&padded("@(#) SPSS DATA FILE lophi test", 60));
+    put_i32(&mut out, 2); // layout code
+    put_i32(&mut out, 6); // elements per case
+    put_i32(&mut out, i32::from(compressed));
+    put_i32(&mut out, 0); // weight index
+    put_i32(&mut out, ROWS as i32);
+    put_f64(&mut out, 100.0);
+    out.extend_from_slice(&padded("", 9 + 8 + 64 + 3));
+
+    let f8_2 = (5 << 16) | (8 << 8) | 2;
+    put_variable(&mut out, "TARGET", 0, (5 << 16) | (1 << 8), &[]);
+    put_variable(&mut out, "INCOME", 0, f8_2, &[-99.0]);
+    put_variable(&mut out, "REGION", 12, (1 << 16) | (12 << 8), &[]);
+    put_variable(&mut out, "", -1, 0, &[]);
+    put_variable(&mut out, "OPENED", 0, (20 << 16) | (11 << 8), &[]);
+    put_variable(&mut out, "SEGMENT", 4, (1 << 16) | (4 << 8), &[]);
+
+    // Long variable names (record 7, subtype 13)
+    let names = b"SEGMENT=customer_segment";
+    for v in [7, 13, 1, names.len() as i32] {
+        put_i32(&mut out, v);
+    }
+    out.extend_from_slice(names);
+    put_i32(&mut out, 999);
+    put_i32(&mut out, 0);
+
+    let elements: Vec<Option<[u8; 8]>> = (0..ROWS).flat_map(sav_elements).collect();
+    if !compressed {
+        // System-missing is stored as the lowest double
+        for element in elements {
+            out.extend_from_slice(&element.unwrap_or((-f64::MAX).to_le_bytes()));
+        }
+    } else {
+        // Blocks of 8 command bytes, each followed by its raw elements
+        let mut commands = Vec::new();
+        let mut raw = Vec::new();
+        for element in elements.into_iter().map(Some).chain(std::iter::once(None)) {
+            let code = match element {
+                None => 252,
+                Some(None) => 255,
+                Some(Some(bytes)) if bytes == *b"        " => 254,
+                Some(Some(bytes)) => {
+                    let v = f64::from_le_bytes(bytes);
+                    if v.fract() == 0.0 && (-99.0..=151.0).contains(&v) {
+                        (v + 100.0) as u8
+                    } else {
+                        raw.extend_from_slice(&bytes);
+                        253
+                    }
+                }
+            };
+            commands.push(code);
+            if commands.len() == 8 || element.is_none() {
+                commands.resize(8, 0);
+                out.append(&mut commands);
+                out.append(&mut raw);
+            }
+        }
+    }
+    std::fs::write(path, out).unwrap();
+}
+
+// ============================================================================
+// Stata writer
+// ============================================================================
+
+/// Release 118, little-endian: target (byte), income (double), region
+/// (str12), opened (long, %td) and note (strL, empty on every 4th row)
+fn write_dta_118(path: &Path) {
+    let mut out = Vec::new();
+    out.extend_from_slice(b"<stata_dta><header><release>118</release><byteorder>LSF</byteorder>");
+    out.extend_from_slice(b"<K>");
+    out.extend_from_slice(&5u16.to_le_bytes());
+    out.extend_from_slice(b"</K><N>");
+    out.extend_from_slice(&(ROWS as u64).to_le_bytes());
+    out.extend_from_slice(b"</N><label>");
+    out.extend_from_slice(&0u16.to_le_bytes());
+    out.extend_from_slice(b"</label><timestamp>");
+    out.push(0);
+    out.extend_from_slice(b"</timestamp></header><map>");
+    let map_at = out.len();
+    out.extend_from_slice(&[0; 14 * 8]);
+    out.extend_from_slice(b"</map><variable_types>");
+    for code in [65530u16, 65526, 12, 65528, 32768] {
+        out.extend_from_slice(&code.to_le_bytes());
+    }
+    out.extend_from_slice(b"</variable_types><varnames>");
+    for name in ["target", "income", "region", "opened", "note"] {
+        let mut field = name.as_bytes().to_vec();
+        field.resize(129, 0);
+        out.extend_from_slice(&field);
+    }
+    out.extend_from_slice(b"</varnames><sortlist>");
+    out.extend_from_slice(&[0; 6 * 2]);
+    out.extend_from_slice(b"</sortlist><formats>");
+    for format in ["%8.0g", "%10.0g", "%12s", "%td", "%9s"] {
+        let mut field = format.as_bytes().to_vec();
+        field.resize(57, 0);
+        out.extend_from_slice(&field);
+    }
+    out.extend_from_slice(b"</formats><value_label_names>");
+    out.extend_from_slice(&[0; 5 * 129]);
+    out.extend_from_slice(b"</value_label_names><variable_labels>");
+    out.extend_from_slice(&[0; 5 * 321]);
+    out.extend_from_slice(b"</variable_labels><characteristics></characteristics>");
+
+    let data_at = out.len();
+    out.extend_from_slice(b"<data>");
+    for i in 0..ROWS {
+        // Byte 101 is the missing code `.`
+        out.push(if i == 4 { 101 } else { (i % 2) as u8 });
+        let income = income(i).unwrap_or(f64::from_bits(0x7fe0_0000_0000_0000));
+        out.extend_from_slice(&income.to_le_bytes());
+        let mut region = REGIONS[i % 3].as_bytes().to_vec();
+        region.resize(12, 0);
+        out.extend_from_slice(&region);
+        out.extend_from_slice(&(opened_day(i) + 3_653).to_le_bytes());
+        // strL reference: v = 5 (variable) in the low 2 bytes, o = row + 1
+        let packed = if i % 4 == 0 {
+            0
+        } else {
+            5 | ((i as u64 + 1) << 16)
+        };
+        out.extend_from_slice(&packed.to_le_bytes());
+    }
+    out.extend_from_slice(b"</data>");
+
+    let strls_at = out.len();
+    out.extend_from_slice(b"<strls>");
+    for i in (0..ROWS).filter(|i| i % 4 != 0) {
+        let text = format!("note for row {}\0", i);
+        out.extend_from_slice(b"GSO");
+        out.extend_from_slice(&5u32.to_le_bytes());
+        out.extend_from_slice(&(i as u64 + 1).to_le_bytes());
+        out.push(130);
+        out.extend_from_slice(&(text.len() as u32).to_le_bytes());
+        out.extend_from_slice(text.as_bytes());
+    }
+    out.extend_from_slice(b"</strls><value_labels></value_labels></stata_dta>");
+
+    for (slot, offset) in [(9, data_at), (10, strls_at)] {
+        let at = map_at + slot * 8;
+        out[at..at + 8].copy_from_slice(&(offset as u64).to_le_bytes());
+    }
+    std::fs::write(path, out).unwrap();
+}
+
+/// Release 114 (Stata 10), big-endian: target (int) and score (float)
+fn write_dta_114(path: &Path) {
+    let mut out = vec![114, 1, 1, 0];
+    out.extend_from_slice(&2u16.to_be_bytes());
+    out.extend_from_slice(&(ROWS as u32).to_be_bytes());
+    out.extend_from_slice(&[0; 81 + 18]);
+    out.extend_from_slice(&[252, 254]);
+    for name in ["target", "score"] {
+        let mut field = name.as_bytes().to_vec();
+        field.resize(33, 0);
+        out.extend_from_slice(&field);
+    }
+    out.extend_from_slice(&[0; 3 * 2]);
+    for _ in 0..2 {
+        let mut field = b"%9.0g".to_vec();
+        field.resize(49, 0);
+        out.extend_from_slice(&field);
+    }
+    out.extend_from_slice(&[0; 2 * (33 + 81)]);
+    out.extend_from_slice(&[0; 5]); // end of expansion fields
+    for i in 0..ROWS {
+        out.extend_from_slice(&((i % 2) as i16).to_be_bytes());
+        let score = if i == 2 {
+            f32::from_bits(0x7f00_0000)
+        } else {
+            i as f32 * 0.5
+        };
+        out.extend_from_slice(&score.to_be_bytes());
+    }
+    std::fs::write(path, out).unwrap();
+}
+
+fn reduce(input: &Path, target: &str) -> PathBuf {
+    let dir = input.parent().unwrap();
+    let config = dir.join("pipeline.toml");
+    std::fs::write(&config, "use_solver = false\n").unwrap();
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--config")
+        .arg(&config)
+        .arg("--input")
+        .arg(input)
+        .args(["--target", target, "--no-confirm", "--gini-threshold", "0"])
+        .assert()
+        .success();
+    let stem = input.file_stem().unwrap().to_str().unwrap();
+    dir.join(format!("{}_reduced.parquet", stem))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_load_sav_uncompressed_and_compressed() {
+    let dir = TempDir::new().unwrap();
+    let plain = dir.path().join("plain.sav");
+    let packed = dir.path().join("packed.sav");
+    write_sav(&plain, false);
+    write_sav(&packed, true);
+
+    assert_eq!(
+        get_column_names(&plain).unwrap(),
+        vec!["TARGET", "INCOME", "REGION", "OPENED", "customer_segment"]
+    );
+
+    let (df, rows, cols, _) = load_dataset_with_progress(&plain, 0).unwrap();
+    assert_eq!((rows, cols), (ROWS, 5));
+    assert_eq!(df.column("TARGET").unwrap().dtype(), &DataType::Float64);
+    assert_eq!(df.column("OPENED").unwrap().dtype(), &DataType::Date);
+
+    // System-missing and the declared user-missing value -99 are null
+    let income = df.column("INCOME").unwrap().f64().unwrap();
+    assert_eq!(income.get(3), None);
+    assert_eq!(income.get(5), None);
+    assert_eq!(income.get(1), Some(1_012.5));
+
+    // Strings spanning a continuation record are joined and trimmed
+    let region = df.column("REGION").unwrap().str().unwrap();
+    assert_eq!(region.get(2), Some("central-east"));
+    let segment = df.column("customer_segment").unwrap().str().unwrap();
+    assert_eq!(segment.get(0), Some("A"));
+    assert_eq!(segment.get(1), None);
+
+    let opened = df.column("OPENED").unwrap().cast(&DataType::Int32).unwrap();
+    assert_eq!(opened.i32().unwrap().get(7), Some(opened_day(7)));
+
+    let (compressed, _, _, _) = load_dataset_with_progress(&packed, 0).unwrap();
+    assert!(compressed.equals_missing(&df));
+}
+
+#[test]
+fn test_load_dta_118_with_strls() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("loans.dta");
+    write_dta_118(&path);
+
+    assert_eq!(
+        get_column_names(&path).unwrap(),
+        vec!["target", "income", "region", "opened", "note"]
+    );
+    let (df, rows, _, _) = load_dataset_with_progress(&path, 0).unwrap();
+    assert_eq!(rows, ROWS);
+    assert_eq!(df.column("target").unwrap().dtype(), &DataType::Int8);
+    assert_eq!(df.column("income").unwrap().dtype(), &DataType::Float64);
+    assert_eq!(df.column("opened").unwrap().dtype(), &DataType::Date);
+
+    // Stata missing codes load as null
+    assert_eq!(df.column("target").unwrap().i8().unwrap().get(4), None);
+    assert_eq!(df.column("income").unwrap().f64().unwrap().get(3), None);
+
+    let region = df.column("region").unwrap().str().unwrap();
+    assert_eq!(region.get(2), Some("central-east"));
+    let opened = df.column("opened").unwrap().cast(&DataType::Int32).unwrap();
+    assert_eq!(opened.i32().unwrap().get(0), Some(opened_day(0)));
+    let note = df.column("note").unwrap().str().unwrap();
+    assert_eq!(note.get(0), None);
+    assert_eq!(note.get(5), Some("note for row 5"));
+}
+
+#[test]
+fn test_load_dta_114_big_endian() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("old.dta");
+    write_dta_114(&path);
+
+    let (df, _, _, _) = load_dataset_with_progress(&path, 0).unwrap();
+    assert_eq!(df.column("target").unwrap().dtype(), &DataType::Int16);
+    let score = df.column("score").unwrap().f32().unwrap();
+    assert_eq!(score.get(3), Some(1.5));
+    assert_eq!(score.get(2), None);
+
+    // Files that are not Stata datasets are rejected
+    std::fs::write(&path, b"not a stata file").unwrap();
+    assert!(load_dataset_with_progress(&path, 0).is_err());
+}
+
+#[test]
+fn test_convert_sav_and_dta_to_parquet() {
+    let dir = TempDir::new().unwrap();
+    let sav = dir.path().join("survey.sav");
+    let dta = dir.path().join("panel.dta");
+    write_sav(&sav, true);
+    write_dta_118(&dta);
+
+    for (input, output) in [(&sav, "survey.parquet"), (&dta, "panel.parquet")] {
+        run_convert(input, None, 1000, None, false).unwrap();
+        let parquet = LazyFrame::scan_parquet(dir.path().join(output), Default::default())
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert_eq!(parquet.height(), ROWS, "{}", output);
+    }
+}
+
+#[test]
+fn test_cli_reduces_sav_and_dta_to_parquet() {
+    let dir = TempDir::new().unwrap();
+    let dta = dir.path().join("panel.dta");
+    let sav = dir.path().join("survey.sav");
+    write_dta_118(&dta);
+    write_sav(&sav, true);
+
+    // Neither format can be written back, so the reduced output is Parquet
+    for (input, target) in [(&dta, "target"), (&sav, "TARGET")] {
+        let reduced = LazyFrame::scan_parquet(reduce(input, target), Default::default())
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert_eq!(reduced.height(), ROWS);
+        assert_has_columns(&reduced, &[target]);
+    }
+}
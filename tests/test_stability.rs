@@ -0,0 +1,217 @@
+//! Integration tests for the train/validation IV stability check (`--iv-stability`)
+
+use assert_cmd::Command;
+use polars::prelude::{df, CsvReadOptions, DataFrame, SerReader};
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 800 rows split TRAIN/TEST in halves with a 25% event rate. `stable`
+/// separates the classes the same way on both halves; `drifting` only on the
+/// training rows and not at all on the test rows.
+fn drift_df() -> DataFrame {
+    let n = 800;
+    let target: Vec<i32> = (0..n).map(|i| i32::from(i % 4 == 0)).collect();
+    let stable: Vec<f64> = (0..n)
+        .map(|i| (i % 3) as f64 + if i % 4 == 0 { 1.5 } else { 0.0 })
+        .collect();
+    let drifting: Vec<f64> = (0..n)
+        .map(|i| {
+            let noise = (i % 5) as f64;
+            if i < n / 2 && i % 4 == 0 {
+                noise + 10.0
+            } else {
+                noise
+            }
+        })
+        .collect();
+    let sample: Vec<&str> = (0..n)
+        .map(|i| if i < n / 2 { "TRAIN" } else { "TEST" })
+        .collect();
+    df! {
+        "target" => target,
+        "stable" => stable,
+        "drifting" => drifting,
+        "sample" => sample,
+    }
+    .unwrap()
+}
+
+fn run_lophi(dir: &std::path::Path, input: &std::path::Path, args: &[&str]) {
+    let config = dir.join("pipeline.toml");
+    std::fs::write(&config, "use_solver = false\n").unwrap();
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--config")
+        .arg(&config)
+        .arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--no-confirm"])
+        // `stable` and `drifting` both follow the target on the training rows
+        .args(["--correlation-threshold", "0.99"])
+        .args(args)
+        .assert()
+        .success();
+}
+
+fn read_report_json(dir: &std::path::Path) -> serde_json::Value {
+    use std::io::Read;
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_reduction_report.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+fn stability_entry<'a>(report: &'a serde_json::Value, feature: &str) -> &'a serde_json::Value {
+    report["iv_stability"]["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["feature"] == feature)
+        .unwrap_or_else(|| panic!("no stability entry for {}", feature))
+}
+
+fn feature_entry<'a>(report: &'a serde_json::Value, feature: &str) -> &'a serde_json::Value {
+    report["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["name"] == feature)
+        .unwrap_or_else(|| panic!("no report entry for {}", feature))
+}
+
+fn reduced_columns(dir: &std::path::Path) -> Vec<String> {
+    CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(dir.join("test_data_reduced.csv")))
+        .unwrap()
+        .finish()
+        .unwrap()
+        .get_column_names_str()
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_split_column_flags_unstable_iv() {
+    let mut df = drift_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    run_lophi(
+        dir.path(),
+        &input,
+        &[
+            "--split-column",
+            "sample",
+            "--train-value",
+            "TRAIN",
+            "--iv-stability",
+        ],
+    );
+
+    let report = read_report_json(dir.path());
+    let section = &report["iv_stability"];
+    assert_eq!(section["split"], "split_column");
+    assert!(section["validation_fraction"].is_null());
+    assert_eq!(section["train_rows"], 400);
+    assert_eq!(section["validation_rows"], 400);
+    assert_eq!(section["drop_unstable"], false);
+
+    let drifting = stability_entry(&report, "drifting");
+    assert_eq!(drifting["unstable"], true);
+    assert!(drifting["validation_iv"].as_f64().unwrap() < 0.01);
+    assert_eq!(stability_entry(&report, "stable")["unstable"], false);
+
+    // Flagged only: the feature is still written out
+    assert!(reduced_columns(dir.path()).contains(&"drifting".to_string()));
+}
+
+#[test]
+fn test_stability_threshold_drops_unstable_features() {
+    let mut df = drift_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    run_lophi(
+        dir.path(),
+        &input,
+        &[
+            "--split-column",
+            "sample",
+            "--train-value",
+            "TRAIN",
+            "--iv-stability-threshold",
+            "0.5",
+        ],
+    );
+
+    let columns = reduced_columns(dir.path());
+    assert!(!columns.contains(&"drifting".to_string()));
+    assert!(columns.contains(&"stable".to_string()));
+
+    let report = read_report_json(dir.path());
+    assert_eq!(report["iv_stability"]["drop_unstable"], true);
+    let drifting = feature_entry(&report, "drifting");
+    assert_eq!(drifting["status"], "dropped");
+    assert_eq!(drifting["dropped_at_stage"], "gini");
+    assert!(drifting["reason"]
+        .as_str()
+        .unwrap()
+        .starts_with("IV unstable between train and validation rows"));
+    assert_eq!(report["summary"]["by_stage"]["gini"]["dropped"], 1);
+}
+
+#[test]
+fn test_random_validation_split() {
+    let mut df = drift_df().drop("sample").unwrap();
+    let (dir, input) = create_temp_csv(&mut df);
+    run_lophi(
+        dir.path(),
+        &input,
+        &["--iv-stability", "--validation-frac", "0.25"],
+    );
+
+    let report = read_report_json(dir.path());
+    let section = &report["iv_stability"];
+    assert_eq!(section["split"], "random");
+    assert_eq!(section["validation_fraction"], 0.25);
+    assert_eq!(section["train_rows"], 600);
+    assert_eq!(section["validation_rows"], 200);
+    assert_eq!(section["features"].as_array().unwrap().len(), 2);
+    assert_eq!(stability_entry(&report, "stable")["unstable"], false);
+}
+
+#[test]
+fn test_iv_stability_rejects_continuous_target() {
+    let mut df = df! {
+        "target" => (0..50).map(|i| i as f64 * 1.5).collect::<Vec<_>>(),
+        "x" => (0..50).map(|i| (i % 7) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap();
+    let (_dir, input) = create_temp_csv(&mut df);
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args([
+            "--target",
+            "target",
+            "--target-type",
+            "continuous",
+            "--no-confirm",
+            "--iv-stability",
+        ])
+        .assert()
+        .failure();
+}
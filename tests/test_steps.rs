@@ -0,0 +1,168 @@
+//! Integration tests for step selection and ordering (`--steps`, `--skip-gini`)
+
+use std::io::Read;
+
+use assert_cmd::Command;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 400 rows with a 25% event rate. `score` separates the classes,
+/// `score_copy` is a linear copy of it that is null on half the rows and
+/// `noise` is unrelated to the target.
+fn steps_df() -> DataFrame {
+    let n = 400;
+    let event = |i: usize| i.is_multiple_of(4);
+    let score = |i: usize| ((i / 4) % 9) as f64 + if event(i) { 5.0 } else { 0.0 };
+    df! {
+        "target" => (0..n).map(|i| i32::from(event(i))).collect::<Vec<_>>(),
+        "score" => (0..n).map(score).collect::<Vec<_>>(),
+        "score_copy" => (0..n)
+            .map(|i| ((i / 4) % 2 == 1).then_some(score(i) * 2.0 + 1.0))
+            .collect::<Vec<_>>(),
+        "noise" => (0..n).map(|i| ((i / 4) % 7) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn run_lophi(input: &std::path::Path, args: &[&str]) {
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--binning-backend", "greedy"])
+        .args(["--missing-threshold", "0.3", "--gini-threshold", "0.05"])
+        .args(["--correlation-threshold", "0.9"])
+        .args(args)
+        .assert()
+        .success();
+}
+
+fn zip_entries(dir: &std::path::Path) -> Vec<String> {
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let archive = ::zip::ZipArchive::new(file).unwrap();
+    archive.file_names().map(String::from).collect()
+}
+
+fn read_report_json(dir: &std::path::Path) -> serde_json::Value {
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_reduction_report.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+fn dropped_at(report: &serde_json::Value, feature: &str) -> serde_json::Value {
+    report["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["name"] == feature)
+        .unwrap_or_else(|| panic!("no report entry for {}", feature))["dropped_at_stage"]
+        .clone()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_default_steps_are_not_recorded() {
+    let mut df = steps_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    run_lophi(&input, &[]);
+
+    let report = read_report_json(dir.path());
+    assert!(report["metadata"]["settings"].get("steps").is_none());
+    assert_eq!(dropped_at(&report, "score_copy"), "missing");
+    assert_eq!(dropped_at(&report, "noise"), "gini");
+    assert!(zip_entries(dir.path()).contains(&"test_data_gini_analysis.json".to_string()));
+}
+
+#[test]
+fn test_skip_gini_keeps_weak_features_and_omits_the_gini_export() {
+    let mut df = steps_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    run_lophi(&input, &["--skip-gini"]);
+
+    let report = read_report_json(dir.path());
+    let steps = &report["metadata"]["settings"]["steps"];
+    assert_eq!(
+        steps["order"],
+        serde_json::json!(["missing", "correlation"])
+    );
+    assert_eq!(steps["skipped"], serde_json::json!(["gini"]));
+    assert!(report["summary"]["by_stage"].get("gini").is_none());
+    assert!(dropped_at(&report, "noise").is_null());
+    assert_eq!(dropped_at(&report, "score_copy"), "missing");
+    assert_eq!(report["features"].as_array().unwrap().len(), 3);
+
+    let entries = zip_entries(dir.path());
+    assert!(
+        !entries.iter().any(|e| e.contains("gini_analysis")),
+        "{:?}",
+        entries
+    );
+    assert!(entries.contains(&"test_data_reduction_report.json".to_string()));
+}
+
+#[test]
+fn test_steps_run_in_the_given_order() {
+    let mut df = steps_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    run_lophi(&input, &["--steps", "correlation,missing"]);
+
+    let report = read_report_json(dir.path());
+    let steps = &report["metadata"]["settings"]["steps"];
+    assert_eq!(
+        steps["order"],
+        serde_json::json!(["correlation", "missing"])
+    );
+    assert_eq!(steps["skipped"], serde_json::json!(["gini"]));
+
+    // The copy is resolved against `score` before missing analysis sees it
+    assert_eq!(report["summary"]["by_stage"]["correlation"]["dropped"], 1);
+    let copies: Vec<_> = ["score", "score_copy"]
+        .iter()
+        .map(|f| dropped_at(&report, f))
+        .collect();
+    assert!(copies.contains(&"correlation".into()), "{:?}", copies);
+    // Every feature is listed, including those dropped before missing analysis
+    assert_eq!(report["features"].as_array().unwrap().len(), 3);
+}
+
+#[test]
+fn test_invalid_step_lists_are_rejected() {
+    let mut df = steps_df();
+    let (_dir, input) = create_temp_parquet(&mut df);
+    for (args, message) in [
+        (vec!["--steps", "missing,woe"], "Unknown step: 'woe'"),
+        (vec!["--steps", "gini,gini"], "listed more than once"),
+        (
+            vec!["--steps", "gini", "--skip-gini"],
+            "No steps left to run",
+        ),
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_lophi"))
+            .arg("--input")
+            .arg(&input)
+            .args(["--target", "target", "--no-confirm"])
+            .args(&args)
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "{:?} should fail", args);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains(message), "{:?}: {}", args, stderr);
+    }
+}
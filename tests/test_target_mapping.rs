@@ -1,8 +1,13 @@
 //! Tests for target column mapping functionality
 
+use assert_cmd::Command;
 use lophi::pipeline::*;
 use polars::prelude::*;
 
+mod common;
+
+use common::create_temp_csv;
+
 /// Create a DataFrame with string target values ("G" for good, "B" for bad)
 fn create_string_target_dataframe() -> DataFrame {
     df! {
@@ -164,6 +169,9 @@ fn test_iv_analysis_with_string_target_mapping() {
         &weights,
         None,
         None,
+        None,
+        MissingBinPolicy::Separate,
+        None,
     );
     assert!(
         result.is_ok(),
@@ -202,6 +210,9 @@ fn test_iv_analysis_with_multivalue_target_ignores_unknown() {
         &weights,
         None,
         None,
+        None,
+        MissingBinPolicy::Separate,
+        None,
     );
     assert!(
         result.is_ok(),
@@ -236,6 +247,9 @@ fn test_iv_analysis_without_mapping_on_binary_target() {
         &weights,
         None,
         None,
+        None,
+        MissingBinPolicy::Separate,
+        None,
     );
     assert!(
         result.is_ok(),
@@ -355,3 +369,86 @@ fn test_count_mapped_records_empty_series() {
     assert_eq!(non_events, 0);
     assert_eq!(ignored, 0);
 }
+
+#[test]
+fn test_write_mapped_target_keeps_raw_values() {
+    let mut df = create_multivalue_target_dataframe();
+    let mapping = TargetMapping::new("bad".to_string(), "good".to_string());
+
+    write_mapped_target(&mut df, "target", &mapping).unwrap();
+
+    assert_eq!(
+        df.get_column_names(),
+        ["target", "target_raw", "feature1", "feature2"]
+    );
+    let mapped: Vec<Option<i32>> = df
+        .column("target")
+        .unwrap()
+        .i32()
+        .unwrap()
+        .into_iter()
+        .collect();
+    assert_eq!(&mapped[..3], &[Some(0), Some(1), None]);
+    let raw = df.column("target_raw").unwrap().str().unwrap();
+    assert_eq!(raw.get(2), Some("unknown"));
+}
+
+#[test]
+fn test_write_mapped_target_rejects_existing_raw_column() {
+    let mut df = create_string_target_dataframe();
+    df.with_column(Column::new("target_raw".into(), vec![0i32; 20]))
+        .unwrap();
+    let mapping = TargetMapping::new("B".to_string(), "G".to_string());
+
+    let err = write_mapped_target(&mut df, "target", &mapping)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("'target_raw'"), "{}", err);
+}
+
+#[test]
+fn test_cli_output_include_target_mapping() {
+    let mut df = create_multivalue_target_dataframe();
+    let (dir, input) = create_temp_csv(&mut df);
+    let config = dir.path().join("pipeline.toml");
+    std::fs::write(
+        &config,
+        "use_solver = false\ngini_threshold = 0.0\ncorrelation_threshold = 0.99\n",
+    )
+    .unwrap();
+    let output = dir.path().join("out.csv");
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--config")
+        .arg(&config)
+        .arg("--input")
+        .arg(&input)
+        .arg("--output")
+        .arg(&output)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--event-value", "bad", "--non-event-value", "good"])
+        .arg("--output-include-target-mapping")
+        .assert()
+        .success();
+
+    let reduced = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(output))
+        .unwrap()
+        .finish()
+        .unwrap();
+    let names = reduced.get_column_names();
+    assert_eq!(names[0].as_str(), "target");
+    assert_eq!(names[1].as_str(), "target_raw");
+    let mapped: Vec<Option<i64>> = reduced
+        .column("target")
+        .unwrap()
+        .i64()
+        .unwrap()
+        .into_iter()
+        .collect();
+    assert_eq!(&mapped[..3], &[Some(0), Some(1), None]);
+    assert_eq!(
+        reduced.column("target_raw").unwrap().str().unwrap().get(1),
+        Some("bad")
+    );
+}
@@ -0,0 +1,222 @@
+//! Tests for the tighten subcommand: stricter cuts from a saved report
+
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+use lophi::pipeline::{
+    analyze_features_iv, apply_tightening, plan_tightening, BinningStrategy, MissingBinPolicy,
+    PriorReduction, TightenMetric, TightenThresholds,
+};
+use lophi::report::{
+    export_reduction_report, DropStage, ReductionReportBuilder, ReportBuilderParams,
+};
+use polars::prelude::*;
+use tempfile::TempDir;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+fn training_df() -> DataFrame {
+    df! {
+        "target" => [0i32, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1],
+        "income" => [1.0f64, 8.0, 2.0, 9.0, 3.0, 10.0, 4.0, 11.0, 5.0, 12.0, 6.0, 13.0],
+        "tenure" => [1.0f64, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0, 5.0, 5.0, 6.0, 6.0, 1.0],
+        "sparse" => [Some(1.0f64), Some(2.0), None, None, Some(2.0), Some(1.0), None, Some(1.0), Some(1.0), Some(2.0), Some(2.0), Some(1.0)],
+    }
+    .unwrap()
+}
+
+/// Write the reduction report of a run that kept every feature
+fn write_report(dir: &Path) -> PathBuf {
+    let df = training_df();
+    let weights = vec![1.0; df.height()];
+    let analyses = analyze_features_iv(
+        &df,
+        "target",
+        4,
+        8,
+        None,
+        BinningStrategy::Quantile,
+        Some(1),
+        None,
+        &weights,
+        None,
+        None,
+        None,
+        MissingBinPolicy::Separate,
+        None,
+    )
+    .unwrap();
+
+    let mut builder = ReductionReportBuilder::new(ReportBuilderParams {
+        input_file: "train.csv".to_string(),
+        output_file: "train_reduced.csv".to_string(),
+        target_column: "target".to_string(),
+        weight_column: None,
+        binning_strategy: "quantile".to_string(),
+        num_bins: 4,
+        missing_threshold: 0.5,
+        gini_threshold: 0.0,
+        correlation_threshold: 0.9,
+    });
+    builder.set_missing_results(
+        &[
+            ("income".to_string(), 0.0),
+            ("tenure".to_string(), 0.0),
+            ("sparse".to_string(), 0.25),
+        ],
+        &[],
+    );
+    builder.set_gini_results(&analyses, &[]);
+    builder.set_correlation_results(&[], &[]);
+    let path = dir.join("train_reduction_report.json");
+    export_reduction_report(&builder.build(), &path).unwrap();
+    path
+}
+
+fn gini_of(prior: &PriorReduction, name: &str) -> f64 {
+    prior
+        .features
+        .iter()
+        .find(|f| f.name == name)
+        .and_then(|f| f.univariate)
+        .unwrap()
+}
+
+#[test]
+fn test_tighten_drops_features_failing_the_stricter_cut() {
+    let dir = TempDir::new().unwrap();
+    let report_path = write_report(dir.path());
+    let prior = PriorReduction::load(&report_path).unwrap();
+    assert_eq!(prior.metric, TightenMetric::Gini);
+    assert_eq!(prior.features.len(), 3);
+
+    // A Gini cut between tenure and income
+    let (income, tenure) = (gini_of(&prior, "income"), gini_of(&prior, "tenure"));
+    assert!(income > tenure);
+    let delta = plan_tightening(
+        &prior,
+        TightenThresholds {
+            missing: Some(0.2),
+            gini: Some((income + tenure) / 2.0),
+        },
+        &report_path,
+    )
+    .unwrap();
+
+    assert_eq!(delta.kept, vec!["income".to_string()]);
+    let dropped: Vec<(&str, &DropStage)> = delta
+        .dropped
+        .iter()
+        .map(|d| (d.name.as_str(), &d.stage))
+        .collect();
+    assert_eq!(dropped.len(), 2);
+    for (name, stage) in dropped {
+        match name {
+            "sparse" => assert!(matches!(stage, DropStage::Missing)),
+            "tenure" => assert!(matches!(stage, DropStage::Gini)),
+            other => panic!("unexpected drop {}", other),
+        }
+    }
+    assert_eq!(delta.missing_threshold.previous, 0.5);
+    assert_eq!(delta.missing_threshold.current, 0.2);
+
+    let tightened = apply_tightening(training_df(), &delta);
+    assert_has_columns(&tightened, &["target", "income"]);
+    assert_missing_columns(&tightened, &["tenure", "sparse"]);
+}
+
+#[test]
+fn test_tighten_rejects_looser_thresholds() {
+    let dir = TempDir::new().unwrap();
+    let report_path = write_report(dir.path());
+    let prior = PriorReduction::load(&report_path).unwrap();
+
+    let looser_missing = TightenThresholds {
+        missing: Some(0.6),
+        gini: None,
+    };
+    assert!(plan_tightening(&prior, looser_missing, &report_path).is_err());
+    let looser_gini = TightenThresholds {
+        missing: None,
+        gini: Some(-0.1),
+    };
+    assert!(plan_tightening(&prior, looser_gini, &report_path).is_err());
+
+    // Unchanged thresholds drop nothing
+    let delta = plan_tightening(&prior, TightenThresholds::default(), &report_path).unwrap();
+    assert!(delta.dropped.is_empty());
+    assert_eq!(delta.kept.len(), 3);
+}
+
+#[test]
+fn test_cli_tighten_reduced_output() {
+    let mut df = training_df();
+    let (dir, input) = create_temp_csv(&mut df);
+    let output = dir.path().join("reduced.csv");
+    let config = dir.path().join("pipeline.toml");
+    std::fs::write(&config, "use_solver = false\n").unwrap();
+
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--config")
+        .arg(&config)
+        .arg("--input")
+        .arg(&input)
+        .arg("--output")
+        .arg(&output)
+        .args([
+            "--target",
+            "target",
+            "--no-confirm",
+            "--missing-threshold",
+            "0.5",
+            "--gini-threshold",
+            "0.0",
+            "--correlation-threshold",
+            "0.99",
+        ])
+        .assert()
+        .success();
+    let report = dir.path().join("test_data_reduction_report.zip");
+    assert!(report.exists());
+
+    let tightened = dir.path().join("tight.csv");
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("tighten")
+        .arg("--report")
+        .arg(&report)
+        .arg("--input")
+        .arg(&output)
+        .arg("--output")
+        .arg(&tightened)
+        .args(["--missing-threshold", "0.2"])
+        .assert()
+        .success();
+
+    let result = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(tightened))
+        .unwrap()
+        .finish()
+        .unwrap();
+    assert_missing_columns(&result, &["sparse"]);
+    assert_has_columns(&result, &["target", "income"]);
+
+    let delta: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(dir.path().join("tight_tighten_report.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(delta["dropped"][0]["name"], "sparse");
+    assert_eq!(delta["dropped"][0]["stage"], "missing");
+
+    // Nothing to tighten
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("tighten")
+        .arg("--report")
+        .arg(&report)
+        .arg("--input")
+        .arg(&output)
+        .assert()
+        .failure();
+}
@@ -0,0 +1,184 @@
+//! Integration tests for the per-period stability check (`--time-column`)
+
+use assert_cmd::Command;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 900 applications over January to March 2024, 300 a month, with a 25% event
+/// rate. `stable` separates the classes the same way every month; `fading`
+/// too, but it is missing on 0%, 30% and 60% of the rows month by month;
+/// `weakening` separates the classes in January and not at all in March.
+fn monthly_df() -> DataFrame {
+    let n = 900;
+    let month_starts = [19_723, 19_754, 19_783]; // 2024-01-01, 02-01, 03-01
+    let month = |i: i32| (i / 300) as usize;
+    let event = |i: i32| i % 4 == 0;
+    let app_date = Series::new(
+        "app_date".into(),
+        (0..n)
+            .map(|i| month_starts[month(i)] + i % 28)
+            .collect::<Vec<_>>(),
+    )
+    .cast(&DataType::Date)
+    .unwrap();
+    let stable: Vec<f64> = (0..n)
+        .map(|i| (i % 3) as f64 + if event(i) { 1.5 } else { 0.0 })
+        .collect();
+    let fading: Vec<Option<f64>> = (0..n)
+        .map(|i| {
+            let missing = (i % 10) < 3 * month(i) as i32;
+            (!missing).then(|| (i % 5) as f64 + if event(i) { 2.5 } else { 0.0 })
+        })
+        .collect();
+    let weakening: Vec<f64> = (0..n)
+        .map(|i| {
+            let signal = [10.0, 5.0, 0.0][month(i)];
+            (i % 7) as f64 + if event(i) { signal } else { 0.0 }
+        })
+        .collect();
+    DataFrame::new(vec![
+        Column::new(
+            "target".into(),
+            (0..n).map(|i| i32::from(event(i))).collect::<Vec<_>>(),
+        ),
+        app_date.into(),
+        Column::new("stable".into(), stable),
+        Column::new("fading".into(), fading),
+        Column::new("weakening".into(), weakening),
+    ])
+    .unwrap()
+}
+
+fn lophi(input: &std::path::Path, args: &[&str]) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("--input")
+        .arg(input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--binning-backend", "greedy"])
+        .args(["--missing-threshold", "0.9", "--gini-threshold", "0.0"])
+        .args(["--correlation-threshold", "1.0"])
+        .args(args);
+    cmd
+}
+
+fn read_report_json(dir: &std::path::Path) -> serde_json::Value {
+    use std::io::Read;
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_reduction_report.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+fn drift_entry<'a>(report: &'a serde_json::Value, feature: &str) -> &'a serde_json::Value {
+    report["time_stability"]["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["feature"] == feature)
+        .unwrap_or_else(|| panic!("no time stability entry for {}", feature))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_time_column_flags_drifting_features() {
+    let mut df = monthly_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    lophi(&input, &["--time-column", "app_date"])
+        .assert()
+        .success();
+
+    let report = read_report_json(dir.path());
+    let section = &report["time_stability"];
+    assert_eq!(section["column"], "app_date");
+    assert_eq!(section["freq"], "month");
+    assert_eq!(section["undated_rows"], 0);
+    let periods = section["periods"].as_array().unwrap();
+    let labels: Vec<&str> = periods
+        .iter()
+        .map(|p| p["period"].as_str().unwrap())
+        .collect();
+    assert_eq!(labels, ["2024-01", "2024-02", "2024-03"]);
+    assert!(periods
+        .iter()
+        .all(|p| p["rows"] == 300 && p["scored"] == true));
+
+    assert_eq!(drift_entry(&report, "stable")["drifting"], false);
+
+    let fading = drift_entry(&report, "fading");
+    assert_eq!(fading["drifting"], true);
+    assert!((fading["fill_rate_change"].as_f64().unwrap() + 0.6).abs() < 1e-9);
+    let missing: Vec<f64> = fading["periods"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["missing_ratio"].as_f64().unwrap())
+        .collect();
+    assert_eq!(missing, [0.0, 0.3, 0.6]);
+
+    let weakening = drift_entry(&report, "weakening");
+    assert_eq!(weakening["drifting"], true);
+    assert!(weakening["iv_change"].as_f64().unwrap() < -0.5);
+
+    // Flagged only, and the time column itself rides through
+    let file = std::fs::File::open(dir.path().join("test_data_reduced.parquet")).unwrap();
+    let output = ParquetReader::new(file).finish().unwrap();
+    for column in ["app_date", "fading", "weakening"] {
+        assert!(output.column(column).is_ok(), "{} missing", column);
+    }
+}
+
+#[test]
+fn test_time_column_needs_two_periods() {
+    let mut df = monthly_df();
+    let (_dir, input) = create_temp_parquet(&mut df);
+    let output = lophi(
+        &input,
+        &["--time-column", "app_date", "--time-freq", "quarter"],
+    )
+    .assert()
+    .failure()
+    .get_output()
+    .clone();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("spans 1 quarter period(s)"), "{}", stderr);
+}
+
+#[test]
+fn test_time_column_must_be_a_date() {
+    let mut df = monthly_df();
+    let (_dir, input) = create_temp_parquet(&mut df);
+    let output = lophi(&input, &["--time-column", "stable"])
+        .assert()
+        .failure()
+        .get_output()
+        .clone();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("must be a date or datetime column"),
+        "{}",
+        stderr
+    );
+
+    lophi(
+        &input,
+        &["--time-column", "app_date", "--time-freq", "week"],
+    )
+    .assert()
+    .failure();
+}
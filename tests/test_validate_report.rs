@@ -0,0 +1,95 @@
+//! Integration tests for the report `schema_version` and `validate-report`
+
+use assert_cmd::Command;
+use lophi::report::{validate_report, validate_report_file, REPORT_SCHEMA_VERSION};
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+/// Reduce the binary-target fixture and return its directory and report zip
+fn reduced_report() -> (tempfile::TempDir, std::path::PathBuf) {
+    let mut df = create_binary_target_dataframe();
+    let (dir, input) = create_temp_parquet(&mut df);
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target", "--binning-backend", "greedy"])
+        .arg("--no-confirm")
+        .assert()
+        .success();
+    let zip = dir.path().join("test_data_reduction_report.zip");
+    assert!(zip.exists());
+    (dir, zip)
+}
+
+fn validate(report: &std::path::Path) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_lophi"));
+    cmd.arg("validate-report").arg(report).env("NO_COLOR", "1");
+    cmd
+}
+
+/// The report JSON inside a packaged zip
+fn report_json(zip: &std::path::Path) -> serde_json::Value {
+    use std::io::Read;
+    let mut archive = ::zip::ZipArchive::new(std::fs::File::open(zip).unwrap()).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name("test_data_reduction_report.json")
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+#[test]
+fn test_pipeline_report_is_versioned_and_valid() {
+    let (_dir, zip) = reduced_report();
+    assert_eq!(
+        report_json(&zip)["schema_version"],
+        serde_json::json!(REPORT_SCHEMA_VERSION)
+    );
+
+    let validation = validate_report_file(&zip).unwrap();
+    assert!(validation.is_valid(), "{:?}", validation.errors);
+    assert!(validation.warnings.is_empty(), "{:?}", validation.warnings);
+    assert_eq!(validation.schema_version, Some(1));
+
+    let assert = validate(&zip).assert().success();
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout);
+    assert!(stdout.contains("schema_version 1"));
+    assert!(stdout.contains("Report is valid"));
+}
+
+#[test]
+fn test_tampered_report_fails_validation() {
+    let (dir, zip) = reduced_report();
+    let mut report = report_json(&zip);
+    let dropped = report["summary"]["dropped_count"].as_u64().unwrap();
+    report["summary"]["dropped_count"] = serde_json::json!(dropped + 1);
+    report["metadata"]["thresholds"]
+        .as_object_mut()
+        .unwrap()
+        .remove("gini");
+    let tampered = dir.path().join("tampered.json");
+    std::fs::write(&tampered, serde_json::to_string_pretty(&report).unwrap()).unwrap();
+
+    let assert = validate(&tampered).assert().code(2);
+    let output = assert.get_output();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("is not final_features"));
+    assert!(stdout.contains("Missing number 'metadata.thresholds.gini'"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("failed validation"));
+}
+
+#[test]
+fn test_newer_schema_version_is_rejected() {
+    let (_dir, zip) = reduced_report();
+    let mut report = report_json(&zip);
+    report["schema_version"] = serde_json::json!(REPORT_SCHEMA_VERSION + 1);
+
+    let validation = validate_report(&report);
+    assert!(!validation.is_valid());
+    assert!(validation.errors[0].contains("newer than this lophi supports"));
+}
@@ -0,0 +1,235 @@
+//! Property tests for weighted quantile prebinning
+//! (`pipeline::iv::compute_weighted_quantile_prebins`)
+//!
+//! Each property is checked on a few hundred seeded random samples with ties,
+//! uneven weights and bin counts from 2 to 12.
+
+use lophi::pipeline::iv::compute_weighted_quantile_prebins;
+use lophi::pipeline::{
+    analyze_features_iv, analyze_features_r2, BinningStrategy, MissingBinPolicy,
+};
+use polars::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+const CASES: u64 = 300;
+
+/// A random sample: 1-200 values drawn from a small range so ties are
+/// common, weights in (0, 5], and a bin count
+struct Sample {
+    values: Vec<f64>,
+    weights: Vec<f64>,
+    n: usize,
+}
+
+fn sample(seed: u64) -> Sample {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let len = rng.gen_range(1..=200);
+    let distinct = rng.gen_range(1..=60);
+    let values = (0..len)
+        .map(|_| rng.gen_range(0..distinct) as f64 * 0.5 - 7.0)
+        .collect();
+    let weights = (0..len).map(|_| rng.gen_range(0.01..=5.0)).collect();
+    Sample {
+        values,
+        weights,
+        n: rng.gen_range(2..=12),
+    }
+}
+
+fn weight_below(s: &Sample, cut: f64) -> f64 {
+    s.values
+        .iter()
+        .zip(&s.weights)
+        .filter(|(v, _)| **v < cut)
+        .map(|(_, w)| w)
+        .sum()
+}
+
+fn for_each_sample(check: impl Fn(&Sample, &[f64])) {
+    for seed in 0..CASES {
+        let s = sample(seed);
+        let cuts = compute_weighted_quantile_prebins(&s.values, &s.weights, s.n);
+        check(&s, &cuts);
+    }
+}
+
+fn weighted_df(n: usize, seed: u64) -> (DataFrame, Vec<f64>) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let x: Vec<f64> = (0..n).map(|i| i as f64).collect();
+    let target: Vec<i32> = (0..n)
+        .map(|i| i32::from(rng.gen_bool(0.2 + 0.5 * i as f64 / n as f64)))
+        .collect();
+    let y: Vec<f64> = x
+        .iter()
+        .map(|v| v * 0.1 + rng.gen_range(-1.0..1.0))
+        .collect();
+    // Reject-inference style weights: the first quarter of rows count 8 times over
+    let weights = (0..n).map(|i| if i < n / 4 { 8.0 } else { 1.0 }).collect();
+    let df = df! { "target" => target, "y" => y, "x" => x }.unwrap();
+    (df, weights)
+}
+
+// ---------------------------------------------------------------------------
+// Properties of the cut points
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_cuts_are_increasing_sample_values() {
+    for_each_sample(|s, cuts| {
+        assert!(cuts.len() < s.n);
+        assert!(cuts.windows(2).all(|w| w[0] < w[1]));
+        let min = s.values.iter().copied().fold(f64::INFINITY, f64::min);
+        for cut in cuts {
+            assert!(s.values.contains(cut));
+            assert!(*cut > min);
+        }
+    });
+}
+
+#[test]
+fn test_each_cut_follows_its_share_of_the_weight() {
+    for_each_sample(|s, cuts| {
+        let total: f64 = s.weights.iter().sum();
+        for (j, &cut) in cuts.iter().enumerate() {
+            let share = (j + 1) as f64 / s.n as f64;
+            assert!(weight_below(s, cut) >= share * total - 1e-9 * total);
+        }
+    });
+}
+
+#[test]
+fn test_bins_without_their_largest_value_stay_under_one_quantile() {
+    for_each_sample(|s, cuts| {
+        let total: f64 = s.weights.iter().sum();
+        let mut bounds = vec![f64::NEG_INFINITY];
+        bounds.extend_from_slice(cuts);
+        bounds.push(f64::INFINITY);
+        for w in bounds.windows(2) {
+            let in_bin = |v: f64| v >= w[0] && v < w[1];
+            let largest = s
+                .values
+                .iter()
+                .copied()
+                .filter(|&v| in_bin(v))
+                .fold(f64::NEG_INFINITY, f64::max);
+            let weight: f64 = s
+                .values
+                .iter()
+                .zip(&s.weights)
+                .filter(|(v, _)| in_bin(**v) && **v < largest)
+                .map(|(_, w)| w)
+                .sum();
+            assert!(weight < total / s.n as f64 + 1e-9 * total);
+        }
+    });
+}
+
+#[test]
+fn test_cuts_ignore_the_scale_of_the_weights() {
+    for_each_sample(|s, cuts| {
+        let scaled: Vec<f64> = s.weights.iter().map(|w| w * 4.0).collect();
+        assert_eq!(
+            compute_weighted_quantile_prebins(&s.values, &scaled, s.n),
+            cuts
+        );
+    });
+}
+
+#[test]
+fn test_integer_weights_act_like_repeated_rows() {
+    for seed in 0..CASES {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let s = sample(seed);
+        let weights: Vec<f64> = s
+            .values
+            .iter()
+            .map(|_| rng.gen_range(1..=3) as f64)
+            .collect();
+        let repeated: Vec<f64> = s
+            .values
+            .iter()
+            .zip(&weights)
+            .flat_map(|(&v, &w)| std::iter::repeat_n(v, w as usize))
+            .collect();
+        assert_eq!(
+            compute_weighted_quantile_prebins(&s.values, &weights, s.n),
+            compute_weighted_quantile_prebins(&repeated, &vec![1.0; repeated.len()], s.n)
+        );
+    }
+}
+
+#[test]
+fn test_unit_weights_give_equal_bins() {
+    let values: Vec<f64> = (0..120).rev().map(f64::from).collect();
+    let cuts = compute_weighted_quantile_prebins(&values, &[1.0; 120], 6);
+    assert_eq!(cuts, [20.0, 40.0, 60.0, 80.0, 100.0]);
+}
+
+#[test]
+fn test_degenerate_inputs_give_no_cuts() {
+    assert!(compute_weighted_quantile_prebins(&[], &[], 5).is_empty());
+    assert!(compute_weighted_quantile_prebins(&[3.0; 10], &[1.0; 10], 5).is_empty());
+    assert!(compute_weighted_quantile_prebins(&[1.0, 2.0], &[1.0, 1.0], 1).is_empty());
+    assert!(compute_weighted_quantile_prebins(&[1.0, 2.0], &[0.0, 0.0], 2).is_empty());
+    // NaN values are left out
+    assert_eq!(
+        compute_weighted_quantile_prebins(&[1.0, f64::NAN, 2.0], &[1.0, 50.0, 1.0], 2),
+        [2.0]
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Weights in the binning paths
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_quantile_iv_prebins_split_the_weight_evenly() {
+    let (df, weights) = weighted_df(400, 7);
+    let df = df.drop("y").unwrap();
+
+    let analyses = analyze_features_iv(
+        &df,
+        "target",
+        4,
+        4,
+        None,
+        BinningStrategy::Quantile,
+        None,
+        None,
+        &weights,
+        None,
+        None,
+        None,
+        MissingBinPolicy::Separate,
+        None,
+    )
+    .unwrap();
+
+    let bins = &analyses[0].bins;
+    assert_eq!(bins.len(), 4);
+    for bin in bins {
+        // A single row weighs at most 8 / 1100 of the total, under 1%
+        assert!((bin.population_pct - 25.0).abs() < 1.0, "{:?}", bin);
+    }
+    // Unweighted quartiles would cut at 100; the heavy rows pull it to 35
+    assert_eq!(bins[0].upper_bound, 35.0);
+}
+
+#[test]
+fn test_continuous_target_bins_split_the_weight_evenly() {
+    let (df, weights) = weighted_df(400, 11);
+    let df = df.drop("target").unwrap();
+
+    let analyses = analyze_features_r2(&df, "y", 4, None, &weights, None).unwrap();
+
+    let bins = &analyses[0].bins;
+    assert_eq!(bins.len(), 4);
+    for bin in bins {
+        assert!((bin.population_pct - 25.0).abs() < 1.0, "{:?}", bin);
+    }
+}
@@ -9,10 +9,14 @@
 //! DO NOT test TUI rendering or terminal operations here - those require
 //! integration tests with mocked terminal interfaces.
 
+#![cfg(feature = "tui")]
+
 use std::collections::HashSet;
 
 use lophi::cli::wizard::{validate_schema_inference, validate_threshold};
-use lophi::cli::wizard::{WizardData, WizardState, WizardStep, WizardTask};
+use lophi::cli::wizard::{DataPreview, WizardData, WizardState, WizardStep, WizardTask};
+use lophi::pipeline::{TargetAnalysis, TargetMapping};
+use polars::prelude::*;
 
 // ============================================================================
 // T041: Test reduction path step sequencing
@@ -772,3 +776,147 @@ fn test_current_step_returns_none_for_invalid_index() {
     let step = wizard.current_step();
     assert!(step.is_none(), "Should return None for out-of-bounds index");
 }
+
+// ============================================================================
+// Data preview step
+// ============================================================================
+
+#[test]
+fn test_data_preview_lists_dtypes_and_cells() {
+    let df = df!(
+        "income" => [Some(1234.5), None],
+        "region" => ["north", "south"],
+    )
+    .unwrap();
+    let preview = DataPreview::from_frame(&df);
+
+    assert_eq!(
+        preview.columns,
+        vec![
+            ("income".to_string(), "f64".to_string()),
+            ("region".to_string(), "str".to_string()),
+        ]
+    );
+    // Strings are shown unquoted and nulls as blank cells
+    assert_eq!(preview.rows[0], vec!["1234.5", "north"]);
+    assert_eq!(preview.rows[1], vec!["", "south"]);
+    assert!(preview.error.is_none());
+}
+
+#[test]
+fn test_data_preview_step_follows_task_selection_when_loaded() {
+    let mut wizard = WizardState::new();
+    wizard.data.task = Some(WizardTask::Reduction);
+    wizard.data.available_columns = vec!["col1".to_string(), "col2".to_string()];
+    wizard.build_steps();
+    assert_eq!(wizard.steps.len(), 8, "No preview step without a preview");
+
+    wizard.data.preview = Some(DataPreview::default());
+    wizard.build_steps();
+    assert_eq!(wizard.steps.len(), 9);
+    assert!(matches!(wizard.steps[1], WizardStep::DataPreview { .. }));
+    assert!(matches!(
+        wizard.steps[2],
+        WizardStep::TargetSelection { .. }
+    ));
+
+    wizard.data.task = Some(WizardTask::Sampling);
+    wizard.build_steps();
+    assert!(matches!(wizard.steps[1], WizardStep::DataPreview { .. }));
+    assert!(matches!(
+        wizard.steps[2],
+        WizardStep::SamplingMethodSelection { .. }
+    ));
+}
+
+// ============================================================================
+// Target mapping step
+// ============================================================================
+
+fn reduction_wizard() -> WizardState {
+    let mut wizard = WizardState::new();
+    wizard.data.task = Some(WizardTask::Reduction);
+    wizard.data.available_columns = vec!["target".to_string(), "x".to_string()];
+    wizard.build_steps();
+    wizard
+}
+
+fn mapping_selection(wizard: &WizardState) -> (Vec<String>, Option<usize>, Option<usize>) {
+    wizard
+        .steps
+        .iter()
+        .find_map(|step| match step {
+            WizardStep::TargetMapping {
+                unique_values,
+                event_selected,
+                non_event_selected,
+                ..
+            } => Some((unique_values.clone(), *event_selected, *non_event_selected)),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_target_analysis_fills_mapping_step_and_preselects_cli_values() {
+    let values = vec!["bad".to_string(), "good".to_string(), "unknown".to_string()];
+    let mut wizard = reduction_wizard();
+    wizard.data.target_mapping = Some(TargetMapping::new(
+        "unknown".to_string(),
+        "good".to_string(),
+    ));
+    wizard.apply_target_analysis(Ok(TargetAnalysis::NeedsMapping {
+        unique_values: values.clone(),
+    }));
+
+    assert!(!wizard.data.target_is_binary);
+    assert!(wizard.data.target_error.is_none());
+    assert_eq!(wizard.data.target_unique_values, values);
+    // "good" is second in the non-event list once "unknown" is left out
+    assert_eq!(
+        mapping_selection(&wizard),
+        (values.clone(), Some(2), Some(1))
+    );
+    assert!(wizard.data.target_mapping.is_some());
+
+    // A mapping whose values the target does not have is dropped
+    wizard.data.target_mapping = Some(TargetMapping::new("Y".to_string(), "N".to_string()));
+    wizard.apply_target_analysis(Ok(TargetAnalysis::NeedsMapping {
+        unique_values: values.clone(),
+    }));
+    assert!(wizard.data.target_mapping.is_none());
+    assert_eq!(mapping_selection(&wizard), (values, Some(0), Some(0)));
+}
+
+#[test]
+fn test_target_analysis_errors_are_kept_for_the_mapping_step() {
+    let mut wizard = reduction_wizard();
+    wizard.apply_target_analysis(Ok(TargetAnalysis::NeedsMapping {
+        unique_values: vec!["yes".to_string()],
+    }));
+    assert!(wizard
+        .data
+        .target_error
+        .as_deref()
+        .unwrap()
+        .contains("'yes'"));
+    assert!(wizard.data.target_unique_values.is_empty());
+
+    wizard.apply_target_analysis(Err(anyhow::anyhow!("Target column 'target' is empty")));
+    assert_eq!(
+        wizard.data.target_error.as_deref(),
+        Some("Target column 'target' is empty")
+    );
+    assert!(!wizard.data.target_is_binary);
+
+    // A binary target clears the error and any mapping but a 0/1 one
+    wizard.data.target_mapping = Some(TargetMapping::new("Y".to_string(), "N".to_string()));
+    wizard.apply_target_analysis(Ok(TargetAnalysis::AlreadyBinary));
+    assert!(wizard.data.target_is_binary);
+    assert!(wizard.data.target_error.is_none());
+    assert!(wizard.data.target_mapping.is_none());
+
+    wizard.data.target_mapping = Some(TargetMapping::new("0".to_string(), "1".to_string()));
+    wizard.apply_target_analysis(Ok(TargetAnalysis::AlreadyBinary));
+    assert!(wizard.data.target_mapping.is_some());
+}
@@ -0,0 +1,107 @@
+//! Integration tests for the WoE transformation SQL in the report zip
+
+use std::io::Read;
+
+use assert_cmd::Command;
+use polars::prelude::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+use common::*;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// 400 rows with a 25% event rate. `age` and `region` separate the classes,
+/// `noise` is unrelated to the target.
+fn woe_df() -> DataFrame {
+    let n = 400;
+    let event = |i: usize| i.is_multiple_of(4);
+    df! {
+        "target" => (0..n).map(|i| i32::from(event(i))).collect::<Vec<_>>(),
+        "age" => (0..n)
+            .map(|i| 20.0 + ((i / 4) % 40) as f64 + if event(i) { 0.0 } else { 5.0 })
+            .collect::<Vec<_>>(),
+        "region" => (0..n)
+            .map(|i| if event(i) == (i % 3 == 0) { "north" } else { "south" })
+            .collect::<Vec<_>>(),
+        "noise" => (0..n).map(|i| ((i / 4) % 7) as f64).collect::<Vec<_>>(),
+    }
+    .unwrap()
+}
+
+fn read_zip_entry(dir: &std::path::Path, name: &str) -> Option<String> {
+    let file = std::fs::File::open(dir.join("test_data_reduction_report.zip")).unwrap();
+    let mut archive = ::zip::ZipArchive::new(file).unwrap();
+    let mut entry = archive.by_name(name).ok()?;
+    let mut content = String::new();
+    entry.read_to_string(&mut content).unwrap();
+    Some(content)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_woe_sql_matches_the_final_bins() {
+    let mut df = woe_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "target", "--no-confirm"])
+        .args(["--binning-strategy", "manual", "--bin-breaks", "age:30,45"])
+        .args(["--gini-threshold", "0.05"])
+        .assert()
+        .success();
+
+    let export: serde_json::Value =
+        serde_json::from_str(&read_zip_entry(dir.path(), "test_data_gini_analysis.json").unwrap())
+            .unwrap();
+    let age = export["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|f| f["feature_name"] == "age")
+        .unwrap();
+    let woes: Vec<f64> = age["bins"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|b| b["woe"].as_f64().unwrap())
+        .collect();
+    assert_eq!(woes.len(), 3);
+
+    let sql = read_zip_entry(dir.path(), "test_data_woe_transform.sql").unwrap();
+    let age_case = format!(
+        "    CASE\n        WHEN \"age\" IS NULL THEN 0\n        \
+         WHEN \"age\" < 30 THEN {}\n        WHEN \"age\" < 45 THEN {}\n        \
+         ELSE {}\n    END AS \"age\"",
+        woes[0], woes[1], woes[2]
+    );
+    assert!(sql.starts_with("SELECT\n"), "{sql}");
+    assert!(sql.contains(&age_case), "{sql}");
+    assert!(sql.contains("WHEN \"region\" IN ('north') THEN"), "{sql}");
+    assert!(sql.contains("END AS \"region\""), "{sql}");
+    // Dropped at the Gini step, so not scored
+    assert!(!sql.contains("noise"), "{sql}");
+}
+
+#[test]
+fn test_no_woe_sql_for_a_continuous_target() {
+    let mut df = woe_df();
+    let (dir, input) = create_temp_parquet(&mut df);
+    Command::new(env!("CARGO_BIN_EXE_lophi"))
+        .arg("--input")
+        .arg(&input)
+        .args(["--target", "age", "--target-type", "continuous"])
+        .arg("--no-confirm")
+        .assert()
+        .success();
+
+    assert!(read_zip_entry(dir.path(), "test_data_reduction_report.json").is_some());
+    assert!(read_zip_entry(dir.path(), "test_data_woe_transform.sql").is_none());
+}
@@ -0,0 +1,210 @@
+//! Tests for SAS transport (XPT) output
+
+use lophi::cli::convert::run_convert;
+use lophi::pipeline::xpt::{write_xpt, XptVersion};
+use polars::prelude::*;
+use tempfile::TempDir;
+
+const RECORD: usize = 80;
+
+/// One variable as read back from the namestr records
+struct Variable {
+    name: String,
+    numeric: bool,
+    length: usize,
+    position: usize,
+    format: String,
+}
+
+/// Minimal transport reader: the member name, variables and raw rows
+struct Transport {
+    member: String,
+    variables: Vec<Variable>,
+    rows: Vec<Vec<u8>>,
+}
+
+fn ibm_to_f64(bytes: &[u8]) -> Option<f64> {
+    if bytes[0] == 0x2e && bytes[1..].iter().all(|&b| b == 0) {
+        return None;
+    }
+    let sign = if bytes[0] & 0x80 != 0 { -1.0 } else { 1.0 };
+    let exponent = i32::from(bytes[0] & 0x7f) - 64;
+    let fraction = bytes[1..]
+        .iter()
+        .fold(0u64, |acc, &b| (acc << 8) | u64::from(b));
+    Some(sign * fraction as f64 / 2f64.powi(56) * 16f64.powi(exponent))
+}
+
+fn text(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).trim_end().to_string()
+}
+
+fn read_transport(bytes: &[u8], v8: bool) -> Transport {
+    assert_eq!(
+        bytes.len() % RECORD,
+        0,
+        "file must be whole 80-byte records"
+    );
+    let record = |i: usize| &bytes[i * RECORD..(i + 1) * RECORD];
+    let library = if v8 { "LIBV8" } else { "LIBRARY" };
+    assert!(text(record(0)).starts_with(&format!("HEADER RECORD*******{:<8}", library)));
+
+    let member = if v8 {
+        text(&record(5)[8..40])
+    } else {
+        text(&record(5)[8..16])
+    };
+    let namestr_header = text(record(7));
+    let count: usize = namestr_header[53..58].parse().unwrap();
+
+    let start = 8 * RECORD;
+    let mut variables = Vec::new();
+    for i in 0..count {
+        let ns = &bytes[start + i * 140..start + (i + 1) * 140];
+        let short = |range: std::ops::Range<usize>| {
+            i16::from_be_bytes([ns[range.start], ns[range.start + 1]])
+        };
+        variables.push(Variable {
+            name: if v8 {
+                text(&ns[88..120])
+            } else {
+                text(&ns[8..16])
+            },
+            numeric: short(0..2) == 1,
+            length: short(4..6) as usize,
+            position: i32::from_be_bytes(ns[84..88].try_into().unwrap()) as usize,
+            format: text(&ns[56..64]),
+        });
+    }
+
+    let obs_start = (start + count * 140).div_ceil(RECORD) * RECORD;
+    let obs_name = if v8 { "OBSV8" } else { "OBS" };
+    assert!(text(&bytes[obs_start..obs_start + RECORD])
+        .starts_with(&format!("HEADER RECORD*******{:<8}", obs_name)));
+    let width: usize = variables.iter().map(|v| v.length).sum();
+    let data = &bytes[obs_start + RECORD..];
+    let rows = data
+        .chunks_exact(width)
+        .filter(|row| row.iter().any(|&b| b != b' '))
+        .map(<[u8]>::to_vec)
+        .collect();
+    Transport {
+        member,
+        variables,
+        rows,
+    }
+}
+
+#[test]
+fn test_write_xpt_v5_types() {
+    let dates = Column::new("opened".into(), [Some(0i32), Some(19_000), None])
+        .cast(&DataType::Date)
+        .unwrap();
+    let df = DataFrame::new(vec![
+        Column::new("id".into(), [1i64, 2, 3]),
+        Column::new("balance".into(), [Some(-118.625f64), None, Some(1e6)]),
+        Column::new("grade".into(), [Some("A"), Some("BBB"), None]),
+        Column::new("default".into(), [true, false, true]),
+        dates,
+    ])
+    .unwrap();
+    assert_eq!(XptVersion::for_dataframe(&df), XptVersion::V5);
+
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("loans.xpt");
+    write_xpt(&df, &path, XptVersion::V5).unwrap();
+
+    let xpt = read_transport(&std::fs::read(&path).unwrap(), false);
+    assert_eq!(xpt.member, "LOANS");
+    let names: Vec<&str> = xpt.variables.iter().map(|v| v.name.as_str()).collect();
+    assert_eq!(names, ["id", "balance", "grade", "default", "opened"]);
+    assert_eq!(xpt.rows.len(), 3);
+
+    let grade = &xpt.variables[2];
+    assert!(!grade.numeric);
+    assert_eq!(grade.length, 3);
+    assert_eq!(xpt.variables[4].format, "DATE");
+
+    let number = |row: usize, var: usize| {
+        let v = &xpt.variables[var];
+        ibm_to_f64(&xpt.rows[row][v.position..v.position + 8])
+    };
+    let chars = |row: usize, var: usize| {
+        let v = &xpt.variables[var];
+        text(&xpt.rows[row][v.position..v.position + v.length])
+    };
+    assert_eq!(number(2, 0), Some(3.0));
+    assert_eq!(number(0, 1), Some(-118.625));
+    assert_eq!(number(1, 1), None);
+    assert_eq!(number(2, 1), Some(1e6));
+    assert_eq!(chars(1, 2), "BBB");
+    assert_eq!(chars(2, 2), "");
+    assert_eq!(number(0, 3), Some(1.0));
+    // SAS dates count days from 1960-01-01
+    assert_eq!(number(0, 4), Some(3653.0));
+    assert_eq!(number(1, 4), Some(22_653.0));
+    assert_eq!(number(2, 4), None);
+}
+
+#[test]
+fn test_long_names_select_v8() {
+    let df = df! {
+        "months_since_last_delinquency" => [1.5f64, 2.5],
+        "target" => [0i32, 1],
+    }
+    .unwrap();
+    assert_eq!(XptVersion::for_dataframe(&df), XptVersion::V8);
+
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("reduced.xpt");
+    let err = write_xpt(&df, &path, XptVersion::V5).unwrap_err();
+    assert!(err.to_string().contains("months_since_last_delinquency"));
+
+    write_xpt(&df, &path, XptVersion::V8).unwrap();
+    let xpt = read_transport(&std::fs::read(&path).unwrap(), true);
+    assert_eq!(xpt.member, "REDUCED");
+    assert_eq!(xpt.variables[0].name, "months_since_last_delinquency");
+    assert_eq!(xpt.rows.len(), 2);
+}
+
+#[test]
+fn test_unsupported_dtype_is_rejected() {
+    let list = Series::new("ids".into(), [1i32, 2])
+        .implode()
+        .unwrap()
+        .into_series();
+    let df = DataFrame::new(vec![list.into()]).unwrap();
+
+    let temp_dir = TempDir::new().unwrap();
+    let err = write_xpt(&df, &temp_dir.path().join("x.xpt"), XptVersion::V5).unwrap_err();
+    assert!(err.to_string().contains("cannot be written to XPT"));
+}
+
+#[test]
+fn test_csv_to_xpt_conversion() {
+    let temp_dir = TempDir::new().unwrap();
+    let csv_path = temp_dir.path().join("scores.csv");
+    std::fs::write(
+        &csv_path,
+        "id,score,band\n1,0.25,low\n2,0.75,high\n3,,mid\n",
+    )
+    .unwrap();
+    let xpt_path = temp_dir.path().join("scores.xpt");
+
+    // Streaming is requested but XPT is always written in memory
+    run_convert(&csv_path, Some(&xpt_path), 1000, None, false).unwrap();
+
+    let xpt = read_transport(&std::fs::read(&xpt_path).unwrap(), false);
+    assert_eq!(xpt.member, "SCORES");
+    assert_eq!(xpt.variables.len(), 3);
+    assert_eq!(xpt.rows.len(), 3);
+    let score = &xpt.variables[1];
+    assert_eq!(
+        ibm_to_f64(&xpt.rows[1][score.position..score.position + 8]),
+        Some(0.75)
+    );
+    assert_eq!(
+        ibm_to_f64(&xpt.rows[2][score.position..score.position + 8]),
+        None
+    );
+}